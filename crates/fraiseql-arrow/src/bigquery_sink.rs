@@ -0,0 +1,674 @@
+//! `BigQuery` sink streaming Arrow `RecordBatches` through the Storage Write API.
+//!
+//! Observer events and bulk exports are written to `BigQuery` tables with the
+//! `BigQueryWrite` gRPC service, rows serialized as protobuf messages described by
+//! a descriptor derived from the batch's Arrow schema:
+//!
+//! ```text
+//! Arrow RecordBatch
+//!     ↓
+//! BigQuerySink::run(mpsc::Receiver) / BigQuerySink::write_batches
+//!     ↓
+//! Route rows by `entity_type` → table
+//!     ↓
+//! CreateWriteStream (PENDING) → AppendRows (offsets) → FinalizeWriteStream
+//!     ↓
+//! BatchCommitWriteStreams → rows visible atomically
+//! ```
+//!
+//! Each flush of a table goes through its own pending stream, so its rows appear
+//! all at once or not at all. Quota (`RESOURCE_EXHAUSTED`) and transient errors
+//! retry the whole flush on a fresh stream with exponential backoff; the abandoned
+//! stream is never committed, so a retry does not duplicate rows.
+//!
+//! The Storage Write API does not create tables: create them beforehand, e.g.,
+//! from [`bigquery_table_schema`] with `bq mk --table`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+
+use arrow::{
+    array::{AsArray, RecordBatch, UInt32Array},
+    compute::take_record_batch,
+    datatypes::Schema,
+};
+use prost_types::DescriptorProto;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tonic::{Code, Status};
+use tracing::{error, info, warn};
+
+mod client;
+mod proto;
+
+pub use self::client::{BigQueryTokenProvider, MetadataServerTokenProvider, StaticTokenProvider};
+use self::{
+    client::WriteClient,
+    proto::{
+        AppendRows, AppendRowsOutcome, AppendRowsRequest, AppendRowsResponse,
+        BatchCommitWriteStreamsResponse, Int64Value, ProtoData, ProtoRows, ProtoSchema,
+    },
+};
+use crate::error::{ArrowFlightError, Result};
+
+/// Upper bound of an `AppendRows` request imposed by the API.
+const MAX_APPEND_REQUEST_BYTES: usize = 10_000_000;
+
+/// `BigQuery` sink configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BigQuerySinkConfig {
+    /// Google Cloud project of the dataset
+    #[serde(default = "default_bigquery_project_id")]
+    pub project_id: String,
+
+    /// Dataset holding the tables
+    #[serde(default = "default_bigquery_dataset")]
+    pub dataset: String,
+
+    /// Table of events whose entity type has no route (default: `fraiseql_events`)
+    #[serde(default = "default_bigquery_table")]
+    pub default_table: String,
+
+    /// Table per entity type (e.g., `Order` → `order_events`)
+    #[serde(default)]
+    pub table_routing: HashMap<String, String>,
+
+    /// Storage Write API endpoint (default: `https://bigquerystorage.googleapis.com`)
+    #[serde(default = "default_bigquery_endpoint")]
+    pub endpoint: String,
+
+    /// Rows buffered before flushing (default: 10000)
+    #[serde(default = "default_bigquery_batch_size")]
+    pub batch_size: usize,
+
+    /// Batch timeout in seconds (default: 5)
+    #[serde(default = "default_bigquery_batch_timeout_secs")]
+    pub batch_timeout_secs: u64,
+
+    /// Maximum number of retries of a flush on quota or transient errors
+    /// (default: 5)
+    #[serde(default = "default_bigquery_max_retries")]
+    pub max_retries: usize,
+
+    /// Delay before the first retry, doubled on each further one (default: 500)
+    #[serde(default = "default_bigquery_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Upper bound of the retry delay (default: 32000)
+    #[serde(default = "default_bigquery_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Maximum serialized rows per `AppendRows` request (default: 9 MiB; the API
+    /// rejects requests above 10 MB)
+    #[serde(default = "default_bigquery_max_request_bytes")]
+    pub max_request_bytes: usize,
+}
+
+/// Default project
+fn default_bigquery_project_id() -> String {
+    std::env::var("FRAISEQL_BIGQUERY_PROJECT").unwrap_or_default()
+}
+
+/// Default dataset
+fn default_bigquery_dataset() -> String {
+    std::env::var("FRAISEQL_BIGQUERY_DATASET").unwrap_or_default()
+}
+
+/// Default table
+fn default_bigquery_table() -> String {
+    std::env::var("FRAISEQL_BIGQUERY_TABLE").unwrap_or_else(|_| "fraiseql_events".to_string())
+}
+
+/// Default Storage Write API endpoint
+fn default_bigquery_endpoint() -> String {
+    std::env::var("FRAISEQL_BIGQUERY_ENDPOINT")
+        .unwrap_or_else(|_| "https://bigquerystorage.googleapis.com".to_string())
+}
+
+/// Default batch size
+fn default_bigquery_batch_size() -> usize {
+    std::env::var("FRAISEQL_BIGQUERY_BATCH_SIZE")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(10_000)
+}
+
+/// Default batch timeout
+fn default_bigquery_batch_timeout_secs() -> u64 {
+    std::env::var("FRAISEQL_BIGQUERY_BATCH_TIMEOUT_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(5)
+}
+
+/// Default max retries
+fn default_bigquery_max_retries() -> usize {
+    std::env::var("FRAISEQL_BIGQUERY_MAX_RETRIES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(5)
+}
+
+/// Default initial backoff
+const fn default_bigquery_initial_backoff_ms() -> u64 {
+    500
+}
+
+/// Default maximum backoff
+const fn default_bigquery_max_backoff_ms() -> u64 {
+    32_000
+}
+
+/// Default request size limit
+const fn default_bigquery_max_request_bytes() -> usize {
+    9 * 1024 * 1024
+}
+
+impl Default for BigQuerySinkConfig {
+    fn default() -> Self {
+        Self {
+            project_id:         default_bigquery_project_id(),
+            dataset:            default_bigquery_dataset(),
+            default_table:      default_bigquery_table(),
+            table_routing:      HashMap::new(),
+            endpoint:           default_bigquery_endpoint(),
+            batch_size:         default_bigquery_batch_size(),
+            batch_timeout_secs: default_bigquery_batch_timeout_secs(),
+            max_retries:        default_bigquery_max_retries(),
+            initial_backoff_ms: default_bigquery_initial_backoff_ms(),
+            max_backoff_ms:     default_bigquery_max_backoff_ms(),
+            max_request_bytes:  default_bigquery_max_request_bytes(),
+        }
+    }
+}
+
+/// Whether `table` is a valid `BigQuery` table name (letters, digits, underscores).
+fn is_valid_table_name(table: &str) -> bool {
+    !table.is_empty()
+        && table.len() <= 1024
+        && table.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl BigQuerySinkConfig {
+    /// Apply environment variable overrides to the configuration
+    #[must_use]
+    pub fn with_env_overrides(mut self) -> Self {
+        if let Ok(project_id) = std::env::var("FRAISEQL_BIGQUERY_PROJECT") {
+            self.project_id = project_id;
+        }
+        if let Ok(dataset) = std::env::var("FRAISEQL_BIGQUERY_DATASET") {
+            self.dataset = dataset;
+        }
+        if let Ok(table) = std::env::var("FRAISEQL_BIGQUERY_TABLE") {
+            self.default_table = table;
+        }
+        if let Ok(endpoint) = std::env::var("FRAISEQL_BIGQUERY_ENDPOINT") {
+            self.endpoint = endpoint;
+        }
+        if let Ok(batch_size) = std::env::var("FRAISEQL_BIGQUERY_BATCH_SIZE") {
+            if let Ok(size) = batch_size.parse() {
+                self.batch_size = size;
+            }
+        }
+        if let Ok(timeout) = std::env::var("FRAISEQL_BIGQUERY_BATCH_TIMEOUT_SECS") {
+            if let Ok(secs) = timeout.parse() {
+                self.batch_timeout_secs = secs;
+            }
+        }
+        if let Ok(retries) = std::env::var("FRAISEQL_BIGQUERY_MAX_RETRIES") {
+            if let Ok(count) = retries.parse() {
+                self.max_retries = count;
+            }
+        }
+        self
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Configuration`] if the project or dataset is
+    /// empty, a table name is invalid, the endpoint is not an `http(s)` URL, batch
+    /// size is out of range (1–100,000), batch timeout is zero, the initial backoff
+    /// is zero or above the maximum, or the request size limit is out of range
+    /// (1–10,000,000 bytes).
+    pub fn validate(&self) -> Result<()> {
+        if self.project_id.is_empty() {
+            return Err(ArrowFlightError::Configuration(
+                "BigQuery project cannot be empty".to_string(),
+            ));
+        }
+        if self.dataset.is_empty() {
+            return Err(ArrowFlightError::Configuration(
+                "BigQuery dataset cannot be empty".to_string(),
+            ));
+        }
+        if let Some(table) = std::iter::once(&self.default_table)
+            .chain(self.table_routing.values())
+            .find(|table| !is_valid_table_name(table))
+        {
+            return Err(ArrowFlightError::Configuration(format!(
+                "Invalid BigQuery table name '{table}': use letters, digits and underscores"
+            )));
+        }
+        if !self.endpoint.starts_with("https://") && !self.endpoint.starts_with("http://") {
+            return Err(ArrowFlightError::Configuration(format!(
+                "BigQuery endpoint must use http:// or https:// scheme: {}",
+                self.endpoint
+            )));
+        }
+        if self.batch_size == 0 || self.batch_size > 100_000 {
+            return Err(ArrowFlightError::Configuration(
+                "Batch size must be between 1 and 100,000".to_string(),
+            ));
+        }
+        if self.batch_timeout_secs == 0 {
+            return Err(ArrowFlightError::Configuration(
+                "Batch timeout must be greater than 0".to_string(),
+            ));
+        }
+        if self.initial_backoff_ms == 0 || self.initial_backoff_ms > self.max_backoff_ms {
+            return Err(ArrowFlightError::Configuration(
+                "Initial backoff must be greater than 0 and at most the maximum backoff"
+                    .to_string(),
+            ));
+        }
+        if self.max_request_bytes == 0 || self.max_request_bytes > MAX_APPEND_REQUEST_BYTES {
+            return Err(ArrowFlightError::Configuration(
+                "Maximum request size must be between 1 and 10,000,000 bytes".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// The table events of `entity_type` are written to.
+    #[must_use]
+    pub fn table_for(&self, entity_type: &str) -> &str {
+        self.table_routing.get(entity_type).unwrap_or(&self.default_table)
+    }
+
+    /// Resource name of `table` (`projects/*/datasets/*/tables/*`).
+    #[must_use]
+    pub fn table_path(&self, table: &str) -> String {
+        format!("projects/{}/datasets/{}/tables/{table}", self.project_id, self.dataset)
+    }
+
+    /// Delay before retry number `attempt` (0-based): the initial backoff doubled
+    /// per attempt, capped at the maximum.
+    #[must_use]
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = u32::try_from(attempt).map_or(u64::MAX, |exp| 2_u64.saturating_pow(exp));
+        Duration::from_millis(
+            self.initial_backoff_ms.saturating_mul(factor).min(self.max_backoff_ms),
+        )
+    }
+}
+
+/// `BigQuery` table schema (the JSON accepted by `bq mk --table`) of Arrow `schema`.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Conversion`] for a column the sink cannot write.
+pub fn bigquery_table_schema(schema: &Schema) -> Result<serde_json::Value> {
+    let kinds = proto::column_kinds(schema)?;
+    let fields = schema
+        .fields()
+        .iter()
+        .zip(kinds)
+        .map(|(field, kind)| {
+            serde_json::json!({
+                "name": field.name(),
+                "type": kind.bigquery_type(),
+                "mode": if field.is_nullable() { "NULLABLE" } else { "REQUIRED" },
+            })
+        })
+        .collect();
+    Ok(serde_json::Value::Array(fields))
+}
+
+/// Split each batch into per-table batches by its `entity_type` column.
+///
+/// Rows with a null entity type go to the default table.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Conversion`] if a batch has no `Utf8` `entity_type`
+/// column.
+fn route_by_entity_type(
+    config: &BigQuerySinkConfig,
+    batches: &[RecordBatch],
+) -> Result<BTreeMap<String, Vec<RecordBatch>>> {
+    let mut routed: BTreeMap<String, Vec<RecordBatch>> = BTreeMap::new();
+    for batch in batches {
+        let entity_type = batch
+            .column_by_name("entity_type")
+            .ok_or_else(|| {
+                ArrowFlightError::Conversion("Missing 'entity_type' column".to_string())
+            })?
+            .as_string_opt::<i32>()
+            .ok_or_else(|| {
+                ArrowFlightError::Conversion("'entity_type' column is not StringArray".to_string())
+            })?;
+
+        let mut rows: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+        for (row, entity) in (0_u32..).zip(entity_type.iter()) {
+            let table = entity.map_or(config.default_table.as_str(), |e| config.table_for(e));
+            rows.entry(table).or_default().push(row);
+        }
+        for (table, indices) in rows {
+            let part = if indices.len() == batch.num_rows() {
+                batch.clone()
+            } else {
+                take_record_batch(batch, &UInt32Array::from(indices))?
+            };
+            routed.entry(table.to_string()).or_default().push(part);
+        }
+    }
+    Ok(routed)
+}
+
+/// Rows of one flush, serialized once and re-sent as-is by retries.
+struct EncodedRows {
+    descriptor: DescriptorProto,
+    rows:       Vec<Vec<u8>>,
+}
+
+impl EncodedRows {
+    /// Encode `batches`, which must share one schema.
+    fn new(batches: &[RecordBatch]) -> Result<Option<Self>> {
+        let Some(first) = batches.first() else {
+            return Ok(None);
+        };
+        let schema = first.schema();
+        let mut rows = Vec::new();
+        for batch in batches {
+            if batch.schema() != schema {
+                return Err(ArrowFlightError::Conversion(
+                    "BigQuery sink batches of one table must share a schema".to_string(),
+                ));
+            }
+            rows.extend(proto::encode_rows(batch)?);
+        }
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            descriptor: proto::row_descriptor(&schema)?,
+            rows,
+        }))
+    }
+
+    /// `AppendRows` requests of `stream`, each at most `max_bytes` of rows (or a
+    /// single larger row), with consecutive offsets. Only the first carries the
+    /// writer schema.
+    fn append_requests(&self, stream: &str, max_bytes: usize) -> Vec<AppendRowsRequest> {
+        let mut requests = Vec::new();
+        let mut start = 0;
+        while start < self.rows.len() {
+            let mut end = start;
+            let mut size = 0;
+            while end < self.rows.len()
+                && (end == start || size + self.rows[end].len() <= max_bytes)
+            {
+                size += self.rows[end].len();
+                end += 1;
+            }
+            requests.push(AppendRowsRequest {
+                write_stream: stream.to_string(),
+                offset:       Some(Int64Value {
+                    value: i64::try_from(start).unwrap_or(i64::MAX),
+                }),
+                rows:         Some(AppendRows::ProtoRows(ProtoData {
+                    writer_schema: requests.is_empty().then(|| ProtoSchema {
+                        proto_descriptor: Some(self.descriptor.clone()),
+                    }),
+                    rows:          Some(ProtoRows {
+                        serialized_rows: self.rows[start..end].to_vec(),
+                    }),
+                })),
+            });
+            start = end;
+        }
+        requests
+    }
+}
+
+/// Whether a failed flush is retried: quota exhaustion and transient failures.
+const fn is_retryable(code: Code) -> bool {
+    matches!(
+        code,
+        Code::ResourceExhausted | Code::Unavailable | Code::DeadlineExceeded | Code::Aborted
+    )
+}
+
+/// Turn an `AppendRows` response reporting a failure into an error.
+///
+/// Rejected rows fail the flush permanently; retrying cannot make them valid.
+fn check_append(response: &AppendRowsResponse) -> std::result::Result<(), Status> {
+    if let Some(AppendRowsOutcome::Error(status)) = &response.response {
+        return Err(Status::new(Code::from_i32(status.code), status.message.clone()));
+    }
+    if let Some(first) = response.row_errors.first() {
+        return Err(Status::invalid_argument(format!(
+            "{} row(s) rejected; row {}: {}",
+            response.row_errors.len(),
+            first.index,
+            first.message
+        )));
+    }
+    Ok(())
+}
+
+/// Turn a `BatchCommitWriteStreams` response without commit time into an error.
+fn check_commit(response: &BatchCommitWriteStreamsResponse) -> std::result::Result<(), Status> {
+    if response.commit_time.is_some() {
+        return Ok(());
+    }
+    let reason = response.stream_errors.first().map_or_else(
+        || "no commit time returned".to_string(),
+        |e| format!("{}: {}", e.entity, e.error_message),
+    );
+    Err(Status::aborted(format!("Commit failed: {reason}")))
+}
+
+/// `BigQuery` sink for consuming Arrow `RecordBatches`
+pub struct BigQuerySink {
+    config: BigQuerySinkConfig,
+    client: WriteClient,
+    tokens: Arc<dyn BigQueryTokenProvider>,
+}
+
+impl BigQuerySink {
+    /// Create a new `BigQuery` sink authenticating with `tokens`.
+    ///
+    /// The connection is established on the first write.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Configuration`] if `config.validate()` fails or
+    /// the endpoint is not a valid URI.
+    pub fn new(config: BigQuerySinkConfig, tokens: Arc<dyn BigQueryTokenProvider>) -> Result<Self> {
+        config.validate()?;
+        let client = WriteClient::new(&config.endpoint)?;
+
+        info!(
+            project = %config.project_id,
+            dataset = %config.dataset,
+            default_table = %config.default_table,
+            routes = config.table_routing.len(),
+            batch_size = config.batch_size,
+            timeout_secs = config.batch_timeout_secs,
+            "Creating BigQuery sink"
+        );
+
+        Ok(Self {
+            config,
+            client,
+            tokens,
+        })
+    }
+
+    /// The sink's configuration.
+    #[must_use]
+    pub const fn config(&self) -> &BigQuerySinkConfig {
+        &self.config
+    }
+
+    /// Write `batches` (e.g., a bulk export) to `table` in one atomic commit.
+    ///
+    /// Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Conversion`] if the batches cannot be encoded
+    /// (unsupported column types, differing schemas), or
+    /// [`ArrowFlightError::External`] if the write fails permanently or after
+    /// `max_retries` retries.
+    pub async fn write_batches(&self, table: &str, batches: &[RecordBatch]) -> Result<u64> {
+        if !is_valid_table_name(table) {
+            return Err(ArrowFlightError::Configuration(format!(
+                "Invalid BigQuery table name '{table}'"
+            )));
+        }
+        let Some(encoded) = EncodedRows::new(batches)? else {
+            return Ok(0);
+        };
+        self.write_with_retry(table, &encoded).await
+    }
+
+    /// Write observer event batches, each row to the table routed from its
+    /// `entity_type`.
+    ///
+    /// Returns the number of rows written. Tables are committed one after the
+    /// other: when a later table fails, the earlier ones stay written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a batch has no `entity_type` column, or as
+    /// [`write_batches`](Self::write_batches) does.
+    pub async fn write_events(&self, batches: &[RecordBatch]) -> Result<u64> {
+        let mut written = 0;
+        for (table, parts) in route_by_entity_type(&self.config, batches)? {
+            written += self.write_batches(&table, &parts).await?;
+        }
+        Ok(written)
+    }
+
+    /// Run the sink, consuming observer event `RecordBatches` from the channel
+    ///
+    /// Batches are buffered until `batch_size` rows or `batch_timeout_secs` and
+    /// then written with [`write_events`](Self::write_events).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a flush fails permanently or after retries.
+    pub async fn run(&self, mut rx: mpsc::Receiver<RecordBatch>) -> Result<()> {
+        let mut buffer: Vec<RecordBatch> = Vec::new();
+        let mut buffered_rows = 0;
+        let batch_timeout = Duration::from_secs(self.config.batch_timeout_secs);
+
+        loop {
+            tokio::select! {
+                maybe_batch = rx.recv() => {
+                    let Some(batch) = maybe_batch else {
+                        if !buffer.is_empty() {
+                            info!(count = buffered_rows, "Flushing final batch on channel close");
+                            self.flush(&buffer).await?;
+                        }
+                        return Ok(());
+                    };
+                    buffered_rows += batch.num_rows();
+                    buffer.push(batch);
+                    if buffered_rows >= self.config.batch_size {
+                        self.flush(&buffer).await?;
+                        buffer.clear();
+                        buffered_rows = 0;
+                    }
+                }
+
+                () = tokio::time::sleep(batch_timeout) => {
+                    if !buffer.is_empty() {
+                        info!(count = buffered_rows, "Flushing batch due to timeout");
+                        self.flush(&buffer).await?;
+                        buffer.clear();
+                        buffered_rows = 0;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Write buffered event batches, logging a failure before returning it.
+    async fn flush(&self, buffer: &[RecordBatch]) -> Result<()> {
+        match self.write_events(buffer).await {
+            Ok(count) => {
+                info!(count, "Batch written to BigQuery");
+                Ok(())
+            },
+            Err(e) => {
+                error!(error = %e, "Failed to write batch to BigQuery");
+                Err(e)
+            },
+        }
+    }
+
+    /// Write `encoded` to `table`, retrying quota and transient failures with
+    /// exponential backoff.
+    async fn write_with_retry(&self, table: &str, encoded: &EncodedRows) -> Result<u64> {
+        let mut attempt = 0;
+        loop {
+            match self.try_write(table, encoded).await {
+                Ok(count) => return Ok(count),
+                Err(status) if is_retryable(status.code()) && attempt < self.config.max_retries => {
+                    let backoff = self.config.backoff(attempt);
+                    warn!(
+                        table,
+                        attempt = attempt + 1,
+                        code = ?status.code(),
+                        error = %status.message(),
+                        backoff_ms = backoff.as_millis(),
+                        "BigQuery write failed, retrying on a new stream"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                },
+                Err(status) => {
+                    return Err(ArrowFlightError::External(format!(
+                        "BigQuery write to table '{table}' failed ({:?}): {}",
+                        status.code(),
+                        status.message()
+                    )));
+                },
+            }
+        }
+    }
+
+    /// Write `encoded` through a new pending stream and commit it.
+    async fn try_write(
+        &self,
+        table: &str,
+        encoded: &EncodedRows,
+    ) -> std::result::Result<u64, Status> {
+        let token = self
+            .tokens
+            .access_token()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let parent = self.config.table_path(table);
+
+        let stream = self.client.create_pending_stream(&token, &parent).await?;
+        let requests = encoded.append_requests(&stream, self.config.max_request_bytes);
+        for response in self.client.append_rows(&token, &stream, requests).await? {
+            check_append(&response)?;
+        }
+        let row_count = self.client.finalize(&token, &stream).await?;
+        check_commit(&self.client.commit(&token, &parent, vec![stream]).await?)?;
+        Ok(u64::try_from(row_count).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests;
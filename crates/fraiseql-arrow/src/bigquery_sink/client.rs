@@ -0,0 +1,300 @@
+//! Minimal `BigQueryWrite` gRPC client and access token providers.
+
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use tonic::{
+    Request, Status,
+    client::Grpc,
+    codegen::http::uri::PathAndQuery,
+    metadata::MetadataValue,
+    transport::{Channel, ClientTlsConfig, Endpoint},
+};
+use tonic_prost::ProstCodec;
+
+use super::proto::{
+    AppendRowsRequest, AppendRowsResponse, BatchCommitWriteStreamsRequest,
+    BatchCommitWriteStreamsResponse, CreateWriteStreamRequest, FinalizeWriteStreamRequest,
+    FinalizeWriteStreamResponse, WriteStream,
+};
+use crate::error::{ArrowFlightError, Result};
+
+/// Source of OAuth 2.0 access tokens for the Storage Write API.
+// Reason: used as dyn Trait (Arc<dyn BigQueryTokenProvider>); async_trait ensures Send bounds and
+// dyn-compatibility async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+pub trait BigQueryTokenProvider: Send + Sync {
+    /// A currently valid access token with the `bigquery.insertdata` scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::External`] if no token can be obtained.
+    async fn access_token(&self) -> Result<String>;
+}
+
+/// A fixed access token (e.g., from `gcloud auth print-access-token`).
+#[derive(Debug, Clone)]
+pub struct StaticTokenProvider {
+    token: String,
+}
+
+impl StaticTokenProvider {
+    /// Use `token` for every request.
+    #[must_use]
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl BigQueryTokenProvider for StaticTokenProvider {
+    async fn access_token(&self) -> Result<String> {
+        Ok(self.token.clone())
+    }
+}
+
+/// Default token endpoint of the GCE / GKE metadata server.
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// Tokens are refreshed this long before they expire.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// Access tokens of the attached service account, from the GCE / GKE metadata
+/// server, cached until shortly before they expire.
+pub struct MetadataServerTokenProvider {
+    url:    String,
+    http:   reqwest::Client,
+    cached: Mutex<Option<(String, Instant)>>,
+}
+
+#[derive(Deserialize)]
+struct MetadataToken {
+    access_token: String,
+    expires_in:   u64,
+}
+
+impl MetadataServerTokenProvider {
+    /// Use the default metadata server.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_url(METADATA_TOKEN_URL)
+    }
+
+    /// Use the token endpoint at `url` (e.g., a metadata server emulator).
+    #[must_use]
+    pub fn with_url(url: impl Into<String>) -> Self {
+        Self {
+            url:    url.into(),
+            http:   reqwest::Client::new(),
+            cached: Mutex::new(None),
+        }
+    }
+}
+
+impl Default for MetadataServerTokenProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BigQueryTokenProvider for MetadataServerTokenProvider {
+    async fn access_token(&self) -> Result<String> {
+        let mut cached = self.cached.lock().await;
+        if let Some((token, refresh_at)) = cached.as_ref() {
+            if Instant::now() < *refresh_at {
+                return Ok(token.clone());
+            }
+        }
+
+        let token: MetadataToken = self
+            .http
+            .get(&self.url)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| {
+                ArrowFlightError::External(format!("Failed to fetch BigQuery access token: {e}"))
+            })?
+            .json()
+            .await
+            .map_err(|e| {
+                ArrowFlightError::External(format!("Invalid metadata server token response: {e}"))
+            })?;
+        let lifetime = Duration::from_secs(token.expires_in).saturating_sub(TOKEN_EXPIRY_MARGIN);
+        *cached = Some((token.access_token.clone(), Instant::now() + lifetime));
+        Ok(token.access_token)
+    }
+}
+
+const CREATE_WRITE_STREAM: &str =
+    "/google.cloud.bigquery.storage.v1.BigQueryWrite/CreateWriteStream";
+const APPEND_ROWS: &str = "/google.cloud.bigquery.storage.v1.BigQueryWrite/AppendRows";
+const FINALIZE_WRITE_STREAM: &str =
+    "/google.cloud.bigquery.storage.v1.BigQueryWrite/FinalizeWriteStream";
+const BATCH_COMMIT_WRITE_STREAMS: &str =
+    "/google.cloud.bigquery.storage.v1.BigQueryWrite/BatchCommitWriteStreams";
+
+/// `BigQueryWrite` calls the sink makes, over a lazily connected channel.
+#[derive(Clone)]
+pub(super) struct WriteClient {
+    channel: Channel,
+}
+
+impl WriteClient {
+    /// Client of the service at `endpoint`; TLS with the webpki roots for `https`.
+    pub(super) fn new(endpoint: &str) -> Result<Self> {
+        let mut builder = Endpoint::from_shared(endpoint.to_string()).map_err(|e| {
+            ArrowFlightError::Configuration(format!("Invalid BigQuery endpoint {endpoint}: {e}"))
+        })?;
+        if endpoint.starts_with("https://") {
+            builder =
+                builder.tls_config(ClientTlsConfig::new().with_webpki_roots()).map_err(|e| {
+                    ArrowFlightError::Configuration(format!("BigQuery TLS configuration: {e}"))
+                })?;
+        }
+        Ok(Self {
+            channel: builder.connect_lazy(),
+        })
+    }
+
+    /// Authorize `message` with `token` and attach its routing header (e.g.,
+    /// `parent=projects/...`).
+    fn request<T>(
+        message: T,
+        token: &str,
+        routing: &str,
+    ) -> std::result::Result<Request<T>, Status> {
+        let mut request = Request::new(message);
+        let metadata = request.metadata_mut();
+        metadata.insert(
+            "authorization",
+            MetadataValue::try_from(format!("Bearer {token}"))
+                .map_err(|_| Status::unauthenticated("access token is not a valid header"))?,
+        );
+        metadata.insert(
+            "x-goog-request-params",
+            MetadataValue::try_from(routing)
+                .map_err(|_| Status::invalid_argument("resource name is not a valid header"))?,
+        );
+        Ok(request)
+    }
+
+    async fn ready(&self) -> std::result::Result<Grpc<Channel>, Status> {
+        let mut grpc = Grpc::new(self.channel.clone());
+        grpc.ready()
+            .await
+            .map_err(|e| Status::unavailable(format!("BigQuery service not ready: {e}")))?;
+        Ok(grpc)
+    }
+
+    /// Create a `PENDING` write stream on `table` (`projects/*/datasets/*/tables/*`).
+    pub(super) async fn create_pending_stream(
+        &self,
+        token: &str,
+        table: &str,
+    ) -> std::result::Result<String, Status> {
+        let message = CreateWriteStreamRequest {
+            parent:       table.to_string(),
+            write_stream: Some(WriteStream {
+                name:   String::new(),
+                r#type: super::proto::WRITE_STREAM_PENDING,
+            }),
+        };
+        let request = Self::request(message, token, &format!("parent={table}"))?;
+        let stream: WriteStream = self
+            .ready()
+            .await?
+            .unary(request, PathAndQuery::from_static(CREATE_WRITE_STREAM), ProstCodec::default())
+            .await?
+            .into_inner();
+        Ok(stream.name)
+    }
+
+    /// Send `requests` on one `AppendRows` connection and collect a response per
+    /// request.
+    pub(super) async fn append_rows(
+        &self,
+        token: &str,
+        stream: &str,
+        requests: Vec<AppendRowsRequest>,
+    ) -> std::result::Result<Vec<AppendRowsResponse>, Status> {
+        let expected = requests.len();
+        let request =
+            Self::request(tokio_stream::iter(requests), token, &format!("write_stream={stream}"))?;
+        let mut responses = self
+            .ready()
+            .await?
+            .streaming(request, PathAndQuery::from_static(APPEND_ROWS), ProstCodec::default())
+            .await?
+            .into_inner();
+        let mut collected = Vec::with_capacity(expected);
+        while collected.len() < expected {
+            match responses.message().await? {
+                Some(response) => collected.push(response),
+                None => {
+                    return Err(Status::unavailable(format!(
+                        "AppendRows closed after {} of {expected} responses",
+                        collected.len()
+                    )));
+                },
+            }
+        }
+        Ok(collected)
+    }
+
+    /// Finalize `stream`, returning its row count.
+    pub(super) async fn finalize(
+        &self,
+        token: &str,
+        stream: &str,
+    ) -> std::result::Result<i64, Status> {
+        let request = Self::request(
+            FinalizeWriteStreamRequest {
+                name: stream.to_string(),
+            },
+            token,
+            &format!("name={stream}"),
+        )?;
+        let response: FinalizeWriteStreamResponse = self
+            .ready()
+            .await?
+            .unary(request, PathAndQuery::from_static(FINALIZE_WRITE_STREAM), ProstCodec::default())
+            .await?
+            .into_inner();
+        Ok(response.row_count)
+    }
+
+    /// Atomically commit finalized `streams` of `table`.
+    pub(super) async fn commit(
+        &self,
+        token: &str,
+        table: &str,
+        streams: Vec<String>,
+    ) -> std::result::Result<BatchCommitWriteStreamsResponse, Status> {
+        let request = Self::request(
+            BatchCommitWriteStreamsRequest {
+                parent:        table.to_string(),
+                write_streams: streams,
+            },
+            token,
+            &format!("parent={table}"),
+        )?;
+        Ok(self
+            .ready()
+            .await?
+            .unary(
+                request,
+                PathAndQuery::from_static(BATCH_COMMIT_WRITE_STREAMS),
+                ProstCodec::default(),
+            )
+            .await?
+            .into_inner())
+    }
+}
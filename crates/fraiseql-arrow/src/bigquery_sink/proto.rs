@@ -0,0 +1,363 @@
+//! Storage Write API messages and Arrow → protobuf row encoding.
+//!
+//! Only the subset of `google.cloud.bigquery.storage.v1` the sink uses is declared,
+//! with the upstream field numbers. Rows are self-described: every append carries a
+//! proto2 `DescriptorProto` derived from the batch's Arrow schema, and each row is
+//! serialized against it field by field.
+
+use arrow::{
+    array::{Array, ArrayRef, AsArray, RecordBatch},
+    compute::{CastOptions, cast_with_options},
+    datatypes::{DataType, Date32Type, Float64Type, Int64Type, Schema, TimeUnit},
+};
+use prost::{
+    bytes::BufMut,
+    encoding::{WireType, encode_key, encode_varint},
+};
+use prost_types::{
+    DescriptorProto, FieldDescriptorProto,
+    field_descriptor_proto::{Label, Type},
+};
+
+use crate::error::{ArrowFlightError, Result};
+
+/// `WriteStream.Type.PENDING`: rows become visible when the stream is committed.
+pub(super) const WRITE_STREAM_PENDING: i32 = 2;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct WriteStream {
+    #[prost(string, tag = "1")]
+    pub name:   String,
+    #[prost(int32, tag = "2")]
+    pub r#type: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct CreateWriteStreamRequest {
+    #[prost(string, tag = "1")]
+    pub parent:       String,
+    #[prost(message, optional, tag = "2")]
+    pub write_stream: Option<WriteStream>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct Int64Value {
+    #[prost(int64, tag = "1")]
+    pub value: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct ProtoSchema {
+    #[prost(message, optional, tag = "1")]
+    pub proto_descriptor: Option<DescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct ProtoRows {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub serialized_rows: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct ProtoData {
+    #[prost(message, optional, tag = "1")]
+    pub writer_schema: Option<ProtoSchema>,
+    #[prost(message, optional, tag = "2")]
+    pub rows:          Option<ProtoRows>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub(super) enum AppendRows {
+    #[prost(message, tag = "4")]
+    ProtoRows(ProtoData),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct AppendRowsRequest {
+    #[prost(string, tag = "1")]
+    pub write_stream: String,
+    #[prost(message, optional, tag = "2")]
+    pub offset:       Option<Int64Value>,
+    #[prost(oneof = "AppendRows", tags = "4")]
+    pub rows:         Option<AppendRows>,
+}
+
+/// `google.rpc.Status`
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    pub code:    i32,
+    #[prost(string, tag = "2")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct AppendResult {
+    #[prost(message, optional, tag = "1")]
+    pub offset: Option<Int64Value>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub(super) enum AppendRowsOutcome {
+    #[prost(message, tag = "1")]
+    AppendResult(AppendResult),
+    #[prost(message, tag = "2")]
+    Error(RpcStatus),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct RowError {
+    #[prost(int64, tag = "1")]
+    pub index:   i64,
+    #[prost(int32, tag = "2")]
+    pub code:    i32,
+    #[prost(string, tag = "3")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct AppendRowsResponse {
+    #[prost(oneof = "AppendRowsOutcome", tags = "1, 2")]
+    pub response:   Option<AppendRowsOutcome>,
+    #[prost(message, repeated, tag = "4")]
+    pub row_errors: Vec<RowError>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct FinalizeWriteStreamRequest {
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct FinalizeWriteStreamResponse {
+    #[prost(int64, tag = "1")]
+    pub row_count: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BatchCommitWriteStreamsRequest {
+    #[prost(string, tag = "1")]
+    pub parent:        String,
+    #[prost(string, repeated, tag = "2")]
+    pub write_streams: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct StorageError {
+    #[prost(int32, tag = "1")]
+    pub code:          i32,
+    #[prost(string, tag = "2")]
+    pub entity:        String,
+    #[prost(string, tag = "3")]
+    pub error_message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BatchCommitWriteStreamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub commit_time:   Option<prost_types::Timestamp>,
+    #[prost(message, repeated, tag = "2")]
+    pub stream_errors: Vec<StorageError>,
+}
+
+/// How a column is stored in the protobuf row and which BigQuery type receives it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ColumnKind {
+    /// `STRING` (also `JSON`)
+    String,
+    /// `BOOL`
+    Bool,
+    /// `INT64`
+    Int64,
+    /// `FLOAT64`
+    Float64,
+    /// `TIMESTAMP`, as microseconds since the epoch
+    Timestamp,
+    /// `DATE`, as days since the epoch
+    Date,
+}
+
+impl ColumnKind {
+    /// The kind storing Arrow `data_type`, if supported.
+    pub(super) fn of(data_type: &DataType) -> Option<Self> {
+        Some(match data_type {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Self::String,
+            DataType::Dictionary(_, value) if Self::of(value) == Some(Self::String) => Self::String,
+            DataType::Boolean => Self::Bool,
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64 => Self::Int64,
+            DataType::Float32 | DataType::Float64 => Self::Float64,
+            DataType::Timestamp(..) => Self::Timestamp,
+            DataType::Date32 => Self::Date,
+            _ => return None,
+        })
+    }
+
+    /// BigQuery column type name.
+    pub(super) const fn bigquery_type(self) -> &'static str {
+        match self {
+            Self::String => "STRING",
+            Self::Bool => "BOOL",
+            Self::Int64 => "INT64",
+            Self::Float64 => "FLOAT64",
+            Self::Timestamp => "TIMESTAMP",
+            Self::Date => "DATE",
+        }
+    }
+
+    const fn proto_type(self) -> Type {
+        match self {
+            Self::String => Type::String,
+            Self::Bool => Type::Bool,
+            Self::Int64 | Self::Timestamp => Type::Int64,
+            Self::Float64 => Type::Double,
+            Self::Date => Type::Int32,
+        }
+    }
+
+    /// Arrow type a column is cast to before encoding.
+    const fn canonical_type(self) -> DataType {
+        match self {
+            Self::String => DataType::Utf8,
+            Self::Bool => DataType::Boolean,
+            Self::Int64 => DataType::Int64,
+            Self::Float64 => DataType::Float64,
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, None),
+            Self::Date => DataType::Date32,
+        }
+    }
+}
+
+/// The [`ColumnKind`] of every field of `schema`.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Conversion`] for a column type BigQuery rows cannot
+/// carry or a column name that is not a valid protobuf field name.
+pub(super) fn column_kinds(schema: &Schema) -> Result<Vec<ColumnKind>> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let name = field.name();
+            let valid_name =
+                name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid_name {
+                return Err(ArrowFlightError::Conversion(format!(
+                    "column '{name}' is not a valid BigQuery Storage Write field name"
+                )));
+            }
+            ColumnKind::of(field.data_type()).ok_or_else(|| {
+                ArrowFlightError::Conversion(format!(
+                    "column '{name}' has Arrow type {}, which the BigQuery sink cannot write",
+                    field.data_type()
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Proto2 descriptor of the rows of `schema`; field `i + 1` is column `i`.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Conversion`] as [`column_kinds`] does.
+pub(super) fn row_descriptor(schema: &Schema) -> Result<DescriptorProto> {
+    let kinds = column_kinds(schema)?;
+    let field = schema.fields().iter().zip(kinds).zip(1..).map(|((field, kind), number)| {
+        FieldDescriptorProto {
+            name: Some(field.name().clone()),
+            number: Some(number),
+            label: Some(Label::Optional.into()),
+            r#type: Some(kind.proto_type().into()),
+            ..FieldDescriptorProto::default()
+        }
+    });
+    Ok(DescriptorProto {
+        name: Some("FraiseqlRow".to_string()),
+        field: field.collect(),
+        ..DescriptorProto::default()
+    })
+}
+
+/// Serialize every row of `batch` against [`row_descriptor`]; null cells are
+/// omitted.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Conversion`] for unsupported columns or a value that
+/// does not fit its BigQuery type (e.g., a `UInt64` above `i64::MAX`).
+pub(super) fn encode_rows(batch: &RecordBatch) -> Result<Vec<Vec<u8>>> {
+    let kinds = column_kinds(&batch.schema())?;
+    let options = CastOptions {
+        safe: false,
+        ..CastOptions::default()
+    };
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(&kinds)
+        .map(|(column, kind)| {
+            let canonical = cast_with_options(column, &kind.canonical_type(), &options)?;
+            // Timestamps are written as their raw microsecond counts.
+            Ok(if *kind == ColumnKind::Timestamp {
+                cast_with_options(&canonical, &DataType::Int64, &options)?
+            } else {
+                canonical
+            })
+        })
+        .collect::<std::result::Result<Vec<ArrayRef>, arrow::error::ArrowError>>()
+        .map_err(|e| ArrowFlightError::Conversion(format!("BigQuery row encoding: {e}")))?;
+
+    let mut rows = Vec::with_capacity(batch.num_rows());
+    for row in 0..batch.num_rows() {
+        let mut buf = Vec::new();
+        for ((column, kind), tag) in columns.iter().zip(&kinds).zip(1_u32..) {
+            if column.is_null(row) {
+                continue;
+            }
+            encode_cell(&mut buf, tag, *kind, column, row);
+        }
+        rows.push(buf);
+    }
+    Ok(rows)
+}
+
+/// Append field `tag` holding `column[row]` (already cast to the kind's canonical
+/// type) to `buf`.
+fn encode_cell(buf: &mut Vec<u8>, tag: u32, kind: ColumnKind, column: &ArrayRef, row: usize) {
+    match kind {
+        ColumnKind::String => {
+            let value = column.as_string::<i32>().value(row);
+            encode_key(tag, WireType::LengthDelimited, buf);
+            encode_varint(value.len() as u64, buf);
+            buf.put_slice(value.as_bytes());
+        },
+        ColumnKind::Bool => {
+            encode_key(tag, WireType::Varint, buf);
+            encode_varint(u64::from(column.as_boolean().value(row)), buf);
+        },
+        ColumnKind::Int64 | ColumnKind::Timestamp => {
+            encode_key(tag, WireType::Varint, buf);
+            encode_varint(column.as_primitive::<Int64Type>().value(row).cast_unsigned(), buf);
+        },
+        ColumnKind::Float64 => {
+            encode_key(tag, WireType::SixtyFourBit, buf);
+            buf.put_f64_le(column.as_primitive::<Float64Type>().value(row));
+        },
+        ColumnKind::Date => {
+            // int32 values are sign-extended to 64 bits on the wire.
+            let days = i64::from(column.as_primitive::<Date32Type>().value(row));
+            encode_key(tag, WireType::Varint, buf);
+            encode_varint(days.cast_unsigned(), buf);
+        },
+    }
+}
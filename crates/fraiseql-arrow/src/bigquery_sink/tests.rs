@@ -0,0 +1,364 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use arrow::{
+    array::{
+        ArrayRef, BooleanArray, Date32Array, Float64Array, StringArray, TimestampMicrosecondArray,
+        UInt64Array,
+    },
+    datatypes::{DataType, Field, TimeUnit},
+};
+use prost::Message;
+use prost_types::field_descriptor_proto::Type;
+
+use super::{
+    proto::{AppendResult, RowError, RpcStatus},
+    *,
+};
+use crate::schema::observer_event_schema;
+
+fn test_config() -> BigQuerySinkConfig {
+    BigQuerySinkConfig {
+        project_id: "analytics-prod".to_string(),
+        dataset: "fraiseql".to_string(),
+        ..Default::default()
+    }
+}
+
+/// Decoding side of a row with a string, int64, double, bool and date column.
+#[derive(Clone, PartialEq, prost::Message)]
+struct DecodedRow {
+    #[prost(string, optional, tag = "1")]
+    name:    Option<String>,
+    #[prost(int64, optional, tag = "2")]
+    count:   Option<i64>,
+    #[prost(double, optional, tag = "3")]
+    score:   Option<f64>,
+    #[prost(bool, optional, tag = "4")]
+    active:  Option<bool>,
+    #[prost(int32, optional, tag = "5")]
+    day:     Option<i32>,
+    #[prost(int64, optional, tag = "6")]
+    seen_at: Option<i64>,
+}
+
+fn mixed_batch() -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("name", DataType::Utf8, true),
+        Field::new("count", DataType::UInt64, false),
+        Field::new("score", DataType::Float64, true),
+        Field::new("active", DataType::Boolean, false),
+        Field::new("day", DataType::Date32, true),
+        Field::new(
+            "seen_at",
+            DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("UTC"))),
+            false,
+        ),
+    ]));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(vec![Some("alice"), None])),
+        Arc::new(UInt64Array::from(vec![3, 0])),
+        Arc::new(Float64Array::from(vec![Some(0.5), None])),
+        Arc::new(BooleanArray::from(vec![true, false])),
+        Arc::new(Date32Array::from(vec![Some(-1), Some(19_000)])),
+        Arc::new(
+            TimestampMicrosecondArray::from(vec![1_700_000_000_000_000, 1]).with_timezone("UTC"),
+        ),
+    ];
+    RecordBatch::try_new(schema, columns).unwrap()
+}
+
+fn event_batch(entity_types: &[Option<&str>]) -> RecordBatch {
+    let n = entity_types.len();
+    let ids: Vec<String> = (0..n).map(|i| format!("evt-{i}")).collect();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("event_id", DataType::Utf8, false),
+        Field::new("entity_type", DataType::Utf8, true),
+    ]));
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(StringArray::from(ids)),
+            Arc::new(StringArray::from(entity_types.to_vec())),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_config_default() {
+    let config = BigQuerySinkConfig::default();
+    assert_eq!(config.default_table, "fraiseql_events");
+    assert_eq!(config.endpoint, "https://bigquerystorage.googleapis.com");
+    assert_eq!(config.batch_size, 10_000);
+    assert_eq!(config.max_retries, 5);
+    assert_eq!(config.max_request_bytes, 9 * 1024 * 1024);
+}
+
+#[test]
+fn test_config_validate() {
+    assert!(test_config().validate().is_ok());
+
+    let invalid = [
+        BigQuerySinkConfig {
+            project_id: String::new(),
+            ..test_config()
+        },
+        BigQuerySinkConfig {
+            dataset: String::new(),
+            ..test_config()
+        },
+        BigQuerySinkConfig {
+            table_routing: HashMap::from([("Order".to_string(), "orders; DROP".to_string())]),
+            ..test_config()
+        },
+        BigQuerySinkConfig {
+            endpoint: "grpc://bigquerystorage.googleapis.com".to_string(),
+            ..test_config()
+        },
+        BigQuerySinkConfig {
+            batch_size: 0,
+            ..test_config()
+        },
+        BigQuerySinkConfig {
+            initial_backoff_ms: 64_000,
+            ..test_config()
+        },
+        BigQuerySinkConfig {
+            max_request_bytes: 20_000_000,
+            ..test_config()
+        },
+    ];
+    for config in invalid {
+        assert!(
+            matches!(config.validate(), Err(ArrowFlightError::Configuration(_))),
+            "expected Configuration error for {config:?}"
+        );
+    }
+}
+
+#[test]
+fn test_table_routing_and_path() {
+    let config = BigQuerySinkConfig {
+        table_routing: HashMap::from([("Order".to_string(), "order_events".to_string())]),
+        ..test_config()
+    };
+    assert_eq!(config.table_for("Order"), "order_events");
+    assert_eq!(config.table_for("User"), "fraiseql_events");
+    assert_eq!(
+        config.table_path("order_events"),
+        "projects/analytics-prod/datasets/fraiseql/tables/order_events"
+    );
+}
+
+#[test]
+fn test_backoff_is_exponential_and_capped() {
+    let config = BigQuerySinkConfig {
+        initial_backoff_ms: 500,
+        max_backoff_ms: 4_000,
+        ..test_config()
+    };
+    let delays: Vec<u128> = (0..6).map(|attempt| config.backoff(attempt).as_millis()).collect();
+    assert_eq!(delays, vec![500, 1_000, 2_000, 4_000, 4_000, 4_000]);
+    assert_eq!(config.backoff(200).as_millis(), 4_000);
+}
+
+#[test]
+fn test_retryable_codes() {
+    assert!(is_retryable(Code::ResourceExhausted));
+    assert!(is_retryable(Code::Unavailable));
+    assert!(!is_retryable(Code::InvalidArgument));
+    assert!(!is_retryable(Code::PermissionDenied));
+}
+
+#[test]
+fn test_row_descriptor_maps_arrow_types() {
+    let descriptor = proto::row_descriptor(&mixed_batch().schema()).unwrap();
+    let fields: Vec<(&str, i32, Type)> =
+        descriptor.field.iter().map(|f| (f.name(), f.number(), f.r#type())).collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("name", 1, Type::String),
+            ("count", 2, Type::Int64),
+            ("score", 3, Type::Double),
+            ("active", 4, Type::Bool),
+            ("day", 5, Type::Int32),
+            ("seen_at", 6, Type::Int64),
+        ]
+    );
+}
+
+#[test]
+fn test_row_descriptor_rejects_unsupported_columns() {
+    let binary = Schema::new(vec![Field::new("payload", DataType::Binary, false)]);
+    assert!(matches!(proto::row_descriptor(&binary), Err(ArrowFlightError::Conversion(_))));
+
+    let bad_name = Schema::new(vec![Field::new("order-id", DataType::Utf8, false)]);
+    assert!(matches!(proto::row_descriptor(&bad_name), Err(ArrowFlightError::Conversion(_))));
+}
+
+#[test]
+fn test_encode_rows_roundtrips_and_omits_nulls() {
+    let rows = proto::encode_rows(&mixed_batch()).unwrap();
+    assert_eq!(rows.len(), 2);
+
+    let first = DecodedRow::decode(rows[0].as_slice()).unwrap();
+    assert_eq!(
+        first,
+        DecodedRow {
+            name:    Some("alice".to_string()),
+            count:   Some(3),
+            score:   Some(0.5),
+            active:  Some(true),
+            day:     Some(-1),
+            seen_at: Some(1_700_000_000_000_000),
+        }
+    );
+
+    let second = DecodedRow::decode(rows[1].as_slice()).unwrap();
+    assert_eq!(second.name, None);
+    assert_eq!(second.score, None);
+    assert_eq!(second.active, Some(false));
+    assert_eq!(second.seen_at, Some(1));
+}
+
+#[test]
+fn test_encode_rows_rejects_out_of_range_uint64() {
+    let schema = Arc::new(Schema::new(vec![Field::new("count", DataType::UInt64, false)]));
+    let batch =
+        RecordBatch::try_new(schema, vec![Arc::new(UInt64Array::from(vec![u64::MAX]))]).unwrap();
+    assert!(matches!(proto::encode_rows(&batch), Err(ArrowFlightError::Conversion(_))));
+}
+
+#[test]
+fn test_bigquery_table_schema_of_observer_events() {
+    let schema = bigquery_table_schema(&observer_event_schema()).unwrap();
+    let fields = schema.as_array().unwrap();
+    assert_eq!(fields.len(), 8);
+    assert_eq!(
+        fields[4],
+        serde_json::json!({"name": "timestamp", "type": "TIMESTAMP", "mode": "REQUIRED"})
+    );
+    assert_eq!(
+        fields[7],
+        serde_json::json!({"name": "org_id", "type": "STRING", "mode": "NULLABLE"})
+    );
+}
+
+#[test]
+fn test_route_by_entity_type() {
+    let config = BigQuerySinkConfig {
+        table_routing: HashMap::from([
+            ("Order".to_string(), "order_events".to_string()),
+            ("Invoice".to_string(), "billing_events".to_string()),
+        ]),
+        ..test_config()
+    };
+    let batch = event_batch(&[
+        Some("Order"),
+        Some("User"),
+        Some("Order"),
+        None,
+        Some("Invoice"),
+    ]);
+
+    let routed = route_by_entity_type(&config, &[batch]).unwrap();
+    let event_ids = |table: &str| -> Vec<String> {
+        routed[table]
+            .iter()
+            .flat_map(|b| {
+                b.column_by_name("event_id")
+                    .unwrap()
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|v| v.unwrap().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    };
+    assert_eq!(routed.len(), 3);
+    assert_eq!(event_ids("order_events"), vec!["evt-0", "evt-2"]);
+    assert_eq!(event_ids("fraiseql_events"), vec!["evt-1", "evt-3"]);
+    assert_eq!(event_ids("billing_events"), vec!["evt-4"]);
+}
+
+#[test]
+fn test_route_requires_entity_type_column() {
+    let schema = Arc::new(Schema::new(vec![Field::new("event_id", DataType::Utf8, false)]));
+    let batch =
+        RecordBatch::try_new(schema, vec![Arc::new(StringArray::from(vec!["evt-0"]))]).unwrap();
+    assert!(matches!(
+        route_by_entity_type(&test_config(), &[batch]),
+        Err(ArrowFlightError::Conversion(_))
+    ));
+}
+
+#[test]
+fn test_append_requests_are_chunked_with_offsets() {
+    let encoded = EncodedRows::new(&[event_batch(&[Some("Order"); 10])]).unwrap().unwrap();
+    let row_size = encoded.rows[0].len();
+    let requests = encoded.append_requests("stream-1", row_size * 4);
+
+    let offsets: Vec<i64> = requests.iter().map(|r| r.offset.as_ref().unwrap().value).collect();
+    assert_eq!(offsets, vec![0, 4, 8]);
+    let schemas: Vec<bool> = requests
+        .iter()
+        .map(|r| {
+            let Some(AppendRows::ProtoRows(data)) = &r.rows else {
+                panic!("expected proto rows");
+            };
+            data.writer_schema.is_some()
+        })
+        .collect();
+    assert_eq!(schemas, vec![true, false, false]);
+}
+
+#[test]
+fn test_encoded_rows_require_shared_schema() {
+    assert!(EncodedRows::new(&[]).unwrap().is_none());
+    assert!(matches!(
+        EncodedRows::new(&[event_batch(&[Some("Order")]), mixed_batch()]),
+        Err(ArrowFlightError::Conversion(_))
+    ));
+}
+
+#[test]
+fn test_check_append_and_commit_responses() {
+    let ok = AppendRowsResponse {
+        response:   Some(AppendRowsOutcome::AppendResult(AppendResult { offset: None })),
+        row_errors: Vec::new(),
+    };
+    assert!(check_append(&ok).is_ok());
+
+    let quota = AppendRowsResponse {
+        response:   Some(AppendRowsOutcome::Error(RpcStatus {
+            code:    Code::ResourceExhausted as i32,
+            message: "quota exceeded".to_string(),
+        })),
+        row_errors: Vec::new(),
+    };
+    assert_eq!(check_append(&quota).unwrap_err().code(), Code::ResourceExhausted);
+
+    let rejected = AppendRowsResponse {
+        response:   None,
+        row_errors: vec![RowError {
+            index:   3,
+            code:    1,
+            message: "invalid value".to_string(),
+        }],
+    };
+    let status = check_append(&rejected).unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert!(!is_retryable(status.code()));
+
+    let uncommitted = BatchCommitWriteStreamsResponse {
+        commit_time:   None,
+        stream_errors: Vec::new(),
+    };
+    assert_eq!(check_commit(&uncommitted).unwrap_err().code(), Code::Aborted);
+}
+
+#[tokio::test]
+async fn test_static_token_provider() {
+    let tokens = StaticTokenProvider::new("ya29.token");
+    assert_eq!(tokens.access_token().await.unwrap(), "ya29.token");
+}
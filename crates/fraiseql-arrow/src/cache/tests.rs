@@ -287,3 +287,80 @@ fn test_result_is_shared_via_arc() {
     // Both Arcs point to the same allocation
     assert!(Arc::ptr_eq(&original, &retrieved));
 }
+
+fn rows(value: &str) -> Rows {
+    Arc::new(vec![HashMap::from([("v".to_string(), serde_json::json!(value))])])
+}
+
+fn batch(values: &[i64]) -> Arc<Vec<RecordBatch>> {
+    let array = arrow::array::Int64Array::from(values.to_vec());
+    Arc::new(vec![RecordBatch::try_from_iter([("v", Arc::new(array) as _)]).unwrap()])
+}
+
+#[test]
+fn test_batches_round_trip_separately_from_rows() {
+    let cache = QueryCache::new(60);
+    cache.put_batches("SELECT * FROM va_orders", batch(&[1, 2]), &["va_orders"]);
+
+    assert_eq!(cache.get_batches("SELECT * FROM va_orders").unwrap()[0].num_rows(), 2);
+    // A key holding batches is not a row hit.
+    assert!(cache.get("SELECT * FROM va_orders").is_none());
+}
+
+#[test]
+fn test_invalidate_entity_by_tag_and_registered_views() {
+    let cache = QueryCache::new(60);
+    cache.register_entity_views("Order", ["va_orders", "tv_order"]);
+    cache.put_batches("q1", batch(&[1]), &["va_orders"]);
+    cache.put_batches("q2", batch(&[1]), &["tv_order"]);
+    cache.put_tagged("q3", rows("a"), &["Order"]);
+    cache.put_tagged("q4", rows("b"), &["va_users"]);
+
+    assert_eq!(cache.invalidate_entity("Order"), 3);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get("q4").is_some());
+    // Nothing left to evict.
+    assert_eq!(cache.invalidate_entity("Order"), 0);
+}
+
+#[test]
+fn test_memory_accounting_tracks_replacements_and_removals() {
+    let cache = QueryCache::new(60);
+    cache.put_batches("q", batch(&[1, 2, 3]), &["t"]);
+    let one = cache.memory_bytes();
+    assert!(one > 0);
+
+    // Replacing an entry does not double-count it.
+    cache.put_batches("q", batch(&[1, 2, 3]), &["t"]);
+    assert_eq!(cache.memory_bytes(), one);
+
+    assert_eq!(cache.invalidate_tag("t"), 1);
+    assert_eq!(cache.memory_bytes(), 0);
+}
+
+#[test]
+fn test_max_bytes_evicts_soonest_expiring_entries() {
+    let probe = QueryCache::new(60);
+    probe.put_tagged("probe", rows("x"), &[]);
+    let entry_bytes = probe.memory_bytes();
+
+    let cache = QueryCache::new(60).with_max_bytes(entry_bytes * 2);
+    cache.put_tagged("a", rows("x"), &["t"]);
+    cache.put_tagged("b", rows("y"), &[]);
+    cache.put_tagged("c", rows("z"), &[]);
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.memory_bytes() <= entry_bytes * 2);
+    assert!(cache.get("a").is_none());
+    assert!(cache.get("c").is_some());
+    // Evicted entries leave the tag index too.
+    assert_eq!(cache.invalidate_tag("t"), 0);
+}
+
+#[test]
+fn test_result_larger_than_budget_is_not_cached() {
+    let cache = QueryCache::new(60).with_max_bytes(1);
+    cache.put_tagged("q", rows("too big"), &[]);
+    assert!(cache.is_empty());
+    assert_eq!(cache.memory_bytes(), 0);
+}
@@ -19,6 +19,23 @@
 //!     ↓
 //! ClickHouse MergeTree table
 //! ```
+//!
+//! # Schema management
+//!
+//! On startup [`ClickHouseSink::run`] creates the events table if it is missing
+//! (engine, monthly partitioning and `ORDER BY` keys from [`ClickHouseSchemaConfig`]),
+//! adds the columns the observer event schema gained since, and refuses to start
+//! when an existing column's type cannot store the sink's values.
+//!
+//! # Exactly-once delivery
+//!
+//! With `exactly_once` enabled (the default), each flushed block is inserted with
+//! a deterministic `insert_deduplication_token`, so retried flushes are not
+//! duplicated, and the last flushed event is recorded in a high-watermark table.
+//! After a restart, the source's replay is skipped up to that watermark: events
+//! are dropped only until the first one past it arrives, so late or out-of-order
+//! events received afterwards are still inserted. This relies on the source
+//! replaying events in `(timestamp, event_id)` order.
 
 use std::time::Duration;
 
@@ -28,8 +45,12 @@ use arrow::{
 };
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
+mod ddl;
+mod watermark;
+
+pub use self::ddl::ClickHouseSchemaConfig;
 use crate::error::{ArrowFlightError, Result};
 
 /// `ClickHouse` sink configuration
@@ -58,6 +79,20 @@ pub struct ClickHouseSinkConfig {
     /// Maximum number of retries for transient errors (default: 3)
     #[serde(default = "default_clickhouse_max_retries")]
     pub max_retries: usize,
+
+    /// DDL management of the events table
+    #[serde(default)]
+    pub schema: ClickHouseSchemaConfig,
+
+    /// Deduplicate retried flushes and skip events flushed before a restart
+    /// (default: true)
+    #[serde(default = "default_clickhouse_exactly_once")]
+    pub exactly_once: bool,
+
+    /// Table recording the last flushed event of each target table (default:
+    /// `fraiseql_sink_watermarks`)
+    #[serde(default = "default_clickhouse_watermark_table")]
+    pub watermark_table: String,
 }
 
 /// Default `ClickHouse` URL
@@ -99,6 +134,16 @@ fn default_clickhouse_max_retries() -> usize {
         .unwrap_or(3)
 }
 
+/// Default exactly-once delivery
+const fn default_clickhouse_exactly_once() -> bool {
+    true
+}
+
+/// Default high-watermark table
+fn default_clickhouse_watermark_table() -> String {
+    "fraiseql_sink_watermarks".to_string()
+}
+
 /// Validate that the `ClickHouse` URL is safe to connect to (SSRF protection).
 ///
 /// Rejects:
@@ -184,6 +229,9 @@ impl Default for ClickHouseSinkConfig {
             batch_size:         default_clickhouse_batch_size(),
             batch_timeout_secs: default_clickhouse_batch_timeout_secs(),
             max_retries:        default_clickhouse_max_retries(),
+            schema:             ClickHouseSchemaConfig::default(),
+            exactly_once:       default_clickhouse_exactly_once(),
+            watermark_table:    default_clickhouse_watermark_table(),
         }
     }
 }
@@ -225,7 +273,9 @@ impl ClickHouseSinkConfig {
     ///
     /// Returns [`ArrowFlightError::Configuration`] if the URL is empty, targets a
     /// private/loopback address (SSRF protection), the database or table name is
-    /// empty, batch size is out of range (1–100,000), or batch timeout is zero.
+    /// empty, batch size is out of range (1–100,000), batch timeout is zero, the
+    /// watermark table is empty while `exactly_once` is enabled, or the DDL settings
+    /// are invalid (see [`ClickHouseSchemaConfig::validate`]).
     pub fn validate(&self) -> Result<()> {
         if self.url.is_empty() {
             return Err(ArrowFlightError::Configuration(
@@ -253,7 +303,13 @@ impl ClickHouseSinkConfig {
                 "Batch timeout must be greater than 0".to_string(),
             ));
         }
-        Ok(())
+        if self.exactly_once && self.watermark_table.is_empty() {
+            return Err(ArrowFlightError::Configuration(
+                "ClickHouse watermark table cannot be empty when exactly_once is enabled"
+                    .to_string(),
+            ));
+        }
+        self.schema.validate()
     }
 }
 
@@ -322,16 +378,96 @@ impl ClickHouseSink {
         }
     }
 
+    /// Create the events table if it is missing and add the columns it lacks.
+    ///
+    /// With `exactly_once` enabled, the high-watermark table is created too.
+    ///
+    /// The table stores the
+    /// [`observer_event_schema`](crate::schema::observer_event_schema) columns the
+    /// sink inserts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Configuration`] if an existing column's type cannot
+    /// store the sink's values, or [`ArrowFlightError::External`] if a `ClickHouse`
+    /// query fails.
+    #[cfg(feature = "clickhouse")]
+    pub async fn ensure_schema(&self) -> Result<()> {
+        let schema = crate::schema::observer_event_schema();
+        let existing: Vec<ddl::TableColumn> = self
+            .client
+            .query(
+                "SELECT name, type FROM system.columns \
+                 WHERE database = ? AND table = ? ORDER BY position",
+            )
+            .bind(&self.config.database)
+            .bind(&self.config.table)
+            .fetch_all()
+            .await
+            .map_err(|e| {
+                ArrowFlightError::External(format!("Failed to read ClickHouse table schema: {e}"))
+            })?;
+
+        let mut statements = if existing.is_empty() {
+            vec![ddl::create_table_sql(
+                &self.config.database,
+                &self.config.table,
+                &schema,
+                &self.config.schema,
+            )?]
+        } else {
+            ddl::plan_migration(&self.config.database, &self.config.table, &schema, &existing)?
+        };
+        if self.config.exactly_once {
+            statements.push(watermark::watermark_table_sql(
+                &self.config.database,
+                &self.config.watermark_table,
+            ));
+        }
+
+        for statement in &statements {
+            self.client.query(statement).execute().await.map_err(|e| {
+                ArrowFlightError::External(format!("Failed to apply ClickHouse DDL: {e}"))
+            })?;
+        }
+        if !statements.is_empty() {
+            info!(
+                table = %ddl::qualified_table(&self.config.database, &self.config.table),
+                created = existing.is_empty(),
+                statements = statements.len(),
+                "Applied ClickHouse events table DDL"
+            );
+        }
+        Ok(())
+    }
+
     /// Run the sink, consuming `RecordBatches` from the channel
     ///
+    /// Unless `schema.manage_schema` is disabled, the events table is created or
+    /// migrated first (see [`ensure_schema`](Self::ensure_schema)). With
+    /// `exactly_once` enabled, the replayed events leading up to the recorded
+    /// high-watermark are skipped; the skip ends at the first event past it.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The events table is missing columns or incompatible and cannot be migrated
+    /// - The high-watermark cannot be read
     /// - The channel is closed unexpectedly
     /// - Conversion fails (Arrow → `EventRow`)
     /// - `ClickHouse` insertion fails permanently after retries
     #[cfg(feature = "clickhouse")]
     pub async fn run(&self, mut rx: mpsc::Receiver<RecordBatch>) -> Result<()> {
+        if self.config.schema.manage_schema {
+            self.ensure_schema().await?;
+        }
+        // The replay up to the watermark was flushed before the restart.
+        let mut recovered = if self.config.exactly_once {
+            self.load_watermark().await?
+        } else {
+            None
+        };
+
         let mut batch_buffer: Vec<EventRow> = Vec::with_capacity(self.config.batch_size);
         let batch_timeout = Duration::from_secs(self.config.batch_timeout_secs);
 
@@ -355,7 +491,21 @@ impl ClickHouseSink {
                         return Ok(());
                     };
                     match self.process_batch(&record_batch) {
-                        Ok(rows) => {
+                        Ok(mut rows) => {
+                            if let Some(watermark) = &recovered {
+                                let replayed = watermark.replayed_prefix(&rows);
+                                if replayed > 0 {
+                                    debug!(
+                                        skipped = replayed,
+                                        "Skipping events flushed before restart"
+                                    );
+                                }
+                                if replayed < rows.len() {
+                                    info!("Replay caught up with the ClickHouse watermark");
+                                    recovered = None;
+                                }
+                                rows.drain(..replayed);
+                            }
                             batch_buffer.extend(rows);
                             if batch_buffer.len() >= self.config.batch_size {
                                 self.flush_batch(&batch_buffer).await?;
@@ -489,15 +639,71 @@ impl ClickHouseSink {
         Ok(rows)
     }
 
+    /// Read the recorded high-watermark of the target table.
+    #[cfg(feature = "clickhouse")]
+    async fn load_watermark(&self) -> Result<Option<watermark::Watermark>> {
+        let recovered = self
+            .client
+            .query(&watermark::watermark_query(&self.config.database, &self.config.watermark_table))
+            .bind(&self.config.table)
+            .fetch_optional::<watermark::Watermark>()
+            .await
+            .map_err(|e| {
+                ArrowFlightError::External(format!("Failed to read ClickHouse watermark: {e}"))
+            })?;
+        if let Some(recovered) = &recovered {
+            info!(
+                event_id = %recovered.event_id,
+                event_timestamp = recovered.event_timestamp,
+                "Resuming ClickHouse sink after recorded watermark"
+            );
+        }
+        Ok(recovered)
+    }
+
+    /// Record the last event of a flushed batch as the high-watermark.
+    ///
+    /// A failure is only logged: the rows are already inserted, and a lagging
+    /// watermark at worst lets a restart re-send them.
+    #[cfg(feature = "clickhouse")]
+    async fn record_watermark(&self, rows: &[EventRow]) {
+        use clickhouse::inserter::Inserter;
+
+        let Some(watermark) = watermark::Watermark::after(&self.config.table, rows) else {
+            return;
+        };
+        let mut inserter: Inserter<watermark::Watermark> =
+            self.client.inserter(&self.config.watermark_table);
+        let recorded = match inserter.write(&watermark).await {
+            Ok(()) => inserter.end().await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = recorded {
+            warn!(error = %e, "Failed to record ClickHouse watermark");
+        }
+    }
+
     /// Flush a batch of rows to `ClickHouse` with retry logic
+    ///
+    /// Every attempt carries the same deduplication token when `exactly_once` is
+    /// enabled, so an attempt that reached `ClickHouse` before failing is not
+    /// inserted again.
     #[cfg(feature = "clickhouse")]
     async fn flush_batch(&self, rows: &[EventRow]) -> Result<()> {
         let mut last_error = None;
+        let token = if self.config.exactly_once {
+            watermark::dedup_token(rows)
+        } else {
+            None
+        };
 
         for attempt in 1..=self.config.max_retries {
-            match self.try_insert(rows).await {
+            match self.try_insert(rows, token.as_deref()).await {
                 Ok(()) => {
                     info!(count = rows.len(), attempt, "Batch inserted successfully");
+                    if self.config.exactly_once {
+                        self.record_watermark(rows).await;
+                    }
                     return Ok(());
                 },
                 Err(e) => {
@@ -532,11 +738,15 @@ impl ClickHouseSink {
         }))
     }
 
-    /// Attempt to insert rows into `ClickHouse`
+    /// Attempt to insert rows into `ClickHouse`, deduplicated by `token` if given
     #[cfg(feature = "clickhouse")]
-    async fn try_insert(&self, rows: &[EventRow]) -> Result<()> {
+    async fn try_insert(&self, rows: &[EventRow], token: Option<&str>) -> Result<()> {
         use clickhouse::inserter::Inserter;
-        let mut inserter: Inserter<EventRow> = self.client.inserter(&self.config.table);
+        let client = match token {
+            Some(token) => self.client.clone().with_option("insert_deduplication_token", token),
+            None => self.client.clone(),
+        };
+        let mut inserter: Inserter<EventRow> = client.inserter(&self.config.table);
 
         for row in rows {
             inserter.write(row).await.map_err(|e| {
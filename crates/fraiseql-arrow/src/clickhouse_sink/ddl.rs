@@ -0,0 +1,260 @@
+//! DDL management of the `ClickHouse` sink's events table.
+//!
+//! The table layout is derived from the Arrow schema the sink writes: a missing
+//! table is created, columns the schema gained since the table was created are
+//! added with `ALTER TABLE ... ADD COLUMN`, and a column whose type no longer
+//! matches is reported instead of being altered.
+
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ArrowFlightError, Result};
+
+/// DDL settings of the sink's events table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClickHouseSchemaConfig {
+    /// Create the table and add missing columns when the sink starts (default: true).
+    ///
+    /// When disabled the table is left untouched and must match the sink's schema.
+    pub manage_schema: bool,
+
+    /// Table engine of a created table (default: `MergeTree`)
+    pub engine: String,
+
+    /// `PARTITION BY` expression of a created table (default: monthly,
+    /// `toYYYYMM(timestamp)`); empty for an unpartitioned table
+    pub partition_by: String,
+
+    /// `ORDER BY` columns of a created table (default: `entity_type`, `timestamp`)
+    pub order_by: Vec<String>,
+
+    /// `non_replicated_deduplication_window` of a created non-replicated table
+    /// (default: 1000); the number of recent blocks whose deduplication tokens are
+    /// remembered, so retried flushes are not inserted twice. 0 disables it.
+    pub deduplication_window: u64,
+}
+
+impl Default for ClickHouseSchemaConfig {
+    fn default() -> Self {
+        Self {
+            manage_schema:        true,
+            engine:               "MergeTree".to_string(),
+            partition_by:         "toYYYYMM(timestamp)".to_string(),
+            order_by:             vec!["entity_type".to_string(), "timestamp".to_string()],
+            deduplication_window: 1000,
+        }
+    }
+}
+
+impl ClickHouseSchemaConfig {
+    /// Validate the DDL settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Configuration`] if the engine is empty, the engine
+    /// or partition expression contains a `;`, or no `ORDER BY` column is given.
+    pub fn validate(&self) -> Result<()> {
+        if self.engine.trim().is_empty() {
+            return Err(ArrowFlightError::Configuration(
+                "ClickHouse table engine cannot be empty".to_string(),
+            ));
+        }
+        if self.engine.contains(';') || self.partition_by.contains(';') {
+            return Err(ArrowFlightError::Configuration(
+                "ClickHouse engine and partition expression must be a single expression"
+                    .to_string(),
+            ));
+        }
+        if self.order_by.is_empty() {
+            return Err(ArrowFlightError::Configuration(
+                "ClickHouse ORDER BY needs at least one column".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A column of the existing table, as listed by `system.columns`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, clickhouse::Row)]
+pub(super) struct TableColumn {
+    /// Column name
+    pub name:        String,
+    /// `ClickHouse` type (e.g., `Nullable(String)`)
+    #[serde(rename = "type")]
+    pub column_type: String,
+}
+
+/// Quote an identifier with backticks.
+fn quote(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('\\', "\\\\").replace('`', "\\`"))
+}
+
+/// Fully qualified, quoted name of `database`.`table`.
+pub(super) fn qualified_table(database: &str, table: &str) -> String {
+    format!("{}.{}", quote(database), quote(table))
+}
+
+/// The `ClickHouse` column type storing `field`.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Configuration`] for Arrow types the sink cannot store.
+pub(super) fn column_type(field: &Field) -> Result<String> {
+    let base = match field.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 => "String".to_string(),
+        DataType::Dictionary(_, value) if value.as_ref() == &DataType::Utf8 => {
+            let inner = if field.is_nullable() {
+                "Nullable(String)"
+            } else {
+                "String"
+            };
+            return Ok(format!("LowCardinality({inner})"));
+        },
+        DataType::Boolean => "Bool".to_string(),
+        DataType::Int8 => "Int8".to_string(),
+        DataType::Int16 => "Int16".to_string(),
+        DataType::Int32 => "Int32".to_string(),
+        DataType::Int64 => "Int64".to_string(),
+        DataType::UInt8 => "UInt8".to_string(),
+        DataType::UInt16 => "UInt16".to_string(),
+        DataType::UInt32 => "UInt32".to_string(),
+        DataType::UInt64 => "UInt64".to_string(),
+        DataType::Float32 => "Float32".to_string(),
+        DataType::Float64 => "Float64".to_string(),
+        DataType::Date32 => "Date32".to_string(),
+        DataType::Timestamp(unit, tz) => {
+            let precision = match unit {
+                TimeUnit::Second => 0,
+                TimeUnit::Millisecond => 3,
+                TimeUnit::Microsecond => 6,
+                TimeUnit::Nanosecond => 9,
+            };
+            match tz {
+                Some(tz) => format!("DateTime64({precision}, '{tz}')"),
+                None => format!("DateTime64({precision})"),
+            }
+        },
+        other => {
+            return Err(ArrowFlightError::Configuration(format!(
+                "column '{}' has Arrow type {other}, which the ClickHouse sink cannot store",
+                field.name()
+            )));
+        },
+    };
+    Ok(if field.is_nullable() {
+        format!("Nullable({base})")
+    } else {
+        base
+    })
+}
+
+/// `CREATE TABLE IF NOT EXISTS` statement of a table storing `schema`.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Configuration`] if a column type is unsupported or an
+/// `ORDER BY` column is missing from `schema` or nullable.
+pub(super) fn create_table_sql(
+    database: &str,
+    table: &str,
+    schema: &Schema,
+    config: &ClickHouseSchemaConfig,
+) -> Result<String> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| Ok(format!("    {} {}", quote(field.name()), column_type(field)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let order_by = config
+        .order_by
+        .iter()
+        .map(|column| match schema.field_with_name(column) {
+            Ok(field) if !field.is_nullable() => Ok(quote(column)),
+            Ok(_) => Err(ArrowFlightError::Configuration(format!(
+                "ORDER BY column '{column}' is nullable"
+            ))),
+            Err(_) => Err(ArrowFlightError::Configuration(format!(
+                "ORDER BY column '{column}' is not a column of the events schema"
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut sql = format!(
+        "CREATE TABLE IF NOT EXISTS {} (\n{}\n)\nENGINE = {}\n",
+        qualified_table(database, table),
+        columns.join(",\n"),
+        config.engine.trim(),
+    );
+    if !config.partition_by.trim().is_empty() {
+        sql.push_str(&format!("PARTITION BY {}\n", config.partition_by.trim()));
+    }
+    sql.push_str(&format!("ORDER BY ({})", order_by.join(", ")));
+    // Replicated engines deduplicate inserts by default; the others only within
+    // this window.
+    if config.deduplication_window > 0 && !config.engine.trim().starts_with("Replicated") {
+        sql.push_str(&format!(
+            "\nSETTINGS non_replicated_deduplication_window = {}",
+            config.deduplication_window
+        ));
+    }
+    Ok(sql)
+}
+
+/// Strip a `LowCardinality(...)` wrapper: it changes the storage, not the values.
+fn storage_agnostic(column_type: &str) -> &str {
+    column_type
+        .strip_prefix("LowCardinality(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(column_type)
+}
+
+/// Whether a table column of type `actual` can store values of type `expected`.
+///
+/// A nullable table column accepts non-null values; the reverse does not hold.
+fn compatible(expected: &str, actual: &str) -> bool {
+    let (expected, actual) = (storage_agnostic(expected), storage_agnostic(actual));
+    expected == actual || actual == format!("Nullable({expected})")
+}
+
+/// `ALTER TABLE` statements adding the columns of `schema` the table lacks.
+///
+/// Table columns that `schema` does not have are left alone: inserts name their
+/// columns, so `ClickHouse` fills them with their defaults.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::Configuration`] if an existing column's type cannot
+/// store the schema's values, listing every incompatible column.
+pub(super) fn plan_migration(
+    database: &str,
+    table: &str,
+    schema: &Schema,
+    existing: &[TableColumn],
+) -> Result<Vec<String>> {
+    let mut alters = Vec::new();
+    let mut incompatible = Vec::new();
+    for field in schema.fields() {
+        let expected = column_type(field)?;
+        match existing.iter().find(|column| column.name == *field.name()) {
+            None => alters.push(format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS {} {expected}",
+                qualified_table(database, table),
+                quote(field.name()),
+            )),
+            Some(column) if !compatible(&expected, &column.column_type) => incompatible.push(
+                format!("'{}' is {} but must store {expected}", field.name(), column.column_type),
+            ),
+            Some(_) => {},
+        }
+    }
+    if !incompatible.is_empty() {
+        return Err(ArrowFlightError::Configuration(format!(
+            "ClickHouse table {} is incompatible with the events schema: {}",
+            qualified_table(database, table),
+            incompatible.join("; ")
+        )));
+    }
+    Ok(alters)
+}
@@ -201,3 +201,209 @@ fn test_clickhouse_url_blocks_ipv6_link_local() {
         );
     }
 }
+
+// --- Schema management ---
+
+fn table_column(name: &str, column_type: &str) -> ddl::TableColumn {
+    ddl::TableColumn {
+        name:        name.to_string(),
+        column_type: column_type.to_string(),
+    }
+}
+
+/// The columns `create_table_sql` declares for the observer event schema.
+fn event_table_columns() -> Vec<ddl::TableColumn> {
+    crate::schema::observer_event_schema()
+        .fields()
+        .iter()
+        .map(|field| table_column(field.name(), &ddl::column_type(field).unwrap()))
+        .collect()
+}
+
+#[test]
+fn test_create_table_sql_partitions_by_month() {
+    let sql = ddl::create_table_sql(
+        "analytics",
+        "fraiseql_events",
+        &crate::schema::observer_event_schema(),
+        &ClickHouseSchemaConfig::default(),
+    )
+    .unwrap();
+
+    assert!(sql.starts_with("CREATE TABLE IF NOT EXISTS `analytics`.`fraiseql_events` ("));
+    assert!(sql.contains("`timestamp` DateTime64(6, 'UTC')"));
+    assert!(sql.contains("`org_id` Nullable(String)"));
+    assert!(sql.contains("ENGINE = MergeTree\nPARTITION BY toYYYYMM(timestamp)\n"));
+    assert!(sql.contains("ORDER BY (`entity_type`, `timestamp`)"));
+    assert!(sql.ends_with("SETTINGS non_replicated_deduplication_window = 1000"));
+
+    let replicated = ClickHouseSchemaConfig {
+        engine: "ReplicatedMergeTree('/clickhouse/tables/{shard}/events', '{replica}')".to_string(),
+        ..ClickHouseSchemaConfig::default()
+    };
+    let sql = ddl::create_table_sql(
+        "analytics",
+        "fraiseql_events",
+        &crate::schema::observer_event_schema(),
+        &replicated,
+    )
+    .unwrap();
+    assert!(!sql.contains("SETTINGS"), "replicated engines deduplicate by default");
+}
+
+#[test]
+fn test_create_table_sql_rejects_unusable_order_by() {
+    let schema = crate::schema::observer_event_schema();
+    for column in ["org_id", "missing"] {
+        let config = ClickHouseSchemaConfig {
+            order_by: vec![column.to_string()],
+            ..ClickHouseSchemaConfig::default()
+        };
+        assert!(
+            matches!(
+                ddl::create_table_sql("default", "events", &schema, &config),
+                Err(ArrowFlightError::Configuration(_))
+            ),
+            "ORDER BY {column} must be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_migration_adds_missing_columns_only() {
+    let schema = crate::schema::observer_event_schema();
+    let mut existing = event_table_columns();
+    assert!(ddl::plan_migration("default", "events", &schema, &existing).unwrap().is_empty());
+
+    existing.retain(|column| column.name != "org_id");
+    let alters = ddl::plan_migration("default", "events", &schema, &existing).unwrap();
+    assert_eq!(
+        alters,
+        vec![
+            "ALTER TABLE `default`.`events` ADD COLUMN IF NOT EXISTS `org_id` Nullable(String)"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_migration_accepts_wider_column_types() {
+    let schema = crate::schema::observer_event_schema();
+    let mut existing = event_table_columns();
+    for column in &mut existing {
+        match column.name.as_str() {
+            "event_type" => column.column_type = "LowCardinality(String)".to_string(),
+            "data" => column.column_type = "Nullable(String)".to_string(),
+            _ => {},
+        }
+    }
+    existing.push(table_column("ingested_at", "DateTime"));
+
+    assert!(ddl::plan_migration("default", "events", &schema, &existing).unwrap().is_empty());
+}
+
+#[test]
+fn test_migration_rejects_incompatible_columns() {
+    let schema = crate::schema::observer_event_schema();
+    let mut existing = event_table_columns();
+    for column in &mut existing {
+        match column.name.as_str() {
+            "timestamp" => column.column_type = "DateTime('UTC')".to_string(),
+            "user_id" => column.column_type = "String".to_string(),
+            _ => {},
+        }
+    }
+
+    let Err(ArrowFlightError::Configuration(message)) =
+        ddl::plan_migration("default", "events", &schema, &existing)
+    else {
+        panic!("incompatible columns must be rejected");
+    };
+    assert!(message.contains("'timestamp' is DateTime('UTC')"), "{message}");
+    assert!(message.contains("'user_id' is String"), "{message}");
+}
+
+#[test]
+fn test_config_validate_schema_settings() {
+    let config = ClickHouseSinkConfig {
+        url: TEST_URL.to_string(),
+        schema: ClickHouseSchemaConfig {
+            engine: "MergeTree; DROP TABLE events".to_string(),
+            ..ClickHouseSchemaConfig::default()
+        },
+        ..Default::default()
+    };
+    assert!(matches!(config.validate(), Err(ArrowFlightError::Configuration(_))));
+}
+
+// --- Exactly-once delivery ---
+
+fn event_row(event_id: &str, timestamp: i64) -> EventRow {
+    EventRow {
+        event_id: event_id.to_string(),
+        event_type: "INSERT".to_string(),
+        entity_type: "Order".to_string(),
+        entity_id: "order-1".to_string(),
+        timestamp,
+        data: "{}".to_string(),
+        user_id: None,
+        org_id: None,
+    }
+}
+
+#[test]
+fn test_dedup_token_is_deterministic_per_block() {
+    let rows = vec![event_row("b", 2), event_row("a", 1), event_row("c", 3)];
+
+    let token = watermark::dedup_token(&rows).unwrap();
+    assert!(token.starts_with("a..c:3:"), "{token}");
+    assert_eq!(watermark::dedup_token(&rows).unwrap(), token, "a retry repeats the token");
+
+    let mut reordered = rows.clone();
+    reordered.swap(0, 1);
+    assert_ne!(watermark::dedup_token(&reordered).unwrap(), token);
+    assert_ne!(watermark::dedup_token(&rows[..2]).unwrap(), token);
+    assert!(watermark::dedup_token(&[]).is_none());
+}
+
+#[test]
+fn test_watermark_covers_flushed_events_only() {
+    let flushed = vec![event_row("a", 1), event_row("c", 5), event_row("b", 5)];
+    let watermark = watermark::Watermark::after("fraiseql_events", &flushed).unwrap();
+    assert_eq!(watermark.event_timestamp, 5);
+    assert_eq!(watermark.event_id, "c");
+
+    assert!(flushed.iter().all(|row| watermark.covers(row)));
+    assert!(!watermark.covers(&event_row("d", 5)));
+    assert!(!watermark.covers(&event_row("a", 6)));
+    assert!(watermark::Watermark::after("fraiseql_events", &[]).is_none());
+}
+
+#[test]
+fn test_watermark_skips_only_the_replayed_prefix() {
+    let watermark = watermark::Watermark::after("fraiseql_events", &[event_row("c", 5)]).unwrap();
+
+    let replay = vec![event_row("a", 1), event_row("c", 5), event_row("d", 6)];
+    assert_eq!(watermark.replayed_prefix(&replay), 2);
+    assert_eq!(watermark.replayed_prefix(&replay[..2]), 2, "the whole batch may be replay");
+
+    // A late event after the replay ended is sent, even though it is older.
+    let live = vec![event_row("d", 6), event_row("late", 2)];
+    assert_eq!(watermark.replayed_prefix(&live), 0);
+}
+
+#[test]
+fn test_config_validate_watermark_table() {
+    let config = ClickHouseSinkConfig {
+        url: TEST_URL.to_string(),
+        watermark_table: String::new(),
+        ..Default::default()
+    };
+    assert!(matches!(config.validate(), Err(ArrowFlightError::Configuration(_))));
+
+    let at_least_once = ClickHouseSinkConfig {
+        exactly_once: false,
+        ..config
+    };
+    assert!(at_least_once.validate().is_ok());
+}
@@ -7,13 +7,22 @@ use std::sync::Arc;
 
 use arrow::{
     array::{
-        ArrayBuilder, BooleanBuilder, Date32Builder, Float64Builder, Int32Builder, Int64Builder,
-        RecordBatch, StringBuilder, TimestampNanosecondBuilder,
+        ArrayBuilder, ArrayRef, BooleanBuilder, Date32Builder, Float64Builder, Int32Builder,
+        Int64Builder, ListArray, RecordBatch, StringBuilder, StructArray,
+        TimestampMicrosecondBuilder, TimestampNanosecondBuilder, UInt32Builder,
     },
-    datatypes::{DataType, Schema, TimeUnit},
+    buffer::{NullBuffer, OffsetBuffer},
+    compute::cast,
+    datatypes::{DataType, Field, Schema, TimeUnit},
     error::ArrowError,
 };
 
+use crate::{
+    delivery_storage::{DlqRecord, JobAttemptRecord},
+    event_storage::HistoricalEvent,
+    schema::{dlq_item_schema, job_attempt_schema, observer_event_schema},
+};
+
 /// Configuration for Arrow batch conversion.
 #[derive(Debug, Clone, Copy)]
 pub struct ConvertConfig {
@@ -52,6 +61,10 @@ pub enum Value {
     Timestamp(i64),
     /// Date as days since Unix epoch
     Date(i32),
+    /// Elements of a `List` column (`None` for null elements)
+    List(Vec<Option<Value>>),
+    /// Fields of a `Struct` value, in the struct type's field order (`None` for null fields)
+    Struct(Vec<Option<Value>>),
 }
 
 /// Convert SQL rows to Arrow `RecordBatches`.
@@ -111,149 +124,221 @@ impl RowToArrowConverter {
         }
 
         let num_columns = self.schema.fields().len();
-        let mut column_builders = self.create_builders(num_columns)?;
-
-        // Populate builders row by row
-        for row in rows {
-            if row.len() != num_columns {
-                return Err(ArrowError::InvalidArgumentError(format!(
-                    "Row has {} columns, expected {}",
-                    row.len(),
-                    num_columns
-                )));
-            }
-
-            for (col_idx, value) in row.iter().enumerate() {
-                let field = self.schema.field(col_idx);
-                self.append_value(&mut column_builders[col_idx], value, field.data_type())?;
-            }
+        if let Some(row) = rows.iter().find(|row| row.len() != num_columns) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Row has {} columns, expected {}",
+                row.len(),
+                num_columns
+            )));
         }
 
-        // Finish builders and create RecordBatch
-        let columns: Vec<_> =
-            column_builders.into_iter().map(|mut builder| builder.finish()).collect();
+        // Build column by column so nested (List / Struct) columns can be assembled
+        // from their flattened children.
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(col_idx, field)| {
+                let values: Vec<Option<&Value>> =
+                    rows.iter().map(|row| row[col_idx].as_ref()).collect();
+                build_array(&values, field.data_type(), self.config.batch_size)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         RecordBatch::try_new(self.schema.clone(), columns)
     }
+}
 
-    /// Create array builders for each column in the schema.
-    ///
-    /// # Errors
-    ///
-    /// Returns `ArrowError::InvalidArgumentError` if any column has an unsupported data type.
-    fn create_builders(
-        &self,
-        num_columns: usize,
-    ) -> Result<Vec<Box<dyn ArrayBuilder>>, ArrowError> {
-        (0..num_columns)
-            .map(|i| {
-                let field = self.schema.field(i);
-                create_builder_for_type(field.data_type(), self.config.batch_size)
-            })
-            .collect()
-    }
-
-    /// Append a value to the appropriate builder based on data type.
-    fn append_value(
-        &self,
-        builder: &mut Box<dyn ArrayBuilder>,
-        value: &Option<Value>,
-        data_type: &DataType,
-    ) -> Result<(), ArrowError> {
-        match data_type {
-            DataType::Utf8 => {
-                let b = downcast_builder::<StringBuilder>(builder, "StringBuilder", "Utf8")?;
+/// Build an Arrow array of `data_type` from one column's values.
+///
+/// `List` and `Struct` types recurse into their children: list elements are flattened
+/// into a single child array addressed by offsets, and struct fields each become a
+/// child column. Every other type is appended through its scalar builder.
+///
+/// # Errors
+///
+/// Returns `ArrowError::InvalidArgumentError` if a value does not match `data_type` or
+/// the type is not supported.
+fn build_array(
+    values: &[Option<&Value>],
+    data_type: &DataType,
+    capacity: usize,
+) -> Result<ArrayRef, ArrowError> {
+    match data_type {
+        DataType::List(item) => {
+            let mut lengths = Vec::with_capacity(values.len());
+            let mut validity = Vec::with_capacity(values.len());
+            let mut elements = Vec::new();
+            for value in values {
                 match value {
-                    Some(Value::String(s)) => b.append_value(s),
-                    None => b.append_null(),
-                    _ => {
-                        return Err(ArrowError::InvalidArgumentError(
-                            "Expected string value".into(),
-                        ));
+                    Some(Value::List(items)) => {
+                        lengths.push(items.len());
+                        validity.push(true);
+                        elements.extend(items.iter().map(Option::as_ref));
                     },
-                }
-            },
-            DataType::Int32 => {
-                let b = downcast_builder::<Int32Builder>(builder, "Int32Builder", "Int32")?;
-                match value {
-                    Some(Value::Int(i)) => b
-                        .append_value(i32::try_from(*i).map_err(|_| {
-                            ArrowError::InvalidArgumentError("Int overflow".into())
-                        })?),
-                    None => b.append_null(),
-                    _ => return Err(ArrowError::InvalidArgumentError("Expected int value".into())),
-                }
-            },
-            DataType::Int64 => {
-                let b = downcast_builder::<Int64Builder>(builder, "Int64Builder", "Int64")?;
-                match value {
-                    Some(Value::Int(i)) => b.append_value(*i),
-                    None => b.append_null(),
-                    _ => {
-                        return Err(ArrowError::InvalidArgumentError(
-                            "Expected int64 value".into(),
-                        ));
+                    None => {
+                        lengths.push(0);
+                        validity.push(false);
                     },
-                }
-            },
-            DataType::Float64 => {
-                let b = downcast_builder::<Float64Builder>(builder, "Float64Builder", "Float64")?;
-                match value {
-                    Some(Value::Float(f)) => b.append_value(*f),
-                    None => b.append_null(),
                     _ => {
-                        return Err(ArrowError::InvalidArgumentError(
-                            "Expected float value".into(),
-                        ));
+                        return Err(ArrowError::InvalidArgumentError("Expected list value".into()));
                     },
                 }
-            },
-            DataType::Boolean => {
-                let b = downcast_builder::<BooleanBuilder>(builder, "BooleanBuilder", "Boolean")?;
+            }
+            let child = build_array(&elements, item.data_type(), elements.len())?;
+            Ok(Arc::new(ListArray::try_new(
+                item.clone(),
+                OffsetBuffer::from_lengths(lengths),
+                child,
+                Some(NullBuffer::from(validity)),
+            )?))
+        },
+        DataType::Struct(fields) => {
+            let mut validity = Vec::with_capacity(values.len());
+            for value in values {
                 match value {
-                    Some(Value::Bool(b_val)) => b.append_value(*b_val),
-                    None => b.append_null(),
-                    _ => {
-                        return Err(ArrowError::InvalidArgumentError("Expected bool value".into()));
+                    Some(Value::Struct(field_values)) if field_values.len() == fields.len() => {
+                        validity.push(true);
                     },
-                }
-            },
-            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-                let b = downcast_builder::<TimestampNanosecondBuilder>(
-                    builder,
-                    "TimestampNanosecondBuilder",
-                    "Timestamp(Nanosecond)",
-                )?;
-                match value {
-                    Some(Value::Timestamp(nanos)) => b.append_value(*nanos),
-                    None => b.append_null(),
+                    Some(Value::Struct(field_values)) => {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "Struct value has {} fields, expected {}",
+                            field_values.len(),
+                            fields.len()
+                        )));
+                    },
+                    None => validity.push(false),
                     _ => {
                         return Err(ArrowError::InvalidArgumentError(
-                            "Expected timestamp value".into(),
+                            "Expected struct value".into(),
                         ));
                     },
                 }
-            },
-            DataType::Date32 => {
-                let b = downcast_builder::<Date32Builder>(builder, "Date32Builder", "Date32")?;
-                match value {
-                    Some(Value::Date(days)) => b.append_value(*days),
-                    None => b.append_null(),
-                    _ => {
-                        return Err(ArrowError::InvalidArgumentError("Expected date value".into()));
-                    },
-                }
-            },
-            _ => {
-                return Err(ArrowError::InvalidArgumentError(format!(
-                    "Unsupported data type: {data_type:?}"
-                )));
-            },
-        }
-        Ok(())
+            }
+            let children = fields
+                .iter()
+                .enumerate()
+                .map(|(field_idx, field)| {
+                    let child: Vec<Option<&Value>> = values
+                        .iter()
+                        .map(|value| match value {
+                            Some(Value::Struct(field_values)) => field_values[field_idx].as_ref(),
+                            _ => None,
+                        })
+                        .collect();
+                    build_array(&child, field.data_type(), child.len())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Arc::new(StructArray::try_new(
+                fields.clone(),
+                children,
+                Some(NullBuffer::from(validity)),
+            )?))
+        },
+        _ => {
+            let mut builder = create_builder_for_type(data_type, capacity)?;
+            for value in values {
+                append_value(&mut builder, *value, data_type)?;
+            }
+            Ok(builder.finish())
+        },
     }
 }
 
+/// Append a value to the appropriate builder based on data type.
+fn append_value(
+    builder: &mut Box<dyn ArrayBuilder>,
+    value: Option<&Value>,
+    data_type: &DataType,
+) -> Result<(), ArrowError> {
+    match data_type {
+        DataType::Utf8 => {
+            let b = downcast_builder::<StringBuilder>(builder, "StringBuilder", "Utf8")?;
+            match value {
+                Some(Value::String(s)) => b.append_value(s),
+                None => b.append_null(),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError("Expected string value".into()));
+                },
+            }
+        },
+        DataType::Int32 => {
+            let b = downcast_builder::<Int32Builder>(builder, "Int32Builder", "Int32")?;
+            match value {
+                Some(Value::Int(i)) => b.append_value(
+                    i32::try_from(*i)
+                        .map_err(|_| ArrowError::InvalidArgumentError("Int overflow".into()))?,
+                ),
+                None => b.append_null(),
+                _ => return Err(ArrowError::InvalidArgumentError("Expected int value".into())),
+            }
+        },
+        DataType::Int64 => {
+            let b = downcast_builder::<Int64Builder>(builder, "Int64Builder", "Int64")?;
+            match value {
+                Some(Value::Int(i)) => b.append_value(*i),
+                None => b.append_null(),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError("Expected int64 value".into()));
+                },
+            }
+        },
+        DataType::Float64 => {
+            let b = downcast_builder::<Float64Builder>(builder, "Float64Builder", "Float64")?;
+            match value {
+                Some(Value::Float(f)) => b.append_value(*f),
+                None => b.append_null(),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError("Expected float value".into()));
+                },
+            }
+        },
+        DataType::Boolean => {
+            let b = downcast_builder::<BooleanBuilder>(builder, "BooleanBuilder", "Boolean")?;
+            match value {
+                Some(Value::Bool(b_val)) => b.append_value(*b_val),
+                None => b.append_null(),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError("Expected bool value".into()));
+                },
+            }
+        },
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let b = downcast_builder::<TimestampNanosecondBuilder>(
+                builder,
+                "TimestampNanosecondBuilder",
+                "Timestamp(Nanosecond)",
+            )?;
+            match value {
+                Some(Value::Timestamp(nanos)) => b.append_value(*nanos),
+                None => b.append_null(),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "Expected timestamp value".into(),
+                    ));
+                },
+            }
+        },
+        DataType::Date32 => {
+            let b = downcast_builder::<Date32Builder>(builder, "Date32Builder", "Date32")?;
+            match value {
+                Some(Value::Date(days)) => b.append_value(*days),
+                None => b.append_null(),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError("Expected date value".into()));
+                },
+            }
+        },
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Unsupported data type: {data_type:?}"
+            )));
+        },
+    }
+    Ok(())
+}
+
 /// Downcast a boxed `ArrayBuilder` to a concrete type, returning `ArrowError` on mismatch.
 fn downcast_builder<'a, T: ArrayBuilder + 'static>(
     builder: &'a mut Box<dyn ArrayBuilder>,
@@ -293,5 +378,231 @@ fn create_builder_for_type(
     }
 }
 
+/// Arrow type of a dictionary-encoded string column.
+#[must_use]
+pub fn string_dictionary_type() -> DataType {
+    DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+}
+
+/// Replace the named `Utf8` columns of `batch` with `Dictionary(Int32, Utf8)` columns.
+///
+/// Dictionary encoding stores each distinct value once, which shrinks low-cardinality
+/// columns (`status`, `entity_type`, ...) by an order of magnitude on the wire. Columns
+/// that are already dictionary-encoded are left as they are.
+///
+/// # Errors
+///
+/// Returns `ArrowError::InvalidArgumentError` if a named column does not exist or is not
+/// a `Utf8` column.
+pub fn dictionary_encode_columns(
+    batch: &RecordBatch,
+    columns: &[String],
+) -> Result<RecordBatch, ArrowError> {
+    if columns.is_empty() {
+        return Ok(batch.clone());
+    }
+
+    let schema = batch.schema();
+    for name in columns {
+        let field = schema.field_with_name(name).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot dictionary-encode unknown column '{name}'"
+            ))
+        })?;
+        if !matches!(field.data_type(), DataType::Utf8 | DataType::Dictionary(..)) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot dictionary-encode column '{name}' of type {:?}; only Utf8 columns are supported",
+                field.data_type()
+            )));
+        }
+    }
+
+    let dictionary_type = string_dictionary_type();
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(schema.fields().len());
+    for (field, array) in schema.fields().iter().zip(batch.columns()) {
+        if field.data_type() == &DataType::Utf8 && columns.iter().any(|c| c == field.name()) {
+            fields.push(field.as_ref().clone().with_data_type(dictionary_type.clone()));
+            arrays.push(cast(array, &dictionary_type)?);
+        } else {
+            fields.push(field.as_ref().clone());
+            arrays.push(array.clone());
+        }
+    }
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        arrays,
+    )
+}
+
+/// Convert historical observer events to a `RecordBatch` with the
+/// [`observer_event_schema`].
+///
+/// The event payload is serialized to a JSON string and the event's tenant fills the
+/// `org_id` column.
+///
+/// # Errors
+///
+/// Returns `ArrowError` if the batch cannot be assembled.
+pub fn observer_events_to_batch(events: &[HistoricalEvent]) -> Result<RecordBatch, ArrowError> {
+    let capacity = events.len();
+    let mut event_id = StringBuilder::with_capacity(capacity, capacity * 36);
+    let mut event_type = StringBuilder::with_capacity(capacity, capacity * 8);
+    let mut entity_type = StringBuilder::with_capacity(capacity, capacity * 16);
+    let mut entity_id = StringBuilder::with_capacity(capacity, capacity * 36);
+    let mut timestamp = TimestampMicrosecondBuilder::with_capacity(capacity).with_timezone("UTC");
+    let mut data = StringBuilder::with_capacity(capacity, capacity * 64);
+    let mut user_id = StringBuilder::with_capacity(capacity, capacity * 16);
+    let mut org_id = StringBuilder::with_capacity(capacity, capacity * 16);
+
+    for event in events {
+        event_id.append_value(event.id.to_string());
+        event_type.append_value(&event.event_type);
+        entity_type.append_value(&event.entity_type);
+        entity_id.append_value(event.entity_id.to_string());
+        timestamp.append_value(event.timestamp.timestamp_micros());
+        data.append_value(event.data.to_string());
+        user_id.append_option(event.user_id.as_deref());
+        org_id.append_option(event.tenant_id.as_deref());
+    }
+
+    RecordBatch::try_new(
+        observer_event_schema(),
+        vec![
+            Arc::new(event_id.finish()),
+            Arc::new(event_type.finish()),
+            Arc::new(entity_type.finish()),
+            Arc::new(entity_id.finish()),
+            Arc::new(timestamp.finish()),
+            Arc::new(data.finish()),
+            Arc::new(user_id.finish()),
+            Arc::new(org_id.finish()),
+        ],
+    )
+}
+
+/// Convert dead letter queue records to a `RecordBatch` with the
+/// [`dlq_item_schema`].
+///
+/// # Errors
+///
+/// Returns `ArrowError` if the batch cannot be assembled.
+pub fn dlq_items_to_batch(items: &[DlqRecord]) -> Result<RecordBatch, ArrowError> {
+    let capacity = items.len();
+    let mut id = StringBuilder::with_capacity(capacity, capacity * 36);
+    let mut action_type = StringBuilder::with_capacity(capacity, capacity * 8);
+    let mut event_id = StringBuilder::with_capacity(capacity, capacity * 36);
+    let mut event_type = StringBuilder::with_capacity(capacity, capacity * 8);
+    let mut entity_type = StringBuilder::with_capacity(capacity, capacity * 16);
+    let mut error_code = StringBuilder::with_capacity(capacity, capacity * 5);
+    let mut error_message = StringBuilder::with_capacity(capacity, capacity * 64);
+    let mut attempts = UInt32Builder::with_capacity(capacity);
+    let mut org_id = StringBuilder::with_capacity(capacity, capacity * 16);
+    let mut created_at = TimestampMicrosecondBuilder::with_capacity(capacity).with_timezone("UTC");
+
+    for item in items {
+        id.append_value(item.id.to_string());
+        action_type.append_value(&item.action_type);
+        event_id.append_value(item.event_id.to_string());
+        event_type.append_value(&item.event_type);
+        entity_type.append_value(&item.entity_type);
+        error_code.append_option(item.error_code.as_deref());
+        error_message.append_value(&item.error_message);
+        attempts.append_value(item.attempts);
+        org_id.append_option(item.tenant_id.as_deref());
+        created_at.append_value(item.created_at.timestamp_micros());
+    }
+
+    RecordBatch::try_new(
+        dlq_item_schema(),
+        vec![
+            Arc::new(id.finish()),
+            Arc::new(action_type.finish()),
+            Arc::new(event_id.finish()),
+            Arc::new(event_type.finish()),
+            Arc::new(entity_type.finish()),
+            Arc::new(error_code.finish()),
+            Arc::new(error_message.finish()),
+            Arc::new(attempts.finish()),
+            Arc::new(org_id.finish()),
+            Arc::new(created_at.finish()),
+        ],
+    )
+}
+
+/// Convert job attempt records to a `RecordBatch` with the
+/// [`job_attempt_schema`].
+///
+/// # Errors
+///
+/// Returns `ArrowError` if the batch cannot be assembled.
+pub fn job_attempts_to_batch(attempts: &[JobAttemptRecord]) -> Result<RecordBatch, ArrowError> {
+    let capacity = attempts.len();
+    let mut job_id = StringBuilder::with_capacity(capacity, capacity * 36);
+    let mut event_id = StringBuilder::with_capacity(capacity, capacity * 36);
+    let mut action_type = StringBuilder::with_capacity(capacity, capacity * 8);
+    let mut state = StringBuilder::with_capacity(capacity, capacity * 8);
+    let mut attempt = UInt32Builder::with_capacity(capacity);
+    let mut max_attempts = UInt32Builder::with_capacity(capacity);
+    let mut success = BooleanBuilder::with_capacity(capacity);
+    let mut error_code = StringBuilder::with_capacity(capacity, capacity * 5);
+    let mut error_message = StringBuilder::with_capacity(capacity, capacity * 64);
+    let mut job_created_at =
+        TimestampMicrosecondBuilder::with_capacity(capacity).with_timezone("UTC");
+    let mut started_at = TimestampMicrosecondBuilder::with_capacity(capacity).with_timezone("UTC");
+
+    for record in attempts {
+        job_id.append_value(record.job_id.to_string());
+        event_id.append_value(record.event_id.to_string());
+        action_type.append_value(&record.action_type);
+        state.append_value(&record.state);
+        attempt.append_value(record.attempt);
+        max_attempts.append_value(record.max_attempts);
+        success.append_value(record.success);
+        error_code.append_option(record.error_code.as_deref());
+        error_message.append_option(record.error_message.as_deref());
+        job_created_at.append_value(record.job_created_at.timestamp_micros());
+        started_at.append_value(record.started_at.timestamp_micros());
+    }
+
+    RecordBatch::try_new(
+        job_attempt_schema(),
+        vec![
+            Arc::new(job_id.finish()),
+            Arc::new(event_id.finish()),
+            Arc::new(action_type.finish()),
+            Arc::new(state.finish()),
+            Arc::new(attempt.finish()),
+            Arc::new(max_attempts.finish()),
+            Arc::new(success.finish()),
+            Arc::new(error_code.finish()),
+            Arc::new(error_message.finish()),
+            Arc::new(job_created_at.finish()),
+            Arc::new(started_at.finish()),
+        ],
+    )
+}
+
+/// Schema of `schema` once the named `Utf8` columns are dictionary-encoded.
+///
+/// Unknown and non-`Utf8` columns are ignored here; [`dictionary_encode_columns`]
+/// reports them when the batches are encoded.
+#[must_use]
+pub fn dictionary_encoded_schema(schema: &Schema, columns: &[String]) -> Schema {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            if field.data_type() == &DataType::Utf8 && columns.iter().any(|c| c == field.name()) {
+                field.as_ref().clone().with_data_type(string_dictionary_type())
+            } else {
+                field.as_ref().clone()
+            }
+        })
+        .collect();
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
+
 #[cfg(test)]
 mod tests;
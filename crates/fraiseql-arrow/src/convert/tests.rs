@@ -703,3 +703,198 @@ fn test_downcast_builder_error_message_includes_both_type_names() {
     assert!(msg.contains("Float64Builder"));
     assert!(msg.contains("Float64"));
 }
+
+#[test]
+fn test_dictionary_encode_columns() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("status", DataType::Utf8, true),
+    ]));
+    let converter = RowToArrowConverter::new(schema, ConvertConfig::default());
+    let rows = ["open", "closed", "open", "open"]
+        .iter()
+        .enumerate()
+        .map(|(i, status)| {
+            vec![
+                Some(Value::Int(i as i64)),
+                Some(Value::String((*status).to_string())),
+            ]
+        })
+        .collect();
+    let batch = converter.convert_batch(rows).unwrap();
+
+    let encoded = dictionary_encode_columns(&batch, &["status".to_string()]).unwrap();
+
+    assert_eq!(encoded.num_rows(), 4);
+    assert_eq!(encoded.schema().field(0).data_type(), &DataType::Int64);
+    assert_eq!(encoded.schema().field(1).data_type(), &string_dictionary_type());
+    let status = encoded
+        .column(1)
+        .as_any()
+        .downcast_ref::<arrow::array::DictionaryArray<arrow::datatypes::Int32Type>>()
+        .unwrap();
+    assert_eq!(status.values().len(), 2);
+    assert_eq!(
+        encoded.schema().as_ref(),
+        &dictionary_encoded_schema(&batch.schema(), &["status".to_string()])
+    );
+}
+
+#[test]
+fn test_dictionary_encode_rejects_unknown_and_non_string_columns() {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+    let converter = RowToArrowConverter::new(schema, ConvertConfig::default());
+    let batch = converter.convert_batch(vec![vec![Some(Value::Int(1))]]).unwrap();
+
+    assert!(dictionary_encode_columns(&batch, &["missing".to_string()]).is_err());
+    assert!(dictionary_encode_columns(&batch, &["id".to_string()]).is_err());
+}
+
+#[test]
+fn test_observer_events_to_batch() {
+    let event = crate::event_storage::HistoricalEvent {
+        id:          uuid::Uuid::from_u128(1),
+        event_type:  "INSERT".to_string(),
+        entity_type: "Order".to_string(),
+        entity_id:   uuid::Uuid::from_u128(2),
+        data:        serde_json::json!({"total": 10}),
+        user_id:     None,
+        tenant_id:   Some("acme".to_string()),
+        timestamp:   chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+    };
+
+    let batch = observer_events_to_batch(&[event]).unwrap();
+
+    assert_eq!(batch.schema(), crate::schema::observer_event_schema());
+    assert_eq!(batch.num_rows(), 1);
+    assert!(batch.column_by_name("user_id").unwrap().is_null(0));
+    let org_id = batch.column_by_name("org_id").unwrap();
+    let org_id = org_id.as_any().downcast_ref::<arrow::array::StringArray>().unwrap();
+    assert_eq!(org_id.value(0), "acme");
+}
+
+#[test]
+fn test_dlq_items_to_batch() {
+    let item = crate::delivery_storage::DlqRecord {
+        id:            uuid::Uuid::from_u128(1),
+        action_type:   "webhook".to_string(),
+        event_id:      uuid::Uuid::from_u128(2),
+        event_type:    "INSERT".to_string(),
+        entity_type:   "Order".to_string(),
+        error_code:    Some("OB006".to_string()),
+        error_message: "OB006: Action execution failed: 503".to_string(),
+        attempts:      5,
+        tenant_id:     None,
+        created_at:    chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+    };
+
+    let batch = dlq_items_to_batch(&[item]).unwrap();
+
+    assert_eq!(batch.schema(), crate::schema::dlq_item_schema());
+    assert_eq!(batch.num_rows(), 1);
+    assert!(batch.column_by_name("org_id").unwrap().is_null(0));
+    let attempts = batch.column_by_name("attempts").unwrap();
+    let attempts = attempts.as_any().downcast_ref::<arrow::array::UInt32Array>().unwrap();
+    assert_eq!(attempts.value(0), 5);
+}
+
+#[test]
+fn test_job_attempts_to_batch() {
+    let started_at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let attempt = |attempt: u32, error: Option<&str>| crate::delivery_storage::JobAttemptRecord {
+        job_id: uuid::Uuid::from_u128(1),
+        event_id: uuid::Uuid::from_u128(2),
+        action_type: "email".to_string(),
+        state: "completed".to_string(),
+        attempt,
+        max_attempts: 3,
+        success: error.is_none(),
+        error_code: None,
+        error_message: error.map(str::to_string),
+        job_created_at: started_at,
+        started_at,
+    };
+
+    let batch = job_attempts_to_batch(&[attempt(1, Some("timeout")), attempt(2, None)]).unwrap();
+
+    assert_eq!(batch.schema(), crate::schema::job_attempt_schema());
+    assert_eq!(batch.num_rows(), 2);
+    let success = batch.column_by_name("success").unwrap();
+    let success = success.as_any().downcast_ref::<arrow::array::BooleanArray>().unwrap();
+    assert!(!success.value(0));
+    assert!(success.value(1));
+    assert!(batch.column_by_name("error_message").unwrap().is_null(1));
+}
+
+// --- Nested List<Struct> columns ---
+
+fn items_type() -> DataType {
+    DataType::List(Arc::new(Field::new(
+        "item",
+        DataType::Struct(
+            vec![
+                Field::new("sku", DataType::Utf8, true),
+                Field::new("qty", DataType::Int64, true),
+            ]
+            .into(),
+        ),
+        true,
+    )))
+}
+
+fn item(sku: &str, qty: Option<i64>) -> Option<Value> {
+    Some(Value::Struct(vec![Some(Value::String(sku.to_string())), qty.map(Value::Int)]))
+}
+
+#[test]
+fn test_list_of_struct_column() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("items", items_type(), true),
+    ]));
+    let converter = RowToArrowConverter::new(schema, ConvertConfig::default());
+    let rows = vec![
+        vec![
+            Some(Value::Int(1)),
+            Some(Value::List(vec![item("a", Some(2)), item("b", None)])),
+        ],
+        vec![Some(Value::Int(2)), None],
+        vec![Some(Value::Int(3)), Some(Value::List(vec![]))],
+    ];
+
+    let batch = converter.convert_batch(rows).unwrap();
+
+    let items = batch.column(1).as_any().downcast_ref::<ListArray>().unwrap();
+    assert_eq!(items.value_offsets(), &[0, 2, 2, 2]);
+    assert!(items.is_valid(0) && items.is_null(1) && items.is_valid(2));
+
+    let first = items.value(0);
+    let first = first.as_any().downcast_ref::<StructArray>().unwrap();
+    let skus = first.column(0).as_any().downcast_ref::<arrow::array::StringArray>().unwrap();
+    let qtys = first.column(1).as_any().downcast_ref::<arrow::array::Int64Array>().unwrap();
+    assert_eq!((skus.value(0), skus.value(1)), ("a", "b"));
+    assert_eq!(qtys.value(0), 2);
+    assert!(qtys.is_null(1));
+}
+
+#[test]
+fn test_scalar_in_list_column_is_error() {
+    let schema = Arc::new(Schema::new(vec![Field::new("items", items_type(), true)]));
+    let converter = RowToArrowConverter::new(schema, ConvertConfig::default());
+    let result = converter.convert_batch(vec![vec![Some(Value::Int(1))]]);
+    assert!(result.unwrap_err().to_string().contains("Expected list value"));
+}
+
+#[test]
+fn test_struct_field_count_mismatch_is_error() {
+    let schema = Arc::new(Schema::new(vec![Field::new("items", items_type(), true)]));
+    let converter = RowToArrowConverter::new(schema, ConvertConfig::default());
+    let short = Some(Value::Struct(vec![Some(Value::String("a".to_string()))]));
+    let result = converter.convert_batch(vec![vec![Some(Value::List(vec![short]))]]);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("Struct value has 1 fields, expected 2")
+    );
+}
@@ -13,6 +13,7 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use tokio::sync::mpsc;
 
 /// Error type for database operations.
 ///
@@ -99,4 +100,37 @@ pub trait ArrowDatabaseAdapter: Send + Sync {
         &self,
         sql: &str,
     ) -> DatabaseResult<Vec<HashMap<String, serde_json::Value>>>;
+
+    /// Execute a raw SQL query and send its rows to `chunks`, at most `chunk_rows` rows
+    /// per chunk.
+    ///
+    /// Each send waits while `chunks` is full, so a bounded channel paces the query to
+    /// its consumer. Once the receiver is dropped the query stops and `Ok(())` is
+    /// returned.
+    ///
+    /// The default implementation runs [`execute_raw_query`](Self::execute_raw_query)
+    /// and splits its result, which paces the consumer but still reads the whole result
+    /// first. Adapters that can fetch incrementally (e.g. through a cursor) should
+    /// override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the query fails for any reason.
+    async fn execute_raw_query_chunked(
+        &self,
+        sql: &str,
+        chunk_rows: usize,
+        chunks: mpsc::Sender<Vec<HashMap<String, serde_json::Value>>>,
+    ) -> DatabaseResult<()> {
+        let mut rows = self.execute_raw_query(sql).await?.into_iter();
+        loop {
+            let chunk: Vec<_> = rows.by_ref().take(chunk_rows.max(1)).collect();
+            if chunk.is_empty() || chunks.send(chunk).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
 }
+
+#[cfg(test)]
+mod tests;
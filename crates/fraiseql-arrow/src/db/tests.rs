@@ -0,0 +1,39 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
+use serde_json::json;
+
+use super::*;
+
+struct FixedRows(usize);
+
+// Reason: ArrowDatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl ArrowDatabaseAdapter for FixedRows {
+    async fn execute_raw_query(
+        &self,
+        _sql: &str,
+    ) -> DatabaseResult<Vec<HashMap<String, serde_json::Value>>> {
+        Ok((0..self.0).map(|i| HashMap::from([("id".to_string(), json!(i))])).collect())
+    }
+}
+
+#[tokio::test]
+async fn default_chunked_query_splits_the_result() {
+    let (tx, mut rx) = mpsc::channel(8);
+    FixedRows(25).execute_raw_query_chunked("SELECT 1", 10, tx).await.unwrap();
+
+    let mut sizes = Vec::new();
+    while let Some(chunk) = rx.recv().await {
+        sizes.push(chunk.len());
+    }
+    assert_eq!(sizes, vec![10, 10, 5]);
+}
+
+#[tokio::test]
+async fn default_chunked_query_stops_when_the_receiver_is_dropped() {
+    let (tx, rx) = mpsc::channel(1);
+    drop(rx);
+
+    FixedRows(25).execute_raw_query_chunked("SELECT 1", 10, tx).await.unwrap();
+}
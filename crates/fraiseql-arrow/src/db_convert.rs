@@ -2,8 +2,22 @@
 //!
 //! This module bridges database-agnostic row data (`HashMap`<String, `serde_json::Value`>)
 //! to Arrow Values for `RecordBatch` construction.
+//!
+//! Nested JSONB arrays of objects (e.g. `order.items`) convert to `List<Struct>` values
+//! when the schema declares the column that way, so clients receive structured data
+//! instead of JSON strings.
+//!
+//! [`adaptive_batch_size`] sizes record batches to a byte budget rather than a fixed
+//! row count, so wide JSONB rows do not blow memory and narrow rows are not split into
+//! needlessly small batches.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use arrow::datatypes::{DataType, Schema};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
@@ -13,6 +27,89 @@ use crate::{
     error::{ArrowFlightError, Result},
 };
 
+/// Default byte budget of one Arrow record batch.
+pub const DEFAULT_BATCH_BYTE_BUDGET: usize = 8 * 1024 * 1024;
+
+/// Upper bound on the rows of one record batch, whatever the row width.
+pub const MAX_BATCH_ROWS: usize = 65_536;
+
+/// Number of leading rows sampled to estimate the row width.
+const BATCH_SIZE_SAMPLE_ROWS: usize = 64;
+
+static BATCHES_TOTAL: AtomicU64 = AtomicU64::new(0);
+static BATCH_ROWS_TOTAL: AtomicU64 = AtomicU64::new(0);
+static BATCH_BYTES_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+/// Estimated in-memory size of `value` once converted to Arrow: fixed-width values take
+/// their native width, strings their bytes plus an offset, lists and structs the sum of
+/// their children.
+#[must_use]
+pub fn estimate_value_bytes(value: &Value) -> usize {
+    match value {
+        Value::String(s) => s.len() + 4,
+        Value::Int(_) | Value::Float(_) | Value::Timestamp(_) => 8,
+        Value::Date(_) => 4,
+        Value::Bool(_) => 1,
+        Value::List(items) => 4 + estimate_row_bytes(items),
+        Value::Struct(fields) => estimate_row_bytes(fields),
+    }
+}
+
+/// Estimated in-memory size of one converted row. Nulls count one byte for the
+/// validity bitmap and the unused slot.
+#[must_use]
+pub fn estimate_row_bytes(row: &[Option<Value>]) -> usize {
+    row.iter().map(|value| value.as_ref().map_or(1, estimate_value_bytes)).sum()
+}
+
+/// Rows per record batch so that one batch stays within `byte_budget`, estimated from
+/// the average width of the first rows.
+///
+/// The result is clamped to `[1, max_rows]`; empty input yields `max_rows`.
+#[must_use]
+pub fn adaptive_batch_size(
+    rows: &[Vec<Option<Value>>],
+    byte_budget: usize,
+    max_rows: usize,
+) -> usize {
+    let max_rows = max_rows.max(1);
+    let sample = &rows[..rows.len().min(BATCH_SIZE_SAMPLE_ROWS)];
+    if sample.is_empty() {
+        return max_rows;
+    }
+    let sampled_bytes: usize = sample.iter().map(|row| estimate_row_bytes(row)).sum();
+    let row_bytes = sampled_bytes.div_ceil(sample.len()).max(1);
+    (byte_budget / row_bytes).clamp(1, max_rows)
+}
+
+/// Record one produced record batch of `rows` rows and roughly `bytes` bytes.
+pub fn record_batch_size(rows: usize, bytes: usize) {
+    BATCHES_TOTAL.fetch_add(1, Ordering::Relaxed);
+    BATCH_ROWS_TOTAL.fetch_add(rows as u64, Ordering::Relaxed);
+    BATCH_BYTES_TOTAL.fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+/// Achieved record batch sizes since process start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchSizeStats {
+    /// Record batches produced
+    pub batches: u64,
+    /// Rows across all produced batches
+    pub rows:    u64,
+    /// Estimated bytes across all produced batches
+    pub bytes:   u64,
+}
+
+/// Snapshot of the batch size counters.
+#[must_use]
+pub fn batch_size_stats() -> BatchSizeStats {
+    BatchSizeStats {
+        batches: BATCHES_TOTAL.load(Ordering::Relaxed),
+        rows:    BATCH_ROWS_TOTAL.load(Ordering::Relaxed),
+        bytes:   BATCH_BYTES_TOTAL.load(Ordering::Relaxed),
+    }
+}
+
 /// Convert database rows to Arrow Values.
 ///
 /// Takes rows returned from `DatabaseAdapter::execute_raw_query()` and converts
@@ -189,6 +286,44 @@ fn json_to_arrow_value(json_val: &serde_json::Value, data_type: &DataType) -> Re
                 "Expected string or number for Date32, got {json_val}"
             ))),
         },
+        DataType::List(item) => match json_val {
+            JsonValue::Array(items) => items
+                .iter()
+                .map(|element| {
+                    if element.is_null() {
+                        Ok(None)
+                    } else {
+                        json_to_arrow_value(element, item.data_type()).map(Some)
+                    }
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(Value::List),
+            // Drivers may hand JSON columns over as text.
+            JsonValue::String(s) => match serde_json::from_str::<JsonValue>(s) {
+                Ok(parsed @ JsonValue::Array(_)) => json_to_arrow_value(&parsed, data_type),
+                _ => Err(ArrowFlightError::Conversion(format!(
+                    "Expected JSON array for List, got {json_val}"
+                ))),
+            },
+            _ => Err(ArrowFlightError::Conversion(format!(
+                "Expected array for List, got {json_val}"
+            ))),
+        },
+        DataType::Struct(fields) => match json_val {
+            JsonValue::Object(object) => fields
+                .iter()
+                .map(|field| match object.get(field.name()) {
+                    Some(value) if !value.is_null() => {
+                        json_to_arrow_value(value, field.data_type()).map(Some)
+                    },
+                    _ => Ok(None), // NULL or missing key
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Struct),
+            _ => Err(ArrowFlightError::Conversion(format!(
+                "Expected object for Struct, got {json_val}"
+            ))),
+        },
         _ => Err(ArrowFlightError::Conversion(format!("Unsupported data type: {data_type:?}"))),
     }
 }
@@ -426,3 +426,102 @@ fn test_json_null_treated_as_none() {
     let arrow_rows = convert_db_rows_to_arrow(&rows, &schema).unwrap();
     assert_eq!(arrow_rows[0][0], None);
 }
+
+// --- nested JSONB arrays of objects ---
+
+fn items_schema() -> Arc<Schema> {
+    let item = DataType::Struct(
+        vec![
+            Field::new("sku", DataType::Utf8, true),
+            Field::new("qty", DataType::Int64, true),
+        ]
+        .into(),
+    );
+    Arc::new(Schema::new(vec![Field::new(
+        "items",
+        DataType::List(Arc::new(Field::new("item", item, true))),
+        true,
+    )]))
+}
+
+#[test]
+fn test_array_of_objects_converts_to_list_of_struct() {
+    let mut row = HashMap::new();
+    row.insert("items".to_string(), json!([{"sku": "a", "qty": 2}, {"sku": "b"}, null]));
+    let arrow_rows = convert_db_rows_to_arrow(&[row], &items_schema()).unwrap();
+
+    assert_eq!(
+        arrow_rows[0][0],
+        Some(Value::List(vec![
+            Some(Value::Struct(vec![Some(Value::String("a".to_string())), Some(Value::Int(2))])),
+            Some(Value::Struct(vec![Some(Value::String("b".to_string())), None])),
+            None,
+        ]))
+    );
+}
+
+#[test]
+fn test_json_text_array_converts_to_list() {
+    let mut row = HashMap::new();
+    row.insert("items".to_string(), json!(r#"[{"sku": "a", "qty": 1}]"#));
+    let arrow_rows = convert_db_rows_to_arrow(&[row], &items_schema()).unwrap();
+    assert!(matches!(&arrow_rows[0][0], Some(Value::List(items)) if items.len() == 1));
+}
+
+#[test]
+fn test_scalar_for_list_column_is_error() {
+    let mut row = HashMap::new();
+    row.insert("items".to_string(), json!(42));
+    let result = convert_db_rows_to_arrow(&[row], &items_schema());
+    assert!(matches!(result, Err(ArrowFlightError::Conversion(_))));
+}
+
+fn text_rows(count: usize, width: usize) -> Vec<Vec<Option<Value>>> {
+    (0..count)
+        .map(|i| {
+            vec![
+                Some(Value::Int(i64::try_from(i).unwrap())),
+                Some(Value::String("x".repeat(width))),
+            ]
+        })
+        .collect()
+}
+
+#[test]
+fn test_estimate_row_bytes_counts_nested_values_and_nulls() {
+    let row = vec![
+        Some(Value::Int(1)),
+        None,
+        Some(Value::String("abcd".to_string())),
+        Some(Value::List(vec![Some(Value::Bool(true)), None])),
+    ];
+    // 8 + 1 + (4 + 4) + (4 + 1 + 1)
+    assert_eq!(estimate_row_bytes(&row), 23);
+}
+
+#[test]
+fn test_adaptive_batch_size_shrinks_for_wide_rows() {
+    let narrow = adaptive_batch_size(&text_rows(100, 1), 1024 * 1024, MAX_BATCH_ROWS);
+    let wide = adaptive_batch_size(&text_rows(100, 64 * 1024), 1024 * 1024, MAX_BATCH_ROWS);
+
+    assert!(wide < narrow);
+    assert_eq!(wide, 15, "1 MiB over rows of ~64 KiB");
+    assert_eq!(narrow, 65_536, "narrow rows are capped at max_rows");
+}
+
+#[test]
+fn test_adaptive_batch_size_is_never_zero() {
+    assert_eq!(adaptive_batch_size(&text_rows(3, 1024), 1, 0), 1);
+    assert_eq!(adaptive_batch_size(&[], 1024, 500), 500);
+}
+
+#[test]
+fn test_batch_size_stats_accumulate() {
+    let before = batch_size_stats();
+    record_batch_size(10, 400);
+
+    let after = batch_size_stats();
+    assert!(after.batches > before.batches);
+    assert!(after.rows >= before.rows + 10);
+    assert!(after.bytes >= before.bytes + 400);
+}
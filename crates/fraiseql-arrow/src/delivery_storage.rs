@@ -0,0 +1,117 @@
+//! Delivery history interface for exporting failed and queued observer actions.
+//!
+//! This module provides a trait for reading the observer dead letter queue and
+//! the job queue's attempt history, so `DlqItems` and `JobHistory` Flight
+//! tickets can export them as Arrow batches. Implementations are provided by
+//! the application (e.g., `fraiseql-observers`' `arrow_bridge`), which keeps
+//! read access to the operational Redis/PostgreSQL stores on the server.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An action that exhausted its retries, as held in the dead letter queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DlqRecord {
+    /// DLQ item ID
+    pub id:            Uuid,
+    /// Action type (e.g., "webhook", "email")
+    pub action_type:   String,
+    /// ID of the event the action ran for
+    pub event_id:      Uuid,
+    /// Event type: "INSERT", "UPDATE", "DELETE", or "CUSTOM"
+    pub event_type:    String,
+    /// Entity type of the event (e.g., "Order")
+    pub entity_type:   String,
+    /// Error code of the final failure (e.g., "OB006"), if the error carried one
+    pub error_code:    Option<String>,
+    /// Error message of the final failure
+    pub error_message: String,
+    /// Number of attempts made
+    pub attempts:      u32,
+    /// Tenant of the event (if applicable)
+    pub tenant_id:     Option<String>,
+    /// When the item entered the DLQ; stores without that timestamp use the
+    /// event's
+    pub created_at:    DateTime<Utc>,
+}
+
+/// One execution attempt of a queued job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobAttemptRecord {
+    /// Job ID
+    pub job_id:         Uuid,
+    /// ID of the event the job runs an action for
+    pub event_id:       Uuid,
+    /// Action type (e.g., "webhook", "email")
+    pub action_type:    String,
+    /// Current state of the job (e.g., "pending", "dead_lettered")
+    pub state:          String,
+    /// Attempt number (1-based)
+    pub attempt:        u32,
+    /// Maximum attempts of the job
+    pub max_attempts:   u32,
+    /// Whether this attempt succeeded
+    pub success:        bool,
+    /// Error code of the attempt's failure (e.g., "OB006"), if it carried one
+    pub error_code:     Option<String>,
+    /// Error message of the attempt's failure
+    pub error_message:  Option<String>,
+    /// When the job was created
+    pub job_created_at: DateTime<Utc>,
+    /// When this attempt started
+    pub started_at:     DateTime<Utc>,
+}
+
+/// Filters of a delivery history export.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeliveryQuery {
+    /// Only records of this action type
+    pub action_type: Option<String>,
+    /// Start of the time range (inclusive)
+    pub start_date:  Option<DateTime<Utc>>,
+    /// End of the time range (inclusive)
+    pub end_date:    Option<DateTime<Utc>>,
+    /// Maximum number of records
+    pub limit:       Option<usize>,
+}
+
+impl DeliveryQuery {
+    /// Whether a record of `action_type` at `timestamp` passes the filters.
+    ///
+    /// For implementations filtering in memory; `limit` is not applied.
+    #[must_use]
+    pub fn matches(&self, action_type: &str, timestamp: DateTime<Utc>) -> bool {
+        self.action_type.as_deref().is_none_or(|wanted| wanted == action_type)
+            && self.start_date.is_none_or(|start| timestamp >= start)
+            && self.end_date.is_none_or(|end| timestamp <= end)
+    }
+}
+
+/// Arrow Flight-specific trait for reading observer delivery history.
+///
+/// DLQ records are filtered on `created_at` and job attempts on `started_at`.
+/// Both are returned most recent first.
+// Reason: used as dyn Trait (Arc<dyn ArrowDeliveryStorage>); async_trait ensures Send bounds and
+// dyn-compatibility async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+pub trait ArrowDeliveryStorage: Send + Sync {
+    /// Query the dead letter queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the store cannot be read.
+    async fn query_dlq_items(&self, query: &DeliveryQuery) -> Result<Vec<DlqRecord>, String>;
+
+    /// Query the attempts of queued jobs, optionally only of jobs in `state`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the store cannot be read.
+    async fn query_job_attempts(
+        &self,
+        query: &DeliveryQuery,
+        state: Option<&str>,
+    ) -> Result<Vec<JobAttemptRecord>, String>;
+}
@@ -0,0 +1,487 @@
+//! `DuckDB` sink materializing Arrow `RecordBatches` into a local database file.
+//!
+//! Exported batches (observer events, bulk exports) are appended to a table of a local
+//! `DuckDB` file, which then serves dashboard aggregates without shipping raw rows:
+//!
+//! ```text
+//! Arrow RecordBatch
+//!     ↓
+//! DuckDbSink::write_batch / DuckDbSink::run(mpsc::Receiver)
+//!     ↓
+//! DuckDB appender → local table (created from the first batch's schema)
+//!     ↓
+//! DuckDbSink::aggregate (GROUP BY / COUNT / SUM / ... computed in DuckDB)
+//!     ↓
+//! Aggregated Arrow RecordBatch (Flight `Aggregate` ticket)
+//! ```
+//!
+//! Values are exchanged with `DuckDB` row by row through its own value types, so the
+//! sink does not depend on the Arrow version `DuckDB` is built against. All calls are
+//! blocking local file I/O; the connection is shared behind a mutex.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use arrow::{
+    array::{Array, ArrayRef, AsArray, Float64Builder, Int64Builder, RecordBatch, StringBuilder},
+    compute::cast,
+    datatypes::{
+        DataType, Date32Type, Float64Type, Int32Type, Int64Type, Schema, TimeUnit,
+        TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+        TimestampSecondType,
+    },
+};
+use duckdb::{
+    Connection, appender_params_from_iter, params_from_iter,
+    types::{TimeUnit as DuckTimeUnit, Value},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+use crate::{
+    error::{ArrowFlightError, Result},
+    schema::aggregate_result_schema,
+    ticket::{AggregateFunction, AggregateSpec},
+};
+
+/// `DuckDB` sink configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuckDbSinkConfig {
+    /// Database file path; `:memory:` keeps the database in memory (default:
+    /// `fraiseql_analytics.duckdb`)
+    #[serde(default = "default_duckdb_path")]
+    pub path: String,
+
+    /// Table batches are appended to (default: `fraiseql_events`)
+    #[serde(default = "default_duckdb_table")]
+    pub table: String,
+}
+
+/// Default `DuckDB` database path
+fn default_duckdb_path() -> String {
+    std::env::var("FRAISEQL_DUCKDB_PATH")
+        .unwrap_or_else(|_| "fraiseql_analytics.duckdb".to_string())
+}
+
+/// Default `DuckDB` table
+fn default_duckdb_table() -> String {
+    std::env::var("FRAISEQL_DUCKDB_TABLE").unwrap_or_else(|_| "fraiseql_events".to_string())
+}
+
+impl Default for DuckDbSinkConfig {
+    fn default() -> Self {
+        Self {
+            path:  default_duckdb_path(),
+            table: default_duckdb_table(),
+        }
+    }
+}
+
+impl DuckDbSinkConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Configuration`] if the path is empty or the table is
+    /// not a plain SQL identifier.
+    pub fn validate(&self) -> Result<()> {
+        if self.path.is_empty() {
+            return Err(ArrowFlightError::Configuration("DuckDB path cannot be empty".to_string()));
+        }
+        if !is_identifier(&self.table) {
+            return Err(ArrowFlightError::Configuration(format!(
+                "DuckDB table must be a plain SQL identifier: '{}'",
+                self.table
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Whether `name` is a plain `[A-Za-z_][A-Za-z0-9_]*` identifier.
+fn is_identifier(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Quote `name` as a SQL identifier.
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn duckdb_error(context: &str, e: &duckdb::Error) -> ArrowFlightError {
+    ArrowFlightError::External(format!("DuckDB {context} failed: {e}"))
+}
+
+/// `DuckDB` column type for an Arrow type, after [`normalize_column`].
+fn duckdb_type(data_type: &DataType) -> Result<&'static str> {
+    match data_type {
+        DataType::Utf8 => Ok("VARCHAR"),
+        DataType::Boolean => Ok("BOOLEAN"),
+        DataType::Int32 => Ok("INTEGER"),
+        DataType::Int64 => Ok("BIGINT"),
+        DataType::Float64 => Ok("DOUBLE"),
+        DataType::Date32 => Ok("DATE"),
+        // Timestamps are stored as UTC wall-clock TIMESTAMPs.
+        DataType::Timestamp(..) => Ok("TIMESTAMP"),
+        other => Err(ArrowFlightError::Conversion(format!(
+            "Arrow type {other:?} cannot be stored in DuckDB"
+        ))),
+    }
+}
+
+/// Cast dictionary-encoded and large string columns to plain `Utf8`.
+fn normalize_column(array: &ArrayRef) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::LargeUtf8 | DataType::Utf8View | DataType::Dictionary(..) => {
+            Ok(cast(array, &DataType::Utf8)?)
+        },
+        _ => Ok(array.clone()),
+    }
+}
+
+/// The `DuckDB` value of one cell of a normalized column.
+fn cell_value(array: &dyn Array, row: usize) -> Result<Value> {
+    if array.is_null(row) {
+        return Ok(Value::Null);
+    }
+    Ok(match array.data_type() {
+        DataType::Utf8 => Value::Text(array.as_string::<i32>().value(row).to_string()),
+        DataType::Boolean => Value::Boolean(array.as_boolean().value(row)),
+        DataType::Int32 => Value::Int(array.as_primitive::<Int32Type>().value(row)),
+        DataType::Int64 => Value::BigInt(array.as_primitive::<Int64Type>().value(row)),
+        DataType::Float64 => Value::Double(array.as_primitive::<Float64Type>().value(row)),
+        DataType::Date32 => Value::Date32(array.as_primitive::<Date32Type>().value(row)),
+        DataType::Timestamp(TimeUnit::Second, _) => Value::Timestamp(
+            DuckTimeUnit::Second,
+            array.as_primitive::<TimestampSecondType>().value(row),
+        ),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => Value::Timestamp(
+            DuckTimeUnit::Millisecond,
+            array.as_primitive::<TimestampMillisecondType>().value(row),
+        ),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => Value::Timestamp(
+            DuckTimeUnit::Microsecond,
+            array.as_primitive::<TimestampMicrosecondType>().value(row),
+        ),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => Value::Timestamp(
+            DuckTimeUnit::Nanosecond,
+            array.as_primitive::<TimestampNanosecondType>().value(row),
+        ),
+        other => {
+            return Err(ArrowFlightError::Conversion(format!(
+                "Arrow type {other:?} cannot be stored in DuckDB"
+            )));
+        },
+    })
+}
+
+/// Builder of one aggregate output column.
+enum AggregateBuilder {
+    Count(Int64Builder),
+    Value(Float64Builder),
+}
+
+impl AggregateBuilder {
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Self::Count(builder) => Arc::new(builder.finish()),
+            Self::Value(builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
+/// `DuckDB` sink for materializing Arrow `RecordBatches` and aggregating them locally
+pub struct DuckDbSink {
+    config:     DuckDbSinkConfig,
+    connection: Mutex<Connection>,
+}
+
+impl DuckDbSink {
+    /// Open (or create) the configured database file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Configuration`] if `config.validate()` fails and
+    /// [`ArrowFlightError::External`] if the database cannot be opened.
+    pub fn new(config: DuckDbSinkConfig) -> Result<Self> {
+        config.validate()?;
+
+        let connection = if config.path == ":memory:" {
+            Connection::open_in_memory()
+        } else {
+            Connection::open(&config.path)
+        }
+        .map_err(|e| duckdb_error("open", &e))?;
+
+        info!(path = %config.path, table = %config.table, "Opened DuckDB sink");
+
+        Ok(Self {
+            config,
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// The configured sink table.
+    #[must_use]
+    pub fn table(&self) -> &str {
+        &self.config.table
+    }
+
+    fn connection(&self) -> Result<MutexGuard<'_, Connection>> {
+        self.connection
+            .lock()
+            .map_err(|_| ArrowFlightError::External("DuckDB connection mutex poisoned".to_string()))
+    }
+
+    /// Column names of a table of the sink's database, in declaration order (empty if
+    /// the table does not exist).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::External`] if the catalog cannot be read.
+    pub fn columns(&self, table: &str) -> Result<Vec<String>> {
+        Self::table_columns(&self.connection()?, table)
+    }
+
+    /// Column names of `table` in declaration order (empty if it does not exist).
+    fn table_columns(connection: &Connection, table: &str) -> Result<Vec<String>> {
+        let mut stmt = connection
+            .prepare(
+                "SELECT column_name FROM information_schema.columns \
+                 WHERE table_name = ? ORDER BY ordinal_position",
+            )
+            .map_err(|e| duckdb_error("catalog lookup", &e))?;
+        let columns = stmt
+            .query_map([table], |row| row.get::<_, String>(0))
+            .map_err(|e| duckdb_error("catalog lookup", &e))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| duckdb_error("catalog lookup", &e))?;
+        Ok(columns)
+    }
+
+    /// Create the sink table from `schema`, or check an existing table has the same
+    /// columns in the same order (the appender writes columns positionally).
+    fn ensure_table(&self, connection: &Connection, schema: &Schema) -> Result<()> {
+        let existing = Self::table_columns(connection, &self.config.table)?;
+        if existing.is_empty() {
+            let columns = schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    let data_type = match field.data_type() {
+                        DataType::LargeUtf8 | DataType::Utf8View | DataType::Dictionary(..) => {
+                            DataType::Utf8
+                        },
+                        other => other.clone(),
+                    };
+                    Ok(format!("{} {}", quote_identifier(field.name()), duckdb_type(&data_type)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let sql = format!(
+                "CREATE TABLE IF NOT EXISTS {} ({})",
+                quote_identifier(&self.config.table),
+                columns.join(", ")
+            );
+            connection.execute_batch(&sql).map_err(|e| duckdb_error("CREATE TABLE", &e))?;
+            info!(table = %self.config.table, "Created DuckDB sink table");
+            return Ok(());
+        }
+
+        let incoming: Vec<&String> = schema.fields().iter().map(|f| f.name()).collect();
+        if existing.iter().collect::<Vec<_>>() != incoming {
+            return Err(ArrowFlightError::Conversion(format!(
+                "Batch columns {incoming:?} do not match DuckDB table '{}' columns {existing:?}",
+                self.config.table
+            )));
+        }
+        Ok(())
+    }
+
+    /// Append a `RecordBatch` to the sink table, creating the table from the batch's
+    /// schema if needed.
+    ///
+    /// Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::Conversion`] if a column type is not supported or the
+    /// batch does not match an existing table, and [`ArrowFlightError::External`] if
+    /// `DuckDB` rejects the write.
+    pub fn write_batch(&self, batch: &RecordBatch) -> Result<usize> {
+        let columns = batch
+            .columns()
+            .iter()
+            .map(normalize_column)
+            .collect::<Result<Vec<ArrayRef>>>()?;
+
+        let connection = self.connection()?;
+        self.ensure_table(&connection, &batch.schema())?;
+
+        let mut appender = connection
+            .appender(&self.config.table)
+            .map_err(|e| duckdb_error("appender", &e))?;
+        for row in 0..batch.num_rows() {
+            let values = columns
+                .iter()
+                .map(|column| cell_value(column.as_ref(), row))
+                .collect::<Result<Vec<Value>>>()?;
+            appender
+                .append_row(appender_params_from_iter(values))
+                .map_err(|e| duckdb_error("append", &e))?;
+        }
+        appender.flush().map_err(|e| duckdb_error("flush", &e))?;
+
+        debug!(table = %self.config.table, rows = batch.num_rows(), "Appended batch to DuckDB");
+        Ok(batch.num_rows())
+    }
+
+    /// Run the sink, appending every `RecordBatch` received until the channel closes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`write_batch`](Self::write_batch).
+    pub async fn run(&self, mut rx: mpsc::Receiver<RecordBatch>) -> Result<()> {
+        let mut total = 0;
+        while let Some(batch) = rx.recv().await {
+            total += self.write_batch(&batch)?;
+        }
+        info!(table = %self.config.table, rows = total, "DuckDB sink channel closed");
+        Ok(())
+    }
+
+    /// Compute an aggregate over a table of the sink's database.
+    ///
+    /// Builds `SELECT <group_by>, <aggregates> FROM <table> [WHERE tenant] GROUP BY
+    /// <group_by> ORDER BY <group_by> [LIMIT n]`; every column reference is checked
+    /// against the table's catalog and quoted. `tenant` restricts the rows to
+    /// `column = value` (bound as a parameter). The result follows
+    /// [`aggregate_result_schema`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::SchemaNotFound`] for an unknown table,
+    /// [`ArrowFlightError::InvalidTicket`] for an unknown column, a missing aggregate
+    /// column, or no aggregates, and [`ArrowFlightError::External`] if `DuckDB` fails.
+    pub fn aggregate(
+        &self,
+        table: &str,
+        group_by: &[String],
+        aggregates: &[AggregateSpec],
+        limit: Option<usize>,
+        tenant: Option<(&str, &str)>,
+    ) -> Result<RecordBatch> {
+        if aggregates.is_empty() {
+            return Err(ArrowFlightError::InvalidTicket(
+                "Aggregate ticket needs at least one aggregate".to_string(),
+            ));
+        }
+
+        let connection = self.connection()?;
+        let columns = Self::table_columns(&connection, table)?;
+        if columns.is_empty() {
+            return Err(ArrowFlightError::SchemaNotFound(format!("DuckDB table '{table}'")));
+        }
+        let known = |column: &str| -> Result<String> {
+            if columns.iter().any(|c| c == column) {
+                Ok(quote_identifier(column))
+            } else {
+                Err(ArrowFlightError::InvalidTicket(format!(
+                    "Unknown column '{column}' in DuckDB table '{table}'"
+                )))
+            }
+        };
+
+        let mut select = Vec::with_capacity(group_by.len() + aggregates.len());
+        let mut groups = Vec::with_capacity(group_by.len());
+        for column in group_by {
+            let quoted = known(column)?;
+            select.push(format!("CAST({quoted} AS VARCHAR) AS {quoted}"));
+            groups.push(quoted);
+        }
+        for spec in aggregates {
+            let column = spec.column.as_deref().map(known).transpose()?;
+            let alias = quote_identifier(&spec.alias);
+            let expression = match (spec.function, column) {
+                (AggregateFunction::Count, None) => "COUNT(*)".to_string(),
+                (AggregateFunction::Count, Some(column)) => format!("COUNT({column})"),
+                (function, Some(column)) => {
+                    let name = match function {
+                        AggregateFunction::Sum => "SUM",
+                        AggregateFunction::Avg => "AVG",
+                        AggregateFunction::Min => "MIN",
+                        _ => "MAX",
+                    };
+                    format!("CAST({name}({column}) AS DOUBLE)")
+                },
+                (function, None) => {
+                    return Err(ArrowFlightError::InvalidTicket(format!(
+                        "Aggregate '{}' ({function:?}) needs a column",
+                        spec.alias
+                    )));
+                },
+            };
+            select.push(format!("{expression} AS {alias}"));
+        }
+
+        let mut sql = format!("SELECT {} FROM {}", select.join(", "), quote_identifier(table));
+        let mut params = Vec::new();
+        if let Some((column, value)) = tenant {
+            sql.push_str(&format!(" WHERE {} = ?", known(column)?));
+            params.push(value.to_string());
+        }
+        if !groups.is_empty() {
+            sql.push_str(&format!(" GROUP BY {0} ORDER BY {0}", groups.join(", ")));
+        }
+        if let Some(limit) = limit {
+            sql.push_str(&format!(" LIMIT {limit}"));
+        }
+        debug!(sql = %sql, "Executing DuckDB aggregate");
+
+        let schema = aggregate_result_schema(group_by, aggregates);
+        let mut group_builders: Vec<StringBuilder> =
+            group_by.iter().map(|_| StringBuilder::new()).collect();
+        let mut value_builders: Vec<AggregateBuilder> = aggregates
+            .iter()
+            .map(|spec| match spec.function {
+                AggregateFunction::Count => AggregateBuilder::Count(Int64Builder::new()),
+                _ => AggregateBuilder::Value(Float64Builder::new()),
+            })
+            .collect();
+
+        let mut stmt = connection.prepare(&sql).map_err(|e| duckdb_error("aggregate", &e))?;
+        let mut rows = stmt
+            .query(params_from_iter(params))
+            .map_err(|e| duckdb_error("aggregate", &e))?;
+        while let Some(row) = rows.next().map_err(|e| duckdb_error("aggregate", &e))? {
+            for (index, builder) in group_builders.iter_mut().enumerate() {
+                let value: Option<String> =
+                    row.get(index).map_err(|e| duckdb_error("aggregate", &e))?;
+                builder.append_option(value);
+            }
+            for (offset, builder) in value_builders.iter_mut().enumerate() {
+                let index = group_by.len() + offset;
+                match builder {
+                    AggregateBuilder::Count(builder) => builder.append_value(
+                        row.get::<_, i64>(index).map_err(|e| duckdb_error("aggregate", &e))?,
+                    ),
+                    AggregateBuilder::Value(builder) => builder.append_option(
+                        row.get::<_, Option<f64>>(index)
+                            .map_err(|e| duckdb_error("aggregate", &e))?,
+                    ),
+                }
+            }
+        }
+
+        let arrays: Vec<ArrayRef> = group_builders
+            .iter_mut()
+            .map(|builder| Arc::new(builder.finish()) as ArrayRef)
+            .chain(value_builders.iter_mut().map(AggregateBuilder::finish))
+            .collect();
+
+        Ok(RecordBatch::try_new(schema, arrays)?)
+    }
+}
+
+#[cfg(test)]
+mod tests;
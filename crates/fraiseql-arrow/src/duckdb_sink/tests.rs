@@ -0,0 +1,164 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use arrow::{
+    array::{Float64Array, Int64Array, StringArray},
+    datatypes::Field,
+};
+
+use super::*;
+
+fn memory_sink() -> DuckDbSink {
+    DuckDbSink::new(DuckDbSinkConfig {
+        path:  ":memory:".to_string(),
+        table: "events".to_string(),
+    })
+    .unwrap()
+}
+
+fn orders(tenants: &[&str], statuses: &[&str], totals: &[f64]) -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("tenant_id", DataType::Utf8, false),
+        Field::new("status", DataType::Utf8, false),
+        Field::new("total", DataType::Float64, true),
+    ]));
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(StringArray::from(tenants.to_vec())),
+            Arc::new(StringArray::from(statuses.to_vec())),
+            Arc::new(Float64Array::from(totals.to_vec())),
+        ],
+    )
+    .unwrap()
+}
+
+fn spec(function: AggregateFunction, column: Option<&str>, alias: &str) -> AggregateSpec {
+    AggregateSpec {
+        function,
+        column: column.map(str::to_string),
+        alias: alias.to_string(),
+    }
+}
+
+#[test]
+fn test_config_rejects_invalid_table() {
+    let config = DuckDbSinkConfig {
+        path:  ":memory:".to_string(),
+        table: "events; DROP TABLE x".to_string(),
+    };
+    assert!(config.validate().is_err());
+    assert!(DuckDbSink::new(config).is_err());
+}
+
+#[test]
+fn test_write_batch_creates_and_appends() {
+    let sink = memory_sink();
+    let batch = orders(&["a", "b"], &["paid", "open"], &[10.0, 5.0]);
+
+    assert_eq!(sink.write_batch(&batch).unwrap(), 2);
+    assert_eq!(sink.write_batch(&batch).unwrap(), 2);
+    assert_eq!(sink.columns("events").unwrap(), vec!["tenant_id", "status", "total"]);
+
+    let result = sink
+        .aggregate("events", &[], &[spec(AggregateFunction::Count, None, "n")], None, None)
+        .unwrap();
+    let counts = result.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(counts.value(0), 4);
+}
+
+#[test]
+fn test_write_batch_rejects_mismatched_schema() {
+    let sink = memory_sink();
+    sink.write_batch(&orders(&["a"], &["paid"], &[1.0])).unwrap();
+
+    let other = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![Field::new("other", DataType::Int64, false)])),
+        vec![Arc::new(Int64Array::from(vec![1]))],
+    )
+    .unwrap();
+    assert!(sink.write_batch(&other).is_err());
+}
+
+#[test]
+fn test_aggregate_group_by() {
+    let sink = memory_sink();
+    sink.write_batch(&orders(
+        &["a", "a", "a", "b"],
+        &["paid", "paid", "open", "paid"],
+        &[10.0, 20.0, 5.0, 100.0],
+    ))
+    .unwrap();
+
+    let result = sink
+        .aggregate(
+            "events",
+            &["status".to_string()],
+            &[
+                spec(AggregateFunction::Count, None, "orders"),
+                spec(AggregateFunction::Sum, Some("total"), "revenue"),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(
+        result.schema(),
+        aggregate_result_schema(
+            &["status".to_string()],
+            &[
+                spec(AggregateFunction::Count, None, "orders"),
+                spec(AggregateFunction::Sum, Some("total"), "revenue"),
+            ],
+        )
+    );
+    let statuses = result.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+    let counts = result.column(1).as_any().downcast_ref::<Int64Array>().unwrap();
+    let sums = result.column(2).as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!((statuses.value(0), counts.value(0), sums.value(0)), ("open", 1, 5.0));
+    assert_eq!((statuses.value(1), counts.value(1), sums.value(1)), ("paid", 3, 130.0));
+}
+
+#[test]
+fn test_aggregate_tenant_filter_and_limit() {
+    let sink = memory_sink();
+    sink.write_batch(&orders(&["a", "a", "b"], &["paid", "open", "paid"], &[1.0, 2.0, 3.0]))
+        .unwrap();
+
+    let result = sink
+        .aggregate(
+            "events",
+            &["status".to_string()],
+            &[spec(AggregateFunction::Max, Some("total"), "largest")],
+            Some(1),
+            Some(("tenant_id", "b")),
+        )
+        .unwrap();
+
+    assert_eq!(result.num_rows(), 1);
+    let largest = result.column(1).as_any().downcast_ref::<Float64Array>().unwrap();
+    assert!((largest.value(0) - 3.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_aggregate_rejects_unknown_columns_and_tables() {
+    let sink = memory_sink();
+    sink.write_batch(&orders(&["a"], &["paid"], &[1.0])).unwrap();
+    let count = [spec(AggregateFunction::Count, None, "n")];
+
+    assert!(matches!(
+        sink.aggregate("missing", &[], &count, None, None),
+        Err(ArrowFlightError::SchemaNotFound(_))
+    ));
+    assert!(matches!(
+        sink.aggregate("events", &["status\" --".to_string()], &count, None, None),
+        Err(ArrowFlightError::InvalidTicket(_))
+    ));
+    assert!(matches!(
+        sink.aggregate("events", &[], &[spec(AggregateFunction::Sum, None, "s")], None, None),
+        Err(ArrowFlightError::InvalidTicket(_))
+    ));
+    assert!(matches!(
+        sink.aggregate("events", &[], &[], None, None),
+        Err(ArrowFlightError::InvalidTicket(_))
+    ));
+}
@@ -9,6 +9,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::export::incremental::ExportWatermark;
+
 /// An observer event record for Arrow Flight querying.
 ///
 /// This is a simplified representation suitable for analytics queries.
@@ -75,6 +77,61 @@ pub trait ArrowEventStorage: Send + Sync {
         limit: Option<usize>,
     ) -> Result<Vec<HistoricalEvent>, String>;
 
+    /// Query the events of one `EntityHash` partition: those whose
+    /// [`entity_bucket`](crate::ticket::entity_bucket)`(entity_id, buckets)` is `bucket`.
+    ///
+    /// The default implementation queries the whole range and filters in memory, then
+    /// applies `limit`. Backends should override it to push the bucket predicate
+    /// (`entity_id` as a 128-bit integer modulo `buckets`) into the query, so each
+    /// parallel partition only reads its own rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if database query fails or events cannot be deserialized.
+    async fn query_events_in_bucket(
+        &self,
+        entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+        limit: Option<usize>,
+        bucket: u32,
+        buckets: u32,
+    ) -> Result<Vec<HistoricalEvent>, String> {
+        let mut events = self.query_events(entity_type, start_date, end_date, None).await?;
+        events.retain(|event| crate::ticket::entity_bucket(&event.entity_id, buckets) == bucket);
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
+        Ok(events)
+    }
+
+    /// Query the events after an incremental export's watermark, oldest first.
+    ///
+    /// Returns at most `limit` events ordered by `(timestamp, id)` ascending, all of
+    /// which come after `after` in that order (every event when `after` is `None`).
+    /// The default implementation queries from the watermark's timestamp and orders
+    /// and filters in memory. Backends should override it with a keyset query
+    /// (`WHERE (timestamp, id) > ($1, $2) ORDER BY timestamp, id LIMIT $3`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if database query fails or events cannot be deserialized.
+    async fn query_events_after(
+        &self,
+        entity_type: &str,
+        after: Option<&ExportWatermark>,
+        limit: Option<usize>,
+    ) -> Result<Vec<HistoricalEvent>, String> {
+        let start_date = after.map(|watermark| watermark.last_timestamp);
+        let mut events = self.query_events(entity_type, start_date, None, None).await?;
+        events.retain(|event| after.is_none_or(|watermark| watermark.precedes(event)));
+        events.sort_by(|a, b| (a.timestamp, a.id).cmp(&(b.timestamp, b.id)));
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
+        Ok(events)
+    }
+
     /// Count events matching the filter criteria.
     ///
     /// Useful for pagination and understanding result sizes.
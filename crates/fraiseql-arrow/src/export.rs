@@ -1,6 +1,14 @@
 //! Bulk export functionality for multiple data formats.
 //!
-//! Supports exporting Arrow `RecordBatches` to Parquet, CSV, and JSON formats.
+//! Supports exporting Arrow `RecordBatches` to Parquet, CSV, and JSON formats,
+//! incremental (CDC) exports of observer events (see [`incremental`]),
+//! memory-bounded buffering of large exports (see [`spill`]), and appends to Delta
+//! Lake tables in object storage (`delta`, with the `parquet` feature).
+
+#[cfg(feature = "parquet")]
+pub mod delta;
+pub mod incremental;
+pub mod spill;
 
 use std::str::FromStr;
 
@@ -19,6 +27,13 @@ pub enum ExportFormat {
     Csv,
     /// JSON Lines (one JSON object per line)
     Json,
+    /// Delta Lake table: Parquet data files plus a transaction log entry, written
+    /// to object storage with [`BulkExporter::export_delta`] rather than returned
+    /// as bytes.
+    ///
+    /// Available only when the `parquet` feature is enabled.
+    #[cfg(feature = "parquet")]
+    Delta,
 }
 
 impl FromStr for ExportFormat {
@@ -36,6 +51,10 @@ impl FromStr for ExportFormat {
             },
             "csv" => Ok(Self::Csv),
             "json" => Ok(Self::Json),
+            #[cfg(feature = "parquet")]
+            "delta" => Ok(Self::Delta),
+            #[cfg(not(feature = "parquet"))]
+            "delta" => Err("Delta export requires the `parquet` Cargo feature".into()),
             _ => Err(format!("Unsupported export format: {}", s)),
         }
     }
@@ -57,12 +76,12 @@ impl ExportFormat {
         <Self as FromStr>::from_str(s)
     }
 
-    /// Get file extension for this format.
+    /// Get file extension for this format (of the data files, for Delta).
     #[must_use]
     pub const fn extension(&self) -> &'static str {
         match self {
             #[cfg(feature = "parquet")]
-            Self::Parquet => "parquet",
+            Self::Parquet | Self::Delta => "parquet",
             Self::Csv => "csv",
             Self::Json => "jsonl",
         }
@@ -76,6 +95,9 @@ impl ExportFormat {
             Self::Parquet => "application/octet-stream",
             Self::Csv => "text/csv",
             Self::Json => "application/x-ndjson",
+            // The commit summary returned for a Delta export
+            #[cfg(feature = "parquet")]
+            Self::Delta => "application/json",
         }
     }
 }
@@ -97,16 +119,42 @@ impl BulkExporter {
     ///
     /// # Errors
     ///
-    /// Returns error if export fails (e.g., Parquet encoding error)
+    /// Returns error if export fails (e.g., Parquet encoding error), or for
+    /// [`ExportFormat::Delta`], which writes a table instead (see
+    /// [`export_delta`](Self::export_delta)).
     pub fn export_batch(batch: &RecordBatch, format: ExportFormat) -> Result<Vec<u8>, String> {
         match format {
             #[cfg(feature = "parquet")]
             ExportFormat::Parquet => Self::export_parquet(batch),
             ExportFormat::Csv => Self::export_csv(batch),
             ExportFormat::Json => Self::export_json(batch),
+            #[cfg(feature = "parquet")]
+            ExportFormat::Delta => {
+                Err("Delta exports are written to a table with BulkExporter::export_delta"
+                    .to_string())
+            },
         }
     }
 
+    /// Append `batches` to the Delta Lake table of `writer` in one commit,
+    /// creating the table on first export.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if there are no rows, the batches do not match the table, or
+    /// the object store fails (see [`delta::DeltaTableWriter::append`]).
+    #[cfg(feature = "parquet")]
+    pub async fn export_delta<I>(
+        batches: I,
+        writer: &delta::DeltaTableWriter,
+    ) -> Result<delta::DeltaCommit, String>
+    where
+        I: IntoIterator<Item = Result<RecordBatch, arrow::error::ArrowError>>,
+        I::IntoIter: Send,
+    {
+        writer.append(batches).await
+    }
+
     /// Export `RecordBatch` to Parquet format.
     ///
     /// Parquet provides efficient columnar storage with compression.
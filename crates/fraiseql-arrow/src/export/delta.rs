@@ -0,0 +1,602 @@
+//! Delta Lake table writer for bulk exports.
+//!
+//! [`DeltaTableWriter`] appends exported batches to a Delta Lake table in a
+//! [`LakehouseStore`]: rows are written as Parquet data files, then committed in a
+//! single transaction log entry (`_delta_log/<version>.json`), so readers (Spark,
+//! Trino, DuckDB, ...) see either all of an export or none of it.
+//!
+//! A missing table is created by the first export, with the Delta schema derived
+//! from the Arrow schema. Later exports must match the table's schema; schema
+//! evolution is not performed. Commits are made with a put-if-absent write of the
+//! next log entry: when a concurrent writer takes the version first, the log is
+//! re-read and the commit retried at the next version. Tables whose protocol
+//! requires writer features beyond version 2, or whose metadata only survives in
+//! a checkpoint, are refused.
+
+use std::{path::PathBuf, sync::Arc};
+
+use arrow::{
+    array::{ArrayRef, RecordBatch},
+    compute::cast,
+    datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit},
+    error::ArrowError,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+/// Default target size of a data file (128 MiB).
+pub const DEFAULT_TARGET_FILE_BYTES: usize = 128 * 1024 * 1024;
+
+/// Writer protocol version of the tables this writer creates, and the highest it
+/// appends to.
+const WRITER_VERSION: u64 = 2;
+
+/// Commit attempts before giving up on a contended table.
+const MAX_COMMIT_ATTEMPTS: usize = 10;
+
+/// Object storage holding lakehouse tables.
+///
+/// Keys are `/`-separated paths relative to the store's root (e.g.,
+/// `exports/users/_delta_log/00000000000000000000.json`).
+// Reason: used as dyn Trait (Arc<dyn LakehouseStore>); async_trait ensures Send bounds and
+// dyn-compatibility async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+pub trait LakehouseStore: Send + Sync {
+    /// Write `data` at `key`, replacing any existing object.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the object cannot be written.
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), String>;
+
+    /// Atomically write `data` at `key` unless an object already exists there.
+    ///
+    /// Returns `false`, writing nothing, if `key` exists. Transaction log commits
+    /// rely on this being atomic (e.g., S3 `If-None-Match: *`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the object cannot be written.
+    async fn put_if_absent(&self, key: &str, data: Vec<u8>) -> Result<bool, String>;
+
+    /// Read the object at `key`, or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the object cannot be read.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String>;
+
+    /// Names of the objects directly under the `dir` prefix (e.g., the file names
+    /// of `<table>/_delta_log`); empty if there are none.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the store cannot be listed.
+    async fn list(&self, dir: &str) -> Result<Vec<String>, String>;
+}
+
+/// Lakehouse store on a local (or mounted) filesystem.
+#[derive(Debug, Clone)]
+pub struct LocalLakehouseStore {
+    root: PathBuf,
+}
+
+impl LocalLakehouseStore {
+    /// Store objects below `root`.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Filesystem path of `key`; keys cannot escape the root.
+    fn path(&self, key: &str) -> Result<PathBuf, String> {
+        if key.split('/').any(|part| part == ".." || part == ".") || key.starts_with('/') {
+            return Err(format!("Invalid lakehouse key: {key}"));
+        }
+        Ok(self.root.join(key))
+    }
+
+    async fn create_parent(path: &std::path::Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+        }
+        Ok(())
+    }
+}
+
+// Reason: LakehouseStore is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl LakehouseStore for LocalLakehouseStore {
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), String> {
+        let path = self.path(key)?;
+        Self::create_parent(&path).await?;
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| format!("Failed to write {}: {e}", path.display()))
+    }
+
+    async fn put_if_absent(&self, key: &str, data: Vec<u8>) -> Result<bool, String> {
+        let path = self.path(key)?;
+        Self::create_parent(&path).await?;
+        // Write a temporary file, then hard-link it into place: linking fails if the
+        // key exists, and readers never see a partially written object.
+        let staged = path.with_extension(format!("tmp-{}", Uuid::new_v4()));
+        tokio::fs::write(&staged, data)
+            .await
+            .map_err(|e| format!("Failed to write {}: {e}", staged.display()))?;
+        let linked = tokio::fs::hard_link(&staged, &path).await;
+        // Best effort: a leftover staging file is ignored by readers.
+        let _ = tokio::fs::remove_file(&staged).await;
+        match linked {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(format!("Failed to write {}: {e}", path.display())),
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let path = self.path(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("Failed to read {}: {e}", path.display())),
+        }
+    }
+
+    async fn list(&self, dir: &str) -> Result<Vec<String>, String> {
+        let path = self.path(dir)?;
+        let mut entries = match tokio::fs::read_dir(&path).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(format!("Failed to list {}: {e}", path.display())),
+        };
+        let mut names = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| format!("Failed to list {}: {e}", path.display()))?
+        {
+            if let Some(name) = entry.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+        Ok(names)
+    }
+}
+
+/// Outcome of a committed Delta export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeltaCommit {
+    /// Table the rows were appended to
+    pub table_path:  String,
+    /// Table version created by the commit
+    pub version:     u64,
+    /// Data files added, relative to the table
+    pub files:       Vec<String>,
+    /// Rows appended
+    pub num_records: u64,
+    /// Total size of the added data files in bytes
+    pub size_bytes:  u64,
+}
+
+/// Delta type of `data_type` and the Arrow type its values are written as.
+///
+/// Delta has no unsigned integers, so they are widened; every timestamp is stored
+/// as UTC microseconds, Delta's `timestamp` precision.
+///
+/// # Errors
+///
+/// Returns error for Arrow types without a Delta counterpart.
+fn delta_type(name: &str, data_type: &DataType) -> Result<(String, DataType), String> {
+    let (delta, arrow) = match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => ("string", DataType::Utf8),
+        DataType::Boolean => ("boolean", DataType::Boolean),
+        DataType::Int8 => ("byte", DataType::Int8),
+        DataType::Int16 | DataType::UInt8 => ("short", DataType::Int16),
+        DataType::Int32 | DataType::UInt16 => ("integer", DataType::Int32),
+        DataType::Int64 | DataType::UInt32 => ("long", DataType::Int64),
+        DataType::Float32 => ("float", DataType::Float32),
+        DataType::Float64 => ("double", DataType::Float64),
+        DataType::Date32 => ("date", DataType::Date32),
+        DataType::Timestamp(..) => {
+            ("timestamp", DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("UTC"))))
+        },
+        DataType::Binary | DataType::LargeBinary => ("binary", DataType::Binary),
+        DataType::Decimal128(precision, scale) => {
+            return Ok((format!("decimal({precision},{scale})"), data_type.clone()));
+        },
+        other => {
+            return Err(format!(
+                "column '{name}' has Arrow type {other}, which Delta tables cannot store"
+            ));
+        },
+    };
+    Ok((delta.to_string(), arrow))
+}
+
+/// Delta schema of an export, and the Arrow schema its data files are written with.
+#[derive(Debug, Clone)]
+struct DeltaSchema {
+    /// `schemaString` of the table metadata
+    fields: Vec<Value>,
+    /// Arrow schema of the data files
+    arrow:  SchemaRef,
+}
+
+impl DeltaSchema {
+    fn new(schema: &Schema) -> Result<Self, String> {
+        let mut fields = Vec::with_capacity(schema.fields().len());
+        let mut arrow_fields = Vec::with_capacity(schema.fields().len());
+        for field in schema.fields() {
+            let (delta, arrow) = delta_type(field.name(), field.data_type())?;
+            fields.push(json!({
+                "name": field.name(),
+                "type": delta,
+                "nullable": field.is_nullable(),
+                "metadata": {},
+            }));
+            arrow_fields.push(Field::new(field.name(), arrow, field.is_nullable()));
+        }
+        Ok(Self {
+            fields,
+            arrow: Arc::new(Schema::new(arrow_fields)),
+        })
+    }
+
+    fn schema_string(&self) -> String {
+        json!({"type": "struct", "fields": self.fields}).to_string()
+    }
+
+    /// Check that rows of this schema can be appended to a table of `table_schema`
+    /// (its `schemaString`).
+    ///
+    /// Every column must exist in the table with the same type, and may only be
+    /// nullable if the table column is. Table columns the export lacks must be
+    /// nullable; they read as null in the appended files.
+    fn check_appendable(&self, table_schema: &str) -> Result<(), String> {
+        let table: Value = serde_json::from_str(table_schema)
+            .map_err(|e| format!("Invalid Delta table schema: {e}"))?;
+        let table_fields = table["fields"].as_array().cloned().unwrap_or_default();
+        let find =
+            |fields: &[Value], name: &Value| fields.iter().find(|f| &f["name"] == name).cloned();
+
+        let mut problems = Vec::new();
+        for field in &self.fields {
+            match find(&table_fields, &field["name"]) {
+                None => problems.push(format!("{} is not a table column", field["name"])),
+                Some(column) if column["type"] != field["type"] => problems.push(format!(
+                    "{} is {} in the table but {} in the export",
+                    field["name"], column["type"], field["type"]
+                )),
+                Some(column) if column["nullable"] == false && field["nullable"] == true => {
+                    problems.push(format!("{} is not nullable in the table", field["name"]));
+                },
+                Some(_) => {},
+            }
+        }
+        for column in &table_fields {
+            if column["nullable"] == false && find(&self.fields, &column["name"]).is_none() {
+                problems.push(format!("required column {} is missing", column["name"]));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Export does not match the Delta table schema: {}", problems.join("; ")))
+        }
+    }
+
+    /// `batch` cast to the data file schema.
+    fn normalize(&self, batch: &RecordBatch) -> Result<RecordBatch, String> {
+        if batch.schema().fields().len() != self.arrow.fields().len() {
+            return Err("Exported batches must share a schema".to_string());
+        }
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(self.arrow.fields())
+            .map(|(column, field)| cast(column, field.data_type()))
+            .collect::<Result<Vec<ArrayRef>, ArrowError>>()
+            .map_err(|e| format!("Failed to convert batch for Delta export: {e}"))?;
+        RecordBatch::try_new(self.arrow.clone(), columns)
+            .map_err(|e| format!("Exported batches must share a schema: {e}"))
+    }
+}
+
+/// State of an existing table, read from its transaction log.
+#[derive(Debug, Default)]
+struct TableSnapshot {
+    /// Latest committed version; `None` if the table does not exist
+    version:       Option<u64>,
+    /// `schemaString` of the latest metadata
+    schema_string: Option<String>,
+}
+
+/// A data file written and awaiting commit.
+struct AddedFile {
+    path:        String,
+    size:        u64,
+    num_records: u64,
+}
+
+/// A data file being written.
+struct OpenDataFile {
+    name:        String,
+    writer:      ArrowWriter<Vec<u8>>,
+    num_records: u64,
+}
+
+impl OpenDataFile {
+    /// Start data file number `index` of an export.
+    fn create(index: usize, schema: &DeltaSchema) -> Result<Self, String> {
+        let properties = WriterProperties::builder().set_compression(Compression::SNAPPY).build();
+        let writer = ArrowWriter::try_new(Vec::new(), schema.arrow.clone(), Some(properties))
+            .map_err(|e| format!("Failed to create Parquet writer: {e}"))?;
+        Ok(Self {
+            name: format!("part-{index:05}-{}-c000.snappy.parquet", Uuid::new_v4()),
+            writer,
+            num_records: 0,
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<(), String> {
+        self.writer
+            .write(batch)
+            .map_err(|e| format!("Failed to write Parquet data: {e}"))?;
+        self.num_records += batch.num_rows() as u64;
+        Ok(())
+    }
+
+    /// Bytes written so far plus those buffered for the current row group.
+    fn size(&self) -> usize {
+        self.writer.bytes_written() + self.writer.in_progress_size()
+    }
+}
+
+/// Version of a transaction log entry named `name` (`<20 digits>.json`).
+fn commit_version(name: &str) -> Option<u64> {
+    let stem = name.strip_suffix(".json")?;
+    if stem.len() != 20 || !stem.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    stem.parse().ok()
+}
+
+/// Appends exported batches to a Delta Lake table.
+pub struct DeltaTableWriter {
+    store:             Arc<dyn LakehouseStore>,
+    table_path:        String,
+    target_file_bytes: usize,
+}
+
+impl DeltaTableWriter {
+    /// Write the table at `table_path` (relative to the store root).
+    #[must_use]
+    pub fn new(store: Arc<dyn LakehouseStore>, table_path: impl Into<String>) -> Self {
+        Self {
+            store,
+            table_path: table_path.into().trim_matches('/').to_string(),
+            target_file_bytes: DEFAULT_TARGET_FILE_BYTES,
+        }
+    }
+
+    /// Start a new data file once the current one reaches `bytes` (default:
+    /// [`DEFAULT_TARGET_FILE_BYTES`]).
+    #[must_use]
+    pub const fn with_target_file_bytes(mut self, bytes: usize) -> Self {
+        self.target_file_bytes = bytes;
+        self
+    }
+
+    /// Path of the table relative to the store root.
+    #[must_use]
+    pub fn table_path(&self) -> &str {
+        &self.table_path
+    }
+
+    fn log_key(&self, version: u64) -> String {
+        format!("{}/_delta_log/{version:020}.json", self.table_path)
+    }
+
+    /// Append `batches` to the table in one commit, creating the table if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if there are no rows, a batch cannot be read or converted,
+    /// the batches do not match the table's schema or protocol, the store fails,
+    /// or the commit stays contended after repeated attempts.
+    pub async fn append<I>(&self, batches: I) -> Result<DeltaCommit, String>
+    where
+        I: IntoIterator<Item = Result<RecordBatch, ArrowError>>,
+        I::IntoIter: Send,
+    {
+        let mut snapshot = self.snapshot().await?;
+        let mut schema: Option<DeltaSchema> = None;
+        let mut files = Vec::new();
+        let mut open: Option<OpenDataFile> = None;
+
+        for batch in batches {
+            let batch = batch.map_err(|e| format!("Failed to read export batch: {e}"))?;
+            let delta_schema = match schema.as_ref() {
+                Some(delta_schema) => delta_schema,
+                None => {
+                    let delta_schema = DeltaSchema::new(&batch.schema())?;
+                    if let Some(table_schema) = &snapshot.schema_string {
+                        delta_schema.check_appendable(table_schema)?;
+                    }
+                    &*schema.insert(delta_schema)
+                },
+            };
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let batch = delta_schema.normalize(&batch)?;
+
+            let file = match open.as_mut() {
+                Some(file) => file,
+                None => open.insert(OpenDataFile::create(files.len(), delta_schema)?),
+            };
+            file.write(&batch)?;
+            if file.size() >= self.target_file_bytes {
+                if let Some(file) = open.take() {
+                    files.push(self.put_data_file(file).await?);
+                }
+            }
+        }
+        if let Some(file) = open.take() {
+            files.push(self.put_data_file(file).await?);
+        }
+
+        let Some(schema) = schema.filter(|_| !files.is_empty()) else {
+            return Err("No rows to export".to_string());
+        };
+
+        for _ in 0..MAX_COMMIT_ATTEMPTS {
+            let version = snapshot.version.map_or(0, |latest| latest + 1);
+            let entry = self.commit_entry(snapshot.version.is_none(), &schema, &files);
+            if self.store.put_if_absent(&self.log_key(version), entry.into_bytes()).await? {
+                return Ok(DeltaCommit {
+                    table_path: self.table_path.clone(),
+                    version,
+                    files: files.iter().map(|f| f.path.clone()).collect(),
+                    num_records: files.iter().map(|f| f.num_records).sum(),
+                    size_bytes: files.iter().map(|f| f.size).sum(),
+                });
+            }
+            // Another writer committed this version: append on top of it instead.
+            snapshot = self.snapshot().await?;
+            if let Some(table_schema) = &snapshot.schema_string {
+                schema.check_appendable(table_schema)?;
+            }
+        }
+        Err(format!(
+            "Delta table {} stayed contended after {MAX_COMMIT_ATTEMPTS} commit attempts",
+            self.table_path
+        ))
+    }
+
+    /// Close `file` and store it as a data file of the table.
+    async fn put_data_file(&self, file: OpenDataFile) -> Result<AddedFile, String> {
+        let data = file
+            .writer
+            .into_inner()
+            .map_err(|e| format!("Failed to close Parquet writer: {e}"))?;
+        let size = data.len() as u64;
+        self.store.put(&format!("{}/{}", self.table_path, file.name), data).await?;
+        Ok(AddedFile {
+            path: file.name,
+            size,
+            num_records: file.num_records,
+        })
+    }
+
+    /// Newline-delimited actions of the commit adding `files`; a new table also
+    /// gets its protocol and metadata.
+    fn commit_entry(&self, new_table: bool, schema: &DeltaSchema, files: &[AddedFile]) -> String {
+        let now = Utc::now().timestamp_millis();
+        let mut actions = vec![json!({"commitInfo": {
+            "timestamp": now,
+            "operation": "WRITE",
+            "operationParameters": {"mode": "Append"},
+            "isBlindAppend": true,
+            "engineInfo": concat!("fraiseql-arrow/", env!("CARGO_PKG_VERSION")),
+        }})];
+        if new_table {
+            actions.push(
+                json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": WRITER_VERSION}}),
+            );
+            actions.push(json!({"metaData": {
+                "id": Uuid::new_v4().to_string(),
+                "format": {"provider": "parquet", "options": {}},
+                "schemaString": schema.schema_string(),
+                "partitionColumns": [],
+                "configuration": {},
+                "createdTime": now,
+            }}));
+        }
+        for file in files {
+            actions.push(json!({"add": {
+                "path": file.path,
+                "partitionValues": {},
+                "size": file.size,
+                "modificationTime": now,
+                "dataChange": true,
+                "stats": json!({"numRecords": file.num_records}).to_string(),
+            }}));
+        }
+        let mut entry = actions.iter().map(Value::to_string).collect::<Vec<_>>().join("\n");
+        entry.push('\n');
+        entry
+    }
+
+    /// Read the latest version, protocol and metadata of the table.
+    async fn snapshot(&self) -> Result<TableSnapshot, String> {
+        let log_dir = format!("{}/_delta_log", self.table_path);
+        let listing = self.store.list(&log_dir).await?;
+        let mut versions: Vec<u64> =
+            listing.iter().filter_map(|name| commit_version(name)).collect();
+        versions.sort_unstable();
+        let Some(&latest) = versions.last() else {
+            return Ok(TableSnapshot::default());
+        };
+
+        let mut snapshot = TableSnapshot {
+            version:       Some(latest),
+            schema_string: None,
+        };
+        let mut protocol_seen = false;
+        // The newest protocol and metadata actions are in effect.
+        for &version in versions.iter().rev() {
+            let Some(entry) = self.store.get(&self.log_key(version)).await? else {
+                continue;
+            };
+            for line in String::from_utf8_lossy(&entry).lines().filter(|l| !l.trim().is_empty()) {
+                let action: Value = serde_json::from_str(line)
+                    .map_err(|e| format!("Invalid Delta log entry {version}: {e}"))?;
+                if let Some(protocol) = action.get("protocol").filter(|_| !protocol_seen) {
+                    protocol_seen = true;
+                    let writer_version = protocol["minWriterVersion"].as_u64().unwrap_or(0);
+                    if writer_version > WRITER_VERSION {
+                        return Err(format!(
+                            "Delta table {} requires writer version {writer_version}; only \
+                             version {WRITER_VERSION} tables can be appended to",
+                            self.table_path
+                        ));
+                    }
+                }
+                if let Some(metadata) = action.get("metaData") {
+                    if snapshot.schema_string.is_none() {
+                        snapshot.schema_string =
+                            metadata["schemaString"].as_str().map(str::to_string);
+                        if !metadata["partitionColumns"].as_array().is_none_or(Vec::is_empty) {
+                            return Err(format!(
+                                "Delta table {} is partitioned; only unpartitioned tables can \
+                                 be appended to",
+                                self.table_path
+                            ));
+                        }
+                    }
+                }
+            }
+            if protocol_seen && snapshot.schema_string.is_some() {
+                return Ok(snapshot);
+            }
+        }
+        Err(format!(
+            "Delta table {} has no metadata in its JSON log (checkpointed tables are not \
+             supported)",
+            self.table_path
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
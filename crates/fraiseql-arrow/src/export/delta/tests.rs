@@ -0,0 +1,210 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use arrow::array::{Int64Array, StringArray, TimestampMicrosecondArray, UInt32Array};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+use super::*;
+
+fn users_batch(ids: &[i64]) -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+        Field::new("logins", DataType::UInt32, false),
+        Field::new("created_at", DataType::Timestamp(TimeUnit::Microsecond, None), false),
+    ]));
+    let names: Vec<String> = ids.iter().map(|id| format!("user-{id}")).collect();
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(ids.to_vec())),
+            Arc::new(StringArray::from(names)),
+            Arc::new(UInt32Array::from(vec![1; ids.len()])),
+            Arc::new(TimestampMicrosecondArray::from(vec![1_700_000_000_000_000; ids.len()])),
+        ],
+    )
+    .unwrap()
+}
+
+fn log_actions(root: &std::path::Path, table: &str, version: u64) -> Vec<Value> {
+    let entry =
+        std::fs::read_to_string(root.join(format!("{table}/_delta_log/{version:020}.json")))
+            .unwrap();
+    entry.lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+}
+
+#[tokio::test]
+async fn test_first_export_creates_table() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(LocalLakehouseStore::new(dir.path()));
+    let writer = DeltaTableWriter::new(store, "exports/users/");
+
+    let commit = writer
+        .append(vec![Ok(users_batch(&[1, 2])), Ok(users_batch(&[3]))])
+        .await
+        .unwrap();
+    assert_eq!(commit.table_path, "exports/users");
+    assert_eq!(commit.version, 0);
+    assert_eq!(commit.num_records, 3);
+    assert_eq!(commit.files.len(), 1);
+
+    let actions = log_actions(dir.path(), "exports/users", 0);
+    assert!(actions[0].get("commitInfo").is_some());
+    assert_eq!(actions[1]["protocol"]["minWriterVersion"], 2);
+    let schema: Value =
+        serde_json::from_str(actions[2]["metaData"]["schemaString"].as_str().unwrap()).unwrap();
+    let types: Vec<&str> = schema["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["type"].as_str().unwrap())
+        .collect();
+    assert_eq!(types, vec!["long", "string", "long", "timestamp"]);
+    assert_eq!(actions[3]["add"]["path"], commit.files[0].as_str());
+    assert_eq!(actions[3]["add"]["stats"], r#"{"numRecords":3}"#);
+
+    let file =
+        std::fs::File::open(dir.path().join("exports/users").join(&commit.files[0])).unwrap();
+    let rows: usize = ParquetRecordBatchReaderBuilder::try_new(file)
+        .unwrap()
+        .build()
+        .unwrap()
+        .map(|batch| batch.unwrap().num_rows())
+        .sum();
+    assert_eq!(rows, 3);
+}
+
+#[tokio::test]
+async fn test_second_export_appends_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(LocalLakehouseStore::new(dir.path()));
+    let writer = DeltaTableWriter::new(store, "users");
+
+    writer.append(vec![Ok(users_batch(&[1]))]).await.unwrap();
+    let commit = writer.append(vec![Ok(users_batch(&[2, 3]))]).await.unwrap();
+    assert_eq!(commit.version, 1);
+
+    let actions = log_actions(dir.path(), "users", 1);
+    assert_eq!(actions.len(), 2, "an append carries commitInfo and add only");
+    assert!(actions[1].get("add").is_some());
+}
+
+#[tokio::test]
+async fn test_data_files_roll_over_at_target_size() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(LocalLakehouseStore::new(dir.path()));
+    let writer = DeltaTableWriter::new(store, "users").with_target_file_bytes(1);
+
+    let batches: Vec<_> = (0..3).map(|i| Ok(users_batch(&[i]))).collect();
+    let commit = writer.append(batches).await.unwrap();
+    assert_eq!(commit.files.len(), 3);
+    assert_eq!(commit.num_records, 3);
+}
+
+#[tokio::test]
+async fn test_incompatible_schema_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(LocalLakehouseStore::new(dir.path()));
+    let writer = DeltaTableWriter::new(store, "users");
+    writer.append(vec![Ok(users_batch(&[1]))]).await.unwrap();
+
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)]));
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(StringArray::from(vec!["1"]))]).unwrap();
+    let err = writer.append(vec![Ok(batch)]).await.unwrap_err();
+    assert!(err.contains("\"id\" is \"long\" in the table"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn test_unsupported_writer_protocol_is_refused() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(LocalLakehouseStore::new(dir.path()));
+    store
+        .put(
+            "users/_delta_log/00000000000000000000.json",
+            br#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7}}"#.to_vec(),
+        )
+        .await
+        .unwrap();
+
+    let err = DeltaTableWriter::new(store, "users")
+        .append(vec![Ok(users_batch(&[1]))])
+        .await
+        .unwrap_err();
+    assert!(err.contains("writer version 7"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn test_empty_export_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let writer = DeltaTableWriter::new(Arc::new(LocalLakehouseStore::new(dir.path())), "users");
+    assert!(writer.append(vec![Ok(users_batch(&[]))]).await.is_err());
+    assert!(!dir.path().join("users/_delta_log").exists());
+}
+
+/// Store letting another writer commit the next version just before our first
+/// commit attempt.
+struct RacingStore {
+    inner: LocalLakehouseStore,
+    raced: AtomicBool,
+}
+
+#[async_trait]
+impl LakehouseStore for RacingStore {
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), String> {
+        self.inner.put(key, data).await
+    }
+
+    async fn put_if_absent(&self, key: &str, data: Vec<u8>) -> Result<bool, String> {
+        if !self.raced.swap(true, Ordering::SeqCst) {
+            let rival = br#"{"commitInfo":{"operation":"WRITE"}}"#.to_vec();
+            assert!(self.inner.put_if_absent(key, rival).await?);
+        }
+        self.inner.put_if_absent(key, data).await
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        self.inner.get(key).await
+    }
+
+    async fn list(&self, dir: &str) -> Result<Vec<String>, String> {
+        self.inner.list(dir).await
+    }
+}
+
+#[tokio::test]
+async fn test_lost_commit_race_retries_at_next_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let local = LocalLakehouseStore::new(dir.path());
+    DeltaTableWriter::new(Arc::new(local.clone()), "users")
+        .append(vec![Ok(users_batch(&[1]))])
+        .await
+        .unwrap();
+
+    let store = Arc::new(RacingStore {
+        inner: local,
+        raced: AtomicBool::new(false),
+    });
+    let commit = DeltaTableWriter::new(store, "users")
+        .append(vec![Ok(users_batch(&[2]))])
+        .await
+        .unwrap();
+    assert_eq!(commit.version, 2);
+    assert!(log_actions(dir.path(), "users", 2)[1].get("add").is_some());
+}
+
+#[tokio::test]
+async fn test_local_store_rejects_escaping_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = LocalLakehouseStore::new(dir.path());
+    assert!(store.put("../outside", Vec::new()).await.is_err());
+    assert!(store.get("/etc/passwd").await.is_err());
+    assert!(store.list("missing").await.unwrap().is_empty());
+}
+
+#[test]
+fn test_commit_version_parsing() {
+    assert_eq!(commit_version("00000000000000000012.json"), Some(12));
+    assert_eq!(commit_version("00000000000000000012.checkpoint.parquet"), None);
+    assert_eq!(commit_version("00000000000000000012.tmp-1234"), None);
+    assert_eq!(commit_version("_last_checkpoint"), None);
+}
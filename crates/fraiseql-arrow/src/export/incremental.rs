@@ -0,0 +1,399 @@
+//! Incremental (CDC) export of observer events with persisted watermarks.
+//!
+//! Each incremental export is identified by an export key (typically one per ticket or
+//! per warehouse table). Its watermark — the timestamp and id of the last exported
+//! event — is kept in a [`WatermarkStore`], so repeated runs only ship events recorded
+//! after it:
+//!
+//! 1. [`BulkExporter::export_incremental`] loads the watermark, reads the next events in
+//!    `(timestamp, id)` order and encodes them with the [`cdc_event_schema`].
+//! 2. The caller delivers the batch (e.g. loads it into the warehouse).
+//! 3. [`BulkExporter::commit_incremental`] persists the batch's new watermark.
+//!
+//! Committing only after delivery gives at-least-once semantics: a run that fails
+//! between export and commit re-ships the same events next time, which an idempotent
+//! warehouse merge on `event_id` absorbs.
+//!
+//! `DELETE` events are skipped unless tombstones are requested; then they are emitted
+//! with `deleted = true` so the warehouse can remove the entity.
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{ArrayRef, BooleanArray, RecordBatch},
+    error::ArrowError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{BatchStats, BulkExporter, ExportFormat};
+use crate::{
+    convert::observer_events_to_batch,
+    db::ArrowDatabaseAdapter,
+    event_storage::{ArrowEventStorage, HistoricalEvent},
+    schema::cdc_event_schema,
+};
+
+/// Default table holding incremental export watermarks.
+pub const DEFAULT_WATERMARK_TABLE: &str = "fraiseql_export_watermarks";
+
+/// Event type marking an entity deletion.
+const DELETE_EVENT_TYPE: &str = "DELETE";
+
+/// Position of the last event an incremental export shipped.
+///
+/// Events are exported in `(timestamp, id)` order, so the pair identifies the position
+/// even when several events share a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportWatermark {
+    /// Id of the last exported event
+    pub last_event_id:  Uuid,
+    /// Timestamp of the last exported event
+    pub last_timestamp: DateTime<Utc>,
+}
+
+impl ExportWatermark {
+    /// The watermark just past `event`.
+    #[must_use]
+    pub const fn after(event: &HistoricalEvent) -> Self {
+        Self {
+            last_event_id:  event.id,
+            last_timestamp: event.timestamp,
+        }
+    }
+
+    /// Whether `event` comes after this watermark in export order.
+    #[must_use]
+    pub fn precedes(&self, event: &HistoricalEvent) -> bool {
+        (event.timestamp, event.id) > (self.last_timestamp, self.last_event_id)
+    }
+}
+
+/// Persistent storage of incremental export watermarks, keyed by export key.
+// Reason: used as dyn Trait (Arc<dyn WatermarkStore>); async_trait ensures Send bounds and
+// dyn-compatibility async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+pub trait WatermarkStore: Send + Sync {
+    /// Load the watermark of `export_key`, or `None` if it has never been committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backing store cannot be read.
+    async fn load(&self, export_key: &str) -> Result<Option<ExportWatermark>, String>;
+
+    /// Persist the watermark of `export_key`, replacing any previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backing store cannot be written.
+    async fn save(&self, export_key: &str, watermark: &ExportWatermark) -> Result<(), String>;
+}
+
+/// Process-local watermark store, for tests and single-process development setups.
+#[derive(Debug, Default)]
+pub struct InMemoryWatermarkStore {
+    watermarks: DashMap<String, ExportWatermark>,
+}
+
+impl InMemoryWatermarkStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Reason: WatermarkStore is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl WatermarkStore for InMemoryWatermarkStore {
+    async fn load(&self, export_key: &str) -> Result<Option<ExportWatermark>, String> {
+        Ok(self.watermarks.get(export_key).map(|w| *w))
+    }
+
+    async fn save(&self, export_key: &str, watermark: &ExportWatermark) -> Result<(), String> {
+        self.watermarks.insert(export_key.to_string(), *watermark);
+        Ok(())
+    }
+}
+
+/// Watermark store backed by a PostgreSQL table, accessed through an
+/// [`ArrowDatabaseAdapter`].
+///
+/// Call [`ensure_table`](Self::ensure_table) once at startup to create the table.
+pub struct PostgresWatermarkStore {
+    adapter: Arc<dyn ArrowDatabaseAdapter>,
+    table:   String,
+}
+
+impl PostgresWatermarkStore {
+    /// Create a store using the [`DEFAULT_WATERMARK_TABLE`].
+    #[must_use]
+    pub fn new(adapter: Arc<dyn ArrowDatabaseAdapter>) -> Self {
+        Self {
+            adapter,
+            table: DEFAULT_WATERMARK_TABLE.to_string(),
+        }
+    }
+
+    /// Use a different watermark table (optionally schema-qualified, e.g. `analytics.watermarks`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `table` is not a plain, optionally schema-qualified SQL identifier.
+    pub fn with_table(mut self, table: impl Into<String>) -> Result<Self, String> {
+        let table = table.into();
+        if !is_table_name(&table) {
+            return Err(format!("Invalid watermark table name: '{table}'"));
+        }
+        self.table = table;
+        Ok(self)
+    }
+
+    /// Create the watermark table if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the statement fails (e.g. missing `CREATE` permission).
+    pub async fn ensure_table(&self) -> Result<(), String> {
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS {} (\
+               export_key     TEXT        PRIMARY KEY, \
+               last_event_id  UUID        NOT NULL, \
+               last_timestamp TIMESTAMPTZ NOT NULL, \
+               updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()\
+             )",
+            self.table
+        );
+        self.adapter
+            .execute_raw_query(&sql)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Failed to create watermark table '{}': {e}", self.table))
+    }
+}
+
+/// Whether `table` is a plain, optionally schema-qualified SQL identifier.
+pub(crate) fn is_table_name(table: &str) -> bool {
+    !table.is_empty()
+        && table.split('.').count() <= 2
+        && table.split('.').all(|part| {
+            part.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+/// Quote `value` as a SQL string literal.
+pub(crate) fn sql_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+// Reason: WatermarkStore is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl WatermarkStore for PostgresWatermarkStore {
+    async fn load(&self, export_key: &str) -> Result<Option<ExportWatermark>, String> {
+        let sql = format!(
+            "SELECT last_event_id::text AS last_event_id, \
+             to_char(last_timestamp AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS.US\"Z\"') \
+             AS last_timestamp FROM {} WHERE export_key = {}",
+            self.table,
+            sql_literal(export_key)
+        );
+        let rows = self
+            .adapter
+            .execute_raw_query(&sql)
+            .await
+            .map_err(|e| format!("Failed to load watermark '{export_key}': {e}"))?;
+        let Some(row) = rows.first() else {
+            return Ok(None);
+        };
+
+        let field = |name: &str| {
+            row.get(name)
+                .and_then(serde_json::Value::as_str)
+                .ok_or_else(|| format!("Watermark row for '{export_key}' has no {name}"))
+        };
+        let last_event_id = Uuid::parse_str(field("last_event_id")?)
+            .map_err(|e| format!("Invalid watermark event id for '{export_key}': {e}"))?;
+        let last_timestamp = DateTime::parse_from_rfc3339(field("last_timestamp")?)
+            .map_err(|e| format!("Invalid watermark timestamp for '{export_key}': {e}"))?
+            .with_timezone(&Utc);
+        Ok(Some(ExportWatermark {
+            last_event_id,
+            last_timestamp,
+        }))
+    }
+
+    async fn save(&self, export_key: &str, watermark: &ExportWatermark) -> Result<(), String> {
+        let sql = format!(
+            "INSERT INTO {} (export_key, last_event_id, last_timestamp) \
+             VALUES ({}, {}::uuid, {}::timestamptz) \
+             ON CONFLICT (export_key) DO UPDATE SET \
+               last_event_id = EXCLUDED.last_event_id, \
+               last_timestamp = EXCLUDED.last_timestamp, \
+               updated_at = NOW() \
+             RETURNING export_key",
+            self.table,
+            sql_literal(export_key),
+            sql_literal(&watermark.last_event_id.to_string()),
+            // Truncated to the column's microsecond precision: a truncated watermark can
+            // only re-ship an event, never skip one.
+            sql_literal(&watermark.last_timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)),
+        );
+        self.adapter
+            .execute_raw_query(&sql)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Failed to save watermark '{export_key}': {e}"))
+    }
+}
+
+/// An incremental export of one entity type's observer events.
+#[derive(Debug, Clone)]
+pub struct IncrementalExport {
+    /// Key the watermark is stored under
+    pub export_key:      String,
+    /// Entity type whose events are exported
+    pub entity_type:     String,
+    /// Output format of each batch
+    pub format:          ExportFormat,
+    /// Maximum number of events per run (`None` = all new events)
+    pub batch_limit:     Option<usize>,
+    /// Emit `DELETE` events as tombstones (`deleted = true`) instead of skipping them
+    pub emit_tombstones: bool,
+}
+
+impl IncrementalExport {
+    /// Export all new events of `entity_type` as JSON Lines, without tombstones.
+    #[must_use]
+    pub fn new(export_key: impl Into<String>, entity_type: impl Into<String>) -> Self {
+        Self {
+            export_key:      export_key.into(),
+            entity_type:     entity_type.into(),
+            format:          ExportFormat::Json,
+            batch_limit:     None,
+            emit_tombstones: false,
+        }
+    }
+
+    /// Set the output format.
+    #[must_use]
+    pub const fn with_format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Cap the number of events shipped per run.
+    #[must_use]
+    pub const fn with_batch_limit(mut self, limit: usize) -> Self {
+        self.batch_limit = Some(limit);
+        self
+    }
+
+    /// Emit delete tombstones.
+    #[must_use]
+    pub const fn with_tombstones(mut self, emit: bool) -> Self {
+        self.emit_tombstones = emit;
+        self
+    }
+}
+
+/// One run of an incremental export, ready to deliver and then commit.
+#[derive(Debug, Clone)]
+pub struct IncrementalBatch {
+    /// Key the watermark is committed under
+    pub export_key: String,
+    /// Encoded rows
+    pub data:       Vec<u8>,
+    /// Statistics of the encoded batch
+    pub stats:      BatchStats,
+    /// Number of insert/update rows
+    pub upserts:    usize,
+    /// Number of delete tombstones
+    pub tombstones: usize,
+    /// Watermark to commit once `data` has been delivered
+    pub watermark:  ExportWatermark,
+}
+
+/// Encode events as a [`cdc_event_schema`] batch.
+///
+/// # Errors
+///
+/// Returns `ArrowError` if the batch cannot be assembled.
+pub fn cdc_batch(events: &[HistoricalEvent]) -> Result<RecordBatch, ArrowError> {
+    let batch = observer_events_to_batch(events)?;
+    let deleted: ArrayRef = Arc::new(BooleanArray::from(
+        events.iter().map(|e| e.event_type == DELETE_EVENT_TYPE).collect::<Vec<_>>(),
+    ));
+    let mut columns = batch.columns().to_vec();
+    columns.push(deleted);
+    RecordBatch::try_new(cdc_event_schema(), columns)
+}
+
+impl BulkExporter {
+    /// Export the events recorded since the export's committed watermark.
+    ///
+    /// Returns `None` when there is nothing new. The returned batch's watermark is
+    /// **not** persisted; call [`commit_incremental`](Self::commit_incremental) once
+    /// the data has been delivered. A run made only of skipped `DELETE` events yields
+    /// an empty batch so its watermark can still be committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the watermark cannot be loaded, the events cannot be read, or
+    /// encoding fails.
+    pub async fn export_incremental(
+        storage: &dyn ArrowEventStorage,
+        watermarks: &dyn WatermarkStore,
+        export: &IncrementalExport,
+    ) -> Result<Option<IncrementalBatch>, String> {
+        let watermark = watermarks.load(&export.export_key).await?;
+        let events = storage
+            .query_events_after(&export.entity_type, watermark.as_ref(), export.batch_limit)
+            .await?;
+        let Some(last) = events.last() else {
+            return Ok(None);
+        };
+        let next_watermark = ExportWatermark::after(last);
+
+        let rows: Vec<HistoricalEvent> = events
+            .into_iter()
+            .filter(|e| export.emit_tombstones || e.event_type != DELETE_EVENT_TYPE)
+            .collect();
+        let tombstones = rows.iter().filter(|e| e.event_type == DELETE_EVENT_TYPE).count();
+
+        let batch =
+            cdc_batch(&rows).map_err(|e| format!("Failed to build incremental batch: {e}"))?;
+        let data = Self::export_batch(&batch, export.format)?;
+
+        Ok(Some(IncrementalBatch {
+            export_key: export.export_key.clone(),
+            data,
+            stats: Self::batch_stats(&batch),
+            upserts: rows.len() - tombstones,
+            tombstones,
+            watermark: next_watermark,
+        }))
+    }
+
+    /// Persist the watermark of a delivered incremental batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the watermark store cannot be written.
+    pub async fn commit_incremental(
+        watermarks: &dyn WatermarkStore,
+        batch: &IncrementalBatch,
+    ) -> Result<(), String> {
+        watermarks.save(&batch.export_key, &batch.watermark).await
+    }
+}
+
+#[cfg(test)]
+mod tests;
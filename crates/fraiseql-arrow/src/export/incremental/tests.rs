@@ -0,0 +1,238 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::{collections::HashMap, sync::Mutex};
+
+use super::*;
+use crate::db::DatabaseResult;
+
+/// Event storage returning its events newest first, like real backends.
+struct VecEvents(Vec<HistoricalEvent>);
+
+fn event(n: u128, seconds: i64, event_type: &str) -> HistoricalEvent {
+    HistoricalEvent {
+        id:          Uuid::from_u128(n),
+        event_type:  event_type.to_string(),
+        entity_type: "Order".to_string(),
+        entity_id:   Uuid::from_u128(n + 100),
+        data:        serde_json::json!({ "n": n }),
+        user_id:     None,
+        tenant_id:   None,
+        timestamp:   DateTime::from_timestamp(1_700_000_000 + seconds, 0).unwrap(),
+    }
+}
+
+// Reason: ArrowEventStorage is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl ArrowEventStorage for VecEvents {
+    async fn query_events(
+        &self,
+        _entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+        limit: Option<usize>,
+    ) -> Result<Vec<HistoricalEvent>, String> {
+        let mut events: Vec<_> = self
+            .0
+            .iter()
+            .filter(|e| start_date.is_none_or(|s| e.timestamp >= s))
+            .filter(|e| end_date.is_none_or(|end| e.timestamp <= end))
+            .cloned()
+            .collect();
+        events.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
+        Ok(events)
+    }
+
+    async fn count_events(
+        &self,
+        entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+    ) -> Result<usize, String> {
+        Ok(self.query_events(entity_type, start_date, end_date, None).await?.len())
+    }
+}
+
+fn deleted_flags(batch: &IncrementalBatch) -> Vec<bool> {
+    String::from_utf8(batch.data.clone())
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["deleted"] == true)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_incremental_export_ships_only_new_events() {
+    let storage = VecEvents(vec![event(1, 0, "INSERT"), event(2, 10, "UPDATE")]);
+    let watermarks = InMemoryWatermarkStore::new();
+    let export = IncrementalExport::new("orders-to-warehouse", "Order");
+
+    let first = BulkExporter::export_incremental(&storage, &watermarks, &export)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.upserts, 2);
+    assert_eq!(first.watermark.last_event_id, Uuid::from_u128(2));
+    BulkExporter::commit_incremental(&watermarks, &first).await.unwrap();
+
+    // Nothing new since the commit.
+    let none = BulkExporter::export_incremental(&storage, &watermarks, &export).await.unwrap();
+    assert!(none.is_none());
+
+    // A new event, including one sharing the watermark's timestamp.
+    let storage = VecEvents(vec![
+        event(1, 0, "INSERT"),
+        event(2, 10, "UPDATE"),
+        event(3, 10, "INSERT"),
+        event(4, 20, "INSERT"),
+    ]);
+    let next = BulkExporter::export_incremental(&storage, &watermarks, &export)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(next.upserts, 2);
+    assert_eq!(next.watermark.last_event_id, Uuid::from_u128(4));
+}
+
+#[tokio::test]
+async fn test_uncommitted_batch_is_shipped_again() {
+    let storage = VecEvents(vec![event(1, 0, "INSERT")]);
+    let watermarks = InMemoryWatermarkStore::new();
+    let export = IncrementalExport::new("orders", "Order");
+
+    let first = BulkExporter::export_incremental(&storage, &watermarks, &export)
+        .await
+        .unwrap()
+        .unwrap();
+    let again = BulkExporter::export_incremental(&storage, &watermarks, &export)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(first.watermark, again.watermark);
+}
+
+#[tokio::test]
+async fn test_batch_limit_pages_oldest_first() {
+    let storage =
+        VecEvents((1..=5).map(|n| event(n, i64::try_from(n).unwrap(), "INSERT")).collect());
+    let watermarks = InMemoryWatermarkStore::new();
+    let export = IncrementalExport::new("orders", "Order").with_batch_limit(2);
+
+    let mut pages = Vec::new();
+    while let Some(batch) =
+        BulkExporter::export_incremental(&storage, &watermarks, &export).await.unwrap()
+    {
+        pages.push(batch.watermark.last_event_id);
+        BulkExporter::commit_incremental(&watermarks, &batch).await.unwrap();
+    }
+
+    assert_eq!(pages, vec![Uuid::from_u128(2), Uuid::from_u128(4), Uuid::from_u128(5)]);
+}
+
+#[tokio::test]
+async fn test_deletes_skipped_unless_tombstones_requested() {
+    let storage = VecEvents(vec![event(1, 0, "INSERT"), event(2, 5, "DELETE")]);
+    let watermarks = InMemoryWatermarkStore::new();
+
+    let plain = IncrementalExport::new("plain", "Order");
+    let batch = BulkExporter::export_incremental(&storage, &watermarks, &plain)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((batch.upserts, batch.tombstones), (1, 0));
+    assert_eq!(deleted_flags(&batch), vec![false]);
+    // The skipped delete still advances the watermark.
+    assert_eq!(batch.watermark.last_event_id, Uuid::from_u128(2));
+
+    let cdc = IncrementalExport::new("cdc", "Order").with_tombstones(true);
+    let batch = BulkExporter::export_incremental(&storage, &watermarks, &cdc)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((batch.upserts, batch.tombstones), (1, 1));
+    assert_eq!(deleted_flags(&batch), vec![false, true]);
+}
+
+/// Adapter recording the SQL it runs and answering watermark loads from a fixed row.
+struct RecordingAdapter {
+    statements: Mutex<Vec<String>>,
+    row:        Option<HashMap<String, serde_json::Value>>,
+}
+
+// Reason: ArrowDatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl ArrowDatabaseAdapter for RecordingAdapter {
+    async fn execute_raw_query(
+        &self,
+        sql: &str,
+    ) -> DatabaseResult<Vec<HashMap<String, serde_json::Value>>> {
+        self.statements.lock().unwrap().push(sql.to_string());
+        if sql.starts_with("SELECT") {
+            return Ok(self.row.clone().into_iter().collect());
+        }
+        Ok(Vec::new())
+    }
+}
+
+#[tokio::test]
+async fn test_postgres_store_round_trip_sql() {
+    let row = HashMap::from([
+        (
+            "last_event_id".to_string(),
+            serde_json::json!("00000000-0000-0000-0000-000000000007"),
+        ),
+        ("last_timestamp".to_string(), serde_json::json!("2026-01-01T00:00:00.123456Z")),
+    ]);
+    let adapter = Arc::new(RecordingAdapter {
+        statements: Mutex::new(Vec::new()),
+        row:        Some(row),
+    });
+    let store = PostgresWatermarkStore::new(adapter.clone());
+
+    let loaded = store.load("o'rders").await.unwrap().unwrap();
+    assert_eq!(loaded.last_event_id, Uuid::from_u128(7));
+    assert_eq!(loaded.last_timestamp.timestamp_subsec_micros(), 123_456);
+
+    store.save("o'rders", &loaded).await.unwrap();
+
+    let statements = adapter.statements.lock().unwrap();
+    assert!(statements[0].contains("WHERE export_key = 'o''rders'"));
+    assert!(statements[1].starts_with("INSERT INTO fraiseql_export_watermarks"));
+    assert!(statements[1].contains("ON CONFLICT (export_key) DO UPDATE"));
+    assert!(statements[1].contains("'2026-01-01T00:00:00.123456Z'::timestamptz"));
+}
+
+#[tokio::test]
+async fn test_postgres_store_missing_watermark() {
+    let adapter = Arc::new(RecordingAdapter {
+        statements: Mutex::new(Vec::new()),
+        row:        None,
+    });
+    let store = PostgresWatermarkStore::new(adapter);
+
+    assert!(store.load("new-export").await.unwrap().is_none());
+}
+
+#[test]
+fn test_postgres_store_rejects_invalid_table_names() {
+    let adapter = || -> Arc<dyn ArrowDatabaseAdapter> {
+        Arc::new(RecordingAdapter {
+            statements: Mutex::new(Vec::new()),
+            row:        None,
+        })
+    };
+
+    assert!(
+        PostgresWatermarkStore::new(adapter())
+            .with_table("analytics.watermarks")
+            .is_ok()
+    );
+    assert!(PostgresWatermarkStore::new(adapter()).with_table("w; DROP TABLE x").is_err());
+    assert!(PostgresWatermarkStore::new(adapter()).with_table("a.b.c").is_err());
+}
@@ -0,0 +1,208 @@
+//! Memory-bounded buffering of export batches with spill-to-disk.
+//!
+//! A bulk export converts every batch before streaming the first one, so the whole
+//! result would otherwise sit in memory. [`SpillingBatchBuffer`] accounts for the
+//! memory of the batches it holds against a budget; when the next batch would exceed
+//! it, the buffered batches are written to a temporary Arrow IPC file and released.
+//! [`SpillingBatchBuffer::finish`] then yields every batch in push order: the spilled
+//! runs first, read back one batch at a time, then the batches still in memory.
+//!
+//! Spill files are anonymous temporary files, removed by the OS once dropped.
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufReader, BufWriter, Seek, SeekFrom},
+    path::PathBuf,
+};
+
+use arrow::{
+    array::RecordBatch,
+    error::ArrowError,
+    ipc::{reader::FileReader, writer::FileWriter},
+};
+
+/// Default memory budget of one bulk export's buffered batches.
+pub const DEFAULT_EXPORT_MEMORY_BUDGET: usize = 256 * 1024 * 1024;
+
+/// Memory and spill accounting of a [`SpillingBatchBuffer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpillStats {
+    /// Batches currently held in memory
+    pub in_memory_batches: usize,
+    /// Memory held by the in-memory batches, in bytes
+    pub in_memory_bytes:   usize,
+    /// Batches written to spill files
+    pub spilled_batches:   usize,
+    /// In-memory size of the spilled batches, in bytes
+    pub spilled_bytes:     usize,
+    /// Spill files written
+    pub spill_files:       usize,
+}
+
+impl SpillStats {
+    /// Total number of batches pushed.
+    #[must_use]
+    pub const fn total_batches(&self) -> usize {
+        self.in_memory_batches + self.spilled_batches
+    }
+}
+
+/// Buffer of export batches that spills to temporary Arrow IPC files once its memory
+/// budget is exceeded.
+///
+/// A single batch larger than the budget is still buffered; it is spilled as soon as
+/// another batch arrives.
+#[derive(Debug)]
+pub struct SpillingBatchBuffer {
+    budget:    usize,
+    spill_dir: Option<PathBuf>,
+    in_memory: Vec<RecordBatch>,
+    spilled:   Vec<File>,
+    stats:     SpillStats,
+}
+
+impl SpillingBatchBuffer {
+    /// Create a buffer holding at most `budget` bytes of batches in memory.
+    #[must_use]
+    pub const fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            spill_dir: None,
+            in_memory: Vec::new(),
+            spilled: Vec::new(),
+            stats: SpillStats {
+                in_memory_batches: 0,
+                in_memory_bytes:   0,
+                spilled_batches:   0,
+                spilled_bytes:     0,
+                spill_files:       0,
+            },
+        }
+    }
+
+    /// Write spill files to `dir` instead of the system temporary directory.
+    #[must_use]
+    pub fn with_spill_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.spill_dir = Some(dir.into());
+        self
+    }
+
+    /// Current accounting.
+    #[must_use]
+    pub const fn stats(&self) -> SpillStats {
+        self.stats
+    }
+
+    /// Whether no batch has been pushed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.stats.total_batches() == 0
+    }
+
+    /// Buffer `batch`, spilling the batches already in memory first if it would
+    /// exceed the budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spill file cannot be created or written.
+    pub fn push(&mut self, batch: RecordBatch) -> Result<(), ArrowError> {
+        let bytes = batch.get_array_memory_size();
+        if !self.in_memory.is_empty() && self.stats.in_memory_bytes + bytes > self.budget {
+            self.spill()?;
+        }
+        self.in_memory.push(batch);
+        self.stats.in_memory_batches += 1;
+        self.stats.in_memory_bytes += bytes;
+        Ok(())
+    }
+
+    /// Write the in-memory batches to a new spill file and release them.
+    fn spill(&mut self) -> Result<(), ArrowError> {
+        let file = match &self.spill_dir {
+            Some(dir) => tempfile::tempfile_in(dir),
+            None => tempfile::tempfile(),
+        }
+        .map_err(|e| ArrowError::IoError("Failed to create export spill file".to_string(), e))?;
+
+        let schema = self.in_memory[0].schema();
+        let mut writer = FileWriter::try_new(BufWriter::new(file), &schema)?;
+        for batch in &self.in_memory {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+        let mut file = writer.into_inner()?.into_inner().map_err(|e| {
+            ArrowError::IoError("Failed to flush export spill file".to_string(), e.into_error())
+        })?;
+        file.seek(SeekFrom::Start(0)).map_err(|e| {
+            ArrowError::IoError("Failed to rewind export spill file".to_string(), e)
+        })?;
+
+        tracing::debug!(
+            batches = self.in_memory.len(),
+            bytes = self.stats.in_memory_bytes,
+            "Spilled export batches to disk"
+        );
+        self.spilled.push(file);
+        self.stats.spill_files += 1;
+        self.stats.spilled_batches += self.stats.in_memory_batches;
+        self.stats.spilled_bytes += self.stats.in_memory_bytes;
+        self.stats.in_memory_batches = 0;
+        self.stats.in_memory_bytes = 0;
+        self.in_memory.clear();
+        Ok(())
+    }
+
+    /// Consume the buffer, yielding every pushed batch in push order.
+    #[must_use]
+    pub fn finish(self) -> SpilledBatches {
+        SpilledBatches {
+            spilled:   self.spilled.into(),
+            reader:    None,
+            in_memory: self.in_memory.into_iter(),
+        }
+    }
+}
+
+/// Batches of a finished [`SpillingBatchBuffer`]: the spilled runs read back from disk
+/// one batch at a time, then the batches that stayed in memory.
+pub struct SpilledBatches {
+    spilled:   VecDeque<File>,
+    reader:    Option<FileReader<BufReader<File>>>,
+    in_memory: std::vec::IntoIter<RecordBatch>,
+}
+
+impl Iterator for SpilledBatches {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(reader) = &mut self.reader {
+                match reader.next() {
+                    Some(batch) => return Some(batch),
+                    None => self.reader = None,
+                }
+            }
+            let Some(file) = self.spilled.pop_front() else {
+                return self.in_memory.next().map(Ok);
+            };
+            match FileReader::try_new(BufReader::new(file), None) {
+                Ok(reader) => self.reader = Some(reader),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for SpilledBatches {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpilledBatches")
+            .field("pending_spill_files", &self.spilled.len())
+            .field("reading_spill_file", &self.reader.is_some())
+            .field("in_memory_batches", &self.in_memory.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests;
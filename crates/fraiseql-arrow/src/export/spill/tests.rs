@@ -0,0 +1,84 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
+use std::sync::Arc;
+
+use arrow::{
+    array::{Array, Int64Array},
+    datatypes::{DataType, Field, Schema},
+};
+
+use super::*;
+
+fn batch(start: i64, len: i64) -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+    let ids = Int64Array::from((start..start + len).collect::<Vec<_>>());
+    RecordBatch::try_new(schema, vec![Arc::new(ids)]).unwrap()
+}
+
+fn ids(batches: SpilledBatches) -> Vec<i64> {
+    batches
+        .map(|batch| {
+            let batch = batch.unwrap();
+            let column = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap().clone();
+            column.values().to_vec()
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+#[test]
+fn batches_within_budget_stay_in_memory() {
+    let mut buffer = SpillingBatchBuffer::new(DEFAULT_EXPORT_MEMORY_BUDGET);
+    buffer.push(batch(0, 10)).unwrap();
+    buffer.push(batch(10, 10)).unwrap();
+
+    let stats = buffer.stats();
+    assert_eq!(stats.in_memory_batches, 2);
+    assert_eq!(stats.spill_files, 0);
+    assert_eq!(ids(buffer.finish()), (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn exceeding_the_budget_spills_and_preserves_order() {
+    let budget = batch(0, 100).get_array_memory_size() * 2;
+    let mut buffer = SpillingBatchBuffer::new(budget);
+    for i in 0..7 {
+        buffer.push(batch(i * 100, 100)).unwrap();
+    }
+
+    let stats = buffer.stats();
+    assert_eq!(stats.spill_files, 3);
+    assert_eq!(stats.spilled_batches, 6);
+    assert_eq!(stats.in_memory_batches, 1);
+    assert!(stats.in_memory_bytes <= budget);
+    assert_eq!(stats.total_batches(), 7);
+    assert_eq!(ids(buffer.finish()), (0..700).collect::<Vec<_>>());
+}
+
+#[test]
+fn oversized_batch_is_held_until_the_next_push() {
+    let mut buffer = SpillingBatchBuffer::new(1);
+    buffer.push(batch(0, 50)).unwrap();
+    assert_eq!(buffer.stats().spill_files, 0);
+
+    buffer.push(batch(50, 50)).unwrap();
+    assert_eq!(buffer.stats().spill_files, 1);
+    assert_eq!(ids(buffer.finish()), (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn spill_dir_is_honoured() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut buffer = SpillingBatchBuffer::new(1).with_spill_dir(dir.path());
+    buffer.push(batch(0, 5)).unwrap();
+    buffer.push(batch(5, 5)).unwrap();
+
+    assert_eq!(buffer.stats().spill_files, 1);
+    assert_eq!(ids(buffer.finish()), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn empty_buffer_yields_nothing() {
+    let buffer = SpillingBatchBuffer::new(DEFAULT_EXPORT_MEMORY_BUDGET);
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.finish().count(), 0);
+}
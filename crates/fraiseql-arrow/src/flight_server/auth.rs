@@ -1,11 +1,12 @@
 //! Authentication helpers for Flight service session tokens.
 
 use chrono::Utc;
+use fraiseql_core::security::{SecurityContext, auth_middleware::AuthenticatedUser};
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
-use tonic::{Request, Status};
-use tracing::warn;
+use tonic::{Request, Status, metadata::MetadataMap};
+use tracing::{debug, warn};
 
-use super::SessionTokenClaims;
+use super::{FraiseQLFlightService, SessionTokenClaims};
 
 /// Map security error to gRPC status.
 pub fn map_security_error_to_status(error: fraiseql_core::security::SecurityError) -> Status {
@@ -45,6 +46,7 @@ pub fn create_session_token(
         iat:          now.timestamp(),
         scopes:       user.scopes.clone(),
         session_type: "flight".to_string(),
+        extra_claims: user.extra_claims.clone(),
     };
 
     let key = EncodingKey::from_secret(secret.as_bytes());
@@ -79,7 +81,7 @@ pub fn validate_session_token(
 
     // Decode and verify token
     let token_data = decode::<SessionTokenClaims>(token, &key, &validation).map_err(|e| {
-        warn!(error = %e, "Session token validation failed");
+        debug!(error = %e, "Session token validation failed");
         match e.kind() {
             jsonwebtoken::errors::ErrorKind::ExpiredSignature => {
                 Status::unauthenticated("Session token expired - perform handshake again")
@@ -108,7 +110,7 @@ pub fn validate_session_token(
         expires_at,
         email: None,
         display_name: None,
-        extra_claims: std::collections::HashMap::new(),
+        extra_claims: claims.extra_claims,
     })
 }
 
@@ -124,11 +126,26 @@ pub fn validate_session_token(
 /// * `Err(Status)` - Missing or malformed authorization header
 #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
 pub fn extract_session_token<T>(request: &Request<T>) -> std::result::Result<String, Status> {
-    let metadata = request.metadata();
+    extract_bearer_token(request.metadata())?.ok_or_else(|| {
+        Status::unauthenticated(
+            "Missing authorization header - perform handshake or send a bearer token",
+        )
+    })
+}
 
-    let auth_header = metadata.get("authorization").ok_or_else(|| {
-        Status::unauthenticated("Missing authorization header - perform handshake first")
-    })?;
+/// Extract the bearer token from gRPC metadata, if an "authorization" header is present.
+///
+/// # Errors
+///
+/// Returns `Status::unauthenticated` when the header is present but is not valid
+/// ASCII or not in `Bearer <token>` form.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+pub(crate) fn extract_bearer_token(
+    metadata: &MetadataMap,
+) -> std::result::Result<Option<String>, Status> {
+    let Some(auth_header) = metadata.get("authorization") else {
+        return Ok(None);
+    };
 
     let auth_str = auth_header
         .to_str()
@@ -139,5 +156,136 @@ pub fn extract_session_token<T>(request: &Request<T>) -> std::result::Result<Str
         .ok_or_else(|| {
             Status::unauthenticated("Invalid authorization format, expected 'Bearer <token>'")
         })
-        .map(|s| s.to_string())
+        .map(|s| Some(s.to_string()))
+}
+
+/// Authenticate the bearer token presented on a Flight call.
+///
+/// Accepts either a session token issued by `handshake()` or, per call, a JWT that the
+/// configured OIDC validator accepts — the same validation the handshake performs.
+/// Session tokens are tried first because they are cheap to verify (HMAC, no JWKS).
+///
+/// Callers extract the token with [`extract_session_token`] before awaiting: request
+/// bodies such as `Streaming<FlightData>` are not `Sync`, so a borrowed request must
+/// not be held across the validator's await point.
+///
+/// # Errors
+///
+/// Returns `Status::unauthenticated` when no configured mechanism accepts the token,
+/// and `Status::internal` when neither a session secret nor an OIDC validator is
+/// configured.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+pub(crate) async fn authenticate_bearer(
+    svc: &FraiseQLFlightService,
+    token: &str,
+) -> std::result::Result<AuthenticatedUser, Status> {
+    let session_error = match svc.session_secret.as_deref() {
+        Some(secret) => match validate_session_token(token, secret) {
+            Ok(user) => return Ok(user),
+            Err(status) => Some(status),
+        },
+        None => None,
+    };
+
+    match (svc.oidc_validator.as_ref(), session_error) {
+        (Some(validator), _) => validator.validate_token(token).await.map_err(|e| {
+            warn!(error = %e, "Flight bearer token rejected");
+            map_security_error_to_status(e)
+        }),
+        (None, Some(status)) => {
+            warn!(error = %status.message(), "Flight session token rejected");
+            Err(status)
+        },
+        (None, None) => Err(Status::internal(
+            "Flight authentication not configured: set FLIGHT_SESSION_SECRET or an OIDC validator",
+        )),
+    }
+}
+
+/// Build the per-call [`SecurityContext`] for an authenticated Flight request.
+///
+/// Mirrors the HTTP extractor: custom claims become context attributes (except the
+/// framework-reserved `fraiseql.` namespace, which a token must not be able to forge)
+/// and the tenant comes from the `tenant_id` claim, falling back to `org_id`. The
+/// client-controlled `x-tenant-id` header is never trusted to *choose* a tenant; it is
+/// accepted only when it agrees with the token, so a client that pins a tenant gets a
+/// hard error instead of another tenant's rows.
+///
+/// # Errors
+///
+/// Returns `Status::permission_denied` when `x-tenant-id` names a tenant other than the
+/// token's.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+pub(crate) fn flight_security_context(
+    user: &AuthenticatedUser,
+    metadata: &MetadataMap,
+) -> std::result::Result<SecurityContext, Status> {
+    let request_id = metadata
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok())
+        .map_or_else(|| uuid::Uuid::new_v4().to_string(), str::to_string);
+
+    let mut context = SecurityContext::from_user(user, request_id);
+    for (key, value) in &user.extra_claims {
+        if key.starts_with("fraiseql.") {
+            continue;
+        }
+        context.attributes.insert(key.clone(), value.clone());
+    }
+
+    let claimed_tenant = ["tenant_id", "org_id"]
+        .iter()
+        .find_map(|claim| user.extra_claims.get(*claim).and_then(|v| v.as_str()));
+    let requested_tenant = metadata.get("x-tenant-id").and_then(|v| v.to_str().ok());
+
+    match (claimed_tenant, requested_tenant) {
+        (Some(claimed), Some(requested)) if claimed != requested => {
+            warn!(
+                user_id = %user.user_id,
+                "Flight request x-tenant-id does not match the token's tenant"
+            );
+            return Err(Status::permission_denied(
+                "x-tenant-id does not match the authenticated tenant",
+            ));
+        },
+        (None, Some(_)) => {
+            debug!(
+                user_id = %user.user_id,
+                "Ignoring x-tenant-id on a Flight request whose token carries no tenant"
+            );
+        },
+        _ => {},
+    }
+    context.tenant_id = claimed_tenant.map(fraiseql_core::types::TenantId::new);
+
+    Ok(context)
+}
+
+/// Resolve the caller of a discovery RPC (`ListFlights`, `GetFlightInfo`, `GetSchema`).
+///
+/// Discovery stays anonymous unless an authorizer is configured: then the caller must
+/// authenticate, so that only what it may query is described to it. A caller that
+/// presents a bearer token is always authenticated, with or without an authorizer.
+///
+/// # Errors
+///
+/// Returns `Status::unauthenticated` when an authorizer is configured and no token is
+/// presented, or when the presented token is rejected.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+pub(crate) async fn discovery_principal(
+    svc: &FraiseQLFlightService,
+    metadata: &MetadataMap,
+) -> std::result::Result<Option<SecurityContext>, Status> {
+    let Some(token) = extract_bearer_token(metadata)? else {
+        if svc.authorizer.is_some() {
+            return Err(Status::unauthenticated(
+                "Flight discovery requires authentication - perform handshake or send a bearer \
+                 token",
+            ));
+        }
+        return Ok(None);
+    };
+
+    let user = authenticate_bearer(svc, &token).await?;
+    flight_security_context(&user, metadata).map(Some)
 }
@@ -4,13 +4,21 @@ use std::sync::Arc;
 
 use arrow::{
     array::RecordBatch,
-    ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions},
+    datatypes::Schema,
+    ipc::writer::{
+        DictionaryTracker, EncodedData, IpcDataGenerator, IpcWriteContext, IpcWriteOptions,
+    },
 };
 use arrow_flight::FlightData;
 use tonic::Status;
 #[cfg(any(test, feature = "testing"))]
 use tracing::warn;
 
+use crate::{
+    convert::{dictionary_encode_columns, dictionary_encoded_schema},
+    ticket::{BatchEncoding, IpcCompression},
+};
+
 /// Convert `RecordBatch` to `FlightData` using Arrow IPC encoding.
 ///
 /// # Arguments
@@ -26,8 +34,6 @@ use tracing::warn;
 /// Returns error if IPC encoding fails
 #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
 pub fn record_batch_to_flight_data(batch: &RecordBatch) -> std::result::Result<FlightData, Status> {
-    use arrow::ipc::writer::IpcWriteContext;
-
     let options = IpcWriteOptions::default();
     let data_gen = IpcDataGenerator::default();
     let mut dict_tracker = DictionaryTracker::new(false);
@@ -76,12 +82,124 @@ pub fn schema_to_flight_data(
     })
 }
 
+/// Stateful `FlightData` encoder for one `DoGet` stream honouring a ticket's
+/// [`BatchEncoding`].
+///
+/// Dictionary ids are assigned when the schema message is encoded, so the same
+/// encoder must produce the schema message and every batch of the stream. Each batch is
+/// preceded by the dictionary batches its dictionary columns need.
+pub(crate) struct BatchStreamEncoder {
+    data_gen:           IpcDataGenerator,
+    options:            IpcWriteOptions,
+    dictionary_tracker: DictionaryTracker,
+    write_context:      IpcWriteContext,
+    dictionary_columns: Vec<String>,
+}
+
+impl BatchStreamEncoder {
+    /// Create an encoder for `encoding`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Status::invalid_argument` if compression is requested but the crate was
+    /// built without the `compression` feature.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    pub(crate) fn new(encoding: &BatchEncoding) -> std::result::Result<Self, Status> {
+        Ok(Self {
+            data_gen:           IpcDataGenerator::default(),
+            options:            ipc_write_options(encoding.compression)?,
+            dictionary_tracker: DictionaryTracker::new(false),
+            write_context:      IpcWriteContext::default(),
+            dictionary_columns: encoding.dictionary_columns.clone(),
+        })
+    }
+
+    /// The schema the stream's batches are encoded with.
+    pub(crate) fn encoded_schema(&self, schema: &Schema) -> Arc<Schema> {
+        Arc::new(dictionary_encoded_schema(schema, &self.dictionary_columns))
+    }
+
+    /// Encode the stream's schema message.
+    pub(crate) fn schema_message(&mut self, schema: &Schema) -> FlightData {
+        let encoded_data = self.data_gen.schema_to_bytes_with_dictionary_tracker(
+            &self.encoded_schema(schema),
+            &mut self.dictionary_tracker,
+            &self.options,
+        );
+        encoded_to_flight_data(encoded_data)
+    }
+
+    /// Encode one batch as its dictionary messages followed by the record batch message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Status::invalid_argument` if a dictionary column is missing or not `Utf8`,
+    /// and `Status::internal` if IPC encoding fails.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    pub(crate) fn batch_messages(
+        &mut self,
+        batch: &RecordBatch,
+    ) -> std::result::Result<Vec<FlightData>, Status> {
+        let batch = dictionary_encode_columns(batch, &self.dictionary_columns)
+            .map_err(|e| Status::invalid_argument(format!("Invalid batch encoding: {e}")))?;
+        let (dictionaries, encoded_batch) = self
+            .data_gen
+            .encode(&batch, &mut self.dictionary_tracker, &self.options, &mut self.write_context)
+            .map_err(|e| Status::internal(format!("Failed to encode RecordBatch: {e}")))?;
+
+        Ok(dictionaries
+            .into_iter()
+            .chain(std::iter::once(encoded_batch))
+            .map(encoded_to_flight_data)
+            .collect())
+    }
+}
+
+/// IPC write options for a ticket's compression codec.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+fn ipc_write_options(
+    compression: Option<IpcCompression>,
+) -> std::result::Result<IpcWriteOptions, Status> {
+    let Some(compression) = compression else {
+        return Ok(IpcWriteOptions::default());
+    };
+
+    #[cfg(feature = "compression")]
+    {
+        use arrow::ipc::CompressionType;
+
+        let codec = match compression {
+            IpcCompression::Lz4 => CompressionType::LZ4_FRAME,
+            IpcCompression::Zstd => CompressionType::ZSTD,
+        };
+        IpcWriteOptions::default()
+            .try_with_compression(Some(codec))
+            .map_err(|e| Status::invalid_argument(format!("Invalid IPC compression: {e}")))
+    }
+    #[cfg(not(feature = "compression"))]
+    {
+        Err(Status::invalid_argument(format!(
+            "IPC compression {compression:?} is not available: fraiseql-arrow was built \
+             without the `compression` feature"
+        )))
+    }
+}
+
+fn encoded_to_flight_data(encoded_data: EncodedData) -> FlightData {
+    FlightData {
+        data_header: encoded_data.ipc_message.into(),
+        data_body: encoded_data.arrow_data.into(),
+        ..Default::default()
+    }
+}
+
 /// Build optimized SQL query for va_* view.
 ///
 /// # Arguments
 ///
 /// * `view` - View name (e.g., "`va_orders`"); quoted as a SQL identifier
 /// * `filter` - Must be `None`; raw WHERE clauses are rejected to prevent SQL injection
+/// * `tenant_predicate` - Optional server-built tenant predicate (see `tenant_filter`)
 /// * `order_by` - Optional ORDER BY as `"col [ASC|DESC], ..."` (validated, identifiers quoted)
 /// * `limit` - Optional LIMIT
 /// * `offset` - Optional OFFSET
@@ -97,15 +215,18 @@ pub fn schema_to_flight_data(
 /// let sql = build_optimized_sql(
 ///     "va_orders",
 ///     None,
+///     Some("\"tenant_id\" = 'acme'"),
 ///     Some("created_at DESC".to_string()),
 ///     Some(100),
 ///     Some(0)
 /// )?;
-/// // Returns: SELECT * FROM "va_orders" ORDER BY "created_at" DESC LIMIT 100 OFFSET 0
+/// // Returns: SELECT * FROM "va_orders" WHERE "tenant_id" = 'acme'
+/// //          ORDER BY "created_at" DESC LIMIT 100 OFFSET 0
 /// ```
 pub fn build_optimized_sql(
     view: &str,
     filter: Option<String>,
+    tenant_predicate: Option<&str>,
     order_by: Option<String>,
     limit: Option<usize>,
     offset: Option<usize>,
@@ -122,6 +243,10 @@ pub fn build_optimized_sql(
     let quoted_view = format!("\"{}\"", view.replace('"', "\"\""));
     let mut sql = format!("SELECT * FROM {quoted_view}");
 
+    if let Some(predicate) = tenant_predicate {
+        sql.push_str(&format!(" WHERE {predicate}"));
+    }
+
     if let Some(order_clause) = order_by {
         let safe_order = build_safe_order_by(&order_clause)?;
         sql.push_str(&format!(" ORDER BY {safe_order}"));
@@ -3,7 +3,8 @@
 //! This file contains only the thin `#[tonic::async_trait]` impl block that
 //! satisfies the `FlightService` trait.  All business logic lives in the
 //! sub-modules declared below; each handler simply delegates to the
-//! corresponding `handle` (or named) function.
+//! corresponding `handle` (or named) function, run as a [`FlightCall`] so it is
+//! rate limited, measured, logged and isolated from panics.
 
 mod actions;
 mod do_exchange;
@@ -20,7 +21,7 @@ use tonic::{Request, Response, Status, Streaming};
 
 use super::{
     ActionResultStream, ActionTypeStream, FlightDataStream, FlightInfoStream,
-    FraiseQLFlightService, HandshakeStream, PutResultStream,
+    FraiseQLFlightService, HandshakeStream, PutResultStream, middleware::FlightCall,
 };
 
 #[tonic::async_trait]
@@ -58,7 +59,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Streaming<HandshakeRequest>>,
     ) -> std::result::Result<Response<Self::HandshakeStream>, Status> {
-        metadata::handshake(self, request).await
+        let call = FlightCall::new("handshake", &request);
+        let call = call.admit(self).await?;
+        call.streaming(metadata::handshake(self, request)).await
     }
 
     /// List available datasets/queries.
@@ -68,7 +71,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Criteria>,
     ) -> std::result::Result<Response<Self::ListFlightsStream>, Status> {
-        metadata::list_flights(self, request).await
+        let call = FlightCall::new("list_flights", &request);
+        let call = call.admit(self).await?;
+        call.streaming(metadata::list_flights(self, request)).await
     }
 
     /// Get schema for a dataset without fetching data.
@@ -78,7 +83,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<SchemaResult>, Status> {
-        metadata::get_schema(self, request).await
+        let call = FlightCall::new("get_schema", &request);
+        let call = call.admit(self).await?;
+        call.unary(metadata::get_schema(self, request)).await
     }
 
     /// Fetch data stream (main data retrieval method).
@@ -89,7 +96,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Ticket>,
     ) -> std::result::Result<Response<Self::DoGetStream>, Status> {
-        do_get::handle(self, request).await
+        let call = FlightCall::new("do_get", &request).with_ticket(&request.get_ref().ticket);
+        let call = call.admit(self).await?;
+        call.streaming(do_get::handle(self, request)).await
     }
 
     /// Upload data stream (for client-to-server data transfer).
@@ -100,7 +109,8 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Streaming<FlightData>>,
     ) -> std::result::Result<Response<Self::DoPutStream>, Status> {
-        do_put::handle(self, request).await
+        let call = FlightCall::new("do_put", &request).admit(self).await?;
+        call.streaming(do_put::handle(self, request)).await
     }
 
     /// Execute an action (RPC method for operations beyond data transfer).
@@ -117,7 +127,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Action>,
     ) -> std::result::Result<Response<Self::DoActionStream>, Status> {
-        actions::do_action(self, request).await
+        let call = FlightCall::new("do_action", &request);
+        let call = call.admit(self).await?;
+        call.streaming(actions::do_action(self, request)).await
     }
 
     /// List available actions.
@@ -127,7 +139,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Empty>,
     ) -> std::result::Result<Response<Self::ListActionsStream>, Status> {
-        actions::list_actions(self, request).await
+        let call = FlightCall::new("list_actions", &request);
+        let call = call.admit(self).await?;
+        call.streaming(actions::list_actions(self, request)).await
     }
 
     /// Bidirectional streaming with correlation ID matching.
@@ -142,7 +156,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<Streaming<FlightData>>,
     ) -> std::result::Result<Response<Self::DoExchangeStream>, Status> {
-        do_exchange::handle(self, request).await
+        let call = FlightCall::new("do_exchange", &request);
+        let call = call.admit(self).await?;
+        call.streaming(do_exchange::handle(self, request)).await
     }
 
     /// Get flight info for a descriptor (metadata about available data).
@@ -176,7 +192,9 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        metadata::get_flight_info(self, request).await
+        let call = FlightCall::new("get_flight_info", &request);
+        let call = call.admit(self).await?;
+        call.unary(metadata::get_flight_info(self, request)).await
     }
 
     /// Poll for flight info (synchronous implementation).
@@ -193,6 +211,8 @@ impl FlightService for FraiseQLFlightService {
         &self,
         request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<PollInfo>, Status> {
-        metadata::poll_flight_info(self, request).await
+        let call = FlightCall::new("poll_flight_info", &request);
+        let call = call.admit(self).await?;
+        call.unary(metadata::poll_flight_info(self, request)).await
     }
 }
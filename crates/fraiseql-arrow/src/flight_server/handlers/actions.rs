@@ -8,8 +8,8 @@ use tonic::{Request, Response, Status};
 use tracing::info;
 
 use super::super::{
-    ActionResultStream, ActionTypeStream, FraiseQLFlightService, extract_session_token,
-    validate_session_token,
+    ActionResultStream, ActionTypeStream, FraiseQLFlightService, authenticate_bearer,
+    extract_session_token,
 };
 
 /// `do_action` handler: executes a named admin operation on behalf of an authenticated client.
@@ -18,12 +18,8 @@ pub(super) async fn do_action(
     request: Request<Action>,
 ) -> std::result::Result<Response<ActionResultStream>, Status> {
     // Validate session token for admin operations
-    let session_token = extract_session_token(&request)?;
-    let secret = svc
-        .session_secret
-        .as_deref()
-        .ok_or_else(|| Status::internal("FLIGHT_SESSION_SECRET not configured"))?;
-    let authenticated_user = validate_session_token(&session_token, secret)?;
+    let bearer_token = extract_session_token(&request)?;
+    let authenticated_user = authenticate_bearer(svc, &bearer_token).await?;
 
     let action = request.into_inner();
     info!(
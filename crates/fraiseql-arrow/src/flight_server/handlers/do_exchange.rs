@@ -14,9 +14,9 @@ use tonic::{Request, Response, Status, Streaming};
 use tracing::{info, warn};
 
 use super::super::{
-    FlightDataStream, FraiseQLFlightService, QueryExecutor, build_insert_query,
-    decode_upload_batch, encode_json_to_arrow_batch, extract_session_token,
-    record_batch_to_flight_data, validate_session_token,
+    FlightDataStream, FraiseQLFlightService, QueryExecutor, authenticate_bearer,
+    build_insert_query, decode_upload_batch, encode_json_to_arrow_batch, extract_session_token,
+    flight_security_context, record_batch_to_flight_data,
 };
 use crate::{
     exchange_protocol::{ExchangeMessage, RequestType},
@@ -229,20 +229,13 @@ pub(super) async fn handle(
     request: Request<Streaming<FlightData>>,
 ) -> std::result::Result<Response<FlightDataStream>, Status> {
     // Validate session token for bidirectional streams
-    let session_token = extract_session_token(&request)?;
-    let secret = svc
-        .session_secret
-        .as_deref()
-        .ok_or_else(|| Status::internal("FLIGHT_SESSION_SECRET not configured"))?;
-    let authenticated_user = validate_session_token(&session_token, secret)?;
+    let bearer_token = extract_session_token(&request)?;
+    let authenticated_user = authenticate_bearer(svc, &bearer_token).await?;
 
     info!(user_id = %authenticated_user.user_id, "Authenticated do_exchange request");
 
     // Create security context for RLS
-    let security_context = fraiseql_core::security::SecurityContext::from_user(
-        &authenticated_user,
-        uuid::Uuid::new_v4().to_string(),
-    );
+    let security_context = flight_security_context(&authenticated_user, request.metadata())?;
 
     let mut incoming = request.into_inner();
     let (tx, rx) = tokio::sync::mpsc::channel(100);
@@ -1,14 +1,15 @@
 //! Handler for the Arrow Flight `do_get` RPC method.
 //!
-//! Authenticates the caller via session token and dispatches to the appropriate
-//! data-fetching path based on the decoded `FlightTicket` variant.
+//! Authenticates the caller via bearer token, authorizes the decoded `FlightTicket`, and
+//! dispatches to the appropriate data-fetching path based on its variant.
 
 use arrow_flight::Ticket;
 use tonic::{Request, Response, Status};
 use tracing::info;
 
 use super::super::{
-    FlightDataStream, FraiseQLFlightService, extract_session_token, validate_session_token,
+    FlightDataStream, FraiseQLFlightService, authenticate_bearer, authorize_ticket,
+    extract_session_token, flight_security_context,
 };
 use crate::ticket::FlightTicket;
 
@@ -19,18 +20,14 @@ pub(super) async fn handle(
 ) -> std::result::Result<Response<FlightDataStream>, Status> {
     // Enforce concurrent stream limit: non-blocking acquire so we immediately
     // reject when at capacity rather than queueing indefinitely.
-    let _permit = svc
-        .stream_semaphore
-        .try_acquire()
-        .map_err(|_| tonic::Status::resource_exhausted("Max concurrent Flight streams reached"))?;
+    let permit =
+        svc.stream_semaphore.clone().try_acquire_owned().map_err(|_| {
+            tonic::Status::resource_exhausted("Max concurrent Flight streams reached")
+        })?;
 
-    // Validate session token from metadata
-    let session_token = extract_session_token(&request)?;
-    let secret = svc
-        .session_secret
-        .as_deref()
-        .ok_or_else(|| Status::internal("FLIGHT_SESSION_SECRET not configured"))?;
-    let authenticated_user = validate_session_token(&session_token, secret)?;
+    // Authenticate the bearer token (handshake session token or per-call JWT)
+    let bearer_token = extract_session_token(&request)?;
+    let authenticated_user = authenticate_bearer(svc, &bearer_token).await?;
 
     info!(
         user_id = %authenticated_user.user_id,
@@ -38,6 +35,9 @@ pub(super) async fn handle(
         "Authenticated do_get request"
     );
 
+    // Create security context for RLS filtering and tenant scoping
+    let security_context = flight_security_context(&authenticated_user, request.metadata())?;
+
     // Extract ticket
     let ticket_bytes = request.into_inner().ticket;
     let ticket = FlightTicket::decode(&ticket_bytes)
@@ -45,11 +45,7 @@ pub(super) async fn handle(
 
     info!("DoGet called (authenticated): {:?}", ticket);
 
-    // Create security context for RLS filtering
-    let security_context = fraiseql_core::security::SecurityContext::from_user(
-        &authenticated_user,
-        uuid::Uuid::new_v4().to_string(),
-    );
+    authorize_ticket(svc, &ticket, &security_context)?;
 
     match ticket {
         FlightTicket::GraphQLQuery { query, variables } => {
@@ -63,19 +59,41 @@ pub(super) async fn handle(
             order_by,
             limit,
             offset,
+            encoding,
         } => {
             // Pass security_context to execute_optimized_view for RLS
             let stream = svc
-                .execute_optimized_view(&view, filter, order_by, limit, offset, &security_context)
+                .execute_optimized_view(
+                    &view,
+                    filter,
+                    order_by,
+                    limit,
+                    offset,
+                    encoding,
+                    &security_context,
+                )
                 .await?;
-            Ok(Response::new(Box::pin(stream)))
+            Ok(Response::new(stream))
         },
         FlightTicket::ObserverEvents {
             entity_type,
             start_date,
             end_date,
             limit,
-        } => svc.execute_observer_events(&entity_type, start_date, end_date, limit).await,
+            partition,
+            encoding,
+        } => {
+            svc.execute_observer_events(
+                &entity_type,
+                start_date,
+                end_date,
+                limit,
+                partition,
+                encoding,
+                &security_context,
+            )
+            .await
+        },
         FlightTicket::BulkExport {
             table,
             filter,
@@ -87,5 +105,62 @@ pub(super) async fn handle(
             let stream = svc.execute_batched_queries(queries, &security_context).await?;
             Ok(Response::new(Box::pin(stream)))
         },
+        FlightTicket::Aggregate {
+            table,
+            group_by,
+            aggregates,
+            limit,
+        } => {
+            svc.execute_aggregate(&table, group_by, aggregates, limit, &security_context)
+                .await
+        },
+        FlightTicket::DlqItems {
+            action_type,
+            start_date,
+            end_date,
+            limit,
+            encoding,
+        } => {
+            svc.execute_dlq_items(
+                action_type,
+                start_date,
+                end_date,
+                limit,
+                encoding,
+                &security_context,
+            )
+            .await
+        },
+        FlightTicket::JobHistory {
+            action_type,
+            state,
+            start_date,
+            end_date,
+            limit,
+            encoding,
+        } => {
+            svc.execute_job_history(
+                action_type,
+                state,
+                start_date,
+                end_date,
+                limit,
+                encoding,
+                &security_context,
+            )
+            .await
+        },
+        // Long-lived: the stream keeps its concurrency permit until it closes.
+        FlightTicket::Subscription {
+            entity_type,
+            filter,
+            flush_interval_ms,
+        } => svc.execute_subscription(
+            entity_type,
+            filter,
+            flush_interval_ms,
+            &security_context,
+            permit,
+        ),
     }
 }
@@ -12,8 +12,8 @@ use tracing::{info, warn};
 
 use super::{
     super::{
-        FraiseQLFlightService, PutResultStream, build_insert_query, decode_flight_data_to_batch,
-        extract_session_token, validate_session_token,
+        FraiseQLFlightService, PutResultStream, authenticate_bearer, build_insert_query,
+        decode_flight_data_to_batch, extract_session_token,
     },
     send_helpers::{send_err, send_ok},
 };
@@ -24,12 +24,8 @@ pub(super) async fn handle(
     request: Request<Streaming<FlightData>>,
 ) -> std::result::Result<Response<PutResultStream>, Status> {
     // Validate session token for data uploads
-    let session_token = extract_session_token(&request)?;
-    let secret = svc
-        .session_secret
-        .as_deref()
-        .ok_or_else(|| Status::internal("FLIGHT_SESSION_SECRET not configured"))?;
-    let authenticated_user = validate_session_token(&session_token, secret)?;
+    let bearer_token = extract_session_token(&request)?;
+    let authenticated_user = authenticate_bearer(svc, &bearer_token).await?;
 
     info!(
         user_id = %authenticated_user.user_id,
@@ -3,38 +3,46 @@
 //! Contains handlers for: `handshake`, `list_flights`, `get_schema`,
 //! `get_flight_info`, and `poll_flight_info`.
 
+use std::sync::Arc;
+
 use arrow::{
     datatypes::SchemaRef,
     ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions},
 };
 use arrow_flight::{
-    Criteria, FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse, PollInfo,
-    SchemaResult,
+    Criteria, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse,
+    Location, PollInfo, SchemaResult, Ticket, flight_descriptor::DescriptorType,
 };
 use prost::bytes::Bytes;
 use tonic::{Request, Response, Status, Streaming};
 use tracing::{error, info, warn};
 
 use super::super::{
-    FlightInfoStream, FraiseQLFlightService, HandshakeStream, create_session_token,
-    map_security_error_to_status,
+    FlightInfoStream, FraiseQLFlightService, HandshakeStream, authorize_ticket,
+    create_session_token, discovery_principal, map_security_error_to_status,
 };
 use crate::{
-    schema::{graphql_result_schema, observer_event_schema},
+    convert::dictionary_encoded_schema,
+    schema::{
+        aggregate_result_schema, dlq_item_schema, graphql_result_schema, job_attempt_schema,
+        observer_event_schema,
+    },
     ticket::FlightTicket,
 };
 
 /// Resolve the Arrow schema for a `FlightTicket`, enforcing unsupported-ticket errors.
 fn ticket_to_schema(
     svc: &FraiseQLFlightService,
-    ticket: FlightTicket,
+    ticket: &FlightTicket,
 ) -> std::result::Result<SchemaRef, Status> {
-    match ticket {
+    let schema = match ticket {
         FlightTicket::GraphQLQuery { .. } => Ok(graphql_result_schema()),
-        FlightTicket::ObserverEvents { .. } => Ok(observer_event_schema()),
+        FlightTicket::ObserverEvents { .. } | FlightTicket::Subscription { .. } => {
+            Ok(observer_event_schema())
+        },
         FlightTicket::OptimizedView { view, .. } => svc
             .schema_registry
-            .get(&view)
+            .get(view)
             .map_err(|e| Status::not_found(format!("Schema not found for view {view}: {e}"))),
         FlightTicket::BulkExport { .. } => Err(Status::unimplemented(
             "BulkExport schema introspection is not supported: the schema varies by \
@@ -44,9 +52,126 @@ fn ticket_to_schema(
         FlightTicket::BatchedQueries { .. } => Err(Status::unimplemented(
             "GetSchema for BatchedQueries returns per-query schemas in the data stream",
         )),
+        FlightTicket::Aggregate {
+            group_by,
+            aggregates,
+            ..
+        } => Ok(aggregate_result_schema(group_by, aggregates)),
+        FlightTicket::DlqItems { .. } => Ok(dlq_item_schema()),
+        FlightTicket::JobHistory { .. } => Ok(job_attempt_schema()),
+    }?;
+
+    // Dictionary-encoded columns change the schema the stream is written with.
+    Ok(match ticket.encoding() {
+        Some(encoding) if !encoding.dictionary_columns.is_empty() => {
+            Arc::new(dictionary_encoded_schema(&schema, &encoding.dictionary_columns))
+        },
+        _ => schema,
+    })
+}
+
+/// Resolve the ticket a `FlightDescriptor` describes.
+///
+/// A `CMD` descriptor carries an encoded `FlightTicket`. A `PATH` descriptor's first
+/// element is either a registered view name — as listed by `ListFlights` — which
+/// describes an unbounded `OptimizedView` ticket, or an encoded `FlightTicket`.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+fn descriptor_to_ticket(
+    svc: &FraiseQLFlightService,
+    descriptor: &FlightDescriptor,
+) -> std::result::Result<FlightTicket, Status> {
+    if descriptor.r#type == DescriptorType::Cmd as i32 {
+        return FlightTicket::decode(&descriptor.cmd)
+            .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")));
+    }
+
+    let Some(first) = descriptor.path.first() else {
+        return Err(Status::invalid_argument("Empty flight descriptor path"));
+    };
+
+    if svc.schema_registry.contains(first) {
+        return Ok(view_ticket(first));
+    }
+
+    FlightTicket::decode(first.as_bytes())
+        .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))
+}
+
+/// The unbounded `OptimizedView` ticket for a registered view.
+fn view_ticket(view: &str) -> FlightTicket {
+    FlightTicket::OptimizedView {
+        view:     view.to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    None,
+        offset:   None,
+        encoding: None,
     }
 }
 
+/// Build the `FlightInfo` describing `ticket`: its schema and the endpoints that serve it.
+///
+/// Each endpoint carries the encoded ticket to pass to `DoGet` and the service's
+/// advertised locations (none by default: fetch from the service that answered). A
+/// ticket requesting partitioning yields one endpoint per partition, which clients may
+/// fetch in parallel. Views also report their registry schema version in
+/// `app_metadata`, so clients can tell when a cached schema went stale.
+#[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+fn flight_info_for(
+    svc: &FraiseQLFlightService,
+    descriptor: FlightDescriptor,
+    ticket: &FlightTicket,
+    schema: &SchemaRef,
+) -> std::result::Result<FlightInfo, Status> {
+    let partitions = ticket.partitions().map_err(|e| Status::invalid_argument(e.to_string()))?;
+    let location: Vec<Location> = svc
+        .advertised_locations
+        .iter()
+        .map(|uri| Location { uri: uri.clone() })
+        .collect();
+
+    let endpoint = partitions
+        .iter()
+        .map(|partition| {
+            let ticket_bytes = partition
+                .encode()
+                .map_err(|e| Status::internal(format!("Failed to encode ticket: {e}")))?;
+            Ok(FlightEndpoint {
+                ticket:          Some(Ticket {
+                    ticket: ticket_bytes.into(),
+                }),
+                location:        location.clone(),
+                expiration_time: None,
+                app_metadata:    vec![].into(),
+            })
+        })
+        .collect::<std::result::Result<Vec<_>, Status>>()?;
+
+    let (ordered, app_metadata) = match ticket {
+        FlightTicket::OptimizedView { view, order_by, .. } => {
+            let metadata = svc
+                .schema_registry
+                .get_version_info(view)
+                .map(|(version, _)| {
+                    serde_json::json!({ "schema_version": version }).to_string().into_bytes()
+                })
+                .unwrap_or_default();
+            (order_by.is_some(), metadata)
+        },
+        _ => (false, Vec::new()),
+    };
+
+    Ok(FlightInfo {
+        schema: schema_to_ipc_bytes(schema),
+        flight_descriptor: Some(descriptor),
+        endpoint,
+        total_records: -1, // Unknown until executed
+        total_bytes: -1,   // Unknown until executed
+        ordered,
+        app_metadata: app_metadata.into(),
+    })
+}
+
 /// Serialize an Arrow schema to IPC bytes.
 fn schema_to_ipc_bytes(schema: &SchemaRef) -> Bytes {
     let options = IpcWriteOptions::default();
@@ -131,35 +256,48 @@ pub(super) async fn handshake(
     Ok(Response::new(Box::pin(stream) as HandshakeStream))
 }
 
-/// `list_flights` handler: returns metadata about available pre-compiled Arrow views.
+/// `list_flights` handler: enumerates every view registered in the schema registry.
+///
+/// Each view is described by a `PATH` descriptor holding its name, its Arrow schema and
+/// an endpoint whose ticket fetches it. `Criteria.expression`, when non-empty, is a
+/// view-name prefix. When the caller is authenticated and an authorizer is configured,
+/// views the caller may not query are omitted.
 pub(super) async fn list_flights(
     svc: &FraiseQLFlightService,
-    _request: Request<Criteria>,
+    request: Request<Criteria>,
 ) -> std::result::Result<Response<FlightInfoStream>, Status> {
     info!("ListFlights called");
 
+    let principal = discovery_principal(svc, request.metadata()).await?;
+    let prefix = String::from_utf8(request.into_inner().expression.to_vec())
+        .map_err(|_| Status::invalid_argument("ListFlights criteria must be UTF-8"))?;
+
     let mut flight_infos = Vec::new();
 
-    for view_name in &["va_orders", "va_users", "ta_orders", "ta_users"] {
-        if let Ok(schema) = svc.schema_registry.get(view_name) {
-            let descriptor = FlightDescriptor {
-                r#type: 1, // PATH
-                path:   vec![(*view_name).to_string()],
-                cmd:    b"".to_vec().into(),
-            };
-
-            let flight_info = FlightInfo {
-                schema:            schema_to_ipc_bytes(&schema),
-                flight_descriptor: Some(descriptor),
-                endpoint:          vec![],
-                total_records:     -1, // Unknown until executed
-                total_bytes:       -1,
-                ordered:           false,
-                app_metadata:      vec![].into(),
-            };
-
-            flight_infos.push(Ok(flight_info));
+    for view_name in svc.schema_registry.view_names() {
+        if !view_name.starts_with(&prefix) {
+            continue;
         }
+
+        let ticket = view_ticket(&view_name);
+        if let Some(ref security_context) = principal {
+            if authorize_ticket(svc, &ticket, security_context).is_err() {
+                continue;
+            }
+        }
+
+        // A view removed since `view_names()` was taken is simply not listed.
+        let Ok(schema) = svc.schema_registry.get(&view_name) else {
+            continue;
+        };
+
+        let descriptor = FlightDescriptor {
+            r#type: DescriptorType::Path as i32,
+            path:   vec![view_name],
+            cmd:    b"".to_vec().into(),
+        };
+
+        flight_infos.push(Ok(flight_info_for(svc, descriptor, &ticket, &schema)?));
     }
 
     info!("ListFlights returning {} datasets", flight_infos.len());
@@ -171,51 +309,44 @@ pub(super) async fn get_schema(
     svc: &FraiseQLFlightService,
     request: Request<FlightDescriptor>,
 ) -> std::result::Result<Response<SchemaResult>, Status> {
+    let principal = discovery_principal(svc, request.metadata()).await?;
     let descriptor = request.into_inner();
     info!("GetSchema called: {:?}", descriptor);
 
-    if descriptor.path.is_empty() {
-        return Err(Status::invalid_argument("Empty flight descriptor path"));
+    let ticket = descriptor_to_ticket(svc, &descriptor)?;
+    if let Some(ref security_context) = principal {
+        authorize_ticket(svc, &ticket, security_context)?;
     }
 
-    let ticket = FlightTicket::decode(descriptor.path[0].as_bytes())
-        .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))?;
-
-    let schema = ticket_to_schema(svc, ticket)?;
+    let schema = ticket_to_schema(svc, &ticket)?;
 
     Ok(Response::new(SchemaResult {
         schema: schema_to_ipc_bytes(&schema),
     }))
 }
 
-/// `get_flight_info` handler: returns metadata about a dataset without fetching it.
+/// `get_flight_info` handler: returns the schema and endpoints of a dataset without
+/// fetching it.
+///
+/// The returned endpoint's ticket is what the client passes to `DoGet`.
 pub(super) async fn get_flight_info(
     svc: &FraiseQLFlightService,
     request: Request<FlightDescriptor>,
 ) -> std::result::Result<Response<FlightInfo>, Status> {
+    let principal = discovery_principal(svc, request.metadata()).await?;
     let descriptor = request.into_inner();
     info!("GetFlightInfo called: {:?}", descriptor);
 
-    if descriptor.path.is_empty() {
-        return Err(Status::invalid_argument("Empty flight descriptor path"));
-    }
-
-    let ticket = FlightTicket::decode(descriptor.path[0].as_bytes())
-        .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))?;
+    let ticket = descriptor_to_ticket(svc, &descriptor)?;
 
     info!("GetFlightInfo decoded ticket: {:?}", ticket);
 
-    let schema = ticket_to_schema(svc, ticket)?;
+    if let Some(ref security_context) = principal {
+        authorize_ticket(svc, &ticket, security_context)?;
+    }
 
-    let flight_info = FlightInfo {
-        schema:            schema_to_ipc_bytes(&schema),
-        flight_descriptor: Some(descriptor),
-        endpoint:          vec![], // Data retrieved via DoGet with same descriptor
-        total_records:     -1,     // Unknown until executed
-        total_bytes:       -1,     // Unknown until executed
-        ordered:           false,
-        app_metadata:      vec![].into(),
-    };
+    let schema = ticket_to_schema(svc, &ticket)?;
+    let flight_info = flight_info_for(svc, descriptor, &ticket, &schema)?;
 
     info!("GetFlightInfo returning schema for ticket");
     Ok(Response::new(flight_info))
@@ -229,11 +360,10 @@ pub(super) async fn poll_flight_info(
     svc: &FraiseQLFlightService,
     request: Request<FlightDescriptor>,
 ) -> std::result::Result<Response<PollInfo>, Status> {
-    let descriptor = request.into_inner();
-    info!("PollFlightInfo called: {:?}", descriptor);
+    info!("PollFlightInfo called: {:?}", request.get_ref());
 
-    // Reuse get_flight_info logic to build the FlightInfo.
-    let flight_info = get_flight_info(svc, Request::new(descriptor)).await?.into_inner();
+    // Reuse get_flight_info logic (including caller authentication) to build the FlightInfo.
+    let flight_info = get_flight_info(svc, request).await?.into_inner();
 
     // flight_descriptor = None signals "complete — no need to poll again".
     // progress = 1.0 confirms 100 % complete.
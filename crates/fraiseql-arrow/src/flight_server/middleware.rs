@@ -0,0 +1,324 @@
+//! Per-call middleware of the Flight service: metrics, request logs, panic isolation and
+//! per-peer rate limiting.
+//!
+//! The Flight counterpart of the HTTP server's metrics, trace and rate-limit layers. A
+//! tonic interceptor only sees request metadata, so the stack runs inside the
+//! `FlightService` impl instead: every RPC is started as a [`FlightCall`], which
+//! admits it through the configured [`FlightRateLimiter`], runs the handler with panics
+//! caught, and records the call once it completes — for streaming calls, when the
+//! response stream ends or the client drops it.
+//!
+//! Metrics are emitted through the [`metrics`] facade, like `fraiseql-wire`'s, so they
+//! reach `/metrics` when the server installs its Prometheus recorder and cost nothing
+//! otherwise:
+//!
+//! - `fraiseql_flight_calls_total{method, ticket, code}` — completed calls by gRPC status code
+//!   (`Ok`, `ResourceExhausted`, `Internal`, ...). Rate-limited calls count here too.
+//! - `fraiseql_flight_call_duration_seconds{method, ticket}` — wall-clock from the call's arrival
+//!   to its last response message.
+//! - `fraiseql_flight_bytes_sent_total{method, ticket}` — encoded size of the response messages
+//!   sent.
+//! - `fraiseql_flight_panics_total{method}` — handler or stream panics answered with `INTERNAL`.
+//!
+//! `ticket` is the [`FlightTicket::kind`] of a `do_get` ticket, and `none` for every
+//! other method.
+
+use std::{
+    any::Any,
+    fmt,
+    future::Future,
+    net::IpAddr,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use async_trait::async_trait;
+use futures::{FutureExt, Stream};
+use metrics::{counter, histogram};
+use tonic::{Code, Request, Response, Status};
+use tracing::{error, info, warn};
+
+use super::{
+    FraiseQLFlightService,
+    auth::{authenticate_bearer, extract_bearer_token},
+};
+use crate::ticket::FlightTicket;
+
+/// `fraiseql_flight_calls_total{method, ticket, code}` — completed calls.
+const CALLS_TOTAL: &str = "fraiseql_flight_calls_total";
+/// `fraiseql_flight_call_duration_seconds{method, ticket}` — call wall-clock, seconds.
+const CALL_DURATION_SECONDS: &str = "fraiseql_flight_call_duration_seconds";
+/// `fraiseql_flight_bytes_sent_total{method, ticket}` — encoded response bytes.
+const BYTES_SENT_TOTAL: &str = "fraiseql_flight_bytes_sent_total";
+/// `fraiseql_flight_panics_total{method}` — panics turned into `INTERNAL`.
+const PANICS_TOTAL: &str = "fraiseql_flight_panics_total";
+
+/// `ticket` label of calls that carry no `do_get` ticket.
+const NO_TICKET: &str = "none";
+
+/// Who a Flight call comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlightPeer {
+    /// Id of the user the call's bearer token authenticates. `None` when the call
+    /// carries no token or one the service rejects: such calls are limited by `addr`,
+    /// so a forged token cannot pick another user's bucket or a fresh one.
+    pub subject: Option<String>,
+    /// Remote IP address of the connection, when the transport exposes it.
+    pub addr:    Option<IpAddr>,
+}
+
+impl fmt::Display for FlightPeer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.subject, self.addr) {
+            (Some(subject), _) => write!(f, "user:{subject}"),
+            (None, Some(addr)) => write!(f, "ip:{addr}"),
+            (None, None) => f.write_str("unknown"),
+        }
+    }
+}
+
+/// Per-peer admission control for Flight calls.
+///
+/// Consulted once per RPC, before the handler runs; a rejected call fails with
+/// `RESOURCE_EXHAUSTED` and never reaches the handler or the database.
+// Reason: used as dyn Trait (Arc<dyn FlightRateLimiter>); async_trait ensures Send bounds and
+// dyn-compatibility async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+pub trait FlightRateLimiter: Send + Sync {
+    /// Admit one call from `peer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the number of seconds the peer should wait before retrying when it is
+    /// over its limit.
+    async fn check(&self, peer: &FlightPeer) -> Result<(), u32>;
+}
+
+/// One in-progress Flight RPC.
+pub(crate) struct FlightCall {
+    method:  &'static str,
+    ticket:  &'static str,
+    peer:    FlightPeer,
+    /// Bearer token of the call, until [`admit`](Self::admit) resolves its subject.
+    bearer:  Option<String>,
+    started: Instant,
+}
+
+impl FlightCall {
+    /// Start a `method` call of `request`.
+    pub(crate) fn new<T>(method: &'static str, request: &Request<T>) -> Self {
+        Self {
+            method,
+            ticket: NO_TICKET,
+            peer: FlightPeer {
+                subject: None,
+                addr:    request.remote_addr().map(|addr| addr.ip()),
+            },
+            bearer: extract_bearer_token(request.metadata()).ok().flatten(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Admit the call, unless its peer is over the rate limit of `svc`.
+    ///
+    /// The bearer token is verified like the handler does (see [`authenticate_bearer`])
+    /// to key the call by its user; a call whose token is missing or rejected is keyed
+    /// by its remote address, and the handler then refuses it as unauthenticated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Status::resource_exhausted` when the rate limiter refuses the call.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    pub(crate) async fn admit(mut self, svc: &FraiseQLFlightService) -> Result<Self, Status> {
+        let bearer = self.bearer.take();
+        let Some(limiter) = svc.rate_limiter.as_deref() else {
+            return Ok(self);
+        };
+        if let Some(token) = bearer {
+            self.peer.subject =
+                authenticate_bearer(svc, &token).await.ok().map(|user| user.user_id.0);
+        }
+        match limiter.check(&self.peer).await {
+            Ok(()) => Ok(self),
+            Err(retry_after_secs) => {
+                warn!(method = self.method, peer = %self.peer, "Flight rate limit exceeded");
+                let status = Status::resource_exhausted(format!(
+                    "Rate limit exceeded; retry after {retry_after_secs}s"
+                ));
+                self.finish(status.code(), 0);
+                Err(status)
+            },
+        }
+    }
+
+    /// Label the call with the kind of its `do_get` ticket (`invalid` when it does not
+    /// decode; the handler then rejects it).
+    pub(crate) fn with_ticket(mut self, ticket: &[u8]) -> Self {
+        self.ticket = FlightTicket::decode(ticket).map_or("invalid", |t| t.kind());
+        self
+    }
+
+    /// Run the handler of a call answering a single message.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    pub(crate) async fn unary<M, F>(self, handler: F) -> Result<Response<M>, Status>
+    where
+        M: prost::Message,
+        F: Future<Output = Result<Response<M>, Status>> + Send,
+    {
+        let result = self.guard(handler).await;
+        match &result {
+            Ok(response) => self.finish(Code::Ok, response.get_ref().encoded_len()),
+            Err(status) => self.finish(status.code(), 0),
+        }
+        result
+    }
+
+    /// Run the handler of a call answering a stream. The call is recorded when the
+    /// stream ends; a panic while producing it ends the stream with `INTERNAL`.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    pub(crate) async fn streaming<M, F>(
+        self,
+        handler: F,
+    ) -> Result<Response<BoxedStream<M>>, Status>
+    where
+        M: prost::Message + 'static,
+        F: Future<Output = Result<Response<BoxedStream<M>>, Status>> + Send,
+    {
+        match self.guard(handler).await {
+            Ok(response) => Ok(response.map(|inner| {
+                Box::pin(ObservedStream {
+                    inner,
+                    call: Some(self),
+                    bytes: 0,
+                    code: Code::Ok,
+                }) as BoxedStream<M>
+            })),
+            Err(status) => {
+                self.finish(status.code(), 0);
+                Err(status)
+            },
+        }
+    }
+
+    /// Await `handler`, answering a panic with `INTERNAL`.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    async fn guard<R: Send>(
+        &self,
+        handler: impl Future<Output = Result<R, Status>> + Send,
+    ) -> Result<R, Status> {
+        AssertUnwindSafe(handler)
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|payload| Err(panic_status(self.method, &*payload)))
+    }
+
+    /// Record the completed call: its metrics and one structured log line.
+    fn finish(&self, code: Code, bytes: usize) {
+        let elapsed = self.started.elapsed();
+        let code_label = format!("{code:?}");
+        counter!(
+            CALLS_TOTAL,
+            "method" => self.method,
+            "ticket" => self.ticket,
+            "code" => code_label.clone(),
+        )
+        .increment(1);
+        histogram!(CALL_DURATION_SECONDS, "method" => self.method, "ticket" => self.ticket)
+            .record(elapsed.as_secs_f64());
+        counter!(BYTES_SENT_TOTAL, "method" => self.method, "ticket" => self.ticket)
+            .increment(bytes as u64);
+
+        let duration_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        info!(
+            method = self.method,
+            ticket = self.ticket,
+            peer = %self.peer,
+            code = %code_label,
+            duration_ms,
+            bytes,
+            "Flight call completed"
+        );
+    }
+}
+
+/// A boxed response stream of a Flight RPC.
+pub(crate) type BoxedStream<M> = Pin<Box<dyn Stream<Item = Result<M, Status>> + Send>>;
+
+/// A response stream counting the bytes it sends, recording its call when it ends.
+struct ObservedStream<M> {
+    inner: BoxedStream<M>,
+    /// `None` once the call has been recorded.
+    call:  Option<FlightCall>,
+    bytes: usize,
+    /// Status of the first error the stream sent, which ends the gRPC call.
+    code:  Code,
+}
+
+impl<M: prost::Message> Stream for ObservedStream<M> {
+    type Item = Result<M, Status>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let Some(call) = &this.call else {
+            return Poll::Ready(None);
+        };
+        let polled =
+            std::panic::catch_unwind(AssertUnwindSafe(|| this.inner.as_mut().poll_next(cx)));
+        match polled {
+            Ok(Poll::Ready(Some(Ok(message)))) => {
+                this.bytes += message.encoded_len();
+                Poll::Ready(Some(Ok(message)))
+            },
+            Ok(Poll::Ready(Some(Err(status)))) => {
+                if this.code == Code::Ok {
+                    this.code = status.code();
+                }
+                Poll::Ready(Some(Err(status)))
+            },
+            Ok(Poll::Ready(None)) => {
+                call.finish(this.code, this.bytes);
+                this.call = None;
+                Poll::Ready(None)
+            },
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => {
+                let status = panic_status(call.method, &*payload);
+                call.finish(status.code(), this.bytes);
+                this.call = None;
+                Poll::Ready(Some(Err(status)))
+            },
+        }
+    }
+}
+
+impl<M> Drop for ObservedStream<M> {
+    /// The client went away before the stream ended.
+    fn drop(&mut self) {
+        if let Some(call) = self.call.take() {
+            let code = if self.code == Code::Ok {
+                Code::Cancelled
+            } else {
+                self.code
+            };
+            call.finish(code, self.bytes);
+        }
+    }
+}
+
+/// Log and count a panic of a `method` call, and return the `INTERNAL` status sent in
+/// its place. The panic message is logged but not sent to the client.
+pub(crate) fn panic_status(method: &'static str, payload: &(dyn Any + Send)) -> Status {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload");
+    error!(method, panic = message, "Flight call panicked");
+    counter!(PANICS_TOTAL, "method" => method).increment(1);
+    Status::internal("Internal error while serving the Flight call")
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,148 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::sync::{Arc, Mutex};
+
+use arrow_flight::{FlightData, SchemaResult, Ticket, flight_service_server::FlightService};
+use chrono::Utc;
+use fraiseql_core::{security::auth_middleware::AuthenticatedUser, types::UserId};
+use futures::StreamExt;
+
+use super::*;
+use crate::flight_server::{FraiseQLFlightService, auth::create_session_token};
+
+const SESSION_SECRET: &str = "test-flight-session-secret-for-unit-tests-only";
+
+/// A JWT claiming `{"sub": "bob"}` with a bogus signature.
+const FORGED_JWT: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiJib2IifQ.signature";
+
+/// A valid session token of `user_id`.
+fn session_token(user_id: &str) -> String {
+    let user = AuthenticatedUser {
+        user_id:      UserId::new(user_id),
+        scopes:       vec![],
+        expires_at:   Utc::now() + chrono::Duration::minutes(5),
+        email:        None,
+        display_name: None,
+        extra_claims: std::collections::HashMap::new(),
+    };
+    create_session_token(&user, SESSION_SECRET).unwrap()
+}
+
+fn bearer_request<T>(message: T, token: &str) -> Request<T> {
+    let mut request = Request::new(message);
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    request
+}
+
+/// Refuses every call, remembering who asked.
+#[derive(Default)]
+struct DenyAll {
+    seen: Mutex<Vec<FlightPeer>>,
+}
+
+#[async_trait]
+impl FlightRateLimiter for DenyAll {
+    async fn check(&self, peer: &FlightPeer) -> Result<(), u32> {
+        self.seen.lock().unwrap().push(peer.clone());
+        Err(7)
+    }
+}
+
+async fn failing_handler() -> Result<Response<SchemaResult>, Status> {
+    panic!("handler bug")
+}
+
+#[test]
+fn test_peer_display() {
+    let user = FlightPeer {
+        subject: Some("alice".to_string()),
+        addr:    Some("10.0.0.1".parse().unwrap()),
+    };
+    assert_eq!(user.to_string(), "user:alice");
+    let anonymous = FlightPeer {
+        subject: None,
+        addr:    Some("10.0.0.1".parse().unwrap()),
+    };
+    assert_eq!(anonymous.to_string(), "ip:10.0.0.1");
+    assert_eq!(FlightPeer::default().to_string(), "unknown");
+}
+
+#[test]
+fn test_call_is_labelled_with_ticket_kind() {
+    let ticket = crate::ticket::FlightTicket::BatchedQueries {
+        queries: vec!["SELECT 1".to_string()],
+    };
+    let call = FlightCall::new("do_get", &Request::new(())).with_ticket(&ticket.encode().unwrap());
+    assert_eq!(call.ticket, "BatchedQueries");
+
+    let call = FlightCall::new("do_get", &Request::new(())).with_ticket(b"garbage");
+    assert_eq!(call.ticket, "invalid");
+}
+
+#[tokio::test]
+async fn test_handler_panic_becomes_internal() {
+    let call = FlightCall::new("get_schema", &Request::new(()));
+    let status = call.unary(failing_handler()).await.unwrap_err();
+    assert_eq!(status.code(), Code::Internal);
+    assert!(!status.message().contains("handler bug"), "panic message leaked to the client");
+}
+
+#[tokio::test]
+async fn test_stream_panic_ends_stream_with_internal() {
+    let inner: BoxedStream<FlightData> = Box::pin(futures::stream::iter(0..3).map(|i| {
+        assert_ne!(i, 1, "stream bug");
+        Ok(FlightData::default())
+    }));
+    let call = FlightCall::new("do_get", &Request::new(()));
+    let mut stream = call.streaming(async { Ok(Response::new(inner)) }).await.unwrap().into_inner();
+
+    assert!(stream.next().await.unwrap().is_ok());
+    assert_eq!(stream.next().await.unwrap().unwrap_err().code(), Code::Internal);
+    assert!(stream.next().await.is_none(), "the stream ends after a panic");
+}
+
+/// Refuse one `do_get` carrying `token`, returning the peer the limiter saw.
+async fn limited_peer(token: &str) -> FlightPeer {
+    let limiter = Arc::new(DenyAll::default());
+    let service = FraiseQLFlightService::new()
+        .with_session_secret(SESSION_SECRET)
+        .with_rate_limiter(limiter.clone());
+
+    let request = bearer_request(
+        Ticket {
+            ticket: b"{}".to_vec().into(),
+        },
+        token,
+    );
+    let Err(status) = service.do_get(request).await else {
+        panic!("a refused call must fail");
+    };
+    assert_eq!(status.code(), Code::ResourceExhausted);
+    assert!(status.message().contains("retry after 7s"), "unexpected message: {status}");
+    let seen = limiter.seen.lock().unwrap();
+    seen[0].clone()
+}
+
+#[tokio::test]
+async fn test_rate_limited_call_is_keyed_by_verified_user() {
+    let peer = limited_peer(&session_token("alice")).await;
+    assert_eq!(peer.subject.as_deref(), Some("alice"));
+}
+
+#[tokio::test]
+async fn test_rate_limited_call_ignores_forged_subject() {
+    // Without the limiter the forged token would be rejected as unauthenticated; the
+    // limiter must not see its claimed `sub`.
+    let peer = limited_peer(FORGED_JWT).await;
+    assert_eq!(peer.subject, None);
+}
+
+#[tokio::test]
+async fn test_call_without_limiter_reaches_handler() {
+    let service = FraiseQLFlightService::new();
+    let Err(status) = service.do_get(Request::new(Ticket::default())).await else {
+        panic!("an unauthenticated do_get must fail");
+    };
+    assert_eq!(status.code(), Code::Unauthenticated);
+}
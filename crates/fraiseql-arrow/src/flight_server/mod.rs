@@ -7,21 +7,42 @@
 //!
 //! **Authenticated Query Execution**:
 //! - `handshake()` validates JWT tokens and returns 5-minute HMAC-SHA256 session tokens
-//! - `do_get()`, `do_action()`, `do_put()`, `do_exchange()` require valid session tokens via
-//!   "Authorization: Bearer" header
-//! - Session tokens are validated using `validate_session_token()` helper
-//! - Extracted tokens come from `extract_session_token()` helper
-//! - `SecurityContext` created for each request to enable Row-Level Security (RLS)
+//! - `do_get()`, `do_action()`, `do_put()`, `do_exchange()` require an "Authorization: Bearer"
+//!   header carrying either a handshake session token or, per call, a JWT accepted by the
+//!   configured OIDC validator (`authenticate_bearer()`)
+//! - Session tokens carry the original token's custom claims, so tenancy and roles survive the
+//!   handshake
+//! - `SecurityContext` created for each request via `flight_security_context()`; the tenant comes
+//!   from the `tenant_id` / `org_id` claim and a conflicting `x-tenant-id` header is rejected
+//! - Every `do_get` ticket except `GraphQLQuery` (which the executor authorizes itself) is passed
+//!   to the configured [`Authorizer`] before it runs, and tenant-scoped principals only see rows of
+//!   their own tenant
 //! - Admin operations (cache invalidation, schema refresh) require "admin" scope
 //! - All failed auth attempts return descriptive errors guiding users to re-handshake if needed
 //!
+//! # Middleware
+//!
+//! Every RPC runs through a per-call middleware stack mirroring the HTTP server's: an
+//! optional per-peer [`FlightRateLimiter`], latency / byte / status metrics, one structured
+//! log line per call (method, `do_get` ticket type, peer, status), and panic isolation that
+//! answers a panicking handler or stream with `INTERNAL` instead of resetting the stream.
+//!
+//! # Discovery
+//!
+//! - `list_flights()` enumerates every view in the [`SchemaRegistry`] (optionally filtered by a
+//!   name prefix in `Criteria.expression`) with its Arrow schema and a ready-to-use ticket
+//! - `get_flight_info()` accepts a `CMD` descriptor holding an encoded ticket or a `PATH`
+//!   descriptor holding a view name or encoded ticket, and returns the schema plus the endpoints
+//!   (ticket and advertised locations) to fetch it from
+//! - Discovery is anonymous unless an authorizer is configured; then callers authenticate and only
+//!   see what they may query
+//!
 //! # Deferred Features (v2.1+)
 //!
 //! The following features are intentionally deferred to v2.1 to focus on core functionality:
 //!
 //! | Feature | Status | Reason |
 //! |---------|--------|--------|
-//! | Subscribe (`do_exchange`) | v2.1 | Real-time event streaming requires observer integration |
 //! | `BulkExport` GetSchema/GetFlightInfo | — | Schema varies by table; use `do_get` directly |
 //! | `RefreshSchemaRegistry` action | v2.1 | Requires safe schema update mechanism for running queries |
 //! | Observer events (`do_get`) | v2.1 | Requires observer system integration |
@@ -32,8 +53,10 @@
 //! indicating they will be available in a future release.
 
 mod auth;
+mod authz;
 mod convert;
 mod handlers;
+mod middleware;
 mod service;
 #[cfg(test)]
 mod tests;
@@ -42,7 +65,7 @@ use std::{pin::Pin, sync::Arc};
 
 use arrow_flight::{ActionType, FlightData, FlightInfo, HandshakeResponse, PutResult};
 use async_trait::async_trait;
-use fraiseql_core::security::OidcValidator;
+use fraiseql_core::security::{Authorizer, OidcValidator};
 use futures::Stream; // Stream required for type aliases
 use serde::{Deserialize, Serialize};
 use tokio::sync::Semaphore;
@@ -50,19 +73,26 @@ use tonic::Status;
 
 // Re-export auth helpers for use across submodules
 pub(crate) use self::auth::{
-    create_session_token, extract_session_token, map_security_error_to_status,
-    validate_session_token,
+    authenticate_bearer, create_session_token, discovery_principal, extract_session_token,
+    flight_security_context, map_security_error_to_status,
 };
+pub(crate) use self::authz::{authorize_ticket, tenant_filter};
 #[cfg(any(test, feature = "testing"))]
 pub(crate) use self::convert::execute_placeholder_query;
 // Re-export convert functions for use across submodules
 pub(crate) use self::convert::{
-    build_insert_query, build_optimized_sql, decode_flight_data_to_batch, decode_upload_batch,
-    encode_json_to_arrow_batch, record_batch_to_flight_data, schema_to_flight_data,
+    BatchStreamEncoder, build_insert_query, build_optimized_sql, decode_flight_data_to_batch,
+    decode_upload_batch, encode_json_to_arrow_batch, record_batch_to_flight_data,
+    schema_to_flight_data,
 };
+pub use self::middleware::{FlightPeer, FlightRateLimiter};
 use crate::{
-    cache::QueryCache, db::ArrowDatabaseAdapter, event_storage::ArrowEventStorage,
-    metadata::SchemaRegistry, subscription::SubscriptionManager,
+    cache::QueryCache,
+    db::ArrowDatabaseAdapter,
+    delivery_storage::ArrowDeliveryStorage,
+    event_storage::ArrowEventStorage,
+    metadata::SchemaRegistry,
+    subscription::{SubscriptionManager, SubscriptionStreamConfig},
 };
 
 /// Trait for executing GraphQL queries with security context (RLS filtering).
@@ -77,6 +107,11 @@ use crate::{
 /// 1. Accepts GraphQL queries as strings
 /// 2. Applies Row-Level Security (RLS) policies based on `SecurityContext`
 /// 3. Returns JSON results that can be converted to Arrow `RecordBatches`
+///
+/// Implementations should push the query's selection set down to SQL so only the
+/// requested JSONB keys (`data->>'x'`) leave the database; the Flight service converts
+/// every key of the returned rows into an Arrow column. The core `Executor` does this
+/// through the adapter's `SqlProjectionHint`.
 // Reason: used as dyn Trait (Arc<dyn QueryExecutor>); async_trait ensures Send bounds and
 // dyn-compatibility async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
 #[async_trait]
@@ -154,8 +189,12 @@ pub struct FraiseQLFlightService {
     pub(crate) oidc_validator: Option<Arc<OidcValidator>>,
     /// Optional event storage for historical observer event queries
     pub(crate) event_storage: Option<Arc<dyn ArrowEventStorage>>,
+    /// Optional delivery storage for `DlqItems` / `JobHistory` exports
+    pub(crate) delivery_storage: Option<Arc<dyn ArrowDeliveryStorage>>,
     /// Subscription manager for real-time event streaming
     pub(crate) subscription_manager: Arc<SubscriptionManager>,
+    /// Delivery settings for `Subscription` tickets (flush / heartbeat intervals)
+    pub(crate) subscription_stream: SubscriptionStreamConfig,
     /// Allow clients to submit raw SQL via `BatchedQueries` tickets.
     ///
     /// **SECURITY**: Disabled by default. Enabling this allows authenticated clients
@@ -165,8 +204,8 @@ pub struct FraiseQLFlightService {
     /// Tables an authenticated client may `BulkExport` via Flight tickets.
     ///
     /// **SECURITY**: `None` (the default) disables `BulkExport` entirely. `BulkExport`
-    /// runs `SELECT * FROM "<table>"` and applies **no per-user RLS filtering** (the
-    /// `SecurityContext` is recorded for audit only), so it is fail-closed: a request is
+    /// runs `SELECT * FROM "<table>"` and applies **no per-user RLS filtering** beyond
+    /// restricting tenant-scoped callers to their tenant, so it is fail-closed: a request is
     /// rejected unless its table is explicitly allow-listed here via
     /// `with_bulk_export_tables`. Only allow-list tables whose full contents every
     /// `BulkExport`-capable client is permitted to read.
@@ -182,6 +221,47 @@ pub struct FraiseQLFlightService {
     /// `do_get` calls immediately return `Status::resource_exhausted` instead of
     /// queuing indefinitely. Default capacity: 50.
     pub(crate) stream_semaphore: Arc<Semaphore>,
+    /// Optional operation-level authorizer consulted for every `do_get` ticket.
+    ///
+    /// `None` (the default) authorizes every authenticated caller. Typically the same
+    /// authorizer the GraphQL executor runs, so a view or table a principal may not
+    /// query over HTTP cannot be fetched over Flight either.
+    pub(crate) authorizer: Option<Arc<dyn Authorizer>>,
+    /// Optional per-peer rate limit applied to every RPC before it runs.
+    ///
+    /// `None` (the default) admits every call; see
+    /// [`FraiseQLFlightService::with_rate_limiter`].
+    pub(crate) rate_limiter: Option<Arc<dyn FlightRateLimiter>>,
+    /// Column holding the tenant id on tenant-scoped views and bulk-export tables.
+    ///
+    /// Defaults to `tenant_id`; override with
+    /// [`FraiseQLFlightService::with_tenant_column`].
+    pub(crate) tenant_column: String,
+    /// Flight URIs advertised as endpoint locations by `GetFlightInfo`.
+    ///
+    /// Empty (the default) advertises no location, which Flight clients read as
+    /// "fetch from the service that answered". Set with
+    /// [`FraiseQLFlightService::with_advertised_locations`] when clients must reach the
+    /// data through another address (a load balancer, or several replicas).
+    pub(crate) advertised_locations: Vec<String>,
+    /// Memory budget of one `BulkExport`'s converted batches; beyond it, batches are
+    /// spilled to temporary Arrow IPC files (see
+    /// [`FraiseQLFlightService::with_export_memory_budget`]).
+    pub(crate) export_memory_budget: usize,
+    /// Directory for export spill files; the system temporary directory when `None`.
+    pub(crate) export_spill_dir: Option<std::path::PathBuf>,
+    /// Rows fetched per database round trip when an `OptimizedView` is streamed
+    /// straight from the database (see
+    /// [`FraiseQLFlightService::with_stream_fetch_rows`]).
+    pub(crate) stream_fetch_rows: usize,
+    /// Local `DuckDB` sink answering `Aggregate` tickets (see
+    /// [`FraiseQLFlightService::with_duckdb_sink`]).
+    #[cfg(feature = "duckdb_sink")]
+    pub(crate) duckdb_sink: Option<Arc<crate::duckdb_sink::DuckDbSink>>,
+    /// Object store `BulkExport` tickets with `format: "delta"` write their tables to
+    /// (see [`FraiseQLFlightService::with_lakehouse_store`]).
+    #[cfg(feature = "parquet")]
+    pub(crate) lakehouse_store: Option<Arc<dyn crate::export::delta::LakehouseStore>>,
 }
 
 /// Security context for authenticated Flight requests.
@@ -209,4 +289,8 @@ pub(crate) struct SessionTokenClaims {
     pub(crate) scopes:       Vec<String>,
     /// Session type marker
     pub(crate) session_type: String,
+    /// Custom claims of the original token (tenant, roles, ...), carried through the
+    /// handshake so the per-call security context matches the original principal
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub(crate) extra_claims: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -1,13 +1,16 @@
 //! `FraiseQLFlightService` construction and state management methods.
 
-use std::sync::Arc;
+use std::{panic::AssertUnwindSafe, sync::Arc};
 
 use arrow::array::RecordBatch;
 use arrow_flight::{FlightData, flight_service_server::FlightServiceServer};
 use chrono::Utc;
-use fraiseql_core::security::OidcValidator;
-use futures::Stream;
-use tokio::sync::{Semaphore, mpsc};
+use fraiseql_core::{
+    graphql::{FieldSelection, FragmentResolver, parse_query},
+    security::OidcValidator,
+};
+use futures::{FutureExt, Stream};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc};
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Response, Status};
 use tracing::{debug, info, warn};
@@ -15,19 +18,33 @@ use tracing::{debug, info, warn};
 #[cfg(any(test, feature = "testing"))]
 use super::execute_placeholder_query;
 use super::{
-    ActionResultStream, FlightDataStream, FraiseQLFlightService, QueryExecutor, SecurityContext,
-    build_optimized_sql, encode_json_to_arrow_batch, record_batch_to_flight_data,
-    schema_to_flight_data,
+    ActionResultStream, BatchStreamEncoder, FlightDataStream, FlightRateLimiter,
+    FraiseQLFlightService, QueryExecutor, SecurityContext, build_optimized_sql,
+    encode_json_to_arrow_batch, middleware::panic_status, record_batch_to_flight_data,
+    schema_to_flight_data, tenant_filter,
 };
 use crate::{
     cache::QueryCache,
-    convert::{ConvertConfig, RowToArrowConverter},
+    convert::{
+        ConvertConfig, RowToArrowConverter, dlq_items_to_batch, job_attempts_to_batch,
+        observer_events_to_batch,
+    },
     db::ArrowDatabaseAdapter,
-    db_convert::convert_db_rows_to_arrow,
+    db_convert::{
+        DEFAULT_BATCH_BYTE_BUDGET, MAX_BATCH_ROWS, adaptive_batch_size, convert_db_rows_to_arrow,
+        estimate_row_bytes, record_batch_size,
+    },
+    delivery_storage::{ArrowDeliveryStorage, DeliveryQuery},
     event_storage::ArrowEventStorage,
-    export::{BulkExporter, ExportFormat},
+    export::{
+        BulkExporter, ExportFormat,
+        spill::{DEFAULT_EXPORT_MEMORY_BUDGET, SpillingBatchBuffer},
+    },
     metadata::SchemaRegistry,
-    subscription::SubscriptionManager,
+    subscription::{SubscriptionManager, SubscriptionStreamConfig},
+    ticket::{
+        AggregateSpec, BatchEncoding, PartitionScheme, TicketPartition, partition_date_bounds,
+    },
 };
 
 /// Split converted rows into Arrow record batches of at most `batch_size` rows.
@@ -36,15 +53,103 @@ use crate::{
 /// that derives the batch size from a client ticket could pass `limit = 0`
 /// (audit H38, a remotely-triggerable abort in `do_get`). Routing every chunk
 /// loop through this helper makes a zero-sized chunk impossible at any site.
+///
+/// Every produced batch is recorded in the batch size metrics.
 fn chunk_into_batches(
     arrow_rows: &[Vec<Option<crate::convert::Value>>],
     converter: &RowToArrowConverter,
     batch_size: usize,
 ) -> std::result::Result<Vec<arrow::array::RecordBatch>, arrow::error::ArrowError> {
-    arrow_rows
-        .chunks(batch_size.max(1))
-        .map(|chunk| converter.convert_batch(chunk.to_vec()))
-        .collect()
+    let mut batches = Vec::with_capacity(arrow_rows.len().div_ceil(batch_size.max(1)));
+    for_each_batch(arrow_rows, converter, batch_size, |batch| {
+        batches.push(batch);
+        Ok(())
+    })?;
+    Ok(batches)
+}
+
+/// Convert `arrow_rows` batch by batch like [`chunk_into_batches`], handing each batch
+/// to `sink` as soon as it is built.
+fn for_each_batch(
+    arrow_rows: &[Vec<Option<crate::convert::Value>>],
+    converter: &RowToArrowConverter,
+    batch_size: usize,
+    mut sink: impl FnMut(RecordBatch) -> std::result::Result<(), arrow::error::ArrowError>,
+) -> std::result::Result<(), arrow::error::ArrowError> {
+    for chunk in arrow_rows.chunks(batch_size.max(1)) {
+        let bytes = chunk.iter().map(|row| estimate_row_bytes(row)).sum();
+        record_batch_size(chunk.len(), bytes);
+        sink(converter.convert_batch(chunk.to_vec())?)?;
+    }
+    Ok(())
+}
+
+/// Rows per record batch for `arrow_rows`: as many as fit the ticket's byte budget
+/// (or [`DEFAULT_BATCH_BYTE_BUDGET`]), at most `max_rows`.
+fn batch_size_for(
+    arrow_rows: &[Vec<Option<crate::convert::Value>>],
+    encoding: Option<&BatchEncoding>,
+    max_rows: usize,
+) -> usize {
+    let byte_budget = encoding
+        .and_then(|encoding| encoding.max_batch_bytes)
+        .unwrap_or(DEFAULT_BATCH_BYTE_BUDGET);
+    adaptive_batch_size(arrow_rows, byte_budget, max_rows)
+}
+
+/// Build the storage query of a `DlqItems` / `JobHistory` ticket.
+///
+/// Unlike `ObserverEvents`, an unparseable date is rejected instead of ignored: an
+/// export silently widened to the whole history is worse than an error.
+fn delivery_query(
+    action_type: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    limit: Option<usize>,
+) -> std::result::Result<DeliveryQuery, Status> {
+    let parse = |field: &str, value: Option<String>| {
+        value
+            .map(|s| {
+                chrono::DateTime::parse_from_rfc3339(&s)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|e| {
+                        Status::invalid_argument(format!("{field} is not an RFC 3339 date: {e}"))
+                    })
+            })
+            .transpose()
+    };
+    Ok(DeliveryQuery {
+        action_type,
+        start_date: parse("start_date", start_date)?,
+        end_date: parse("end_date", end_date)?,
+        limit,
+    })
+}
+
+/// Root field selections of `query` with fragment spreads inlined; empty when
+/// `query` does not parse (the executor has then already rejected it).
+fn root_selections(query: &str) -> Vec<FieldSelection> {
+    let Ok(parsed) = parse_query(query) else {
+        return Vec::new();
+    };
+    FragmentResolver::new(&parsed.fragments)
+        .resolve_spreads(&parsed.selections)
+        .unwrap_or_default()
+}
+
+/// Response keys (alias or name) of `selections`, in selection order and without
+/// duplicates. Inline fragments contribute their fields in place.
+fn response_keys(selections: &[FieldSelection]) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut pending: Vec<&FieldSelection> = selections.iter().rev().collect();
+    while let Some(selection) = pending.pop() {
+        if selection.name.starts_with("...") {
+            pending.extend(selection.nested_fields.iter().rev());
+        } else if !keys.iter().any(|key| key == selection.response_key()) {
+            keys.push(selection.response_key().to_string());
+        }
+    }
+    keys
 }
 
 /// Read `FLIGHT_SESSION_SECRET` from the environment once.
@@ -77,6 +182,9 @@ fn read_flight_session_secret() -> Option<String> {
 /// `Status::resource_exhausted` rather than being queued indefinitely.
 const DEFAULT_MAX_CONCURRENT_STREAMS: usize = 50;
 
+/// Default column holding the tenant id on tenant-scoped views and tables.
+const DEFAULT_TENANT_COLUMN: &str = "tenant_id";
+
 /// Channel buffer size for the per-stream `FlightData` producer task (F011).
 ///
 /// Each in-flight Arrow Flight stream uses a bounded `mpsc` channel between the
@@ -85,6 +193,14 @@ const DEFAULT_MAX_CONCURRENT_STREAMS: usize = 50;
 /// the encoder run a few batches ahead of the network.
 const FLIGHT_DATA_CHANNEL_BUFFER: usize = 4;
 
+/// Default number of rows fetched per database round trip when an `OptimizedView` is
+/// streamed straight from the database.
+const DEFAULT_STREAM_FETCH_ROWS: usize = 5_000;
+
+/// Fetched chunks queued between the database fetch and the `FlightData` producer of a
+/// streamed `OptimizedView`: the next chunk is fetched while the current one is sent.
+const FETCH_CHUNK_BUFFER: usize = 1;
+
 /// Spawn a `FlightData` producer task that encodes `RecordBatch`es into
 /// `FlightData` messages and sends them through a bounded channel.
 ///
@@ -96,6 +212,9 @@ const FLIGHT_DATA_CHANNEL_BUFFER: usize = 4;
 /// The encoder is `FnOnce` because the producer task takes ownership of any
 /// captured state (batches, schema, exporter) and drops it when the stream
 /// ends.
+///
+/// A panicking producer ends the stream with `INTERNAL`: without it the channel
+/// would just close, and the client would take a truncated result for a complete one.
 fn spawn_flight_data_stream<F, Fut>(
     producer: F,
 ) -> impl Stream<Item = std::result::Result<FlightData, Status>>
@@ -105,10 +224,45 @@ where
 {
     let (tx, rx) =
         mpsc::channel::<std::result::Result<FlightData, Status>>(FLIGHT_DATA_CHANNEL_BUFFER);
-    tokio::spawn(producer(tx));
+    let panic_tx = tx.clone();
+    tokio::spawn(async move {
+        if let Err(payload) = AssertUnwindSafe(producer(tx)).catch_unwind().await {
+            let status = panic_status("do_get", &*payload);
+            let _ = panic_tx.send(Err(status)).await;
+        }
+    });
     ReceiverStream::new(rx)
 }
 
+/// Encode `batch` — preceded by its dictionary messages when a ticket-requested
+/// `encoder` is set — and send it to `tx`.
+///
+/// Returns `false` once the stream should stop: the client went away, or encoding
+/// failed (the error has then been sent).
+async fn send_batch(
+    tx: &mpsc::Sender<std::result::Result<FlightData, Status>>,
+    encoder: Option<&mut BatchStreamEncoder>,
+    batch: &RecordBatch,
+) -> bool {
+    let messages = match encoder {
+        Some(encoder) => encoder.batch_messages(batch),
+        None => record_batch_to_flight_data(batch).map(|msg| vec![msg]),
+    };
+    let messages = match messages {
+        Ok(messages) => messages,
+        Err(status) => {
+            let _ = tx.send(Err(status)).await;
+            return false;
+        },
+    };
+    for msg in messages {
+        if tx.send(Ok(msg)).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
 impl FraiseQLFlightService {
     /// Create a new Flight service with placeholder data (for testing/development).
     #[must_use]
@@ -124,11 +278,24 @@ impl FraiseQLFlightService {
             security_context: None,
             oidc_validator: None,
             event_storage: None,
+            delivery_storage: None,
             subscription_manager: Arc::new(SubscriptionManager::new()),
+            subscription_stream: SubscriptionStreamConfig::default(),
             allow_raw_sql: false,
             bulk_export_allowed_tables: None,
             session_secret: read_flight_session_secret(),
             stream_semaphore: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_STREAMS)),
+            authorizer: None,
+            rate_limiter: None,
+            tenant_column: DEFAULT_TENANT_COLUMN.to_string(),
+            advertised_locations: Vec::new(),
+            export_memory_budget: DEFAULT_EXPORT_MEMORY_BUDGET,
+            export_spill_dir: None,
+            stream_fetch_rows: DEFAULT_STREAM_FETCH_ROWS,
+            #[cfg(feature = "duckdb_sink")]
+            duckdb_sink: None,
+            #[cfg(feature = "parquet")]
+            lakehouse_store: None,
         }
     }
 
@@ -173,11 +340,24 @@ impl FraiseQLFlightService {
             security_context: None,
             oidc_validator: None,
             event_storage: None,
+            delivery_storage: None,
             subscription_manager: Arc::new(SubscriptionManager::new()),
+            subscription_stream: SubscriptionStreamConfig::default(),
             allow_raw_sql: false,
             bulk_export_allowed_tables: None,
             session_secret: read_flight_session_secret(),
             stream_semaphore: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_STREAMS)),
+            authorizer: None,
+            rate_limiter: None,
+            tenant_column: DEFAULT_TENANT_COLUMN.to_string(),
+            advertised_locations: Vec::new(),
+            export_memory_budget: DEFAULT_EXPORT_MEMORY_BUDGET,
+            export_spill_dir: None,
+            stream_fetch_rows: DEFAULT_STREAM_FETCH_ROWS,
+            #[cfg(feature = "duckdb_sink")]
+            duckdb_sink: None,
+            #[cfg(feature = "parquet")]
+            lakehouse_store: None,
         }
     }
 
@@ -220,11 +400,24 @@ impl FraiseQLFlightService {
             security_context: None,
             oidc_validator: None,
             event_storage: None,
+            delivery_storage: None,
             subscription_manager: Arc::new(SubscriptionManager::new()),
+            subscription_stream: SubscriptionStreamConfig::default(),
             allow_raw_sql: false,
             bulk_export_allowed_tables: None,
             session_secret: read_flight_session_secret(),
             stream_semaphore: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_STREAMS)),
+            authorizer: None,
+            rate_limiter: None,
+            tenant_column: DEFAULT_TENANT_COLUMN.to_string(),
+            advertised_locations: Vec::new(),
+            export_memory_budget: DEFAULT_EXPORT_MEMORY_BUDGET,
+            export_spill_dir: None,
+            stream_fetch_rows: DEFAULT_STREAM_FETCH_ROWS,
+            #[cfg(feature = "duckdb_sink")]
+            duckdb_sink: None,
+            #[cfg(feature = "parquet")]
+            lakehouse_store: None,
         }
     }
 
@@ -303,11 +496,24 @@ impl FraiseQLFlightService {
             security_context: None,
             oidc_validator: Some(oidc_validator),
             event_storage: None,
+            delivery_storage: None,
             subscription_manager: Arc::new(SubscriptionManager::new()),
+            subscription_stream: SubscriptionStreamConfig::default(),
             allow_raw_sql: false,
             bulk_export_allowed_tables: None,
             session_secret: Some(session_secret),
             stream_semaphore: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_STREAMS)),
+            authorizer: None,
+            rate_limiter: None,
+            tenant_column: DEFAULT_TENANT_COLUMN.to_string(),
+            advertised_locations: Vec::new(),
+            export_memory_budget: DEFAULT_EXPORT_MEMORY_BUDGET,
+            export_spill_dir: None,
+            stream_fetch_rows: DEFAULT_STREAM_FETCH_ROWS,
+            #[cfg(feature = "duckdb_sink")]
+            duckdb_sink: None,
+            #[cfg(feature = "parquet")]
+            lakehouse_store: None,
         }
     }
 
@@ -391,8 +597,10 @@ impl FraiseQLFlightService {
     ///
     /// **SECURITY WARNING**: `BulkExport` runs `SELECT * FROM "<table>"` and applies **no
     /// per-user RLS filtering** — every `BulkExport`-capable client receives the table's
-    /// full contents. `BulkExport` is disabled by default (the allow-list is `None`); call
-    /// this only for tables whose entire contents those clients are permitted to read.
+    /// full contents (a tenant-scoped client: every row of its tenant, so allow-listed
+    /// tables must carry the tenant column). `BulkExport` is disabled by default (the
+    /// allow-list is `None`); call this only for tables whose entire contents those
+    /// clients are permitted to read.
     #[must_use]
     pub fn with_bulk_export_tables<I, S>(mut self, tables: I) -> Self
     where
@@ -408,6 +616,121 @@ impl FraiseQLFlightService {
         self
     }
 
+    /// Authorize every `do_get` ticket with an operation-level [`Authorizer`].
+    ///
+    /// Each ticket is presented as a `Query` operation named after the view, table or
+    /// entity type it reads (`BatchedQueries` tickets as `"BatchedQueries"`), with
+    /// `{"flightTicket": "<kind>"}` as input. `GraphQLQuery` tickets are authorized by the
+    /// executor itself. Any deny or policy error rejects the call with
+    /// `PERMISSION_DENIED`.
+    ///
+    /// [`Authorizer`]: fraiseql_core::security::Authorizer
+    #[must_use]
+    pub fn with_authorizer(
+        mut self,
+        authorizer: Arc<dyn fraiseql_core::security::Authorizer>,
+    ) -> Self {
+        self.authorizer = Some(authorizer);
+        self
+    }
+
+    /// Rate limit every RPC by the calling peer.
+    ///
+    /// The limiter is consulted before the handler runs, with the user the call's bearer
+    /// token authenticates and its remote address (see [`FlightPeer`]). A refused call
+    /// fails with `RESOURCE_EXHAUSTED`.
+    ///
+    /// [`FlightPeer`]: super::FlightPeer
+    #[must_use]
+    pub fn with_rate_limiter(mut self, limiter: Arc<dyn FlightRateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Set the column that holds the tenant id on tenant-scoped views and tables.
+    ///
+    /// When the caller's security context carries a tenant, `OptimizedView` tickets on
+    /// views exposing this column and every `BulkExport` are restricted to rows whose
+    /// column equals the caller's tenant. Defaults to `tenant_id`.
+    #[must_use]
+    pub fn with_tenant_column(mut self, column: impl Into<String>) -> Self {
+        self.tenant_column = column.into();
+        self
+    }
+
+    /// Advertise these Flight URIs (e.g. `grpc+tls://flight.example.com:50051`) as the
+    /// locations of every endpoint returned by `GetFlightInfo`.
+    ///
+    /// By default no location is advertised, so clients fetch from the service that
+    /// answered `GetFlightInfo`.
+    #[must_use]
+    pub fn with_advertised_locations<I, S>(mut self, locations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.advertised_locations = locations.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Bound the memory a `BulkExport` holds in converted batches to `bytes`
+    /// (256 MiB by default).
+    ///
+    /// Once the budget is exceeded the buffered batches are spilled to temporary Arrow
+    /// IPC files and streamed back from disk after the in-memory conversion finishes.
+    #[must_use]
+    pub const fn with_export_memory_budget(mut self, bytes: usize) -> Self {
+        self.export_memory_budget = bytes;
+        self
+    }
+
+    /// Fetch `rows` rows per database round trip when streaming an `OptimizedView`
+    /// (5 000 by default, floored at 1).
+    ///
+    /// Without a result cache, `OptimizedView` tickets are streamed straight from the
+    /// database and the next chunk is only fetched once the client has taken the
+    /// previous one, so this bounds the rows a slow client can hold in memory.
+    #[must_use]
+    pub const fn with_stream_fetch_rows(mut self, rows: usize) -> Self {
+        self.stream_fetch_rows = if rows == 0 { 1 } else { rows };
+        self
+    }
+
+    /// Write `BulkExport` spill files to `dir` instead of the system temporary directory.
+    #[must_use]
+    pub fn with_export_spill_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.export_spill_dir = Some(dir.into());
+        self
+    }
+
+    /// Serve `Aggregate` tickets from a local `DuckDB` sink.
+    ///
+    /// The sink's tables are reachable by any caller the authorizer admits for the
+    /// table name, so only materialize data every such caller may see (tenant-scoped
+    /// callers are restricted to their own rows).
+    #[cfg(feature = "duckdb_sink")]
+    #[must_use]
+    pub fn with_duckdb_sink(mut self, sink: Arc<crate::duckdb_sink::DuckDbSink>) -> Self {
+        self.duckdb_sink = Some(sink);
+        self
+    }
+
+    /// Write `BulkExport` tickets with `format: "delta"` as Delta Lake tables in
+    /// `store`.
+    ///
+    /// Each export appends to the table named after the exported table (relative to
+    /// the store root) and streams back the commit summary. Without a store, Delta
+    /// exports are rejected.
+    #[cfg(feature = "parquet")]
+    #[must_use]
+    pub fn with_lakehouse_store(
+        mut self,
+        store: Arc<dyn crate::export::delta::LakehouseStore>,
+    ) -> Self {
+        self.lakehouse_store = Some(store);
+        self
+    }
+
     /// Set the query executor for GraphQL query execution.
     ///
     /// The executor must be passed as `Arc<Executor<A>>` wrapped in Arc for shared ownership.
@@ -489,12 +812,53 @@ impl FraiseQLFlightService {
         self.event_storage.is_some()
     }
 
+    /// Set the delivery storage backing `DlqItems` and `JobHistory` tickets.
+    ///
+    /// `fraiseql-observers` provides one over its dead letter queue and job queue
+    /// (`arrow_bridge::DeliveryHistoryStorage`).
+    pub fn set_delivery_storage(&mut self, delivery_storage: Arc<dyn ArrowDeliveryStorage>) {
+        self.delivery_storage = Some(delivery_storage);
+    }
+
+    /// Get a reference to the delivery storage, if set.
+    #[must_use]
+    pub fn delivery_storage(&self) -> Option<&Arc<dyn ArrowDeliveryStorage>> {
+        self.delivery_storage.as_ref()
+    }
+
+    /// Check if delivery storage is configured for DLQ and job history exports.
+    ///
+    /// Returns true if delivery storage has been set via `set_delivery_storage()`.
+    #[must_use]
+    pub fn has_delivery_storage(&self) -> bool {
+        self.delivery_storage.is_some()
+    }
+
+    /// Get a reference to the query result cache, if caching is enabled.
+    ///
+    /// Share it with the observer Arrow bridge so entity changes evict cached results
+    /// (see [`QueryCache::invalidate_entity`]).
+    #[must_use]
+    pub fn cache(&self) -> Option<&Arc<QueryCache>> {
+        self.cache.as_ref()
+    }
+
     /// Get a reference to the subscription manager for real-time event subscriptions.
     #[must_use]
     pub const fn subscription_manager(&self) -> &Arc<SubscriptionManager> {
         &self.subscription_manager
     }
 
+    /// Set the delivery settings for `Subscription` tickets streamed over `DoGet`.
+    #[must_use]
+    pub const fn with_subscription_stream_config(
+        mut self,
+        config: SubscriptionStreamConfig,
+    ) -> Self {
+        self.subscription_stream = config;
+        self
+    }
+
     /// Check if service has authenticated security context.
     ///
     /// Returns true if handshake was successful and security context is set.
@@ -555,6 +919,10 @@ impl FraiseQLFlightService {
     /// 3. Call `executor.execute_with_security(query, variables, &security_context).await`
     /// 4. Convert JSON to Arrow `RecordBatches`
     ///
+    /// The executor pushes the selection set down to SQL, so each row only carries the
+    /// selected keys and the inferred schema has one column per selected field, in
+    /// selection-set order.
+    ///
     /// **Result Streaming**:
     /// 1. Schema message (first)
     /// 2. Data batches (`RecordBatch` messages)
@@ -582,21 +950,22 @@ impl FraiseQLFlightService {
 
             // Convert JSON to Arrow RecordBatches
             let batches = self
-                .convert_json_to_arrow_batches(&parsed)
+                .convert_json_to_arrow_batches(&parsed, query)
                 .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))?;
 
             // Encode the schema header eagerly (must precede the batches so the
-            // client can decode the rest of the stream).
-            let schema_header =
-                batches.first().map(|b| schema_to_flight_data(&b.schema())).transpose()?;
+            // client can decode the rest of the stream). An empty page still sends
+            // one, with no columns, so clients read zero rows.
+            let schema_header = match batches.first() {
+                Some(batch) => schema_to_flight_data(&batch.schema())?,
+                None => schema_to_flight_data(&Arc::new(arrow::datatypes::Schema::empty()))?,
+            };
 
             // Stream the batches through a bounded mpsc channel so the encode
             // step pauses when the consumer is slow (F011 backpressure).
             let stream = spawn_flight_data_stream(move |tx| async move {
-                if let Some(header) = schema_header {
-                    if tx.send(Ok(header)).await.is_err() {
-                        return;
-                    }
+                if tx.send(Ok(schema_header)).await.is_err() {
+                    return;
                 }
                 for batch in batches {
                     let msg = record_batch_to_flight_data(&batch);
@@ -622,70 +991,71 @@ impl FraiseQLFlightService {
     /// Convert a GraphQL JSON result to Arrow `RecordBatch`es.
     ///
     /// Handles the standard GraphQL response envelope `{"data": {...}}`.
-    /// Finds the first field inside `data` that is a non-empty array of objects,
-    /// infers an Arrow schema from the first row, and converts all rows to columnar
-    /// Arrow format.
+    /// Converts the first list inside `data` — the first root field of `query` holding
+    /// one — so the rows keep the order the executor produced them in (`orderBy`,
+    /// `limit`, `offset` are applied there, exactly as for HTTP). The Arrow schema is
+    /// inferred from the first row and its columns follow the field's selection set
+    /// in `query` (fragments inlined); keys the selection does not name (or every key,
+    /// when `query` does not parse) follow in the row's order. An empty list (e.g. an
+    /// `offset` past the last row) yields no batches.
     ///
     /// Falls back to wrapping the entire JSON as a single `result` string column when:
-    /// - The result is a scalar (no array of objects found)
-    /// - The `data` field contains only non-array values
+    /// - The result is a scalar (no list found)
+    /// - The `data` field contains only non-list values
+    /// - The list holds no objects
     ///
     /// # Errors
     ///
-    /// Returns an error string if schema inference or Arrow conversion fails.
+    /// Returns an error string if Arrow conversion fails.
     fn convert_json_to_arrow_batches(
         &self,
         json: &serde_json::Value,
+        query: &str,
     ) -> Result<Vec<RecordBatch>, String> {
         // Extract the data payload from a GraphQL response envelope.
         // Typical structure: {"data": {"field": [...]}, "errors": [...]}.
         let data = json.get("data").unwrap_or(json);
+        let roots = root_selections(query);
 
-        // Find a non-empty array of objects to convert to columnar Arrow format.
-        let rows: Vec<std::collections::HashMap<String, serde_json::Value>> = match data {
-            serde_json::Value::Array(arr) => arr
+        // The top-level array itself, or the first root field holding one.
+        let (list, columns) = match data {
+            serde_json::Value::Array(arr) => {
+                (Some(arr), roots.first().map(|root| response_keys(&root.nested_fields)))
+            },
+            serde_json::Value::Object(map) => roots
                 .iter()
-                .filter_map(|v| {
-                    v.as_object()
-                        .map(|obj| obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
-                })
-                .collect(),
-            serde_json::Value::Object(map) => map
-                .values()
-                .find_map(|v| {
-                    if let serde_json::Value::Array(arr) = v {
-                        let converted: Vec<_> = arr
-                            .iter()
-                            .filter_map(|item| {
-                                item.as_object().map(|obj| {
-                                    obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
-                                })
-                            })
-                            .collect();
-                        (!converted.is_empty()).then_some(converted)
-                    } else {
-                        None
-                    }
+                .find_map(|root| {
+                    let arr = map.get(root.response_key())?.as_array()?;
+                    Some((Some(arr), Some(response_keys(&root.nested_fields))))
                 })
-                .unwrap_or_default(),
-            _ => vec![],
+                .unwrap_or_else(|| (map.values().find_map(serde_json::Value::as_array), None)),
+            _ => (None, None),
+        };
+        let objects: Vec<&serde_json::Map<String, serde_json::Value>> = match list {
+            Some(arr) if arr.is_empty() => return Ok(Vec::new()),
+            Some(arr) => arr.iter().filter_map(serde_json::Value::as_object).collect(),
+            None => Vec::new(),
         };
 
-        if rows.is_empty() {
+        let Some(first) = objects.first() else {
             // No columnar data found — wrap the entire JSON as a single string column.
             // This handles scalar results and error-only responses gracefully.
             return encode_json_to_arrow_batch(&json.to_string()).map(|b| vec![b]);
-        }
+        };
 
         // Infer Arrow schema from the first row, then convert all rows.
-        let schema = crate::schema_gen::infer_schema_from_rows(&rows)
-            .map_err(|e| format!("Schema inference failed: {e}"))?;
+        let schema =
+            crate::schema_gen::infer_schema_from_object(first, &columns.unwrap_or_default());
+        let rows: Vec<std::collections::HashMap<String, serde_json::Value>> = objects
+            .iter()
+            .map(|obj| obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+            .collect();
 
         let arrow_rows = convert_db_rows_to_arrow(&rows, &schema)
             .map_err(|e| format!("Row conversion failed: {e}"))?;
 
         let config = ConvertConfig {
-            batch_size: 10_000,
+            batch_size: batch_size_for(&arrow_rows, None, MAX_BATCH_ROWS),
             max_rows:   None,
         };
         let converter = RowToArrowConverter::new(schema, config);
@@ -698,15 +1068,15 @@ impl FraiseQLFlightService {
     /// Uses pre-compiled Arrow schemas, eliminating runtime type inference.
     /// Results are cached if caching is enabled.
     ///
-    /// # Security — no per-user RLS filtering
+    /// # Security — tenant scoping only
     ///
     /// This path executes the built SQL directly against the raw database adapter
-    /// (`db_adapter.execute_raw_query`); it does **not** run the RLS-aware query executor,
-    /// so **no per-user row-level filtering is applied** here. `security_context` is used
-    /// for audit logging only. Any row scoping must be enforced by the underlying `va_*`
-    /// view itself (e.g. a view that filters on a session/tenant setting), not by this
-    /// method. Do not expose `va_*` views over Flight that depend on FraiseQL's
-    /// application-level RLS for isolation.
+    /// (`db_adapter.execute_raw_query`); it does **not** run the RLS-aware query executor.
+    /// The only row filter applied here is tenant scoping: when the caller carries a
+    /// tenant and the view exposes the service's tenant column, rows are restricted to
+    /// that tenant. Any other row scoping must be enforced by the underlying `va_*` view
+    /// itself. Do not expose `va_*` views over Flight that depend on FraiseQL's
+    /// application-level RLS policies (beyond tenancy) for isolation.
     ///
     /// # Arguments
     ///
@@ -715,7 +1085,9 @@ impl FraiseQLFlightService {
     /// * `order_by` - Optional ORDER BY clause
     /// * `limit` - Optional LIMIT
     /// * `offset` - Optional OFFSET for pagination
-    /// * `security_context` - User's security context (audit logging only; not an RLS filter)
+    /// * `encoding` - Optional dictionary encoding / IPC compression of the streamed batches
+    /// * `security_context` - User's security context; a tenant-scoped caller only sees rows of its
+    ///   own tenant when the view exposes the service's tenant column
     ///
     /// # Implementation Status
     ///
@@ -723,6 +1095,7 @@ impl FraiseQLFlightService {
     /// - Pre-load and cache pre-compiled Arrow schemas from metadata
     /// - Schema optimization with registry
     /// - Database adapter for real data execution (fallback to placeholder if not configured)
+    #[allow(clippy::too_many_arguments)] // Reason: mirrors the OptimizedView ticket fields plus the caller's context
     pub(crate) async fn execute_optimized_view(
         &self,
         view: &str,
@@ -730,9 +1103,9 @@ impl FraiseQLFlightService {
         order_by: Option<String>,
         limit: Option<usize>,
         offset: Option<usize>,
+        encoding: Option<BatchEncoding>,
         security_context: &fraiseql_core::security::SecurityContext,
-    ) -> std::result::Result<impl Stream<Item = std::result::Result<FlightData, Status>>, Status>
-    {
+    ) -> std::result::Result<FlightDataStream, Status> {
         // Reject a zero limit fail-loud: it is a meaningless request and
         // previously produced a zero-sized batch chunk (`chunks(0)` panics).
         // The clamp at the batch-size site below is defence-in-depth (H38).
@@ -747,26 +1120,45 @@ impl FraiseQLFlightService {
             .map_err(|e| Status::not_found(format!("Schema not found for view {view}: {e}")))?;
 
         // 2. Build optimized SQL query (validates filter/order_by for injection safety).
-        let sql = build_optimized_sql(view, filter, order_by, limit, offset)?;
+        // Views exposing the tenant column are restricted to the caller's tenant; the
+        // tenant literal is part of the SQL, so cached results never cross tenants.
+        let tenant_predicate = if schema.field_with_name(&self.tenant_column).is_ok() {
+            tenant_filter(&self.tenant_column, security_context)
+        } else {
+            None
+        };
+        let sql = build_optimized_sql(
+            view,
+            filter,
+            tenant_predicate.as_deref(),
+            order_by,
+            limit,
+            offset,
+        )?;
         info!(
             user_id = %security_context.user_id,
-            "Executing optimized view (no per-user RLS on this path): {}",
+            tenant_scoped = tenant_predicate.is_some(),
+            "Executing optimized view: {}",
             sql
         );
 
-        // 3. Check cache before executing query
-        let db_rows = if let Some(cache) = &self.cache {
-            if let Some(cached_result) = cache.get(&sql) {
-                debug!("Cache hit for query: {}", sql);
-                (*cached_result).clone()
-            } else {
-                // Cache miss: execute query and cache result
-                let result = self.execute_raw_query_and_cache(&sql).await?;
-                result
+        // Without a result cache there is nothing to keep the full result for, so the
+        // view is streamed straight from the database with flow control.
+        let max_rows = limit.unwrap_or(MAX_BATCH_ROWS).clamp(1, MAX_BATCH_ROWS);
+        if self.cache.is_none() {
+            if let Some(db) = &self.db_adapter {
+                return self.stream_view_from_database(db, sql, schema, max_rows, encoding);
             }
+        }
+
+        // 3. Serve the converted batches from the cache when possible. Entries are
+        // tagged with the view so entity changes evict them.
+        let cached = self.cache.as_ref().and_then(|cache| cache.get_batches(&sql));
+        let batches = if let Some(batches) = cached {
+            debug!("Cache hit for query: {}", sql);
+            batches
         } else {
-            // No cache: execute query normally
-            if let Some(db) = &self.db_adapter {
+            let db_rows = if let Some(db) = &self.db_adapter {
                 db.execute_raw_query(&sql)
                     .await
                     .map_err(|e| Status::internal(format!("Database query failed: {e}")))?
@@ -783,23 +1175,31 @@ impl FraiseQLFlightService {
                          for development use.",
                     ));
                 }
-            }
-        };
+            };
 
-        // 4. Convert database rows to Arrow Values
-        let arrow_rows = convert_db_rows_to_arrow(&db_rows, &schema)
-            .map_err(|e| Status::internal(format!("Row conversion failed: {e}")))?;
+            // 4. Convert database rows to Arrow Values
+            let arrow_rows = convert_db_rows_to_arrow(&db_rows, &schema)
+                .map_err(|e| Status::internal(format!("Row conversion failed: {e}")))?;
 
-        // 5. Convert to RecordBatches. Clamp the client-derived batch size to
-        // [1, 10_000] so it can never be zero (defence-in-depth for H38).
-        let config = ConvertConfig {
-            batch_size: limit.unwrap_or(10_000).clamp(1, 10_000),
-            max_rows:   limit,
-        };
-        let converter = RowToArrowConverter::new(schema.clone(), config);
+            // 5. Convert to RecordBatches sized to the ticket's byte budget. The
+            // client-derived row cap is clamped to [1, MAX_BATCH_ROWS] above so it can
+            // never be zero (defence-in-depth for H38). Cached batches keep the size of
+            // the ticket that filled the cache.
+            let config = ConvertConfig {
+                batch_size: batch_size_for(&arrow_rows, encoding.as_ref(), max_rows),
+                max_rows:   limit,
+            };
+            let converter = RowToArrowConverter::new(schema.clone(), config);
 
-        let batches = chunk_into_batches(&arrow_rows, &converter, config.batch_size)
-            .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))?;
+            let batches = Arc::new(
+                chunk_into_batches(&arrow_rows, &converter, config.batch_size)
+                    .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))?,
+            );
+            if let Some(cache) = &self.cache {
+                cache.put_batches(sql.clone(), Arc::clone(&batches), &[view]);
+            }
+            batches
+        };
 
         info!("Generated {} Arrow batches", batches.len());
 
@@ -807,19 +1207,94 @@ impl FraiseQLFlightService {
         // Schema header is encoded eagerly so any encoding error surfaces
         // before the stream starts; data batches stream through a bounded
         // channel so the encode step backpressures on the consumer (F011).
-        let schema_message = schema_to_flight_data(&schema)?;
+        // A ticket-requested encoding shares one dictionary tracker across the stream,
+        // so each batch is preceded by its dictionary messages.
+        let mut encoder = encoding.as_ref().map(BatchStreamEncoder::new).transpose()?;
+        let schema_message = match encoder.as_mut() {
+            Some(encoder) => encoder.schema_message(&schema),
+            None => schema_to_flight_data(&schema)?,
+        };
         let stream = spawn_flight_data_stream(move |tx| async move {
             if tx.send(Ok(schema_message)).await.is_err() {
                 return;
             }
-            for batch in batches {
-                let msg = record_batch_to_flight_data(&batch);
-                if tx.send(msg).await.is_err() {
+            for batch in batches.as_slice() {
+                if !send_batch(&tx, encoder.as_mut(), batch).await {
                     return;
                 }
             }
         });
-        Ok(stream)
+        Ok(Box::pin(stream))
+    }
+
+    /// Stream an `OptimizedView` query straight from the database.
+    ///
+    /// The query runs in its own task and hands over chunks of `stream_fetch_rows`
+    /// rows through a one-slot channel; the producer converts and sends each chunk
+    /// before taking the next. When the client stops reading, the bounded `FlightData`
+    /// channel fills, the producer parks on its send, the chunk channel fills and the
+    /// database fetch pauses — so a slow client holds at most a few chunks in memory.
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    fn stream_view_from_database(
+        &self,
+        db: &Arc<dyn ArrowDatabaseAdapter>,
+        sql: String,
+        schema: arrow::datatypes::SchemaRef,
+        max_rows: usize,
+        encoding: Option<BatchEncoding>,
+    ) -> std::result::Result<FlightDataStream, Status> {
+        let mut encoder = encoding.as_ref().map(BatchStreamEncoder::new).transpose()?;
+        let schema_message = match encoder.as_mut() {
+            Some(encoder) => encoder.schema_message(&schema),
+            None => schema_to_flight_data(&schema)?,
+        };
+        let db = Arc::clone(db);
+        let fetch_rows = self.stream_fetch_rows;
+
+        let stream = spawn_flight_data_stream(move |tx| async move {
+            if tx.send(Ok(schema_message)).await.is_err() {
+                return;
+            }
+            let (chunk_tx, mut chunk_rx) = mpsc::channel(FETCH_CHUNK_BUFFER);
+            let fetch = tokio::spawn(async move {
+                db.execute_raw_query_chunked(&sql, fetch_rows, chunk_tx).await
+            });
+
+            while let Some(rows) = chunk_rx.recv().await {
+                let batches = convert_db_rows_to_arrow(&rows, &schema)
+                    .map_err(|e| Status::internal(format!("Row conversion failed: {e}")))
+                    .and_then(|arrow_rows| {
+                        let config = ConvertConfig {
+                            batch_size: batch_size_for(&arrow_rows, encoding.as_ref(), max_rows),
+                            max_rows:   None,
+                        };
+                        let converter = RowToArrowConverter::new(schema.clone(), config);
+                        chunk_into_batches(&arrow_rows, &converter, config.batch_size)
+                            .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))
+                    });
+                let batches = match batches {
+                    Ok(batches) => batches,
+                    Err(status) => {
+                        let _ = tx.send(Err(status)).await;
+                        return;
+                    },
+                };
+                // Returning drops `chunk_rx`, which stops the database fetch.
+                for batch in &batches {
+                    if !send_batch(&tx, encoder.as_mut(), batch).await {
+                        return;
+                    }
+                }
+            }
+
+            let failure = match fetch.await {
+                Ok(Ok(())) => return,
+                Ok(Err(e)) => Status::internal(format!("Database query failed: {e}")),
+                Err(e) => Status::internal(format!("Database fetch task failed: {e}")),
+            };
+            let _ = tx.send(Err(failure)).await;
+        });
+        Ok(Box::pin(stream))
     }
 
     /// Execute raw query and cache the result if caching is enabled.
@@ -877,6 +1352,13 @@ impl FraiseQLFlightService {
             ));
         }
 
+        // Raw SQL cannot be scoped to a tenant, so tenant-scoped principals never get it.
+        if security_context.tenant_id.is_some() {
+            return Err(Status::permission_denied(
+                "BatchedQueries raw SQL is not available to tenant-scoped principals",
+            ));
+        }
+
         if queries.is_empty() {
             return Err(Status::invalid_argument("BatchedQueries must contain at least one query"));
         }
@@ -929,7 +1411,7 @@ impl FraiseQLFlightService {
 
             // Convert to RecordBatches
             let config = ConvertConfig {
-                batch_size: 10_000,
+                batch_size: batch_size_for(&arrow_rows, None, MAX_BATCH_ROWS),
                 max_rows:   None,
             };
             let converter = RowToArrowConverter::new(inferred_schema.clone(), config);
@@ -975,12 +1457,22 @@ impl FraiseQLFlightService {
     /// * `start_date` - Optional ISO 8601 start date
     /// * `end_date` - Optional ISO 8601 end date
     /// * `limit` - Optional maximum number of events
+    /// * `partition` - Optional partition of the ticket to serve; only its date window or entity
+    ///   bucket is read from storage, and `limit` applies per partition
+    /// * `encoding` - When set, events are streamed as one Arrow record batch with the observer
+    ///   event schema, encoded as requested, instead of a single JSON message
+    /// * `security_context` - Caller's security context; a tenant-scoped caller only sees events of
+    ///   its own tenant
+    #[allow(clippy::too_many_arguments)] // Reason: mirrors the ObserverEvents ticket fields plus the caller's context
     pub(crate) async fn execute_observer_events(
         &self,
         entity_type: &str,
         start_date: Option<String>,
         end_date: Option<String>,
         limit: Option<usize>,
+        partition: Option<TicketPartition>,
+        encoding: Option<BatchEncoding>,
+        security_context: &fraiseql_core::security::SecurityContext,
     ) -> std::result::Result<Response<FlightDataStream>, Status> {
         // Check if event storage is configured
         let event_storage = self.event_storage.as_ref().ok_or_else(|| {
@@ -1000,11 +1492,61 @@ impl FraiseQLFlightService {
             .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
             .map(|dt| dt.with_timezone(&Utc));
 
-        // Query events from storage
-        let events = event_storage
-            .query_events(entity_type, start, end, limit)
-            .await
-            .map_err(|e| Status::internal(format!("Failed to query events: {}", e)))?;
+        // Query events from storage, pruned to the requested partition
+        let mut events = match partition {
+            None => event_storage.query_events(entity_type, start, end, limit).await,
+            Some(partition) => {
+                partition.validate().map_err(|e| Status::invalid_argument(e.to_string()))?;
+                let index = partition.index.ok_or_else(|| {
+                    Status::invalid_argument(
+                        "Partitioned ObserverEvents ticket has no partition index; \
+                         fetch the partition tickets with GetFlightInfo",
+                    )
+                })?;
+                match partition.scheme {
+                    PartitionScheme::DateRange => {
+                        let (start, end) =
+                            partition_date_bounds(start_date.as_deref(), end_date.as_deref())
+                                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+                        let (lo, hi) = partition.date_window(start, end).ok_or_else(|| {
+                            Status::invalid_argument(
+                                "DateRange partitioning requires start_date <= end_date",
+                            )
+                        })?;
+                        // Storage ranges are inclusive; every window but the last excludes
+                        // its upper bound, which belongs to the next partition.
+                        event_storage
+                            .query_events(entity_type, Some(lo), Some(hi), limit)
+                            .await
+                            .map(|mut events| {
+                                if !partition.is_last() {
+                                    events.retain(|event| event.timestamp < hi);
+                                }
+                                events
+                            })
+                    },
+                    PartitionScheme::EntityHash => {
+                        event_storage
+                            .query_events_in_bucket(
+                                entity_type,
+                                start,
+                                end,
+                                limit,
+                                index,
+                                partition.count,
+                            )
+                            .await
+                    },
+                }
+            },
+        }
+        .map_err(|e| Status::internal(format!("Failed to query events: {}", e)))?;
+
+        // Events without a tenant are global and stay visible; events of another tenant
+        // are dropped.
+        if let Some(tenant) = &security_context.tenant_id {
+            events.retain(|event| event.tenant_id.as_deref().is_none_or(|t| t == tenant.as_str()));
+        }
 
         info!(
             entity_type = %entity_type,
@@ -1012,6 +1554,12 @@ impl FraiseQLFlightService {
             "Queried historical observer events"
         );
 
+        if let Some(encoding) = encoding {
+            let batch = observer_events_to_batch(&events)
+                .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))?;
+            return Self::encoded_batch_stream(&batch, &encoding);
+        }
+
         // Build response as vector of FlightData messages
         let mut messages: Vec<std::result::Result<FlightData, Status>> = Vec::new();
 
@@ -1030,6 +1578,96 @@ impl FraiseQLFlightService {
         Ok(Response::new(Box::pin(stream)))
     }
 
+    /// Export the observer dead letter queue as Arrow batches.
+    ///
+    /// A tenant-scoped caller only sees items of events of its own tenant; items of
+    /// events without a tenant stay visible, as with `ObserverEvents`.
+    pub(crate) async fn execute_dlq_items(
+        &self,
+        action_type: Option<String>,
+        start_date: Option<String>,
+        end_date: Option<String>,
+        limit: Option<usize>,
+        encoding: Option<BatchEncoding>,
+        security_context: &fraiseql_core::security::SecurityContext,
+    ) -> std::result::Result<Response<FlightDataStream>, Status> {
+        let delivery_storage = self.require_delivery_storage()?;
+        let query = delivery_query(action_type, start_date, end_date, limit)?;
+
+        let mut items = delivery_storage
+            .query_dlq_items(&query)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to query DLQ items: {e}")))?;
+        if let Some(tenant) = &security_context.tenant_id {
+            items.retain(|item| item.tenant_id.as_deref().is_none_or(|t| t == tenant.as_str()));
+        }
+
+        info!(item_count = items.len(), "Exported observer DLQ items");
+
+        let batch = dlq_items_to_batch(&items)
+            .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))?;
+        Self::encoded_batch_stream(&batch, &encoding.unwrap_or_default())
+    }
+
+    /// Export the job queue's attempt history as Arrow batches.
+    ///
+    /// Jobs carry no tenant, so tenant-scoped callers are refused rather than shown
+    /// other tenants' deliveries.
+    #[allow(clippy::too_many_arguments)] // Reason: mirrors the JobHistory ticket fields plus the caller's context
+    pub(crate) async fn execute_job_history(
+        &self,
+        action_type: Option<String>,
+        state: Option<String>,
+        start_date: Option<String>,
+        end_date: Option<String>,
+        limit: Option<usize>,
+        encoding: Option<BatchEncoding>,
+        security_context: &fraiseql_core::security::SecurityContext,
+    ) -> std::result::Result<Response<FlightDataStream>, Status> {
+        if security_context.tenant_id.is_some() {
+            return Err(Status::permission_denied(
+                "JobHistory tickets are not available to tenant-scoped callers: \
+                 queued jobs carry no tenant",
+            ));
+        }
+        let delivery_storage = self.require_delivery_storage()?;
+        let query = delivery_query(action_type, start_date, end_date, limit)?;
+
+        let attempts = delivery_storage
+            .query_job_attempts(&query, state.as_deref())
+            .await
+            .map_err(|e| Status::internal(format!("Failed to query job history: {e}")))?;
+
+        info!(attempt_count = attempts.len(), "Exported job queue history");
+
+        let batch = job_attempts_to_batch(&attempts)
+            .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))?;
+        Self::encoded_batch_stream(&batch, &encoding.unwrap_or_default())
+    }
+
+    /// The configured delivery storage, or `failed_precondition` when there is none.
+    fn require_delivery_storage(
+        &self,
+    ) -> std::result::Result<&Arc<dyn ArrowDeliveryStorage>, Status> {
+        self.delivery_storage.as_ref().ok_or_else(|| {
+            Status::failed_precondition(
+                "Delivery storage not configured - cannot export DLQ items or job history",
+            )
+        })
+    }
+
+    /// Stream `batch` as a schema message followed by its encoded record batches.
+    fn encoded_batch_stream(
+        batch: &RecordBatch,
+        encoding: &BatchEncoding,
+    ) -> std::result::Result<Response<FlightDataStream>, Status> {
+        let mut encoder = BatchStreamEncoder::new(encoding)?;
+        let mut messages = vec![encoder.schema_message(&batch.schema())];
+        messages.extend(encoder.batch_messages(batch)?);
+        let stream = futures::stream::iter(messages.into_iter().map(Ok));
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     /// Export table data in bulk with multiple format support.
     ///
     /// # Arguments
@@ -1037,8 +1675,9 @@ impl FraiseQLFlightService {
     /// * `table` - Table name to export
     /// * `filter` - Optional WHERE clause filter
     /// * `limit` - Optional row limit
-    /// * `format` - Export format: "parquet", "csv", or "json" (default: "parquet")
-    /// * `security_context` - Security context for RLS
+    /// * `format` - Export format: "parquet", "csv", "json", or "delta" (default: "parquet")
+    /// * `security_context` - Caller's security context; a tenant-scoped caller only exports rows
+    ///   whose tenant column matches its tenant, and cannot export to Delta tables
     #[allow(clippy::cognitive_complexity)] // Reason: multi-format export with format negotiation, query construction, and encoding
     pub(crate) async fn execute_bulk_export(
         &self,
@@ -1048,7 +1687,8 @@ impl FraiseQLFlightService {
         format: Option<String>,
         security_context: &fraiseql_core::security::SecurityContext,
     ) -> std::result::Result<Response<FlightDataStream>, Status> {
-        // H39: BulkExport runs `SELECT * FROM "<table>"` with NO per-user RLS filtering, so
+        // H39: BulkExport runs `SELECT * FROM "<table>"` with no per-user RLS filtering
+        // (tenant scoping aside), so
         // it is fail-closed — rejected unless the operator explicitly allow-lists the table
         // via `with_bulk_export_tables`. `None` ⇒ BulkExport disabled entirely.
         let Some(allowed_tables) = self.bulk_export_allowed_tables.as_ref() else {
@@ -1075,6 +1715,24 @@ impl FraiseQLFlightService {
             None => ExportFormat::Json,
         };
 
+        // A Delta export appends to a lakehouse table shared by every caller, so
+        // tenant-scoped callers cannot write to it.
+        #[cfg(feature = "parquet")]
+        let lakehouse_store = if export_format == ExportFormat::Delta {
+            if tenant_filter(&self.tenant_column, security_context).is_some() {
+                return Err(Status::permission_denied(
+                    "Delta exports are not available to tenant-scoped callers",
+                ));
+            }
+            Some(self.lakehouse_store.clone().ok_or_else(|| {
+                Status::failed_precondition(
+                    "Lakehouse store not configured - cannot export Delta tables",
+                )
+            })?)
+        } else {
+            None
+        };
+
         info!(
             user_id = %security_context.user_id,
             table = %table,
@@ -1082,9 +1740,9 @@ impl FraiseQLFlightService {
             "Starting bulk export"
         );
 
-        // SECURITY: Reject raw WHERE clause filters — they allow SQL injection. BulkExport
-        // applies no per-user row filtering of its own; access is controlled by the table
-        // allow-list above, so an allow-listed table is exported in full.
+        // SECURITY: Reject raw WHERE clause filters — they allow SQL injection. Beyond tenant
+        // scoping, BulkExport applies no per-user row filtering of its own; access is
+        // controlled by the table allow-list above.
         if filter.is_some() {
             return Err(Status::invalid_argument("BulkExport filter parameter is not supported."));
         }
@@ -1098,6 +1756,13 @@ impl FraiseQLFlightService {
         let quoted_table = format!("\"{}\"", table.replace('"', "\"\""));
         let mut sql = format!("SELECT * FROM {quoted_table}");
 
+        // A tenant-scoped caller only exports its own tenant's rows. The table must carry
+        // the tenant column; if it does not, the query fails rather than leaking rows.
+        if let Some(predicate) = tenant_filter(&self.tenant_column, security_context) {
+            sql.push_str(" WHERE ");
+            sql.push_str(&predicate);
+        }
+
         if let Some(l) = limit {
             sql.push_str(" LIMIT ");
             sql.push_str(&l.to_string());
@@ -1135,22 +1800,32 @@ impl FraiseQLFlightService {
 
         // Convert to RecordBatches
         let config = ConvertConfig {
-            batch_size: 10_000,
+            batch_size: batch_size_for(&arrow_rows, None, MAX_BATCH_ROWS),
             max_rows:   None,
         };
         let converter = RowToArrowConverter::new(schema, config);
 
-        let batches: Vec<RecordBatch> =
-            chunk_into_batches(&arrow_rows, &converter, config.batch_size)
-                .map_err(|e| Status::internal(format!("Arrow conversion failed: {}", e)))?;
+        // Batches beyond the export memory budget are spilled to temporary IPC files;
+        // the source rows are released before streaming starts.
+        let mut buffer = SpillingBatchBuffer::new(self.export_memory_budget);
+        if let Some(dir) = &self.export_spill_dir {
+            buffer = buffer.with_spill_dir(dir);
+        }
+        for_each_batch(&arrow_rows, &converter, config.batch_size, |batch| buffer.push(batch))
+            .map_err(|e| Status::internal(format!("Arrow conversion failed: {}", e)))?;
+        drop(arrow_rows);
+        drop(rows);
 
-        if batches.is_empty() {
+        if buffer.is_empty() {
             return Err(Status::internal("No Arrow batches created".to_string()));
         }
 
+        let spill = buffer.stats();
         info!(
             table = %table,
-            batch_count = batches.len(),
+            batch_count = spill.total_batches(),
+            spilled_batches = spill.spilled_batches,
+            spill_files = spill.spill_files,
             format = ?export_format,
             "Bulk export started"
         );
@@ -1159,24 +1834,55 @@ impl FraiseQLFlightService {
         // `BulkExporter::export_batch` call only runs when the consumer is
         // ready for it — peak memory holds the channel-buffer-sized window of
         // serialised payloads rather than the full Vec<FlightData> (F011).
+        // Spilled batches are read back from disk one at a time.
+        let batches = buffer.finish();
         let mime = export_format.mime_type().as_bytes().to_vec();
+
+        // A Delta export is committed before responding; the stream carries the
+        // commit summary.
+        #[cfg(feature = "parquet")]
+        if let Some(store) = lakehouse_store {
+            let writer = crate::export::delta::DeltaTableWriter::new(store, table);
+            let commit = BulkExporter::export_delta(batches, &writer)
+                .await
+                .map_err(|e| Status::internal(format!("Delta export failed: {e}")))?;
+            info!(
+                table = %table,
+                version = commit.version,
+                files = commit.files.len(),
+                rows = commit.num_records,
+                "Committed Delta export"
+            );
+            let summary = serde_json::to_vec(&commit)
+                .map_err(|e| Status::internal(format!("Failed to encode commit: {e}")))?;
+            let data = FlightData {
+                data_body: summary.into(),
+                app_metadata: mime.into(),
+                ..Default::default()
+            };
+            return Ok(Response::new(Box::pin(futures::stream::iter(vec![Ok(data)]))));
+        }
+
         let stream = spawn_flight_data_stream(move |tx| async move {
-            for (index, batch) in batches.iter().enumerate() {
-                let msg = match BulkExporter::export_batch(batch, export_format) {
-                    Ok(exported_bytes) => {
+            for (index, batch) in batches.enumerate() {
+                let msg = batch
+                    .map_err(|e| Status::internal(format!("Failed to read spilled batch: {e}")))
+                    .and_then(|batch| {
+                        BulkExporter::export_batch(&batch, export_format)
+                            .map_err(|e| Status::internal(format!("Export failed: {e}")))
+                    })
+                    .map(|exported_bytes| {
                         info!(
                             batch_index = index,
                             bytes_size = exported_bytes.len(),
                             "Exported batch"
                         );
-                        Ok(FlightData {
+                        FlightData {
                             data_body: exported_bytes.into(),
                             app_metadata: mime.clone().into(),
                             ..Default::default()
-                        })
-                    },
-                    Err(e) => Err(Status::internal(format!("Export failed: {e}"))),
-                };
+                        }
+                    });
                 let is_err = msg.is_err();
                 if tx.send(msg).await.is_err() || is_err {
                     return;
@@ -1186,6 +1892,232 @@ impl FraiseQLFlightService {
         Ok(Response::new(Box::pin(stream)))
     }
 
+    /// Stream live observer events for a `Subscription` ticket.
+    ///
+    /// Registers a subscription with the service's [`SubscriptionManager`] and keeps
+    /// the `DoGet` stream open: the schema message is sent first, then events are
+    /// buffered and flushed as one Arrow batch (`app_metadata` = `events`) every flush
+    /// interval or as soon as `max_batch_rows` are buffered. When nothing has been
+    /// sent for a heartbeat interval an empty batch (`app_metadata` = `heartbeat`)
+    /// is sent. The subscription is removed once the client disconnects or the
+    /// manager drops it.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_type` - Entity type to subscribe to
+    /// * `filter` - Optional event data filter
+    /// * `flush_interval_ms` - Requested flush interval (raised to the configured minimum)
+    /// * `security_context` - Caller's security context; a tenant-scoped caller only receives
+    ///   events of its own tenant (and global events without a tenant)
+    /// * `permit` - Concurrent-stream permit held for the lifetime of the stream
+    #[allow(clippy::result_large_err)] // Reason: tonic::Status is inherently large; boxing would add indirection in hot path
+    #[allow(clippy::cognitive_complexity)] // Reason: single select loop multiplexing events, flush and heartbeat timers
+    pub(crate) fn execute_subscription(
+        &self,
+        entity_type: String,
+        filter: Option<String>,
+        flush_interval_ms: Option<u64>,
+        security_context: &fraiseql_core::security::SecurityContext,
+        permit: OwnedSemaphorePermit,
+    ) -> std::result::Result<Response<FlightDataStream>, Status> {
+        // `tokio::time::interval` panics on a zero period.
+        let floor = std::time::Duration::from_millis(1);
+        let config = self.subscription_stream;
+        let flush_interval = config.effective_flush_interval(flush_interval_ms).max(floor);
+        let heartbeat_interval = config.heartbeat_interval.max(floor);
+        let max_batch_rows = config.max_batch_rows.max(1);
+
+        let subscription_id = format!("flight-{}", uuid::Uuid::new_v4());
+        let mut events = self.subscription_manager.subscribe(
+            subscription_id.clone(),
+            entity_type.clone(),
+            filter,
+        );
+        let manager = self.subscription_manager.clone();
+        let tenant = security_context.tenant_id.as_ref().map(|t| t.as_str().to_string());
+
+        info!(
+            user_id = %security_context.user_id,
+            subscription_id = %subscription_id,
+            entity_type = %entity_type,
+            flush_interval = ?flush_interval,
+            "Starting Flight subscription stream"
+        );
+
+        let stream = spawn_flight_data_stream(move |tx| async move {
+            let _permit = permit;
+            let schema = crate::schema::observer_event_schema();
+            if tx.send(schema_to_flight_data(&schema)).await.is_err() {
+                manager.unsubscribe(&subscription_id);
+                return;
+            }
+
+            let tagged = |batch: &RecordBatch, tag: &'static [u8]| {
+                record_batch_to_flight_data(batch).map(|mut data| {
+                    data.app_metadata = tag.to_vec().into();
+                    data
+                })
+            };
+            let encode_events = |buffered: &[crate::HistoricalEvent]| {
+                observer_events_to_batch(buffered)
+                    .map_err(|e| Status::internal(format!("Arrow conversion failed: {e}")))
+                    .and_then(|batch| tagged(&batch, b"events"))
+            };
+            let mut buffer = Vec::with_capacity(max_batch_rows);
+            let mut flush_tick = tokio::time::interval(flush_interval);
+            let mut heartbeat_tick = tokio::time::interval(heartbeat_interval);
+            flush_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            heartbeat_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            heartbeat_tick.reset();
+            let mut sent_since_heartbeat = false;
+
+            loop {
+                let flush = tokio::select! {
+                    () = tx.closed() => break,
+                    event = events.recv() => match event {
+                        Some(event) => {
+                            // Events without a tenant are global and stay visible.
+                            let visible = tenant.as_deref().is_none_or(|tenant| {
+                                event.tenant_id.as_deref().is_none_or(|t| t == tenant)
+                            });
+                            if visible {
+                                buffer.push(event);
+                            }
+                            buffer.len() >= max_batch_rows
+                        },
+                        // The manager dropped the subscription: deliver what is left.
+                        None => {
+                            if !buffer.is_empty() {
+                                let _ = tx.send(encode_events(&buffer)).await;
+                            }
+                            break;
+                        },
+                    },
+                    _ = flush_tick.tick() => !buffer.is_empty(),
+                    _ = heartbeat_tick.tick() => {
+                        if !sent_since_heartbeat {
+                            let empty = RecordBatch::new_empty(schema.clone());
+                            if tx.send(tagged(&empty, b"heartbeat")).await.is_err() {
+                                break;
+                            }
+                        }
+                        sent_since_heartbeat = false;
+                        false
+                    },
+                };
+
+                if flush {
+                    let message = encode_events(&buffer);
+                    buffer.clear();
+                    let failed = message.is_err();
+                    if tx.send(message).await.is_err() || failed {
+                        break;
+                    }
+                    sent_since_heartbeat = true;
+                }
+            }
+
+            manager.unsubscribe(&subscription_id);
+            debug!(subscription_id = %subscription_id, "Flight subscription stream closed");
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    /// Compute an `Aggregate` ticket in the local `DuckDB` sink and stream the result.
+    ///
+    /// A tenant-scoped caller only aggregates rows of its own tenant, and only on tables
+    /// carrying the service's tenant column; other tables are refused.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - Table of the `DuckDB` sink to aggregate
+    /// * `group_by` - Grouping columns
+    /// * `aggregates` - Aggregate output columns
+    /// * `limit` - Optional maximum number of groups
+    /// * `security_context` - Caller's security context
+    #[cfg(feature = "duckdb_sink")]
+    pub(crate) async fn execute_aggregate(
+        &self,
+        table: &str,
+        group_by: Vec<String>,
+        aggregates: Vec<AggregateSpec>,
+        limit: Option<usize>,
+        security_context: &fraiseql_core::security::SecurityContext,
+    ) -> std::result::Result<Response<FlightDataStream>, Status> {
+        use crate::error::ArrowFlightError;
+
+        let sink = self.duckdb_sink.clone().ok_or_else(|| {
+            Status::failed_precondition(
+                "DuckDB sink not configured - cannot serve Aggregate tickets",
+            )
+        })?;
+
+        let tenant = match &security_context.tenant_id {
+            None => None,
+            Some(tenant) => {
+                let columns = sink
+                    .columns(table)
+                    .map_err(|e| Status::internal(format!("DuckDB catalog lookup failed: {e}")))?;
+                if !columns.iter().any(|c| c == &self.tenant_column) {
+                    return Err(Status::permission_denied(format!(
+                        "Table '{table}' has no '{}' column and cannot be aggregated by a \
+                         tenant-scoped principal",
+                        self.tenant_column
+                    )));
+                }
+                Some((self.tenant_column.clone(), tenant.as_str().to_string()))
+            },
+        };
+
+        info!(
+            user_id = %security_context.user_id,
+            table = %table,
+            tenant_scoped = tenant.is_some(),
+            "Executing DuckDB aggregate"
+        );
+
+        // DuckDB runs blocking file I/O; keep it off the async workers.
+        let table = table.to_string();
+        let batch = tokio::task::spawn_blocking(move || {
+            sink.aggregate(
+                &table,
+                &group_by,
+                &aggregates,
+                limit,
+                tenant.as_ref().map(|(column, value)| (column.as_str(), value.as_str())),
+            )
+        })
+        .await
+        .map_err(|e| Status::internal(format!("Aggregate task failed: {e}")))?
+        .map_err(|e| match e {
+            ArrowFlightError::InvalidTicket(msg) => Status::invalid_argument(msg),
+            ArrowFlightError::SchemaNotFound(msg) => Status::not_found(msg),
+            other => Status::internal(format!("Aggregate failed: {other}")),
+        })?;
+
+        let messages = vec![
+            schema_to_flight_data(&batch.schema()),
+            record_batch_to_flight_data(&batch),
+        ];
+        Ok(Response::new(Box::pin(futures::stream::iter(messages))))
+    }
+
+    /// `Aggregate` tickets need the `duckdb_sink` feature.
+    #[cfg(not(feature = "duckdb_sink"))]
+    pub(crate) async fn execute_aggregate(
+        &self,
+        _table: &str,
+        _group_by: Vec<String>,
+        _aggregates: Vec<AggregateSpec>,
+        _limit: Option<usize>,
+        _security_context: &fraiseql_core::security::SecurityContext,
+    ) -> std::result::Result<Response<FlightDataStream>, Status> {
+        Err(Status::unimplemented(
+            "Aggregate tickets require fraiseql-arrow's `duckdb_sink` feature",
+        ))
+    }
+
     /// Handle `ClearCache` action
     pub(crate) fn handle_clear_cache(&self) -> ActionResultStream {
         info!("ClearCache action triggered");
@@ -1318,7 +2250,7 @@ mod convert_tests;
 
 #[cfg(test)]
 mod backpressure_tests {
-    #![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+    #![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics are acceptable
 
     use std::{
         sync::{
@@ -1425,6 +2357,23 @@ mod backpressure_tests {
             "producer kept running after consumer dropped (produced {final_count})"
         );
     }
+
+    /// A panicking producer must end the stream with `INTERNAL`, not just close it:
+    /// a silently closed stream reads as a complete (truncated) result.
+    #[tokio::test]
+    async fn producer_panic_ends_stream_with_internal() {
+        let stream = spawn_flight_data_stream(move |tx| async move {
+            let _ = tx.send(Ok(FlightData::default())).await;
+            panic!("encoder bug");
+        });
+        let mut stream = Box::pin(stream);
+
+        stream.next().await.unwrap().unwrap();
+        let status = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Internal);
+        assert!(!status.message().contains("encoder bug"), "panic message leaked to the client");
+        assert!(stream.next().await.is_none());
+    }
 }
 
 #[cfg(test)]
@@ -1494,6 +2443,23 @@ mod bulk_export_authz_tests {
             "an allow-listed table must pass the authz gate (then fail on the missing adapter)"
         );
     }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn delta_export_requires_lakehouse_store() {
+        let svc = FraiseQLFlightService::new().with_bulk_export_tables(["orders"]);
+        let format = Some("delta".to_string());
+        match svc
+            .execute_bulk_export("orders", None, None, format, &test_security_context())
+            .await
+        {
+            Ok(_) => panic!("expected Delta export without a lakehouse store to error"),
+            Err(status) => {
+                assert_eq!(status.code(), Code::FailedPrecondition);
+                assert!(status.message().contains("Lakehouse store"), "{}", status.message());
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -13,7 +13,7 @@ fn test_flat_array_produces_batches() {
         {"id": 1, "name": "Alice"},
         {"id": 2, "name": "Bob"},
     ]);
-    let batches = service.convert_json_to_arrow_batches(&json).unwrap();
+    let batches = service.convert_json_to_arrow_batches(&json, "{ users { id name } }").unwrap();
     assert!(!batches.is_empty(), "Must produce at least one batch");
     assert_eq!(batches[0].num_rows(), 2);
     assert_eq!(batches[0].num_columns(), 2);
@@ -32,7 +32,7 @@ fn test_graphql_envelope_finds_array() {
             ]
         }
     });
-    let batches = service.convert_json_to_arrow_batches(&json).unwrap();
+    let batches = service.convert_json_to_arrow_batches(&json, "{ users { id email } }").unwrap();
     assert!(!batches.is_empty());
     assert_eq!(batches[0].num_rows(), 3);
 }
@@ -42,7 +42,7 @@ fn test_graphql_envelope_finds_array() {
 fn test_scalar_falls_back_to_string_column() {
     let service = FraiseQLFlightService::new();
     let json = serde_json::json!({"data": {"ok": true}});
-    let batches = service.convert_json_to_arrow_batches(&json).unwrap();
+    let batches = service.convert_json_to_arrow_batches(&json, "{ ok }").unwrap();
     assert!(!batches.is_empty(), "Must produce the fallback batch");
     assert_eq!(batches[0].num_columns(), 1, "Fallback uses a single 'result' column");
 }
@@ -52,6 +52,76 @@ fn test_scalar_falls_back_to_string_column() {
 fn test_empty_object_produces_fallback() {
     let service = FraiseQLFlightService::new();
     let json = serde_json::json!({});
-    let batches = service.convert_json_to_arrow_batches(&json).unwrap();
+    let batches = service.convert_json_to_arrow_batches(&json, "{ ok }").unwrap();
     assert!(!batches.is_empty());
 }
+
+/// Columns follow the selection set of the query, not the row's key order.
+#[test]
+fn test_columns_follow_response_key_order() {
+    let service = FraiseQLFlightService::new();
+    let json = serde_json::json!({
+        "data": {"users": [{"name": "Alice", "id": 1, "email": "a@test.com", "age": 30}]}
+    });
+    let batches = service
+        .convert_json_to_arrow_batches(&json, "{ users { name id email age } }")
+        .unwrap();
+    let schema = batches[0].schema();
+    let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(names, vec!["name", "id", "email", "age"]);
+}
+
+/// Rows keep the executor's order (`orderBy` / `offset` are applied there).
+#[test]
+fn test_rows_keep_executor_order() {
+    use arrow::array::Array;
+
+    let service = FraiseQLFlightService::new();
+    let json = serde_json::json!({
+        "data": {"users": [{"id": 3}, {"id": 1}, {"id": 2}]}
+    });
+    let batches = service.convert_json_to_arrow_batches(&json, "{ users { id } }").unwrap();
+    let ids = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<arrow::array::Int64Array>()
+        .unwrap();
+    assert_eq!(ids.values().to_vec(), vec![3, 1, 2]);
+}
+
+/// An empty page is zero rows, not the response wrapped as a string.
+#[test]
+fn test_empty_list_produces_no_batches() {
+    let service = FraiseQLFlightService::new();
+    let json = serde_json::json!({"data": {"users": [], "posts": [{"id": 1}]}});
+    let batches = service
+        .convert_json_to_arrow_batches(&json, "{ users { id } posts { id } }")
+        .unwrap();
+    assert!(batches.is_empty(), "the first selected root field is the result");
+}
+
+/// A list of scalars has no columns to map and falls back to the string column.
+#[test]
+fn test_scalar_list_falls_back_to_string_column() {
+    let service = FraiseQLFlightService::new();
+    let json = serde_json::json!({"data": {"tags": ["a", "b"]}});
+    let batches = service.convert_json_to_arrow_batches(&json, "{ tags }").unwrap();
+    assert_eq!(batches[0].num_columns(), 1);
+    assert_eq!(batches[0].num_rows(), 1);
+}
+
+/// Aliases and fragment fields take their place in the selection; keys the
+/// selection does not name come last.
+#[test]
+fn test_columns_follow_aliases_and_fragments() {
+    let service = FraiseQLFlightService::new();
+    let json = serde_json::json!({
+        "data": {"people": [{"name": "Alice", "mail": "a@test.com", "id": 1, "extra": 0}]}
+    });
+    let query = "query { people: users { name ...Contact id } } \
+                 fragment Contact on User { mail: email }";
+    let batches = service.convert_json_to_arrow_batches(&json, query).unwrap();
+    let schema = batches[0].schema();
+    let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(names, vec!["name", "mail", "id", "extra"]);
+}
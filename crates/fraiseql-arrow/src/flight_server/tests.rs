@@ -5,10 +5,12 @@
 
 use std::sync::Arc;
 
-use arrow_flight::{Action, Empty, FlightDescriptor, flight_service_server::FlightService};
+use arrow_flight::{
+    Action, Criteria, Empty, FlightDescriptor, flight_service_server::FlightService,
+};
 use async_trait::async_trait;
 use chrono::Utc;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
 use tonic::Request;
 
@@ -177,6 +179,7 @@ async fn test_get_flight_info_for_optimized_view() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
     let ticket_bytes = ticket.encode().expect("Failed to encode ticket");
 
@@ -211,6 +214,7 @@ async fn test_get_flight_info_invalid_view() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
     let ticket_bytes = ticket.encode().expect("Failed to encode ticket");
 
@@ -278,6 +282,7 @@ async fn test_do_action_health_check() {
             iat:          now.timestamp(),
             scopes:       vec!["user".to_string()],
             session_type: "flight".to_string(),
+            extra_claims: Default::default(),
         };
 
         let key = EncodingKey::from_secret(TEST_FLIGHT_SECRET.as_bytes());
@@ -329,6 +334,7 @@ async fn test_do_action_unknown_action() {
             iat:          now.timestamp(),
             scopes:       vec!["user".to_string()],
             session_type: "flight".to_string(),
+            extra_claims: Default::default(),
         };
 
         let key = EncodingKey::from_secret(TEST_FLIGHT_SECRET.as_bytes());
@@ -350,3 +356,990 @@ async fn test_do_action_unknown_action() {
     })
     .await;
 }
+
+fn tenant_user(
+    claims: serde_json::Value,
+) -> fraiseql_core::security::auth_middleware::AuthenticatedUser {
+    let extra_claims = claims
+        .as_object()
+        .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        .unwrap_or_default();
+    fraiseql_core::security::auth_middleware::AuthenticatedUser {
+        user_id: fraiseql_core::types::UserId::new("user-1"),
+        scopes: vec!["user".to_string()],
+        expires_at: Utc::now() + chrono::Duration::minutes(5),
+        email: None,
+        display_name: None,
+        extra_claims,
+    }
+}
+
+/// Authorizer denying every operation named `va_orders`.
+struct DenyOrders;
+
+impl fraiseql_core::security::Authorizer for DenyOrders {
+    fn authorize(
+        &self,
+        req: &fraiseql_core::security::AuthzRequest<'_>,
+    ) -> fraiseql_core::error::Result<fraiseql_core::security::AuthzDecision> {
+        if req.name == "va_orders" {
+            Ok(fraiseql_core::security::AuthzDecision::Deny {
+                reason: "orders are restricted".to_string(),
+            })
+        } else {
+            Ok(fraiseql_core::security::AuthzDecision::Allow)
+        }
+    }
+}
+
+fn bearer_ticket_request(token: &str, ticket: &FlightTicket) -> Request<arrow_flight::Ticket> {
+    let mut request = Request::new(arrow_flight::Ticket {
+        ticket: ticket.encode().unwrap().into(),
+    });
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    request
+}
+
+/// Session tokens carry the original token's custom claims through the handshake.
+#[test]
+fn test_session_token_preserves_extra_claims() {
+    let user = tenant_user(serde_json::json!({"tenant_id": "acme", "roles": ["analyst"]}));
+    let token = super::create_session_token(&user, TEST_FLIGHT_SECRET).unwrap();
+
+    let restored = super::auth::validate_session_token(&token, TEST_FLIGHT_SECRET).unwrap();
+    assert_eq!(restored.extra_claims.get("tenant_id"), Some(&serde_json::json!("acme")));
+    assert_eq!(restored.extra_claims.get("roles"), Some(&serde_json::json!(["analyst"])));
+}
+
+/// The tenant comes from the `tenant_id` claim, falling back to `org_id`.
+#[test]
+fn test_flight_security_context_tenant_from_claims() {
+    let metadata = tonic::metadata::MetadataMap::new();
+
+    let ctx = super::flight_security_context(
+        &tenant_user(serde_json::json!({"tenant_id": "acme", "org_id": "other"})),
+        &metadata,
+    )
+    .unwrap();
+    assert_eq!(ctx.tenant_id.as_ref().map(|t| t.as_str()), Some("acme"));
+
+    let ctx = super::flight_security_context(
+        &tenant_user(serde_json::json!({"org_id": "globex"})),
+        &metadata,
+    )
+    .unwrap();
+    assert_eq!(ctx.tenant_id.as_ref().map(|t| t.as_str()), Some("globex"));
+    assert_eq!(ctx.attributes.get("org_id"), Some(&serde_json::json!("globex")));
+}
+
+/// Reserved `fraiseql.` claims are not copied into the context attributes.
+#[test]
+fn test_flight_security_context_skips_reserved_claims() {
+    let ctx = super::flight_security_context(
+        &tenant_user(serde_json::json!({"fraiseql.actor_type": "system", "region": "eu"})),
+        &tonic::metadata::MetadataMap::new(),
+    )
+    .unwrap();
+    assert!(!ctx.attributes.contains_key("fraiseql.actor_type"));
+    assert_eq!(ctx.attributes.get("region"), Some(&serde_json::json!("eu")));
+}
+
+/// A conflicting `x-tenant-id` header is rejected; a header without a tenant claim is
+/// ignored rather than trusted.
+#[test]
+fn test_flight_security_context_tenant_header() {
+    let mut metadata = tonic::metadata::MetadataMap::new();
+    metadata.insert("x-tenant-id", "globex".parse().unwrap());
+
+    let status = super::flight_security_context(
+        &tenant_user(serde_json::json!({"tenant_id": "acme"})),
+        &metadata,
+    )
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+    let ctx =
+        super::flight_security_context(&tenant_user(serde_json::json!({})), &metadata).unwrap();
+    assert!(ctx.tenant_id.is_none());
+
+    metadata.insert("x-tenant-id", "acme".parse().unwrap());
+    let ctx = super::flight_security_context(
+        &tenant_user(serde_json::json!({"tenant_id": "acme"})),
+        &metadata,
+    )
+    .unwrap();
+    assert_eq!(ctx.tenant_id.as_ref().map(|t| t.as_str()), Some("acme"));
+}
+
+/// `do_get` without a bearer token is unauthenticated.
+#[tokio::test]
+async fn test_do_get_requires_bearer_token() {
+    let service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    let ticket = FlightTicket::OptimizedView {
+        view:     "va_orders".to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    Some(1),
+        offset:   None,
+        encoding: None,
+    };
+    let request = Request::new(arrow_flight::Ticket {
+        ticket: ticket.encode().unwrap().into(),
+    });
+
+    let Err(status) = service.do_get(request).await else {
+        panic!("do_get without a bearer token must fail");
+    };
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+}
+
+/// The configured authorizer is consulted per ticket, by view name.
+#[tokio::test]
+async fn test_do_get_enforces_ticket_authorization() {
+    let service = FraiseQLFlightService::new()
+        .with_session_secret(TEST_FLIGHT_SECRET)
+        .with_authorizer(Arc::new(DenyOrders));
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+
+    let denied = FlightTicket::OptimizedView {
+        view:     "va_orders".to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    Some(1),
+        offset:   None,
+        encoding: None,
+    };
+    let Err(status) = service.do_get(bearer_ticket_request(&token, &denied)).await else {
+        panic!("a denied view must not be served");
+    };
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    assert!(status.message().contains("orders are restricted"));
+
+    let allowed = FlightTicket::OptimizedView {
+        view:     "va_users".to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    Some(1),
+        offset:   None,
+        encoding: None,
+    };
+    assert!(service.do_get(bearer_ticket_request(&token, &allowed)).await.is_ok());
+}
+
+/// Raw-SQL `BatchedQueries` are refused for tenant-scoped principals even when enabled.
+#[tokio::test]
+async fn test_do_get_batched_queries_refused_for_tenant() {
+    let service = FraiseQLFlightService::new()
+        .with_session_secret(TEST_FLIGHT_SECRET)
+        .with_raw_sql_enabled();
+    let token = super::create_session_token(
+        &tenant_user(serde_json::json!({"tenant_id": "acme"})),
+        TEST_FLIGHT_SECRET,
+    )
+    .unwrap();
+    let ticket = FlightTicket::BatchedQueries {
+        queries: vec!["SELECT 1".to_string()],
+    };
+
+    let Err(status) = service.do_get(bearer_ticket_request(&token, &ticket)).await else {
+        panic!("tenant-scoped raw SQL must be refused");
+    };
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+}
+
+/// `Aggregate` tickets fail cleanly when no `DuckDB` sink can answer them.
+#[tokio::test]
+async fn test_do_get_aggregate_without_sink() {
+    let service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::Aggregate {
+        table:      "fraiseql_events".to_string(),
+        group_by:   vec![],
+        aggregates: vec![crate::ticket::AggregateSpec {
+            function: crate::ticket::AggregateFunction::Count,
+            column:   None,
+            alias:    "events".to_string(),
+        }],
+        limit:      None,
+    };
+
+    let Err(status) = service.do_get(bearer_ticket_request(&token, &ticket)).await else {
+        panic!("an Aggregate ticket needs a DuckDB sink");
+    };
+    if cfg!(feature = "duckdb_sink") {
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    } else {
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+    }
+}
+
+fn subscription_event(tenant_id: &str) -> crate::HistoricalEvent {
+    crate::HistoricalEvent {
+        id:          uuid::Uuid::new_v4(),
+        event_type:  "INSERT".to_string(),
+        entity_type: "Order".to_string(),
+        entity_id:   uuid::Uuid::new_v4(),
+        data:        serde_json::json!({"status": "paid"}),
+        user_id:     None,
+        tenant_id:   Some(tenant_id.to_string()),
+        timestamp:   Utc::now(),
+    }
+}
+
+/// A `Subscription` ticket keeps the stream open and pushes the caller's tenant's events.
+#[tokio::test]
+async fn test_do_get_subscription_pushes_events() {
+    let service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    let token = super::create_session_token(
+        &tenant_user(serde_json::json!({"tenant_id": "acme"})),
+        TEST_FLIGHT_SECRET,
+    )
+    .unwrap();
+    let ticket = FlightTicket::Subscription {
+        entity_type:       "Order".to_string(),
+        filter:            None,
+        flush_interval_ms: Some(50),
+    };
+
+    let mut stream = service
+        .do_get(bearer_ticket_request(&token, &ticket))
+        .await
+        .unwrap()
+        .into_inner();
+    let schema_message = stream.next().await.unwrap().unwrap();
+    assert_eq!(service.subscription_manager().subscription_count(), 1);
+
+    let manager = service.subscription_manager();
+    manager.broadcast_event(&subscription_event("other"));
+    manager.broadcast_event(&subscription_event("acme"));
+
+    let events = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(events.app_metadata.as_ref(), b"events");
+    let batches = arrow_flight::utils::flight_data_to_batches(&[schema_message, events]).unwrap();
+    assert_eq!(batches[0].num_rows(), 1);
+
+    // Dropping the stream removes the subscription.
+    drop(stream);
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        while service.subscription_manager().subscription_count() > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// An idle subscription stream receives empty heartbeat batches.
+#[tokio::test]
+async fn test_do_get_subscription_heartbeat() {
+    let service = FraiseQLFlightService::new()
+        .with_session_secret(TEST_FLIGHT_SECRET)
+        .with_subscription_stream_config(crate::SubscriptionStreamConfig {
+            heartbeat_interval: std::time::Duration::from_millis(20),
+            ..Default::default()
+        });
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::Subscription {
+        entity_type:       "Order".to_string(),
+        filter:            None,
+        flush_interval_ms: None,
+    };
+
+    let mut stream = service
+        .do_get(bearer_ticket_request(&token, &ticket))
+        .await
+        .unwrap()
+        .into_inner();
+    let schema_message = stream.next().await.unwrap().unwrap();
+    let heartbeat = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(heartbeat.app_metadata.as_ref(), b"heartbeat");
+    let batches =
+        arrow_flight::utils::flight_data_to_batches(&[schema_message, heartbeat]).unwrap();
+    assert_eq!(batches[0].num_rows(), 0);
+}
+
+/// Tenant-scoped optimized-view SQL filters on the tenant column with an escaped literal.
+#[test]
+fn test_optimized_sql_tenant_predicate() {
+    let ctx = super::flight_security_context(
+        &tenant_user(serde_json::json!({"tenant_id": "o'brien"})),
+        &tonic::metadata::MetadataMap::new(),
+    )
+    .unwrap();
+    let predicate = super::tenant_filter("tenant_id", &ctx).unwrap();
+    assert_eq!(predicate, "\"tenant_id\" = 'o''brien'");
+
+    let sql = super::build_optimized_sql(
+        "va_orders",
+        None,
+        Some(&predicate),
+        Some("id DESC".to_string()),
+        Some(10),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"va_orders\" WHERE \"tenant_id\" = 'o''brien' ORDER BY \"id\" DESC LIMIT 10"
+    );
+}
+
+/// `get_flight_info` accepts a view name as descriptor path and returns an endpoint whose
+/// ticket fetches that view from the advertised locations.
+#[tokio::test]
+async fn test_get_flight_info_returns_endpoint_for_view_name() {
+    let service = FraiseQLFlightService::new()
+        .with_advertised_locations(["grpc+tls://flight.example.com:50051"]);
+    let descriptor = FlightDescriptor {
+        r#type: 1, // PATH
+        path:   vec!["va_users".to_string()],
+        cmd:    Default::default(),
+    };
+
+    let info = service.get_flight_info(Request::new(descriptor)).await.unwrap().into_inner();
+
+    assert_eq!(info.endpoint.len(), 1);
+    let endpoint = &info.endpoint[0];
+    let ticket = FlightTicket::decode(&endpoint.ticket.as_ref().unwrap().ticket).unwrap();
+    assert!(matches!(ticket, FlightTicket::OptimizedView { ref view, .. } if view == "va_users"));
+    assert_eq!(endpoint.location.len(), 1);
+    assert_eq!(endpoint.location[0].uri, "grpc+tls://flight.example.com:50051");
+
+    let metadata: serde_json::Value = serde_json::from_slice(&info.app_metadata).unwrap();
+    assert!(metadata["schema_version"].is_u64());
+}
+
+/// `get_flight_info` accepts a `CMD` descriptor carrying an encoded ticket.
+#[tokio::test]
+async fn test_get_flight_info_accepts_cmd_descriptor() {
+    let service = FraiseQLFlightService::new();
+    let ticket = FlightTicket::OptimizedView {
+        view:     "va_orders".to_string(),
+        filter:   None,
+        order_by: Some("id DESC".to_string()),
+        limit:    Some(10),
+        offset:   None,
+        encoding: None,
+    };
+    let descriptor = FlightDescriptor {
+        r#type: 2, // CMD
+        path:   vec![],
+        cmd:    ticket.encode().unwrap().into(),
+    };
+
+    let info = service.get_flight_info(Request::new(descriptor)).await.unwrap().into_inner();
+
+    assert!(info.ordered, "an ordered ticket must be reported as ordered");
+    let endpoint_ticket =
+        FlightTicket::decode(&info.endpoint[0].ticket.as_ref().unwrap().ticket).unwrap();
+    assert_eq!(endpoint_ticket, ticket);
+}
+
+/// `list_flights` lists registered views filtered by the name prefix in the criteria.
+#[tokio::test]
+async fn test_list_flights_filters_by_prefix() {
+    let service = FraiseQLFlightService::new();
+    let criteria = Criteria {
+        expression: b"va_".to_vec().into(),
+    };
+
+    let mut stream = service.list_flights(Request::new(criteria)).await.unwrap().into_inner();
+    let mut names = Vec::new();
+    while let Some(info) = stream.next().await {
+        let info = info.unwrap();
+        assert_eq!(info.endpoint.len(), 1, "every listed view carries a fetch endpoint");
+        names.push(info.flight_descriptor.unwrap().path[0].clone());
+    }
+
+    assert_eq!(names, vec!["va_orders", "va_users"]);
+}
+
+/// With an authorizer configured, discovery requires authentication and omits views the
+/// caller may not query.
+#[tokio::test]
+async fn test_list_flights_with_authorizer() {
+    let service = FraiseQLFlightService::new()
+        .with_session_secret(TEST_FLIGHT_SECRET)
+        .with_authorizer(Arc::new(DenyOrders));
+
+    let Err(status) = service.list_flights(Request::new(Criteria::default())).await else {
+        panic!("anonymous discovery must be refused when an authorizer is configured");
+    };
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let mut request = Request::new(Criteria::default());
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+
+    let mut stream = service.list_flights(request).await.unwrap().into_inner();
+    let mut names = Vec::new();
+    while let Some(info) = stream.next().await {
+        names.push(info.unwrap().flight_descriptor.unwrap().path[0].clone());
+    }
+
+    assert!(!names.contains(&"va_orders".to_string()));
+    assert!(names.contains(&"va_users".to_string()));
+}
+
+/// In-memory event storage with one event per hour from 2026-01-01T00:00Z (inclusive
+/// date-range semantics, like real backends).
+struct HourlyEvents(Vec<crate::event_storage::HistoricalEvent>);
+
+impl HourlyEvents {
+    fn new(hours: u32) -> Self {
+        let start = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        Self(
+            (0..hours)
+                .map(|h| crate::event_storage::HistoricalEvent {
+                    id:          uuid::Uuid::from_u128(u128::from(h)),
+                    event_type:  "INSERT".to_string(),
+                    entity_type: "Order".to_string(),
+                    entity_id:   uuid::Uuid::from_u128(u128::from(h) * 7),
+                    data:        serde_json::json!({}),
+                    user_id:     None,
+                    tenant_id:   None,
+                    timestamp:   start + chrono::Duration::hours(i64::from(h)),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[async_trait]
+impl crate::event_storage::ArrowEventStorage for HourlyEvents {
+    async fn query_events(
+        &self,
+        _entity_type: &str,
+        start_date: Option<chrono::DateTime<Utc>>,
+        end_date: Option<chrono::DateTime<Utc>>,
+        limit: Option<usize>,
+    ) -> std::result::Result<Vec<crate::event_storage::HistoricalEvent>, String> {
+        let mut events: Vec<_> = self
+            .0
+            .iter()
+            .filter(|e| start_date.is_none_or(|s| e.timestamp >= s))
+            .filter(|e| end_date.is_none_or(|end| e.timestamp <= end))
+            .cloned()
+            .collect();
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
+        Ok(events)
+    }
+
+    async fn count_events(
+        &self,
+        entity_type: &str,
+        start_date: Option<chrono::DateTime<Utc>>,
+        end_date: Option<chrono::DateTime<Utc>>,
+    ) -> std::result::Result<usize, String> {
+        Ok(self.query_events(entity_type, start_date, end_date, None).await?.len())
+    }
+}
+
+/// Fetch every partition endpoint `GetFlightInfo` returns for `ticket` and collect the
+/// ids of the events served.
+async fn fetch_partitioned_event_ids(ticket: &FlightTicket) -> (usize, Vec<uuid::Uuid>) {
+    let mut service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    service.set_event_storage(Arc::new(HourlyEvents::new(48)));
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+
+    let descriptor = FlightDescriptor {
+        r#type: 2, // CMD
+        path:   vec![],
+        cmd:    ticket.encode().unwrap().into(),
+    };
+    let info = service.get_flight_info(Request::new(descriptor)).await.unwrap().into_inner();
+
+    let mut ids = Vec::new();
+    for endpoint in &info.endpoint {
+        let partition = FlightTicket::decode(&endpoint.ticket.as_ref().unwrap().ticket).unwrap();
+        let mut stream = service
+            .do_get(bearer_ticket_request(&token, &partition))
+            .await
+            .unwrap()
+            .into_inner();
+        while let Some(data) = stream.next().await {
+            let events: Vec<crate::event_storage::HistoricalEvent> =
+                serde_json::from_slice(&data.unwrap().data_body).unwrap();
+            ids.extend(events.into_iter().map(|e| e.id));
+        }
+    }
+    (info.endpoint.len(), ids)
+}
+
+/// Date-range partitions read disjoint windows that together cover the whole range.
+#[tokio::test]
+async fn test_date_range_partitions_cover_range_without_duplicates() {
+    let ticket = FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  Some("2026-01-01T00:00:00Z".to_string()),
+        end_date:    Some("2026-01-02T23:00:00Z".to_string()),
+        limit:       None,
+        partition:   Some(crate::ticket::TicketPartition {
+            scheme: crate::ticket::PartitionScheme::DateRange,
+            count:  5,
+            index:  None,
+        }),
+        encoding:    None,
+    };
+
+    let (endpoints, mut ids) = fetch_partitioned_event_ids(&ticket).await;
+
+    assert_eq!(endpoints, 5);
+    ids.sort_unstable();
+    let expected: Vec<_> = (0..48_u128).map(uuid::Uuid::from_u128).collect();
+    assert_eq!(ids, expected);
+}
+
+/// Entity-hash partitions split events by entity bucket with no overlap.
+#[tokio::test]
+async fn test_entity_hash_partitions_cover_events_without_duplicates() {
+    let ticket = FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  None,
+        end_date:    None,
+        limit:       None,
+        partition:   Some(crate::ticket::TicketPartition {
+            scheme: crate::ticket::PartitionScheme::EntityHash,
+            count:  3,
+            index:  None,
+        }),
+        encoding:    None,
+    };
+
+    let (endpoints, mut ids) = fetch_partitioned_event_ids(&ticket).await;
+
+    assert_eq!(endpoints, 3);
+    ids.sort_unstable();
+    let expected: Vec<_> = (0..48_u128).map(uuid::Uuid::from_u128).collect();
+    assert_eq!(ids, expected);
+}
+
+/// `DoGet` refuses a split request: partitions are fetched by index.
+#[tokio::test]
+async fn test_do_get_rejects_unindexed_partition() {
+    let mut service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    service.set_event_storage(Arc::new(HourlyEvents::new(4)));
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  None,
+        end_date:    None,
+        limit:       None,
+        partition:   Some(crate::ticket::TicketPartition {
+            scheme: crate::ticket::PartitionScheme::EntityHash,
+            count:  2,
+            index:  None,
+        }),
+        encoding:    None,
+    };
+
+    let Err(status) = service.do_get(bearer_ticket_request(&token, &ticket)).await else {
+        panic!("an unindexed partition ticket must be rejected");
+    };
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Decode a `DoGet` stream into record batches, resolving dictionary messages.
+async fn decode_batches(
+    stream: super::FlightDataStream,
+) -> std::result::Result<Vec<arrow::array::RecordBatch>, arrow_flight::error::FlightError> {
+    arrow_flight::decode::FlightRecordBatchStream::new_from_flight_data(
+        stream.map_err(arrow_flight::error::FlightError::from),
+    )
+    .try_collect()
+    .await
+}
+
+fn dictionary_encoding(columns: &[&str]) -> crate::ticket::BatchEncoding {
+    crate::ticket::BatchEncoding {
+        dictionary_columns: columns.iter().map(ToString::to_string).collect(),
+        compression:        None,
+        max_batch_bytes:    None,
+    }
+}
+
+/// An encoded `ObserverEvents` ticket streams Arrow batches with dictionary columns.
+#[tokio::test]
+async fn test_observer_events_stream_dictionary_encoded_batches() {
+    use arrow::array::AsArray;
+
+    let mut service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    service.set_event_storage(Arc::new(HourlyEvents::new(6)));
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  None,
+        end_date:    None,
+        limit:       None,
+        partition:   None,
+        encoding:    Some(dictionary_encoding(&["event_type", "entity_type"])),
+    };
+
+    let stream = service.do_get(bearer_ticket_request(&token, &ticket)).await.unwrap();
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+    assert_eq!(batch.num_rows(), 6);
+    assert_eq!(
+        batch.schema().field_with_name("entity_type").unwrap().data_type(),
+        &crate::convert::string_dictionary_type()
+    );
+    let entity_types = batch.column_by_name("entity_type").unwrap().as_any_dictionary();
+    assert_eq!(entity_types.values().len(), 1, "one distinct entity type is stored once");
+    assert_eq!(
+        batch.column_by_name("event_id").unwrap().data_type(),
+        &arrow::datatypes::DataType::Utf8
+    );
+}
+
+/// Delivery storage serving two DLQ items of different tenants and one two-attempt job.
+struct FailedDeliveries;
+
+impl FailedDeliveries {
+    fn at(hour: i64) -> chrono::DateTime<Utc> {
+        chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+            + chrono::Duration::hours(hour)
+    }
+}
+
+#[async_trait]
+impl crate::delivery_storage::ArrowDeliveryStorage for FailedDeliveries {
+    async fn query_dlq_items(
+        &self,
+        query: &crate::delivery_storage::DeliveryQuery,
+    ) -> std::result::Result<Vec<crate::delivery_storage::DlqRecord>, String> {
+        let item = |id: u128, tenant: &str, hour: i64| crate::delivery_storage::DlqRecord {
+            id:            uuid::Uuid::from_u128(id),
+            action_type:   "webhook".to_string(),
+            event_id:      uuid::Uuid::from_u128(id * 7),
+            event_type:    "INSERT".to_string(),
+            entity_type:   "Order".to_string(),
+            error_code:    Some("OB006".to_string()),
+            error_message: "OB006: Action execution failed: 503".to_string(),
+            attempts:      5,
+            tenant_id:     Some(tenant.to_string()),
+            created_at:    Self::at(hour),
+        };
+        Ok([item(1, "acme", 2), item(2, "globex", 1)]
+            .into_iter()
+            .filter(|item| query.matches(&item.action_type, item.created_at))
+            .collect())
+    }
+
+    async fn query_job_attempts(
+        &self,
+        _query: &crate::delivery_storage::DeliveryQuery,
+        _state: Option<&str>,
+    ) -> std::result::Result<Vec<crate::delivery_storage::JobAttemptRecord>, String> {
+        let attempt = |attempt: u32| crate::delivery_storage::JobAttemptRecord {
+            job_id: uuid::Uuid::from_u128(9),
+            event_id: uuid::Uuid::from_u128(7),
+            action_type: "email".to_string(),
+            state: "completed".to_string(),
+            attempt,
+            max_attempts: 3,
+            success: attempt == 2,
+            error_code: None,
+            error_message: (attempt == 1).then(|| "timeout".to_string()),
+            job_created_at: Self::at(0),
+            started_at: Self::at(i64::from(attempt)),
+        };
+        Ok(vec![attempt(2), attempt(1)])
+    }
+}
+
+/// A `DlqItems` ticket streams Arrow batches, filtered by date and by the caller's tenant.
+#[tokio::test]
+async fn test_dlq_items_export_is_filtered_and_tenant_scoped() {
+    let mut service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    service.set_delivery_storage(Arc::new(FailedDeliveries));
+    let ticket = |start_date: Option<&str>| FlightTicket::DlqItems {
+        action_type: None,
+        start_date:  start_date.map(str::to_string),
+        end_date:    None,
+        limit:       None,
+        encoding:    None,
+    };
+
+    let operator =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let stream = service.do_get(bearer_ticket_request(&operator, &ticket(None))).await.unwrap();
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+    assert_eq!(batches[0].schema(), crate::schema::dlq_item_schema());
+    assert_eq!(batches[0].num_rows(), 2);
+
+    let recent = ticket(Some("2026-01-01T02:00:00Z"));
+    let stream = service.do_get(bearer_ticket_request(&operator, &recent)).await.unwrap();
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+    assert_eq!(batches[0].num_rows(), 1);
+
+    let tenant = super::create_session_token(
+        &tenant_user(serde_json::json!({"tenant_id": "globex"})),
+        TEST_FLIGHT_SECRET,
+    )
+    .unwrap();
+    let stream = service.do_get(bearer_ticket_request(&tenant, &ticket(None))).await.unwrap();
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+    assert_eq!(batches[0].num_rows(), 1);
+    let org_id = batches[0].column_by_name("org_id").unwrap();
+    let org_id = org_id.as_any().downcast_ref::<arrow::array::StringArray>().unwrap();
+    assert_eq!(org_id.value(0), "globex");
+
+    let status = service
+        .do_get(bearer_ticket_request(&operator, &ticket(Some("yesterday"))))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// A `JobHistory` ticket streams one row per attempt and is refused to tenant-scoped callers.
+#[tokio::test]
+async fn test_job_history_export_streams_attempts() {
+    let mut service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    let ticket = FlightTicket::JobHistory {
+        action_type: None,
+        state:       None,
+        start_date:  None,
+        end_date:    None,
+        limit:       None,
+        encoding:    Some(dictionary_encoding(&["action_type", "state"])),
+    };
+    let operator =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+
+    let status = service.do_get(bearer_ticket_request(&operator, &ticket)).await.err().unwrap();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    service.set_delivery_storage(Arc::new(FailedDeliveries));
+    let stream = service.do_get(bearer_ticket_request(&operator, &ticket)).await.unwrap();
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+    assert_eq!(batches[0].num_rows(), 2);
+    assert_eq!(
+        batches[0].column_by_name("state").unwrap().data_type(),
+        &crate::convert::string_dictionary_type()
+    );
+
+    let tenant = super::create_session_token(
+        &tenant_user(serde_json::json!({"tenant_id": "acme"})),
+        TEST_FLIGHT_SECRET,
+    )
+    .unwrap();
+    let status = service.do_get(bearer_ticket_request(&tenant, &ticket)).await.err().unwrap();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+}
+
+/// An encoded `OptimizedView` ticket streams the view with its dictionary columns, and
+/// `GetSchema` reports the encoded schema.
+#[tokio::test]
+async fn test_optimized_view_dictionary_encoding() {
+    let service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::OptimizedView {
+        view:     "ta_orders".to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    Some(5),
+        offset:   None,
+        encoding: Some(dictionary_encoding(&["customer_name"])),
+    };
+
+    let stream = service.do_get(bearer_ticket_request(&token, &ticket)).await.unwrap();
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+    let dictionary_type = crate::convert::string_dictionary_type();
+    assert_eq!(batches.iter().map(arrow::array::RecordBatch::num_rows).sum::<usize>(), 5);
+    for batch in &batches {
+        assert_eq!(batch.column_by_name("customer_name").unwrap().data_type(), &dictionary_type);
+    }
+
+    let descriptor = FlightDescriptor {
+        r#type: 2, // CMD
+        path:   vec![],
+        cmd:    ticket.encode().unwrap().into(),
+    };
+    let schema_result = service.get_schema(Request::new(descriptor)).await.unwrap().into_inner();
+    let schema = arrow::datatypes::Schema::try_from(&schema_result).unwrap();
+    assert_eq!(schema.field_with_name("customer_name").unwrap().data_type(), &dictionary_type);
+}
+
+/// Dictionary-encoding a column the view does not have fails the stream.
+#[tokio::test]
+async fn test_dictionary_encoding_unknown_column_fails() {
+    let service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::OptimizedView {
+        view:     "ta_orders".to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    Some(5),
+        offset:   None,
+        encoding: Some(dictionary_encoding(&["no_such_column"])),
+    };
+
+    let stream = service.do_get(bearer_ticket_request(&token, &ticket)).await.unwrap();
+    let Err(error) = decode_batches(stream.into_inner()).await else {
+        panic!("an unknown dictionary column must fail the stream");
+    };
+    assert!(error.to_string().contains("no_such_column"));
+}
+
+/// Requested IPC compression is applied when the `compression` feature is enabled and
+/// refused otherwise.
+#[tokio::test]
+async fn test_ipc_compression_option() {
+    let mut service = FraiseQLFlightService::new().with_session_secret(TEST_FLIGHT_SECRET);
+    service.set_event_storage(Arc::new(HourlyEvents::new(24)));
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  None,
+        end_date:    None,
+        limit:       None,
+        partition:   None,
+        encoding:    Some(crate::ticket::BatchEncoding {
+            dictionary_columns: vec!["entity_type".to_string()],
+            compression:        Some(crate::ticket::IpcCompression::Zstd),
+            max_batch_bytes:    None,
+        }),
+    };
+
+    let result = service.do_get(bearer_ticket_request(&token, &ticket)).await;
+
+    if cfg!(feature = "compression") {
+        let batches = decode_batches(result.unwrap().into_inner()).await.unwrap();
+        assert_eq!(batches[0].num_rows(), 24);
+    } else {
+        let Err(status) = result else {
+            panic!("compression must be refused without the `compression` feature");
+        };
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
+
+/// Adapter that serves `chunks` chunks of ten `va_users` rows, counting the chunks
+/// the consumer has taken.
+struct ChunkedUsersAdapter {
+    chunks:  usize,
+    fetched: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+// Reason: ArrowDatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl crate::db::ArrowDatabaseAdapter for ChunkedUsersAdapter {
+    async fn execute_raw_query(
+        &self,
+        _sql: &str,
+    ) -> crate::db::DatabaseResult<Vec<std::collections::HashMap<String, serde_json::Value>>> {
+        Ok(Vec::new())
+    }
+
+    async fn execute_raw_query_chunked(
+        &self,
+        _sql: &str,
+        chunk_rows: usize,
+        chunks: tokio::sync::mpsc::Sender<
+            Vec<std::collections::HashMap<String, serde_json::Value>>,
+        >,
+    ) -> crate::db::DatabaseResult<()> {
+        for chunk in 0..self.chunks {
+            let rows = (0..chunk_rows)
+                .map(|i| {
+                    let id = chunk * chunk_rows + i;
+                    serde_json::from_value(serde_json::json!({
+                        "id": id,
+                        "email": format!("user{id}@example.com"),
+                        "name": null,
+                        "created_at": "2024-01-01T00:00:00Z",
+                    }))
+                    .unwrap()
+                })
+                .collect();
+            if chunks.send(rows).await.is_err() {
+                return Ok(());
+            }
+            self.fetched.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+        Ok(())
+    }
+}
+
+/// Without a result cache an `OptimizedView` is streamed from the database chunk by
+/// chunk, and the fetch pauses while the client is not reading.
+#[tokio::test]
+async fn test_optimized_view_fetch_pauses_for_slow_client() {
+    let fetched = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let adapter = ChunkedUsersAdapter {
+        chunks:  50,
+        fetched: Arc::clone(&fetched),
+    };
+    let service = FraiseQLFlightService::new_with_db(Arc::new(adapter))
+        .with_session_secret(TEST_FLIGHT_SECRET)
+        .with_stream_fetch_rows(10);
+    let token =
+        super::create_session_token(&tenant_user(serde_json::json!({})), TEST_FLIGHT_SECRET)
+            .unwrap();
+    let ticket = FlightTicket::OptimizedView {
+        view:     "va_users".to_string(),
+        filter:   None,
+        order_by: None,
+        limit:    None,
+        offset:   None,
+        encoding: None,
+    };
+
+    let stream = service.do_get(bearer_ticket_request(&token, &ticket)).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let paused_at = fetched.load(std::sync::atomic::Ordering::SeqCst);
+    assert!(
+        paused_at < 10,
+        "fetch must pause for an idle client, fetched {paused_at} chunks"
+    );
+
+    let batches = decode_batches(stream.into_inner()).await.unwrap();
+    assert_eq!(batches.iter().map(arrow::array::RecordBatch::num_rows).sum::<usize>(), 500);
+    assert_eq!(fetched.load(std::sync::atomic::Ordering::SeqCst), 50);
+}
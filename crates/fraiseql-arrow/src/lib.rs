@@ -22,6 +22,8 @@
 //! - High-performance columnar data transfer
 //! - Zero-copy deserialization in clients (Python, R, Java)
 //! - Direct integration with data warehouses (`ClickHouse`, Snowflake)
+//! - Local `DuckDB` sink serving aggregates to dashboards (`duckdb_sink` feature)
+//! - `BigQuery` sink over the Storage Write API (`bigquery_sink` feature)
 //!
 //! Arrow columnar format provides better throughput and memory efficiency compared to
 //! row-oriented JSON. See `benches/arrow_vs_json_serialization.rs` for performance measurements.
@@ -78,6 +80,7 @@ pub mod cache;
 pub mod convert;
 pub mod db;
 pub mod db_convert;
+pub mod delivery_storage;
 pub mod error;
 pub mod event_schema;
 pub mod event_storage;
@@ -85,23 +88,51 @@ pub mod exchange_protocol;
 pub mod export;
 pub mod flight_server;
 pub mod metadata;
+pub mod projection;
 pub mod schema;
 pub mod schema_gen;
 pub mod subscription;
 pub mod ticket;
 
+#[cfg(feature = "bigquery_sink")]
+pub mod bigquery_sink;
 #[cfg(feature = "clickhouse")]
 pub mod clickhouse_sink;
+#[cfg(feature = "duckdb_sink")]
+pub mod duckdb_sink;
 
+#[cfg(feature = "bigquery_sink")]
+pub use bigquery_sink::{
+    BigQuerySink, BigQuerySinkConfig, BigQueryTokenProvider, MetadataServerTokenProvider,
+    StaticTokenProvider, bigquery_table_schema,
+};
 pub use cache::QueryCache;
 #[cfg(feature = "clickhouse")]
-pub use clickhouse_sink::{ClickHouseSink, ClickHouseSinkConfig, EventRow};
+pub use clickhouse_sink::{ClickHouseSchemaConfig, ClickHouseSink, ClickHouseSinkConfig, EventRow};
 pub use db::{ArrowDatabaseAdapter, DatabaseError, DatabaseResult};
+pub use delivery_storage::{ArrowDeliveryStorage, DeliveryQuery, DlqRecord, JobAttemptRecord};
+#[cfg(feature = "duckdb_sink")]
+pub use duckdb_sink::{DuckDbSink, DuckDbSinkConfig};
 pub use error::{ArrowFlightError, Result};
 pub use event_storage::{ArrowEventStorage, HistoricalEvent};
 pub use exchange_protocol::{ExchangeMessage, RequestType};
-pub use export::{BatchStats, BulkExporter, ExportFormat};
-pub use flight_server::{FraiseQLFlightService, QueryExecutor};
+#[cfg(feature = "parquet")]
+pub use export::delta::{DeltaCommit, DeltaTableWriter, LakehouseStore, LocalLakehouseStore};
+pub use export::{
+    BatchStats, BulkExporter, ExportFormat,
+    incremental::{
+        ExportWatermark, InMemoryWatermarkStore, IncrementalBatch, IncrementalExport,
+        PostgresWatermarkStore, WatermarkStore,
+    },
+    spill::{DEFAULT_EXPORT_MEMORY_BUDGET, SpillStats, SpilledBatches, SpillingBatchBuffer},
+};
+pub use flight_server::{FlightPeer, FlightRateLimiter, FraiseQLFlightService, QueryExecutor};
 pub use metadata::SchemaRegistry;
-pub use subscription::{EventSubscription, SubscriptionManager};
-pub use ticket::FlightTicket;
+pub use projection::{
+    ProjectionDefinition, ProjectionProgress, ProjectionRunner, ProjectionStatus,
+};
+pub use subscription::{EventSubscription, SubscriptionManager, SubscriptionStreamConfig};
+pub use ticket::{
+    AggregateFunction, AggregateSpec, BatchEncoding, FlightTicket, IpcCompression, PartitionScheme,
+    TicketPartition,
+};
@@ -287,6 +287,16 @@ impl SchemaRegistry {
         Ok(reloaded_count)
     }
 
+    /// Names of all registered views, sorted.
+    ///
+    /// Used by Flight `ListFlights` to enumerate what is queryable.
+    #[must_use]
+    pub fn view_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.schemas.iter().map(|entry| entry.key().clone()).collect();
+        names.sort_unstable();
+        names
+    }
+
     /// Check if a view has a registered schema.
     #[must_use]
     pub fn contains(&self, view_name: &str) -> bool {
@@ -40,6 +40,18 @@ fn test_contains() {
     assert!(registry.contains("va_test"));
 }
 
+#[test]
+fn test_view_names_sorted() {
+    let registry = SchemaRegistry::new();
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+
+    registry.register("va_users", schema.clone());
+    registry.register("ta_orders", schema.clone());
+    registry.register("va_orders", schema);
+
+    assert_eq!(registry.view_names(), vec!["ta_orders", "va_orders", "va_users"]);
+}
+
 #[test]
 fn test_remove() {
     let registry = SchemaRegistry::new();
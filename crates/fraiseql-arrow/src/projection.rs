@@ -0,0 +1,702 @@
+//! Event-sourced projections: read models folded from historical observer events.
+//!
+//! A projection maintains a derived PostgreSQL table (e.g. daily order totals) by
+//! applying a user-declared SQL *fold* to every event of one entity type, oldest
+//! first. Projections are declared in configuration:
+//!
+//! ```toml
+//! [[projections]]
+//! name = "daily_order_totals"
+//! entity_type = "Order"
+//! table = "analytics.daily_order_totals"
+//! setup_sql = """
+//! CREATE TABLE IF NOT EXISTS analytics.daily_order_totals (
+//!     day         DATE    PRIMARY KEY,
+//!     total       NUMERIC NOT NULL,
+//!     order_count BIGINT  NOT NULL
+//! )"""
+//!
+//! [projections.fold]
+//! INSERT = """
+//! INSERT INTO analytics.daily_order_totals AS t (day, total, order_count)
+//! VALUES ({{ event.timestamp }}::timestamptz::date, {{ data.total }}::numeric, 1)
+//! ON CONFLICT (day) DO UPDATE
+//!     SET total = t.total + EXCLUDED.total, order_count = t.order_count + 1"""
+//! ```
+//!
+//! `fold` maps an event type (`INSERT`, `UPDATE`, `DELETE`, `CUSTOM`) to the SQL
+//! applied for it; events of other types are skipped. `{{ … }}` placeholders are
+//! replaced by SQL string literals (or `NULL`), so cast them to the column type:
+//!
+//! | Placeholder | Value |
+//! |---|---|
+//! | `{{ event.id }}`, `{{ event.entity_id }}` | UUID |
+//! | `{{ event.event_type }}`, `{{ event.entity_type }}` | text |
+//! | `{{ event.timestamp }}` | RFC 3339 timestamp |
+//! | `{{ event.user_id }}`, `{{ event.tenant_id }}` | text or `NULL` |
+//! | `{{ data }}` | the event payload as JSON text |
+//! | `{{ data.a.b }}` | a payload field (array indexes allowed); `NULL` when absent |
+//!
+//! # Exactly-once application
+//!
+//! [`ProjectionRunner`] reads events after the projection's checkpoint through
+//! [`ArrowEventStorage::query_events_after`] and applies each page — every fold
+//! statement plus the checkpoint advance — as a single PostgreSQL `DO` block, so a
+//! page is applied completely or not at all. The block takes a per-projection
+//! advisory lock and refuses to apply if the checkpoint moved since it was read, so
+//! two runners on the same projection cannot double-count. Fold statements run
+//! inside PL/pgSQL and must therefore be data-modifying statements (`INSERT`,
+//! `UPDATE`, `DELETE`), not bare `SELECT`s. Checkpoints keep microsecond
+//! precision, which matches the `TIMESTAMPTZ` event timestamps of the PostgreSQL
+//! event store; a backend with finer timestamps could re-fold an event.
+//!
+//! [`ProjectionRunner::rebuild`] truncates the table, clears the checkpoint, and
+//! folds the full history again. [`ProjectionRunner::status`] reports how far
+//! behind each projection is.
+
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    db::ArrowDatabaseAdapter,
+    event_storage::{ArrowEventStorage, HistoricalEvent},
+    export::incremental::{ExportWatermark, is_table_name, sql_literal},
+};
+
+/// Default table holding projection checkpoints.
+pub const DEFAULT_PROJECTION_CHECKPOINT_TABLE: &str = "fraiseql_projection_checkpoints";
+
+/// Default number of events folded per page.
+pub const DEFAULT_PROJECTION_BATCH_SIZE: usize = 500;
+
+/// Upper bound on [`ProjectionDefinition::batch_size`].
+pub const MAX_PROJECTION_BATCH_SIZE: usize = 10_000;
+
+/// Event types a fold can be declared for.
+const FOLD_EVENT_TYPES: [&str; 4] = ["INSERT", "UPDATE", "DELETE", "CUSTOM"];
+
+/// One projection: a table maintained by folding one entity type's events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectionDefinition {
+    /// Unique projection name; the checkpoint key
+    pub name:        String,
+    /// Entity type whose events are folded (e.g. `"Order"`)
+    pub entity_type: String,
+    /// Table the folds maintain, truncated on rebuild (optionally schema-qualified)
+    pub table:       String,
+    /// DDL run by [`ProjectionRunner::ensure_tables`], typically `CREATE TABLE IF NOT EXISTS`
+    #[serde(default)]
+    pub setup_sql:   Option<String>,
+    /// Fold SQL template per event type
+    pub fold:        BTreeMap<String, String>,
+    /// Events folded per page (and per transaction)
+    #[serde(default = "default_batch_size")]
+    pub batch_size:  usize,
+}
+
+const fn default_batch_size() -> usize {
+    DEFAULT_PROJECTION_BATCH_SIZE
+}
+
+impl ProjectionDefinition {
+    /// Validate the definition, including every fold template's placeholders.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the name, entity type, or table is invalid, the fold map
+    /// is empty or names an unknown event type, a template is empty, unclosed, or
+    /// uses an unknown placeholder, or the batch size is out of range.
+    pub fn validate(&self) -> Result<(), String> {
+        let valid_name = !self.name.is_empty()
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if !valid_name {
+            return Err(format!(
+                "Invalid projection name '{}': use letters, digits, '_', '-', '.'",
+                self.name
+            ));
+        }
+        if self.entity_type.trim().is_empty() {
+            return Err(format!("Projection '{}' has an empty entity_type", self.name));
+        }
+        if !is_table_name(&self.table) {
+            return Err(format!("Invalid projection table name: '{}'", self.table));
+        }
+        if self.fold.is_empty() {
+            return Err(format!("Projection '{}' declares no fold", self.name));
+        }
+        for (event_type, template) in &self.fold {
+            if !FOLD_EVENT_TYPES.contains(&event_type.as_str()) {
+                return Err(format!(
+                    "Projection '{}' folds unknown event type '{event_type}' (expected one of {})",
+                    self.name,
+                    FOLD_EVENT_TYPES.join(", ")
+                ));
+            }
+            if template.trim().is_empty() {
+                return Err(format!("Projection '{}' has an empty {event_type} fold", self.name));
+            }
+            for segment in parse_template(template)
+                .map_err(|e| format!("Projection '{}' {event_type} fold: {e}", self.name))?
+            {
+                if let Segment::Placeholder(key) = segment {
+                    if !is_known_placeholder(key) {
+                        return Err(format!(
+                            "Projection '{}' {event_type} fold uses unknown placeholder \
+                             '{{{{ {key} }}}}'",
+                            self.name
+                        ));
+                    }
+                }
+            }
+        }
+        if self.batch_size == 0 || self.batch_size > MAX_PROJECTION_BATCH_SIZE {
+            return Err(format!(
+                "Projection '{}' batch_size must be between 1 and {MAX_PROJECTION_BATCH_SIZE}, \
+                 got {}",
+                self.name, self.batch_size
+            ));
+        }
+        Ok(())
+    }
+
+    /// Render the fold statement for `event`, or `None` if its event type has no fold.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the template is malformed or uses an unknown placeholder.
+    pub fn render_fold(&self, event: &HistoricalEvent) -> Result<Option<String>, String> {
+        let Some(template) = self.fold.get(&event.event_type) else {
+            return Ok(None);
+        };
+        let mut sql = String::with_capacity(template.len());
+        for segment in parse_template(template)? {
+            match segment {
+                Segment::Text(text) => sql.push_str(text),
+                Segment::Placeholder(key) => sql.push_str(&placeholder_literal(key, event)?),
+            }
+        }
+        Ok(Some(sql.trim().trim_end_matches(';').to_string()))
+    }
+}
+
+/// A piece of a fold template.
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+/// Split a fold template into literal text and `{{ key }}` placeholders.
+fn parse_template(template: &str) -> Result<Vec<Segment<'_>>, String> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find("{{") {
+        segments.push(Segment::Text(&rest[..open]));
+        let inner = &rest[open + 2..];
+        let close = inner.find("}}").ok_or_else(|| "unclosed '{{' placeholder".to_string())?;
+        segments.push(Segment::Placeholder(inner[..close].trim()));
+        rest = &inner[close + 2..];
+    }
+    segments.push(Segment::Text(rest));
+    Ok(segments)
+}
+
+fn is_known_placeholder(key: &str) -> bool {
+    matches!(
+        key,
+        "event.id"
+            | "event.event_type"
+            | "event.entity_type"
+            | "event.entity_id"
+            | "event.timestamp"
+            | "event.user_id"
+            | "event.tenant_id"
+            | "data"
+    ) || key
+        .strip_prefix("data.")
+        .is_some_and(|path| path.split('.').all(|s| !s.is_empty()))
+}
+
+/// Render placeholder `key` of `event` as a SQL string literal, or `NULL`.
+fn placeholder_literal(key: &str, event: &HistoricalEvent) -> Result<String, String> {
+    let value = match key {
+        "event.id" => Some(event.id.to_string()),
+        "event.event_type" => Some(event.event_type.clone()),
+        "event.entity_type" => Some(event.entity_type.clone()),
+        "event.entity_id" => Some(event.entity_id.to_string()),
+        "event.timestamp" => Some(event.timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)),
+        "event.user_id" => event.user_id.clone(),
+        "event.tenant_id" => event.tenant_id.clone(),
+        "data" => Some(event.data.to_string()),
+        _ if is_known_placeholder(key) => {
+            key.strip_prefix("data.").and_then(|path| json_path(&event.data, path))
+        },
+        _ => return Err(format!("unknown placeholder '{{{{ {key} }}}}'")),
+    };
+    Ok(value.map_or_else(|| "NULL".to_string(), |v| sql_literal(&v)))
+}
+
+/// Look up a dotted path in `data`; strings render bare, other scalars and
+/// containers as JSON text, and `null` or a missing field as `None`.
+fn json_path(data: &Value, path: &str) -> Option<String> {
+    let mut current = data;
+    for segment in path.split('.') {
+        current = match current {
+            Value::Object(map) => map.get(segment)?,
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    match current {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Outcome of folding one or more pages of a projection.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectionProgress {
+    /// Events a fold was applied for
+    pub events_applied: usize,
+    /// Events read but without a fold for their event type
+    pub events_skipped: usize,
+    /// Checkpoint after the last applied page, if any page was applied
+    pub watermark:      Option<ExportWatermark>,
+    /// Whether the projection has folded every stored event
+    pub caught_up:      bool,
+}
+
+/// Runtime state and lag of one projection, as seen by this runner.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProjectionStatus {
+    /// Projection name
+    pub name:           String,
+    /// Events folded by this runner since it started (or since the last rebuild)
+    pub events_applied: u64,
+    /// Timestamp of the last folded event
+    pub last_event_at:  Option<DateTime<Utc>>,
+    /// Whether the last run reached the end of the stored events
+    pub caught_up:      bool,
+    /// Seconds between the last folded event and the last run; `0` when caught up
+    pub lag_seconds:    f64,
+    /// When the projection was last advanced
+    pub last_run_at:    Option<DateTime<Utc>>,
+    /// Error of the last failed run, cleared by the next successful one
+    pub last_error:     Option<String>,
+}
+
+impl ProjectionStatus {
+    fn new(name: &str) -> Self {
+        Self {
+            name:           name.to_string(),
+            events_applied: 0,
+            last_event_at:  None,
+            caught_up:      false,
+            lag_seconds:    0.0,
+            last_run_at:    None,
+            last_error:     None,
+        }
+    }
+}
+
+/// Folds historical events into projection tables.
+///
+/// Call [`ensure_tables`](Self::ensure_tables) once at startup, then either
+/// [`catch_up`](Self::catch_up) on demand or spawn [`run`](Self::run).
+pub struct ProjectionRunner {
+    storage:          Arc<dyn ArrowEventStorage>,
+    adapter:          Arc<dyn ArrowDatabaseAdapter>,
+    checkpoint_table: String,
+    status:           DashMap<String, ProjectionStatus>,
+}
+
+impl ProjectionRunner {
+    /// Create a runner using the [`DEFAULT_PROJECTION_CHECKPOINT_TABLE`].
+    #[must_use]
+    pub fn new(
+        storage: Arc<dyn ArrowEventStorage>,
+        adapter: Arc<dyn ArrowDatabaseAdapter>,
+    ) -> Self {
+        Self {
+            storage,
+            adapter,
+            checkpoint_table: DEFAULT_PROJECTION_CHECKPOINT_TABLE.to_string(),
+            status: DashMap::new(),
+        }
+    }
+
+    /// Use a different checkpoint table (optionally schema-qualified).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `table` is not a plain, optionally schema-qualified SQL identifier.
+    pub fn with_checkpoint_table(mut self, table: impl Into<String>) -> Result<Self, String> {
+        let table = table.into();
+        if !is_table_name(&table) {
+            return Err(format!("Invalid projection checkpoint table name: '{table}'"));
+        }
+        self.checkpoint_table = table;
+        Ok(self)
+    }
+
+    /// Validate `projections`, create the checkpoint table, and run each
+    /// projection's `setup_sql`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a definition is invalid, names are duplicated, or a
+    /// statement fails.
+    pub async fn ensure_tables(&self, projections: &[ProjectionDefinition]) -> Result<(), String> {
+        let mut seen = std::collections::HashSet::new();
+        for projection in projections {
+            projection.validate()?;
+            if !seen.insert(projection.name.as_str()) {
+                return Err(format!("Duplicate projection name '{}'", projection.name));
+            }
+        }
+
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS {} (\
+               projection     TEXT        PRIMARY KEY, \
+               last_event_id  UUID        NOT NULL, \
+               last_timestamp TIMESTAMPTZ NOT NULL, \
+               events_applied BIGINT      NOT NULL DEFAULT 0, \
+               updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()\
+             )",
+            self.checkpoint_table
+        );
+        self.execute(&sql, "create projection checkpoint table").await?;
+
+        for projection in projections {
+            if let Some(setup) = &projection.setup_sql {
+                self.execute(setup, &format!("set up projection '{}'", projection.name)).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Load the committed checkpoint of projection `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the checkpoint table cannot be read or holds an invalid row.
+    pub async fn checkpoint(&self, name: &str) -> Result<Option<ExportWatermark>, String> {
+        let sql = format!(
+            "SELECT last_event_id::text AS last_event_id, \
+             to_char(last_timestamp AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS.US\"Z\"') \
+             AS last_timestamp FROM {} WHERE projection = {}",
+            self.checkpoint_table,
+            sql_literal(name)
+        );
+        let rows = self
+            .adapter
+            .execute_raw_query(&sql)
+            .await
+            .map_err(|e| format!("Failed to load checkpoint of projection '{name}': {e}"))?;
+        let Some(row) = rows.first() else {
+            return Ok(None);
+        };
+
+        let field = |column: &str| {
+            row.get(column)
+                .and_then(Value::as_str)
+                .ok_or_else(|| format!("Checkpoint row for projection '{name}' has no {column}"))
+        };
+        let last_event_id = Uuid::parse_str(field("last_event_id")?)
+            .map_err(|e| format!("Invalid checkpoint event id for projection '{name}': {e}"))?;
+        let last_timestamp = DateTime::parse_from_rfc3339(field("last_timestamp")?)
+            .map_err(|e| format!("Invalid checkpoint timestamp for projection '{name}': {e}"))?
+            .with_timezone(&Utc);
+        Ok(Some(ExportWatermark {
+            last_event_id,
+            last_timestamp,
+        }))
+    }
+
+    /// Fold the next page of events after the projection's checkpoint.
+    ///
+    /// The page's folds and the checkpoint advance are applied atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the definition is invalid, events cannot be read, a fold
+    /// cannot be rendered, the checkpoint moved concurrently, or a statement fails.
+    /// On error nothing from the page is applied.
+    pub async fn step(
+        &self,
+        projection: &ProjectionDefinition,
+    ) -> Result<ProjectionProgress, String> {
+        projection.validate()?;
+        let result = self.apply_next_page(projection).await;
+        self.record_outcome(projection, &result);
+        result
+    }
+
+    /// Fold pages until every stored event has been applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing page's error; pages applied before it stay applied.
+    pub async fn catch_up(
+        &self,
+        projection: &ProjectionDefinition,
+    ) -> Result<ProjectionProgress, String> {
+        let mut total = ProjectionProgress::default();
+        loop {
+            let page = self.step(projection).await?;
+            total.events_applied += page.events_applied;
+            total.events_skipped += page.events_skipped;
+            total.watermark = page.watermark.or(total.watermark);
+            if page.caught_up {
+                total.caught_up = true;
+                return Ok(total);
+            }
+        }
+    }
+
+    /// Rebuild a projection from scratch: truncate its table, clear its
+    /// checkpoint, and fold the full event history again.
+    ///
+    /// The truncate and checkpoint reset are atomic; readers then see the table
+    /// fill up again as the catch-up proceeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the reset or the catch-up fails.
+    pub async fn rebuild(
+        &self,
+        projection: &ProjectionDefinition,
+    ) -> Result<ProjectionProgress, String> {
+        projection.validate()?;
+        let body = format!(
+            "{lock};\nTRUNCATE {table};\nDELETE FROM {checkpoints} WHERE projection = {name};",
+            lock = advisory_lock(&projection.name),
+            table = projection.table,
+            checkpoints = self.checkpoint_table,
+            name = sql_literal(&projection.name),
+        );
+        self.execute(&do_block(&body)?, &format!("reset projection '{}'", projection.name))
+            .await?;
+        self.status
+            .insert(projection.name.clone(), ProjectionStatus::new(&projection.name));
+        info!(projection = %projection.name, "Projection reset; rebuilding from history");
+        self.catch_up(projection).await
+    }
+
+    /// Keep every projection caught up, polling every `poll_interval`.
+    ///
+    /// Never returns: spawn it on a task and abort the task to stop. A failing
+    /// projection is logged, recorded in its [`status`](Self::status), and retried
+    /// on the next tick without holding back the others.
+    pub async fn run(&self, projections: &[ProjectionDefinition], poll_interval: Duration) {
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            for projection in projections {
+                match self.catch_up(projection).await {
+                    Ok(progress) if progress.events_applied > 0 => debug!(
+                        projection = %projection.name,
+                        applied = progress.events_applied,
+                        "Projection advanced"
+                    ),
+                    Ok(_) => {},
+                    Err(e) => warn!(projection = %projection.name, "Projection failed: {e}"),
+                }
+            }
+        }
+    }
+
+    /// Current status of every projection this runner has advanced, by name.
+    #[must_use]
+    pub fn status(&self) -> Vec<ProjectionStatus> {
+        let mut statuses: Vec<_> = self.status.iter().map(|entry| entry.value().clone()).collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        statuses
+    }
+
+    async fn apply_next_page(
+        &self,
+        projection: &ProjectionDefinition,
+    ) -> Result<ProjectionProgress, String> {
+        let checkpoint = self.checkpoint(&projection.name).await?;
+        let events = self
+            .storage
+            .query_events_after(
+                &projection.entity_type,
+                checkpoint.as_ref(),
+                Some(projection.batch_size),
+            )
+            .await?;
+        let caught_up = events.len() < projection.batch_size;
+        let Some(last) = events.last() else {
+            return Ok(ProjectionProgress {
+                caught_up,
+                ..ProjectionProgress::default()
+            });
+        };
+        let watermark = ExportWatermark::after(last);
+
+        let mut statements = vec![
+            advisory_lock(&projection.name),
+            self.checkpoint_guard(projection, checkpoint.as_ref()),
+        ];
+        let mut applied = 0;
+        for event in &events {
+            if let Some(sql) = projection.render_fold(event)? {
+                statements.push(sql);
+                applied += 1;
+            }
+        }
+        statements.push(self.checkpoint_upsert(projection, &watermark, applied));
+
+        let mut body = String::new();
+        for statement in &statements {
+            body.push_str(statement);
+            body.push_str(";\n");
+        }
+        self.execute(&do_block(&body)?, &format!("apply projection '{}'", projection.name))
+            .await?;
+
+        Ok(ProjectionProgress {
+            events_applied: applied,
+            events_skipped: events.len() - applied,
+            watermark: Some(watermark),
+            caught_up,
+        })
+    }
+
+    /// PL/pgSQL that aborts the block if the checkpoint is no longer `expected`.
+    fn checkpoint_guard(
+        &self,
+        projection: &ProjectionDefinition,
+        expected: Option<&ExportWatermark>,
+    ) -> String {
+        let name = sql_literal(&projection.name);
+        let moved = expected.map_or_else(
+            || {
+                format!(
+                    "EXISTS (SELECT 1 FROM {} WHERE projection = {name})",
+                    self.checkpoint_table
+                )
+            },
+            |watermark| {
+                format!(
+                    "(SELECT ROW(last_event_id, last_timestamp) FROM {} WHERE projection = {name}) \
+                     IS DISTINCT FROM ROW({}::uuid, {}::timestamptz)",
+                    self.checkpoint_table,
+                    sql_literal(&watermark.last_event_id.to_string()),
+                    sql_literal(&timestamp_text(watermark.last_timestamp)),
+                )
+            },
+        );
+        format!(
+            "IF {moved} THEN RAISE EXCEPTION 'projection % checkpoint moved concurrently', {name}; \
+             END IF"
+        )
+    }
+
+    fn checkpoint_upsert(
+        &self,
+        projection: &ProjectionDefinition,
+        watermark: &ExportWatermark,
+        applied: usize,
+    ) -> String {
+        format!(
+            "INSERT INTO {table} (projection, last_event_id, last_timestamp, events_applied) \
+             VALUES ({name}, {id}::uuid, {ts}::timestamptz, {applied}) \
+             ON CONFLICT (projection) DO UPDATE SET \
+               last_event_id = EXCLUDED.last_event_id, \
+               last_timestamp = EXCLUDED.last_timestamp, \
+               events_applied = {table}.events_applied + EXCLUDED.events_applied, \
+               updated_at = NOW()",
+            table = self.checkpoint_table,
+            name = sql_literal(&projection.name),
+            id = sql_literal(&watermark.last_event_id.to_string()),
+            ts = sql_literal(&timestamp_text(watermark.last_timestamp)),
+        )
+    }
+
+    fn record_outcome(
+        &self,
+        projection: &ProjectionDefinition,
+        result: &Result<ProjectionProgress, String>,
+    ) {
+        let now = Utc::now();
+        let mut status = self
+            .status
+            .entry(projection.name.clone())
+            .or_insert_with(|| ProjectionStatus::new(&projection.name));
+        status.last_run_at = Some(now);
+        match result {
+            Ok(progress) => {
+                status.events_applied += progress.events_applied as u64;
+                if let Some(watermark) = progress.watermark {
+                    status.last_event_at = Some(watermark.last_timestamp);
+                }
+                status.caught_up = progress.caught_up;
+                status.lag_seconds = if progress.caught_up {
+                    0.0
+                } else {
+                    status.last_event_at.map_or(0.0, |at| seconds_between(at, now))
+                };
+                status.last_error = None;
+            },
+            Err(e) => {
+                status.caught_up = false;
+                status.last_error = Some(e.clone());
+            },
+        }
+    }
+
+    async fn execute(&self, sql: &str, what: &str) -> Result<(), String> {
+        self.adapter
+            .execute_raw_query(sql)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Failed to {what}: {e}"))
+    }
+}
+
+/// PL/pgSQL statement serializing writers of projection `name` for the transaction.
+fn advisory_lock(name: &str) -> String {
+    format!(
+        "PERFORM pg_advisory_xact_lock(hashtext({}))",
+        sql_literal(&format!("fraiseql_projection:{name}"))
+    )
+}
+
+/// Wrap PL/pgSQL `body` in an anonymous `DO` block under a fresh dollar-quote tag,
+/// so the whole body runs as one atomic statement.
+fn do_block(body: &str) -> Result<String, String> {
+    let tag = format!("$fraiseql_{}$", Uuid::new_v4().simple());
+    if body.contains(&tag) {
+        return Err("Projection SQL collides with its dollar-quote tag".to_string());
+    }
+    Ok(format!("DO {tag}\nBEGIN\n{body}END\n{tag}"))
+}
+
+/// Timestamp text at the checkpoint column's microsecond precision.
+fn timestamp_text(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn seconds_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    // Reason: lag is reported with millisecond resolution; precision loss above 2^53 ms is moot.
+    #[allow(clippy::cast_precision_loss)]
+    let millis = (to - from).num_milliseconds().max(0) as f64;
+    millis / 1000.0
+}
+
+#[cfg(test)]
+mod tests;
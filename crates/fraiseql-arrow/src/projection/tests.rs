@@ -0,0 +1,394 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use async_trait::async_trait;
+
+use super::*;
+use crate::db::{DatabaseError, DatabaseResult};
+
+const DAILY_TOTALS: &str = r#"
+[[projections]]
+name = "daily_order_totals"
+entity_type = "Order"
+table = "analytics.daily_order_totals"
+setup_sql = "CREATE TABLE IF NOT EXISTS analytics.daily_order_totals (day DATE PRIMARY KEY, total NUMERIC NOT NULL)"
+
+[projections.fold]
+INSERT = """
+INSERT INTO analytics.daily_order_totals AS t (day, total)
+VALUES ({{ event.timestamp }}::timestamptz::date, {{ data.total }}::numeric)
+ON CONFLICT (day) DO UPDATE SET total = t.total + EXCLUDED.total;"""
+DELETE = "UPDATE analytics.daily_order_totals SET total = total - {{ data.total }}::numeric WHERE day = {{ event.timestamp }}::timestamptz::date"
+"#;
+
+#[derive(Deserialize)]
+struct Config {
+    projections: Vec<ProjectionDefinition>,
+}
+
+fn daily_totals() -> ProjectionDefinition {
+    toml::from_str::<Config>(DAILY_TOTALS).unwrap().projections.remove(0)
+}
+
+fn event(n: u128, seconds: i64, event_type: &str) -> HistoricalEvent {
+    HistoricalEvent {
+        id:          Uuid::from_u128(n),
+        event_type:  event_type.to_string(),
+        entity_type: "Order".to_string(),
+        entity_id:   Uuid::from_u128(n + 100),
+        data:        serde_json::json!({ "total": 10, "customer": { "name": "O'Brien" } }),
+        user_id:     None,
+        tenant_id:   Some("acme".to_string()),
+        timestamp:   DateTime::from_timestamp(1_700_000_000 + seconds, 0).unwrap(),
+    }
+}
+
+/// Event storage returning its events newest first, like real backends.
+struct VecEvents(Vec<HistoricalEvent>);
+
+// Reason: ArrowEventStorage is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl ArrowEventStorage for VecEvents {
+    async fn query_events(
+        &self,
+        _entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+        limit: Option<usize>,
+    ) -> Result<Vec<HistoricalEvent>, String> {
+        let mut events: Vec<_> = self
+            .0
+            .iter()
+            .filter(|e| start_date.is_none_or(|s| e.timestamp >= s))
+            .filter(|e| end_date.is_none_or(|end| e.timestamp <= end))
+            .cloned()
+            .collect();
+        events.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
+        Ok(events)
+    }
+
+    async fn count_events(
+        &self,
+        entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+    ) -> Result<usize, String> {
+        Ok(self.query_events(entity_type, start_date, end_date, None).await?.len())
+    }
+}
+
+/// Adapter recording its SQL and emulating the checkpoint table: a `DO` block
+/// commits the checkpoint it upserts, and a rebuild's `TRUNCATE` clears it.
+#[derive(Default)]
+struct FakeDatabase {
+    statements: Mutex<Vec<String>>,
+    checkpoint: Mutex<Option<(String, String)>>,
+    fail_folds: AtomicBool,
+}
+
+impl FakeDatabase {
+    fn blocks(&self) -> Vec<String> {
+        self.statements
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| s.starts_with("DO "))
+            .cloned()
+            .collect()
+    }
+}
+
+/// The first two quoted literals after `VALUES (` in the checkpoint upsert.
+fn upserted_checkpoint(block: &str) -> (String, String) {
+    let insert = block.rfind("INSERT INTO fraiseql_projection_checkpoints").unwrap();
+    let values = &block[insert..];
+    let values = &values[values.find("VALUES (").unwrap()..];
+    let literals: Vec<&str> = values.split('\'').skip(1).step_by(2).collect();
+    (literals[1].to_string(), literals[2].to_string())
+}
+
+// Reason: ArrowDatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl ArrowDatabaseAdapter for FakeDatabase {
+    async fn execute_raw_query(
+        &self,
+        sql: &str,
+    ) -> DatabaseResult<Vec<HashMap<String, serde_json::Value>>> {
+        self.statements.lock().unwrap().push(sql.to_string());
+        if sql.starts_with("SELECT") {
+            return Ok(self
+                .checkpoint
+                .lock()
+                .unwrap()
+                .clone()
+                .map(|(id, ts)| {
+                    HashMap::from([
+                        ("last_event_id".to_string(), serde_json::json!(id)),
+                        ("last_timestamp".to_string(), serde_json::json!(ts)),
+                    ])
+                })
+                .into_iter()
+                .collect());
+        }
+        if sql.starts_with("DO ") {
+            if sql.contains("TRUNCATE") {
+                *self.checkpoint.lock().unwrap() = None;
+            } else if self.fail_folds.load(Ordering::SeqCst) {
+                return Err(DatabaseError::new("division by zero"));
+            } else {
+                *self.checkpoint.lock().unwrap() = Some(upserted_checkpoint(sql));
+            }
+        }
+        Ok(Vec::new())
+    }
+}
+
+fn runner(events: Vec<HistoricalEvent>) -> (ProjectionRunner, Arc<FakeDatabase>) {
+    let db = Arc::new(FakeDatabase::default());
+    (ProjectionRunner::new(Arc::new(VecEvents(events)), db.clone()), db)
+}
+
+#[test]
+fn test_definition_parses_from_toml_with_defaults() {
+    let projection = daily_totals();
+    assert_eq!(projection.name, "daily_order_totals");
+    assert_eq!(projection.batch_size, DEFAULT_PROJECTION_BATCH_SIZE);
+    assert_eq!(projection.fold.len(), 2);
+    projection.validate().unwrap();
+}
+
+#[test]
+fn test_validate_rejects_bad_definitions() {
+    let with = |edit: fn(&mut ProjectionDefinition)| {
+        let mut projection = daily_totals();
+        edit(&mut projection);
+        projection.validate().unwrap_err()
+    };
+
+    assert!(with(|p| p.name = "daily totals".to_string()).contains("Invalid projection name"));
+    assert!(with(|p| p.table = "t; DROP TABLE x".to_string()).contains("table name"));
+    assert!(with(|p| p.fold.clear()).contains("no fold"));
+    assert!(
+        with(|p| {
+            p.fold.insert("insert".to_string(), "DELETE FROM t".to_string());
+        })
+        .contains("unknown event type 'insert'")
+    );
+    assert!(
+        with(|p| {
+            p.fold.insert(
+                "UPDATE".to_string(),
+                "DELETE FROM t WHERE id = {{ event.uuid }}".to_string(),
+            );
+        })
+        .contains("unknown placeholder '{{ event.uuid }}'")
+    );
+    assert!(
+        with(|p| {
+            p.fold
+                .insert("UPDATE".to_string(), "DELETE FROM t WHERE id = {{ event.id".to_string());
+        })
+        .contains("unclosed")
+    );
+    assert!(with(|p| p.batch_size = 0).contains("batch_size"));
+}
+
+#[test]
+fn test_render_fold_quotes_placeholders_as_literals() {
+    let mut projection = daily_totals();
+    projection.fold.insert(
+        "UPDATE".to_string(),
+        "UPDATE t SET name = {{ data.customer.name }}, note = {{ data.missing }}, \
+         tenant = {{ event.tenant_id }}, user_id = {{ event.user_id }}, \
+         entity = {{ event.entity_id }}::uuid;"
+            .to_string(),
+    );
+
+    let sql = projection.render_fold(&event(1, 0, "UPDATE")).unwrap().unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE t SET name = 'O''Brien', note = NULL, tenant = 'acme', user_id = NULL, \
+         entity = '00000000-0000-0000-0000-000000000065'::uuid"
+    );
+
+    let insert = projection.render_fold(&event(1, 0, "INSERT")).unwrap().unwrap();
+    assert!(
+        insert.contains("VALUES ('2023-11-14T22:13:20.000000Z'::timestamptz::date, '10'::numeric)")
+    );
+    assert!(!insert.ends_with(';'));
+
+    assert_eq!(projection.render_fold(&event(1, 0, "CUSTOM")).unwrap(), None);
+}
+
+#[test]
+fn test_json_path_walks_objects_and_arrays() {
+    let data =
+        serde_json::json!({ "items": [{ "sku": "A-1", "qty": 2 }], "flag": true, "none": null });
+    assert_eq!(json_path(&data, "items.0.sku").as_deref(), Some("A-1"));
+    assert_eq!(json_path(&data, "items.0.qty").as_deref(), Some("2"));
+    assert_eq!(json_path(&data, "flag").as_deref(), Some("true"));
+    assert_eq!(json_path(&data, "items").as_deref(), Some(r#"[{"qty":2,"sku":"A-1"}]"#));
+    assert_eq!(json_path(&data, "none"), None);
+    assert_eq!(json_path(&data, "items.1.sku"), None);
+    assert_eq!(json_path(&data, "flag.deeper"), None);
+}
+
+#[tokio::test]
+async fn test_catch_up_folds_pages_atomically_with_checkpoint() {
+    let events = vec![
+        event(1, 0, "INSERT"),
+        event(2, 10, "UPDATE"),
+        event(3, 20, "INSERT"),
+        event(4, 30, "DELETE"),
+        event(5, 40, "INSERT"),
+    ];
+    let (runner, db) = runner(events);
+    let mut projection = daily_totals();
+    projection.batch_size = 2;
+
+    let progress = runner.catch_up(&projection).await.unwrap();
+
+    assert_eq!(progress.events_applied, 4);
+    assert_eq!(progress.events_skipped, 1, "UPDATE has no fold");
+    assert!(progress.caught_up);
+    assert_eq!(progress.watermark.unwrap().last_event_id, Uuid::from_u128(5));
+
+    let blocks = db.blocks();
+    assert_eq!(blocks.len(), 3, "pages of 2, 2 and 1 events");
+    for block in &blocks {
+        assert!(block.contains(
+            "PERFORM pg_advisory_xact_lock(hashtext('fraiseql_projection:daily_order_totals'))"
+        ));
+        assert!(block.contains("INSERT INTO fraiseql_projection_checkpoints"));
+        let tag = block.split_whitespace().nth(1).unwrap();
+        assert!(tag.starts_with("$fraiseql_") && block.ends_with(tag));
+    }
+    assert!(blocks[0].contains("IF EXISTS (SELECT 1 FROM fraiseql_projection_checkpoints"));
+    assert!(blocks[1].contains(
+        "IS DISTINCT FROM ROW('00000000-0000-0000-0000-000000000002'::uuid, \
+         '2023-11-14T22:13:30.000000Z'::timestamptz)"
+    ));
+
+    let checkpoint = runner.checkpoint("daily_order_totals").await.unwrap().unwrap();
+    assert_eq!(checkpoint.last_event_id, Uuid::from_u128(5));
+
+    let status = runner.status();
+    assert_eq!(status.len(), 1);
+    assert_eq!(status[0].events_applied, 4);
+    assert!(status[0].caught_up);
+    assert!(status[0].lag_seconds.abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn test_catch_up_without_new_events_runs_no_block() {
+    let (runner, db) = runner(vec![event(1, 0, "INSERT")]);
+    let projection = daily_totals();
+
+    runner.catch_up(&projection).await.unwrap();
+    let again = runner.catch_up(&projection).await.unwrap();
+
+    assert_eq!(
+        again,
+        ProjectionProgress {
+            caught_up: true,
+            ..ProjectionProgress::default()
+        }
+    );
+    assert_eq!(db.blocks().len(), 1);
+}
+
+#[tokio::test]
+async fn test_rebuild_resets_table_and_checkpoint_then_refolds() {
+    let (runner, db) = runner(vec![event(1, 0, "INSERT"), event(2, 10, "INSERT")]);
+    let projection = daily_totals();
+    runner.catch_up(&projection).await.unwrap();
+
+    let progress = runner.rebuild(&projection).await.unwrap();
+
+    assert_eq!(progress.events_applied, 2);
+    let blocks = db.blocks();
+    assert_eq!(blocks.len(), 3);
+    assert!(blocks[1].contains("TRUNCATE analytics.daily_order_totals;"));
+    assert!(blocks[1].contains(
+        "DELETE FROM fraiseql_projection_checkpoints WHERE projection = 'daily_order_totals';"
+    ));
+    assert!(blocks[2].contains("IF EXISTS"), "refold starts from an empty checkpoint");
+    assert_eq!(runner.status()[0].events_applied, 2, "status restarts at the rebuild");
+}
+
+#[tokio::test]
+async fn test_failed_page_keeps_checkpoint_and_records_error() {
+    let (runner, db) = runner(vec![event(1, 0, "INSERT")]);
+    db.fail_folds.store(true, Ordering::SeqCst);
+    let projection = daily_totals();
+
+    let err = runner.step(&projection).await.unwrap_err();
+
+    assert!(err.contains("apply projection 'daily_order_totals'"));
+    assert!(err.contains("division by zero"));
+    assert!(runner.checkpoint("daily_order_totals").await.unwrap().is_none());
+    let status = runner.status();
+    assert!(!status[0].caught_up);
+    assert_eq!(status[0].last_error.as_deref(), Some(err.as_str()));
+
+    db.fail_folds.store(false, Ordering::SeqCst);
+    runner.step(&projection).await.unwrap();
+    assert_eq!(runner.status()[0].last_error, None);
+}
+
+#[tokio::test]
+async fn test_lag_is_reported_while_behind() {
+    let events = (1..=3).map(|n| event(n, 0, "INSERT")).collect();
+    let (runner, _db) = runner(events);
+    let mut projection = daily_totals();
+    projection.batch_size = 2;
+
+    let first = runner.step(&projection).await.unwrap();
+
+    assert!(!first.caught_up);
+    let status = &runner.status()[0];
+    assert!(!status.caught_up);
+    assert!(status.lag_seconds > 0.0, "the events are from 2023");
+}
+
+#[tokio::test]
+async fn test_ensure_tables_creates_checkpoint_table_and_runs_setup() {
+    let (runner, db) = runner(Vec::new());
+    let projection = daily_totals();
+
+    runner.ensure_tables(std::slice::from_ref(&projection)).await.unwrap();
+
+    let statements = db.statements.lock().unwrap().clone();
+    assert_eq!(statements.len(), 2);
+    assert!(
+        statements[0].starts_with("CREATE TABLE IF NOT EXISTS fraiseql_projection_checkpoints")
+    );
+    assert_eq!(statements[1], projection.setup_sql.clone().unwrap());
+
+    let err = runner.ensure_tables(&[projection.clone(), projection]).await.unwrap_err();
+    assert!(err.contains("Duplicate projection name"));
+}
+
+#[test]
+fn test_checkpoint_table_name_is_validated() {
+    let (runner, _db) = runner(Vec::new());
+    assert!(runner.with_checkpoint_table("bad name").is_err());
+    let (runner, _db) = self::runner(Vec::new());
+    assert_eq!(
+        runner.with_checkpoint_table("analytics.checkpoints").unwrap().checkpoint_table,
+        "analytics.checkpoints"
+    );
+}
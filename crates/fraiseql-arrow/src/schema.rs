@@ -7,6 +7,8 @@ use std::sync::Arc;
 
 use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
 
+use crate::ticket::{AggregateFunction, AggregateSpec};
+
 /// Arrow schema for GraphQL query results.
 ///
 /// This is a placeholder schema.
@@ -56,6 +58,33 @@ pub fn observer_event_schema() -> Arc<Schema> {
     ]))
 }
 
+/// Arrow schema for incremental (CDC) event exports.
+///
+/// The [`observer_event_schema`] columns followed by:
+///
+/// - `deleted`: `true` for delete tombstones, `false` for inserts and updates
+#[must_use]
+pub fn cdc_event_schema() -> Arc<Schema> {
+    let mut fields: Vec<Field> =
+        observer_event_schema().fields().iter().map(|f| f.as_ref().clone()).collect();
+    fields.push(Field::new("deleted", DataType::Boolean, false));
+    Arc::new(Schema::new(fields))
+}
+
+/// Arrow schema of an `Aggregate` ticket's result.
+///
+/// Group-by columns come first as nullable `Utf8`, followed by one column per aggregate:
+/// `Int64` for `count`, `Float64` otherwise.
+#[must_use]
+pub fn aggregate_result_schema(group_by: &[String], aggregates: &[AggregateSpec]) -> Arc<Schema> {
+    let groups = group_by.iter().map(|column| Field::new(column, DataType::Utf8, true));
+    let values = aggregates.iter().map(|spec| match spec.function {
+        AggregateFunction::Count => Field::new(&spec.alias, DataType::Int64, false),
+        _ => Field::new(&spec.alias, DataType::Float64, true),
+    });
+    Arc::new(Schema::new(groups.chain(values).collect::<Vec<_>>()))
+}
+
 /// Arrow schema for bulk exports (table rows).
 ///
 /// This is a placeholder schema.
@@ -73,5 +102,62 @@ pub fn bulk_export_schema() -> Arc<Schema> {
     ]))
 }
 
+/// Arrow schema of a `DlqItems` ticket's result.
+///
+/// - `id`: DLQ item identifier
+/// - `action_type`: Action that failed (e.g., "webhook")
+/// - `event_id`, `event_type`, `entity_type`: The event the action ran for
+/// - `error_code`: Error code of the final failure (e.g., "OB006"), if any
+/// - `error_message`: Error message of the final failure
+/// - `attempts`: Number of attempts made
+/// - `org_id`: Optional organization identifier
+/// - `created_at`: When the item entered the DLQ (UTC)
+#[must_use]
+pub fn dlq_item_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("action_type", DataType::Utf8, false),
+        Field::new("event_id", DataType::Utf8, false),
+        Field::new("event_type", DataType::Utf8, false),
+        Field::new("entity_type", DataType::Utf8, false),
+        Field::new("error_code", DataType::Utf8, true),
+        Field::new("error_message", DataType::Utf8, false),
+        Field::new("attempts", DataType::UInt32, false),
+        Field::new("org_id", DataType::Utf8, true),
+        Field::new(
+            "created_at",
+            DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("UTC"))),
+            false,
+        ),
+    ]))
+}
+
+/// Arrow schema of a `JobHistory` ticket's result: one row per job attempt.
+///
+/// - `job_id`, `event_id`: The job and the event it runs an action for
+/// - `action_type`: Action the job runs (e.g., "webhook")
+/// - `state`: Current state of the job (e.g., "dead_lettered")
+/// - `attempt`, `max_attempts`: Attempt number (1-based) and the job's limit
+/// - `success`: Whether the attempt succeeded
+/// - `error_code`, `error_message`: The attempt's failure, if any
+/// - `job_created_at`, `started_at`: Job creation and attempt start (UTC)
+#[must_use]
+pub fn job_attempt_schema() -> Arc<Schema> {
+    let timestamp = DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("UTC")));
+    Arc::new(Schema::new(vec![
+        Field::new("job_id", DataType::Utf8, false),
+        Field::new("event_id", DataType::Utf8, false),
+        Field::new("action_type", DataType::Utf8, false),
+        Field::new("state", DataType::Utf8, false),
+        Field::new("attempt", DataType::UInt32, false),
+        Field::new("max_attempts", DataType::UInt32, false),
+        Field::new("success", DataType::Boolean, false),
+        Field::new("error_code", DataType::Utf8, true),
+        Field::new("error_message", DataType::Utf8, true),
+        Field::new("job_created_at", timestamp.clone(), false),
+        Field::new("started_at", timestamp, false),
+    ]))
+}
+
 #[cfg(test)]
 mod tests;
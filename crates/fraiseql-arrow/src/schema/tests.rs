@@ -1,4 +1,4 @@
-#![allow(clippy::panic)] // Reason: test code, panics acceptable
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
 use super::*;
 
 #[test]
@@ -49,3 +49,36 @@ fn test_bulk_export_schema() {
     assert_eq!(schema.field(0).name(), "id");
     assert_eq!(schema.field(0).data_type(), &DataType::Int64);
 }
+
+#[test]
+fn test_cdc_event_schema_extends_observer_schema() {
+    let schema = cdc_event_schema();
+    assert_eq!(schema.fields().len(), observer_event_schema().fields().len() + 1);
+    let deleted = schema.field_with_name("deleted").unwrap();
+    assert_eq!(deleted.data_type(), &DataType::Boolean);
+    assert!(!deleted.is_nullable());
+}
+
+#[test]
+fn test_aggregate_result_schema() {
+    let aggregates = [
+        AggregateSpec {
+            function: AggregateFunction::Count,
+            column:   None,
+            alias:    "events".to_string(),
+        },
+        AggregateSpec {
+            function: AggregateFunction::Sum,
+            column:   Some("total".to_string()),
+            alias:    "revenue".to_string(),
+        },
+    ];
+
+    let schema = aggregate_result_schema(&["status".to_string()], &aggregates);
+
+    assert_eq!(schema.fields().len(), 3);
+    assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+    assert_eq!(schema.field(1).name(), "events");
+    assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+    assert_eq!(schema.field(2).data_type(), &DataType::Float64);
+}
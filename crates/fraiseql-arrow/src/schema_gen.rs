@@ -127,6 +127,40 @@ pub fn infer_schema_from_rows(
     Ok(Arc::new(Schema::new(arrow_fields)))
 }
 
+/// Infer Arrow schema from a JSON object row, with its columns in `columns` order.
+///
+/// The keys of `row` listed in `columns` come first, in that order (e.g. the
+/// response keys of a GraphQL selection set, so the columns line up with the
+/// fields the client selected); keys not listed follow in the row's own order.
+/// Entries of `columns` missing from the row are skipped. The order never depends
+/// on `serde_json`'s `preserve_order` feature. All fields are nullable.
+///
+/// # Example
+///
+/// ```
+/// use fraiseql_arrow::schema_gen::infer_schema_from_object;
+///
+/// let row = serde_json::json!({"name": "Alice", "id": 1});
+/// let columns = ["name".to_string(), "id".to_string()];
+/// let schema = infer_schema_from_object(row.as_object().unwrap(), &columns);
+/// assert_eq!(schema.field(0).name(), "name");
+/// assert_eq!(schema.field(1).name(), "id");
+/// ```
+#[must_use]
+pub fn infer_schema_from_object(
+    row: &serde_json::Map<String, Value>,
+    columns: &[String],
+) -> Arc<Schema> {
+    let listed = columns.iter().filter_map(|name| row.get_key_value(name));
+    let unlisted = row.iter().filter(|(name, _)| !columns.contains(name));
+    let arrow_fields: Vec<Field> = listed
+        .chain(unlisted)
+        .map(|(name, value)| Field::new(name.clone(), json_value_to_arrow_type(value), true))
+        .collect();
+
+    Arc::new(Schema::new(arrow_fields))
+}
+
 /// Infer an Arrow [`DataType`] from a JSON value.
 ///
 /// This is the single source of truth for JSON-row → Arrow type inference,
@@ -136,8 +170,10 @@ pub fn infer_schema_from_rows(
 /// JSON `null` maps to [`DataType::Utf8`] — a nullable string column — **not**
 /// [`DataType::Null`]. The array converters reject `DataType::Null`, so a column
 /// whose first row happened to be `null` previously poisoned the entire result
-/// (H37). A whole-number is `Int64`, any other number `Float64`; arrays and
-/// objects are carried as their JSON string form.
+/// (H37). A whole-number is `Int64`, any other number `Float64`. An array of
+/// objects (e.g. `order.items`) becomes `List<Struct>` (see
+/// [`object_list_type`]); other arrays and objects are carried as their JSON
+/// string form.
 pub(crate) fn json_value_to_arrow_type(value: &Value) -> DataType {
     match value {
         Value::Null => DataType::Utf8,
@@ -150,10 +186,63 @@ pub(crate) fn json_value_to_arrow_type(value: &Value) -> DataType {
             }
         },
         Value::String(_) => DataType::Utf8,
-        Value::Array(_) => DataType::Utf8, // JSON arrays as strings
+        Value::Array(items) => object_list_type(items).unwrap_or(DataType::Utf8),
         Value::Object(_) => DataType::Utf8, // JSON objects as strings
     }
 }
 
+/// Infer `List<Struct>` for a JSON array whose non-null elements are all objects.
+///
+/// The struct's fields are the union of the elements' keys (all nullable); a key
+/// whose values disagree on type widens `Int64` + `Float64` to `Float64` and falls
+/// back to `Utf8` otherwise. Returns `None` for arrays containing non-objects, or
+/// whose objects have no keys, which stay JSON strings.
+fn object_list_type(items: &[Value]) -> Option<DataType> {
+    let mut fields: Vec<(String, Option<DataType>)> = Vec::new();
+    for item in items {
+        let object = match item {
+            Value::Null => continue,
+            Value::Object(object) => object,
+            _ => return None,
+        };
+        for (key, value) in object {
+            let data_type = (!value.is_null()).then(|| json_value_to_arrow_type(value));
+            match fields.iter_mut().find(|(name, _)| name == key) {
+                Some((_, existing)) => {
+                    *existing = match (existing.take(), data_type) {
+                        (Some(a), Some(b)) => Some(widen_arrow_type(a, b)),
+                        (a, b) => a.or(b),
+                    };
+                },
+                None => fields.push((key.clone(), data_type)),
+            }
+        }
+    }
+    if fields.is_empty() {
+        return None;
+    }
+
+    let struct_fields: Vec<Field> = fields
+        .into_iter()
+        .map(|(name, data_type)| Field::new(name, data_type.unwrap_or(DataType::Utf8), true))
+        .collect();
+    Some(DataType::List(Arc::new(Field::new(
+        "item",
+        DataType::Struct(struct_fields.into()),
+        true,
+    ))))
+}
+
+/// Common type of two inferred types of the same JSON key.
+fn widen_arrow_type(a: DataType, b: DataType) -> DataType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (DataType::Int64 | DataType::Float64, DataType::Int64 | DataType::Float64) => {
+            DataType::Float64
+        },
+        _ => DataType::Utf8,
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -316,6 +316,42 @@ fn test_infer_schema_from_rows_array_value_gives_utf8() {
     assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
 }
 
+#[test]
+fn test_infer_schema_from_rows_array_of_objects_gives_list_of_struct() {
+    use serde_json::json;
+    let mut row = HashMap::new();
+    row.insert(
+        "items".to_string(),
+        json!([{"sku": "a", "qty": 1}, {"sku": "b", "qty": 1.5, "note": null}, null]),
+    );
+    let schema = infer_schema_from_rows(&[row]).unwrap();
+
+    let DataType::List(item) = schema.field(0).data_type() else {
+        panic!("expected List, got {:?}", schema.field(0).data_type());
+    };
+    let DataType::Struct(fields) = item.data_type() else {
+        panic!("expected Struct items, got {:?}", item.data_type());
+    };
+    let type_of = |name: &str| fields.find(name).map(|(_, f)| f.data_type().clone());
+    assert_eq!(fields.len(), 3);
+    assert_eq!(type_of("sku"), Some(DataType::Utf8));
+    // Int64 and Float64 values of one key widen to Float64.
+    assert_eq!(type_of("qty"), Some(DataType::Float64));
+    // A key that is only ever null defaults to Utf8.
+    assert_eq!(type_of("note"), Some(DataType::Utf8));
+    assert!(fields.iter().all(|f| f.is_nullable()));
+}
+
+#[test]
+fn test_infer_schema_from_rows_mixed_array_gives_utf8() {
+    use serde_json::json;
+    let mut row = HashMap::new();
+    row.insert("mixed".to_string(), json!([{"a": 1}, 2]));
+    row.insert("empty".to_string(), json!([{}]));
+    let schema = infer_schema_from_rows(&[row]).unwrap();
+    assert!(schema.fields().iter().all(|f| f.data_type() == &DataType::Utf8));
+}
+
 #[test]
 fn test_infer_schema_from_rows_object_value_gives_utf8() {
     use serde_json::json;
@@ -2,7 +2,7 @@
 //!
 //! Manages active subscriptions and streams events to subscribers with filtering.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use dashmap::DashMap;
 use tokio::sync::mpsc;
@@ -31,6 +31,44 @@ pub struct EventSubscription {
 /// growing without limit.
 const DEFAULT_SUBSCRIPTION_BUFFER: usize = 256;
 
+/// Delivery settings for `Subscription` tickets streamed over Flight `DoGet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionStreamConfig {
+    /// Interval at which buffered events are flushed as one batch (default: 250 ms)
+    pub flush_interval:     Duration,
+    /// Shortest flush interval a ticket may request (default: 50 ms)
+    pub min_flush_interval: Duration,
+    /// Idle time after which an empty heartbeat batch is sent (default: 15 s)
+    pub heartbeat_interval: Duration,
+    /// Buffered events that trigger a flush before the interval elapses (default: 1,024)
+    pub max_batch_rows:     usize,
+}
+
+impl Default for SubscriptionStreamConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval:     Duration::from_millis(250),
+            min_flush_interval: Duration::from_millis(50),
+            heartbeat_interval: Duration::from_secs(15),
+            max_batch_rows:     1_024,
+        }
+    }
+}
+
+impl SubscriptionStreamConfig {
+    /// Flush interval for a ticket requesting `requested_ms`.
+    ///
+    /// Falls back to [`flush_interval`](Self::flush_interval) and never goes below
+    /// [`min_flush_interval`](Self::min_flush_interval), so clients cannot force the
+    /// server into a busy flush loop.
+    #[must_use]
+    pub fn effective_flush_interval(&self, requested_ms: Option<u64>) -> Duration {
+        requested_ms
+            .map_or(self.flush_interval, Duration::from_millis)
+            .max(self.min_flush_interval)
+    }
+}
+
 /// Manages active subscriptions and event routing.
 ///
 /// This manager maintains a set of active subscriptions and routes events
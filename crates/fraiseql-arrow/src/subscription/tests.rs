@@ -243,3 +243,13 @@ fn test_subscription_channel_is_not_unbounded() {
     assert!(rx.try_recv().is_ok());
     assert!(rx.try_recv().is_err(), "channel must be bounded to capacity=1");
 }
+
+#[test]
+fn test_stream_config_flush_interval_floor() {
+    let config = SubscriptionStreamConfig::default();
+
+    assert_eq!(config.effective_flush_interval(None), config.flush_interval);
+    assert_eq!(config.effective_flush_interval(Some(2_000)), Duration::from_secs(2));
+    // Clients cannot push the server below its minimum flush interval.
+    assert_eq!(config.effective_flush_interval(Some(0)), config.min_flush_interval);
+}
@@ -3,7 +3,9 @@
 //! Flight tickets are opaque bytes that identify what data to fetch.
 //! We use JSON encoding for human readability during development.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::error::{ArrowFlightError, Result};
 
@@ -14,6 +16,192 @@ use crate::error::{ArrowFlightError, Result};
 /// pathologically deep JSON structure that exhausts the parser's stack/heap.
 pub(crate) const MAX_FLIGHT_TICKET_BYTES: usize = 256 * 1024; // 256 KiB
 
+/// Maximum number of partitions a single ticket may be split into.
+///
+/// Each partition is a separate `DoGet` stream holding one of the service's
+/// concurrent-stream permits, so the cap keeps one export from starving others.
+pub const MAX_TICKET_PARTITIONS: u32 = 64;
+
+/// How a partitionable ticket is split for parallel `DoGet` reads.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PartitionScheme {
+    /// Contiguous, equal-width time windows between the ticket's `start_date` and
+    /// `end_date` (both required, RFC 3339). Each partition only queries its own
+    /// window, so storage prunes the rest.
+    DateRange,
+    /// Buckets of `entity_id`: an event belongs to partition
+    /// [`entity_bucket`]`(entity_id, count)`. All events of one entity land in the
+    /// same partition.
+    EntityHash,
+}
+
+/// Partitioning of a ticket into `count` parallel parts.
+///
+/// A ticket whose partition has no `index` is a *request* to split: `GetFlightInfo`
+/// answers it with `count` endpoints, one per partition, whose tickets carry their
+/// `index`. `DoGet` only serves tickets with an `index`.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "type": "ObserverEvents",
+///   "entity_type": "Order",
+///   "start_date": "2026-01-01T00:00:00Z",
+///   "end_date": "2026-02-01T00:00:00Z",
+///   "partition": { "scheme": "date_range", "count": 8 }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TicketPartition {
+    /// How the ticket is split
+    pub scheme: PartitionScheme,
+    /// Number of partitions (1..=[`MAX_TICKET_PARTITIONS`])
+    pub count:  u32,
+    /// Partition this ticket reads; `None` on a split request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index:  Option<u32>,
+}
+
+impl TicketPartition {
+    /// Check that `count` is within bounds and `index`, if set, is below `count`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::InvalidTicket`] when either bound is violated.
+    pub fn validate(&self) -> Result<()> {
+        if self.count == 0 || self.count > MAX_TICKET_PARTITIONS {
+            return Err(ArrowFlightError::InvalidTicket(format!(
+                "Partition count must be between 1 and {MAX_TICKET_PARTITIONS}, got {}",
+                self.count
+            )));
+        }
+        if let Some(index) = self.index {
+            if index >= self.count {
+                return Err(ArrowFlightError::InvalidTicket(format!(
+                    "Partition index {index} out of range for {} partitions",
+                    self.count
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether this is the last partition (the one whose date window includes the end).
+    #[must_use]
+    pub fn is_last(&self) -> bool {
+        self.index.is_some_and(|index| index + 1 == self.count)
+    }
+
+    /// The time window `[lo, hi)` this partition reads out of `[start, end]`.
+    ///
+    /// Windows are equal-width and contiguous; the last one ends at — and includes —
+    /// `end`. Returns `None` when no `index` is set or `end` precedes `start`.
+    #[must_use]
+    pub fn date_window(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let index = self.index?;
+        if end < start {
+            return None;
+        }
+        let width = (end - start) / i32::try_from(self.count).ok()?;
+        let lo = start + width * i32::try_from(index).ok()?;
+        let hi = if self.is_last() {
+            end
+        } else {
+            start + width * i32::try_from(index + 1).ok()?
+        };
+        Some((lo, hi))
+    }
+}
+
+/// IPC body compression codec for the record batches of a `DoGet` stream.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum IpcCompression {
+    /// LZ4 frame compression: fast, moderate ratio
+    Lz4,
+    /// Zstandard compression: slower, best ratio
+    Zstd,
+}
+
+/// Per-ticket encoding of the Arrow record batches a `DoGet` streams.
+///
+/// The encoding options are lossless and transparent to Arrow readers: dictionary-encoded
+/// columns arrive as `Dictionary(Int32, Utf8)` arrays and compressed bodies are
+/// decompressed by the IPC reader. Compression requires the crate's `compression`
+/// feature.
+///
+/// `max_batch_bytes` only changes how rows are split across batches; it applies to
+/// `OptimizedView` tickets (observer events are streamed as a single batch).
+///
+/// # Example
+///
+/// ```json
+/// { "dictionary_columns": ["status", "entity_type"], "compression": "zstd", "max_batch_bytes": 4194304 }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchEncoding {
+    /// `Utf8` columns to dictionary-encode — worthwhile for low-cardinality columns
+    /// such as `status` or `entity_type`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dictionary_columns: Vec<String>,
+    /// IPC body compression codec (none by default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression:        Option<IpcCompression>,
+    /// Target size of one record batch in bytes, estimated from the first rows
+    /// (defaults to [`DEFAULT_BATCH_BYTE_BUDGET`](crate::db_convert::DEFAULT_BATCH_BYTE_BUDGET))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_batch_bytes:    Option<usize>,
+}
+
+/// Aggregate function of an [`AggregateSpec`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AggregateFunction {
+    /// Number of rows (or of non-null values of `column`)
+    Count,
+    /// Sum of `column`
+    Sum,
+    /// Average of `column`
+    Avg,
+    /// Minimum of `column`
+    Min,
+    /// Maximum of `column`
+    Max,
+}
+
+/// One aggregate output column of an `Aggregate` ticket.
+///
+/// `Count` results are `Int64`; every other aggregate is returned as `Float64`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AggregateSpec {
+    /// Aggregate function
+    pub function: AggregateFunction,
+    /// Aggregated column; optional for `Count` (`COUNT(*)`), required otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column:   Option<String>,
+    /// Name of the output column
+    pub alias:    String,
+}
+
+/// The `EntityHash` bucket of an entity: its UUID value modulo `buckets`.
+///
+/// Deterministic and trivially reproducible in any language or in SQL, so storage
+/// backends can push the predicate down. `buckets` must be non-zero.
+#[must_use]
+pub fn entity_bucket(entity_id: &Uuid, buckets: u32) -> u32 {
+    // The remainder is < buckets, so the cast is lossless.
+    (entity_id.as_u128() % u128::from(buckets.max(1))) as u32
+}
+
 /// Flight ticket identifying what data to fetch.
 ///
 /// Tickets are serialized as JSON for human readability during development.
@@ -59,8 +247,16 @@ pub enum FlightTicket {
         start_date:  Option<String>,
         /// End date filter (ISO 8601 format)
         end_date:    Option<String>,
-        /// Maximum number of events to return
+        /// Maximum number of events to return (per partition when partitioned)
         limit:       Option<usize>,
+        /// Optional split into parallel partitions (see [`TicketPartition`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        partition:   Option<TicketPartition>,
+        /// Optional batch encoding; when set, events are streamed as Arrow record batches
+        /// (see [`observer_event_schema`](crate::schema::observer_event_schema)) instead
+        /// of a single JSON message
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encoding:    Option<BatchEncoding>,
     },
 
     /// Optimized pre-compiled Arrow view.
@@ -89,6 +285,9 @@ pub enum FlightTicket {
         limit:    Option<usize>,
         /// Offset for pagination
         offset:   Option<usize>,
+        /// Optional dictionary encoding / compression of the streamed batches
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encoding: Option<BatchEncoding>,
     },
 
     /// Bulk data export.
@@ -110,7 +309,7 @@ pub enum FlightTicket {
         filter: Option<String>,
         /// Maximum number of rows
         limit:  Option<usize>,
-        /// Export format: "parquet", "csv", or "json" (default: "parquet")
+        /// Export format: "parquet", "csv", "json", or "delta" (default: "parquet")
         format: Option<String>,
     },
 
@@ -135,9 +334,217 @@ pub enum FlightTicket {
         /// List of SQL queries to execute
         queries: Vec<String>,
     },
+
+    /// Aggregate computed inside the server's local `DuckDB` sink.
+    ///
+    /// Group-by / count / sum queries over a materialized table return only the
+    /// aggregated rows, so dashboards never ship raw rows. Requires the `duckdb_sink`
+    /// feature and a sink configured on the service. Group-by columns are returned as
+    /// `Utf8`.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "type": "Aggregate",
+    ///   "table": "fraiseql_events",
+    ///   "group_by": ["entity_type"],
+    ///   "aggregates": [{ "function": "count", "alias": "events" }]
+    /// }
+    /// ```
+    Aggregate {
+        /// Table of the `DuckDB` sink to aggregate
+        table:      String,
+        /// Grouping columns
+        #[serde(default)]
+        group_by:   Vec<String>,
+        /// Aggregate output columns
+        aggregates: Vec<AggregateSpec>,
+        /// Maximum number of groups
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit:      Option<usize>,
+    },
+
+    /// Live observer events pushed over a long-lived `DoGet` stream.
+    ///
+    /// The stream stays open and delivers new events as Arrow batches (observer event
+    /// schema) as they are broadcast through the service's `SubscriptionManager`.
+    /// Events are buffered and flushed on a server-driven interval; an idle stream
+    /// receives empty heartbeat batches (`app_metadata` = `heartbeat`) so clients and
+    /// proxies can tell a quiet subscription from a dead connection.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "type": "Subscription",
+    ///   "entity_type": "Order",
+    ///   "filter": "status = 'shipped'",
+    ///   "flush_interval_ms": 500
+    /// }
+    /// ```
+    Subscription {
+        /// Entity type to subscribe to
+        entity_type:       String,
+        /// Optional filter (`field = 'value'` or `field != 'value'` on event data)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filter:            Option<String>,
+        /// Requested flush interval in milliseconds; the server raises it to its
+        /// configured minimum
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        flush_interval_ms: Option<u64>,
+    },
+
+    /// Export of the observer dead letter queue.
+    ///
+    /// Streams the actions that exhausted their retries as Arrow batches (see
+    /// [`dlq_item_schema`](crate::schema::dlq_item_schema)), most recent first.
+    /// Requires a delivery storage configured on the service.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "type": "DlqItems",
+    ///   "action_type": "webhook",
+    ///   "start_date": "2026-01-01T00:00:00Z",
+    ///   "limit": 10000
+    /// }
+    /// ```
+    DlqItems {
+        /// Only items of this action type (e.g., "webhook", "email")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        action_type: Option<String>,
+        /// Start date filter (RFC 3339) on when the item entered the DLQ
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_date:  Option<String>,
+        /// End date filter (RFC 3339)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end_date:    Option<String>,
+        /// Maximum number of items
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit:       Option<usize>,
+        /// Optional dictionary encoding / compression of the streamed batches
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encoding:    Option<BatchEncoding>,
+    },
+
+    /// Export of the job queue's attempt history.
+    ///
+    /// Streams one row per job attempt as Arrow batches (see
+    /// [`job_attempt_schema`](crate::schema::job_attempt_schema)), most recent first.
+    /// Requires a delivery storage configured on the service.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "type": "JobHistory",
+    ///   "state": "dead_lettered",
+    ///   "start_date": "2026-01-01T00:00:00Z"
+    /// }
+    /// ```
+    JobHistory {
+        /// Only jobs of this action type (e.g., "webhook", "email")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        action_type: Option<String>,
+        /// Only jobs in this state ("pending", "running", "completed", "failed",
+        /// "`dead_lettered`")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        state:       Option<String>,
+        /// Start date filter (RFC 3339) on when the attempt started
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_date:  Option<String>,
+        /// End date filter (RFC 3339)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end_date:    Option<String>,
+        /// Maximum number of attempts
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit:       Option<usize>,
+        /// Optional dictionary encoding / compression of the streamed batches
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encoding:    Option<BatchEncoding>,
+    },
 }
 
 impl FlightTicket {
+    /// The ticket's variant name, as written in its `type` tag.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::GraphQLQuery { .. } => "GraphQLQuery",
+            Self::ObserverEvents { .. } => "ObserverEvents",
+            Self::OptimizedView { .. } => "OptimizedView",
+            Self::BulkExport { .. } => "BulkExport",
+            Self::BatchedQueries { .. } => "BatchedQueries",
+            Self::Aggregate { .. } => "Aggregate",
+            Self::Subscription { .. } => "Subscription",
+            Self::DlqItems { .. } => "DlqItems",
+            Self::JobHistory { .. } => "JobHistory",
+        }
+    }
+
+    /// The batch encoding the ticket requests, if any.
+    #[must_use]
+    pub const fn encoding(&self) -> Option<&BatchEncoding> {
+        match self {
+            Self::OptimizedView { encoding, .. }
+            | Self::ObserverEvents { encoding, .. }
+            | Self::DlqItems { encoding, .. }
+            | Self::JobHistory { encoding, .. } => encoding.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Expand a split request into one ticket per partition.
+    ///
+    /// An `ObserverEvents` ticket whose partition has no `index` yields `count` tickets
+    /// with `index` `0..count`; any other ticket is returned unchanged as the only
+    /// partition.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowFlightError::InvalidTicket`] when the partition is out of bounds, or
+    /// when `DateRange` partitioning lacks an RFC 3339 `start_date` / `end_date` or the
+    /// range is reversed.
+    pub fn partitions(&self) -> Result<Vec<Self>> {
+        let Self::ObserverEvents {
+            start_date,
+            end_date,
+            partition: Some(partition),
+            ..
+        } = self
+        else {
+            return Ok(vec![self.clone()]);
+        };
+
+        partition.validate()?;
+        if partition.scheme == PartitionScheme::DateRange {
+            let (start, end) = partition_date_bounds(start_date.as_deref(), end_date.as_deref())?;
+            if end < start {
+                return Err(ArrowFlightError::InvalidTicket(
+                    "DateRange partitioning requires start_date <= end_date".to_string(),
+                ));
+            }
+        }
+        if partition.index.is_some() {
+            return Ok(vec![self.clone()]);
+        }
+
+        Ok((0..partition.count)
+            .map(|index| {
+                let mut ticket = self.clone();
+                if let Self::ObserverEvents {
+                    partition: Some(p), ..
+                } = &mut ticket
+                {
+                    p.index = Some(index);
+                }
+                ticket
+            })
+            .collect())
+    }
+
     /// Encode ticket as bytes for Flight protocol.
     ///
     /// # Errors
@@ -165,5 +572,26 @@ impl FlightTicket {
     }
 }
 
+/// Parse the RFC 3339 date bounds a `DateRange` partition splits.
+///
+/// # Errors
+///
+/// Returns [`ArrowFlightError::InvalidTicket`] when either bound is missing or not
+/// RFC 3339.
+pub fn partition_date_bounds(
+    start_date: Option<&str>,
+    end_date: Option<&str>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let parse = |name: &str, value: Option<&str>| {
+        let value = value.ok_or_else(|| {
+            ArrowFlightError::InvalidTicket(format!("DateRange partitioning requires {name}"))
+        })?;
+        DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| ArrowFlightError::InvalidTicket(format!("{name} is not RFC 3339: {e}")))
+    };
+    Ok((parse("start_date", start_date)?, parse("end_date", end_date)?))
+}
+
 #[cfg(test)]
 mod tests;
@@ -40,6 +40,8 @@ fn test_observer_events_ticket_roundtrip() {
         start_date:  Some("2026-01-01".to_string()),
         end_date:    Some("2026-01-31".to_string()),
         limit:       Some(10_000),
+        partition:   None,
+        encoding:    None,
     };
 
     let bytes = ticket.encode().unwrap();
@@ -64,6 +66,7 @@ fn test_optimized_view_ticket() {
         order_by: Some("created_at DESC".to_string()),
         limit:    Some(100_000),
         offset:   Some(0),
+        encoding: None,
     };
 
     let bytes = ticket.encode().unwrap();
@@ -76,8 +79,10 @@ fn test_optimized_view_ticket() {
             order_by,
             limit,
             offset,
+            encoding,
         } => {
             assert_eq!(view, "va_orders");
+            assert!(encoding.is_none());
             assert_eq!(filter, Some("created_at > '2026-01-01'".to_string()));
             assert_eq!(order_by, Some("created_at DESC".to_string()));
             assert_eq!(limit, Some(100_000));
@@ -95,6 +100,7 @@ fn test_optimized_view_minimal() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
 
     let bytes = ticket.encode().unwrap();
@@ -189,6 +195,8 @@ fn test_observer_events_all_none_optional_fields() {
         start_date:  None,
         end_date:    None,
         limit:       None,
+        partition:   None,
+        encoding:    None,
     };
     let bytes = ticket.encode().unwrap();
     let decoded = FlightTicket::decode(&bytes).unwrap();
@@ -198,8 +206,12 @@ fn test_observer_events_all_none_optional_fields() {
             start_date,
             end_date,
             limit,
+            partition,
+            encoding,
         } => {
             assert_eq!(entity_type, "User");
+            assert!(partition.is_none());
+            assert!(encoding.is_none());
             assert!(start_date.is_none());
             assert!(end_date.is_none());
             assert!(limit.is_none());
@@ -232,6 +244,76 @@ fn test_batched_queries_empty_list_roundtrips() {
     }
 }
 
+#[test]
+fn test_aggregate_ticket_roundtrip() {
+    let ticket = FlightTicket::Aggregate {
+        table:      "fraiseql_events".to_string(),
+        group_by:   vec!["event_type".to_string()],
+        aggregates: vec![AggregateSpec {
+            function: AggregateFunction::Count,
+            column:   None,
+            alias:    "events".to_string(),
+        }],
+        limit:      Some(10),
+    };
+    let bytes = ticket.encode().unwrap();
+    assert!(String::from_utf8_lossy(&bytes).contains(r#""function":"count""#));
+    assert_eq!(FlightTicket::decode(&bytes).unwrap(), ticket);
+
+    // `group_by` may be omitted for a single, ungrouped row.
+    let decoded = FlightTicket::decode(
+        br#"{"type":"Aggregate","table":"t","aggregates":[{"function":"sum","column":"total","alias":"revenue"}],"limit":null}"#,
+    )
+    .unwrap();
+    assert!(matches!(decoded, FlightTicket::Aggregate { group_by, .. } if group_by.is_empty()));
+}
+
+#[test]
+fn test_subscription_ticket_roundtrip() {
+    let ticket = FlightTicket::Subscription {
+        entity_type:       "Order".to_string(),
+        filter:            Some("status = 'paid'".to_string()),
+        flush_interval_ms: Some(500),
+    };
+    let bytes = ticket.encode().unwrap();
+    assert_eq!(FlightTicket::decode(&bytes).unwrap(), ticket);
+
+    let minimal = FlightTicket::decode(br#"{"type":"Subscription","entity_type":"Order"}"#);
+    assert!(matches!(
+        minimal.unwrap(),
+        FlightTicket::Subscription {
+            filter: None,
+            flush_interval_ms: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_delivery_history_tickets_roundtrip() {
+    let ticket = FlightTicket::JobHistory {
+        action_type: Some("webhook".to_string()),
+        state:       Some("dead_lettered".to_string()),
+        start_date:  Some("2026-01-01T00:00:00Z".to_string()),
+        end_date:    None,
+        limit:       Some(500),
+        encoding:    None,
+    };
+    let bytes = ticket.encode().unwrap();
+    assert_eq!(FlightTicket::decode(&bytes).unwrap(), ticket);
+
+    let minimal = FlightTicket::decode(br#"{"type":"DlqItems"}"#).unwrap();
+    assert!(matches!(
+        minimal,
+        FlightTicket::DlqItems {
+            action_type: None,
+            limit: None,
+            encoding: None,
+            ..
+        }
+    ));
+}
+
 #[test]
 fn test_invalid_json_with_valid_utf8_returns_error() {
     // Valid UTF-8, but not valid JSON
@@ -274,6 +356,7 @@ fn test_optimized_view_offset_zero_roundtrips() {
         order_by: None,
         limit:    Some(1000),
         offset:   Some(0),
+        encoding: None,
     };
     let bytes = ticket.encode().unwrap();
     let decoded = FlightTicket::decode(&bytes).unwrap();
@@ -323,3 +406,131 @@ fn test_ticket_exceeding_size_limit_is_rejected() {
         other => panic!("expected InvalidTicket size-limit error, got: {other:?}"),
     }
 }
+
+fn partitioned_events(scheme: PartitionScheme, count: u32, index: Option<u32>) -> FlightTicket {
+    FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  Some("2026-01-01T00:00:00Z".to_string()),
+        end_date:    Some("2026-01-05T00:00:00Z".to_string()),
+        limit:       None,
+        partition:   Some(TicketPartition {
+            scheme,
+            count,
+            index,
+        }),
+        encoding:    None,
+    }
+}
+
+#[test]
+fn test_partition_request_expands_to_indexed_tickets() {
+    let tickets = partitioned_events(PartitionScheme::DateRange, 4, None).partitions().unwrap();
+
+    assert_eq!(tickets.len(), 4);
+    for (i, ticket) in tickets.iter().enumerate() {
+        match ticket {
+            FlightTicket::ObserverEvents {
+                partition: Some(p), ..
+            } => assert_eq!(p.index, Some(i as u32)),
+            other => panic!("expected a partitioned ObserverEvents ticket, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_unpartitioned_ticket_is_its_own_partition() {
+    let ticket = FlightTicket::GraphQLQuery {
+        query:     "{ users { id } }".to_string(),
+        variables: None,
+    };
+    assert_eq!(ticket.partitions().unwrap(), vec![ticket]);
+}
+
+#[test]
+fn test_partition_bounds_are_validated() {
+    assert!(partitioned_events(PartitionScheme::EntityHash, 0, None).partitions().is_err());
+    assert!(
+        partitioned_events(PartitionScheme::EntityHash, MAX_TICKET_PARTITIONS + 1, None)
+            .partitions()
+            .is_err()
+    );
+    assert!(
+        partitioned_events(PartitionScheme::EntityHash, 4, Some(4))
+            .partitions()
+            .is_err()
+    );
+
+    let undated = FlightTicket::ObserverEvents {
+        entity_type: "Order".to_string(),
+        start_date:  None,
+        end_date:    None,
+        limit:       None,
+        partition:   Some(TicketPartition {
+            scheme: PartitionScheme::DateRange,
+            count:  2,
+            index:  None,
+        }),
+        encoding:    None,
+    };
+    assert!(undated.partitions().is_err(), "DateRange partitioning requires both dates");
+}
+
+#[test]
+fn test_date_windows_are_contiguous_and_cover_the_range() {
+    let (start, end) =
+        partition_date_bounds(Some("2026-01-01T00:00:00Z"), Some("2026-01-05T00:00:00Z")).unwrap();
+
+    let windows: Vec<_> = (0..4)
+        .map(|index| {
+            TicketPartition {
+                scheme: PartitionScheme::DateRange,
+                count:  4,
+                index:  Some(index),
+            }
+            .date_window(start, end)
+            .unwrap()
+        })
+        .collect();
+
+    assert_eq!(windows[0].0, start);
+    assert_eq!(windows[3].1, end);
+    for pair in windows.windows(2) {
+        assert_eq!(pair[0].1, pair[1].0, "windows must be contiguous");
+    }
+    assert_eq!(windows[1].0, start + chrono::Duration::days(1));
+}
+
+#[test]
+fn test_entity_bucket_is_stable_modulo() {
+    let id = Uuid::from_u128(42);
+    assert_eq!(entity_bucket(&id, 8), 2);
+    assert_eq!(entity_bucket(&id, 1), 0);
+}
+
+#[test]
+fn test_partition_serialization_omits_unset_index() {
+    let ticket = partitioned_events(PartitionScheme::EntityHash, 3, None);
+    let json: serde_json::Value = serde_json::from_slice(&ticket.encode().unwrap()).unwrap();
+
+    assert_eq!(json["partition"]["scheme"], "entity_hash");
+    assert_eq!(json["partition"]["count"], 3);
+    assert!(json["partition"].get("index").is_none());
+}
+
+#[test]
+fn test_kind_matches_type_tag() {
+    let tickets = [
+        FlightTicket::GraphQLQuery {
+            query:     "{ users { id } }".to_string(),
+            variables: None,
+        },
+        FlightTicket::BatchedQueries {
+            queries: vec!["SELECT 1".to_string()],
+        },
+        partitioned_events(PartitionScheme::EntityHash, 3, None),
+    ];
+    for ticket in tickets {
+        let json: serde_json::Value = serde_json::from_slice(&ticket.encode().unwrap()).unwrap();
+        assert_eq!(json["type"], ticket.kind());
+    }
+}
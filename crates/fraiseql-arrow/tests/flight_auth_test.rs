@@ -98,6 +98,7 @@ async fn test_do_get_without_authorization_header() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
     let ticket_bytes = ticket.encode().expect("Failed to encode ticket");
 
@@ -135,6 +136,7 @@ async fn test_do_get_with_invalid_session_token() {
             order_by: None,
             limit:    None,
             offset:   None,
+            encoding: None,
         };
         let ticket_bytes = ticket.encode().expect("Failed to encode ticket");
 
@@ -204,6 +206,7 @@ async fn test_do_get_with_expired_session_token() {
             order_by: None,
             limit:    None,
             offset:   None,
+            encoding: None,
         };
         let ticket_bytes = ticket.encode().expect("Failed to encode ticket");
 
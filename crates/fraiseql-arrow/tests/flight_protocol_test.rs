@@ -188,6 +188,7 @@ async fn test_get_schema_for_optimized_view_returns_schema() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
     let result = service.get_schema(Request::new(descriptor_for_ticket(&ticket))).await;
     assert!(result.is_ok(), "get_schema for va_orders must succeed");
@@ -263,6 +264,7 @@ async fn test_poll_flight_info_returns_completed_poll_info() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
 
     let poll_info = service
@@ -294,6 +296,7 @@ async fn test_poll_flight_info_unknown_view_returns_not_found() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
 
     let err = service
@@ -348,6 +351,7 @@ async fn test_do_get_optimized_view_streams_arrow_data() {
         order_by: None,
         limit:    Some(5),
         offset:   None,
+        encoding: None,
     };
 
     let result = service.do_get(authenticated_ticket_request(&ticket)).await;
@@ -374,6 +378,7 @@ async fn test_do_get_unknown_view_returns_not_found() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
 
     let result = service.do_get(authenticated_ticket_request(&ticket)).await;
@@ -399,6 +404,7 @@ async fn test_do_get_without_auth_returns_error() {
         order_by: None,
         limit:    None,
         offset:   None,
+        encoding: None,
     };
 
     let bytes = ticket.encode().expect("Failed to encode ticket");
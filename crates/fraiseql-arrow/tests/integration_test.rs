@@ -81,6 +81,8 @@ async fn test_get_schema_for_observer_events() {
             start_date:  None,
             end_date:    None,
             limit:       None,
+            partition:   None,
+            encoding:    None,
         };
 
         let ticket_bytes = ticket.encode().unwrap();
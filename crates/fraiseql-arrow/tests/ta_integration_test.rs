@@ -92,6 +92,7 @@ async fn test_get_schema_for_ta_orders() {
             order_by: None,
             limit:    None,
             offset:   None,
+            encoding: None,
         };
 
         let ticket_bytes = ticket.encode().unwrap();
@@ -140,6 +141,7 @@ async fn test_get_schema_for_ta_users() {
             order_by: None,
             limit:    None,
             offset:   None,
+            encoding: None,
         };
 
         let ticket_bytes = ticket.encode().unwrap();
@@ -187,6 +189,7 @@ async fn test_do_get_ta_orders_returns_data() {
             order_by: None,
             limit:    Some(5),
             offset:   None,
+            encoding:   None,
         };
 
         let ticket_bytes = ticket.encode().unwrap();
@@ -251,6 +254,7 @@ async fn test_do_get_ta_users_returns_data() {
             order_by: None,
             limit:    Some(10),
             offset:   None,
+            encoding:   None,
         };
 
         let ticket_bytes = ticket.encode().unwrap();
@@ -49,7 +49,8 @@ EXAMPLES:
     fraiseql compile fraiseql.toml
     fraiseql compile fraiseql.toml --types types.json
     fraiseql compile schema.json -o schema.compiled.json
-    fraiseql compile fraiseql.toml --check")]
+    fraiseql compile fraiseql.toml --check
+    fraiseql compile fraiseql.toml --sign-key schema-signing.pem")]
     Compile {
         /// Input file path: fraiseql.toml (TOML) or schema.json (legacy)
         #[arg(value_name = "INPUT")]
@@ -93,6 +94,14 @@ EXAMPLES:
         #[arg(long)]
         skip_hash: bool,
 
+        /// Sign the compiled schema with this Ed25519 private key (PKCS#8 PEM)
+        ///
+        /// Embeds a `_signature` of the schema body next to `_content_hash`. A server
+        /// configured with the matching `schema_public_key` refuses unsigned or
+        /// mismatched schemas.
+        #[arg(long, value_name = "KEY_FILE", conflicts_with = "skip_hash")]
+        sign_key: Option<String>,
+
         /// Optional database URL for indexed column validation
         /// When provided, validates that indexed columns exist in database views
         #[arg(long, value_name = "DATABASE_URL")]
@@ -176,11 +185,17 @@ EXAMPLES:
     #[command(after_help = "\
 EXAMPLES:
     fraiseql analyze schema.compiled.json
-    fraiseql analyze schema.compiled.json --json")]
+    fraiseql analyze schema.compiled.json --json
+    fraiseql analyze schema.compiled.json --usage postgres://localhost/mydb")]
     Analyze {
         /// Path to schema.compiled.json
         #[arg(value_name = "SCHEMA")]
         schema: String,
+
+        /// Report unused and still-selected deprecated fields from the
+        /// `fraiseql_field_usage` table (requires `[field_usage]` on the server)
+        #[arg(long, value_name = "DATABASE_URL")]
+        usage: Option<String>,
     },
 
     /// Analyze schema type dependencies
@@ -417,7 +432,8 @@ EXAMPLES:
     #[command(after_help = "\
 EXAMPLES:
     fraiseql generate-client typescript --out ./src/generated
-    fraiseql generate-client typescript --schema ./schema.compiled.json --out ./gen --force")]
+    fraiseql generate-client typescript --schema ./schema.compiled.json --out ./gen --force
+    fraiseql generate-client python --out ./api_client --incremental")]
     GenerateClient {
         #[command(subcommand)]
         language: GenerateClientCommands,
@@ -542,6 +558,39 @@ TOML CONFIG:
         introspection: bool,
     },
 
+    /// Start the development server: watch, recompile, and serve with the playground
+    ///
+    /// Like `run --watch`, but watches every schema source under the project directory,
+    /// always enables the GraphQL playground and introspection, and keeps running on a
+    /// compile error — the bind address serves an error overlay (HTML for browsers, a
+    /// GraphQL error for API clients) until the schema compiles again.
+    ///
+    /// The database URL is resolved like `run`: --database flag > DATABASE_URL env var >
+    /// [database].url in fraiseql.toml.
+    #[cfg(feature = "run-server")]
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql serve
+    fraiseql serve fraiseql.toml --database postgres://localhost/mydb
+    fraiseql serve --port 3000")]
+    Serve {
+        /// Input file path (fraiseql.toml or schema.json); auto-detected if omitted
+        #[arg(value_name = "INPUT")]
+        input: Option<String>,
+
+        /// Database URL (overrides [database].url in fraiseql.toml and DATABASE_URL env var)
+        #[arg(short, long, value_name = "DATABASE_URL")]
+        database: Option<String>,
+
+        /// Port to listen on (overrides [server].port in fraiseql.toml)
+        #[arg(short, long, value_name = "PORT")]
+        port: Option<u16>,
+
+        /// Bind address (overrides [server].host in fraiseql.toml)
+        #[arg(long, value_name = "HOST")]
+        bind: Option<String>,
+    },
+
     /// Watch a schema source and recompile + live-reload a running server on change
     ///
     /// Recompiles `schema.compiled.json` on every save and, with `--reload-url`,
@@ -659,6 +708,90 @@ EXAMPLES:
         dry_run: bool,
     },
 
+    /// Interactive GraphQL shell against a running server
+    ///
+    /// Introspects the endpoint for schema-aware tab completion (root fields,
+    /// fields, arguments, enum values), buffers multi-line documents until their
+    /// braces balance, and prints each response as a table or JSON. Session
+    /// variables set with `:set` are sent with every operation that references
+    /// them; `:help` lists the meta-commands.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql repl
+    fraiseql repl --endpoint https://api.example.com/graphql --token $TOKEN
+    fraiseql repl --format json --timing")]
+    Repl {
+        /// GraphQL endpoint URL.
+        #[arg(
+            short,
+            long,
+            value_name = "URL",
+            default_value = "http://localhost:8080/graphql"
+        )]
+        endpoint: String,
+
+        /// Bearer token sent with every request.
+        #[arg(short, long, value_name = "TOKEN")]
+        token: Option<String>,
+
+        /// Output format: table or json (switch with `:format`).
+        #[arg(long, default_value = "table")]
+        format: String,
+
+        /// Print the round-trip time after each operation (toggle with `:timing`).
+        #[arg(long)]
+        timing: bool,
+    },
+
+    /// Fill the database with fake rows shaped by the compiled schema
+    ///
+    /// Generates realistic values for every field of the entity (names, emails,
+    /// titles, timestamps, enum values, nested input objects) and writes them
+    /// through the entity's insert mutation, or straight into its `tb_*` base table
+    /// in batches (`--via table`). Foreign keys reference rows that already exist,
+    /// so seed parent entities first. For load testing and demos — rows COMMIT.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql seed --entity User --count 1000
+    fraiseql seed --entity Post --count 5000 --via table --seed 42
+    fraiseql seed --entity Order --via table --table sales.tb_order --batch-size 1000")]
+    Seed {
+        /// GraphQL type to seed (e.g. User).
+        #[arg(long, value_name = "TYPE")]
+        entity: String,
+
+        /// Number of rows to create.
+        #[arg(long, default_value_t = 100)]
+        count: usize,
+
+        /// Path to schema.compiled.json.
+        #[arg(short = 's', long, default_value = "schema.compiled.json")]
+        schema: std::path::PathBuf,
+
+        /// Database URL (PostgreSQL only). Falls back to `fraiseql.toml` and the
+        /// DATABASE_URL env var.
+        #[arg(long, value_name = "DATABASE_URL")]
+        database: Option<String>,
+
+        /// Write path: auto (the insert mutation when there is one), mutation, or
+        /// table.
+        #[arg(long, default_value = "auto")]
+        via: String,
+
+        /// Base table for `--via table` (default: derived from the entity's view,
+        /// `v_user` → `tb_user`).
+        #[arg(long)]
+        table: Option<String>,
+
+        /// Seed for the value generator, for reproducible datasets.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Rows per INSERT statement for `--via table`.
+        #[arg(long, default_value_t = 500)]
+        batch_size: usize,
+    },
+
     /// Run diagnostic checks for common FraiseQL setup problems
     ///
     /// Checks schema file, TOML config, DATABASE_URL, JWT secret, Redis, TLS,
@@ -685,6 +818,8 @@ EXAMPLES:
         db_url: Option<String>,
 
         /// Run the live-database passes against this PostgreSQL database: the
+        /// server version and pgcrypto / ltree / PostGIS extension checks, the
+        /// observer tables (when the schema declares observers), the
         /// change-log contract drift check (compares core.tb_entity_change_log
         /// against the shipped contract — #380), the PL/pgSQL body-resolution
         /// pass (reports internal calls that no longer resolve — #409), and the
@@ -764,6 +899,279 @@ EXAMPLES:
     #[cfg(feature = "functions-invoke")]
     #[command(subcommand)]
     Functions(FunctionsCommands),
+
+    /// Take, list, and restore logical backups (the server's `[backup]` section)
+    #[cfg(feature = "run-server")]
+    #[command(subcommand)]
+    Backup(BackupCommands),
+
+    /// Search index maintenance (Elasticsearch, Meilisearch, Typesense)
+    #[command(subcommand)]
+    Search(SearchCommands),
+
+    /// Pause and resume observers on a running server
+    #[command(subcommand)]
+    Observers(ObserversCommands),
+
+    /// Contract tests for the compiler
+    #[command(subcommand)]
+    Test(TestCommands),
+}
+
+/// `fraiseql test` subcommands.
+#[derive(Subcommand)]
+pub(crate) enum TestCommands {
+    /// Check compiled operations against golden SQL snapshots
+    ///
+    /// Runs every `.graphql` / `.gql` file under DIR through the compiled schema
+    /// without a database, recording the SQL each one compiles to, and compares
+    /// it with the `<name>.sql` snapshot beside the file. Variables are read from
+    /// an optional `<name>.variables.json`. Exits non-zero when an operation
+    /// fails, has no snapshot, or its SQL changed; `--update` writes the new
+    /// snapshots instead, for review in the diff.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql test sql tests/operations
+    fraiseql test sql tests/operations --schema build/schema.compiled.json
+    fraiseql test sql tests/operations --update")]
+    Sql {
+        /// Directory of GraphQL operation files.
+        dir: std::path::PathBuf,
+
+        /// Path to schema.compiled.json.
+        #[arg(long, default_value = "schema.compiled.json")]
+        schema: std::path::PathBuf,
+
+        /// Write the recorded SQL as the new snapshots instead of comparing.
+        #[arg(long)]
+        update: bool,
+    },
+}
+
+/// `fraiseql observers` subcommands.
+///
+/// All of them call the server's observer admin API, which needs a token with
+/// the `fraiseql:admin` scope.
+#[derive(Subcommand)]
+pub(crate) enum ObserversCommands {
+    /// Pause an observer without dropping its events
+    ///
+    /// The observer stops receiving events at the next batch and its change-log
+    /// position is pinned; events accumulate until it is resumed. A warning is
+    /// logged by the server once the backlog reaches --max-backlog.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql observers pause order_webhook
+    fraiseql observers pause order_webhook --max-backlog 50000 --reason \"partner maintenance\"
+    fraiseql observers pause order_webhook --server https://api.example.com --token $TOKEN")]
+    Pause {
+        /// Observer name.
+        name: String,
+
+        /// Backlog size that raises an alert (server default: 10000).
+        #[arg(long)]
+        max_backlog: Option<u64>,
+
+        /// Why the observer is paused, recorded with the pause.
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Server base URL.
+        #[arg(
+            short,
+            long,
+            value_name = "URL",
+            default_value = "http://localhost:8080"
+        )]
+        server: String,
+
+        /// Bearer token for the admin API.
+        #[arg(short, long, value_name = "TOKEN")]
+        token: Option<String>,
+    },
+
+    /// Resume a paused observer, delivering its backlog first
+    ///
+    /// Returns once the resume has started. The server replays the events the
+    /// observer missed, then puts it back on live events; `fraiseql observers
+    /// paused` shows it as `resuming` until then.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql observers resume order_webhook")]
+    Resume {
+        /// Observer name.
+        name: String,
+
+        /// Server base URL.
+        #[arg(
+            short,
+            long,
+            value_name = "URL",
+            default_value = "http://localhost:8080"
+        )]
+        server: String,
+
+        /// Bearer token for the admin API.
+        #[arg(short, long, value_name = "TOKEN")]
+        token: Option<String>,
+    },
+
+    /// List paused observers with their backlogs
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql observers paused
+    fraiseql observers paused --json")]
+    Paused {
+        /// Server base URL.
+        #[arg(
+            short,
+            long,
+            value_name = "URL",
+            default_value = "http://localhost:8080"
+        )]
+        server: String,
+
+        /// Bearer token for the admin API.
+        #[arg(short, long, value_name = "TOKEN")]
+        token: Option<String>,
+    },
+}
+
+/// `fraiseql search` subcommands.
+#[derive(Subcommand)]
+pub(crate) enum SearchCommands {
+    /// Rebuild an entity's search documents from its backing view
+    ///
+    /// Pages the entity's view (its `sql_source` in the compiled schema, usually
+    /// a `tv_*` table) in id order and bulk-indexes every row as a snapshot
+    /// document. The cursor is saved after each batch; an interrupted run
+    /// continues with `--resume`.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql search reindex --entity Order --backend meilisearch --url https://search.example.com
+    fraiseql search reindex --entity Order --backend typesense --rate-limit 500
+    fraiseql search reindex --entity Order --backend meilisearch --resume
+
+ENVIRONMENT:
+    FRAISEQL_SEARCH_URL      Search engine URL (when --url is omitted)
+    FRAISEQL_SEARCH_API_KEY  Search engine API key (when --api-key is omitted)")]
+    Reindex {
+        /// Entity (GraphQL type) to reindex.
+        #[arg(short, long)]
+        entity: String,
+
+        /// Search backend: elasticsearch, meilisearch, or typesense.
+        #[arg(short, long)]
+        backend: String,
+
+        /// Search engine base URL.
+        #[arg(long)]
+        url: Option<String>,
+
+        /// Index (Meilisearch) or collection (Typesense) name.
+        #[arg(long)]
+        index: Option<String>,
+
+        /// Search engine API key (prefer FRAISEQL_SEARCH_API_KEY).
+        #[arg(long)]
+        api_key: Option<String>,
+
+        /// Path to schema.compiled.json (resolves the entity's view and columns).
+        #[arg(long, default_value = "schema.compiled.json")]
+        schema: std::path::PathBuf,
+
+        /// View to scan instead of the entity's `sql_source`.
+        #[arg(long)]
+        view: Option<String>,
+
+        /// PostgreSQL URL (defaults to `fraiseql.toml` / `DATABASE_URL`).
+        #[arg(long)]
+        db_url: Option<String>,
+
+        /// Tenant recorded on rows of views without a tenant column.
+        #[arg(long, default_value = "default")]
+        tenant: String,
+
+        /// Rows read and indexed per batch.
+        #[arg(long, default_value_t = fraiseql_observers::search::reindex::DEFAULT_REINDEX_BATCH_SIZE)]
+        batch_size: usize,
+
+        /// Maximum documents indexed per second.
+        #[arg(long)]
+        rate_limit: Option<u32>,
+
+        /// Resume-cursor file (default: `.fraiseql/reindex-<entity>.json`).
+        #[arg(long)]
+        state_file: Option<std::path::PathBuf>,
+
+        /// Continue from the cursor saved in the state file.
+        #[arg(long)]
+        resume: bool,
+    },
+}
+
+/// `fraiseql backup` subcommands.
+///
+/// All of them read the `[backup]` section of the server config file; the
+/// database URL resolves as `--database` > `DATABASE_URL` > `database_url` in
+/// that file.
+#[cfg(feature = "run-server")]
+#[derive(Subcommand)]
+pub(crate) enum BackupCommands {
+    /// Take one backup now, then apply the retention policy
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql backup run
+    fraiseql backup run --config /etc/fraiseql/server.toml")]
+    Run {
+        /// Server config file containing the `[backup]` section.
+        #[arg(short, long, default_value = "fraiseql.toml")]
+        config: std::path::PathBuf,
+
+        /// Database URL to dump (overrides DATABASE_URL and the config file).
+        #[arg(long, value_name = "DATABASE_URL")]
+        database: Option<String>,
+    },
+
+    /// List stored backups, newest first
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql backup list
+    fraiseql backup list --json")]
+    List {
+        /// Server config file containing the `[backup]` section.
+        #[arg(short, long, default_value = "fraiseql.toml")]
+        config: std::path::PathBuf,
+    },
+
+    /// Restore a backup into a database
+    ///
+    /// The archive is downloaded, checked against its manifest (size and
+    /// SHA-256), decrypted, and read back with `pg_restore --list` before
+    /// anything is written. `--dry-run` stops after that verification. A real
+    /// restore drops and recreates the archived objects in a single
+    /// transaction.
+    #[command(after_help = "\
+EXAMPLES:
+    fraiseql backup restore latest --dry-run
+    fraiseql backup restore 20260301T020000Z --database postgres://localhost/staging")]
+    Restore {
+        /// Backup id (`YYYYMMDDTHHMMSSZ`, see `fraiseql backup list`) or `latest`.
+        #[arg(default_value = "latest")]
+        backup: String,
+
+        /// Server config file containing the `[backup]` section.
+        #[arg(short, long, default_value = "fraiseql.toml")]
+        config: std::path::PathBuf,
+
+        /// Database URL to restore into (overrides DATABASE_URL and the config file).
+        #[arg(long, value_name = "DATABASE_URL")]
+        database: Option<String>,
+
+        /// Verify the archive without restoring it.
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 /// `fraiseql functions` subcommands.
@@ -958,6 +1366,30 @@ pub(crate) enum GenerateClientCommands {
         /// Overwrite an existing generated client in the output directory.
         #[arg(long, default_value_t = false)]
         force: bool,
+
+        /// Regenerate in place: rewrite only changed files and remove stale ones.
+        #[arg(long, default_value_t = false)]
+        incremental: bool,
+    },
+
+    /// Generate a Python client (`TypedDict` types + typed query/mutation functions).
+    Python {
+        /// Path to schema.compiled.json (auto-detected from conventional
+        /// locations if omitted).
+        #[arg(long, value_name = "SCHEMA")]
+        schema: Option<std::path::PathBuf>,
+
+        /// Output directory (Python package) for the generated client.
+        #[arg(long, value_name = "DIR")]
+        out: std::path::PathBuf,
+
+        /// Overwrite an existing generated client in the output directory.
+        #[arg(long, default_value_t = false)]
+        force: bool,
+
+        /// Regenerate in place: rewrite only changed files and remove stale ones.
+        #[arg(long, default_value_t = false)]
+        incremental: bool,
     },
 }
 
@@ -1,10 +1,19 @@
 //! Analyze command - schema optimization analysis
 //!
 //! Usage: fraiseql analyze <schema.compiled.json> [--json]
+//!        fraiseql analyze <schema.compiled.json> --usage <DATABASE_URL> [--json]
+//!
+//! With `--usage`, the report is built from the per-client field selection
+//! counters the server flushes to `fraiseql_field_usage` (`[field_usage]`):
+//! fields no client has selected, and deprecated fields that are still in use.
 
-use std::{collections::HashMap, fs};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use fraiseql_core::schema::{CompiledSchema, DeprecationInfo};
 use serde::Serialize;
 
 use crate::output::CommandResult;
@@ -128,3 +137,170 @@ pub fn run(schema_path: &str) -> Result<CommandResult> {
 
     Ok(CommandResult::success("analyze", serde_json::to_value(&analysis)?))
 }
+
+/// One persisted field-usage counter row.
+#[derive(Debug, Clone)]
+pub struct FieldUsageRow {
+    /// Client id that selected the coordinate.
+    pub client_id:  String,
+    /// Schema coordinate, e.g. `User.email`.
+    pub coordinate: String,
+    /// Number of requests that selected it.
+    pub count:      u64,
+}
+
+/// Deprecated schema coordinate that clients still select.
+#[derive(Debug, Serialize)]
+pub struct DeprecatedFieldUsage {
+    /// Schema coordinate.
+    pub coordinate: String,
+    /// Deprecation reason, if one was given.
+    pub reason:     Option<String>,
+    /// Total selections across all clients.
+    pub count:      u64,
+    /// Clients still selecting it, sorted.
+    pub clients:    Vec<String>,
+}
+
+/// Field usage report produced by `fraiseql analyze --usage`.
+#[derive(Debug, Serialize)]
+pub struct FieldUsageReport {
+    /// Path to analyzed schema
+    pub schema_file:       String,
+    /// Number of coordinates the schema defines.
+    pub total_fields:      usize,
+    /// Number of those coordinates selected at least once.
+    pub used_fields:       usize,
+    /// Coordinates no client has selected — candidates for removal.
+    pub unused_fields:     Vec<String>,
+    /// Deprecated coordinates that are still selected.
+    pub deprecated_in_use: Vec<DeprecatedFieldUsage>,
+    /// Recorded coordinates the schema no longer defines.
+    pub unknown_fields:    Vec<String>,
+}
+
+/// Every selectable coordinate in the schema with its deprecation, if any.
+///
+/// Root operations are `Query.<name>` / `Mutation.<name>`; type and interface
+/// fields use the GraphQL (output) name clients select.
+fn schema_coordinates(schema: &CompiledSchema) -> BTreeMap<String, Option<&DeprecationInfo>> {
+    let mut coordinates = BTreeMap::new();
+    for query in &schema.queries {
+        coordinates.insert(format!("Query.{}", query.name), query.deprecation.as_ref());
+    }
+    for mutation in &schema.mutations {
+        coordinates.insert(format!("Mutation.{}", mutation.name), mutation.deprecation.as_ref());
+    }
+    for ty in &schema.types {
+        for field in &ty.fields {
+            coordinates
+                .insert(format!("{}.{}", ty.name, field.output_name()), field.deprecation.as_ref());
+        }
+    }
+    for iface in &schema.interfaces {
+        for field in &iface.fields {
+            coordinates.insert(
+                format!("{}.{}", iface.name, field.output_name()),
+                field.deprecation.as_ref(),
+            );
+        }
+    }
+    coordinates
+}
+
+/// Compare recorded field usage against the schema.
+#[must_use]
+pub fn field_usage_report(
+    schema_file: &str,
+    schema: &CompiledSchema,
+    rows: &[FieldUsageRow],
+) -> FieldUsageReport {
+    let mut usage: BTreeMap<&str, (u64, BTreeSet<&str>)> = BTreeMap::new();
+    for row in rows.iter().filter(|row| row.count > 0) {
+        let (count, clients) = usage.entry(row.coordinate.as_str()).or_default();
+        *count = count.saturating_add(row.count);
+        clients.insert(row.client_id.as_str());
+    }
+
+    let coordinates = schema_coordinates(schema);
+    let mut unused_fields = Vec::new();
+    let mut deprecated_in_use = Vec::new();
+    for (coordinate, deprecation) in &coordinates {
+        match (usage.get(coordinate.as_str()), deprecation) {
+            (None, _) => unused_fields.push(coordinate.clone()),
+            (Some((count, clients)), Some(info)) => deprecated_in_use.push(DeprecatedFieldUsage {
+                coordinate: coordinate.clone(),
+                reason:     info.reason.clone(),
+                count:      *count,
+                clients:    clients.iter().map(ToString::to_string).collect(),
+            }),
+            (Some(_), None) => {},
+        }
+    }
+    let unknown_fields = usage
+        .keys()
+        .filter(|coordinate| !coordinates.contains_key(**coordinate))
+        .map(ToString::to_string)
+        .collect();
+
+    FieldUsageReport {
+        schema_file: schema_file.to_string(),
+        total_fields: coordinates.len(),
+        used_fields: coordinates.len() - unused_fields.len(),
+        unused_fields,
+        deprecated_in_use,
+        unknown_fields,
+    }
+}
+
+/// Run `analyze --usage`: report dead and still-used deprecated fields.
+///
+/// # Errors
+///
+/// Returns an error if the schema cannot be loaded or the usage table cannot
+/// be read.
+pub async fn run_usage(schema_path: &str, database_url: &str) -> Result<CommandResult> {
+    use deadpool_postgres::{Config, ManagerConfig, RecyclingMethod, Runtime};
+    use tokio_postgres::NoTls;
+
+    let schema_content = fs::read_to_string(schema_path)?;
+    let schema = CompiledSchema::from_json(&schema_content, false)
+        .with_context(|| format!("Failed to parse {schema_path}"))?;
+
+    let mut cfg = Config::new();
+    cfg.url = Some(database_url.to_string());
+    cfg.manager = Some(ManagerConfig {
+        recycling_method: RecyclingMethod::Fast,
+    });
+    cfg.pool = Some(deadpool_postgres::PoolConfig::new(1));
+    let pool = cfg
+        .create_pool(Some(Runtime::Tokio1), NoTls)
+        .context("Failed to create database pool")?;
+    let client = pool.get().await.context("Failed to connect to database")?;
+    let rows = client
+        .query("SELECT client_id, coordinate, count FROM fraiseql_field_usage", &[])
+        .await
+        .context("Failed to read fraiseql_field_usage (is [field_usage] enabled on the server?)")?;
+    let rows: Vec<FieldUsageRow> = rows
+        .iter()
+        .map(|row| FieldUsageRow {
+            client_id:  row.get(0),
+            coordinate: row.get(1),
+            count:      row.get::<_, i64>(2).max(0).cast_unsigned(),
+        })
+        .collect();
+
+    let report = field_usage_report(schema_path, &schema, &rows);
+    let mut warnings = Vec::new();
+    if !report.deprecated_in_use.is_empty() {
+        warnings.push(format!(
+            "{} deprecated field(s) are still selected by clients",
+            report.deprecated_in_use.len()
+        ));
+    }
+    Ok(CommandResult::success_with_warnings(
+        "analyze",
+        serde_json::to_value(&report)?,
+        warnings,
+    ))
+}
@@ -0,0 +1,167 @@
+//! `fraiseql backup` — take, list, and restore logical backups.
+//!
+//! A thin front end over [`fraiseql_server::backup`]: every subcommand loads the
+//! `[backup]` section from the server config file and builds the same
+//! [`BackupRunner`] the server's scheduler uses, so archives taken by a running
+//! server and by `fraiseql backup run` are interchangeable.
+//!
+//! `restore` always verifies before it writes: the archive's size and SHA-256
+//! are checked against its manifest, it is decrypted, and `pg_restore --list`
+//! must read its table of contents. `--dry-run` stops there.
+
+#[cfg(test)]
+mod tests;
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use fraiseql_server::{
+    ServerConfig,
+    backup::{BackupManifest, BackupRunner},
+};
+
+/// Run `fraiseql backup run`: take one backup now and prune.
+///
+/// # Errors
+///
+/// Returns an error if the config has no `[backup]` section, the runner cannot
+/// be built, or the backup fails. A pruning failure is reported as a warning.
+pub async fn run(config_path: &Path, database: Option<String>) -> Result<()> {
+    let runner = load_runner(config_path, database).await?;
+    let manifest = runner.run_once().await.context("backup failed")?;
+    println!(
+        "Backed up {} to {} ({})",
+        manifest.schemas.join(", "),
+        manifest.archive_key,
+        format_size(manifest.size_bytes)
+    );
+    match runner.prune().await {
+        Ok(pruned) if !pruned.is_empty() => {
+            println!("Pruned {} expired backup(s): {}", pruned.len(), pruned.join(", "));
+        },
+        Ok(_) => {},
+        Err(e) => eprintln!("warning: retention pruning failed: {e}"),
+    }
+    Ok(())
+}
+
+/// Run `fraiseql backup list`.
+///
+/// # Errors
+///
+/// Returns an error if the runner cannot be built or the storage backend
+/// cannot be listed.
+pub async fn list(config_path: &Path, json: bool) -> Result<()> {
+    let runner = load_runner(config_path, None).await?;
+    let manifests = runner.list().await.context("cannot list backups")?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&manifests)?);
+    } else {
+        print!("{}", render_list(&manifests));
+    }
+    Ok(())
+}
+
+/// Run `fraiseql backup restore`.
+///
+/// # Errors
+///
+/// Returns an error if the backup cannot be found, fails verification, or
+/// `pg_restore` rejects it.
+pub async fn restore(
+    config_path: &Path,
+    backup: &str,
+    database: Option<String>,
+    dry_run: bool,
+    json: bool,
+) -> Result<()> {
+    let config = load_config(config_path, database)?;
+    let target = config.database_url.clone();
+    let runner = build_runner(config).await?;
+    let report = runner
+        .restore(backup, &target, dry_run)
+        .await
+        .with_context(|| format!("restore of backup `{backup}` failed"))?;
+
+    if json {
+        let payload = serde_json::json!({
+            "backup": report.manifest,
+            "toc_entries": report.toc_entries,
+            "applied": report.applied,
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else if report.applied {
+        println!(
+            "Restored backup {} ({} archive entries) into the target database",
+            report.manifest.id, report.toc_entries
+        );
+    } else {
+        println!(
+            "Dry run: backup {} verified ({}, {} archive entries); nothing was restored",
+            report.manifest.id,
+            format_size(report.manifest.size_bytes),
+            report.toc_entries
+        );
+    }
+    Ok(())
+}
+
+/// Load the server config and apply the database URL precedence:
+/// `--database` > `DATABASE_URL` > `database_url` in the file.
+fn load_config(config_path: &Path, database: Option<String>) -> Result<ServerConfig> {
+    let mut config = ServerConfig::from_file(config_path)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", config_path.display()))?;
+    if let Some(url) = database.or_else(|| std::env::var("DATABASE_URL").ok()) {
+        config.database_url = url;
+    }
+    Ok(config)
+}
+
+async fn load_runner(config_path: &Path, database: Option<String>) -> Result<BackupRunner> {
+    build_runner(load_config(config_path, database)?).await
+}
+
+async fn build_runner(config: ServerConfig) -> Result<BackupRunner> {
+    let backup = config
+        .backup
+        .ok_or_else(|| anyhow::anyhow!("the config file has no [backup] section"))?;
+    BackupRunner::from_config(*backup, config.database_url)
+        .await
+        .context("cannot initialize backups")
+}
+
+/// Render the human-readable `backup list` table.
+pub(crate) fn render_list(manifests: &[BackupManifest]) -> String {
+    if manifests.is_empty() {
+        return "No backups found.\n".to_string();
+    }
+    let mut out = format!("{:<18} {:>10}  {:<9}  {}\n", "ID", "SIZE", "ENCRYPTED", "SCHEMAS");
+    for manifest in manifests {
+        out.push_str(&format!(
+            "{:<18} {:>10}  {:<9}  {}\n",
+            manifest.id,
+            format_size(manifest.size_bytes),
+            if manifest.encrypted { "yes" } else { "no" },
+            manifest.schemas.join(", ")
+        ));
+    }
+    out
+}
+
+/// Format a byte count with a binary unit (`1.5 MiB`).
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    // Reason: display-only rounding; precision loss above 2^53 bytes is irrelevant.
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
@@ -0,0 +1,31 @@
+//! Tests for the `fraiseql backup` output helpers.
+
+use super::*;
+
+#[test]
+fn format_size_uses_binary_units() {
+    assert_eq!(format_size(512), "512 B");
+    assert_eq!(format_size(1536), "1.5 KiB");
+    assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+}
+
+#[test]
+fn render_list_shows_one_row_per_backup() {
+    assert_eq!(render_list(&[]), "No backups found.\n");
+    let manifest = BackupManifest {
+        version:           1,
+        id:                "20260301T020000Z".to_string(),
+        archive_key:       "backups/20260301T020000Z.dump.enc".to_string(),
+        created_at:        "2026-03-01T02:00:00Z".parse().expect("timestamp"),
+        schemas:           vec!["public".to_string()],
+        compression_level: 6,
+        encrypted:         true,
+        size_bytes:        2048,
+        sha256:            String::new(),
+    };
+    let rendered = render_list(&[manifest]);
+    assert_eq!(rendered.lines().count(), 2);
+    assert!(rendered.contains("20260301T020000Z"));
+    assert!(rendered.contains("2.0 KiB"));
+    assert!(rendered.contains("yes"));
+}
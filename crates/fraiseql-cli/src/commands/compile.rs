@@ -5,9 +5,12 @@
 use std::{fs, path::Path, process::Command};
 
 use anyhow::{Context, Result};
-use fraiseql_core::schema::{
-    CURRENT_SCHEMA_FORMAT_VERSION, CompiledSchema, FieldType, InputStyle, MutationOperation,
-    NamingConvention, canonicalize_json,
+use fraiseql_core::{
+    compiler::CompilerPluginRegistry,
+    schema::{
+        CURRENT_SCHEMA_FORMAT_VERSION, CompiledSchema, FieldType, InputStyle, MutationOperation,
+        NamingConvention, SchemaSigningKey, seal_artifact,
+    },
 };
 use tracing::{info, warn};
 
@@ -41,6 +44,9 @@ pub struct CompileOptions<'a> {
     pub database:       Option<&'a str>,
     /// Skip embedding content hash in compiled schema (for test fixtures).
     pub skip_hash:      bool,
+    /// Compiler plugins run before SQL codegen, merged with any declared in
+    /// `[fraiseql.compiler]` of `fraiseql.toml`.
+    pub plugins:        CompilerPluginRegistry,
 }
 
 impl<'a> CompileOptions<'a> {
@@ -73,6 +79,13 @@ impl<'a> CompileOptions<'a> {
         self.database = Some(database);
         self
     }
+
+    /// Set the compiler plugins.
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: CompilerPluginRegistry) -> Self {
+        self.plugins = plugins;
+        self
+    }
 }
 
 /// Select and execute the appropriate schema-loading strategy for TOML-based workflows.
@@ -139,7 +152,7 @@ fn load_intermediate_schema(
 /// connection fails (when `database` is provided).
 #[allow(clippy::cognitive_complexity)] // Reason: end-to-end compilation pipeline with validation, introspection, and output stages
 pub async fn compile_to_schema(
-    opts: CompileOptions<'_>,
+    mut opts: CompileOptions<'_>,
 ) -> Result<(CompiledSchema, OptimizationReport)> {
     info!("Compiling schema: {}", opts.input);
 
@@ -220,6 +233,11 @@ pub async fn compile_to_schema(
                 auto_error_union = config.fraiseql.mutations.auto_error_union;
                 naming_acronyms.clone_from(&config.fraiseql.naming.acronyms);
                 operation_cost_weights.clone_from(&config.fraiseql.cost_weights);
+                for plugin in &config.fraiseql.compiler.inject_fields {
+                    opts.plugins
+                        .register(Box::new(plugin.to_plugin()))
+                        .context("Invalid [fraiseql.compiler] plugin")?;
+                }
                 naming_convention = config.fraiseql.naming.convention;
 
                 info!("Applying security configuration to schema...");
@@ -321,6 +339,13 @@ pub async fn compile_to_schema(
         schema.operation_cost_weights = operation_cost_weights;
     }
 
+    // 4a. Run compiler plugins before SQL codegen; the registry records the
+    //     order they ran in on the schema.
+    if !opts.plugins.is_empty() {
+        info!("Applying {} compiler plugin(s)...", opts.plugins.len());
+        opts.plugins.apply(&mut schema).context("Compiler plugin failed")?;
+    }
+
     // 5. Optimize schema and generate SQL hints (mutates schema in place, report for display)
     info!("Analyzing schema for optimization opportunities...");
     let report = SchemaOptimizer::optimize(&mut schema).context("Failed to optimize schema")?;
@@ -351,6 +376,11 @@ pub async fn compile_to_schema(
             }
         }
 
+        match detect_geo_backend(&pg_introspector).await {
+            Ok(backend) => schema.geo_backend = backend,
+            Err(e) => warn!("Could not detect spatial extensions, assuming PostGIS: {e}"),
+        }
+
         // Mutation call/response contract (#384 item 3: inject_params resolve to real
         // function arguments). PostgreSQL-only — the catalog reads `pg_proc`; other
         // dialects are validated structurally above. Advisory like the rest of
@@ -426,6 +456,7 @@ pub async fn compile_to_schema(
 /// * `emit_ddl` - Optional directory to write `CREATE TABLE` DDL files (confiture format)
 /// * `check_migrations` - If true, run `confiture migrate validate` after compilation
 /// * `skip_hash` - Skip embedding content hash (for test fixtures)
+/// * `sign_key` - Optional path to an Ed25519 private key; embeds a `_signature` of the output
 ///
 /// # Errors
 ///
@@ -438,6 +469,7 @@ pub async fn compile_to_schema(
 /// - DDL output directory cannot be created (when `emit_ddl` is provided)
 /// - `confiture` is not installed (when `check_migrations` is true)
 /// - Migration drift detected (when `check_migrations` is true)
+/// - The signing key cannot be read or parsed, or is combined with `skip_hash`
 #[allow(clippy::too_many_arguments)]
 // Reason: run() is the CLI entry point that receives individual args from clap; keeping them
 // separate for clarity
@@ -455,7 +487,12 @@ pub async fn run(
     emit_ddl: Option<&str>,
     check_migrations: bool,
     skip_hash: bool,
+    sign_key: Option<&str>,
 ) -> Result<()> {
+    if skip_hash && sign_key.is_some() {
+        anyhow::bail!("--sign-key signs the content hash and cannot be combined with --skip-hash");
+    }
+
     // Defense-in-depth: never write the compiled output over the input schema.
     // The removed `serve` command did exactly this (H23) by deriving an output
     // path identical to its input via a faulty extension swap. `--check` writes
@@ -476,6 +513,7 @@ pub async fn run(
         mutation_files,
         database,
         skip_hash,
+        plugins: CompilerPluginRegistry::new(),
     };
     let (schema, optimization_report) = compile_to_schema(opts).await?;
 
@@ -491,28 +529,17 @@ pub async fn run(
 
     // Write compiled schema
     info!("Writing compiled schema to: {output}");
-    let output_json = if skip_hash {
-        serde_json::to_string_pretty(&schema).context("Failed to serialize compiled schema")?
-    } else {
-        use sha2::{Digest, Sha256};
-
-        let body =
+    let (output_json, content_hash) = if skip_hash {
+        let json =
             serde_json::to_string_pretty(&schema).context("Failed to serialize compiled schema")?;
-        let value: serde_json::Value = serde_json::from_str(&body)?;
-        // Canonicalize (recursively sort keys) before hashing — matches from_json verifier
-        let canonical = serde_json::to_string_pretty(&canonicalize_json(&value))?;
-        let hash = Sha256::digest(canonical.as_bytes());
-        let hash_hex = hex::encode(&hash[..16]);
-
-        let obj = value.as_object().context("schema must serialise as JSON object")?;
-
-        // Insert _content_hash as the first field (serde_json::Map preserves insertion order)
-        let mut new_obj = serde_json::Map::new();
-        new_obj.insert("_content_hash".to_string(), serde_json::Value::String(hash_hex));
-        for (k, v) in obj {
-            new_obj.insert(k.clone(), v.clone());
-        }
-        serde_json::to_string_pretty(&serde_json::Value::Object(new_obj))?
+        (json, None)
+    } else {
+        let signing_key = sign_key.map(load_signing_key).transpose()?;
+        let value = serde_json::to_value(&schema).context("Failed to serialize compiled schema")?;
+        // Hash (and sign) the canonical body — matches the from_json / server verifiers
+        let (json, hash) = seal_artifact(&value, signing_key.as_ref())
+            .context("Failed to seal compiled schema")?;
+        (json, Some(hash))
     };
 
     fs::write(output, output_json).context("Failed to write compiled schema")?;
@@ -521,6 +548,10 @@ pub async fn run(
     println!("✓ Schema compiled successfully");
     println!("  Input:  {input}");
     println!("  Output: {output}");
+    if let Some(hash) = &content_hash {
+        let signed = if sign_key.is_some() { " (signed)" } else { "" };
+        println!("  Content hash: {hash}{signed}");
+    }
     println!("  Types: {}", schema.types.len());
     println!("  Queries: {}", schema.queries.len());
     println!("  Mutations: {}", schema.mutations.len());
@@ -539,6 +570,13 @@ pub async fn run(
     Ok(())
 }
 
+/// Read the Ed25519 private key passed to `--sign-key` (PKCS#8 PEM or base64 seed).
+fn load_signing_key(path: &str) -> Result<SchemaSigningKey> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read schema signing key: {path}"))?;
+    SchemaSigningKey::parse(&text).with_context(|| format!("Invalid schema signing key: {path}"))
+}
+
 /// Emit `CREATE TABLE` DDL files for all compiled schema types to `output_dir`.
 ///
 /// Each type produces one `<type_snake_case>.sql` file containing a `CREATE TABLE IF NOT EXISTS`
@@ -911,6 +949,34 @@ fn warn_jsonb_preserve_mismatch(schema: &CompiledSchema) {
     }
 }
 
+/// Pick the spatial extension geospatial WHERE operators are rendered for.
+///
+/// PostGIS when installed (or when the database cannot be probed); otherwise
+/// the `earthdistance` fallback if that is installed. With neither, PostGIS SQL
+/// is kept and `near` / `withinPolygon` filters fail at query time.
+///
+/// # Errors
+///
+/// Returns error if the extension catalog query fails.
+async fn detect_geo_backend(
+    introspector: &impl fraiseql_core::db::DatabaseIntrospector,
+) -> fraiseql_core::Result<fraiseql_core::db::GeoBackend> {
+    use fraiseql_core::db::GeoBackend;
+
+    if introspector.extension_installed("postgis").await?.unwrap_or(true) {
+        return Ok(GeoBackend::PostGis);
+    }
+    if introspector.extension_installed("earthdistance").await?.unwrap_or(false) {
+        info!("PostGIS not installed; geospatial filters will use the earthdistance extension");
+        return Ok(GeoBackend::EarthDistance);
+    }
+    warn!(
+        "Neither PostGIS nor earthdistance is installed; geospatial WHERE operators \
+         (near, withinPolygon, withinBoundingBox) will fail at query time"
+    );
+    Ok(GeoBackend::PostGis)
+}
+
 /// Build a PostgreSQL introspector connected to `db_url`.
 ///
 /// Shared by `validate_indexed_columns` and the native column validation path.
@@ -168,6 +168,50 @@ pub fn check_schema_version(path: &Path) -> DoctorCheck {
     }
 }
 
+/// Prefixes of FraiseQL read views: `v_` (view), `tv_` (table-backed view),
+/// `mv_` (materialized view), `va_` / `ta_` (Arrow views).
+const VIEW_PREFIXES: &[&str] = &["v_", "tv_", "mv_", "va_", "ta_"];
+
+/// Check that every query's `sql_source` follows the view naming conventions.
+pub fn check_view_naming(path: &Path) -> DoctorCheck {
+    match load_compiled_schema(path) {
+        Ok(schema) => view_naming_check(&schema),
+        Err(e) => DoctorCheck::warn(
+            "View naming",
+            format!("{e} — naming check skipped"),
+            "Run `fraiseql compile` to regenerate",
+        ),
+    }
+}
+
+/// Classify the query `sql_source` names of `schema` against [`VIEW_PREFIXES`].
+///
+/// Pure — unit-tested without a schema file. A source outside the conventions
+/// still works; it is a `Warn` because tooling that derives the backing table or
+/// entity from the view name (cache invalidation, `generate-views`) skips it.
+pub(crate) fn view_naming_check(schema: &CompiledSchema) -> DoctorCheck {
+    let mut offenders: Vec<&str> = schema
+        .queries
+        .iter()
+        .filter_map(|q| q.sql_source.as_deref())
+        .filter(|source| {
+            let name = source.rsplit('.').next().unwrap_or(source);
+            !VIEW_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+        })
+        .collect();
+    offenders.sort_unstable();
+    offenders.dedup();
+    if offenders.is_empty() {
+        return DoctorCheck::pass("View naming", "every query sql_source uses a view prefix");
+    }
+    DoctorCheck::warn(
+        "View naming",
+        format!("query sql_source without a view prefix: {}", offenders.join(", ")),
+        "Name read views v_<entity> (or tv_/mv_/va_/ta_) so the backing table and entity can \
+         be derived from the view name",
+    )
+}
+
 /// Check whether `fraiseql.toml` exists.
 pub fn check_toml_exists(path: &Path) -> DoctorCheck {
     if path.exists() {
@@ -519,6 +563,7 @@ pub fn run_checks(
     if schema_path.exists() {
         checks.push(check_schema_parses(schema_path));
         checks.push(check_schema_version(schema_path));
+        checks.push(check_view_naming(schema_path));
     }
 
     // TOML config checks. When `--schema` is a compiled JSON, `--config` is a
@@ -570,6 +615,7 @@ pub async fn run_with_db_checks(
     // checks themselves, for the no-flag case).
     let mut checks = run_checks(config, schema, effective_db_url(db_url, against_db));
     if let Some(url) = against_db {
+        checks.extend(environment_checks(url, schema).await);
         checks.extend(changelog_contract_checks(url).await);
         checks.extend(changelog_rls_checks(url).await);
         checks.extend(changelog_public_grants_checks(url).await);
@@ -594,6 +640,177 @@ pub async fn run_with_db_checks(
     checks.iter().all(|c| c.status != CheckStatus::Fail)
 }
 
+// ─── Database environment ────────────────────────────────────────────────────
+
+const POSTGRES_VERSION_NAME: &str = "PostgreSQL version";
+const OBSERVER_TABLES_NAME: &str = "Observer tables";
+
+/// Oldest supported PostgreSQL `server_version_num`.
+const MIN_POSTGRES_VERSION: i32 = 120_000;
+
+/// `server_version_num` from which `security_invoker` views are available.
+const SECURITY_INVOKER_POSTGRES_VERSION: i32 = 150_000;
+
+/// Tables the observer runtime reads and writes on PostgreSQL.
+const OBSERVER_TABLES: &[&str] = &[
+    "core.tb_entity_change_log",
+    "observer_checkpoints",
+    "_fraiseql_observer_pause",
+];
+
+/// Check the live database environment: server version, the extensions the
+/// schema relies on, and the observer tables when the schema declares observers.
+///
+/// The compiled schema decides what is required; when it cannot be loaded the
+/// extension checks report presence only. A connection failure becomes a single
+/// `Fail` check (never panics).
+async fn environment_checks(db_url: &str, schema_path: &Path) -> Vec<DoctorCheck> {
+    let catalog = match PgCatalog::connect(db_url) {
+        Ok(c) => c,
+        Err(e) => {
+            return vec![DoctorCheck::fail(
+                POSTGRES_VERSION_NAME,
+                format!("cannot connect: {e}"),
+                "Pass a reachable postgres:// URL to --against-db",
+            )];
+        },
+    };
+    let schema = load_compiled_schema(schema_path).ok();
+
+    let mut checks = vec![match catalog.server_version_num().await {
+        Ok(version) => postgres_version_check(version),
+        Err(e) => DoctorCheck::fail(
+            POSTGRES_VERSION_NAME,
+            format!("cannot query the server: {e}"),
+            "Check the credentials and database name in the --against-db URL",
+        ),
+    }];
+
+    match catalog.installed_extensions().await {
+        Ok(installed) => checks.extend(extension_checks(&installed, schema.as_ref())),
+        Err(e) => checks.push(DoctorCheck::fail(
+            "Extensions",
+            format!("introspection failed: {e}"),
+            "Ensure the connecting role can read pg_extension",
+        )),
+    }
+
+    if let Some(schema) = schema.as_ref().filter(|s| !s.observers.is_empty()) {
+        let mut missing = Vec::new();
+        for table in OBSERVER_TABLES {
+            match catalog.relation_exists(table).await {
+                Ok(true) => {},
+                Ok(false) => missing.push(*table),
+                Err(e) => {
+                    checks.push(DoctorCheck::fail(
+                        OBSERVER_TABLES_NAME,
+                        format!("introspection failed: {e}"),
+                        "Ensure the connecting role can resolve the observer tables",
+                    ));
+                    return checks;
+                },
+            }
+        }
+        checks.push(observer_tables_check(schema.observers.len(), &missing));
+    }
+    checks
+}
+
+/// Classify a `server_version_num` against the supported range.
+pub(crate) fn postgres_version_check(version_num: i32) -> DoctorCheck {
+    let version = format!("{}.{}", version_num / 10_000, version_num % 10_000);
+    if version_num < MIN_POSTGRES_VERSION {
+        DoctorCheck::fail(
+            POSTGRES_VERSION_NAME,
+            format!("PostgreSQL {version} is older than the supported minimum (12)"),
+            "Upgrade to PostgreSQL 15 or newer",
+        )
+    } else if version_num < SECURITY_INVOKER_POSTGRES_VERSION {
+        DoctorCheck::warn(
+            POSTGRES_VERSION_NAME,
+            format!("PostgreSQL {version} — security_invoker views need 15+"),
+            "Upgrade to PostgreSQL 15 or newer so sql_source views can honour base-table RLS",
+        )
+    } else {
+        DoctorCheck::pass(POSTGRES_VERSION_NAME, format!("PostgreSQL {version}"))
+    }
+}
+
+/// Classify the `pgcrypto`, `ltree` and `postgis` extensions against what
+/// `schema` uses: `ltree` backs `[hierarchies]`, `postgis` the geospatial filters
+/// of a schema compiled for the PostGIS backend. Pure — unit-tested without a
+/// connection.
+pub(crate) fn extension_checks(
+    installed: &[(String, String)],
+    schema: Option<&CompiledSchema>,
+) -> Vec<DoctorCheck> {
+    let version = |name: &str| {
+        installed
+            .iter()
+            .find(|(ext, _)| ext == name)
+            .map(|(_, version)| version.as_str())
+    };
+    let pgcrypto = match version("pgcrypto") {
+        Some(v) => DoctorCheck::pass("Extension pgcrypto", format!("installed ({v})")),
+        None => DoctorCheck::warn(
+            "Extension pgcrypto",
+            "not installed — mutation functions using crypt(), gen_salt() or \
+             gen_random_bytes() will fail",
+            "CREATE EXTENSION IF NOT EXISTS pgcrypto;",
+        ),
+    };
+
+    let hierarchies =
+        schema.is_some_and(|s| s.hierarchies_config.as_ref().is_some_and(|h| !h.is_empty()));
+    let ltree = match version("ltree") {
+        Some(v) => DoctorCheck::pass("Extension ltree", format!("installed ({v})")),
+        None if hierarchies => DoctorCheck::fail(
+            "Extension ltree",
+            "not installed, but the schema declares [hierarchies] — descendantOfId / \
+             ancestorOfId filters will fail",
+            "CREATE EXTENSION IF NOT EXISTS ltree;",
+        ),
+        None => DoctorCheck::pass("Extension ltree", "not installed (no [hierarchies] declared)"),
+    };
+
+    let postgis_backend =
+        schema.is_none_or(|s| s.geo_backend == fraiseql_core::db::GeoBackend::PostGis);
+    let postgis = match version("postgis") {
+        Some(v) => DoctorCheck::pass("Extension postgis", format!("installed ({v})")),
+        None if postgis_backend => DoctorCheck::warn(
+            "Extension postgis",
+            "not installed — geospatial filters (near, withinPolygon, withinBoundingBox) \
+             will fail",
+            "CREATE EXTENSION IF NOT EXISTS postgis; or install cube + earthdistance and \
+             recompile with `fraiseql compile --database` to use the earthdistance backend",
+        ),
+        None => DoctorCheck::pass(
+            "Extension postgis",
+            "not installed (the schema uses the earthdistance geo backend)",
+        ),
+    };
+    vec![pgcrypto, ltree, postgis]
+}
+
+/// Classify the observer tables missing from a database serving `observers`
+/// observers. Pure — unit-tested without a connection.
+pub(crate) fn observer_tables_check(observers: usize, missing: &[&str]) -> DoctorCheck {
+    if missing.is_empty() {
+        return DoctorCheck::pass(
+            OBSERVER_TABLES_NAME,
+            format!("present for {observers} observer(s)"),
+        );
+    }
+    DoctorCheck::fail(
+        OBSERVER_TABLES_NAME,
+        format!(
+            "the schema declares {observers} observer(s) but these tables are missing: {}",
+            missing.join(", ")
+        ),
+        "Run `fraiseql setup` or `fraiseql migrate up` to install the observer tables",
+    )
+}
+
 // ─── Runtime smoke (#501) ───────────────────────────────────────────────────────
 
 const RUNTIME_NAME: &str = "Runtime smoke";
@@ -49,6 +49,11 @@ impl SchemaExtractor for GoExtractor {
                 embedded: false,
                 subscribable_tables: None,
                 subscribable_pre_image: false,
+                default_order_by: Vec::new(),
+                search: None,
+                order_by_aggregates: Vec::new(),
+                soft_delete_field: None,
+                include_deleted_scope: None,
             });
         }
 
@@ -110,6 +115,9 @@ pub(super) fn extract_go_struct_fields(lines: &[&str], start: usize) -> Vec<Inte
                 on_deny: None,
                 authorize: None,
                 hierarchy: None,
+                sql_expression: None,
+                encrypted: None,
+                encryption_key: None,
             });
         }
     }
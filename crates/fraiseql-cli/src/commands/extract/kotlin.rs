@@ -41,6 +41,11 @@ impl SchemaExtractor for KotlinExtractor {
                 embedded: false,
                 subscribable_tables: None,
                 subscribable_pre_image: false,
+                default_order_by: Vec::new(),
+                search: None,
+                order_by_aggregates: Vec::new(),
+                soft_delete_field: None,
+                include_deleted_scope: None,
             });
         }
 
@@ -101,6 +106,9 @@ pub(super) fn extract_kotlin_fields(body: &str) -> Vec<IntermediateField> {
             on_deny: None,
             authorize: None,
             hierarchy: None,
+            sql_expression: None,
+            encrypted: None,
+            encryption_key: None,
         });
     }
     fields
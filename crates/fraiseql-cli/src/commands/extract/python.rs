@@ -61,6 +61,9 @@ impl SchemaExtractor for PythonExtractor {
                         on_deny: None,
                         authorize: None,
                         hierarchy: None,
+                        sql_expression: None,
+                        encrypted: None,
+                        encryption_key: None,
                     });
                 }
             }
@@ -78,6 +81,11 @@ impl SchemaExtractor for PythonExtractor {
                 embedded: false,
                 subscribable_tables: None,
                 subscribable_pre_image: false,
+                default_order_by: Vec::new(),
+                search: None,
+                order_by_aggregates: Vec::new(),
+                soft_delete_field: None,
+                include_deleted_scope: None,
             });
         }
 
@@ -150,6 +150,9 @@ fn sample_schema() -> IntermediateSchema {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "id".to_string(),
@@ -161,6 +164,9 @@ fn sample_schema() -> IntermediateSchema {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "name".to_string(),
@@ -172,6 +178,9 @@ fn sample_schema() -> IntermediateSchema {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "bio".to_string(),
@@ -183,6 +192,9 @@ fn sample_schema() -> IntermediateSchema {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -193,6 +205,11 @@ fn sample_schema() -> IntermediateSchema {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         queries: vec![
             IntermediateQuery {
@@ -12,28 +12,47 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use fraiseql_core::schema::CompiledSchema;
 
+/// Marker every generated file carries in its header comment.
+const GENERATED_MARKER: &str = "AUTO-GENERATED by fraiseql-codegen";
+
 /// Supported client target languages.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClientLanguage {
     /// TypeScript client (interfaces + typed query/mutation functions).
     TypeScript,
+    /// Python client package (`TypedDict` types + typed query/mutation functions).
+    Python,
+}
+
+impl ClientLanguage {
+    /// File extension of the generated sources.
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::TypeScript => "ts",
+            Self::Python => "py",
+        }
+    }
 }
 
 /// Run `generate-client <language>`.
 ///
 /// `schema_path` is auto-detected from conventional locations when `None`.
-/// Refuses to overwrite an existing generated tree unless `force` is set.
+/// Refuses to overwrite an existing generated tree unless `force` or `incremental`
+/// is set. With `incremental`, files whose content is unchanged are left untouched
+/// (so file watchers and build caches see no spurious modification) and generated
+/// files the schema no longer produces are removed.
 ///
 /// # Errors
 ///
 /// Returns an error if the schema cannot be found, read, or parsed, if the output
-/// directory already contains a generated client and `force` is not set, or if any
-/// file cannot be written.
+/// directory already contains a generated client and neither `force` nor
+/// `incremental` is set, or if any file cannot be written or removed.
 pub fn run(
     language: ClientLanguage,
     schema_path: Option<&Path>,
     out_dir: &Path,
     force: bool,
+    incremental: bool,
 ) -> Result<()> {
     let schema_path = match schema_path {
         Some(p) => p.to_path_buf(),
@@ -47,9 +66,10 @@ pub fn run(
     })?;
 
     let mut files = match language {
-        ClientLanguage::TypeScript => fraiseql_codegen::client::typescript::generate(&schema)
-            .map_err(|e| anyhow::anyhow!("client generation failed: {e}"))?,
-    };
+        ClientLanguage::TypeScript => fraiseql_codegen::client::typescript::generate(&schema),
+        ClientLanguage::Python => fraiseql_codegen::client::python::generate(&schema),
+    }
+    .map_err(|e| anyhow::anyhow!("client generation failed: {e}"))?;
 
     // `functions.d.ts` (phase 08): typed guest payloads + host-op declarations, when
     // the compiled schema declares functions. Parsed from the same raw JSON — the
@@ -63,15 +83,21 @@ pub fn run(
         }
     }
 
-    if out_dir.exists() && !force && contains_generated_client(out_dir) {
+    if out_dir.exists() && !force && !incremental && contains_generated_client(out_dir, language) {
         anyhow::bail!(
-            "Output directory {} already contains a generated client. Pass --force to overwrite.",
+            "Output directory {} already contains a generated client. Pass --force to overwrite \
+             or --incremental to regenerate in place.",
             out_dir.display()
         );
     }
 
+    let mut unchanged = 0_usize;
     for (rel_path, content) in &files {
         let full_path = out_dir.join(rel_path);
+        if incremental && std::fs::read_to_string(&full_path).is_ok_and(|old| old == *content) {
+            unchanged += 1;
+            continue;
+        }
         if let Some(parent) = full_path.parent() {
             std::fs::create_dir_all(parent)
                 .with_context(|| format!("Failed to create {}", parent.display()))?;
@@ -81,15 +107,70 @@ pub fn run(
         println!("  wrote {}", full_path.display());
     }
 
+    let removed = if incremental {
+        remove_stale_files(out_dir, language, &files)?
+    } else {
+        0
+    };
+
     println!(
-        "\nGenerated {} files into {}. Type-check with `tsc --strict --noEmit {}/index.ts`.",
+        "\nGenerated {} files into {} ({unchanged} unchanged, {removed} stale removed).",
         files.len(),
         out_dir.display(),
-        out_dir.display(),
     );
+    match language {
+        ClientLanguage::TypeScript => {
+            println!("Type-check with `tsc --strict --noEmit {}/index.ts`.", out_dir.display())
+        },
+        ClientLanguage::Python => {
+            println!("Type-check with `mypy --strict {}`.", out_dir.display());
+        },
+    }
     Ok(())
 }
 
+/// Delete generated files (carrying [`GENERATED_MARKER`]) that the current schema
+/// no longer produces, e.g. `mutations.ts` after the last mutation is removed.
+/// The output directory is walked recursively, so stale modules in generated
+/// subpackages are removed too. Hand-written files are never touched.
+fn remove_stale_files(
+    out_dir: &Path,
+    language: ClientLanguage,
+    files: &fraiseql_codegen::Generated,
+) -> Result<usize> {
+    let mut removed = 0;
+    let mut dirs = vec![out_dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // `file_type` does not follow symlinks: linked directories are not entered.
+            if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                dirs.push(path);
+                continue;
+            }
+            let is_source = path.extension().is_some_and(|ext| ext == language.extension());
+            let Ok(rel_path) = path.strip_prefix(out_dir) else {
+                continue;
+            };
+            if !is_source || files.contains_key(rel_path) {
+                continue;
+            }
+            if std::fs::read_to_string(&path)
+                .is_ok_and(|content| content.contains(GENERATED_MARKER))
+            {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+                println!("  removed {}", path.display());
+                removed += 1;
+            }
+        }
+    }
+    Ok(removed)
+}
+
 /// Resolve the `functions.d.ts` type specs from the compiled schema's `functions`
 /// section (parsed from the raw JSON — it is not part of [`CompiledSchema`]).
 ///
@@ -153,11 +234,10 @@ fn functions_type_specs(
 }
 
 /// Whether a directory already holds a generated client (detected via the
-/// auto-generated sentinel in `types.ts`).
-fn contains_generated_client(out_dir: &Path) -> bool {
-    let marker = out_dir.join("types.ts");
-    std::fs::read_to_string(marker)
-        .is_ok_and(|content| content.contains("AUTO-GENERATED by fraiseql-codegen"))
+/// auto-generated sentinel in `types.ts` / `types.py`).
+fn contains_generated_client(out_dir: &Path, language: ClientLanguage) -> bool {
+    let marker = out_dir.join(format!("types.{}", language.extension()));
+    std::fs::read_to_string(marker).is_ok_and(|content| content.contains(GENERATED_MARKER))
 }
 
 /// Search conventional locations for a `schema.compiled.json`.
@@ -175,6 +255,6 @@ fn auto_detect_compiled_schema() -> Result<PathBuf> {
     }
     anyhow::bail!(
         "No compiled schema found. Compile first (`fraiseql compile`) or pass an explicit path: \
-         fraiseql generate-client typescript --schema <path> --out <dir>"
+         fraiseql generate-client <typescript|python> --schema <path> --out <dir>"
     )
 }
@@ -1,6 +1,8 @@
 //! CLI commands module
 
 pub mod analyze;
+#[cfg(feature = "run-server")]
+pub mod backup;
 pub mod compile;
 pub mod cost;
 pub mod dependency_graph;
@@ -18,14 +20,21 @@ pub mod init;
 pub mod introspect_facts;
 pub mod lint;
 pub mod migrate;
+pub mod observers;
 pub mod perf;
 pub mod query;
+pub mod repl;
 #[cfg(feature = "run-server")]
 pub mod run;
 pub mod sbom;
 pub mod schema;
+pub mod search;
+pub mod seed;
+#[cfg(feature = "run-server")]
+pub mod serve;
 pub mod setup;
 pub mod sources;
+pub mod test_sql;
 pub mod validate;
 pub mod validate_documents;
 pub mod validate_facts;
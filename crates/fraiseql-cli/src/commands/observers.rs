@@ -0,0 +1,177 @@
+//! `fraiseql observers` — pause and resume observers on a running server.
+//!
+//! Calls the observer runtime admin API under `/api/observers/runtime`:
+//!
+//! ```text
+//! Observer       State     Backlog        Pinned at  Paused by
+//! -------------  --------  -------------  ---------  ---------------
+//! order_webhook  paused    1200 / 10000   48211      ops@example.com
+//! ```
+
+use anyhow::{Context, Result};
+use reqwest::{Client, Method, Url};
+use serde_json::{Value, json};
+
+/// Pause observer `name`.
+///
+/// # Errors
+///
+/// Returns an error if the server is unreachable or rejects the pause (unknown
+/// observer, already paused, non-PostgreSQL transport).
+pub async fn pause(
+    server: &str,
+    token: Option<&str>,
+    name: &str,
+    max_backlog: Option<u64>,
+    reason: Option<&str>,
+    json_output: bool,
+) -> Result<()> {
+    let url = runtime_url(server, &["observers", name, "pause"])?;
+    let body = json!({ "max_backlog": max_backlog, "reason": reason });
+    let status = send(Method::POST, url, token, Some(&body)).await?;
+    print_result(&status, json_output, &format!("Paused observer {name}"));
+    Ok(())
+}
+
+/// Resume observer `name`; its backlog is delivered by the server in the
+/// background.
+///
+/// # Errors
+///
+/// Returns an error if the server is unreachable or rejects the resume (not
+/// paused, already resuming).
+pub async fn resume(
+    server: &str,
+    token: Option<&str>,
+    name: &str,
+    json_output: bool,
+) -> Result<()> {
+    let url = runtime_url(server, &["observers", name, "resume"])?;
+    let status = send(Method::POST, url, token, None).await?;
+    print_result(
+        &status,
+        json_output,
+        &format!("Resuming observer {name}; its backlog is being delivered"),
+    );
+    Ok(())
+}
+
+/// List paused observers.
+///
+/// # Errors
+///
+/// Returns an error if the server is unreachable or the response is not a list.
+pub async fn paused(server: &str, token: Option<&str>, json_output: bool) -> Result<()> {
+    let url = runtime_url(server, &["paused"])?;
+    let body = send(Method::GET, url, token, None).await?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+    let entries = body.as_array().context("Unexpected response shape — expected a list")?;
+    print!("{}", format_table(entries));
+    Ok(())
+}
+
+/// Build `{server}/api/observers/runtime/{segments…}`, percent-encoding each
+/// segment so any observer name is addressed verbatim.
+pub(crate) fn runtime_url(server: &str, segments: &[&str]) -> Result<Url> {
+    let mut url = Url::parse(server).with_context(|| format!("Invalid server URL: {server}"))?;
+    url.path_segments_mut()
+        .map_err(|()| anyhow::anyhow!("Server URL cannot be a base: {server}"))?
+        .pop_if_empty()
+        .extend(["api", "observers", "runtime"])
+        .extend(segments);
+    Ok(url)
+}
+
+async fn send(
+    method: Method,
+    url: Url,
+    token: Option<&str>,
+    body: Option<&Value>,
+) -> Result<Value> {
+    let client = Client::builder().timeout(std::time::Duration::from_secs(30)).build()?;
+    let mut request = client.request(method, url.clone());
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to server at {url}: {e}"))?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+
+    if !status.is_success() {
+        let detail = body.get("error").and_then(Value::as_str).unwrap_or("no details");
+        return Err(anyhow::anyhow!("Server returned HTTP {status}: {detail}"));
+    }
+    Ok(body)
+}
+
+fn print_result(status: &Value, json_output: bool, headline: &str) {
+    if json_output {
+        println!("{status}");
+    } else {
+        println!("{headline}");
+        print!("{}", format_table(std::slice::from_ref(status)));
+    }
+}
+
+/// Render paused-observer statuses as an aligned plain-text table.
+pub(crate) fn format_table(entries: &[Value]) -> String {
+    if entries.is_empty() {
+        return "No paused observers.\n".to_string();
+    }
+
+    let text = |entry: &Value, key: &str| match entry.get(key) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => "-".to_string(),
+        Some(other) => other.to_string(),
+    };
+    let header = ["Observer", "State", "Backlog", "Pinned at", "Paused by"].map(str::to_string);
+    let rows: Vec<[String; 5]> = entries
+        .iter()
+        .map(|entry| {
+            let mut backlog =
+                format!("{} / {}", text(entry, "backlog"), text(entry, "max_backlog"));
+            if entry.get("backlog_exceeded").and_then(Value::as_bool) == Some(true) {
+                backlog.push_str(" !");
+            }
+            [
+                text(entry, "observer"),
+                text(entry, "state"),
+                backlog,
+                text(entry, "pinned_checkpoint"),
+                text(entry, "paused_by"),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.each_ref().map(String::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let render = |cells: &[String; 5]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        format!("{}\n", line.join("  ").trim_end())
+    };
+    let mut out = render(&header);
+    out.push_str(&render(&widths.map(|w| "-".repeat(w))));
+    for row in &rows {
+        out.push_str(&render(row));
+    }
+    out
+}
@@ -0,0 +1,248 @@
+//! `fraiseql repl` — an interactive GraphQL shell against a running server.
+//!
+//! Introspects the endpoint once at start (and on `:reload`) to build the
+//! completion vocabulary: root queries and mutations, fields, arguments, input
+//! fields, enum values and type names, plus `$variables` and `:commands`. Input is
+//! buffered until its braces balance, so documents can span lines; each operation
+//! is sent with the session variables it references, and the response is printed
+//! as a table or as JSON, optionally with its round-trip time.
+//!
+//! Meta-command parsing, buffering, completion and rendering live in [`session`]
+//! and are unit-tested; this module owns the terminal and the HTTP client.
+
+pub mod session;
+
+#[cfg(test)]
+mod tests;
+
+use std::{collections::BTreeMap, time::Instant};
+
+use anyhow::{Context as _, Result};
+use reqwest::Client;
+use rustyline::{
+    Context, Editor, Helper,
+    completion::{Completer, Pair},
+    error::ReadlineError,
+    highlight::Highlighter,
+    hint::Hinter,
+    history::DefaultHistory,
+    validate::Validator,
+};
+use serde_json::{Value, json};
+
+use self::session::{
+    INTROSPECTION_QUERY, OutputFormat, ReplCommand, Vocabulary, help_text, is_complete,
+    parse_command, referenced_variables, render_response,
+};
+
+/// Arguments for `fraiseql repl`.
+pub struct ReplArgs {
+    /// GraphQL endpoint URL (e.g. `http://localhost:8080/graphql`).
+    pub endpoint: String,
+    /// Bearer token sent with every request.
+    pub token:    Option<String>,
+    /// Initial output format: `table` or `json`.
+    pub format:   String,
+    /// Print the round-trip time after each operation.
+    pub timing:   bool,
+}
+
+/// Completion for the line editor, backed by the session vocabulary.
+struct ReplHelper {
+    vocabulary: Vocabulary,
+    variables:  BTreeMap<String, Value>,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let (start, candidates) = self.vocabulary.complete(line, pos, &self.variables);
+        Ok((
+            start,
+            candidates
+                .into_iter()
+                .map(|c| Pair {
+                    display:     c.clone(),
+                    replacement: c,
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// A GraphQL endpoint plus the credentials to call it.
+struct Endpoint {
+    client: Client,
+    url:    String,
+    token:  Option<String>,
+}
+
+impl Endpoint {
+    /// POST one operation and return the response body.
+    async fn execute(
+        &self,
+        query: &str,
+        variables: &serde_json::Map<String, Value>,
+    ) -> Result<Value> {
+        let mut body = json!({ "query": query });
+        if !variables.is_empty() {
+            body["variables"] = Value::Object(variables.clone());
+        }
+        let mut request = self.client.post(&self.url).json(&body);
+        if let Some(ref token) = self.token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to connect to server at {}: {e}", self.url))?;
+        let status = response.status();
+        let text = response.text().await.context("failed to read the response body")?;
+        // GraphQL errors arrive with 4xx statuses too; print them as responses.
+        serde_json::from_str(&text).map_err(|_| {
+            let snippet: String = text.chars().take(200).collect();
+            anyhow::anyhow!("Server returned HTTP {status} with a non-JSON body: {snippet}")
+        })
+    }
+
+    async fn vocabulary(&self) -> Result<Vocabulary> {
+        let response = self.execute(INTROSPECTION_QUERY, &serde_json::Map::new()).await?;
+        Ok(Vocabulary::from_introspection(&response))
+    }
+}
+
+/// Run the `fraiseql repl` command.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be initialised, `--format` is unknown
+/// or the server cannot be reached at start. Failures of individual operations
+/// are printed and the session continues.
+pub async fn run(args: ReplArgs) -> Result<()> {
+    let mut format: OutputFormat = args.format.parse().map_err(anyhow::Error::msg)?;
+    let mut timing = args.timing;
+    let endpoint = Endpoint {
+        client: Client::builder().timeout(std::time::Duration::from_secs(60)).build()?,
+        url:    args.endpoint,
+        token:  args.token,
+    };
+
+    let vocabulary = endpoint.vocabulary().await?;
+    if vocabulary.queries.is_empty() && vocabulary.mutations.is_empty() {
+        eprintln!("note: introspection returned no schema; completion is limited to keywords");
+    }
+    println!(
+        "Connected to {} ({} queries, {} mutations). :help for commands, Ctrl-D to quit.",
+        endpoint.url,
+        vocabulary.queries.len(),
+        vocabulary.mutations.len()
+    );
+
+    let mut editor: Editor<ReplHelper, DefaultHistory> =
+        Editor::new().context("failed to initialise the terminal")?;
+    editor.set_helper(Some(ReplHelper {
+        vocabulary,
+        variables: BTreeMap::new(),
+    }));
+
+    let mut buffer = String::new();
+    loop {
+        let prompt = if buffer.is_empty() {
+            "fraiseql> "
+        } else {
+            "     ...> "
+        };
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+            // Ctrl-C abandons the document being typed.
+            Err(ReadlineError::Interrupted) => {
+                buffer.clear();
+                continue;
+            },
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e).context("failed to read input"),
+        };
+
+        if buffer.is_empty() && line.trim_start().starts_with(':') {
+            let _ = editor.add_history_entry(line.as_str());
+            let Some(helper) = editor.helper_mut() else {
+                break;
+            };
+            match parse_command(&line) {
+                Ok(ReplCommand::Quit) => break,
+                Ok(ReplCommand::Help) => print!("{}", help_text()),
+                Ok(ReplCommand::Set { name, value }) => {
+                    helper.variables.insert(name, value);
+                },
+                Ok(ReplCommand::Unset(name)) => {
+                    if helper.variables.remove(&name).is_none() {
+                        eprintln!("no variable `{name}`");
+                    }
+                },
+                Ok(ReplCommand::Vars) => {
+                    for (name, value) in &helper.variables {
+                        println!("  ${name} = {value}");
+                    }
+                },
+                Ok(ReplCommand::Format(new_format)) => format = new_format,
+                Ok(ReplCommand::Timing(on)) => timing = on,
+                Ok(ReplCommand::Schema) => {
+                    println!("queries:   {}", helper.vocabulary.queries.join(", "));
+                    println!("mutations: {}", helper.vocabulary.mutations.join(", "));
+                },
+                Ok(ReplCommand::Reload) => match endpoint.vocabulary().await {
+                    Ok(vocabulary) => helper.vocabulary = vocabulary,
+                    Err(e) => eprintln!("error: {e:#}"),
+                },
+                Err(message) => eprintln!("{message}"),
+            }
+            continue;
+        }
+
+        if line.trim().is_empty() && buffer.is_empty() {
+            continue;
+        }
+        buffer.push_str(&line);
+        buffer.push('\n');
+        if !is_complete(&buffer) {
+            continue;
+        }
+
+        let document = std::mem::take(&mut buffer);
+        let _ = editor.add_history_entry(document.trim_end());
+        let variables = editor
+            .helper()
+            .map(|h| referenced_variables(&document, &h.variables))
+            .unwrap_or_default();
+        let started = Instant::now();
+        match endpoint.execute(&document, &variables).await {
+            Ok(response) => {
+                print!("{}", render_response(&response, format));
+                if format == OutputFormat::Json {
+                    println!();
+                }
+                if timing {
+                    println!("({:.1} ms)", started.elapsed().as_secs_f64() * 1000.0);
+                }
+            },
+            Err(e) => eprintln!("error: {e:#}"),
+        }
+    }
+    Ok(())
+}
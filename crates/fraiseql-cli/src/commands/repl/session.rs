@@ -0,0 +1,421 @@
+//! The pure half of `fraiseql repl`: meta-commands, session variables, input
+//! buffering, the completion vocabulary and output rendering.
+//!
+//! Nothing here touches the terminal or the network, so it is unit-tested
+//! directly; the parent module wires it to `rustyline` and the HTTP client.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    str::FromStr,
+};
+
+use serde_json::{Map, Value};
+
+/// The introspection query the completion vocabulary is built from.
+pub const INTROSPECTION_QUERY: &str = "query FraiseqlRepl { __schema { queryType { name } \
+    mutationType { name } types { name kind fields(includeDeprecated: true) { name args { name } \
+    } inputFields { name } enumValues(includeDeprecated: true) { name } } } }";
+
+/// GraphQL keywords offered by completion.
+const KEYWORDS: &[&str] = &[
+    "query", "mutation", "fragment", "on", "true", "false", "null",
+];
+
+/// Meta-commands, with their one-line help.
+pub const COMMANDS: &[(&str, &str)] = &[
+    (":help", "show this help"),
+    (":quit", "leave the REPL (also Ctrl-D)"),
+    (
+        ":set",
+        ":set <name> <json> — set a variable sent with every operation that uses it",
+    ),
+    (":unset", ":unset <name> — remove a variable"),
+    (":vars", "list variables"),
+    (":format", ":format table|json — how results are printed"),
+    (":timing", ":timing on|off — show round-trip time"),
+    (":schema", "list root queries and mutations"),
+    (":reload", "re-run introspection (after a schema reload)"),
+];
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Lists of objects as aligned tables, everything else as JSON.
+    Table,
+    /// The raw response as pretty JSON.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown format `{other}` (expected table or json)")),
+        }
+    }
+}
+
+/// A parsed meta-command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplCommand {
+    /// `:help`
+    Help,
+    /// `:quit` / `:exit`
+    Quit,
+    /// `:set <name> <json>`
+    Set {
+        /// Variable name (without `$`).
+        name:  String,
+        /// Variable value.
+        value: Value,
+    },
+    /// `:unset <name>`
+    Unset(String),
+    /// `:vars`
+    Vars,
+    /// `:format table|json`
+    Format(OutputFormat),
+    /// `:timing on|off`
+    Timing(bool),
+    /// `:schema`
+    Schema,
+    /// `:reload`
+    Reload,
+}
+
+/// Parse a `:`-prefixed meta-command line.
+///
+/// A `:set` value that is not valid JSON is taken as a string, so
+/// `:set status active` works without quoting.
+///
+/// # Errors
+///
+/// Returns a message for an unknown command or malformed arguments.
+pub fn parse_command(line: &str) -> Result<ReplCommand, String> {
+    let line = line.trim();
+    let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    match command {
+        ":help" | ":h" | ":?" => Ok(ReplCommand::Help),
+        ":quit" | ":q" | ":exit" => Ok(ReplCommand::Quit),
+        ":set" => {
+            let (name, value) =
+                rest.split_once(char::is_whitespace).ok_or("usage: :set <name> <json>")?;
+            let name = name.trim_start_matches('$');
+            if name.is_empty() {
+                return Err("usage: :set <name> <json>".to_string());
+            }
+            let value = value.trim();
+            let value =
+                serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+            Ok(ReplCommand::Set {
+                name: name.to_string(),
+                value,
+            })
+        },
+        ":unset" if !rest.is_empty() => {
+            Ok(ReplCommand::Unset(rest.trim_start_matches('$').to_string()))
+        },
+        ":unset" => Err("usage: :unset <name>".to_string()),
+        ":vars" => Ok(ReplCommand::Vars),
+        ":format" => rest.parse().map(ReplCommand::Format),
+        ":timing" => match rest {
+            "on" => Ok(ReplCommand::Timing(true)),
+            "off" => Ok(ReplCommand::Timing(false)),
+            _ => Err("usage: :timing on|off".to_string()),
+        },
+        ":schema" => Ok(ReplCommand::Schema),
+        ":reload" => Ok(ReplCommand::Reload),
+        other => Err(format!("unknown command `{other}` — try :help")),
+    }
+}
+
+/// Whether `buffer` is a complete document: every `{`/`(` closed outside
+/// strings and comments. Lines are buffered until it is.
+#[must_use]
+pub fn is_complete(buffer: &str) -> bool {
+    let mut depth: i64 = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for line in buffer.lines() {
+        for c in line.chars() {
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {},
+                }
+                continue;
+            }
+            match c {
+                '#' => break,
+                '"' => in_string = true,
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth -= 1,
+                _ => {},
+            }
+        }
+    }
+    depth <= 0 && !in_string
+}
+
+/// The session variables a document uses (`$name`), as a GraphQL variables object.
+#[must_use]
+pub fn referenced_variables(
+    document: &str,
+    variables: &BTreeMap<String, Value>,
+) -> Map<String, Value> {
+    variables
+        .iter()
+        .filter(|(name, _)| {
+            let needle = format!("${name}");
+            document.match_indices(&needle).any(|(i, _)| {
+                !document[i + needle.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric() || c == '_')
+            })
+        })
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect()
+}
+
+/// Schema names offered by completion, built from an introspection result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    /// Root query fields.
+    pub queries:   Vec<String>,
+    /// Root mutation fields.
+    pub mutations: Vec<String>,
+    /// Every field, argument, input field, enum value and type name.
+    words:         BTreeSet<String>,
+}
+
+impl Vocabulary {
+    /// Build the vocabulary from an introspection response (`{"data": {"__schema": …}}`).
+    /// Missing parts are skipped: a server with introspection disabled yields an
+    /// empty vocabulary, and completion falls back to keywords and commands.
+    #[must_use]
+    pub fn from_introspection(response: &Value) -> Self {
+        let Some(schema) = response.pointer("/data/__schema") else {
+            return Self::default();
+        };
+        let root = |key: &str| schema.pointer(&format!("/{key}/name")).and_then(Value::as_str);
+        let names = |value: Option<&Value>| -> Vec<String> {
+            value
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.get("name").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect()
+        };
+
+        let mut vocabulary = Self::default();
+        let types = schema.get("types").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+        for ty in types {
+            let Some(type_name) = ty.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+            if type_name.starts_with("__") {
+                continue;
+            }
+            vocabulary.words.insert(type_name.to_string());
+            let fields = names(ty.get("fields"));
+            if Some(type_name) == root("queryType") {
+                vocabulary.queries.clone_from(&fields);
+            } else if Some(type_name) == root("mutationType") {
+                vocabulary.mutations.clone_from(&fields);
+            }
+            vocabulary.words.extend(fields);
+            for field in ty.get("fields").and_then(Value::as_array).into_iter().flatten() {
+                vocabulary.words.extend(names(field.get("args")));
+            }
+            vocabulary.words.extend(names(ty.get("inputFields")));
+            vocabulary.words.extend(names(ty.get("enumValues")));
+        }
+        vocabulary
+    }
+
+    /// Completions for the word ending at `pos` in `line`: the byte offset the
+    /// word starts at and the candidates, sorted.
+    ///
+    /// `:` at the start of the line completes meta-commands, `$` completes
+    /// variables, anything else completes keywords and schema names.
+    #[must_use]
+    pub fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        variables: &BTreeMap<String, Value>,
+    ) -> (usize, Vec<String>) {
+        let before = line.get(..pos).unwrap_or(line);
+        let mut start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !(c.is_alphanumeric() || *c == '_' || *c == '$'))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        if &before[..start] == ":" {
+            start = 0;
+        }
+        let word = &before[start..];
+        if word.is_empty() {
+            return (start, Vec::new());
+        }
+
+        let candidates: Vec<String> = if word.starts_with(':') {
+            COMMANDS
+                .iter()
+                .map(|(name, _)| (*name).to_string())
+                .filter(|c| c.starts_with(word))
+                .collect()
+        } else if let Some(prefix) = word.strip_prefix('$') {
+            variables
+                .keys()
+                .filter(|name| name.starts_with(prefix))
+                .map(|name| format!("${name}"))
+                .collect()
+        } else {
+            let candidates: BTreeSet<&str> = KEYWORDS
+                .iter()
+                .copied()
+                .chain(self.words.iter().map(String::as_str))
+                .filter(|c| c.starts_with(word))
+                .collect();
+            candidates.into_iter().map(str::to_string).collect()
+        };
+        (start, candidates)
+    }
+}
+
+/// Help text for the meta-commands.
+#[must_use]
+pub fn help_text() -> String {
+    let mut out = String::from(
+        "Type a GraphQL query or mutation; it runs once its braces balance. Tab completes \
+         schema names.\n\n",
+    );
+    for (name, help) in COMMANDS {
+        let _ = writeln!(out, "  {name:<9} {help}");
+    }
+    out
+}
+
+/// Render a GraphQL response for the terminal.
+///
+/// In table format each root field of `data` that holds a list of objects is
+/// printed as an aligned table and a single object as `key  value` rows; other
+/// values, and `errors`, are printed as JSON.
+#[must_use]
+pub fn render_response(response: &Value, format: OutputFormat) -> String {
+    if format == OutputFormat::Json {
+        return serde_json::to_string_pretty(response).unwrap_or_else(|_| response.to_string());
+    }
+    let mut out = String::new();
+    if let Some(data) = response.get("data").and_then(Value::as_object) {
+        for (field, value) in data {
+            let _ = writeln!(out, "{field}:");
+            out.push_str(&render_value(value));
+        }
+    }
+    if let Some(errors) = response.get("errors").and_then(Value::as_array) {
+        for error in errors {
+            let message = error.get("message").and_then(Value::as_str).unwrap_or("(no message)");
+            let path = error
+                .get("path")
+                .and_then(Value::as_array)
+                .map(|p| p.iter().map(cell).collect::<Vec<_>>().join("."));
+            match path {
+                Some(path) => {
+                    let _ = writeln!(out, "error at {path}: {message}");
+                },
+                None => {
+                    let _ = writeln!(out, "error: {message}");
+                },
+            }
+        }
+    }
+    if out.is_empty() {
+        out = serde_json::to_string_pretty(response).unwrap_or_else(|_| response.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_object) => {
+            let mut columns: Vec<String> = Vec::new();
+            for item in items.iter().filter_map(Value::as_object) {
+                for key in item.keys() {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+            }
+            let rows: Vec<Vec<String>> = items
+                .iter()
+                .map(|item| {
+                    columns.iter().map(|c| item.get(c).map_or_else(String::new, cell)).collect()
+                })
+                .collect();
+            let mut out = format_table(&columns, &rows);
+            let _ = writeln!(out, "({} row{})", rows.len(), if rows.len() == 1 { "" } else { "s" });
+            out
+        },
+        Value::Object(object) if !object.is_empty() => {
+            let width = object.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+            let mut out = String::new();
+            for (key, value) in object {
+                let _ = writeln!(out, "  {key:<width$}  {}", cell(value));
+            }
+            out
+        },
+        other => format!(
+            "  {}\n",
+            serde_json::to_string_pretty(other).unwrap_or_else(|_| other.to_string())
+        ),
+    }
+}
+
+/// One table cell: strings unquoted, `null` blank, anything else compact JSON.
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn format_table(columns: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            rows.iter()
+                .map(|r| r[i].chars().count())
+                .chain([c.chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |cells: &[String]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        format!("  {}\n", padded.join("  ").trim_end())
+    };
+    let mut out = line(columns);
+    out.push_str(&line(&widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>()));
+    for row in rows {
+        out.push_str(&line(row));
+    }
+    out
+}
@@ -0,0 +1,168 @@
+//! Tests for `fraiseql repl`.
+//!
+//! The [`session`](super::session) half is pure, so meta-commands, buffering,
+//! completion and rendering are covered here without a terminal or a server.
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
+
+use super::session::{
+    OutputFormat, ReplCommand, Vocabulary, is_complete, parse_command, referenced_variables,
+    render_response,
+};
+
+fn introspection() -> Value {
+    json!({ "data": { "__schema": {
+        "queryType": { "name": "Query" },
+        "mutationType": { "name": "Mutation" },
+        "types": [
+            { "name": "Query", "kind": "OBJECT", "fields": [
+                { "name": "users", "args": [{ "name": "limit" }, { "name": "where" }] },
+                { "name": "user", "args": [{ "name": "id" }] },
+            ] },
+            { "name": "Mutation", "kind": "OBJECT", "fields": [
+                { "name": "createUser", "args": [{ "name": "input" }] },
+            ] },
+            { "name": "User", "kind": "OBJECT", "fields": [
+                { "name": "id", "args": [] },
+                { "name": "username", "args": [] },
+                { "name": "userRole", "args": [] },
+            ] },
+            { "name": "Role", "kind": "ENUM", "enumValues": [{ "name": "ADMIN" }] },
+            { "name": "__Type", "kind": "OBJECT", "fields": [{ "name": "ofType", "args": [] }] },
+        ],
+    } } })
+}
+
+#[test]
+fn meta_commands_parse() {
+    assert_eq!(parse_command(":q").unwrap(), ReplCommand::Quit);
+    assert_eq!(
+        parse_command(":set limit 10").unwrap(),
+        ReplCommand::Set {
+            name:  "limit".to_string(),
+            value: json!(10),
+        }
+    );
+    assert_eq!(
+        parse_command(":set $where {\"name\": {\"_eq\": \"x\"}}").unwrap(),
+        ReplCommand::Set {
+            name:  "where".to_string(),
+            value: json!({ "name": { "_eq": "x" } }),
+        }
+    );
+    // A bare word is taken as a string.
+    assert_eq!(
+        parse_command(":set status active").unwrap(),
+        ReplCommand::Set {
+            name:  "status".to_string(),
+            value: json!("active"),
+        }
+    );
+    assert_eq!(parse_command(":format json").unwrap(), ReplCommand::Format(OutputFormat::Json));
+    assert_eq!(parse_command(":timing off").unwrap(), ReplCommand::Timing(false));
+    assert!(parse_command(":format xml").is_err());
+    assert!(parse_command(":set limit").is_err());
+    assert!(parse_command(":frobnicate").unwrap_err().contains(":help"));
+}
+
+#[test]
+fn documents_are_buffered_until_balanced() {
+    assert!(!is_complete("{ users {\n"));
+    assert!(!is_complete("query($id: ID!) { user(id: $id) { id\n"));
+    assert!(is_complete("{ users { id } }\n"));
+    // Braces inside strings and comments do not count.
+    assert!(is_complete("{ users(where: { name: { _eq: \"{\" } }) { id } }\n"));
+    assert!(is_complete("{ users { id } } # trailing {\n"));
+    assert!(!is_complete("{ users(where: \"unterminated) { id } }\n"));
+}
+
+#[test]
+fn only_referenced_variables_are_sent() {
+    let variables = BTreeMap::from([
+        ("id".to_string(), json!(1)),
+        ("idList".to_string(), json!([1, 2])),
+        ("limit".to_string(), json!(5)),
+    ]);
+    let sent = referenced_variables("query($id: ID!) { user(id: $id) { id } }", &variables);
+    assert_eq!(Value::Object(sent), json!({ "id": 1 }));
+    assert!(referenced_variables("{ users { id } }", &variables).is_empty());
+}
+
+#[test]
+fn vocabulary_is_built_from_introspection() {
+    let vocabulary = Vocabulary::from_introspection(&introspection());
+    assert_eq!(vocabulary.queries, vec!["users", "user"]);
+    assert_eq!(vocabulary.mutations, vec!["createUser"]);
+    assert_eq!(Vocabulary::from_introspection(&json!({ "errors": [] })), Vocabulary::default());
+}
+
+#[test]
+fn completion_covers_schema_names_variables_and_commands() {
+    let vocabulary = Vocabulary::from_introspection(&introspection());
+    let variables = BTreeMap::from([("limit".to_string(), json!(5))]);
+
+    let line = "{ use";
+    assert_eq!(
+        vocabulary.complete(line, line.len(), &variables),
+        (
+            2,
+            vec![
+                "user".to_string(),
+                "userRole".to_string(),
+                "username".to_string(),
+                "users".to_string()
+            ]
+        )
+    );
+    let line = "{ users(li";
+    assert_eq!(
+        vocabulary.complete(line, line.len(), &variables),
+        (8, vec!["limit".to_string()])
+    );
+    let line = "{ users(limit: $l";
+    assert_eq!(
+        vocabulary.complete(line, line.len(), &variables),
+        (15, vec!["$limit".to_string()])
+    );
+    assert_eq!(vocabulary.complete(":ti", 3, &variables), (0, vec![":timing".to_string()]));
+    let line = "mut";
+    assert_eq!(
+        vocabulary.complete(line, line.len(), &variables),
+        (0, vec!["mutation".to_string()])
+    );
+    // Introspection's own types are not offered.
+    assert!(vocabulary.complete("ofT", 3, &variables).1.is_empty());
+}
+
+#[test]
+fn lists_of_objects_render_as_tables() {
+    let response = json!({ "data": { "users": [
+        { "id": 1, "username": "alice", "tags": ["a"] },
+        { "id": 2, "username": "bob", "tags": null },
+    ] } });
+    let out = render_response(&response, OutputFormat::Table);
+    assert_eq!(
+        out,
+        "users:\n  id  username  tags\n  --  --------  -----\n  1   alice     [\"a\"]\n  2   bob\n(2 rows)\n"
+    );
+}
+
+#[test]
+fn objects_and_errors_render_in_table_format() {
+    let response = json!({
+        "data": { "user": { "id": 1, "username": "alice" } },
+        "errors": [{ "message": "denied", "path": ["user", "email"] }],
+    });
+    let out = render_response(&response, OutputFormat::Table);
+    assert_eq!(out, "user:\n  id        1\n  username  alice\nerror at user.email: denied\n");
+}
+
+#[test]
+fn json_format_prints_the_raw_response() {
+    let response = json!({ "data": { "users": [] } });
+    let out = render_response(&response, OutputFormat::Json);
+    assert_eq!(serde_json::from_str::<Value>(&out).unwrap(), response);
+}
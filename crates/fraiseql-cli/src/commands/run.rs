@@ -186,19 +186,19 @@ async fn run_watch_loop(
 /// Wait for the OS-level shutdown signal (SIGINT / Ctrl-C), wrapped so that
 /// each [`dispatch_serve`] call can wire it into a `serve_with_shutdown`
 /// future without taking a turbofish on the concrete `Server<…>` type.
-async fn sigint_signal() {
+pub(crate) async fn sigint_signal() {
     let _ = tokio::signal::ctrl_c().await;
 }
 
 /// Type alias for the per-loop shutdown future passed to
 /// [`dispatch_serve`]. `None` means "use the default `serve()` shutdown
 /// handling"; `Some(fut)` is forwarded to `serve_with_shutdown(fut)`.
-type ShutdownFuture =
+pub(crate) type ShutdownFuture =
     Option<std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'static>>>;
 
 /// Dispatch the configured database URL scheme to the matching adapter and
 /// hand the resulting `Server` to either `serve()` or `serve_with_shutdown()`.
-async fn dispatch_serve(
+pub(crate) async fn dispatch_serve(
     scheme: DatabaseScheme,
     config: ServerConfig,
     schema: CompiledSchema,
@@ -461,6 +461,7 @@ const RUN_IGNORED_CONFIG_SECTIONS: &[&str] = &[
     "tenancy",
     "storage",
     "security",
+    "backup",
 ];
 
 /// Return the ignored platform sections that are present in a raw TOML config, in
@@ -563,7 +564,7 @@ fn build_tls_config(tls: &TlsRuntimeConfig) -> TlsServerConfig {
 }
 
 /// Compile the schema at `path`, printing progress to stdout.
-async fn compile_schema(path: &Path) -> Result<fraiseql_core::schema::CompiledSchema> {
+pub(crate) async fn compile_schema(path: &Path) -> Result<fraiseql_core::schema::CompiledSchema> {
     let input = path.to_str().ok_or_else(|| anyhow::anyhow!("Input path is not valid UTF-8"))?;
 
     println!("Compiling schema...");
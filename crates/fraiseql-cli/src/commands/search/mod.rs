@@ -0,0 +1,353 @@
+//! `fraiseql search reindex` — bootstrap a search index from an entity's view.
+//!
+//! Live indexing only covers entities that change after a search backend is
+//! configured. This command fills the gap: it pages the entity's backing view
+//! (`tv_*`, resolved from the compiled schema unless `--view` is given) in id
+//! order, turns every row into a snapshot [`IndexedEvent`] and bulk-indexes the
+//! batches with the chosen backend via the observers crate's [`Reindexer`].
+//!
+//! After every accepted batch the cursor is written to a state file
+//! (`.fraiseql/reindex-<entity>.json` by default). An interrupted run is
+//! continued with `--resume`; the file is removed once the reindex completes.
+//!
+//! Architecture mirrors `sources`: a thin PostgreSQL [`reader`] plus pure,
+//! unit-tested resolve/format functions.
+//!
+//! [`IndexedEvent`]: fraiseql_observers::IndexedEvent
+
+pub mod reader;
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{Context, Result};
+use fraiseql_core::schema::CompiledSchema;
+use fraiseql_observers::{
+    HttpSearchBackend, MeilisearchBackend, ObserverError, ReindexCursor, ReindexRequest, Reindexer,
+    SearchBackend, TypesenseBackend,
+    search::{meilisearch::DEFAULT_MEILISEARCH_INDEX, typesense::DEFAULT_TYPESENSE_COLLECTION},
+};
+
+use self::reader::ViewReader;
+use crate::commands::migrate::resolve_database_url;
+
+/// Environment variable supplying the search engine URL when `--url` is omitted.
+pub const SEARCH_URL_ENV: &str = "FRAISEQL_SEARCH_URL";
+
+/// Environment variable supplying the search engine API key when `--api-key` is
+/// omitted.
+pub const SEARCH_API_KEY_ENV: &str = "FRAISEQL_SEARCH_API_KEY";
+
+/// Arguments for `fraiseql search reindex`.
+pub struct ReindexArgs {
+    /// Entity (GraphQL type) to reindex, e.g. `Order`.
+    pub entity:     String,
+    /// Path to the compiled schema, used to resolve the entity's view.
+    pub schema:     PathBuf,
+    /// Explicit view to scan; overrides the compiled schema.
+    pub view:       Option<String>,
+    /// Explicit PostgreSQL URL; falls back to `fraiseql.toml` / `DATABASE_URL`.
+    pub database:   Option<String>,
+    /// Search backend name: `elasticsearch`, `meilisearch`, or `typesense`.
+    pub backend:    String,
+    /// Search engine base URL; falls back to [`SEARCH_URL_ENV`].
+    pub url:        Option<String>,
+    /// Index (Meilisearch) or collection (Typesense) name.
+    pub index:      Option<String>,
+    /// Search engine API key; falls back to [`SEARCH_API_KEY_ENV`].
+    pub api_key:    Option<String>,
+    /// Tenant recorded on rows of views without a tenant column.
+    pub tenant:     String,
+    /// Rows read and indexed per batch.
+    pub batch_size: usize,
+    /// Maximum documents indexed per second.
+    pub rate_limit: Option<u32>,
+    /// Where the resume cursor is kept; defaults to `.fraiseql/reindex-<entity>.json`.
+    pub state_file: Option<PathBuf>,
+    /// Continue from the cursor in the state file.
+    pub resume:     bool,
+    /// Emit a machine-readable JSON summary instead of the human one.
+    pub json:       bool,
+}
+
+/// The search engines `search reindex` can write to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BackendKind {
+    Elasticsearch,
+    Meilisearch,
+    Typesense,
+}
+
+impl std::str::FromStr for BackendKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "elasticsearch" => Ok(Self::Elasticsearch),
+            "meilisearch" => Ok(Self::Meilisearch),
+            "typesense" => Ok(Self::Typesense),
+            other => anyhow::bail!(
+                "unknown search backend '{other}' (expected elasticsearch, meilisearch, or \
+                 typesense)"
+            ),
+        }
+    }
+}
+
+/// The view a reindex scans and the columns it reads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexTarget {
+    /// View (or table), optionally schema-qualified.
+    pub view:          String,
+    /// JSONB column holding the entity's representation.
+    pub data_column:   String,
+    /// Column holding the row's tenant, if the entity is tenant-scoped.
+    pub tenant_column: Option<String>,
+}
+
+/// Resolve the view to scan for `entity`.
+///
+/// `--view` wins; otherwise the entity's `sql_source`, JSONB column and tenant
+/// column come from the compiled schema. An explicit view without a schema reads
+/// the conventional `data` column and no tenant column.
+///
+/// # Errors
+///
+/// Returns an error if neither a view nor a schema entry is available, or if a
+/// resolved identifier is not a plain (optionally schema-qualified) SQL name.
+pub(crate) fn resolve_target(
+    schema: Option<&CompiledSchema>,
+    entity: &str,
+    view: Option<&str>,
+) -> Result<ReindexTarget> {
+    let type_def = schema.and_then(|s| s.find_type(entity));
+    let target = match (view, type_def) {
+        (Some(view), type_def) => ReindexTarget {
+            view:          view.to_string(),
+            data_column:   type_def.map_or_else(|| "data".to_string(), |t| t.jsonb_column.clone()),
+            tenant_column: type_def.and_then(|t| t.tenant_column.clone()),
+        },
+        (None, Some(type_def)) => ReindexTarget {
+            view:          type_def.sql_source.as_str().to_string(),
+            data_column:   type_def.jsonb_column.clone(),
+            tenant_column: type_def.tenant_column.clone(),
+        },
+        (None, None) if schema.is_some() => {
+            anyhow::bail!("type '{entity}' is not in the compiled schema; pass --view explicitly")
+        },
+        (None, None) => {
+            anyhow::bail!("no compiled schema to resolve '{entity}' from; pass --view explicitly")
+        },
+    };
+
+    if !is_relation_name(&target.view) {
+        anyhow::bail!("'{}' is not a valid view name", target.view);
+    }
+    for column in std::iter::once(&target.data_column).chain(&target.tenant_column) {
+        if !is_identifier(column) {
+            anyhow::bail!("'{column}' is not a valid column name");
+        }
+    }
+    Ok(target)
+}
+
+/// A plain SQL identifier: `[A-Za-z_][A-Za-z0-9_]*`.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// An identifier, optionally qualified by one schema (`benchmark.tv_order`).
+pub(crate) fn is_relation_name(name: &str) -> bool {
+    match name.split_once('.') {
+        Some((schema, relation)) => is_identifier(schema) && is_identifier(relation),
+        None => is_identifier(name),
+    }
+}
+
+/// Default state file for `entity`: `.fraiseql/reindex-<entity>.json`.
+pub(crate) fn default_state_file(entity: &str) -> PathBuf {
+    PathBuf::from(".fraiseql").join(format!("reindex-{}.json", entity.to_ascii_lowercase()))
+}
+
+/// Read a saved cursor.
+pub(crate) fn load_state(path: &Path) -> Result<ReindexCursor> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("cannot read reindex state {}", path.display()))?;
+    serde_json::from_str(&text)
+        .with_context(|| format!("cannot parse reindex state {}", path.display()))
+}
+
+/// Write `cursor` to `path` atomically (temp file + rename), creating the parent
+/// directory if needed.
+pub(crate) fn save_state(path: &Path, cursor: &ReindexCursor) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(cursor)?)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// One progress line: rows indexed, share of the total, throughput, last id.
+#[allow(clippy::cast_precision_loss)] // Reason: progress percentages and rates are approximate
+pub(crate) fn render_progress(
+    cursor: &ReindexCursor,
+    total: Option<u64>,
+    indexed_this_run: usize,
+    elapsed_secs: f64,
+) -> String {
+    let share = match total {
+        Some(total) if total > 0 => {
+            format!("/{total} ({:.1}%)", cursor.indexed as f64 * 100.0 / total as f64)
+        },
+        _ => String::new(),
+    };
+    let rate = if elapsed_secs > 0.0 {
+        indexed_this_run as f64 / elapsed_secs
+    } else {
+        0.0
+    };
+    format!(
+        "{}: {}{share} rows indexed, {rate:.0} rows/s, last id {}",
+        cursor.entity_type,
+        cursor.indexed,
+        cursor.last_id.as_deref().unwrap_or("-")
+    )
+}
+
+/// Run `fraiseql search reindex`.
+///
+/// # Errors
+///
+/// Returns an error if the target or backend cannot be resolved, the state file
+/// cannot be read on `--resume`, or the reindex fails. On failure the state file
+/// holds the last indexed cursor.
+pub async fn reindex(args: ReindexArgs) -> Result<()> {
+    let backend: BackendKind = args.backend.parse()?;
+    let url = args
+        .url
+        .clone()
+        .or_else(|| std::env::var(SEARCH_URL_ENV).ok())
+        .with_context(|| format!("no search engine URL: pass --url or set {SEARCH_URL_ENV}"))?;
+    let api_key = args.api_key.clone().or_else(|| std::env::var(SEARCH_API_KEY_ENV).ok());
+
+    let schema = if args.schema.exists() {
+        let text = std::fs::read_to_string(&args.schema)
+            .with_context(|| format!("cannot read compiled schema {}", args.schema.display()))?;
+        Some(CompiledSchema::from_json(&text, false).map_err(|e| {
+            anyhow::anyhow!("cannot parse compiled schema {}: {e}", args.schema.display())
+        })?)
+    } else {
+        None
+    };
+    let target = resolve_target(schema.as_ref(), &args.entity, args.view.as_deref())?;
+
+    let db_url = resolve_database_url(args.database.as_deref())?;
+    let reader = ViewReader::connect(&db_url, target.clone())?;
+    let total = reader.count().await.ok();
+
+    let state_file = args.state_file.clone().unwrap_or_else(|| default_state_file(&args.entity));
+    let mut request =
+        ReindexRequest::new(&args.entity, &args.tenant).with_batch_size(args.batch_size);
+    if let Some(rate) = args.rate_limit {
+        request = request.with_rate_limit(rate);
+    }
+    if args.resume {
+        request = request.with_resume(load_state(&state_file)?);
+    }
+
+    let cursor = match backend {
+        BackendKind::Elasticsearch => {
+            if args.index.is_some() {
+                eprintln!(
+                    "warning: --index is ignored for Elasticsearch (daily `events-*` indices)"
+                );
+            }
+            let backend = HttpSearchBackend::new(url)?;
+            run_reindex(reader, backend, &request, &state_file, total).await?
+        },
+        BackendKind::Meilisearch => {
+            let index = args.index.as_deref().unwrap_or(DEFAULT_MEILISEARCH_INDEX);
+            let mut backend = MeilisearchBackend::new(url, index)?;
+            if let Some(key) = api_key {
+                backend = backend.with_api_key(key);
+            }
+            backend.ensure_index().await.context("cannot prepare the Meilisearch index")?;
+            run_reindex(reader, backend, &request, &state_file, total).await?
+        },
+        BackendKind::Typesense => {
+            let key = api_key.with_context(|| {
+                format!("Typesense requires an API key: pass --api-key or set {SEARCH_API_KEY_ENV}")
+            })?;
+            let collection = args.index.as_deref().unwrap_or(DEFAULT_TYPESENSE_COLLECTION);
+            let backend = TypesenseBackend::new(url, collection, key)?;
+            backend
+                .ensure_collection()
+                .await
+                .context("cannot prepare the Typesense collection")?;
+            run_reindex(reader, backend, &request, &state_file, total).await?
+        },
+    };
+
+    if state_file.exists() {
+        std::fs::remove_file(&state_file)
+            .with_context(|| format!("cannot remove reindex state {}", state_file.display()))?;
+    }
+
+    if args.json {
+        let payload = serde_json::json!({
+            "entity": cursor.entity_type,
+            "view": target.view,
+            "indexed": cursor.indexed,
+            "snapshot_at": cursor.snapshot_at,
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else {
+        println!(
+            "Reindexed {} {} row(s) from {} into {}",
+            cursor.indexed, cursor.entity_type, target.view, args.backend
+        );
+    }
+    Ok(())
+}
+
+async fn run_reindex<B: SearchBackend>(
+    reader: ViewReader,
+    backend: B,
+    request: &ReindexRequest,
+    state_file: &Path,
+    total: Option<u64>,
+) -> Result<ReindexCursor> {
+    let started = Instant::now();
+    let already_indexed = request.resume.as_ref().map_or(0, |c| c.indexed);
+    Reindexer::new(reader, backend)
+        .run(request, |cursor| {
+            save_state(state_file, cursor).map_err(|e| ObserverError::StorageError {
+                reason: format!("cannot write reindex state {}: {e}", state_file.display()),
+            })?;
+            eprintln!(
+                "{}",
+                render_progress(
+                    cursor,
+                    total,
+                    cursor.indexed.saturating_sub(already_indexed),
+                    started.elapsed().as_secs_f64()
+                )
+            );
+            Ok(())
+        })
+        .await
+        .with_context(|| {
+            format!(
+                "reindex failed; progress is saved in {} — rerun with --resume to continue",
+                state_file.display()
+            )
+        })
+}
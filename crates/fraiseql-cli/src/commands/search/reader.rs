@@ -0,0 +1,158 @@
+//! The PostgreSQL view reader behind `fraiseql search reindex`.
+//!
+//! Pages an entity's backing view by its `id` column with a keyset query, so a
+//! reindex of a large view never holds more than one batch and can resume from
+//! the last id it indexed. The id's SQL type is read from the catalog once, so
+//! the cursor (carried as text) is compared in the column's own type and the
+//! ordering matches the index on `id`.
+
+use anyhow::{Context, Result};
+use deadpool_postgres::{Config, ManagerConfig, Pool, RecyclingMethod, Runtime};
+use fraiseql_observers::{ObserverError, ReindexRow, ReindexSource};
+use tokio::sync::OnceCell;
+use tokio_postgres::NoTls;
+
+use super::ReindexTarget;
+
+/// A live PostgreSQL connection pool reading one entity view.
+pub struct ViewReader {
+    pool:    Pool,
+    target:  ReindexTarget,
+    id_type: OnceCell<String>,
+}
+
+impl ViewReader {
+    /// Connect to `db_url` (PostgreSQL only) for reads of `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `db_url` is not a `postgres://` URL or the pool cannot be
+    /// created. (Connection failures surface lazily on the first query.)
+    pub fn connect(db_url: &str, target: ReindexTarget) -> Result<Self> {
+        if !db_url.starts_with("postgres") {
+            anyhow::bail!(
+                "search reindex requires a PostgreSQL connection URL (postgres://…); got: {db_url}"
+            );
+        }
+        let mut cfg = Config::new();
+        cfg.url = Some(db_url.to_string());
+        cfg.manager = Some(ManagerConfig {
+            recycling_method: RecyclingMethod::Fast,
+        });
+        cfg.pool = Some(deadpool_postgres::PoolConfig::new(2));
+        let pool = cfg
+            .create_pool(Some(Runtime::Tokio1), NoTls)
+            .context("failed to create PostgreSQL connection pool for search reindex")?;
+        Ok(Self {
+            pool,
+            target,
+            id_type: OnceCell::new(),
+        })
+    }
+
+    /// Count the rows of the view, for progress reporting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the query fails.
+    pub async fn count(&self) -> Result<u64> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let row = client
+            .query_one(&format!("SELECT count(*) FROM {}", self.target.view), &[])
+            .await
+            .with_context(|| format!("failed to count rows of {}", self.target.view))?;
+        let count: i64 = row.get(0);
+        Ok(u64::try_from(count).unwrap_or(0))
+    }
+
+    /// The SQL type of the view's `id` column (e.g. `uuid`, `bigint`).
+    async fn id_type(&self) -> Result<&str, ObserverError> {
+        let id_type = self
+            .id_type
+            .get_or_try_init(|| async {
+                let client = self.pool.get().await.map_err(db_error)?;
+                let row = client
+                    .query_opt(
+                        "SELECT format_type(atttypid, atttypmod) FROM pg_attribute \
+                         WHERE attrelid = $1::text::regclass AND attname = 'id' \
+                         AND NOT attisdropped",
+                        &[&self.target.view],
+                    )
+                    .await
+                    .map_err(db_error)?
+                    .ok_or_else(|| ObserverError::DatabaseError {
+                        reason: format!("{} has no `id` column", self.target.view),
+                    })?;
+                let id_type: String = row.get(0);
+                if !is_type_name(&id_type) {
+                    return Err(ObserverError::DatabaseError {
+                        reason: format!(
+                            "unsupported id column type `{id_type}` on {}",
+                            self.target.view
+                        ),
+                    });
+                }
+                Ok(id_type)
+            })
+            .await?;
+        Ok(id_type)
+    }
+}
+
+#[async_trait::async_trait]
+impl ReindexSource for ViewReader {
+    async fn fetch_after(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<ReindexRow>, ObserverError> {
+        let sql = page_sql(&self.target, self.id_type().await?);
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let client = self.pool.get().await.map_err(db_error)?;
+        let rows = client.query(&sql, &[&after, &limit]).await.map_err(db_error)?;
+        Ok(rows
+            .iter()
+            .map(|row| ReindexRow {
+                id:        row.get("id"),
+                tenant_id: row.get("tenant_id"),
+                data:      row.get("data"),
+            })
+            .collect())
+    }
+}
+
+/// The keyset page query: rows after the `$1` cursor (all rows when NULL), in
+/// `id` order, at most `$2` of them.
+///
+/// `target` identifiers and `id_type` are validated before they get here
+/// ([`is_relation_name`](super::is_relation_name), [`is_type_name`]).
+pub(crate) fn page_sql(target: &ReindexTarget, id_type: &str) -> String {
+    let tenant = target
+        .tenant_column
+        .as_deref()
+        .map_or_else(|| "NULL".to_string(), |c| format!("{c}::text"));
+    format!(
+        "SELECT id::text AS id, {tenant} AS tenant_id, {data}::jsonb AS data \
+         FROM {view} \
+         WHERE $1::text IS NULL OR id > $1::text::{id_type} \
+         ORDER BY id \
+         LIMIT $2",
+        data = target.data_column,
+        view = target.view,
+    )
+}
+
+/// Whether `name` looks like a `format_type` result that is safe to splice into
+/// a cast (`uuid`, `bigint`, `character varying(64)`, …).
+pub(crate) fn is_type_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | ' ' | '(' | ')' | ','))
+}
+
+fn db_error(e: impl std::fmt::Display) -> ObserverError {
+    ObserverError::DatabaseError {
+        reason: format!("search reindex: {e}"),
+    }
+}
@@ -0,0 +1,137 @@
+//! Tests for `fraiseql search reindex`.
+//!
+//! Target resolution, state persistence, SQL rendering and progress formatting
+//! are pure, so they are covered here without a database or search engine. The
+//! batching itself is covered by the observers crate's `Reindexer` tests.
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use fraiseql_core::schema::{CompiledSchema, TypeDefinition};
+use fraiseql_observers::ReindexCursor;
+
+use super::{
+    BackendKind, ReindexTarget, default_state_file, is_relation_name, load_state,
+    reader::{is_type_name, page_sql},
+    render_progress, resolve_target, save_state,
+};
+
+fn schema_with_order() -> CompiledSchema {
+    let mut order = TypeDefinition::new("Order", "tv_order");
+    order.tenant_column = Some("tenant_id".to_string());
+    CompiledSchema {
+        types: vec![order],
+        ..CompiledSchema::default()
+    }
+}
+
+fn cursor(indexed: usize) -> ReindexCursor {
+    ReindexCursor {
+        entity_type: "Order".to_string(),
+        snapshot_at: 1_700_000_000,
+        last_id: Some("42".to_string()),
+        indexed,
+    }
+}
+
+#[test]
+fn backend_names_parse_case_insensitively() {
+    assert_eq!("Meilisearch".parse::<BackendKind>().unwrap(), BackendKind::Meilisearch);
+    assert_eq!("typesense".parse::<BackendKind>().unwrap(), BackendKind::Typesense);
+    assert_eq!("elasticsearch".parse::<BackendKind>().unwrap(), BackendKind::Elasticsearch);
+    assert!("solr".parse::<BackendKind>().is_err());
+}
+
+#[test]
+fn target_comes_from_the_compiled_schema() {
+    let schema = schema_with_order();
+    let target = resolve_target(Some(&schema), "Order", None).unwrap();
+    assert_eq!(
+        target,
+        ReindexTarget {
+            view:          "tv_order".to_string(),
+            data_column:   "data".to_string(),
+            tenant_column: Some("tenant_id".to_string()),
+        }
+    );
+}
+
+#[test]
+fn explicit_view_overrides_the_schema_and_works_without_one() {
+    let schema = schema_with_order();
+    let target = resolve_target(Some(&schema), "Order", Some("reporting.tv_order_v2")).unwrap();
+    assert_eq!(target.view, "reporting.tv_order_v2");
+    assert_eq!(target.tenant_column.as_deref(), Some("tenant_id"));
+
+    let target = resolve_target(None, "Order", Some("tv_order")).unwrap();
+    assert_eq!(target.data_column, "data");
+    assert_eq!(target.tenant_column, None);
+}
+
+#[test]
+fn unknown_entity_or_unsafe_view_is_rejected() {
+    let schema = schema_with_order();
+    let err = resolve_target(Some(&schema), "Invoice", None).unwrap_err();
+    assert!(err.to_string().contains("not in the compiled schema"));
+    assert!(resolve_target(None, "Order", None).is_err());
+    assert!(resolve_target(None, "Order", Some("tv_order; DROP TABLE x")).is_err());
+}
+
+#[test]
+fn relation_and_type_names_are_validated() {
+    assert!(is_relation_name("tv_order"));
+    assert!(is_relation_name("benchmark.tv_order"));
+    assert!(!is_relation_name("a.b.c"));
+    assert!(!is_relation_name("1tv"));
+    assert!(is_type_name("uuid"));
+    assert!(is_type_name("character varying(64)"));
+    assert!(!is_type_name("uuid); DROP TABLE x; --"));
+}
+
+#[test]
+fn page_sql_is_a_keyset_query_in_the_id_columns_type() {
+    let target = ReindexTarget {
+        view:          "tv_order".to_string(),
+        data_column:   "data".to_string(),
+        tenant_column: Some("tenant_id".to_string()),
+    };
+    let sql = page_sql(&target, "uuid");
+    assert!(sql.contains("tenant_id::text AS tenant_id"));
+    assert!(sql.contains("FROM tv_order"));
+    assert!(sql.contains("id > $1::text::uuid"));
+    assert!(sql.contains("ORDER BY id"));
+
+    let untenanted = ReindexTarget {
+        tenant_column: None,
+        ..target
+    };
+    assert!(page_sql(&untenanted, "bigint").contains("NULL AS tenant_id"));
+}
+
+#[test]
+fn state_round_trips_through_the_state_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested").join("reindex-order.json");
+
+    save_state(&path, &cursor(1500)).unwrap();
+    assert_eq!(load_state(&path).unwrap(), cursor(1500));
+    assert!(load_state(&dir.path().join("missing.json")).is_err());
+}
+
+#[test]
+fn default_state_file_is_per_entity() {
+    assert_eq!(
+        default_state_file("Order"),
+        std::path::Path::new(".fraiseql").join("reindex-order.json")
+    );
+}
+
+#[test]
+fn progress_reports_share_rate_and_cursor() {
+    assert_eq!(
+        render_progress(&cursor(500), Some(2000), 500, 2.0),
+        "Order: 500/2000 (25.0%) rows indexed, 250 rows/s, last id 42"
+    );
+    assert_eq!(
+        render_progress(&cursor(500), None, 0, 0.0),
+        "Order: 500 rows indexed, 0 rows/s, last id 42"
+    );
+}
@@ -0,0 +1,478 @@
+//! Fake values shaped by the compiled schema, for `fraiseql seed`.
+//!
+//! [`Faker`] produces the primitives — person names, emails, sentences, timestamps —
+//! picking a flavour from the field name (`email`, `title`, `price`, …) or, for rich
+//! scalars, the scalar name. [`RowGenerator`] walks a compiled type, input object or
+//! mutation argument list with it, drawing enum values from the schema and foreign
+//! keys from rows that already exist, so a seeded row always references real rows.
+//!
+//! Generation is deterministic for a given `--seed`, which keeps load-test datasets
+//! reproducible.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use fraiseql_core::{
+    schema::{CompiledSchema, FieldType, MutationDefinition, TypeDefinition},
+    utils::to_snake_case,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IndexedRandom};
+use serde_json::{Map, Value, json};
+
+/// Nested value objects (non-entity object fields) are expanded this deep.
+const MAX_DEPTH: usize = 3;
+
+/// Share of nullable fields left `null`.
+const NULL_PROBABILITY: f64 = 0.1;
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Bruno", "Chloé", "Daniel", "Emma", "Farid", "Grace", "Hiro", "Inès", "Jonas", "Kara",
+    "Liam", "Maya", "Noah", "Olga", "Priya", "Quentin", "Rosa", "Sami", "Tara", "Uma", "Victor",
+    "Wen", "Yara", "Zoe",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Anderson", "Bernard", "Chen", "Dubois", "Evans", "Fischer", "García", "Hansen", "Ito",
+    "Johnson", "Kowalski", "Lambert", "Martin", "Nakamura", "Okafor", "Patel", "Rossi", "Schmidt",
+    "Tanaka", "Usman", "Varga", "Williams", "Yilmaz", "Zhang",
+];
+
+const WORDS: &[&str] = &[
+    "alpha", "amber", "bright", "cedar", "delta", "ember", "field", "garden", "harbor", "iron",
+    "jade", "keystone", "lumen", "meadow", "north", "orbit", "pine", "quartz", "river", "summit",
+    "tidal", "urban", "vector", "willow", "zenith",
+];
+
+const CITIES: &[&str] = &[
+    "Amsterdam",
+    "Berlin",
+    "Chicago",
+    "Dublin",
+    "Lisbon",
+    "Lyon",
+    "Montréal",
+    "Nairobi",
+    "Osaka",
+    "Porto",
+    "Seoul",
+    "Toronto",
+    "Valencia",
+    "Vienna",
+    "Zürich",
+];
+
+const COUNTRIES: &[&str] = &[
+    "CA", "DE", "ES", "FR", "GB", "IE", "JP", "KE", "NL", "PT", "US",
+];
+
+const STREETS: &[&str] = &[
+    "Main Street",
+    "Oak Avenue",
+    "Harbour Road",
+    "Rue de la Paix",
+    "Elm Street",
+    "Station Road",
+    "Park Lane",
+    "Mill Road",
+];
+
+const DOMAINS: &[&str] = &["example.com", "example.org", "example.net"];
+
+/// Primitive fake-value source.
+pub struct Faker {
+    rng:      StdRng,
+    sequence: u64,
+}
+
+impl Faker {
+    /// A faker seeded with `seed`, or from OS entropy when `None`.
+    #[must_use]
+    pub fn new(seed: Option<u64>) -> Self {
+        let rng = seed.map_or_else(StdRng::from_os_rng, StdRng::seed_from_u64);
+        Self { rng, sequence: 0 }
+    }
+
+    fn pick(&mut self, values: &'static [&'static str]) -> &'static str {
+        values.choose(&mut self.rng).copied().unwrap_or_default()
+    }
+
+    /// A strictly increasing number, keeping generated unique-ish strings unique.
+    const fn next_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+
+    fn words(&mut self, min: usize, max: usize) -> String {
+        let count = self.rng.random_range(min..=max);
+        (0..count).map(|_| self.pick(WORDS)).collect::<Vec<_>>().join(" ")
+    }
+
+    fn sentence(&mut self, min: usize, max: usize) -> String {
+        let words = self.words(min, max);
+        let mut chars = words.chars();
+        chars
+            .next()
+            .map_or_else(String::new, |first| format!("{}{}", first.to_uppercase(), chars.as_str()))
+    }
+
+    fn email(&mut self) -> String {
+        let first = self.pick(FIRST_NAMES);
+        let last = self.pick(LAST_NAMES);
+        let n = self.next_sequence();
+        let domain = self.pick(DOMAINS);
+        format!("{}.{}{n}@{domain}", ascii_lower(first), ascii_lower(last))
+    }
+
+    /// A random v4 UUID (derived from the seeded RNG, so reproducible).
+    #[must_use]
+    pub fn uuid(&mut self) -> String {
+        let bytes: [u8; 16] = self.rng.random();
+        uuid::Builder::from_random_bytes(bytes).into_uuid().to_string()
+    }
+
+    fn date(&mut self) -> String {
+        let year = self.rng.random_range(2022..=2026);
+        let month = self.rng.random_range(1..=12);
+        let day = self.rng.random_range(1..=28);
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+
+    fn time(&mut self) -> String {
+        let hour = self.rng.random_range(0..24);
+        let minute = self.rng.random_range(0..60);
+        let second = self.rng.random_range(0..60);
+        format!("{hour:02}:{minute:02}:{second:02}")
+    }
+
+    /// A string flavoured by `hint`: a field name, or a rich scalar name.
+    pub fn string_for(&mut self, hint: &str) -> String {
+        let hint = hint.to_lowercase().replace('_', "");
+        let has = |needle: &str| hint.contains(needle);
+        if has("email") {
+            self.email()
+        } else if has("firstname") || hint == "givenname" {
+            self.pick(FIRST_NAMES).to_string()
+        } else if has("lastname") || has("surname") || hint == "familyname" {
+            self.pick(LAST_NAMES).to_string()
+        } else if has("username") || has("login") || has("handle") {
+            format!("{}{}", ascii_lower(self.pick(FIRST_NAMES)), self.next_sequence())
+        } else if has("phone") {
+            let n = self.rng.random_range(0..10_000_000_u32);
+            format!("+1-555-{:03}-{:04}", n / 10_000, n % 10_000)
+        } else if has("url") || has("website") || has("link") {
+            let domain = self.pick(DOMAINS);
+            format!("https://{domain}/{}", self.words(1, 3).replace(' ', "-"))
+        } else if has("slug") {
+            format!("{}-{}", self.words(2, 3).replace(' ', "-"), self.next_sequence())
+        } else if has("city") {
+            self.pick(CITIES).to_string()
+        } else if has("country") {
+            self.pick(COUNTRIES).to_string()
+        } else if has("street") || has("address") {
+            let number = self.rng.random_range(1..500);
+            format!("{number} {}", self.pick(STREETS))
+        } else if has("postal") || has("zip") {
+            format!("{:05}", self.rng.random_range(1000..99_999))
+        } else if has("color") || has("colour") {
+            format!("#{:06x}", self.rng.random_range(0..0x0100_0000_u32))
+        } else if has("currency") {
+            self.pick(&["EUR", "GBP", "JPY", "USD"]).to_string()
+        } else if has("description")
+            || has("body")
+            || has("content")
+            || has("bio")
+            || has("summary")
+            || has("comment")
+            || has("note")
+        {
+            format!("{}. {}.", self.sentence(6, 12), self.sentence(4, 10))
+        } else if has("title") || has("subject") || has("headline") || has("label") {
+            self.sentence(2, 5)
+        } else if has("name") {
+            format!("{} {}", self.pick(FIRST_NAMES), self.pick(LAST_NAMES))
+        } else if has("code") || has("sku") || has("reference") {
+            format!("{}-{:05}", self.pick(WORDS).to_uppercase(), self.next_sequence())
+        } else {
+            format!("{} {}", self.sentence(1, 2), self.next_sequence())
+        }
+    }
+
+    fn int_for(&mut self, hint: &str) -> i64 {
+        let hint = hint.to_lowercase();
+        if hint.contains("age") {
+            self.rng.random_range(18..90)
+        } else if hint.contains("year") {
+            self.rng.random_range(1990..=2026)
+        } else if hint.contains("count") || hint.contains("quantity") || hint.contains("stock") {
+            self.rng.random_range(0..100)
+        } else {
+            self.rng.random_range(0..1000)
+        }
+    }
+
+    fn float_for(&mut self, hint: &str) -> f64 {
+        let hint = hint.to_lowercase();
+        let (low, high) = if hint.contains("lat") {
+            (-90.0, 90.0)
+        } else if hint.contains("lng") || hint.contains("lon") {
+            (-180.0, 180.0)
+        } else if hint.contains("rating") || hint.contains("score") {
+            (0.0, 5.0)
+        } else {
+            (1.0, 1000.0)
+        };
+        (self.rng.random_range(low..high) * 100.0_f64).round() / 100.0
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        self.rng.random_bool(probability)
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        self.rng.random_range(0..len)
+    }
+}
+
+fn ascii_lower(s: &str) -> String {
+    s.chars().filter(char::is_ascii_alphabetic).collect::<String>().to_lowercase()
+}
+
+/// An existing row another entity can reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// The row's surrogate key (`pk_<entity>`), when read from its base table.
+    pub pk: Option<i64>,
+    /// The row's public `id`.
+    pub id: String,
+}
+
+/// The entity a `<entity>Id` / `<entity>_id` field refers to, if the schema has it.
+#[must_use]
+pub fn referenced_entity(field_name: &str, schema: &CompiledSchema) -> Option<String> {
+    let snake = to_snake_case(field_name);
+    let base = snake.strip_suffix("_id").filter(|b| !b.is_empty())?;
+    entity_for_snake_name(base, schema)
+}
+
+/// The entity a `fk_<entity>` column refers to, if the schema has it.
+#[must_use]
+pub fn fk_entity(column: &str, schema: &CompiledSchema) -> Option<String> {
+    entity_for_snake_name(column.strip_prefix("fk_")?, schema)
+}
+
+fn entity_for_snake_name(snake: &str, schema: &CompiledSchema) -> Option<String> {
+    schema
+        .types
+        .iter()
+        .find(|t| !t.is_error && to_snake_case(t.name.as_str()) == snake)
+        .map(|t| t.name.as_str().to_string())
+}
+
+/// Whether a type is backed by its own view (it is seeded on its own, never nested).
+fn is_entity(type_name: &str, schema: &CompiledSchema) -> bool {
+    schema
+        .queries
+        .iter()
+        .any(|q| q.return_type == type_name && q.sql_source.is_some())
+}
+
+/// Builds rows and mutation variables from compiled type shapes.
+pub struct RowGenerator<'a> {
+    schema:     &'a CompiledSchema,
+    faker:      Faker,
+    references: HashMap<String, Vec<Reference>>,
+    /// The reference picked per entity for the current row, so a row's `fk_*`
+    /// column and its `…Id` field agree.
+    picked:     HashMap<String, usize>,
+}
+
+impl<'a> RowGenerator<'a> {
+    /// A generator over `schema`, drawing foreign keys from `references`.
+    #[must_use]
+    pub fn new(
+        schema: &'a CompiledSchema,
+        faker: Faker,
+        references: HashMap<String, Vec<Reference>>,
+    ) -> Self {
+        Self {
+            schema,
+            faker,
+            references,
+            picked: HashMap::new(),
+        }
+    }
+
+    /// Start a new row: foreign keys are picked afresh.
+    pub fn begin_row(&mut self) {
+        self.picked.clear();
+    }
+
+    /// A fresh UUID for a row's `id`.
+    pub fn uuid(&mut self) -> String {
+        self.faker.uuid()
+    }
+
+    /// The existing `entity` row this row references, or `None` when `nullable`
+    /// and none exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the reference is required and `entity` has no rows.
+    pub fn reference(&mut self, entity: &str, nullable: bool) -> Result<Option<Reference>> {
+        let pool = self.references.get(entity).map_or(&[][..], Vec::as_slice);
+        if pool.is_empty() {
+            if nullable {
+                return Ok(None);
+            }
+            bail!("no `{entity}` rows to reference; seed `{entity}` first");
+        }
+        let index = match self.picked.get(entity) {
+            Some(&index) => index,
+            None => {
+                let index = self.faker.index(pool.len());
+                self.picked.insert(entity.to_string(), index);
+                index
+            },
+        };
+        Ok(pool.get(index).cloned())
+    }
+
+    /// The JSONB document of one `type_def` row, keyed by `snake_case` field name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a required foreign key has no rows to reference.
+    pub fn object_for_type(&mut self, type_def: &TypeDefinition, id: &str) -> Result<Value> {
+        self.type_object(type_def, Some(id), 0)
+    }
+
+    fn type_object(
+        &mut self,
+        type_def: &TypeDefinition,
+        id: Option<&str>,
+        depth: usize,
+    ) -> Result<Value> {
+        let mut object = Map::new();
+        for field in &type_def.fields {
+            let name = field.name.as_str();
+            // Computed fields are evaluated by the view, not stored.
+            if field.sql_expression.is_some() {
+                continue;
+            }
+            let value = match (name, id) {
+                ("id", Some(id)) => Value::String(id.to_string()),
+                _ => self.value(name, &field.field_type, field.nullable, depth)?,
+            };
+            object.insert(to_snake_case(name), value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    /// Variables for one call of `mutation`, keyed by argument name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a required foreign key has no rows to reference.
+    pub fn mutation_variables(&mut self, mutation: &MutationDefinition) -> Result<Value> {
+        let mut variables = Map::new();
+        for arg in &mutation.arguments {
+            let value = self.value(&arg.name, &arg.arg_type, arg.nullable, 0)?;
+            variables.insert(arg.name.clone(), value);
+        }
+        Ok(Value::Object(variables))
+    }
+
+    fn value(&mut self, name: &str, ty: &FieldType, nullable: bool, depth: usize) -> Result<Value> {
+        if let Some(entity) = referenced_entity(name, self.schema) {
+            if matches!(ty, FieldType::Id | FieldType::Uuid | FieldType::String) {
+                return Ok(self.reference(&entity, nullable)?.map_or(Value::Null, |r| json!(r.id)));
+            }
+        }
+        if nullable && self.faker.chance(NULL_PROBABILITY) {
+            return Ok(Value::Null);
+        }
+        Ok(match ty {
+            FieldType::String => json!(self.faker.string_for(name)),
+            FieldType::Scalar(scalar) => self.scalar(name, scalar),
+            FieldType::Int => json!(self.faker.int_for(name)),
+            FieldType::Float => json!(self.faker.float_for(name)),
+            FieldType::Decimal => json!(format!("{:.2}", self.faker.float_for(name))),
+            FieldType::Boolean => json!(self.faker.chance(0.5)),
+            FieldType::Id | FieldType::Uuid => json!(self.faker.uuid()),
+            FieldType::DateTime => {
+                json!(format!("{}T{}Z", self.faker.date(), self.faker.time()))
+            },
+            FieldType::Date => json!(self.faker.date()),
+            FieldType::Time => json!(self.faker.time()),
+            FieldType::Json => json!({ "source": "fraiseql-seed", "tag": self.faker.words(1, 1) }),
+            FieldType::Enum(enum_name) => self.enum_value(enum_name),
+            FieldType::List(inner) => {
+                let len = self.faker.index(3) + 1;
+                let items = (0..len)
+                    .map(|_| self.value(name, inner, false, depth + 1))
+                    .collect::<Result<Vec<_>>>()?;
+                Value::Array(items)
+            },
+            FieldType::Input(input_name) => self.input_object(input_name, depth)?,
+            FieldType::Object(type_name) => self.nested_object(type_name, depth)?,
+            // Vector dimensions are not part of the compiled type; interfaces and
+            // unions have no single shape to generate.
+            FieldType::Vector | FieldType::Interface(_) | FieldType::Union(_) => Value::Null,
+        })
+    }
+
+    fn scalar(&mut self, name: &str, scalar: &str) -> Value {
+        match scalar {
+            "Latitude" | "Longitude" => json!(self.faker.float_for(scalar)),
+            "Money" | "ExchangeRate" => json!(format!("{:.2}", self.faker.float_for(name))),
+            "CountryCode" => json!(self.faker.string_for("country")),
+            "CurrencyCode" => json!(self.faker.string_for("currency")),
+            "PhoneNumber" => json!(self.faker.string_for("phone")),
+            "PostalCode" => json!(self.faker.string_for("postal")),
+            "Email" | "URL" | "Slug" => json!(self.faker.string_for(scalar)),
+            _ => json!(self.faker.string_for(name)),
+        }
+    }
+
+    fn enum_value(&mut self, enum_name: &str) -> Value {
+        let values = self.schema.find_enum(enum_name).map_or(&[][..], |e| e.values.as_slice());
+        if values.is_empty() {
+            return Value::Null;
+        }
+        json!(values[self.faker.index(values.len())].name)
+    }
+
+    fn input_object(&mut self, input_name: &str, depth: usize) -> Result<Value> {
+        let Some(input) = self.schema.find_input_type(input_name) else {
+            return Ok(Value::Null);
+        };
+        if depth >= MAX_DEPTH {
+            return Ok(Value::Null);
+        }
+        let mut object = Map::new();
+        for field in &input.fields {
+            let ty = FieldType::parse(&field.field_type);
+            let nullable = field.nullable && !field.field_type.ends_with('!');
+            let value = self.value(&field.name, &ty, nullable, depth + 1)?;
+            object.insert(field.name.clone(), value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    /// A value object embedded in the parent document; entity relationships are
+    /// composed by the views, so those are left out.
+    fn nested_object(&mut self, type_name: &str, depth: usize) -> Result<Value> {
+        // Input field types are parsed from their type string, so an input object
+        // reference arrives here as an `Object`.
+        if self.schema.find_input_type(type_name).is_some() {
+            return self.input_object(type_name, depth);
+        }
+        if depth >= MAX_DEPTH || is_entity(type_name, self.schema) {
+            return Ok(Value::Null);
+        }
+        match self.schema.find_type(type_name) {
+            Some(type_def) => self.type_object(type_def, None, depth + 1),
+            None => Ok(Value::Null),
+        }
+    }
+}
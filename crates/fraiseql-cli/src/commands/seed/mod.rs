@@ -0,0 +1,543 @@
+//! `fraiseql seed` — fill the database with fake rows shaped by the compiled
+//! schema, for load testing and demos.
+//!
+//! Two write paths, chosen with `--via`:
+//!
+//! - **mutation** — call the entity's insert mutation (an `Insert` operation, or `create<Entity>`)
+//!   once per row through an in-process `Executor`, so rows go through the same write-side
+//!   function, validation and change-log capture as production traffic.
+//! - **table** — insert straight into the entity's base table (`tb_<entity>`, derived from its
+//!   `v_`/`tv_`/`mv_` view or given with `--table`) in batches: the JSONB column gets a document
+//!   built from the type's fields, `id` a fresh UUID, `fk_<entity>` columns an existing row's
+//!   `pk_<entity>`, and other required columns the value of the field with the same name.
+//!
+//! `auto` (the default) uses the mutation when one exists. Foreign keys always
+//! point at rows that already exist, so related entities are seeded parent first
+//! (`--entity User`, then `--entity Post`). Values come from [`fake`]: deterministic
+//! for a given `--seed`, flavoured by field names and rich scalar types.
+
+pub mod fake;
+pub mod writer;
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
+
+use anyhow::{Context, Result, bail};
+use fraiseql_core::{
+    db::postgres::PostgresAdapter,
+    runtime::{Executor, RuntimeConfig},
+    schema::{
+        ArgumentDefinition, CompiledSchema, FieldType, MutationDefinition, MutationOperation,
+        TypeDefinition,
+    },
+    utils::to_snake_case,
+};
+use serde_json::{Map, Value};
+
+use self::{
+    fake::{Faker, Reference, RowGenerator, fk_entity, referenced_entity},
+    writer::{SeedWriter, TableColumn},
+};
+use crate::commands::{
+    doctor::minimal_selection_for_type,
+    migrate::resolve_database_url,
+    query::{ensure_postgres_url, has_errors},
+};
+
+/// At most this many existing rows are read per referenced entity.
+const REFERENCE_LIMIT: i64 = 10_000;
+
+/// Arguments for `fraiseql seed`.
+pub struct SeedArgs {
+    /// Path to the compiled schema.
+    pub schema:     PathBuf,
+    /// Explicit PostgreSQL URL; falls back to `fraiseql.toml` / `DATABASE_URL`.
+    pub database:   Option<String>,
+    /// GraphQL type to seed.
+    pub entity:     String,
+    /// Number of rows to create.
+    pub count:      usize,
+    /// Write path: `auto`, `mutation` or `table`.
+    pub via:        String,
+    /// Base table override for the table path.
+    pub table:      Option<String>,
+    /// RNG seed for reproducible data.
+    pub seed:       Option<u64>,
+    /// Rows per `INSERT` on the table path.
+    pub batch_size: usize,
+}
+
+/// How generated rows reach the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedVia {
+    /// The insert mutation when the entity has one, the base table otherwise.
+    Auto,
+    /// The entity's insert mutation.
+    Mutation,
+    /// Direct inserts into the entity's base table.
+    Table,
+}
+
+impl FromStr for SeedVia {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "mutation" => Ok(Self::Mutation),
+            "table" => Ok(Self::Table),
+            other => bail!("unknown --via `{other}` (expected auto, mutation or table)"),
+        }
+    }
+}
+
+/// The resolved write path for one entity.
+#[derive(Debug, PartialEq)]
+pub enum SeedPlan<'a> {
+    /// Call `mutation` once per row with `document`.
+    Mutation {
+        /// The insert mutation.
+        mutation: &'a MutationDefinition,
+        /// The GraphQL document, with one variable per argument.
+        document: String,
+    },
+    /// Batch-insert into `table`.
+    Table {
+        /// The base table (bare or schema-qualified).
+        table:        String,
+        /// The column holding the JSONB document.
+        jsonb_column: String,
+    },
+}
+
+/// Where the value of one base-table column comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnSource {
+    /// The generated JSONB document.
+    Document,
+    /// The row's generated `id`.
+    Id,
+    /// The `pk_*` of an existing row of the entity.
+    ForeignKey {
+        /// The referenced entity.
+        entity:   String,
+        /// Whether the column may be left NULL when the entity has no rows.
+        nullable: bool,
+    },
+    /// The document's value under this key.
+    Field(String),
+}
+
+/// Run the `fraiseql seed` command.
+///
+/// # Errors
+///
+/// Returns an error if the schema cannot be loaded, the entity is unknown or has
+/// no write path, no PostgreSQL URL resolves, a required foreign key has no rows
+/// to reference, or the database rejects a row.
+pub async fn run(args: SeedArgs) -> Result<()> {
+    let via: SeedVia = args.via.parse()?;
+    let text = std::fs::read_to_string(&args.schema)
+        .with_context(|| format!("failed to read schema file {}", args.schema.display()))?;
+    let schema = CompiledSchema::from_json(&text, false)
+        .with_context(|| format!("failed to parse compiled schema {}", args.schema.display()))?;
+    let db_url = resolve_database_url(args.database.as_deref())?;
+    ensure_postgres_url(&db_url)?;
+
+    let plan = plan(&schema, &args.entity, via, args.table.as_deref())?;
+    let writer = SeedWriter::connect(&db_url)?;
+    let faker = Faker::new(args.seed);
+
+    let (inserted, how) = match plan {
+        SeedPlan::Mutation { mutation, document } => {
+            let references =
+                load_references(&writer, &schema, &mutation_references(mutation, &schema), false)
+                    .await?;
+            let generator = RowGenerator::new(&schema, faker, references);
+            let name = mutation.name.clone();
+            let inserted = Box::pin(seed_via_mutation(
+                &db_url, &schema, mutation, &document, generator, args.count,
+            ))
+            .await?;
+            (inserted, format!("mutation `{name}`"))
+        },
+        SeedPlan::Table {
+            table,
+            jsonb_column,
+        } => {
+            let type_def = find_entity(&schema, &args.entity)?;
+            let columns = writer.table_columns(&table).await?;
+            if columns.is_empty() {
+                bail!(
+                    "table `{table}` does not exist; pass --table with the base table of `{}`",
+                    args.entity
+                );
+            }
+            let sources = column_sources(&columns, &jsonb_column, type_def, &schema, &table)?;
+            let mut entities = type_references(type_def, &schema);
+            entities.extend(sources.iter().filter_map(|(_, source)| match source {
+                ColumnSource::ForeignKey { entity, .. } => Some(entity.clone()),
+                _ => None,
+            }));
+            let references = load_references(&writer, &schema, &entities, true).await?;
+            let mut generator = RowGenerator::new(&schema, faker, references);
+            let inserted = seed_via_table(
+                &writer,
+                &table,
+                type_def,
+                &sources,
+                &mut generator,
+                args.count,
+                args.batch_size.max(1),
+            )
+            .await?;
+            (inserted, format!("table `{table}`"))
+        },
+    };
+
+    println!("Seeded {inserted} `{}` row(s) via {how}.", args.entity);
+    Ok(())
+}
+
+/// Resolve how `entity` is written.
+///
+/// # Errors
+///
+/// Returns an error if `entity` is not a type of the schema, or the requested
+/// write path is not available for it.
+pub fn plan<'a>(
+    schema: &'a CompiledSchema,
+    entity: &str,
+    via: SeedVia,
+    table: Option<&str>,
+) -> Result<SeedPlan<'a>> {
+    find_entity(schema, entity)?;
+    let mutation = insert_mutation_for(schema, entity);
+    let use_mutation = match via {
+        SeedVia::Auto => mutation.is_some(),
+        SeedVia::Mutation => true,
+        SeedVia::Table => false,
+    };
+
+    if use_mutation {
+        let Some(mutation) = mutation else {
+            bail!("no insert mutation returns `{entity}`; seed it with --via table");
+        };
+        return Ok(SeedPlan::Mutation {
+            mutation,
+            document: mutation_document(mutation, schema),
+        });
+    }
+
+    let query = schema
+        .queries
+        .iter()
+        .find(|q| q.return_type == entity && q.sql_source.is_some());
+    let table = match (table, query.and_then(|q| q.sql_source.as_deref())) {
+        (Some(table), _) => table.to_string(),
+        (None, Some(view)) => base_table_for_view(view).with_context(|| {
+            format!("cannot derive the base table of `{entity}` from view `{view}`; pass --table")
+        })?,
+        (None, None) => bail!("no query reads `{entity}` from a view; pass --table"),
+    };
+    let jsonb_column = query.map_or_else(|| "data".to_string(), |q| q.jsonb_column.clone());
+    Ok(SeedPlan::Table {
+        table,
+        jsonb_column,
+    })
+}
+
+fn find_entity<'a>(schema: &'a CompiledSchema, entity: &str) -> Result<&'a TypeDefinition> {
+    schema
+        .find_type(entity)
+        .with_context(|| format!("`{entity}` is not a type of the compiled schema"))
+}
+
+/// The mutation that creates one `entity`: an `Insert` operation returning it,
+/// or failing that `create<Entity>`.
+#[must_use]
+pub fn insert_mutation_for<'a>(
+    schema: &'a CompiledSchema,
+    entity: &str,
+) -> Option<&'a MutationDefinition> {
+    let returns_entity = |m: &&MutationDefinition| m.return_type == entity;
+    schema
+        .mutations
+        .iter()
+        .filter(returns_entity)
+        .find(|m| matches!(m.operation, MutationOperation::Insert { .. }))
+        .or_else(|| {
+            let name = format!("create{entity}");
+            schema.mutations.iter().filter(returns_entity).find(|m| m.name == name)
+        })
+}
+
+/// The base table behind a `v_`, `tv_` or `mv_` view (`app.v_user` → `app.tb_user`).
+#[must_use]
+pub fn base_table_for_view(view: &str) -> Option<String> {
+    let (schema, name) = match view.rsplit_once('.') {
+        Some((schema, name)) => (Some(schema), name),
+        None => (None, view),
+    };
+    let base = ["v_", "tv_", "mv_"].iter().find_map(|prefix| name.strip_prefix(prefix))?;
+    Some(match schema {
+        Some(schema) => format!("{schema}.tb_{base}"),
+        None => format!("tb_{base}"),
+    })
+}
+
+/// A GraphQL document calling `mutation` with one variable per argument.
+#[must_use]
+pub fn mutation_document(mutation: &MutationDefinition, schema: &CompiledSchema) -> String {
+    let selection = minimal_selection_for_type(&mutation.return_type, schema)
+        .unwrap_or_else(|| "__typename".to_string());
+    if mutation.arguments.is_empty() {
+        return format!("mutation {{ {} {{ {selection} }} }}", mutation.name);
+    }
+    let params = mutation
+        .arguments
+        .iter()
+        .map(variable_definition)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let args = mutation
+        .arguments
+        .iter()
+        .map(|a| format!("{0}: ${0}", a.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("mutation Seed({params}) {{ {}({args}) {{ {selection} }} }}", mutation.name)
+}
+
+fn variable_definition(arg: &ArgumentDefinition) -> String {
+    let ty = arg.arg_type.to_graphql_string();
+    let ty = ty.trim_end_matches('!');
+    let bang = if arg.nullable { "" } else { "!" };
+    format!("${}: {ty}{bang}", arg.name)
+}
+
+/// Entities referenced by `…Id` arguments of `mutation`, including inside its
+/// input objects.
+#[must_use]
+pub fn mutation_references(
+    mutation: &MutationDefinition,
+    schema: &CompiledSchema,
+) -> BTreeSet<String> {
+    let mut out = BTreeSet::new();
+    for arg in &mutation.arguments {
+        collect_references(&arg.name, &arg.arg_type, schema, &mut out, 0);
+    }
+    out
+}
+
+/// Entities referenced by `…Id` fields of `type_def`.
+#[must_use]
+pub fn type_references(type_def: &TypeDefinition, schema: &CompiledSchema) -> BTreeSet<String> {
+    let mut out = BTreeSet::new();
+    for field in &type_def.fields {
+        collect_references(field.name.as_str(), &field.field_type, schema, &mut out, 0);
+    }
+    out
+}
+
+fn collect_references(
+    name: &str,
+    ty: &FieldType,
+    schema: &CompiledSchema,
+    out: &mut BTreeSet<String>,
+    depth: usize,
+) {
+    if depth > 3 {
+        return;
+    }
+    match ty {
+        FieldType::Id | FieldType::Uuid | FieldType::String => {
+            if let Some(entity) = referenced_entity(name, schema) {
+                out.insert(entity);
+            }
+        },
+        FieldType::List(inner) => collect_references(name, inner, schema, out, depth + 1),
+        FieldType::Input(input) | FieldType::Object(input) => {
+            if let Some(input) = schema.find_input_type(input) {
+                for field in &input.fields {
+                    let ty = FieldType::parse(&field.field_type);
+                    collect_references(&field.name, &ty, schema, out, depth + 1);
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Decide where each column of the base table gets its value from; columns that
+/// are left to their default (or NULL) are omitted.
+///
+/// # Errors
+///
+/// Returns an error for a NOT NULL column without a default that nothing can
+/// fill.
+pub fn column_sources(
+    columns: &[TableColumn],
+    jsonb_column: &str,
+    type_def: &TypeDefinition,
+    schema: &CompiledSchema,
+    table: &str,
+) -> Result<Vec<(String, ColumnSource)>> {
+    let field_keys: BTreeSet<String> = type_def
+        .fields
+        .iter()
+        .filter(|f| f.sql_expression.is_none())
+        .map(|f| to_snake_case(f.name.as_str()))
+        .collect();
+    let mut sources = Vec::new();
+    for column in columns {
+        let name = column.name.as_str();
+        let source = if name == jsonb_column {
+            ColumnSource::Document
+        } else if name.starts_with("pk_") {
+            continue;
+        } else if name == "id" {
+            ColumnSource::Id
+        } else if let Some(entity) = fk_entity(name, schema) {
+            ColumnSource::ForeignKey {
+                entity,
+                nullable: column.nullable,
+            }
+        } else if field_keys.contains(name) {
+            ColumnSource::Field(name.to_string())
+        } else if column.has_default || column.nullable {
+            continue;
+        } else {
+            bail!(
+                "column `{name}` of `{table}` is NOT NULL without a default and matches no field \
+                 of `{}`",
+                type_def.name
+            );
+        };
+        sources.push((name.to_string(), source));
+    }
+    Ok(sources)
+}
+
+/// Read the rows each of `entities` can be referenced by: from the entity's view
+/// for the mutation path, from its base table (with `pk_<entity>`) for the table
+/// path. Entities without a view are skipped — a required reference to one fails
+/// at generation time.
+async fn load_references(
+    writer: &SeedWriter,
+    schema: &CompiledSchema,
+    entities: &BTreeSet<String>,
+    with_pk: bool,
+) -> Result<HashMap<String, Vec<Reference>>> {
+    let mut references = HashMap::new();
+    for entity in entities {
+        let Some(view) = schema
+            .queries
+            .iter()
+            .find(|q| &q.return_type == entity && q.sql_source.is_some())
+            .and_then(|q| q.sql_source.as_deref())
+        else {
+            continue;
+        };
+        let rows = if with_pk {
+            let Some(table) = base_table_for_view(view) else {
+                continue;
+            };
+            let pk = format!("pk_{}", to_snake_case(entity));
+            writer.references(&table, Some(&pk), REFERENCE_LIMIT).await?
+        } else {
+            writer.references(view, None, REFERENCE_LIMIT).await?
+        };
+        references.insert(entity.clone(), rows);
+    }
+    Ok(references)
+}
+
+/// Call `mutation` `count` times through an in-process executor.
+async fn seed_via_mutation(
+    db_url: &str,
+    schema: &CompiledSchema,
+    mutation: &MutationDefinition,
+    document: &str,
+    mut generator: RowGenerator<'_>,
+    count: usize,
+) -> Result<usize> {
+    let adapter =
+        Arc::new(PostgresAdapter::new(db_url).await.context("failed to connect to PostgreSQL")?);
+    let executor = Executor::with_config(schema.clone(), adapter, RuntimeConfig::default());
+    for n in 0..count {
+        generator.begin_row();
+        let variables = generator.mutation_variables(mutation)?;
+        let result = executor
+            .execute(document, Some(&variables))
+            .await
+            .with_context(|| format!("`{}` failed after {n} row(s)", mutation.name))?;
+        if has_errors(&result) {
+            bail!(
+                "`{}` failed after {n} row(s): {}",
+                mutation.name,
+                result.get("errors").map_or_else(String::new, Value::to_string)
+            );
+        }
+    }
+    Ok(count)
+}
+
+/// Insert `count` generated rows into `table`, `batch_size` per statement.
+async fn seed_via_table(
+    writer: &SeedWriter,
+    table: &str,
+    type_def: &TypeDefinition,
+    sources: &[(String, ColumnSource)],
+    generator: &mut RowGenerator<'_>,
+    count: usize,
+    batch_size: usize,
+) -> Result<usize> {
+    let columns: Vec<String> = sources.iter().map(|(name, _)| name.clone()).collect();
+    let mut inserted = 0;
+    while inserted < count {
+        let batch = batch_size.min(count - inserted);
+        let rows = (0..batch)
+            .map(|_| table_row(generator, type_def, sources))
+            .collect::<Result<Vec<_>>>()?;
+        writer.insert_rows(table, &columns, &Value::Array(rows)).await?;
+        inserted += batch;
+    }
+    Ok(inserted)
+}
+
+/// One base-table row, keyed by column name.
+///
+/// # Errors
+///
+/// Returns an error when a required foreign key has no rows to reference.
+pub fn table_row(
+    generator: &mut RowGenerator<'_>,
+    type_def: &TypeDefinition,
+    sources: &[(String, ColumnSource)],
+) -> Result<Value> {
+    generator.begin_row();
+    let id = generator.uuid();
+    let document = generator.object_for_type(type_def, &id)?;
+    let mut row = Map::new();
+    for (column, source) in sources {
+        let value = match source {
+            ColumnSource::Document => document.clone(),
+            ColumnSource::Id => Value::String(id.clone()),
+            ColumnSource::ForeignKey { entity, nullable } => generator
+                .reference(entity, *nullable)?
+                .and_then(|r| r.pk)
+                .map_or(Value::Null, Value::from),
+            ColumnSource::Field(key) => document.get(key).cloned().unwrap_or(Value::Null),
+        };
+        row.insert(column.clone(), value);
+    }
+    Ok(Value::Object(row))
+}
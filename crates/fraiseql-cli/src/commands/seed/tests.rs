@@ -0,0 +1,283 @@
+//! Tests for `fraiseql seed`.
+//!
+//! Planning, column mapping and value generation are pure, so they are covered
+//! here without a database. The [`writer`](super::writer) is a thin PostgreSQL
+//! projection exercised by the CLI integration leg.
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::collections::HashMap;
+
+use fraiseql_core::schema::{
+    ArgumentDefinition, CompiledSchema, EnumDefinition, EnumValueDefinition, FieldDefinition,
+    FieldType, InputFieldDefinition, InputObjectDefinition, MutationDefinition, MutationOperation,
+    QueryDefinition, TypeDefinition,
+};
+use serde_json::{Value, json};
+
+use super::{
+    ColumnSource, SeedPlan, SeedVia, base_table_for_view, column_sources,
+    fake::{Faker, Reference, RowGenerator, referenced_entity},
+    mutation_document, mutation_references, plan, table_row,
+    writer::TableColumn,
+};
+
+fn blog_schema() -> CompiledSchema {
+    let user = TypeDefinition::new("User", "v_user")
+        .with_field(FieldDefinition::new("id", FieldType::Uuid))
+        .with_field(FieldDefinition::new("name", FieldType::String))
+        .with_field(FieldDefinition::new("email", FieldType::String))
+        .with_field(FieldDefinition::new("role", FieldType::Enum("Role".to_string())));
+    let post = TypeDefinition::new("Post", "v_post")
+        .with_field(FieldDefinition::new("id", FieldType::Uuid))
+        .with_field(FieldDefinition::new("title", FieldType::String))
+        .with_field(FieldDefinition::new("userId", FieldType::Uuid))
+        .with_field(FieldDefinition::new("author", FieldType::Object("User".to_string())))
+        .with_field(
+            FieldDefinition::new("wordCount", FieldType::Int).with_sql_expression("length(data)"),
+        );
+    let role = EnumDefinition::new("Role").with_values(vec![
+        EnumValueDefinition::new("ADMIN"),
+        EnumValueDefinition::new("MEMBER"),
+    ]);
+    let input = InputObjectDefinition::new("CreatePostInput")
+        .with_field(InputFieldDefinition::new("title", "String!"))
+        .with_field(InputFieldDefinition::new("userId", "ID!"));
+
+    let mut create_post = MutationDefinition::new("createPost", "Post");
+    create_post.operation = MutationOperation::Insert {
+        table: "tb_post".to_string(),
+    };
+    create_post.arguments.push(ArgumentDefinition::new(
+        "input",
+        FieldType::Input("CreatePostInput".to_string()),
+    ));
+
+    CompiledSchema {
+        types: vec![user, post],
+        enums: vec![role],
+        input_types: vec![input],
+        queries: vec![
+            QueryDefinition::new("users", "User").with_sql_source("v_user").returning_list(),
+            QueryDefinition::new("posts", "Post")
+                .with_sql_source("app.v_post")
+                .returning_list(),
+        ],
+        mutations: vec![create_post],
+        ..Default::default()
+    }
+}
+
+fn column(name: &str, nullable: bool, has_default: bool) -> TableColumn {
+    TableColumn {
+        name: name.to_string(),
+        data_type: "text".to_string(),
+        nullable,
+        has_default,
+    }
+}
+
+fn users() -> Vec<Reference> {
+    vec![
+        Reference {
+            pk: Some(1),
+            id: "00000000-0000-4000-8000-000000000001".to_string(),
+        },
+        Reference {
+            pk: Some(2),
+            id: "00000000-0000-4000-8000-000000000002".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn via_parses_known_strategies_only() {
+    assert_eq!("auto".parse::<SeedVia>().unwrap(), SeedVia::Auto);
+    assert_eq!("table".parse::<SeedVia>().unwrap(), SeedVia::Table);
+    assert!("copy".parse::<SeedVia>().is_err());
+}
+
+#[test]
+fn base_table_is_derived_from_the_view_name() {
+    assert_eq!(base_table_for_view("v_user").as_deref(), Some("tb_user"));
+    assert_eq!(base_table_for_view("app.tv_post").as_deref(), Some("app.tb_post"));
+    assert_eq!(base_table_for_view("users"), None);
+}
+
+#[test]
+fn auto_prefers_the_insert_mutation() {
+    let schema = blog_schema();
+    let plan = plan(&schema, "Post", SeedVia::Auto, None).unwrap();
+    assert_eq!(
+        plan,
+        SeedPlan::Mutation {
+            mutation: &schema.mutations[0],
+            document:
+                "mutation Seed($input: CreatePostInput!) { createPost(input: $input) { id } }"
+                    .to_string(),
+        }
+    );
+}
+
+#[test]
+fn auto_falls_back_to_the_base_table() {
+    let schema = blog_schema();
+    assert_eq!(
+        plan(&schema, "User", SeedVia::Auto, None).unwrap(),
+        SeedPlan::Table {
+            table:        "tb_user".to_string(),
+            jsonb_column: "data".to_string(),
+        }
+    );
+    let forced = plan(&schema, "Post", SeedVia::Table, Some("app.posts")).unwrap();
+    assert!(matches!(forced, SeedPlan::Table { ref table, .. } if table == "app.posts"));
+}
+
+#[test]
+fn unavailable_paths_are_errors() {
+    let schema = blog_schema();
+    let err = plan(&schema, "User", SeedVia::Mutation, None).unwrap_err();
+    assert!(err.to_string().contains("no insert mutation returns `User`"), "got: {err}");
+    let err = plan(&schema, "Comment", SeedVia::Auto, None).unwrap_err();
+    assert!(err.to_string().contains("not a type"), "got: {err}");
+}
+
+#[test]
+fn mutation_without_arguments_has_no_variables() {
+    let schema = blog_schema();
+    let create_user = MutationDefinition::new("createUser", "User");
+    assert_eq!(mutation_document(&create_user, &schema), "mutation { createUser { id } }");
+}
+
+#[test]
+fn id_suffixed_fields_reference_known_entities() {
+    let schema = blog_schema();
+    assert_eq!(referenced_entity("userId", &schema).as_deref(), Some("User"));
+    assert_eq!(referenced_entity("user_id", &schema).as_deref(), Some("User"));
+    assert_eq!(referenced_entity("tenantId", &schema), None);
+    assert_eq!(referenced_entity("id", &schema), None);
+
+    let create_post = &schema.mutations[0];
+    assert_eq!(
+        mutation_references(create_post, &schema).into_iter().collect::<Vec<_>>(),
+        vec!["User".to_string()]
+    );
+}
+
+#[test]
+fn columns_map_to_document_id_foreign_keys_and_fields() {
+    let schema = blog_schema();
+    let post = schema.find_type("Post").unwrap();
+    let columns = [
+        column("pk_post", false, true),
+        column("id", false, true),
+        column("fk_user", false, false),
+        column("title", false, false),
+        column("data", false, false),
+        column("created_at", false, true),
+        column("archived_reason", true, false),
+    ];
+    let sources = column_sources(&columns, "data", post, &schema, "tb_post").unwrap();
+    assert_eq!(
+        sources,
+        vec![
+            ("id".to_string(), ColumnSource::Id),
+            (
+                "fk_user".to_string(),
+                ColumnSource::ForeignKey {
+                    entity:   "User".to_string(),
+                    nullable: false,
+                }
+            ),
+            ("title".to_string(), ColumnSource::Field("title".to_string())),
+            ("data".to_string(), ColumnSource::Document),
+        ]
+    );
+
+    let err = column_sources(&[column("sku", false, false)], "data", post, &schema, "tb_post")
+        .unwrap_err();
+    assert!(err.to_string().contains("column `sku` of `tb_post`"), "got: {err}");
+}
+
+#[test]
+fn table_rows_reference_one_existing_row_consistently() {
+    let schema = blog_schema();
+    let post = schema.find_type("Post").unwrap();
+    let sources = column_sources(
+        &[
+            column("id", false, true),
+            column("fk_user", false, false),
+            column("data", false, false),
+        ],
+        "data",
+        post,
+        &schema,
+        "tb_post",
+    )
+    .unwrap();
+    let references = HashMap::from([("User".to_string(), users())]);
+    let mut generator = RowGenerator::new(&schema, Faker::new(Some(7)), references);
+
+    for _ in 0..20 {
+        let row = table_row(&mut generator, post, &sources).unwrap();
+        let data = &row["data"];
+        assert_eq!(data["id"], row["id"]);
+        // The fk column and the document's userId point at the same user.
+        let user = users().into_iter().find(|u| json!(u.pk) == row["fk_user"]).unwrap();
+        assert_eq!(data["user_id"], json!(user.id));
+        // Computed fields are left to the view; entity relationships are composed there.
+        assert!(data.get("word_count").is_none());
+        assert_eq!(data["author"], Value::Null);
+        assert!(data["title"].as_str().is_some_and(|t| !t.is_empty()));
+    }
+}
+
+#[test]
+fn required_references_without_rows_fail() {
+    let schema = blog_schema();
+    let post = schema.find_type("Post").unwrap();
+    let sources =
+        column_sources(&[column("fk_user", false, false)], "data", post, &schema, "tb_post")
+            .unwrap();
+    let mut generator = RowGenerator::new(&schema, Faker::new(Some(1)), HashMap::new());
+    let err = table_row(&mut generator, post, &sources).unwrap_err();
+    assert!(err.to_string().contains("seed `User` first"), "got: {err}");
+}
+
+#[test]
+fn values_follow_field_names_and_enums() {
+    let schema = blog_schema();
+    let user = schema.find_type("User").unwrap();
+    let mut generator = RowGenerator::new(&schema, Faker::new(Some(42)), HashMap::new());
+    let row = generator.object_for_type(user, "u-1").unwrap();
+    assert_eq!(row["id"], "u-1");
+    assert!(row["email"].as_str().unwrap().contains('@'));
+    assert_eq!(row["name"].as_str().unwrap().split(' ').count(), 2);
+    assert!(matches!(row["role"].as_str(), Some("ADMIN" | "MEMBER")));
+}
+
+#[test]
+fn mutation_variables_fill_input_objects_with_references() {
+    let schema = blog_schema();
+    let references = HashMap::from([("User".to_string(), users())]);
+    let mut generator = RowGenerator::new(&schema, Faker::new(Some(3)), references);
+    let variables = generator.mutation_variables(&schema.mutations[0]).unwrap();
+    let input = &variables["input"];
+    assert!(input["title"].is_string());
+    assert!(users().iter().any(|u| json!(u.id) == input["userId"]));
+}
+
+#[test]
+fn the_same_seed_generates_the_same_rows() {
+    let schema = blog_schema();
+    let user = schema.find_type("User").unwrap();
+    let generate = || {
+        let mut generator = RowGenerator::new(&schema, Faker::new(Some(99)), HashMap::new());
+        (0..5)
+            .map(|_| {
+                let id = generator.uuid();
+                generator.object_for_type(user, &id).unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(generate(), generate());
+}
@@ -0,0 +1,153 @@
+//! The PostgreSQL side of `fraiseql seed`: reading rows to reference and the
+//! base-table shape, and batch-inserting generated rows.
+//!
+//! Deliberately thin (deadpool + tokio-postgres, like the `sources` reader): the
+//! decisions about what to generate live in the parent module and [`fake`](super::fake),
+//! which are unit-tested without a database.
+
+use anyhow::{Context, Result};
+use deadpool_postgres::{Config, ManagerConfig, Pool, RecyclingMethod, Runtime};
+use tokio_postgres::NoTls;
+
+use super::fake::Reference;
+
+/// One column of the base table rows are inserted into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableColumn {
+    /// Column name.
+    pub name:        String,
+    /// `format_type` rendering of the column type (e.g. `uuid`, `jsonb`).
+    pub data_type:   String,
+    /// Whether the column accepts NULL.
+    pub nullable:    bool,
+    /// Whether the column has a default or is an identity column.
+    pub has_default: bool,
+}
+
+/// A live PostgreSQL connection pool for seeding.
+pub struct SeedWriter {
+    pool: Pool,
+}
+
+impl SeedWriter {
+    /// Connect to `db_url` (PostgreSQL only).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool cannot be created. (Connection failures
+    /// surface lazily on the first query.)
+    pub fn connect(db_url: &str) -> Result<Self> {
+        let mut cfg = Config::new();
+        cfg.url = Some(db_url.to_string());
+        cfg.manager = Some(ManagerConfig {
+            recycling_method: RecyclingMethod::Fast,
+        });
+        cfg.pool = Some(deadpool_postgres::PoolConfig::new(2));
+        let pool = cfg
+            .create_pool(Some(Runtime::Tokio1), NoTls)
+            .context("failed to create PostgreSQL connection pool for seeding")?;
+        Ok(Self { pool })
+    }
+
+    /// Existing rows of `relation` to reference: `id` always, and `pk_column` when
+    /// given (read from a base table).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the query fails — typically because
+    /// the relation has no `id` (or `pk_column`) column.
+    pub async fn references(
+        &self,
+        relation: &str,
+        pk_column: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<Reference>> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let pk = pk_column.map_or_else(
+            || "NULL::bigint".to_string(),
+            |column| format!("{}::bigint", quote_ident(column)),
+        );
+        let sql =
+            format!("SELECT {pk} AS pk, id::text AS id FROM {} LIMIT $1", quote_relation(relation));
+        let rows = client
+            .query(&sql, &[&limit])
+            .await
+            .with_context(|| format!("failed to read rows to reference from {relation}"))?;
+        Ok(rows
+            .iter()
+            .map(|row| Reference {
+                pk: row.get("pk"),
+                id: row.get("id"),
+            })
+            .collect())
+    }
+
+    /// Columns of `table` (bare or schema-qualified) in ordinal order; empty when
+    /// the table does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the catalog query fails.
+    pub async fn table_columns(&self, table: &str) -> Result<Vec<TableColumn>> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let rows = client
+            .query(
+                "SELECT attname::text AS name, format_type(atttypid, atttypmod) AS data_type, \
+                 NOT attnotnull AS nullable, (atthasdef OR attidentity <> '') AS has_default \
+                 FROM pg_attribute \
+                 WHERE attrelid = to_regclass($1::text) AND attnum > 0 AND NOT attisdropped \
+                 ORDER BY attnum",
+                &[&table],
+            )
+            .await
+            .with_context(|| format!("failed to read the columns of {table}"))?;
+        Ok(rows
+            .iter()
+            .map(|row| TableColumn {
+                name:        row.get("name"),
+                data_type:   row.get("data_type"),
+                nullable:    row.get("nullable"),
+                has_default: row.get("has_default"),
+            })
+            .collect())
+    }
+
+    /// Insert `rows` (a JSON array of objects keyed by column name) into `table`,
+    /// writing only `columns`. Returns the number of rows inserted.
+    ///
+    /// The batch travels as one JSONB parameter expanded with
+    /// `jsonb_populate_recordset`, so each value is cast to its column type by
+    /// PostgreSQL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection fails or PostgreSQL rejects a row.
+    pub async fn insert_rows(
+        &self,
+        table: &str,
+        columns: &[String],
+        rows: &serde_json::Value,
+    ) -> Result<u64> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let column_list = columns.iter().map(|c| quote_ident(c)).collect::<Vec<_>>().join(", ");
+        let table_sql = quote_relation(table);
+        let sql = format!(
+            "INSERT INTO {table_sql} ({column_list}) SELECT {column_list} \
+             FROM jsonb_populate_recordset(NULL::{table_sql}, $1)"
+        );
+        client
+            .execute(&sql, &[rows])
+            .await
+            .with_context(|| format!("failed to insert into {table}"))
+    }
+}
+
+/// Quote one identifier for PostgreSQL.
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Quote a bare or schema-qualified relation name component by component.
+fn quote_relation(relation: &str) -> String {
+    relation.split('.').map(quote_ident).collect::<Vec<_>>().join(".")
+}
@@ -0,0 +1,300 @@
+//! `fraiseql serve` — the development server.
+//!
+//! `serve` is `fraiseql run --watch` tuned for the inner development loop:
+//!
+//! - every schema **source** under the project directory is watched (not just the entry file), so
+//!   editing a type file referenced from `fraiseql.toml` triggers a recompile;
+//! - the playground (`GraphiQL` by default) and introspection are always enabled, without auth;
+//! - a compile error does not kill the process. The bind address instead serves an **error
+//!   overlay**: browsers get an HTML page showing the compiler output (auto-refreshing until the
+//!   schema compiles again) and API clients get a `GraphQL` error response carrying the same
+//!   message.
+//!
+//! The database is resolved exactly like `fraiseql run`: `--database` >
+//! `DATABASE_URL` > `[database].url` in `fraiseql.toml`.
+
+use std::{
+    net::SocketAddr,
+    path::{Component, Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    http::{Method, StatusCode, header},
+    response::{Html, IntoResponse, Response},
+};
+use fraiseql_server::url_guard::parse_database_url;
+use notify::{
+    Config as NotifyConfig, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher,
+};
+use tracing::info;
+
+use super::run::{
+    ShutdownFuture, build_config_from, compile_schema, dispatch_serve, resolve_input,
+    resolve_runtime_config, sigint_signal,
+};
+
+#[cfg(test)]
+#[path = "serve_tests.rs"]
+mod tests;
+
+/// File extensions that count as schema sources. Changes to anything else under
+/// the project directory (editor swap files, build output, logs) are ignored.
+const SOURCE_EXTENSIONS: &[&str] = &["toml", "json", "graphql", "gql", "py", "ts", "sql"];
+
+/// Directories never watched: build output, VCS metadata, dependency caches.
+const IGNORED_DIRS: &[&str] = &[
+    "target",
+    ".git",
+    "node_modules",
+    ".venv",
+    "__pycache__",
+    "dist",
+    "build",
+];
+
+/// How often the error overlay page re-polls the server, in seconds.
+const OVERLAY_REFRESH_SECS: u32 = 2;
+
+/// Run the `fraiseql serve` command.
+///
+/// # Arguments
+///
+/// * `input`    - Path to the input file; `None` triggers auto-detection.
+/// * `database` - Database URL override (see the module docs for precedence).
+/// * `port`     - TCP port override.
+/// * `bind`     - Bind host override.
+///
+/// # Errors
+///
+/// Returns an error if the input file cannot be found, the database URL is
+/// missing from all sources, the project directory cannot be watched, or the
+/// server fails to start against a compiled schema. Compile errors are never
+/// returned — they are rendered in the error overlay instead.
+pub async fn run(
+    input: Option<&str>,
+    database: Option<String>,
+    port: Option<u16>,
+    bind: Option<String>,
+) -> Result<()> {
+    let input_path = resolve_input(input)?;
+    let (db_url, bind_addr, server_cfg, db_cfg) =
+        resolve_runtime_config(&input_path, database, port, bind)?;
+    let scheme = parse_database_url(&db_url)?;
+    let watch_root = watch_root(&input_path);
+
+    println!("FraiseQL dev server");
+    println!("   Schema:     {}", input_path.display());
+    println!("   Watching:   {}", watch_root.display());
+    println!();
+
+    loop {
+        // Arm the watcher before compiling so an edit made mid-compile still
+        // triggers the next iteration.
+        let (change_tx, change_rx) = tokio::sync::oneshot::channel::<()>();
+        let restarting = Arc::new(AtomicBool::new(false));
+        let restarting_for_watcher = restarting.clone();
+        let _watcher_guard = spawn_source_watcher(&watch_root, move |event| {
+            if let Some(path) = event.paths.first() {
+                println!("Changed: {}", path.display());
+            }
+            restarting_for_watcher.store(true, Ordering::SeqCst);
+            let _ = change_tx.send(());
+        })?;
+        let shutdown: ShutdownFuture = Some(Box::pin(async move {
+            tokio::select! {
+                () = sigint_signal() => {},
+                _ = change_rx => {},
+            }
+        }));
+
+        match compile_schema(&input_path).await {
+            Ok(schema) => {
+                let mut config = build_config_from(&db_url, bind_addr, &server_cfg, &db_cfg, true);
+                config.playground_enabled = true;
+                config.playground_require_auth = Some(false);
+
+                println!("Server ready at http://{bind_addr}{}", config.graphql_path);
+                println!("   Playground: http://{bind_addr}{}", config.playground_path);
+                println!("   Press Ctrl+C to stop");
+                println!();
+
+                Box::pin(dispatch_serve(scheme, config, schema, shutdown)).await?;
+            },
+            Err(e) => {
+                let message = format!("{e:#}");
+                eprintln!("Compile error:\n{message}\n");
+                println!("Serving error overlay at http://{bind_addr} until the schema compiles");
+                serve_error_overlay(bind_addr, message, shutdown).await?;
+            },
+        }
+
+        if !restarting.load(Ordering::SeqCst) {
+            break;
+        }
+
+        // Let the editor finish its write burst before re-reading the sources.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        println!("Schema source changed, recompiling...");
+    }
+
+    Ok(())
+}
+
+/// The directory whose schema sources are watched: the input file's parent.
+fn watch_root(input_path: &Path) -> PathBuf {
+    match input_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Whether a changed path should trigger a recompile.
+fn is_schema_source(path: &Path) -> bool {
+    let in_ignored_dir = path.components().any(|c| match c {
+        Component::Normal(name) => name.to_str().is_some_and(|n| IGNORED_DIRS.contains(&n)),
+        _ => false,
+    });
+    if in_ignored_dir {
+        return false;
+    }
+    // The compiled artifact is an output, not a source; rewriting it must not loop.
+    if path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.ends_with(".compiled.json"))
+    {
+        return false;
+    }
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+}
+
+/// Spawn a recursive watcher over `root` that calls `on_change` once when a
+/// schema source is created, modified, or removed.
+///
+/// Returns the watcher guard — drop it to stop watching.
+fn spawn_source_watcher<F>(root: &Path, on_change: F) -> Result<RecommendedWatcher>
+where
+    F: FnOnce(Event) + Send + 'static,
+{
+    use std::sync::mpsc::channel;
+
+    let (tx, rx) = channel::<Result<Event, notify::Error>>();
+
+    let mut watcher = RecommendedWatcher::new(
+        move |res| {
+            let _ = tx.send(res);
+        },
+        NotifyConfig::default().with_poll_interval(Duration::from_millis(500)),
+    )
+    .context("Failed to create file watcher")?;
+
+    watcher
+        .watch(root, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", root.display()))?;
+
+    std::thread::spawn(move || {
+        for event in rx.into_iter().flatten() {
+            let relevant = matches!(
+                event.kind,
+                EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
+            ) && event.paths.iter().any(|p| is_schema_source(p));
+            if relevant {
+                info!("Schema source changed");
+                on_change(event);
+                break;
+            }
+        }
+    });
+
+    Ok(watcher)
+}
+
+/// Serve the compile-error overlay on `bind_addr` until `shutdown` resolves.
+async fn serve_error_overlay(
+    bind_addr: SocketAddr,
+    message: String,
+    shutdown: ShutdownFuture,
+) -> Result<()> {
+    let message = Arc::new(message);
+    let app = Router::new().fallback(move |method: Method, headers: axum::http::HeaderMap| {
+        let message = message.clone();
+        async move { overlay_response(&method, &headers, &message) }
+    });
+
+    let listener = tokio::net::TcpListener::bind(bind_addr)
+        .await
+        .with_context(|| format!("Failed to bind error overlay on {bind_addr}"))?;
+    let serve = axum::serve(listener, app);
+    match shutdown {
+        Some(shutdown) => serve.with_graceful_shutdown(shutdown).await,
+        None => serve.await,
+    }
+    .context("Error overlay server failed")
+}
+
+/// Browsers (which ask for `text/html`) get the HTML overlay; everything else —
+/// `GraphQL` clients POSTing to the endpoint — gets a `GraphQL` error body.
+fn overlay_response(method: &Method, headers: &axum::http::HeaderMap, message: &str) -> Response {
+    let wants_html = *method == Method::GET
+        && headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|accept| accept.contains("text/html"));
+
+    if wants_html {
+        return (StatusCode::SERVICE_UNAVAILABLE, Html(overlay_html(message))).into_response();
+    }
+
+    let body = serde_json::json!({
+        "errors": [{
+            "message": format!("Schema compilation failed: {message}"),
+            "extensions": { "code": "SCHEMA_COMPILE_ERROR" },
+        }],
+    });
+    (StatusCode::SERVICE_UNAVAILABLE, Json(body)).into_response()
+}
+
+/// Render the overlay page. The page re-polls every [`OVERLAY_REFRESH_SECS`] so
+/// it turns into the real server (playground) as soon as the schema compiles.
+fn overlay_html(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta http-equiv=\"refresh\" content=\"{OVERLAY_REFRESH_SECS}\">\n\
+         <title>FraiseQL — schema compilation failed</title>\n\
+         <style>body{{margin:0;background:#1e1e1e;color:#eee;font-family:system-ui,sans-serif}}\
+         main{{max-width:960px;margin:48px auto;padding:0 24px}}\
+         h1{{color:#ff6b6b;font-size:20px}}\
+         pre{{background:#2d2d2d;border-left:4px solid #ff6b6b;padding:16px;\
+         white-space:pre-wrap;font-size:14px;line-height:1.5}}\
+         p{{color:#999;font-size:13px}}</style>\n</head>\n<body>\n<main>\n\
+         <h1>Schema compilation failed</h1>\n<pre>{}</pre>\n\
+         <p>Fix the schema source and save — this page reloads automatically.</p>\n\
+         </main>\n</body>\n</html>\n",
+        escape_html(message)
+    )
+}
+
+/// Minimal HTML escaping for text content.
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
@@ -0,0 +1,59 @@
+//! Unit tests for `fraiseql serve` helpers (pure; no server or filesystem).
+
+use std::path::Path;
+
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+
+use super::{escape_html, is_schema_source, overlay_html, overlay_response, watch_root};
+
+#[test]
+fn schema_sources_are_recognised_by_extension() {
+    assert!(is_schema_source(Path::new("fraiseql.toml")));
+    assert!(is_schema_source(Path::new("schema/types/user.py")));
+    assert!(is_schema_source(Path::new("schema.json")));
+    assert!(!is_schema_source(Path::new("notes.md")));
+    assert!(!is_schema_source(Path::new(".schema.py.swp")));
+}
+
+#[test]
+fn build_output_and_compiled_artifacts_are_ignored() {
+    // Rewriting the compiled artifact must not retrigger a compile (loop).
+    assert!(!is_schema_source(Path::new("schema.compiled.json")));
+    assert!(!is_schema_source(Path::new("target/fraiseql/schema.json")));
+    assert!(!is_schema_source(Path::new("web/node_modules/pkg/index.ts")));
+}
+
+#[test]
+fn watch_root_is_the_input_directory() {
+    assert_eq!(watch_root(Path::new("fraiseql.toml")), Path::new("."));
+    assert_eq!(watch_root(Path::new("app/fraiseql.toml")), Path::new("app"));
+}
+
+#[test]
+fn overlay_escapes_compiler_output() {
+    assert_eq!(escape_html("<T> & \"q\""), "&lt;T&gt; &amp; &quot;q&quot;");
+    let html = overlay_html("unknown type `<User>`");
+    assert!(html.contains("unknown type `&lt;User&gt;`"));
+    assert!(html.contains("http-equiv=\"refresh\""));
+}
+
+#[test]
+fn overlay_serves_html_to_browsers_and_graphql_errors_to_clients() {
+    let mut browser = HeaderMap::new();
+    browser.insert(header::ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml"));
+    let page = overlay_response(&Method::GET, &browser, "boom");
+    assert_eq!(page.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(
+        page.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .is_ok_and(|ct| ct.starts_with("text/html"))
+    );
+
+    let api = overlay_response(&Method::POST, &HeaderMap::new(), "boom");
+    assert_eq!(api.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(
+        api.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .is_ok_and(|ct| ct.starts_with("application/json"))
+    );
+}
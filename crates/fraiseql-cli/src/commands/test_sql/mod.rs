@@ -0,0 +1,188 @@
+//! `fraiseql test sql` — golden SQL snapshots for compiled operations.
+//!
+//! Every `.graphql` / `.gql` file under the operations directory is executed
+//! against the compiled schema through an in-process `Executor` whose adapter
+//! ([`recorder`]) records the statements it is handed instead of running them.
+//! The recording is compared with the `<name>.sql` snapshot beside the file; any
+//! difference — a changed WHERE, a lost projection, a new statement — fails the
+//! run with a diff, so compiler changes that silently alter query plans surface
+//! in CI. `--update` writes the new snapshots instead.
+//!
+//! Variables come from an optional `<name>.variables.json`. No database is
+//! needed: reads return no rows, so only the statements of root fields are
+//! recorded.
+
+pub mod recorder;
+pub mod snapshot;
+
+#[cfg(test)]
+mod tests;
+
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::{Context, Result, bail};
+use fraiseql_core::{
+    runtime::{Executor, RuntimeConfig},
+    schema::CompiledSchema,
+};
+use serde_json::Value;
+
+use self::{
+    recorder::SqlRecorder,
+    snapshot::{Operation, diff, discover, render},
+};
+use crate::commands::query::has_errors;
+
+/// Arguments for `fraiseql test sql`.
+pub struct TestSqlArgs {
+    /// Directory of GraphQL operation files.
+    pub dir:    PathBuf,
+    /// Path to the compiled schema.
+    pub schema: PathBuf,
+    /// Write snapshots instead of comparing against them.
+    pub update: bool,
+}
+
+/// What happened to one operation's snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The recording matches the snapshot.
+    Unchanged,
+    /// No snapshot existed; one was written (`--update`).
+    Created,
+    /// The snapshot differed and was rewritten (`--update`).
+    Updated,
+    /// No snapshot exists.
+    Missing,
+    /// The recording differs from the snapshot; carries the diff.
+    Changed(String),
+    /// The operation could not be executed; carries the reason.
+    Failed(String),
+}
+
+impl Outcome {
+    /// Whether this outcome fails the run.
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        matches!(self, Self::Missing | Self::Changed(_) | Self::Failed(_))
+    }
+
+    /// Compare a fresh rendering with the snapshot on disk (`None` when absent).
+    #[must_use]
+    pub fn compare(existing: Option<&str>, rendered: &str, update: bool) -> Self {
+        match existing {
+            Some(existing) if existing == rendered => Self::Unchanged,
+            Some(_) if update => Self::Updated,
+            Some(existing) => Self::Changed(diff(existing, rendered)),
+            None if update => Self::Created,
+            None => Self::Missing,
+        }
+    }
+}
+
+/// Run the `fraiseql test sql` command.
+///
+/// # Errors
+///
+/// Returns an error if the schema or the operations directory cannot be read,
+/// a snapshot cannot be written, or any operation fails, has no snapshot, or
+/// no longer matches its snapshot.
+pub async fn run(args: TestSqlArgs) -> Result<()> {
+    let text = std::fs::read_to_string(&args.schema)
+        .with_context(|| format!("failed to read schema file {}", args.schema.display()))?;
+    let schema = CompiledSchema::from_json(&text, false)
+        .with_context(|| format!("failed to parse compiled schema {}", args.schema.display()))?;
+    let operations = discover(&args.dir)?;
+    if operations.is_empty() {
+        bail!("no .graphql or .gql files found under {}", args.dir.display());
+    }
+
+    let recorder = Arc::new(SqlRecorder::default());
+    let executor = Executor::with_config(schema, Arc::clone(&recorder), RuntimeConfig::default());
+
+    let mut failures = 0;
+    for operation in &operations {
+        let outcome = Box::pin(check(&executor, &recorder, operation, args.update)).await?;
+        match &outcome {
+            Outcome::Unchanged => println!("ok       {}", operation.name),
+            Outcome::Created => println!("created  {}", operation.name),
+            Outcome::Updated => println!("updated  {}", operation.name),
+            Outcome::Missing => println!(
+                "MISSING  {} (no {}; run with --update to create it)",
+                operation.name,
+                operation.snapshot.display()
+            ),
+            Outcome::Changed(diff) => println!("CHANGED  {}\n{diff}", operation.name),
+            Outcome::Failed(reason) => println!("FAILED   {}: {reason}", operation.name),
+        }
+        if outcome.is_failure() {
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        bail!(
+            "{failures} of {} SQL snapshot(s) failed; if the changes are intended, \
+             rerun with --update and review the diff",
+            operations.len()
+        );
+    }
+    println!("{} SQL snapshot(s) checked", operations.len());
+    Ok(())
+}
+
+/// Execute one operation, then compare (or write) its snapshot.
+async fn check(
+    executor: &Executor<SqlRecorder>,
+    recorder: &SqlRecorder,
+    operation: &Operation,
+    update: bool,
+) -> Result<Outcome> {
+    let document = std::fs::read_to_string(&operation.document)
+        .with_context(|| format!("failed to read {}", operation.document.display()))?;
+    let variables: Option<Value> = match std::fs::read_to_string(&operation.variables) {
+        Ok(raw) => Some(
+            serde_json::from_str(&raw)
+                .with_context(|| format!("{} is not valid JSON", operation.variables.display()))?,
+        ),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            return Err(e)
+                .with_context(|| format!("failed to read {}", operation.variables.display()));
+        },
+    };
+
+    recorder.clear();
+    let result = executor.execute(&document, variables.as_ref()).await;
+    let statements = recorder.take();
+    // Reads return no rows, so errors raised after SQL was produced (a mutation
+    // with no result row, say) are expected; only an operation that never
+    // reached the database has failed.
+    if statements.is_empty() {
+        match result {
+            Err(e) => return Ok(Outcome::Failed(e.to_string())),
+            Ok(response) if has_errors(&response) => {
+                return Ok(Outcome::Failed(
+                    response.get("errors").map_or_else(String::new, Value::to_string),
+                ));
+            },
+            Ok(_) => {},
+        }
+    }
+
+    let rendered = render(&statements);
+    let existing = match std::fs::read_to_string(&operation.snapshot) {
+        Ok(existing) => Some(existing),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            return Err(e)
+                .with_context(|| format!("failed to read {}", operation.snapshot.display()));
+        },
+    };
+    let outcome = Outcome::compare(existing.as_deref(), &rendered, update);
+    if matches!(outcome, Outcome::Created | Outcome::Updated) {
+        std::fs::write(&operation.snapshot, &rendered)
+            .with_context(|| format!("failed to write {}", operation.snapshot.display()))?;
+    }
+    Ok(outcome)
+}
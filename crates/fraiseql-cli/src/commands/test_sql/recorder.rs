@@ -0,0 +1,153 @@
+//! A [`DatabaseAdapter`] that records the SQL it is asked to run instead of
+//! running it.
+//!
+//! Reads are rendered with the same builders the PostgreSQL adapter uses
+//! ([`render_select_sql`]), mutation calls with [`render_function_call_sql`], and
+//! hand-built SQL (aggregates, raw queries) is kept verbatim. Every call returns
+//! no rows, so each operation records the statements its root fields compile to.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use fraiseql_core::{
+    db::{
+        DatabaseAdapter, DatabaseType, JsonbValue, OrderByClause, PoolMetrics, SqlProjectionHint,
+        SupportsMutations, WhereClause,
+        postgres::{render_function_call_sql, render_select_sql},
+        types::QueryParam,
+    },
+    error::Result,
+};
+
+/// One statement an operation compiled to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedStatement {
+    /// The SQL text, with `$n` placeholders.
+    pub sql:    String,
+    /// The bound parameters, rendered for review.
+    pub params: String,
+}
+
+impl RecordedStatement {
+    fn typed(sql: String, params: &[QueryParam]) -> Self {
+        Self {
+            sql,
+            params: format!("{params:?}"),
+        }
+    }
+
+    fn json(sql: String, params: &[serde_json::Value]) -> Self {
+        Self {
+            sql,
+            params: serde_json::Value::Array(params.to_vec()).to_string(),
+        }
+    }
+}
+
+/// Records statements until [`take`](Self::take) drains them.
+#[derive(Default)]
+pub struct SqlRecorder {
+    statements: Mutex<Vec<RecordedStatement>>,
+}
+
+impl SqlRecorder {
+    /// Drain the statements recorded since the last call.
+    #[must_use]
+    pub fn take(&self) -> Vec<RecordedStatement> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Forget anything recorded so far.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn record(&self, statement: RecordedStatement) {
+        self.lock().push(statement);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<RecordedStatement>> {
+        // A poisoned lock only means another recording panicked; the list is intact.
+        self.statements.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+// Reason: DatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl DatabaseAdapter for SqlRecorder {
+    async fn execute_where_query(
+        &self,
+        view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        let (sql, params) = render_select_sql(view, None, where_clause, limit, offset, order_by)?;
+        self.record(RecordedStatement::typed(sql, &params));
+        Ok(Vec::new())
+    }
+
+    async fn execute_with_projection(
+        &self,
+        view: &str,
+        projection: Option<&SqlProjectionHint>,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        let (sql, params) =
+            render_select_sql(view, projection, where_clause, limit, offset, order_by)?;
+        self.record(RecordedStatement::typed(sql, &params));
+        Ok(Vec::new())
+    }
+
+    fn database_type(&self) -> DatabaseType {
+        DatabaseType::PostgreSQL
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn pool_metrics(&self) -> PoolMetrics {
+        PoolMetrics {
+            total_connections:  0,
+            active_connections: 0,
+            idle_connections:   0,
+            waiting_requests:   0,
+        }
+    }
+
+    async fn execute_raw_query(
+        &self,
+        sql: &str,
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.record(RecordedStatement::json(sql.to_string(), &[]));
+        Ok(Vec::new())
+    }
+
+    async fn execute_parameterized_aggregate(
+        &self,
+        sql: &str,
+        params: &[serde_json::Value],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.record(RecordedStatement::json(sql.to_string(), params));
+        Ok(Vec::new())
+    }
+
+    async fn execute_function_call(
+        &self,
+        function_name: &str,
+        args: &[serde_json::Value],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        let sql = render_function_call_sql(function_name, args.len());
+        self.record(RecordedStatement::json(sql, args));
+        Ok(Vec::new())
+    }
+}
+
+impl SupportsMutations for SqlRecorder {}
@@ -0,0 +1,128 @@
+//! Snapshot files for `fraiseql test sql`: discovering operations, rendering
+//! recorded statements, and diffing a snapshot against a fresh rendering.
+//!
+//! Everything here is pure (apart from the directory walk), so it is unit-tested
+//! without an executor.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::recorder::RecordedStatement;
+
+/// First line of every snapshot file.
+pub const HEADER: &str = "-- fraiseql test sql snapshot: review changes, then accept with --update";
+
+/// One GraphQL operation file and its snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    /// Path relative to the operations directory, without extension (`users/by_email`).
+    pub name:      String,
+    /// The `.graphql` / `.gql` file.
+    pub document:  PathBuf,
+    /// The optional `<name>.variables.json` next to it.
+    pub variables: PathBuf,
+    /// The `<name>.sql` snapshot next to it.
+    pub snapshot:  PathBuf,
+}
+
+/// Every operation file under `dir`, sorted by name.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn discover(dir: &Path) -> Result<Vec<Operation>> {
+    let mut operations = Vec::new();
+    for entry in walkdir::WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry.with_context(|| format!("failed to read {}", dir.display()))?;
+        let path = entry.path();
+        let is_operation = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e == "graphql" || e == "gql");
+        if !entry.file_type().is_file() || !is_operation {
+            continue;
+        }
+        let stem = path.with_extension("");
+        let name = stem
+            .strip_prefix(dir)
+            .unwrap_or(&stem)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        operations.push(Operation {
+            name,
+            document: path.to_path_buf(),
+            variables: sibling(&stem, ".variables.json"),
+            snapshot: sibling(&stem, ".sql"),
+        });
+    }
+    Ok(operations)
+}
+
+/// `stem` with `suffix` appended (`with_extension` would eat a dotted stem).
+fn sibling(stem: &Path, suffix: &str) -> PathBuf {
+    let mut path = stem.as_os_str().to_os_string();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Render recorded statements as snapshot text: the header, then each
+/// statement followed by its parameters, separated by blank lines.
+#[must_use]
+pub fn render(statements: &[RecordedStatement]) -> String {
+    let mut out = format!("{HEADER}\n");
+    if statements.is_empty() {
+        out.push_str("-- (no SQL)\n");
+    }
+    for (i, statement) in statements.iter().enumerate() {
+        out.push_str(&format!(
+            "\n-- statement {}\n{}\n-- params: {}\n",
+            i + 1,
+            statement.sql,
+            statement.params
+        ));
+    }
+    out
+}
+
+/// A line diff of `expected` against `actual`: unchanged lines prefixed with two
+/// spaces, removed with `- `, added with `+ `. Empty when they are equal.
+#[must_use]
+pub fn diff(expected: &str, actual: &str) -> String {
+    if expected == actual {
+        return String::new();
+    }
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // Longest common subsequence table, filled from the end.
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out.push_str(&format!("  {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push_str(&format!("+ {}\n", new[j]));
+            j += 1;
+        } else {
+            out.push_str(&format!("- {}\n", old[i]));
+            i += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,105 @@
+//! Tests for `fraiseql test sql`.
+//!
+//! Snapshot rendering, diffing and outcome classification are pure; discovery
+//! runs against a temporary directory, and the recorder behind a real executor.
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::sync::Arc;
+
+use fraiseql_core::{
+    runtime::{Executor, RuntimeConfig},
+    schema::{CompiledSchema, FieldDefinition, FieldType, QueryDefinition, TypeDefinition},
+};
+
+use super::{
+    Outcome,
+    recorder::{RecordedStatement, SqlRecorder},
+    snapshot::{HEADER, diff, discover, render},
+};
+
+fn statement(sql: &str, params: &str) -> RecordedStatement {
+    RecordedStatement {
+        sql:    sql.to_string(),
+        params: params.to_string(),
+    }
+}
+
+#[test]
+fn snapshots_list_each_statement_with_its_params() {
+    let rendered = render(&[
+        statement(r#"SELECT data FROM "v_user" LIMIT $1"#, "[BigInt(10)]"),
+        statement(r#"SELECT * FROM "fn_create_user"($1)"#, r#"[{"name":"a"}]"#),
+    ]);
+    assert_eq!(
+        rendered,
+        format!(
+            "{HEADER}\n\n-- statement 1\nSELECT data FROM \"v_user\" LIMIT $1\n-- params: \
+             [BigInt(10)]\n\n-- statement 2\nSELECT * FROM \"fn_create_user\"($1)\n-- params: \
+             [{{\"name\":\"a\"}}]\n"
+        )
+    );
+    assert_eq!(render(&[]), format!("{HEADER}\n-- (no SQL)\n"));
+}
+
+#[test]
+fn diff_marks_removed_and_added_lines() {
+    assert_eq!(diff("a\nb\n", "a\nb\n"), "");
+    assert_eq!(diff("a\nb\nc\n", "a\nx\nc\n"), "  a\n- b\n+ x\n  c\n");
+    assert_eq!(diff("a\n", "a\nb\n"), "  a\n+ b\n");
+}
+
+#[test]
+fn outcomes_fail_only_without_update() {
+    assert_eq!(Outcome::compare(Some("x"), "x", false), Outcome::Unchanged);
+    assert_eq!(Outcome::compare(None, "x", false), Outcome::Missing);
+    assert_eq!(
+        Outcome::compare(Some("x\n"), "y\n", false),
+        Outcome::Changed("- x\n+ y\n".into())
+    );
+    assert_eq!(Outcome::compare(None, "x", true), Outcome::Created);
+    assert_eq!(Outcome::compare(Some("x"), "y", true), Outcome::Updated);
+
+    assert!(Outcome::Missing.is_failure());
+    assert!(Outcome::Failed("unknown field".into()).is_failure());
+    assert!(!Outcome::Updated.is_failure());
+}
+
+#[test]
+fn discovery_finds_operations_and_their_siblings() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("users")).unwrap();
+    std::fs::write(dir.path().join("users/by_email.graphql"), "{ users { id } }").unwrap();
+    std::fs::write(dir.path().join("orders.v2.gql"), "{ orders { id } }").unwrap();
+    std::fs::write(dir.path().join("orders.v2.sql"), "").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+    let operations = discover(dir.path()).unwrap();
+    let names: Vec<&str> = operations.iter().map(|o| o.name.as_str()).collect();
+    assert_eq!(names, vec!["orders.v2", "users/by_email"]);
+    assert_eq!(operations[0].snapshot, dir.path().join("orders.v2.sql"));
+    assert_eq!(operations[1].variables, dir.path().join("users/by_email.variables.json"));
+}
+
+#[tokio::test]
+async fn the_recorder_captures_the_sql_of_root_fields() {
+    let schema = CompiledSchema {
+        types: vec![
+            TypeDefinition::new("User", "v_user")
+                .with_field(FieldDefinition::new("id", FieldType::Uuid))
+                .with_field(FieldDefinition::new("name", FieldType::String)),
+        ],
+        queries: vec![
+            QueryDefinition::new("users", "User").with_sql_source("v_user").returning_list(),
+        ],
+        ..Default::default()
+    };
+    let recorder = Arc::new(SqlRecorder::default());
+    let executor = Executor::with_config(schema, Arc::clone(&recorder), RuntimeConfig::default());
+
+    let response = executor.execute("{ users { id name } }", None).await.unwrap();
+    assert_eq!(response["data"]["users"], serde_json::json!([]));
+    let statements = recorder.take();
+    assert_eq!(statements.len(), 1, "{statements:?}");
+    assert!(statements[0].sql.contains(r#"FROM "v_user""#), "{}", statements[0].sql);
+    assert!(recorder.take().is_empty());
+}
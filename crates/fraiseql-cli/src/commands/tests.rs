@@ -304,8 +304,8 @@ mod compile_tests {
     fn test_validate_schema_success() {
         let schema = CompiledSchema {
             types: vec![TypeDefinition {
-                name:                "User".into(),
-                fields:              vec![
+                name:                  "User".into(),
+                fields:                vec![
                     FieldDefinition {
                         name:           "id".into(),
                         field_type:     FieldType::Int,
@@ -320,6 +320,7 @@ mod compile_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     },
                     FieldDefinition {
                         name:           "name".into(),
@@ -335,20 +336,27 @@ mod compile_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     },
                 ],
-                description:         Some("User type".to_string()),
-                sql_source:          String::new().into(),
-                jsonb_column:        String::new(),
-                sql_projection_hint: None,
-                implements:          vec![],
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       Vec::new(),
-                subscription_policy: None,
+                description:           Some("User type".to_string()),
+                sql_source:            String::new().into(),
+                jsonb_column:          String::new(),
+                sql_projection_hint:   None,
+                implements:            vec![],
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         Vec::new(),
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             }],
             queries: vec![QueryDefinition {
                 name:                "users".to_string(),
@@ -665,8 +673,8 @@ mod compile_tests {
         let tmp = tempfile::tempdir().unwrap();
         let schema = CompiledSchema {
             types: vec![TypeDefinition {
-                name:                "UserProfile".into(),
-                fields:              vec![
+                name:                  "UserProfile".into(),
+                fields:                vec![
                     FieldDefinition {
                         name:           "id".into(),
                         field_type:     FieldType::Id,
@@ -681,6 +689,7 @@ mod compile_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     },
                     FieldDefinition {
                         name:           "email".into(),
@@ -696,20 +705,27 @@ mod compile_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     },
                 ],
-                description:         Some("Test type".to_string()),
-                sql_source:          "tv_user_profile".into(),
-                jsonb_column:        "data".to_string(),
-                sql_projection_hint: None,
-                implements:          vec![],
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       vec![],
-                subscription_policy: None,
+                description:           Some("Test type".to_string()),
+                sql_source:            "tv_user_profile".into(),
+                jsonb_column:          "data".to_string(),
+                sql_projection_hint:   None,
+                implements:            vec![],
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         vec![],
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             }],
             ..Default::default()
         };
@@ -1659,6 +1675,79 @@ mod doctor_tests {
             "schema parse should run for a .toml schema: {check:?}"
         );
     }
+
+    // ── database environment / view naming ───────────────────────────────────
+
+    fn schema_with_sources(sources: &[&str]) -> fraiseql_core::schema::CompiledSchema {
+        fraiseql_core::schema::CompiledSchema {
+            queries: sources
+                .iter()
+                .map(|s| fraiseql_core::schema::QueryDefinition::new(*s, "T").with_sql_source(*s))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn view_naming_accepts_prefixed_and_qualified_views() {
+        let schema = schema_with_sources(&["v_user", "app.tv_order", "mv_sales", "va_embedding"]);
+        assert_eq!(view_naming_check(&schema).status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn view_naming_warns_on_unprefixed_sources() {
+        let schema = schema_with_sources(&["v_user", "users", "app.orders"]);
+        let check = view_naming_check(&schema);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.detail.contains("app.orders, users"), "got: {}", check.detail);
+    }
+
+    #[test]
+    fn postgres_version_ranges() {
+        assert_eq!(postgres_version_check(110_022).status, CheckStatus::Fail);
+        let old = postgres_version_check(140_011);
+        assert_eq!(old.status, CheckStatus::Warn);
+        assert!(old.detail.contains("14.11"), "got: {}", old.detail);
+        assert_eq!(postgres_version_check(160_002).status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn ltree_is_required_only_with_hierarchies() {
+        let mut schema = fraiseql_core::schema::CompiledSchema::default();
+        let without = extension_checks(&[], Some(&schema));
+        assert_eq!(without[1].name, "Extension ltree");
+        assert_eq!(without[1].status, CheckStatus::Pass);
+
+        let mut hierarchies = fraiseql_core::schema::HierarchiesConfig::new();
+        hierarchies.insert(
+            "category".to_string(),
+            fraiseql_core::schema::HierarchyDefinition {
+                table:       "tb_category".to_string(),
+                path_column: "path".to_string(),
+            },
+        );
+        schema.hierarchies_config = Some(hierarchies);
+        assert_eq!(extension_checks(&[], Some(&schema))[1].status, CheckStatus::Fail);
+
+        let installed = [("ltree".to_string(), "1.2".to_string())];
+        assert_eq!(extension_checks(&installed, Some(&schema))[1].status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn postgis_is_not_expected_with_the_earthdistance_backend() {
+        let mut schema = fraiseql_core::schema::CompiledSchema::default();
+        assert_eq!(extension_checks(&[], Some(&schema))[2].status, CheckStatus::Warn);
+        schema.geo_backend = fraiseql_core::db::GeoBackend::EarthDistance;
+        assert_eq!(extension_checks(&[], Some(&schema))[2].status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn missing_observer_tables_fail() {
+        assert_eq!(observer_tables_check(2, &[]).status, CheckStatus::Pass);
+        let check = observer_tables_check(2, &["observer_checkpoints"]);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.detail.contains("observer_checkpoints"), "got: {}", check.detail);
+    }
 }
 
 mod explain_tests {
@@ -2045,6 +2134,65 @@ mod migrate_tests {
     }
 }
 
+mod observers_tests {
+    use serde_json::json;
+
+    use super::super::observers::*;
+
+    #[test]
+    fn runtime_url_appends_encoded_segments_to_the_server_path() {
+        let url =
+            runtime_url("http://localhost:8080", &["observers", "order webhook", "pause"]).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "http://localhost:8080/api/observers/runtime/observers/order%20webhook/pause"
+        );
+
+        let url = runtime_url("https://example.com/fraiseql/", &["paused"]).unwrap();
+        assert_eq!(url.as_str(), "https://example.com/fraiseql/api/observers/runtime/paused");
+    }
+
+    #[test]
+    fn runtime_url_rejects_an_invalid_server() {
+        assert!(runtime_url("not a url", &["paused"]).is_err());
+    }
+
+    #[test]
+    fn table_lists_backlog_against_threshold_and_flags_exceeded() {
+        let table = format_table(&[
+            json!({
+                "observer": "order_webhook",
+                "state": "paused",
+                "pinned_checkpoint": 48211,
+                "backlog": 12000,
+                "max_backlog": 10000,
+                "backlog_exceeded": true,
+                "paused_by": "ops@example.com"
+            }),
+            json!({
+                "observer": "audit",
+                "state": "resuming",
+                "pinned_checkpoint": 7,
+                "backlog": 3,
+                "max_backlog": 10000,
+                "backlog_exceeded": false,
+                "paused_by": null
+            }),
+        ]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("Observer"));
+        assert!(lines[2].contains("order_webhook"));
+        assert!(lines[2].contains("12000 / 10000 !"));
+        assert!(lines[3].contains("resuming"));
+        assert!(lines[3].ends_with('-'));
+    }
+
+    #[test]
+    fn table_reports_no_paused_observers() {
+        assert_eq!(format_table(&[]), "No paused observers.\n");
+    }
+}
+
 #[cfg(feature = "run-server")]
 mod run_tests {
     use std::net::SocketAddr;
@@ -10,7 +10,10 @@ pub mod toml_schema;
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use fraiseql_core::schema::NamingConvention;
+use fraiseql_core::{
+    compiler::InjectFieldsPlugin,
+    schema::{FieldDefinition, FieldType, NamingConvention},
+};
 pub use runtime::{DatabaseRuntimeConfig, ServerRuntimeConfig};
 pub use security::SecurityConfig;
 use serde::{Deserialize, Serialize};
@@ -88,6 +91,9 @@ pub struct FraiseQLSettings {
     /// per-tenant cost-budget check. Empty by default.
     #[serde(default)]
     pub cost_weights: std::collections::HashMap<String, usize>,
+    /// Config-driven compiler plugins (`[fraiseql.compiler]`).
+    #[serde(default)]
+    pub compiler:     CompilerTomlConfig,
 }
 
 impl Default for FraiseQLSettings {
@@ -100,6 +106,7 @@ impl Default for FraiseQLSettings {
             mutations:    MutationsConfig::default(),
             naming:       NamingTomlConfig::default(),
             cost_weights: std::collections::HashMap::new(),
+            compiler:     CompilerTomlConfig::default(),
         }
     }
 }
@@ -135,6 +142,76 @@ impl Default for NamingTomlConfig {
     }
 }
 
+/// Compiler plugin options from `[fraiseql.compiler]`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompilerTomlConfig {
+    /// Field-injection plugins (`[[fraiseql.compiler.inject_fields]]`), e.g.
+    /// audit columns added to every type. Run before SQL codegen together with
+    /// any programmatically registered plugins, ordered by `order` then `name`.
+    pub inject_fields: Vec<InjectFieldsTomlConfig>,
+}
+
+/// One `[[fraiseql.compiler.inject_fields]]` plugin.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InjectFieldsTomlConfig {
+    /// Plugin name, recorded in the compiled schema.
+    pub name:   String,
+    /// Pipeline order; lower runs first.
+    #[serde(default)]
+    pub order:  i32,
+    /// Target type names; empty targets every object type.
+    #[serde(default)]
+    pub types:  Vec<String>,
+    /// Fields to add.
+    pub fields: Vec<InjectedFieldTomlConfig>,
+}
+
+/// A field added by an `inject_fields` plugin.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InjectedFieldTomlConfig {
+    /// Field name (JSONB key).
+    pub name:        String,
+    /// GraphQL type (e.g. `DateTime`, `[String]`).
+    #[serde(rename = "type")]
+    pub field_type:  String,
+    /// Whether the field is nullable.
+    #[serde(default = "default_true")]
+    pub nullable:    bool,
+    /// Field description.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl InjectFieldsTomlConfig {
+    /// Build the compiler plugin this entry configures.
+    #[must_use]
+    pub fn to_plugin(&self) -> InjectFieldsPlugin {
+        InjectFieldsPlugin {
+            name:   self.name.clone(),
+            order:  self.order,
+            types:  self.types.clone(),
+            fields: self
+                .fields
+                .iter()
+                .map(|f| {
+                    let mut field =
+                        FieldDefinition::new(f.name.as_str(), FieldType::parse(&f.field_type));
+                    field.nullable = f.nullable;
+                    field.description.clone_from(&f.description);
+                    field
+                })
+                .collect(),
+        }
+    }
+}
+
+const fn default_true() -> bool {
+    true
+}
+
 /// Mutation compilation options from `[fraiseql.mutations]`.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
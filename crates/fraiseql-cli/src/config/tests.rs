@@ -78,6 +78,37 @@ searchUsers = 250
         assert_eq!(config.fraiseql.cost_weights.get("searchUsers"), Some(&250));
     }
 
+    #[test]
+    fn test_parse_compiler_inject_fields_from_toml() {
+        let toml_str = r#"
+[project]
+name = "test-app"
+
+[fraiseql]
+schema_file = "schema.json"
+
+[[fraiseql.compiler.inject_fields]]
+name = "audit_columns"
+order = 10
+fields = [
+  { name = "created_at", type = "DateTime", nullable = false },
+  { name = "updated_by", type = "String" },
+]
+"#;
+        let config: TomlProjectConfig = toml::from_str(toml_str).expect("Failed to parse TOML");
+        let plugins = &config.fraiseql.compiler.inject_fields;
+        assert_eq!(plugins.len(), 1);
+
+        let plugin = plugins[0].to_plugin();
+        assert_eq!(plugin.name, "audit_columns");
+        assert_eq!(plugin.order, 10);
+        assert!(plugin.types.is_empty());
+        assert_eq!(plugin.fields.len(), 2);
+        assert_eq!(plugin.fields[0].field_type, fraiseql_core::schema::FieldType::DateTime);
+        assert!(!plugin.fields[0].nullable);
+        assert!(plugin.fields[1].nullable, "injected fields default to nullable");
+    }
+
     #[test]
     fn test_naming_convention_defaults_to_camel_case() {
         // Workflow-B compiles to a camelCase GraphQL surface by default, both when
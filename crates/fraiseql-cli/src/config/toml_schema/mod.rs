@@ -48,7 +48,7 @@ pub use security::{
 use serde::{Deserialize, Serialize};
 pub use server_settings::{DebugConfig, McpConfig, ValidationConfig};
 pub use subscriptions::{SubscriptionHooksConfig, SubscriptionsConfig};
-pub use types::{ArgumentDefinition, FieldDefinition, TypeDefinition};
+pub use types::{ArgumentDefinition, FieldDefinition, ScalarDefinition, TypeDefinition};
 
 use super::{
     expand_env_vars,
@@ -88,6 +88,10 @@ pub struct TomlSchema {
     #[serde(rename = "types")]
     pub types: BTreeMap<String, TypeDefinition>,
 
+    /// Custom scalar definitions (validation, SQL type mapping, serialization)
+    #[serde(rename = "scalars")]
+    pub scalars: BTreeMap<String, ScalarDefinition>,
+
     /// Query definitions
     #[serde(rename = "queries")]
     pub queries: BTreeMap<String, QueryDefinition>,
@@ -438,6 +442,15 @@ impl TomlSchema {
                         );
                     }
                 }
+                if let Some(ref expr) = field_def.sql_expression {
+                    if !fraiseql_core::db::is_safe_sql_expression(expr) {
+                        anyhow::bail!(
+                            "Field '{type_name}.{field_name}' has an invalid sql_expression: it \
+                             must be a single SQL expression (no ';' or comments, balanced \
+                             parentheses and quotes)"
+                        );
+                    }
+                }
             }
         }
 
@@ -450,6 +463,24 @@ impl TomlSchema {
             }
         }
 
+        // Validate custom scalars: no clash with object types, and a plain SQL type name
+        for (scalar_name, scalar_def) in &self.scalars {
+            if self.types.contains_key(scalar_name) {
+                anyhow::bail!(
+                    "Scalar '{scalar_name}' clashes with the type of the same name in \
+                     [types.{scalar_name}]"
+                );
+            }
+            if let Some(ref sql_type) = scalar_def.sql_type {
+                if !fraiseql_core::db::is_safe_sql_type_name(sql_type) {
+                    anyhow::bail!(
+                        "Scalar '{scalar_name}' has invalid sql_type '{sql_type}': expected a \
+                         type name such as \"numeric(12,2)\" or \"citext\""
+                    );
+                }
+            }
+        }
+
         // Validate federation entities reference existing types
         for entity in &self.federation.entities {
             if !self.types.contains_key(&entity.name) {
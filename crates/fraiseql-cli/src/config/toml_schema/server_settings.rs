@@ -1,5 +1,8 @@
 //! Server settings configuration for TOML schema (validation, debug, MCP).
 
+use std::collections::BTreeMap;
+
+use fraiseql_core::schema::RoleValidationLimits;
 use serde::{Deserialize, Serialize};
 
 /// MCP (Model Context Protocol) server configuration.
@@ -44,12 +47,17 @@ impl Default for McpConfig {
     }
 }
 
-/// Query validation limits (depth and complexity).
+/// Query validation limits (depth, complexity, aliases, root fields).
 ///
 /// ```toml
 /// [validation]
 /// max_query_depth = 10
 /// max_query_complexity = 100
+/// max_root_fields = 20
+/// max_introspection_depth = 12
+///
+/// [validation.roles.admin]
+/// max_query_depth = 25
 /// ```
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
@@ -68,6 +76,24 @@ pub struct ValidationConfig {
     /// `FRAISEQL_MAX_PAGE_SIZE` environment variable as an override.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_page_size: Option<u32>,
+
+    /// Maximum number of aliased fields per query. `None` uses the server default (30).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_aliases: Option<u32>,
+
+    /// Maximum number of root fields a single operation may select. `None` means unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_root_fields: Option<u32>,
+
+    /// Maximum nesting depth below `__schema` / `__type`. `None` means unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_introspection_depth: Option<u32>,
+
+    /// Per-role overrides of the limits above (`[validation.roles.<role>]`).
+    /// A caller holding several listed roles gets the most permissive value
+    /// per limit.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub roles: BTreeMap<String, RoleValidationLimits>,
 }
 
 /// Debug/development configuration.
@@ -634,6 +634,59 @@ sql_source = "v_category"
         );
     }
 
+    #[test]
+    fn test_computed_field_expression_deserializes_and_validates() {
+        let toml = r#"
+[schema]
+name = "myapp"
+version = "1.0.0"
+database_target = "postgresql"
+
+[types.User]
+sql_source = "v_user"
+
+[types.User.fields.full_name]
+type = "String"
+sql_expression = "data->>'first' || ' ' || data->>'last'"
+
+[queries.users]
+return_type = "User"
+return_array = true
+sql_source = "v_user"
+"#;
+        let schema = TomlSchema::parse_toml(toml).unwrap();
+        schema.validate().unwrap();
+        assert_eq!(
+            schema.types["User"].fields["full_name"].sql_expression.as_deref(),
+            Some("data->>'first' || ' ' || data->>'last'")
+        );
+    }
+
+    #[test]
+    fn test_computed_field_expression_rejects_multiple_statements() {
+        let toml = r#"
+[schema]
+name = "myapp"
+version = "1.0.0"
+database_target = "postgresql"
+
+[types.User]
+sql_source = "v_user"
+
+[types.User.fields.full_name]
+type = "String"
+sql_expression = "1; DROP TABLE users"
+
+[queries.users]
+return_type = "User"
+return_array = true
+sql_source = "v_user"
+"#;
+        let schema = TomlSchema::parse_toml(toml).unwrap();
+        let err = schema.validate().unwrap_err();
+        assert!(err.to_string().contains("User.full_name"), "{err}");
+    }
+
     #[test]
     fn test_hierarchy_config_rejects_empty_path_column() {
         let toml = r#"
@@ -646,4 +699,57 @@ path_column = ""
         let bad = &hierarchies["bad"];
         assert!(bad.validate().is_err());
     }
+
+    #[test]
+    fn test_scalars_section_deserializes() {
+        let toml = r#"
+[schema]
+name = "myapp"
+version = "1.0.0"
+database_target = "postgresql"
+
+[scalars.Money]
+description = "Decimal amount with two places"
+sql_type = "numeric(12,2)"
+serialize_as = "string"
+
+[[scalars.Money.validation_rules]]
+type = "pattern"
+value = { pattern = "^-?[0-9]+\\.[0-9]{2}$" }
+"#;
+        let schema = TomlSchema::parse_toml(toml).unwrap();
+        schema.validate().unwrap();
+        let money = &schema.scalars["Money"];
+        assert_eq!(money.sql_type.as_deref(), Some("numeric(12,2)"));
+        assert_eq!(
+            money.serialize_as,
+            Some(fraiseql_core::validation::ScalarSerialization::String)
+        );
+        assert_eq!(money.validation_rules.len(), 1);
+    }
+
+    #[test]
+    fn test_scalars_reject_unsafe_sql_type() {
+        let toml = r#"
+[scalars.Money]
+sql_type = "numeric; DROP TABLE users"
+"#;
+        let schema = TomlSchema::parse_toml(toml).unwrap();
+        let err = schema.validate().unwrap_err();
+        assert!(err.to_string().contains("invalid sql_type"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn test_scalars_reject_clash_with_type() {
+        let toml = r#"
+[types.Money]
+sql_source = "v_money"
+
+[scalars.Money]
+sql_type = "numeric"
+"#;
+        let schema = TomlSchema::parse_toml(toml).unwrap();
+        let err = schema.validate().unwrap_err();
+        assert!(err.to_string().contains("clashes"), "unexpected error: {err}");
+    }
 }
@@ -2,6 +2,7 @@
 
 use std::collections::BTreeMap;
 
+use fraiseql_core::validation::{ScalarSerialization, ValidationRule};
 use serde::{Deserialize, Serialize};
 
 /// Type definition in TOML
@@ -32,16 +33,20 @@ impl Default for TypeDefinition {
 pub struct FieldDefinition {
     /// GraphQL field type (ID, String, Int, Boolean, DateTime, etc.)
     #[serde(rename = "type")]
-    pub field_type:  String,
+    pub field_type:     String,
     /// Whether field can be null
     #[serde(default)]
-    pub nullable:    bool,
+    pub nullable:       bool,
     /// Field description
-    pub description: Option<String>,
+    pub description:    Option<String>,
     /// Named hierarchy reference for ID-based ltree operators.
     /// References a key in `[hierarchies.<name>]` config.
     #[serde(default)]
-    pub hierarchy:   Option<String>,
+    pub hierarchy:      Option<String>,
+    /// SQL expression computing this field from the query's `sql_source` row,
+    /// e.g. `"data->>'first' || ' ' || data->>'last'"`.
+    #[serde(default)]
+    pub sql_expression: Option<String>,
 }
 
 /// Argument definition
@@ -61,3 +66,26 @@ pub struct ArgumentDefinition {
     /// Argument description
     pub description: Option<String>,
 }
+
+/// Custom scalar definition (`[scalars.<Name>]`)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScalarDefinition {
+    /// Scalar description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description:      Option<String>,
+    /// URL to specification/RFC (GraphQL spec §3.5.1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specified_by_url: Option<String>,
+    /// Base type for type aliases (e.g., "String")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_type:        Option<String>,
+    /// SQL type used when filtering on this scalar (e.g., "numeric(12,2)")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_type:         Option<String>,
+    /// JSON representation on the wire (`string`, `number`, `integer`, `boolean`, `object`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serialize_as:     Option<ScalarSerialization>,
+    /// Validation rules applied to every input value
+    pub validation_rules: Vec<ValidationRule>,
+}
@@ -229,6 +229,31 @@ fn analyze_success_schema() -> Value {
                                 "suggestion": { "type": "string" }
                             }
                         }
+                    },
+                    "total_fields": { "type": "integer" },
+                    "used_fields": { "type": "integer" },
+                    "unused_fields": {
+                        "type": "array",
+                        "items": { "type": "string" }
+                    },
+                    "deprecated_in_use": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "coordinate": { "type": "string" },
+                                "reason": { "type": ["string", "null"] },
+                                "count": { "type": "integer" },
+                                "clients": {
+                                    "type": "array",
+                                    "items": { "type": "string" }
+                                }
+                            }
+                        }
+                    },
+                    "unknown_fields": {
+                        "type": "array",
+                        "items": { "type": "string" }
                     }
                 }
             }
@@ -37,6 +37,7 @@ pub async fn run() {
             output,
             check,
             skip_hash,
+            sign_key,
             database,
             emit_ddl,
             check_migrations,
@@ -54,6 +55,7 @@ pub async fn run() {
                 emit_ddl.as_deref(),
                 check_migrations,
                 skip_hash,
+                sign_key.as_deref(),
             )
             .await
         },
@@ -81,12 +83,21 @@ pub async fn run() {
             Err(e) => Err(e),
         },
 
-        Commands::Analyze { schema } => match commands::analyze::run(&schema) {
-            Ok(result) => {
-                println!("{}", output::OutputFormatter::new(cli.json, cli.quiet).format(&result));
-                Ok(())
-            },
-            Err(e) => Err(e),
+        Commands::Analyze { schema, usage } => {
+            let result = match usage {
+                Some(database_url) => commands::analyze::run_usage(&schema, &database_url).await,
+                None => commands::analyze::run(&schema),
+            };
+            match result {
+                Ok(result) => {
+                    println!(
+                        "{}",
+                        output::OutputFormatter::new(cli.json, cli.quiet).format(&result)
+                    );
+                    Ok(())
+                },
+                Err(e) => Err(e),
+            }
         },
 
         Commands::DependencyGraph { schema, format } => {
@@ -301,14 +312,30 @@ pub async fn run() {
         },
 
         Commands::GenerateClient { language } => match language {
-            GenerateClientCommands::Typescript { schema, out, force } => {
-                commands::generate_client::run(
-                    commands::generate_client::ClientLanguage::TypeScript,
-                    schema.as_deref(),
-                    &out,
-                    force,
-                )
-            },
+            GenerateClientCommands::Typescript {
+                schema,
+                out,
+                force,
+                incremental,
+            } => commands::generate_client::run(
+                commands::generate_client::ClientLanguage::TypeScript,
+                schema.as_deref(),
+                &out,
+                force,
+                incremental,
+            ),
+            GenerateClientCommands::Python {
+                schema,
+                out,
+                force,
+                incremental,
+            } => commands::generate_client::run(
+                commands::generate_client::ClientLanguage::Python,
+                schema.as_deref(),
+                &out,
+                force,
+                incremental,
+            ),
         },
 
         Commands::Init {
@@ -438,6 +465,14 @@ pub async fn run() {
             .await
         },
 
+        #[cfg(feature = "run-server")]
+        Commands::Serve {
+            input,
+            database,
+            port,
+            bind,
+        } => Box::pin(commands::serve::run(input.as_deref(), database, port, bind)).await,
+
         Commands::Watch {
             input,
             output,
@@ -489,6 +524,44 @@ pub async fn run() {
             Box::pin(commands::query::run(&query, &schema, database, variables, dry_run)).await
         },
 
+        Commands::Repl {
+            endpoint,
+            token,
+            format,
+            timing,
+        } => {
+            commands::repl::run(commands::repl::ReplArgs {
+                endpoint,
+                token,
+                format,
+                timing,
+            })
+            .await
+        },
+
+        Commands::Seed {
+            entity,
+            count,
+            schema,
+            database,
+            via,
+            table,
+            seed,
+            batch_size,
+        } => {
+            Box::pin(commands::seed::run(commands::seed::SeedArgs {
+                schema,
+                database,
+                entity,
+                count,
+                via,
+                table,
+                seed,
+                batch_size,
+            }))
+            .await
+        },
+
         Commands::Doctor {
             config,
             schema,
@@ -633,6 +706,97 @@ pub async fn run() {
                 Err(error) => Err(error),
             },
         },
+
+        Commands::Search(crate::cli::SearchCommands::Reindex {
+            entity,
+            backend,
+            url,
+            index,
+            api_key,
+            schema,
+            view,
+            db_url,
+            tenant,
+            batch_size,
+            rate_limit,
+            state_file,
+            resume,
+        }) => {
+            commands::search::reindex(commands::search::ReindexArgs {
+                entity,
+                schema,
+                view,
+                database: db_url,
+                backend,
+                url,
+                index,
+                api_key,
+                tenant,
+                batch_size,
+                rate_limit,
+                state_file,
+                resume,
+                json: cli.json,
+            })
+            .await
+        },
+
+        Commands::Observers(command) => match command {
+            crate::cli::ObserversCommands::Pause {
+                name,
+                max_backlog,
+                reason,
+                server,
+                token,
+            } => {
+                commands::observers::pause(
+                    &server,
+                    token.as_deref(),
+                    &name,
+                    max_backlog,
+                    reason.as_deref(),
+                    cli.json,
+                )
+                .await
+            },
+            crate::cli::ObserversCommands::Resume {
+                name,
+                server,
+                token,
+            } => commands::observers::resume(&server, token.as_deref(), &name, cli.json).await,
+            crate::cli::ObserversCommands::Paused { server, token } => {
+                commands::observers::paused(&server, token.as_deref(), cli.json).await
+            },
+        },
+
+        Commands::Test(crate::cli::TestCommands::Sql {
+            dir,
+            schema,
+            update,
+        }) => {
+            Box::pin(commands::test_sql::run(commands::test_sql::TestSqlArgs {
+                dir,
+                schema,
+                update,
+            }))
+            .await
+        },
+
+        #[cfg(feature = "run-server")]
+        Commands::Backup(command) => match command {
+            crate::cli::BackupCommands::Run { config, database } => {
+                commands::backup::run(&config, database).await
+            },
+            crate::cli::BackupCommands::List { config } => {
+                commands::backup::list(&config, cli.json).await
+            },
+            crate::cli::BackupCommands::Restore {
+                backup,
+                config,
+                database,
+                dry_run,
+            } => commands::backup::restore(&config, &backup, database, dry_run, cli.json).await,
+        },
     };
 
     if let Err(e) = result {
@@ -0,0 +1,63 @@
+//! Filter input types for array fields.
+//!
+//! Every scalar or enum list field (`tags: [String]`) can be filtered with the
+//! array operators `contains` (`@>`), `containedBy` (`<@`), `overlaps` (`&&`)
+//! and the `length*` comparisons (`jsonb_array_length`). One `<Element>ArrayFilter`
+//! input type is generated per element type and shared by all array fields of
+//! that type, documenting the operators accepted inside `where`.
+
+use std::collections::BTreeSet;
+
+use fraiseql_core::schema::{
+    CompiledSchema, FieldType, InputFieldDefinition, InputObjectDefinition,
+};
+
+/// Length comparisons accepted on array fields, with their descriptions.
+const LENGTH_OPERATORS: &[(&str, &str)] = &[
+    ("lengthEq", "Array length equals"),
+    ("lengthNeq", "Array length does not equal"),
+    ("lengthGt", "Array length greater than"),
+    ("lengthGte", "Array length greater than or equal"),
+    ("lengthLt", "Array length less than"),
+    ("lengthLte", "Array length less than or equal"),
+];
+
+/// Add an `<Element>ArrayFilter` input type for each element type of a scalar or
+/// enum list field. An input type already declared under that name is kept.
+pub(super) fn inject_array_filter_types(schema: &mut CompiledSchema) {
+    let elements: BTreeSet<String> = schema
+        .types
+        .iter()
+        .flat_map(|t| &t.fields)
+        .filter_map(|f| f.field_type.inner_type())
+        .filter(|inner| {
+            (inner.is_scalar() && !inner.is_vector()) || matches!(inner, FieldType::Enum(_))
+        })
+        .map(FieldType::to_graphql_string)
+        .collect();
+
+    for element in elements {
+        let name = format!("{element}ArrayFilter");
+        if schema.input_types.iter().any(|t| t.name == name) {
+            continue;
+        }
+        let list = format!("[{element}!]");
+        let mut fields = vec![
+            InputFieldDefinition::new("contains", &list)
+                .with_description("Contains all of these elements (@>)"),
+            InputFieldDefinition::new("containedBy", &list)
+                .with_description("Every element is one of these (<@)"),
+            InputFieldDefinition::new("overlaps", &list)
+                .with_description("Shares at least one element with these (&&)"),
+        ];
+        fields.extend(LENGTH_OPERATORS.iter().map(|(op, description)| {
+            InputFieldDefinition::new(*op, "Int").with_description(*description)
+        }));
+        fields.push(InputFieldDefinition::new("isnull", "Boolean").with_description("Is null"));
+        schema.input_types.push(
+            InputObjectDefinition::new(name)
+                .with_description(format!("Filter operations for [{element}] fields"))
+                .with_fields(fields),
+        );
+    }
+}
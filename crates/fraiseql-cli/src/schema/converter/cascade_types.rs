@@ -323,6 +323,7 @@ fn synth_field(name: &str, field_type: FieldType, nullable: bool, desc: &str) ->
         authorize: false,
         encryption: None,
         hierarchy: None,
+        sql_expression: None,
     }
 }
 
@@ -343,6 +344,12 @@ fn synth_type(name: &str, fields: Vec<FieldDefinition>, desc: &str) -> TypeDefin
         embedded: false,
         relationships: Vec::new(),
         subscription_policy: None,
+        tenant_column: None,
+        default_order_by: Vec::new(),
+        search: None,
+        order_by_aggregates: Vec::new(),
+        soft_delete_field: None,
+        include_deleted_scope: None,
     }
 }
 
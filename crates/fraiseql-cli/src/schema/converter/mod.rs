@@ -2,6 +2,7 @@
 //!
 //! Converts `IntermediateSchema` (language-agnostic) to `CompiledSchema` (Rust-specific)
 
+mod array_filters;
 mod cascade_types;
 mod directives;
 mod identity;
@@ -10,8 +11,10 @@ mod mutation_error_union;
 mod mutations;
 mod queries;
 mod relay;
+mod soft_delete;
 mod subscriptions;
 pub(crate) mod tenancy;
+mod text_search;
 mod types;
 
 #[cfg(test)]
@@ -272,6 +275,12 @@ impl SchemaConverter {
         // conformant `id: ID` on every entity. Wire-transparent (a UUID is an `ID`).
         identity::normalize_entity_identity(&mut compiled);
 
+        // Full-text search: `rank` on searchable types, `search` on their list queries.
+        text_search::wire_text_search(&mut compiled);
+
+        // Soft delete: `includeDeleted` on queries returning soft-deleted types.
+        soft_delete::wire_soft_delete(&mut compiled);
+
         // Inject synthetic Relay types (PageInfo, Node interface, XxxConnection, XxxEdge).
         relay::inject_relay_types(&mut compiled)?;
 
@@ -280,6 +289,9 @@ impl SchemaConverter {
         compile_rich_filters(&mut compiled, &rich_filter_config)
             .context("Failed to compile rich filter types")?;
 
+        // Filter input types for array fields (`contains`, `overlaps`, `lengthGt`, ...).
+        array_filters::inject_array_filter_types(&mut compiled);
+
         // Inject the changelog GraphQL surface (EntityChangeLog / TransportCheckpoint
         // types + cursor query + point lookup + upsert mutation) when opted in.
         fraiseql_core::schema::inject_changelog(&mut compiled);
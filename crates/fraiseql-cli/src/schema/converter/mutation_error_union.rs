@@ -125,12 +125,13 @@ fn mutation_error_type() -> TypeDefinition {
         authorize: false,
         encryption: None,
         hierarchy: None,
+        sql_expression: None,
     };
     TypeDefinition {
-        name:                ERROR_TYPE.into(),
-        sql_source:          String::new().into(), // synthetic — never queried via SQL
-        jsonb_column:        String::new(),
-        fields:              vec![
+        name:                  ERROR_TYPE.into(),
+        sql_source:            String::new().into(), // synthetic — never queried via SQL
+        jsonb_column:          String::new(),
+        fields:                vec![
             field(
                 "status",
                 FieldType::String,
@@ -146,19 +147,25 @@ fn mutation_error_type() -> TypeDefinition {
                 "Error classification (the same value surfaced as `status`).",
             ),
         ],
-        description:         Some(
+        description:           Some(
             "Shared mutation error type (auto-synthesized by auto_error_union). Populated from \
              the mutation_response composite."
                 .to_string(),
         ),
-        sql_projection_hint: None,
-        implements:          Vec::new(),
-        requires_role:       None,
-        is_error:            true,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       Vec::new(),
-        subscription_policy: None,
+        sql_projection_hint:   None,
+        implements:            Vec::new(),
+        requires_role:         None,
+        is_error:              true,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         Vec::new(),
+        subscription_policy:   None,
+        tenant_column:         None,
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     }
 }
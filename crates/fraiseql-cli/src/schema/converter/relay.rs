@@ -52,6 +52,7 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
             authorize:      false,
             encryption:     None,
             hierarchy:      None,
+            sql_expression: None,
         };
         schema.interfaces.push(
             InterfaceDefinition::new("Node")
@@ -77,12 +78,13 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
             authorize: false,
             encryption: None,
             hierarchy: None,
+            sql_expression: None,
         };
         let page_info = TypeDefinition {
-            name:                "PageInfo".into(),
-            sql_source:          String::new().into(), // synthetic — no DB source
-            jsonb_column:        String::new(),
-            fields:              vec![
+            name:                  "PageInfo".into(),
+            sql_source:            String::new().into(), // synthetic — no DB source
+            jsonb_column:          String::new(),
+            fields:                vec![
                 make_field(
                     "hasNextPage",
                     FieldType::Boolean,
@@ -108,16 +110,22 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
                     "Cursor for the last item in the current page.",
                 ),
             ],
-            description:         Some("Relay pagination info.".to_string()),
-            sql_projection_hint: None,
-            implements:          Vec::new(),
-            requires_role:       None,
-            is_error:            false,
-            relay:               false,
-            internal:            false,
-            embedded:            false,
-            relationships:       Vec::new(),
-            subscription_policy: None,
+            description:           Some("Relay pagination info.".to_string()),
+            sql_projection_hint:   None,
+            implements:            Vec::new(),
+            requires_role:         None,
+            is_error:              false,
+            relay:                 false,
+            internal:              false,
+            embedded:              false,
+            relationships:         Vec::new(),
+            subscription_policy:   None,
+            tenant_column:         None,
+            default_order_by:      Vec::new(),
+            search:                None,
+            order_by_aggregates:   Vec::new(),
+            soft_delete_field:     None,
+            include_deleted_scope: None,
         };
         schema.types.push(page_info);
     }
@@ -144,6 +152,7 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
         authorize: false,
         encryption: None,
         hierarchy: None,
+        sql_expression: None,
     };
 
     let mut new_types: Vec<TypeDefinition> = Vec::new();
@@ -156,10 +165,10 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
         let has_edge = schema.types.iter().any(|t| t.name == edge_name);
         if !has_edge {
             new_types.push(TypeDefinition {
-                name:                edge_name.clone().into(),
-                sql_source:          String::new().into(),
-                jsonb_column:        String::new(),
-                fields:              vec![
+                name:                  edge_name.clone().into(),
+                sql_source:            String::new().into(),
+                jsonb_column:          String::new(),
+                fields:                vec![
                     make_field(
                         "cursor",
                         FieldType::String,
@@ -175,26 +184,34 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
                         "The item at this edge.",
                     ),
                 ],
-                description:         Some(format!("An edge in the {type_name} Relay connection.")),
-                sql_projection_hint: None,
-                implements:          Vec::new(),
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       Vec::new(),
-                subscription_policy: None,
+                description:           Some(format!(
+                    "An edge in the {type_name} Relay connection."
+                )),
+                sql_projection_hint:   None,
+                implements:            Vec::new(),
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         Vec::new(),
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             });
         }
 
         let has_conn = schema.types.iter().any(|t| t.name == conn_name);
         if !has_conn {
             new_types.push(TypeDefinition {
-                name:                conn_name.into(),
-                sql_source:          String::new().into(),
-                jsonb_column:        String::new(),
-                fields:              vec![
+                name:                  conn_name.into(),
+                sql_source:            String::new().into(),
+                jsonb_column:          String::new(),
+                fields:                vec![
                     make_field(
                         "edges",
                         FieldType::List(Box::new(FieldType::Object(edge_name))),
@@ -214,18 +231,24 @@ pub(super) fn inject_relay_types(schema: &mut CompiledSchema) -> anyhow::Result<
                         "Total number of items matching the filter.",
                     ),
                 ],
-                description:         Some(format!(
+                description:           Some(format!(
                     "A Relay connection for paginating {type_name} records."
                 )),
-                sql_projection_hint: None,
-                implements:          Vec::new(),
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       Vec::new(),
-                subscription_policy: None,
+                sql_projection_hint:   None,
+                implements:            Vec::new(),
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         Vec::new(),
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             });
         }
     }
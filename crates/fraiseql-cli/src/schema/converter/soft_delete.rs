@@ -0,0 +1,25 @@
+//! Soft-delete wiring for types that declare a `soft_delete_field`.
+//!
+//! Every query returning such a type gains the optional `includeDeleted`
+//! argument via
+//! [`AutoParams::has_include_deleted`](fraiseql_core::schema::AutoParams::has_include_deleted);
+//! the runtime excludes deleted rows unless it is passed.
+
+use std::collections::HashSet;
+
+use fraiseql_core::schema::CompiledSchema;
+
+/// Enable `includeDeleted` on the queries returning soft-deleted types.
+pub(super) fn wire_soft_delete(schema: &mut CompiledSchema) {
+    let soft_deleted: HashSet<String> = schema
+        .types
+        .iter()
+        .filter(|t| t.soft_delete_field.is_some())
+        .map(|t| t.name.to_string())
+        .collect();
+    for query in &mut schema.queries {
+        if soft_deleted.contains(&query.return_type) {
+            query.auto_params.has_include_deleted = true;
+        }
+    }
+}
@@ -6,6 +6,10 @@
 //!    - If `inject` is empty → auto-adds `{ <field>: jwt:<tenant_claim> }`.
 //!    - If `inject` is non-empty but missing the annotated field → compile error.
 //! 3. When no types have `@tenant_id` annotations → warning.
+//!
+//! Independently of `inject`, the annotated field is compiled into
+//! `TypeDefinition::tenant_column`; the executor then scopes every read of the
+//! type (GraphQL, Relay, `node`, REST, `_entities`) to the caller's tenant.
 
 use std::collections::{HashMap, HashSet};
 
@@ -157,6 +157,95 @@ fn convert_threads_type_level_sql_source_into_compiled() {
     );
 }
 
+#[test]
+fn convert_appends_id_tiebreaker_to_default_order_by() {
+    use fraiseql_core::{schema::DefaultOrderBy, types::OrderDirection};
+
+    let json = r#"{
+        "types": [{
+            "name": "Post",
+            "fields": [
+                { "name": "id", "type": "ID", "nullable": false },
+                { "name": "createdAt", "type": "DateTime", "nullable": false }
+            ],
+            "default_order_by": [{ "field": "createdAt", "direction": "DESC" }]
+        }]
+    }"#;
+    let intermediate: IntermediateSchema = serde_json::from_str(json).unwrap();
+
+    let compiled = SchemaConverter::convert(intermediate).expect("convert");
+
+    assert_eq!(
+        compiled.types[0].default_order_by,
+        vec![
+            DefaultOrderBy::new("createdAt", OrderDirection::Desc),
+            DefaultOrderBy::new("id", OrderDirection::Asc),
+        ]
+    );
+}
+
+#[test]
+fn convert_enables_search_on_list_queries_of_searchable_types() {
+    use fraiseql_core::schema::FieldType;
+
+    let json = r#"{
+        "types": [{
+            "name": "Post",
+            "fields": [
+                { "name": "id", "type": "ID", "nullable": false },
+                { "name": "title", "type": "String", "nullable": false }
+            ],
+            "search": { "fields": [{ "field": "title", "weight": "A" }] }
+        }],
+        "queries": [
+            { "name": "posts", "return_type": "Post", "returns_list": true, "sql_source": "v_post" },
+            { "name": "post", "return_type": "Post", "sql_source": "v_post" }
+        ]
+    }"#;
+    let intermediate: IntermediateSchema = serde_json::from_str(json).unwrap();
+
+    let compiled = SchemaConverter::convert(intermediate).expect("convert");
+
+    assert_eq!(compiled.types[0].search.as_ref().unwrap().language, "english");
+    let rank = compiled.types[0].fields.iter().find(|f| f.name == "rank").unwrap();
+    assert_eq!(rank.field_type, FieldType::Float);
+    assert!(rank.nullable);
+    assert!(compiled.queries[0].auto_params.has_search);
+    assert!(!compiled.queries[1].auto_params.has_search, "single-row query");
+}
+
+#[test]
+fn convert_enables_include_deleted_on_queries_of_soft_deleted_types() {
+    let json = r#"{
+        "types": [
+            {
+                "name": "Post",
+                "fields": [
+                    { "name": "id", "type": "ID", "nullable": false },
+                    { "name": "deleted_at", "type": "DateTime", "nullable": true }
+                ],
+                "soft_delete_field": "deleted_at",
+                "include_deleted_scope": "admin:posts"
+            },
+            { "name": "Tag", "fields": [{ "name": "id", "type": "ID", "nullable": false }] }
+        ],
+        "queries": [
+            { "name": "posts", "return_type": "Post", "returns_list": true, "sql_source": "v_post" },
+            { "name": "post", "return_type": "Post", "sql_source": "v_post" },
+            { "name": "tags", "return_type": "Tag", "returns_list": true, "sql_source": "v_tag" }
+        ]
+    }"#;
+    let intermediate: IntermediateSchema = serde_json::from_str(json).unwrap();
+
+    let compiled = SchemaConverter::convert(intermediate).expect("convert");
+
+    assert_eq!(compiled.types[0].soft_delete_field.as_deref(), Some("deleted_at"));
+    assert_eq!(compiled.types[0].include_deleted_scope.as_deref(), Some("admin:posts"));
+    assert!(compiled.queries[0].auto_params.has_include_deleted);
+    assert!(compiled.queries[1].auto_params.has_include_deleted, "single-row query");
+    assert!(!compiled.queries[2].auto_params.has_include_deleted);
+}
+
 #[test]
 fn test_convert_minimal_schema() {
     let intermediate = IntermediateSchema {
@@ -210,6 +299,9 @@ fn convert_field_maps_authorize_true() {
         on_deny:        None,
         authorize:      Some(true),
         hierarchy:      None,
+        sql_expression: None,
+        encrypted:      None,
+        encryption_key: None,
     };
     let compiled = SchemaConverter::convert_field(intermediate).unwrap();
     assert!(compiled.authorize, "authorize: Some(true) must compile to authorize == true");
@@ -227,11 +319,43 @@ fn convert_field_authorize_absent_defaults_false() {
         on_deny:        None,
         authorize:      None,
         hierarchy:      None,
+        sql_expression: None,
+        encrypted:      None,
+        encryption_key: None,
     };
     let compiled = SchemaConverter::convert_field(intermediate).unwrap();
     assert!(!compiled.authorize, "absent authorize must compile to authorize == false");
 }
 
+#[test]
+fn convert_field_maps_encrypted_to_encryption_config() {
+    let mut intermediate = IntermediateField {
+        name:           "ssn".to_string(),
+        field_type:     "String".to_string(),
+        nullable:       true,
+        description:    None,
+        directives:     None,
+        requires_scope: None,
+        on_deny:        None,
+        authorize:      None,
+        hierarchy:      None,
+        sql_expression: None,
+        encrypted:      Some(true),
+        encryption_key: None,
+    };
+    let compiled = SchemaConverter::convert_field(intermediate.clone()).unwrap();
+    let encryption = compiled.encryption.expect("encrypted: true must compile to encryption");
+    assert_eq!(encryption.key_reference, "fraiseql/field-keys/ssn");
+    assert_eq!(encryption.algorithm, "AES-256-GCM");
+
+    intermediate.encryption_key = Some("keys/employee-ssn".to_string());
+    let compiled = SchemaConverter::convert_field(intermediate.clone()).unwrap();
+    assert_eq!(compiled.encryption.unwrap().key_reference, "keys/employee-ssn");
+
+    intermediate.encrypted = None;
+    assert!(SchemaConverter::convert_field(intermediate).unwrap().encryption.is_none());
+}
+
 // ── #434: list field types must compile to FieldType::List ──────────────
 //
 // `parse_field_type` matched built-in scalar names and routed everything else —
@@ -301,6 +425,9 @@ fn convert_field_list_type_compiles_to_list() {
         on_deny:        None,
         authorize:      None,
         hierarchy:      None,
+        sql_expression: None,
+        encrypted:      None,
+        encryption_key: None,
     };
     let compiled = SchemaConverter::convert_field(intermediate).unwrap();
     assert_eq!(
@@ -329,6 +456,9 @@ fn test_convert_type_with_fields() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "name".to_string(),
@@ -340,6 +470,9 @@ fn test_convert_type_with_fields() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            Some("User type".to_string()),
@@ -350,6 +483,11 @@ fn test_convert_type_with_fields() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -462,6 +600,11 @@ fn test_convert_query_with_arguments() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -544,6 +687,11 @@ fn test_list_query_without_auto_params_defaults_to_all() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -616,6 +764,11 @@ fn test_single_item_query_without_auto_params_defaults_to_none() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -695,6 +848,9 @@ fn test_convert_field_with_deprecated_directive() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "id".to_string(),
@@ -706,6 +862,9 @@ fn test_convert_field_with_deprecated_directive() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -716,6 +875,11 @@ fn test_convert_field_with_deprecated_directive() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -1190,8 +1354,8 @@ fn test_convert_interface() {
         enums:             vec![],
         input_types:       vec![],
         interfaces:        vec![IntermediateInterface {
-            name:        "Node".to_string(),
-            fields:      vec![IntermediateField {
+            name:          "Node".to_string(),
+            fields:        vec![IntermediateField {
                 name:           "id".to_string(),
                 field_type:     "ID".to_string(),
                 nullable:       false,
@@ -1201,8 +1365,12 @@ fn test_convert_interface() {
                 on_deny:        None,
                 authorize:      None,
                 hierarchy:      None,
+                sql_expression: None,
+                encrypted:      None,
+                encryption_key: None,
             }],
-            description: Some("An object with a globally unique ID".to_string()),
+            description:   Some("An object with a globally unique ID".to_string()),
+            discriminator: None,
         }],
         unions:            vec![],
         queries:           vec![],
@@ -1262,6 +1430,9 @@ fn test_convert_type_implements_interface() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "name".to_string(),
@@ -1273,6 +1444,9 @@ fn test_convert_type_implements_interface() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -1283,12 +1457,17 @@ fn test_convert_type_implements_interface() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
         interfaces:        vec![IntermediateInterface {
-            name:        "Node".to_string(),
-            fields:      vec![IntermediateField {
+            name:          "Node".to_string(),
+            fields:        vec![IntermediateField {
                 name:           "id".to_string(),
                 field_type:     "ID".to_string(),
                 nullable:       false,
@@ -1298,8 +1477,12 @@ fn test_convert_type_implements_interface() {
                 on_deny:        None,
                 authorize:      None,
                 hierarchy:      None,
+                sql_expression: None,
+                encrypted:      None,
+                encryption_key: None,
             }],
-            description: None,
+            description:   None,
+            discriminator: None,
         }],
         unions:            vec![],
         queries:           vec![],
@@ -1358,6 +1541,9 @@ fn test_validate_unknown_interface() {
                 on_deny:        None,
                 authorize:      None,
                 hierarchy:      None,
+                sql_expression: None,
+                encrypted:      None,
+                encryption_key: None,
             }],
             description:            None,
             implements:             vec!["UnknownInterface".to_string()],
@@ -1367,6 +1553,11 @@ fn test_validate_unknown_interface() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -1424,6 +1615,9 @@ fn test_validate_missing_interface_field() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -1434,12 +1628,17 @@ fn test_validate_missing_interface_field() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
         interfaces:        vec![IntermediateInterface {
-            name:        "Node".to_string(),
-            fields:      vec![IntermediateField {
+            name:          "Node".to_string(),
+            fields:        vec![IntermediateField {
                 name:           "id".to_string(),
                 field_type:     "ID".to_string(),
                 nullable:       false,
@@ -1449,8 +1648,12 @@ fn test_validate_missing_interface_field() {
                 on_deny:        None,
                 authorize:      None,
                 hierarchy:      None,
+                sql_expression: None,
+                encrypted:      None,
+                encryption_key: None,
             }],
-            description: None,
+            description:   None,
+            discriminator: None,
         }],
         unions:            vec![],
         queries:           vec![],
@@ -1504,6 +1707,9 @@ fn test_convert_union() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 }],
                 description:            None,
                 implements:             vec![],
@@ -1513,6 +1719,11 @@ fn test_convert_union() {
                 embedded:               false,
                 subscribable_tables:    None,
                 subscribable_pre_image: false,
+                default_order_by:       Vec::new(),
+                search:                 None,
+                order_by_aggregates:    Vec::new(),
+                soft_delete_field:      None,
+                include_deleted_scope:  None,
             },
             IntermediateType {
                 name:                   "Post".to_string(),
@@ -1527,6 +1738,9 @@ fn test_convert_union() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 }],
                 description:            None,
                 implements:             vec![],
@@ -1536,15 +1750,21 @@ fn test_convert_union() {
                 embedded:               false,
                 subscribable_tables:    None,
                 subscribable_pre_image: false,
+                default_order_by:       Vec::new(),
+                search:                 None,
+                order_by_aggregates:    Vec::new(),
+                soft_delete_field:      None,
+                include_deleted_scope:  None,
             },
         ],
         enums:             vec![],
         input_types:       vec![],
         interfaces:        vec![],
         unions:            vec![IntermediateUnion {
-            name:         "SearchResult".to_string(),
-            member_types: vec!["User".to_string(), "Post".to_string()],
-            description:  Some("Result from a search query".to_string()),
+            name:          "SearchResult".to_string(),
+            member_types:  vec!["User".to_string(), "Post".to_string()],
+            description:   Some("Result from a search query".to_string()),
+            discriminator: Some("kind".to_string()),
         }],
         queries:           vec![],
         mutations:         vec![],
@@ -1579,6 +1799,7 @@ fn test_convert_union() {
     assert_eq!(union_def.name, "SearchResult");
     assert_eq!(union_def.member_types, vec!["User", "Post"]);
     assert_eq!(union_def.description, Some("Result from a search query".to_string()));
+    assert_eq!(union_def.discriminator.as_deref(), Some("kind"));
 }
 
 #[test]
@@ -1602,6 +1823,9 @@ fn test_convert_field_requires_scope() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "name".to_string(),
@@ -1613,6 +1837,9 @@ fn test_convert_field_requires_scope() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "salary".to_string(),
@@ -1624,6 +1851,9 @@ fn test_convert_field_requires_scope() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "ssn".to_string(),
@@ -1635,6 +1865,9 @@ fn test_convert_field_requires_scope() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -1645,6 +1878,11 @@ fn test_convert_field_requires_scope() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -1781,6 +2019,11 @@ mod tenancy_tests {
             embedded: false,
             subscribable_tables: None,
             subscribable_pre_image: false,
+            default_order_by: Vec::new(),
+            search: None,
+            order_by_aggregates: Vec::new(),
+            soft_delete_field: None,
+            include_deleted_scope: None,
         }
     }
 
@@ -1795,6 +2038,9 @@ mod tenancy_tests {
             on_deny:        None,
             authorize:      None,
             hierarchy:      None,
+            sql_expression: None,
+            encrypted:      None,
+            encryption_key: None,
         }
     }
 
@@ -1812,6 +2058,9 @@ mod tenancy_tests {
             on_deny:        None,
             authorize:      None,
             hierarchy:      None,
+            sql_expression: None,
+            encrypted:      None,
+            encryption_key: None,
         }
     }
 
@@ -2407,9 +2656,10 @@ mod tenancy_tests {
     /// An interface named `Node` with a single `id: ID!` field, for return-type tests.
     fn node_interface() -> crate::schema::intermediate::IntermediateInterface {
         crate::schema::intermediate::IntermediateInterface {
-            name:        "Node".to_string(),
-            fields:      vec![make_field("id", "ID")],
-            description: None,
+            name:          "Node".to_string(),
+            fields:        vec![make_field("id", "ID")],
+            description:   None,
+            discriminator: None,
         }
     }
 
@@ -2528,6 +2778,20 @@ mod tenancy_tests {
         );
         validate_tenant_annotations(&mut schema, "tenant_id").unwrap();
     }
+
+    // ── Compiled tenant column ──────────────────────────────────────────
+
+    #[test]
+    fn compiled_type_carries_tenant_column() {
+        let scoped =
+            make_type("Order", vec![make_field("id", "Int"), make_tenant_id_field("org_id")]);
+        let compiled = super::super::SchemaConverter::convert_type(scoped).unwrap();
+        assert_eq!(compiled.tenant_column.as_deref(), Some("org_id"));
+
+        let unscoped = make_type("Post", vec![make_field("id", "Int")]);
+        let compiled = super::super::SchemaConverter::convert_type(unscoped).unwrap();
+        assert_eq!(compiled.tenant_column, None);
+    }
 }
 
 // ── converter types tests ───────────────────────────────────────────────────
@@ -2714,6 +2978,9 @@ mod changelog_cascade_conformance_tests {
             on_deny:        None,
             authorize:      None,
             hierarchy:      None,
+            sql_expression: None,
+            encrypted:      None,
+            encryption_key: None,
         }
     }
 
@@ -3099,3 +3366,33 @@ fn absent_sources_convert_to_an_empty_list() {
     let compiled = SchemaConverter::convert(IntermediateSchema::default()).unwrap();
     assert!(compiled.sources.is_empty());
 }
+
+#[test]
+fn convert_generates_array_filter_input_per_element_type() {
+    let json = r#"{
+        "types": [{
+            "name": "Post",
+            "fields": [
+                { "name": "id", "type": "ID", "nullable": false },
+                { "name": "tags", "type": "[String]", "nullable": false },
+                { "name": "labels", "type": "[String]", "nullable": true },
+                { "name": "scores", "type": "[Int]", "nullable": true }
+            ]
+        }]
+    }"#;
+    let intermediate: IntermediateSchema = serde_json::from_str(json).unwrap();
+
+    let compiled = SchemaConverter::convert(intermediate).expect("convert");
+
+    let names: Vec<&str> = compiled
+        .input_types
+        .iter()
+        .filter(|t| t.name.ends_with("ArrayFilter"))
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(names, ["IntArrayFilter", "StringArrayFilter"]);
+    let filter = compiled.input_types.iter().find(|t| t.name == "StringArrayFilter").unwrap();
+    assert_eq!(filter.find_field("contains").unwrap().field_type, "[String!]");
+    assert_eq!(filter.find_field("overlaps").unwrap().field_type, "[String!]");
+    assert_eq!(filter.find_field("lengthGt").unwrap().field_type, "Int");
+}
@@ -0,0 +1,30 @@
+//! Full-text search wiring for types that declare a `search` document.
+//!
+//! A searchable type gains a nullable `rank: Float` field (the row's relevance,
+//! `null` outside a searched query), and every non-Relay list query returning
+//! it gains the optional `search` argument via
+//! [`AutoParams::has_search`](fraiseql_core::schema::AutoParams::has_search).
+
+use std::collections::HashSet;
+
+use fraiseql_core::schema::{CompiledSchema, FieldDefinition, FieldType};
+
+/// Add the `rank` field to searchable types and enable `search` on their list
+/// queries. A type that already declares `rank` keeps its own definition.
+pub(super) fn wire_text_search(schema: &mut CompiledSchema) {
+    let mut searchable = HashSet::new();
+    for ty in schema.types.iter_mut().filter(|t| t.search.is_some()) {
+        searchable.insert(ty.name.to_string());
+        if !ty.fields.iter().any(|f| f.name == "rank") {
+            ty.fields
+                .push(FieldDefinition::nullable("rank", FieldType::Float).with_description(
+                    "Full-text search relevance; null unless `search` is given.",
+                ));
+        }
+    }
+    for query in &mut schema.queries {
+        if query.returns_list && !query.relay && searchable.contains(&query.return_type) {
+            query.auto_params.has_search = true;
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use anyhow::{Context, Result};
 use fraiseql_core::{
     schema::{
-        EnumDefinition, EnumValueDefinition, FieldDefinition, FieldDenyPolicy, FieldType,
-        InputFieldDefinition, InputObjectDefinition, InterfaceDefinition, TypeDefinition,
-        UnionDefinition,
+        DefaultOrderBy, EnumDefinition, EnumValueDefinition, FieldDefinition, FieldDenyPolicy,
+        FieldEncryptionConfig, FieldType, InputFieldDefinition, InputObjectDefinition,
+        InterfaceDefinition, TypeDefinition, UnionDefinition,
     },
+    types::OrderDirection,
     validation::CustomTypeDef,
 };
 
@@ -22,6 +23,28 @@ impl SchemaConverter {
     ///
     /// Returns an error if any field in the type cannot be converted.
     pub(super) fn convert_type(intermediate: IntermediateType) -> Result<TypeDefinition> {
+        // The `@tenant_id` field marks the type tenant-scoped; row-isolation
+        // schemas filter every read of it on this column.
+        let tenant_column = intermediate
+            .fields
+            .iter()
+            .find(|f| {
+                f.directives
+                    .as_ref()
+                    .is_some_and(|dirs| dirs.iter().any(|d| d.name == "tenant_id"))
+            })
+            .map(|f| f.name.clone());
+
+        // A declared default ordering gets an `id` tiebreaker so rows that tie on
+        // the declared keys still page deterministically.
+        let mut default_order_by = intermediate.default_order_by;
+        if !default_order_by.is_empty()
+            && !default_order_by.iter().any(|o| o.field == "id")
+            && intermediate.fields.iter().any(|f| f.name == "id")
+        {
+            default_order_by.push(DefaultOrderBy::new("id", OrderDirection::Asc));
+        }
+
         let fields = intermediate
             .fields
             .into_iter()
@@ -59,6 +82,12 @@ impl SchemaConverter {
             internal: false,
             relationships: Vec::new(),
             subscription_policy: None,
+            tenant_column,
+            default_order_by,
+            search: intermediate.search,
+            order_by_aggregates: intermediate.order_by_aggregates,
+            soft_delete_field: intermediate.soft_delete_field,
+            include_deleted_scope: intermediate.include_deleted_scope,
         })
     }
 
@@ -100,6 +129,8 @@ impl SchemaConverter {
             validation_rules: intermediate.validation_rules,
             elo_expression:   None,
             base_type:        intermediate.base_type,
+            sql_type:         intermediate.sql_type,
+            serialize_as:     intermediate.serialize_as,
         })
     }
 
@@ -157,6 +188,7 @@ impl SchemaConverter {
             name: intermediate.name,
             fields,
             description: intermediate.description,
+            discriminator: intermediate.discriminator,
         })
     }
 
@@ -167,6 +199,9 @@ impl SchemaConverter {
         if let Some(desc) = intermediate.description {
             union_def = union_def.with_description(&desc);
         }
+        if let Some(key) = intermediate.discriminator {
+            union_def = union_def.with_discriminator(key);
+        }
         union_def
     }
 
@@ -192,6 +227,14 @@ impl SchemaConverter {
             })
         });
 
+        // Encrypted fields fetch their key by reference; default to one key per field name.
+        let encryption = intermediate.encrypted.unwrap_or(false).then(|| FieldEncryptionConfig {
+            key_reference: intermediate
+                .encryption_key
+                .unwrap_or_else(|| format!("fraiseql/field-keys/{}", intermediate.name)),
+            algorithm:     "AES-256-GCM".to_string(),
+        });
+
         Ok(FieldDefinition {
             name: intermediate.name.into(),
             field_type,
@@ -210,8 +253,9 @@ impl SchemaConverter {
                 }
             }),
             authorize: intermediate.authorize.unwrap_or(false),
-            encryption: None,
+            encryption,
             hierarchy: intermediate.hierarchy,
+            sql_expression: intermediate.sql_expression,
         })
     }
 
@@ -17,7 +17,10 @@ use fraiseql_core::{
         DatabaseType,
         introspector::{DatabaseIntrospector, RelationInfo},
     },
-    schema::{CompiledSchema, FieldType, SourceKind, SourceProbe, sql_source_probes},
+    schema::{
+        CompiledSchema, SourceKind, SourceProbe, argument_column_compatible, is_json_column_type,
+        sql_source_probes,
+    },
 };
 
 /// Report containing all database validation warnings and discovered metadata.
@@ -105,6 +108,20 @@ pub enum DatabaseWarning {
         /// The missing cursor column name.
         column_name: String,
     },
+    /// L2: a computed field's SQL expression is rejected by the database when
+    /// evaluated against the query's relation.
+    InvalidComputedField {
+        /// Name of the query.
+        query_name: String,
+        /// The GraphQL type that declares the field (where to fix it).
+        type_name:  String,
+        /// The computed GraphQL field name.
+        field_name: String,
+        /// The `sql_source` relation the expression is evaluated against.
+        sql_source: String,
+        /// The database's error message.
+        message:    String,
+    },
     /// L3: a JSON key path is declared but not found in sampled data.
     MissingJsonKey {
         /// Name of the query.
@@ -224,6 +241,19 @@ impl fmt::Display for DatabaseWarning {
                     "query `{query_name}`: relay cursor column `{column_name}` not found on `{sql_source}`"
                 )
             },
+            Self::InvalidComputedField {
+                query_name,
+                type_name,
+                field_name,
+                sql_source,
+                message,
+            } => {
+                write!(
+                    f,
+                    "query `{query_name}`: computed field `{type_name}.{field_name}` is not a valid \
+                     expression on `{sql_source}`: {message}"
+                )
+            },
             Self::MissingJsonKey {
                 query_name,
                 type_name,
@@ -267,87 +297,6 @@ impl fmt::Display for DatabaseWarning {
     }
 }
 
-/// Check if a SQL data type represents a JSON column for the given database.
-pub(crate) fn is_json_type(data_type: &str, db_type: DatabaseType) -> bool {
-    let lower = data_type.to_lowercase();
-    match db_type {
-        DatabaseType::PostgreSQL => lower == "jsonb" || lower == "json",
-        DatabaseType::MySQL => lower == "json",
-        DatabaseType::SQLite => lower.contains("json"),
-        // SQL Server has no native JSON type — always attempt JSON
-        // validation for the configured jsonb_column
-        DatabaseType::SQLServer => true,
-    }
-}
-
-/// Coarse SQL type families, used to flag argument↔column type mismatches.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SqlFamily {
-    /// Integer/decimal/float numeric types.
-    Numeric,
-    /// Boolean.
-    Boolean,
-    /// UUID / `uniqueidentifier`.
-    Uuid,
-    /// Character/string types.
-    Text,
-    /// Date/time/interval types.
-    Temporal,
-    /// `json` / `jsonb`.
-    Json,
-    /// Anything else (custom domains, enums, arrays, geometry, …) — never flagged.
-    Other,
-}
-
-/// Classify a (any-dialect) SQL type string into a coarse [`SqlFamily`].
-///
-/// Strips length/precision and multi-word suffixes (`character varying`,
-/// `timestamp with time zone`, `double precision`) down to the leading base word.
-fn sql_type_family(sql_type: &str) -> SqlFamily {
-    let lower = sql_type.trim().to_lowercase();
-    let base = lower.split(['(', ' ', '[']).next().unwrap_or(lower.as_str());
-    match base {
-        "smallint" | "integer" | "int" | "int2" | "int4" | "int8" | "bigint" | "serial"
-        | "bigserial" | "smallserial" | "numeric" | "decimal" | "real" | "double" | "float"
-        | "float4" | "float8" | "money" | "mediumint" | "tinyint" => SqlFamily::Numeric,
-        "boolean" | "bool" | "bit" => SqlFamily::Boolean,
-        "uuid" | "uniqueidentifier" => SqlFamily::Uuid,
-        "text" | "varchar" | "char" | "bpchar" | "name" | "citext" | "nvarchar" | "nchar"
-        | "character" | "clob" | "string" => SqlFamily::Text,
-        "date" | "time" | "timestamp" | "timestamptz" | "timetz" | "datetime" | "datetime2"
-        | "interval" | "smalldatetime" => SqlFamily::Temporal,
-        "json" | "jsonb" => SqlFamily::Json,
-        _ => SqlFamily::Other,
-    }
-}
-
-/// Whether a query argument's GraphQL type can cleanly drive an equality predicate
-/// against a native column of `sql_type`.
-///
-/// Conservative by design: only the strongly-typed scalars (`Int`, `Float`,
-/// `Decimal`, `Boolean`, `UUID`) can flag a mismatch, and only against a *known*
-/// incompatible column family. Permissive scalars (`String`, `ID`, date/time, `JSON`,
-/// custom scalars) and non-scalar references never warn — the runtime binds them as
-/// text-coercible parameters, and `ID` intentionally spans uuid / integer / text keys.
-fn arg_type_convertible(field_type: &FieldType, sql_type: &str) -> bool {
-    // A list filter (`[ID!]`) drives the predicate with its element type.
-    let base = match field_type {
-        FieldType::List(inner) => inner.as_ref(),
-        other => other,
-    };
-    let family = sql_type_family(sql_type);
-    // Unknown column family → don't second-guess it.
-    if family == SqlFamily::Other {
-        return true;
-    }
-    match base {
-        FieldType::Int | FieldType::Float | FieldType::Decimal => family == SqlFamily::Numeric,
-        FieldType::Boolean => family == SqlFamily::Boolean,
-        FieldType::Uuid => matches!(family, SqlFamily::Uuid | SqlFamily::Text),
-        _ => true,
-    }
-}
-
 /// Split a potentially schema-qualified name into (optional_schema, name).
 fn split_schema_qualified(sql_source: &str) -> (Option<&str>, &str) {
     match sql_source.split_once('.') {
@@ -426,7 +375,15 @@ pub async fn validate_schema_against_database(
     // Auto-wired argument names excluded from direct-arg native column detection.
     // Must stay in sync with AUTO_PARAM_NAMES in fraiseql-core/runtime/executor/query.rs.
     const AUTO_PARAM_NAMES: &[&str] = &[
-        "where", "limit", "offset", "orderBy", "first", "last", "after", "before",
+        "where",
+        "limit",
+        "offset",
+        "orderBy",
+        "first",
+        "last",
+        "after",
+        "before",
+        "includeDeleted",
     ];
 
     let mut warnings = Vec::new();
@@ -462,7 +419,7 @@ pub async fn validate_schema_against_database(
             let jsonb_col = &query.jsonb_column;
             if !jsonb_col.is_empty() {
                 if let Some(actual_type) = column_map.get(jsonb_col) {
-                    if !is_json_type(actual_type, db_type) {
+                    if !is_json_column_type(actual_type, db_type) {
                         warnings.push(DatabaseWarning::WrongJsonColumnType {
                             query_name:  query.name.clone(),
                             sql_source:  source.clone(),
@@ -492,10 +449,31 @@ pub async fn validate_schema_against_database(
                 }
             }
 
+            // L2: Computed field expressions must be valid in the relation's SELECT list.
+            if let Some(type_def) = schema.find_type(&query.return_type) {
+                let computed = type_def
+                    .fields
+                    .iter()
+                    .filter_map(|f| f.sql_expression.as_deref().map(|expr| (f, expr)));
+                for (field, expression) in computed {
+                    if let Some(Some(message)) =
+                        introspector.expression_error(source, expression).await?
+                    {
+                        warnings.push(DatabaseWarning::InvalidComputedField {
+                            query_name: query.name.clone(),
+                            type_name: type_def.name.to_string(),
+                            field_name: field.name.to_string(),
+                            sql_source: source.clone(),
+                            message,
+                        });
+                    }
+                }
+            }
+
             // L3: Sample JSON keys if jsonb_column is valid JSON type
             if !jsonb_col.is_empty() {
                 let json_type_ok =
-                    column_map.get(jsonb_col).is_some_and(|t| is_json_type(t, db_type));
+                    column_map.get(jsonb_col).is_some_and(|t| is_json_column_type(t, db_type));
 
                 if json_type_ok {
                     validate_json_keys(
@@ -540,7 +518,7 @@ pub async fn validate_schema_against_database(
             // authoring bug (e.g. an `Int` argument filtering a `uuid` column).
             for arg in &query.arguments {
                 if let Some(col_type) = query_native.get(&arg.name) {
-                    if !arg_type_convertible(&arg.arg_type, col_type) {
+                    if !argument_column_compatible(&arg.arg_type, col_type) {
                         warnings.push(DatabaseWarning::TypeConvertibility {
                             query_name:   query.name.clone(),
                             sql_source:   source.clone(),
@@ -727,6 +705,10 @@ async fn validate_json_keys(
             if field_str == "id" || field_str.starts_with("pk_") || field_str.starts_with("fk_") {
                 continue;
             }
+            // Computed fields are evaluated from an expression, never stored.
+            if field.sql_expression.is_some() {
+                continue;
+            }
             if !all_keys.contains(&json_key) && !all_keys.contains(field_str) {
                 warnings.push(DatabaseWarning::MissingJsonKey {
                     query_name: query.name.clone(),
@@ -891,6 +873,34 @@ impl DatabaseIntrospector for AnyIntrospector {
             Self::SqlServer(i) => i.qualified_relation_exists(schema, name).await,
         }
     }
+
+    async fn expression_error(
+        &self,
+        relation: &str,
+        expression: &str,
+    ) -> fraiseql_core::Result<Option<Option<String>>> {
+        match self {
+            Self::Postgres(i) => i.expression_error(relation, expression).await,
+            #[cfg(feature = "mysql")]
+            Self::MySql(i) => i.expression_error(relation, expression).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(i) => i.expression_error(relation, expression).await,
+            #[cfg(feature = "sqlserver")]
+            Self::SqlServer(i) => i.expression_error(relation, expression).await,
+        }
+    }
+
+    async fn extension_installed(&self, name: &str) -> fraiseql_core::Result<Option<bool>> {
+        match self {
+            Self::Postgres(i) => i.extension_installed(name).await,
+            #[cfg(feature = "mysql")]
+            Self::MySql(i) => i.extension_installed(name).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(i) => i.extension_installed(name).await,
+            #[cfg(feature = "sqlserver")]
+            Self::SqlServer(i) => i.extension_installed(name).await,
+        }
+    }
 }
 
 /// Create an introspector from a database URL.
@@ -4,9 +4,9 @@
 
 use std::collections::HashMap;
 
-use fraiseql_core::schema::FieldType;
+use fraiseql_core::schema::{FieldType, argument_column_compatible};
 
-use super::{arg_type_convertible, detect_query_native_columns};
+use super::detect_query_native_columns;
 
 fn cols(pairs: &[(&str, &str)]) -> HashMap<String, String> {
     pairs.iter().map(|(k, v)| ((*k).to_string(), (*v).to_string())).collect()
@@ -70,57 +70,57 @@ fn args_and_inject_params_combine() {
 
 #[test]
 fn int_arg_against_numeric_column_is_convertible() {
-    assert!(arg_type_convertible(&FieldType::Int, "integer"));
-    assert!(arg_type_convertible(&FieldType::Int, "bigint"));
-    assert!(arg_type_convertible(&FieldType::Float, "numeric"));
-    assert!(arg_type_convertible(&FieldType::Decimal, "double precision"));
+    assert!(argument_column_compatible(&FieldType::Int, "integer"));
+    assert!(argument_column_compatible(&FieldType::Int, "bigint"));
+    assert!(argument_column_compatible(&FieldType::Float, "numeric"));
+    assert!(argument_column_compatible(&FieldType::Decimal, "double precision"));
 }
 
 #[test]
 fn int_arg_against_non_numeric_column_is_not_convertible() {
     // The canonical bug: an Int filter bound against a uuid/text/bool column.
-    assert!(!arg_type_convertible(&FieldType::Int, "uuid"));
-    assert!(!arg_type_convertible(&FieldType::Int, "text"));
-    assert!(!arg_type_convertible(&FieldType::Int, "boolean"));
+    assert!(!argument_column_compatible(&FieldType::Int, "uuid"));
+    assert!(!argument_column_compatible(&FieldType::Int, "text"));
+    assert!(!argument_column_compatible(&FieldType::Int, "boolean"));
 }
 
 #[test]
 fn boolean_arg_only_matches_boolean_column() {
-    assert!(arg_type_convertible(&FieldType::Boolean, "boolean"));
-    assert!(!arg_type_convertible(&FieldType::Boolean, "text"));
-    assert!(!arg_type_convertible(&FieldType::Boolean, "integer"));
+    assert!(argument_column_compatible(&FieldType::Boolean, "boolean"));
+    assert!(!argument_column_compatible(&FieldType::Boolean, "text"));
+    assert!(!argument_column_compatible(&FieldType::Boolean, "integer"));
 }
 
 #[test]
 fn uuid_arg_matches_uuid_or_text_column() {
-    assert!(arg_type_convertible(&FieldType::Uuid, "uuid"));
+    assert!(argument_column_compatible(&FieldType::Uuid, "uuid"));
     // uuids are commonly stored as text in SQLite / portable schemas.
-    assert!(arg_type_convertible(&FieldType::Uuid, "character varying"));
-    assert!(!arg_type_convertible(&FieldType::Uuid, "bigint"));
+    assert!(argument_column_compatible(&FieldType::Uuid, "character varying"));
+    assert!(!argument_column_compatible(&FieldType::Uuid, "bigint"));
 }
 
 #[test]
 fn id_and_string_args_are_permissive() {
     // `ID` intentionally spans uuid / integer / text key columns — never warn.
-    assert!(arg_type_convertible(&FieldType::Id, "uuid"));
-    assert!(arg_type_convertible(&FieldType::Id, "bigint"));
-    assert!(arg_type_convertible(&FieldType::Id, "text"));
+    assert!(argument_column_compatible(&FieldType::Id, "uuid"));
+    assert!(argument_column_compatible(&FieldType::Id, "bigint"));
+    assert!(argument_column_compatible(&FieldType::Id, "text"));
     // `String` binds as a text-coercible parameter — never warn.
-    assert!(arg_type_convertible(&FieldType::String, "integer"));
+    assert!(argument_column_compatible(&FieldType::String, "integer"));
 }
 
 #[test]
 fn unknown_column_family_is_never_flagged() {
     // Custom domains, enums, geometry, arrays … are not second-guessed.
-    assert!(arg_type_convertible(&FieldType::Int, "mood_enum"));
-    assert!(arg_type_convertible(&FieldType::Boolean, "ltree"));
+    assert!(argument_column_compatible(&FieldType::Int, "mood_enum"));
+    assert!(argument_column_compatible(&FieldType::Boolean, "ltree"));
 }
 
 #[test]
 fn list_arg_is_checked_by_its_element_type() {
     // `[Int!]` against a uuid column is still a mismatch; `[ID!]` is permissive.
     let int_list = FieldType::List(Box::new(FieldType::Int));
-    assert!(!arg_type_convertible(&int_list, "uuid"));
+    assert!(!argument_column_compatible(&int_list, "uuid"));
     let id_list = FieldType::List(Box::new(FieldType::Id));
-    assert!(arg_type_convertible(&id_list, "uuid"));
+    assert!(argument_column_compatible(&id_list, "uuid"));
 }
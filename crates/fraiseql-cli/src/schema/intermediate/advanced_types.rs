@@ -36,6 +36,10 @@ pub struct IntermediateInterface {
     /// Interface description (from docstring)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// JSONB key in the backing view naming each row's concrete type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<String>,
 }
 
 // =============================================================================
@@ -68,6 +72,10 @@ pub struct IntermediateUnion {
     /// Union description (from docstring)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// JSONB key in the backing view naming each row's concrete member type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<String>,
 }
 
 // =============================================================================
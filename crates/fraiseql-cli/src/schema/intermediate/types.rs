@@ -1,7 +1,11 @@
 //! Core type structs: `IntermediateType`, `IntermediateField`, `IntermediateEnum`,
 //! `IntermediateEnumValue`, `IntermediateScalar`, `IntermediateDeprecation`.
 
-use fraiseql_core::validation::ValidationRule;
+use fraiseql_core::{
+    db::TextSearchConfig,
+    schema::{AggregateOrderBy, DefaultOrderBy},
+    validation::{ScalarSerialization, ValidationRule},
+};
 use serde::{Deserialize, Serialize};
 
 use super::fragments::IntermediateAppliedDirective;
@@ -88,6 +92,78 @@ pub struct IntermediateType {
     /// `false`/absent (the default) captures the after-image only.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub subscribable_pre_image: bool,
+
+    /// Ordering for list queries returning this type when the client passes no
+    /// `orderBy`. The compiler appends an `id` tiebreaker.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "name": "Post",
+    ///   "fields": [],
+    ///   "default_order_by": [{ "field": "createdAt", "direction": "DESC" }]
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_order_by: Vec<DefaultOrderBy>,
+
+    /// Full-text search document. List queries returning this type gain a
+    /// `search` argument and the type gains a nullable `rank: Float` field.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "name": "Post",
+    ///   "fields": [],
+    ///   "search": {
+    ///     "language": "english",
+    ///     "fields": [{ "field": "title", "weight": "A" }, { "field": "body" }]
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<TextSearchConfig>,
+
+    /// Child relationship aggregates that list queries returning this type may
+    /// be ordered by. Aggregate orderings not listed here are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "name": "User",
+    ///   "fields": [],
+    ///   "order_by_aggregates": [
+    ///     { "relationship": "orders", "function": "count" },
+    ///     { "relationship": "orders", "function": "max", "field": "total" }
+    ///   ]
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order_by_aggregates: Vec<AggregateOrderBy>,
+
+    /// Nullable timestamp field marking a row soft-deleted. Queries returning
+    /// the type exclude deleted rows unless passed `includeDeleted: true`.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "name": "Post",
+    ///   "fields": [{ "name": "deleted_at", "type": "DateTime", "nullable": true }],
+    ///   "soft_delete_field": "deleted_at",
+    ///   "include_deleted_scope": "admin:posts"
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soft_delete_field: Option<String>,
+
+    /// Scope required to pass `includeDeleted: true`; defaults to
+    /// `read:<Type>.deleted`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_deleted_scope: Option<String>,
 }
 
 /// Field definition in intermediate format
@@ -161,6 +237,47 @@ pub struct IntermediateField {
     /// References a key in the `hierarchies` config map.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hierarchy: Option<String>,
+
+    /// SQL expression computing this field from the query's `sql_source` row.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "name": "fullName",
+    ///   "type": "String",
+    ///   "nullable": true,
+    ///   "sql_expression": "data->>'first' || ' ' || data->>'last'"
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_expression: Option<String>,
+
+    /// Whether this field is encrypted at rest.
+    ///
+    /// When `true`, mutations encrypt the value before it is written and queries
+    /// decrypt it on read. Encrypted fields cannot be used as query filters.
+    /// Defaults to `false` when absent.
+    ///
+    /// # Example
+    ///
+    /// ```json
+    /// {
+    ///   "name": "ssn",
+    ///   "type": "String",
+    ///   "nullable": true,
+    ///   "encrypted": true,
+    ///   "encryption_key": "keys/employee-ssn"
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted: Option<bool>,
+
+    /// Secrets-backend key reference for an encrypted field.
+    ///
+    /// Defaults to `fraiseql/field-keys/<field>` when the field is encrypted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption_key: Option<String>,
 }
 
 // =============================================================================
@@ -279,4 +396,12 @@ pub struct IntermediateScalar {
     /// Base type for type aliases (e.g., "String" for Email scalar)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub base_type: Option<String>,
+
+    /// SQL type used when filtering on this scalar (e.g., "numeric(12,2)")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_type: Option<String>,
+
+    /// JSON representation on the wire (e.g., `string` for Money)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serialize_as: Option<ScalarSerialization>,
 }
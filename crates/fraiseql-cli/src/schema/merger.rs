@@ -466,6 +466,9 @@ impl SchemaMerger {
                         if let Some(ref h) = fdef.hierarchy {
                             field["hierarchy"] = json!(h);
                         }
+                        if let Some(ref expr) = fdef.sql_expression {
+                            field["sql_expression"] = json!(expr);
+                        }
                         field
                     }).collect::<Vec<_>>(),
                 }));
@@ -534,6 +537,31 @@ impl SchemaMerger {
             "mutations": mutations_array,
         });
 
+        // Custom scalars: types.json `custom_scalars` plus TOML `[scalars.*]`. A name
+        // declared in both places is ambiguous (which rules win?) and is rejected.
+        let mut scalars_array: Vec<Value> = match types_value.get("custom_scalars") {
+            Some(Value::Array(list)) => list.clone(),
+            _ => Vec::new(),
+        };
+        for (scalar_name, scalar_def) in &toml_schema.scalars {
+            if scalars_array
+                .iter()
+                .any(|s| s.get("name").and_then(Value::as_str) == Some(scalar_name.as_str()))
+            {
+                anyhow::bail!(
+                    "Custom scalar '{scalar_name}' is defined in both types.json and \
+                     [scalars.{scalar_name}]; define it in one place only"
+                );
+            }
+            let mut scalar_value = serde_json::to_value(scalar_def)
+                .with_context(|| format!("Failed to serialize scalar '{scalar_name}'"))?;
+            scalar_value["name"] = json!(scalar_name);
+            scalars_array.push(scalar_value);
+        }
+        if !scalars_array.is_empty() {
+            merged["custom_scalars"] = Value::Array(scalars_array);
+        }
+
         // Warn when PKCE is enabled without state encryption (insecure configuration).
         if let Some(pkce) = &toml_schema.security.pkce {
             if pkce.enabled {
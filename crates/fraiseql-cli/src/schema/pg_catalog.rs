@@ -552,6 +552,48 @@ impl PgCatalog {
             }
         }))
     }
+
+    /// The server's `server_version_num` (e.g. `160002` for 16.2).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the query fails.
+    pub async fn server_version_num(&self) -> Result<i32> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let row = client
+            .query_one("SELECT current_setting('server_version_num')::int AS num", &[])
+            .await
+            .context("failed to read server_version_num")?;
+        Ok(row.get("num"))
+    }
+
+    /// Installed extensions as `(extname, extversion)` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the catalog query fails.
+    pub async fn installed_extensions(&self) -> Result<Vec<(String, String)>> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let rows = client
+            .query("SELECT extname::text AS name, extversion AS version FROM pg_extension", &[])
+            .await
+            .context("failed to query pg_extension")?;
+        Ok(rows.iter().map(|row| (row.get("name"), row.get("version"))).collect())
+    }
+
+    /// Whether `relation` (bare or schema-qualified) resolves via `to_regclass`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the query fails.
+    pub async fn relation_exists(&self, relation: &str) -> Result<bool> {
+        let client = self.pool.get().await.context("failed to acquire DB connection")?;
+        let row = client
+            .query_one("SELECT to_regclass($1::text) IS NOT NULL AS present", &[&relation])
+            .await
+            .with_context(|| format!("failed to resolve relation {relation}"))?;
+        Ok(row.get("present"))
+    }
 }
 
 /// Build an [`OutColumn`] from a `(name, type_name, is_enum)` catalog row.
@@ -11,7 +11,7 @@ mod database_validator_tests {
         },
         schema::{
             ArgumentDefinition, AutoParams, CompiledSchema, CursorType, FieldDefinition, FieldType,
-            MutationDefinition, QueryDefinition, TypeDefinition,
+            MutationDefinition, QueryDefinition, TypeDefinition, is_json_column_type,
         },
         validation::CustomTypeRegistry,
     };
@@ -161,23 +161,29 @@ mod database_validator_tests {
 
     fn make_type(name: &str, fields: Vec<(&str, FieldType)>) -> TypeDefinition {
         TypeDefinition {
-            name:                name.into(),
-            fields:              fields
+            name:                  name.into(),
+            fields:                fields
                 .into_iter()
                 .map(|(n, ft)| FieldDefinition::new(n, ft))
                 .collect(),
-            description:         None,
-            sql_source:          "".into(),
-            jsonb_column:        "data".to_string(),
-            sql_projection_hint: None,
-            implements:          vec![],
-            requires_role:       None,
-            is_error:            false,
-            relay:               false,
-            internal:            false,
-            embedded:            false,
-            relationships:       Vec::new(),
-            subscription_policy: None,
+            description:           None,
+            sql_source:            "".into(),
+            jsonb_column:          "data".to_string(),
+            sql_projection_hint:   None,
+            implements:            vec![],
+            requires_role:         None,
+            is_error:              false,
+            relay:                 false,
+            internal:              false,
+            embedded:              false,
+            relationships:         Vec::new(),
+            subscription_policy:   None,
+            tenant_column:         None,
+            default_order_by:      Vec::new(),
+            search:                None,
+            order_by_aggregates:   Vec::new(),
+            soft_delete_field:     None,
+            include_deleted_scope: None,
         }
     }
 
@@ -682,29 +688,29 @@ mod database_validator_tests {
 
     #[test]
     fn test_is_json_type_postgres() {
-        assert!(is_json_type("jsonb", DatabaseType::PostgreSQL));
-        assert!(is_json_type("json", DatabaseType::PostgreSQL));
-        assert!(!is_json_type("text", DatabaseType::PostgreSQL));
+        assert!(is_json_column_type("jsonb", DatabaseType::PostgreSQL));
+        assert!(is_json_column_type("json", DatabaseType::PostgreSQL));
+        assert!(!is_json_column_type("text", DatabaseType::PostgreSQL));
     }
 
     #[test]
     fn test_is_json_type_mysql() {
-        assert!(is_json_type("json", DatabaseType::MySQL));
-        assert!(!is_json_type("varchar", DatabaseType::MySQL));
+        assert!(is_json_column_type("json", DatabaseType::MySQL));
+        assert!(!is_json_column_type("varchar", DatabaseType::MySQL));
     }
 
     #[test]
     fn test_is_json_type_sqlite() {
-        assert!(is_json_type("json", DatabaseType::SQLite));
-        assert!(is_json_type("JSON", DatabaseType::SQLite));
-        assert!(!is_json_type("text", DatabaseType::SQLite));
+        assert!(is_json_column_type("json", DatabaseType::SQLite));
+        assert!(is_json_column_type("JSON", DatabaseType::SQLite));
+        assert!(!is_json_column_type("text", DatabaseType::SQLite));
     }
 
     #[test]
     fn test_is_json_type_sqlserver() {
         // SQL Server always returns true
-        assert!(is_json_type("nvarchar", DatabaseType::SQLServer));
-        assert!(is_json_type("varchar", DatabaseType::SQLServer));
+        assert!(is_json_column_type("nvarchar", DatabaseType::SQLServer));
+        assert!(is_json_column_type("varchar", DatabaseType::SQLServer));
     }
 
     #[test]
@@ -1049,6 +1055,10 @@ type = "ID"
 max_query_depth = 3
 max_query_complexity = 25
 max_page_size = 750
+max_root_fields = 8
+
+[validation.roles.admin]
+max_query_depth = 9
 "#;
 
         let tmp = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
@@ -1063,6 +1073,8 @@ max_page_size = 750
         assert_eq!(vc.max_query_complexity, Some(25));
         // #421: the page-size ceiling flows TOML → compiled schema.
         assert_eq!(vc.max_page_size, Some(750));
+        assert_eq!(vc.max_root_fields, Some(8));
+        assert_eq!(vc.roles["admin"].max_query_depth, Some(9));
     }
 
     #[test]
@@ -1093,6 +1105,68 @@ type = "ID"
         assert!(schema.validation_config.is_none());
     }
 
+    #[test]
+    fn test_merge_toml_scalars_into_custom_scalars() {
+        let toml_content = r#"
+[schema]
+name = "test"
+version = "1.0.0"
+database_target = "postgresql"
+
+[scalars.Money]
+sql_type = "numeric(12,2)"
+serialize_as = "string"
+
+[types.Order]
+sql_source = "v_order"
+
+[types.Order.fields.total]
+type = "Money"
+"#;
+
+        let tmp = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+        std::fs::write(tmp.path(), toml_content).unwrap();
+
+        let schema = SchemaMerger::merge_toml_only(tmp.path().to_str().unwrap())
+            .unwrap_or_else(|e| panic!("expected Ok from merge_toml_only (scalars): {e}"));
+
+        let scalars = schema.custom_scalars.expect("custom_scalars populated from [scalars]");
+        assert_eq!(scalars.len(), 1);
+        assert_eq!(scalars[0].name, "Money");
+        assert_eq!(scalars[0].sql_type.as_deref(), Some("numeric(12,2)"));
+    }
+
+    #[test]
+    fn test_merge_files_rejects_scalar_defined_twice() {
+        let types_json = r#"{
+            "types": [],
+            "queries": [],
+            "mutations": [],
+            "custom_scalars": [{ "name": "Money", "base_type": "String" }]
+        }"#;
+        let toml_content = r#"
+[schema]
+name = "test"
+version = "1.0.0"
+database_target = "postgresql"
+
+[scalars.Money]
+sql_type = "numeric"
+"#;
+
+        let types_file = tempfile::NamedTempFile::with_suffix(".json").unwrap();
+        fs::write(types_file.path(), types_json).unwrap();
+        let toml_file = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+        fs::write(toml_file.path(), toml_content).unwrap();
+
+        let err = SchemaMerger::merge_files(
+            types_file.path().to_str().unwrap(),
+            toml_file.path().to_str().unwrap(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("defined in both"), "unexpected error: {err}");
+    }
+
     // ── CRUD naming config ─────────────────────────────────────────────────────
 
     #[test]
@@ -1841,10 +1915,12 @@ mod optimizer_tests {
                 sql_source:          Some("products".to_string()),
                 description:         None,
                 auto_params:         AutoParams {
-                    has_where:    false,
-                    has_order_by: false,
-                    has_limit:    true,
-                    has_offset:   true,
+                    has_where:           false,
+                    has_order_by:        false,
+                    has_limit:           true,
+                    has_offset:          true,
+                    has_search:          false,
+                    has_include_deleted: false,
                 },
                 deprecation:         None,
                 jsonb_column:        "data".to_string(),
@@ -1885,10 +1961,10 @@ mod optimizer_tests {
     fn test_large_type_warning() {
         let mut schema = CompiledSchema {
             types: vec![TypeDefinition {
-                name:                "BigType".into(),
-                sql_source:          String::new().into(),
-                jsonb_column:        String::new(),
-                fields:              (0..25)
+                name:                  "BigType".into(),
+                sql_source:            String::new().into(),
+                jsonb_column:          String::new(),
+                fields:                (0..25)
                     .map(|i| FieldDefinition {
                         name:           format!("field{i}").into(),
                         field_type:     FieldType::String,
@@ -1903,18 +1979,25 @@ mod optimizer_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     })
                     .collect(),
-                description:         None,
-                sql_projection_hint: None,
-                implements:          vec![],
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       Vec::new(),
-                subscription_policy: None,
+                description:           None,
+                sql_projection_hint:   None,
+                implements:            vec![],
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         Vec::new(),
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             }],
             enums: vec![],
             input_types: vec![],
@@ -1947,10 +2030,10 @@ mod optimizer_tests {
     fn test_projection_hint_for_large_type() {
         let mut schema = CompiledSchema {
             types: vec![TypeDefinition {
-                name:                "User".into(),
-                sql_source:          "users".into(),
-                jsonb_column:        "data".to_string(),
-                fields:              (0..15)
+                name:                  "User".into(),
+                sql_source:            "users".into(),
+                jsonb_column:          "data".to_string(),
+                fields:                (0..15)
                     .map(|i| FieldDefinition {
                         name:           format!("field{i}").into(),
                         field_type:     FieldType::String,
@@ -1965,18 +2048,25 @@ mod optimizer_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     })
                     .collect(),
-                description:         None,
-                sql_projection_hint: None,
-                implements:          vec![],
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       Vec::new(),
-                subscription_policy: None,
+                description:           None,
+                sql_projection_hint:   None,
+                implements:            vec![],
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         Vec::new(),
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             }],
             enums: vec![],
             input_types: vec![],
@@ -2019,10 +2109,10 @@ mod optimizer_tests {
     fn test_projection_not_applied_without_jsonb() {
         let mut schema = CompiledSchema {
             types: vec![TypeDefinition {
-                name:                "SmallType".into(),
-                sql_source:          "small_table".into(),
-                jsonb_column:        String::new(), // No JSONB column
-                fields:              (0..15)
+                name:                  "SmallType".into(),
+                sql_source:            "small_table".into(),
+                jsonb_column:          String::new(), // No JSONB column
+                fields:                (0..15)
                     .map(|i| FieldDefinition {
                         name:           format!("field{i}").into(),
                         field_type:     FieldType::String,
@@ -2037,18 +2127,25 @@ mod optimizer_tests {
                         authorize:      false,
                         encryption:     None,
                         hierarchy:      None,
+                        sql_expression: None,
                     })
                     .collect(),
-                description:         None,
-                sql_projection_hint: None,
-                implements:          vec![],
-                requires_role:       None,
-                is_error:            false,
-                relay:               false,
-                internal:            false,
-                embedded:            false,
-                relationships:       Vec::new(),
-                subscription_policy: None,
+                description:           None,
+                sql_projection_hint:   None,
+                implements:            vec![],
+                requires_role:         None,
+                is_error:              false,
+                relay:                 false,
+                internal:              false,
+                embedded:              false,
+                relationships:         Vec::new(),
+                subscription_policy:   None,
+                tenant_column:         None,
+                default_order_by:      Vec::new(),
+                search:                None,
+                order_by_aggregates:   Vec::new(),
+                soft_delete_field:     None,
+                include_deleted_scope: None,
             }],
             enums: vec![],
             input_types: vec![],
@@ -3,6 +3,7 @@
 use std::collections::HashSet;
 
 use anyhow::Result;
+use fraiseql_core::db::OrderByAggregateFunction;
 use tracing::{debug, info};
 
 use super::{
@@ -64,6 +65,69 @@ impl SchemaValidator {
             type_names.insert(union_def.name.clone());
         }
 
+        // A discriminated union or interface is projected per row at the concrete type
+        // its discriminator names, so every possible type must be a declared object type.
+        for (idx, union_def) in schema.unions.iter().enumerate() {
+            if union_def.discriminator.is_none() {
+                continue;
+            }
+            for member in &union_def.member_types {
+                if !schema.types.iter().any(|t| &t.name == member) {
+                    report.errors.push(ValidationError {
+                        message:    format!(
+                            "Union '{}' has a discriminator but member '{member}' is not a \
+                             declared object type",
+                            union_def.name
+                        ),
+                        path:       format!("unions[{idx}].member_types"),
+                        severity:   ErrorSeverity::Error,
+                        suggestion: Some(
+                            "Declare each member type so its rows can be projected".to_string(),
+                        ),
+                    });
+                }
+            }
+        }
+        for (idx, interface) in schema.interfaces.iter().enumerate() {
+            if interface.discriminator.is_some()
+                && !schema.types.iter().any(|t| t.implements.contains(&interface.name))
+            {
+                report.errors.push(ValidationError {
+                    message:    format!(
+                        "Interface '{}' has a discriminator but no type implements it",
+                        interface.name
+                    ),
+                    path:       format!("interfaces[{idx}].discriminator"),
+                    severity:   ErrorSeverity::Warning,
+                    suggestion: Some(
+                        "Add the interface to the `implements` list of its variants".to_string(),
+                    ),
+                });
+            }
+        }
+
+        // Add declared custom scalars — valid as argument types, and their SQL type
+        // must be a plain type name since it is spliced into WHERE casts.
+        for (idx, scalar) in schema.custom_scalars.iter().flatten().enumerate() {
+            if let Some(sql_type) = &scalar.sql_type {
+                if !fraiseql_core::db::is_safe_sql_type_name(sql_type) {
+                    report.errors.push(ValidationError {
+                        message:    format!(
+                            "Custom scalar '{}' has invalid sql_type '{sql_type}'",
+                            scalar.name
+                        ),
+                        path:       format!("custom_scalars[{idx}].sql_type"),
+                        severity:   ErrorSeverity::Error,
+                        suggestion: Some(
+                            "Use a plain SQL type name such as \"numeric(12,2)\" or \"citext\""
+                                .to_string(),
+                        ),
+                    });
+                }
+            }
+            type_names.insert(scalar.name.clone());
+        }
+
         // Add built-in scalars
         for scalar in crate::schema::BUILTIN_SCALAR_NAMES {
             type_names.insert((*scalar).to_string());
@@ -78,6 +142,187 @@ impl SchemaValidator {
             }
         }
 
+        // Computed fields splice their SQL expression into the SELECT list, so it must
+        // stay a single expression.
+        for (type_idx, type_def) in schema.types.iter().enumerate() {
+            for (field_idx, field) in type_def.fields.iter().enumerate() {
+                let Some(expr) = &field.sql_expression else {
+                    continue;
+                };
+                if !fraiseql_core::db::is_safe_sql_expression(expr) {
+                    report.errors.push(ValidationError {
+                        message:    format!(
+                            "Computed field '{}.{}' has an invalid sql_expression",
+                            type_def.name, field.name
+                        ),
+                        path:       format!("types[{type_idx}].fields[{field_idx}].sql_expression"),
+                        severity:   ErrorSeverity::Error,
+                        suggestion: Some(
+                            "Use a single SQL expression: no ';' or comments, with balanced \
+                             parentheses and quotes"
+                                .to_string(),
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Encrypted values are stored as base64 ciphertext, so only String fields qualify,
+        // and a computed field has no stored value to encrypt.
+        for (type_idx, type_def) in schema.types.iter().enumerate() {
+            for (field_idx, field) in type_def.fields.iter().enumerate() {
+                if field.encrypted != Some(true) {
+                    continue;
+                }
+                let reason = if extract_base_type(&field.field_type) != "String" {
+                    "must be a String (or a list of String)"
+                } else if field.sql_expression.is_some() {
+                    "cannot be a computed field"
+                } else {
+                    continue;
+                };
+                report.errors.push(ValidationError {
+                    message:    format!(
+                        "Encrypted field '{}.{}' {reason}",
+                        type_def.name, field.name
+                    ),
+                    path:       format!("types[{type_idx}].fields[{field_idx}].encrypted"),
+                    severity:   ErrorSeverity::Error,
+                    suggestion: Some(
+                        "Remove `encrypted` or store the value as a String".to_string(),
+                    ),
+                });
+            }
+        }
+
+        // A default ordering key must name a declared field of its type.
+        for (type_idx, type_def) in schema.types.iter().enumerate() {
+            for (order_idx, order) in type_def.default_order_by.iter().enumerate() {
+                if type_def.fields.iter().any(|f| f.name == order.field) {
+                    continue;
+                }
+                report.errors.push(ValidationError {
+                    message:    format!(
+                        "Type '{}' default_order_by references unknown field '{}'",
+                        type_def.name, order.field
+                    ),
+                    path:       format!("types[{type_idx}].default_order_by[{order_idx}].field"),
+                    severity:   ErrorSeverity::Error,
+                    suggestion: Some(format!(
+                        "Order by one of the fields declared on '{}'",
+                        type_def.name
+                    )),
+                });
+            }
+        }
+
+        // A soft-delete field must be a declared, nullable field of its type.
+        for (type_idx, type_def) in schema.types.iter().enumerate() {
+            let Some(field_name) = &type_def.soft_delete_field else {
+                continue;
+            };
+            let message = match type_def.fields.iter().find(|f| &f.name == field_name) {
+                None => format!(
+                    "Type '{}' soft_delete_field references unknown field '{field_name}'",
+                    type_def.name
+                ),
+                Some(field) if !field.nullable => format!(
+                    "Type '{}' soft_delete_field '{field_name}' must be nullable",
+                    type_def.name
+                ),
+                Some(_) => continue,
+            };
+            report.errors.push(ValidationError {
+                message,
+                path: format!("types[{type_idx}].soft_delete_field"),
+                severity: ErrorSeverity::Error,
+                suggestion: Some(
+                    "Point soft_delete_field at a nullable timestamp field that is null for \
+                     live rows"
+                        .to_string(),
+                ),
+            });
+        }
+
+        // A search document must render, and be built from declared fields.
+        for (type_idx, type_def) in schema.types.iter().enumerate() {
+            let Some(search) = &type_def.search else {
+                continue;
+            };
+            if let Err(e) = search.validate() {
+                report.errors.push(ValidationError {
+                    message:    format!("Type '{}' search: {e}", type_def.name),
+                    path:       format!("types[{type_idx}].search"),
+                    severity:   ErrorSeverity::Error,
+                    suggestion: Some(
+                        "List at least one field and use a text search configuration name \
+                         such as 'english' or 'simple'"
+                            .to_string(),
+                    ),
+                });
+            }
+            for (field_idx, field) in search.fields.iter().enumerate() {
+                let declared = type_def.fields.iter().find(|f| f.name == field.field);
+                if declared.is_some_and(|f| f.encrypted == Some(true)) {
+                    report.errors.push(ValidationError {
+                        message:    format!(
+                            "Type '{}' search covers encrypted field '{}'",
+                            type_def.name, field.field
+                        ),
+                        path:       format!("types[{type_idx}].search.fields[{field_idx}].field"),
+                        severity:   ErrorSeverity::Error,
+                        suggestion: Some(
+                            "Encrypted fields are stored as ciphertext and cannot be searched"
+                                .to_string(),
+                        ),
+                    });
+                    continue;
+                }
+                if declared.is_some() {
+                    continue;
+                }
+                report.errors.push(ValidationError {
+                    message:    format!(
+                        "Type '{}' search references unknown field '{}'",
+                        type_def.name, field.field
+                    ),
+                    path:       format!("types[{type_idx}].search.fields[{field_idx}].field"),
+                    severity:   ErrorSeverity::Error,
+                    suggestion: Some(format!(
+                        "Search one of the fields declared on '{}'",
+                        type_def.name
+                    )),
+                });
+            }
+        }
+
+        // `count` aggregates rows and takes no field; every other aggregate needs one.
+        for (type_idx, type_def) in schema.types.iter().enumerate() {
+            for (agg_idx, agg) in type_def.order_by_aggregates.iter().enumerate() {
+                let is_count = agg.function == OrderByAggregateFunction::Count;
+                if is_count == agg.field.is_none() {
+                    continue;
+                }
+                report.errors.push(ValidationError {
+                    message:    format!(
+                        "Type '{}' order_by_aggregates entry for '{}' {}",
+                        type_def.name,
+                        agg.relationship,
+                        if is_count {
+                            "uses count, which takes no field"
+                        } else {
+                            "needs a field to aggregate"
+                        }
+                    ),
+                    path:       format!("types[{type_idx}].order_by_aggregates[{agg_idx}]"),
+                    severity:   ErrorSeverity::Error,
+                    suggestion: Some(
+                        "Set 'field' for sum/avg/min/max and omit it for count".to_string(),
+                    ),
+                });
+            }
+        }
+
         // Validate queries
         let mut query_names = HashSet::new();
         for (idx, query) in schema.queries.iter().enumerate() {
@@ -130,6 +375,43 @@ impl SchemaValidator {
                 }
             }
 
+            // Encrypted fields hold ciphertext, so an argument filter or injected
+            // parameter on one can never match a plaintext value.
+            if let Some(return_def) = schema.types.iter().find(|t| t.name == base_return) {
+                let is_encrypted = |name: &str| {
+                    return_def.fields.iter().any(|f| f.name == name && f.encrypted == Some(true))
+                };
+                let filters = query
+                    .arguments
+                    .iter()
+                    .enumerate()
+                    .map(|(arg_idx, arg)| (arg.name.as_str(), format!("arguments[{arg_idx}]")))
+                    .chain(
+                        query
+                            .inject
+                            .keys()
+                            .map(|param| (param.as_str(), format!("inject.{param}"))),
+                    );
+                for (name, path) in filters {
+                    if !is_encrypted(name) {
+                        continue;
+                    }
+                    report.errors.push(ValidationError {
+                        message:    format!(
+                            "Query '{}' filters on encrypted field '{}.{name}'",
+                            query.name, return_def.name
+                        ),
+                        path:       format!("queries[{idx}].{path}"),
+                        severity:   ErrorSeverity::Error,
+                        suggestion: Some(
+                            "Encrypted fields are stored as ciphertext and cannot be used in \
+                             WHERE conditions; filter on a non-encrypted field instead"
+                                .to_string(),
+                        ),
+                    });
+                }
+            }
+
             // Validate sql_source is a safe SQL identifier
             if let Some(sql_source) = &query.sql_source {
                 if let Err(e) = validate_sql_identifier(
@@ -121,6 +121,11 @@ fn test_detect_duplicate_query_names() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -210,6 +215,11 @@ fn test_warning_for_query_without_sql_source() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -285,6 +295,11 @@ fn test_valid_observer() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:                vec![],
         input_types:          vec![],
@@ -410,6 +425,11 @@ fn test_observer_with_invalid_event() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:                vec![],
         input_types:          vec![],
@@ -477,6 +497,11 @@ fn test_observer_with_invalid_action_type() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:                vec![],
         input_types:          vec![],
@@ -544,6 +569,11 @@ fn test_observer_with_invalid_retry_config() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:                vec![],
         input_types:          vec![],
@@ -606,6 +636,11 @@ fn test_query_injection_in_sql_source_rejected() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -675,6 +710,11 @@ fn test_query_schema_qualified_sql_source_passes() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
@@ -753,6 +793,9 @@ mod schema_validator_tests {
             on_deny:        None,
             authorize:      None,
             hierarchy:      None,
+            sql_expression: None,
+            encrypted:      None,
+            encryption_key: None,
         }
     }
 
@@ -1100,3 +1143,407 @@ fn run_as_with_blank_grant_is_rejected() {
         "the blank role is rejected"
     );
 }
+
+fn money_scalar(sql_type: &str) -> crate::schema::intermediate::IntermediateScalar {
+    crate::schema::intermediate::IntermediateScalar {
+        name:             "Money".to_string(),
+        description:      None,
+        specified_by_url: None,
+        validation_rules: vec![],
+        base_type:        Some("String".to_string()),
+        sql_type:         Some(sql_type.to_string()),
+        serialize_as:     None,
+    }
+}
+
+fn orders_query_with_money_arg() -> IntermediateQuery {
+    IntermediateQuery {
+        name:              "orders".to_string(),
+        return_type:       "Order".to_string(),
+        returns_list:      true,
+        nullable:          false,
+        arguments:         vec![crate::schema::intermediate::IntermediateArgument {
+            name:       "minTotal".to_string(),
+            arg_type:   "Money".to_string(),
+            nullable:   true,
+            default:    None,
+            deprecated: None,
+        }],
+        description:       None,
+        sql_source:        Some("v_order".to_string()),
+        auto_params:       None,
+        deprecated:        None,
+        jsonb_column:      None,
+        relay:             false,
+        inject:            IndexMap::default(),
+        cache_ttl_seconds: None,
+        additional_views:  vec![],
+        requires_role:     None,
+        relay_cursor_type: None,
+    }
+}
+
+fn order_type() -> IntermediateType {
+    IntermediateType {
+        name:                   "Order".to_string(),
+        sql_source:             None,
+        fields:                 vec![],
+        description:            None,
+        implements:             vec![],
+        requires_role:          None,
+        is_error:               false,
+        relay:                  false,
+        embedded:               false,
+        subscribable_tables:    None,
+        subscribable_pre_image: false,
+        default_order_by:       Vec::new(),
+        search:                 None,
+        order_by_aggregates:    Vec::new(),
+        soft_delete_field:      None,
+        include_deleted_scope:  None,
+    }
+}
+
+#[test]
+fn test_declared_custom_scalar_is_a_known_argument_type() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![order_type()],
+        queries: vec![orders_query_with_money_arg()],
+        custom_scalars: Some(vec![money_scalar("numeric(12,2)")]),
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert!(report.is_valid(), "unexpected errors: {:?}", report.errors);
+}
+
+#[test]
+fn test_detect_invalid_custom_scalar_sql_type() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![order_type()],
+        queries: vec![orders_query_with_money_arg()],
+        custom_scalars: Some(vec![money_scalar("numeric); DROP TABLE users; --")]),
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.errors[0].path, "custom_scalars[0].sql_type");
+}
+
+fn pet_union(members: &[&str]) -> crate::schema::intermediate::IntermediateUnion {
+    crate::schema::intermediate::IntermediateUnion {
+        name:          "Pet".to_string(),
+        member_types:  members.iter().map(|m| (*m).to_string()).collect(),
+        description:   None,
+        discriminator: Some("kind".to_string()),
+    }
+}
+
+#[test]
+fn test_discriminated_union_with_declared_members_is_valid() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![IntermediateType {
+            name: "Dog".to_string(),
+            ..IntermediateType::default()
+        }],
+        unions: vec![pet_union(&["Dog"])],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert!(report.is_valid(), "unexpected errors: {:?}", report.errors);
+}
+
+#[test]
+fn test_detect_discriminated_union_with_undeclared_member() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![IntermediateType {
+            name: "Dog".to_string(),
+            ..IntermediateType::default()
+        }],
+        unions: vec![pet_union(&["Dog", "Parrot"])],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.errors[0].path, "unions[0].member_types");
+    assert!(report.errors[0].message.contains("Parrot"));
+}
+
+#[test]
+fn test_warning_for_discriminated_interface_without_implementors() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        interfaces: vec![crate::schema::intermediate::IntermediateInterface {
+            name:          "Animal".to_string(),
+            fields:        vec![],
+            description:   None,
+            discriminator: Some("kind".to_string()),
+        }],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert!(report.is_valid(), "a warning must not fail validation: {:?}", report.errors);
+    assert_eq!(report.warning_count(), 1);
+    assert_eq!(report.errors[0].path, "interfaces[0].discriminator");
+}
+
+fn user_type_with_computed(expr: &str) -> IntermediateType {
+    let field = crate::schema::intermediate::IntermediateField {
+        name:           "fullName".to_string(),
+        field_type:     "String".to_string(),
+        nullable:       true,
+        description:    None,
+        directives:     None,
+        requires_scope: None,
+        on_deny:        None,
+        authorize:      None,
+        hierarchy:      None,
+        sql_expression: Some(expr.to_string()),
+        encrypted:      None,
+        encryption_key: None,
+    };
+    IntermediateType {
+        name: "User".to_string(),
+        fields: vec![field],
+        ..IntermediateType::default()
+    }
+}
+
+#[test]
+fn test_computed_field_expression_is_valid() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user_type_with_computed(
+            "data->>'first' || ' ' || data->>'last'",
+        )],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert!(report.is_valid(), "unexpected errors: {:?}", report.errors);
+}
+
+#[test]
+fn test_detect_unsafe_computed_field_expression() {
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user_type_with_computed("1) FROM secrets --")],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.errors[0].path, "types[0].fields[0].sql_expression");
+}
+
+fn user_type_with_encrypted_email() -> IntermediateType {
+    let mut user = user_type_with_computed("data->>'first'");
+    let mut email = user.fields[0].clone();
+    email.name = "email".to_string();
+    email.sql_expression = None;
+    email.encrypted = Some(true);
+    user.fields.push(email);
+    user
+}
+
+#[test]
+fn test_detect_filter_on_encrypted_field() {
+    use crate::schema::intermediate::IntermediateArgument;
+
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user_type_with_encrypted_email()],
+        queries: vec![IntermediateQuery {
+            name: "userByEmail".to_string(),
+            return_type: "User".to_string(),
+            sql_source: Some("v_user".to_string()),
+            arguments: vec![IntermediateArgument {
+                name:       "email".to_string(),
+                arg_type:   "String".to_string(),
+                nullable:   false,
+                default:    None,
+                deprecated: None,
+            }],
+            ..IntermediateQuery::default()
+        }],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1, "{:?}", report.errors);
+    assert_eq!(report.errors[0].path, "queries[0].arguments[0]");
+    assert!(report.errors[0].message.contains("encrypted field 'User.email'"));
+}
+
+#[test]
+fn test_encrypted_field_must_be_string() {
+    let mut user = user_type_with_encrypted_email();
+    user.fields[1].field_type = "Int".to_string();
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.errors[0].path, "types[0].fields[1].encrypted");
+}
+
+#[test]
+fn test_search_rejects_encrypted_field() {
+    use fraiseql_core::db::{SearchField, TextSearchConfig};
+
+    let mut user = user_type_with_encrypted_email();
+    user.search = Some(TextSearchConfig {
+        language: "english".to_string(),
+        fields:   vec![SearchField {
+            field:  "email".to_string(),
+            weight: None,
+        }],
+    });
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user],
+        ..IntermediateSchema::default()
+    };
+
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.errors[0].path, "types[0].search.fields[0].field");
+}
+
+#[test]
+fn test_default_order_by_must_reference_declared_field() {
+    use fraiseql_core::{schema::DefaultOrderBy, types::OrderDirection};
+
+    let mut user = user_type_with_computed("data->>'first'");
+    user.default_order_by = vec![DefaultOrderBy::new("fullName", OrderDirection::Asc)];
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user.clone()],
+        ..IntermediateSchema::default()
+    };
+    assert!(SchemaValidator::validate(&schema).unwrap().is_valid());
+
+    user.default_order_by
+        .push(DefaultOrderBy::new("createdAt", OrderDirection::Desc));
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user],
+        ..IntermediateSchema::default()
+    };
+    let report = SchemaValidator::validate(&schema).unwrap();
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.errors[0].path, "types[0].default_order_by[1].field");
+}
+
+#[test]
+fn test_search_must_reference_declared_fields() {
+    use fraiseql_core::db::{SearchField, TextSearchConfig};
+
+    let mut user = user_type_with_computed("data->>'first'");
+    user.search = Some(TextSearchConfig {
+        language: "english".to_string(),
+        fields:   vec![SearchField {
+            field:  "fullName".to_string(),
+            weight: None,
+        }],
+    });
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user.clone()],
+        ..IntermediateSchema::default()
+    };
+    assert!(SchemaValidator::validate(&schema).unwrap().is_valid());
+
+    user.search.as_mut().unwrap().fields.push(SearchField {
+        field:  "bio".to_string(),
+        weight: None,
+    });
+    user.search.as_mut().unwrap().language = "english; drop".to_string();
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user],
+        ..IntermediateSchema::default()
+    };
+    let report = SchemaValidator::validate(&schema).unwrap();
+    let paths: Vec<&str> = report.errors.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, vec!["types[0].search", "types[0].search.fields[1].field"]);
+}
+
+#[test]
+fn test_order_by_aggregates_field_matches_function() {
+    use fraiseql_core::{db::OrderByAggregateFunction, schema::AggregateOrderBy};
+
+    let mut user = user_type_with_computed("data->>'first'");
+    user.order_by_aggregates = vec![
+        AggregateOrderBy::new("orders", OrderByAggregateFunction::Count, None),
+        AggregateOrderBy::new("orders", OrderByAggregateFunction::Max, Some("total".to_string())),
+    ];
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user.clone()],
+        ..IntermediateSchema::default()
+    };
+    assert!(SchemaValidator::validate(&schema).unwrap().is_valid());
+
+    user.order_by_aggregates.extend([
+        AggregateOrderBy::new("orders", OrderByAggregateFunction::Sum, None),
+        AggregateOrderBy::new("orders", OrderByAggregateFunction::Count, Some("total".to_string())),
+    ]);
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user],
+        ..IntermediateSchema::default()
+    };
+    let report = SchemaValidator::validate(&schema).unwrap();
+    let paths: Vec<&str> = report.errors.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "types[0].order_by_aggregates[2]",
+            "types[0].order_by_aggregates[3]"
+        ]
+    );
+}
+
+#[test]
+fn test_soft_delete_field_must_be_declared_and_nullable() {
+    let mut user = user_type_with_computed("data->>'first'");
+    user.soft_delete_field = Some("fullName".to_string());
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user.clone()],
+        ..IntermediateSchema::default()
+    };
+    assert!(SchemaValidator::validate(&schema).unwrap().is_valid());
+
+    let mut required = user.clone();
+    required.name = "Author".to_string();
+    required.fields[0].nullable = false;
+    user.soft_delete_field = Some("deletedAt".to_string());
+    let schema = IntermediateSchema {
+        version: "2.0.0".to_string(),
+        types: vec![user, required],
+        ..IntermediateSchema::default()
+    };
+    let report = SchemaValidator::validate(&schema).unwrap();
+    let messages: Vec<&str> = report.errors.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Type 'User' soft_delete_field references unknown field 'deletedAt'",
+            "Type 'Author' soft_delete_field 'fullName' must be nullable",
+        ]
+    );
+    assert_eq!(report.errors[1].path, "types[1].soft_delete_field");
+}
@@ -2,7 +2,8 @@
 //! Integration tests for `fraiseql analyze`.
 //!
 //! Invokes the real CLI binary and inspects exit codes and JSON output.
-//! No database required — `analyze` works purely on the schema file.
+//! No database required — `analyze` works purely on the schema file, and the
+//! `--usage` report logic is exercised directly on in-memory rows.
 //!
 //! **Execution engine:** none (CLI binary only)
 //! **Infrastructure:** none (filesystem only)
@@ -10,6 +11,11 @@
 
 use std::process::Command;
 
+use fraiseql_cli::commands::analyze::{FieldUsageRow, field_usage_report};
+use fraiseql_core::schema::{
+    CompiledSchema, FieldDefinition, FieldType, QueryDefinition, TypeDefinition,
+};
+
 fn cli() -> Command {
     Command::new(env!("CARGO_BIN_EXE_fraiseql-cli"))
 }
@@ -174,3 +180,66 @@ fn analyze_exit_code_one_on_file_not_found() {
     let code = out.status.code().unwrap_or(-1);
     assert_eq!(code, 1, "file-not-found must exit with code 1, got {code}");
 }
+
+// ── Field usage report (`--usage`) ────────────────────────────────────────────
+
+fn usage_row(client: &str, coordinate: &str, count: u64) -> FieldUsageRow {
+    FieldUsageRow {
+        client_id: client.to_string(),
+        coordinate: coordinate.to_string(),
+        count,
+    }
+}
+
+fn usage_schema() -> CompiledSchema {
+    let mut schema = CompiledSchema::default();
+    schema.queries.push(QueryDefinition::new("users", "User"));
+    schema.types.push(
+        TypeDefinition::new("User", "v_user")
+            .with_field(FieldDefinition::new("id", FieldType::Id))
+            .with_field(FieldDefinition::new("email", FieldType::String))
+            .with_field(
+                FieldDefinition::new("legacyName", FieldType::String)
+                    .deprecated(Some("Use 'name'".to_string())),
+            ),
+    );
+    schema
+}
+
+/// Fields nobody selected are reported as unused; deprecated fields still
+/// selected are listed with the clients that use them.
+#[test]
+fn usage_report_finds_dead_and_deprecated_fields() {
+    let rows = [
+        usage_row("web", "Query.users", 10),
+        usage_row("web", "User.id", 10),
+        usage_row("ios", "User.legacyName", 3),
+        usage_row("web", "User.legacyName", 1),
+    ];
+    let report = field_usage_report("schema.compiled.json", &usage_schema(), &rows);
+
+    assert_eq!(report.total_fields, 4);
+    assert_eq!(report.used_fields, 3);
+    assert_eq!(report.unused_fields, ["User.email"]);
+    assert_eq!(report.deprecated_in_use.len(), 1);
+    let deprecated = &report.deprecated_in_use[0];
+    assert_eq!(deprecated.coordinate, "User.legacyName");
+    assert_eq!(deprecated.reason.as_deref(), Some("Use 'name'"));
+    assert_eq!(deprecated.count, 4);
+    assert_eq!(deprecated.clients, ["ios", "web"]);
+}
+
+/// Recorded coordinates the schema no longer defines are surfaced separately,
+/// and zero-count rows do not mark a field as used.
+#[test]
+fn usage_report_separates_unknown_and_ignores_zero_counts() {
+    let rows = [
+        usage_row("web", "User.removed", 5),
+        usage_row("web", "User.email", 0),
+    ];
+    let report = field_usage_report("schema.compiled.json", &usage_schema(), &rows);
+
+    assert_eq!(report.unknown_fields, ["User.removed"]);
+    assert!(report.unused_fields.contains(&"User.email".to_string()));
+    assert_eq!(report.used_fields, 0);
+}
@@ -142,9 +142,10 @@ fn explicit_union_return_is_preserved() {
         vec![object_type("User"), object_type("EmailTakenError")],
         vec![object_mutation("createUser", "CreateUserResult")],
         vec![IntermediateUnion {
-            name:         "CreateUserResult".to_string(),
-            member_types: vec!["User".to_string(), "EmailTakenError".to_string()],
-            description:  None,
+            name:          "CreateUserResult".to_string(),
+            member_types:  vec!["User".to_string(), "EmailTakenError".to_string()],
+            description:   None,
+            discriminator: None,
         }],
     ));
 
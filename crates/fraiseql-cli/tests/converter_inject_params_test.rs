@@ -36,6 +36,9 @@ fn order_type() -> IntermediateType {
             on_deny:        None,
             authorize:      None,
             hierarchy:      None,
+            sql_expression: None,
+            encrypted:      None,
+            encryption_key: None,
         }],
         ..Default::default()
     }
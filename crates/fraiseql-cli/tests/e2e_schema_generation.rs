@@ -437,6 +437,9 @@ fn test_e2e_full_field_assertion() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "email".to_string(),
@@ -448,6 +451,9 @@ fn test_e2e_full_field_assertion() {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -458,6 +464,11 @@ fn test_e2e_full_field_assertion() {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         queries:           vec![IntermediateQuery {
             name:              "users".to_string(),
@@ -0,0 +1,139 @@
+//! Integration tests for `fraiseql generate-client python` and `--incremental`
+//! regeneration.
+//!
+//! The compiled-schema fixture is embedded inline so it is not subject to the
+//! repo-wide `*.compiled.json` gitignore rule.
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use std::path::Path;
+
+use assert_cmd::Command;
+
+const SCHEMA: &str = r#"{
+  "enums": [
+    { "name": "Role", "values": [ { "name": "ADMIN" }, { "name": "USER" } ] }
+  ],
+  "types": [
+    {
+      "name": "User",
+      "sql_source": "v_user",
+      "fields": [
+        { "name": "id", "field_type": "ID" },
+        { "name": "name", "field_type": "String" },
+        { "name": "role", "field_type": { "Enum": "Role" } }
+      ]
+    }
+  ],
+  "queries": [
+    {
+      "name": "getUser",
+      "return_type": "User",
+      "nullable": true,
+      "arguments": [ { "name": "id", "arg_type": "ID", "nullable": false } ]
+    }
+  ],
+  "mutations": [
+    {
+      "name": "createUser",
+      "return_type": "User",
+      "arguments": [ { "name": "name", "arg_type": "String", "nullable": false } ]
+    }
+  ]
+}"#;
+
+fn generate(
+    schema: &Path,
+    out: &Path,
+    language: &str,
+    extra: &[&str],
+) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("fraiseql-cli")
+        .unwrap()
+        .args(["generate-client", language])
+        .arg("--schema")
+        .arg(schema)
+        .arg("--out")
+        .arg(out)
+        .args(extra)
+        .assert()
+}
+
+#[test]
+fn generate_client_python_writes_expected_package() {
+    let tmp = tempfile::tempdir().unwrap();
+    let schema = tmp.path().join("schema.compiled.json");
+    std::fs::write(&schema, SCHEMA).unwrap();
+    let out = tmp.path().join("api_client");
+
+    generate(&schema, &out, "python", &[]).success();
+
+    for f in [
+        "__init__.py",
+        "client.py",
+        "enums.py",
+        "types.py",
+        "queries.py",
+        "mutations.py",
+    ] {
+        assert!(out.join(f).exists(), "expected generated file {f}");
+    }
+    assert!(!out.join("inputs.py").exists(), "no input types → inputs.py skipped");
+
+    let enums = std::fs::read_to_string(out.join("enums.py")).unwrap();
+    assert!(enums.contains("Role = Literal[\"ADMIN\", \"USER\"]"));
+
+    let mutations = std::fs::read_to_string(out.join("mutations.py")).unwrap();
+    assert!(mutations.contains("def create_user(client: FraiseqlClient, *, name: str) -> User:"));
+}
+
+#[test]
+fn generate_client_incremental_rewrites_only_changed_files() {
+    let tmp = tempfile::tempdir().unwrap();
+    let schema = tmp.path().join("schema.compiled.json");
+    std::fs::write(&schema, SCHEMA).unwrap();
+    let out = tmp.path().join("api_client");
+
+    generate(&schema, &out, "python", &[]).success();
+    std::fs::write(out.join("README.md"), "hand-written").unwrap();
+
+    // A module left in a subpackage by an earlier generator version, next to a
+    // hand-written one.
+    let legacy = out.join("legacy");
+    std::fs::create_dir(&legacy).unwrap();
+    let stamp = std::fs::read_to_string(out.join("enums.py")).unwrap();
+    std::fs::write(legacy.join("old_types.py"), stamp).unwrap();
+    std::fs::write(legacy.join("helpers.py"), "def helper() -> None: ...\n").unwrap();
+
+    // Drop the mutation: the schema hash changes (so every stamped file is
+    // rewritten) and `mutations.py` becomes stale.
+    let without_mutations = SCHEMA.replace(
+        r#""mutations": [
+    {
+      "name": "createUser",
+      "return_type": "User",
+      "arguments": [ { "name": "name", "arg_type": "String", "nullable": false } ]
+    }
+  ]"#,
+        r#""mutations": []"#,
+    );
+    std::fs::write(&schema, without_mutations).unwrap();
+
+    // Without --incremental or --force the existing client is protected.
+    generate(&schema, &out, "python", &[]).failure();
+
+    generate(&schema, &out, "python", &["--incremental"])
+        .success()
+        .stdout(predicates::str::contains("2 stale removed"));
+    assert!(!out.join("mutations.py").exists(), "stale generated module must be removed");
+    assert!(
+        !legacy.join("old_types.py").exists(),
+        "stale generated modules in subdirectories must be removed"
+    );
+    assert!(legacy.join("helpers.py").exists(), "hand-written modules must be left alone");
+    assert!(out.join("README.md").exists(), "hand-written files must be left alone");
+
+    // A second incremental run against the same schema touches nothing.
+    generate(&schema, &out, "python", &["--incremental"])
+        .success()
+        .stdout(predicates::str::contains("(5 unchanged, 0 stale removed)"));
+}
@@ -40,6 +40,8 @@ fn test_compile_schema_with_single_custom_scalar() {
                 message: Some("Invalid email format".to_string()),
             }],
             base_type:        Some("String".to_string()),
+            sql_type:         None,
+            serialize_as:     None,
         }]),
         security:             None,
         observers_config:     None,
@@ -93,6 +95,8 @@ fn test_compile_schema_with_multiple_custom_scalars() {
                 specified_by_url: None,
                 validation_rules: vec![],
                 base_type:        Some("String".to_string()),
+                sql_type:         None,
+                serialize_as:     None,
             },
             IntermediateScalar {
                 name:             "Phone".to_string(),
@@ -103,6 +107,8 @@ fn test_compile_schema_with_multiple_custom_scalars() {
                     message: Some("Invalid phone format".to_string()),
                 }],
                 base_type:        Some("String".to_string()),
+                sql_type:         None,
+                serialize_as:     None,
             },
         ]),
         security:             None,
@@ -165,6 +171,8 @@ fn test_custom_scalar_with_multiple_validation_rules() {
                 },
             ],
             base_type:        Some("String".to_string()),
+            sql_type:         None,
+            serialize_as:     None,
         }]),
         security:             None,
         observers_config:     None,
@@ -213,6 +221,8 @@ fn test_custom_scalar_preserves_all_metadata() {
             specified_by_url: Some(url.clone()),
             validation_rules: vec![],
             base_type:        Some("Int".to_string()),
+            sql_type:         None,
+            serialize_as:     None,
         }]),
         security:             None,
         observers_config:     None,
@@ -305,6 +315,8 @@ fn test_custom_scalar_with_no_validation_rules() {
             specified_by_url: None,
             validation_rules: vec![], // No rules
             base_type:        None,
+            sql_type:         None,
+            serialize_as:     None,
         }]),
         security:             None,
         observers_config:     None,
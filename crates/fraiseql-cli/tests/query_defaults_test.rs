@@ -35,6 +35,11 @@ fn base_schema_with_query(
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums: vec![],
         input_types: vec![],
@@ -37,6 +37,9 @@ fn relay_intermediate_schema() -> IntermediateSchema {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
                 IntermediateField {
                     name:           "name".to_string(),
@@ -48,6 +51,9 @@ fn relay_intermediate_schema() -> IntermediateSchema {
                     on_deny:        None,
                     authorize:      None,
                     hierarchy:      None,
+                    sql_expression: None,
+                    encrypted:      None,
+                    encryption_key: None,
                 },
             ],
             description:            None,
@@ -58,6 +64,11 @@ fn relay_intermediate_schema() -> IntermediateSchema {
             embedded:               false,
             subscribable_tables:    None,
             subscribable_pre_image: false,
+            default_order_by:       Vec::new(),
+            search:                 None,
+            order_by_aggregates:    Vec::new(),
+            soft_delete_field:      None,
+            include_deleted_scope:  None,
         }],
         enums:             vec![],
         input_types:       vec![],
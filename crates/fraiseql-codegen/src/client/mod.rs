@@ -3,7 +3,7 @@
 //! Given a compiled schema, the generators here emit typed clients that
 //! *callers* of a FraiseQL API use to query and mutate it — interfaces for every
 //! type, typed query/mutation functions, relationship metadata, and a tiny
-//! `fetch`-based runtime client.
+//! runtime client (`fetch` for `TypeScript`, `urllib` for Python).
 //!
 //! Each generated file is stamped with a hash of the schema it was generated
 //! from (see [`schema_hash`]). Consumers can recompute the live schema's hash in
@@ -14,6 +14,7 @@ use sha2::{Digest, Sha256};
 
 use crate::{FraiseQLError, Result};
 
+pub mod python;
 pub mod typescript;
 
 /// Compute the canonical schema hash used to stamp generated files.
@@ -0,0 +1,674 @@
+//! Python client generator.
+//!
+//! `generate(&CompiledSchema)` returns a [`Generated`] map of Python files
+//! implementing a typed client package: `TypedDict` result/input types,
+//! `Literal` enums, keyword-only operation functions, and a stdlib-only
+//! `urllib` runtime. The layout mirrors the `TypeScript` generator so the two
+//! clients stay conceptually interchangeable; the generated code targets
+//! Python 3.11+ (`NotRequired`, generic `TypedDict`).
+//!
+//! Result types use the functional `TypedDict("Name", {...})` form because the
+//! `__typename` key would otherwise be name-mangled inside a class body.
+
+mod runtime;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    path::PathBuf,
+};
+
+use fraiseql_core::schema::{
+    ArgumentDefinition, CompiledSchema, FieldDefinition, FieldType, TypeDefinition,
+};
+
+use crate::{Generated, Result};
+
+const RELAY_HELPERS: &str = "T = TypeVar(\"T\")\n\n\nclass PageInfo(TypedDict):\n    hasNextPage: bool\n    hasPreviousPage: bool\n    startCursor: Optional[str]\n    endCursor: Optional[str]\n\n\nclass Edge(TypedDict, Generic[T]):\n    cursor: str\n    node: T\n\n\nclass Connection(TypedDict, Generic[T]):\n    edges: list[Edge[T]]\n    pageInfo: PageInfo\n    totalCount: NotRequired[int]\n";
+
+/// Python keywords (and soft keywords that are awkward as identifiers) that
+/// cannot be used as parameter names; they get a trailing underscore.
+const PY_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Generate a Python client package from a compiled schema.
+///
+/// Returns a map of relative path → file content. As with the `TypeScript`
+/// generator, `enums.py`, `inputs.py`, `queries.py`, and `mutations.py` are
+/// present only when the schema has the corresponding definitions, and
+/// `__init__.py` re-exports exactly the modules that were emitted.
+///
+/// # Errors
+///
+/// Returns an error if the schema cannot be hashed for stamping (see
+/// [`crate::client::schema_hash`]).
+pub fn generate(schema: &CompiledSchema) -> Result<Generated> {
+    let ctx = Ctx::new(schema);
+    let hash = super::schema_hash(schema)?;
+
+    let mut files = Generated::new();
+    let mut modules: Vec<&str> = Vec::new();
+    let add = |files: &mut Generated,
+               modules: &mut Vec<&'static str>,
+               name: &'static str,
+               body: String| {
+        files.insert(PathBuf::from(format!("{name}.py")), stamp(&body, &hash));
+        modules.push(name);
+    };
+
+    add(&mut files, &mut modules, "client", runtime::CLIENT_PY.to_string());
+    if !schema.enums.is_empty() {
+        add(&mut files, &mut modules, "enums", enums(schema));
+    }
+    if !schema.input_types.is_empty() {
+        add(&mut files, &mut modules, "inputs", inputs(&ctx));
+    }
+    add(&mut files, &mut modules, "types", types(&ctx));
+    if !schema.queries.is_empty() {
+        add(&mut files, &mut modules, "queries", queries(&ctx));
+    }
+    if !schema.mutations.is_empty() {
+        add(&mut files, &mut modules, "mutations", mutations(&ctx));
+    }
+
+    files.insert(PathBuf::from("__init__.py"), stamp(&init(&modules), &hash));
+
+    Ok(files)
+}
+
+/// Prepend the auto-generated header (with the schema hash) to a file body.
+fn stamp(body: &str, hash: &str) -> String {
+    format!(
+        "# AUTO-GENERATED by fraiseql-codegen. DO NOT EDIT.\n# schema-hash: {hash}\n# fraiseql-codegen: {version}\n\n{body}",
+        version = env!("CARGO_PKG_VERSION"),
+    )
+}
+
+/// Resolved lookups over a [`CompiledSchema`], shared by the emitters.
+struct Ctx<'a> {
+    schema:       &'a CompiledSchema,
+    object_types: BTreeMap<&'a str, &'a TypeDefinition>,
+    union_names:  BTreeSet<&'a str>,
+    iface_names:  BTreeSet<&'a str>,
+    enum_names:   BTreeSet<&'a str>,
+    input_names:  BTreeSet<&'a str>,
+    has_relay:    bool,
+}
+
+impl<'a> Ctx<'a> {
+    fn new(schema: &'a CompiledSchema) -> Self {
+        Self {
+            schema,
+            object_types: schema.types.iter().map(|t| (t.name.as_str(), t)).collect(),
+            union_names: schema.unions.iter().map(|u| u.name.as_str()).collect(),
+            iface_names: schema.interfaces.iter().map(|i| i.name.as_str()).collect(),
+            enum_names: schema.enums.iter().map(|e| e.name.as_str()).collect(),
+            input_names: schema.input_types.iter().map(|i| i.name.as_str()).collect(),
+            has_relay: schema.queries.iter().any(|q| q.relay),
+        }
+    }
+
+    /// Which generated module defines a referenced type name, if any.
+    fn module_of(&self, name: &str) -> Option<&'static str> {
+        if self.enum_names.contains(name) {
+            Some("enums")
+        } else if self.input_names.contains(name) {
+            Some("inputs")
+        } else if name == "Connection"
+            || self.object_types.contains_key(name)
+            || self.union_names.contains(name)
+            || self.iface_names.contains(name)
+        {
+            Some("types")
+        } else {
+            None
+        }
+    }
+}
+
+// =============================================================================
+// Type rendering
+// =============================================================================
+
+/// Map a `GraphQL` named-scalar to its Python type, if it is a known scalar.
+fn named_scalar_py(name: &str) -> Option<&'static str> {
+    match name.to_ascii_lowercase().as_str() {
+        "string" | "id" | "uuid" | "decimal" | "datetime" | "timestamp" | "date" | "time" => {
+            Some("str")
+        },
+        "int" | "integer" => Some("int"),
+        "float" | "double" => Some("float"),
+        "boolean" | "bool" => Some("bool"),
+        "json" | "jsonb" => Some("Any"),
+        _ => None,
+    }
+}
+
+/// Render a structured [`FieldType`] as a Python type (no nullability).
+fn field_type_py(ft: &FieldType) -> String {
+    match ft {
+        FieldType::String
+        | FieldType::Id
+        | FieldType::Uuid
+        | FieldType::Decimal
+        | FieldType::DateTime
+        | FieldType::Date
+        | FieldType::Time
+        | FieldType::Scalar(_) => "str".to_string(),
+        FieldType::Int => "int".to_string(),
+        FieldType::Float => "float".to_string(),
+        FieldType::Boolean => "bool".to_string(),
+        FieldType::Vector => "list[float]".to_string(),
+        FieldType::List(inner) => format!("list[{}]", field_type_py(inner)),
+        FieldType::Enum(name)
+        | FieldType::Object(name)
+        | FieldType::Input(name)
+        | FieldType::Interface(name)
+        | FieldType::Union(name) => name.clone(),
+        // Reason: `Json` maps to `Any`; the wildcard also covers any future
+        // #[non_exhaustive] scalar variant.
+        _ => "Any".to_string(),
+    }
+}
+
+/// Wrap a Python type in `Optional[...]` when nullable.
+fn optional(py: String, nullable: bool) -> String {
+    if nullable {
+        format!("Optional[{py}]")
+    } else {
+        py
+    }
+}
+
+/// Parse an input-field `GraphQL` type **string** (`"String!"`, `"[Int]"`)
+/// into a Python type, returning `(type, required)`.
+fn parse_input_type(type_str: &str) -> (String, bool) {
+    let s = type_str.trim();
+    let (s, required) = match s.strip_suffix('!') {
+        Some(rest) => (rest.trim_end(), true),
+        None => (s, false),
+    };
+
+    if let Some(inner) = s.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+        let (inner_py, inner_required) = parse_input_type(inner);
+        return (format!("list[{}]", optional(inner_py, !inner_required)), required);
+    }
+
+    let py = named_scalar_py(s).map_or_else(|| s.to_string(), str::to_string);
+    (py, required)
+}
+
+/// The base named type of an input-field `GraphQL` type **string** (strips `[]!`).
+fn input_base_name(type_str: &str) -> String {
+    type_str.chars().filter(|c| !matches!(c, '[' | ']' | '!' | ' ')).collect()
+}
+
+/// Whether a field is a `GraphQL` leaf (selectable without a sub-selection).
+fn is_leaf(ft: &FieldType) -> bool {
+    match ft {
+        FieldType::Object(_) | FieldType::Interface(_) | FieldType::Union(_) => false,
+        FieldType::List(inner) => is_leaf(inner),
+        _ => true,
+    }
+}
+
+/// The innermost named type a [`FieldType`] references (recursing into lists).
+fn referenced_named_type(ft: &FieldType) -> Option<&str> {
+    match ft {
+        FieldType::Enum(n)
+        | FieldType::Object(n)
+        | FieldType::Input(n)
+        | FieldType::Interface(n)
+        | FieldType::Union(n) => Some(n),
+        FieldType::List(inner) => referenced_named_type(inner),
+        _ => None,
+    }
+}
+
+fn leaf_fields(fields: &[FieldDefinition]) -> impl Iterator<Item = &FieldDefinition> {
+    fields.iter().filter(|f| is_leaf(&f.field_type))
+}
+
+/// Quote a type expression that names a generated type, so functional
+/// `TypedDict` definitions tolerate forward references.
+fn quote_if_named(py: String, named: bool) -> String {
+    if named { format!("\"{py}\"") } else { py }
+}
+
+// =============================================================================
+// Per-file emitters
+// =============================================================================
+
+fn enums(schema: &CompiledSchema) -> String {
+    let has_empty = schema.enums.iter().any(|e| e.values.is_empty());
+    let mut out = if has_empty {
+        String::from("from typing import Literal, NoReturn\n")
+    } else {
+        String::from("from typing import Literal\n")
+    };
+    for def in &schema.enums {
+        out.push_str("\n\n");
+        let members = if def.values.is_empty() {
+            "NoReturn".to_string()
+        } else {
+            let values = def.values.iter().map(|v| format!("\"{}\"", v.name)).collect::<Vec<_>>();
+            format!("Literal[{}]", values.join(", "))
+        };
+        let _ = writeln!(out, "{} = {members}", def.name);
+        push_doc(&mut out, "", def.description.as_deref());
+    }
+    finish(out)
+}
+
+fn inputs(ctx: &Ctx) -> String {
+    let schema = ctx.schema;
+    let mut refs: BTreeSet<String> = BTreeSet::new();
+    for input in &schema.input_types {
+        for field in &input.fields {
+            let base = input_base_name(&field.field_type);
+            if ctx.enum_names.contains(base.as_str()) {
+                refs.insert(base);
+            }
+        }
+    }
+
+    let mut out = String::from(
+        "from __future__ import annotations\n\nfrom typing import Any, NotRequired, Optional, TypedDict\n",
+    );
+    push_imports(&mut out, ctx, refs.iter().map(String::as_str), "inputs");
+
+    for input in &schema.input_types {
+        out.push_str("\n\n");
+        let _ = writeln!(out, "{} = TypedDict(\"{}\", {{", input.name, input.name);
+        for field in &input.fields {
+            let (py, required) = parse_input_type(&field.field_type);
+            let named = named_scalar_py(&input_base_name(&field.field_type)).is_none();
+            let ty = if required {
+                py
+            } else {
+                format!("NotRequired[Optional[{py}]]")
+            };
+            let _ = writeln!(out, "    \"{}\": {},", field.name, quote_if_named(ty, named));
+        }
+        out.push_str("})\n");
+        push_doc(&mut out, "", input.description.as_deref());
+    }
+    finish(out)
+}
+
+fn types(ctx: &Ctx) -> String {
+    let schema = ctx.schema;
+    let mut refs: BTreeSet<&str> = BTreeSet::new();
+    for fields in schema
+        .types
+        .iter()
+        .map(|t| &t.fields)
+        .chain(schema.interfaces.iter().map(|i| &i.fields))
+    {
+        for field in leaf_fields(fields) {
+            if let Some(name) = referenced_named_type(&field.field_type) {
+                refs.insert(name);
+            }
+        }
+    }
+
+    let mut out = String::from(
+        "from __future__ import annotations\n\nfrom typing import Any, Generic, Literal, NotRequired, Optional, TypedDict, TypeVar, Union\n",
+    );
+    push_imports(&mut out, ctx, refs.iter().copied(), "types");
+
+    if ctx.has_relay {
+        out.push_str("\n\n");
+        out.push_str(RELAY_HELPERS);
+    }
+    for iface in &schema.interfaces {
+        out.push_str("\n\n");
+        emit_typed_dict(&mut out, iface.name.as_str(), "str", &iface.fields);
+        push_doc(&mut out, "", iface.description.as_deref());
+    }
+    for ty in &schema.types {
+        out.push_str("\n\n");
+        let typename = format!("Literal[\"{}\"]", ty.name);
+        emit_typed_dict(&mut out, ty.name.as_str(), &typename, &ty.fields);
+        push_doc(&mut out, "", ty.description.as_deref());
+    }
+    for union in &schema.unions {
+        out.push_str("\n\n");
+        let members = if union.member_types.is_empty() {
+            "Any".to_string()
+        } else {
+            format!("Union[{}]", union.member_types.join(", "))
+        };
+        let _ = writeln!(out, "{} = {members}", union.name);
+        push_doc(&mut out, "", union.description.as_deref());
+    }
+    finish(out)
+}
+
+fn emit_typed_dict(out: &mut String, name: &str, typename: &str, fields: &[FieldDefinition]) {
+    let _ = writeln!(out, "{name} = TypedDict(\"{name}\", {{");
+    let _ = writeln!(out, "    \"__typename\": {typename},");
+    for field in leaf_fields(fields) {
+        let named = referenced_named_type(&field.field_type).is_some();
+        let py = optional(field_type_py(&field.field_type), field.nullable);
+        let _ = writeln!(out, "    \"{}\": {},", field.name, quote_if_named(py, named));
+    }
+    out.push_str("})\n");
+}
+
+fn queries(ctx: &Ctx) -> String {
+    let schema = ctx.schema;
+    let mut refs: BTreeSet<&str> = BTreeSet::new();
+    for q in &schema.queries {
+        refs.insert(&q.return_type);
+        collect_arg_refs(&q.arguments, &mut refs);
+        if q.relay {
+            refs.insert("Connection");
+        }
+    }
+
+    let mut out = operations_header();
+    push_imports(&mut out, ctx, refs.iter().copied(), "queries");
+
+    for q in &schema.queries {
+        let arguments = q.graphql_arguments();
+        let node = type_name_py(&q.return_type);
+        let result = if q.relay {
+            format!("Connection[{node}]")
+        } else {
+            let base = if q.returns_list {
+                format!("list[{node}]")
+            } else {
+                node
+            };
+            optional(base, q.nullable)
+        };
+        let selection = selection_for_return(ctx, &q.return_type, q.relay);
+        emit_operation(
+            &mut out,
+            "query",
+            &q.name,
+            &arguments,
+            q.relay,
+            &selection,
+            &result,
+            q.description.as_deref(),
+        );
+    }
+    finish(out)
+}
+
+fn mutations(ctx: &Ctx) -> String {
+    let schema = ctx.schema;
+    let mut refs: BTreeSet<&str> = BTreeSet::new();
+    for m in &schema.mutations {
+        refs.insert(&m.return_type);
+        collect_arg_refs(&m.arguments, &mut refs);
+    }
+
+    let mut out = operations_header();
+    push_imports(&mut out, ctx, refs.iter().copied(), "mutations");
+
+    for m in &schema.mutations {
+        let selection = selection_for_return(ctx, &m.return_type, false);
+        let result = type_name_py(&m.return_type);
+        emit_operation(
+            &mut out,
+            "mutation",
+            &m.name,
+            &m.arguments,
+            false,
+            &selection,
+            &result,
+            m.description.as_deref(),
+        );
+    }
+    finish(out)
+}
+
+fn init(modules: &[&str]) -> String {
+    let mut out = String::new();
+    for module in modules {
+        let _ = writeln!(out, "from .{module} import *  # noqa: F401,F403");
+    }
+    out
+}
+
+// =============================================================================
+// Operation building
+// =============================================================================
+
+fn operations_header() -> String {
+    String::from(
+        "from __future__ import annotations\n\nfrom typing import Any, Optional\n\nfrom .client import FraiseqlClient, compact\n",
+    )
+}
+
+fn collect_arg_refs<'a>(arguments: &'a [ArgumentDefinition], refs: &mut BTreeSet<&'a str>) {
+    for arg in arguments {
+        if let Some(name) = referenced_named_type(&arg.arg_type) {
+            refs.insert(name);
+        }
+    }
+}
+
+/// Emit the document constant and the keyword-only function wrapping it.
+#[allow(clippy::too_many_arguments)] // Reason: flat emitter; a struct would only rename the args
+fn emit_operation(
+    out: &mut String,
+    kind: &str,
+    name: &str,
+    arguments: &[ArgumentDefinition],
+    relay: bool,
+    selection: &str,
+    result: &str,
+    description: Option<&str>,
+) {
+    let mut var_decls = Vec::new();
+    let mut call_args = Vec::new();
+    let mut params = Vec::new();
+    let mut variables = Vec::new();
+
+    for arg in arguments {
+        let gql_type = arg.arg_type.to_graphql_string();
+        let gql_type = if arg.nullable {
+            gql_type
+        } else {
+            format!("{gql_type}!")
+        };
+        var_decls.push(format!("${}: {gql_type}", arg.name));
+        call_args.push(format!("{0}: ${0}", arg.name));
+
+        let param = param_name(&arg.name);
+        let py = field_type_py(&arg.arg_type);
+        if arg.nullable {
+            params.push(format!("{param}: Optional[{py}] = None"));
+        } else {
+            params.push(format!("{param}: {py}"));
+        }
+        variables.push(format!("\"{}\": {param}", arg.name));
+    }
+    if relay {
+        var_decls.push("$first: Int".to_string());
+        var_decls.push("$after: String".to_string());
+        call_args.push("first: $first".to_string());
+        call_args.push("after: $after".to_string());
+        params.push("first: Optional[int] = None".to_string());
+        params.push("after: Optional[str] = None".to_string());
+        variables.push("\"first\": first".to_string());
+        variables.push("\"after\": after".to_string());
+    }
+
+    let var_sig = if var_decls.is_empty() {
+        String::new()
+    } else {
+        format!("({})", var_decls.join(", "))
+    };
+    let call_sig = if call_args.is_empty() {
+        String::new()
+    } else {
+        format!("({})", call_args.join(", "))
+    };
+    let constant = const_name(name);
+
+    out.push_str("\n\n");
+    let _ = writeln!(
+        out,
+        "{constant} = \"\"\"{kind} {name}{var_sig} {{\n  {name}{call_sig} {{\n{selection}  }}\n}}\"\"\""
+    );
+    out.push_str("\n\n");
+
+    let signature = if params.is_empty() {
+        "client: FraiseqlClient".to_string()
+    } else {
+        format!("client: FraiseqlClient, *, {}", params.join(", "))
+    };
+    let _ = writeln!(out, "def {}({signature}) -> {result}:", snake_case(name));
+    push_doc(out, "    ", description);
+    if variables.is_empty() {
+        let _ = writeln!(out, "    data = client.request({constant})");
+    } else {
+        let _ = writeln!(
+            out,
+            "    data = client.request({constant}, compact({{{}}}))",
+            variables.join(", ")
+        );
+    }
+    let _ = writeln!(out, "    return data[\"{name}\"]");
+}
+
+/// Build the indented selection-set lines for an operation's return type,
+/// following the same rules as the `TypeScript` generator: leaf fields only,
+/// inline fragments per union member, relay results wrapped in the connection.
+fn selection_for_return(ctx: &Ctx, return_type: &str, relay: bool) -> String {
+    if relay {
+        let mut sel = String::from("    edges {\n      cursor\n      node {\n");
+        sel.push_str(&type_selection(ctx, return_type, "        "));
+        sel.push_str("      }\n    }\n");
+        sel.push_str("    pageInfo {\n      hasNextPage\n      hasPreviousPage\n      startCursor\n      endCursor\n    }\n");
+        return sel;
+    }
+    type_selection(ctx, return_type, "    ")
+}
+
+fn type_selection(ctx: &Ctx, type_name: &str, indent: &str) -> String {
+    let mut sel = String::new();
+    let _ = writeln!(sel, "{indent}__typename");
+    if let Some(union) = ctx.schema.unions.iter().find(|u| u.name == type_name) {
+        for member in &union.member_types {
+            let _ = writeln!(sel, "{indent}... on {member} {{");
+            sel.push_str(&leaf_name_lines(ctx, member, &format!("{indent}  ")));
+            let _ = writeln!(sel, "{indent}}}");
+        }
+    } else {
+        sel.push_str(&leaf_name_lines(ctx, type_name, indent));
+    }
+    sel
+}
+
+fn leaf_name_lines(ctx: &Ctx, type_name: &str, indent: &str) -> String {
+    let mut out = String::new();
+    if let Some(ty) = ctx.object_types.get(type_name) {
+        for field in leaf_fields(&ty.fields) {
+            let _ = writeln!(out, "{indent}{}", field.name);
+        }
+    }
+    out
+}
+
+// =============================================================================
+// Small shared helpers
+// =============================================================================
+
+/// Resolve a schema type name to its Python type (scalars mapped, else name).
+fn type_name_py(name: &str) -> String {
+    named_scalar_py(name).map_or_else(|| name.to_string(), str::to_string)
+}
+
+/// `getUser` → `get_user`, `postsConnection` → `posts_connection`.
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for ch in name.chars() {
+        if ch.is_ascii_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+            prev_lower = false;
+        } else {
+            out.push(ch);
+            prev_lower = ch.is_ascii_lowercase() || ch.is_ascii_digit();
+        }
+    }
+    out
+}
+
+/// `getUser` → `GET_USER`.
+fn const_name(name: &str) -> String {
+    snake_case(name).to_ascii_uppercase()
+}
+
+/// A Python-safe keyword parameter name for a `GraphQL` argument.
+fn param_name(name: &str) -> String {
+    let snake = snake_case(name);
+    if PY_KEYWORDS.contains(&snake.as_str()) {
+        format!("{snake}_")
+    } else {
+        snake
+    }
+}
+
+/// Emit a `from .module import A, B` line per generated module that defines one
+/// of `names` (scalars and names defined in `current` are skipped).
+fn push_imports<'a>(
+    out: &mut String,
+    ctx: &Ctx,
+    names: impl Iterator<Item = &'a str>,
+    current: &str,
+) {
+    let mut by_module: BTreeMap<&'static str, BTreeSet<&str>> = BTreeMap::new();
+    for name in names {
+        if let Some(module) = ctx.module_of(name) {
+            if module != current {
+                by_module.entry(module).or_default().insert(name);
+            }
+        }
+    }
+    if by_module.is_empty() {
+        return;
+    }
+    out.push('\n');
+    for (module, idents) in by_module {
+        let joined = idents.into_iter().collect::<Vec<_>>().join(", ");
+        let _ = writeln!(out, "from .{module} import {joined}");
+    }
+}
+
+/// Emit a docstring (module-level attribute docstrings sit below the
+/// assignment, function docstrings are the first statement of the body).
+fn push_doc(out: &mut String, indent: &str, description: Option<&str>) {
+    if let Some(desc) = description {
+        let escaped = desc.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+        let one_line = escaped.replace('\n', " ");
+        let _ = writeln!(out, "{indent}\"\"\"{one_line}\"\"\"");
+    }
+}
+
+/// Normalize trailing whitespace: collapse to a single trailing newline.
+fn finish(mut out: String) -> String {
+    while out.ends_with("\n\n") {
+        out.pop();
+    }
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+//! The `client.py` runtime template.
+//!
+//! Like the `TypeScript` runtime, this is identical for every generated client
+//! and depends only on the Python standard library (`urllib.request`). The
+//! generator prepends the schema-hash header; this constant is the body.
+
+/// Contents of the generated `client.py` (without the auto-generated header).
+pub(super) const CLIENT_PY: &str = r#""""Minimal GraphQL client over ``urllib``.
+
+The only runtime dependency of the generated client is the Python standard
+library. Pass a custom ``transport`` to route requests through ``httpx``,
+``requests``, or a test double.
+"""
+
+from __future__ import annotations
+
+import json
+import urllib.error
+import urllib.request
+from typing import Any, Callable, Mapping, Optional, Sequence, Union
+
+Headers = Union[Mapping[str, str], Callable[[], Mapping[str, str]]]
+Transport = Callable[[str, bytes, Mapping[str, str], float], bytes]
+
+
+class FraiseqlError(Exception):
+    """Raised when a GraphQL request fails at the HTTP or GraphQL-errors layer."""
+
+    def __init__(self, message: str, errors: Sequence[Mapping[str, Any]] = ()) -> None:
+        super().__init__(message)
+        self.errors = list(errors)
+
+
+def compact(values: Mapping[str, Any]) -> dict[str, Any]:
+    """Drop unset (``None``) variables so server-side argument defaults apply."""
+    return {key: value for key, value in values.items() if value is not None}
+
+
+def _urllib_transport(endpoint: str, body: bytes, headers: Mapping[str, str], timeout: float) -> bytes:
+    request = urllib.request.Request(endpoint, data=body, headers=dict(headers), method="POST")
+    try:
+        with urllib.request.urlopen(request, timeout=timeout) as response:
+            return response.read()
+    except urllib.error.HTTPError as exc:
+        raise FraiseqlError(f"GraphQL request failed with HTTP {exc.code} {exc.reason}") from exc
+
+
+class FraiseqlClient:
+    """Executes GraphQL documents against a FraiseQL endpoint.
+
+    The generated operation functions wrap :meth:`request` and unwrap their
+    single root field.
+    """
+
+    def __init__(
+        self,
+        endpoint: str,
+        *,
+        headers: Optional[Headers] = None,
+        timeout: float = 30.0,
+        transport: Optional[Transport] = None,
+    ) -> None:
+        self.endpoint = endpoint
+        self.headers = headers
+        self.timeout = timeout
+        self.transport = transport or _urllib_transport
+
+    def request(self, document: str, variables: Optional[Mapping[str, Any]] = None) -> dict[str, Any]:
+        """Execute a GraphQL document and return its ``data`` payload.
+
+        Raises :class:`FraiseqlError` when the response carries GraphQL errors,
+        a non-2xx HTTP status, or no ``data``.
+        """
+        extra = self.headers() if callable(self.headers) else (self.headers or {})
+        headers = {"content-type": "application/json", "accept": "application/json", **extra}
+        body = json.dumps({"query": document, "variables": dict(variables or {})}).encode()
+
+        payload = json.loads(self.transport(self.endpoint, body, headers, self.timeout))
+        errors = payload.get("errors") or []
+        if errors:
+            raise FraiseqlError(errors[0].get("message", "GraphQL error"), errors)
+        data = payload.get("data")
+        if data is None:
+            raise FraiseqlError("GraphQL response contained no data")
+        return data
+"#;
@@ -0,0 +1,68 @@
+//! Tests for the Python client generator.
+
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use std::path::Path;
+
+use fraiseql_core::schema::{
+    ArgumentDefinition, CompiledSchema, FieldDefinition, FieldType, QueryDefinition, TypeDefinition,
+};
+
+use super::{generate, param_name, parse_input_type, snake_case};
+
+fn user_schema() -> CompiledSchema {
+    let mut schema = CompiledSchema::default();
+    let mut user = TypeDefinition::new("User", "v_user");
+    user.fields.push(FieldDefinition::new("id", FieldType::Id));
+    user.fields.push(FieldDefinition::nullable("displayName", FieldType::String));
+    schema.types.push(user);
+
+    let mut get_user = QueryDefinition::new("getUser", "User");
+    get_user.nullable = true;
+    get_user.arguments.push(ArgumentDefinition::new("id", FieldType::Id));
+    schema.queries.push(get_user);
+    schema
+}
+
+#[test]
+fn snake_case_and_keyword_escaping() {
+    assert_eq!(snake_case("getUser"), "get_user");
+    assert_eq!(snake_case("postsConnection"), "posts_connection");
+    assert_eq!(param_name("from"), "from_");
+    assert_eq!(param_name("orderBy"), "order_by");
+}
+
+#[test]
+fn input_type_strings_preserve_nullability() {
+    assert_eq!(parse_input_type("String!"), ("str".to_string(), true));
+    assert_eq!(parse_input_type("[Int]"), ("list[Optional[int]]".to_string(), false));
+    assert_eq!(parse_input_type("[UserRole!]!"), ("list[UserRole]".to_string(), true));
+}
+
+#[test]
+fn generates_stamped_package_with_typed_operations() {
+    let files = generate(&user_schema()).unwrap();
+
+    for name in ["__init__.py", "client.py", "types.py", "queries.py"] {
+        let content = files.get(Path::new(name)).unwrap();
+        assert!(content.starts_with("# AUTO-GENERATED by fraiseql-codegen. DO NOT EDIT.\n"));
+    }
+    assert!(!files.contains_key(Path::new("mutations.py")), "no mutations → no module");
+
+    let types = &files[Path::new("types.py")];
+    assert!(types.contains("User = TypedDict(\"User\", {"));
+    assert!(types.contains("\"__typename\": Literal[\"User\"],"));
+    assert!(types.contains("\"displayName\": Optional[str],"));
+
+    let queries = &files[Path::new("queries.py")];
+    assert!(queries.contains("from .types import User"));
+    assert!(
+        queries.contains("def get_user(client: FraiseqlClient, *, id: str) -> Optional[User]:")
+    );
+    assert!(queries.contains("query getUser($id: ID!) {\n  getUser(id: $id) {"));
+    assert!(queries.contains("data = client.request(GET_USER, compact({\"id\": id}))"));
+    assert!(queries.contains("return data[\"getUser\"]"));
+
+    let init = &files[Path::new("__init__.py")];
+    assert!(init.contains("from .queries import *"));
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::unwrap_used)] // Reason: benchmark setup code, panics acceptable
+#![allow(missing_docs)] // Reason: criterion_group!/criterion_main! macros generate undocumented items
+
+//! JSONB Projection Throughput Benchmarks
+//!
+//! Measures the two CPU-bound steps of turning database rows into a GraphQL
+//! response:
+//!
+//! 1. **Key recasing** - `to_camel_case` (memchr-based) against the previous char-by-char
+//!    transform.
+//! 2. **Row projection** - `ResultProjector::project_results` pinned to one thread against the
+//!    default rayon pool, which takes over from `PARALLEL_PROJECTION_MIN_ROWS` rows.
+//!
+//! # Running Benchmarks
+//!
+//! ```bash
+//! cargo bench --bench projection_throughput
+//!
+//! # Only the parallel projection group
+//! cargo bench --bench projection_throughput -- "project_results"
+//! ```
+
+#![allow(clippy::missing_errors_doc)] // Reason: criterion_group! macro generates undocumented items
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use fraiseql_core::{
+    db::types::JsonbValue,
+    runtime::{PARALLEL_PROJECTION_MIN_ROWS, ResultProjector},
+    utils::casing::to_camel_case,
+};
+use serde_json::json;
+
+/// The char-by-char transform `to_camel_case` used before the memchr rewrite,
+/// kept as the baseline.
+fn to_camel_case_chars(s: &str) -> String {
+    if !s.contains('_') {
+        return s.to_string();
+    }
+    let mut result = String::with_capacity(s.len());
+    let mut capitalize_next = false;
+    for c in s.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+const KEYS: &[&str] = &[
+    "id",
+    "user_id",
+    "created_at",
+    "billing_address_line_2",
+    "last_successful_payment_method_fingerprint",
+    "alreadyCamel",
+];
+
+fn bench_camel_case(c: &mut Criterion) {
+    let mut group = c.benchmark_group("camel_case");
+    group.throughput(Throughput::Elements(KEYS.len() as u64));
+    group.bench_function("chars", |b| {
+        b.iter(|| KEYS.iter().map(|k| to_camel_case_chars(black_box(k))).collect::<Vec<_>>());
+    });
+    group.bench_function("memchr", |b| {
+        b.iter(|| KEYS.iter().map(|k| to_camel_case(black_box(k))).collect::<Vec<_>>());
+    });
+    group.finish();
+}
+
+/// Rows with 20 fields, 8 of which are projected.
+fn rows(count: usize) -> Vec<JsonbValue> {
+    (0..count)
+        .map(|i| {
+            let mut obj = serde_json::Map::new();
+            for f in 0..20 {
+                obj.insert(format!("field_{f}"), json!(format!("value {i}/{f}")));
+            }
+            obj.insert("profile".to_string(), json!({ "bio": "x".repeat(64), "age": i }));
+            JsonbValue::new(serde_json::Value::Object(obj))
+        })
+        .collect()
+}
+
+fn bench_project_results(c: &mut Criterion) {
+    let fields: Vec<String> = (0..8).map(|f| format!("field_{f}")).collect();
+    let projector = ResultProjector::new(fields);
+    let single_thread = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+
+    let mut group = c.benchmark_group("project_results");
+    for count in [
+        PARALLEL_PROJECTION_MIN_ROWS / 4,
+        PARALLEL_PROJECTION_MIN_ROWS * 8,
+        50_000,
+    ] {
+        let data = rows(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::new("single_thread", count), &data, |b, data| {
+            b.iter(|| single_thread.install(|| projector.project_results(data, true).unwrap()));
+        });
+        group.bench_with_input(BenchmarkId::new("rayon", count), &data, |b, data| {
+            b.iter(|| projector.project_results(data, true).unwrap());
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_camel_case, bench_project_results);
+criterion_main!(benches);
@@ -92,6 +92,30 @@ impl ApqMetrics {
         }
     }
 
+    /// Get the share of persisted-query resolutions that required a
+    /// registration (0.0 to 1.0).
+    ///
+    /// Every registration follows a `PersistedQueryNotFound` round trip, so
+    /// this is the fraction of APQ traffic that paid the extra request. With a
+    /// shared backend it should fall towards zero once every replica has seen
+    /// the working set; a persistently high value points at an in-process or
+    /// undersized store.
+    #[must_use]
+    pub fn registration_rate(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let stored = self.stored.load(Ordering::Relaxed);
+
+        if hits + stored == 0 {
+            0.0
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            // Reason: APQ counters are display metrics; f64 precision loss is acceptable
+            {
+                stored as f64 / (hits + stored) as f64
+            }
+        }
+    }
+
     /// Get metrics as JSON value
     #[must_use]
     pub fn as_json(&self) -> serde_json::Value {
@@ -101,6 +125,7 @@ impl ApqMetrics {
             "stored": self.get_stored(),
             "errors": self.get_errors(),
             "hit_rate": self.hit_rate(),
+            "registration_rate": self.registration_rate(),
         })
     }
 
@@ -14,11 +14,13 @@
 //!
 //! - **hasher**: Query hashing with SHA-256 (pure Rust implementation)
 //! - **storage**: APQ result storage and retrieval
+//! - **postgres_storage**: Shared `PostgreSQL` backend for multi-replica deployments
 //! - **metrics**: APQ performance metrics and monitoring
 
 pub mod hasher; // Pure Rust query hasher
 pub mod memory_storage;
 pub mod metrics;
+pub mod postgres_storage;
 #[cfg(feature = "redis-apq")]
 pub mod redis_storage;
 pub mod storage;
@@ -27,6 +29,7 @@ pub mod storage;
 pub use hasher::{hash_query, hash_query_with_variables, verify_hash, verify_hash_with_variables};
 pub use memory_storage::InMemoryApqStorage;
 pub use metrics::ApqMetrics;
+pub use postgres_storage::PostgresApqStorage;
 #[cfg(feature = "redis-apq")]
 pub use redis_storage::RedisApqStorage;
 pub use storage::{ApqError, ApqStats, ApqStorage, ArcApqStorage};
@@ -0,0 +1,341 @@
+//! `PostgreSQL`-backed APQ storage backend.
+//!
+//! Stores persisted queries in a shared table so that a hash registered on one
+//! replica resolves on every other replica, and registrations survive
+//! restarts. Expired rows are ignored on read and deleted by
+//! [`PostgresApqStorage::run_cleanup`], which is meant to run on a
+//! long-lived background task.
+//!
+//! Like the Redis backend, the request-path operations (`get`, `set`,
+//! `exists`, `remove`) fail open: database errors are logged, counted, and
+//! treated as cache misses, since APQ is an optimisation, not a correctness
+//! requirement.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use deadpool_postgres::Pool;
+use serde_json::json;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, warn};
+
+use super::storage::{ApqError, ApqStats, ApqStorage};
+
+/// Default table holding persisted queries.
+pub const DEFAULT_APQ_TABLE: &str = "tb_apq_query";
+
+/// Default TTL for stored queries (24 hours).
+///
+/// Longer than the in-memory default because the table is shared and
+/// persistent: one registration serves every replica.
+const DEFAULT_TTL: Duration = Duration::from_secs(86_400);
+
+/// Counter of `PostgreSQL` errors encountered (for metrics / diagnostics).
+static POSTGRES_APQ_ERRORS: AtomicU64 = AtomicU64::new(0);
+
+/// Counter of expired rows deleted by the background cleanup.
+static POSTGRES_APQ_PURGED: AtomicU64 = AtomicU64::new(0);
+
+/// Return the cumulative count of `PostgreSQL` APQ errors since process start.
+#[must_use]
+pub fn postgres_apq_error_count_total() -> u64 {
+    POSTGRES_APQ_ERRORS.load(Ordering::Relaxed)
+}
+
+/// Return the cumulative count of expired APQ rows purged since process start.
+#[must_use]
+pub fn postgres_apq_purged_count_total() -> u64 {
+    POSTGRES_APQ_PURGED.load(Ordering::Relaxed)
+}
+
+/// `PostgreSQL`-backed APQ storage shared across replicas.
+///
+/// Each persisted query is one row keyed by its SHA-256 hash. Re-registering
+/// a hash refreshes its expiry, so queries in active use never age out while
+/// abandoned ones are reclaimed by the cleanup task.
+pub struct PostgresApqStorage {
+    pool:  Arc<Pool>,
+    table: String,
+    ttl:   Duration,
+}
+
+impl PostgresApqStorage {
+    /// Connect to `PostgreSQL`, create the APQ table if needed, and return the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApqError::DatabaseError`] if the pool cannot be created or the
+    /// table DDL fails.
+    pub async fn connect(database_url: &str) -> Result<Self, ApqError> {
+        let pg_config = deadpool_postgres::Config {
+            url: Some(database_url.to_string()),
+            // Small dedicated pool — lookups are single-row primary-key reads.
+            pool: Some(deadpool_postgres::PoolConfig::new(4)),
+            ..Default::default()
+        };
+        let pool = pg_config
+            .create_pool(
+                Some(deadpool_postgres::Runtime::Tokio1),
+                deadpool_postgres::tokio_postgres::NoTls,
+            )
+            .map_err(|e| ApqError::DatabaseError(format!("Failed to create APQ pool: {e}")))?;
+        let store = Self::with_pool(Arc::new(pool));
+        store.ensure_table().await?;
+        Ok(store)
+    }
+
+    /// Build the store over an existing pool using the default table and TTL.
+    ///
+    /// The caller is responsible for calling [`Self::ensure_table`].
+    #[must_use]
+    pub fn with_pool(pool: Arc<Pool>) -> Self {
+        Self {
+            pool,
+            table: DEFAULT_APQ_TABLE.to_string(),
+            ttl: DEFAULT_TTL,
+        }
+    }
+
+    /// Use a different table, optionally schema-qualified (`schema.table`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApqError::ConfigError`] if `table` is not a plain SQL identifier.
+    pub fn with_table(mut self, table: impl Into<String>) -> Result<Self, ApqError> {
+        let table = table.into();
+        if !is_valid_table_name(&table) {
+            return Err(ApqError::ConfigError(format!(
+                "invalid APQ table name '{table}': expected [schema.]identifier"
+            )));
+        }
+        self.table = table;
+        Ok(self)
+    }
+
+    /// Set the TTL applied when a query is registered.
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Table holding the persisted queries.
+    #[must_use]
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    /// Create the APQ table and its expiry index if they do not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApqError::DatabaseError`] if the DDL fails.
+    pub async fn ensure_table(&self) -> Result<(), ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        let index = self.table.replace('.', "_");
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {table} (
+                    hash       TEXT PRIMARY KEY,
+                    query      TEXT NOT NULL,
+                    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                    expires_at TIMESTAMPTZ NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS idx_{index}_expires_at
+                    ON {table} (expires_at);",
+                table = self.table,
+            ))
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    /// Delete every expired row and return how many were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApqError::DatabaseError`] if the delete fails.
+    pub async fn purge_expired(&self) -> Result<u64, ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        let purged = client
+            .execute(&format!("DELETE FROM {} WHERE expires_at <= now()", self.table), &[])
+            .await
+            .map_err(db_error)?;
+        POSTGRES_APQ_PURGED.fetch_add(purged, Ordering::Relaxed);
+        Ok(purged)
+    }
+
+    /// Purge expired rows every `interval` for as long as the future is polled.
+    ///
+    /// Every replica may run this; the delete is idempotent, so concurrent
+    /// cleanups only cost a redundant index scan. Errors are logged and the
+    /// loop carries on.
+    pub async fn run_cleanup(self: Arc<Self>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            match self.purge_expired().await {
+                Ok(0) => {},
+                Ok(purged) => debug!(purged, table = %self.table, "APQ: purged expired queries"),
+                Err(e) => {
+                    POSTGRES_APQ_ERRORS.fetch_add(1, Ordering::Relaxed);
+                    warn!(error = %e, table = %self.table, "APQ: cleanup failed");
+                },
+            }
+        }
+    }
+
+    /// Record a database error and return a fail-open result.
+    fn fail_open<T: Default>(err: &ApqError, operation: &str) -> Result<T, ApqError> {
+        POSTGRES_APQ_ERRORS.fetch_add(1, Ordering::Relaxed);
+        warn!(operation, error = %err, "PostgreSQL APQ: fail-open on error");
+        Ok(T::default())
+    }
+
+    async fn try_get(&self, hash: &str) -> Result<Option<String>, ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        let row = client
+            .query_opt(
+                &format!("SELECT query FROM {} WHERE hash = $1 AND expires_at > now()", self.table),
+                &[&hash],
+            )
+            .await
+            .map_err(db_error)?;
+        Ok(row.map(|r| r.get(0)))
+    }
+
+    async fn try_set(&self, hash: &str, query: &str) -> Result<(), ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        let ttl_secs = self.ttl.as_secs_f64();
+        client
+            .execute(
+                &format!(
+                    "INSERT INTO {} (hash, query, expires_at)
+                     VALUES ($1, $2, now() + make_interval(secs => $3))
+                     ON CONFLICT (hash) DO UPDATE
+                        SET query = EXCLUDED.query, expires_at = EXCLUDED.expires_at",
+                    self.table
+                ),
+                &[&hash, &query, &ttl_secs],
+            )
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn try_remove(&self, hash: &str) -> Result<(), ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        client
+            .execute(&format!("DELETE FROM {} WHERE hash = $1", self.table), &[&hash])
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+// Reason: ApqStorage is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl ApqStorage for PostgresApqStorage {
+    async fn get(&self, hash: &str) -> Result<Option<String>, ApqError> {
+        match self.try_get(hash).await {
+            Ok(result) => Ok(result),
+            Err(e) => Self::fail_open(&e, "SELECT"),
+        }
+    }
+
+    async fn set(&self, hash: String, query: String) -> Result<(), ApqError> {
+        match self.try_set(&hash, &query).await {
+            Ok(()) => Ok(()),
+            Err(e) => Self::fail_open(&e, "UPSERT"),
+        }
+    }
+
+    async fn exists(&self, hash: &str) -> Result<bool, ApqError> {
+        match self.try_get(hash).await {
+            Ok(result) => Ok(result.is_some()),
+            Err(e) => Self::fail_open(&e, "EXISTS"),
+        }
+    }
+
+    async fn remove(&self, hash: &str) -> Result<(), ApqError> {
+        match self.try_remove(hash).await {
+            Ok(()) => Ok(()),
+            Err(e) => Self::fail_open(&e, "DELETE"),
+        }
+    }
+
+    async fn stats(&self) -> Result<ApqStats, ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        let row = client
+            .query_one(
+                &format!(
+                    "SELECT count(*) FILTER (WHERE expires_at > now()),
+                            count(*) FILTER (WHERE expires_at <= now())
+                     FROM {}",
+                    self.table
+                ),
+                &[],
+            )
+            .await
+            .map_err(db_error)?;
+        let live: i64 = row.get(0);
+        let expired: i64 = row.get(1);
+
+        Ok(ApqStats::with_extra(
+            usize::try_from(live).unwrap_or(0),
+            "postgres".to_string(),
+            json!({
+                "table": self.table,
+                "ttl_secs": self.ttl.as_secs(),
+                "expired_pending_cleanup": expired,
+                "postgres_errors_total": postgres_apq_error_count_total(),
+                "purged_total": postgres_apq_purged_count_total(),
+            }),
+        ))
+    }
+
+    async fn clear(&self) -> Result<(), ApqError> {
+        let client = self.pool.get().await.map_err(pool_error)?;
+        client
+            .batch_execute(&format!("DELETE FROM {}", self.table))
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+/// Accept `identifier` or `schema.identifier`, where each part is a plain
+/// unquoted SQL identifier of at most 63 bytes.
+///
+/// The table name is interpolated into SQL, so anything else is rejected.
+pub(crate) fn is_valid_table_name(name: &str) -> bool {
+    let mut parts = name.split('.');
+    let valid_part = |part: &str| {
+        let mut chars = part.chars();
+        part.len() <= 63
+            && chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(table), None, None) => valid_part(table),
+        (Some(schema), Some(table), None) => valid_part(schema) && valid_part(table),
+        _ => false,
+    }
+}
+
+fn pool_error(e: deadpool_postgres::PoolError) -> ApqError {
+    ApqError::DatabaseError(format!("APQ pool error: {e}"))
+}
+
+fn db_error(e: deadpool_postgres::tokio_postgres::Error) -> ApqError {
+    ApqError::DatabaseError(e.to_string())
+}
@@ -563,6 +563,28 @@ mod metrics_tests {
         assert_eq!(json["errors"], 0);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)] // Reason: test assertion comparing exact metric values
+    fn test_registration_rate_no_requests() {
+        let metrics = ApqMetrics::default();
+        assert_eq!(metrics.registration_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_registration_rate_mixed() {
+        let metrics = ApqMetrics::default();
+        for _ in 0..3 {
+            metrics.record_hit();
+        }
+        metrics.record_miss();
+        metrics.record_store();
+
+        // Misses are the PersistedQueryNotFound round trips; only completed
+        // registrations count against hits.
+        assert!((metrics.registration_rate() - 0.25).abs() < 0.0001);
+        assert!((metrics.as_json()["registration_rate"].as_f64().unwrap() - 0.25).abs() < 0.0001);
+    }
+
     #[test]
     fn test_reset() {
         let metrics = ApqMetrics::default();
@@ -655,6 +677,75 @@ mod redis_storage_tests {
     }
 }
 
+mod postgres_storage_tests {
+
+    #![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+    use super::super::{postgres_storage::is_valid_table_name, *};
+
+    #[test]
+    fn test_table_name_accepts_plain_and_qualified() {
+        assert!(is_valid_table_name("tb_apq_query"));
+        assert!(is_valid_table_name("_apq"));
+        assert!(is_valid_table_name("cache.tb_apq_query"));
+    }
+
+    #[test]
+    fn test_table_name_rejects_injection() {
+        assert!(!is_valid_table_name(""));
+        assert!(!is_valid_table_name("1apq"));
+        assert!(!is_valid_table_name("apq; DROP TABLE users"));
+        assert!(!is_valid_table_name("\"apq\""));
+        assert!(!is_valid_table_name("a.b.c"));
+        assert!(!is_valid_table_name("schema."));
+        assert!(!is_valid_table_name(&"a".repeat(64)));
+    }
+
+    /// These tests require a running `PostgreSQL` instance at `DATABASE_URL`.
+    /// Run with: `DATABASE_URL=postgres://localhost/fraiseql_test cargo test -p fraiseql-core
+    /// -- postgres_apq --ignored`
+    fn database_url() -> Option<String> {
+        std::env::var("DATABASE_URL").ok()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn postgres_apq_shared_across_instances() {
+        let url = database_url().expect("DATABASE_URL must be set");
+        let replica_a = PostgresApqStorage::connect(&url).await.unwrap();
+        let replica_b = PostgresApqStorage::connect(&url).await.unwrap();
+
+        let hash = "postgres_apq_shared_hash";
+        let query = "{ users { id name } }";
+        replica_a.set(hash.to_string(), query.to_string()).await.unwrap();
+
+        assert_eq!(replica_b.get(hash).await.unwrap().as_deref(), Some(query));
+        assert!(replica_b.exists(hash).await.unwrap());
+
+        replica_b.remove(hash).await.unwrap();
+        assert!(replica_a.get(hash).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn postgres_apq_expired_rows_are_hidden_and_purged() {
+        let url = database_url().expect("DATABASE_URL must be set");
+        let store = PostgresApqStorage::connect(&url)
+            .await
+            .unwrap()
+            .with_ttl(std::time::Duration::ZERO);
+
+        let hash = "postgres_apq_expired_hash";
+        store.set(hash.to_string(), "{ users { id } }".to_string()).await.unwrap();
+
+        assert!(store.get(hash).await.unwrap().is_none());
+        assert!(store.purge_expired().await.unwrap() >= 1);
+        let stats = store.stats().await.unwrap();
+        assert_eq!(stats.backend, "postgres");
+        assert_eq!(stats.extra["expired_pending_cleanup"], 0);
+    }
+}
+
 mod storage_tests {
 
     use super::{super::*, *};
@@ -78,7 +78,7 @@ use crate::{
     cache::config::RlsEnforcement,
     db::{
         ChangeLogWrite, DatabaseAdapter, DatabaseType, DirectMutationContext, MutationStrategy,
-        PoolMetrics, SupportsMutations, WhereClause,
+        MutationTransaction, PoolMetrics, SupportsMutations, WhereClause,
         types::{JsonbValue, OrderByClause},
     },
     error::{FraiseQLError, Result},
@@ -90,6 +90,7 @@ mod query;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use query::accessed_views;
 pub use query::view_name_to_entity_type;
 
 /// Cached database adapter wrapper.
@@ -615,6 +616,15 @@ impl<A: DatabaseAdapter + Clone> Clone for CachedDatabaseAdapter<A> {
     }
 }
 
+/// Whether `session_vars` leave query results unchanged, so the cache may serve them.
+///
+/// `statement_timeout` (set from the request time budget) only bounds how long a
+/// statement may run, not what it returns. A cache miss on this path runs
+/// without it; the executor's own timeout still bounds the request.
+fn only_cache_neutral(session_vars: &[(&str, &str)]) -> bool {
+    session_vars.iter().all(|(name, _)| *name == "statement_timeout")
+}
+
 // Reason: DatabaseAdapter is defined with #[async_trait]; all implementations must match
 // its transformed method signatures to satisfy the trait contract
 // async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
@@ -753,6 +763,12 @@ impl<A: DatabaseAdapter> DatabaseAdapter for CachedDatabaseAdapter<A> {
             .await
     }
 
+    async fn begin_mutation_transaction(&self) -> Result<Box<dyn MutationTransaction>> {
+        // Mutations are never cached. The executor defers the batch's cache
+        // invalidation until the transaction commits, and drops it on rollback.
+        self.adapter.begin_mutation_transaction().await
+    }
+
     // Mutation-strategy delegation: a cache-wrapped adapter must report and use the
     // inner adapter's strategy, so a wrapped SqliteAdapter still dispatches DirectSql
     // instead of falling back to the trait defaults (FunctionCall / Unsupported).
@@ -781,8 +797,8 @@ impl<A: DatabaseAdapter> DatabaseAdapter for CachedDatabaseAdapter<A> {
         order_by: Option<&[OrderByClause]>,
         session_vars: &[(&str, &str)],
     ) -> Result<Arc<Vec<JsonbValue>>> {
-        // No session variables => preserve the cached read path unchanged.
-        if session_vars.is_empty() {
+        // No tenant-scoping session variables => preserve the cached read path.
+        if only_cache_neutral(session_vars) {
             return self
                 .execute_where_query_impl(view, where_clause, limit, offset, order_by)
                 .await;
@@ -809,8 +825,8 @@ impl<A: DatabaseAdapter> DatabaseAdapter for CachedDatabaseAdapter<A> {
         request: &crate::db::ProjectionRequest<'_>,
         session_vars: &[(&str, &str)],
     ) -> Result<Arc<Vec<JsonbValue>>> {
-        // No session variables => preserve the cached read path unchanged.
-        if session_vars.is_empty() {
+        // No tenant-scoping session variables => preserve the cached read path.
+        if only_cache_neutral(session_vars) {
             return self
                 .execute_with_projection_impl(
                     request.view,
@@ -863,6 +879,7 @@ impl<A: DatabaseAdapter> DatabaseAdapter for CachedDatabaseAdapter<A> {
         // Clear all cached entries — they reference the old schema's content hash
         // and per-view TTL configuration.
         let _ = self.cache.clear();
+        self.adapter.on_schema_reload();
     }
 }
 
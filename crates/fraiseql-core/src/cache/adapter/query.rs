@@ -54,6 +54,50 @@ pub fn view_name_to_entity_type(view: &str) -> Option<String> {
     Some(pascal)
 }
 
+/// Views a cached result depends on: `view` plus the related views read by its
+/// relation filters and relationship-aggregate orderings, so a write to a child
+/// view also invalidates parent queries that filter or sort on it.
+pub(crate) fn accessed_views(
+    view: &str,
+    where_clause: Option<&WhereClause>,
+    order_by: Option<&[OrderByClause]>,
+) -> Vec<String> {
+    let mut views = vec![view.to_string()];
+    if let Some(clause) = where_clause {
+        collect_relation_sources(clause, &mut views);
+    }
+    for clause in order_by.into_iter().flatten() {
+        if let Some(join) = clause.aggregate.as_ref().and_then(|a| a.join.as_ref()) {
+            push_unique(&mut views, &join.source);
+        }
+    }
+    views
+}
+
+fn collect_relation_sources(clause: &WhereClause, views: &mut Vec<String>) {
+    match clause {
+        WhereClause::And(clauses) | WhereClause::Or(clauses) => {
+            for c in clauses {
+                collect_relation_sources(c, views);
+            }
+        },
+        WhereClause::Not(inner) => collect_relation_sources(inner, views),
+        WhereClause::Relation(relation) => {
+            if let Some(join) = &relation.join {
+                push_unique(views, &join.source);
+            }
+            collect_relation_sources(&relation.filter, views);
+        },
+        _ => {},
+    }
+}
+
+fn push_unique(views: &mut Vec<String>, view: &str) {
+    if !views.iter().any(|v| v == view) {
+        views.push(view.to_string());
+    }
+}
+
 impl<A: DatabaseAdapter> CachedDatabaseAdapter<A> {
     /// Cache-aware implementation of `execute_with_projection`.
     ///
@@ -115,7 +159,7 @@ impl<A: DatabaseAdapter> CachedDatabaseAdapter<A> {
         self.cache.put_arc(
             cache_key,
             Arc::clone(&arc),
-            vec![view.to_string()],
+            accessed_views(view, where_clause, order_by),
             ttl,
             entity_type.as_deref(),
         )?;
@@ -190,7 +234,7 @@ impl<A: DatabaseAdapter> CachedDatabaseAdapter<A> {
         self.cache.put_arc(
             cache_key,
             Arc::clone(&arc),
-            vec![view.to_string()],
+            accessed_views(view, where_clause, order_by),
             ttl,
             entity_type.as_deref(),
         )?;
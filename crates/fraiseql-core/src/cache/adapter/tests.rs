@@ -155,6 +155,32 @@ async fn test_cache_miss_then_hit() {
     assert_eq!(adapter.inner().call_count(), 1); // Still 1 - cache hit!
 }
 
+#[tokio::test]
+async fn test_statement_timeout_session_var_keeps_cache() {
+    let mock = MockAdapter::new();
+    let cache = QueryResultCache::new(CacheConfig::enabled());
+    let adapter = CachedDatabaseAdapter::new(mock, cache, "1.0.0".to_string());
+    let budget = [("statement_timeout", "250")];
+
+    for _ in 0..2 {
+        adapter
+            .execute_where_query_arc_with_session("v_user", None, None, None, None, &budget)
+            .await
+            .unwrap();
+    }
+    assert_eq!(adapter.inner().call_count(), 1, "a time budget must not bypass the cache");
+
+    // Tenant-scoping variables still bypass it.
+    let tenant = [("statement_timeout", "250"), ("app.tenant_id", "t1")];
+    for _ in 0..2 {
+        adapter
+            .execute_where_query_arc_with_session("v_user", None, None, None, None, &tenant)
+            .await
+            .unwrap();
+    }
+    assert_eq!(adapter.inner().call_count(), 3);
+}
+
 #[tokio::test]
 async fn test_different_where_clauses_produce_different_cache_entries() {
     let mock = MockAdapter::new();
@@ -1186,3 +1212,39 @@ async fn with_rls_does_not_disable_cache() {
         .unwrap();
     assert_eq!(adapter.inner().call_count(), 1, "has_rls=true must not prevent cache hits");
 }
+
+#[test]
+fn test_accessed_views_include_related_views() {
+    use crate::db::{
+        OrderByAggregate, OrderByAggregateFunction, OrderDirection, RelationFilter, RelationJoin,
+        RelationQuantifier,
+    };
+
+    let join = |source: &str| RelationJoin {
+        source:     source.to_string(),
+        alias:      "_r0".to_string(),
+        key:        "user_id".to_string(),
+        parent:     "v_user".to_string(),
+        parent_key: "id".to_string(),
+    };
+    let filter = WhereClause::Relation(Box::new(RelationFilter {
+        path:       vec!["orders".to_string()],
+        quantifier: RelationQuantifier::Some,
+        filter:     WhereClause::And(vec![]),
+        join:       Some(join("v_order")),
+    }));
+    let mut reviews = OrderByClause::new("reviews".to_string(), OrderDirection::Desc);
+    reviews.aggregate = Some(OrderByAggregate {
+        function: OrderByAggregateFunction::Count,
+        field:    None,
+        join:     Some(join("v_review")),
+    });
+    let mut orders = reviews.clone();
+    orders.aggregate.as_mut().unwrap().join = Some(join("v_order"));
+
+    assert_eq!(
+        accessed_views("v_user", Some(&filter), Some(&[reviews, orders])),
+        vec!["v_user", "v_order", "v_review"]
+    );
+    assert_eq!(accessed_views("v_user", None, None), vec!["v_user"]);
+}
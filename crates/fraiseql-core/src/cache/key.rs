@@ -390,8 +390,8 @@ fn hash_where_operator(h: &mut impl Hasher, op: &WhereOperator) {
 
 /// Hash an optional `OrderByClause` slice into the given hasher.
 ///
-/// Hashes each clause's `storage_key()` (`snake_case`) and `direction` discriminant,
-/// ensuring that different orderings produce different cache keys.
+/// Hashes each clause's `storage_key()` (`snake_case`), `direction` discriminant and
+/// relationship aggregate, ensuring that different orderings produce different cache keys.
 fn hash_order_by(h: &mut impl Hasher, order_by: Option<&[OrderByClause]>) {
     match order_by.filter(|c| !c.is_empty()) {
         Some(clauses) => {
@@ -401,6 +401,14 @@ fn hash_order_by(h: &mut impl Hasher, order_by: Option<&[OrderByClause]>) {
                 let key = clause.storage_key();
                 h.write(key.as_bytes());
                 h.write_u8(clause.direction as u8);
+                match &clause.aggregate {
+                    Some(aggregate) => {
+                        h.write_u8(1);
+                        h.write(aggregate.function.as_sql().as_bytes());
+                        h.write(aggregate.field.as_deref().unwrap_or_default().as_bytes());
+                    },
+                    None => h.write_u8(0),
+                }
             }
         },
         None => h.write_u8(0),
@@ -225,6 +225,7 @@ pub mod fact_table_version;
 // Re-export `ViewName` from `fraiseql-db` so consumers of the cache API can
 // import the typed view-name newtype alongside `QueryResultCache` etc. without
 // pulling in `fraiseql-db` directly.
+pub(crate) use adapter::accessed_views;
 pub use adapter::{CachedDatabaseAdapter, view_name_to_entity_type};
 pub use cascade_invalidator::{CascadeInvalidator, InvalidationStats};
 pub use cascade_metadata::CascadeMetadata;
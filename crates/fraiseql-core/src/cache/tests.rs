@@ -1631,10 +1631,12 @@ mod key_tests {
             sql_source:          Some("v_user".to_string()),
             description:         None,
             auto_params:         AutoParams {
-                has_where:    true,
-                has_order_by: false,
-                has_limit:    true,
-                has_offset:   false,
+                has_where:           true,
+                has_order_by:        false,
+                has_limit:           true,
+                has_offset:          false,
+                has_search:          false,
+                has_include_deleted: false,
             },
             deprecation:         None,
             jsonb_column:        "data".to_string(),
@@ -1667,10 +1669,12 @@ mod key_tests {
             sql_source:          None, // No SQL source (custom resolver)
             description:         None,
             auto_params:         AutoParams {
-                has_where:    false,
-                has_order_by: false,
-                has_limit:    false,
-                has_offset:   false,
+                has_where:           false,
+                has_order_by:        false,
+                has_limit:           false,
+                has_offset:          false,
+                has_search:          false,
+                has_include_deleted: false,
             },
             deprecation:         None,
             jsonb_column:        "data".to_string(),
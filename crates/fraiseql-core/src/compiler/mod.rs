@@ -8,6 +8,9 @@
 //!   (`AuthoringIR`) and validate it. Used by the CLI `validate-facts` command and by
 //!   `SchemaConverter` (in `fraiseql-cli`).
 //!
+//! - **Plugins** — [`CompilerPlugin`] transforms applied to the compiled schema before SQL codegen,
+//!   run in a deterministic order recorded in the artifact.
+//!
 //! - **Runtime sub-modules** — aggregate types, aggregation planning, fact tables, and window
 //!   functions, consumed by the runtime executor.
 
@@ -17,6 +20,7 @@ pub mod enum_validator;
 pub mod fact_table;
 pub mod ir;
 pub mod parser;
+pub mod plugin;
 pub mod validator;
 pub mod window_allowlist;
 pub mod window_functions;
@@ -29,6 +33,9 @@ pub use ir::{
     MutationOperation,
 };
 pub use parser::SchemaParser;
+pub use plugin::{
+    AppliedCompilerPlugin, CompilerPlugin, CompilerPluginRegistry, InjectFieldsPlugin,
+};
 pub use validator::{SchemaValidationError, SchemaValidator};
 pub use window_functions::{WindowExecutionPlan, WindowFunction, WindowFunctionPlanner};
 
@@ -0,0 +1,172 @@
+//! Compiler plugins: schema transforms run before SQL codegen.
+//!
+//! A [`CompilerPlugin`] rewrites the [`CompiledSchema`] after conversion and
+//! before the optimizer derives SQL hints, so conventions such as soft-delete
+//! filters, naming rules or audit columns can be injected without touching the
+//! authoring SDKs. Plugins come from external crates (implement the trait and
+//! register it) or from configuration ([`InjectFieldsPlugin`]).
+//!
+//! [`CompilerPluginRegistry::apply`] runs plugins in a deterministic order —
+//! ascending [`CompilerPlugin::order`], ties broken by name — regardless of
+//! registration order, and records that order in
+//! [`CompiledSchema::compiler_plugins`] so the artifact (and its content hash)
+//! shows exactly which transforms produced it.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{FraiseQLError, Result},
+    schema::{CompiledSchema, FieldDefinition},
+};
+
+/// A transform over the compiled schema, run before SQL codegen.
+pub trait CompilerPlugin: Send + Sync {
+    /// Unique plugin name, recorded in the compiled artifact.
+    fn name(&self) -> &str;
+
+    /// Position in the plugin pipeline; lower runs first. Defaults to `0`.
+    fn order(&self) -> i32 {
+        0
+    }
+
+    /// Transform the schema in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error to abort compilation.
+    fn transform(&self, schema: &mut CompiledSchema) -> Result<()>;
+}
+
+/// A plugin applied while compiling, as recorded in the compiled artifact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedCompilerPlugin {
+    /// Plugin name.
+    pub name:  String,
+    /// Order the plugin ran at.
+    #[serde(default)]
+    pub order: i32,
+}
+
+/// Ordered set of compiler plugins.
+#[derive(Default)]
+pub struct CompilerPluginRegistry {
+    plugins: Vec<Box<dyn CompilerPlugin>>,
+}
+
+impl fmt::Debug for CompilerPluginRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.plugins.iter().map(|p| p.name())).finish()
+    }
+}
+
+impl CompilerPluginRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Configuration` if a plugin with the same name is
+    /// already registered.
+    pub fn register(&mut self, plugin: Box<dyn CompilerPlugin>) -> Result<()> {
+        if self.plugins.iter().any(|p| p.name() == plugin.name()) {
+            return Err(FraiseQLError::Configuration {
+                message: format!("compiler plugin `{}` is registered twice", plugin.name()),
+            });
+        }
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Number of registered plugins.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// Whether no plugin is registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Plugins in execution order: ascending `order`, then name.
+    fn ordered(&self) -> Vec<&dyn CompilerPlugin> {
+        let mut ordered: Vec<&dyn CompilerPlugin> =
+            self.plugins.iter().map(AsRef::as_ref).collect();
+        ordered.sort_by(|a, b| a.order().cmp(&b.order()).then_with(|| a.name().cmp(b.name())));
+        ordered
+    }
+
+    /// Run every plugin against `schema` in execution order and record the
+    /// order in `schema.compiler_plugins`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first plugin error; plugins after it are not run.
+    pub fn apply(&self, schema: &mut CompiledSchema) -> Result<()> {
+        for plugin in self.ordered() {
+            plugin.transform(schema)?;
+            schema.compiler_plugins.push(AppliedCompilerPlugin {
+                name:  plugin.name().to_string(),
+                order: plugin.order(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Config-driven plugin adding fields (e.g. audit columns) to object types.
+///
+/// Fields are appended to every type listed in `types`, or to every object
+/// type when `types` is empty. A type that already declares a field of the
+/// same name keeps its own definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InjectFieldsPlugin {
+    /// Plugin name.
+    pub name:   String,
+    /// Pipeline order.
+    pub order:  i32,
+    /// Target type names; empty targets every object type.
+    pub types:  Vec<String>,
+    /// Fields to add.
+    pub fields: Vec<FieldDefinition>,
+}
+
+impl CompilerPlugin for InjectFieldsPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn order(&self) -> i32 {
+        self.order
+    }
+
+    fn transform(&self, schema: &mut CompiledSchema) -> Result<()> {
+        if let Some(missing) = self.types.iter().find(|name| schema.find_type(name).is_none()) {
+            return Err(FraiseQLError::Configuration {
+                message: format!(
+                    "compiler plugin `{}` targets unknown type `{missing}`",
+                    self.name
+                ),
+            });
+        }
+        for ty in &mut schema.types {
+            if !self.types.is_empty() && !self.types.iter().any(|t| ty.name == t.as_str()) {
+                continue;
+            }
+            for field in &self.fields {
+                if !ty.fields.iter().any(|f| f.name == field.name) {
+                    ty.fields.push(field.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+}
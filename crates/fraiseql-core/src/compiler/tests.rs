@@ -2689,3 +2689,206 @@ mod aggregation_tests {
             .unwrap_or_else(|e| panic!("any path should be accepted when paths empty: {e}"));
     }
 }
+
+// ---------------------------------------------------------------------------
+// plugin.rs tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod plugin_tests {
+    use super::super::plugin::{
+        AppliedCompilerPlugin, CompilerPlugin, CompilerPluginRegistry, InjectFieldsPlugin,
+    };
+    use crate::{
+        error::{FraiseQLError, Result},
+        schema::{CompiledSchema, FieldDefinition, FieldType, TypeDefinition},
+    };
+
+    /// Appends its name to the description of the first type, so the test can
+    /// observe the order plugins ran in.
+    struct Marker {
+        name:  &'static str,
+        order: i32,
+    }
+
+    impl CompilerPlugin for Marker {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn order(&self) -> i32 {
+            self.order
+        }
+
+        fn transform(&self, schema: &mut CompiledSchema) -> Result<()> {
+            let ty = &mut schema.types[0];
+            let trail = ty.description.get_or_insert_with(String::new);
+            trail.push_str(self.name);
+            trail.push(';');
+            Ok(())
+        }
+    }
+
+    struct Failing;
+
+    impl CompilerPlugin for Failing {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn transform(&self, _schema: &mut CompiledSchema) -> Result<()> {
+            Err(FraiseQLError::Validation {
+                message: "rejected".to_string(),
+                path:    None,
+            })
+        }
+    }
+
+    fn schema_with(types: &[(&str, &str)]) -> CompiledSchema {
+        let mut schema = CompiledSchema::new();
+        for (name, source) in types {
+            let mut ty = TypeDefinition::new(*name, *source);
+            ty.fields.push(FieldDefinition::new("id", FieldType::Id));
+            schema.types.push(ty);
+        }
+        schema
+    }
+
+    #[test]
+    fn test_plugins_run_by_order_then_name_and_are_recorded() {
+        let mut registry = CompilerPluginRegistry::new();
+        registry
+            .register(Box::new(Marker {
+                name:  "zeta",
+                order: 0,
+            }))
+            .unwrap();
+        registry
+            .register(Box::new(Marker {
+                name:  "late",
+                order: 5,
+            }))
+            .unwrap();
+        registry
+            .register(Box::new(Marker {
+                name:  "alpha",
+                order: 0,
+            }))
+            .unwrap();
+        registry
+            .register(Box::new(Marker {
+                name:  "early",
+                order: -1,
+            }))
+            .unwrap();
+
+        let mut schema = schema_with(&[("User", "v_user")]);
+        registry.apply(&mut schema).unwrap();
+
+        assert_eq!(schema.types[0].description.as_deref(), Some("early;alpha;zeta;late;"));
+        let recorded: Vec<(&str, i32)> =
+            schema.compiler_plugins.iter().map(|p| (p.name.as_str(), p.order)).collect();
+        assert_eq!(recorded, [("early", -1), ("alpha", 0), ("zeta", 0), ("late", 5)]);
+    }
+
+    #[test]
+    fn test_duplicate_plugin_name_rejected() {
+        let mut registry = CompilerPluginRegistry::new();
+        registry
+            .register(Box::new(Marker {
+                name:  "audit",
+                order: 0,
+            }))
+            .unwrap();
+        let err = registry
+            .register(Box::new(Marker {
+                name:  "audit",
+                order: 1,
+            }))
+            .unwrap_err();
+        assert!(matches!(err, FraiseQLError::Configuration { .. }), "got {err:?}");
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_plugin_error_stops_pipeline() {
+        let mut registry = CompilerPluginRegistry::new();
+        registry.register(Box::new(Failing)).unwrap();
+        registry
+            .register(Box::new(Marker {
+                name:  "after",
+                order: 1,
+            }))
+            .unwrap();
+
+        let mut schema = schema_with(&[("User", "v_user")]);
+        registry.apply(&mut schema).unwrap_err();
+        assert!(schema.types[0].description.is_none());
+        assert!(schema.compiler_plugins.is_empty());
+    }
+
+    #[test]
+    fn test_inject_fields_targets_all_types_and_keeps_existing_fields() {
+        let plugin = InjectFieldsPlugin {
+            name:   "audit_columns".to_string(),
+            order:  0,
+            types:  Vec::new(),
+            fields: vec![
+                FieldDefinition::nullable("created_at", FieldType::DateTime),
+                FieldDefinition::nullable("id", FieldType::String),
+            ],
+        };
+        let mut schema = schema_with(&[("User", "v_user"), ("Post", "v_post")]);
+        plugin.transform(&mut schema).unwrap();
+
+        for ty in &schema.types {
+            let names: Vec<&str> = ty.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(names, ["id", "created_at"]);
+            assert_eq!(ty.fields[0].field_type, FieldType::Id, "existing field kept");
+        }
+    }
+
+    #[test]
+    fn test_inject_fields_restricted_to_listed_types() {
+        let plugin = InjectFieldsPlugin {
+            name:   "audit_columns".to_string(),
+            order:  0,
+            types:  vec!["Post".to_string()],
+            fields: vec![FieldDefinition::nullable("created_at", FieldType::DateTime)],
+        };
+        let mut schema = schema_with(&[("User", "v_user"), ("Post", "v_post")]);
+        plugin.transform(&mut schema).unwrap();
+
+        assert_eq!(schema.types[0].fields.len(), 1);
+        assert_eq!(schema.types[1].fields.len(), 2);
+    }
+
+    #[test]
+    fn test_inject_fields_unknown_type_rejected() {
+        let plugin = InjectFieldsPlugin {
+            name:   "audit_columns".to_string(),
+            order:  0,
+            types:  vec!["Missing".to_string()],
+            fields: vec![FieldDefinition::nullable("created_at", FieldType::DateTime)],
+        };
+        let mut schema = schema_with(&[("User", "v_user")]);
+        let err = plugin.transform(&mut schema).unwrap_err();
+        assert!(err.to_string().contains("Missing"), "got {err}");
+    }
+
+    #[test]
+    fn test_applied_plugins_serialized_only_when_present() {
+        let schema = CompiledSchema::new();
+        let json = schema.to_json().unwrap();
+        assert!(!json.contains("compiler_plugins"));
+
+        let mut schema = CompiledSchema::new();
+        schema.compiler_plugins.push(AppliedCompilerPlugin {
+            name:  "audit_columns".to_string(),
+            order: 10,
+        });
+        let json = schema.to_json().unwrap();
+        let reloaded = CompiledSchema::from_json(&json, false).unwrap();
+        assert_eq!(reloaded.compiler_plugins, schema.compiler_plugins);
+    }
+}
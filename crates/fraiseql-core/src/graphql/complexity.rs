@@ -4,7 +4,10 @@
 // operation names, arguments, fragment spreads, and aliases (which a
 // character-scan approach cannot distinguish from field names).
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
 
 use graphql_parser::query::{
     Definition, Document, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
@@ -49,32 +52,63 @@ pub const MAX_VARIABLES_COUNT: usize = 1_000;
 #[derive(Debug, Clone)]
 pub struct ComplexityConfig {
     /// Maximum query depth (nesting level) — default: 10
-    pub max_depth:      usize,
+    pub max_depth:               usize,
     /// Maximum complexity score — default: 100
-    pub max_complexity: usize,
+    pub max_complexity:          usize,
     /// Maximum number of field aliases per query — default: 30
-    pub max_aliases:    usize,
+    pub max_aliases:             usize,
+    /// Maximum number of root fields per operation — default: unlimited
+    pub max_root_fields:         Option<usize>,
+    /// Maximum nesting depth below `__schema` / `__type` — default: unlimited
+    pub max_introspection_depth: Option<usize>,
 }
 
 impl Default for ComplexityConfig {
     fn default() -> Self {
         Self {
-            max_depth:      10,
-            max_complexity: 100,
-            max_aliases:    DEFAULT_MAX_ALIASES,
+            max_depth:               10,
+            max_complexity:          100,
+            max_aliases:             DEFAULT_MAX_ALIASES,
+            max_root_fields:         None,
+            max_introspection_depth: None,
         }
     }
 }
 
+/// Per-role overrides for [`RequestValidator`] limits.
+///
+/// Each `Some` field replaces the validator's base limit for callers holding
+/// the role; `None` inherits the base limit. When a caller holds several roles
+/// with overrides, the most permissive value per limit wins — roles grant
+/// headroom, they never take it away from one another.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoleLimits {
+    /// Override for the maximum query depth.
+    pub max_depth:               Option<usize>,
+    /// Override for the maximum complexity score.
+    pub max_complexity:          Option<usize>,
+    /// Override for the maximum alias count.
+    pub max_aliases:             Option<usize>,
+    /// Override for the maximum number of root fields.
+    pub max_root_fields:         Option<usize>,
+    /// Override for the maximum introspection depth.
+    pub max_introspection_depth: Option<usize>,
+}
+
 /// Metrics returned by the AST-based analyzer.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryMetrics {
     /// Maximum selection-set nesting depth.
-    pub depth:       usize,
+    pub depth:               usize,
     /// Total complexity score (accounts for pagination multipliers).
-    pub complexity:  usize,
+    pub complexity:          usize,
     /// Number of aliased fields in the document.
-    pub alias_count: usize,
+    pub alias_count:         usize,
+    /// Largest number of root fields selected by a single operation.
+    pub root_field_count:    usize,
+    /// Deepest selection below a `__schema` / `__type` root field (0 when the
+    /// document does not introspect).
+    pub introspection_depth: usize,
 }
 
 /// GraphQL query validation error types (depth, complexity, aliases).
@@ -108,6 +142,26 @@ pub enum ComplexityValidationError {
         actual_aliases: usize,
     },
 
+    /// An operation selects too many root fields (root-field fan-out).
+    #[error(
+        "Query exceeds maximum root field count of {max_root_fields}: count = {actual_root_fields}"
+    )]
+    TooManyRootFields {
+        /// Maximum allowed root fields per operation
+        max_root_fields:    usize,
+        /// Actual root field count
+        actual_root_fields: usize,
+    },
+
+    /// An introspection selection (`__schema` / `__type`) nests too deeply.
+    #[error("Introspection exceeds maximum depth of {max_depth}: depth = {actual_depth}")]
+    IntrospectionTooDeep {
+        /// Maximum allowed introspection depth
+        max_depth:    usize,
+        /// Actual introspection depth
+        actual_depth: usize,
+    },
+
     /// Invalid query variables.
     #[error("Invalid variables: {0}")]
     InvalidVariables(String),
@@ -117,6 +171,40 @@ pub enum ComplexityValidationError {
     MalformedQuery(String),
 }
 
+impl ComplexityValidationError {
+    /// The violated limit as `(name, max, actual)`, for limit errors.
+    ///
+    /// The name matches the `[validation]` key that configures the limit, so
+    /// clients can report exactly which knob rejected the query. Returns `None`
+    /// for malformed queries and invalid variables.
+    #[must_use]
+    pub const fn violated_limit(&self) -> Option<(&'static str, usize, usize)> {
+        match *self {
+            Self::QueryTooDeep {
+                max_depth,
+                actual_depth,
+            } => Some(("max_query_depth", max_depth, actual_depth)),
+            Self::QueryTooComplex {
+                max_complexity,
+                actual_complexity,
+            } => Some(("max_query_complexity", max_complexity, actual_complexity)),
+            Self::TooManyAliases {
+                max_aliases,
+                actual_aliases,
+            } => Some(("max_aliases", max_aliases, actual_aliases)),
+            Self::TooManyRootFields {
+                max_root_fields,
+                actual_root_fields,
+            } => Some(("max_root_fields", max_root_fields, actual_root_fields)),
+            Self::IntrospectionTooDeep {
+                max_depth,
+                actual_depth,
+            } => Some(("max_introspection_depth", max_depth, actual_depth)),
+            Self::InvalidVariables(_) | Self::MalformedQuery(_) => None,
+        }
+    }
+}
+
 /// AST-based GraphQL request validator.
 ///
 /// Uses `graphql-parser` to walk the full document tree. Correctly handles
@@ -125,15 +213,21 @@ pub enum ComplexityValidationError {
 #[derive(Debug, Clone)]
 pub struct RequestValidator {
     /// Maximum query depth allowed.
-    max_depth:             usize,
+    max_depth:               usize,
     /// Maximum query complexity score allowed.
-    max_complexity:        usize,
+    max_complexity:          usize,
     /// Maximum number of field aliases per query (alias amplification protection).
-    max_aliases_per_query: usize,
+    max_aliases_per_query:   usize,
     /// Enable query depth validation.
-    validate_depth:        bool,
+    validate_depth:          bool,
     /// Enable query complexity validation.
-    validate_complexity:   bool,
+    validate_complexity:     bool,
+    /// Maximum root fields per operation (`None` = unlimited).
+    max_root_fields:         Option<usize>,
+    /// Maximum depth below `__schema` / `__type` (`None` = unlimited).
+    max_introspection_depth: Option<usize>,
+    /// Per-role limit overrides, applied by [`Self::for_roles`].
+    role_limits:             Vec<(String, RoleLimits)>,
 }
 
 impl RequestValidator {
@@ -147,11 +241,14 @@ impl RequestValidator {
     #[must_use]
     pub const fn from_config(config: &ComplexityConfig) -> Self {
         Self {
-            max_depth:             config.max_depth,
-            max_complexity:        config.max_complexity,
-            max_aliases_per_query: config.max_aliases,
-            validate_depth:        true,
-            validate_complexity:   true,
+            max_depth:               config.max_depth,
+            max_complexity:          config.max_complexity,
+            max_aliases_per_query:   config.max_aliases,
+            validate_depth:          true,
+            validate_complexity:     true,
+            max_root_fields:         config.max_root_fields,
+            max_introspection_depth: config.max_introspection_depth,
+            role_limits:             Vec::new(),
         }
     }
 
@@ -190,6 +287,70 @@ impl RequestValidator {
         self
     }
 
+    /// Set maximum number of root fields per operation.
+    #[must_use]
+    pub const fn with_max_root_fields(mut self, max_root_fields: usize) -> Self {
+        self.max_root_fields = Some(max_root_fields);
+        self
+    }
+
+    /// Set maximum nesting depth below `__schema` / `__type`.
+    #[must_use]
+    pub const fn with_max_introspection_depth(mut self, max_depth: usize) -> Self {
+        self.max_introspection_depth = Some(max_depth);
+        self
+    }
+
+    /// Override limits for callers holding `role`.
+    ///
+    /// Registering the same role twice replaces the earlier overrides.
+    #[must_use]
+    pub fn with_role_limits(mut self, role: impl Into<String>, limits: RoleLimits) -> Self {
+        let role = role.into();
+        self.role_limits.retain(|(r, _)| *r != role);
+        self.role_limits.push((role, limits));
+        self
+    }
+
+    /// The validator to apply for a caller holding `roles`.
+    ///
+    /// Borrows `self` unchanged when none of the roles has overrides (the
+    /// common case, and always the case for anonymous callers). Otherwise each
+    /// limit is replaced by the most permissive override among the matching
+    /// roles; limits no matching role overrides keep their base value.
+    #[must_use]
+    pub fn for_roles<S: AsRef<str>>(&self, roles: &[S]) -> Cow<'_, Self> {
+        let mut matching = self
+            .role_limits
+            .iter()
+            .filter(|(role, _)| roles.iter().any(|r| r.as_ref() == role))
+            .map(|(_, limits)| limits)
+            .peekable();
+        if matching.peek().is_none() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut merged = RoleLimits::default();
+        for limits in matching {
+            merged.max_depth = most_permissive(merged.max_depth, limits.max_depth);
+            merged.max_complexity = most_permissive(merged.max_complexity, limits.max_complexity);
+            merged.max_aliases = most_permissive(merged.max_aliases, limits.max_aliases);
+            merged.max_root_fields =
+                most_permissive(merged.max_root_fields, limits.max_root_fields);
+            merged.max_introspection_depth =
+                most_permissive(merged.max_introspection_depth, limits.max_introspection_depth);
+        }
+
+        let mut effective = self.clone();
+        effective.max_depth = merged.max_depth.unwrap_or(self.max_depth);
+        effective.max_complexity = merged.max_complexity.unwrap_or(self.max_complexity);
+        effective.max_aliases_per_query = merged.max_aliases.unwrap_or(self.max_aliases_per_query);
+        effective.max_root_fields = merged.max_root_fields.or(self.max_root_fields);
+        effective.max_introspection_depth =
+            merged.max_introspection_depth.or(self.max_introspection_depth);
+        Cow::Owned(effective)
+    }
+
     /// Compute query metrics without enforcing any limits.
     ///
     /// Returns [`QueryMetrics`] for the query.
@@ -225,12 +386,18 @@ impl RequestValidator {
     /// expensive AST parse can be skipped entirely.
     ///
     /// A validator is "fully disabled" only when depth, complexity, AND alias
-    /// checks are all off. The alias-amplification check is a distinct `DoS`
-    /// vector — `max_aliases_per_query == 0` disables it, any other value
-    /// keeps it active even when depth/complexity validation are turned off.
+    /// checks are all off and no root-field or introspection-depth limit is
+    /// set. The alias-amplification check is a distinct `DoS` vector —
+    /// `max_aliases_per_query == 0` disables it, any other value keeps it
+    /// active even when depth/complexity validation are turned off. Role
+    /// overrides are not consulted; call [`Self::for_roles`] first.
     #[must_use]
     pub const fn is_no_op(&self) -> bool {
-        !self.validate_depth && !self.validate_complexity && self.max_aliases_per_query == 0
+        !self.validate_depth
+            && !self.validate_complexity
+            && self.max_aliases_per_query == 0
+            && self.max_root_fields.is_none()
+            && self.max_introspection_depth.is_none()
     }
 
     /// Validate a GraphQL query string, enforcing configured limits.
@@ -262,7 +429,8 @@ impl RequestValidator {
     /// # Errors
     ///
     /// Returns [`ComplexityValidationError`] if the document violates depth,
-    /// complexity, or alias-amplification limits.
+    /// complexity, alias-amplification, root-field, or introspection-depth
+    /// limits.
     pub fn validate_query_doc<'a>(
         &self,
         document: &'a Document<'a, String>,
@@ -294,6 +462,24 @@ impl RequestValidator {
             });
         }
 
+        if let Some(max_root_fields) = self.max_root_fields {
+            if metrics.root_field_count > max_root_fields {
+                return Err(ComplexityValidationError::TooManyRootFields {
+                    max_root_fields,
+                    actual_root_fields: metrics.root_field_count,
+                });
+            }
+        }
+
+        if let Some(max_depth) = self.max_introspection_depth {
+            if metrics.introspection_depth > max_depth {
+                return Err(ComplexityValidationError::IntrospectionTooDeep {
+                    max_depth,
+                    actual_depth: metrics.introspection_depth,
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -351,6 +537,14 @@ struct SelectionMetrics {
     aliases:    usize,
 }
 
+/// Root-level shape of one operation: how many root fields it selects and how
+/// deep its introspection subtrees go.
+#[derive(Debug, Clone, Copy, Default)]
+struct RootShape {
+    fields:              usize,
+    introspection_depth: usize,
+}
+
 /// Single-pass, memoizing analyzer shared by depth, complexity, and alias
 /// counting.
 ///
@@ -410,16 +604,19 @@ impl<'a> DocumentAnalyzer<'a> {
         let mut depth = 0usize;
         let mut complexity = 0usize;
         let mut aliases = 0usize;
+        let mut root_fields = 0usize;
+        let mut introspection_depth = 0usize;
         for def in &document.definitions {
             match def {
                 Definition::Operation(op) => {
-                    let m = self.selection_metrics(
-                        operation_selection_set(op),
-                        FRAGMENT_SPREAD_DEPTH_LIMIT,
-                    );
+                    let selection_set = operation_selection_set(op);
+                    let m = self.selection_metrics(selection_set, FRAGMENT_SPREAD_DEPTH_LIMIT);
                     depth = depth.max(m.depth);
                     complexity = complexity.saturating_add(m.complexity);
                     aliases = aliases.saturating_add(m.aliases);
+                    let root = self.root_shape(selection_set, FRAGMENT_SPREAD_DEPTH_LIMIT);
+                    root_fields = root_fields.max(root.fields);
+                    introspection_depth = introspection_depth.max(root.introspection_depth);
                 },
                 Definition::Fragment(f) => {
                     let m = self.resolve_fragment(f.name.as_str(), FRAGMENT_SPREAD_DEPTH_LIMIT);
@@ -431,7 +628,56 @@ impl<'a> DocumentAnalyzer<'a> {
             depth,
             complexity,
             alias_count: aliases,
+            root_field_count: root_fields,
+            introspection_depth,
+        }
+    }
+
+    /// Root-field count and introspection depth of an operation's top-level
+    /// selection set.
+    ///
+    /// Inline fragments and fragment spreads at the root are flattened, so
+    /// `{ ...A ...B }` counts the root fields of both fragments. A spread
+    /// cycle (or an exhausted spread budget) contributes nothing here — the
+    /// depth gate already rejects it via [`Self::over_limit`].
+    fn root_shape(
+        &mut self,
+        selection_set: &'a SelectionSet<'a, String>,
+        budget: usize,
+    ) -> RootShape {
+        let mut shape = RootShape::default();
+        for sel in &selection_set.items {
+            let child = match sel {
+                Selection::Field(field) => {
+                    let introspection_depth =
+                        if matches!(field.name.as_str(), "__schema" | "__type") {
+                            self.selection_metrics(&field.selection_set, budget).depth
+                        } else {
+                            0
+                        };
+                    RootShape {
+                        fields: 1,
+                        introspection_depth,
+                    }
+                },
+                Selection::InlineFragment(inline) => self.root_shape(&inline.selection_set, budget),
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.fragment_name.as_str();
+                    match self.by_name.get(name).copied() {
+                        Some(frag) if budget > 0 && !self.visiting.contains(name) => {
+                            self.visiting.insert(name);
+                            let s = self.root_shape(&frag.selection_set, budget - 1);
+                            self.visiting.remove(name);
+                            s
+                        },
+                        _ => RootShape::default(),
+                    }
+                },
+            };
+            shape.fields = shape.fields.saturating_add(child.fields);
+            shape.introspection_depth = shape.introspection_depth.max(child.introspection_depth);
         }
+        shape
     }
 
     /// Memoized metrics for a fragment by name.
@@ -622,15 +868,26 @@ const fn operation_selection_set<'a>(
 impl Default for RequestValidator {
     fn default() -> Self {
         Self {
-            max_depth:             10,
-            max_complexity:        100,
-            max_aliases_per_query: DEFAULT_MAX_ALIASES,
-            validate_depth:        true,
-            validate_complexity:   true,
+            max_depth:               10,
+            max_complexity:          100,
+            max_aliases_per_query:   DEFAULT_MAX_ALIASES,
+            validate_depth:          true,
+            validate_complexity:     true,
+            max_root_fields:         None,
+            max_introspection_depth: None,
+            role_limits:             Vec::new(),
         }
     }
 }
 
+/// The more permissive of two optional limit overrides (`None` = no override).
+fn most_permissive(current: Option<usize>, candidate: Option<usize>) -> Option<usize> {
+    match (current, candidate) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
 /// Collect all fragment definitions from a parsed document.
 fn collect_fragments<'a>(
     document: &'a Document<'a, String>,
@@ -743,6 +743,8 @@ mod types_tests {
 
 mod complexity_tests {
 
+    use std::borrow::Cow;
+
     use super::super::*;
     #[allow(unused_imports)]
     // Reason: nested test mod re-imports may not all be used by every test
@@ -1177,9 +1179,10 @@ mod complexity_tests {
     #[test]
     fn test_from_config() {
         let config = ComplexityConfig {
-            max_depth:      5,
+            max_depth: 5,
             max_complexity: 20,
-            max_aliases:    3,
+            max_aliases: 3,
+            ..ComplexityConfig::default()
         };
         let validator = RequestValidator::from_config(&config);
         // Depth-6 query should fail
@@ -1190,6 +1193,121 @@ mod complexity_tests {
         );
     }
 
+    // ── Root fields & introspection depth ──
+
+    #[test]
+    fn test_root_field_limit() {
+        let validator = RequestValidator::default().with_max_root_fields(2);
+        validator
+            .validate_query("{ a { id } b { id } }")
+            .expect("two root fields allowed");
+        let result = validator.validate_query("{ a { id } ... on Query { b { id } c { id } } }");
+        assert!(
+            matches!(
+                result,
+                Err(ComplexityValidationError::TooManyRootFields {
+                    max_root_fields:    2,
+                    actual_root_fields: 3,
+                })
+            ),
+            "inline-fragment root fields must count; got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_root_fields_counted_through_fragment_spreads() {
+        let metrics = RequestValidator::default()
+            .analyze("query { ...Roots d { id } } fragment Roots on Query { a { id } b { id } }")
+            .expect("valid");
+        assert_eq!(metrics.root_field_count, 3);
+    }
+
+    #[test]
+    fn test_introspection_depth_limit() {
+        let validator = RequestValidator::default().with_max_introspection_depth(3);
+        validator
+            .validate_query("{ __schema { types { fields { name } } } }")
+            .expect("depth 3 allowed");
+        let result = validator
+            .validate_query("{ __schema { types { fields { type { ofType { name } } } } } }");
+        assert!(
+            matches!(
+                result,
+                Err(ComplexityValidationError::IntrospectionTooDeep {
+                    max_depth:    3,
+                    actual_depth: 5,
+                })
+            ),
+            "got: {result:?}"
+        );
+        // Regular fields are not subject to the introspection limit.
+        validator
+            .validate_query("{ a { b { c { d { e } } } } }")
+            .expect("not introspection");
+    }
+
+    #[test]
+    fn test_violated_limit_names_config_key() {
+        let err = ComplexityValidationError::TooManyRootFields {
+            max_root_fields:    5,
+            actual_root_fields: 9,
+        };
+        assert_eq!(err.violated_limit(), Some(("max_root_fields", 5, 9)));
+        assert_eq!(ComplexityValidationError::MalformedQuery("x".into()).violated_limit(), None);
+    }
+
+    // ── Per-role overrides ──
+
+    #[test]
+    fn test_role_limits_apply_only_to_matching_roles() {
+        let validator = RequestValidator::default().with_max_depth(3).with_role_limits(
+            "admin",
+            RoleLimits {
+                max_depth: Some(6),
+                ..RoleLimits::default()
+            },
+        );
+        let query = "{ a { b { c { d { e } } } } }";
+
+        assert!(matches!(validator.for_roles(&["user"]), Cow::Borrowed(_)));
+        assert!(validator.for_roles(&["user"]).validate_query(query).is_err());
+        validator
+            .for_roles(&["user", "admin"])
+            .validate_query(query)
+            .expect("admin override");
+    }
+
+    #[test]
+    fn test_role_limits_most_permissive_wins() {
+        let validator = RequestValidator::default()
+            .with_max_aliases(2)
+            .with_role_limits(
+                "support",
+                RoleLimits {
+                    max_aliases: Some(5),
+                    max_root_fields: Some(1),
+                    ..RoleLimits::default()
+                },
+            )
+            .with_role_limits(
+                "analyst",
+                RoleLimits {
+                    max_aliases: Some(10),
+                    ..RoleLimits::default()
+                },
+            );
+        let effective = validator.for_roles(&["support", "analyst"]);
+        let query = "{ a1: a { id } a2: a { id } a3: a { id } a4: a { id } }";
+        // Aliases: analyst's 10 beats support's 5. Root fields: only support
+        // overrides it, so its limit of 1 applies.
+        assert!(matches!(
+            effective.validate_query(query),
+            Err(ComplexityValidationError::TooManyRootFields { .. })
+        ));
+        let single_root = "{ a { x1: id x2: id x3: id x4: id x5: id x6: id } }";
+        effective.validate_query(single_root).expect("10 aliases allowed");
+    }
+
     // ── Fragment-spread amplification & cycles (audit H4 DoS) ──
 
     /// Builds the audit's worker-pinning construction: `chain` fragments where
@@ -14,10 +14,18 @@ impl AggregationSqlGenerator {
         params: &mut Vec<serde_json::Value>,
     ) -> Result<String> {
         match clause {
+            // Fact-table dimensions are compared as extracted text; the scalar
+            // SQL type of a `TypedField` does not apply here.
             WhereClause::Field {
                 path,
                 operator,
                 value,
+            }
+            | WhereClause::TypedField {
+                path,
+                operator,
+                value,
+                ..
             } => {
                 let field_name = &path[0];
                 let is_denormalized =
@@ -0,0 +1,81 @@
+//! Per-request time budgets.
+//!
+//! The HTTP layer knows how long a request may still run: its configured
+//! request timeout minus the time already spent in middleware, APQ lookup and
+//! validation. [`with_deadline`] scopes that deadline over the execution
+//! future, and everything awaited on the same task sees it through
+//! [`remaining`]:
+//!
+//! - the executor races execution against the smaller of `query_timeout_ms` and the remaining
+//!   budget, so a request never times out at the HTTP layer while the executor still believes it
+//!   has time;
+//! - on `PostgreSQL`, reads run with `statement_timeout` set to the remaining budget, so the
+//!   database abandons a statement once the caller has given up instead of finishing work nobody
+//!   will read.
+//!
+//! The deadline lives in a Tokio task-local so it reaches the adapter calls
+//! without threading a parameter through every runner. Work moved onto another
+//! task with `tokio::spawn` does not inherit it; the executor's multi-root
+//! fan-out stays on the calling task, so it does.
+
+use std::{future::Future, time::Duration};
+
+use tokio::time::Instant;
+
+use crate::db::types::DatabaseType;
+
+tokio::task_local! {
+    static DEADLINE: Instant;
+}
+
+/// Largest value `PostgreSQL` accepts for `statement_timeout` (`INT_MAX` ms).
+const MAX_STATEMENT_TIMEOUT_MS: u128 = 2_147_483_647;
+
+/// Run `future` with `deadline` as its time budget.
+///
+/// Nested scopes replace the outer deadline for their duration, so callers
+/// that want to tighten the budget should pass the earlier of the two.
+pub async fn with_deadline<F: Future>(deadline: Instant, future: F) -> F::Output {
+    DEADLINE.scope(deadline, future).await
+}
+
+/// Budget left in the enclosing [`with_deadline`] scope.
+///
+/// Returns `None` outside a scope and `Duration::ZERO` once the deadline has
+/// passed.
+#[must_use]
+pub fn remaining() -> Option<Duration> {
+    DEADLINE
+        .try_with(|deadline| deadline.saturating_duration_since(Instant::now()))
+        .ok()
+}
+
+/// The smaller of a configured timeout (`None` = unbounded) and the remaining budget.
+#[must_use]
+pub fn effective_timeout(configured: Option<Duration>) -> Option<Duration> {
+    match (configured, remaining()) {
+        (Some(configured), Some(remaining)) => Some(configured.min(remaining)),
+        (configured, remaining) => configured.or(remaining),
+    }
+}
+
+/// Append a transaction-local `statement_timeout` for the remaining budget.
+///
+/// Only `PostgreSQL` is covered: the other adapters either ignore session
+/// variables or give `statement_timeout` no meaning. `PostgreSQL` reads `0` as
+/// "no timeout", so an exhausted budget is sent as 1 ms rather than 0.
+pub(crate) fn push_statement_timeout(
+    session_vars: &mut Vec<(String, String)>,
+    database_type: DatabaseType,
+) {
+    if database_type != DatabaseType::PostgreSQL {
+        return;
+    }
+    if let Some(remaining) = remaining() {
+        let millis = remaining.as_millis().clamp(1, MAX_STATEMENT_TIMEOUT_MS);
+        session_vars.push(("statement_timeout".to_string(), millis.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use super::*;
+
+#[tokio::test]
+async fn no_budget_outside_a_scope() {
+    assert_eq!(remaining(), None);
+    assert_eq!(effective_timeout(None), None);
+    assert_eq!(effective_timeout(Some(Duration::from_secs(5))), Some(Duration::from_secs(5)));
+
+    let mut vars = Vec::new();
+    push_statement_timeout(&mut vars, DatabaseType::PostgreSQL);
+    assert!(vars.is_empty());
+}
+
+#[tokio::test]
+async fn effective_timeout_is_the_smaller_bound() {
+    let deadline = Instant::now() + Duration::from_secs(2);
+    with_deadline(deadline, async {
+        let left = remaining().unwrap();
+        assert!(left <= Duration::from_secs(2));
+        assert!(left > Duration::from_secs(1));
+
+        assert_eq!(
+            effective_timeout(Some(Duration::from_millis(10))),
+            Some(Duration::from_millis(10))
+        );
+        assert!(
+            effective_timeout(Some(Duration::from_secs(30))).unwrap() <= Duration::from_secs(2)
+        );
+        assert!(effective_timeout(None).unwrap() <= Duration::from_secs(2));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn statement_timeout_follows_the_budget_on_postgres_only() {
+    let deadline = Instant::now() + Duration::from_secs(3);
+    with_deadline(deadline, async {
+        let mut vars = vec![("app.tenant_id".to_string(), "t1".to_string())];
+        push_statement_timeout(&mut vars, DatabaseType::PostgreSQL);
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[1].0, "statement_timeout");
+        let millis: u64 = vars[1].1.parse().unwrap();
+        assert!((2_000..=3_000).contains(&millis), "statement_timeout = {millis}");
+
+        let mut vars = Vec::new();
+        push_statement_timeout(&mut vars, DatabaseType::MySQL);
+        assert!(vars.is_empty());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn exhausted_budget_never_disables_statement_timeout() {
+    with_deadline(Instant::now(), async {
+        assert_eq!(remaining(), Some(Duration::ZERO));
+        let mut vars = Vec::new();
+        push_statement_timeout(&mut vars, DatabaseType::PostgreSQL);
+        assert_eq!(vars, [("statement_timeout".to_string(), "1".to_string())]);
+    })
+    .await;
+}
@@ -42,7 +42,7 @@ pub(super) struct ExecutorContext<A: DatabaseAdapter> {
     /// `FraiseQLError::Validation` for relay queries — no `unreachable!()`.
     pub(super) relay: Option<Arc<dyn RelayDispatch>>,
 
-    /// Query matching engine (stateless).
+    /// Query matching engine, holding the query plan cache.
     pub(super) matcher: QueryMatcher,
 
     /// Query execution planner (stateless).
@@ -51,6 +51,15 @@ fn build_introspection(schema: &CompiledSchema) -> IntrospectionResponses {
 /// not duplicated.
 const PARSE_CACHE_CAPACITY: u64 = 1_024;
 
+/// Plan cache size for `config`: `0` (disabled) unless `cache_query_plans` is set.
+const fn plan_cache_capacity(config: &RuntimeConfig) -> usize {
+    if config.cache_query_plans {
+        config.plan_cache_capacity
+    } else {
+        0
+    }
+}
+
 /// Query executor - executes compiled GraphQL queries.
 ///
 /// This is the main entry point for runtime query execution.
@@ -138,7 +147,8 @@ impl<A: DatabaseAdapter> Executor<A> {
     /// * `config` - Runtime configuration
     #[must_use]
     pub fn with_config(schema: CompiledSchema, adapter: Arc<A>, config: RuntimeConfig) -> Self {
-        let matcher = QueryMatcher::new(schema.clone());
+        let matcher =
+            QueryMatcher::new(schema.clone()).with_plan_cache(plan_cache_capacity(&config));
         let planner = QueryPlanner::new(config.cache_query_plans);
         // Build introspection responses at startup (zero-cost at runtime),
         // with `@inaccessible` fields filtered out. Shared with the relay
@@ -213,6 +223,12 @@ impl<A: DatabaseAdapter> Executor<A> {
         self.ctx.parse_cache.entry_count()
     }
 
+    /// Query plan cache statistics, or `None` when plan caching is disabled.
+    #[must_use]
+    pub fn plan_cache_stats(&self) -> Option<crate::runtime::PlanCacheStats> {
+        self.ctx.matcher.plan_cache_stats()
+    }
+
     /// Attach an executor-level response cache.
     ///
     /// When enabled, the executor caches the final projected response
@@ -387,7 +403,8 @@ impl<A: DatabaseAdapter + RelayDatabaseAdapter + 'static> Executor<A> {
     ) -> Self {
         let relay_dispatch: Arc<dyn RelayDispatch> =
             Arc::new(RelayDispatchImpl(Arc::clone(&adapter)));
-        let matcher = QueryMatcher::new(schema.clone());
+        let matcher =
+            QueryMatcher::new(schema.clone()).with_plan_cache(plan_cache_capacity(&config));
         let planner = QueryPlanner::new(config.cache_query_plans);
         // Use the same filtered builder as `with_config` so a relay-enabled
         // executor never silently exposes `@inaccessible` fields in
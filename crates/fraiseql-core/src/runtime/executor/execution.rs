@@ -7,6 +7,7 @@ use super::{Executor, QueryType, pipeline, root_type_name, support};
 use crate::{
     db::traits::DatabaseAdapter,
     error::{FraiseQLError, Result},
+    runtime::budget,
     security::{QueryValidator, SecurityContext},
 };
 
@@ -41,11 +42,12 @@ impl<A: DatabaseAdapter> Executor<A> {
     ///
     /// Shared by the anonymous [`execute`](Self::execute) and the authenticated
     /// `execute_with_security` entry points so both honor `query_timeout_ms`
-    /// identically.
+    /// identically. Inside a request time budget ([`budget::with_deadline`])
+    /// the timeout is shortened to the budget that remains.
     ///
     /// # Errors
     ///
-    /// - [`FraiseQLError::Timeout`] — execution exceeded `query_timeout_ms`.
+    /// - [`FraiseQLError::Timeout`] — execution exceeded `query_timeout_ms` or the request budget.
     /// - Any error returned by [`execute_dispatch`](Self::execute_dispatch).
     pub(super) async fn execute_with_timeout(
         &self,
@@ -53,8 +55,9 @@ impl<A: DatabaseAdapter> Executor<A> {
         variables: Option<&serde_json::Value>,
         security_context: Option<&SecurityContext>,
     ) -> Result<serde_json::Value> {
-        if self.ctx.config.query_timeout_ms > 0 {
-            let timeout_duration = Duration::from_millis(self.ctx.config.query_timeout_ms);
+        let configured = (self.ctx.config.query_timeout_ms > 0)
+            .then(|| Duration::from_millis(self.ctx.config.query_timeout_ms));
+        if let Some(timeout_duration) = budget::effective_timeout(configured) {
             tokio::time::timeout(
                 timeout_duration,
                 self.execute_dispatch(query, variables, security_context),
@@ -64,7 +67,7 @@ impl<A: DatabaseAdapter> Executor<A> {
                 // Truncate query (char-boundary-safe) for error reporting.
                 let query_snippet = crate::utils::text::truncate_for_display(query, 100);
                 FraiseQLError::Timeout {
-                    timeout_ms: self.ctx.config.query_timeout_ms,
+                    timeout_ms: u64::try_from(timeout_duration.as_millis()).unwrap_or(u64::MAX),
                     query:      Some(query_snippet),
                 }
             })?
@@ -201,6 +204,9 @@ impl<A: DatabaseAdapter> Executor<A> {
                 )
                 .await
             },
+            QueryType::MutationBatch(roots) => {
+                self.execute_mutation_batch_query(&roots, variables, security_context).await
+            },
             QueryType::NodeQuery { selections } => {
                 // The node runner fails closed for any RLS/inject/role-gated type
                 // when `security_context` is `None` (H2 IDOR fix).
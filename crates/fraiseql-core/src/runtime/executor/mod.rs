@@ -227,6 +227,12 @@ enum QueryType {
         arguments:  Vec<crate::graphql::GraphQLArgument>,
     },
 
+    /// Mutation operation with several top-level fields, run serially in
+    /// document order. Each field runs in its own transaction unless the
+    /// request asks for a transactional batch
+    /// ([`mutation_transaction`](crate::runtime::mutation_transaction)).
+    MutationBatch(Vec<MutationRoot>),
+
     /// Relay global node lookup: `node(id: ID!)`.
     /// Resolves any type that implements the Node interface by global opaque ID.
     /// Contains the field selections from the inline fragment for projection.
@@ -255,6 +261,18 @@ enum QueryType {
     },
 }
 
+/// One top-level field of a multi-field mutation operation.
+///
+/// Carries the same data as [`QueryType::Mutation`], plus the response key so
+/// aliased fields land under their alias.
+#[derive(Debug, Clone, PartialEq)]
+struct MutationRoot {
+    name:         String,
+    response_key: String,
+    selections:   Vec<crate::graphql::FieldSelection>,
+    arguments:    Vec<crate::graphql::GraphQLArgument>,
+}
+
 /// Resolve a GraphQL operation type string to its root type name.
 ///
 /// Matches the hardcoded root type names emitted by the introspection schema
@@ -11,11 +11,12 @@
 //! - Convenience wrappers used by the REST transport ([`execute_mutation_with_security`],
 //!   [`execute_mutation_batch`], [`execute_bulk_by_filter`]).
 
-use super::{Executor, runners};
+use super::{Executor, MutationRoot, root_type_name, runners};
 use crate::{
     db::traits::{DatabaseAdapter, SupportsMutations},
     error::{FraiseQLError, Result},
     graphql::FieldSelection,
+    runtime::mutation_transaction,
     security::SecurityContext,
 };
 
@@ -128,16 +129,7 @@ impl<A: DatabaseAdapter> Executor<A> {
         // determines the operation type at runtime, which precludes compile-time
         // mutation gating. The direct execute_mutation() API provides compile-time
         // enforcement via the SupportsMutations bound on MutationRunner.
-        if !self.ctx.adapter.supports_mutations() {
-            return Err(FraiseQLError::Validation {
-                message: format!(
-                    "Mutation '{mutation_name}' cannot be executed: the configured database \
-                     adapter does not support mutations. Use PostgresAdapter, MySqlAdapter, \
-                     or SqlServerAdapter for mutation operations."
-                ),
-                path:    None,
-            });
-        }
+        self.ensure_mutations_supported(mutation_name)?;
         runners::mutation::execute_mutation_impl(
             &self.ctx,
             mutation_name,
@@ -145,10 +137,117 @@ impl<A: DatabaseAdapter> Executor<A> {
             security_context,
             selections,
             inline_arguments,
+            None,
         )
         .await
     }
 
+    /// Execute a mutation operation with several top-level fields.
+    ///
+    /// Fields run serially, in document order, each through
+    /// [`execute_mutation_impl`](runners::mutation::execute_mutation_impl), and
+    /// their results are merged under their response keys. By default each
+    /// field commits on its own and the first failure stops the batch. When the
+    /// request asked for a transactional batch
+    /// ([`mutation_transaction::requested`]), every field runs in one
+    /// [`MutationTransaction`](crate::db::MutationTransaction) that commits only
+    /// if all of them succeed; a field whose function reports a failure also
+    /// rolls the batch back. The batch's cache invalidation is applied only
+    /// once the commit succeeds, and dropped on rollback.
+    ///
+    /// # Errors
+    ///
+    /// Same as `execute_mutation_query`, for the first field that fails. In a
+    /// transactional batch the error also names that field and how many earlier
+    /// mutations were rolled back; adapters without multi-call transactions
+    /// return `FraiseQLError::Unsupported`.
+    pub(super) async fn execute_mutation_batch_query(
+        &self,
+        roots: &[MutationRoot],
+        variables: Option<&serde_json::Value>,
+        security_context: Option<&SecurityContext>,
+    ) -> Result<serde_json::Value> {
+        if let Some(first) = roots.iter().find(|root| root.name != "__typename") {
+            self.ensure_mutations_supported(&first.name)?;
+        }
+        // Dry-run calls already roll back one by one; there is nothing to commit.
+        let transactional = mutation_transaction::requested() && !self.ctx.config.dry_run_mutations;
+        let mut batch = if transactional {
+            Some(runners::mutation::TransactionalBatch::new(
+                self.ctx.adapter.begin_mutation_transaction().await?,
+            ))
+        } else {
+            None
+        };
+
+        let mut data = serde_json::Map::new();
+        for (index, root) in roots.iter().enumerate() {
+            if root.name == "__typename" {
+                data.insert(
+                    root.response_key.clone(),
+                    serde_json::Value::String(root_type_name("mutation").to_string()),
+                );
+                continue;
+            }
+            let result = runners::mutation::execute_mutation_impl(
+                &self.ctx,
+                &root.name,
+                variables,
+                security_context,
+                &root.selections,
+                &root.arguments,
+                batch.as_mut(),
+            )
+            .await;
+            match result {
+                Ok(mut response) => {
+                    let value = response
+                        .get_mut("data")
+                        .and_then(|d| d.get_mut(&root.name))
+                        .map(serde_json::Value::take)
+                        .unwrap_or_default();
+                    data.insert(root.response_key.clone(), value);
+                },
+                Err(err) => {
+                    let Some(batch) = batch.take() else {
+                        return Err(err);
+                    };
+                    if let Err(rollback_err) = batch.txn.rollback().await {
+                        tracing::warn!(
+                            error = %rollback_err,
+                            "mutation transaction rollback failed; connection discarded"
+                        );
+                    }
+                    tracing::info!(
+                        failed_field = %root.response_key,
+                        undone = index,
+                        "mutation transaction rolled back"
+                    );
+                    return Err(mutation_transaction::rolled_back(err, &root.response_key, index));
+                },
+            }
+        }
+        if let Some(batch) = batch {
+            batch.commit(&self.ctx).await?;
+        }
+        Ok(serde_json::json!({ "data": data }))
+    }
+
+    /// Runtime guard: verify this adapter supports mutations.
+    fn ensure_mutations_supported(&self, mutation_name: &str) -> Result<()> {
+        if self.ctx.adapter.supports_mutations() {
+            return Ok(());
+        }
+        Err(FraiseQLError::Validation {
+            message: format!(
+                "Mutation '{mutation_name}' cannot be executed: the configured database adapter \
+                 does not support mutations. Use PostgresAdapter, MySqlAdapter, or \
+                 SqlServerAdapter for mutation operations."
+            ),
+            path:    None,
+        })
+    }
+
     /// Execute a mutation with security context for REST transport.
     ///
     /// Delegates to the standard mutation execution path with RLS enforcement.
@@ -23,18 +23,21 @@ impl<A: DatabaseAdapter> AggregateRunner<A> {
     /// Resolve configured session variables for `security_context` into owned
     /// `(name, value)` pairs, for passing to the connection-affine
     /// `*_with_session` adapter methods so `current_setting()`-backed RLS on
-    /// aggregate views is effective (#329).
+    /// aggregate views is effective (#329), plus the request budget's
+    /// `statement_timeout` (see [`crate::runtime::budget`]).
     fn resolve_session_vars(
         &self,
         security_context: Option<&SecurityContext>,
     ) -> Result<Vec<(String, String)>> {
         let sv = &self.ctx.schema.session_variables;
-        match security_context {
+        let mut vars = match security_context {
             Some(sec) if !sv.variables.is_empty() || sv.inject_started_at => {
-                crate::runtime::executor::security::resolve_session_variables(sv, sec)
+                crate::runtime::executor::security::resolve_session_variables(sv, sec)?
             },
-            _ => Ok(Vec::new()),
-        }
+            _ => Vec::new(),
+        };
+        crate::runtime::budget::push_statement_timeout(&mut vars, self.ctx.adapter.database_type());
+        Ok(vars)
     }
 
     /// Execute an aggregate query dispatch.
@@ -0,0 +1,103 @@
+//! Cache invalidation for mutations, deferred to commit inside transactional batches.
+//!
+//! A standalone mutation commits inside its own function call, so its cache
+//! invalidation runs straight after. In a transactional batch nothing is visible
+//! until the batch commits: invalidating earlier would let a concurrent read
+//! re-cache the pre-commit rows, and a rollback would leave nothing to
+//! invalidate. [`TransactionalBatch`] therefore collects each mutation's
+//! [`CacheInvalidation`] and the batch applies it only once the commit succeeds.
+
+use fraiseql_db::{MutationTransaction, ViewName};
+
+use super::super::super::context::ExecutorContext;
+use crate::{db::traits::DatabaseAdapter, error::Result};
+
+/// Cache entries made stale by one or more successful mutations.
+#[derive(Debug, Default)]
+pub(in crate::runtime::executor) struct CacheInvalidation {
+    /// Fact tables whose cached aggregation results must be versioned out.
+    pub(super) fact_tables:    Vec<String>,
+    /// `(entity type, entity id)` pairs evicted from the adapter cache.
+    pub(super) entities:       Vec<(String, String)>,
+    /// Views whose list (multi-row) adapter cache entries are evicted.
+    pub(super) list_views:     Vec<ViewName>,
+    /// Views whose adapter cache entries are all evicted.
+    pub(super) views:          Vec<ViewName>,
+    /// Views evicted from the response cache.
+    pub(super) response_views: Vec<ViewName>,
+}
+
+impl CacheInvalidation {
+    /// Add the invalidation of a later mutation to this one.
+    pub(super) fn extend(&mut self, other: Self) {
+        self.fact_tables.extend(other.fact_tables);
+        self.entities.extend(other.entities);
+        self.list_views.extend(other.list_views);
+        self.views.extend(other.views);
+        self.response_views.extend(other.response_views);
+    }
+
+    /// Evict the collected entries from the adapter and response caches.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by the adapter cache.
+    pub(in crate::runtime::executor) async fn apply<A: DatabaseAdapter>(
+        self,
+        ctx: &ExecutorContext<A>,
+    ) -> Result<()> {
+        // Non-cached adapters implement all of these as no-ops; only
+        // `CachedDatabaseAdapter` performs actual work.
+        if !self.fact_tables.is_empty() {
+            ctx.adapter.bump_fact_table_versions(&self.fact_tables).await?;
+        }
+        for (entity_type, entity_id) in &self.entities {
+            ctx.adapter.invalidate_by_entity(entity_type, entity_id).await?;
+        }
+        if !self.list_views.is_empty() {
+            ctx.adapter.invalidate_list_queries(&self.list_views).await?;
+        }
+        if !self.views.is_empty() {
+            ctx.adapter.invalidate_views(&self.views).await?;
+        }
+        if let Some(ref rc) = ctx.response_cache {
+            if !self.response_views.is_empty() {
+                let _ = rc.invalidate_views(&self.response_views);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The open transaction of a transactional mutation batch, with the cache
+/// invalidation its mutations defer until it commits.
+pub(in crate::runtime::executor) struct TransactionalBatch {
+    /// Transaction every mutation of the batch runs on.
+    pub(in crate::runtime::executor) txn:          Box<dyn MutationTransaction>,
+    /// Invalidation collected from the batch's successful mutations.
+    pub(in crate::runtime::executor) invalidation: CacheInvalidation,
+}
+
+impl TransactionalBatch {
+    /// Start collecting invalidation for the mutations run on `txn`.
+    pub(in crate::runtime::executor) fn new(txn: Box<dyn MutationTransaction>) -> Self {
+        Self {
+            txn,
+            invalidation: CacheInvalidation::default(),
+        }
+    }
+
+    /// Commit the transaction, then apply the collected invalidation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the commit error, in which case nothing is invalidated, or the
+    /// first error reported by the adapter cache.
+    pub(in crate::runtime::executor) async fn commit<A: DatabaseAdapter>(
+        self,
+        ctx: &ExecutorContext<A>,
+    ) -> Result<()> {
+        self.txn.commit().await?;
+        self.invalidation.apply(ctx).await
+    }
+}
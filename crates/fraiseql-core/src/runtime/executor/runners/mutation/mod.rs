@@ -7,8 +7,12 @@
 //! [`MutationRunner`] path and the runtime-guarded `execute_mutation_query` path on
 //! [`Executor`](super::super::core::Executor).
 
+mod batch;
+
 use std::sync::Arc;
 
+use batch::CacheInvalidation;
+pub(in crate::runtime::executor) use batch::TransactionalBatch;
 use fraiseql_db::{
     ChangeLogWrite, DirectMutationContext, DirectMutationOp, MutationStrategy, ViewName,
 };
@@ -625,7 +629,8 @@ impl<A: DatabaseAdapter + SupportsMutations> MutationRunner<A> {
     ) -> Result<serde_json::Value> {
         // The typed SupportsMutations API supplies the input via `variables`; it
         // has no inline-literal root arguments to resolve.
-        execute_mutation_impl(&self.ctx, mutation_name, variables, None, selections, &[]).await
+        execute_mutation_impl(&self.ctx, mutation_name, variables, None, selections, &[], None)
+            .await
     }
 }
 
@@ -779,10 +784,17 @@ fn nested_input_type_name(field_type: &str, schema: &CompiledSchema) -> Option<S
 /// this function (either via the compile-time `SupportsMutations` bound or a runtime
 /// `supports_mutations()` guard).
 ///
+/// `batch` is the open transaction of a transactional mutation batch
+/// (`Executor::execute_mutation_batch_query`); the function call then runs on it
+/// instead of committing on its own, a function-reported failure becomes an
+/// error so the batch rolls back, and the cache invalidation is added to the
+/// batch to be applied after it commits. Every other caller passes `None`.
+///
 /// # Errors
 ///
 /// * [`FraiseQLError::Validation`] — mutation not found, no `sql_source`, missing security context
-///   for `inject` params, or database function returned no rows.
+///   for `inject` params, database function returned no rows, or (inside a transaction) the
+///   function reported a failure.
 /// * [`FraiseQLError::Database`] — the adapter's `execute_function_call` failed.
 pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
     ctx: &ExecutorContext<A>,
@@ -791,7 +803,10 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
     security_ctx: Option<&SecurityContext>,
     selections: &[FieldSelection],
     inline_arguments: &[crate::graphql::GraphQLArgument],
+    mut batch: Option<&mut TransactionalBatch>,
 ) -> Result<serde_json::Value> {
+    let in_transaction = batch.is_some();
+
     // 1. Locate the mutation definition
     let mutation_def = ctx.schema.find_mutation(mutation_name).ok_or_else(|| {
         let display_names: Vec<String> =
@@ -916,6 +931,14 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
 
     let vars_obj = variables.and_then(|v| v.as_object());
 
+    // Reject arguments that fail their custom scalar definition before building the
+    // SQL call.
+    crate::runtime::input_validator::validate_custom_scalar_arguments(
+        &mutation_def.arguments,
+        |name| vars_obj.and_then(|obj| obj.get(name)),
+        &ctx.schema,
+    )?;
+
     let mut missing_required: Vec<&str> = Vec::new();
     let total_args = mutation_def.arguments.len() + mutation_def.inject_params.len();
     let mut args: Vec<serde_json::Value> = Vec::with_capacity(total_args);
@@ -1145,6 +1168,14 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
                 });
             },
         };
+        // A delete of a soft-deleted type stamps its timestamp column instead.
+        let soft_delete_column = if operation == DirectMutationOp::Delete {
+            ctx.schema
+                .soft_delete(&mutation_def.return_type)
+                .map(|(field, _)| crate::utils::to_snake_case(field))
+        } else {
+            None
+        };
         let direct_ctx = DirectMutationContext {
             operation,
             table,
@@ -1152,6 +1183,7 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
             values: &args,
             inject_columns: &direct_inject_columns,
             return_type: &mutation_def.return_type,
+            soft_delete_column: soft_delete_column.as_deref(),
         };
         let rows = ctx.adapter.execute_direct_mutation(&direct_ctx).await?;
         let row_value = rows.into_iter().next().ok_or_else(|| FraiseQLError::Validation {
@@ -1272,7 +1304,14 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
                 // default → no extra column, byte-for-byte today's behavior.
                 .with_pre_image(mutation_def.changelog_pre_image)
         });
-        let rows = if ctx.config.dry_run_mutations {
+        let rows = if let Some(batch) = batch.as_deref_mut() {
+            // Transactional batch: run on the batch's connection; the outbox row
+            // commits (or rolls back) with the rest of the batch.
+            batch
+                .txn
+                .execute_function_call(sql_source, &args, &session_pairs, changelog.as_ref())
+                .await?
+        } else if ctx.config.dry_run_mutations {
             // Validate-bind-without-commit (#501): run the function inside a
             // transaction the adapter rolls back, so nothing persists and no
             // outbox row is written. The `changelog` descriptor above is unused
@@ -1302,19 +1341,34 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
         parse_mutation_row(&row)?
     };
 
-    // 6a. Bump fact table versions after a successful mutation.
-    //
-    // This invalidates cached aggregation results for any fact tables listed
-    // in `MutationDefinition.invalidates_fact_tables`.  We bump versions on
-    // Success only — an Error outcome means no data was written, so caches
-    // remain valid.  Non-cached adapters return Ok(()) from the default trait
-    // implementation (no-op); only `CachedDatabaseAdapter` performs actual work.
-    if matches!(outcome, MutationOutcome::Success { .. })
-        && !mutation_def.invalidates_fact_tables.is_empty()
-    {
-        ctx.adapter
-            .bump_fact_table_versions(&mutation_def.invalidates_fact_tables)
-            .await?;
+    // Inside a transactional batch a reported failure must undo the mutations
+    // before it, so it aborts the batch instead of being projected.
+    if in_transaction {
+        if let MutationOutcome::Error {
+            error_class,
+            message,
+            ..
+        } = &outcome
+        {
+            return Err(FraiseQLError::Validation {
+                message: format!(
+                    "Mutation '{mutation_name}' failed ({}): {message}",
+                    error_class.as_str()
+                ),
+                path:    None,
+            });
+        }
+    }
+
+    // 6a. Collect the cache entries this mutation makes stale. Only a Success
+    // outcome invalidates — an Error outcome means no data was written, so
+    // caches remain valid.
+    let mut invalidation = CacheInvalidation::default();
+
+    // Bump fact table versions: invalidates cached aggregation results for any
+    // fact tables listed in `MutationDefinition.invalidates_fact_tables`.
+    if matches!(outcome, MutationOutcome::Success { .. }) {
+        invalidation.fact_tables.clone_from(&mutation_def.invalidates_fact_tables);
     }
 
     // Invalidate query result cache for views/entities touched by this mutation.
@@ -1334,22 +1388,18 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
     {
         // Entity-aware path: precise eviction for UPDATE/DELETE.
         if let (Some(etype), Some(eid)) = (entity_type.as_deref(), entity_id.as_deref()) {
-            ctx.adapter.invalidate_by_entity(etype, eid).await?;
+            invalidation.entities.push((etype.to_string(), eid.to_string()));
 
             // The response cache doesn't have entity-level granularity, so
             // invalidate by the inferred view for this entity type.
-            if let Some(ref rc) = ctx.response_cache {
-                let inferred_view = ctx
-                    .schema
-                    .types
-                    .iter()
-                    .find(|t| t.name == etype)
-                    .filter(|t| !t.sql_source.as_str().is_empty())
-                    .map(|t| t.sql_source.to_string());
-                if let Some(view) = inferred_view {
-                    let _ = rc.invalidate_views(&[ViewName::from(view)]);
-                }
-            }
+            let inferred_view = ctx
+                .schema
+                .types
+                .iter()
+                .find(|t| t.name == etype)
+                .filter(|t| !t.sql_source.as_str().is_empty())
+                .map(|t| ViewName::from(t.sql_source.as_str()));
+            invalidation.response_views.extend(inferred_view);
         }
 
         // View-level path: needed when entity_id is absent (CREATE) or when the developer
@@ -1369,21 +1419,17 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
             } else {
                 mutation_def.invalidates_views.iter().map(ViewName::from).collect()
             };
-            if !views_to_invalidate.is_empty() {
-                if entity_id.is_none() {
-                    // CREATE: the new entity is absent from all existing cache entries,
-                    // so point-lookup entries for other entities remain valid.  Only
-                    // list queries need eviction (the new row must appear in results).
-                    ctx.adapter.invalidate_list_queries(&views_to_invalidate).await?;
-                } else {
-                    // Developer-declared invalidates_views on an UPDATE/DELETE: honour
-                    // the explicit annotation with a full view sweep.
-                    ctx.adapter.invalidate_views(&views_to_invalidate).await?;
-                }
-                // Also invalidate the response cache for these views
-                if let Some(ref rc) = ctx.response_cache {
-                    let _ = rc.invalidate_views(&views_to_invalidate);
-                }
+            // Also invalidate the response cache for these views
+            invalidation.response_views.extend(views_to_invalidate.iter().cloned());
+            if entity_id.is_none() {
+                // CREATE: the new entity is absent from all existing cache entries,
+                // so point-lookup entries for other entities remain valid.  Only
+                // list queries need eviction (the new row must appear in results).
+                invalidation.list_views.extend(views_to_invalidate);
+            } else {
+                // Developer-declared invalidates_views on an UPDATE/DELETE: honour
+                // the explicit annotation with a full view sweep.
+                invalidation.views.extend(views_to_invalidate);
             }
         }
     }
@@ -1403,15 +1449,19 @@ pub(in super::super) async fn execute_mutation_impl<A: DatabaseAdapter>(
         } = &outcome
         {
             let views = resolve_cascade_views(cascade_json, &ctx.schema);
-            if !views.is_empty() {
-                ctx.adapter.invalidate_views(&views).await?;
-                if let Some(ref rc) = ctx.response_cache {
-                    let _ = rc.invalidate_views(&views);
-                }
-            }
+            invalidation.response_views.extend(views.iter().cloned());
+            invalidation.views.extend(views);
         }
     }
 
+    // Inside a transactional batch nothing is visible before COMMIT: evicting now
+    // would let a concurrent read re-cache the pre-commit rows. The batch applies
+    // the invalidation after it commits and drops it on rollback.
+    match batch {
+        Some(batch) => batch.invalidation.extend(invalidation),
+        None => invalidation.apply(ctx).await?,
+    }
+
     // Clone name and return_type to avoid borrow issues after schema lookups
     let mutation_return_type = mutation_def.return_type.clone();
     let mutation_name_owned = mutation_name.to_string();
@@ -3586,3 +3586,330 @@ mod cascade {
         );
     }
 }
+
+// ── mod transaction_batch: multi-root and transactional mutation operations ──
+
+mod transaction_batch {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use fraiseql_db::MutationTransaction;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{runtime::mutation_transaction::with_transaction, schema::MutationDefinition};
+
+    type Journal = Arc<Mutex<Vec<String>>>;
+
+    /// `mutation_response` row for `function_name`: `fn_fail` reports a
+    /// failure, every other function succeeds with its name as the entity id.
+    fn response_row(function_name: &str) -> HashMap<String, serde_json::Value> {
+        let mut row = HashMap::new();
+        if function_name == "fn_fail" {
+            row.insert("succeeded".to_string(), json!(false));
+            row.insert("state_changed".to_string(), json!(false));
+            row.insert("error_class".to_string(), json!("conflict"));
+            row.insert("message".to_string(), json!("already exists"));
+        } else {
+            row.insert("succeeded".to_string(), json!(true));
+            row.insert("state_changed".to_string(), json!(true));
+            row.insert("entity".to_string(), json!({ "id": function_name }));
+            row.insert("entity_type".to_string(), json!("User"));
+            row.insert("message".to_string(), json!(""));
+        }
+        row
+    }
+
+    /// Transaction handle that journals its calls and how it finished.
+    struct JournalingTransaction {
+        journal: Journal,
+    }
+
+    #[async_trait]
+    impl MutationTransaction for JournalingTransaction {
+        async fn execute_function_call(
+            &mut self,
+            function_name: &str,
+            _args: &[serde_json::Value],
+            _session_vars: &[(&str, &str)],
+            _changelog: Option<&ChangeLogWrite<'_>>,
+        ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+            self.journal.lock().unwrap().push(format!("txn {function_name}"));
+            Ok(vec![response_row(function_name)])
+        }
+
+        async fn commit(self: Box<Self>) -> Result<()> {
+            self.journal.lock().unwrap().push("commit".to_string());
+            Ok(())
+        }
+
+        async fn rollback(self: Box<Self>) -> Result<()> {
+            self.journal.lock().unwrap().push("rollback".to_string());
+            Ok(())
+        }
+    }
+
+    /// Adapter that journals standalone function calls and opens
+    /// [`JournalingTransaction`]s sharing the same journal.
+    #[derive(Default)]
+    struct TransactionalMockAdapter {
+        journal: Journal,
+    }
+
+    impl TransactionalMockAdapter {
+        fn journal(&self) -> Vec<String> {
+            self.journal.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl DatabaseAdapter for TransactionalMockAdapter {
+        async fn execute_function_call(
+            &self,
+            function_name: &str,
+            _args: &[serde_json::Value],
+        ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+            self.journal.lock().unwrap().push(format!("call {function_name}"));
+            Ok(vec![response_row(function_name)])
+        }
+
+        async fn bump_fact_table_versions(&self, tables: &[String]) -> Result<()> {
+            self.journal.lock().unwrap().push(format!("invalidate {}", tables.join(",")));
+            Ok(())
+        }
+
+        async fn begin_mutation_transaction(&self) -> Result<Box<dyn MutationTransaction>> {
+            self.journal.lock().unwrap().push("begin".to_string());
+            Ok(Box::new(JournalingTransaction {
+                journal: Arc::clone(&self.journal),
+            }))
+        }
+
+        async fn execute_with_projection(
+            &self,
+            _view: &str,
+            _projection: Option<&crate::schema::SqlProjectionHint>,
+            _where_clause: Option<&WhereClause>,
+            _limit: Option<u32>,
+            _offset: Option<u32>,
+            _order_by: Option<&[OrderByClause]>,
+        ) -> Result<Vec<JsonbValue>> {
+            Ok(vec![])
+        }
+
+        async fn execute_where_query(
+            &self,
+            _view: &str,
+            _where_clause: Option<&WhereClause>,
+            _limit: Option<u32>,
+            _offset: Option<u32>,
+            _order_by: Option<&[OrderByClause]>,
+        ) -> Result<Vec<JsonbValue>> {
+            Ok(vec![])
+        }
+
+        async fn health_check(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn database_type(&self) -> DatabaseType {
+            DatabaseType::PostgreSQL
+        }
+
+        fn pool_metrics(&self) -> PoolMetrics {
+            PoolMetrics {
+                total_connections:  1,
+                active_connections: 0,
+                idle_connections:   1,
+                waiting_requests:   0,
+            }
+        }
+
+        async fn execute_raw_query(
+            &self,
+            _sql: &str,
+        ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+            Ok(vec![])
+        }
+
+        async fn execute_parameterized_aggregate(
+            &self,
+            _sql: &str,
+            _params: &[serde_json::Value],
+        ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+            Ok(vec![])
+        }
+    }
+
+    impl SupportsMutations for TransactionalMockAdapter {}
+
+    fn batch_executor() -> (Executor<TransactionalMockAdapter>, Arc<TransactionalMockAdapter>) {
+        let mut schema = CompiledSchema::new();
+        for (name, function) in [
+            ("createUser", "fn_create_user"),
+            ("createPost", "fn_create_post"),
+            ("failingMutation", "fn_fail"),
+        ] {
+            schema.mutations.push(MutationDefinition {
+                sql_source: Some(function.to_string()),
+                ..MutationDefinition::new(name, "User")
+            });
+        }
+        schema.mutations.push(MutationDefinition {
+            sql_source: Some("fn_create_order".to_string()),
+            invalidates_fact_tables: vec!["tf_orders".to_string()],
+            ..MutationDefinition::new("createOrder", "User")
+        });
+        let adapter = Arc::new(TransactionalMockAdapter::default());
+        (Executor::new(schema, Arc::clone(&adapter)), adapter)
+    }
+
+    /// Every top-level field runs, in document order, keyed by its alias; by
+    /// default each one commits on its own.
+    #[tokio::test]
+    async fn multi_root_mutation_runs_every_field_serially() {
+        let (executor, adapter) = batch_executor();
+        let result = executor
+            .execute("mutation { a: createUser { id } b: createPost { id } __typename }", None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            json!({ "data": {
+                "a": { "id": "fn_create_user" },
+                "b": { "id": "fn_create_post" },
+                "__typename": "Mutation",
+            }})
+        );
+        assert_eq!(adapter.journal(), ["call fn_create_user", "call fn_create_post"]);
+    }
+
+    /// With a transaction requested, all fields share one transaction that
+    /// commits once at the end.
+    #[tokio::test]
+    async fn transactional_batch_commits_once() {
+        let (executor, adapter) = batch_executor();
+        let result = with_transaction(
+            true,
+            executor.execute("mutation { a: createUser { id } b: createPost { id } }", None),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result["data"]["b"]["id"], "fn_create_post");
+        assert_eq!(
+            adapter.journal(),
+            [
+                "begin",
+                "txn fn_create_user",
+                "txn fn_create_post",
+                "commit"
+            ]
+        );
+    }
+
+    /// A reported failure rolls back the whole batch, and the error names the
+    /// failed field and the mutations undone before it.
+    #[tokio::test]
+    async fn transactional_batch_rolls_back_on_reported_failure() {
+        let (executor, adapter) = batch_executor();
+        let err = with_transaction(
+            true,
+            executor.execute(
+                "mutation { a: createUser { id } b: failingMutation { id } c: createPost { id } }",
+                None,
+            ),
+        )
+        .await
+        .unwrap_err();
+
+        let msg = err.to_string();
+        assert!(
+            msg.contains("rolled back at 'b'") && msg.contains("1 earlier mutation(s) undone"),
+            "got: {msg}"
+        );
+        assert!(msg.contains("already exists"), "got: {msg}");
+        assert_eq!(adapter.journal(), ["begin", "txn fn_create_user", "txn fn_fail", "rollback"]);
+    }
+
+    /// A transactional batch invalidates caches only once it has committed, so a
+    /// read between a mutation and the commit cannot re-cache pre-commit rows.
+    #[tokio::test]
+    async fn transactional_batch_invalidates_caches_after_commit() {
+        let (executor, adapter) = batch_executor();
+        with_transaction(
+            true,
+            executor.execute("mutation { a: createOrder { id } b: createPost { id } }", None),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            adapter.journal(),
+            [
+                "begin",
+                "txn fn_create_order",
+                "txn fn_create_post",
+                "commit",
+                "invalidate tf_orders"
+            ]
+        );
+    }
+
+    /// A rolled-back batch wrote nothing, so it invalidates nothing.
+    #[tokio::test]
+    async fn transactional_batch_rollback_drops_invalidation() {
+        let (executor, adapter) = batch_executor();
+        with_transaction(
+            true,
+            executor.execute("mutation { a: createOrder { id } b: failingMutation { id } }", None),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(adapter.journal(), ["begin", "txn fn_create_order", "txn fn_fail", "rollback"]);
+    }
+
+    /// Outside a transaction a mutation commits on its own and invalidates
+    /// straight after.
+    #[tokio::test]
+    async fn standalone_mutation_invalidates_immediately() {
+        let (executor, adapter) = batch_executor();
+        executor
+            .execute("mutation { a: createOrder { id } b: createPost { id } }", None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            adapter.journal(),
+            [
+                "call fn_create_order",
+                "invalidate tf_orders",
+                "call fn_create_post"
+            ]
+        );
+    }
+
+    /// Adapters without multi-call transactions refuse the batch instead of
+    /// running it without atomicity.
+    #[tokio::test]
+    async fn transactional_batch_unsupported_without_adapter_support() {
+        let mut schema = CompiledSchema::new();
+        schema.mutations.push(MutationDefinition {
+            sql_source: Some("fn_create_user".to_string()),
+            ..MutationDefinition::new("createUser", "User")
+        });
+        let executor = Executor::new(schema, Arc::new(MockAdapter::new(vec![])));
+        let err = with_transaction(
+            true,
+            executor.execute("mutation { a: createUser { id } b: createUser { id } }", None),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, FraiseQLError::Unsupported { .. }), "got: {err:?}");
+    }
+}
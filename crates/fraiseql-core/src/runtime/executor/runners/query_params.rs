@@ -4,14 +4,22 @@
 //! explicit query arguments, and compute response cache keys.
 
 use crate::{
-    db::{WhereClause, WhereOperator},
+    db::{RelationJoin, TextSearchConfig, WhereClause, WhereOperator},
     error::{FraiseQLError, Result},
 };
 
 /// Auto-wired argument names that are handled by the `auto_params` system.
 /// These are never treated as explicit WHERE filters.
 pub const AUTO_PARAM_NAMES: &[&str] = &[
-    "where", "limit", "offset", "orderBy", "first", "last", "after", "before",
+    "where",
+    "limit",
+    "offset",
+    "orderBy",
+    "first",
+    "last",
+    "after",
+    "before",
+    "includeDeleted",
 ];
 
 /// Build a `WhereClause` for a single inject param, respecting `native_columns`.
@@ -43,6 +51,133 @@ pub fn inject_param_where_clause(
     }
 }
 
+/// Build the row-tenancy predicate for a read through `query_def`.
+///
+/// Under `TenancyMode::Row`, a return type marked `@tenant_id`
+/// ([`TypeDefinition::tenant_column`](crate::schema::TypeDefinition::tenant_column))
+/// is only ever read scoped to the requesting tenant: the predicate is built
+/// from the schema, not from the query's own `inject_params`, so a hand-authored
+/// query, a REST read or a `node` lookup cannot forget it.
+///
+/// Returns `Ok(None)` when the schema is not row-isolated, the type is not
+/// tenant-scoped, or the query already injects the tenant column (the
+/// compiler's auto-injection — adding it twice is redundant).
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Authorization`] when the type is tenant-scoped but the
+/// request carries no tenant claim. Fail-closed: an unscoped read would return
+/// every tenant's rows.
+pub fn tenant_row_filter(
+    schema: &crate::schema::CompiledSchema,
+    query_def: &crate::schema::QueryDefinition,
+    security_context: Option<&crate::security::SecurityContext>,
+) -> Result<Option<WhereClause>> {
+    let Some(column) = schema.tenant_column(&query_def.return_type) else {
+        return Ok(None);
+    };
+    if query_def.inject_params.contains_key(column) {
+        return Ok(None);
+    }
+    let claim = schema.tenancy_config().map_or("tenant_id", |t| t.tenant_claim.as_str());
+    let tenant = security_context
+        .and_then(|ctx| crate::tenancy::TenantContext::from_security_context(ctx, claim))
+        .ok_or_else(|| FraiseQLError::Authorization {
+            message:  format!(
+                "Type '{}' is tenant-scoped but the request carries no '{claim}' claim",
+                query_def.return_type
+            ),
+            action:   Some("read".to_string()),
+            resource: Some(query_def.return_type.clone()),
+        })?;
+    Ok(Some(inject_param_where_clause(
+        column,
+        serde_json::Value::String(tenant.id().to_string()),
+        &query_def.native_columns,
+    )))
+}
+
+/// Build the soft-delete predicate for a read through `query_def`.
+///
+/// A return type declaring a
+/// [`soft_delete_field`](crate::schema::TypeDefinition::soft_delete_field) is
+/// read without its deleted rows (`<field> IS NULL`). `includeDeleted: true`
+/// lifts the predicate for a caller holding the type's include-deleted scope,
+/// directly or through one of its roles.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Authorization`] when `includeDeleted: true` is passed
+/// without that scope, or by an unauthenticated request.
+pub fn soft_delete_filter(
+    schema: &crate::schema::CompiledSchema,
+    query_def: &crate::schema::QueryDefinition,
+    arguments: &std::collections::HashMap<String, serde_json::Value>,
+    security_context: Option<&crate::security::SecurityContext>,
+) -> Result<Option<WhereClause>> {
+    let Some((field, scope)) = schema.soft_delete(&query_def.return_type) else {
+        return Ok(None);
+    };
+    let include_deleted = arguments
+        .get("includeDeleted")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    if !include_deleted {
+        let value = serde_json::Value::Bool(true);
+        return Ok(Some(match query_def.native_columns.get(field) {
+            Some(pg_type) => WhereClause::NativeField {
+                column: crate::utils::to_snake_case(field),
+                pg_cast: pg_type_to_cast(pg_type).to_string(),
+                operator: WhereOperator::IsNull,
+                value,
+            },
+            None => WhereClause::Field {
+                path: vec![crate::utils::to_snake_case(field)],
+                operator: WhereOperator::IsNull,
+                value,
+            },
+        }));
+    }
+    let allowed = security_context.is_some_and(|ctx| {
+        ctx.has_scope(&scope)
+            || schema
+                .security
+                .as_ref()
+                .is_some_and(|config| ctx.can_access_scope(config, &scope))
+    });
+    if allowed {
+        Ok(None)
+    } else {
+        Err(FraiseQLError::Authorization {
+            message:  format!(
+                "Reading deleted '{}' rows requires the '{scope}' scope",
+                query_def.return_type
+            ),
+            action:   Some("read".to_string()),
+            resource: Some(query_def.return_type.clone()),
+        })
+    }
+}
+
+/// AND a security predicate in front of `rest`, so it cannot be bypassed.
+pub fn prepend_where(
+    security: Option<WhereClause>,
+    rest: Option<WhereClause>,
+) -> Option<WhereClause> {
+    match (security, rest) {
+        (Some(sec), Some(rest)) => Some(WhereClause::And(vec![sec, rest])),
+        (sec, rest) => sec.or(rest),
+    }
+}
+
+/// Build the WHERE condition of a resolved full-text `search` argument.
+pub fn search_where(search: Option<(&TextSearchConfig, &str)>) -> Option<WhereClause> {
+    search.map(|(config, query)| WhereClause::TextSearch {
+        config: config.clone(),
+        query:  query.to_string(),
+    })
+}
+
 /// Convert PostgreSQL `information_schema.data_type` to a safe SQL cast suffix.
 ///
 /// Returns an empty string for types that need no cast (e.g. `text`, `varchar`).
@@ -148,6 +283,236 @@ pub fn combine_explicit_arg_where(
     }
 }
 
+/// Rewrite `Field` predicates on custom-scalar fields into `TypedField` ones.
+///
+/// A predicate whose path resolves (through nested object fields of
+/// `return_type`) to a custom scalar with a declared `sql_type` is compared in
+/// that SQL type, so `price: { gte: "10.00" }` on a `Money` field mapped to
+/// `numeric(12,2)` orders numerically rather than lexically. Every other
+/// predicate is returned unchanged.
+#[must_use]
+pub fn apply_scalar_casts(
+    clause: WhereClause,
+    return_type: &str,
+    schema: &crate::schema::CompiledSchema,
+) -> WhereClause {
+    if schema.custom_scalars.is_empty() {
+        return clause;
+    }
+    match clause {
+        WhereClause::And(clauses) => WhereClause::And(
+            clauses
+                .into_iter()
+                .map(|c| apply_scalar_casts(c, return_type, schema))
+                .collect(),
+        ),
+        WhereClause::Or(clauses) => WhereClause::Or(
+            clauses
+                .into_iter()
+                .map(|c| apply_scalar_casts(c, return_type, schema))
+                .collect(),
+        ),
+        WhereClause::Not(inner) => {
+            WhereClause::Not(Box::new(apply_scalar_casts(*inner, return_type, schema)))
+        },
+        WhereClause::Field {
+            path,
+            operator,
+            value,
+        } => match scalar_sql_type(&path, return_type, schema) {
+            Some(sql_type) => WhereClause::TypedField {
+                path,
+                sql_type,
+                operator,
+                value,
+            },
+            None => WhereClause::Field {
+                path,
+                operator,
+                value,
+            },
+        },
+        other => other,
+    }
+}
+
+/// Rewrite geospatial `Field` predicates into `Geo` ones bound to the schema's
+/// [`GeoBackend`](crate::db::GeoBackend).
+///
+/// Plain geospatial `Field` predicates render PostGIS SQL, so this is a no-op
+/// unless `fraiseql compile --database` found only the `earthdistance`
+/// fallback. Every other predicate is returned unchanged.
+#[must_use]
+pub fn apply_geo_backend(
+    clause: WhereClause,
+    schema: &crate::schema::CompiledSchema,
+) -> WhereClause {
+    if schema.geo_backend == crate::db::GeoBackend::PostGis {
+        return clause;
+    }
+    match clause {
+        WhereClause::And(clauses) => {
+            WhereClause::And(clauses.into_iter().map(|c| apply_geo_backend(c, schema)).collect())
+        },
+        WhereClause::Or(clauses) => {
+            WhereClause::Or(clauses.into_iter().map(|c| apply_geo_backend(c, schema)).collect())
+        },
+        WhereClause::Not(inner) => WhereClause::Not(Box::new(apply_geo_backend(*inner, schema))),
+        WhereClause::Field {
+            path,
+            operator,
+            value,
+        } if operator.is_geo() => WhereClause::Geo {
+            path,
+            operator,
+            value,
+            backend: schema.geo_backend,
+        },
+        other => other,
+    }
+}
+
+/// Bind `some` / `every` / `none` relation filters to the related views.
+///
+/// Each [`WhereClause::Relation`] is matched against the `relationships`
+/// declared on `type_name` and gets the join that turns it into an `EXISTS`
+/// subquery; `parent` is the relation the outer rows are read from (the
+/// query's `sql_source`). Nested filters get the same scalar-cast and geo
+/// rewrites as the top level, resolved against the related type.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Validation`] when a quantifier is applied to a
+/// field that is not a declared relationship, or the related type has no view.
+pub fn resolve_relation_filters(
+    clause: WhereClause,
+    type_name: &str,
+    parent: &str,
+    schema: &crate::schema::CompiledSchema,
+) -> Result<WhereClause> {
+    bind_relation_filters(clause, type_name, parent, schema, 0)
+}
+
+fn bind_relation_filters(
+    clause: WhereClause,
+    type_name: &str,
+    parent: &str,
+    schema: &crate::schema::CompiledSchema,
+    depth: usize,
+) -> Result<WhereClause> {
+    let bind_all = |clauses: Vec<WhereClause>| -> Result<Vec<WhereClause>> {
+        clauses
+            .into_iter()
+            .map(|c| bind_relation_filters(c, type_name, parent, schema, depth))
+            .collect()
+    };
+    match clause {
+        WhereClause::And(clauses) => Ok(WhereClause::And(bind_all(clauses)?)),
+        WhereClause::Or(clauses) => Ok(WhereClause::Or(bind_all(clauses)?)),
+        WhereClause::Not(inner) => Ok(WhereClause::Not(Box::new(bind_relation_filters(
+            *inner, type_name, parent, schema, depth,
+        )?))),
+        WhereClause::Relation(mut relation) => {
+            let field = relation.path.join(".");
+            let alias = format!("_r{depth}");
+            let bound = match relation.path.as_slice() {
+                [name] => relationship_join(schema, type_name, name, alias.clone(), parent)?,
+                _ => None,
+            };
+            let (join, target_type) = bound.ok_or_else(|| FraiseQLError::Validation {
+                message: format!(
+                    "`{}` filter on `{type_name}.{field}` requires a declared relationship",
+                    relation.quantifier.as_str()
+                ),
+                path:    Some(format!("where.{field}")),
+            })?;
+            let filter =
+                apply_scalar_casts(apply_geo_backend(relation.filter, schema), target_type, schema);
+            relation.filter =
+                bind_relation_filters(filter, target_type, &alias, schema, depth + 1)?;
+            relation.join = Some(join);
+            Ok(WhereClause::Relation(relation))
+        },
+        other => Ok(other),
+    }
+}
+
+/// Join from `parent` rows of `type_name` to the view of its relationship
+/// `name` (`snake_case`), aliased `alias`, along with the related type's name.
+///
+/// Returns `Ok(None)` when `name` is not a declared relationship of the type.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Validation`] when the related type has no view.
+pub(super) fn relationship_join<'a>(
+    schema: &'a crate::schema::CompiledSchema,
+    type_name: &str,
+    name: &str,
+    alias: String,
+    parent: &str,
+) -> Result<Option<(RelationJoin, &'a str)>> {
+    let Some(rel) = schema.find_type(type_name).and_then(|t| {
+        t.relationships.iter().find(|r| crate::utils::to_snake_case(&r.name) == name)
+    }) else {
+        return Ok(None);
+    };
+    let source = schema
+        .queries
+        .iter()
+        .find(|q| q.return_type == rel.target_type && q.returns_list)
+        .and_then(|q| q.sql_source.clone())
+        .or_else(|| schema.find_type(&rel.target_type).map(|t| t.sql_source.as_str().to_string()))
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| FraiseQLError::Validation {
+            message: format!(
+                "relationship `{type_name}.{name}` targets `{}`, which has no SQL view",
+                rel.target_type
+            ),
+            path:    None,
+        })?;
+    // Same key direction as REST embedding: a one-to-many child points at
+    // the parent's referenced key, otherwise the parent holds the FK.
+    let (key, parent_key) = match rel.cardinality {
+        crate::schema::Cardinality::ManyToOne | crate::schema::Cardinality::OneToOne => {
+            (&rel.referenced_key, &rel.foreign_key)
+        },
+        crate::schema::Cardinality::OneToMany => (&rel.foreign_key, &rel.referenced_key),
+    };
+    let join = RelationJoin {
+        source,
+        alias,
+        key: key.clone(),
+        parent: parent.to_string(),
+        parent_key: parent_key.clone(),
+    };
+    Ok(Some((join, rel.target_type.as_str())))
+}
+
+/// Resolve a `snake_case` WHERE path against `type_name` and return the SQL
+/// type of the custom scalar it ends on, if any.
+fn scalar_sql_type(
+    path: &[String],
+    type_name: &str,
+    schema: &crate::schema::CompiledSchema,
+) -> Option<String> {
+    let (head, rest) = path.split_first()?;
+    let field = schema
+        .find_type(type_name)?
+        .fields
+        .iter()
+        .find(|f| crate::utils::to_snake_case(f.jsonb_key()) == *head)?;
+    let name = match &field.field_type {
+        crate::schema::FieldType::Object(name) | crate::schema::FieldType::Scalar(name) => name,
+        _ => return None,
+    };
+    if rest.is_empty() {
+        schema.custom_scalars.sql_type(name)
+    } else {
+        scalar_sql_type(rest, name, schema)
+    }
+}
+
 /// Reject a pagination argument that exceeds the configured maximum page size.
 ///
 /// Returns the value unchanged when it is within the ceiling, when no ceiling is
@@ -221,3 +221,267 @@ fn inject_param_native_camel_is_recased_to_snake_column() {
         other => panic!("expected WhereClause::NativeField, got {other:?}"),
     }
 }
+
+fn money_schema() -> crate::schema::CompiledSchema {
+    use crate::{
+        schema::{CompiledSchema, FieldDefinition, FieldType, TypeDefinition},
+        validation::CustomTypeDef,
+    };
+
+    let mut schema = CompiledSchema::new();
+    let mut money = CustomTypeDef::new("Money".to_string());
+    money.sql_type = Some("numeric(12,2)".to_string());
+    schema.custom_scalars.register("Money".to_string(), money).unwrap();
+    schema.types.push(
+        TypeDefinition::new("Order", "v_order")
+            .with_field(FieldDefinition::new("unitPrice", FieldType::Object("Money".into())))
+            .with_field(FieldDefinition::new("shipping", FieldType::Object("Shipping".into())))
+            .with_field(FieldDefinition::new("reference", FieldType::String)),
+    );
+    schema.types.push(
+        TypeDefinition::new("Shipping", "v_shipping")
+            .with_field(FieldDefinition::new("cost", FieldType::Object("Money".into()))),
+    );
+    schema
+}
+
+#[test]
+fn apply_scalar_casts_types_custom_scalar_fields() {
+    let schema = money_schema();
+    let clause = WhereClause::from_graphql_json(&serde_json::json!({
+        "unitPrice": { "gte": "10.00" },
+        "shipping": { "cost": { "lt": "5.00" } },
+        "reference": { "eq": "A-1" },
+    }))
+    .expect("where-input parses");
+
+    let WhereClause::And(clauses) = apply_scalar_casts(clause, "Order", &schema) else {
+        panic!("expected WhereClause::And");
+    };
+    let typed: Vec<(Vec<String>, String)> = clauses
+        .iter()
+        .filter_map(|c| match c {
+            WhereClause::TypedField { path, sql_type, .. } => {
+                Some((path.clone(), sql_type.clone()))
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(typed.len(), 2, "both Money predicates are typed: {clauses:?}");
+    assert!(typed.contains(&(vec!["unit_price".to_string()], "numeric(12,2)".to_string())));
+    assert!(typed.contains(&(
+        vec!["shipping".to_string(), "cost".to_string()],
+        "numeric(12,2)".to_string()
+    )));
+    assert!(
+        clauses
+            .iter()
+            .any(|c| matches!(c, WhereClause::Field { path, .. } if path == &["reference"])),
+        "plain String field stays untyped: {clauses:?}"
+    );
+}
+
+#[test]
+fn apply_scalar_casts_leaves_unknown_paths_untouched() {
+    let schema = money_schema();
+    let clause = WhereClause::Field {
+        path:     vec!["unknown".to_string()],
+        operator: WhereOperator::Eq,
+        value:    serde_json::json!("x"),
+    };
+    assert_eq!(apply_scalar_casts(clause.clone(), "Order", &schema), clause);
+    assert_eq!(apply_scalar_casts(clause.clone(), "Missing", &schema), clause);
+}
+
+#[test]
+fn apply_geo_backend_binds_geo_predicates_to_schema_backend() {
+    use crate::db::GeoBackend;
+
+    let clause = WhereClause::from_graphql_json(&serde_json::json!({
+        "location": { "near": { "lat": 52.5, "lng": 13.4, "radiusMeters": 500 } },
+        "name": { "eq": "Café" },
+    }))
+    .expect("where-input parses");
+
+    let mut schema = crate::schema::CompiledSchema::new();
+    assert_eq!(apply_geo_backend(clause.clone(), &schema), clause, "PostGIS needs no rewrite");
+
+    schema.geo_backend = GeoBackend::EarthDistance;
+    let WhereClause::And(clauses) = apply_geo_backend(clause, &schema) else {
+        panic!("expected WhereClause::And");
+    };
+    assert!(
+        clauses.iter().any(|c| matches!(
+            c,
+            WhereClause::Geo {
+                operator: WhereOperator::Near,
+                backend: GeoBackend::EarthDistance,
+                ..
+            }
+        )),
+        "near predicate carries the fallback backend: {clauses:?}"
+    );
+    assert!(
+        clauses
+            .iter()
+            .any(|c| matches!(c, WhereClause::Field { path, .. } if path == &["name"])),
+        "non-geo predicate is untouched: {clauses:?}"
+    );
+}
+
+#[test]
+fn resolve_relation_filters_binds_exists_join_from_relationships() {
+    use crate::{
+        db::RelationQuantifier,
+        schema::{Cardinality, QueryDefinition, Relationship, TypeDefinition},
+    };
+
+    let mut schema = money_schema();
+    let mut user = TypeDefinition::new("User", "v_user");
+    user.relationships.push(Relationship {
+        name:           "orders".to_string(),
+        target_type:    "Order".to_string(),
+        cardinality:    Cardinality::OneToMany,
+        foreign_key:    "user_id".to_string(),
+        referenced_key: "id".to_string(),
+    });
+    schema.types.push(user);
+    schema.queries.push(
+        QueryDefinition::new("orders", "Order")
+            .returning_list()
+            .with_sql_source("tv_order"),
+    );
+
+    let clause = WhereClause::from_graphql_json(&serde_json::json!({
+        "orders": { "every": { "unitPrice": { "gt": "10" } } }
+    }))
+    .unwrap();
+    let WhereClause::Relation(relation) =
+        resolve_relation_filters(clause, "User", "v_user", &schema).unwrap()
+    else {
+        panic!("expected WhereClause::Relation");
+    };
+
+    assert_eq!(relation.quantifier, RelationQuantifier::Every);
+    assert_eq!(
+        relation.join,
+        Some(RelationJoin {
+            source:     "tv_order".to_string(),
+            alias:      "_r0".to_string(),
+            key:        "user_id".to_string(),
+            parent:     "v_user".to_string(),
+            parent_key: "id".to_string(),
+        })
+    );
+    assert!(
+        matches!(&relation.filter, WhereClause::TypedField { sql_type, .. } if sql_type == "numeric(12,2)"),
+        "nested filter is cast against the related type: {:?}",
+        relation.filter
+    );
+
+    let not_a_relation =
+        WhereClause::from_graphql_json(&serde_json::json!({ "reference": { "some": {} } }))
+            .unwrap();
+    assert!(matches!(
+        resolve_relation_filters(not_a_relation, "Order", "v_order", &schema),
+        Err(FraiseQLError::Validation { .. })
+    ));
+}
+
+// ── Soft delete ──────────────────────────────────────────────────────────────
+
+fn soft_delete_schema() -> crate::schema::CompiledSchema {
+    use crate::schema::{CompiledSchema, FieldDefinition, TypeDefinition};
+
+    let mut schema = CompiledSchema::new();
+    schema.types.push(
+        TypeDefinition::new("Post", "v_post")
+            .with_field(FieldDefinition::new("title", FieldType::String))
+            .with_field(FieldDefinition::nullable("deletedAt", FieldType::DateTime))
+            .with_soft_delete("deletedAt"),
+    );
+    schema.types.push(
+        TypeDefinition::new("Tag", "v_tag")
+            .with_field(FieldDefinition::new("name", FieldType::String)),
+    );
+    schema
+}
+
+fn reader_context(scopes: &[&str]) -> crate::security::SecurityContext {
+    crate::security::SecurityContext {
+        user_id:          "user-1".into(),
+        roles:            vec![],
+        tenant_id:        None,
+        scopes:           scopes.iter().map(ToString::to_string).collect(),
+        attributes:       HashMap::default(),
+        request_id:       "req-1".to_string(),
+        ip_address:       None,
+        expires_at:       chrono::Utc::now() + chrono::Duration::hours(1),
+        authenticated_at: chrono::Utc::now(),
+        issuer:           None,
+        audience:         None,
+        email:            None,
+        display_name:     None,
+    }
+}
+
+#[test]
+fn soft_delete_filter_hides_deleted_rows_by_default() {
+    let schema = soft_delete_schema();
+    let query = crate::schema::QueryDefinition::new("posts", "Post").returning_list();
+    let filter = soft_delete_filter(&schema, &query, &HashMap::new(), None).unwrap();
+    assert_eq!(
+        filter,
+        Some(WhereClause::Field {
+            path:     vec!["deleted_at".to_string()],
+            operator: WhereOperator::IsNull,
+            value:    serde_json::Value::Bool(true),
+        })
+    );
+}
+
+#[test]
+fn soft_delete_filter_ignores_types_without_soft_delete() {
+    let schema = soft_delete_schema();
+    let query = crate::schema::QueryDefinition::new("tags", "Tag").returning_list();
+    let args = HashMap::from([("includeDeleted".to_string(), serde_json::json!(true))]);
+    assert_eq!(soft_delete_filter(&schema, &query, &args, None).unwrap(), None);
+}
+
+#[test]
+fn soft_delete_filter_requires_scope_for_include_deleted() {
+    let schema = soft_delete_schema();
+    let query = crate::schema::QueryDefinition::new("posts", "Post").returning_list();
+    let args = HashMap::from([("includeDeleted".to_string(), serde_json::json!(true))]);
+
+    let anonymous = soft_delete_filter(&schema, &query, &args, None).unwrap_err();
+    assert!(
+        matches!(anonymous, crate::FraiseQLError::Authorization { .. }),
+        "got {anonymous:?}"
+    );
+
+    let reader = reader_context(&["read:Post"]);
+    let err = soft_delete_filter(&schema, &query, &args, Some(&reader)).unwrap_err();
+    match err {
+        crate::FraiseQLError::Authorization { message, .. } => {
+            assert!(message.contains("read:Post.deleted"), "message was: {message}");
+        },
+        other => panic!("expected Authorization error, got {other:?}"),
+    }
+
+    let auditor = reader_context(&["read:Post.deleted"]);
+    assert_eq!(soft_delete_filter(&schema, &query, &args, Some(&auditor)).unwrap(), None);
+}
+
+#[test]
+fn soft_delete_filter_honours_custom_include_deleted_scope() {
+    let mut schema = soft_delete_schema();
+    schema.types[0].include_deleted_scope = Some("admin:posts".to_string());
+    let query = crate::schema::QueryDefinition::new("posts", "Post").returning_list();
+    let args = HashMap::from([("includeDeleted".to_string(), serde_json::json!(true))]);
+
+    let default_scope = reader_context(&["read:Post.deleted"]);
+    assert!(soft_delete_filter(&schema, &query, &args, Some(&default_scope)).is_err());
+    let admin = reader_context(&["admin:posts"]);
+    assert_eq!(soft_delete_filter(&schema, &query, &args, Some(&admin)).unwrap(), None);
+}
@@ -3,10 +3,20 @@
 //! These pure functions transform GraphQL selection sets into SQL projection
 //! hints and enrich ORDER BY clauses with schema-derived type information.
 
+use std::collections::HashMap;
+
+use super::query_params::relationship_join;
 use crate::{
-    db::{OrderByClause, OrderByFieldType, ProjectionField, projection_generator::FieldKind},
+    db::{
+        OrderByAggregate, OrderByAggregateFunction, OrderByClause, OrderByFieldType,
+        OrderDirection, PostgresProjectionGenerator, ProjectionField, TextSearchConfig,
+        projection_generator::FieldKind, types::JsonbValue,
+    },
+    error::{FraiseQLError, Result},
     graphql::FieldSelection,
-    schema::CompiledSchema,
+    runtime::{QueryMatch, ResultProjector, project_abstract_results, project_nested_lists},
+    schema::{CompiledSchema, QueryDefinition},
+    utils::to_snake_case,
 };
 
 /// Build a recursive [`ProjectionField`] tree from a GraphQL selection set.
@@ -90,6 +100,13 @@ pub fn build_typed_projection_fields(
                 source: sel.name.clone(),
                 kind,
                 sub_fields,
+                // Computed fields are evaluated against the query's own `sql_source` row,
+                // so only the root type's expressions apply.
+                expression: if depth == 0 {
+                    field_def.and_then(|fd| fd.sql_expression.clone())
+                } else {
+                    None
+                },
             }
         })
         .collect()
@@ -146,6 +163,176 @@ pub fn enrich_order_by_clauses(
     clauses
 }
 
+/// Bind orderings by a child relationship aggregate to the related view.
+///
+/// Accepts the nested form (`orders: { max: { total: DESC } }`) and the
+/// `<relationship>Count` shorthand (`ordersCount: DESC`, unless the type has a
+/// field of that name). `parent` is the relation the sorted rows are read from
+/// (the query's `sql_source`).
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Validation`] for an aggregate that is not in the
+/// return type's `order_by_aggregates` allowlist, or whose relationship is not
+/// declared.
+pub fn resolve_order_by_aggregates(
+    mut clauses: Vec<OrderByClause>,
+    schema: &CompiledSchema,
+    return_type: &str,
+    parent: &str,
+) -> Result<Vec<OrderByClause>> {
+    let Some(type_def) = schema.find_type(return_type) else {
+        return Ok(clauses);
+    };
+    for (idx, clause) in clauses.iter_mut().enumerate() {
+        if clause.aggregate.is_none() {
+            let Some(relationship) = count_shorthand(type_def, &clause.field) else {
+                continue;
+            };
+            clause.field = relationship;
+            clause.aggregate = Some(OrderByAggregate {
+                function: OrderByAggregateFunction::Count,
+                field:    None,
+                join:     None,
+            });
+        }
+        let Some(aggregate) = clause.aggregate.as_mut() else {
+            continue;
+        };
+        let relationship = to_snake_case(&clause.field);
+        let agg_field = aggregate.field.as_deref().map(to_snake_case);
+        let allowed = type_def.order_by_aggregates.iter().any(|allowed| {
+            allowed.function == aggregate.function
+                && to_snake_case(&allowed.relationship) == relationship
+                && allowed.field.as_deref().map(to_snake_case) == agg_field
+        });
+        let not_enabled = || FraiseQLError::Validation {
+            message: format!(
+                "ordering `{return_type}` by {} of `{}` is not enabled",
+                aggregate.function.as_sql().to_ascii_lowercase(),
+                clause.field
+            ),
+            path:    Some(format!("orderBy.{}", clause.field)),
+        };
+        if !allowed {
+            return Err(not_enabled());
+        }
+        let (join, target_type) =
+            relationship_join(schema, return_type, &relationship, format!("_oa{idx}"), parent)?
+                .ok_or_else(not_enabled)?;
+        if let Some(field) = &aggregate.field {
+            clause.field_type = schema
+                .find_type(target_type)
+                .and_then(|t| t.find_field(field))
+                .map_or(OrderByFieldType::Text, |f| field_type_to_order_by_type(&f.field_type));
+            // SUM / AVG need a number even over fields the schema types as text.
+            if matches!(
+                aggregate.function,
+                OrderByAggregateFunction::Sum | OrderByAggregateFunction::Avg
+            ) && clause.field_type == OrderByFieldType::Text
+            {
+                clause.field_type = OrderByFieldType::Numeric;
+            }
+        }
+        aggregate.join = Some(join);
+    }
+    Ok(clauses)
+}
+
+/// The relationship named by a `<relationship>Count` orderBy key, when the type
+/// allows ordering by that relationship's count and has no field of that name.
+fn count_shorthand(type_def: &crate::schema::TypeDefinition, field: &str) -> Option<String> {
+    if type_def.find_field(field).is_some() {
+        return None;
+    }
+    let key = to_snake_case(field);
+    let relationship = key.strip_suffix("_count")?;
+    type_def
+        .order_by_aggregates
+        .iter()
+        .find(|a| {
+            a.function == OrderByAggregateFunction::Count
+                && to_snake_case(&a.relationship) == relationship
+        })
+        .map(|a| a.relationship.clone())
+}
+
+/// Ordering for a list query whose client passed no `orderBy`.
+///
+/// Returns the return type's compiled `default_order_by` (which already ends
+/// in the compiler-appended `id` tiebreaker), enriched like a client ordering.
+/// Returns `None` for single-row queries and types without a default.
+pub fn default_order_by_clauses(
+    schema: &CompiledSchema,
+    query_def: &QueryDefinition,
+) -> Option<Vec<OrderByClause>> {
+    if !query_def.returns_list {
+        return None;
+    }
+    let type_def = schema.find_type(&query_def.return_type)?;
+    if type_def.default_order_by.is_empty() {
+        return None;
+    }
+    let clauses = type_def
+        .default_order_by
+        .iter()
+        .map(|o| OrderByClause::new(o.field.clone(), o.direction))
+        .collect();
+    Some(enrich_order_by_clauses(
+        clauses,
+        schema,
+        &query_def.return_type,
+        &query_def.native_columns,
+    ))
+}
+
+/// Ordering for a list query whose client passed no `orderBy`, given the
+/// query's search rank expression (see [`text_search_request`]).
+///
+/// A searched query orders by relevance first, keeping the type's
+/// [`default_order_by_clauses`] as tiebreakers; otherwise the default applies.
+pub fn fallback_order_by_clauses(
+    schema: &CompiledSchema,
+    query_def: &QueryDefinition,
+    search_rank: Option<&str>,
+) -> Option<Vec<OrderByClause>> {
+    let default = default_order_by_clauses(schema, query_def);
+    let Some(rank) = search_rank else {
+        return default;
+    };
+    let mut clauses = vec![OrderByClause::expression(
+        "rank".to_string(),
+        rank.to_string(),
+        OrderDirection::Desc,
+    )];
+    clauses.extend(default.unwrap_or_default());
+    Some(clauses)
+}
+
+/// Resolve the full-text search a list query was asked for.
+///
+/// Returns the return type's search config and the query text when the query
+/// has `search` enabled and the client passed a non-blank `search` string.
+pub fn text_search_request<'a>(
+    schema: &'a CompiledSchema,
+    query_def: &QueryDefinition,
+    arguments: &'a HashMap<String, serde_json::Value>,
+) -> Option<(&'a TextSearchConfig, &'a str)> {
+    if !query_def.auto_params.has_search {
+        return None;
+    }
+    let query = arguments.get("search")?.as_str().filter(|q| !q.trim().is_empty())?;
+    let config = schema.find_type(&query_def.return_type)?.search.as_ref()?;
+    Some((config, query))
+}
+
+/// Compute the selected root `rank` field from the search rank expression.
+pub fn apply_search_rank(fields: &mut [ProjectionField], rank_sql: &str) {
+    for field in fields.iter_mut().filter(|f| f.source == "rank" && f.expression.is_none()) {
+        field.expression = Some(rank_sql.to_string());
+    }
+}
+
 /// Return `true` if `field_name` appears in `selections`, including inside inline
 /// fragment entries (`FieldSelection` whose name starts with `"..."`).
 ///
@@ -164,6 +351,120 @@ pub fn selections_contain_field(selections: &[FieldSelection], field_name: &str)
     false
 }
 
+/// Resolve the discriminator key of a query returning an interface or union.
+///
+/// Returns `Ok(None)` for object return types and for abstract types declared
+/// without a discriminator. A discriminated result is projected per row at its
+/// concrete type, outside the per-type field RBAC and field-authorizer passes,
+/// so a possible type declaring a scoped or policy-gated field fails closed.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Authorization`] if any possible type of
+/// `return_type` has a field with `requires_scope` or `authorize`.
+pub fn abstract_return_discriminator<'a>(
+    schema: &'a CompiledSchema,
+    return_type: &str,
+) -> Result<Option<&'a str>> {
+    let Some(discriminator) = schema.abstract_discriminator(return_type) else {
+        return Ok(None);
+    };
+    let restricted = schema
+        .possible_types(return_type)
+        .into_iter()
+        .find(|t| t.fields.iter().any(|f| f.authorize || f.requires_scope.is_some()));
+    if let Some(type_def) = restricted {
+        return Err(FraiseQLError::Authorization {
+            message:  format!(
+                "Field-level authorization is not enforced on abstract type '{return_type}', \
+                 but its possible type '{}' declares a scoped or policy-gated field",
+                type_def.name
+            ),
+            action:   Some("read".to_string()),
+            resource: Some(return_type.to_string()),
+        });
+    }
+    Ok(Some(discriminator))
+}
+
+/// Build the full-row projection for an object return type with computed fields.
+///
+/// Paths that skip the typed projection still read the whole JSONB row; this
+/// overlays each computed field's `sql_expression` onto it so those fields
+/// resolve like stored ones. Returns `Ok(None)` when `return_type` is not an
+/// object type or declares no computed fields. A searched query's
+/// `search_rank` is overlaid as `rank`.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Validation`] if a computed field's expression is
+/// not a single safe SQL expression.
+pub fn computed_fields_row_projection(
+    schema: &CompiledSchema,
+    return_type: &str,
+    search_rank: Option<&str>,
+) -> Result<Option<String>> {
+    let Some(type_def) = schema.find_type(return_type) else {
+        return Ok(None);
+    };
+    let mut computed: Vec<(&str, &str)> = type_def
+        .fields
+        .iter()
+        .filter_map(|f| f.sql_expression.as_deref().map(|expr| (f.name.as_str(), expr)))
+        .collect();
+    if let Some(rank) = search_rank {
+        computed.push(("rank", rank));
+    }
+    if computed.is_empty() {
+        return Ok(None);
+    }
+    PostgresProjectionGenerator::new()
+        .generate_computed_overlay_sql(&computed)
+        .map(Some)
+}
+
+/// Project a regular query's rows into its GraphQL result value.
+///
+/// Object return types go through [`ResultProjector`] over the (possibly
+/// SQL-projected) rows, then [`project_nested_lists`] (#489). When
+/// `discriminator` is set (see [`abstract_return_discriminator`]) the rows are
+/// full JSONB blobs and each is projected at the concrete type it names.
+///
+/// # Errors
+///
+/// Returns an error if projection fails or a row's discriminator does not name
+/// a possible type of the return type.
+pub fn project_query_results(
+    results: &[JsonbValue],
+    projection_fields: Vec<String>,
+    query_match: &QueryMatch,
+    discriminator: Option<&str>,
+    schema: &CompiledSchema,
+) -> Result<serde_json::Value> {
+    let query_def = &query_match.query_def;
+    let root_fields =
+        query_match.selections.first().map_or(&[][..], |r| r.nested_fields.as_slice());
+    if let Some(key) = discriminator {
+        return project_abstract_results(
+            results,
+            &query_def.return_type,
+            key,
+            root_fields,
+            query_def.returns_list,
+            schema,
+        );
+    }
+
+    let projector = ResultProjector::new(projection_fields)
+        .configure_typename_from_selections(&query_match.selections, &query_def.return_type);
+    let mut projected = projector.project_results(results, query_def.returns_list)?;
+    // #489: recase + project nested list-of-object fields the SQL projection left as
+    // the raw stored sub-blob (snake_case keys, unselected keys). The SQL side already
+    // projected top-level fields and nested single objects.
+    project_nested_lists(&mut projected, &query_def.return_type, root_fields, schema);
+    Ok(projected)
+}
+
 #[cfg(test)]
 #[path = "query_projection_tests.rs"]
 mod query_projection_tests;
@@ -9,11 +9,16 @@
 
 #![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
 
-use super::build_typed_projection_fields;
+use super::{
+    abstract_return_discriminator, apply_search_rank, build_typed_projection_fields,
+    computed_fields_row_projection, default_order_by_clauses, fallback_order_by_clauses,
+    resolve_order_by_aggregates, text_search_request,
+};
 use crate::{
     db::projection_generator::PostgresProjectionGenerator,
+    error::FraiseQLError,
     graphql::FieldSelection,
-    schema::{CompiledSchema, FieldDefinition, FieldType, TypeDefinition},
+    schema::{CompiledSchema, FieldDefinition, FieldType, TypeDefinition, UnionDefinition},
 };
 
 /// Build a bare field selection (no arguments / directives).
@@ -113,3 +118,286 @@ fn non_aliased_field_projects_its_own_column() {
     assert!(sql.contains("'fullName',"), "output key is the field name: {sql}");
     assert!(sql.contains("->>'full_name'"), "reads its own snake_case column: {sql}");
 }
+
+/// A discriminated union over `Dog`/`Cat`; `restrict_cat` gives `Cat` a scoped field.
+fn pet_schema(restrict_cat: bool) -> CompiledSchema {
+    let mut lives = FieldDefinition::new("livesLeft", FieldType::Int);
+    if restrict_cat {
+        lives.requires_scope = Some("read:cat_lives".to_string());
+    }
+    let mut schema = CompiledSchema::new();
+    schema.types.push(
+        TypeDefinition::new("Dog", "v_pet")
+            .with_field(FieldDefinition::new("name", FieldType::String)),
+    );
+    schema.types.push(TypeDefinition::new("Cat", "v_pet").with_field(lives));
+    schema.unions.push(
+        UnionDefinition::new("Pet")
+            .with_members(vec!["Dog".to_string(), "Cat".to_string()])
+            .with_discriminator("kind"),
+    );
+    schema
+}
+
+#[test]
+fn abstract_return_discriminator_resolves_only_discriminated_types() {
+    let schema = pet_schema(false);
+
+    assert_eq!(abstract_return_discriminator(&schema, "Pet").unwrap(), Some("kind"));
+    assert_eq!(abstract_return_discriminator(&schema, "Dog").unwrap(), None);
+}
+
+/// Per-variant projection bypasses the per-type field RBAC pass, so a scoped
+/// field on any possible type must fail closed rather than be served.
+#[test]
+fn abstract_return_discriminator_fails_closed_on_scoped_member_field() {
+    let err = abstract_return_discriminator(&pet_schema(true), "Pet").unwrap_err();
+
+    assert!(matches!(err, FraiseQLError::Authorization { .. }), "got {err:?}");
+    assert!(err.to_string().contains("Cat"), "names the restricted type: {err}");
+}
+
+/// A `User` with a computed `fullName` built from two stored keys.
+fn computed_user_schema() -> CompiledSchema {
+    let mut schema = CompiledSchema::new();
+    schema.types.push(
+        TypeDefinition::new("User", "v_user")
+            .with_field(FieldDefinition::new("id", FieldType::Id))
+            .with_field(
+                FieldDefinition::new("fullName", FieldType::String)
+                    .with_sql_expression("data->>'first' || ' ' || data->>'last'"),
+            ),
+    );
+    schema
+}
+
+#[test]
+fn computed_field_projects_its_expression_under_the_response_key() {
+    let schema = computed_user_schema();
+
+    let sql = projection_sql(&[field("fullName", Some("name"), vec![])], &schema, "User");
+
+    assert_eq!(sql, "jsonb_build_object('name', (data->>'first' || ' ' || data->>'last'))");
+}
+
+#[test]
+fn computed_fields_row_projection_overlays_only_computed_fields() {
+    let schema = computed_user_schema();
+
+    let sql = computed_fields_row_projection(&schema, "User", None).unwrap();
+
+    assert_eq!(
+        sql.as_deref(),
+        Some(
+            "\"data\" || jsonb_build_object('full_name', (data->>'first' || ' ' || data->>'last'))"
+        )
+    );
+    assert_eq!(computed_fields_row_projection(&pet_schema(false), "Dog", None).unwrap(), None);
+    assert_eq!(computed_fields_row_projection(&schema, "Missing", None).unwrap(), None);
+}
+
+#[test]
+fn default_order_by_applies_to_list_queries_only() {
+    use crate::{
+        db::{OrderByFieldType, OrderDirection},
+        schema::{DefaultOrderBy, QueryDefinition},
+    };
+
+    let mut schema = CompiledSchema::new();
+    schema.types.push(
+        TypeDefinition::new("Post", "v_post")
+            .with_field(FieldDefinition::new("id", FieldType::Id))
+            .with_field(FieldDefinition::new("createdAt", FieldType::DateTime))
+            .with_default_order_by(vec![
+                DefaultOrderBy::new("createdAt", OrderDirection::Desc),
+                DefaultOrderBy::new("id", OrderDirection::Asc),
+            ]),
+    );
+    let mut posts = QueryDefinition::new("posts", "Post");
+    posts.returns_list = true;
+
+    let clauses = default_order_by_clauses(&schema, &posts).unwrap();
+
+    assert_eq!(clauses.len(), 2);
+    assert_eq!(clauses[0].field, "createdAt");
+    assert_eq!(clauses[0].direction, OrderDirection::Desc);
+    assert_eq!(clauses[0].field_type, OrderByFieldType::DateTime);
+    assert_eq!(clauses[1].field, "id");
+
+    let post = QueryDefinition::new("post", "Post");
+    assert!(default_order_by_clauses(&schema, &post).is_none(), "single-row query");
+}
+
+fn searchable_post_schema() -> CompiledSchema {
+    use crate::db::{SearchField, SearchWeight, TextSearchConfig};
+
+    let mut schema = CompiledSchema::new();
+    schema.types.push(
+        TypeDefinition::new("Post", "v_post")
+            .with_field(FieldDefinition::new("id", FieldType::Id))
+            .with_field(FieldDefinition::new("title", FieldType::String))
+            .with_field(FieldDefinition::nullable("rank", FieldType::Float))
+            .with_search(TextSearchConfig {
+                language: "english".to_string(),
+                fields:   vec![SearchField {
+                    field:  "title".to_string(),
+                    weight: Some(SearchWeight::A),
+                }],
+            }),
+    );
+    schema
+}
+
+#[test]
+fn search_argument_resolves_only_when_enabled_and_non_blank() {
+    use std::collections::HashMap;
+
+    use crate::schema::{AutoParams, QueryDefinition};
+
+    let schema = searchable_post_schema();
+    let mut posts = QueryDefinition::new("posts", "Post").returning_list();
+    let args = HashMap::from([("search".to_string(), serde_json::json!("rust"))]);
+    assert!(text_search_request(&schema, &posts, &args).is_none(), "has_search is off");
+
+    posts.auto_params = AutoParams {
+        has_search: true,
+        ..AutoParams::default()
+    };
+    let (config, query) = text_search_request(&schema, &posts, &args).unwrap();
+    assert_eq!(config.language, "english");
+    assert_eq!(query, "rust");
+
+    let blank = HashMap::from([("search".to_string(), serde_json::json!("  "))]);
+    assert!(
+        text_search_request(&schema, &posts, &blank).is_none(),
+        "blank search is ignored"
+    );
+}
+
+#[test]
+fn searched_query_orders_by_rank_then_default_and_projects_rank() {
+    use crate::{
+        db::OrderDirection,
+        schema::{DefaultOrderBy, QueryDefinition},
+    };
+
+    let mut schema = searchable_post_schema();
+    schema.types[0].default_order_by = vec![DefaultOrderBy::new("id", OrderDirection::Asc)];
+    let posts = QueryDefinition::new("posts", "Post").returning_list();
+    let rank = schema.types[0].search.as_ref().unwrap().rank_sql("rust").unwrap();
+
+    let clauses = fallback_order_by_clauses(&schema, &posts, Some(&rank)).unwrap();
+    assert_eq!(clauses.len(), 2);
+    assert_eq!(clauses[0].expression.as_deref(), Some(rank.as_str()));
+    assert_eq!(clauses[0].direction, OrderDirection::Desc);
+    assert_eq!(clauses[1].field, "id");
+    assert_eq!(fallback_order_by_clauses(&schema, &posts, None).unwrap().len(), 1);
+
+    let mut typed = build_typed_projection_fields(
+        &[
+            field("title", None, vec![]),
+            field("rank", Some("score"), vec![]),
+        ],
+        &schema,
+        "Post",
+        0,
+    );
+    apply_search_rank(&mut typed, &rank);
+    let sql = PostgresProjectionGenerator::new()
+        .generate_typed_projection_sql(&typed)
+        .unwrap();
+    assert!(sql.contains(&format!("'score', ({rank})")), "{sql}");
+
+    let overlay = computed_fields_row_projection(&schema, "Post", Some(&rank)).unwrap().unwrap();
+    assert!(overlay.contains(&format!("'rank', ({rank})")), "{overlay}");
+}
+
+fn user_orders_schema() -> CompiledSchema {
+    use crate::{
+        db::OrderByAggregateFunction,
+        schema::{AggregateOrderBy, Cardinality, Relationship},
+    };
+
+    let mut schema = CompiledSchema::new();
+    let mut user =
+        TypeDefinition::new("User", "v_user").with_field(FieldDefinition::new("id", FieldType::Id));
+    user.relationships.push(Relationship {
+        name:           "orders".to_string(),
+        target_type:    "Order".to_string(),
+        cardinality:    Cardinality::OneToMany,
+        foreign_key:    "userId".to_string(),
+        referenced_key: "id".to_string(),
+    });
+    user.order_by_aggregates = vec![
+        AggregateOrderBy::new("orders", OrderByAggregateFunction::Count, None),
+        AggregateOrderBy::new("orders", OrderByAggregateFunction::Max, Some("total".to_string())),
+    ];
+    schema.types.push(user);
+    schema.types.push(
+        TypeDefinition::new("Order", "v_order")
+            .with_field(FieldDefinition::new("total", FieldType::Decimal))
+            .with_field(FieldDefinition::new("note", FieldType::String)),
+    );
+    schema
+}
+
+#[test]
+fn order_by_aggregates_bind_allowlisted_relationship_aggregates() {
+    use crate::db::{OrderByAggregateFunction, OrderByClause, OrderByFieldType, RelationJoin};
+
+    let schema = user_orders_schema();
+    let clauses = OrderByClause::from_graphql_json(&serde_json::json!([
+        { "field": "ordersCount", "direction": "DESC" },
+    ]))
+    .unwrap();
+    let mut clauses = resolve_order_by_aggregates(clauses, &schema, "User", "v_user").unwrap();
+    clauses.extend(
+        resolve_order_by_aggregates(
+            OrderByClause::from_graphql_json(&serde_json::json!({
+                "orders": { "max": { "total": "ASC" } }
+            }))
+            .unwrap(),
+            &schema,
+            "User",
+            "v_user",
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(clauses[0].field, "orders");
+    let count = clauses[0].aggregate.as_ref().unwrap();
+    assert_eq!(count.function, OrderByAggregateFunction::Count);
+    assert_eq!(
+        count.join,
+        Some(RelationJoin {
+            source:     "v_order".to_string(),
+            alias:      "_oa0".to_string(),
+            key:        "userId".to_string(),
+            parent:     "v_user".to_string(),
+            parent_key: "id".to_string(),
+        })
+    );
+    assert_eq!(clauses[1].field_type, OrderByFieldType::Numeric);
+    assert!(clauses[1].aggregate.as_ref().unwrap().join.is_some());
+}
+
+#[test]
+fn order_by_aggregates_reject_aggregates_outside_the_allowlist() {
+    use crate::db::OrderByClause;
+
+    let schema = user_orders_schema();
+    let max_note = OrderByClause::from_graphql_json(&serde_json::json!({
+        "orders": { "max": { "note": "DESC" } }
+    }))
+    .unwrap();
+    assert!(matches!(
+        resolve_order_by_aggregates(max_note, &schema, "User", "v_user"),
+        Err(FraiseQLError::Validation { .. })
+    ));
+
+    // Without an allowlisted count, `ordersCount` stays a plain field.
+    let plain =
+        OrderByClause::from_graphql_json(&serde_json::json!({ "ordersCount": "DESC" })).unwrap();
+    let plain = resolve_order_by_aggregates(plain, &schema, "Order", "v_order").unwrap();
+    assert!(plain[0].aggregate.is_none());
+}
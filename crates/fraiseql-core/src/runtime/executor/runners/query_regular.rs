@@ -8,10 +8,16 @@ use super::{
     super::{null_masked_fields, resolve_inject_value},
     query::QueryRunner,
     query_params::{
-        combine_explicit_arg_where, compute_projection_reduction, enforce_max_page_size,
-        inject_param_where_clause,
+        apply_geo_backend, apply_scalar_casts, combine_explicit_arg_where,
+        compute_projection_reduction, enforce_max_page_size, inject_param_where_clause,
+        prepend_where, resolve_relation_filters, search_where, soft_delete_filter,
+        tenant_row_filter,
+    },
+    query_projection::{
+        abstract_return_discriminator, apply_search_rank, build_typed_projection_fields,
+        computed_fields_row_projection, enrich_order_by_clauses, fallback_order_by_clauses,
+        project_query_results, resolve_order_by_aggregates, text_search_request,
     },
-    query_projection::{build_typed_projection_fields, enrich_order_by_clauses},
 };
 use crate::{
     db::{WhereClause, projection_generator::PostgresProjectionGenerator, traits::DatabaseAdapter},
@@ -33,9 +39,12 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
     /// transaction-locally on the same connection as the read (fixes #329 for
     /// RLS policies backed by `current_setting()`).
     ///
-    /// Returns an empty vec when there is no security context or no session
-    /// variables are configured; the adapter treats an empty slice as "no
-    /// session variables" with zero overhead.
+    /// Inside a request time budget (see [`crate::runtime::budget`]) a
+    /// `statement_timeout` for the remaining budget is appended on `PostgreSQL`.
+    ///
+    /// Otherwise returns an empty vec when there is no security context or no
+    /// session variables are configured; the adapter treats an empty slice as
+    /// "no session variables" with zero overhead.
     ///
     /// `pub(super)` so the sibling `query_relay` impl of the same `QueryRunner` can
     /// reuse it for the node-lookup path (#610).
@@ -44,12 +53,14 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         security_context: Option<&SecurityContext>,
     ) -> Result<Vec<(String, String)>> {
         let sv = &self.ctx.schema.session_variables;
-        match security_context {
+        let mut vars = match security_context {
             Some(sec) if !sv.variables.is_empty() || sv.inject_started_at => {
-                crate::runtime::executor::security::resolve_session_variables(sv, sec)
+                crate::runtime::executor::security::resolve_session_variables(sv, sec)?
             },
-            _ => Ok(Vec::new()),
-        }
+            _ => Vec::new(),
+        };
+        crate::runtime::budget::push_statement_timeout(&mut vars, self.ctx.adapter.database_type());
+        Ok(vars)
     }
 
     /// Execute a regular query with row-level security (RLS) filtering.
@@ -97,6 +108,10 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             }
         }
 
+        // 2c. Row tenancy: a tenant-scoped type is only readable with a tenant claim.
+        let tenant_where =
+            tenant_row_filter(&self.ctx.schema, &query_match.query_def, Some(security_context))?;
+
         // Resolve session variables once. They are applied transaction-locally
         // on the same connection as the read (fixes #329) by passing them into
         // the connection-affine adapter call below, so PostgreSQL RLS policies
@@ -129,6 +144,12 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             root_fields,
         );
 
+        // 0b. A discriminated interface/union return type reads full rows and projects
+        //     each at the concrete type its discriminator names (fails closed on scoped or
+        //     policy-gated possible types).
+        let discriminator =
+            abstract_return_discriminator(&self.ctx.schema, &query_match.query_def.return_type)?;
+
         // 0. Check response cache (skips all projection/RBAC/serialization work on hit)
         let response_cache_key = if !gated_present
             && self.ctx.response_cache.as_ref().is_some_and(|rc| rc.is_enabled())
@@ -208,30 +229,23 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                     path:    None,
                 })?;
 
+        // Full-text `search`: matched in WHERE, ranked in ORDER BY and the `rank` field.
+        let search =
+            text_search_request(&self.ctx.schema, &query_match.query_def, &query_match.arguments);
+        let search_rank = search.map(|(config, query)| config.rank_sql(query)).transpose()?;
+
         // 6. Generate SQL projection hint for requested fields (optimization). Build a recursive
         //    ProjectionField tree from the selection set so that composite sub-fields are projected
         //    with nested jsonb_build_object instead of returning the full blob. When a policy-gated
         //    field is selected (#423), the hint is skipped so the adapter returns the full row,
-        //    giving the field authorizer the complete `parent` to decide on.
+        //    giving the field authorizer the complete `parent` to decide on. A discriminated
+        //    abstract return type likewise needs the full row to project each variant.
         let projection_hint = if !gated_present
+            && discriminator.is_none()
             && !plan.projection_fields.is_empty()
             && plan.jsonb_strategy == JsonbStrategy::Project
         {
-            let root_fields = query_match
-                .selections
-                .first()
-                .map_or(&[] as &[_], |s| s.nested_fields.as_slice());
-            let typed_fields = build_typed_projection_fields(
-                root_fields,
-                &self.ctx.schema,
-                &query_match.query_def.return_type,
-                0,
-            );
-
-            let generator = PostgresProjectionGenerator::new();
-            let projection_sql = generator
-                .generate_typed_projection_sql(&typed_fields)
-                .unwrap_or_else(|_| "data".to_string());
+            let projection_sql = self.typed_projection_sql(&query_match, search_rank.as_deref());
 
             Some(SqlProjectionHint::new(
                 self.ctx.adapter.database_type(),
@@ -239,8 +253,13 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 compute_projection_reduction(plan.projection_fields.len()),
             ))
         } else {
-            // Stream strategy: return full JSONB, no projection hint
-            None
+            // Stream strategy: return full JSONB, with computed fields overlaid if any.
+            computed_fields_row_projection(
+                &self.ctx.schema,
+                &query_match.query_def.return_type,
+                search_rank.as_deref(),
+            )?
+            .map(|sql| SqlProjectionHint::new(self.ctx.adapter.database_type(), sql, 0))
         };
 
         // 7. AND inject conditions onto the RLS WHERE clause. Inject conditions always come after
@@ -269,6 +288,16 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 _ => Some(WhereClause::And(conditions)),
             }
         };
+        let combined_where = prepend_where(tenant_where, combined_where);
+        let combined_where = prepend_where(
+            soft_delete_filter(
+                &self.ctx.schema,
+                &query_match.query_def,
+                &query_match.arguments,
+                Some(security_context),
+            )?,
+            combined_where,
+        );
 
         // 5b. Compose user-supplied WHERE from GraphQL arguments when has_where is enabled.
         //     Security conditions (RLS + inject) are always first so they cannot be bypassed.
@@ -277,6 +306,22 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 .arguments
                 .get("where")
                 .map(WhereClause::from_graphql_json)
+                .transpose()?
+                .map(|w| {
+                    apply_scalar_casts(
+                        apply_geo_backend(w, &self.ctx.schema),
+                        &query_match.query_def.return_type,
+                        &self.ctx.schema,
+                    )
+                })
+                .map(|w| {
+                    resolve_relation_filters(
+                        w,
+                        &query_match.query_def.return_type,
+                        sql_source,
+                        &self.ctx.schema,
+                    )
+                })
                 .transpose()?;
             match (combined_where, user_where) {
                 (None, None) => None,
@@ -297,6 +342,7 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             &query_match.arguments,
             &query_match.query_def.native_columns,
         );
+        let combined_where = prepend_where(combined_where, search_where(search));
 
         // 8. Extract limit/offset from query arguments when auto_params are enabled
         // The top-level page size is capped (#421: unbounded-pagination DoS guard).
@@ -341,10 +387,28 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                         &query_match.query_def.native_columns,
                     )
                 })
+                .map(|clauses| {
+                    resolve_order_by_aggregates(
+                        clauses,
+                        &self.ctx.schema,
+                        &query_match.query_def.return_type,
+                        sql_source,
+                    )
+                })
+                .transpose()?
         } else {
             None
         };
 
+        // No client `orderBy`: order by search relevance, then the return type's default.
+        let order_by_clauses = order_by_clauses.or_else(|| {
+            fallback_order_by_clauses(
+                &self.ctx.schema,
+                &query_match.query_def,
+                search_rank.as_deref(),
+            )
+        });
+
         // 9. Execute query with combined WHERE clause filter, pinning session variables to the
         //    read's connection (fixes #329 for RLS).
         let results = self
@@ -374,23 +438,13 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         // 11. Project results — include both allowed and masked fields in projection
         let mut all_projection_fields = access.allowed;
         all_projection_fields.extend(access.masked.iter().cloned());
-        let projector = ResultProjector::new(all_projection_fields)
-            .configure_typename_from_selections(
-                &query_match.selections,
-                &query_match.query_def.return_type,
-            );
-        let mut projected =
-            projector.project_results(&results, query_match.query_def.returns_list)?;
-
-        // 11a. #489: recase + project nested list-of-object fields the SQL projection
-        //      left as the raw stored sub-blob (snake_case keys, unselected keys). The
-        //      SQL side already projected top-level fields and nested single objects.
-        crate::runtime::project_nested_lists(
-            &mut projected,
-            &query_match.query_def.return_type,
-            query_match.selections.first().map_or(&[][..], |r| r.nested_fields.as_slice()),
+        let mut projected = project_query_results(
+            &results,
+            all_projection_fields,
+            &query_match,
+            discriminator,
             &self.ctx.schema,
-        );
+        )?;
 
         // 11. Null out masked fields in the projected result
         if !access.masked.is_empty() {
@@ -463,9 +517,13 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         if let (Some((query_key, sec_hash)), Some(rc)) =
             (response_cache_key, self.ctx.response_cache.as_ref())
         {
-            let sql_source = query_match.query_def.sql_source.as_deref().unwrap_or("");
+            let views = crate::cache::accessed_views(
+                sql_source,
+                combined_where.as_ref(),
+                order_by_clauses.as_deref(),
+            );
             let cached = Arc::new(response);
-            let _ = rc.put(query_key, sec_hash, Arc::clone(&cached), vec![sql_source.to_string()]);
+            let _ = rc.put(query_key, sec_hash, Arc::clone(&cached), views);
             return Ok(Arc::unwrap_or_clone(cached));
         }
 
@@ -542,6 +600,41 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         }
     }
 
+    /// Typed projection SQL for the root selection of `query_match`: composite
+    /// sub-fields are projected with nested `jsonb_build_object`, and the full-text
+    /// `rank` field is filled from `search_rank` when searching.
+    ///
+    /// Without a search rank the SQL depends only on the selection set, so a match
+    /// bound from the plan cache builds it once per cached plan.
+    fn typed_projection_sql(
+        &self,
+        query_match: &crate::runtime::matcher::QueryMatch,
+        search_rank: Option<&str>,
+    ) -> String {
+        let build = || {
+            let root_fields = query_match
+                .selections
+                .first()
+                .map_or(&[] as &[_], |s| s.nested_fields.as_slice());
+            let mut typed_fields = build_typed_projection_fields(
+                root_fields,
+                &self.ctx.schema,
+                &query_match.query_def.return_type,
+                0,
+            );
+            if let Some(rank) = search_rank {
+                apply_search_rank(&mut typed_fields, rank);
+            }
+            PostgresProjectionGenerator::new()
+                .generate_typed_projection_sql(&typed_fields)
+                .unwrap_or_else(|_| "data".to_string())
+        };
+        match (&query_match.prepared, search_rank) {
+            (Some(prepared), None) => prepared.projection_sql.get_or_init(build).clone(),
+            _ => build(),
+        }
+    }
+
     /// Execute a regular (non-aggregate, non-relay) GraphQL query.
     ///
     /// # Errors
@@ -576,6 +669,9 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             });
         }
 
+        // Guard: tenant-scoped types are never readable without a tenant (row tenancy).
+        tenant_row_filter(&self.ctx.schema, &query_match.query_def, None)?;
+
         // Route relay queries to dedicated handler.
         // No session vars: unauthenticated entrypoint (no SecurityContext). See #329.
         if query_match.query_def.relay {
@@ -592,6 +688,8 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             root_fields,
             "unauthenticated query",
         )?;
+        let discriminator =
+            abstract_return_discriminator(&self.ctx.schema, &query_match.query_def.return_type)?;
 
         // 2. Create execution plan
         let plan = self.ctx.planner.plan(&query_match)?;
@@ -604,26 +702,20 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             }
         })?;
 
+        // Full-text `search`: matched in WHERE, ranked in ORDER BY and the `rank` field.
+        let search =
+            text_search_request(&self.ctx.schema, &query_match.query_def, &query_match.arguments);
+        let search_rank = search.map(|(config, query)| config.rank_sql(query)).transpose()?;
+
         // 3a. Generate SQL projection hint for requested fields (optimization).
         //     Recursive typed projection: composite sub-fields are projected with nested
-        //     jsonb_build_object instead of returning the full blob.
-        let projection_hint = if !plan.projection_fields.is_empty()
+        //     jsonb_build_object instead of returning the full blob. Skipped for a
+        //     discriminated abstract return type, which projects each full row per variant.
+        let projection_hint = if discriminator.is_none()
+            && !plan.projection_fields.is_empty()
             && plan.jsonb_strategy == JsonbStrategy::Project
         {
-            let root_fields = query_match
-                .selections
-                .first()
-                .map_or(&[] as &[_], |s| s.nested_fields.as_slice());
-            let typed_fields = build_typed_projection_fields(
-                root_fields,
-                &self.ctx.schema,
-                &query_match.query_def.return_type,
-                0,
-            );
-            let generator = PostgresProjectionGenerator::new();
-            let projection_sql = generator
-                .generate_typed_projection_sql(&typed_fields)
-                .unwrap_or_else(|_| "data".to_string());
+            let projection_sql = self.typed_projection_sql(&query_match, search_rank.as_deref());
 
             Some(SqlProjectionHint::new(
                 self.ctx.adapter.database_type(),
@@ -631,8 +723,13 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 compute_projection_reduction(plan.projection_fields.len()),
             ))
         } else {
-            // Stream strategy: return full JSONB, no projection hint
-            None
+            // Stream strategy: return full JSONB, with computed fields overlaid if any.
+            computed_fields_row_projection(
+                &self.ctx.schema,
+                &query_match.query_def.return_type,
+                search_rank.as_deref(),
+            )?
+            .map(|sql| SqlProjectionHint::new(self.ctx.adapter.database_type(), sql, 0))
         };
 
         // 3b. Extract auto_params (limit, offset, where, order_by) from arguments
@@ -642,6 +739,22 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 .get("where")
                 .map(WhereClause::from_graphql_json)
                 .transpose()?
+                .map(|w| {
+                    apply_scalar_casts(
+                        apply_geo_backend(w, &self.ctx.schema),
+                        &query_match.query_def.return_type,
+                        &self.ctx.schema,
+                    )
+                })
+                .map(|w| {
+                    resolve_relation_filters(
+                        w,
+                        &query_match.query_def.return_type,
+                        sql_source,
+                        &self.ctx.schema,
+                    )
+                })
+                .transpose()?
         } else {
             None
         };
@@ -653,6 +766,16 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             &query_match.arguments,
             &query_match.query_def.native_columns,
         );
+        let user_where = prepend_where(user_where, search_where(search));
+        let user_where = prepend_where(
+            soft_delete_filter(
+                &self.ctx.schema,
+                &query_match.query_def,
+                &query_match.arguments,
+                None,
+            )?,
+            user_where,
+        );
 
         // The top-level page size is capped (#421: unbounded-pagination DoS guard).
         let limit = enforce_max_page_size(
@@ -693,41 +816,58 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                         &query_match.query_def.native_columns,
                     )
                 })
+                .map(|clauses| {
+                    resolve_order_by_aggregates(
+                        clauses,
+                        &self.ctx.schema,
+                        &query_match.query_def.return_type,
+                        sql_source,
+                    )
+                })
+                .transpose()?
         } else {
             None
         };
 
-        // No session vars: this is the unauthenticated entrypoint (no
-        // SecurityContext), so there is nothing to resolve session variables
-        // from. See #329 / resolve_session_vars.
+        // No client `orderBy`: order by search relevance, then the return type's default.
+        let order_by_clauses = order_by_clauses.or_else(|| {
+            fallback_order_by_clauses(
+                &self.ctx.schema,
+                &query_match.query_def,
+                search_rank.as_deref(),
+            )
+        });
+
+        // This is the unauthenticated entrypoint (no SecurityContext), so no
+        // configured session variables apply (#329); only the request budget's
+        // `statement_timeout` can. An empty slice takes the plain read path.
+        let resolved_session_vars = self.resolve_session_vars(None)?;
+        let session_pairs: Vec<(&str, &str)> =
+            resolved_session_vars.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
         let results = self
             .ctx
             .adapter
-            .execute_with_projection_arc(&crate::db::ProjectionRequest {
-                view: sql_source,
-                projection: projection_hint.as_ref(),
-                where_clause: user_where.as_ref(),
-                order_by: order_by_clauses.as_deref(),
-                limit,
-                offset,
-            })
+            .execute_with_projection_arc_with_session(
+                &crate::db::ProjectionRequest {
+                    view: sql_source,
+                    projection: projection_hint.as_ref(),
+                    where_clause: user_where.as_ref(),
+                    order_by: order_by_clauses.as_deref(),
+                    limit,
+                    offset,
+                },
+                &session_pairs,
+            )
             .await?;
 
         // 4. Project results
-        let projector = ResultProjector::new(plan.projection_fields)
-            .configure_typename_from_selections(
-                &query_match.selections,
-                &query_match.query_def.return_type,
-            );
-        let mut projected =
-            projector.project_results(&results, query_match.query_def.returns_list)?;
-        // #489: recase + project nested list-of-object fields left raw by SQL projection.
-        crate::runtime::project_nested_lists(
-            &mut projected,
-            &query_match.query_def.return_type,
-            query_match.selections.first().map_or(&[][..], |r| r.nested_fields.as_slice()),
+        let projected = project_query_results(
+            &results,
+            plan.projection_fields,
+            &query_match,
+            discriminator,
             &self.ctx.schema,
-        );
+        )?;
 
         // 5. Wrap in GraphQL data envelope
         let response =
@@ -771,6 +911,8 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             root_fields,
             "REST",
         )?;
+        let discriminator =
+            abstract_return_discriminator(&self.ctx.schema, &query_match.query_def.return_type)?;
 
         // Evaluate RLS policy if present.
         let rls_where_clause: Option<RlsWhereClause> = if let (Some(ref rls_policy), Some(ctx)) =
@@ -795,6 +937,11 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         // Build execution plan.
         let plan = self.ctx.planner.plan(query_match)?;
 
+        // Full-text `search`: matched in WHERE, ranked in ORDER BY and the `rank` field.
+        let search =
+            text_search_request(&self.ctx.schema, &query_match.query_def, &query_match.arguments);
+        let search_rank = search.map(|(config, query)| config.rank_sql(query)).transpose()?;
+
         // Extract auto_params from arguments.
         let user_where: Option<WhereClause> = if query_match.query_def.auto_params.has_where {
             query_match
@@ -802,6 +949,22 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 .get("where")
                 .map(WhereClause::from_graphql_json)
                 .transpose()?
+                .map(|w| {
+                    apply_scalar_casts(
+                        apply_geo_backend(w, &self.ctx.schema),
+                        &query_match.query_def.return_type,
+                        &self.ctx.schema,
+                    )
+                })
+                .map(|w| {
+                    resolve_relation_filters(
+                        w,
+                        &query_match.query_def.return_type,
+                        sql_source,
+                        &self.ctx.schema,
+                    )
+                })
+                .transpose()?
         } else {
             None
         };
@@ -835,7 +998,25 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                     &query_match.query_def.return_type,
                     &query_match.query_def.native_columns,
                 )
-            });
+            })
+            .map(|clauses| {
+                resolve_order_by_aggregates(
+                    clauses,
+                    &self.ctx.schema,
+                    &query_match.query_def.return_type,
+                    sql_source,
+                )
+            })
+            .transpose()?;
+
+        // No client `orderBy`: order by search relevance, then the return type's default.
+        let order_by_clauses = order_by_clauses.or_else(|| {
+            fallback_order_by_clauses(
+                &self.ctx.schema,
+                &query_match.query_def,
+                search_rank.as_deref(),
+            )
+        });
 
         // Convert explicit arguments to WHERE conditions.
         let user_where = combine_explicit_arg_where(
@@ -844,6 +1025,7 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             &query_match.arguments,
             &query_match.query_def.native_columns,
         );
+        let user_where = prepend_where(user_where, search_where(search));
 
         // Compose RLS and user WHERE clauses.
         let composed_where = match (&rls_where_clause, &user_where) {
@@ -854,6 +1036,20 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             (None, Some(user)) => Some(user.clone()),
             (None, None) => None,
         };
+        // Row tenancy goes first so a REST filter cannot widen it.
+        let composed_where = prepend_where(
+            tenant_row_filter(&self.ctx.schema, &query_match.query_def, security_context)?,
+            composed_where,
+        );
+        let composed_where = prepend_where(
+            soft_delete_filter(
+                &self.ctx.schema,
+                &query_match.query_def,
+                &query_match.arguments,
+                security_context,
+            )?,
+            composed_where,
+        );
 
         // Inject security-derived params.
         if !query_match.query_def.inject_params.is_empty() {
@@ -866,6 +1062,14 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             }
         }
 
+        // REST reads return the full row; overlay computed fields (and `rank`) onto it.
+        let projection_hint = computed_fields_row_projection(
+            &self.ctx.schema,
+            &query_match.query_def.return_type,
+            search_rank.as_deref(),
+        )?
+        .map(|sql| SqlProjectionHint::new(self.ctx.adapter.database_type(), sql, 0));
+
         // Execute, pinning session variables to the read's connection (#329).
         let resolved_session_vars = self.resolve_session_vars(security_context)?;
         let session_pairs: Vec<(&str, &str)> =
@@ -876,7 +1080,7 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             .execute_with_projection_arc_with_session(
                 &crate::db::ProjectionRequest {
                     view: sql_source,
-                    projection: None,
+                    projection: projection_hint.as_ref(),
                     where_clause: composed_where.as_ref(),
                     order_by: order_by_clauses.as_deref(),
                     limit,
@@ -887,20 +1091,13 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             .await?;
 
         // Project results.
-        let projector = ResultProjector::new(plan.projection_fields)
-            .configure_typename_from_selections(
-                &query_match.selections,
-                &query_match.query_def.return_type,
-            );
-        let mut projected =
-            projector.project_results(&results, query_match.query_def.returns_list)?;
-        // #489: recase + project nested list-of-object fields left raw by SQL projection.
-        crate::runtime::project_nested_lists(
-            &mut projected,
-            &query_match.query_def.return_type,
-            query_match.selections.first().map_or(&[][..], |r| r.nested_fields.as_slice()),
+        let projected = project_query_results(
+            &results,
+            plan.projection_fields,
+            query_match,
+            discriminator,
             &self.ctx.schema,
-        );
+        )?;
 
         // Wrap in GraphQL data envelope.
         let response =
@@ -992,12 +1189,42 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             }
         };
 
+        let combined_where = prepend_where(
+            tenant_row_filter(&self.ctx.schema, &query_match.query_def, security_context)?,
+            combined_where,
+        );
+        let combined_where = prepend_where(
+            soft_delete_filter(
+                &self.ctx.schema,
+                &query_match.query_def,
+                &query_match.arguments,
+                security_context,
+            )?,
+            combined_where,
+        );
+
         // 3b. Compose user-supplied WHERE when has_where is enabled (same as execute_from_match).
         let combined_where: Option<WhereClause> = if query_match.query_def.auto_params.has_where {
             let user_where = query_match
                 .arguments
                 .get("where")
                 .map(WhereClause::from_graphql_json)
+                .transpose()?
+                .map(|w| {
+                    apply_scalar_casts(
+                        apply_geo_backend(w, &self.ctx.schema),
+                        &query_match.query_def.return_type,
+                        &self.ctx.schema,
+                    )
+                })
+                .map(|w| {
+                    resolve_relation_filters(
+                        w,
+                        &query_match.query_def.return_type,
+                        sql_source,
+                        &self.ctx.schema,
+                    )
+                })
                 .transpose()?;
             match (combined_where, user_where) {
                 (None, None) => None,
@@ -1008,6 +1235,15 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         } else {
             combined_where
         };
+        // 3c. A searched listing counts only the matching rows.
+        let combined_where = prepend_where(
+            combined_where,
+            search_where(text_search_request(
+                &self.ctx.schema,
+                &query_match.query_def,
+                &query_match.arguments,
+            )),
+        );
 
         // 4. Execute COUNT query via adapter, pinning session variables to the read's connection so
         //    RLS counts match the filtered rows (#329).
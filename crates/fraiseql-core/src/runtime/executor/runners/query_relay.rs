@@ -6,7 +6,9 @@ use super::{
     super::resolve_inject_value,
     query::QueryRunner,
     query_params::{
-        compute_projection_reduction, enforce_max_page_size, inject_param_where_clause,
+        apply_geo_backend, apply_scalar_casts, compute_projection_reduction, enforce_max_page_size,
+        inject_param_where_clause, prepend_where, resolve_relation_filters, soft_delete_filter,
+        tenant_row_filter,
     },
     query_projection::{
         build_typed_projection_fields, enrich_order_by_clauses, selections_contain_field,
@@ -166,6 +168,20 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 _ => Some(WhereClause::And(conditions)),
             }
         };
+        // Row tenancy: scope tenant-scoped types to the caller's tenant.
+        let security_where = prepend_where(
+            tenant_row_filter(&self.ctx.schema, query_def, security_context)?,
+            security_where,
+        );
+        let security_where = prepend_where(
+            soft_delete_filter(
+                &self.ctx.schema,
+                query_def,
+                &query_match.arguments,
+                security_context,
+            )?,
+            security_where,
+        );
 
         // Extract relay pagination arguments from variables.
         let vars = variables.and_then(|v| v.as_object());
@@ -255,6 +271,22 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             vars.and_then(|v| v.get("where"))
                 .map(WhereClause::from_graphql_json)
                 .transpose()?
+                .map(|w| {
+                    apply_scalar_casts(
+                        apply_geo_backend(w, &self.ctx.schema),
+                        &query_def.return_type,
+                        &self.ctx.schema,
+                    )
+                })
+                .map(|w| {
+                    resolve_relation_filters(
+                        w,
+                        &query_def.return_type,
+                        sql_source,
+                        &self.ctx.schema,
+                    )
+                })
+                .transpose()?
         } else {
             None
         };
@@ -284,6 +316,16 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
         } else {
             None
         };
+        // Relay cursors are keyed on row fields; an aggregate sort key would not page.
+        if order_by.iter().flatten().any(|c| c.aggregate.is_some()) {
+            return Err(FraiseQLError::Validation {
+                message: format!(
+                    "Relay query '{}' cannot be ordered by a relationship aggregate",
+                    query_def.name
+                ),
+                path:    Some("orderBy".to_string()),
+            });
+        }
 
         // Detect whether the client selected `totalCount` inside the connection.
         // Named fragment spreads are already expanded by the matcher's FragmentResolver.
@@ -521,13 +563,20 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
                 if let Some(rls) = rls {
                     conditions.insert(0, rls);
                 }
-                match conditions.len() {
+                let security_where = match conditions.len() {
                     0 => None,
                     1 => Some(conditions.remove(0)),
                     _ => Some(WhereClause::And(conditions)),
-                }
+                };
+                prepend_where(
+                    tenant_row_filter(&self.ctx.schema, node_qdef, Some(sc))?,
+                    security_where,
+                )
             },
-            None if self.ctx.config.rls_policy.is_some() || !node_qdef.inject_params.is_empty() => {
+            None if self.ctx.config.rls_policy.is_some()
+                || !node_qdef.inject_params.is_empty()
+                || self.ctx.schema.tenant_column(&type_name).is_some() =>
+            {
                 // Fail closed: anonymous lookup of a policy-gated type yields nothing.
                 let response =
                     ResultProjector::wrap_in_data_envelope(serde_json::Value::Null, "node");
@@ -543,6 +592,16 @@ impl<A: DatabaseAdapter> QueryRunner<A> {
             operator: WhereOperator::Eq,
             value:    serde_json::Value::String(uuid),
         };
+        // A soft-deleted row is not resolvable by id (`node` takes no `includeDeleted`).
+        let security_where = prepend_where(
+            soft_delete_filter(
+                &self.ctx.schema,
+                node_qdef,
+                &std::collections::HashMap::new(),
+                security_context,
+            )?,
+            security_where,
+        );
         let where_clause = match security_where {
             Some(sec) => WhereClause::And(vec![sec, id_where]),
             None => id_where,
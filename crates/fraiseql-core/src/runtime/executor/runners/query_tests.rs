@@ -103,10 +103,12 @@ mod auto_params {
     #[tokio::test]
     async fn test_has_limit_threads_to_adapter() {
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    true,
-            has_offset:   false,
-            has_where:    false,
-            has_order_by: false,
+            has_limit:           true,
+            has_offset:          false,
+            has_where:           false,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -121,10 +123,12 @@ mod auto_params {
     async fn test_limit_over_max_page_size_is_rejected() {
         // Default RuntimeConfig caps the top-level page size at 1000 (#421).
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    true,
-            has_offset:   false,
-            has_where:    false,
-            has_order_by: false,
+            has_limit:           true,
+            has_offset:          false,
+            has_where:           false,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -144,10 +148,12 @@ mod auto_params {
     #[tokio::test]
     async fn test_limit_at_max_page_size_is_allowed() {
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    true,
-            has_offset:   false,
-            has_where:    false,
-            has_order_by: false,
+            has_limit:           true,
+            has_offset:          false,
+            has_where:           false,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -162,10 +168,12 @@ mod auto_params {
     #[tokio::test]
     async fn test_has_offset_threads_to_adapter() {
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    false,
-            has_offset:   true,
-            has_where:    false,
-            has_order_by: false,
+            has_limit:           false,
+            has_offset:          true,
+            has_where:           false,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -179,10 +187,12 @@ mod auto_params {
     #[tokio::test]
     async fn test_has_where_threads_user_filter_to_adapter() {
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    false,
-            has_offset:   false,
-            has_where:    true,
-            has_order_by: false,
+            has_limit:           false,
+            has_offset:          false,
+            has_where:           true,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -200,10 +210,12 @@ mod auto_params {
     #[tokio::test]
     async fn test_has_where_false_ignores_user_filter() {
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    false,
-            has_offset:   false,
-            has_where:    false,
-            has_order_by: false,
+            has_limit:           false,
+            has_offset:          false,
+            has_where:           false,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -221,10 +233,12 @@ mod auto_params {
     #[tokio::test]
     async fn test_has_limit_and_offset_together() {
         let schema = schema_with_auto_params(AutoParams {
-            has_limit:    true,
-            has_offset:   true,
-            has_where:    false,
-            has_order_by: false,
+            has_limit:           true,
+            has_offset:          true,
+            has_where:           false,
+            has_order_by:        false,
+            has_search:          false,
+            has_include_deleted: false,
         });
         let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
         let executor = Executor::new(schema, adapter.clone());
@@ -672,6 +686,32 @@ mod session_variables {
         );
     }
 
+    /// C-SV3: a request time budget reaches the read as a transaction-local
+    /// `statement_timeout`, even for anonymous requests.
+    #[tokio::test]
+    async fn test_request_budget_sets_statement_timeout() {
+        let adapter = Arc::new(SessionVarCapturingAdapter::new(mock_user_results()));
+        let executor = Executor::new(test_schema(), adapter.clone());
+
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(5);
+        crate::runtime::budget::with_deadline(
+            deadline,
+            executor.execute("{ users { id name } }", None),
+        )
+        .await
+        .unwrap();
+
+        let pairs = adapter.captured_pairs();
+        let timeout_ms = pairs
+            .iter()
+            .find(|(k, _)| k == "statement_timeout")
+            .map(|(_, v)| v.parse::<u64>().unwrap());
+        assert!(
+            matches!(timeout_ms, Some(ms) if ms > 0 && ms <= 5_000),
+            "expected a statement_timeout within the 5s budget, got: {pairs:?}"
+        );
+    }
+
     /// A node-resolvable variant of [`schema_with_session_vars`]: the Relay `node(id:)`
     /// path resolves the type by name from `schema.types`, so the `User` type must be
     /// registered (the list-query-only `test_schema` does not register it).
@@ -1306,3 +1346,146 @@ mod explicit_arg_recasing {
         assert_eq!(path, vec!["status".to_string()]);
     }
 }
+
+// ── mod row_tenancy: `@tenant_id` types scoped on every read ──────────────
+
+mod row_tenancy {
+    use super::*;
+    use crate::{
+        db::where_clause::WhereOperator,
+        error::FraiseQLError,
+        schema::{SecurityConfig, TenancyMode},
+    };
+
+    /// `users` over `v_user`, with `User` marked tenant-scoped on `tenant_id`.
+    fn row_tenancy_schema(mode: TenancyMode) -> CompiledSchema {
+        let mut schema = CompiledSchema::new();
+        schema.queries.push(QueryDefinition {
+            name:                "users".to_string(),
+            return_type:         "User".to_string(),
+            returns_list:        true,
+            nullable:            false,
+            arguments:           Vec::new(),
+            sql_source:          Some("v_user".to_string()),
+            description:         None,
+            auto_params:         AutoParams {
+                has_where: true,
+                ..AutoParams::default()
+            },
+            deprecation:         None,
+            jsonb_column:        "data".to_string(),
+            relay:               false,
+            relay_cursor_column: None,
+            relay_cursor_type:   CursorType::default(),
+            inject_params:       IndexMap::default(),
+            cache_ttl_seconds:   None,
+            additional_views:    vec![],
+            requires_role:       None,
+            rest_path:           None,
+            rest_method:         None,
+            native_columns:      HashMap::new(),
+        });
+        schema.types.push(
+            TypeDefinition::new("User", "v_user")
+                .with_field(FieldDefinition::new("id", FieldType::String))
+                .with_field(FieldDefinition::new("name", FieldType::String))
+                .with_tenant_column("tenant_id"),
+        );
+        let mut security = SecurityConfig::new();
+        security.tenancy.mode = mode;
+        schema.security = Some(security);
+        schema
+    }
+
+    fn security_context(tenant_id: Option<&str>) -> SecurityContext {
+        SecurityContext {
+            user_id:          "user-42".into(),
+            roles:            vec![],
+            tenant_id:        tenant_id.map(Into::into),
+            scopes:           vec![],
+            attributes:       HashMap::default(),
+            request_id:       "req-001".to_string(),
+            ip_address:       None,
+            expires_at:       Utc::now() + chrono::Duration::hours(1),
+            authenticated_at: Utc::now(),
+            issuer:           None,
+            audience:         None,
+            email:            None,
+            display_name:     None,
+        }
+    }
+
+    fn assert_tenant_predicate(clause: &WhereClause, tenant: &str) {
+        match clause {
+            WhereClause::Field {
+                path,
+                operator,
+                value,
+            } => {
+                assert_eq!(path, &vec!["tenant_id".to_string()]);
+                assert_eq!(operator, &WhereOperator::Eq);
+                assert_eq!(value, &serde_json::json!(tenant));
+            },
+            other => panic!("expected the tenant predicate, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn read_is_scoped_to_the_callers_tenant() {
+        let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
+        let executor = Executor::new(row_tenancy_schema(TenancyMode::Row), adapter.clone());
+
+        executor
+            .execute_with_security("{ users { id name } }", None, &security_context(Some("t-1")))
+            .await
+            .unwrap();
+
+        assert_tenant_predicate(&adapter.captured_where().expect("tenant filter"), "t-1");
+    }
+
+    #[tokio::test]
+    async fn tenant_predicate_precedes_user_where() {
+        let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
+        let executor = Executor::new(row_tenancy_schema(TenancyMode::Row), adapter.clone());
+        let vars = serde_json::json!({ "where": { "name": { "eq": "Alice" } } });
+
+        executor
+            .execute_with_security(
+                "{ users { id name } }",
+                Some(&vars),
+                &security_context(Some("t-1")),
+            )
+            .await
+            .unwrap();
+
+        match adapter.captured_where().expect("composed filter") {
+            WhereClause::And(clauses) => assert_tenant_predicate(&clauses[0], "t-1"),
+            other => panic!("expected AND(tenant, user), got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn request_without_tenant_is_refused() {
+        let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
+        let executor = Executor::new(row_tenancy_schema(TenancyMode::Row), adapter.clone());
+
+        let err = executor
+            .execute_with_security("{ users { id name } }", None, &security_context(None))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FraiseQLError::Authorization { .. }), "got: {err:?}");
+
+        let err = executor.execute("{ users { id name } }", None).await.unwrap_err();
+        assert!(matches!(err, FraiseQLError::Authorization { .. }), "got: {err:?}");
+    }
+
+    #[tokio::test]
+    async fn other_tenancy_modes_ignore_the_marker() {
+        let adapter = Arc::new(CapturingMockAdapter::new(mock_user_results()));
+        let executor = Executor::new(row_tenancy_schema(TenancyMode::Schema), adapter.clone());
+
+        executor.execute("{ users { id name } }", None).await.unwrap();
+
+        assert!(adapter.captured_where().is_none());
+    }
+}
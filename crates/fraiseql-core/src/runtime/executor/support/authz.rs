@@ -40,8 +40,11 @@ pub(in crate::runtime::executor) fn collect_authz_ops(
         QueryType::IntrospectionType(_) => vec![(OperationKind::Query, "__type".to_string())],
         QueryType::NodeQuery { .. } => vec![(OperationKind::Query, "node".to_string())],
         // Both yield an empty op-list:
-        // - `Mutation` is gated downstream at `execute_mutation_impl` (see fn-level docs).
+        // - `Mutation` / `MutationBatch` are gated downstream at `execute_mutation_impl` (see
+        //   fn-level docs).
         // - `TypeName` (`__typename`) is a GraphQL spec meta-field, always allowed.
-        QueryType::Mutation { .. } | QueryType::TypeName { .. } => Vec::new(),
+        QueryType::Mutation { .. } | QueryType::MutationBatch(_) | QueryType::TypeName { .. } => {
+            Vec::new()
+        },
     }
 }
@@ -1,6 +1,6 @@
 //! Query classification — determines operation type for routing.
 
-use super::super::{Executor, QueryType};
+use super::super::{Executor, MutationRoot, QueryType};
 use crate::{
     db::traits::DatabaseAdapter,
     error::{FraiseQLError, Result},
@@ -129,8 +129,32 @@ impl<A: DatabaseAdapter> Executor<A> {
         // definitions; `@skip`/`@include` directives are evaluated later in
         // `execute_mutation_impl`, where the request variables are available.
         if parsed.operation_type == "mutation" {
-            let raw = parsed.selections.first().map_or(&[][..], |s| s.nested_fields.as_slice());
             let resolver = crate::graphql::FragmentResolver::new(&parsed.fragments);
+            // Several top-level fields run serially, in document order (GraphQL
+            // spec §6.2.2 "Mutation"), rather than only the first one.
+            if parsed.selections.len() > 1 {
+                let roots = parsed
+                    .selections
+                    .iter()
+                    .map(|root| {
+                        let selections =
+                            resolver.resolve_spreads(&root.nested_fields).map_err(|e| {
+                                FraiseQLError::Validation {
+                                    message: e.to_string(),
+                                    path:    Some("fragments".to_string()),
+                                }
+                            })?;
+                        Ok(MutationRoot {
+                            name: root.name.clone(),
+                            response_key: root.response_key().to_string(),
+                            selections,
+                            arguments: root.arguments.clone(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                return Ok((QueryType::MutationBatch(roots), None));
+            }
+            let raw = parsed.selections.first().map_or(&[][..], |s| s.nested_fields.as_slice());
             let selections =
                 resolver.resolve_spreads(raw).map_err(|e| FraiseQLError::Validation {
                     message: e.to_string(),
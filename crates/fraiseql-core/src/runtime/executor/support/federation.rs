@@ -249,9 +249,11 @@ impl<A: DatabaseAdapter> Executor<A> {
                 }
             }
 
-            // inject_params (tenant/owner scoping): fail closed for anonymous callers —
-            // the resolver cannot apply the per-row filter.
-            if !qdef.inject_params.is_empty() && security_context.is_none() {
+            // inject_params / `@tenant_id` (tenant/owner scoping): fail closed for anonymous
+            // callers — the resolver cannot apply the per-row filter.
+            let scoped = !qdef.inject_params.is_empty()
+                || self.ctx.schema.tenant_column(&rep.typename).is_some();
+            if scoped && security_context.is_none() {
                 return Err(entities_authz_denied(&format!(
                     "type '{}' is tenant/owner-scoped but the _entities request is unauthenticated",
                     rep.typename
@@ -305,11 +307,42 @@ impl<A: DatabaseAdapter> Executor<A> {
             else {
                 continue;
             };
-            if qdef.inject_params.is_empty() {
+            // Row tenancy (`@tenant_id`) applies even when the backing query injects nothing.
+            let tenant_column = self
+                .ctx
+                .schema
+                .tenant_column(&rep.typename)
+                .filter(|col| !qdef.inject_params.contains_key(*col));
+            if qdef.inject_params.is_empty() && tenant_column.is_none() {
                 continue;
             }
 
-            let mut conditions: Vec<WhereClause> = Vec::with_capacity(qdef.inject_params.len());
+            let mut conditions: Vec<WhereClause> = Vec::with_capacity(qdef.inject_params.len() + 1);
+            if let Some(col) = tenant_column {
+                let claim = self
+                    .ctx
+                    .schema
+                    .tenancy_config()
+                    .map_or("tenant_id", |t| t.tenant_claim.as_str());
+                let tenant = crate::tenancy::TenantContext::from_security_context(sc, claim)
+                    .ok_or_else(|| {
+                        entities_authz_denied(&format!(
+                            "type '{}' is tenant-scoped but the request carries no '{claim}' claim",
+                            rep.typename
+                        ))
+                    })?;
+                let pg_cast = qdef
+                    .native_columns
+                    .get(col)
+                    .map(|t| crate::runtime::native_columns::pg_type_to_cast(t).to_string())
+                    .unwrap_or_default();
+                conditions.push(WhereClause::NativeField {
+                    column: crate::utils::to_snake_case(col),
+                    pg_cast,
+                    operator: WhereOperator::Eq,
+                    value: serde_json::Value::String(tenant.id().to_string()),
+                });
+            }
             for (col, source) in &qdef.inject_params {
                 let value = super::super::resolve_inject_value(col, source, sc)?;
                 let pg_cast = qdef
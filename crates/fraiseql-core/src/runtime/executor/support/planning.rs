@@ -76,6 +76,39 @@ impl<A: DatabaseAdapter> Executor<A> {
                     query_type:     "mutation".to_string(),
                 })
             },
+            QueryType::MutationBatch(ref roots) => {
+                let fn_names = roots
+                    .iter()
+                    .filter(|root| root.name != "__typename")
+                    .map(|root| {
+                        let mutation_def =
+                            self.ctx.schema.find_mutation(&root.name).ok_or_else(|| {
+                                FraiseQLError::Validation {
+                                    message: format!(
+                                        "Mutation '{}' not found in schema",
+                                        root.name
+                                    ),
+                                    path:    None,
+                                }
+                            })?;
+                        Ok(mutation_def
+                            .sql_source
+                            .clone()
+                            .unwrap_or_else(|| format!("fn_{}", root.name)))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ExplainPlan {
+                    sql:            fn_names
+                        .iter()
+                        .map(|fn_name| format!("SELECT * FROM {fn_name}(...)"))
+                        .collect::<Vec<_>>()
+                        .join(";\n"),
+                    parameters:     Vec::new(),
+                    estimated_cost: 100 * fn_names.len(),
+                    views_accessed: fn_names,
+                    query_type:     "mutation".to_string(),
+                })
+            },
             QueryType::Aggregate(ref name) => {
                 let sql_source = self
                     .ctx
@@ -63,6 +63,7 @@ mod query {
         let adapter = Arc::new(MockAdapter::new(vec![]));
         let config = RuntimeConfig {
             cache_query_plans:    false,
+            plan_cache_capacity:  crate::runtime::plan_cache::DEFAULT_PLAN_CACHE_CAPACITY,
             max_query_depth:      5,
             max_query_complexity: 500,
             max_page_size:        Some(1000),
@@ -853,6 +854,7 @@ mod config {
     fn test_jsonb_strategy_in_runtime_config() {
         let config = RuntimeConfig {
             cache_query_plans:    false,
+            plan_cache_capacity:  crate::runtime::plan_cache::DEFAULT_PLAN_CACHE_CAPACITY,
             max_query_depth:      5,
             max_query_complexity: 500,
             max_page_size:        Some(1000),
@@ -883,6 +885,7 @@ mod config {
 
         let config = RuntimeConfig {
             cache_query_plans:    false,
+            plan_cache_capacity:  crate::runtime::plan_cache::DEFAULT_PLAN_CACHE_CAPACITY,
             max_query_depth:      5,
             max_query_complexity: 500,
             max_page_size:        Some(1000),
@@ -1236,6 +1239,7 @@ mod field_rbac {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "name".into(),
@@ -1251,6 +1255,7 @@ mod field_rbac {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             // Protected field: reject when unauthorized
             FieldDefinition {
@@ -1267,6 +1272,7 @@ mod field_rbac {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             // Protected field: mask when unauthorized
             FieldDefinition {
@@ -1283,6 +1289,7 @@ mod field_rbac {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
         ];
 
@@ -8,7 +8,7 @@ use serde_json::Value;
 
 use crate::{
     error::{FraiseQLError, Result, ValidationFieldError},
-    schema::CompiledSchema,
+    schema::{ArgumentDefinition, CompiledSchema, FieldType},
     validation::ValidationRule,
 };
 
@@ -92,6 +92,88 @@ pub fn validate_custom_scalar_from_schema(
     }
 }
 
+/// Validate operation arguments against the custom scalars they are declared as.
+///
+/// Walks each supplied argument through list and input-object types, so a
+/// custom scalar nested inside an input field is validated too. Null and
+/// absent values are skipped; required-argument checks happen elsewhere.
+///
+/// # Arguments
+///
+/// * `arguments` - Argument definitions of the query or mutation
+/// * `lookup` - Resolves an argument name to the value the client supplied
+/// * `schema` - The compiled schema containing custom scalar definitions
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Validation`] for the first value that fails its
+/// custom scalar definition, with `path` set to the argument path
+/// (e.g. `input.price`).
+pub fn validate_custom_scalar_arguments<'a>(
+    arguments: &[ArgumentDefinition],
+    lookup: impl Fn(&str) -> Option<&'a Value>,
+    schema: &CompiledSchema,
+) -> Result<()> {
+    if schema.custom_scalars.is_empty() {
+        return Ok(());
+    }
+    for arg in arguments {
+        if let Some(value) = lookup(&arg.name) {
+            validate_custom_scalar_value(value, &arg.arg_type, &arg.name, schema)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validate one value against its declared type, recursing into lists and
+/// input objects.
+fn validate_custom_scalar_value(
+    value: &Value,
+    field_type: &FieldType,
+    path: &str,
+    schema: &CompiledSchema,
+) -> Result<()> {
+    if value.is_null() {
+        return Ok(());
+    }
+    match field_type {
+        FieldType::List(inner) => {
+            if let Value::Array(items) = value {
+                for (i, item) in items.iter().enumerate() {
+                    validate_custom_scalar_value(item, inner, &format!("{path}[{i}]"), schema)?;
+                }
+            }
+            Ok(())
+        },
+        // The compiler emits custom scalars and input types as `Object` references.
+        FieldType::Scalar(name) | FieldType::Object(name) | FieldType::Input(name) => {
+            if schema.custom_scalars.exists(name) {
+                return schema.custom_scalars.validate(name, value).map_err(|e| match e {
+                    FraiseQLError::Validation { message, .. } => FraiseQLError::Validation {
+                        message,
+                        path: Some(path.to_string()),
+                    },
+                    other => other,
+                });
+            }
+            if let (Some(input_type), Value::Object(obj)) = (schema.find_input_type(name), value) {
+                for field in &input_type.fields {
+                    if let Some(field_value) = obj.get(&schema.display_name(&field.name)) {
+                        validate_custom_scalar_value(
+                            field_value,
+                            &FieldType::parse(&field.field_type),
+                            &format!("{path}.{}", field.name),
+                            schema,
+                        )?;
+                    }
+                }
+            }
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
 /// Validate JSON input against validation rules.
 ///
 /// This function recursively validates a JSON value against a set of
@@ -1,10 +1,11 @@
 //! Query pattern matching - matches incoming GraphQL queries to compiled templates.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
 
 use crate::{
     error::{FraiseQLError, Result},
     graphql::{DirectiveEvaluator, FieldSelection, FragmentResolver, ParsedQuery, parse_query},
+    runtime::plan_cache::{PlanCache, PlanCacheStats, PreparedPlan, plan_cache_key},
     schema::{CompiledSchema, QueryDefinition},
 };
 
@@ -28,6 +29,10 @@ pub struct QueryMatch {
 
     /// The parsed query (for access to fragments, variables, etc.).
     pub parsed_query: ParsedQuery,
+
+    /// Cached plan this match was bound from; `None` when the matcher has no
+    /// plan cache or the match was built without one (e.g. [`Self::from_operation`]).
+    pub prepared: Option<Arc<PreparedPlan>>,
 }
 
 impl QueryMatch {
@@ -74,6 +79,7 @@ impl QueryMatch {
             arguments,
             operation_name: None,
             parsed_query,
+            prepared: None,
         })
     }
 }
@@ -83,7 +89,8 @@ impl QueryMatch {
 /// Matches incoming GraphQL queries against the compiled schema to determine
 /// which pre-compiled SQL template to execute.
 pub struct QueryMatcher {
-    schema: CompiledSchema,
+    schema:     CompiledSchema,
+    plan_cache: Option<PlanCache>,
 }
 
 impl QueryMatcher {
@@ -95,7 +102,25 @@ impl QueryMatcher {
     #[must_use]
     pub fn new(mut schema: CompiledSchema) -> Self {
         schema.build_indexes();
-        Self { schema }
+        Self {
+            schema,
+            plan_cache: None,
+        }
+    }
+
+    /// Cache up to `capacity` prepared plans, keyed by query text and variables
+    /// shape (see [`crate::runtime::plan_cache`]). A capacity of `0` disables the
+    /// cache.
+    #[must_use]
+    pub fn with_plan_cache(mut self, capacity: usize) -> Self {
+        self.plan_cache = NonZeroUsize::new(capacity).map(PlanCache::new);
+        self
+    }
+
+    /// Plan cache statistics, or `None` when the cache is disabled.
+    #[must_use]
+    pub fn plan_cache_stats(&self) -> Option<PlanCacheStats> {
+        self.plan_cache.as_ref().map(PlanCache::stats)
     }
 
     /// Match a GraphQL query to a compiled template.
@@ -140,18 +165,57 @@ impl QueryMatcher {
         query: &str,
         variables: Option<&serde_json::Value>,
     ) -> Result<QueryMatch> {
+        // 1. Build the variables map once. The same map is used for `@skip`/`@include` directive
+        //    evaluation (by reference) and then moved onto the returned `QueryMatch` as
+        //    `arguments`, so we never pay for a second clone of the JSON tree.
+        let variables_map = Self::variables_to_map(variables);
+
+        // 2. Reuse the prepared plan for this query text and variables shape when cached; otherwise
+        //    parse, resolve and match it.
+        let Some(cache) = &self.plan_cache else {
+            let prepared = self.prepare(query, &variables_map)?;
+            return self.bind(
+                prepared.query_def,
+                prepared.fields,
+                prepared.selections,
+                prepared.parsed_query,
+                variables_map,
+                None,
+            );
+        };
+        let key = plan_cache_key(query, variables);
+        let prepared = match cache.get(key, query) {
+            Some(prepared) => prepared,
+            None => {
+                let prepared = Arc::new(self.prepare(query, &variables_map)?);
+                cache.insert(key, Arc::clone(&prepared));
+                prepared
+            },
+        };
+        self.bind(
+            prepared.query_def.clone(),
+            prepared.fields.clone(),
+            prepared.selections.clone(),
+            prepared.parsed_query.clone(),
+            variables_map,
+            Some(prepared),
+        )
+    }
+
+    /// Parse `query` and match it to its template: the part of matching that
+    /// depends only on the query text and the variables shape.
+    fn prepare(
+        &self,
+        query: &str,
+        variables_map: &HashMap<String, serde_json::Value>,
+    ) -> Result<PreparedPlan> {
         // 1. Parse GraphQL query using proper parser
         let parsed = parse_query(query).map_err(|e| FraiseQLError::Parse {
             message:  e.to_string(),
             location: "query".to_string(),
         })?;
 
-        // 2. Build the variables map once. The same map is used for `@skip`/`@include` directive
-        //    evaluation (by reference) and then moved onto the returned `QueryMatch` as
-        //    `arguments`, so we never pay for a second clone of the JSON tree.
-        let variables_map = Self::variables_to_map(variables);
-
-        // 3. Resolve fragment spreads
+        // 2. Resolve fragment spreads
         let resolver = FragmentResolver::new(&parsed.fragments);
         let resolved_selections = resolver.resolve_spreads(&parsed.selections).map_err(|e| {
             FraiseQLError::Validation {
@@ -160,16 +224,16 @@ impl QueryMatcher {
             }
         })?;
 
-        // 4. Evaluate directives (@skip, @include) and filter selections
+        // 3. Evaluate directives (@skip, @include) and filter selections
         let final_selections =
-            DirectiveEvaluator::filter_selections(&resolved_selections, &variables_map).map_err(
+            DirectiveEvaluator::filter_selections(&resolved_selections, variables_map).map_err(
                 |e| FraiseQLError::Validation {
                     message: e.to_string(),
                     path:    Some("directives".to_string()),
                 },
             )?;
 
-        // 5. Find matching query definition using root field
+        // 4. Find matching query definition using root field
         let query_def = self
             .schema
             .find_query(&parsed.root_field)
@@ -200,15 +264,33 @@ impl QueryMatcher {
             })?
             .clone();
 
-        // 6. Extract field names for backward compatibility
+        // 5. Extract field names for backward compatibility
         let fields = self.extract_field_names(&final_selections);
 
-        // 7. Take ownership of the variables map for `QueryMatch.arguments`. `variables_map` was
-        //    built once at step 2 and only borrowed by the directive evaluator; no additional clone
-        //    needed.
+        Ok(PreparedPlan::new(
+            query_def,
+            fields,
+            final_selections,
+            parsed,
+            variables_map.keys().cloned(),
+        ))
+    }
+
+    /// Bind a prepared template to this request's variables.
+    fn bind(
+        &self,
+        query_def: QueryDefinition,
+        fields: Vec<String>,
+        final_selections: Vec<FieldSelection>,
+        parsed: ParsedQuery,
+        variables_map: HashMap<String, serde_json::Value>,
+        prepared: Option<Arc<PreparedPlan>>,
+    ) -> Result<QueryMatch> {
+        // 6. Take ownership of the variables map for `QueryMatch.arguments`; it was only borrowed
+        //    by the directive evaluator, so no additional clone is needed.
         let mut arguments = variables_map;
 
-        // 8. Merge inline arguments from root field selection (e.g., `posts(limit: 3)`). Variables
+        // 7. Merge inline arguments from root field selection (e.g., `posts(limit: 3)`). Variables
         //    take precedence over inline arguments when both are provided.
         if let Some(root) = final_selections.first() {
             for arg in &root.arguments {
@@ -220,6 +302,14 @@ impl QueryMatcher {
             }
         }
 
+        // 8. Validate arguments declared as custom scalars (rules, ELO expression and serialization
+        //    shape) before anything reaches the database.
+        crate::runtime::input_validator::validate_custom_scalar_arguments(
+            &query_def.arguments,
+            |name| arguments.get(name),
+            &self.schema,
+        )?;
+
         Ok(QueryMatch {
             query_def,
             fields,
@@ -227,6 +317,7 @@ impl QueryMatcher {
             arguments,
             operation_name: parsed.operation_name.clone(),
             parsed_query: parsed,
+            prepared,
         })
     }
 
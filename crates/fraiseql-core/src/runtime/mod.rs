@@ -47,6 +47,7 @@
 mod aggregate_parser;
 mod aggregate_projector;
 pub mod aggregation;
+pub mod budget;
 pub mod cascade;
 mod executor;
 pub mod executor_adapter;
@@ -56,8 +57,10 @@ pub mod input_validator;
 pub mod jsonb_strategy;
 mod matcher;
 pub mod mutation_result;
+pub mod mutation_transaction;
 pub(crate) mod native_columns;
 pub mod partial_period;
+pub mod plan_cache;
 mod planner;
 pub(crate) mod projection;
 pub mod query_tracing;
@@ -83,9 +86,11 @@ pub use explain::{ExplainPlan, ExplainResult};
 pub use field_filter::{FieldAccessResult, can_access_field, classify_field_access, filter_fields};
 pub use jsonb_strategy::{JsonbOptimizationOptions, JsonbStrategy};
 pub use matcher::{QueryMatch, QueryMatcher, suggest_similar};
+pub use plan_cache::{PlanCache, PlanCacheStats, PlanTemplate, PreparedPlan};
 pub use planner::{ExecutionPlan, QueryPlanner};
 pub use projection::{
-    FieldMapping, ProjectionMapper, ResultProjector, project_entity, project_nested_lists,
+    FieldMapping, PARALLEL_PROJECTION_MIN_ROWS, ProjectionMapper, ResultProjector,
+    project_abstract_results, project_entity, project_nested_lists,
 };
 pub use query_tracing::{
     QueryExecutionTrace, QueryPhaseSpan, QueryTraceBuilder, create_phase_span, create_query_span,
@@ -125,6 +130,7 @@ use crate::security::{
 /// | Field | Default | Notes |
 /// |-------|---------|-------|
 /// | `cache_query_plans` | `true` | Caches parsed query plans for repeated queries |
+/// | `plan_cache_capacity` | `1024` | Plans kept before LRU eviction |
 /// | `max_query_depth` | `10` | Prevents stack overflow on recursive GraphQL |
 /// | `max_query_complexity` | `1000` | Rough cost model; tune per workload |
 /// | `enable_tracing` | `false` | Emit `OpenTelemetry` spans for each query |
@@ -156,6 +162,11 @@ pub struct RuntimeConfig {
     /// Enable query plan caching.
     pub cache_query_plans: bool,
 
+    /// Maximum number of query plans kept by the plan cache before the least
+    /// recently used one is evicted (see [`plan_cache`]). Ignored when
+    /// `cache_query_plans` is `false`; `0` also disables the cache.
+    pub plan_cache_capacity: usize,
+
     /// Maximum query depth (prevents deeply nested queries).
     pub max_query_depth: usize,
 
@@ -304,6 +315,7 @@ impl std::fmt::Debug for RuntimeConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RuntimeConfig")
             .field("cache_query_plans", &self.cache_query_plans)
+            .field("plan_cache_capacity", &self.plan_cache_capacity)
             .field("max_query_depth", &self.max_query_depth)
             .field("max_query_complexity", &self.max_query_complexity)
             .field("max_page_size", &self.max_page_size)
@@ -327,6 +339,7 @@ impl Default for RuntimeConfig {
     fn default() -> Self {
         Self {
             cache_query_plans:    true,
+            plan_cache_capacity:  plan_cache::DEFAULT_PLAN_CACHE_CAPACITY,
             max_query_depth:      10,
             max_query_complexity: 1000,
             max_page_size:        Some(1000),
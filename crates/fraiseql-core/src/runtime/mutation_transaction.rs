@@ -0,0 +1,62 @@
+//! Per-request transactional mutation batches.
+//!
+//! GraphQL runs the top-level fields of a mutation operation serially, and by
+//! default each one commits in its own transaction, so a failure part-way
+//! through leaves the earlier mutations applied. A request can opt into
+//! all-or-nothing semantics instead: [`with_transaction`] scopes the flag over
+//! the execution future, and the executor then runs every top-level mutation
+//! of the operation in one database transaction
+//! ([`MutationTransaction`](crate::db::MutationTransaction)), committing only
+//! when all of them succeed.
+//!
+//! A mutation whose function reports a failure (`succeeded = false`) counts as
+//! a failure here too. The error returned for a rolled-back batch names the
+//! field that failed and how many earlier mutations were undone.
+//!
+//! Like [`budget`](super::budget), the flag lives in a Tokio task-local so the
+//! HTTP layer can set it without a parameter on every executor entry point.
+
+use std::future::Future;
+
+use crate::error::FraiseQLError;
+
+tokio::task_local! {
+    static TRANSACTIONAL: bool;
+}
+
+/// Run `future` with transactional mutation batches switched on or off.
+pub async fn with_transaction<F: Future>(transactional: bool, future: F) -> F::Output {
+    TRANSACTIONAL.scope(transactional, future).await
+}
+
+/// Whether the enclosing [`with_transaction`] scope asked for a transactional
+/// batch. `false` outside a scope.
+#[must_use]
+pub fn requested() -> bool {
+    TRANSACTIONAL.try_with(|transactional| *transactional).unwrap_or(false)
+}
+
+/// Report that a transactional batch was rolled back at `failed_field` after
+/// `undone` earlier mutations had run.
+///
+/// Validation and database errors keep their kind and gain the rollback
+/// context in their message; other errors pass through unchanged.
+pub(crate) fn rolled_back(err: FraiseQLError, failed_field: &str, undone: usize) -> FraiseQLError {
+    let context = format!(
+        "Mutation transaction rolled back at '{failed_field}' ({undone} earlier mutation(s) undone)"
+    );
+    match err {
+        FraiseQLError::Validation { message, path } => FraiseQLError::Validation {
+            message: format!("{context}: {message}"),
+            path:    path.or_else(|| Some(failed_field.to_string())),
+        },
+        FraiseQLError::Database { message, sql_state } => FraiseQLError::Database {
+            message: format!("{context}: {message}"),
+            sql_state,
+        },
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,27 @@
+#![allow(clippy::panic)] // Reason: test code, panics are acceptable
+
+use super::*;
+
+#[tokio::test]
+async fn not_requested_outside_a_scope() {
+    assert!(!requested());
+    assert!(with_transaction(true, async { requested() }).await);
+    assert!(!with_transaction(false, async { requested() }).await);
+}
+
+#[test]
+fn rolled_back_keeps_the_error_kind_and_names_the_failed_field() {
+    let err = rolled_back(FraiseQLError::validation("boom"), "second", 1);
+    match err {
+        FraiseQLError::Validation { message, path } => {
+            assert!(message.contains("rolled back at 'second'"), "message was: {message}");
+            assert!(message.contains("1 earlier mutation(s) undone"), "message was: {message}");
+            assert!(message.ends_with("boom"), "message was: {message}");
+            assert_eq!(path.as_deref(), Some("second"));
+        },
+        other => panic!("expected Validation error, got {other:?}"),
+    }
+
+    let err = rolled_back(FraiseQLError::unauthorized("nope"), "second", 1);
+    assert!(matches!(err, FraiseQLError::Authorization { .. }));
+}
@@ -0,0 +1,247 @@
+//! Query plan cache - reuses matched templates for hot operations.
+//!
+//! Matching a GraphQL query to its compiled template (parse, fragment
+//! resolution, `@skip`/`@include` evaluation, template lookup) and deriving the
+//! SQL, parameter order and projection from it depends only on the query text
+//! and the *shape* of its variables, never on their values — with one
+//! exception: directive conditions, so boolean variables are part of the shape.
+//!
+//! [`PlanCache`] keys a [`PreparedPlan`] by that pair and evicts the least
+//! recently used entry once full. A hit skips everything but argument binding
+//! and custom-scalar validation, which always run per request.
+
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use lru::LruCache;
+use parking_lot::Mutex;
+use serde::Serialize;
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::{
+    graphql::{FieldSelection, ParsedQuery},
+    runtime::JsonbStrategy,
+    schema::QueryDefinition,
+};
+
+/// Default number of prepared plans kept by the executor's plan cache.
+pub const DEFAULT_PLAN_CACHE_CAPACITY: usize = 1_024;
+
+/// Variable-independent part of an [`ExecutionPlan`](super::ExecutionPlan),
+/// derived once per cached plan by the query planner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanTemplate {
+    /// SQL query to execute.
+    pub sql:               String,
+    /// Fields to project from the JSONB result.
+    pub projection_fields: Vec<String>,
+    /// JSONB handling strategy.
+    pub jsonb_strategy:    JsonbStrategy,
+}
+
+/// A matched query template, ready to be bound to a request's variables.
+#[derive(Debug)]
+pub struct PreparedPlan {
+    pub(crate) query_def:       QueryDefinition,
+    pub(crate) fields:          Vec<String>,
+    pub(crate) selections:      Vec<FieldSelection>,
+    pub(crate) parsed_query:    ParsedQuery,
+    pub(crate) parameter_order: Vec<String>,
+    pub(crate) template:        OnceLock<PlanTemplate>,
+    pub(crate) projection_sql:  OnceLock<String>,
+}
+
+impl PreparedPlan {
+    pub(crate) fn new(
+        query_def: QueryDefinition,
+        fields: Vec<String>,
+        selections: Vec<FieldSelection>,
+        parsed_query: ParsedQuery,
+        variable_names: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let parameter_order = parameter_order(&query_def, &selections, variable_names);
+        Self {
+            query_def,
+            fields,
+            selections,
+            parsed_query,
+            parameter_order,
+            template: OnceLock::new(),
+            projection_sql: OnceLock::new(),
+        }
+    }
+
+    /// Order in which bound arguments are emitted as plan parameters: the
+    /// query's declared arguments first, then any other argument by name.
+    #[must_use]
+    pub fn parameter_order(&self) -> &[String] {
+        &self.parameter_order
+    }
+
+    /// The planner's template for this plan, once it has been planned.
+    #[must_use]
+    pub fn template(&self) -> Option<&PlanTemplate> {
+        self.template.get()
+    }
+}
+
+/// Declared arguments in schema order, followed by every other name that can
+/// end up in `QueryMatch::arguments` (variables and inline root arguments),
+/// sorted for a stable order.
+fn parameter_order(
+    query_def: &QueryDefinition,
+    selections: &[FieldSelection],
+    variable_names: impl IntoIterator<Item = String>,
+) -> Vec<String> {
+    let mut order: Vec<String> = query_def.arguments.iter().map(|a| a.name.clone()).collect();
+    let mut rest: Vec<String> = variable_names
+        .into_iter()
+        .chain(
+            selections
+                .first()
+                .into_iter()
+                .flat_map(|root| root.arguments.iter().map(|arg| arg.name.clone())),
+        )
+        .filter(|name| !order.contains(name))
+        .collect();
+    rest.sort_unstable();
+    rest.dedup();
+    order.extend(rest);
+    order
+}
+
+/// Cache key for `query` with `variables`: the query text plus each
+/// variable's name and JSON type, and the value of boolean variables (which
+/// `@skip`/`@include` evaluate).
+#[must_use]
+pub fn plan_cache_key(query: &str, variables: Option<&serde_json::Value>) -> u64 {
+    use serde_json::Value;
+
+    let mut hasher = Xxh3::new();
+    hasher.update(query.as_bytes());
+    if let Some(Value::Object(map)) = variables {
+        let mut names: Vec<&String> = map.keys().collect();
+        names.sort_unstable();
+        for name in names {
+            let tag: &[u8] = match &map[name] {
+                Value::Null => b"n",
+                Value::Bool(true) => b"t",
+                Value::Bool(false) => b"f",
+                Value::Number(_) => b"#",
+                Value::String(_) => b"s",
+                Value::Array(_) => b"[",
+                Value::Object(_) => b"{",
+            };
+            hasher.update(&[0]);
+            hasher.update(name.as_bytes());
+            hasher.update(tag);
+        }
+    }
+    hasher.digest()
+}
+
+/// Point-in-time plan cache statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PlanCacheStats {
+    /// Lookups served from the cache.
+    pub hits:      u64,
+    /// Lookups that had to prepare a new plan.
+    pub misses:    u64,
+    /// Plans dropped to make room for newer ones.
+    pub evictions: u64,
+    /// Plans currently cached.
+    pub entries:   usize,
+    /// Maximum number of cached plans.
+    pub capacity:  usize,
+}
+
+impl PlanCacheStats {
+    /// Fraction of lookups served from the cache (`0.0` before any lookup).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Reason: a ratio; exactness is irrelevant
+    pub const fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Bounded LRU cache of [`PreparedPlan`]s keyed by [`plan_cache_key`].
+pub struct PlanCache {
+    entries:   Mutex<LruCache<u64, Arc<PreparedPlan>>>,
+    hits:      AtomicU64,
+    misses:    AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl PlanCache {
+    /// Create a cache holding at most `capacity` plans.
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries:   Mutex::new(LruCache::new(capacity)),
+            hits:      AtomicU64::new(0),
+            misses:    AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Look up the plan for `key`, verifying it was prepared from `query` so a
+    /// hash collision can never serve another operation's plan.
+    pub fn get(&self, key: u64, query: &str) -> Option<Arc<PreparedPlan>> {
+        let found = self
+            .entries
+            .lock()
+            .get(&key)
+            .filter(|plan| &*plan.parsed_query.source == query)
+            .cloned();
+        let counter = if found.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    /// Cache `plan` under `key`, evicting the least recently used plan if full.
+    pub fn insert(&self, key: u64, plan: Arc<PreparedPlan>) {
+        if let Some((evicted, _)) = self.entries.lock().push(key, plan) {
+            if evicted != key {
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Drop every cached plan. Counters are kept.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    /// Current statistics.
+    #[must_use]
+    pub fn stats(&self) -> PlanCacheStats {
+        let entries = self.entries.lock();
+        PlanCacheStats {
+            hits:      self.hits.load(Ordering::Relaxed),
+            misses:    self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            entries:   entries.len(),
+            capacity:  entries.cap().get(),
+        }
+    }
+}
+
+impl std::fmt::Debug for PlanCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlanCache").field("stats", &self.stats()).finish()
+    }
+}
@@ -1,6 +1,6 @@
 //! Query plan selection - chooses optimal execution strategy.
 
-use super::matcher::QueryMatch;
+use super::{matcher::QueryMatch, plan_cache::PlanTemplate};
 use crate::{
     error::Result,
     graphql::FieldSelection,
@@ -16,7 +16,7 @@ pub struct ExecutionPlan {
     /// Parameter bindings (parameter name → value).
     pub parameters: Vec<(String, serde_json::Value)>,
 
-    /// Whether this plan uses a cached result.
+    /// Whether this plan was built from a cached [`PlanTemplate`].
     pub is_cached: bool,
 
     /// Estimated cost (for optimization).
@@ -92,9 +92,45 @@ impl QueryPlanner {
         // extracting the pre-compiled SQL from the matched query definition.
         // No dynamic query optimization is needed - templates are pre-optimized.
 
-        let sql = self.generate_sql(query_match);
-        let parameters = self.extract_parameters(query_match);
+        // A match bound from the plan cache carries its template: derive it once per
+        // cached plan and emit the parameters in the plan's stable order.
+        if let Some(prepared) = query_match.prepared.as_ref().filter(|_| self.cache_enabled) {
+            let template = prepared.template.get_or_init(|| self.template(query_match));
+            let parameters = prepared
+                .parameter_order()
+                .iter()
+                .filter_map(|name| {
+                    query_match.arguments.get(name).map(|value| (name.clone(), value.clone()))
+                })
+                .collect();
+            return Ok(ExecutionPlan {
+                sql: template.sql.clone(),
+                parameters,
+                is_cached: true,
+                estimated_cost: self.estimate_cost(query_match),
+                projection_fields: template.projection_fields.clone(),
+                jsonb_strategy: template.jsonb_strategy,
+            });
+        }
+
+        let PlanTemplate {
+            sql,
+            projection_fields,
+            jsonb_strategy,
+        } = self.template(query_match);
 
+        Ok(ExecutionPlan {
+            sql,
+            parameters: self.extract_parameters(query_match),
+            is_cached: false,
+            estimated_cost: self.estimate_cost(query_match),
+            projection_fields,
+            jsonb_strategy,
+        })
+    }
+
+    /// Derive the variable-independent part of a plan.
+    fn template(&self, query_match: &QueryMatch) -> PlanTemplate {
         // Extract nested field names from the first selection's nested_fields
         // The first selection is typically the root query field (e.g., "users")
         let projection_fields = self.extract_projection_fields(&query_match.selections);
@@ -102,14 +138,11 @@ impl QueryPlanner {
         // Determine JSONB optimization strategy based on field count
         let jsonb_strategy = self.choose_jsonb_strategy(&projection_fields);
 
-        Ok(ExecutionPlan {
-            sql,
-            parameters,
-            is_cached: false,
-            estimated_cost: self.estimate_cost(query_match),
+        PlanTemplate {
+            sql: self.generate_sql(query_match),
             projection_fields,
             jsonb_strategy,
-        })
+        }
     }
 
     /// Choose JSONB handling strategy based on requested fields.
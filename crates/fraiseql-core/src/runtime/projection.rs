@@ -1,5 +1,6 @@
 //! Result projection - transforms JSONB database results to GraphQL responses.
 
+use rayon::prelude::*;
 use serde_json::{Map, Value as JsonValue};
 
 use crate::{
@@ -10,6 +11,23 @@ use crate::{
     utils::casing::{to_camel_case, to_snake_case},
 };
 
+/// Row count from which list results are projected on the rayon thread pool
+/// instead of the calling thread. Below it, fork/join overhead outweighs the gain.
+pub const PARALLEL_PROJECTION_MIN_ROWS: usize = 512;
+
+/// Project every row with `project_row`, in parallel for large result sets.
+fn project_rows<T, F>(rows: &[JsonbValue], project_row: F) -> Result<Vec<T>>
+where
+    T: Send,
+    F: Fn(&JsonbValue) -> Result<T> + Send + Sync,
+{
+    if rows.len() >= PARALLEL_PROJECTION_MIN_ROWS {
+        rows.par_iter().map(project_row).collect()
+    } else {
+        rows.iter().map(project_row).collect()
+    }
+}
+
 /// Field mapping for projection with alias support.
 #[derive(Debug, Clone)]
 pub struct FieldMapping {
@@ -377,11 +395,10 @@ impl ResultProjector {
     /// Returns error if projection fails.
     pub fn project_results(&self, results: &[JsonbValue], is_list: bool) -> Result<JsonValue> {
         if is_list {
-            // Project array of results
-            let projected: Result<Vec<JsonValue>> =
-                results.iter().map(|r| self.mapper.project(r)).collect();
+            // Project array of results (in parallel past `PARALLEL_PROJECTION_MIN_ROWS`)
+            let projected = project_rows(results, |r| self.mapper.project(r))?;
 
-            Ok(JsonValue::Array(projected?))
+            Ok(JsonValue::Array(projected))
         } else {
             // Project single result
             if let Some(first) = results.first() {
@@ -714,6 +731,11 @@ fn project_nested_lists_at(
     match value {
         // A list-returning query: each element is an entity of `type_name` (the list
         // itself is not a nesting level).
+        JsonValue::Array(arr) if arr.len() >= PARALLEL_PROJECTION_MIN_ROWS => {
+            arr.par_iter_mut().for_each(|el| {
+                project_nested_lists_at(el, type_name, selections, schema, depth);
+            });
+        },
         JsonValue::Array(arr) => {
             for el in arr.iter_mut() {
                 project_nested_lists_at(el, type_name, selections, schema, depth);
@@ -765,6 +787,58 @@ fn project_nested_lists_at(
     }
 }
 
+/// Project the rows of a query returning an interface or union.
+///
+/// Each row's concrete type is read from its `discriminator` key (stored casing
+/// first, as for any other field) and must name one of `abstract_type`'s
+/// possible types. The row is then projected at that concrete type with
+/// [`project_entity`], so `__typename` resolves to the concrete type and inline
+/// `... on T` fragments only contribute fields for rows of type `T`.
+///
+/// `selections` is the query field's `nested_fields`. An empty result projects
+/// to `[]` for list queries and `null` otherwise.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Internal`] if a row is not an object, has no
+/// string-valued discriminator, or names a type that is not a possible type of
+/// `abstract_type` — the backing view disagrees with the compiled schema.
+pub fn project_abstract_results(
+    results: &[JsonbValue],
+    abstract_type: &str,
+    discriminator: &str,
+    selections: &[FieldSelection],
+    is_list: bool,
+    schema: &CompiledSchema,
+) -> Result<JsonValue> {
+    let project_row = |row: &JsonbValue| -> Result<JsonValue> {
+        let value = row.as_value();
+        let concrete = value
+            .as_object()
+            .and_then(|obj| lookup_source(obj, discriminator))
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| {
+                FraiseQLError::internal(format!(
+                    "Row for abstract type '{abstract_type}' has no string discriminator \
+                     '{discriminator}'"
+                ))
+            })?;
+        if !schema.is_possible_type(abstract_type, concrete) {
+            return Err(FraiseQLError::internal(format!(
+                "Discriminator '{discriminator}' names '{concrete}', which is not a possible \
+                 type of '{abstract_type}'"
+            )));
+        }
+        Ok(project_entity(value, concrete, selections, schema))
+    };
+
+    if is_list {
+        Ok(JsonValue::Array(project_rows(results, project_row)?))
+    } else {
+        results.first().map_or(Ok(JsonValue::Null), project_row)
+    }
+}
+
 /// Look up a field's stored value: canonical `snake_case` key first, then a
 /// `camelCase` fallback for legacy metadata that used the GraphQL surface casing.
 fn lookup_source<'a>(obj: &'a Map<String, JsonValue>, field_name: &str) -> Option<&'a JsonValue> {
@@ -781,7 +855,8 @@ fn lookup_source<'a>(obj: &'a Map<String, JsonValue>, field_name: &str) -> Optio
 }
 
 /// Flatten a selection set for a concrete object type: direct fields, plus the
-/// contents of any inline fragment `... on T` (or on an interface `T` implements).
+/// contents of any inline fragment `... on T` (or on an interface `T` implements,
+/// or a union `T` belongs to).
 /// Resolve inline `... on T` fragments in `selections` against `type_name`,
 /// returning the flat list of field selections that apply to it.
 ///
@@ -800,7 +875,8 @@ pub fn effective_selections<'a>(
             let applies = frag_type == type_name
                 || schema
                     .find_type(type_name)
-                    .is_some_and(|td| td.implements.iter().any(|i| i == frag_type));
+                    .is_some_and(|td| td.implements.iter().any(|i| i == frag_type))
+                || schema.find_union(frag_type).is_some_and(|u| u.contains_type(type_name));
             if applies {
                 out.extend(effective_selections(&sel.nested_fields, type_name, schema));
             }
@@ -954,6 +954,7 @@ mod field_filter_tests {
             authorize:      false,
             encryption:     None,
             hierarchy:      None,
+            sql_expression: None,
         }
     }
 
@@ -1080,6 +1081,7 @@ mod field_filter_tests {
             authorize: false,
             encryption: None,
             hierarchy: None,
+            sql_expression: None,
         }
     }
 
@@ -1531,6 +1533,75 @@ mod input_validator_tests {
             "expected Validation error for PatientID failing length rule, got: {result:?}"
         );
     }
+
+    fn money_schema() -> crate::schema::CompiledSchema {
+        use crate::{
+            schema::{CompiledSchema, InputFieldDefinition, InputObjectDefinition},
+            validation::{CustomTypeDef, ScalarSerialization},
+        };
+
+        let mut s = CompiledSchema::new();
+        let mut def = CustomTypeDef::new("Money".to_string());
+        def.serialize_as = Some(ScalarSerialization::String);
+        def.validation_rules = vec![ValidationRule::Pattern {
+            pattern: CompiledPattern::new(r"^[0-9]+\.[0-9]{2}$").expect("valid regex"),
+            message: Some("Money must have two decimal places".to_string()),
+        }];
+        s.custom_scalars.register("Money".to_string(), def).unwrap();
+        s.input_types.push(
+            InputObjectDefinition::new("CreateOrderInput")
+                .with_field(InputFieldDefinition::new("total", "Money!"))
+                .with_field(InputFieldDefinition::new("lines", "[Money]")),
+        );
+        s
+    }
+
+    #[test]
+    fn test_validate_custom_scalar_arguments_top_level() {
+        use crate::schema::{ArgumentDefinition, FieldType};
+
+        let schema = money_schema();
+        let args = vec![ArgumentDefinition::new(
+            "price",
+            FieldType::Object("Money".to_string()),
+        )];
+
+        let ok = serde_json::json!("12.50");
+        validate_custom_scalar_arguments(&args, |_| Some(&ok), &schema)
+            .unwrap_or_else(|e| panic!("expected Ok for valid Money: {e}"));
+
+        let wrong_kind = serde_json::json!(12.5);
+        let result = validate_custom_scalar_arguments(&args, |_| Some(&wrong_kind), &schema);
+        assert!(
+            matches!(result, Err(FraiseQLError::Validation { ref path, .. }) if path.as_deref() == Some("price")),
+            "expected Validation error at `price`, got: {result:?}"
+        );
+
+        validate_custom_scalar_arguments(&args, |_| Some(&Value::Null), &schema)
+            .unwrap_or_else(|e| panic!("null must be skipped: {e}"));
+    }
+
+    #[test]
+    fn test_validate_custom_scalar_arguments_nested_input() {
+        use crate::schema::{ArgumentDefinition, FieldType};
+
+        let schema = money_schema();
+        let args = vec![ArgumentDefinition::new(
+            "input",
+            FieldType::Object("CreateOrderInput".to_string()),
+        )];
+
+        let ok = serde_json::json!({"total": "30.00", "lines": ["10.00", "20.00"]});
+        validate_custom_scalar_arguments(&args, |_| Some(&ok), &schema)
+            .unwrap_or_else(|e| panic!("expected Ok for valid input: {e}"));
+
+        let bad = serde_json::json!({"total": "30.00", "lines": ["10.00", "20"]});
+        let result = validate_custom_scalar_arguments(&args, |_| Some(&bad), &schema);
+        assert!(
+            matches!(result, Err(FraiseQLError::Validation { ref path, .. }) if path.as_deref() == Some("input.lines[1]")),
+            "expected Validation error at `input.lines[1]`, got: {result:?}"
+        );
+    }
 }
 
 mod jsonb_strategy_tests {
@@ -2512,6 +2583,7 @@ mod planner_tests {
                 fragments:      vec![],
                 source:         std::sync::Arc::from("{ users { id name } }"),
             },
+            prepared:       None,
         }
     }
 
@@ -2658,6 +2730,151 @@ mod planner_tests {
     }
 }
 
+mod plan_cache_tests {
+    #![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+    use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
+
+    use indexmap::IndexMap;
+    use serde_json::json;
+
+    use crate::{
+        runtime::{
+            QueryMatcher, QueryPlanner,
+            plan_cache::{PlanCache, plan_cache_key},
+        },
+        schema::{ArgumentDefinition, CompiledSchema, CursorType, FieldType, QueryDefinition},
+    };
+
+    fn query(name: &str, arguments: Vec<ArgumentDefinition>) -> QueryDefinition {
+        QueryDefinition {
+            name: name.to_string(),
+            return_type: "User".to_string(),
+            returns_list: true,
+            nullable: false,
+            arguments,
+            sql_source: Some(format!("v_{name}")),
+            description: None,
+            auto_params: crate::schema::AutoParams::default(),
+            deprecation: None,
+            jsonb_column: "data".to_string(),
+            relay: false,
+            relay_cursor_column: None,
+            relay_cursor_type: CursorType::default(),
+            inject_params: IndexMap::default(),
+            cache_ttl_seconds: None,
+            additional_views: vec![],
+            requires_role: None,
+            rest_path: None,
+            rest_method: None,
+            native_columns: HashMap::new(),
+        }
+    }
+
+    fn matcher(capacity: usize) -> QueryMatcher {
+        let mut schema = CompiledSchema::new();
+        schema.queries.push(query(
+            "users",
+            vec![
+                ArgumentDefinition::new("status", FieldType::String),
+                ArgumentDefinition::new("limit", FieldType::Int),
+            ],
+        ));
+        schema.queries.push(query("posts", Vec::new()));
+        QueryMatcher::new(schema).with_plan_cache(capacity)
+    }
+
+    const USERS: &str = "query($limit: Int, $status: String) { users(limit: $limit, status: \
+                         $status) { id name } }";
+
+    #[test]
+    fn repeated_query_is_bound_from_the_cached_plan() {
+        let matcher = matcher(8);
+
+        let first = matcher.match_query(USERS, Some(&json!({ "limit": 5 }))).unwrap();
+        let second = matcher.match_query(USERS, Some(&json!({ "limit": 50 }))).unwrap();
+
+        assert!(Arc::ptr_eq(first.prepared.as_ref().unwrap(), second.prepared.as_ref().unwrap()));
+        assert_eq!(first.arguments["limit"], json!(5));
+        assert_eq!(second.arguments["limit"], json!(50));
+        let stats = matcher.plan_cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+        assert!((stats.hit_rate() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn variables_shape_is_part_of_the_key() {
+        let a = plan_cache_key(USERS, Some(&json!({ "limit": 5 })));
+        assert_eq!(a, plan_cache_key(USERS, Some(&json!({ "limit": 7 }))));
+        assert_ne!(a, plan_cache_key(USERS, Some(&json!({ "limit": "5" }))));
+        assert_ne!(a, plan_cache_key(USERS, Some(&json!({ "limit": 5, "status": "x" }))));
+        assert_ne!(a, plan_cache_key(USERS, None));
+    }
+
+    #[test]
+    fn directive_booleans_select_different_plans() {
+        let matcher = matcher(8);
+        let query = "query($withName: Boolean!) { users { id name @include(if: $withName) } }";
+
+        let with = matcher.match_query(query, Some(&json!({ "withName": true }))).unwrap();
+        let without = matcher.match_query(query, Some(&json!({ "withName": false }))).unwrap();
+
+        assert_eq!(with.selections[0].nested_fields.len(), 2);
+        assert_eq!(without.selections[0].nested_fields.len(), 1);
+        assert_eq!(matcher.plan_cache_stats().unwrap().entries, 2);
+    }
+
+    #[test]
+    fn least_recently_used_plan_is_evicted() {
+        let matcher = matcher(1);
+
+        matcher.match_query("{ users { id } }", None).unwrap();
+        matcher.match_query("{ posts { id } }", None).unwrap();
+        matcher.match_query("{ users { id } }", None).unwrap();
+
+        let stats = matcher.plan_cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (0, 3, 2));
+        assert_eq!((stats.entries, stats.capacity), (1, 1));
+    }
+
+    #[test]
+    fn colliding_key_for_another_query_is_a_miss() {
+        let cache = PlanCache::new(NonZeroUsize::new(4).unwrap());
+        let prepared = matcher(4).match_query("{ users { id } }", None).unwrap().prepared.unwrap();
+        cache.insert(1, prepared);
+
+        assert!(cache.get(1, "{ posts { id } }").is_none());
+        assert!(cache.get(1, "{ users { id } }").is_some());
+    }
+
+    #[test]
+    fn cached_plan_orders_parameters_by_declaration() {
+        let matcher = matcher(8);
+        let planner = QueryPlanner::new(true);
+        let variables = json!({ "status": "active", "limit": 5, "extra": true });
+
+        let query_match = matcher.match_query(USERS, Some(&variables)).unwrap();
+        let plan = planner.plan(&query_match).unwrap();
+
+        assert!(plan.is_cached);
+        let names: Vec<&str> = plan.parameters.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["status", "limit", "extra"]);
+        let template = query_match.prepared.as_ref().unwrap().template().unwrap();
+        assert_eq!(template.sql, "SELECT data FROM v_users");
+        assert_eq!(template.projection_fields, vec!["id", "name"]);
+    }
+
+    #[test]
+    fn disabled_cache_prepares_every_query() {
+        let matcher = matcher(0);
+
+        let query_match = matcher.match_query("{ users { id } }", None).unwrap();
+
+        assert!(query_match.prepared.is_none());
+        assert!(matcher.plan_cache_stats().is_none());
+        assert!(!QueryPlanner::new(true).plan(&query_match).unwrap().is_cached);
+    }
+}
+
 mod projection_tests {
     #![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
     use serde_json::json;
@@ -2715,6 +2932,19 @@ mod projection_tests {
         assert_eq!(result, json!([{ "id": "1" }]));
     }
 
+    #[test]
+    fn test_result_projector_large_list_keeps_row_order() {
+        let projector = ResultProjector::new(vec!["id".to_string()]);
+        let rows = PARALLEL_PROJECTION_MIN_ROWS * 2 + 1;
+
+        let results: Vec<JsonbValue> =
+            (0..rows).map(|i| JsonbValue::new(json!({ "id": i, "name": "x" }))).collect();
+        let result = projector.project_results(&results, true).unwrap();
+
+        let expected: Vec<_> = (0..rows).map(|i| json!({ "id": i })).collect();
+        assert_eq!(result, json!(expected));
+    }
+
     #[test]
     fn test_result_projector_single() {
         let projector = ResultProjector::new(vec!["id".to_string()]);
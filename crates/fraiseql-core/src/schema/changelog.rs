@@ -148,10 +148,12 @@ fn entity_change_logs_query(cfg: &ChangelogConfig, nc: NamingConvention) -> Quer
         cased("modification_type", nc),
     ));
     q.auto_params = AutoParams {
-        has_where:    true,
-        has_order_by: true,
-        has_limit:    true,
-        has_offset:   false,
+        has_where:           true,
+        has_order_by:        true,
+        has_limit:           true,
+        has_offset:          false,
+        has_search:          false,
+        has_include_deleted: false,
     };
     // The change-log is append-only and polled in real time — never serve cached pages.
     q.cache_ttl_seconds = Some(0);
@@ -0,0 +1,90 @@
+//! Which database column types a compiled schema can run against.
+//!
+//! Shared by `fraiseql-cli` (`compile --database` / `validate --against-db`
+//! warnings) and `fraiseql-server` (the opt-in boot-time schema drift check), so
+//! the compile-time report and the boot gate agree on "compatible".
+
+use crate::{db::DatabaseType, schema::FieldType};
+
+/// Whether a SQL data type represents a JSON column for the given database.
+#[must_use]
+pub fn is_json_column_type(data_type: &str, db_type: DatabaseType) -> bool {
+    let lower = data_type.to_lowercase();
+    match db_type {
+        DatabaseType::PostgreSQL => lower == "jsonb" || lower == "json",
+        DatabaseType::MySQL => lower == "json",
+        DatabaseType::SQLite => lower.contains("json"),
+        // SQL Server has no native JSON type — always attempt JSON
+        // validation for the configured jsonb_column
+        DatabaseType::SQLServer => true,
+    }
+}
+
+/// Coarse SQL type families, used to flag argument↔column type mismatches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SqlFamily {
+    /// Integer/decimal/float numeric types.
+    Numeric,
+    /// Boolean.
+    Boolean,
+    /// UUID / `uniqueidentifier`.
+    Uuid,
+    /// Character/string types.
+    Text,
+    /// Date/time/interval types.
+    Temporal,
+    /// `json` / `jsonb`.
+    Json,
+    /// Anything else (custom domains, enums, arrays, geometry, …) — never flagged.
+    Other,
+}
+
+/// Classify a (any-dialect) SQL type string into a coarse [`SqlFamily`].
+///
+/// Strips length/precision and multi-word suffixes (`character varying`,
+/// `timestamp with time zone`, `double precision`) down to the leading base word.
+fn sql_type_family(sql_type: &str) -> SqlFamily {
+    let lower = sql_type.trim().to_lowercase();
+    let base = lower.split(['(', ' ', '[']).next().unwrap_or(lower.as_str());
+    match base {
+        "smallint" | "integer" | "int" | "int2" | "int4" | "int8" | "bigint" | "serial"
+        | "bigserial" | "smallserial" | "numeric" | "decimal" | "real" | "double" | "float"
+        | "float4" | "float8" | "money" | "mediumint" | "tinyint" => SqlFamily::Numeric,
+        "boolean" | "bool" | "bit" => SqlFamily::Boolean,
+        "uuid" | "uniqueidentifier" => SqlFamily::Uuid,
+        "text" | "varchar" | "char" | "bpchar" | "name" | "citext" | "nvarchar" | "nchar"
+        | "character" | "clob" | "string" => SqlFamily::Text,
+        "date" | "time" | "timestamp" | "timestamptz" | "timetz" | "datetime" | "datetime2"
+        | "interval" | "smalldatetime" => SqlFamily::Temporal,
+        "json" | "jsonb" => SqlFamily::Json,
+        _ => SqlFamily::Other,
+    }
+}
+
+/// Whether a query argument's GraphQL type can cleanly drive an equality predicate
+/// against a native column of `sql_type`.
+///
+/// Conservative by design: only the strongly-typed scalars (`Int`, `Float`,
+/// `Decimal`, `Boolean`, `UUID`) can flag a mismatch, and only against a *known*
+/// incompatible column family. Permissive scalars (`String`, `ID`, date/time, `JSON`,
+/// custom scalars) and non-scalar references never warn — the runtime binds them as
+/// text-coercible parameters, and `ID` intentionally spans uuid / integer / text keys.
+#[must_use]
+pub fn argument_column_compatible(field_type: &FieldType, sql_type: &str) -> bool {
+    // A list filter (`[ID!]`) drives the predicate with its element type.
+    let base = match field_type {
+        FieldType::List(inner) => inner.as_ref(),
+        other => other,
+    };
+    let family = sql_type_family(sql_type);
+    // Unknown column family → don't second-guess it.
+    if family == SqlFamily::Other {
+        return true;
+    }
+    match base {
+        FieldType::Int | FieldType::Float | FieldType::Decimal => family == SqlFamily::Numeric,
+        FieldType::Boolean => family == SqlFamily::Boolean,
+        FieldType::Uuid => matches!(family, SqlFamily::Uuid | SqlFamily::Text),
+        _ => true,
+    }
+}
@@ -119,17 +119,31 @@ pub struct AutoParams {
     /// Enable `offset` pagination.
     #[serde(default)]
     pub has_offset: bool,
+
+    /// Enable the full-text `search` argument (return type declares a
+    /// [`search`](crate::schema::TypeDefinition::search) config).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub has_search: bool,
+
+    /// Enable the `includeDeleted` argument (return type declares a
+    /// [`soft_delete_field`](crate::schema::TypeDefinition::soft_delete_field)).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub has_include_deleted: bool,
 }
 
 impl AutoParams {
-    /// Create with all auto-params enabled (common for list queries).
+    /// Create with the `where`/`orderBy`/`limit`/`offset` auto-params enabled
+    /// (common for list queries). `search` and `includeDeleted` are enabled by
+    /// the compiler only for searchable and soft-deleted return types.
     #[must_use]
     pub const fn all() -> Self {
         Self {
-            has_where:    true,
-            has_order_by: true,
-            has_limit:    true,
-            has_offset:   true,
+            has_where:           true,
+            has_order_by:        true,
+            has_limit:           true,
+            has_offset:          true,
+            has_search:          false,
+            has_include_deleted: false,
         }
     }
 
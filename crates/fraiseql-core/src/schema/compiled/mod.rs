@@ -11,6 +11,7 @@ pub mod schema;
 mod schema_domain;
 mod schema_lookup;
 mod schema_serde;
+pub mod signing;
 pub mod validation;
 
 #[cfg(test)]
@@ -22,4 +23,7 @@ pub use mutation::{InputStyle, MutationDefinition, MutationOperation};
 pub use query::{CursorType, QueryDefinition};
 pub use schema::{CURRENT_SCHEMA_FORMAT_VERSION, CompiledSchema, SubscribableEntity};
 pub use schema_serde::canonicalize_json;
+pub use signing::{
+    SchemaSigningKey, SchemaVerifyingKey, artifact_hash, seal_artifact, verify_artifact_signature,
+};
 pub use validation::is_safe_sql_identifier;
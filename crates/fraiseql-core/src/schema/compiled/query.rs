@@ -267,9 +267,9 @@ impl QueryDefinition {
     /// The full set of GraphQL arguments this query accepts, for rendering into
     /// the federation `_service` SDL, generated clients, and introspection.
     ///
-    /// The auto-wired `where`/`orderBy`/`limit`/`offset` arguments are gated by
-    /// [`auto_params`](Self::auto_params) and read directly from the argument map
-    /// at runtime, so they are deliberately *not* stored in
+    /// The auto-wired `where`/`orderBy`/`limit`/`offset`/`search`/`includeDeleted`
+    /// arguments are gated by [`auto_params`](Self::auto_params) and read directly
+    /// from the argument map at runtime, so they are deliberately *not* stored in
     /// [`arguments`](Self::arguments) (where the runtime would otherwise mistake a
     /// synthesized `limit`/`offset` for an explicit column filter). This method
     /// materialises them so every consumer that renders from the argument list can
@@ -282,19 +282,25 @@ impl QueryDefinition {
     /// An explicit argument always wins: if the query already declares an argument
     /// of the same name it is left untouched and no duplicate is synthesized.
     ///
-    /// Relay connection queries are returned unchanged — their pagination surface
-    /// (`first`/`after`/`last`/`before`) is owned by each renderer's dedicated
-    /// relay path, not by `auto_params`.
+    /// Relay connection queries only gain `includeDeleted` — their pagination
+    /// surface (`first`/`after`/`last`/`before`) is owned by each renderer's
+    /// dedicated relay path, not by `auto_params`.
     #[must_use]
     pub fn graphql_arguments(&self) -> Vec<ArgumentDefinition> {
         let mut args = self.arguments.clone();
+        let declared = |name: &str| self.arguments.iter().any(|a| a.name == name);
+        let ap = &self.auto_params;
+
+        if ap.has_include_deleted && !declared("includeDeleted") {
+            args.push(
+                ArgumentDefinition::optional("includeDeleted", FieldType::Boolean)
+                    .with_description("Also return soft-deleted rows (requires permission)."),
+            );
+        }
         if self.relay {
             return args;
         }
 
-        let declared = |name: &str| self.arguments.iter().any(|a| a.name == name);
-        let ap = &self.auto_params;
-
         if ap.has_where && !declared("where") {
             args.push(ArgumentDefinition::optional("where", FieldType::Json).with_description(
                 "Filter predicate: a nested object of `{ field: { operator: value } }`, \
@@ -319,6 +325,12 @@ impl QueryDefinition {
                     .with_description("Number of items to skip before returning results."),
             );
         }
+        if ap.has_search && !declared("search") {
+            args.push(ArgumentDefinition::optional("search", FieldType::String).with_description(
+                "Full-text search (web search syntax: quoted phrases, `or`, `-term`). \
+                 Results are ordered by relevance unless `orderBy` is given.",
+            ));
+        }
 
         args
     }
@@ -19,7 +19,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{directive::DirectiveDefinition, mutation::MutationDefinition, query::QueryDefinition};
 use crate::{
-    compiler::fact_table::FactTableMetadata,
+    compiler::{fact_table::FactTableMetadata, plugin::AppliedCompilerPlugin},
     schema::{
         config_types::{
             ChangelogConfig, DebugConfig, FederationConfig, GrpcConfig, McpConfig,
@@ -45,6 +45,10 @@ use crate::{
 /// backward-incompatible way so that startup rejects stale compiled schemas.
 pub const CURRENT_SCHEMA_FORMAT_VERSION: u32 = 1;
 
+fn is_default_geo_backend(backend: &crate::db::GeoBackend) -> bool {
+    *backend == crate::db::GeoBackend::PostGis
+}
+
 /// A `@subscribable` declaration in the compiled schema (#366).
 ///
 /// Maps a GraphQL type to the physical base table(s) whose **external** writes
@@ -279,18 +283,31 @@ pub struct CompiledSchema {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema_format_version: Option<u32>,
 
+    /// Compiler plugins applied to this schema, in the order they ran.
+    ///
+    /// Written by `CompilerPluginRegistry::apply`; omitted when no plugin ran.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compiler_plugins: Vec<AppliedCompilerPlugin>,
+
     /// Raw GraphQL schema as string (for SDL generation).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema_sdl: Option<String>,
 
     /// Custom scalar type registry.
     ///
-    /// Contains definitions for custom scalar types defined in the schema.
-    /// Built during code generation from `IRScalar` definitions.
-    /// Not serialized - populated at runtime from `ir.scalars`.
-    #[serde(skip)]
+    /// Contains definitions for custom scalar types defined in the schema,
+    /// including their validation rules, SQL type mapping and serialization
+    /// hint. Serialized as a list of definitions sorted by name; omitted when
+    /// no custom scalars are declared.
+    #[serde(default, skip_serializing_if = "CustomTypeRegistry::is_empty")]
     pub custom_scalars: CustomTypeRegistry,
 
+    /// Spatial extension geospatial WHERE operators (`near`, `withinPolygon`,
+    /// `withinBoundingBox`) are rendered for. Detected by `fraiseql compile
+    /// --database`; omitted when PostGIS, the default.
+    #[serde(default, skip_serializing_if = "is_default_geo_backend")]
+    pub geo_backend: crate::db::GeoBackend,
+
     /// O(1) lookup index: query name → index into `self.queries`.
     /// Built at construction time by `build_indexes()`; not serialized.
     /// Populated automatically by `from_json()`; call `build_indexes()` after
@@ -311,11 +328,17 @@ pub struct CompiledSchema {
     /// direct mutation of `self.subscriptions`.
     #[serde(skip)]
     pub subscription_index: HashMap<String, usize>,
+
+    /// Content hash of the `schema.compiled.json` artifact this schema was loaded
+    /// from — the `_content_hash` value `fraiseql compile` printed and embedded.
+    /// Set by `from_json()` when the artifact carries a hash; not serialized.
+    #[serde(skip)]
+    pub artifact_hash: Option<String>,
 }
 
 impl PartialEq for CompiledSchema {
     fn eq(&self, other: &Self) -> bool {
-        // Compare all fields except custom_scalars (runtime state)
+        // Compare all fields except custom_scalars (shared registry, no PartialEq)
         self.schema_format_version == other.schema_format_version
             && self.types == other.types
             && self.enums == other.enums
@@ -340,6 +363,7 @@ impl PartialEq for CompiledSchema {
             && self.changelog == other.changelog
             && self.naming_convention == other.naming_convention
             && self.naming_acronyms == other.naming_acronyms
+            && self.compiler_plugins == other.compiler_plugins
             && self.schema_sdl == other.schema_sdl
     }
 }
@@ -5,7 +5,7 @@
 
 #[cfg(feature = "federation")]
 use std::collections::HashMap;
-use std::fmt::Write as _;
+use std::{borrow::Cow, fmt::Write as _};
 
 use super::schema::{CURRENT_SCHEMA_FORMAT_VERSION, CompiledSchema};
 use crate::{
@@ -288,6 +288,31 @@ impl CompiledSchema {
         self.security.as_ref().map(|s| &s.tenancy)
     }
 
+    /// Returns the tenant column that scopes reads of `type_name`, if any.
+    ///
+    /// Only row-isolation schemas (`TenancyMode::Row`) scope reads; in the other
+    /// modes this is always `None`, whatever the type declares.
+    #[must_use]
+    pub fn tenant_column(&self, type_name: &str) -> Option<&str> {
+        if self.tenancy_mode() != crate::schema::TenancyMode::Row {
+            return None;
+        }
+        self.find_type(type_name)?.tenant_column.as_deref()
+    }
+
+    /// Returns the soft-delete field of `type_name` and the scope required to
+    /// read its deleted rows, if the type is soft-deleted.
+    #[must_use]
+    pub fn soft_delete(&self, type_name: &str) -> Option<(&str, Cow<'_, str>)> {
+        let ty = self.find_type(type_name)?;
+        let field = ty.soft_delete_field.as_deref()?;
+        let scope = ty
+            .include_deleted_scope
+            .as_deref()
+            .map_or_else(|| Cow::Owned(format!("read:{type_name}.deleted")), Cow::Borrowed);
+        Some((field, scope))
+    }
+
     /// Find a role definition by name.
     ///
     /// # Arguments
@@ -143,6 +143,39 @@ impl CompiledSchema {
         self.unions.iter().find(|u| u.name == name)
     }
 
+    /// Discriminator key of an interface or union type, if it declares one.
+    ///
+    /// Returns `None` for object types and for abstract types without a
+    /// discriminator (those cannot back a query result).
+    #[must_use]
+    pub fn abstract_discriminator(&self, name: &str) -> Option<&str> {
+        self.find_union(name)
+            .and_then(|u| u.discriminator.as_deref())
+            .or_else(|| self.find_interface(name).and_then(|i| i.discriminator.as_deref()))
+    }
+
+    /// Whether `type_name` is a possible concrete type of the interface or
+    /// union `abstract_name` (a union member, or an implementor of the interface).
+    #[must_use]
+    pub fn is_possible_type(&self, abstract_name: &str, type_name: &str) -> bool {
+        if let Some(union_def) = self.find_union(abstract_name) {
+            return union_def.contains_type(type_name);
+        }
+        self.find_interface(abstract_name).is_some()
+            && self
+                .find_type(type_name)
+                .is_some_and(|t| t.implements.iter().any(|i| i == abstract_name))
+    }
+
+    /// Object types a value of the interface or union `abstract_name` can have.
+    #[must_use]
+    pub fn possible_types(&self, abstract_name: &str) -> Vec<&TypeDefinition> {
+        if let Some(union_def) = self.find_union(abstract_name) {
+            return union_def.member_types.iter().filter_map(|m| self.find_type(m)).collect();
+        }
+        self.find_implementors(abstract_name)
+    }
+
     /// Find a query definition by name.
     ///
     /// Uses the O(1) pre-built index when available; falls back to O(n) linear
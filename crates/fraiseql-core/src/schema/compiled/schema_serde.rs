@@ -1,9 +1,11 @@
 //! Serialization, deserialization, and integrity checking for [`CompiledSchema`].
 
-use sha2::{Digest, Sha256};
 use tracing::{info, warn};
 
-use super::schema::CompiledSchema;
+use super::{
+    schema::CompiledSchema,
+    signing::{CONTENT_HASH_FIELD, SIGNATURE_FIELD, artifact_body, artifact_hash},
+};
 use crate::error::FraiseQLError;
 
 /// Recursively sort all JSON object keys to produce a canonical representation.
@@ -57,6 +59,10 @@ impl CompiledSchema {
     /// - `strict_integrity = false`: missing hash logs a warning; mismatch logs a warning but
     ///   proceeds (backwards compatibility for schemas compiled without `_content_hash`).
     ///
+    /// The recomputed hash is kept as [`CompiledSchema::artifact_hash`]. A `_signature`
+    /// field is stripped but not checked; see
+    /// [`verify_artifact_signature`](super::signing::verify_artifact_signature).
+    ///
     /// # Errors
     ///
     /// Returns error if JSON is malformed or doesn't match schema structure.
@@ -85,8 +91,10 @@ impl CompiledSchema {
             path:    None,
         })?;
 
-        // Extract and remove _content_hash
-        let expected_hash = if let Some(hash_val) = obj.remove("_content_hash") {
+        // Extract and remove _content_hash; the signature (if any) is checked by the
+        // loader against its configured public key, not here.
+        obj.remove(SIGNATURE_FIELD);
+        let expected_hash = if let Some(hash_val) = obj.remove(CONTENT_HASH_FIELD) {
             if let Some(hash_str) = hash_val.as_str() {
                 Some(hash_str.to_string())
             } else {
@@ -111,10 +119,8 @@ impl CompiledSchema {
         };
 
         // Canonicalize and serialize deterministically (sorted keys at all levels)
-        let canonical = canonicalize_json(&value);
-        let remaining_json = serde_json::to_string_pretty(&canonical).map_err(serde_err)?;
-        let computed_digest = Sha256::digest(remaining_json.as_bytes());
-        let computed_hash = hex::encode(&computed_digest[..16]);
+        let remaining_json = artifact_body(&value).map_err(serde_err)?;
+        let computed_hash = artifact_hash(&remaining_json);
 
         if let Some(expected) = expected_hash {
             if expected != computed_hash {
@@ -136,6 +142,7 @@ impl CompiledSchema {
 
         // Now deserialize the schema from the remaining JSON
         let mut schema: Self = serde_json::from_str(&remaining_json).map_err(serde_err)?;
+        schema.artifact_hash = Some(computed_hash);
         schema.build_indexes();
         Ok(schema)
     }
@@ -3,6 +3,8 @@
 //! These types replace untyped `serde_json::Value` fields in `CompiledSchema`
 //! to enable compile-time validation, IDE autocompletion, and clearer domain modeling.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Federation configuration for Apollo Federation v2 support.
@@ -399,17 +401,53 @@ impl Default for DebugConfig {
 pub struct ValidationConfig {
     /// Maximum allowed query nesting depth.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_query_depth:      Option<u32>,
+    pub max_query_depth:         Option<u32>,
     /// Maximum allowed query complexity score.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_query_complexity: Option<u32>,
+    pub max_query_complexity:    Option<u32>,
     /// Maximum number of rows a top-level `first`/`last`/`limit` argument may
     /// request, guarding against unbounded-pagination denial of service (#421).
     /// When unset, the runtime default (1000) applies; set to a large value to
     /// raise the ceiling. The server also honours the `FRAISEQL_MAX_PAGE_SIZE`
     /// environment variable as an override.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_page_size:        Option<u32>,
+    pub max_page_size:           Option<u32>,
+    /// Maximum number of aliased fields per query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_aliases:             Option<u32>,
+    /// Maximum number of root fields a single operation may select.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_root_fields:         Option<u32>,
+    /// Maximum nesting depth below `__schema` / `__type`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_introspection_depth: Option<u32>,
+    /// Per-role limit overrides, keyed by role name (`[validation.roles.<role>]`).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub roles:                   BTreeMap<String, RoleValidationLimits>,
+}
+
+/// Per-role overrides of the `[validation]` limits.
+///
+/// Unset fields inherit the base limit. A caller holding several overridden
+/// roles gets the most permissive value per limit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RoleValidationLimits {
+    /// Maximum allowed query nesting depth.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_query_depth:         Option<u32>,
+    /// Maximum allowed query complexity score.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_query_complexity:    Option<u32>,
+    /// Maximum number of aliased fields per query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_aliases:             Option<u32>,
+    /// Maximum number of root fields per operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_root_fields:         Option<u32>,
+    /// Maximum nesting depth below `__schema` / `__type`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_introspection_depth: Option<u32>,
 }
 
 /// MCP (Model Context Protocol) server configuration (compiled from `[mcp]` in `fraiseql.toml`).
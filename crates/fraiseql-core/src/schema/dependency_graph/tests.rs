@@ -9,23 +9,29 @@ use crate::schema::{
 /// Helper to create a simple type with the given fields.
 fn make_type(name: &str, fields: Vec<(&str, FieldType)>) -> TypeDefinition {
     TypeDefinition {
-        name:                name.into(),
-        sql_source:          format!("v_{}", name.to_lowercase()).into(),
-        jsonb_column:        "data".to_string(),
-        fields:              fields
+        name:                  name.into(),
+        sql_source:            format!("v_{}", name.to_lowercase()).into(),
+        jsonb_column:          "data".to_string(),
+        fields:                fields
             .into_iter()
             .map(|(n, ft)| FieldDefinition::new(n, ft))
             .collect(),
-        description:         None,
-        sql_projection_hint: None,
-        implements:          vec![],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
+        description:           None,
+        sql_projection_hint:   None,
+        implements:            vec![],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     }
 }
 
@@ -424,25 +430,32 @@ fn test_transitive_dependents() {
 fn test_interface_dependencies() {
     let schema = CompiledSchema {
         types: vec![TypeDefinition {
-            name:                "User".into(),
-            sql_source:          "v_user".into(),
-            jsonb_column:        "data".to_string(),
-            fields:              vec![FieldDefinition::new("id", FieldType::Id)],
-            description:         None,
-            sql_projection_hint: None,
-            implements:          vec!["Node".to_string()],
-            requires_role:       None,
-            is_error:            false,
-            relay:               false,
-            internal:            false,
-            embedded:            false,
-            relationships:       vec![],
-            subscription_policy: None,
+            name:                  "User".into(),
+            sql_source:            "v_user".into(),
+            jsonb_column:          "data".to_string(),
+            fields:                vec![FieldDefinition::new("id", FieldType::Id)],
+            description:           None,
+            sql_projection_hint:   None,
+            implements:            vec!["Node".to_string()],
+            requires_role:         None,
+            is_error:              false,
+            relay:                 false,
+            internal:              false,
+            embedded:              false,
+            relationships:         vec![],
+            subscription_policy:   None,
+            tenant_column:         None,
+            default_order_by:      Vec::new(),
+            search:                None,
+            order_by_aggregates:   Vec::new(),
+            soft_delete_field:     None,
+            include_deleted_scope: None,
         }],
         interfaces: vec![InterfaceDefinition {
-            name:        "Node".to_string(),
-            fields:      vec![FieldDefinition::new("id", FieldType::Id)],
-            description: None,
+            name:          "Node".to_string(),
+            fields:        vec![FieldDefinition::new("id", FieldType::Id)],
+            description:   None,
+            discriminator: None,
         }],
         queries: vec![QueryDefinition::new("users", "User").returning_list()],
         ..Default::default()
@@ -464,9 +477,10 @@ fn test_union_dependencies() {
             make_type("Post", vec![("title", FieldType::String)]),
         ],
         unions: vec![UnionDefinition {
-            name:         "SearchResult".to_string(),
-            member_types: vec!["User".to_string(), "Post".to_string()],
-            description:  None,
+            name:          "SearchResult".to_string(),
+            member_types:  vec!["User".to_string(), "Post".to_string()],
+            description:   None,
+            discriminator: None,
         }],
         queries: vec![QueryDefinition::new("search", "SearchResult").returning_list()],
         ..Default::default()
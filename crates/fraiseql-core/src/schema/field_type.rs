@@ -250,6 +250,7 @@ pub enum FieldDenyPolicy {
 ///     authorize: false,
 ///     encryption: None,
 ///     hierarchy: None,
+///     sql_expression: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -312,6 +313,7 @@ pub struct FieldDefinition {
     ///     authorize: false,
     ///     encryption: None,
     ///     hierarchy: None,
+    ///     sql_expression: None,
     /// };
     /// ```
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -339,12 +341,11 @@ pub struct FieldDefinition {
 
     /// Encryption configuration for this field.
     ///
-    /// **Not supported in this release.** End-to-end field-level at-rest encryption is
-    /// not implemented: the mutation/write path does not encrypt field values, so a field
-    /// marked for encryption would be stored in plaintext and then fail to decrypt on read.
-    /// To avoid silently storing sensitive data unencrypted, the server **refuses to start**
-    /// when any field declares this. The field is retained so existing compiled schemas
-    /// still deserialize and produce that boot-time error rather than a silent plaintext write.
+    /// When set, the server encrypts the field's value in mutation variables before it is
+    /// written and decrypts it when a response is projected, using the key named by
+    /// [`FieldEncryptionConfig::key_reference`]. The stored value is ciphertext, so the
+    /// compiler rejects filters on the field. The server **refuses to start** when any
+    /// field declares this and no secrets backend is configured.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub encryption: Option<FieldEncryptionConfig>,
 
@@ -355,14 +356,22 @@ pub struct FieldDefinition {
     /// which provides the table and ltree path column for subquery generation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hierarchy: Option<String>,
+
+    /// SQL expression computing this field (a computed/derived field).
+    ///
+    /// When set, the field is not read from the JSONB column: the query runner
+    /// evaluates the expression against the query's `sql_source` row (e.g.
+    /// `data->>'first' || ' ' || data->>'last'`) and projects the result under
+    /// the field's key. Only evaluated on the type a query returns — a computed
+    /// field reached through a nested object is read from the stored JSONB.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_expression: Option<String>,
 }
 
 /// Encryption configuration for a field in the compiled schema.
 ///
-/// **Not supported in this release** — see the [`FieldDefinition`] `encryption` field. The
-/// write path does not encrypt, so a field carrying this config would be stored in plaintext;
-/// the server refuses to boot rather than do so. This type describes the *intended* shape
-/// (key reference + algorithm) for when end-to-end field encryption is implemented.
+/// Produced by the compiler for fields marked `encrypted`; see the [`FieldDefinition`]
+/// `encryption` field for how the server applies it.
 ///
 /// # Example
 ///
@@ -426,6 +435,7 @@ impl FieldDefinition {
             authorize: false,
             encryption: None,
             hierarchy: None,
+            sql_expression: None,
         }
     }
 
@@ -446,6 +456,7 @@ impl FieldDefinition {
             authorize: false,
             encryption: None,
             hierarchy: None,
+            sql_expression: None,
         }
     }
 
@@ -629,6 +640,19 @@ impl FieldDefinition {
         self
     }
 
+    /// Compute this field from a SQL expression instead of the stored JSONB.
+    #[must_use]
+    pub fn with_sql_expression(mut self, expression: impl Into<String>) -> Self {
+        self.sql_expression = Some(expression.into());
+        self
+    }
+
+    /// Whether this field is computed from a SQL expression.
+    #[must_use]
+    pub const fn is_computed(&self) -> bool {
+        self.sql_expression.is_some()
+    }
+
     /// Check if this field is encrypted.
     #[must_use]
     pub const fn is_encrypted(&self) -> bool {
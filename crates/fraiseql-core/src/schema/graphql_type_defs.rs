@@ -7,7 +7,7 @@ use super::{
     field_type::{DeprecationInfo, FieldDefinition},
 };
 pub use crate::types::SqlProjectionHint;
-use crate::validation::ValidationRule;
+use crate::{db::OrderByAggregateFunction, types::OrderDirection, validation::ValidationRule};
 
 // =============================================================================
 // Object Type Definitions
@@ -40,6 +40,12 @@ use crate::validation::ValidationRule;
 ///     embedded: false,
 ///     relationships: Vec::new(),
 ///     subscription_policy: None,
+///     tenant_column: None,
+///     default_order_by: Vec::new(),
+///     search: None,
+///     order_by_aggregates: Vec::new(),
+///     soft_delete_field: None,
+///     include_deleted_scope: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -149,6 +155,108 @@ pub struct TypeDefinition {
     /// subscription rather than delivering every row. `None` keeps today's behavior.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subscription_policy: Option<super::SubscriptionPolicy>,
+
+    /// Column carrying the tenant identifier, set from the field annotated
+    /// `@tenant_id`.
+    ///
+    /// Under `[fraiseql.tenancy] mode = "row"` every read of this type's view is
+    /// AND-ed with `<column> = <tenant claim>` by the executor, on every path
+    /// (GraphQL, Relay, `node`, REST), and a request without a tenant is refused.
+    /// Ignored in the other tenancy modes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_column: Option<String>,
+
+    /// Ordering applied to list queries returning this type when the client
+    /// passes no `orderBy`.
+    ///
+    /// The compiler appends an `id` tiebreaker when the type has an `id` field,
+    /// so pages are stable across requests. A client-provided `orderBy`
+    /// replaces it entirely. Relay connections keep their cursor ordering.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_order_by: Vec<DefaultOrderBy>,
+
+    /// Full-text search document of this type.
+    ///
+    /// When set, list queries returning this type accept a `search` argument
+    /// matched with `websearch_to_tsquery` against the weighted document, and
+    /// results are ordered by relevance unless the client passes `orderBy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<crate::db::TextSearchConfig>,
+
+    /// Aggregates of child relationships that list queries returning this type
+    /// may be ordered by (`orderBy: { orders: { max: { total: DESC } } }`).
+    ///
+    /// Each allowed aggregate is a correlated subquery per sorted row, so they
+    /// are opt-in: any aggregate ordering not listed here is rejected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order_by_aggregates: Vec<AggregateOrderBy>,
+
+    /// Timestamp field marking a row soft-deleted (e.g. `deleted_at`).
+    ///
+    /// Every query returning this type excludes rows where the field is set,
+    /// unless the caller passes `includeDeleted: true` and holds
+    /// [`include_deleted_scope`](Self::include_deleted_scope). Delete mutations
+    /// are expected to set the field rather than remove the row.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soft_delete_field: Option<String>,
+
+    /// Scope required to read soft-deleted rows with `includeDeleted: true`;
+    /// `read:<Type>.deleted` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_deleted_scope: Option<String>,
+}
+
+/// One key of a type's [`default_order_by`](TypeDefinition::default_order_by).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DefaultOrderBy {
+    /// Field to order by (GraphQL name).
+    pub field: String,
+
+    /// Sort direction; ascending when omitted.
+    #[serde(default)]
+    pub direction: OrderDirection,
+}
+
+impl DefaultOrderBy {
+    /// Create an ordering key on `field`.
+    #[must_use]
+    pub fn new(field: impl Into<String>, direction: OrderDirection) -> Self {
+        Self {
+            field: field.into(),
+            direction,
+        }
+    }
+}
+
+/// One entry of a type's
+/// [`order_by_aggregates`](TypeDefinition::order_by_aggregates) allowlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregateOrderBy {
+    /// Relationship name (see [`relationships`](TypeDefinition::relationships)).
+    pub relationship: String,
+
+    /// Aggregate function.
+    pub function: OrderByAggregateFunction,
+
+    /// Aggregated field of the related type (GraphQL name); omitted for `count`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl AggregateOrderBy {
+    /// Allow ordering by `function` over `field` of `relationship`.
+    #[must_use]
+    pub fn new(
+        relationship: impl Into<String>,
+        function: OrderByAggregateFunction,
+        field: Option<String>,
+    ) -> Self {
+        Self {
+            relationship: relationship.into(),
+            function,
+            field,
+        }
+    }
 }
 
 pub(super) fn default_jsonb_column() -> String {
@@ -160,20 +268,26 @@ impl TypeDefinition {
     #[must_use]
     pub fn new(name: impl Into<String>, sql_source: impl Into<String>) -> Self {
         Self {
-            name:                TypeName::new(name),
-            sql_source:          SqlSource::new(sql_source),
-            jsonb_column:        "data".to_string(),
-            fields:              Vec::new(),
-            description:         None,
-            sql_projection_hint: None,
-            implements:          Vec::new(),
-            requires_role:       None,
-            is_error:            false,
-            relay:               false,
-            internal:            false,
-            embedded:            false,
-            relationships:       Vec::new(),
-            subscription_policy: None,
+            name:                  TypeName::new(name),
+            sql_source:            SqlSource::new(sql_source),
+            jsonb_column:          "data".to_string(),
+            fields:                Vec::new(),
+            description:           None,
+            sql_projection_hint:   None,
+            implements:            Vec::new(),
+            requires_role:         None,
+            is_error:              false,
+            relay:                 false,
+            internal:              false,
+            embedded:              false,
+            relationships:         Vec::new(),
+            subscription_policy:   None,
+            tenant_column:         None,
+            default_order_by:      Vec::new(),
+            search:                None,
+            order_by_aggregates:   Vec::new(),
+            soft_delete_field:     None,
+            include_deleted_scope: None,
         }
     }
 
@@ -184,6 +298,35 @@ impl TypeDefinition {
         self
     }
 
+    /// Mark this type tenant-scoped on `column` (row-isolation tenancy).
+    #[must_use]
+    pub fn with_tenant_column(mut self, column: impl Into<String>) -> Self {
+        self.tenant_column = Some(column.into());
+        self
+    }
+
+    /// Set the ordering applied to list queries without a client `orderBy`.
+    #[must_use]
+    pub fn with_default_order_by(mut self, order_by: Vec<DefaultOrderBy>) -> Self {
+        self.default_order_by = order_by;
+        self
+    }
+
+    /// Soft-delete this type on `field` (see
+    /// [`soft_delete_field`](Self::soft_delete_field)).
+    #[must_use]
+    pub fn with_soft_delete(mut self, field: impl Into<String>) -> Self {
+        self.soft_delete_field = Some(field.into());
+        self
+    }
+
+    /// Make list queries returning this type full-text searchable.
+    #[must_use]
+    pub fn with_search(mut self, config: crate::db::TextSearchConfig) -> Self {
+        self.search = Some(config);
+        self
+    }
+
     /// Add a field to this type.
     #[must_use]
     pub fn with_field(mut self, field: FieldDefinition) -> Self {
@@ -647,6 +790,7 @@ impl InputFieldDefinition {
 ///         FieldDefinition::new("id", FieldType::Id),
 ///     ],
 ///     description: Some("An object with an ID".to_string()),
+///     discriminator: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -661,6 +805,14 @@ pub struct InterfaceDefinition {
     /// Description of the interface.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// JSONB key in the backing view whose value names the concrete object
+    /// type of each row (e.g. `"kind"` holding `"Dog"` or `"Cat"`).
+    ///
+    /// Required for queries that return this interface: each row is projected
+    /// at its concrete type, which also resolves `__typename`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<String>,
 }
 
 impl InterfaceDefinition {
@@ -668,9 +820,10 @@ impl InterfaceDefinition {
     #[must_use]
     pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name:        name.into(),
-            fields:      Vec::new(),
-            description: None,
+            name:          name.into(),
+            fields:        Vec::new(),
+            description:   None,
+            discriminator: None,
         }
     }
 
@@ -695,6 +848,13 @@ impl InterfaceDefinition {
         self
     }
 
+    /// Set the discriminator key used to resolve each row's concrete type.
+    #[must_use]
+    pub fn with_discriminator(mut self, key: impl Into<String>) -> Self {
+        self.discriminator = Some(key.into());
+        self
+    }
+
     /// Find a field by name.
     #[must_use]
     pub fn find_field(&self, name: &str) -> Option<&FieldDefinition> {
@@ -721,6 +881,7 @@ impl InterfaceDefinition {
 ///     name: "SearchResult".to_string(),
 ///     member_types: vec!["User".to_string(), "Post".to_string(), "Comment".to_string()],
 ///     description: Some("Possible search result types".to_string()),
+///     discriminator: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -735,6 +896,14 @@ pub struct UnionDefinition {
     /// Description of the union.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// JSONB key in the backing view whose value names the member type of each
+    /// row (e.g. `"kind"` holding `"User"` or `"Post"`).
+    ///
+    /// Required for queries that return this union: each row is projected at
+    /// its member type, which also resolves `__typename`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<String>,
 }
 
 impl UnionDefinition {
@@ -742,9 +911,10 @@ impl UnionDefinition {
     #[must_use]
     pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name:         name.into(),
-            member_types: Vec::new(),
-            description:  None,
+            name:          name.into(),
+            member_types:  Vec::new(),
+            description:   None,
+            discriminator: None,
         }
     }
 
@@ -769,6 +939,13 @@ impl UnionDefinition {
         self
     }
 
+    /// Set the discriminator key used to resolve each row's member type.
+    #[must_use]
+    pub fn with_discriminator(mut self, key: impl Into<String>) -> Self {
+        self.discriminator = Some(key.into());
+        self
+    }
+
     /// Check if a type is a member of this union.
     #[must_use]
     pub fn contains_type(&self, type_name: &str) -> bool {
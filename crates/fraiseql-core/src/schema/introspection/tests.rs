@@ -165,10 +165,12 @@ fn test_auto_params_surface_as_query_arguments() {
     let mut schema = test_schema();
     let mut logs = crate::schema::QueryDefinition::new("changeLogs", "User").returning_list();
     logs.auto_params = AutoParams {
-        has_where:    true,
-        has_order_by: true,
-        has_limit:    true,
-        has_offset:   false,
+        has_where:           true,
+        has_order_by:        true,
+        has_limit:           true,
+        has_offset:          false,
+        has_search:          false,
+        has_include_deleted: false,
     };
     schema.queries.push(logs);
 
@@ -217,20 +219,21 @@ fn test_deprecated_field_introspection() {
     // Create a schema with a deprecated field
     let mut schema = CompiledSchema::new();
     schema.types.push(TypeDefinition {
-        name:                "Product".into(),
-        sql_source:          "products".into(),
-        jsonb_column:        "data".to_string(),
-        description:         None,
-        sql_projection_hint: None,
-        implements:          vec![],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
-        fields:              vec![
+        name:                  "Product".into(),
+        sql_source:            "products".into(),
+        jsonb_column:          "data".to_string(),
+        description:           None,
+        sql_projection_hint:   None,
+        implements:            vec![],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        fields:                vec![
             FieldDefinition::new("id", FieldType::Id),
             FieldDefinition {
                 name:           "oldSku".into(),
@@ -248,9 +251,15 @@ fn test_deprecated_field_introspection() {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition::new("sku", FieldType::String),
         ],
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     });
 
     let introspection = IntrospectionBuilder::build(&schema);
@@ -468,50 +477,63 @@ fn test_interface_introspection() {
 
     // Add a Node interface
     schema.interfaces.push(InterfaceDefinition {
-        name:        "Node".to_string(),
-        description: Some("An object with a globally unique ID".to_string()),
-        fields:      vec![FieldDefinition::new("id", FieldType::Id)],
+        name:          "Node".to_string(),
+        description:   Some("An object with a globally unique ID".to_string()),
+        fields:        vec![FieldDefinition::new("id", FieldType::Id)],
+        discriminator: None,
     });
 
     // Add types that implement the interface
     schema.types.push(TypeDefinition {
-        name:                "User".into(),
-        sql_source:          "users".into(),
-        jsonb_column:        "data".to_string(),
-        description:         Some("A user".to_string()),
-        sql_projection_hint: None,
-        implements:          vec!["Node".to_string()],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
-        fields:              vec![
+        name:                  "User".into(),
+        sql_source:            "users".into(),
+        jsonb_column:          "data".to_string(),
+        description:           Some("A user".to_string()),
+        sql_projection_hint:   None,
+        implements:            vec!["Node".to_string()],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        fields:                vec![
             FieldDefinition::new("id", FieldType::Id),
             FieldDefinition::new("name", FieldType::String),
         ],
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     });
 
     schema.types.push(TypeDefinition {
-        name:                "Post".into(),
-        sql_source:          "posts".into(),
-        jsonb_column:        "data".to_string(),
-        description:         Some("A blog post".to_string()),
-        sql_projection_hint: None,
-        implements:          vec!["Node".to_string()],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
-        fields:              vec![
+        name:                  "Post".into(),
+        sql_source:            "posts".into(),
+        jsonb_column:          "data".to_string(),
+        description:           Some("A blog post".to_string()),
+        sql_projection_hint:   None,
+        implements:            vec!["Node".to_string()],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        fields:                vec![
             FieldDefinition::new("id", FieldType::Id),
             FieldDefinition::new("title", FieldType::String),
         ],
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     });
 
     let introspection = IntrospectionBuilder::build(&schema);
@@ -550,37 +572,45 @@ fn test_type_implements_interface() {
 
     // Add interfaces
     schema.interfaces.push(InterfaceDefinition {
-        name:        "Node".to_string(),
-        description: None,
-        fields:      vec![FieldDefinition::new("id", FieldType::Id)],
+        name:          "Node".to_string(),
+        description:   None,
+        fields:        vec![FieldDefinition::new("id", FieldType::Id)],
+        discriminator: None,
     });
 
     schema.interfaces.push(InterfaceDefinition {
-        name:        "Timestamped".to_string(),
-        description: None,
-        fields:      vec![FieldDefinition::new("createdAt", FieldType::DateTime)],
+        name:          "Timestamped".to_string(),
+        description:   None,
+        fields:        vec![FieldDefinition::new("createdAt", FieldType::DateTime)],
+        discriminator: None,
     });
 
     // Add a type that implements both interfaces
     schema.types.push(TypeDefinition {
-        name:                "Comment".into(),
-        sql_source:          "comments".into(),
-        jsonb_column:        "data".to_string(),
-        description:         None,
-        sql_projection_hint: None,
-        implements:          vec!["Node".to_string(), "Timestamped".to_string()],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
-        fields:              vec![
+        name:                  "Comment".into(),
+        sql_source:            "comments".into(),
+        jsonb_column:          "data".to_string(),
+        description:           None,
+        sql_projection_hint:   None,
+        implements:            vec!["Node".to_string(), "Timestamped".to_string()],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        fields:                vec![
             FieldDefinition::new("id", FieldType::Id),
             FieldDefinition::new("createdAt", FieldType::DateTime),
             FieldDefinition::new("text", FieldType::String),
         ],
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     });
 
     let introspection = IntrospectionBuilder::build(&schema);
@@ -607,9 +637,10 @@ fn test_interface_in_type_map() {
 
     let mut schema = CompiledSchema::new();
     schema.interfaces.push(InterfaceDefinition {
-        name:        "Searchable".to_string(),
-        description: None,
-        fields:      vec![],
+        name:          "Searchable".to_string(),
+        description:   None,
+        fields:        vec![],
+        discriminator: None,
     });
 
     let introspection = IntrospectionBuilder::build(&schema);
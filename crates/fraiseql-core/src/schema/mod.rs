@@ -43,6 +43,7 @@
 //! ```
 
 mod changelog;
+mod column_compat;
 mod compiled;
 mod config_types;
 mod dependency_graph;
@@ -63,17 +64,19 @@ mod subscription_policy;
 mod subscription_types;
 
 pub use changelog::inject_changelog;
+pub use column_compat::{argument_column_compatible, is_json_column_type};
 pub use compiled::{
     ArgumentDefinition, AutoParams, CURRENT_SCHEMA_FORMAT_VERSION, CompiledSchema, CursorType,
     DirectiveDefinition, DirectiveLocationKind, InputStyle, MutationDefinition, MutationOperation,
-    QueryDefinition, SubscribableEntity, canonicalize_json, is_safe_sql_identifier,
+    QueryDefinition, SchemaSigningKey, SchemaVerifyingKey, SubscribableEntity, artifact_hash,
+    canonicalize_json, is_safe_sql_identifier, seal_artifact, verify_artifact_signature,
 };
 pub use config_types::{
     AuthorizationPolicy, AuthorizationRule, Cardinality, ChangelogConfig, CircuitBreakerConfig,
     CompiledSecurityConfig, CrudNamingConfig, CrudNamingPreset, DebugConfig, DeleteResponse,
     EnterpriseSecurityConfig, EntityCircuitBreakerOverride, EventHandler, FederationConfig,
     FederationEntity, FieldAuthRule, GrpcConfig, McpConfig, NamingConvention, ObserversConfig,
-    Relationship, RestConfig, SessionVariableMapping, SessionVariableSource,
+    Relationship, RestConfig, RoleValidationLimits, SessionVariableMapping, SessionVariableSource,
     SessionVariablesConfig, SubscriptionHooksConfig, SubscriptionsConfig, ValidationConfig,
 };
 pub use dependency_graph::{ChangeImpact, CyclePath, SchemaDependencyGraph};
@@ -82,8 +85,8 @@ pub use field_type::{
     FieldType, VectorConfig, VectorIndexType,
 };
 pub use graphql_type_defs::{
-    EnumDefinition, EnumValueDefinition, InputFieldDefinition, InputObjectDefinition,
-    InterfaceDefinition, SqlProjectionHint, TypeDefinition, UnionDefinition,
+    AggregateOrderBy, DefaultOrderBy, EnumDefinition, EnumValueDefinition, InputFieldDefinition,
+    InputObjectDefinition, InterfaceDefinition, SqlProjectionHint, TypeDefinition, UnionDefinition,
 };
 pub use graphql_value::GraphQLValue;
 pub use hierarchy::{HierarchiesConfig, HierarchyDefinition};
@@ -117,6 +117,13 @@ pub struct EncryptedData {
     pub encrypted_at:  i64,
     /// Additional authenticated data (AAD)
     pub context:       HashMap<String, String>,
+    /// Version of the data key `ciphertext` was sealed with (envelope encryption only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_version:   Option<u16>,
+    /// The data key `ciphertext` was sealed with, wrapped by `key_reference`
+    /// (envelope encryption only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_key:      Option<Box<EncryptedData>>,
 }
 
 impl EncryptedData {
@@ -135,8 +142,18 @@ impl EncryptedData {
             algorithm,
             encrypted_at,
             context,
+            key_version: None,
+            data_key: None,
         }
     }
+
+    /// Attach the wrapped data key and its version (envelope encryption).
+    #[must_use]
+    pub fn with_data_key(mut self, data_key: Self, key_version: u16) -> Self {
+        self.data_key = Some(Box::new(data_key));
+        self.key_version = Some(key_version);
+        self
+    }
 }
 
 /// Data key pair for envelope encryption.
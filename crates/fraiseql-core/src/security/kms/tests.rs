@@ -10,6 +10,8 @@ mod base_tests {
 }
 
 mod models_tests {
+    use std::collections::HashMap;
+
     use crate::security::kms::*;
 
     #[test]
@@ -49,6 +51,40 @@ mod models_tests {
         assert_eq!(KeyState::Disabled.to_string(), "disabled");
     }
 
+    #[test]
+    #[allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+    fn test_encrypted_data_envelope_fields_are_optional_in_json() {
+        let key_ref = KeyReference::new(
+            "vault".to_string(),
+            "master".to_string(),
+            KeyPurpose::EncryptDecrypt,
+            1_000_000,
+        );
+        let plain = EncryptedData::new(
+            "abcd".to_string(),
+            key_ref.clone(),
+            "aes256-gcm96".to_string(),
+            1_000_000,
+            HashMap::new(),
+        );
+        let json = serde_json::to_value(&plain).unwrap();
+        assert!(json.get("key_version").is_none());
+        assert!(json.get("data_key").is_none());
+
+        let wrapped = EncryptedData::new(
+            "vault:v1:key".to_string(),
+            key_ref,
+            "data-key".to_string(),
+            1_000_000,
+            HashMap::new(),
+        );
+        let envelope = plain.with_data_key(wrapped, 3);
+        let back: EncryptedData =
+            serde_json::from_value(serde_json::to_value(&envelope).unwrap()).unwrap();
+        assert_eq!(back.key_version, Some(3));
+        assert_eq!(back.data_key.unwrap().ciphertext, "vault:v1:key");
+    }
+
     #[test]
     fn test_rotation_policy_new() {
         let policy = RotationPolicy::new(true, 90);
@@ -9,7 +9,7 @@
 //! - Authentication middleware (JWT, Auth0, Clerk)
 //! - OIDC/JWKS support for any OIDC-compliant provider
 //! - Query validation (depth, complexity)
-//! - Audit logging
+//! - Audit logging (including the per-mutation operation audit log)
 //! - TLS enforcement
 //! - Introspection control
 //! - Error formatting
@@ -31,6 +31,7 @@ pub mod headers;
 pub mod introspection_enforcer;
 pub mod kms;
 pub mod oidc;
+pub mod operation_audit;
 pub mod profiles;
 pub mod query_validator;
 pub mod rls_policy;
@@ -72,6 +73,9 @@ pub use kms::{
     KmsResult, RotationPolicy, VaultConfig, VaultKmsProvider,
 };
 pub use oidc::{OidcConfig, OidcValidator};
+pub use operation_audit::{
+    OperationAuditConfig, OperationAuditLog, OperationAuditRecord, OperationAuditWorker,
+};
 pub use profiles::SecurityProfile;
 pub use query_validator::{QueryValidator, QueryValidatorConfig};
 pub use rls_policy::{CompiledRLSPolicy, DefaultRLSPolicy, NoRLSPolicy, RLSPolicy, RlsWhereClause};
@@ -0,0 +1,524 @@
+//! Operation-level audit log for GraphQL mutations.
+//!
+//! Every executed mutation (successful or failed) is described by an
+//! [`OperationAuditRecord`] — operation name, caller identity, tenant, a hash of
+//! the variables, the affected entity ids, and latency — and handed to an
+//! [`OperationAuditLog`]. Recording never blocks the request: records are
+//! sampled, then pushed onto a bounded channel drained by an
+//! [`OperationAuditWorker`] that batch-inserts them into the partitioned
+//! `tb_graphql_audit` table.
+//!
+//! The table is range-partitioned by month on `occurred_at`. The worker creates
+//! partitions on demand and periodically drops partitions that lie entirely
+//! outside the retention window, so pruning is a metadata operation rather
+//! than a large `DELETE`.
+//!
+//! Variables are never stored: only a SHA-256 hash of their canonical JSON
+//! form, which lets auditors correlate repeated payloads without persisting
+//! personal data.
+
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+use super::audit::AuditError;
+
+/// Name of the partitioned parent table.
+pub const OPERATION_AUDIT_TABLE: &str = "tb_graphql_audit";
+
+/// Configuration for the operation audit log (`[operation_audit]`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OperationAuditConfig {
+    /// Fraction of mutations recorded, in `[0.0, 1.0]` (default: 1.0 — every mutation).
+    pub sample_rate:         f64,
+    /// Days of history kept before a monthly partition is dropped (default: 90).
+    pub retention_days:      u32,
+    /// Seconds between retention sweeps (default: 3600).
+    pub prune_interval_secs: u64,
+    /// Records buffered in memory before new records are dropped (default: 10 000).
+    pub queue_capacity:      usize,
+    /// Maximum records written per insert transaction (default: 100).
+    pub batch_size:          usize,
+    /// Maximum time a record waits in the buffer before a flush, in
+    /// milliseconds (default: 1000).
+    pub flush_interval_ms:   u64,
+}
+
+impl Default for OperationAuditConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate:         1.0,
+            retention_days:      90,
+            prune_interval_secs: 3600,
+            queue_capacity:      10_000,
+            batch_size:          100,
+            flush_interval_ms:   1000,
+        }
+    }
+}
+
+impl OperationAuditConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first invalid setting.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.sample_rate) {
+            return Err(format!(
+                "operation_audit.sample_rate must be between 0.0 and 1.0, got {}",
+                self.sample_rate
+            ));
+        }
+        if self.retention_days == 0 {
+            return Err("operation_audit.retention_days must be at least 1".to_string());
+        }
+        if self.queue_capacity == 0 || self.batch_size == 0 {
+            return Err(
+                "operation_audit.queue_capacity and batch_size must be greater than 0".to_string()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// One audited mutation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationAuditRecord {
+    /// When the mutation finished executing.
+    pub occurred_at:    DateTime<Utc>,
+    /// Client-supplied operation name (`mutation CreateUser { ... }`), if any.
+    pub operation_name: Option<String>,
+    /// Root mutation field (`createUser`).
+    pub mutation_name:  String,
+    /// Authenticated caller, or `None` for anonymous requests.
+    pub user_id:        Option<String>,
+    /// Tenant the mutation ran under, if multi-tenant.
+    pub tenant_id:      Option<String>,
+    /// SHA-256 of the canonical variables JSON (see [`hash_variables`]).
+    pub variables_hash: Option<String>,
+    /// Ids of the entities returned by the mutation (see [`affected_entity_ids`]).
+    pub entity_ids:     Vec<String>,
+    /// Execution latency in milliseconds.
+    pub latency_ms:     u64,
+    /// Whether the mutation succeeded.
+    pub success:        bool,
+}
+
+/// Hash request variables for the audit log.
+///
+/// Object keys are sorted recursively before hashing, so the hash depends only
+/// on the variable values, not on the order the client serialized them in.
+/// Returns `None` when there are no variables (absent, `null`, or `{}`).
+#[must_use]
+pub fn hash_variables(variables: Option<&serde_json::Value>) -> Option<String> {
+    let variables = variables?;
+    match variables {
+        serde_json::Value::Null => return None,
+        serde_json::Value::Object(map) if map.is_empty() => return None,
+        _ => {},
+    }
+    let mut canonical = String::new();
+    write_canonical(variables, &mut canonical);
+    Some(hex::encode(Sha256::digest(canonical.as_bytes())))
+}
+
+fn write_canonical(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        },
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        },
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Ids of the entities a mutation returned.
+///
+/// Reads every root field under `data`: an object contributes its `id`, a list
+/// contributes the `id` of each element. Entities without a selected `id` are
+/// not recorded — select `id` in the mutation to have it audited.
+#[must_use]
+pub fn affected_entity_ids(response: &serde_json::Value) -> Vec<String> {
+    fn entity_id(value: &serde_json::Value) -> Option<String> {
+        match value.get("id")? {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    let Some(data) = response.get("data").and_then(serde_json::Value::as_object) else {
+        return Vec::new();
+    };
+    let mut ids = Vec::new();
+    for root in data.values() {
+        match root {
+            serde_json::Value::Array(items) => ids.extend(items.iter().filter_map(entity_id)),
+            other => ids.extend(entity_id(other)),
+        }
+    }
+    ids
+}
+
+/// Handle for recording mutations; cheap to clone.
+///
+/// Created by [`OperationAuditLog::connect`] together with the
+/// [`OperationAuditWorker`] that persists what it records.
+#[derive(Debug, Clone)]
+pub struct OperationAuditLog {
+    tx:          mpsc::Sender<OperationAuditRecord>,
+    sample_rate: f64,
+    dropped:     Arc<AtomicU64>,
+}
+
+impl OperationAuditLog {
+    /// Connect to PostgreSQL, create the audit table if needed, and return the
+    /// recording handle plus the worker that must be spawned to drain it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuditError`] if the pool cannot be created or the table DDL fails.
+    pub async fn connect(
+        database_url: &str,
+        config: &OperationAuditConfig,
+    ) -> Result<(Self, OperationAuditWorker), AuditError> {
+        let pg_config = deadpool_postgres::Config {
+            url: Some(database_url.to_string()),
+            // Small dedicated pool — one writer and one pruner.
+            pool: Some(deadpool_postgres::PoolConfig::new(2)),
+            ..Default::default()
+        };
+        let pool = pg_config
+            .create_pool(
+                Some(deadpool_postgres::Runtime::Tokio1),
+                deadpool_postgres::tokio_postgres::NoTls,
+            )
+            .map_err(|e| AuditError::Export(format!("Failed to create audit pool: {e}")))?;
+        let pool = Arc::new(pool);
+        ensure_audit_table(&pool).await?;
+        Ok(Self::with_pool(pool, config))
+    }
+
+    /// Build the handle and worker over an existing pool.
+    ///
+    /// The caller is responsible for having run [`ensure_audit_table`].
+    #[must_use]
+    pub fn with_pool(
+        pool: Arc<Pool>,
+        config: &OperationAuditConfig,
+    ) -> (Self, OperationAuditWorker) {
+        let (tx, rx) = mpsc::channel(config.queue_capacity.max(1));
+        let dropped = Arc::new(AtomicU64::new(0));
+        let log = Self {
+            tx,
+            sample_rate: config.sample_rate,
+            dropped: Arc::clone(&dropped),
+        };
+        let worker = OperationAuditWorker {
+            pool,
+            rx,
+            config: config.clone(),
+            dropped,
+        };
+        (log, worker)
+    }
+
+    /// Whether the next mutation should be recorded under the sampling rate.
+    ///
+    /// Call this before building an [`OperationAuditRecord`] to skip the work
+    /// for unsampled mutations.
+    #[must_use]
+    pub fn should_sample(&self) -> bool {
+        self.sample_rate >= 1.0
+            || (self.sample_rate > 0.0 && rand::random::<f64>() < self.sample_rate)
+    }
+
+    /// Queue a record for persistence without waiting.
+    ///
+    /// Returns `false` when the record was dropped because the buffer is full
+    /// (the database is slower than the mutation rate) or the worker has
+    /// stopped. Drops are counted in [`Self::dropped`] and logged by the worker.
+    pub fn record(&self, record: OperationAuditRecord) -> bool {
+        if self.tx.try_send(record).is_ok() {
+            true
+        } else {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+
+    /// Number of records dropped since startup.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Background task that writes queued records and prunes expired partitions.
+///
+/// Run it with [`Self::run`] on a long-lived task; it returns once every
+/// [`OperationAuditLog`] handle has been dropped and the buffer is flushed.
+#[derive(Debug)]
+pub struct OperationAuditWorker {
+    pool:    Arc<Pool>,
+    rx:      mpsc::Receiver<OperationAuditRecord>,
+    config:  OperationAuditConfig,
+    dropped: Arc<AtomicU64>,
+}
+
+impl OperationAuditWorker {
+    /// Drain the queue in batches and run the retention sweep on its interval.
+    pub async fn run(mut self) {
+        let mut flush = tokio::time::interval(Duration::from_millis(self.config.flush_interval_ms));
+        let mut prune =
+            tokio::time::interval(Duration::from_secs(self.config.prune_interval_secs.max(1)));
+        flush.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        prune.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        let mut batch = Vec::with_capacity(self.config.batch_size);
+        let mut partitions = HashSet::new();
+        let mut reported_drops = 0;
+        loop {
+            tokio::select! {
+                received = self.rx.recv() => {
+                    let Some(record) = received else { break };
+                    batch.push(record);
+                    if batch.len() >= self.config.batch_size {
+                        self.flush(&mut batch, &mut partitions).await;
+                    }
+                },
+                _ = flush.tick() => {
+                    self.flush(&mut batch, &mut partitions).await;
+                    let dropped = self.dropped.load(Ordering::Relaxed);
+                    if dropped > reported_drops {
+                        warn!(
+                            dropped = dropped - reported_drops,
+                            "Operation audit buffer full — records dropped"
+                        );
+                        reported_drops = dropped;
+                    }
+                },
+                _ = prune.tick() => {
+                    let retention_days = self.config.retention_days;
+                    match prune_expired_partitions(&self.pool, retention_days, Utc::now()).await {
+                        Ok(dropped) if !dropped.is_empty() => {
+                            info!(
+                                partitions = ?dropped,
+                                "Operation audit: dropped expired partitions"
+                            );
+                            for name in &dropped {
+                                partitions.remove(name);
+                            }
+                        },
+                        Ok(_) => {},
+                        Err(e) => warn!(error = %e, "Operation audit: retention sweep failed"),
+                    }
+                },
+            }
+        }
+        self.flush(&mut batch, &mut partitions).await;
+        debug!("Operation audit worker stopped");
+    }
+
+    async fn flush(&self, batch: &mut Vec<OperationAuditRecord>, partitions: &mut HashSet<String>) {
+        if batch.is_empty() {
+            return;
+        }
+        if let Err(e) = write_batch(&self.pool, batch, partitions).await {
+            // A failed batch is dropped rather than retried: the audit log must
+            // never apply back-pressure to mutations.
+            error!(error = %e, records = batch.len(), "Operation audit: failed to write batch");
+        }
+        batch.clear();
+    }
+}
+
+/// Create the partitioned `tb_graphql_audit` table and its indexes.
+///
+/// # Errors
+///
+/// Returns [`AuditError`] if the DDL fails.
+pub async fn ensure_audit_table(pool: &Pool) -> Result<(), AuditError> {
+    let client = pool.get().await?;
+    client
+        .batch_execute(&format!(
+            "CREATE TABLE IF NOT EXISTS {OPERATION_AUDIT_TABLE} (
+                occurred_at    TIMESTAMPTZ NOT NULL,
+                operation_name TEXT,
+                mutation_name  TEXT NOT NULL,
+                user_id        TEXT,
+                tenant_id      TEXT,
+                variables_hash TEXT,
+                entity_ids     TEXT[] NOT NULL DEFAULT '{{}}',
+                latency_ms     BIGINT NOT NULL,
+                success        BOOLEAN NOT NULL
+            ) PARTITION BY RANGE (occurred_at);
+            CREATE INDEX IF NOT EXISTS idx_{OPERATION_AUDIT_TABLE}_tenant_time
+                ON {OPERATION_AUDIT_TABLE} (tenant_id, occurred_at DESC);
+            CREATE INDEX IF NOT EXISTS idx_{OPERATION_AUDIT_TABLE}_user_time
+                ON {OPERATION_AUDIT_TABLE} (user_id, occurred_at DESC);"
+        ))
+        .await?;
+    Ok(())
+}
+
+async fn write_batch(
+    pool: &Pool,
+    batch: &[OperationAuditRecord],
+    partitions: &mut HashSet<String>,
+) -> Result<(), AuditError> {
+    let mut client = pool.get().await?;
+
+    for record in batch {
+        let date = record.occurred_at.date_naive();
+        let name = partition_name(date.year(), date.month());
+        if !partitions.contains(&name) {
+            let (from, to) = month_bounds(date.year(), date.month());
+            client
+                .batch_execute(&format!(
+                    "CREATE TABLE IF NOT EXISTS {name} PARTITION OF {OPERATION_AUDIT_TABLE} \
+                     FOR VALUES FROM ('{from}') TO ('{to}')"
+                ))
+                .await?;
+            partitions.insert(name);
+        }
+    }
+
+    let tx = client.transaction().await?;
+    let stmt = tx
+        .prepare(&format!(
+            "INSERT INTO {OPERATION_AUDIT_TABLE} (occurred_at, operation_name, mutation_name, \
+             user_id, tenant_id, variables_hash, entity_ids, latency_ms, success) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"
+        ))
+        .await?;
+    for record in batch {
+        let latency_ms = i64::try_from(record.latency_ms).unwrap_or(i64::MAX);
+        tx.execute(
+            &stmt,
+            &[
+                &record.occurred_at,
+                &record.operation_name,
+                &record.mutation_name,
+                &record.user_id,
+                &record.tenant_id,
+                &record.variables_hash,
+                &record.entity_ids,
+                &latency_ms,
+                &record.success,
+            ],
+        )
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Drop every monthly partition that lies entirely before the retention cutoff.
+///
+/// Returns the names of the dropped partitions.
+///
+/// # Errors
+///
+/// Returns [`AuditError`] if listing or dropping partitions fails.
+pub async fn prune_expired_partitions(
+    pool: &Pool,
+    retention_days: u32,
+    now: DateTime<Utc>,
+) -> Result<Vec<String>, AuditError> {
+    let client = pool.get().await?;
+    let rows = client
+        .query(
+            "SELECT c.relname FROM pg_inherits i \
+             JOIN pg_class c ON c.oid = i.inhrelid \
+             JOIN pg_class p ON p.oid = i.inhparent \
+             WHERE p.relname = $1",
+            &[&OPERATION_AUDIT_TABLE],
+        )
+        .await?;
+    let names: Vec<String> = rows.iter().map(|row| row.get(0)).collect();
+    let cutoff = now.date_naive() - chrono::Days::new(u64::from(retention_days));
+
+    let expired = expired_partitions(&names, cutoff);
+    for name in &expired {
+        client.batch_execute(&format!("DROP TABLE IF EXISTS {name}")).await?;
+    }
+    Ok(expired)
+}
+
+/// Partition table name for a month: `tb_graphql_audit_y2026m03`.
+#[must_use]
+pub fn partition_name(year: i32, month: u32) -> String {
+    format!("{OPERATION_AUDIT_TABLE}_y{year:04}m{month:02}")
+}
+
+/// `[first day of month, first day of next month)`.
+fn month_bounds(year: i32, month: u32) -> (NaiveDate, NaiveDate) {
+    let from = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(NaiveDate::MIN);
+    let to = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .unwrap_or(NaiveDate::MAX);
+    (from, to)
+}
+
+/// Partitions whose whole month ends on or before `cutoff`.
+///
+/// Names that do not follow [`partition_name`] are never selected, so tables
+/// attached to the parent by hand are left alone.
+#[must_use]
+pub fn expired_partitions(names: &[String], cutoff: NaiveDate) -> Vec<String> {
+    let prefix = format!("{OPERATION_AUDIT_TABLE}_y");
+    names
+        .iter()
+        .filter(|name| {
+            let Some((year, month)) = name.strip_prefix(&prefix).and_then(|rest| {
+                let (year, month) = rest.split_once('m')?;
+                Some((year.parse::<i32>().ok()?, month.parse::<u32>().ok()?))
+            }) else {
+                return false;
+            };
+            if !(1..=12).contains(&month) {
+                return false;
+            }
+            month_bounds(year, month).1 <= cutoff
+        })
+        .cloned()
+        .collect()
+}
@@ -205,6 +205,68 @@ mod audit_tests {
     }
 }
 
+mod operation_audit_tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use crate::security::operation_audit::{
+        OperationAuditConfig, affected_entity_ids, expired_partitions, hash_variables,
+        partition_name,
+    };
+
+    #[test]
+    fn variables_hash_ignores_key_order() {
+        let a = hash_variables(Some(&json!({"id": 1, "input": {"b": true, "a": "x"}})));
+        let b = hash_variables(Some(&json!({"input": {"a": "x", "b": true}, "id": 1})));
+        assert_eq!(a, b);
+        assert_eq!(a.as_deref().map(str::len), Some(64));
+        assert_ne!(a, hash_variables(Some(&json!({"id": 2, "input": {"a": "x", "b": true}}))));
+    }
+
+    #[test]
+    fn empty_variables_have_no_hash() {
+        assert_eq!(hash_variables(None), None);
+        assert_eq!(hash_variables(Some(&json!(null))), None);
+        assert_eq!(hash_variables(Some(&json!({}))), None);
+    }
+
+    #[test]
+    fn entity_ids_read_from_object_and_list_roots() {
+        let response = json!({"data": {
+            "createUser": {"id": "u1", "name": "a"},
+            "deletePosts": [{"id": 7}, {"id": 8}, {"title": "no id"}],
+        }});
+        let mut ids = affected_entity_ids(&response);
+        ids.sort();
+        assert_eq!(ids, vec!["7", "8", "u1"]);
+        assert!(affected_entity_ids(&json!({"errors": []})).is_empty());
+    }
+
+    #[test]
+    fn only_whole_months_before_cutoff_expire() {
+        let names = vec![
+            partition_name(2026, 6),
+            partition_name(2026, 7),
+            partition_name(2026, 8),
+            "tb_graphql_audit_manual".to_string(),
+        ];
+        assert_eq!(partition_name(2026, 7), "tb_graphql_audit_y2026m07");
+        // July ends on 2026-08-01: expired at that cutoff, August is not.
+        let cutoff = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap_or_default();
+        assert_eq!(expired_partitions(&names, cutoff), names[..2].to_vec());
+    }
+
+    #[test]
+    fn config_rejects_out_of_range_sample_rate() {
+        assert!(OperationAuditConfig::default().validate().is_ok());
+        let config = OperationAuditConfig {
+            sample_rate: 1.5,
+            ..OperationAuditConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
+
 #[cfg(feature = "audit-syslog")]
 mod audit_export_syslog_tests {
     #![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
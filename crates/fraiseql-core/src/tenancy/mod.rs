@@ -6,7 +6,10 @@
 //!
 //! Tenants are isolated at the data level:
 //! - Each tenant has a unique ID
-//! - Queries automatically include tenant filter (WHERE `tenant_id` = $1)
+//! - Queries automatically include tenant filter (WHERE `tenant_id` = $1): under row isolation, the
+//!   executor scopes every read of a type marked `@tenant_id` (see
+//!   [`TypeDefinition::tenant_column`](crate::schema::TypeDefinition::tenant_column)) to
+//!   [`TenantContext::from_security_context`]
 //! - JWT claims carry `tenant_id` for authorization
 //! - Cross-tenant access is denied
 //!
@@ -34,6 +37,8 @@ use std::collections::HashMap;
 use chrono::Utc;
 use serde_json::Value as JsonValue;
 
+use crate::security::SecurityContext;
+
 /// Tenant context for row-level security and data isolation.
 ///
 /// Represents a single tenant in a multi-tenant system.
@@ -149,6 +154,21 @@ impl TenantContext {
         Ok(Self::new(tenant_id))
     }
 
+    /// Create a `TenantContext` from an authenticated request's security context.
+    ///
+    /// `claim` is the schema's tenant claim (`[fraiseql.tenancy] tenant_claim`).
+    /// `"tenant_id"` and `"org_id"` read [`SecurityContext::tenant_id`]; any
+    /// other claim is looked up in the token attributes and must be a string.
+    /// Returns `None` when the request carries no tenant.
+    #[must_use]
+    pub fn from_security_context(ctx: &SecurityContext, claim: &str) -> Option<Self> {
+        let id = match claim {
+            "tenant_id" | "org_id" => ctx.tenant_id.as_ref().map(|t| t.as_str().to_string()),
+            other => ctx.attributes.get(other).and_then(JsonValue::as_str).map(str::to_string),
+        }?;
+        (!id.is_empty()).then(|| Self::new(id))
+    }
+
     /// Generate a WHERE clause for tenant filtering.
     ///
     /// Returns a WHERE clause that restricts data to this tenant.
@@ -266,6 +266,61 @@ fn test_from_jwt_claims_uuid_tenant() {
     assert_eq!(tenant.id(), uuid_tenant);
 }
 
+// ============================================================================
+// Test 9b: Tenant From Security Context
+// ============================================================================
+
+fn security_context(
+    tenant_id: Option<&str>,
+    attributes: &[(&str, serde_json::Value)],
+) -> crate::security::SecurityContext {
+    crate::security::SecurityContext {
+        user_id:          "user-42".into(),
+        roles:            vec![],
+        tenant_id:        tenant_id.map(Into::into),
+        scopes:           vec![],
+        attributes:       attributes.iter().map(|(k, v)| ((*k).to_string(), v.clone())).collect(),
+        request_id:       "req-001".to_string(),
+        ip_address:       None,
+        expires_at:       chrono::Utc::now() + chrono::Duration::hours(1),
+        authenticated_at: chrono::Utc::now(),
+        issuer:           None,
+        audience:         None,
+        email:            None,
+        display_name:     None,
+    }
+}
+
+/// Test that the default claim reads the context's tenant
+#[test]
+fn test_from_security_context_default_claim() {
+    let ctx = security_context(Some("acme-corp"), &[]);
+    let tenant = TenantContext::from_security_context(&ctx, "tenant_id").unwrap();
+    assert_eq!(tenant.id(), "acme-corp");
+    assert!(TenantContext::from_security_context(&ctx, "org_id").is_some());
+}
+
+/// Test that a custom claim is read from the token attributes
+#[test]
+fn test_from_security_context_custom_claim() {
+    let ctx = security_context(None, &[("workspace", json!("ws-7")), ("seats", json!(12))]);
+    let tenant = TenantContext::from_security_context(&ctx, "workspace").unwrap();
+    assert_eq!(tenant.id(), "ws-7");
+    assert!(
+        TenantContext::from_security_context(&ctx, "seats").is_none(),
+        "non-string claims do not identify a tenant"
+    );
+}
+
+/// Test that a request without a tenant yields no context
+#[test]
+fn test_from_security_context_missing_tenant() {
+    let ctx = security_context(None, &[]);
+    assert!(TenantContext::from_security_context(&ctx, "tenant_id").is_none());
+    let ctx = security_context(Some(""), &[]);
+    assert!(TenantContext::from_security_context(&ctx, "tenant_id").is_none());
+}
+
 // ============================================================================
 // Test 10: Tenant Where Clauses
 // ============================================================================
@@ -33,26 +33,34 @@ pub use fraiseql_db::utils::{set_runtime_acronyms, to_snake_case};
 /// ```
 #[must_use]
 pub fn to_camel_case(s: &str) -> String {
+    let bytes = s.as_bytes();
     // If no underscores, assume already camelCase
-    if !s.contains('_') {
+    let Some(first) = memchr::memchr(b'_', bytes) else {
         return s.to_string();
+    };
+
+    // Copy the runs between underscores wholesale (memchr finds each `_` with a
+    // vectorised scan), upper-casing the byte that follows each one. Splitting on
+    // an ASCII byte keeps every run valid UTF-8, and `to_ascii_uppercase` leaves
+    // the lead byte of a multi-byte character untouched, as it would the `char`.
+    let mut result = Vec::with_capacity(bytes.len());
+    result.extend_from_slice(&bytes[..first]);
+    let mut start = first + 1;
+    for underscore in memchr::memchr_iter(b'_', &bytes[start..]).map(|i| i + start) {
+        push_capitalized(&mut result, &bytes[start..underscore]);
+        start = underscore + 1;
     }
+    push_capitalized(&mut result, &bytes[start..]);
 
-    let mut result = String::with_capacity(s.len());
-    let mut capitalize_next = false;
+    String::from_utf8(result).unwrap_or_else(|_| s.to_string())
+}
 
-    for c in s.chars() {
-        if c == '_' {
-            capitalize_next = true;
-        } else if capitalize_next {
-            result.push(c.to_ascii_uppercase());
-            capitalize_next = false;
-        } else {
-            result.push(c);
-        }
+/// Append `run` to `out` with its first byte ASCII-uppercased.
+fn push_capitalized(out: &mut Vec<u8>, run: &[u8]) {
+    if let Some((head, tail)) = run.split_first() {
+        out.push(head.to_ascii_uppercase());
+        out.extend_from_slice(tail);
     }
-
-    result
 }
 
 /// Normalize a field path for database access.
@@ -860,7 +860,106 @@ pub static OPERATOR_REGISTRY: LazyLock<HashMap<&'static str, OperatorInfo>> = La
         },
     );
 
+    m.insert(
+        "near",
+        OperatorInfo {
+            name:           "near",
+            sql_op:         "ST_DWithin",
+            category:       OperatorCategory::Spatial,
+            requires_array: false,
+            jsonb_operator: false,
+        },
+    );
+
+    m.insert(
+        "within_polygon",
+        OperatorInfo {
+            name:           "within_polygon",
+            sql_op:         "ST_Within",
+            category:       OperatorCategory::Spatial,
+            requires_array: false,
+            jsonb_operator: false,
+        },
+    );
+
+    m.insert(
+        "within_bounding_box",
+        OperatorInfo {
+            name:           "within_bounding_box",
+            sql_op:         "ST_MakeEnvelope",
+            category:       OperatorCategory::Spatial,
+            requires_array: false,
+            jsonb_operator: false,
+        },
+    );
+
     // ========== JSONB ADVANCED OPERATORS ==========
+    m.insert(
+        "meta_contains",
+        OperatorInfo {
+            name:           "meta_contains",
+            sql_op:         "@>",
+            category:       OperatorCategory::Containment,
+            requires_array: false,
+            jsonb_operator: true,
+        },
+    );
+
+    m.insert(
+        "meta_contained_by",
+        OperatorInfo {
+            name:           "meta_contained_by",
+            sql_op:         "<@",
+            category:       OperatorCategory::Containment,
+            requires_array: false,
+            jsonb_operator: true,
+        },
+    );
+
+    m.insert(
+        "has_key",
+        OperatorInfo {
+            name:           "has_key",
+            sql_op:         "?",
+            category:       OperatorCategory::Containment,
+            requires_array: false,
+            jsonb_operator: true,
+        },
+    );
+
+    m.insert(
+        "has_any_keys",
+        OperatorInfo {
+            name:           "has_any_keys",
+            sql_op:         "?|",
+            category:       OperatorCategory::Containment,
+            requires_array: true,
+            jsonb_operator: true,
+        },
+    );
+
+    m.insert(
+        "has_all_keys",
+        OperatorInfo {
+            name:           "has_all_keys",
+            sql_op:         "?&",
+            category:       OperatorCategory::Containment,
+            requires_array: true,
+            jsonb_operator: true,
+        },
+    );
+
+    m.insert(
+        "json_path",
+        OperatorInfo {
+            name:           "json_path",
+            sql_op:         "jsonb_path_exists",
+            category:       OperatorCategory::Containment,
+            requires_array: false,
+            jsonb_operator: true,
+        },
+    );
+
     m.insert(
         "strictly_contains",
         OperatorInfo {
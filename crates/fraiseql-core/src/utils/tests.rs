@@ -128,6 +128,13 @@ mod casing_tests {
         assert_eq!(to_camel_case("first_name_"), "firstName");
     }
 
+    #[test]
+    fn test_leading_underscore_and_non_ascii() {
+        assert_eq!(to_camel_case("_private_key"), "PrivateKey");
+        assert_eq!(to_camel_case("café_menu"), "caféMenu");
+        assert_eq!(to_camel_case("prix_été"), "prixété");
+    }
+
     #[test]
     fn test_normalize_field_path_simple() {
         assert_eq!(normalize_field_path("userId"), "user_id");
@@ -35,6 +35,63 @@ pub struct CustomTypeDef {
 
     /// Base type for type aliases (e.g., "String" for Email scalar).
     pub base_type: Option<String>,
+
+    /// SQL type values of this scalar are compared as in generated WHERE
+    /// clauses (e.g. `"numeric(12,2)"` for `Money`, `"char(2)"` for
+    /// `CountryCode`). `None` keeps the untyped JSONB comparison.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_type: Option<String>,
+
+    /// JSON representation of the scalar on the wire; inputs of any other
+    /// JSON kind are rejected. `None` accepts any representation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serialize_as: Option<ScalarSerialization>,
+}
+
+/// JSON representation a custom scalar is serialized as.
+///
+/// A serialization hint for clients (`Money` as a `"12.50"` string rather than
+/// a lossy float) that the server also enforces on input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ScalarSerialization {
+    /// A JSON string.
+    String,
+    /// Any JSON number.
+    Number,
+    /// A JSON number without a fractional part.
+    Integer,
+    /// A JSON boolean.
+    Boolean,
+    /// A JSON object.
+    Object,
+}
+
+impl ScalarSerialization {
+    /// Name used in error messages and the schema (`"string"`, `"number"`, ...).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+        }
+    }
+
+    /// Whether `value` uses this representation.
+    #[must_use]
+    pub fn accepts(self, value: &serde_json::Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Boolean => value.is_boolean(),
+            Self::Object => value.is_object(),
+        }
+    }
 }
 
 impl CustomTypeDef {
@@ -48,6 +105,8 @@ impl CustomTypeDef {
             validation_rules: Vec::new(),
             elo_expression: None,
             base_type: None,
+            sql_type: None,
+            serialize_as: None,
         }
     }
 }
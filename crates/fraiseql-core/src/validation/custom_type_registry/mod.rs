@@ -29,6 +29,8 @@
 //!     validation_rules: vec![],
 //!     elo_expression: Some("matches(value, /^[a-z0-9._%+-]+@[a-z0-9.-]+\\.[a-z]{2,}$/)".to_string()),
 //!     base_type: None,
+//!     sql_type: None,
+//!     serialize_as: None,
 //! };
 //! registry.register("Email".to_string(), email_def).unwrap();
 //! assert!(registry.exists("Email"));
@@ -37,7 +39,7 @@
 mod config;
 mod registry;
 
-pub use config::{CustomTypeDef, CustomTypeRegistryConfig};
+pub use config::{CustomTypeDef, CustomTypeRegistryConfig, ScalarSerialization};
 pub use registry::CustomTypeRegistry;
 
 #[cfg(test)]
@@ -3,6 +3,8 @@ use std::{
     sync::{Arc, RwLock},
 };
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use super::config::{CustomTypeDef, CustomTypeRegistryConfig};
 use crate::{
     error::{FraiseQLError, Result},
@@ -30,6 +32,30 @@ impl Default for CustomTypeRegistry {
     }
 }
 
+/// Serialized as a list of definitions sorted by name, so compiled schemas are
+/// byte-for-byte reproducible.
+impl Serialize for CustomTypeRegistry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut defs: Vec<CustomTypeDef> =
+            self.list_all().into_iter().map(|(_, def)| def).collect();
+        defs.sort_by(|a, b| a.name.cmp(&b.name));
+        defs.serialize(serializer)
+    }
+}
+
+/// Deserialized from a list of definitions into a registry with the default
+/// configuration. Duplicate or invalid definitions are rejected.
+impl<'de> Deserialize<'de> for CustomTypeRegistry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let defs = Vec::<CustomTypeDef>::deserialize(deserializer)?;
+        let registry = Self::default();
+        for def in defs {
+            registry.register(def.name.clone(), def).map_err(serde::de::Error::custom)?;
+        }
+        Ok(registry)
+    }
+}
+
 impl CustomTypeRegistry {
     /// Create a new custom type registry.
     ///
@@ -65,6 +91,7 @@ impl CustomTypeRegistry {
     /// Returns error if:
     /// - Scalar name already exists
     /// - Max scalars limit exceeded
+    /// - `sql_type` is not a plain SQL type name
     ///
     /// # Example
     ///
@@ -74,6 +101,18 @@ impl CustomTypeRegistry {
     /// registry.register("Email".to_string(), CustomTypeDef::new("Email".to_string())).unwrap();
     /// ```
     pub fn register(&self, name: String, def: CustomTypeDef) -> Result<()> {
+        if let Some(sql_type) = &def.sql_type {
+            if !crate::db::is_safe_sql_type_name(sql_type) {
+                return Err(FraiseQLError::Validation {
+                    message: format!(
+                        "Custom scalar '{}' has invalid sql_type '{}'",
+                        name, sql_type
+                    ),
+                    path:    Some(format!("custom_scalars.{}", name)),
+                });
+            }
+        }
+
         let mut types = self.types.write().map_err(|_| FraiseQLError::Validation {
             message: "Failed to acquire write lock on custom type registry".to_string(),
             path:    Some("custom_scalars".to_string()),
@@ -154,6 +193,20 @@ impl CustomTypeRegistry {
             .contains_key(name)
     }
 
+    /// Return `true` if no custom scalars are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Return the SQL type a custom scalar maps to, if it declares one.
+    ///
+    /// Unregistered names and scalars without a `sql_type` return `None`.
+    #[must_use]
+    pub fn sql_type(&self, name: &str) -> Option<String> {
+        self.get(name).and_then(|def| def.sql_type)
+    }
+
     /// Remove a custom scalar type definition.
     ///
     /// Returns the removed definition if it existed.
@@ -337,8 +390,9 @@ impl CustomTypeRegistry {
     /// Validate a value against a custom scalar type's rules and ELO expression.
     ///
     /// Executes in order:
-    /// 1. All validation rules from the definition
-    /// 2. ELO expression (if present)
+    /// 1. Serialization shape check (if `serialize_as` is set)
+    /// 2. All validation rules from the definition
+    /// 3. ELO expression (if present)
     ///
     /// # Arguments
     ///
@@ -394,6 +448,20 @@ impl CustomTypeRegistry {
             path:    Some(format!("custom_scalars.{}", type_name)),
         })?;
 
+        // Check the JSON shape declared by `serialize_as`
+        if let Some(kind) = def.serialize_as {
+            if !value.is_null() && !kind.accepts(value) {
+                return Err(FraiseQLError::Validation {
+                    message: format!(
+                        "Custom scalar '{}' expects a {} value",
+                        type_name,
+                        kind.as_str()
+                    ),
+                    path:    Some(format!("custom_scalars.{}", type_name)),
+                });
+            }
+        }
+
         // Execute validation rules
         self.validate_rules(type_name, &def.validation_rules, value)?;
 
@@ -26,6 +26,8 @@ fn test_custom_type_def_with_all_fields() {
         validation_rules: vec![],
         elo_expression:   Some("matches(value, /^[0-9-]{10,17}$/)".to_string()),
         base_type:        Some("String".to_string()),
+        sql_type:         None,
+        serialize_as:     None,
     };
 
     assert_eq!(def.name, "ISBN");
@@ -757,3 +759,75 @@ fn registry_lock_recovery_code_paths_compile_and_return_correct_values() {
     registry.clear();
     assert_eq!(registry.count(), 0);
 }
+
+#[test]
+fn test_registry_rejects_unsafe_sql_type() {
+    let registry = CustomTypeRegistry::new(CustomTypeRegistryConfig::default());
+    let mut def = CustomTypeDef::new("Money".to_string());
+    def.sql_type = Some("numeric; DROP TABLE users".to_string());
+
+    let result = registry.register("Money".to_string(), def);
+    assert!(
+        matches!(result, Err(FraiseQLError::Validation { ref message, .. }) if message.contains("invalid sql_type")),
+        "expected sql_type rejection, got: {result:?}"
+    );
+}
+
+#[test]
+fn test_registry_sql_type_lookup() {
+    let registry = CustomTypeRegistry::new(CustomTypeRegistryConfig::default());
+    let mut def = CustomTypeDef::new("Money".to_string());
+    def.sql_type = Some("numeric(12,2)".to_string());
+    registry.register("Money".to_string(), def).unwrap();
+    registry
+        .register("Email".to_string(), CustomTypeDef::new("Email".to_string()))
+        .unwrap();
+
+    assert_eq!(registry.sql_type("Money").as_deref(), Some("numeric(12,2)"));
+    assert_eq!(registry.sql_type("Email"), None);
+    assert_eq!(registry.sql_type("Unknown"), None);
+}
+
+#[test]
+fn test_validate_serialize_as_shape() {
+    let registry = CustomTypeRegistry::new(CustomTypeRegistryConfig::default());
+    let mut def = CustomTypeDef::new("Money".to_string());
+    def.serialize_as = Some(ScalarSerialization::String);
+    registry.register("Money".to_string(), def).unwrap();
+
+    registry.validate("Money", &serde_json::json!("12.50")).unwrap();
+    registry.validate("Money", &serde_json::Value::Null).unwrap();
+    let result = registry.validate("Money", &serde_json::json!(12.5));
+    assert!(
+        matches!(result, Err(FraiseQLError::Validation { ref message, .. }) if message.contains("expects a string value")),
+        "expected serialization mismatch, got: {result:?}"
+    );
+}
+
+#[test]
+fn test_registry_serde_roundtrip() {
+    let registry = CustomTypeRegistry::new(CustomTypeRegistryConfig::default());
+    let mut money = CustomTypeDef::new("Money".to_string());
+    money.sql_type = Some("numeric(12,2)".to_string());
+    money.serialize_as = Some(ScalarSerialization::String);
+    registry.register("Money".to_string(), money.clone()).unwrap();
+    registry
+        .register("Email".to_string(), CustomTypeDef::new("Email".to_string()))
+        .unwrap();
+
+    let json = serde_json::to_value(&registry).unwrap();
+    assert_eq!(json[0]["name"], "Email");
+    assert_eq!(json[1]["sql_type"], "numeric(12,2)");
+    assert_eq!(json[1]["serialize_as"], "string");
+
+    let restored: CustomTypeRegistry = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.count(), 2);
+    assert_eq!(restored.get("Money"), Some(money));
+}
+
+#[test]
+fn test_registry_deserialize_rejects_duplicates() {
+    let json = serde_json::json!([{"name": "Email"}, {"name": "Email"}]);
+    let result = serde_json::from_value::<CustomTypeRegistry>(json);
+    assert!(result.is_err(), "duplicate scalar names must be rejected");
+}
@@ -41,7 +41,9 @@ pub use composite::{
 };
 pub use cross_field::{ComparisonOperator, validate_cross_field_comparison};
 pub use custom_scalar::CustomScalar;
-pub use custom_type_registry::{CustomTypeDef, CustomTypeRegistry, CustomTypeRegistryConfig};
+pub use custom_type_registry::{
+    CustomTypeDef, CustomTypeRegistry, CustomTypeRegistryConfig, ScalarSerialization,
+};
 pub use date_validators::{
     validate_date_range, validate_max_age, validate_max_date, validate_max_days_in_future,
     validate_max_days_in_past, validate_min_age, validate_min_date,
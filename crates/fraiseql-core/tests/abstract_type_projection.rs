@@ -0,0 +1,175 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+#![allow(missing_docs)]
+
+//! Queries returning an interface or union backed by a single view with a JSONB
+//! discriminator column.
+//!
+//! The abstract type declares which JSONB key names each row's concrete type.
+//! The query runner then reads full rows (no SQL projection hint) and projects
+//! each one at that concrete type with [`project_abstract_results`]:
+//! `__typename` resolves per row, and an inline `... on Dog` fragment only
+//! contributes fields to `Dog` rows.
+
+use fraiseql_core::{
+    db::types::JsonbValue, error::FraiseQLError, graphql::FieldSelection,
+    runtime::project_abstract_results, schema::CompiledSchema,
+};
+use serde_json::json;
+
+/// A leaf field selection (no sub-selection, no alias).
+fn field(name: &str) -> FieldSelection {
+    FieldSelection {
+        name:          name.to_string(),
+        alias:         None,
+        arguments:     vec![],
+        nested_fields: vec![],
+        directives:    vec![],
+    }
+}
+
+/// An inline fragment `... on <type_name> { sub }`.
+fn on(type_name: &str, sub: Vec<FieldSelection>) -> FieldSelection {
+    FieldSelection {
+        name:          format!("...on {type_name}"),
+        alias:         None,
+        arguments:     vec![],
+        nested_fields: sub,
+        directives:    vec![],
+    }
+}
+
+/// Schema: a `Pet` union and an `Animal` interface over `Dog` and `Cat`, both
+/// discriminated by the stored `kind` key of `v_pet`.
+fn schema() -> CompiledSchema {
+    serde_json::from_value(json!({
+        "types": [
+            {
+                "name": "Dog",
+                "sql_source": "v_pet",
+                "implements": ["Animal"],
+                "fields": [
+                    { "name": "id", "field_type": "ID" },
+                    { "name": "name", "field_type": "String" },
+                    { "name": "barkVolume", "field_type": "Int" }
+                ]
+            },
+            {
+                "name": "Cat",
+                "sql_source": "v_pet",
+                "implements": ["Animal"],
+                "fields": [
+                    { "name": "id", "field_type": "ID" },
+                    { "name": "name", "field_type": "String" },
+                    { "name": "livesLeft", "field_type": "Int" }
+                ]
+            }
+        ],
+        "interfaces": [
+            {
+                "name": "Animal",
+                "fields": [{ "name": "name", "field_type": "String" }],
+                "discriminator": "kind"
+            }
+        ],
+        "unions": [
+            { "name": "Pet", "member_types": ["Dog", "Cat"], "discriminator": "kind" }
+        ]
+    }))
+    .unwrap()
+}
+
+fn rows() -> Vec<JsonbValue> {
+    vec![
+        JsonbValue::new(json!({ "id": "1", "kind": "Dog", "name": "Rex", "bark_volume": 9 })),
+        JsonbValue::new(json!({ "id": "2", "kind": "Cat", "name": "Tom", "lives_left": 7 })),
+    ]
+}
+
+#[test]
+fn union_rows_project_per_variant_with_inline_fragments() {
+    let selections = vec![
+        field("__typename"),
+        on("Dog", vec![field("name"), field("barkVolume")]),
+        on("Cat", vec![field("livesLeft")]),
+    ];
+
+    let out =
+        project_abstract_results(&rows(), "Pet", "kind", &selections, true, &schema()).unwrap();
+
+    assert_eq!(
+        out,
+        json!([
+            { "__typename": "Dog", "name": "Rex", "barkVolume": 9 },
+            { "__typename": "Cat", "livesLeft": 7 }
+        ])
+    );
+}
+
+#[test]
+fn interface_rows_keep_shared_fields_and_resolve_typename() {
+    let selections = vec![
+        field("name"),
+        field("__typename"),
+        on("Cat", vec![field("livesLeft")]),
+    ];
+
+    let out =
+        project_abstract_results(&rows(), "Animal", "kind", &selections, true, &schema()).unwrap();
+
+    assert_eq!(
+        out,
+        json!([
+            { "name": "Rex", "__typename": "Dog" },
+            { "name": "Tom", "__typename": "Cat", "livesLeft": 7 }
+        ])
+    );
+}
+
+#[test]
+fn single_result_projects_first_row_or_null() {
+    let selections = vec![field("__typename")];
+    let schema = schema();
+
+    let one =
+        project_abstract_results(&rows()[1..], "Pet", "kind", &selections, false, &schema).unwrap();
+    assert_eq!(one, json!({ "__typename": "Cat" }));
+
+    let none = project_abstract_results(&[], "Pet", "kind", &selections, false, &schema).unwrap();
+    assert_eq!(none, json!(null));
+}
+
+#[test]
+fn discriminator_outside_possible_types_is_rejected() {
+    let rows = vec![JsonbValue::new(
+        json!({ "id": "3", "kind": "Parrot", "name": "Polly" }),
+    )];
+
+    let err = project_abstract_results(&rows, "Pet", "kind", &[field("name")], true, &schema())
+        .unwrap_err();
+
+    assert!(matches!(err, FraiseQLError::Internal { .. }), "got {err:?}");
+    assert!(err.to_string().contains("Parrot"), "got {err}");
+}
+
+#[test]
+fn missing_discriminator_is_rejected() {
+    let rows = vec![JsonbValue::new(json!({ "id": "4", "name": "Nameless" }))];
+
+    let err = project_abstract_results(&rows, "Animal", "kind", &[field("name")], true, &schema())
+        .unwrap_err();
+
+    assert!(matches!(err, FraiseQLError::Internal { .. }), "got {err:?}");
+}
+
+#[test]
+fn fragment_on_the_union_itself_applies_to_every_member() {
+    let selections = vec![
+        on("Pet", vec![field("__typename")]),
+        on("Dog", vec![field("name")]),
+    ];
+
+    let out =
+        project_abstract_results(&rows(), "Pet", "kind", &selections, true, &schema()).unwrap();
+
+    assert_eq!(out, json!([{ "__typename": "Dog", "name": "Rex" }, { "__typename": "Cat" }]));
+}
@@ -37,8 +37,8 @@ use fraiseql_core::{
 /// Simulates Python decorator output
 fn create_user_type_with_scopes() -> TypeDefinition {
     TypeDefinition {
-        name:                "User".into(),
-        fields:              vec![
+        name:                  "User".into(),
+        fields:                vec![
             // Public fields (no scope required)
             FieldDefinition {
                 name:           "id".into(),
@@ -54,6 +54,7 @@ fn create_user_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "name".into(),
@@ -69,6 +70,7 @@ fn create_user_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             // Protected fields
             FieldDefinition {
@@ -85,6 +87,7 @@ fn create_user_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "phone".into(),
@@ -100,6 +103,7 @@ fn create_user_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             // Admin-only fields
             FieldDefinition {
@@ -116,6 +120,7 @@ fn create_user_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "ssn".into(),
@@ -131,20 +136,27 @@ fn create_user_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
         ],
-        description:         Some("User type with field-level scopes".to_string()),
-        sql_source:          "users".into(),
-        jsonb_column:        String::new(),
-        sql_projection_hint: None,
-        implements:          vec![],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
+        description:           Some("User type with field-level scopes".to_string()),
+        sql_source:            "users".into(),
+        jsonb_column:          String::new(),
+        sql_projection_hint:   None,
+        implements:            vec![],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     }
 }
 
@@ -28,8 +28,8 @@ use fraiseql_core::{
 /// Helper to create a realistic Post type with mixed public/protected fields
 fn create_post_type_with_scopes() -> TypeDefinition {
     TypeDefinition {
-        name:                "Post".into(),
-        fields:              vec![
+        name:                  "Post".into(),
+        fields:                vec![
             // Public fields
             FieldDefinition {
                 name:           "id".into(),
@@ -45,6 +45,7 @@ fn create_post_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "title".into(),
@@ -60,6 +61,7 @@ fn create_post_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             // Protected fields
             FieldDefinition {
@@ -76,6 +78,7 @@ fn create_post_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "draft".into(),
@@ -91,6 +94,7 @@ fn create_post_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             // Admin-only fields
             FieldDefinition {
@@ -107,20 +111,27 @@ fn create_post_type_with_scopes() -> TypeDefinition {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
         ],
-        description:         Some("Post type with field-level scopes".to_string()),
-        sql_source:          "posts".into(),
-        jsonb_column:        String::new(),
-        sql_projection_hint: None,
-        implements:          vec![],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
+        description:           Some("Post type with field-level scopes".to_string()),
+        sql_source:            "posts".into(),
+        jsonb_column:          String::new(),
+        sql_projection_hint:   None,
+        implements:            vec![],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     }
 }
 
@@ -309,6 +320,7 @@ fn test_executor_projection_fields_filtered_by_scope() {
 fn test_executor_runtime_config_with_field_filter() {
     let config = RuntimeConfig {
         cache_query_plans:    true,
+        plan_cache_capacity:  fraiseql_core::runtime::plan_cache::DEFAULT_PLAN_CACHE_CAPACITY,
         max_query_depth:      10,
         max_query_complexity: 1000,
         max_page_size:        Some(1000),
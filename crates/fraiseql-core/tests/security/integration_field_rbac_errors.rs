@@ -30,8 +30,8 @@ use fraiseql_core::{
 /// Helper to create a schema with various access levels
 fn create_schema_with_mixed_fields() -> CompiledSchema {
     let user_type = TypeDefinition {
-        name:                "User".into(),
-        fields:              vec![
+        name:                  "User".into(),
+        fields:                vec![
             FieldDefinition {
                 name:           "id".into(),
                 field_type:     FieldType::Int,
@@ -46,6 +46,7 @@ fn create_schema_with_mixed_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "publicInfo".into(),
@@ -61,6 +62,7 @@ fn create_schema_with_mixed_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "email".into(),
@@ -76,6 +78,7 @@ fn create_schema_with_mixed_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "phone".into(),
@@ -91,6 +94,7 @@ fn create_schema_with_mixed_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "ssn".into(),
@@ -106,6 +110,7 @@ fn create_schema_with_mixed_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "bankAccount".into(),
@@ -121,20 +126,27 @@ fn create_schema_with_mixed_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
         ],
-        description:         Some("User with mixed access levels".to_string()),
-        sql_source:          "users".into(),
-        jsonb_column:        String::new(),
-        sql_projection_hint: None,
-        implements:          vec![],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
+        description:           Some("User with mixed access levels".to_string()),
+        sql_source:            "users".into(),
+        jsonb_column:          String::new(),
+        sql_projection_hint:   None,
+        implements:            vec![],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     };
 
     let mut security_config = SecurityConfig::new();
@@ -18,8 +18,8 @@ use fraiseql_core::schema::{
 /// Helper to create a test schema with scoped fields
 fn create_schema_with_scoped_fields() -> CompiledSchema {
     let user_type = TypeDefinition {
-        name:                "User".into(),
-        fields:              vec![
+        name:                  "User".into(),
+        fields:                vec![
             FieldDefinition {
                 name:           "id".into(),
                 field_type:     FieldType::Int,
@@ -34,6 +34,7 @@ fn create_schema_with_scoped_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "name".into(),
@@ -49,6 +50,7 @@ fn create_schema_with_scoped_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "email".into(),
@@ -64,6 +66,7 @@ fn create_schema_with_scoped_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
             FieldDefinition {
                 name:           "password_hash".into(),
@@ -79,20 +82,27 @@ fn create_schema_with_scoped_fields() -> CompiledSchema {
                 authorize:      false,
                 encryption:     None,
                 hierarchy:      None,
+                sql_expression: None,
             },
         ],
-        description:         None,
-        sql_source:          "users".into(),
-        jsonb_column:        String::new(),
-        sql_projection_hint: None,
-        implements:          vec![],
-        requires_role:       None,
-        is_error:            false,
-        relay:               false,
-        internal:            false,
-        embedded:            false,
-        relationships:       vec![],
-        subscription_policy: None,
+        description:           None,
+        sql_source:            "users".into(),
+        jsonb_column:          String::new(),
+        sql_projection_hint:   None,
+        implements:            vec![],
+        requires_role:         None,
+        is_error:              false,
+        relay:                 false,
+        internal:              false,
+        embedded:              false,
+        relationships:         vec![],
+        subscription_policy:   None,
+        tenant_column:         None,
+        default_order_by:      Vec::new(),
+        search:                None,
+        order_by_aggregates:   Vec::new(),
+        soft_delete_field:     None,
+        include_deleted_scope: None,
     };
 
     let mut security_config = SecurityConfig::new();
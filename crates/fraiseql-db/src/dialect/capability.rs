@@ -92,11 +92,29 @@ impl DatabaseType {
     /// ```
     #[must_use]
     pub fn json_field_expr(self, key: &str) -> String {
+        self.json_column_field_expr("data", key)
+    }
+
+    /// Like [`json_field_expr`](Self::json_field_expr), reading from `column`
+    /// (e.g. a qualified `"v_user".data`) instead of the bare `data` column.
+    #[must_use]
+    pub fn json_column_field_expr(self, column: &str, key: &str) -> String {
+        match self {
+            Self::PostgreSQL => format!("{column}->>'{key}'"),
+            Self::MySQL => format!("JSON_UNQUOTE(JSON_EXTRACT({column}, '$.{key}'))"),
+            Self::SQLite => format!("json_extract({column}, '$.{key}')"),
+            Self::SQLServer => format!("JSON_VALUE({column}, '$.{key}')"),
+        }
+    }
+
+    /// Quote a (possibly schema-qualified) identifier for this database.
+    #[must_use]
+    pub fn quote_identifier(self, identifier: &str) -> String {
         match self {
-            Self::PostgreSQL => format!("data->>'{key}'"),
-            Self::MySQL => format!("JSON_UNQUOTE(JSON_EXTRACT(data, '$.{key}'))"),
-            Self::SQLite => format!("json_extract(data, '$.{key}')"),
-            Self::SQLServer => format!("JSON_VALUE(data, '$.{key}')"),
+            Self::PostgreSQL => crate::identifier::quote_postgres_identifier(identifier),
+            Self::MySQL => crate::identifier::quote_mysql_identifier(identifier),
+            Self::SQLite => crate::identifier::quote_sqlite_identifier(identifier),
+            Self::SQLServer => crate::identifier::quote_sqlserver_identifier(identifier),
         }
     }
 
@@ -3,6 +3,7 @@
 use std::{borrow::Cow, fmt::Write};
 
 use super::trait_def::{RowViewColumnType, SqlDialect, UnsupportedOperator};
+use crate::geo::{GeoBackend, GeoFilter};
 
 /// PostgreSQL dialect for [`GenericWhereGenerator`].
 ///
@@ -68,24 +69,54 @@ impl SqlDialect for PostgresDialect {
         }
     }
 
+    fn cast_to_type<'a>(&self, expr: &'a str, sql_type: &str) -> Cow<'a, str> {
+        Cow::Owned(format!("({expr})::{sql_type}"))
+    }
+
     fn ilike_sql(&self, lhs: &str, rhs: &str) -> String {
         format!("{lhs} ILIKE {rhs}")
     }
 
     fn json_array_length(&self, expr: &str) -> String {
-        format!("jsonb_array_length({expr}::jsonb)")
+        format!("jsonb_array_length(({expr})::jsonb)")
     }
 
     fn array_contains_sql(&self, lhs: &str, rhs: &str) -> Result<String, UnsupportedOperator> {
-        Ok(format!("{lhs}::jsonb @> {rhs}::jsonb"))
+        Ok(format!("({lhs})::jsonb @> {rhs}::jsonb"))
     }
 
     fn array_contained_by_sql(&self, lhs: &str, rhs: &str) -> Result<String, UnsupportedOperator> {
-        Ok(format!("{lhs}::jsonb <@ {rhs}::jsonb"))
+        Ok(format!("({lhs})::jsonb <@ {rhs}::jsonb"))
     }
 
     fn array_overlaps_sql(&self, lhs: &str, rhs: &str) -> Result<String, UnsupportedOperator> {
-        Ok(format!("{lhs}::jsonb && {rhs}::jsonb"))
+        Ok(format!("({lhs})::jsonb && {rhs}::jsonb"))
+    }
+
+    fn jsonb_binary_sql(
+        &self,
+        pg_op: &str,
+        column: &str,
+        path: &[String],
+        rhs: &str,
+    ) -> Result<String, UnsupportedOperator> {
+        let lhs = jsonb_value_expr(column, path);
+        Ok(match pg_op {
+            "@>" | "<@" => format!("{lhs} {pg_op} {rhs}::jsonb"),
+            _ => format!("{lhs} {pg_op} {rhs}"),
+        })
+    }
+
+    fn jsonb_path_exists_sql(
+        &self,
+        column: &str,
+        path: &[String],
+        rhs: &str,
+    ) -> Result<String, UnsupportedOperator> {
+        Ok(format!(
+            "jsonb_path_exists({}, {rhs}::jsonpath)",
+            jsonb_value_expr(column, path)
+        ))
     }
 
     fn fts_matches_sql(&self, expr: &str, param: &str) -> Result<String, UnsupportedOperator> {
@@ -108,6 +139,65 @@ impl SqlDialect for PostgresDialect {
         Ok(format!("to_tsvector({expr}) @@ websearch_to_tsquery({param})"))
     }
 
+    fn fts_document_match_sql(
+        &self,
+        document: &str,
+        tsquery: &str,
+    ) -> Result<String, UnsupportedOperator> {
+        Ok(format!("({document}) @@ {tsquery}"))
+    }
+
+    fn geo_filter_sql(
+        &self,
+        filter: &GeoFilter,
+        backend: GeoBackend,
+        lat: &str,
+        lng: &str,
+        params: &[String],
+    ) -> Result<String, UnsupportedOperator> {
+        let lat = format!("({lat})::float8");
+        let lng = format!("({lng})::float8");
+        let num = |i: usize| format!("{}::text::float8", params[i]);
+        Ok(match (backend, filter) {
+            (GeoBackend::PostGis, GeoFilter::Near { .. }) => format!(
+                "ST_DWithin(ST_SetSRID(ST_MakePoint({lng}, {lat}), 4326)::geography, \
+                 ST_SetSRID(ST_MakePoint({}, {}), 4326)::geography, {})",
+                num(1),
+                num(0),
+                num(2)
+            ),
+            (GeoBackend::PostGis, GeoFilter::WithinPolygon(_)) => format!(
+                "ST_Within(ST_SetSRID(ST_MakePoint({lng}, {lat}), 4326), \
+                 ST_GeomFromText({}, 4326))",
+                params[0]
+            ),
+            (GeoBackend::PostGis, GeoFilter::WithinBoundingBox { .. }) => format!(
+                "ST_Within(ST_SetSRID(ST_MakePoint({lng}, {lat}), 4326), \
+                 ST_MakeEnvelope({}, {}, {}, {}, 4326))",
+                num(2),
+                num(0),
+                num(3),
+                num(1)
+            ),
+            (GeoBackend::EarthDistance, GeoFilter::Near { .. }) => format!(
+                "earth_distance(ll_to_earth({lat}, {lng}), ll_to_earth({}, {})) <= {}",
+                num(0),
+                num(1),
+                num(2)
+            ),
+            (GeoBackend::EarthDistance, GeoFilter::WithinPolygon(_)) => {
+                format!("point({lng}, {lat}) <@ {}::text::polygon", params[0])
+            },
+            (GeoBackend::EarthDistance, GeoFilter::WithinBoundingBox { .. }) => format!(
+                "({lat} BETWEEN {} AND {} AND {lng} BETWEEN {} AND {})",
+                num(0),
+                num(1),
+                num(2),
+                num(3)
+            ),
+        })
+    }
+
     fn regex_sql(
         &self,
         lhs: &str,
@@ -323,3 +413,10 @@ impl SqlDialect for PostgresDialect {
         }
     }
 }
+
+/// `column->'a'->'b'`: the JSONB value (not its text) at `path`.
+fn jsonb_value_expr(column: &str, path: &[String]) -> String {
+    crate::path_escape::escape_postgres_jsonb_path(path)
+        .iter()
+        .fold(column.to_owned(), |expr, segment| format!("{expr}->'{segment}'"))
+}
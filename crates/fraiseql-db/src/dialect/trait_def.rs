@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 
+use crate::geo::{GeoBackend, GeoFilter};
+
 /// Column type used by row-shaped view (`vr_*`) DDL generation.
 ///
 /// Maps GraphQL scalar types to their SQL equivalents for typed column
@@ -144,6 +146,18 @@ pub trait SqlDialect: Send + Sync + 'static {
         placeholder.to_string()
     }
 
+    /// Wrap a JSON-extracted scalar expression in a cast to `sql_type`.
+    ///
+    /// Used for [`WhereClause::TypedField`](crate::WhereClause::TypedField);
+    /// `sql_type` has already passed
+    /// [`is_safe_sql_type_name`](crate::is_safe_sql_type_name).
+    ///
+    /// Default: no cast — like [`Self::cast_native_param`], the other dialects
+    /// coerce the text value at comparison time.
+    fn cast_to_type<'a>(&self, expr: &'a str, _sql_type: &str) -> Cow<'a, str> {
+        Cow::Borrowed(expr)
+    }
+
     // ── LIKE / pattern matching ────────────────────────────────────────────────
 
     /// SQL fragment for case-sensitive LIKE: `lhs LIKE rhs`.
@@ -241,6 +255,47 @@ pub trait SqlDialect: Send + Sync + 'static {
         })
     }
 
+    // ── JSONB (returns Err if not supported) ───────────────────────────────────
+
+    /// SQL for a JSONB operator applied to the JSON value at `path` in `column`.
+    ///
+    /// `pg_op` is one of `@>`, `<@`, `?`, `?|`, `?&`. `rhs` is a placeholder, or
+    /// an `ARRAY[...]` of placeholders for `?|` and `?&`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnsupportedOperator`] if this dialect does not support JSONB operators.
+    fn jsonb_binary_sql(
+        &self,
+        _pg_op: &str,
+        _column: &str,
+        _path: &[String],
+        _rhs: &str,
+    ) -> Result<String, UnsupportedOperator> {
+        Err(UnsupportedOperator {
+            dialect:  self.name(),
+            operator: "JsonbBinaryOp",
+        })
+    }
+
+    /// SQL for `jsonb_path_exists(value, rhs::jsonpath)` on the JSON value at
+    /// `path` in `column`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnsupportedOperator`] if this dialect does not support SQL/JSON paths.
+    fn jsonb_path_exists_sql(
+        &self,
+        _column: &str,
+        _path: &[String],
+        _rhs: &str,
+    ) -> Result<String, UnsupportedOperator> {
+        Err(UnsupportedOperator {
+            dialect:  self.name(),
+            operator: "JsonPath",
+        })
+    }
+
     // ── Full-text search (returns Err if not supported) ────────────────────────
 
     /// SQL for `to_tsvector(expr) @@ to_tsquery(param)`.
@@ -303,6 +358,48 @@ pub trait SqlDialect: Send + Sync + 'static {
         })
     }
 
+    /// SQL for matching a pre-built search document against a `tsquery`.
+    ///
+    /// Used for [`WhereClause::TextSearch`](crate::WhereClause::TextSearch).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnsupportedOperator`] if this dialect does not support full-text search.
+    fn fts_document_match_sql(
+        &self,
+        _document: &str,
+        _tsquery: &str,
+    ) -> Result<String, UnsupportedOperator> {
+        Err(UnsupportedOperator {
+            dialect:  self.name(),
+            operator: "TextSearch",
+        })
+    }
+
+    // ── Geospatial (returns Err if not supported) ──────────────────────────────
+
+    /// SQL for a geospatial filter on a `{ lat, lng }` point.
+    ///
+    /// `lat` / `lng` are the point's coordinate expressions; `params` are the
+    /// placeholders bound to [`GeoFilter::params`] for `backend`, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnsupportedOperator`] if this dialect does not support geospatial filters.
+    fn geo_filter_sql(
+        &self,
+        _filter: &GeoFilter,
+        _backend: GeoBackend,
+        _lat: &str,
+        _lng: &str,
+        _params: &[String],
+    ) -> Result<String, UnsupportedOperator> {
+        Err(UnsupportedOperator {
+            dialect:  self.name(),
+            operator: "Geo",
+        })
+    }
+
     // ── Regex (returns Err if not supported) ───────────────────────────────────
 
     /// SQL for POSIX-style regex match.
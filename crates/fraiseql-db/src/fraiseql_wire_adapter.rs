@@ -12,7 +12,7 @@ use futures::stream::StreamExt;
 use super::{
     order_by::render_order_by_columns,
     traits::DatabaseAdapter,
-    types::{DatabaseType, JsonbValue, PoolMetrics, sql_hints::OrderByClause},
+    types::{DatabaseType, JsonbValue, PoolMetrics, SqlProjectionHint, sql_hints::OrderByClause},
     where_clause::WhereClause,
     where_sql_generator::WhereSqlGenerator,
     wire_pool::WireClientFactory,
@@ -28,6 +28,8 @@ use super::{
 /// - Connection Factory: Creates fresh clients on demand
 /// - Streaming: Results are streamed incrementally (O(chunk_size) memory)
 /// - WHERE Translation: AST → SQL via `WhereSqlGenerator`
+/// - Projection: a PostgreSQL [`SqlProjectionHint`] replaces the selected `data` column, so only
+///   the requested JSONB keys leave the database
 ///
 /// # Example
 ///
@@ -105,79 +107,16 @@ impl FraiseWireAdapter {
         self.chunk_size = chunk_size;
         self
     }
+}
 
-    /// Execute manual query with in-memory LIMIT/OFFSET slicing.
-    ///
-    /// fraiseql-wire's `QueryBuilder` does not expose LIMIT/OFFSET, so this collects the
-    /// (optionally ordered + filtered) stream and applies the window in memory. `order_by`
-    /// is pushed down to the wire builder so the slice is taken over correctly-sorted rows.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`FraiseQLError::Validation`] if SQL generation fails, or
-    /// [`FraiseQLError::Database`] if the wire connection or query execution fails.
-    async fn execute_manual_query(
-        &self,
-        view: &str,
-        where_clause: Option<&WhereClause>,
-        limit: Option<u32>,
-        offset: Option<u32>,
-        order_by: Option<&[OrderByClause]>,
-    ) -> Result<Vec<JsonbValue>> {
-        // Create fresh client
-        let client = self.factory.create_client().await?;
-
-        // fraiseql-wire doesn't expose LIMIT/OFFSET on its QueryBuilder, so we collect the
-        // full (filtered + ordered) stream and slice the window in memory below. ORDER BY is
-        // pushed down so the slice is taken over sorted rows, not DB-native order.
-
-        // Pass view name directly - fraiseql-wire now uses entity names as-is (fixed in commit
-        // 6c78e30)
-        let mut builder = client.query::<serde_json::Value>(view).chunk_size(self.chunk_size);
-
-        if let Some(clause) = where_clause {
-            let where_sql = WhereSqlGenerator::to_sql(clause)?;
-            builder = builder.where_sql(where_sql);
-        }
-
-        // FraiseWire is PostgreSQL-only (see `database_type`); render the bare ORDER BY
-        // column list (the wire builder supplies the `ORDER BY` keyword itself).
-        if let Some(columns) = render_order_by_columns(order_by, DatabaseType::PostgreSQL)? {
-            builder = builder.order_by(columns);
-        }
-
-        let mut stream = builder.execute().await.map_err(|e| FraiseQLError::Database {
-            message:   format!("fraiseql-wire query failed: {e}"),
-            sql_state: None,
-        })?;
-
-        // Collect all results
-        let mut results = Vec::new();
-        let offset_usize = offset.unwrap_or(0) as usize;
-        let limit_usize = limit.map(|l| l as usize);
-
-        let mut count = 0;
-        while let Some(item) = stream.next().await {
-            let json = item.map_err(|e| FraiseQLError::Database {
-                message:   format!("Stream error: {e}"),
-                sql_state: None,
-            })?;
-
-            // Apply offset and limit manually
-            if count >= offset_usize {
-                results.push(JsonbValue::new(json));
-
-                if let Some(lim) = limit_usize {
-                    if results.len() >= lim {
-                        break;
-                    }
-                }
-            }
-            count += 1;
-        }
-
-        Ok(results)
-    }
+/// The SELECT expression replacing the full `data` column for `projection`.
+///
+/// A hint generated for another database is ignored (the full row is streamed):
+/// fraiseql-wire only speaks PostgreSQL.
+fn projection_sql(projection: Option<&SqlProjectionHint>) -> Option<&str> {
+    projection
+        .filter(|hint| hint.database == DatabaseType::PostgreSQL)
+        .map(|hint| hint.projection_template.as_str())
 }
 
 // Reason: DatabaseAdapter is defined with #[async_trait]; all implementations must match
@@ -185,52 +124,36 @@ impl FraiseWireAdapter {
 // async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
 #[async_trait]
 impl DatabaseAdapter for FraiseWireAdapter {
-    /// # Errors
-    ///
-    /// Returns [`FraiseQLError::Database`] if the wire connection or query execution fails.
-    async fn execute_with_projection(
-        &self,
-        view: &str,
-        _projection: Option<&crate::types::SqlProjectionHint>,
-        where_clause: Option<&WhereClause>,
-        limit: Option<u32>,
-        offset: Option<u32>,
-        order_by: Option<&[OrderByClause]>,
-    ) -> Result<Vec<JsonbValue>> {
-        // FraiseWire uses streaming, so projection is handled by field selection
-        // Fall back to standard query for now
-        self.execute_where_query(view, where_clause, limit, offset, order_by).await
-    }
-
     /// # Errors
     ///
     /// Returns [`FraiseQLError::Validation`] if SQL generation fails, or
     /// [`FraiseQLError::Database`] if the wire connection or query execution fails.
-    async fn execute_where_query(
+    async fn execute_with_projection(
         &self,
         view: &str,
+        projection: Option<&SqlProjectionHint>,
         where_clause: Option<&WhereClause>,
         limit: Option<u32>,
         offset: Option<u32>,
         order_by: Option<&[OrderByClause]>,
     ) -> Result<Vec<JsonbValue>> {
-        // fraiseql-wire generates SQL as: SELECT data FROM {entity}
+        // fraiseql-wire generates SQL as: SELECT data FROM {entity}, or
+        // SELECT {projection} AS data FROM {entity} when a projection is pushed down,
         // where entity is used exactly as provided (no prefix modifications)
         let entity = view;
 
-        // Add LIMIT and OFFSET if provided
-        // Note: fraiseql-wire QueryBuilder doesn't expose limit/offset, so these cases
-        // collect-and-slice in memory (order_by is pushed down there too).
-        if limit.is_some() || offset.is_some() {
-            return self.execute_manual_query(view, where_clause, limit, offset, order_by).await;
-        }
-
         // Create fresh client
         let client = self.factory.create_client().await?;
 
         // Start building query
         let mut builder = client.query::<serde_json::Value>(entity).chunk_size(self.chunk_size);
 
+        // Extract only the requested JSONB keys server-side instead of streaming the
+        // whole `data` column and projecting it in the executor.
+        if let Some(projection_sql) = projection_sql(projection) {
+            builder = builder.select_projection(projection_sql);
+        }
+
         // Add WHERE clause if provided
         if let Some(clause) = where_clause {
             let where_sql = WhereSqlGenerator::to_sql(clause)?;
@@ -243,6 +166,16 @@ impl DatabaseAdapter for FraiseWireAdapter {
             builder = builder.order_by(columns);
         }
 
+        // LIMIT/OFFSET follow ORDER BY in the generated SQL, exactly as on the PostgreSQL
+        // adapter, so a page is cut from the sorted rows server-side instead of collecting
+        // the whole view and slicing it here.
+        if let Some(limit) = limit {
+            builder = builder.limit(limit as usize);
+        }
+        if let Some(offset) = offset {
+            builder = builder.offset(offset as usize);
+        }
+
         // Execute streaming query
         let mut stream = builder.execute().await.map_err(|e| FraiseQLError::Database {
             message:   format!("fraiseql-wire query failed: {e}"),
@@ -262,6 +195,22 @@ impl DatabaseAdapter for FraiseWireAdapter {
         Ok(results)
     }
 
+    /// # Errors
+    ///
+    /// Returns [`FraiseQLError::Validation`] if SQL generation fails, or
+    /// [`FraiseQLError::Database`] if the wire connection or query execution fails.
+    async fn execute_where_query(
+        &self,
+        view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        self.execute_with_projection(view, None, where_clause, limit, offset, order_by)
+            .await
+    }
+
     fn database_type(&self) -> DatabaseType {
         DatabaseType::PostgreSQL
     }
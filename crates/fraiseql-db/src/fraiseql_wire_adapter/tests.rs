@@ -24,3 +24,21 @@ fn test_pool_metrics() {
     assert_eq!(metrics.active_connections, 0);
     assert_eq!(metrics.waiting_requests, 0);
 }
+
+#[test]
+fn test_projection_sql_pushes_down_postgres_hint() {
+    let template = "jsonb_build_object('id', data->>'id', 'name', data->>'name')";
+    let hint = SqlProjectionHint::new(DatabaseType::PostgreSQL, template.to_string(), 75);
+    assert_eq!(projection_sql(Some(&hint)), Some(template));
+}
+
+#[test]
+fn test_projection_sql_ignores_foreign_or_missing_hint() {
+    let hint = SqlProjectionHint::new(
+        DatabaseType::MySQL,
+        "JSON_OBJECT('id', JSON_EXTRACT(data, '$.id'))".to_string(),
+        50,
+    );
+    assert_eq!(projection_sql(Some(&hint)), None);
+    assert_eq!(projection_sql(None), None);
+}
@@ -0,0 +1,199 @@
+//! Geospatial WHERE filters over `{ lat, lng }` JSONB fields.
+//!
+//! Three operators filter on a field holding a point object:
+//!
+//! - `near: { lat, lng, radiusMeters }` — great-circle distance within a radius
+//! - `withinPolygon: [{ lat, lng }, …]` — inside a polygon (ring closed automatically)
+//! - `withinBoundingBox: { minLat, minLng, maxLat, maxLng }` — inside a lat/lng box
+//!
+//! SQL is generated for PostGIS (`ST_DWithin` / `ST_Within`) by default. Databases
+//! without PostGIS fall back to the `earthdistance` extension for `near` and to
+//! built-in geometric types for the other two; `fraiseql compile --database`
+//! records which one the database has as the schema's [`GeoBackend`].
+
+use fraiseql_error::{FraiseQLError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::where_clause::WhereOperator;
+
+/// Spatial extension used to evaluate geospatial filters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum GeoBackend {
+    /// PostGIS geography/geometry functions.
+    #[default]
+    PostGis,
+    /// The `cube` + `earthdistance` extensions and built-in geometric types.
+    EarthDistance,
+}
+
+/// A latitude/longitude pair, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    /// Latitude, -90 to 90.
+    pub lat: f64,
+    /// Longitude, -180 to 180.
+    pub lng: f64,
+}
+
+/// A parsed geospatial filter value.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum GeoFilter {
+    /// Within `radius_meters` of `center`.
+    Near {
+        /// Center of the search circle.
+        center:        GeoPoint,
+        /// Radius in meters.
+        radius_meters: f64,
+    },
+    /// Inside the polygon with these vertices.
+    WithinPolygon(Vec<GeoPoint>),
+    /// Inside the box spanned by two corners.
+    WithinBoundingBox {
+        /// South-west corner.
+        min: GeoPoint,
+        /// North-east corner.
+        max: GeoPoint,
+    },
+}
+
+impl GeoFilter {
+    /// Parse the GraphQL value of a geospatial operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Validation` if `operator` is not geospatial, or
+    /// the value is malformed or out of range.
+    pub fn parse(operator: &WhereOperator, value: &serde_json::Value) -> Result<Self> {
+        match operator {
+            WhereOperator::Near => {
+                let center = point(value, "near")?;
+                let radius_meters = number(value, "radiusMeters", "near")?;
+                if radius_meters < 0.0 {
+                    return Err(FraiseQLError::validation(
+                        "near.radiusMeters must not be negative",
+                    ));
+                }
+                Ok(Self::Near {
+                    center,
+                    radius_meters,
+                })
+            },
+            WhereOperator::WithinPolygon => {
+                let vertices = value
+                    .as_array()
+                    .ok_or_else(|| {
+                        FraiseQLError::validation("withinPolygon requires a list of points")
+                    })?
+                    .iter()
+                    .map(|v| point(v, "withinPolygon"))
+                    .collect::<Result<Vec<_>>>()?;
+                if vertices.len() < 3 {
+                    return Err(FraiseQLError::validation(
+                        "withinPolygon requires at least three points",
+                    ));
+                }
+                Ok(Self::WithinPolygon(vertices))
+            },
+            WhereOperator::WithinBoundingBox => {
+                let op = "withinBoundingBox";
+                let min = GeoPoint {
+                    lat: number(value, "minLat", op)?,
+                    lng: number(value, "minLng", op)?,
+                };
+                let max = GeoPoint {
+                    lat: number(value, "maxLat", op)?,
+                    lng: number(value, "maxLng", op)?,
+                };
+                check_range(min, op)?;
+                check_range(max, op)?;
+                if min.lat > max.lat || min.lng > max.lng {
+                    return Err(FraiseQLError::validation(
+                        "withinBoundingBox requires minLat <= maxLat and minLng <= maxLng",
+                    ));
+                }
+                Ok(Self::WithinBoundingBox { min, max })
+            },
+            _ => {
+                Err(FraiseQLError::validation(format!("{operator:?} is not a geospatial operator")))
+            },
+        }
+    }
+
+    /// Bind parameters of this filter, in the order the dialect renders them.
+    ///
+    /// - `Near`: center latitude, center longitude, radius
+    /// - `WithinPolygon`: one polygon literal (WKT for PostGIS, a `polygon` literal otherwise)
+    /// - `WithinBoundingBox`: min latitude, max latitude, min longitude, max longitude
+    #[must_use]
+    pub fn params(&self, backend: GeoBackend) -> Vec<serde_json::Value> {
+        match self {
+            Self::Near {
+                center,
+                radius_meters,
+            } => vec![
+                center.lat.into(),
+                center.lng.into(),
+                (*radius_meters).into(),
+            ],
+            Self::WithinPolygon(vertices) => {
+                let mut ring = vertices.clone();
+                if ring.first() != ring.last() {
+                    ring.push(vertices[0]);
+                }
+                let literal = match backend {
+                    GeoBackend::PostGis => {
+                        let coords: Vec<String> =
+                            ring.iter().map(|p| format!("{} {}", p.lng, p.lat)).collect();
+                        format!("POLYGON(({}))", coords.join(", "))
+                    },
+                    GeoBackend::EarthDistance => {
+                        let coords: Vec<String> =
+                            ring.iter().map(|p| format!("({},{})", p.lng, p.lat)).collect();
+                        format!("({})", coords.join(","))
+                    },
+                };
+                vec![literal.into()]
+            },
+            Self::WithinBoundingBox { min, max } => {
+                vec![
+                    min.lat.into(),
+                    max.lat.into(),
+                    min.lng.into(),
+                    max.lng.into(),
+                ]
+            },
+        }
+    }
+}
+
+fn number(value: &serde_json::Value, key: &str, op: &str) -> Result<f64> {
+    value
+        .get(key)
+        .and_then(serde_json::Value::as_f64)
+        .ok_or_else(|| FraiseQLError::validation(format!("{op} requires a numeric `{key}`")))
+}
+
+fn point(value: &serde_json::Value, op: &str) -> Result<GeoPoint> {
+    let p = GeoPoint {
+        lat: number(value, "lat", op)?,
+        lng: number(value, "lng", op)?,
+    };
+    check_range(p, op)?;
+    Ok(p)
+}
+
+fn check_range(p: GeoPoint, op: &str) -> Result<()> {
+    if (-90.0..=90.0).contains(&p.lat) && (-180.0..=180.0).contains(&p.lng) {
+        Ok(())
+    } else {
+        Err(FraiseQLError::validation(format!(
+            "{op}: latitude must be within ±90 and longitude within ±180"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests;
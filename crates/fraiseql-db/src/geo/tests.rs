@@ -0,0 +1,68 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use serde_json::json;
+
+use super::*;
+
+#[test]
+fn near_parses_center_and_radius() {
+    let filter = GeoFilter::parse(
+        &WhereOperator::Near,
+        &json!({"lat": 48.85, "lng": 2.35, "radiusMeters": 500}),
+    )
+    .unwrap();
+    assert_eq!(
+        filter.params(GeoBackend::PostGis),
+        vec![json!(48.85), json!(2.35), json!(500.0)]
+    );
+}
+
+#[test]
+fn near_rejects_out_of_range_and_missing_values() {
+    let bad_lat = json!({"lat": 91, "lng": 0, "radiusMeters": 1});
+    assert!(GeoFilter::parse(&WhereOperator::Near, &bad_lat).is_err());
+    let no_radius = json!({"lat": 0, "lng": 0});
+    assert!(GeoFilter::parse(&WhereOperator::Near, &no_radius).is_err());
+    let negative = json!({"lat": 0, "lng": 0, "radiusMeters": -1});
+    assert!(GeoFilter::parse(&WhereOperator::Near, &negative).is_err());
+}
+
+#[test]
+fn polygon_ring_is_closed_per_backend() {
+    let value = json!([{"lat": 0, "lng": 0}, {"lat": 0, "lng": 1}, {"lat": 1, "lng": 1}]);
+    let filter = GeoFilter::parse(&WhereOperator::WithinPolygon, &value).unwrap();
+
+    assert_eq!(filter.params(GeoBackend::PostGis), vec![json!("POLYGON((0 0, 1 0, 1 1, 0 0))")]);
+    assert_eq!(
+        filter.params(GeoBackend::EarthDistance),
+        vec![json!("((0,0),(1,0),(1,1),(0,0))")]
+    );
+}
+
+#[test]
+fn polygon_requires_three_points() {
+    let value = json!([{"lat": 0, "lng": 0}, {"lat": 0, "lng": 1}]);
+    assert!(GeoFilter::parse(&WhereOperator::WithinPolygon, &value).is_err());
+}
+
+#[test]
+fn bounding_box_requires_ordered_corners() {
+    let ok = json!({"minLat": 1, "minLng": 2, "maxLat": 3, "maxLng": 4});
+    let filter = GeoFilter::parse(&WhereOperator::WithinBoundingBox, &ok).unwrap();
+    assert_eq!(
+        filter.params(GeoBackend::PostGis),
+        vec![json!(1.0), json!(3.0), json!(2.0), json!(4.0)]
+    );
+
+    let flipped = json!({"minLat": 3, "minLng": 2, "maxLat": 1, "maxLng": 4});
+    assert!(GeoFilter::parse(&WhereOperator::WithinBoundingBox, &flipped).is_err());
+}
+
+#[test]
+fn backend_serializes_snake_case() {
+    assert_eq!(
+        serde_json::to_value(GeoBackend::EarthDistance).unwrap(),
+        json!("earth_distance")
+    );
+    assert_eq!(GeoBackend::default(), GeoBackend::PostGis);
+}
@@ -0,0 +1,472 @@
+//! Hedged reads across replicas.
+//!
+//! [`HedgedAdapter`] wraps a primary adapter and one or more read replicas.
+//! View reads go to the primary first; if the primary has not answered once
+//! the read has taken longer than the recent p95 read latency, the same read
+//! is issued to a replica and whichever answers first wins. The loser is
+//! dropped, which cancels it on the client side.
+//!
+//! Hedging trades a little extra database load for a shorter tail: at the p95
+//! threshold at most ~5% of reads are duplicated. Until the latency window has
+//! [`HedgingConfig::min_samples`] observations there is no p95 to compare
+//! against, so reads are not hedged.
+//!
+//! Only view reads (`execute_where_query`, `execute_with_projection` and their
+//! `Arc` / session variants) are hedged. Mutations, aggregates, raw SQL and
+//! cache invalidation always go to the primary. Replicas may lag the primary,
+//! so only wrap adapters where a slightly stale read is acceptable.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use fraiseql_error::Result;
+use tokio::time::Instant;
+
+use crate::{
+    traits::{
+        ChangeLogWrite, DatabaseAdapter, DatabaseCapabilities, DirectMutationContext,
+        MutationStrategy, MutationTransaction, ProjectionRequest, SupportsMutations,
+    },
+    types::{
+        ColumnSpec, ColumnValue, DatabaseType, JsonbValue, PoolMetrics, QueryStatEntry,
+        sql_hints::{OrderByClause, SqlProjectionHint},
+    },
+    view_name::ViewName,
+    where_clause::WhereClause,
+};
+
+/// Counter of reads that were hedged to a replica.
+static HEDGED_READS: AtomicU64 = AtomicU64::new(0);
+
+/// Counter of hedged reads the replica answered first.
+static HEDGE_WINS: AtomicU64 = AtomicU64::new(0);
+
+/// Return the cumulative count of hedged reads since process start.
+#[must_use]
+pub fn hedged_reads_total() -> u64 {
+    HEDGED_READS.load(Ordering::Relaxed)
+}
+
+/// Return the cumulative count of hedged reads won by a replica since process start.
+#[must_use]
+pub fn hedge_wins_total() -> u64 {
+    HEDGE_WINS.load(Ordering::Relaxed)
+}
+
+/// Tuning for [`HedgedAdapter`].
+#[derive(Debug, Clone)]
+pub struct HedgingConfig {
+    /// Lower bound on the hedge delay, so a very fast p95 does not turn every
+    /// read into two. Default: 10 ms.
+    pub min_delay:   Duration,
+    /// Number of recent read latencies kept for the p95 estimate. Default: 256.
+    pub window:      usize,
+    /// Observations required before hedging starts. Default: 20.
+    pub min_samples: usize,
+}
+
+impl Default for HedgingConfig {
+    fn default() -> Self {
+        Self {
+            min_delay:   Duration::from_millis(10),
+            window:      256,
+            min_samples: 20,
+        }
+    }
+}
+
+/// Database adapter that hedges slow view reads to a replica.
+///
+/// See the [module documentation](self) for the policy.
+pub struct HedgedAdapter<A: DatabaseAdapter> {
+    primary:      A,
+    replicas:     Vec<A>,
+    next_replica: AtomicUsize,
+    latencies:    Mutex<VecDeque<Duration>>,
+    config:       HedgingConfig,
+}
+
+impl<A: DatabaseAdapter> HedgedAdapter<A> {
+    /// Wrap `primary`, hedging reads across `replicas` with the default config.
+    ///
+    /// With no replicas every call goes straight to the primary.
+    #[must_use]
+    pub fn new(primary: A, replicas: Vec<A>) -> Self {
+        Self::with_config(primary, replicas, HedgingConfig::default())
+    }
+
+    /// Wrap `primary` with an explicit [`HedgingConfig`].
+    #[must_use]
+    pub fn with_config(primary: A, replicas: Vec<A>, config: HedgingConfig) -> Self {
+        Self {
+            primary,
+            replicas,
+            next_replica: AtomicUsize::new(0),
+            latencies: Mutex::new(VecDeque::with_capacity(config.window)),
+            config,
+        }
+    }
+
+    /// The primary adapter.
+    #[must_use]
+    pub const fn primary(&self) -> &A {
+        &self.primary
+    }
+
+    /// The replica adapters.
+    #[must_use]
+    pub fn replicas(&self) -> &[A] {
+        &self.replicas
+    }
+
+    /// Delay after which a read still running on the primary is hedged.
+    ///
+    /// `None` while there are too few latency samples to estimate p95.
+    #[must_use]
+    pub fn hedge_delay(&self) -> Option<Duration> {
+        let latencies = self.latencies.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if latencies.len() < self.config.min_samples.max(1) {
+            return None;
+        }
+        let mut sorted: Vec<Duration> = latencies.iter().copied().collect();
+        drop(latencies);
+        sorted.sort_unstable();
+        let p95 = sorted.get((sorted.len() * 95).div_ceil(100).saturating_sub(1)).copied()?;
+        Some(p95.max(self.config.min_delay))
+    }
+
+    fn record_latency(&self, elapsed: Duration) {
+        let mut latencies =
+            self.latencies.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if latencies.len() >= self.config.window.max(1) {
+            latencies.pop_front();
+        }
+        latencies.push_back(elapsed);
+    }
+
+    fn pick_replica(&self) -> Option<&A> {
+        if self.replicas.is_empty() {
+            return None;
+        }
+        let index = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        self.replicas.get(index)
+    }
+
+    /// Run `read` on the primary, racing it against a replica once it has
+    /// taken longer than [`Self::hedge_delay`].
+    ///
+    /// If the first attempt to finish fails, the other one's result is used.
+    async fn hedged<'a, T, Fut>(&'a self, read: impl Fn(&'a A) -> Fut + Send) -> Result<T>
+    where
+        T: Send,
+        Fut: Future<Output = Result<T>> + Send + 'a,
+    {
+        let started = Instant::now();
+        let (Some(delay), Some(replica)) = (self.hedge_delay(), self.pick_replica()) else {
+            let result = read(&self.primary).await;
+            self.record_latency(started.elapsed());
+            return result;
+        };
+
+        let primary = read(&self.primary);
+        tokio::pin!(primary);
+        tokio::select! {
+            result = &mut primary => {
+                self.record_latency(started.elapsed());
+                return result;
+            },
+            () = tokio::time::sleep(delay) => {},
+        }
+
+        HEDGED_READS.fetch_add(1, Ordering::Relaxed);
+        let hedge = read(replica);
+        tokio::pin!(hedge);
+        let result = tokio::select! {
+            result = &mut primary => match result {
+                Ok(rows) => Ok(rows),
+                Err(_) => hedge.await,
+            },
+            result = &mut hedge => match result {
+                Ok(rows) => {
+                    HEDGE_WINS.fetch_add(1, Ordering::Relaxed);
+                    Ok(rows)
+                },
+                Err(_) => primary.await,
+            },
+        };
+        self.record_latency(started.elapsed());
+        result
+    }
+}
+
+// Reason: DatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl<A: DatabaseAdapter> DatabaseAdapter for HedgedAdapter<A> {
+    async fn execute_where_query(
+        &self,
+        view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        self.hedged(|adapter| {
+            adapter.execute_where_query(view, where_clause, limit, offset, order_by)
+        })
+        .await
+    }
+
+    async fn execute_with_projection(
+        &self,
+        view: &str,
+        projection: Option<&SqlProjectionHint>,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        self.hedged(|adapter| {
+            adapter.execute_with_projection(view, projection, where_clause, limit, offset, order_by)
+        })
+        .await
+    }
+
+    async fn execute_where_query_arc(
+        &self,
+        view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Arc<Vec<JsonbValue>>> {
+        self.hedged(|adapter| {
+            adapter.execute_where_query_arc(view, where_clause, limit, offset, order_by)
+        })
+        .await
+    }
+
+    async fn execute_with_projection_arc(
+        &self,
+        request: &ProjectionRequest<'_>,
+    ) -> Result<Arc<Vec<JsonbValue>>> {
+        self.hedged(|adapter| adapter.execute_with_projection_arc(request)).await
+    }
+
+    async fn execute_where_query_arc_with_session(
+        &self,
+        view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+        session_vars: &[(&str, &str)],
+    ) -> Result<Arc<Vec<JsonbValue>>> {
+        self.hedged(|adapter| {
+            adapter.execute_where_query_arc_with_session(
+                view,
+                where_clause,
+                limit,
+                offset,
+                order_by,
+                session_vars,
+            )
+        })
+        .await
+    }
+
+    async fn execute_with_projection_arc_with_session(
+        &self,
+        request: &ProjectionRequest<'_>,
+        session_vars: &[(&str, &str)],
+    ) -> Result<Arc<Vec<JsonbValue>>> {
+        self.hedged(|adapter| {
+            adapter.execute_with_projection_arc_with_session(request, session_vars)
+        })
+        .await
+    }
+
+    fn database_type(&self) -> DatabaseType {
+        self.primary.database_type()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.primary.health_check().await
+    }
+
+    fn pool_metrics(&self) -> PoolMetrics {
+        self.primary.pool_metrics()
+    }
+
+    async fn execute_raw_query(
+        &self,
+        sql: &str,
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary.execute_raw_query(sql).await
+    }
+
+    async fn execute_row_query(
+        &self,
+        view_name: &str,
+        columns: &[ColumnSpec],
+        where_sql: Option<&str>,
+        order_by: Option<&str>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Vec<ColumnValue>>> {
+        self.primary
+            .execute_row_query(view_name, columns, where_sql, order_by, limit, offset)
+            .await
+    }
+
+    async fn execute_parameterized_aggregate(
+        &self,
+        sql: &str,
+        params: &[serde_json::Value],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary.execute_parameterized_aggregate(sql, params).await
+    }
+
+    async fn execute_parameterized_aggregate_with_session(
+        &self,
+        sql: &str,
+        params: &[serde_json::Value],
+        session_vars: &[(&str, &str)],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary
+            .execute_parameterized_aggregate_with_session(sql, params, session_vars)
+            .await
+    }
+
+    async fn execute_function_call(
+        &self,
+        function_name: &str,
+        args: &[serde_json::Value],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary.execute_function_call(function_name, args).await
+    }
+
+    async fn execute_function_call_with_session(
+        &self,
+        function_name: &str,
+        args: &[serde_json::Value],
+        session_vars: &[(&str, &str)],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary
+            .execute_function_call_with_session(function_name, args, session_vars)
+            .await
+    }
+
+    async fn execute_function_call_with_changelog(
+        &self,
+        function_name: &str,
+        args: &[serde_json::Value],
+        session_vars: &[(&str, &str)],
+        changelog: Option<&ChangeLogWrite<'_>>,
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary
+            .execute_function_call_with_changelog(function_name, args, session_vars, changelog)
+            .await
+    }
+
+    async fn execute_function_call_dry_run(
+        &self,
+        function_name: &str,
+        args: &[serde_json::Value],
+        session_vars: &[(&str, &str)],
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        self.primary
+            .execute_function_call_dry_run(function_name, args, session_vars)
+            .await
+    }
+
+    async fn begin_mutation_transaction(&self) -> Result<Box<dyn MutationTransaction>> {
+        self.primary.begin_mutation_transaction().await
+    }
+
+    fn supports_mutations(&self) -> bool {
+        self.primary.supports_mutations()
+    }
+
+    fn mutation_strategy(&self) -> MutationStrategy {
+        self.primary.mutation_strategy()
+    }
+
+    async fn execute_direct_mutation(
+        &self,
+        ctx: &DirectMutationContext<'_>,
+    ) -> Result<Vec<serde_json::Value>> {
+        self.primary.execute_direct_mutation(ctx).await
+    }
+
+    async fn bump_fact_table_versions(&self, tables: &[String]) -> Result<()> {
+        self.primary.bump_fact_table_versions(tables).await
+    }
+
+    async fn invalidate_views(&self, views: &[ViewName]) -> Result<u64> {
+        self.primary.invalidate_views(views).await
+    }
+
+    async fn invalidate_by_entity(&self, entity_type: &str, entity_id: &str) -> Result<u64> {
+        self.primary.invalidate_by_entity(entity_type, entity_id).await
+    }
+
+    async fn invalidate_list_queries(&self, views: &[ViewName]) -> Result<u64> {
+        self.primary.invalidate_list_queries(views).await
+    }
+
+    fn capabilities(&self) -> DatabaseCapabilities {
+        self.primary.capabilities()
+    }
+
+    async fn explain_query(
+        &self,
+        sql: &str,
+        params: &[serde_json::Value],
+    ) -> Result<serde_json::Value> {
+        self.primary.explain_query(sql, params).await
+    }
+
+    async fn explain_where_query(
+        &self,
+        view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<serde_json::Value> {
+        self.primary.explain_where_query(view, where_clause, limit, offset).await
+    }
+
+    async fn query_stats(&self, limit: u32) -> Result<Vec<QueryStatEntry>> {
+        self.primary.query_stats(limit).await
+    }
+
+    async fn query_stats_by_id(&self, id: &str) -> Result<Option<QueryStatEntry>> {
+        self.primary.query_stats_by_id(id).await
+    }
+
+    async fn reset_query_stats(&self) -> Result<()> {
+        self.primary.reset_query_stats().await
+    }
+
+    fn on_schema_reload(&self) {
+        self.primary.on_schema_reload();
+        for replica in &self.replicas {
+            replica.on_schema_reload();
+        }
+    }
+}
+
+impl<A: SupportsMutations> SupportsMutations for HedgedAdapter<A> {}
+
+#[cfg(test)]
+mod tests;
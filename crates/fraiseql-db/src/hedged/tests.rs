@@ -0,0 +1,167 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use std::sync::atomic::AtomicU32;
+
+use serde_json::json;
+
+use super::*;
+
+/// Adapter that answers every read with its `name` after `delay`.
+struct DelayedAdapter {
+    name:  &'static str,
+    delay: Mutex<Duration>,
+    reads: AtomicU32,
+}
+
+impl DelayedAdapter {
+    fn new(name: &'static str, delay: Duration) -> Self {
+        Self {
+            name,
+            delay: Mutex::new(delay),
+            reads: AtomicU32::new(0),
+        }
+    }
+
+    fn set_delay(&self, delay: Duration) {
+        *self.delay.lock().unwrap() = delay;
+    }
+
+    fn reads(&self) -> u32 {
+        self.reads.load(Ordering::SeqCst)
+    }
+}
+
+// Reason: DatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl DatabaseAdapter for DelayedAdapter {
+    async fn execute_where_query(
+        &self,
+        _view: &str,
+        _where_clause: Option<&WhereClause>,
+        _limit: Option<u32>,
+        _offset: Option<u32>,
+        _order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        let delay = *self.delay.lock().unwrap();
+        tokio::time::sleep(delay).await;
+        Ok(vec![JsonbValue::new(json!({ "from": self.name }))])
+    }
+
+    async fn execute_with_projection(
+        &self,
+        view: &str,
+        _projection: Option<&SqlProjectionHint>,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Result<Vec<JsonbValue>> {
+        self.execute_where_query(view, where_clause, limit, offset, order_by).await
+    }
+
+    fn database_type(&self) -> DatabaseType {
+        DatabaseType::PostgreSQL
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn pool_metrics(&self) -> PoolMetrics {
+        PoolMetrics::default()
+    }
+
+    async fn execute_raw_query(
+        &self,
+        _sql: &str,
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        Ok(Vec::new())
+    }
+}
+
+fn config() -> HedgingConfig {
+    HedgingConfig {
+        min_delay:   Duration::from_millis(5),
+        window:      16,
+        min_samples: 4,
+    }
+}
+
+async fn read_from(adapter: &HedgedAdapter<DelayedAdapter>) -> String {
+    let rows = adapter.execute_where_query("v_user", None, None, None, None).await.unwrap();
+    rows[0].data["from"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn does_not_hedge_before_min_samples() {
+    let adapter = HedgedAdapter::with_config(
+        DelayedAdapter::new("primary", Duration::from_millis(20)),
+        vec![DelayedAdapter::new("replica", Duration::ZERO)],
+        config(),
+    );
+
+    assert!(adapter.hedge_delay().is_none());
+    assert_eq!(read_from(&adapter).await, "primary");
+    assert_eq!(adapter.replicas()[0].reads(), 0);
+}
+
+#[tokio::test]
+async fn slow_primary_read_is_answered_by_replica() {
+    let adapter = HedgedAdapter::with_config(
+        DelayedAdapter::new("primary", Duration::from_millis(1)),
+        vec![DelayedAdapter::new("replica", Duration::ZERO)],
+        config(),
+    );
+    for _ in 0..4 {
+        assert_eq!(read_from(&adapter).await, "primary");
+    }
+    assert!(adapter.hedge_delay().is_some());
+
+    adapter.primary().set_delay(Duration::from_secs(5));
+    let started = Instant::now();
+    assert_eq!(read_from(&adapter).await, "replica");
+    assert!(
+        started.elapsed() < Duration::from_secs(1),
+        "hedge must not wait for the primary"
+    );
+    assert_eq!(adapter.replicas()[0].reads(), 1);
+}
+
+#[tokio::test]
+async fn fast_primary_read_is_not_hedged() {
+    let adapter = HedgedAdapter::with_config(
+        DelayedAdapter::new("primary", Duration::ZERO),
+        vec![DelayedAdapter::new("replica", Duration::ZERO)],
+        config(),
+    );
+    for _ in 0..8 {
+        assert_eq!(read_from(&adapter).await, "primary");
+    }
+    assert_eq!(adapter.replicas()[0].reads(), 0);
+}
+
+#[test]
+fn hedge_delay_is_p95_floored_at_min_delay() {
+    let adapter = HedgedAdapter::with_config(
+        DelayedAdapter::new("primary", Duration::ZERO),
+        Vec::new(),
+        HedgingConfig {
+            min_delay:   Duration::from_millis(5),
+            window:      20,
+            min_samples: 1,
+        },
+    );
+    for ms in 1..=20 {
+        adapter.record_latency(Duration::from_millis(ms));
+    }
+    assert_eq!(adapter.hedge_delay(), Some(Duration::from_millis(19)));
+
+    // The window drops the oldest samples.
+    for _ in 0..20 {
+        adapter.record_latency(Duration::from_millis(1));
+    }
+    assert_eq!(adapter.hedge_delay(), Some(Duration::from_millis(5)));
+}
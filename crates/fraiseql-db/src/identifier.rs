@@ -110,5 +110,126 @@ pub fn quote_sqlserver_identifier(identifier: &str) -> String {
         .join(".")
 }
 
+/// Check that `sql_type` is a type name that can be spliced into a cast.
+///
+/// Type names come from the compiled schema (custom scalar `sql_type`), never
+/// from request input, but they are interpolated rather than bound, so they
+/// are checked against a conservative grammar: space-separated words, each an
+/// optionally schema-qualified identifier, then an optional `(n)` / `(n, m)`
+/// modifier and an optional `[]` suffix.
+///
+/// # Examples
+///
+/// ```rust
+/// use fraiseql_db::is_safe_sql_type_name;
+/// assert!(is_safe_sql_type_name("numeric(12, 2)"));
+/// assert!(is_safe_sql_type_name("timestamp with time zone"));
+/// assert!(is_safe_sql_type_name("public.citext"));
+/// assert!(is_safe_sql_type_name("char(2)[]"));
+/// assert!(!is_safe_sql_type_name("text; DROP TABLE users"));
+/// assert!(!is_safe_sql_type_name("numeric(12"));
+/// ```
+#[must_use]
+pub fn is_safe_sql_type_name(sql_type: &str) -> bool {
+    let base = sql_type.strip_suffix("[]").unwrap_or(sql_type);
+    let (name, modifiers) = match base.split_once('(') {
+        Some((name, rest)) => match rest.strip_suffix(')') {
+            Some(modifiers) => (name.trim_end(), Some(modifiers)),
+            None => return false,
+        },
+        None => (base, None),
+    };
+    let name_ok =
+        !name.is_empty() && name.split(' ').all(|word| word.split('.').all(is_plain_identifier));
+    let modifiers_ok = modifiers.is_none_or(|m| {
+        let parts: Vec<&str> = m.split(',').map(str::trim).collect();
+        parts.len() <= 2
+            && parts.iter().all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+    });
+    name_ok && modifiers_ok
+}
+
+/// Check that `expression` is a single SQL expression that can be spliced into
+/// a `SELECT` list.
+///
+/// Expressions come from the compiled schema (computed field `sql_expression`),
+/// never from request input, but they are interpolated rather than bound, so
+/// they are checked to stay one expression: outside string literals and quoted
+/// identifiers there must be no `;` and no comment, parentheses must balance,
+/// and every quote must be closed.
+///
+/// Quotes are scanned the way PostgreSQL lexes them: a doubled quote is an
+/// escaped quote, and inside an `E'...'` string a backslash escapes the next
+/// character. A backslash inside any other quote is rejected, because MySQL and
+/// PostgreSQL with `standard_conforming_strings = off` read it as an escape too.
+/// So is a `$` that starts a token (a dollar-quoted string or a parameter).
+///
+/// # Examples
+///
+/// ```rust
+/// use fraiseql_db::is_safe_sql_expression;
+/// assert!(is_safe_sql_expression("data->>'first' || ' ' || data->>'last'"));
+/// assert!(is_safe_sql_expression("upper(\"name\")"));
+/// assert!(is_safe_sql_expression("'a; b -- c'"));
+/// assert!(is_safe_sql_expression("E'it\\'s; fine'"));
+/// assert!(!is_safe_sql_expression("1; DROP TABLE users"));
+/// assert!(!is_safe_sql_expression("1) FROM secrets --"));
+/// assert!(!is_safe_sql_expression("'unterminated"));
+/// // `E'\''` is a complete literal, so the `;` that follows is a real one.
+/// assert!(!is_safe_sql_expression("E'\\'' ; DROP TABLE users --'"));
+/// assert!(!is_safe_sql_expression("'\\'' ; DROP TABLE users --'"));
+/// assert!(!is_safe_sql_expression("$$'$$; DROP TABLE users; SELECT '"));
+/// ```
+#[must_use]
+pub fn is_safe_sql_expression(expression: &str) -> bool {
+    if expression.trim().is_empty() {
+        return false;
+    }
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut depth = 0_usize;
+    // The open quote, and whether a backslash escapes the next character in it.
+    let mut quote: Option<(char, bool)> = None;
+    let (mut prev, mut prev2): (Option<char>, Option<char>) = (None, None);
+    let mut chars = expression.chars().peekable();
+    while let Some(c) = chars.next() {
+        let starts_token = !prev.is_some_and(is_word_char);
+        let after_e_prefix = matches!(prev, Some('e' | 'E')) && !prev2.is_some_and(is_word_char);
+        (prev2, prev) = (prev, Some(c));
+        if let Some((q, backslash_escapes)) = quote {
+            if c == '\\' {
+                if !backslash_escapes || chars.next().is_none() {
+                    return false;
+                }
+            } else if c == q && chars.next_if_eq(&q).is_none() {
+                // A doubled quote inside a literal is an escaped quote, not the end.
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' => quote = Some((c, after_e_prefix)),
+            '"' => quote = Some((c, false)),
+            '(' => depth += 1,
+            ')' => match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => return false,
+            },
+            ';' => return false,
+            '$' if starts_token => return false,
+            '-' if chars.peek() == Some(&'-') => return false,
+            '/' if chars.peek() == Some(&'*') => return false,
+            _ => {},
+        }
+    }
+    quote.is_none() && depth == 0
+}
+
+/// `[A-Za-z_][A-Za-z0-9_]*`.
+fn is_plain_identifier(word: &str) -> bool {
+    let mut bytes = word.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
 #[cfg(test)]
 mod tests;
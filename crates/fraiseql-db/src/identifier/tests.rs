@@ -93,3 +93,85 @@ fn test_sqlserver_escapes_embedded_bracket() {
     // Schema-qualified name where each part escapes its own ']':
     assert_eq!(quote_sqlserver_identifier("dbo.evil]inject"), "[dbo].[evil]]inject]");
 }
+
+#[test]
+fn test_safe_sql_type_names() {
+    for ok in [
+        "text",
+        "numeric",
+        "numeric(12,2)",
+        "numeric(12, 2)",
+        "varchar(255)",
+        "double precision",
+        "timestamp with time zone",
+        "public.citext",
+        "char(2)[]",
+        "_int4",
+    ] {
+        assert!(is_safe_sql_type_name(ok), "{ok} should be accepted");
+    }
+}
+
+#[test]
+fn test_unsafe_sql_type_names() {
+    for bad in [
+        "",
+        " ",
+        "text;",
+        "text) OR (1=1",
+        "numeric(12",
+        "numeric()",
+        "numeric(1,2,3)",
+        "numeric(a)",
+        "double  precision",
+        "1text",
+        "text--",
+        "\"quoted\"",
+        "a..b",
+    ] {
+        assert!(!is_safe_sql_type_name(bad), "{bad:?} should be rejected");
+    }
+}
+
+#[test]
+fn test_safe_sql_expressions() {
+    for ok in [
+        "data->>'first' || ' ' || data->>'last'",
+        "coalesce((data->>'total')::numeric, 0) * 1.2",
+        "upper(\"display name\")",
+        "'it''s; -- not a comment'",
+        "lower(data->>'email') = 'a/*b'",
+        "now() - (data->>'created_at')::timestamptz",
+        r"E'a\'b; c'",
+        r"e'\\'",
+        "'a'E'b'",
+        "price$usd * 2",
+    ] {
+        assert!(is_safe_sql_expression(ok), "{ok:?} should be accepted");
+    }
+}
+
+#[test]
+fn test_unsafe_sql_expressions() {
+    for bad in [
+        "",
+        "   ",
+        "1; DROP TABLE users",
+        "data->>'a' -- trailing",
+        "1 /* hidden */",
+        "1) FROM secrets WHERE (1",
+        "count(*",
+        "'unterminated",
+        "\"unterminated",
+        r"E'\'' ; DROP TABLE users --'",
+        r"e'\'' ; DROP TABLE users --'",
+        r"'\'' ; DROP TABLE users --'",
+        r#""\"" ; DROP TABLE users --""#,
+        r"E'trailing \",
+        "$$'$$; DROP TABLE users; SELECT '",
+        "$tag$'$tag$; DROP TABLE users; SELECT '",
+        "$1",
+    ] {
+        assert!(!is_safe_sql_expression(bad), "{bad:?} should be rejected");
+    }
+}
@@ -106,4 +106,35 @@ pub trait DatabaseIntrospector: Send + Sync {
     async fn qualified_relation_exists(&self, _schema: &str, _name: &str) -> Result<Option<bool>> {
         Ok(None)
     }
+
+    /// Ask the database whether `expression` is valid in the `SELECT` list of
+    /// `relation` — used to check computed field expressions at compile time.
+    ///
+    /// `None` ⇒ this connector cannot probe expressions (e.g. a non-Postgres
+    /// dialect) and the caller should skip the check. `Some(None)` ⇒ the
+    /// expression was accepted; `Some(Some(message))` ⇒ the database rejected it
+    /// with `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the probe cannot reach the database.
+    async fn expression_error(
+        &self,
+        _relation: &str,
+        _expression: &str,
+    ) -> Result<Option<Option<String>>> {
+        Ok(None)
+    }
+
+    /// Probe whether the database extension `name` (e.g. `postgis`) is installed.
+    ///
+    /// `None` ⇒ this connector has no extension catalog (e.g. a non-Postgres
+    /// dialect) and the caller should keep its default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the catalog query fails.
+    async fn extension_installed(&self, _name: &str) -> Result<Option<bool>> {
+        Ok(None)
+    }
 }
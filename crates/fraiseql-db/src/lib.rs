@@ -52,10 +52,14 @@ pub mod utils;
 
 // DB adapter modules (from the old db/ directory)
 pub mod collation;
+pub mod geo;
+pub mod hedged;
 pub mod identifier;
 pub mod order_by;
 pub mod path_escape;
 pub mod projection_generator;
+pub mod relation_filter;
+pub mod text_search;
 pub mod traits;
 pub mod view_name;
 pub mod where_clause;
@@ -95,9 +99,11 @@ pub use dialect::{
 };
 #[cfg(feature = "wire-backend")]
 pub use fraiseql_wire_adapter::FraiseWireAdapter;
+pub use geo::{GeoBackend, GeoFilter, GeoPoint};
+pub use hedged::{HedgedAdapter, HedgingConfig};
 pub use identifier::{
-    quote_mysql_identifier, quote_postgres_identifier, quote_sqlite_identifier,
-    quote_sqlserver_identifier,
+    is_safe_sql_expression, is_safe_sql_type_name, quote_mysql_identifier,
+    quote_postgres_identifier, quote_sqlite_identifier, quote_sqlserver_identifier,
 };
 pub use introspector::{DatabaseIntrospector, RelationInfo, RelationKind};
 #[cfg(feature = "mysql")]
@@ -108,18 +114,24 @@ pub use projection_generator::{
     FieldKind, MySqlProjectionGenerator, PostgresProjectionGenerator, ProjectionField,
     SqliteProjectionGenerator,
 };
+pub use relation_filter::{RelationFilter, RelationJoin, RelationQuantifier};
 #[cfg(feature = "sqlite")]
 pub use sqlite::{SqliteAdapter, SqliteIntrospector};
 #[cfg(feature = "sqlserver")]
 pub use sqlserver::{SqlServerAdapter, SqlServerIntrospector};
+pub use text_search::{SearchField, SearchWeight, TextSearchConfig};
 pub use traits::{
     ArcDatabaseAdapter, BoxDatabaseAdapter, ChangeLogWrite, CursorValue, DatabaseAdapter,
     DatabaseCapabilities, DirectMutationContext, DirectMutationOp, MutationStrategy,
-    ProjectionRequest, RelayDatabaseAdapter, RelayPageResult, SupportsMutations,
+    MutationTransaction, ProjectionRequest, RelayDatabaseAdapter, RelayPageResult,
+    SupportsMutations,
 };
 pub use types::{
     DatabaseType, JsonbValue, PoolMetrics, QueryStatEntry,
-    sql_hints::{OrderByClause, OrderByFieldType, OrderDirection, SqlProjectionHint},
+    sql_hints::{
+        OrderByAggregate, OrderByAggregateFunction, OrderByClause, OrderByFieldType,
+        OrderDirection, SqlProjectionHint,
+    },
 };
 pub use view_name::ViewName;
 pub use where_clause::{HavingClause, WhereClause, WhereOperator};
@@ -6,7 +6,15 @@
 
 use std::fmt::Write;
 
-use crate::types::{DatabaseType, sql_hints::OrderByClause};
+use fraiseql_error::FraiseQLError;
+
+use crate::{
+    types::{
+        DatabaseType,
+        sql_hints::{OrderByAggregate, OrderByAggregateFunction, OrderByClause},
+    },
+    utils::to_snake_case,
+};
 
 /// Append an `ORDER BY` clause to the SQL buffer.
 ///
@@ -90,7 +98,11 @@ pub fn render_order_by_columns(
         }
         // When a native typed column is available, use it directly — this
         // enables index support and avoids JSON extraction + cast overhead.
-        let expr = if let Some(ref col) = clause.native_column {
+        let expr = if let Some(ref aggregate) = clause.aggregate {
+            aggregate_subquery_sql(clause, aggregate, db_type)?
+        } else if let Some(ref expression) = clause.expression {
+            expression.clone()
+        } else if let Some(ref col) = clause.native_column {
             col.clone()
         } else {
             let key = clause.storage_key();
@@ -103,5 +115,50 @@ pub fn render_order_by_columns(
     Ok(Some(columns))
 }
 
+/// Render an aggregate ordering as a correlated subquery over the related view:
+/// `(SELECT MAX((data->>'total')::numeric) FROM "v_order" AS "_oa0" WHERE <join>)`.
+///
+/// Inside the subquery the bare `data` column is the related row's.
+fn aggregate_subquery_sql(
+    clause: &OrderByClause,
+    aggregate: &OrderByAggregate,
+    db_type: DatabaseType,
+) -> crate::Result<String> {
+    let join = aggregate.join.as_ref().ok_or_else(|| FraiseQLError::Validation {
+        message: format!("ordering by an aggregate of '{}' is not enabled", clause.field),
+        path:    None,
+    })?;
+    OrderByClause::validate_field_name(&join.key)?;
+    OrderByClause::validate_field_name(&join.parent_key)?;
+    let value = match (aggregate.function, aggregate.field.as_deref()) {
+        (OrderByAggregateFunction::Count, _) => "COUNT(*)".to_string(),
+        (function, Some(field)) => {
+            OrderByClause::validate_field_name(field)?;
+            let expr = db_type.typed_json_field_expr(&to_snake_case(field), clause.field_type);
+            format!("{}({expr})", function.as_sql())
+        },
+        (function, None) => {
+            return Err(FraiseQLError::Validation {
+                message: format!(
+                    "orderBy {} of '{}' needs a field to aggregate",
+                    function.as_sql(),
+                    clause.field
+                ),
+                path:    None,
+            });
+        },
+    };
+    let alias = db_type.quote_identifier(&join.alias);
+    Ok(format!(
+        "(SELECT {value} FROM {} AS {alias} WHERE {} = {})",
+        db_type.quote_identifier(&join.source),
+        db_type.json_column_field_expr(&format!("{alias}.data"), &join.key),
+        db_type.json_column_field_expr(
+            &format!("{}.data", db_type.quote_identifier(&join.parent)),
+            &join.parent_key
+        ),
+    ))
+}
+
 #[cfg(test)]
 mod tests;
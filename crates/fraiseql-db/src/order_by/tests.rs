@@ -156,6 +156,8 @@ fn test_append_order_by_native_column() {
         direction:     OrderDirection::Desc,
         field_type:    crate::types::sql_hints::OrderByFieldType::DateTime,
         native_column: Some("created_at".to_string()),
+        expression:    None,
+        aggregate:     None,
     };
     let appended = append_order_by(&mut sql, Some(&[clause]), DatabaseType::PostgreSQL).unwrap();
     assert!(appended);
@@ -174,6 +176,8 @@ fn test_append_order_by_mixed_native_and_jsonb() {
             direction:     OrderDirection::Desc,
             field_type:    OrderByFieldType::DateTime,
             native_column: Some("created_at".to_string()),
+            expression:    None,
+            aggregate:     None,
         },
         {
             let mut c = OrderByClause::new("name".to_string(), OrderDirection::Asc);
@@ -186,6 +190,22 @@ fn test_append_order_by_mixed_native_and_jsonb() {
     assert_eq!(sql, "SELECT data FROM tv_user ORDER BY created_at DESC, data->>'name' ASC");
 }
 
+#[test]
+fn test_append_order_by_expression_takes_precedence() {
+    let mut sql = "SELECT data FROM v_post".to_string();
+    let clauses = [
+        OrderByClause::expression(
+            "rank".to_string(),
+            "ts_rank(x, y)".to_string(),
+            OrderDirection::Desc,
+        ),
+        OrderByClause::new("id".to_string(), OrderDirection::Asc),
+    ];
+    let appended = append_order_by(&mut sql, Some(&clauses), DatabaseType::PostgreSQL).unwrap();
+    assert!(appended);
+    assert_eq!(sql, "SELECT data FROM v_post ORDER BY ts_rank(x, y) DESC, data->>'id' ASC");
+}
+
 // ── render_order_by_columns (bare list, for backends that supply the keyword) ──
 
 #[test]
@@ -235,3 +255,58 @@ fn test_render_order_by_columns_invalid_field_name() {
     )];
     assert!(render_order_by_columns(Some(&clauses), DatabaseType::PostgreSQL).is_err());
 }
+
+// ── aggregate ORDER BY ───────────────────────────────────────────────
+
+fn orders_aggregate(
+    function: crate::types::sql_hints::OrderByAggregateFunction,
+    field: Option<&str>,
+) -> OrderByClause {
+    use crate::{relation_filter::RelationJoin, types::sql_hints::OrderByAggregate};
+
+    let mut clause = OrderByClause::new("orders".to_string(), OrderDirection::Desc);
+    clause.aggregate = Some(OrderByAggregate {
+        function,
+        field: field.map(str::to_string),
+        join: Some(RelationJoin {
+            source:     "public.v_order".to_string(),
+            alias:      "_oa0".to_string(),
+            key:        "user_id".to_string(),
+            parent:     "public.v_user".to_string(),
+            parent_key: "id".to_string(),
+        }),
+    });
+    clause
+}
+
+#[test]
+fn test_append_order_by_relation_aggregate_subquery() {
+    use crate::types::sql_hints::{OrderByAggregateFunction, OrderByFieldType};
+
+    let join = "FROM \"public\".\"v_order\" AS \"_oa0\" \
+                WHERE \"_oa0\".data->>'user_id' = \"public\".\"v_user\".data->>'id'";
+
+    let count = orders_aggregate(OrderByAggregateFunction::Count, None);
+    let columns = render_order_by_columns(Some(&[count]), DatabaseType::PostgreSQL)
+        .unwrap()
+        .unwrap();
+    assert_eq!(columns, format!("(SELECT COUNT(*) {join}) DESC"));
+
+    let mut max = orders_aggregate(OrderByAggregateFunction::Max, Some("unitPrice"));
+    max.field_type = OrderByFieldType::Numeric;
+    let columns = render_order_by_columns(Some(&[max]), DatabaseType::PostgreSQL)
+        .unwrap()
+        .unwrap();
+    assert_eq!(columns, format!("(SELECT MAX((data->>'unit_price')::numeric) {join}) DESC"));
+}
+
+#[test]
+fn test_append_order_by_rejects_unbound_aggregate() {
+    let mut clause =
+        orders_aggregate(crate::types::sql_hints::OrderByAggregateFunction::Count, None);
+    if let Some(aggregate) = clause.aggregate.as_mut() {
+        aggregate.join = None;
+    }
+    let mut sql = "SELECT data FROM v_user".to_string();
+    assert!(append_order_by(&mut sql, Some(&[clause]), DatabaseType::PostgreSQL).is_err());
+}
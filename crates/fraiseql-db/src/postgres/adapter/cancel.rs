@@ -0,0 +1,112 @@
+//! Server-side cancellation of statements whose caller went away.
+//!
+//! When an HTTP client disconnects, the server drops the request future and,
+//! with it, the adapter future awaiting a statement. Dropping the future does
+//! not stop `PostgreSQL`: the backend keeps executing until the statement
+//! finishes, holding locks, I/O and a pooled connection for a result nobody
+//! will read. [`CancellableClient`] notices that a statement was still in
+//! flight when it is dropped and sends a cancel request using the
+//! connection's cancel key (the same mechanism as `pg_cancel_backend`).
+//!
+//! A cancelled connection is detached from the pool instead of being
+//! recycled: a cancel request targets whatever the backend is running when it
+//! arrives, so a connection handed to the next caller could otherwise have
+//! *its* statement cancelled.
+//!
+//! Only read paths are wrapped. A mutation's outcome is decided by its
+//! transaction (rolled back when dropped uncommitted), not by a cancel
+//! request that could race its `COMMIT`.
+
+use std::{
+    future::Future,
+    ops::{Deref, DerefMut},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use tokio_postgres::NoTls;
+
+/// Marks a statement as in flight on a [`CancellableClient`] while it is
+/// awaited.
+///
+/// Obtained from [`CancellableClient::in_flight`] before a transaction borrows
+/// the client mutably.
+pub(super) struct InFlight(Arc<AtomicBool>);
+
+impl InFlight {
+    /// Await `statement`; if the caller drops this future first, the owning
+    /// client cancels the statement on the server when it is dropped.
+    pub(super) async fn run<F: Future>(&self, statement: F) -> F::Output {
+        self.0.store(true, Ordering::Release);
+        let output = statement.await;
+        self.0.store(false, Ordering::Release);
+        output
+    }
+}
+
+/// Pooled connection that cancels its in-flight statement when dropped.
+pub(super) struct CancellableClient {
+    client:    Option<deadpool_postgres::Client>,
+    in_flight: Arc<AtomicBool>,
+}
+
+impl CancellableClient {
+    /// Wrap a pooled connection.
+    pub(super) fn new(client: deadpool_postgres::Client) -> Self {
+        Self {
+            client:    Some(client),
+            in_flight: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Handle for marking statements in flight, usable while a transaction
+    /// holds the client.
+    pub(super) fn in_flight(&self) -> InFlight {
+        InFlight(Arc::clone(&self.in_flight))
+    }
+
+    /// Await `statement`, cancelling it on the server if the caller goes away.
+    pub(super) async fn run<F: Future>(&self, statement: F) -> F::Output {
+        self.in_flight().run(statement).await
+    }
+}
+
+impl Deref for CancellableClient {
+    type Target = deadpool_postgres::Client;
+
+    fn deref(&self) -> &Self::Target {
+        self.client.as_ref().expect("client is only taken when the wrapper is dropped")
+    }
+}
+
+impl DerefMut for CancellableClient {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.client.as_mut().expect("client is only taken when the wrapper is dropped")
+    }
+}
+
+impl Drop for CancellableClient {
+    fn drop(&mut self) {
+        if !self.in_flight.load(Ordering::Acquire) {
+            return;
+        }
+        let Some(client) = self.client.take() else {
+            return;
+        };
+        let token = client.cancel_token();
+        // Detach from the pool; the connection closes once the cancel is sent.
+        let detached = deadpool_postgres::Client::take(client);
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        tracing::debug!("caller dropped a running statement, sending cancel request");
+        runtime.spawn(async move {
+            if let Err(e) = token.cancel_query(NoTls).await {
+                tracing::warn!(error = %e, "failed to cancel abandoned statement");
+            }
+            drop(detached);
+        });
+    }
+}
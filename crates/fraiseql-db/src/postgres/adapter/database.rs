@@ -12,7 +12,7 @@ use tokio_postgres::{
 
 use super::{
     PostgresAdapter, build_projection_select_sql, build_where_select_sql,
-    build_where_select_sql_ordered,
+    build_where_select_sql_ordered, cancel::CancellableClient, render_function_call_sql,
 };
 use crate::{
     identifier::quote_postgres_identifier,
@@ -42,7 +42,7 @@ const PG_UNDEFINED_COLUMN: &str = "42703";
 /// - `BOOL`: 1-byte (0 or 1)
 /// - All other types: UTF-8 bytes (PostgreSQL text binary = raw UTF-8)
 #[derive(Debug)]
-enum FlexParam {
+pub(super) enum FlexParam {
     /// SQL NULL — accepted by any PostgreSQL type.
     Null,
     /// A text-encoded value; binary-serialised according to the server-resolved type.
@@ -165,7 +165,7 @@ impl<'a> FromSql<'a> for PgEnumLabel {
 ///
 /// Tries each PostgreSQL type in priority order; falls back to `Null` for
 /// types that cannot be represented as JSON.
-fn row_to_map(row: &Row) -> std::collections::HashMap<String, serde_json::Value> {
+pub(super) fn row_to_map(row: &Row) -> std::collections::HashMap<String, serde_json::Value> {
     let mut map = std::collections::HashMap::new();
     for (idx, column) in row.columns().iter().enumerate() {
         let column_name = column.name().to_string();
@@ -251,6 +251,15 @@ fn row_to_map(row: &Row) -> std::collections::HashMap<String, serde_json::Value>
 pub(super) async fn apply_session_vars(
     txn: &tokio_postgres::Transaction<'_>,
     session_vars: &[(&str, &str)],
+) -> Result<()> {
+    apply_session_vars_on(txn, session_vars).await
+}
+
+/// [`apply_session_vars`] on any client, for callers that manage the
+/// transaction themselves (e.g. a multi-call mutation transaction).
+pub(super) async fn apply_session_vars_on<C: tokio_postgres::GenericClient + Sync>(
+    txn: &C,
+    session_vars: &[(&str, &str)],
 ) -> Result<()> {
     for (name, value) in session_vars {
         // A var carrying the clock-timestamp directive (e.g. fraiseql.started_at)
@@ -288,6 +297,13 @@ pub(super) async fn apply_session_vars(
 /// written), so an opted-out mutation (`changelog=false`) also suppresses the
 /// trigger rather than leaving a degraded fallback row.
 pub(super) async fn mark_cdc_mediated(txn: &tokio_postgres::Transaction<'_>) -> Result<()> {
+    mark_cdc_mediated_on(txn).await
+}
+
+/// [`mark_cdc_mediated`] on any client inside an open transaction.
+pub(super) async fn mark_cdc_mediated_on<C: tokio_postgres::GenericClient + Sync>(
+    txn: &C,
+) -> Result<()> {
     txn.execute(
         "SELECT set_config($1, $2, true)",
         &[
@@ -393,6 +409,57 @@ pub(super) fn build_changelog_cte_sql(quoted_fn: &str, n_args: usize, pre_image:
     )
 }
 
+/// Bind parameters for [`build_changelog_cte_sql`]: the function args followed
+/// by the change-log envelope params.
+pub(super) fn changelog_call_params(
+    args: &[serde_json::Value],
+    changelog: &crate::traits::ChangeLogWrite<'_>,
+) -> Vec<FlexParam> {
+    // Function args first; then the threaded change-log envelope params —
+    // object_type fallback ($n+1), modification_type verb ($n+2), the
+    // tenant_id stamp ($n+3, bound against `::uuid`), the trace_id
+    // ($n+4, plain text), the schema_version ($n+5, plain text), the
+    // trace_context ($n+6, bound against `::jsonb`), the actor_type ($n+7,
+    // plain text) and the acting_for ($n+8, bound against `::uuid`). Order
+    // matches build_changelog_cte_sql's positional contract; appending the
+    // envelope params keeps the SQL text stable for prepare_cached.
+    let mut flex_args: Vec<FlexParam> = args
+        .iter()
+        .map(|v| match v {
+            serde_json::Value::Null => FlexParam::Null,
+            serde_json::Value::String(s) => FlexParam::Text(s.clone()),
+            _ => FlexParam::Text(v.to_string()),
+        })
+        .collect();
+    flex_args.push(FlexParam::Text(changelog.object_type.to_string()));
+    flex_args.push(FlexParam::Text(changelog.modification_type.to_string()));
+    // tenant_id: bound as text and serialised by FlexParam's UUID branch
+    // (the `::uuid` cast pins the param type); None → SQL NULL.
+    flex_args.push(changelog.tenant_id.map_or(FlexParam::Null, |t| FlexParam::Text(t.to_string())));
+    // trace_id ($n+4): plain text, None → SQL NULL.
+    flex_args.push(changelog.trace_id.map_or(FlexParam::Null, |t| FlexParam::Text(t.to_string())));
+    // schema_version ($n+5): plain text, None → SQL NULL.
+    flex_args.push(
+        changelog
+            .schema_version
+            .map_or(FlexParam::Null, |s| FlexParam::Text(s.to_string())),
+    );
+    // trace_context ($n+6): JSON text bound against `::jsonb`, None → SQL NULL.
+    flex_args.push(
+        changelog
+            .trace_context
+            .map_or(FlexParam::Null, |s| FlexParam::Text(s.to_string())),
+    );
+    // actor_type ($n+7): plain text, None → SQL NULL.
+    flex_args
+        .push(changelog.actor_type.map_or(FlexParam::Null, |s| FlexParam::Text(s.to_string())));
+    // acting_for ($n+8): bound as text + serialised by FlexParam's UUID branch
+    // (the `::uuid` cast pins the param type); None → SQL NULL.
+    flex_args
+        .push(changelog.acting_for.map_or(FlexParam::Null, |u| FlexParam::Text(u.to_string())));
+    flex_args
+}
+
 /// Prepare `sql` on `client` using deadpool's **per-connection statement cache**.
 ///
 /// The mutation function-call path sends the same statement (a fixed
@@ -404,7 +471,7 @@ pub(super) fn build_changelog_cte_sql(quoted_fn: &str, n_args: usize, pre_image:
 ///
 /// Returns an owned `Statement` (releasing the `&client` borrow), so it can be
 /// prepared before `client.build_transaction()` and used inside that transaction.
-async fn prepare_cached_stmt(
+pub(super) async fn prepare_cached_stmt(
     client: &deadpool_postgres::Client,
     sql: &str,
 ) -> Result<tokio_postgres::Statement> {
@@ -536,12 +603,13 @@ impl DatabaseAdapter for PostgresAdapter {
         sql: &str,
     ) -> Result<Vec<std::collections::HashMap<String, serde_json::Value>>> {
         // Use retry logic for connection acquisition
-        let client = self.acquire_connection_with_retry().await?;
+        let client = CancellableClient::new(self.acquire_connection_with_retry().await?);
 
-        let rows: Vec<Row> = client.query(sql, &[]).await.map_err(|e| FraiseQLError::Database {
-            message:   format!("Query execution failed: {e}"),
-            sql_state: e.code().map(|c| c.code().to_string()),
-        })?;
+        let rows: Vec<Row> =
+            client.run(client.query(sql, &[])).await.map_err(|e| FraiseQLError::Database {
+                message:   format!("Query execution failed: {e}"),
+                sql_state: e.code().map(|c| c.code().to_string()),
+            })?;
 
         // Convert each row to HashMap<String, Value>
         let results: Vec<std::collections::HashMap<String, serde_json::Value>> =
@@ -561,12 +629,13 @@ impl DatabaseAdapter for PostgresAdapter {
         let typed: Vec<QueryParam> = params.iter().cloned().map(QueryParam::from).collect();
         let param_refs = crate::types::as_sql_param_refs(&typed);
 
-        let client = self.acquire_connection_with_retry().await?;
-        let rows: Vec<Row> =
-            client.query(sql, &param_refs).await.map_err(|e| FraiseQLError::Database {
+        let client = CancellableClient::new(self.acquire_connection_with_retry().await?);
+        let rows: Vec<Row> = self.query_hot(&client, sql, &param_refs).await.map_err(|e| {
+            FraiseQLError::Database {
                 message:   format!("Parameterized aggregate query failed: {e}"),
                 sql_state: e.code().map(|c| c.code().to_string()),
-            })?;
+            }
+        })?;
 
         let results: Vec<std::collections::HashMap<String, serde_json::Value>> =
             rows.iter().map(row_to_map).collect();
@@ -587,18 +656,26 @@ impl DatabaseAdapter for PostgresAdapter {
         let typed: Vec<QueryParam> = params.iter().cloned().map(QueryParam::from).collect();
         let param_refs = crate::types::as_sql_param_refs(&typed);
 
-        let mut client = self.acquire_connection_with_retry().await?;
+        let mut client = CancellableClient::new(self.acquire_connection_with_retry().await?);
+        let in_flight = client.in_flight();
+        let statement = self.hot_statement(&client, sql).await;
         let txn =
             client.build_transaction().start().await.map_err(|e| FraiseQLError::Database {
                 message:   format!("Failed to start session-var transaction: {e}"),
                 sql_state: e.code().map(|c| c.code().to_string()),
             })?;
         apply_session_vars(&txn, session_vars).await?;
-        let rows: Vec<Row> =
-            txn.query(sql, &param_refs).await.map_err(|e| FraiseQLError::Database {
+        let result = match statement {
+            Some(ref statement) => in_flight.run(txn.query(statement, &param_refs)).await,
+            None => in_flight.run(txn.query(sql, &param_refs)).await,
+        };
+        let rows: Vec<Row> = result.map_err(|e| {
+            self.forget_stale_statement(sql, &e);
+            FraiseQLError::Database {
                 message:   format!("Parameterized aggregate query failed: {e}"),
                 sql_state: e.code().map(|c| c.code().to_string()),
-            })?;
+            }
+        })?;
         txn.commit().await.map_err(|e| FraiseQLError::Database {
             message:   format!("Failed to commit session-var transaction: {e}"),
             sql_state: e.code().map(|c| c.code().to_string()),
@@ -613,13 +690,7 @@ impl DatabaseAdapter for PostgresAdapter {
         args: &[serde_json::Value],
     ) -> Result<Vec<std::collections::HashMap<String, serde_json::Value>>> {
         // Build: SELECT * FROM "fn_name"($1, $2, ...)
-        // Use the standard identifier quoting utility so that schema-qualified
-        // names like "benchmark.fn_update_user" are correctly split into
-        // "benchmark"."fn_update_user" instead of being wrapped as a single
-        // identifier.
-        let quoted_fn = quote_postgres_identifier(function_name);
-        let placeholders: Vec<String> = (1..=args.len()).map(|i| format!("${i}")).collect();
-        let sql = format!("SELECT * FROM {quoted_fn}({})", placeholders.join(", "));
+        let sql = render_function_call_sql(function_name, args.len());
 
         let mut client = self.acquire_connection_with_retry().await?;
 
@@ -713,9 +784,7 @@ impl DatabaseAdapter for PostgresAdapter {
         // exercised), but no writes persist. Mirrors execute_function_call's
         // statement build + FlexParam binding; the only difference is rollback
         // instead of commit, and no change-log outbox write.
-        let quoted_fn = quote_postgres_identifier(function_name);
-        let placeholders: Vec<String> = (1..=args.len()).map(|i| format!("${i}")).collect();
-        let sql = format!("SELECT * FROM {quoted_fn}({})", placeholders.join(", "));
+        let sql = render_function_call_sql(function_name, args.len());
 
         // See execute_function_call for why FlexParam is required here.
         let flex_args: Vec<FlexParam> = args
@@ -764,6 +833,12 @@ impl DatabaseAdapter for PostgresAdapter {
         Ok(rows.iter().map(row_to_map).collect())
     }
 
+    async fn begin_mutation_transaction(
+        &self,
+    ) -> Result<Box<dyn crate::traits::MutationTransaction>> {
+        Ok(Box::new(super::transaction::PostgresMutationTransaction::begin(self).await?))
+    }
+
     // PostgreSQL session variables are applied connection-affinely by the
     // `*_with_session` methods below: `set_config(..., true)` and the operation
     // share one transaction on one connection, so transaction-local GUCs are
@@ -782,9 +857,7 @@ impl DatabaseAdapter for PostgresAdapter {
             return self.execute_function_call(function_name, args).await;
         }
 
-        let quoted_fn = quote_postgres_identifier(function_name);
-        let placeholders: Vec<String> = (1..=args.len()).map(|i| format!("${i}")).collect();
-        let sql = format!("SELECT * FROM {quoted_fn}({})", placeholders.join(", "));
+        let sql = render_function_call_sql(function_name, args.len());
 
         // See execute_function_call for why FlexParam is required here.
         let flex_args: Vec<FlexParam> = args
@@ -867,50 +940,7 @@ impl DatabaseAdapter for PostgresAdapter {
         // same txn, atomically, with no extra connection acquire (Change Spine).
         let sql = build_changelog_cte_sql(&quoted_fn, args.len(), changelog.pre_image);
 
-        // Function args first; then the threaded change-log envelope params —
-        // object_type fallback ($n+1), modification_type verb ($n+2), the
-        // tenant_id stamp ($n+3, bound against `::uuid`), the trace_id
-        // ($n+4, plain text), the schema_version ($n+5, plain text), the
-        // trace_context ($n+6, bound against `::jsonb`), the actor_type ($n+7,
-        // plain text) and the acting_for ($n+8, bound against `::uuid`). Order
-        // matches build_changelog_cte_sql's positional contract; appending the
-        // envelope params keeps the SQL text stable for prepare_cached.
-        let mut flex_args: Vec<FlexParam> = args
-            .iter()
-            .map(|v| match v {
-                serde_json::Value::Null => FlexParam::Null,
-                serde_json::Value::String(s) => FlexParam::Text(s.clone()),
-                _ => FlexParam::Text(v.to_string()),
-            })
-            .collect();
-        flex_args.push(FlexParam::Text(changelog.object_type.to_string()));
-        flex_args.push(FlexParam::Text(changelog.modification_type.to_string()));
-        // tenant_id: bound as text and serialised by FlexParam's UUID branch
-        // (the `::uuid` cast pins the param type); None → SQL NULL.
-        flex_args
-            .push(changelog.tenant_id.map_or(FlexParam::Null, |t| FlexParam::Text(t.to_string())));
-        // trace_id ($n+4): plain text, None → SQL NULL.
-        flex_args
-            .push(changelog.trace_id.map_or(FlexParam::Null, |t| FlexParam::Text(t.to_string())));
-        // schema_version ($n+5): plain text, None → SQL NULL.
-        flex_args.push(
-            changelog
-                .schema_version
-                .map_or(FlexParam::Null, |s| FlexParam::Text(s.to_string())),
-        );
-        // trace_context ($n+6): JSON text bound against `::jsonb`, None → SQL NULL.
-        flex_args.push(
-            changelog
-                .trace_context
-                .map_or(FlexParam::Null, |s| FlexParam::Text(s.to_string())),
-        );
-        // actor_type ($n+7): plain text, None → SQL NULL.
-        flex_args
-            .push(changelog.actor_type.map_or(FlexParam::Null, |s| FlexParam::Text(s.to_string())));
-        // acting_for ($n+8): bound as text + serialised by FlexParam's UUID branch
-        // (the `::uuid` cast pins the param type); None → SQL NULL.
-        flex_args
-            .push(changelog.acting_for.map_or(FlexParam::Null, |u| FlexParam::Text(u.to_string())));
+        let flex_args = changelog_call_params(args, changelog);
         let params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> = flex_args
             .iter()
             .map(|v| v as &(dyn tokio_postgres::types::ToSql + Sync))
@@ -1096,6 +1126,12 @@ impl DatabaseAdapter for PostgresAdapter {
     async fn reset_query_stats(&self) -> Result<()> {
         self.pg_reset_query_stats().await
     }
+
+    fn on_schema_reload(&self) {
+        // The reloaded schema may point at replaced views; their prepared
+        // statements would otherwise keep the old result types.
+        self.clear_prepared_statements();
+    }
 }
 
 impl SupportsMutations for PostgresAdapter {}
@@ -69,6 +69,42 @@ async fn test_health_check() {
     adapter.health_check().await.expect("Health check failed");
 }
 
+#[tokio::test]
+async fn test_repeated_query_is_prepared_and_cleared_on_schema_reload() {
+    let adapter = create_test_adapter().await;
+    let first = adapter
+        .execute_where_query("v_user", None, Some(5), None, None)
+        .await
+        .expect("Failed to query v_user");
+    assert_eq!(adapter.prepared_statement_count(), 0, "first execution runs unprepared");
+
+    let second = adapter
+        .execute_where_query("v_user", None, Some(5), None, None)
+        .await
+        .expect("Failed to query v_user prepared");
+    assert_eq!(adapter.prepared_statement_count(), 1);
+    assert_eq!(first.len(), second.len());
+
+    adapter.on_schema_reload();
+    assert_eq!(adapter.prepared_statement_count(), 0);
+    adapter
+        .execute_where_query("v_user", None, Some(5), None, None)
+        .await
+        .expect("Query must still run after the statement caches are cleared");
+}
+
+#[tokio::test]
+async fn test_prepared_statements_disabled() {
+    let adapter = create_test_adapter().await.with_prepared_statement_capacity(0);
+    for _ in 0..3 {
+        adapter
+            .execute_where_query("v_user", None, Some(5), None, None)
+            .await
+            .expect("Failed to query v_user");
+    }
+    assert_eq!(adapter.prepared_statement_count(), 0);
+}
+
 #[tokio::test]
 async fn test_pool_metrics() {
     let adapter = create_test_adapter().await;
@@ -940,3 +976,41 @@ async fn row_to_map_type_conformance() {
     assert_eq!(r["c_jsonb"], json!({"k": 1}));
     assert_eq!(r["c_null"], serde_json::Value::Null);
 }
+
+// ========================================================================
+// Cancellation: a dropped query is cancelled on the server
+// ========================================================================
+
+#[tokio::test]
+async fn dropped_query_is_cancelled_on_the_server() {
+    const MARKER: &str = "SELECT pg_sleep(30) /* fraiseql_cancel_probe */";
+    let adapter = create_test_adapter().await;
+
+    // The caller gives up (as when an HTTP client disconnects) long before the
+    // statement would finish.
+    let abandoned = tokio::time::timeout(
+        std::time::Duration::from_millis(300),
+        adapter.execute_raw_query(MARKER),
+    )
+    .await;
+    assert!(abandoned.is_err(), "pg_sleep(30) must still be running at the timeout");
+
+    let observer = adapter.pool().get().await.expect("observer connection");
+    for _ in 0..50 {
+        let row = observer
+            .query_one(
+                "SELECT count(*) FROM pg_stat_activity \
+                 WHERE state = 'active' AND query LIKE '%fraiseql_cancel_probe%' \
+                 AND pid <> pg_backend_pid()",
+                &[],
+            )
+            .await
+            .expect("pg_stat_activity query");
+        let running: i64 = row.get(0);
+        if running == 0 {
+            return;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    panic!("abandoned statement was still running 5s after its caller dropped it");
+}
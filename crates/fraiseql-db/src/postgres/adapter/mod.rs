@@ -1,8 +1,11 @@
 //! PostgreSQL database adapter implementation.
 
+mod cancel;
 mod database;
 mod query_stats;
 mod relay;
+mod statements;
+mod transaction;
 
 #[cfg(test)]
 mod tests;
@@ -10,12 +13,14 @@ mod tests;
 #[cfg(all(test, feature = "test-postgres"))]
 mod integration_tests;
 
-use std::{fmt::Write, time::Duration};
+use std::{fmt::Write, sync::Arc, time::Duration};
 
 use deadpool_postgres::{Config, ManagerConfig, Pool, RecyclingMethod, Runtime};
 use fraiseql_error::{FraiseQLError, Result};
 use tokio_postgres::{NoTls, Row};
 
+pub use self::statements::DEFAULT_PREPARED_STATEMENT_CAPACITY;
+use self::{cancel::CancellableClient, statements::HotStatements};
 use super::where_generator::PostgresWhereGenerator;
 use crate::{
     dialect::PostgresDialect,
@@ -197,6 +202,8 @@ pub struct PostgresAdapter {
     mutation_timing_enabled: bool,
     /// The PostgreSQL session variable name for timing.
     timing_variable_name:    String,
+    /// Statements hot enough to run prepared (shared by clones of the adapter).
+    statements:              Arc<HotStatements>,
 }
 
 impl std::fmt::Debug for PostgresAdapter {
@@ -204,6 +211,7 @@ impl std::fmt::Debug for PostgresAdapter {
         f.debug_struct("PostgresAdapter")
             .field("mutation_timing_enabled", &self.mutation_timing_enabled)
             .field("timing_variable_name", &self.timing_variable_name)
+            .field("prepared_statements", &self.statements.prepared_len())
             .field("pool", &"<Pool>")
             .finish()
     }
@@ -276,6 +284,7 @@ impl PostgresAdapter {
             pool,
             mutation_timing_enabled: false,
             timing_variable_name: "fraiseql.started_at".to_string(),
+            statements: Arc::new(HotStatements::new(DEFAULT_PREPARED_STATEMENT_CAPACITY)),
         };
 
         // Pre-warm: open `min_size - 1` additional connections (one already exists).
@@ -382,6 +391,20 @@ impl PostgresAdapter {
         self
     }
 
+    /// Set how many frequently executed statements are kept prepared.
+    ///
+    /// A query whose SQL text runs repeatedly is prepared as a named statement on
+    /// each connection, so PostgreSQL skips parsing and planning it on later
+    /// executions. The least recently used statement is deallocated once more
+    /// than `capacity` are hot. `0` disables automatic preparation, e.g. behind a
+    /// pooler that does not support named statements. Defaults to
+    /// [`DEFAULT_PREPARED_STATEMENT_CAPACITY`].
+    #[must_use]
+    pub fn with_prepared_statement_capacity(mut self, capacity: usize) -> Self {
+        self.statements = Arc::new(HotStatements::new(capacity));
+        self
+    }
+
     /// Returns whether mutation timing injection is enabled.
     #[must_use]
     pub const fn mutation_timing_enabled(&self) -> bool {
@@ -398,13 +421,15 @@ impl PostgresAdapter {
         sql: &str,
         params: &[&(dyn tokio_postgres::types::ToSql + Sync)],
     ) -> Result<Vec<JsonbValue>> {
-        let client = self.acquire_connection_with_retry().await?;
+        let client = CancellableClient::new(self.acquire_connection_with_retry().await?);
 
         let rows: Vec<Row> =
-            client.query(sql, params).await.map_err(|e| FraiseQLError::Database {
-                message:   format!("Query execution failed: {e}"),
-                sql_state: e.code().map(|c| c.code().to_string()),
-            })?;
+            self.query_hot(&client, sql, params)
+                .await
+                .map_err(|e| FraiseQLError::Database {
+                    message:   format!("Query execution failed: {e}"),
+                    sql_state: e.code().map(|c| c.code().to_string()),
+                })?;
 
         let results = rows
             .into_iter()
@@ -432,7 +457,11 @@ impl PostgresAdapter {
         params: &[&(dyn tokio_postgres::types::ToSql + Sync)],
         session_vars: &[(&str, &str)],
     ) -> Result<Vec<JsonbValue>> {
-        let mut client = self.acquire_connection_with_retry().await?;
+        let mut client = CancellableClient::new(self.acquire_connection_with_retry().await?);
+        let in_flight = client.in_flight();
+        // Prepared before the transaction borrows the client; the owned statement is
+        // usable inside it.
+        let statement = self.hot_statement(&client, sql).await;
         let txn =
             client.build_transaction().start().await.map_err(|e| FraiseQLError::Database {
                 message:   format!("Failed to start session-var transaction: {e}"),
@@ -441,9 +470,16 @@ impl PostgresAdapter {
 
         database::apply_session_vars(&txn, session_vars).await?;
 
-        let rows: Vec<Row> = txn.query(sql, params).await.map_err(|e| FraiseQLError::Database {
-            message:   format!("Query execution failed: {e}"),
-            sql_state: e.code().map(|c| c.code().to_string()),
+        let result = match statement {
+            Some(ref statement) => in_flight.run(txn.query(statement, params)).await,
+            None => in_flight.run(txn.query(sql, params)).await,
+        };
+        let rows: Vec<Row> = result.map_err(|e| {
+            self.forget_stale_statement(sql, &e);
+            FraiseQLError::Database {
+                message:   format!("Query execution failed: {e}"),
+                sql_state: e.code().map(|c| c.code().to_string()),
+            }
         })?;
 
         txn.commit().await.map_err(|e| FraiseQLError::Database {
@@ -534,6 +570,73 @@ impl PostgresAdapter {
         })
     }
 
+    /// Execute a raw SQL query through a server-side cursor, handing its rows to
+    /// `on_chunk` in chunks of at most `chunk_rows` rows.
+    ///
+    /// The next chunk is only fetched once the future returned by `on_chunk` resolves,
+    /// so a slow consumer pauses the cursor instead of buffering the whole result.
+    /// Returning `false` from `on_chunk` stops the query; the read-only transaction
+    /// holding the cursor is then rolled back.
+    ///
+    /// # Security
+    ///
+    /// `sql` **must** be compiler-generated, as for
+    /// [`execute_raw_query`](DatabaseAdapter::execute_raw_query).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::ConnectionPool` if no connection can be acquired, or
+    /// `FraiseQLError::Database` if declaring or fetching from the cursor fails.
+    pub async fn execute_raw_query_in_chunks<F, Fut>(
+        &self,
+        sql: &str,
+        chunk_rows: usize,
+        mut on_chunk: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Vec<std::collections::HashMap<String, serde_json::Value>>) -> Fut + Send,
+        Fut: std::future::Future<Output = bool> + Send,
+    {
+        let chunk_rows = chunk_rows.max(1);
+        let database_error = |context: &str, e: tokio_postgres::Error| FraiseQLError::Database {
+            message:   format!("{context}: {e}"),
+            sql_state: e.code().map(|c| c.code().to_string()),
+        };
+
+        let mut client = CancellableClient::new(self.acquire_connection_with_retry().await?);
+        let in_flight = client.in_flight();
+        let txn = client
+            .build_transaction()
+            .read_only(true)
+            .start()
+            .await
+            .map_err(|e| database_error("Failed to start cursor transaction", e))?;
+        txn.batch_execute(&format!("DECLARE fraiseql_chunked NO SCROLL CURSOR FOR {sql}"))
+            .await
+            .map_err(|e| database_error("Failed to declare query cursor", e))?;
+
+        let fetch = format!("FETCH FORWARD {chunk_rows} FROM fraiseql_chunked");
+        loop {
+            let rows: Vec<Row> = in_flight
+                .run(txn.query(fetch.as_str(), &[]))
+                .await
+                .map_err(|e| database_error("Query execution failed", e))?;
+            let exhausted = rows.len() < chunk_rows;
+            if !rows.is_empty() && !on_chunk(rows.iter().map(database::row_to_map).collect()).await
+            {
+                // The consumer went away; dropping the transaction rolls it back.
+                return Ok(());
+            }
+            if exhausted {
+                break;
+            }
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| database_error("Failed to commit cursor transaction", e))
+    }
+
     /// Execute query with SQL field projection optimization.
     ///
     /// Uses the provided `SqlProjectionHint` to generate optimized SQL that projects
@@ -770,3 +873,46 @@ pub(super) fn build_projection_select_sql(
 
     Ok((sql, typed_params))
 }
+
+/// Render the `SELECT` the adapter sends for one read, without a connection.
+///
+/// Takes the same branch as [`PostgresAdapter::execute_with_projection_impl`]:
+/// the projection template when a hint is given, `SELECT data` otherwise. Offline
+/// tooling (`fraiseql test sql`) uses it to snapshot the SQL of compiled queries.
+///
+/// # Returns
+///
+/// `(sql, typed_params)` — the SQL string and the bound parameter values.
+///
+/// # Errors
+///
+/// Returns `FraiseQLError` if WHERE clause generation or field name validation fails.
+pub fn render_select_sql(
+    view: &str,
+    projection: Option<&SqlProjectionHint>,
+    where_clause: Option<&WhereClause>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    order_by: Option<&[OrderByClause]>,
+) -> Result<(String, Vec<QueryParam>)> {
+    match projection {
+        Some(projection) => {
+            build_projection_select_sql(projection, view, where_clause, limit, offset, order_by)
+        },
+        None => build_where_select_sql_ordered(view, where_clause, limit, offset, order_by),
+    }
+}
+
+/// Render the statement that calls a mutation function with `arg_count`
+/// positional arguments: `SELECT * FROM "schema"."fn"($1, $2, ...)`.
+#[must_use]
+pub fn render_function_call_sql(function_name: &str, arg_count: usize) -> String {
+    // Schema-qualified names like "benchmark.fn_update_user" are split into
+    // "benchmark"."fn_update_user" by the identifier quoting utility.
+    let placeholders: Vec<String> = (1..=arg_count).map(|i| format!("${i}")).collect();
+    format!(
+        "SELECT * FROM {}({})",
+        quote_postgres_identifier(function_name),
+        placeholders.join(", ")
+    )
+}
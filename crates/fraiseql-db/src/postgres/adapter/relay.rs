@@ -2,7 +2,7 @@
 
 use fraiseql_error::{FraiseQLError, Result};
 
-use super::{PostgresAdapter, escape_jsonb_key};
+use super::{PostgresAdapter, cancel::CancellableClient, escape_jsonb_key};
 use crate::{
     dialect::PostgresDialect,
     identifier::quote_postgres_identifier,
@@ -57,20 +57,21 @@ impl RelayDatabaseAdapter for PostgresAdapter {
         order_by: Option<&[OrderByClause]>,
         include_total_count: bool,
     ) -> Result<RelayPageResult> {
-        let client = self.acquire_connection_with_retry().await?;
-        self.run_relay_page(
-            &**client,
-            view,
-            cursor_column,
-            after,
-            before,
-            limit,
-            forward,
-            where_clause,
-            order_by,
-            include_total_count,
-        )
-        .await
+        let client = CancellableClient::new(self.acquire_connection_with_retry().await?);
+        client
+            .run(self.run_relay_page(
+                &***client,
+                view,
+                cursor_column,
+                after,
+                before,
+                limit,
+                forward,
+                where_clause,
+                order_by,
+                include_total_count,
+            ))
+            .await
     }
 
     #[allow(clippy::too_many_arguments)] // Reason: relay pagination requires all cursor/filter/sort/count arguments plus session vars; no natural grouping
@@ -106,15 +107,16 @@ impl RelayDatabaseAdapter for PostgresAdapter {
 
         // Apply set_config and run BOTH the page and count queries inside one
         // transaction on one connection so RLS sees the session variables.
-        let mut client = self.acquire_connection_with_retry().await?;
+        let mut client = CancellableClient::new(self.acquire_connection_with_retry().await?);
+        let in_flight = client.in_flight();
         let txn =
             client.build_transaction().start().await.map_err(|e| FraiseQLError::Database {
                 message:   format!("Failed to start relay session-var transaction: {e}"),
                 sql_state: e.code().map(|c| c.code().to_string()),
             })?;
         super::database::apply_session_vars(&txn, session_vars).await?;
-        let result = self
-            .run_relay_page(
+        let result = in_flight
+            .run(self.run_relay_page(
                 &*txn,
                 view,
                 cursor_column,
@@ -125,7 +127,7 @@ impl RelayDatabaseAdapter for PostgresAdapter {
                 where_clause,
                 order_by,
                 include_total_count,
-            )
+            ))
             .await?;
         txn.commit().await.map_err(|e| FraiseQLError::Database {
             message:   format!("Failed to commit relay session-var transaction: {e}"),
@@ -0,0 +1,213 @@
+//! Automatic server-side preparation of frequently executed statements.
+//!
+//! Compiled query SQL is sent with the unnamed statement by default, so
+//! PostgreSQL parses and plans it on every execution. [`HotStatements`] counts
+//! executions per SQL text across the adapter; once a statement has run
+//! [`PREPARE_THRESHOLD`] times it is prepared as a named statement on each
+//! connection that runs it (deadpool's per-connection statement cache) and
+//! reused from then on.
+//!
+//! The set of prepared statements is bounded: when more than `capacity`
+//! statements are hot, the least recently used one is evicted and deallocated
+//! from every connection. A schema reload drops them all, since the views
+//! behind them may have changed; a statement whose cached plan PostgreSQL
+//! rejects after a concurrent DDL change is dropped and re-run unprepared.
+
+use std::{num::NonZeroUsize, sync::Mutex};
+
+use lru::LruCache;
+use tokio_postgres::{Row, Statement, error::SqlState, types::ToSql};
+
+use super::{PostgresAdapter, cancel::CancellableClient, database::prepare_cached_stmt};
+
+/// Default number of hot statements kept prepared per adapter.
+pub const DEFAULT_PREPARED_STATEMENT_CAPACITY: usize = 256;
+
+/// Executions of the same SQL text after which it is prepared.
+pub(super) const PREPARE_THRESHOLD: u32 = 2;
+
+/// How many not-yet-hot statements are tracked per hot slot.
+const CANDIDATES_PER_SLOT: usize = 4;
+
+/// Outcome of [`HotStatements::admit`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct Admission {
+    /// Whether the statement should run prepared.
+    pub(super) prepare: bool,
+    /// A statement evicted to make room, to deallocate from every connection.
+    pub(super) evicted: Option<String>,
+}
+
+#[derive(Debug)]
+struct Tracked {
+    /// Execution counts of statements that are not prepared yet.
+    candidates: LruCache<String, u32>,
+    /// Statements currently prepared.
+    prepared:   LruCache<String, ()>,
+}
+
+/// Adapter-wide bookkeeping of which statements are hot enough to prepare.
+#[derive(Debug)]
+pub(super) struct HotStatements {
+    /// `None` when preparation is disabled (capacity 0).
+    tracked: Option<Mutex<Tracked>>,
+}
+
+impl HotStatements {
+    /// Track up to `capacity` prepared statements; `0` disables preparation.
+    pub(super) fn new(capacity: usize) -> Self {
+        let tracked = NonZeroUsize::new(capacity).map(|capacity| {
+            let candidates = NonZeroUsize::new(capacity.get().saturating_mul(CANDIDATES_PER_SLOT))
+                .unwrap_or(capacity);
+            Mutex::new(Tracked {
+                candidates: LruCache::new(candidates),
+                prepared:   LruCache::new(capacity),
+            })
+        });
+        Self { tracked }
+    }
+
+    /// Record an execution of `sql` and decide whether it runs prepared.
+    pub(super) fn admit(&self, sql: &str) -> Admission {
+        let Some(ref tracked) = self.tracked else {
+            return Admission::default();
+        };
+        let mut tracked = tracked.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if tracked.prepared.get(sql).is_some() {
+            return Admission {
+                prepare: true,
+                evicted: None,
+            };
+        }
+
+        let executions = match tracked.candidates.get_mut(sql) {
+            Some(count) => {
+                *count = count.saturating_add(1);
+                *count
+            },
+            None => {
+                tracked.candidates.put(sql.to_string(), 1);
+                1
+            },
+        };
+        if executions < PREPARE_THRESHOLD {
+            return Admission::default();
+        }
+
+        tracked.candidates.pop(sql);
+        let evicted = tracked.prepared.push(sql.to_string(), ()).map(|(evicted, ())| evicted);
+        Admission {
+            prepare: true,
+            evicted,
+        }
+    }
+
+    /// Stop preparing `sql`.
+    pub(super) fn forget(&self, sql: &str) {
+        if let Some(ref tracked) = self.tracked {
+            let mut tracked = tracked.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            tracked.prepared.pop(sql);
+            tracked.candidates.pop(sql);
+        }
+    }
+
+    /// Stop preparing every statement.
+    pub(super) fn clear(&self) {
+        if let Some(ref tracked) = self.tracked {
+            let mut tracked = tracked.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            tracked.prepared.clear();
+            tracked.candidates.clear();
+        }
+    }
+
+    /// Number of statements currently prepared.
+    pub(super) fn prepared_len(&self) -> usize {
+        self.tracked.as_ref().map_or(0, |tracked| {
+            tracked.lock().unwrap_or_else(std::sync::PoisonError::into_inner).prepared.len()
+        })
+    }
+}
+
+/// Whether `error` is PostgreSQL rejecting a prepared statement whose result
+/// type changed under it (a view was replaced since it was prepared).
+fn is_stale_plan(error: &tokio_postgres::Error) -> bool {
+    error.as_db_error().is_some_and(|db| {
+        *db.code() == SqlState::FEATURE_NOT_SUPPORTED && db.message().contains("cached plan")
+    })
+}
+
+impl PostgresAdapter {
+    /// The prepared form of `sql` on `client` when it is hot, `None` to run it
+    /// unprepared.
+    ///
+    /// A statement that fails to prepare runs unprepared, so the caller reports
+    /// the same error it would have without preparation.
+    pub(super) async fn hot_statement(
+        &self,
+        client: &deadpool_postgres::Client,
+        sql: &str,
+    ) -> Option<Statement> {
+        let admission = self.statements.admit(sql);
+        if let Some(ref evicted) = admission.evicted {
+            self.pool.manager().statement_caches.remove(evicted, &[]);
+        }
+        if !admission.prepare {
+            return None;
+        }
+        match prepare_cached_stmt(client, sql).await {
+            Ok(statement) => Some(statement),
+            Err(e) => {
+                tracing::debug!(error = %e, "Statement could not be prepared; running unprepared");
+                self.forget_statement(sql);
+                None
+            },
+        }
+    }
+
+    /// Run `sql` on `client`, prepared when it is hot.
+    pub(super) async fn query_hot(
+        &self,
+        client: &CancellableClient,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> std::result::Result<Vec<Row>, tokio_postgres::Error> {
+        let Some(statement) = self.hot_statement(client, sql).await else {
+            return client.run(client.query(sql, params)).await;
+        };
+        match client.run(client.query(&statement, params)).await {
+            Err(e) if is_stale_plan(&e) => {
+                self.forget_statement(sql);
+                client.run(client.query(sql, params)).await
+            },
+            result => result,
+        }
+    }
+
+    /// Drop `sql` from the hot set and deallocate it from every connection when
+    /// PostgreSQL rejected its cached plan; a no-op for other errors.
+    pub(super) fn forget_stale_statement(&self, sql: &str, error: &tokio_postgres::Error) {
+        if is_stale_plan(error) {
+            self.forget_statement(sql);
+        }
+    }
+
+    fn forget_statement(&self, sql: &str) {
+        self.statements.forget(sql);
+        self.pool.manager().statement_caches.remove(sql, &[]);
+    }
+
+    /// Deallocate every automatically prepared statement from every connection.
+    ///
+    /// Called on schema reload. Statements the mutation path prepares share
+    /// deadpool's statement cache and are re-prepared on their next use.
+    pub fn clear_prepared_statements(&self) {
+        self.statements.clear();
+        self.pool.manager().statement_caches.clear();
+    }
+
+    /// Number of statements currently kept prepared because they are hot.
+    #[must_use]
+    pub fn prepared_statement_count(&self) -> usize {
+        self.statements.prepared_len()
+    }
+}
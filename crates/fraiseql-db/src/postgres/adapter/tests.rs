@@ -4,7 +4,12 @@
 
 use fraiseql_error::FraiseQLError;
 
-use super::{PoolPrewarmConfig, PostgresAdapter, build_where_select_sql, escape_jsonb_key};
+use super::{
+    PoolPrewarmConfig, PostgresAdapter, build_where_select_sql, escape_jsonb_key,
+    render_function_call_sql, render_select_sql,
+    statements::{Admission, HotStatements},
+};
+use crate::types::{DatabaseType, sql_hints::SqlProjectionHint};
 
 // ── build_where_select_sql ─────────────────────────────────────────────────
 
@@ -24,6 +29,36 @@ fn test_build_where_select_sql_with_limit_offset() {
     assert_eq!(params.len(), 2, "expected 2 params (limit + offset)");
 }
 
+// ── render_select_sql / render_function_call_sql ───────────────────────────
+
+#[test]
+fn test_render_select_sql_follows_the_projection_branch() {
+    let (plain, _) = render_select_sql("v_user", None, None, Some(5), None, None).unwrap();
+    assert_eq!(plain, r#"SELECT data FROM "v_user" LIMIT $1"#);
+
+    let hint = SqlProjectionHint::new(
+        DatabaseType::PostgreSQL,
+        "jsonb_build_object('id', data->>'id')".to_string(),
+        50,
+    );
+    let (projected, params) =
+        render_select_sql("v_user", Some(&hint), None, Some(5), None, None).unwrap();
+    assert_eq!(
+        projected,
+        r#"SELECT jsonb_build_object('id', data->>'id') FROM "v_user" LIMIT $1"#
+    );
+    assert_eq!(params.len(), 1);
+}
+
+#[test]
+fn test_render_function_call_sql_quotes_schema_qualified_names() {
+    assert_eq!(
+        render_function_call_sql("app.fn_create_user", 2),
+        r#"SELECT * FROM "app"."fn_create_user"($1, $2)"#
+    );
+    assert_eq!(render_function_call_sql("fn_ping", 0), r#"SELECT * FROM "fn_ping"()"#);
+}
+
 #[test]
 fn test_escape_jsonb_key_no_quotes() {
     assert_eq!(escape_jsonb_key("normal"), "normal");
@@ -81,6 +116,56 @@ fn pool_prewarm_config_min_equals_max_is_valid() {
     assert_eq!(cfg.min_size, cfg.max_size);
 }
 
+// ── HotStatements (automatic PREPARE) ─────────────────────────────────────
+
+#[test]
+fn hot_statements_prepare_after_threshold() {
+    let hot = HotStatements::new(4);
+    assert!(!hot.admit("SELECT 1").prepare, "first execution runs unprepared");
+    assert!(hot.admit("SELECT 1").prepare, "second execution is prepared");
+    assert!(hot.admit("SELECT 1").prepare, "stays prepared");
+    assert_eq!(hot.prepared_len(), 1);
+}
+
+#[test]
+fn hot_statements_evict_least_recently_used() {
+    let hot = HotStatements::new(2);
+    for sql in ["SELECT 1", "SELECT 2"] {
+        hot.admit(sql);
+        hot.admit(sql);
+    }
+    // Touch "SELECT 1" so "SELECT 2" is the least recently used.
+    hot.admit("SELECT 1");
+    hot.admit("SELECT 3");
+    let admission = hot.admit("SELECT 3");
+    assert!(admission.prepare);
+    assert_eq!(admission.evicted.as_deref(), Some("SELECT 2"));
+    assert_eq!(hot.prepared_len(), 2);
+}
+
+#[test]
+fn hot_statements_clear_and_forget() {
+    let hot = HotStatements::new(4);
+    hot.admit("SELECT 1");
+    hot.admit("SELECT 1");
+    hot.forget("SELECT 1");
+    assert_eq!(hot.prepared_len(), 0);
+    assert!(!hot.admit("SELECT 1").prepare, "a forgotten statement starts over");
+
+    hot.admit("SELECT 1");
+    hot.clear();
+    assert_eq!(hot.prepared_len(), 0);
+}
+
+#[test]
+fn hot_statements_disabled_at_zero_capacity() {
+    let hot = HotStatements::new(0);
+    for _ in 0..5 {
+        assert_eq!(hot.admit("SELECT 1"), Admission::default());
+    }
+    assert_eq!(hot.prepared_len(), 0);
+}
+
 // ── EP-5: Connection pool failure paths ───────────────────────────────────
 
 #[tokio::test]
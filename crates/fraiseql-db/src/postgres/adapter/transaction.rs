@@ -0,0 +1,182 @@
+//! `MutationTransaction` implementation for `PostgresAdapter`.
+//!
+//! A transactional mutation batch holds one pooled connection for its whole
+//! lifetime and drives the transaction with explicit `BEGIN` / `COMMIT` /
+//! `ROLLBACK` statements, so each mutation call sees the writes of the calls
+//! before it and nothing persists until the batch commits.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use fraiseql_error::{FraiseQLError, Result};
+use tokio_postgres::Row;
+
+use super::{
+    PostgresAdapter,
+    database::{
+        FlexParam, apply_session_vars_on, build_changelog_cte_sql, changelog_call_params,
+        mark_cdc_mediated_on, prepare_cached_stmt, row_to_map,
+    },
+};
+use crate::{
+    identifier::quote_postgres_identifier,
+    traits::{ChangeLogWrite, MutationTransaction},
+};
+
+/// An open PostgreSQL transaction spanning several mutation function calls.
+///
+/// The connection is `None` once the transaction has been committed or rolled
+/// back. Dropping an unfinished transaction detaches its connection from the
+/// pool and closes it, so the server aborts the transaction instead of a
+/// half-finished one being handed to the next caller.
+pub(super) struct PostgresMutationTransaction {
+    client:          Option<deadpool_postgres::Client>,
+    /// Mutation timing variable to stamp before each call, when enabled.
+    timing_variable: Option<String>,
+}
+
+impl PostgresMutationTransaction {
+    /// Acquire a connection and open the transaction on it.
+    pub(super) async fn begin(adapter: &PostgresAdapter) -> Result<Self> {
+        let client = adapter.acquire_connection_with_retry().await?;
+        let mut txn = Self {
+            client:          None,
+            timing_variable: adapter
+                .mutation_timing_enabled
+                .then(|| adapter.timing_variable_name.clone()),
+        };
+        client.batch_execute("BEGIN").await.map_err(|e| FraiseQLError::Database {
+            message:   format!("Failed to start mutation transaction: {e}"),
+            sql_state: e.code().map(|c| c.code().to_string()),
+        })?;
+        // Owned from here on, so a failure below drops (and discards) it.
+        let pg_client: &tokio_postgres::Client = txn.client.insert(client);
+        // One marker for the whole batch: every call in it is FraiseQL-mediated (#366).
+        mark_cdc_mediated_on(pg_client).await?;
+        Ok(txn)
+    }
+
+    fn client(&self) -> Result<&deadpool_postgres::Client> {
+        self.client.as_ref().ok_or_else(|| FraiseQLError::Internal {
+            message: "mutation transaction used after commit or rollback".to_string(),
+            source:  None,
+        })
+    }
+
+    /// Run `statement` to finish the transaction and return the connection to
+    /// the pool. On failure the connection is discarded instead.
+    async fn finish(mut self: Box<Self>, statement: &str) -> Result<()> {
+        let client = self.client.take().ok_or_else(|| FraiseQLError::Internal {
+            message: "mutation transaction finished twice".to_string(),
+            source:  None,
+        })?;
+        if let Err(e) = client.batch_execute(statement).await {
+            drop(deadpool_postgres::Client::take(client));
+            return Err(FraiseQLError::Database {
+                message:   format!(
+                    "Failed to {} mutation transaction: {e}",
+                    statement.to_lowercase()
+                ),
+                sql_state: e.code().map(|c| c.code().to_string()),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PostgresMutationTransaction {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            drop(deadpool_postgres::Client::take(client));
+        }
+    }
+}
+
+// Reason: MutationTransaction is defined with #[async_trait]; implementations must match
+// its transformed method signatures.
+#[async_trait]
+impl MutationTransaction for PostgresMutationTransaction {
+    async fn execute_function_call(
+        &mut self,
+        function_name: &str,
+        args: &[serde_json::Value],
+        session_vars: &[(&str, &str)],
+        changelog: Option<&ChangeLogWrite<'_>>,
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>> {
+        let client = self.client()?;
+        let pg_client: &tokio_postgres::Client = client;
+
+        // Session variables are transaction-local; re-applying them per call
+        // restamps `fraiseql.started_at` so each outbox row times its own call.
+        apply_session_vars_on(pg_client, session_vars).await?;
+        if let Some(ref timing_variable) = self.timing_variable {
+            pg_client
+                .execute("SELECT set_config($1, clock_timestamp()::text, true)", &[timing_variable])
+                .await
+                .map_err(|e| FraiseQLError::Database {
+                    message:   format!("Failed to set mutation timing variable: {e}"),
+                    sql_state: e.code().map(|c| c.code().to_string()),
+                })?;
+        }
+
+        let quoted_fn = quote_postgres_identifier(function_name);
+        let (sql, flex_args) = if let Some(changelog) = changelog {
+            // The outbox INSERT reads `fraiseql.started_at` without missing_ok;
+            // guarantee it exists, as the single-call outbox path does.
+            let started_at_set =
+                session_vars.iter().any(|(name, _)| *name == crate::changelog::STARTED_AT_VAR)
+                    || self.timing_variable.as_deref() == Some(crate::changelog::STARTED_AT_VAR);
+            if !started_at_set {
+                pg_client
+                    .execute(
+                        "SELECT set_config($1, clock_timestamp()::text, true)",
+                        &[&crate::changelog::STARTED_AT_VAR],
+                    )
+                    .await
+                    .map_err(|e| FraiseQLError::Database {
+                        message:   format!("Failed to stamp change-log started_at: {e}"),
+                        sql_state: e.code().map(|c| c.code().to_string()),
+                    })?;
+            }
+            (
+                build_changelog_cte_sql(&quoted_fn, args.len(), changelog.pre_image),
+                changelog_call_params(args, changelog),
+            )
+        } else {
+            let placeholders: Vec<String> = (1..=args.len()).map(|i| format!("${i}")).collect();
+            let flex_args: Vec<FlexParam> = args
+                .iter()
+                .map(|v| match v {
+                    serde_json::Value::Null => FlexParam::Null,
+                    serde_json::Value::String(s) => FlexParam::Text(s.clone()),
+                    _ => FlexParam::Text(v.to_string()),
+                })
+                .collect();
+            (format!("SELECT * FROM {quoted_fn}({})", placeholders.join(", ")), flex_args)
+        };
+        let params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> = flex_args
+            .iter()
+            .map(|v| v as &(dyn tokio_postgres::types::ToSql + Sync))
+            .collect();
+
+        let stmt = prepare_cached_stmt(client, sql.as_str()).await?;
+        let rows: Vec<Row> = pg_client.query(&stmt, params.as_slice()).await.map_err(|e| {
+            let detail = e.as_db_error().map_or("", |d| d.message());
+            FraiseQLError::Database {
+                message:   format!(
+                    "Function call {function_name} (in mutation transaction) failed: {e}: {detail}"
+                ),
+                sql_state: e.code().map(|c| c.code().to_string()),
+            }
+        })?;
+        Ok(rows.iter().map(row_to_map).collect())
+    }
+
+    async fn commit(self: Box<Self>) -> Result<()> {
+        self.finish("COMMIT").await
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        self.finish("ROLLBACK").await
+    }
+}
@@ -312,6 +312,52 @@ impl DatabaseIntrospector for PostgresIntrospector {
 
         Ok(Some(row.get(0)))
     }
+
+    async fn expression_error(
+        &self,
+        relation: &str,
+        expression: &str,
+    ) -> Result<Option<Option<String>>> {
+        if !crate::identifier::is_safe_sql_expression(expression) {
+            return Ok(Some(Some("not a single SQL expression".to_string())));
+        }
+        let client = self.pool.get().await.map_err(|e| FraiseQLError::ConnectionPool {
+            message: format!("Failed to acquire connection: {e}"),
+        })?;
+
+        // Preparing (not executing) parses and type-checks the expression against the
+        // relation's columns without reading any rows.
+        let sql = format!(
+            "SELECT ({expression}) FROM {} LIMIT 0",
+            crate::identifier::quote_postgres_identifier(relation)
+        );
+        match client.prepare(&sql).await {
+            Ok(_) => Ok(Some(None)),
+            Err(e) => match e.as_db_error() {
+                Some(db_error) => Ok(Some(Some(db_error.message().to_string()))),
+                None => Err(FraiseQLError::Database {
+                    message:   format!("Failed to probe computed field expression: {e}"),
+                    sql_state: e.code().map(|c| c.code().to_string()),
+                }),
+            },
+        }
+    }
+
+    async fn extension_installed(&self, name: &str) -> Result<Option<bool>> {
+        let client = self.pool.get().await.map_err(|e| FraiseQLError::ConnectionPool {
+            message: format!("Failed to acquire connection: {e}"),
+        })?;
+
+        let row = client
+            .query_one("SELECT EXISTS(SELECT 1 FROM pg_extension WHERE extname = $1)", &[&name])
+            .await
+            .map_err(|e| FraiseQLError::Database {
+                message:   format!("Failed to probe extension '{name}': {e}"),
+                sql_state: e.code().map(|c| c.code().to_string()),
+            })?;
+
+        Ok(Some(row.get(0)))
+    }
 }
 
 impl PostgresIntrospector {
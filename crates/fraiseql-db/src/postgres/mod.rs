@@ -6,6 +6,9 @@ mod adapter;
 mod introspector;
 mod where_generator;
 
-pub use adapter::{PoolPrewarmConfig, PostgresAdapter};
+pub use adapter::{
+    DEFAULT_PREPARED_STATEMENT_CAPACITY, PoolPrewarmConfig, PostgresAdapter,
+    render_function_call_sql, render_select_sql,
+};
 pub use introspector::PostgresIntrospector;
 pub use where_generator::{IndexedColumnsCache, PostgresWhereGenerator};
@@ -166,3 +166,235 @@ fn test_ltree_ancestor_of() {
     assert!(sql.contains("ltree"), "Expected ::ltree: {sql}");
     assert_eq!(params.len(), 1);
 }
+
+#[test]
+fn test_text_search_binds_query_against_weighted_document() {
+    use crate::text_search::{SearchField, SearchWeight, TextSearchConfig};
+
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::TextSearch {
+        config: TextSearchConfig {
+            language: "simple".to_string(),
+            fields:   vec![SearchField {
+                field:  "title".to_string(),
+                weight: Some(SearchWeight::A),
+            }],
+        },
+        query:  "rust -java".to_string(),
+    };
+
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(
+        sql,
+        "(setweight(to_tsvector('simple', coalesce(data->>'title', '')), 'A')) @@ \
+         websearch_to_tsquery('simple', $1)"
+    );
+    assert_eq!(params, vec![json!("rust -java")]);
+}
+
+#[test]
+fn test_near_uses_postgis_by_default() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::Field {
+        path:     vec!["location".to_string()],
+        operator: WhereOperator::Near,
+        value:    json!({"lat": 52.5, "lng": 13.4, "radiusMeters": 1000}),
+    };
+
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(
+        sql,
+        "ST_DWithin(ST_SetSRID(ST_MakePoint((data->'location'->>'lng')::float8, \
+         (data->'location'->>'lat')::float8), 4326)::geography, \
+         ST_SetSRID(ST_MakePoint($2::text::float8, $1::text::float8), 4326)::geography, \
+         $3::text::float8)"
+    );
+    assert_eq!(params, vec![json!(52.5), json!(13.4), json!(1000.0)]);
+}
+
+#[test]
+fn test_geo_clause_renders_earthdistance_fallback() {
+    use crate::geo::GeoBackend;
+
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::Geo {
+        path:     vec!["location".to_string()],
+        operator: WhereOperator::WithinBoundingBox,
+        value:    json!({"minLat": 50, "minLng": 10, "maxLat": 55, "maxLng": 15}),
+        backend:  GeoBackend::EarthDistance,
+    };
+
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(
+        sql,
+        "((data->'location'->>'lat')::float8 BETWEEN $1::text::float8 AND $2::text::float8 \
+         AND (data->'location'->>'lng')::float8 BETWEEN $3::text::float8 AND $4::text::float8)"
+    );
+    assert_eq!(params, vec![json!(50.0), json!(55.0), json!(10.0), json!(15.0)]);
+}
+
+#[test]
+fn test_near_rejects_out_of_range_coordinates() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::Field {
+        path:     vec!["location".to_string()],
+        operator: WhereOperator::Near,
+        value:    json!({"lat": 95, "lng": 0, "radiusMeters": 10}),
+    };
+
+    assert!(gen.generate(&clause).is_err());
+}
+
+#[test]
+fn test_jsonb_meta_contains_and_has_key() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::And(vec![
+        WhereClause::Field {
+            path:     vec!["metadata".to_string()],
+            operator: WhereOperator::MetaContains,
+            value:    json!({"plan": "pro"}),
+        },
+        WhereClause::Field {
+            path:     vec!["metadata".to_string(), "flags".to_string()],
+            operator: WhereOperator::HasKey,
+            value:    json!("beta"),
+        },
+    ]);
+
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "(data->'metadata' @> $1::jsonb AND data->'metadata'->'flags' ? $2)");
+    assert_eq!(params, vec![json!({"plan": "pro"}), json!("beta")]);
+}
+
+#[test]
+fn test_jsonb_has_any_and_all_keys() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::Field {
+        path:     vec!["metadata".to_string()],
+        operator: WhereOperator::HasAllKeys,
+        value:    json!(["a", "b"]),
+    };
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "data->'metadata' ?& ARRAY[$1, $2]");
+    assert_eq!(params, vec![json!("a"), json!("b")]);
+
+    let clause = WhereClause::Field {
+        path:     vec!["metadata".to_string()],
+        operator: WhereOperator::HasAnyKeys,
+        value:    json!([]),
+    };
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "FALSE");
+    assert!(params.is_empty());
+
+    let clause = WhereClause::Field {
+        path:     vec!["metadata".to_string()],
+        operator: WhereOperator::HasAnyKeys,
+        value:    json!(["a", 1]),
+    };
+    assert!(gen.generate(&clause).is_err());
+}
+
+#[test]
+fn test_jsonb_path_exists() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::Field {
+        path:     vec!["metadata".to_string()],
+        operator: WhereOperator::JsonPath,
+        value:    json!("$.items[*] ? (@.qty > 10)"),
+    };
+
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "jsonb_path_exists(data->'metadata', $1::jsonpath)");
+    assert_eq!(params, vec![json!("$.items[*] ? (@.qty > 10)")]);
+}
+
+#[test]
+fn test_array_operators_from_list_values() {
+    let cases = [
+        (json!({"tags": {"contains": ["rust"]}}), "(data->>'tags')::jsonb @> $1::jsonb"),
+        (
+            json!({"tags": {"containedBy": ["rust", "go"]}}),
+            "(data->>'tags')::jsonb <@ $1::jsonb",
+        ),
+        (json!({"tags": {"overlaps": ["wasm"]}}), "(data->>'tags')::jsonb && $1::jsonb"),
+        (
+            json!({"tags": {"lengthGt": 0}}),
+            "jsonb_array_length((data->>'tags')::jsonb) > $1",
+        ),
+    ];
+    for (filter, expected) in cases {
+        let gen = PostgresWhereGenerator::new(PostgresDialect);
+        let clause = WhereClause::from_graphql_json(&filter).unwrap();
+        let (sql, _params) = gen.generate(&clause).unwrap();
+        assert_eq!(sql, expected, "filter: {filter}");
+    }
+}
+
+#[test]
+fn test_contains_with_string_value_stays_substring_match() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let clause = WhereClause::Field {
+        path:     vec!["title".to_string()],
+        operator: WhereOperator::Contains,
+        value:    json!("rust"),
+    };
+
+    let (sql, _params) = gen.generate(&clause).unwrap();
+    assert!(sql.contains("LIKE"), "Expected LIKE: {sql}");
+}
+
+fn orders_relation(
+    quantifier: crate::relation_filter::RelationQuantifier,
+) -> crate::relation_filter::RelationFilter {
+    use crate::relation_filter::{RelationFilter, RelationJoin};
+
+    RelationFilter {
+        path: vec!["orders".to_string()],
+        quantifier,
+        filter: WhereClause::Field {
+            path:     vec!["status".to_string()],
+            operator: WhereOperator::Eq,
+            value:    json!("paid"),
+        },
+        join: Some(RelationJoin {
+            source:     "public.v_order".to_string(),
+            alias:      "_r0".to_string(),
+            key:        "user_id".to_string(),
+            parent:     "public.v_user".to_string(),
+            parent_key: "id".to_string(),
+        }),
+    }
+}
+
+#[test]
+fn test_relation_filter_renders_exists_subqueries() {
+    use crate::relation_filter::RelationQuantifier;
+
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let join = "SELECT 1 FROM \"public\".\"v_order\" AS \"_r0\" \
+                WHERE \"_r0\".data->>'user_id' = \"public\".\"v_user\".data->>'id'";
+    let render = |quantifier| {
+        gen.generate(&WhereClause::Relation(Box::new(orders_relation(quantifier))))
+            .unwrap()
+    };
+
+    let (sql, params) = render(RelationQuantifier::Some);
+    assert_eq!(sql, format!("EXISTS ({join} AND data->>'status' = $1)"));
+    assert_eq!(params, vec![json!("paid")]);
+
+    let (sql, _) = render(RelationQuantifier::None);
+    assert_eq!(sql, format!("NOT EXISTS ({join} AND data->>'status' = $1)"));
+
+    let (sql, _) = render(RelationQuantifier::Every);
+    assert_eq!(sql, format!("NOT EXISTS ({join} AND (data->>'status' = $1) IS NOT TRUE)"));
+}
+
+#[test]
+fn test_unbound_relation_filter_is_rejected() {
+    let gen = PostgresWhereGenerator::new(PostgresDialect);
+    let mut relation = orders_relation(crate::relation_filter::RelationQuantifier::Some);
+    relation.join = None;
+
+    assert!(gen.generate(&WhereClause::Relation(Box::new(relation))).is_err());
+}
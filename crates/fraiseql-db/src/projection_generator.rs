@@ -80,6 +80,12 @@ pub struct ProjectionField {
     /// List fields should always use `None` — sub-projection inside aggregated
     /// JSONB arrays is out of scope for this first iteration.
     pub sub_fields: Option<Vec<ProjectionField>>,
+
+    /// SQL expression of a computed field, rendered verbatim (parenthesised) in
+    /// place of the JSONB extraction. Evaluated against the queried relation, so
+    /// only meaningful for top-level fields. Must pass
+    /// [`is_safe_sql_expression`](crate::is_safe_sql_expression).
+    pub expression: Option<String>,
 }
 
 impl ProjectionField {
@@ -95,6 +101,7 @@ impl ProjectionField {
             name,
             kind: FieldKind::Text,
             sub_fields: None,
+            expression: None,
         }
     }
 
@@ -111,6 +118,7 @@ impl ProjectionField {
             name,
             kind: FieldKind::Native,
             sub_fields: None,
+            expression: None,
         }
     }
 
@@ -123,6 +131,7 @@ impl ProjectionField {
             name,
             kind: FieldKind::Composite,
             sub_fields: None,
+            expression: None,
         }
     }
 
@@ -138,9 +147,17 @@ impl ProjectionField {
             name,
             kind: FieldKind::Composite,
             sub_fields: Some(sub_fields),
+            expression: None,
         }
     }
 
+    /// Compute this field from a SQL expression instead of reading its JSONB key.
+    #[must_use]
+    pub fn with_expression(mut self, expression: impl Into<String>) -> Self {
+        self.expression = Some(expression.into());
+        self
+    }
+
     /// Whether this field is a composite type (Object or List).
     #[must_use]
     pub const fn is_composite(&self) -> bool {
@@ -316,6 +333,50 @@ impl PostgresProjectionGenerator {
         Ok(format!("jsonb_build_object({})", field_pairs.join(",")))
     }
 
+    /// Generate a full-row projection that overlays computed fields onto the JSONB column.
+    ///
+    /// Used when no typed projection is emitted (stream strategy, policy-gated
+    /// selections, REST reads) but the returned type declares computed fields:
+    /// each expression is stored under its snake_case key so the result
+    /// projector resolves it exactly like a stored field.
+    ///
+    /// # Arguments
+    ///
+    /// * `computed` - `(field name, SQL expression)` pairs
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Validation` if any expression is not a single
+    /// safe SQL expression.
+    pub fn generate_computed_overlay_sql(&self, computed: &[(&str, &str)]) -> Result<String> {
+        if computed.is_empty() {
+            return Ok(format!("\"{}\"", self.jsonb_column));
+        }
+
+        let field_pairs = computed
+            .iter()
+            .map(|(name, expression)| {
+                if !crate::is_safe_sql_expression(expression) {
+                    return Err(FraiseQLError::Validation {
+                        message: format!(
+                            "computed field '{name}' has an expression that cannot be safely \
+                             projected"
+                        ),
+                        path:    None,
+                    });
+                }
+                let jsonb_key = Self::escape_sql_string(&to_snake_case(name));
+                Ok(format!("'{jsonb_key}', ({expression})"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(format!(
+            "\"{}\" || jsonb_build_object({})",
+            self.jsonb_column,
+            field_pairs.join(",")
+        ))
+    }
+
     /// Recursively render one projection field as a `'key', <expr>` pair for
     /// `jsonb_build_object`.
     ///
@@ -332,6 +393,19 @@ impl PostgresProjectionGenerator {
         let jsonb_key = to_snake_case(&field.source);
         let safe_jsonb_key = Self::escape_sql_string(&jsonb_key);
 
+        if let Some(expression) = &field.expression {
+            if !crate::is_safe_sql_expression(expression) {
+                return Err(FraiseQLError::Validation {
+                    message: format!(
+                        "computed field '{}' has an expression that cannot be safely projected",
+                        field.source
+                    ),
+                    path:    None,
+                });
+            }
+            return Ok(format!("'{}', ({})", resp_key, expression));
+        }
+
         // Recurse into Object sub-fields when available and within depth limit.
         if depth < MAX_PROJECTION_DEPTH {
             if let Some(subs) = &field.sub_fields {
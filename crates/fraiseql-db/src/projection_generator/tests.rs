@@ -539,3 +539,49 @@ fn test_typed_projection_depth_2_recursion() {
     );
     assert!(sql.contains("'profile'->>'bio'"), "bio must use depth-2 path, got: {sql}");
 }
+
+#[test]
+fn test_typed_projection_computed_field_renders_expression() {
+    let generator = PostgresProjectionGenerator::new();
+    let fields = vec![
+        ProjectionField::scalar("id"),
+        ProjectionField::scalar("fullName")
+            .with_expression("data->>'first' || ' ' || data->>'last'"),
+    ];
+    let sql = generator.generate_typed_projection_sql(&fields).unwrap();
+    assert_eq!(
+        sql,
+        "jsonb_build_object('id', \"data\"->>'id','fullName', (data->>'first' || ' ' || \
+         data->>'last'))"
+    );
+}
+
+#[test]
+fn test_typed_projection_rejects_unsafe_computed_expression() {
+    let generator = PostgresProjectionGenerator::new();
+    let fields = vec![ProjectionField::scalar("x").with_expression("1) FROM secrets --")];
+    let err = generator.generate_typed_projection_sql(&fields).unwrap_err();
+    assert!(matches!(err, FraiseQLError::Validation { .. }), "got {err:?}");
+}
+
+#[test]
+fn test_computed_overlay_merges_expressions_into_row() {
+    let generator = PostgresProjectionGenerator::new();
+    let sql = generator
+        .generate_computed_overlay_sql(&[("fullName", "data->>'first' || ' ' || data->>'last'")])
+        .unwrap();
+    assert_eq!(
+        sql,
+        "\"data\" || jsonb_build_object('full_name', (data->>'first' || ' ' || data->>'last'))"
+    );
+    assert_eq!(generator.generate_computed_overlay_sql(&[]).unwrap(), "\"data\"");
+}
+
+#[test]
+fn test_computed_overlay_rejects_unsafe_expression() {
+    let generator = PostgresProjectionGenerator::new();
+    let err = generator
+        .generate_computed_overlay_sql(&[("x", "1; DELETE FROM t")])
+        .unwrap_err();
+    assert!(matches!(err, FraiseQLError::Validation { .. }), "got {err:?}");
+}
@@ -0,0 +1,87 @@
+//! Quantified filters over related rows.
+//!
+//! `users(where: { orders: { some: { total: { gt: 100 } } } })` keeps users with
+//! at least one matching order. The filter compiles to a correlated `EXISTS`
+//! subquery against the related view:
+//!
+//! - `some`  — `EXISTS (… AND <filter>)`
+//! - `none`  — `NOT EXISTS (… AND <filter>)`
+//! - `every` — `NOT EXISTS (… AND (<filter>) IS NOT TRUE)`
+//!
+//! [`WhereClause::from_graphql_json`](crate::WhereClause::from_graphql_json)
+//! only records the quantifier and the nested filter; the join (related view
+//! and key columns) comes from the schema's relationships and is filled in by
+//! the runtime before SQL generation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::where_clause::WhereClause;
+
+/// How many related rows must match the nested filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RelationQuantifier {
+    /// At least one related row matches.
+    Some,
+    /// Every related row matches (vacuously true with no related rows).
+    Every,
+    /// No related row matches.
+    None,
+}
+
+impl RelationQuantifier {
+    /// Parse a quantifier key (`some`, `every`, `none`).
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "some" => Some(Self::Some),
+            "every" => Some(Self::Every),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// The GraphQL key of this quantifier.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Some => "some",
+            Self::Every => "every",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Join between the row being filtered (the parent) and a related view.
+///
+/// Keys are JSONB keys of each side's `data` column, matching the
+/// `foreign_key` / `referenced_key` of the schema relationship.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelationJoin {
+    /// Related view (may be schema-qualified).
+    pub source:     String,
+    /// Alias of the related view inside the subquery; unique per nesting depth
+    /// so a nested filter on the same view still reaches its own parent row.
+    pub alias:      String,
+    /// JSONB key on the related row.
+    pub key:        String,
+    /// Relation or alias the parent row is read from.
+    pub parent:     String,
+    /// JSONB key on the parent row.
+    pub parent_key: String,
+}
+
+/// A quantified filter on the rows related through the field at `path`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationFilter {
+    /// Relationship field path (e.g. `["orders"]`).
+    pub path:       Vec<String>,
+    /// `some`, `every` or `none`.
+    pub quantifier: RelationQuantifier,
+    /// Filter on the related rows, with paths relative to the related type.
+    pub filter:     WhereClause,
+    /// Resolved join; `None` until the runtime binds the relationship.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join:       Option<RelationJoin>,
+}
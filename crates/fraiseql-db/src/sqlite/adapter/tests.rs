@@ -214,12 +214,13 @@ async fn test_direct_mutation_insert() {
     let values = vec![json!("Alice"), json!("alice@example.com")];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Insert,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &[],
-        return_type:    "User",
+        operation:          DirectMutationOp::Insert,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let rows = adapter.execute_direct_mutation(&ctx).await.unwrap();
@@ -244,12 +245,13 @@ async fn test_direct_mutation_insert_with_inject_params() {
     let values = vec![json!("Bob"), json!("bob@example.com"), json!("tenant-42")];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Insert,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &inject_columns,
-        return_type:    "User",
+        operation:          DirectMutationOp::Insert,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &inject_columns,
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let rows = adapter.execute_direct_mutation(&ctx).await.unwrap();
@@ -273,12 +275,13 @@ async fn test_direct_mutation_update() {
     let values = vec![json!(1), json!("Alice Updated")];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Update,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &[],
-        return_type:    "User",
+        operation:          DirectMutationOp::Update,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let rows = adapter.execute_direct_mutation(&ctx).await.unwrap();
@@ -305,12 +308,13 @@ async fn test_direct_mutation_delete() {
     let values = vec![json!(1)];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Delete,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &[],
-        return_type:    "User",
+        operation:          DirectMutationOp::Delete,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let rows = adapter.execute_direct_mutation(&ctx).await.unwrap();
@@ -324,6 +328,49 @@ async fn test_direct_mutation_delete() {
     assert!(remaining.is_empty());
 }
 
+#[tokio::test]
+async fn test_direct_mutation_soft_delete_stamps_column() {
+    use crate::traits::DirectMutationOp;
+
+    let adapter = setup_mutation_table().await;
+    adapter
+        .pool
+        .execute("ALTER TABLE \"users\" ADD COLUMN deleted_at TEXT")
+        .await
+        .unwrap();
+    adapter
+        .pool
+        .execute("INSERT INTO \"users\" (name, email) VALUES ('Alice', 'alice@example.com')")
+        .await
+        .unwrap();
+
+    let columns = vec!["pk_user".to_string()];
+    let values = vec![json!(1)];
+
+    let ctx = DirectMutationContext {
+        operation:          DirectMutationOp::Delete,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: Some("deleted_at"),
+    };
+
+    let rows = adapter.execute_direct_mutation(&ctx).await.unwrap();
+    assert_eq!(rows[0]["status"], json!("deleted"));
+    assert!(rows[0]["entity"]["deleted_at"].is_string(), "deleted_at is stamped");
+
+    // The row is kept, and deleting it again affects no rows.
+    let remaining = adapter.execute_raw_query("SELECT * FROM \"users\"").await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    let err = adapter
+        .execute_direct_mutation(&ctx)
+        .await
+        .expect_err("an already-deleted row is not deleted again");
+    assert!(err.to_string().contains("no rows"));
+}
+
 #[tokio::test]
 async fn test_direct_mutation_delete_nonexistent_row() {
     use crate::traits::DirectMutationOp;
@@ -333,12 +380,13 @@ async fn test_direct_mutation_delete_nonexistent_row() {
     let values = vec![json!(999)];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Delete,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &[],
-        return_type:    "User",
+        operation:          DirectMutationOp::Delete,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let err = adapter
@@ -365,12 +413,13 @@ async fn test_direct_mutation_constraint_violation() {
     let values = vec![json!("Bob"), json!("alice@example.com")];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Insert,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &[],
-        return_type:    "User",
+        operation:          DirectMutationOp::Insert,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let err = adapter
@@ -396,12 +445,13 @@ async fn test_direct_mutation_null_handling() {
     let values = vec![json!(1), serde_json::Value::Null];
 
     let ctx = DirectMutationContext {
-        operation:      DirectMutationOp::Update,
-        table:          "users",
-        columns:        &columns,
-        values:         &values,
-        inject_columns: &[],
-        return_type:    "User",
+        operation:          DirectMutationOp::Update,
+        table:              "users",
+        columns:            &columns,
+        values:             &values,
+        inject_columns:     &[],
+        return_type:        "User",
+        soft_delete_column: None,
     };
 
     let rows = adapter.execute_direct_mutation(&ctx).await.unwrap();
@@ -129,6 +129,7 @@ pub(super) fn build_direct_mutation_sql<'a>(
         },
         DirectMutationOp::Delete => {
             // DELETE FROM "table" WHERE "pk_col" = ? [AND "inject_col" = ?] RETURNING *
+            // (an UPDATE of the soft-delete column when the table has one)
             if ctx.columns.is_empty() {
                 return Err(FraiseQLError::Validation {
                     message: "DELETE mutation requires at least one argument (primary key)".into(),
@@ -142,11 +143,22 @@ pub(super) fn build_direct_mutation_sql<'a>(
                 where_parts.push(format!("{} = ?", quote_sqlite_identifier(ic)));
             }
 
-            let sql = format!(
-                "DELETE FROM {} WHERE {} RETURNING *",
-                quote_sqlite_identifier(ctx.table),
-                where_parts.join(" AND ")
-            );
+            // Soft delete: stamp the column, skipping rows already deleted.
+            let sql = if let Some(column) = ctx.soft_delete_column {
+                let column = quote_sqlite_identifier(column);
+                format!(
+                    "UPDATE {} SET {column} = CURRENT_TIMESTAMP WHERE {} AND {column} IS NULL \
+                     RETURNING *",
+                    quote_sqlite_identifier(ctx.table),
+                    where_parts.join(" AND ")
+                )
+            } else {
+                format!(
+                    "DELETE FROM {} WHERE {} RETURNING *",
+                    quote_sqlite_identifier(ctx.table),
+                    where_parts.join(" AND ")
+                )
+            };
 
             // Bind order: PK value, then inject values
             let mut bind_values: Vec<&serde_json::Value> = Vec::with_capacity(1 + n_inject);
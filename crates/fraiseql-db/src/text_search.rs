@@ -0,0 +1,175 @@
+//! PostgreSQL full-text search over a type's JSONB fields.
+//!
+//! A [`TextSearchConfig`] names the fields that make up a type's search
+//! document, each with an optional `setweight` class, and the text search
+//! configuration (language) used to parse both the document and the query.
+//! It renders the three SQL fragments a searched list query needs:
+//!
+//! - the document: `setweight(to_tsvector('english', coalesce(data->>'title', '')), 'A') || …`
+//! - the match predicate, via [`WhereClause::TextSearch`](crate::WhereClause::TextSearch)
+//! - the relevance rank used for ordering and the `rank` field ([`TextSearchConfig::rank_sql`])
+
+use fraiseql_error::{FraiseQLError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{dialect::SqlDialect, utils::to_snake_case};
+
+/// A `setweight` class, from most (`A`) to least (`D`) relevant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SearchWeight {
+    /// Highest weight (titles, names).
+    A,
+    /// Second weight.
+    B,
+    /// Third weight.
+    C,
+    /// Lowest weight — also PostgreSQL's default for unweighted lexemes.
+    D,
+}
+
+impl SearchWeight {
+    /// The weight letter as used by `setweight`.
+    #[must_use]
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+        }
+    }
+}
+
+/// One field contributing to a search document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchField {
+    /// GraphQL field name; read from its `snake_case` JSONB key.
+    pub field: String,
+
+    /// Weight class; unweighted when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<SearchWeight>,
+}
+
+/// Full-text search configuration of a type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSearchConfig {
+    /// Text search configuration name (e.g. `english`, `simple`).
+    #[serde(default = "default_language")]
+    pub language: String,
+
+    /// Fields making up the search document, in order.
+    pub fields: Vec<SearchField>,
+}
+
+fn default_language() -> String {
+    "english".to_string()
+}
+
+impl TextSearchConfig {
+    /// Check the configuration can be rendered into SQL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Validation` if there are no fields, or if the
+    /// language or a field name is not a plain identifier.
+    pub fn validate(&self) -> Result<()> {
+        if self.fields.is_empty() {
+            return Err(FraiseQLError::validation("full-text search requires at least one field"));
+        }
+        if !is_identifier(&self.language, true) {
+            return Err(FraiseQLError::validation(format!(
+                "invalid full-text search language '{}'",
+                self.language
+            )));
+        }
+        if let Some(bad) = self.fields.iter().find(|f| !is_identifier(&f.field, false)) {
+            return Err(FraiseQLError::validation(format!(
+                "invalid full-text search field '{}'",
+                bad.field
+            )));
+        }
+        Ok(())
+    }
+
+    /// Render the weighted `tsvector` document over the `data` column.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Validation` if the configuration is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fraiseql_db::text_search::{SearchField, SearchWeight, TextSearchConfig};
+    ///
+    /// let config = TextSearchConfig {
+    ///     language: "english".to_string(),
+    ///     fields:   vec![SearchField { field: "title".to_string(), weight: Some(SearchWeight::A) }],
+    /// };
+    /// assert_eq!(
+    ///     config.document_sql().unwrap(),
+    ///     "setweight(to_tsvector('english', coalesce(data->>'title', '')), 'A')"
+    /// );
+    /// ```
+    pub fn document_sql(&self) -> Result<String> {
+        self.validate()?;
+        let parts: Vec<String> = self
+            .fields
+            .iter()
+            .map(|f| {
+                let expr =
+                    crate::PostgresDialect.json_extract_scalar("data", &[to_snake_case(&f.field)]);
+                let vector = format!("to_tsvector('{}', coalesce({expr}, ''))", self.language);
+                match f.weight {
+                    Some(w) => format!("setweight({vector}, '{}')", w.as_sql()),
+                    None => vector,
+                }
+            })
+            .collect();
+        Ok(parts.join(" || "))
+    }
+
+    /// Render the `websearch_to_tsquery` for a query placeholder or literal.
+    #[must_use]
+    pub fn tsquery_sql(&self, query: &str) -> String {
+        format!("websearch_to_tsquery('{}', {query})", self.language)
+    }
+
+    /// Render the relevance rank of a row against `query`.
+    ///
+    /// The query is inlined as an escaped string literal, since it is used in
+    /// the `SELECT` list and `ORDER BY`, which carry no bind parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Validation` if the configuration is invalid or
+    /// the query contains a NUL character.
+    pub fn rank_sql(&self, query: &str) -> Result<String> {
+        let literal = quote_postgres_literal(query)?;
+        Ok(format!("ts_rank({}, {})", self.document_sql()?, self.tsquery_sql(&literal)))
+    }
+}
+
+/// Quote a string as a PostgreSQL escape-string literal.
+///
+/// Mirrors libpq's `PQescapeLiteral`: quotes and backslashes are doubled and
+/// the `E''` form is used so the result is read identically whatever the
+/// server's `standard_conforming_strings` setting.
+fn quote_postgres_literal(value: &str) -> Result<String> {
+    if value.contains('\0') {
+        return Err(FraiseQLError::validation("search text must not contain NUL characters"));
+    }
+    Ok(format!("E'{}'", value.replace('\\', "\\\\").replace('\'', "''")))
+}
+
+/// `[_A-Za-z][_0-9A-Za-z]*`, optionally schema-qualified with `.`.
+fn is_identifier(s: &str, allow_dot: bool) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || (allow_dot && c == '.'))
+}
+
+#[cfg(test)]
+mod tests;
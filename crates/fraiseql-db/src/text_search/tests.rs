@@ -0,0 +1,79 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use super::*;
+
+fn config() -> TextSearchConfig {
+    TextSearchConfig {
+        language: "english".to_string(),
+        fields:   vec![
+            SearchField {
+                field:  "title".to_string(),
+                weight: Some(SearchWeight::A),
+            },
+            SearchField {
+                field:  "bodyText".to_string(),
+                weight: None,
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_document_weights_and_recases_fields() {
+    assert_eq!(
+        config().document_sql().unwrap(),
+        "setweight(to_tsvector('english', coalesce(data->>'title', '')), 'A') || \
+         to_tsvector('english', coalesce(data->>'body_text', ''))"
+    );
+}
+
+#[test]
+fn test_rank_inlines_escaped_query() {
+    let sql = config().rank_sql(r"it's a \ test").unwrap();
+    assert!(
+        sql.ends_with(r"websearch_to_tsquery('english', E'it''s a \\ test'))"),
+        "got {sql}"
+    );
+    assert!(crate::is_safe_sql_expression(&sql), "rank must stay a single expression: {sql}");
+}
+
+#[test]
+fn test_rank_keeps_injection_attempt_inside_literal() {
+    let sql = config().rank_sql("x'); DROP TABLE users; --").unwrap();
+    assert!(sql.contains("E'x''); DROP TABLE users; --'"), "got {sql}");
+    assert!(crate::is_safe_sql_expression(&sql), "got {sql}");
+}
+
+#[test]
+fn test_rank_rejects_nul() {
+    assert!(config().rank_sql("a\0b").is_err());
+}
+
+#[test]
+fn test_validate_rejects_bad_config() {
+    let mut bad = config();
+    bad.language = "english'); --".to_string();
+    assert!(bad.validate().is_err());
+
+    let mut bad = config();
+    bad.fields[0].field = "ti'tle".to_string();
+    assert!(bad.validate().is_err());
+
+    let mut bad = config();
+    bad.fields.clear();
+    assert!(bad.validate().is_err());
+
+    let mut qualified = config();
+    qualified.language = "pg_catalog.simple".to_string();
+    assert!(qualified.validate().is_ok());
+}
+
+#[test]
+fn test_config_deserializes_with_default_language() {
+    let config: TextSearchConfig = serde_json::from_value(
+        serde_json::json!({ "fields": [{ "field": "title", "weight": "B" }] }),
+    )
+    .unwrap();
+    assert_eq!(config.language, "english");
+    assert_eq!(config.fields[0].weight, Some(SearchWeight::B));
+}
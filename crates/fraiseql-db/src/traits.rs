@@ -13,7 +13,7 @@ use std::sync::Arc;
 pub use adapter_types::*;
 use async_trait::async_trait;
 use fraiseql_error::{FraiseQLError, Result};
-pub use mutations::SupportsMutations;
+pub use mutations::{MutationTransaction, SupportsMutations};
 pub use relay::RelayDatabaseAdapter;
 
 use crate::{
@@ -1119,6 +1119,29 @@ pub trait DatabaseAdapter: Send + Sync {
         })
     }
 
+    /// Open a transaction spanning several mutation function calls.
+    ///
+    /// Backs transactional mutation batches: the executor runs each top-level
+    /// mutation of a request through the returned [`MutationTransaction`] and
+    /// commits only when all of them succeed, so a failure part-way through
+    /// rolls back the mutations that already ran.
+    ///
+    /// Only the PostgreSQL adapter overrides this today; every other adapter
+    /// keeps the default below, which returns `Unsupported` rather than running
+    /// the batch without atomicity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Unsupported` on adapters without multi-call
+    /// transactions, and `FraiseQLError::Database` if the transaction cannot be
+    /// started.
+    async fn begin_mutation_transaction(&self) -> Result<Box<dyn MutationTransaction>> {
+        Err(FraiseQLError::Unsupported {
+            message: "Transactional mutation batches are only supported by the PostgreSQL adapter."
+                .to_string(),
+        })
+    }
+
     /// Connection-affine variant of [`execute_where_query_arc`](Self::execute_where_query_arc).
     ///
     /// Applies `session_vars` transaction-locally on the same connection that
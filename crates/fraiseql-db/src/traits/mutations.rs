@@ -1,8 +1,14 @@
-//! Mutation support marker trait.
+//! Mutation support traits.
 //!
-//! [`SupportsMutations`] is a compile-time marker that gates mutation dispatch.
+//! [`SupportsMutations`] is a compile-time marker that gates mutation dispatch;
+//! [`MutationTransaction`] runs several mutation calls in one transaction.
 
-use super::DatabaseAdapter;
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use fraiseql_error::Result;
+
+use super::{ChangeLogWrite, DatabaseAdapter};
 
 /// Marker trait for database adapters that support stored-procedure mutations.
 ///
@@ -31,3 +37,49 @@ use super::DatabaseAdapter;
 /// | `FraiseWireAdapter` | ❌ No — read-only wire protocol |
 /// | `CachedDatabaseAdapter<A>` | ✅ When `A: SupportsMutations` |
 pub trait SupportsMutations: DatabaseAdapter {}
+
+/// A database transaction spanning several mutation function calls.
+///
+/// Opened by [`DatabaseAdapter::begin_mutation_transaction`] to run a batch of
+/// top-level mutations with all-or-nothing semantics: every call runs on the
+/// same connection and transaction, and nothing persists until
+/// [`commit`](Self::commit). Dropping the handle without committing or rolling
+/// back discards the connection, so the server aborts the transaction.
+// Reason: async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+pub trait MutationTransaction: Send {
+    /// Call one mutation function inside the transaction.
+    ///
+    /// Mirrors
+    /// [`DatabaseAdapter::execute_function_call_with_changelog`]: `session_vars`
+    /// are applied transaction-locally before the call, and the change-log
+    /// outbox row (when `changelog` is `Some`) is written in the same
+    /// transaction, so a rollback discards it with the mutation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Database` if the call fails. The transaction is
+    /// then unusable; the caller must roll it back.
+    async fn execute_function_call(
+        &mut self,
+        function_name: &str,
+        args: &[serde_json::Value],
+        session_vars: &[(&str, &str)],
+        changelog: Option<&ChangeLogWrite<'_>>,
+    ) -> Result<Vec<HashMap<String, serde_json::Value>>>;
+
+    /// Commit every call made in the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Database` if the commit fails; nothing persists.
+    async fn commit(self: Box<Self>) -> Result<()>;
+
+    /// Discard every call made in the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Database` if the rollback statement fails; the
+    /// connection is discarded, so nothing persists either way.
+    async fn rollback(self: Box<Self>) -> Result<()>;
+}
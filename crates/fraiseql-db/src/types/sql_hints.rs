@@ -8,7 +8,7 @@
 use fraiseql_error::{FraiseQLError, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::{types::db_types::DatabaseType, utils::to_snake_case};
+use crate::{relation_filter::RelationJoin, types::db_types::DatabaseType, utils::to_snake_case};
 
 /// SQL sort type for ORDER BY cast generation.
 ///
@@ -66,15 +66,90 @@ pub struct OrderByClause {
     /// enabling index support and correct typing without casts.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub native_column: Option<String>,
+    /// Server-built SQL expression to sort by instead of the field, e.g. a
+    /// full-text search rank. Never read from client input.
+    #[serde(skip)]
+    pub expression:    Option<String>,
+    /// Sort by an aggregate of the child relation named `field` instead of a
+    /// field of the row itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregate:     Option<OrderByAggregate>,
 }
 
-/// Sort direction
+/// Aggregate function for ordering by a child relation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum OrderByAggregateFunction {
+    /// Number of related rows.
+    Count,
+    /// Sum of a related field.
+    Sum,
+    /// Average of a related field.
+    Avg,
+    /// Smallest value of a related field.
+    Min,
+    /// Largest value of a related field.
+    Max,
+}
+
+impl OrderByAggregateFunction {
+    /// Parse an aggregate key (`count`, `sum`, `avg`, `min`, `max`).
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "count" => Some(Self::Count),
+            "sum" => Some(Self::Sum),
+            "avg" => Some(Self::Avg),
+            "min" => Some(Self::Min),
+            "max" => Some(Self::Max),
+            _ => None,
+        }
+    }
+
+    /// Return the SQL aggregate function name.
+    #[must_use]
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            Self::Count => "COUNT",
+            Self::Sum => "SUM",
+            Self::Avg => "AVG",
+            Self::Min => "MIN",
+            Self::Max => "MAX",
+        }
+    }
+}
+
+/// Aggregate of a child relation to sort by, rendered as a correlated subquery
+/// against the related view.
+///
+/// Parsed from `orderBy: { orders: { max: { total: DESC } } }`; the runtime
+/// fills in `join` only for aggregates the type allows, and rendering an
+/// unbound aggregate is an error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderByAggregate {
+    /// Aggregate function.
+    pub function: OrderByAggregateFunction,
+    /// Aggregated field of the related type (GraphQL name); `None` for `count`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field:    Option<String>,
+    /// Resolved join to the related view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join:     Option<RelationJoin>,
+}
+
+/// Sort direction
+///
+/// Deserializes from `Asc`/`Desc` as well as the GraphQL spellings `ASC`/`DESC`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum OrderDirection {
     /// Ascending (A-Z, 0-9)
+    #[default]
+    #[serde(alias = "ASC", alias = "asc")]
     Asc,
     /// Descending (Z-A, 9-0)
+    #[serde(alias = "DESC", alias = "desc")]
     Desc,
 }
 
@@ -98,6 +173,17 @@ impl OrderByClause {
             direction,
             field_type: OrderByFieldType::default(),
             native_column: None,
+            expression: None,
+            aggregate: None,
+        }
+    }
+
+    /// Sort by a server-built SQL expression, reported under `field`.
+    #[must_use]
+    pub fn expression(field: String, expression: String, direction: OrderDirection) -> Self {
+        Self {
+            expression: Some(expression),
+            ..Self::new(field, direction)
         }
     }
 
@@ -149,6 +235,10 @@ impl OrderByClause {
     /// - Object: `{ "name": "DESC", "created_at": "ASC" }`
     /// - Array:  `[{ "field": "name", "direction": "DESC" }]`
     ///
+    /// In the object format a child relation may be ordered by an aggregate:
+    /// `{ "orders": { "count": "DESC" } }` or
+    /// `{ "orders": { "max": { "total": "DESC" } } }`.
+    ///
     /// Direction strings are case-insensitive.
     ///
     /// # Errors
@@ -159,22 +249,15 @@ impl OrderByClause {
             // Object format: { "name": "DESC", "created_at": "ASC" }
             obj.iter()
                 .map(|(field, dir_val)| {
+                    if let Some(spec) = dir_val.as_object() {
+                        Self::validate_field_name(field)?;
+                        return Self::parse_aggregate(field, spec);
+                    }
                     let dir_str = dir_val.as_str().ok_or_else(|| FraiseQLError::Validation {
                         message: format!("orderBy direction for '{field}' must be a string"),
                         path:    None,
                     })?;
-                    let direction = match dir_str.to_ascii_uppercase().as_str() {
-                        "ASC" => OrderDirection::Asc,
-                        "DESC" => OrderDirection::Desc,
-                        _ => {
-                            return Err(FraiseQLError::Validation {
-                                message: format!(
-                                    "orderBy direction '{dir_str}' must be ASC or DESC"
-                                ),
-                                path:    None,
-                            });
-                        },
-                    };
+                    let direction = parse_direction(dir_str)?;
                     Self::validate_field_name(field)?;
                     Ok(Self::new(field.clone(), direction))
                 })
@@ -196,18 +279,7 @@ impl OrderByClause {
                         })?
                         .to_string();
                     let dir_str = obj.get("direction").and_then(|v| v.as_str()).unwrap_or("ASC");
-                    let direction = match dir_str.to_ascii_uppercase().as_str() {
-                        "ASC" => OrderDirection::Asc,
-                        "DESC" => OrderDirection::Desc,
-                        _ => {
-                            return Err(FraiseQLError::Validation {
-                                message: format!(
-                                    "orderBy direction '{dir_str}' must be ASC or DESC"
-                                ),
-                                path:    None,
-                            });
-                        },
-                    };
+                    let direction = parse_direction(dir_str)?;
                     Self::validate_field_name(&field)?;
                     Ok(Self::new(field, direction))
                 })
@@ -219,6 +291,57 @@ impl OrderByClause {
             })
         }
     }
+
+    /// Parse the aggregate ordering of child relation `relation`:
+    /// `{ "count": "DESC" }` or `{ "<sum|avg|min|max>": { "<field>": "DESC" } }`.
+    fn parse_aggregate(
+        relation: &str,
+        spec: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<Self> {
+        let invalid = || FraiseQLError::Validation {
+            message: format!(
+                "orderBy aggregate for '{relation}' must be {{ count: ASC|DESC }} or \
+                 {{ sum|avg|min|max: {{ <field>: ASC|DESC }} }}"
+            ),
+            path:    None,
+        };
+        let mut entries = spec.iter();
+        let (Some((key, value)), None) = (entries.next(), entries.next()) else {
+            return Err(invalid());
+        };
+        let function = OrderByAggregateFunction::from_key(key).ok_or_else(invalid)?;
+        let (field, dir_val) = if function == OrderByAggregateFunction::Count {
+            (None, value)
+        } else {
+            let mut fields = value.as_object().ok_or_else(invalid)?.iter();
+            let (Some((field, dir_val)), None) = (fields.next(), fields.next()) else {
+                return Err(invalid());
+            };
+            Self::validate_field_name(field)?;
+            (Some(field.clone()), dir_val)
+        };
+        let direction = parse_direction(dir_val.as_str().ok_or_else(invalid)?)?;
+        Ok(Self {
+            aggregate: Some(OrderByAggregate {
+                function,
+                field,
+                join: None,
+            }),
+            ..Self::new(relation.to_string(), direction)
+        })
+    }
+}
+
+/// Parse a case-insensitive `ASC` / `DESC` orderBy direction.
+fn parse_direction(dir_str: &str) -> Result<OrderDirection> {
+    match dir_str.to_ascii_uppercase().as_str() {
+        "ASC" => Ok(OrderDirection::Asc),
+        "DESC" => Ok(OrderDirection::Desc),
+        _ => Err(FraiseQLError::Validation {
+            message: format!("orderBy direction '{dir_str}' must be ASC or DESC"),
+            path:    None,
+        }),
+    }
 }
 
 /// SQL projection hint for database-specific field projection optimization.
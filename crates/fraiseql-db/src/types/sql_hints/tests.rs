@@ -53,3 +53,50 @@ fn test_validate_field_name_rejects_injection() {
     assert!(OrderByClause::validate_field_name("123start").is_err());
     assert!(OrderByClause::validate_field_name("").is_err());
 }
+
+// ── aggregate orderBy ─────────────────────────────────────────────────
+
+#[test]
+fn test_from_graphql_json_parses_relation_aggregates() {
+    let clauses = OrderByClause::from_graphql_json(&serde_json::json!({
+        "orders": { "max": { "total": "desc" } }
+    }))
+    .unwrap();
+    assert_eq!(clauses.len(), 1);
+    assert_eq!(clauses[0].field, "orders");
+    assert_eq!(clauses[0].direction, OrderDirection::Desc);
+    assert_eq!(
+        clauses[0].aggregate,
+        Some(OrderByAggregate {
+            function: OrderByAggregateFunction::Max,
+            field:    Some("total".to_string()),
+            join:     None,
+        })
+    );
+
+    let clauses =
+        OrderByClause::from_graphql_json(&serde_json::json!({ "orders": { "count": "ASC" } }))
+            .unwrap();
+    let aggregate = clauses[0].aggregate.as_ref().unwrap();
+    assert_eq!(aggregate.function, OrderByAggregateFunction::Count);
+    assert_eq!(aggregate.field, None);
+}
+
+#[test]
+fn test_from_graphql_json_rejects_malformed_aggregates() {
+    for value in [
+        serde_json::json!({ "orders": { "median": { "total": "DESC" } } }),
+        serde_json::json!({ "orders": { "max": "DESC" } }),
+        serde_json::json!({ "orders": { "max": { "total": "DESC", "tax": "ASC" } } }),
+        serde_json::json!({ "orders": { "count": "DESC", "max": { "total": "DESC" } } }),
+        serde_json::json!({ "orders": { "max": { "total'; --": "DESC" } } }),
+    ] {
+        assert!(
+            matches!(
+                OrderByClause::from_graphql_json(&value),
+                Err(FraiseQLError::Validation { .. })
+            ),
+            "expected rejection of {value}"
+        );
+    }
+}
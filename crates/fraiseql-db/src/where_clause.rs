@@ -3,7 +3,12 @@
 use fraiseql_error::{FraiseQLError, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::utils::to_snake_case;
+use crate::{
+    geo::GeoBackend,
+    relation_filter::{RelationFilter, RelationQuantifier},
+    text_search::TextSearchConfig,
+    utils::to_snake_case,
+};
 
 /// WHERE clause abstract syntax tree.
 ///
@@ -75,6 +80,56 @@ pub enum WhereClause {
         /// Value to compare against.
         value:    serde_json::Value,
     },
+
+    /// JSONB field condition compared as an explicit SQL type.
+    ///
+    /// Emitted for fields typed with a custom scalar that declares a
+    /// `sql_type` (e.g. `Money` → `numeric(12,2)`), so comparisons use that
+    /// type's semantics instead of text or the value's JSON kind. Both sides
+    /// are cast: `(data->>'price')::numeric(12,2) >= $1::text::numeric(12,2)`.
+    /// Operators without a typed form fall back to the [`Self::Field`] SQL.
+    TypedField {
+        /// JSONB path (e.g., `["price"]`).
+        path:     Vec<String>,
+        /// SQL type both sides are cast to. Must pass
+        /// [`is_safe_sql_type_name`](crate::is_safe_sql_type_name).
+        sql_type: String,
+        /// Comparison operator.
+        operator: WhereOperator,
+        /// Value to compare against.
+        value:    serde_json::Value,
+    },
+
+    /// Full-text match of a type's search document against a web-search query.
+    ///
+    /// Renders `(<document>) @@ websearch_to_tsquery('<language>', $N)` with the
+    /// query bound as a parameter. Only PostgreSQL supports it.
+    TextSearch {
+        /// Search document (fields, weights, language).
+        config: TextSearchConfig,
+        /// Search text in `websearch_to_tsquery` syntax.
+        query:  String,
+    },
+
+    /// Geospatial filter evaluated with an explicit spatial backend.
+    ///
+    /// A plain [`Field`](Self::Field) with a geospatial operator renders for
+    /// PostGIS; this form carries the backend the schema was compiled against.
+    /// Only PostgreSQL supports it.
+    Geo {
+        /// JSONB path to the `{ lat, lng }` point.
+        path:     Vec<String>,
+        /// `Near`, `WithinPolygon` or `WithinBoundingBox`.
+        operator: WhereOperator,
+        /// Operator value (see [`GeoFilter::parse`](crate::geo::GeoFilter::parse)).
+        value:    serde_json::Value,
+        /// Spatial extension to generate SQL for.
+        backend:  GeoBackend,
+    },
+
+    /// Quantified (`some` / `every` / `none`) filter over related rows,
+    /// rendered as a correlated `EXISTS` subquery once its join is resolved.
+    Relation(Box<RelationFilter>),
 }
 
 impl WhereClause {
@@ -83,7 +138,13 @@ impl WhereClause {
     pub const fn is_empty(&self) -> bool {
         match self {
             Self::And(clauses) | Self::Or(clauses) => clauses.is_empty(),
-            Self::Not(_) | Self::Field { .. } | Self::NativeField { .. } => false,
+            Self::Not(_)
+            | Self::Field { .. }
+            | Self::NativeField { .. }
+            | Self::TypedField { .. }
+            | Self::TextSearch { .. }
+            | Self::Geo { .. }
+            | Self::Relation(_) => false,
         }
     }
 
@@ -109,7 +170,11 @@ impl WhereClause {
             },
             Self::Not(inner) => inner.collect_native_column_names(out),
             Self::NativeField { column, .. } => out.push(column),
-            Self::Field { .. } => {},
+            Self::Field { .. }
+            | Self::TypedField { .. }
+            | Self::TextSearch { .. }
+            | Self::Geo { .. }
+            | Self::Relation(_) => {},
         }
     }
 
@@ -199,6 +264,17 @@ impl WhereClause {
                     field_path.push(to_snake_case(field_name));
 
                     for (op_str, op_val) in ops {
+                        if let Some(quantifier) = RelationQuantifier::from_key(op_str) {
+                            // Relation filter: { orders: { some: { total: { gt: 100 } } } }.
+                            // The nested filter is relative to the related type.
+                            conditions.push(Self::Relation(Box::new(RelationFilter {
+                                path: field_path.clone(),
+                                quantifier,
+                                filter: Self::parse_where_object(op_val, &[])?,
+                                join: None,
+                            })));
+                            continue;
+                        }
                         match WhereOperator::from_str(op_str) {
                             Ok(operator) => {
                                 conditions.push(Self::Field {
@@ -386,11 +462,33 @@ pub enum WhereOperator {
     /// Overlaps (&&) - subnets overlap.
     Overlaps,
 
+    // ========================================================================
+    // Geospatial Operators (PostGIS, or earthdistance fallback)
+    // ========================================================================
+    /// Within a radius of a point: `{ lat, lng, radiusMeters }`.
+    Near,
+    /// Inside a polygon: `[{ lat, lng }, …]`.
+    WithinPolygon,
+    /// Inside a bounding box: `{ minLat, minLng, maxLat, maxLng }`.
+    WithinBoundingBox,
+
     // ========================================================================
     // JSONB Operators
     // ========================================================================
     /// Strictly contains (@>).
     StrictlyContains,
+    /// JSONB value contains the given JSON (@>): `metaContains`.
+    MetaContains,
+    /// JSONB value is contained by the given JSON (<@): `metaContainedBy`.
+    MetaContainedBy,
+    /// JSONB object has the top-level key (?): `hasKey`.
+    HasKey,
+    /// JSONB object has any of the top-level keys (?|): `hasAnyKeys`.
+    HasAnyKeys,
+    /// JSONB object has all of the top-level keys (?&): `hasAllKeys`.
+    HasAllKeys,
+    /// SQL/JSON path matches the JSONB value (`jsonb_path_exists`): `jsonPath`.
+    JsonPath,
 
     // ========================================================================
     // LTree Operators (Hierarchical)
@@ -490,14 +588,14 @@ impl WhereOperator {
             "niregex" => Some(Self::Niregex),
             "isnull" => Some(Self::IsNull),
             "array_contains" => Some(Self::ArrayContains),
-            "array_contained_by" => Some(Self::ArrayContainedBy),
+            "array_contained_by" | "contained_by" => Some(Self::ArrayContainedBy),
             "array_overlaps" => Some(Self::ArrayOverlaps),
-            "len_eq" => Some(Self::LenEq),
-            "len_gt" => Some(Self::LenGt),
-            "len_lt" => Some(Self::LenLt),
-            "len_gte" => Some(Self::LenGte),
-            "len_lte" => Some(Self::LenLte),
-            "len_neq" => Some(Self::LenNeq),
+            "len_eq" | "length_eq" => Some(Self::LenEq),
+            "len_gt" | "length_gt" => Some(Self::LenGt),
+            "len_lt" | "length_lt" => Some(Self::LenLt),
+            "len_gte" | "length_gte" => Some(Self::LenGte),
+            "len_lte" | "length_lte" => Some(Self::LenLte),
+            "len_neq" | "length_neq" => Some(Self::LenNeq),
             "cosine_distance" => Some(Self::CosineDistance),
             "l2_distance" => Some(Self::L2Distance),
             "l1_distance" => Some(Self::L1Distance),
@@ -520,7 +618,16 @@ impl WhereOperator {
             "contains_subnet" => Some(Self::ContainsSubnet),
             "contains_ip" => Some(Self::ContainsIP),
             "overlaps" => Some(Self::Overlaps),
+            "near" => Some(Self::Near),
+            "within_polygon" => Some(Self::WithinPolygon),
+            "within_bounding_box" | "within_bbox" => Some(Self::WithinBoundingBox),
             "strictly_contains" => Some(Self::StrictlyContains),
+            "meta_contains" => Some(Self::MetaContains),
+            "meta_contained_by" => Some(Self::MetaContainedBy),
+            "has_key" => Some(Self::HasKey),
+            "has_any_keys" => Some(Self::HasAnyKeys),
+            "has_all_keys" => Some(Self::HasAllKeys),
+            "json_path" => Some(Self::JsonPath),
             "ancestor_of" => Some(Self::AncestorOf),
             "descendant_of" => Some(Self::DescendantOf),
             "matches_lquery" => Some(Self::MatchesLquery),
@@ -545,6 +652,12 @@ impl WhereOperator {
         matches!(self, Self::In | Self::Nin)
     }
 
+    /// Check if operator is geospatial (`near`, `withinPolygon`, `withinBoundingBox`).
+    #[must_use]
+    pub const fn is_geo(&self) -> bool {
+        matches!(self, Self::Near | Self::WithinPolygon | Self::WithinBoundingBox)
+    }
+
     /// Check if operator is case-insensitive.
     #[must_use]
     pub const fn is_case_insensitive(&self) -> bool {
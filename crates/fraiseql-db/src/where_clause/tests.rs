@@ -21,6 +21,31 @@ fn test_where_operator_expects_array() {
     assert!(!WhereOperator::Eq.expects_array());
 }
 
+#[test]
+fn test_geo_operators_parse_from_camel_case() {
+    assert_eq!(WhereOperator::from_str("near").unwrap(), WhereOperator::Near);
+    assert_eq!(WhereOperator::from_str("withinPolygon").unwrap(), WhereOperator::WithinPolygon);
+    assert_eq!(
+        WhereOperator::from_str("withinBoundingBox").unwrap(),
+        WhereOperator::WithinBoundingBox
+    );
+    assert!(WhereOperator::Near.is_geo());
+    assert!(!WhereOperator::Eq.is_geo());
+}
+
+#[test]
+fn test_jsonb_operators_parse_from_camel_case() {
+    assert_eq!(WhereOperator::from_str("metaContains").unwrap(), WhereOperator::MetaContains);
+    assert_eq!(
+        WhereOperator::from_str("metaContainedBy").unwrap(),
+        WhereOperator::MetaContainedBy
+    );
+    assert_eq!(WhereOperator::from_str("hasKey").unwrap(), WhereOperator::HasKey);
+    assert_eq!(WhereOperator::from_str("hasAnyKeys").unwrap(), WhereOperator::HasAnyKeys);
+    assert_eq!(WhereOperator::from_str("hasAllKeys").unwrap(), WhereOperator::HasAllKeys);
+    assert_eq!(WhereOperator::from_str("jsonPath").unwrap(), WhereOperator::JsonPath);
+}
+
 #[test]
 fn test_where_operator_is_case_insensitive() {
     assert!(WhereOperator::Icontains.is_case_insensitive());
@@ -493,3 +518,41 @@ fn test_descendant_of_id_graphql_json() {
         }
     );
 }
+
+#[test]
+fn test_relation_quantifier_parses_nested_filter() {
+    use crate::relation_filter::{RelationFilter, RelationQuantifier};
+
+    let json = json!({
+        "lineItems": { "some": { "unitPrice": { "gt": 100 } } }
+    });
+    let clause = WhereClause::from_graphql_json(&json).unwrap();
+    assert_eq!(
+        clause,
+        WhereClause::Relation(Box::new(RelationFilter {
+            path:       vec!["line_items".to_string()],
+            quantifier: RelationQuantifier::Some,
+            filter:     WhereClause::Field {
+                path:     vec!["unit_price".to_string()],
+                operator: WhereOperator::Gt,
+                value:    json!(100),
+            },
+            join:       None,
+        }))
+    );
+    assert!(!clause.is_empty());
+
+    for (key, quantifier) in [
+        ("every", RelationQuantifier::Every),
+        ("none", RelationQuantifier::None),
+    ] {
+        let clause = WhereClause::from_graphql_json(
+            &json!({ "orders": { key: { "status": { "eq": "paid" } } } }),
+        )
+        .unwrap();
+        let WhereClause::Relation(relation) = clause else {
+            panic!("expected WhereClause::Relation for {key}");
+        };
+        assert_eq!(relation.quantifier, quantifier);
+    }
+}
@@ -7,6 +7,9 @@ use fraiseql_error::{FraiseQLError, Result};
 use super::counter::ParamCounter;
 use crate::{
     dialect::SqlDialect,
+    geo::{GeoBackend, GeoFilter},
+    identifier::is_safe_sql_type_name,
+    relation_filter::{RelationFilter, RelationQuantifier},
     where_clause::{WhereClause, WhereOperator},
 };
 
@@ -244,9 +247,96 @@ impl<D: SqlDialect> GenericWhereGenerator<D> {
                 operator,
                 value,
             } => self.visit_native_field(column, pg_cast, operator, value, params),
+            WhereClause::TypedField {
+                path,
+                sql_type,
+                operator,
+                value,
+            } => self.visit_typed_field(path, sql_type, operator, value, params, hierarchy_ctx),
+            WhereClause::TextSearch { config, query } => {
+                let document = config.document_sql()?;
+                let p = self.push_param(params, serde_json::Value::String(query.clone()));
+                self.dialect
+                    .fts_document_match_sql(&document, &config.tsquery_sql(&p))
+                    .map_err(|e| FraiseQLError::validation(e.to_string()))
+            },
+            WhereClause::Geo {
+                path,
+                operator,
+                value,
+                backend,
+            } => self.visit_geo(path, operator, value, *backend, params),
+            WhereClause::Relation(relation) => self.visit_relation(relation, params),
         }
     }
 
+    /// Generate a correlated `EXISTS` subquery for a quantified relation filter.
+    fn visit_relation(
+        &self,
+        relation: &RelationFilter,
+        params: &mut Vec<serde_json::Value>,
+    ) -> Result<String> {
+        let join = relation.join.as_ref().ok_or_else(|| {
+            FraiseQLError::validation(format!(
+                "'{}' is not a relationship; `{}` filters require a declared relationship",
+                relation.path.join("."),
+                relation.quantifier.as_str()
+            ))
+        })?;
+        let quote = |name: &str| -> String {
+            name.split('.')
+                .map(|part| self.dialect.quote_identifier(part))
+                .collect::<Vec<_>>()
+                .join(".")
+        };
+        let alias = self.dialect.quote_identifier(&join.alias);
+        let key = |qualifier: &str, key: &str| {
+            self.dialect
+                .json_extract_scalar(&format!("{qualifier}.data"), &[key.to_string()])
+        };
+        let mut sql = format!(
+            "EXISTS (SELECT 1 FROM {} AS {alias} WHERE {} = {}",
+            quote(&join.source),
+            key(&alias, &join.key),
+            key(&quote(&join.parent), &join.parent_key)
+        );
+        if !relation.filter.is_empty() {
+            let filter = self.visit(&relation.filter, params)?;
+            sql.push_str(&match relation.quantifier {
+                RelationQuantifier::Every => format!(" AND ({filter}) IS NOT TRUE"),
+                _ => format!(" AND {filter}"),
+            });
+        }
+        sql.push(')');
+        Ok(match relation.quantifier {
+            RelationQuantifier::Some => sql,
+            _ => format!("NOT {sql}"),
+        })
+    }
+
+    /// Generate SQL for a geospatial filter on the `{ lat, lng }` point at `path`.
+    fn visit_geo(
+        &self,
+        path: &[String],
+        operator: &WhereOperator,
+        value: &serde_json::Value,
+        backend: GeoBackend,
+        params: &mut Vec<serde_json::Value>,
+    ) -> Result<String> {
+        let filter = GeoFilter::parse(operator, value)?;
+        let coordinate = |key: &str| {
+            let mut p = path.to_vec();
+            p.push(key.to_string());
+            self.dialect.json_extract_scalar("data", &p)
+        };
+        let (lat, lng) = (coordinate("lat"), coordinate("lng"));
+        let placeholders: Vec<String> =
+            filter.params(backend).into_iter().map(|v| self.push_param(params, v)).collect();
+        self.dialect
+            .geo_filter_sql(&filter, backend, &lat, &lng, &placeholders)
+            .map_err(|e| FraiseQLError::validation(e.to_string()))
+    }
+
     /// Generate SQL for a native-column condition.
     ///
     /// Emits `"column" = <cast>` where `<cast>` is a dialect-appropriate
@@ -279,6 +369,62 @@ impl<D: SqlDialect> GenericWhereGenerator<D> {
         }
     }
 
+    /// Generate SQL for a JSONB condition compared as an explicit SQL type.
+    ///
+    /// Equality, ordering and `IN` cast both the extracted value and each
+    /// parameter to `sql_type`; every other operator keeps the untyped
+    /// [`Self::visit_field`] form (pattern matching is textual anyway).
+    fn visit_typed_field(
+        &self,
+        path: &[String],
+        sql_type: &str,
+        operator: &WhereOperator,
+        value: &serde_json::Value,
+        params: &mut Vec<serde_json::Value>,
+        hierarchy_ctx: Option<&super::HierarchyContext>,
+    ) -> Result<String> {
+        if !is_safe_sql_type_name(sql_type) {
+            return Err(FraiseQLError::validation(format!(
+                "Invalid SQL type '{sql_type}' for WHERE cast"
+            )));
+        }
+        let sql_op = match operator {
+            WhereOperator::Eq => "=",
+            WhereOperator::Neq => self.dialect.neq_operator(),
+            WhereOperator::Gt => ">",
+            WhereOperator::Gte => ">=",
+            WhereOperator::Lt => "<",
+            WhereOperator::Lte => "<=",
+            WhereOperator::In | WhereOperator::Nin => {
+                // An empty or non-array list has no typed form (constant / error).
+                let Some(items) = value.as_array().filter(|a| !a.is_empty()) else {
+                    return self.visit_field(path, operator, value, params, hierarchy_ctx);
+                };
+                let field_expr = self.resolve_field_expr(path);
+                let lhs = self.dialect.cast_to_type(&field_expr, sql_type);
+                let placeholders: Vec<_> = items
+                    .iter()
+                    .map(|v| {
+                        let p = self.push_param(params, v.clone());
+                        self.dialect.cast_native_param(&p, sql_type)
+                    })
+                    .collect();
+                let sql = format!("{lhs} IN ({})", placeholders.join(", "));
+                return Ok(if matches!(operator, WhereOperator::Nin) {
+                    format!("NOT ({sql})")
+                } else {
+                    sql
+                });
+            },
+            _ => return self.visit_field(path, operator, value, params, hierarchy_ctx),
+        };
+        let field_expr = self.resolve_field_expr(path);
+        let lhs = self.dialect.cast_to_type(&field_expr, sql_type);
+        let p = self.push_param(params, value.clone());
+        let rhs = self.dialect.cast_native_param(&p, sql_type);
+        Ok(format!("{lhs} {sql_op} {rhs}"))
+    }
+
     // ── Field expression resolution ───────────────────────────────────────────
 
     fn resolve_field_expr(&self, path: &[String]) -> String {
@@ -386,6 +532,19 @@ impl<D: SqlDialect> GenericWhereGenerator<D> {
             },
 
             // ── String: LIKE family ───────────────────────────────────────────
+            // `contains` / `overlaps` with a list value are the array operators.
+            WhereOperator::Contains if value.is_array() => {
+                let p = self.push_param(params, value.clone());
+                self.dialect
+                    .array_contains_sql(&field_expr, &p)
+                    .map_err(|e| FraiseQLError::validation(e.to_string()))
+            },
+            WhereOperator::Overlaps if value.is_array() => {
+                let p = self.push_param(params, value.clone());
+                self.dialect
+                    .array_overlaps_sql(&field_expr, &p)
+                    .map_err(|e| FraiseQLError::validation(e.to_string()))
+            },
             WhereOperator::Contains => {
                 let val_str = self.require_str(value, "Contains")?;
                 let escaped = escape_like_literal(val_str);
@@ -525,6 +684,59 @@ impl<D: SqlDialect> GenericWhereGenerator<D> {
                     .map_err(|e| FraiseQLError::validation(e.to_string()))
             },
 
+            // ── JSONB ─────────────────────────────────────────────────────────
+            WhereOperator::MetaContains
+            | WhereOperator::MetaContainedBy
+            | WhereOperator::HasKey => {
+                let pg_op = match operator {
+                    WhereOperator::MetaContains => "@>",
+                    WhereOperator::MetaContainedBy => "<@",
+                    _ => "?",
+                };
+                if matches!(operator, WhereOperator::HasKey) && !value.is_string() {
+                    return Err(FraiseQLError::validation("hasKey requires a string value"));
+                }
+                let p = self.push_param(params, value.clone());
+                self.dialect
+                    .jsonb_binary_sql(pg_op, "data", path, &p)
+                    .map_err(|e| FraiseQLError::validation(e.to_string()))
+            },
+            WhereOperator::HasAnyKeys | WhereOperator::HasAllKeys => {
+                let any = matches!(operator, WhereOperator::HasAnyKeys);
+                let keys = value
+                    .as_array()
+                    .filter(|keys| keys.iter().all(serde_json::Value::is_string))
+                    .ok_or_else(|| {
+                        FraiseQLError::validation(format!(
+                            "{} requires a list of strings",
+                            if any { "hasAnyKeys" } else { "hasAllKeys" }
+                        ))
+                    })?;
+                if keys.is_empty() {
+                    // No keys: "any of" matches nothing, "all of" matches everything.
+                    return Ok(if any {
+                        self.dialect.always_false().to_string()
+                    } else {
+                        self.dialect.always_true().to_string()
+                    });
+                }
+                let placeholders: Vec<String> =
+                    keys.iter().map(|k| self.push_param(params, k.clone())).collect();
+                let rhs = format!("ARRAY[{}]", placeholders.join(", "));
+                self.dialect
+                    .jsonb_binary_sql(if any { "?|" } else { "?&" }, "data", path, &rhs)
+                    .map_err(|e| FraiseQLError::validation(e.to_string()))
+            },
+            WhereOperator::JsonPath => {
+                if !value.is_string() {
+                    return Err(FraiseQLError::validation("jsonPath requires a string value"));
+                }
+                let p = self.push_param(params, value.clone());
+                self.dialect
+                    .jsonb_path_exists_sql("data", path, &p)
+                    .map_err(|e| FraiseQLError::validation(e.to_string()))
+            },
+
             // ── Full-text search ──────────────────────────────────────────────
             WhereOperator::Matches => {
                 let p = self.push_param(params, value.clone());
@@ -679,6 +891,13 @@ impl<D: SqlDialect> GenericWhereGenerator<D> {
                     .map_err(|e| FraiseQLError::validation(e.to_string()))
             },
 
+            // ── Geospatial (PostGIS unless a `Geo` clause names a backend) ────
+            WhereOperator::Near
+            | WhereOperator::WithinPolygon
+            | WhereOperator::WithinBoundingBox => {
+                self.visit_geo(path, operator, value, GeoBackend::PostGis, params)
+            },
+
             // ── LTree ─────────────────────────────────────────────────────────
             WhereOperator::AncestorOf => {
                 let p = self.push_param(params, value.clone());
@@ -474,3 +474,56 @@ fn ltree_id_subquery_escapes_adversarial_identifiers() {
         "Column name should have doubled quotes, got: {sql}"
     );
 }
+
+// ── Typed (custom scalar) fields ─────────────────────────────────────────
+
+fn typed(path: &str, sql_type: &str, op: WhereOperator, val: serde_json::Value) -> WhereClause {
+    WhereClause::TypedField {
+        path:     vec![path.to_string()],
+        sql_type: sql_type.to_string(),
+        operator: op,
+        value:    val,
+    }
+}
+
+#[test]
+fn typed_field_casts_both_sides_postgres() {
+    let gen = GenericWhereGenerator::new(PostgresDialect);
+    let clause = typed("price", "numeric(12,2)", WhereOperator::Gte, json!("9.99"));
+    let (sql, params) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "(data->>'price')::numeric(12,2) >= $1::text::numeric(12,2)");
+    assert_eq!(params, vec![json!("9.99")]);
+}
+
+#[test]
+fn typed_field_in_casts_each_element() {
+    let gen = GenericWhereGenerator::new(PostgresDialect);
+    let clause = typed("code", "citext", WhereOperator::Nin, json!(["fr", "de"]));
+    let (sql, _) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "NOT ((data->>'code')::citext IN ($1::text::citext, $2::text::citext))");
+}
+
+#[test]
+fn typed_field_pattern_operators_stay_textual() {
+    let gen = GenericWhereGenerator::new(PostgresDialect);
+    let clause = typed("code", "citext", WhereOperator::Startswith, json!("F"));
+    let (sql, _) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "data->>'code' LIKE $1 || '%'");
+}
+
+#[test]
+fn typed_field_without_cast_support_compares_untyped() {
+    use crate::dialect::SqliteDialect;
+    let gen = GenericWhereGenerator::new(SqliteDialect);
+    let clause = typed("price", "numeric(12,2)", WhereOperator::Eq, json!("9.99"));
+    let (sql, _) = gen.generate(&clause).unwrap();
+    assert_eq!(sql, "json_extract(data, '$.price') = ?");
+}
+
+#[test]
+fn typed_field_rejects_unsafe_sql_type() {
+    let gen = GenericWhereGenerator::new(PostgresDialect);
+    let clause = typed("price", "numeric); DROP TABLE t; --", WhereOperator::Eq, json!("1"));
+    let err = gen.generate(&clause).unwrap_err();
+    assert!(err.to_string().contains("Invalid SQL type"), "Got: {err}");
+}
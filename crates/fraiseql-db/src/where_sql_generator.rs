@@ -93,6 +93,30 @@ impl WhereSqlGenerator {
                 let val_sql = Self::value_to_sql(value, operator)?;
                 Ok(format!("{col_expr} {sql_op} {val_sql}"))
             },
+            // Wire adapter: compared like an untyped field; as for `NativeField`, the
+            // cast is omitted from the raw SQL.
+            WhereClause::TypedField {
+                path,
+                operator,
+                value,
+                ..
+            } => Self::generate_field_predicate(path, operator, value),
+            // Wire adapter: the search text is embedded as an escaped literal,
+            // like every other value on this path.
+            WhereClause::TextSearch { config, query } => {
+                let tsquery = config.tsquery_sql(&format!("'{}'", Self::escape_sql_string(query)?));
+                Ok(format!("({}) @@ {tsquery}", config.document_sql()?))
+            },
+            WhereClause::Relation(_) => Err(FraiseQLError::Internal {
+                message: "Relation filters not yet supported in fraiseql-wire".to_string(),
+                source:  None,
+            }),
+            WhereClause::Geo { operator, .. } => Err(FraiseQLError::Internal {
+                message: format!(
+                    "Advanced operators not yet supported in fraiseql-wire: {operator:?}"
+                ),
+                source:  None,
+            }),
         }
     }
 
@@ -239,7 +263,16 @@ impl WhereSqlGenerator {
             | WhereOperator::ContainsSubnet
             | WhereOperator::ContainsIP
             | WhereOperator::Overlaps
+            | WhereOperator::Near
+            | WhereOperator::WithinPolygon
+            | WhereOperator::WithinBoundingBox
             | WhereOperator::StrictlyContains
+            | WhereOperator::MetaContains
+            | WhereOperator::MetaContainedBy
+            | WhereOperator::HasKey
+            | WhereOperator::HasAnyKeys
+            | WhereOperator::HasAllKeys
+            | WhereOperator::JsonPath
             | WhereOperator::AncestorOf
             | WhereOperator::DescendantOf
             | WhereOperator::MatchesLquery
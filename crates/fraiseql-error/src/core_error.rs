@@ -9,6 +9,21 @@ use thiserror::Error;
 /// Result type alias for FraiseQL operations.
 pub type Result<T> = std::result::Result<T, FraiseQLError>;
 
+/// Base URL of the error-code reference; each code is an anchor on this page.
+pub const ERROR_DOCS_BASE_URL: &str = "https://docs.fraiseql.dev/errors";
+
+/// Documentation URL for a machine-readable error code.
+///
+/// ```rust
+/// use fraiseql_error::error_docs_url;
+///
+/// assert_eq!(error_docs_url("TIMEOUT"), "https://docs.fraiseql.dev/errors#TIMEOUT");
+/// ```
+#[must_use]
+pub fn error_docs_url(code: &str) -> String {
+    format!("{ERROR_DOCS_BASE_URL}#{code}")
+}
+
 /// Main error type for FraiseQL operations.
 ///
 /// All errors in the core library are converted to this type.
@@ -532,6 +547,12 @@ impl FraiseQLError {
         }
     }
 
+    /// Documentation URL for this error's [`error_code`](Self::error_code).
+    #[must_use]
+    pub fn docs_url(&self) -> String {
+        error_docs_url(self.error_code())
+    }
+
     /// Create an unknown field error with helpful suggestions.
     #[must_use]
     pub fn unknown_field_with_suggestion(
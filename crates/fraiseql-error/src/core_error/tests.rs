@@ -53,6 +53,16 @@ fn test_unsupported_is_501() {
     assert_eq!(err.error_code(), "UNSUPPORTED_OPERATION");
 }
 
+#[test]
+fn test_docs_url_anchors_on_error_code() {
+    let err = FraiseQLError::Timeout {
+        timeout_ms: 5000,
+        query:      None,
+    };
+    assert_eq!(err.docs_url(), "https://docs.fraiseql.dev/errors#TIMEOUT");
+    assert_eq!(err.docs_url(), error_docs_url(err.error_code()));
+}
+
 #[test]
 fn test_from_serde_error() {
     let json_err = serde_json::from_str::<serde_json::Value>("not json")
@@ -5,7 +5,7 @@ use axum::{
 };
 use serde::Serialize;
 
-use crate::{FileError, FraiseQLError};
+use crate::{FileError, FraiseQLError, error_docs_url};
 
 /// Standardised JSON error body returned by all FraiseQL HTTP endpoints.
 ///
@@ -50,7 +50,7 @@ impl ErrorResponse {
         Self {
             error:             error.into(),
             error_description: description.into(),
-            error_uri:         Some(format!("https://docs.fraiseql.dev/errors#{code}")),
+            error_uri:         Some(error_docs_url(&code)),
             error_code:        code,
             details:           None,
             retry_after:       None,
@@ -0,0 +1,331 @@
+//! The Flight client.
+
+use std::future::Future;
+
+use arrow::record_batch::RecordBatch;
+use arrow_flight::{
+    FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, Ticket,
+    flight_service_client::FlightServiceClient,
+};
+use fraiseql_arrow::ticket::FlightTicket;
+use futures::TryStreamExt;
+use tonic::{
+    Code, Request, Response, Status, Streaming,
+    metadata::AsciiMetadataValue,
+    transport::{Channel, Endpoint},
+};
+use tracing::debug;
+
+use crate::{
+    decode::{BatchStream, Payload, decode_batches},
+    error::{FlightClientError, Result},
+    retry::RetryPolicy,
+};
+
+/// Client of a FraiseQL Arrow Flight service.
+///
+/// Calls attach the session token as a bearer `authorization` header. The token is
+/// either obtained through the Flight handshake ([`authenticate`](Self::authenticate))
+/// or set directly ([`with_session_token`](Self::with_session_token)). When the server
+/// rejects an expired session and the client holds the JWT it authenticated with, the
+/// handshake is repeated once and the call retried.
+///
+/// The underlying channel connects lazily and reconnects on its own after the server
+/// went away; calls failing in the meantime are retried according to the client's
+/// [`RetryPolicy`]. Clones share the channel and are cheap: clone the client to run
+/// calls concurrently.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use fraiseql_flight_client::{FlightClient, GraphQLTicket};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut client = FlightClient::new("http://localhost:50051")?;
+/// client.authenticate("<OIDC JWT>").await?;
+///
+/// let batches = client.fetch(GraphQLTicket::new("{ users { id name } }")).await?;
+/// println!("{} batches", batches.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlightClient {
+    service:       FlightServiceClient<Channel>,
+    retry:         RetryPolicy,
+    session_token: Option<String>,
+    /// JWT the session token was obtained with, kept to repeat the handshake.
+    credentials:   Option<String>,
+}
+
+impl FlightClient {
+    /// Client of the Flight service at `uri` (e.g. `http://localhost:50051`).
+    ///
+    /// No connection is made until the first call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FlightClientError::Endpoint`] if `uri` is not a valid URI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    pub fn new(uri: impl Into<String>) -> Result<Self> {
+        let channel = Endpoint::from_shared(uri.into())?.connect_lazy();
+        Ok(Self::from_channel(channel))
+    }
+
+    /// Client over an existing channel (e.g. one configured with TLS or timeouts).
+    #[must_use]
+    pub fn from_channel(channel: Channel) -> Self {
+        Self {
+            service:       FlightServiceClient::new(channel),
+            retry:         RetryPolicy::default(),
+            session_token: None,
+            credentials:   None,
+        }
+    }
+
+    /// Set the retry policy of calls (see [`RetryPolicy`]).
+    #[must_use]
+    pub const fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Authenticate calls with an already issued session token.
+    #[must_use]
+    pub fn with_session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+        self
+    }
+
+    /// The session token attached to calls, if any.
+    #[must_use]
+    pub fn session_token(&self) -> Option<&str> {
+        self.session_token.as_deref()
+    }
+
+    /// Exchange an OIDC `jwt` for a session token through the Flight handshake.
+    ///
+    /// The JWT is kept so the handshake can be repeated once the session expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FlightClientError::Status`] if the server rejects the JWT, or
+    /// [`FlightClientError::Authentication`] if it answers without a session token.
+    pub async fn authenticate(&mut self, jwt: impl Into<String> + Send) -> Result<()> {
+        self.credentials = Some(jwt.into());
+        self.refresh_session().await
+    }
+
+    /// Stream the record batches of `ticket`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ticket cannot be encoded or the call fails. Errors
+    /// while streaming are yielded by the stream.
+    pub async fn do_get(&mut self, ticket: impl Into<FlightTicket> + Send) -> Result<BatchStream> {
+        let ticket = encode(&ticket.into())?;
+        Ok(decode_batches(self.open(ticket).await?))
+    }
+
+    /// Fetch every record batch of `ticket`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the call fails or the stream cannot be decoded.
+    pub async fn fetch(
+        &mut self,
+        ticket: impl Into<FlightTicket> + Send,
+    ) -> Result<Vec<RecordBatch>> {
+        self.do_get(ticket).await?.try_collect().await
+    }
+
+    /// Fetch the opaque payloads of `ticket` (JSON observer events, bulk export files).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the call fails, or [`FlightClientError::Protocol`] if the
+    /// ticket streams record batches instead.
+    pub async fn fetch_payloads(
+        &mut self,
+        ticket: impl Into<FlightTicket> + Send,
+    ) -> Result<Vec<Payload>> {
+        let ticket = encode(&ticket.into())?;
+        let mut messages = self.open(ticket).await?;
+        let mut payloads = Vec::new();
+        while let Some(data) = messages.message().await? {
+            payloads.push(Payload::from_flight_data(data)?);
+        }
+        Ok(payloads)
+    }
+
+    /// Fetch the rows of `ticket` deserialized into `T`, one per row.
+    ///
+    /// Rows are read as JSON objects keyed by column name; null columns are absent,
+    /// so nullable columns map to `Option` fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fetch fails, or [`FlightClientError::Deserialize`] if a
+    /// row does not match `T`.
+    #[cfg(feature = "serde")]
+    pub async fn fetch_rows<T>(&mut self, ticket: impl Into<FlightTicket> + Send) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let batches = self.fetch(ticket).await?;
+        crate::rows::batches_to_rows(&batches)
+    }
+
+    /// Describe `ticket`: its schema and the endpoints serving it.
+    ///
+    /// A ticket requesting partitioning is answered with one endpoint per partition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ticket cannot be encoded or the call fails.
+    pub async fn get_flight_info(
+        &mut self,
+        ticket: impl Into<FlightTicket> + Send,
+    ) -> Result<FlightInfo> {
+        let descriptor = FlightDescriptor::new_cmd(encode(&ticket.into())?.ticket);
+        self.call("get_flight_info", |mut service, authorization| {
+            let request = authorized(descriptor.clone(), authorization);
+            async move { service.get_flight_info(request).await.map(Response::into_inner) }
+        })
+        .await
+    }
+
+    /// Fetch every partition of `ticket` concurrently, in endpoint order.
+    ///
+    /// The ticket's partitions are listed through [`get_flight_info`](Self::get_flight_info)
+    /// and read from this client's service; advertised endpoint locations are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of any partition.
+    pub async fn fetch_partitioned(
+        &mut self,
+        ticket: impl Into<FlightTicket> + Send,
+    ) -> Result<Vec<Vec<RecordBatch>>> {
+        let info = self.get_flight_info(ticket).await?;
+        let reads = info.endpoint.into_iter().map(|endpoint| self.clone().fetch_endpoint(endpoint));
+        futures::future::try_join_all(reads).await
+    }
+
+    /// Fetch every record batch of one endpoint of a `FlightInfo`.
+    async fn fetch_endpoint(mut self, endpoint: FlightEndpoint) -> Result<Vec<RecordBatch>> {
+        let ticket = endpoint.ticket.ok_or_else(|| {
+            FlightClientError::Protocol("flight endpoint without a ticket".to_string())
+        })?;
+        decode_batches(self.open(ticket).await?).try_collect().await
+    }
+
+    /// Open a `DoGet` stream for the encoded `ticket`.
+    async fn open(&mut self, ticket: Ticket) -> Result<Streaming<FlightData>> {
+        self.call("do_get", |mut service, authorization| {
+            let request = authorized(ticket.clone(), authorization);
+            async move { service.do_get(request).await.map(Response::into_inner) }
+        })
+        .await
+    }
+
+    /// Run `rpc` under the retry policy, repeating the handshake once if the server
+    /// rejects the session and the JWT it was obtained with is known.
+    async fn call<T, F, Fut>(&mut self, method: &'static str, mut rpc: F) -> Result<T>
+    where
+        F: FnMut(FlightServiceClient<Channel>, Option<AsciiMetadataValue>) -> Fut + Send,
+        Fut: Future<Output = std::result::Result<T, Status>> + Send,
+        T: Send,
+    {
+        let mut reauthenticated = false;
+        loop {
+            let authorization = self.authorization()?;
+            let result = self
+                .retry
+                .run(method, || rpc(self.service.clone(), authorization.clone()))
+                .await;
+            match result {
+                Err(status)
+                    if status.code() == Code::Unauthenticated
+                        && self.credentials.is_some()
+                        && !reauthenticated =>
+                {
+                    debug!(method, "Flight session rejected; repeating the handshake");
+                    reauthenticated = true;
+                    self.refresh_session().await?;
+                },
+                result => return result.map_err(FlightClientError::from),
+            }
+        }
+    }
+
+    /// Obtain a new session token with the stored JWT.
+    async fn refresh_session(&mut self) -> Result<()> {
+        let Some(jwt) = self.credentials.as_deref() else {
+            return Err(FlightClientError::Authentication(
+                "no JWT to authenticate with".to_string(),
+            ));
+        };
+        let payload = format!("Bearer {jwt}");
+        let service = &self.service;
+        let response = self
+            .retry
+            .run("handshake", || {
+                let mut service = service.clone();
+                let request = HandshakeRequest {
+                    protocol_version: 0,
+                    payload:          payload.clone().into(),
+                };
+                async move {
+                    let mut responses =
+                        service.handshake(futures::stream::iter([request])).await?.into_inner();
+                    responses.message().await
+                }
+            })
+            .await?
+            .ok_or_else(|| {
+                FlightClientError::Authentication(
+                    "the server ended the handshake without a session token".to_string(),
+                )
+            })?;
+
+        let token = String::from_utf8(response.payload.to_vec()).map_err(|_| {
+            FlightClientError::Authentication("the session token is not UTF-8".to_string())
+        })?;
+        self.session_token = Some(token);
+        Ok(())
+    }
+
+    /// The `authorization` header value of calls, if a session token is set.
+    fn authorization(&self) -> Result<Option<AsciiMetadataValue>> {
+        self.session_token
+            .as_ref()
+            .map(|token| {
+                format!("Bearer {token}").parse().map_err(|_| {
+                    FlightClientError::Authentication(
+                        "the session token is not a valid header value".to_string(),
+                    )
+                })
+            })
+            .transpose()
+    }
+}
+
+/// The wire form of `ticket`.
+fn encode(ticket: &FlightTicket) -> Result<Ticket> {
+    Ok(Ticket {
+        ticket: ticket.encode()?.into(),
+    })
+}
+
+/// `message` as a request carrying `authorization`.
+fn authorized<M>(message: M, authorization: Option<AsciiMetadataValue>) -> Request<M> {
+    let mut request = Request::new(message);
+    if let Some(value) = authorization {
+        request.metadata_mut().insert("authorization", value);
+    }
+    request
+}
@@ -0,0 +1,98 @@
+//! Decoding of `DoGet` streams.
+//!
+//! The server answers a ticket in one of two shapes:
+//!
+//! - **Arrow**: a schema message followed by record batches (and dictionary batches for
+//!   dictionary-encoded columns). Subscriptions tag their batches with `app_metadata` `events`, and
+//!   send empty `heartbeat` batches while idle.
+//! - **Payloads**: opaque bodies tagged with their content type in `app_metadata` — the JSON answer
+//!   of an unencoded `ObserverEvents` ticket, or the files of a `BulkExport`.
+
+use std::pin::Pin;
+
+use arrow::record_batch::RecordBatch;
+use arrow_flight::{
+    FlightData,
+    decode::{DecodedPayload, FlightDataDecoder},
+    error::FlightError,
+};
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use tonic::Status;
+
+use crate::error::{FlightClientError, Result};
+
+/// `app_metadata` of the empty batches an idle subscription sends.
+const HEARTBEAT_TAG: &[u8] = b"heartbeat";
+
+/// Record batches decoded from a `DoGet` stream.
+pub type BatchStream = Pin<Box<dyn Stream<Item = Result<RecordBatch>> + Send>>;
+
+/// An opaque message of a `DoGet` stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payload {
+    /// Content type the server tagged the body with (e.g. `application/json`)
+    pub content_type: String,
+    /// Message body
+    pub body:         Bytes,
+}
+
+impl Payload {
+    /// Interpret `data` as an opaque payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FlightClientError::Protocol`] if `data` is an Arrow IPC message: the
+    /// ticket streams record batches, which [`decode_batches`] reads.
+    pub fn from_flight_data(data: FlightData) -> Result<Self> {
+        if !data.data_header.is_empty() {
+            return Err(FlightClientError::Protocol(
+                "expected an opaque payload but the ticket streams Arrow record batches"
+                    .to_string(),
+            ));
+        }
+        Ok(Self {
+            content_type: String::from_utf8_lossy(&data.app_metadata).into_owned(),
+            body:         data.data_body,
+        })
+    }
+}
+
+/// Decode the record batches of a raw `DoGet` stream.
+///
+/// Usable on the stream of any Arrow Flight client, not only [`FlightClient`]'s. Schema
+/// and dictionary messages are consumed, subscription heartbeats are dropped, and an
+/// error status ending the stream is yielded as [`FlightClientError::Status`].
+///
+/// [`FlightClient`]: crate::FlightClient
+pub fn decode_batches<S>(messages: S) -> BatchStream
+where
+    S: Stream<Item = std::result::Result<FlightData, Status>> + Send + 'static,
+{
+    let messages = messages.map(|message| match message {
+        Ok(data) if data.data_header.is_empty() => Err(FlightError::ProtocolError(format!(
+            "expected Arrow record batches but the ticket streams opaque payloads ({})",
+            String::from_utf8_lossy(&data.app_metadata)
+        ))),
+        Ok(data) => Ok(data),
+        // Passed as an external error so `FlightClientError::from` recovers the status.
+        Err(status) => Err(FlightError::ExternalError(Box::new(status))),
+    });
+
+    Box::pin(FlightDataDecoder::new(messages).filter_map(|decoded| async move {
+        match decoded {
+            Ok(decoded) => match decoded.payload {
+                DecodedPayload::RecordBatch(batch)
+                    if decoded.inner.app_metadata.as_ref() != HEARTBEAT_TAG =>
+                {
+                    Some(Ok(batch))
+                },
+                _ => None,
+            },
+            Err(e) => Some(Err(FlightClientError::from(e))),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,91 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::sync::Arc;
+
+use arrow::{
+    array::{Int64Array, StringArray},
+    datatypes::{DataType, Field, Schema},
+};
+use futures::TryStreamExt;
+use tonic::Code;
+
+use super::*;
+
+fn users() -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec![Some("Ada"), None])),
+        ],
+    )
+    .unwrap()
+}
+
+/// `batches` framed as the server sends them: schema message first.
+fn framed(batches: Vec<RecordBatch>) -> Vec<std::result::Result<FlightData, Status>> {
+    let schema = batches[0].schema();
+    arrow_flight::utils::batches_to_flight_data(&schema, batches)
+        .unwrap()
+        .into_iter()
+        .map(Ok)
+        .collect()
+}
+
+async fn decode(
+    messages: Vec<std::result::Result<FlightData, Status>>,
+) -> Result<Vec<RecordBatch>> {
+    decode_batches(futures::stream::iter(messages)).try_collect().await
+}
+
+#[tokio::test]
+async fn test_decodes_schema_then_batches() {
+    let batches = decode(framed(vec![users(), users()])).await.unwrap();
+    assert_eq!(batches, vec![users(), users()]);
+}
+
+#[tokio::test]
+async fn test_heartbeats_are_dropped() {
+    let mut messages = framed(vec![users(), users().slice(0, 0)]);
+    let heartbeat = messages.last_mut().unwrap().as_mut().unwrap();
+    heartbeat.app_metadata = HEARTBEAT_TAG.to_vec().into();
+
+    let batches = decode(messages).await.unwrap();
+    assert_eq!(batches, vec![users()]);
+}
+
+#[tokio::test]
+async fn test_stream_error_keeps_its_status_code() {
+    let mut messages = framed(vec![users()]);
+    messages.push(Err(Status::permission_denied("view not allowed")));
+
+    let error = decode(messages).await.unwrap_err();
+    assert_eq!(error.code(), Some(Code::PermissionDenied));
+}
+
+#[tokio::test]
+async fn test_opaque_payload_is_not_decoded_as_batches() {
+    let json = FlightData {
+        data_body: b"[]".to_vec().into(),
+        app_metadata: b"application/json".to_vec().into(),
+        ..Default::default()
+    };
+    let error = decode(vec![Ok(json.clone())]).await.unwrap_err();
+    assert!(matches!(error, FlightClientError::Protocol(_)), "unexpected error: {error}");
+
+    let payload = Payload::from_flight_data(json).unwrap();
+    assert_eq!(payload.content_type, "application/json");
+    assert_eq!(payload.body.as_ref(), b"[]");
+}
+
+#[test]
+fn test_arrow_message_is_not_a_payload() {
+    let schema_message = framed(vec![users()]).remove(0).unwrap();
+    assert!(matches!(
+        Payload::from_flight_data(schema_message),
+        Err(FlightClientError::Protocol(_))
+    ));
+}
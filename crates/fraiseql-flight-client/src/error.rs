@@ -0,0 +1,76 @@
+//! Error types of the Flight client.
+
+use arrow::error::ArrowError;
+use arrow_flight::error::FlightError;
+use fraiseql_arrow::ArrowFlightError;
+use thiserror::Error;
+use tonic::{Code, Status};
+
+/// Errors returned by [`FlightClient`](crate::FlightClient).
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum FlightClientError {
+    /// The server URI could not be parsed
+    #[error("Invalid endpoint: {0}")]
+    Endpoint(#[from] tonic::transport::Error),
+
+    /// The server answered a call (or ended a stream) with an error status
+    /// (boxed to reduce enum size)
+    #[error("Flight call failed: {0}")]
+    Status(Box<Status>),
+
+    /// Arrow IPC decoding error
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] ArrowError),
+
+    /// The ticket could not be encoded
+    #[error("Invalid ticket: {0}")]
+    Ticket(#[from] ArrowFlightError),
+
+    /// The server sent messages the client cannot interpret
+    #[error("Protocol error: {0}")]
+    Protocol(String),
+
+    /// The handshake did not yield a usable session token
+    #[error("Authentication failed: {0}")]
+    Authentication(String),
+
+    /// Fetched rows could not be deserialized
+    #[error("Deserialization error: {0}")]
+    Deserialize(#[from] serde_json::Error),
+}
+
+/// Result type of Flight client operations
+pub type Result<T> = std::result::Result<T, FlightClientError>;
+
+impl FlightClientError {
+    /// The gRPC status code of a failed call, if the error came from the server.
+    #[must_use]
+    pub fn code(&self) -> Option<Code> {
+        match self {
+            Self::Status(status) => Some(status.code()),
+            _ => None,
+        }
+    }
+}
+
+impl From<Status> for FlightClientError {
+    fn from(status: Status) -> Self {
+        Self::Status(Box::new(status))
+    }
+}
+
+impl From<FlightError> for FlightClientError {
+    fn from(error: FlightError) -> Self {
+        match error {
+            FlightError::Arrow(e) => Self::Arrow(e),
+            // Stream errors are handed to the decoder as external errors (see
+            // `decode_batches`) so their status code survives decoding.
+            FlightError::ExternalError(e) => match e.downcast::<Status>() {
+                Ok(status) => Self::Status(status),
+                Err(other) => Self::Protocol(other.to_string()),
+            },
+            other => Self::Protocol(other.to_string()),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Rust client for FraiseQL's Arrow Flight service.
+//!
+//! Wraps the generated Arrow Flight gRPC client with the protocol knowledge of
+//! FraiseQL's [`FraiseQLFlightService`](fraiseql_arrow::FraiseQLFlightService):
+//!
+//! - **Typed tickets**: builders ([`GraphQLTicket`], [`ViewTicket`], ...) producing the server's
+//!   own [`FlightTicket`] enum, so tickets cannot drift from what the server decodes
+//! - **Authentication**: the Flight handshake exchanging an OIDC JWT for a session token, repeated
+//!   once the session expires
+//! - **Decoding**: `DoGet` streams to [`RecordBatch`](arrow::record_batch::RecordBatch)es
+//!   (dictionary batches and subscription heartbeats handled), or to opaque [`Payload`]s for JSON
+//!   and export tickets
+//! - **Resilience**: lazy connection with reconnects, and a [`RetryPolicy`] for transient failures
+//! - **Rows** (`serde` feature): fetched rows deserialized into user structs
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use fraiseql_flight_client::{FlightClient, ObserverEventsTicket, ViewTicket};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut client = FlightClient::new("http://localhost:50051")?;
+//!     client.authenticate(std::env::var("FRAISEQL_JWT")?).await?;
+//!
+//!     let orders = client.fetch(ViewTicket::new("va_orders").limit(100_000)).await?;
+//!     let rows: usize = orders.iter().map(|batch| batch.num_rows()).sum();
+//!     println!("{rows} orders");
+//!
+//!     let events = client
+//!         .fetch_payloads(ObserverEventsTicket::new("Order").limit(1_000))
+//!         .await?;
+//!     println!("{} bytes of events", events[0].body.len());
+//!     Ok(())
+//! }
+//! ```
+
+#![forbid(unsafe_code)]
+
+mod client;
+pub mod decode;
+pub mod error;
+pub mod retry;
+#[cfg(feature = "serde")]
+pub mod rows;
+pub mod ticket;
+
+pub use client::FlightClient;
+pub use decode::{BatchStream, Payload, decode_batches};
+pub use error::{FlightClientError, Result};
+pub use fraiseql_arrow::ticket::{
+    AggregateFunction, AggregateSpec, BatchEncoding, FlightTicket, IpcCompression, PartitionScheme,
+    TicketPartition,
+};
+pub use retry::RetryPolicy;
+#[cfg(feature = "serde")]
+pub use rows::batches_to_rows;
+pub use ticket::{
+    AggregateTicket, BulkExportTicket, GraphQLTicket, ObserverEventsTicket, SubscriptionTicket,
+    ViewTicket,
+};
@@ -0,0 +1,95 @@
+//! Retry policy of Flight calls.
+
+use std::{future::Future, time::Duration};
+
+use tonic::{Code, Status};
+use tracing::warn;
+
+/// How often, and how patiently, a failed Flight call is retried.
+///
+/// Only the opening of a call is retried: once a `DoGet` stream has started
+/// delivering batches, a failure is returned to the caller, since replaying the
+/// ticket would deliver rows twice. Retried codes are `UNAVAILABLE` (server
+/// unreachable or restarting; the channel reconnects on the next attempt) and
+/// `RESOURCE_EXHAUSTED` (rate limited). Backoff doubles after every attempt, up to
+/// `max_backoff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts per call, including the first one (at least 1)
+    pub max_attempts:    u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between two attempts
+    pub max_backoff:     Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts:    4,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff:     Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            max_attempts:    1,
+            initial_backoff: Duration::ZERO,
+            max_backoff:     Duration::ZERO,
+        }
+    }
+
+    /// Whether a call failing with `code` is worth another attempt.
+    #[must_use]
+    pub const fn is_retryable(code: Code) -> bool {
+        matches!(code, Code::Unavailable | Code::ResourceExhausted)
+    }
+
+    /// Delay before retry number `retry` (1 for the first retry).
+    #[must_use]
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1_u32.checked_shl(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+
+    /// Run `call` until it succeeds, fails with a non-retryable status, or runs out
+    /// of attempts.
+    pub(crate) async fn run<T, F, Fut>(
+        &self,
+        method: &'static str,
+        mut call: F,
+    ) -> std::result::Result<T, Status>
+    where
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = std::result::Result<T, Status>> + Send,
+        T: Send,
+    {
+        let mut attempt = 1;
+        loop {
+            match call().await {
+                Ok(value) => return Ok(value),
+                Err(status) if attempt < self.max_attempts && Self::is_retryable(status.code()) => {
+                    let delay = self.backoff(attempt);
+                    warn!(
+                        method,
+                        attempt,
+                        code = ?status.code(),
+                        delay = ?delay,
+                        "Flight call failed; retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                },
+                Err(status) => return Err(status),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use super::*;
+
+fn fast_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(1),
+    }
+}
+
+#[test]
+fn test_backoff_doubles_up_to_the_cap() {
+    let policy = RetryPolicy {
+        max_attempts:    10,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff:     Duration::from_millis(350),
+    };
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    assert_eq!(policy.backoff(64), Duration::from_millis(350));
+}
+
+#[test]
+fn test_only_transient_codes_are_retried() {
+    assert!(RetryPolicy::is_retryable(Code::Unavailable));
+    assert!(RetryPolicy::is_retryable(Code::ResourceExhausted));
+    assert!(!RetryPolicy::is_retryable(Code::Unauthenticated));
+    assert!(!RetryPolicy::is_retryable(Code::InvalidArgument));
+}
+
+#[tokio::test]
+async fn test_transient_failures_are_retried_until_success() {
+    let calls = AtomicU32::new(0);
+    let result = fast_policy(3)
+        .run("do_get", || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(Status::unavailable("restarting"))
+            } else {
+                Ok("batches")
+            }
+        })
+        .await;
+    assert_eq!(result.unwrap(), "batches");
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let calls = AtomicU32::new(0);
+    let status = fast_policy(2)
+        .run("do_get", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(Status::resource_exhausted("slow down"))
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::ResourceExhausted);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_permanent_failure_is_not_retried() {
+    let calls = AtomicU32::new(0);
+    let status = fast_policy(5)
+        .run("do_get", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(Status::permission_denied("no"))
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
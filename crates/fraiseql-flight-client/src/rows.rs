@@ -0,0 +1,33 @@
+//! Deserialization of record batches into user structs.
+
+use arrow::{json::ArrayWriter, record_batch::RecordBatch};
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+
+/// Deserialize the rows of `batches` into `T`, one per row, in order.
+///
+/// Each row is read as a JSON object keyed by column name, as Arrow's JSON writer
+/// renders it: null columns are absent (nullable columns map to `Option` fields),
+/// timestamps and dates are ISO 8601 strings, and dictionary-encoded columns
+/// arrive as their values.
+///
+/// # Errors
+///
+/// Returns [`FlightClientError::Arrow`] if a column type has no JSON rendering, or
+/// [`FlightClientError::Deserialize`] if a row does not match `T`.
+///
+/// [`FlightClientError::Arrow`]: crate::FlightClientError::Arrow
+/// [`FlightClientError::Deserialize`]: crate::FlightClientError::Deserialize
+pub fn batches_to_rows<T: DeserializeOwned>(batches: &[RecordBatch]) -> Result<Vec<T>> {
+    if batches.iter().all(|batch| batch.num_rows() == 0) {
+        return Ok(Vec::new());
+    }
+    let mut writer = ArrayWriter::new(Vec::new());
+    writer.write_batches(&batches.iter().collect::<Vec<_>>())?;
+    writer.finish()?;
+    Ok(serde_json::from_slice(&writer.into_inner())?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use std::sync::Arc;
+
+use arrow::{
+    array::{Int64Array, StringArray},
+    datatypes::{DataType, Field, Schema},
+};
+use serde::Deserialize;
+
+use super::*;
+use crate::error::FlightClientError;
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct User {
+    id:   i64,
+    name: Option<String>,
+}
+
+fn users(ids: Vec<i64>, names: Vec<Option<&str>>) -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(Int64Array::from(ids)),
+            Arc::new(StringArray::from(names)),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_rows_of_all_batches_in_order() {
+    let batches = [
+        users(vec![1, 2], vec![Some("Ada"), None]),
+        users(vec![3], vec![Some("Bo")]),
+    ];
+    let rows: Vec<User> = batches_to_rows(&batches).unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            User {
+                id:   1,
+                name: Some("Ada".to_string()),
+            },
+            User {
+                id:   2,
+                name: None,
+            },
+            User {
+                id:   3,
+                name: Some("Bo".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_no_rows() {
+    let rows: Vec<User> = batches_to_rows(&[users(vec![], vec![])]).unwrap();
+    assert!(rows.is_empty());
+    assert!(batches_to_rows::<User>(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_mismatched_row_is_an_error() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)] // Reason: only deserialized to provoke a mismatch
+    struct Order {
+        total: f64,
+    }
+    let error = batches_to_rows::<Order>(&[users(vec![1], vec![None])]).unwrap_err();
+    assert!(matches!(error, FlightClientError::Deserialize(_)), "unexpected error: {error}");
+}
@@ -0,0 +1,371 @@
+//! Typed builders of Flight tickets.
+//!
+//! Each builder produces a [`FlightTicket`], the ticket enum the server decodes, so a
+//! ticket built here cannot drift from what the server accepts. Any `FlightTicket`
+//! (including variants without a builder, such as `BatchedQueries`) can be passed to
+//! the client directly.
+//!
+//! ```
+//! use fraiseql_flight_client::{FlightTicket, ViewTicket};
+//!
+//! let ticket: FlightTicket = ViewTicket::new("va_orders")
+//!     .filter("total > 100")
+//!     .order_by("created_at DESC")
+//!     .limit(10_000)
+//!     .into();
+//! assert_eq!(ticket.kind(), "OptimizedView");
+//! ```
+
+use fraiseql_arrow::ticket::{
+    AggregateFunction, AggregateSpec, BatchEncoding, FlightTicket, TicketPartition,
+};
+
+/// A `GraphQLQuery` ticket: the rows of the query's first root field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphQLTicket {
+    query:     String,
+    variables: Option<serde_json::Value>,
+}
+
+impl GraphQLTicket {
+    /// Ticket for `query`, without variables.
+    #[must_use]
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query:     query.into(),
+            variables: None,
+        }
+    }
+
+    /// Set the query variables.
+    #[must_use]
+    pub fn variables(mut self, variables: serde_json::Value) -> Self {
+        self.variables = Some(variables);
+        self
+    }
+}
+
+impl From<GraphQLTicket> for FlightTicket {
+    fn from(ticket: GraphQLTicket) -> Self {
+        Self::GraphQLQuery {
+            query:     ticket.query,
+            variables: ticket.variables,
+        }
+    }
+}
+
+/// An `OptimizedView` ticket: rows of a compiler-generated `va_*` view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewTicket {
+    view:     String,
+    filter:   Option<String>,
+    order_by: Option<String>,
+    limit:    Option<usize>,
+    offset:   Option<usize>,
+    encoding: Option<BatchEncoding>,
+}
+
+impl ViewTicket {
+    /// Ticket for every row of `view`.
+    #[must_use]
+    pub fn new(view: impl Into<String>) -> Self {
+        Self {
+            view:     view.into(),
+            filter:   None,
+            order_by: None,
+            limit:    None,
+            offset:   None,
+            encoding: None,
+        }
+    }
+
+    /// Only rows matching the WHERE clause `filter`.
+    #[must_use]
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Sort rows by the ORDER BY clause `order_by`.
+    #[must_use]
+    pub fn order_by(mut self, order_by: impl Into<String>) -> Self {
+        self.order_by = Some(order_by.into());
+        self
+    }
+
+    /// Return at most `limit` rows.
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first `offset` rows.
+    #[must_use]
+    pub const fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Dictionary-encode or compress the streamed batches.
+    #[must_use]
+    pub fn encoding(mut self, encoding: BatchEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+}
+
+impl From<ViewTicket> for FlightTicket {
+    fn from(ticket: ViewTicket) -> Self {
+        Self::OptimizedView {
+            view:     ticket.view,
+            filter:   ticket.filter,
+            order_by: ticket.order_by,
+            limit:    ticket.limit,
+            offset:   ticket.offset,
+            encoding: ticket.encoding,
+        }
+    }
+}
+
+/// An `ObserverEvents` ticket: historical observer events of an entity type.
+///
+/// Without an [`encoding`](Self::encoding) the server answers with a single JSON
+/// payload (see [`FlightClient::fetch_payloads`](crate::FlightClient::fetch_payloads));
+/// with one, with Arrow record batches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObserverEventsTicket {
+    entity_type: String,
+    start_date:  Option<String>,
+    end_date:    Option<String>,
+    limit:       Option<usize>,
+    partition:   Option<TicketPartition>,
+    encoding:    Option<BatchEncoding>,
+}
+
+impl ObserverEventsTicket {
+    /// Ticket for every event of `entity_type`.
+    #[must_use]
+    pub fn new(entity_type: impl Into<String>) -> Self {
+        Self {
+            entity_type: entity_type.into(),
+            start_date:  None,
+            end_date:    None,
+            limit:       None,
+            partition:   None,
+            encoding:    None,
+        }
+    }
+
+    /// Only events between `start` and `end` (ISO 8601), either bound optional.
+    #[must_use]
+    pub fn between(mut self, start: Option<&str>, end: Option<&str>) -> Self {
+        self.start_date = start.map(str::to_string);
+        self.end_date = end.map(str::to_string);
+        self
+    }
+
+    /// Return at most `limit` events (per partition when partitioned).
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Split the ticket into parallel partitions.
+    #[must_use]
+    pub const fn partition(mut self, partition: TicketPartition) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+
+    /// Stream the events as Arrow record batches with this encoding.
+    #[must_use]
+    pub fn encoding(mut self, encoding: BatchEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+}
+
+impl From<ObserverEventsTicket> for FlightTicket {
+    fn from(ticket: ObserverEventsTicket) -> Self {
+        Self::ObserverEvents {
+            entity_type: ticket.entity_type,
+            start_date:  ticket.start_date,
+            end_date:    ticket.end_date,
+            limit:       ticket.limit,
+            partition:   ticket.partition,
+            encoding:    ticket.encoding,
+        }
+    }
+}
+
+/// A `Subscription` ticket: live observer events over a long-lived stream.
+///
+/// The client drops the server's heartbeat batches, so the stream only yields
+/// batches of events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionTicket {
+    entity_type:       String,
+    filter:            Option<String>,
+    flush_interval_ms: Option<u64>,
+}
+
+impl SubscriptionTicket {
+    /// Subscribe to every new event of `entity_type`.
+    #[must_use]
+    pub fn new(entity_type: impl Into<String>) -> Self {
+        Self {
+            entity_type:       entity_type.into(),
+            filter:            None,
+            flush_interval_ms: None,
+        }
+    }
+
+    /// Only events whose data matches `filter` (`field = 'value'` or `field != 'value'`).
+    #[must_use]
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Request a flush interval; the server raises it to its configured minimum.
+    #[must_use]
+    pub const fn flush_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.flush_interval_ms = Some(interval_ms);
+        self
+    }
+}
+
+impl From<SubscriptionTicket> for FlightTicket {
+    fn from(ticket: SubscriptionTicket) -> Self {
+        Self::Subscription {
+            entity_type:       ticket.entity_type,
+            filter:            ticket.filter,
+            flush_interval_ms: ticket.flush_interval_ms,
+        }
+    }
+}
+
+/// A `BulkExport` ticket: a table exported as Parquet, CSV, JSON or Delta.
+///
+/// Exports arrive as encoded files, not record batches; fetch them with
+/// [`FlightClient::fetch_payloads`](crate::FlightClient::fetch_payloads).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkExportTicket {
+    table:  String,
+    filter: Option<String>,
+    limit:  Option<usize>,
+    format: Option<String>,
+}
+
+impl BulkExportTicket {
+    /// Export every row of `table` in the server's default format (Parquet).
+    #[must_use]
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            table:  table.into(),
+            filter: None,
+            limit:  None,
+            format: None,
+        }
+    }
+
+    /// Only rows matching the WHERE clause `filter`.
+    #[must_use]
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Export at most `limit` rows.
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Export format: "parquet", "csv", "json" or "delta".
+    #[must_use]
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+impl From<BulkExportTicket> for FlightTicket {
+    fn from(ticket: BulkExportTicket) -> Self {
+        Self::BulkExport {
+            table:  ticket.table,
+            filter: ticket.filter,
+            limit:  ticket.limit,
+            format: ticket.format,
+        }
+    }
+}
+
+/// An `Aggregate` ticket: group-by aggregates computed by the server's `DuckDB` sink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateTicket {
+    table:      String,
+    group_by:   Vec<String>,
+    aggregates: Vec<AggregateSpec>,
+    limit:      Option<usize>,
+}
+
+impl AggregateTicket {
+    /// Aggregate `table`; add output columns with [`aggregate`](Self::aggregate).
+    #[must_use]
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            table:      table.into(),
+            group_by:   Vec::new(),
+            aggregates: Vec::new(),
+            limit:      None,
+        }
+    }
+
+    /// Group by `column` (in addition to earlier grouping columns).
+    #[must_use]
+    pub fn group_by(mut self, column: impl Into<String>) -> Self {
+        self.group_by.push(column.into());
+        self
+    }
+
+    /// Add the output column `alias`: `function` over `column` (`None` for `COUNT(*)`).
+    #[must_use]
+    pub fn aggregate(
+        mut self,
+        function: AggregateFunction,
+        column: Option<&str>,
+        alias: impl Into<String>,
+    ) -> Self {
+        self.aggregates.push(AggregateSpec {
+            function,
+            column: column.map(str::to_string),
+            alias: alias.into(),
+        });
+        self
+    }
+
+    /// Return at most `limit` groups.
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl From<AggregateTicket> for FlightTicket {
+    fn from(ticket: AggregateTicket) -> Self {
+        Self::Aggregate {
+            table:      ticket.table,
+            group_by:   ticket.group_by,
+            aggregates: ticket.aggregates,
+            limit:      ticket.limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use serde_json::json;
+
+use super::*;
+
+/// The JSON the server receives for `ticket`.
+fn wire(ticket: impl Into<FlightTicket>) -> serde_json::Value {
+    serde_json::from_slice(&ticket.into().encode().unwrap()).unwrap()
+}
+
+#[test]
+fn test_graphql_ticket_carries_variables() {
+    let ticket = GraphQLTicket::new("query($id: ID!) { user(id: $id) { name } }")
+        .variables(json!({"id": "42"}));
+    assert_eq!(
+        wire(ticket),
+        json!({
+            "type": "GraphQLQuery",
+            "query": "query($id: ID!) { user(id: $id) { name } }",
+            "variables": {"id": "42"},
+        })
+    );
+}
+
+#[test]
+fn test_view_ticket_round_trips_through_the_server_decoder() {
+    let ticket: FlightTicket = ViewTicket::new("va_orders")
+        .filter("total > 100")
+        .order_by("created_at DESC")
+        .limit(500)
+        .offset(1000)
+        .encoding(BatchEncoding {
+            dictionary_columns: vec!["status".to_string()],
+            ..BatchEncoding::default()
+        })
+        .into();
+    assert_eq!(FlightTicket::decode(&ticket.encode().unwrap()).unwrap(), ticket);
+    assert_eq!(
+        wire(ticket),
+        json!({
+            "type": "OptimizedView",
+            "view": "va_orders",
+            "filter": "total > 100",
+            "order_by": "created_at DESC",
+            "limit": 500,
+            "offset": 1000,
+            "encoding": {"dictionary_columns": ["status"]},
+        })
+    );
+}
+
+#[test]
+fn test_observer_events_ticket_leaves_unset_bounds_open() {
+    let ticket = ObserverEventsTicket::new("Order").between(Some("2026-01-01"), None).limit(10);
+    assert_eq!(
+        wire(ticket),
+        json!({
+            "type": "ObserverEvents",
+            "entity_type": "Order",
+            "start_date": "2026-01-01",
+            "end_date": null,
+            "limit": 10,
+        })
+    );
+}
+
+#[test]
+fn test_aggregate_ticket_accumulates_columns() {
+    let ticket = AggregateTicket::new("fraiseql_events")
+        .group_by("entity_type")
+        .aggregate(AggregateFunction::Count, None, "events")
+        .aggregate(AggregateFunction::Sum, Some("total"), "revenue")
+        .limit(20);
+    assert_eq!(
+        wire(ticket),
+        json!({
+            "type": "Aggregate",
+            "table": "fraiseql_events",
+            "group_by": ["entity_type"],
+            "aggregates": [
+                {"function": "count", "alias": "events"},
+                {"function": "sum", "column": "total", "alias": "revenue"},
+            ],
+            "limit": 20,
+        })
+    );
+}
+
+#[test]
+fn test_builders_produce_their_ticket_kind() {
+    let kinds = [
+        FlightTicket::from(SubscriptionTicket::new("Order").filter("status = 'paid'")).kind(),
+        FlightTicket::from(BulkExportTicket::new("users").format("csv")).kind(),
+    ];
+    assert_eq!(kinds, ["Subscription", "BulkExport"]);
+}
@@ -0,0 +1,192 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+
+//! End-to-end tests of the Flight client against an in-process Flight service.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use fraiseql_arrow::{FlightPeer, FlightRateLimiter, FraiseQLFlightService, QueryExecutor};
+use fraiseql_core::security::SecurityContext;
+use fraiseql_flight_client::{FlightClient, GraphQLTicket, RetryPolicy};
+use serde_json::json;
+use tonic::{Code, transport::Server};
+
+const SESSION_SECRET: &str = "flight-client-test-session-secret";
+
+/// Answers every query with the same two users.
+struct UsersExecutor;
+
+// Reason: QueryExecutor is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl QueryExecutor for UsersExecutor {
+    async fn execute_with_security(
+        &self,
+        _query: &str,
+        _variables: Option<&serde_json::Value>,
+        _security_context: &SecurityContext,
+    ) -> Result<serde_json::Value, String> {
+        Ok(json!({"data": {"users": [
+            {"id": 1, "name": "Ada"},
+            {"id": 2, "name": "Bo"},
+        ]}}))
+    }
+}
+
+/// Refuses the first `refusals` calls, then admits everything.
+struct RefuseFirst {
+    refusals: u32,
+    calls:    AtomicU32,
+}
+
+#[async_trait]
+impl FlightRateLimiter for RefuseFirst {
+    async fn check(&self, _peer: &FlightPeer) -> Result<(), u32> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) < self.refusals {
+            Err(1)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Serve `service` on an ephemeral port and return its URI.
+async fn serve(service: FraiseQLFlightService) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(service.into_server())
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+    format!("http://{addr}")
+}
+
+fn users_service() -> FraiseQLFlightService {
+    let mut service = FraiseQLFlightService::new().with_session_secret(SESSION_SECRET);
+    service.set_executor(Arc::new(UsersExecutor));
+    service
+}
+
+/// A session token as the handshake would issue it.
+fn session_token() -> String {
+    let now = chrono::Utc::now().timestamp();
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+        &json!({
+            "sub": "analyst",
+            "iat": now,
+            "exp": now + 300,
+            "scopes": ["read"],
+            "session_type": "flight",
+        }),
+        &jsonwebtoken::EncodingKey::from_secret(SESSION_SECRET.as_bytes()),
+    )
+    .unwrap()
+}
+
+const fn fast_retries(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(5),
+        max_backoff: Duration::from_millis(5),
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_graphql_batches() {
+    let uri = serve(users_service()).await;
+    let mut client = FlightClient::new(uri).unwrap().with_session_token(session_token());
+
+    let batches = client.fetch(GraphQLTicket::new("{ users { id name } }")).await.unwrap();
+    assert_eq!(batches.iter().map(|batch| batch.num_rows()).sum::<usize>(), 2);
+    let schema = batches[0].schema();
+    let columns: Vec<_> = schema.fields().iter().map(|field| field.name().as_str()).collect();
+    assert_eq!(columns, ["id", "name"]);
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_fetch_rows_into_structs() {
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+    struct User {
+        id:   i64,
+        name: String,
+    }
+
+    let uri = serve(users_service()).await;
+    let mut client = FlightClient::new(uri).unwrap().with_session_token(session_token());
+
+    let users: Vec<User> =
+        client.fetch_rows(GraphQLTicket::new("{ users { id name } }")).await.unwrap();
+    assert_eq!(
+        users,
+        [
+            User {
+                id:   1,
+                name: "Ada".to_string(),
+            },
+            User {
+                id:   2,
+                name: "Bo".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_rate_limited_call_is_retried() {
+    let limiter = Arc::new(RefuseFirst {
+        refusals: 2,
+        calls:    AtomicU32::new(0),
+    });
+    let uri = serve(users_service().with_rate_limiter(limiter.clone())).await;
+    let mut client = FlightClient::new(uri)
+        .unwrap()
+        .with_session_token(session_token())
+        .with_retry_policy(fast_retries(3));
+
+    let batches = client.fetch(GraphQLTicket::new("{ users { id } }")).await.unwrap();
+    assert_eq!(batches[0].num_rows(), 2);
+    assert_eq!(limiter.calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_missing_session_is_not_retried() {
+    let limiter = Arc::new(RefuseFirst {
+        refusals: 0,
+        calls:    AtomicU32::new(0),
+    });
+    let uri = serve(users_service().with_rate_limiter(limiter.clone())).await;
+    let mut client = FlightClient::new(uri).unwrap().with_retry_policy(fast_retries(3));
+
+    let error = client.fetch(GraphQLTicket::new("{ users { id } }")).await.unwrap_err();
+    assert_eq!(error.code(), Some(Code::Unauthenticated));
+    assert_eq!(limiter.calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_unreachable_server_is_unavailable() {
+    // Bind then release a port so nothing listens on it.
+    let addr = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let mut client = FlightClient::new(format!("http://{addr}"))
+        .unwrap()
+        .with_session_token(session_token())
+        .with_retry_policy(fast_retries(2));
+
+    let error = client.fetch(GraphQLTicket::new("{ users { id } }")).await.unwrap_err();
+    assert_eq!(error.code(), Some(Code::Unavailable));
+}
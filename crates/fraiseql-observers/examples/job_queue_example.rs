@@ -29,8 +29,9 @@ async fn main() -> fraiseql_observers::Result<()> {
 
     use fraiseql_observers::{
         config::{
-            ActionConfig, BackoffStrategy, FailurePolicy, JobQueueConfig, ObserverDefinition,
-            ObserverRuntimeConfig, PerformanceConfig, RetryConfig, TransportConfig,
+            ActionConfig, BackoffStrategy, DeliveryOrdering, FailurePolicy, JobQueueConfig,
+            ObserverDefinition, ObserverRuntimeConfig, PerformanceConfig, ReplayPolicy,
+            RetryConfig, TransportConfig,
         },
         event::{EntityEvent, EventKind},
         executor::ObserverExecutor,
@@ -91,10 +92,10 @@ async fn main() -> fraiseql_observers::Result<()> {
     observers.insert(
         "user_created_webhook".to_string(),
         ObserverDefinition {
-            event_type: "INSERT".to_string(),
-            entity:     "User".to_string(),
-            condition:  None,
-            actions:    vec![ActionConfig::Webhook {
+            event_type:      "INSERT".to_string(),
+            entity:          "User".to_string(),
+            condition:       None,
+            actions:         vec![ActionConfig::Webhook {
                 url:                Some("https://webhook.example.com/user-created".to_string()),
                 url_env:            None,
                 method:             None,
@@ -102,14 +103,21 @@ async fn main() -> fraiseql_observers::Result<()> {
                 body_template:      None,
                 signing_secret:     None,
                 signing_secret_env: None,
+                signing_secret_ref: None,
+                signing_key_id:     None,
             }],
-            retry:      RetryConfig {
+            retry:           RetryConfig {
                 max_attempts:     3,
                 initial_delay_ms: 100,
                 max_delay_ms:     5000,
                 backoff_strategy: BackoffStrategy::Exponential,
             },
-            on_failure: FailurePolicy::default(),
+            on_failure:      FailurePolicy::default(),
+            replay:          ReplayPolicy::default(),
+            ordering:        DeliveryOrdering::default(),
+            max_concurrency: None,
+            dedup:           None,
+            dry_run:         false,
         },
     );
 
@@ -132,6 +140,7 @@ async fn main() -> fraiseql_observers::Result<()> {
             clickhouse:              None,
             job_queue:               Some(job_queue_config.clone()),
             performance:             PerformanceConfig::default(),
+            metrics:                 Default::default(),
             observers:               HashMap::new(),
             channel_capacity:        1000,
             max_concurrency:         50,
@@ -139,6 +148,8 @@ async fn main() -> fraiseql_observers::Result<()> {
             backlog_alert_threshold: 500,
             shutdown_timeout:        "30s".to_string(),
             max_dlq_size:            None,
+            dry_run:                 false,
+            event_types:             Default::default(),
         },
         Arc::new(MockDeadLetterQueue::new()),
     )
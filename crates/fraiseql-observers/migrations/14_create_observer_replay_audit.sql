@@ -0,0 +1,53 @@
+-- FraiseQL Observer Replay Audit — _fraiseql_observer_replay
+-- ============================================================================
+-- One row per event replay: a re-delivery of stored `fraiseql_events` through the
+-- observer pipeline, typically to backfill a newly added search index or to
+-- recover from a failed downstream. The replay engine upserts its row after every
+-- batch, so a crashed or interrupted replay leaves a `running` row whose
+-- `last_event_at` / `last_event_id` record exactly how far it got.
+--
+-- Columns
+-- -------
+--   replay_id        — primary key, generated per run.
+--   entity_type      — the replayed entity type (e.g. `Order`).
+--   window_start /
+--   window_end       — the requested time window; NULL = unbounded on that side.
+--   tenant_id        — tenant filter the replay was restricted to, if any.
+--   requested_by     — operator or service that started the replay.
+--   reason           — free-text justification (required by the engine).
+--   status           — `running`, `completed`, or `failed`.
+--   events_*         — events read / processed / failed so far.
+--   actions_*        — actions succeeded / failed / skipped by replay policy.
+--   last_event_*     — the keyset cursor of the last processed event.
+--   error            — failure detail for `failed` replays.
+--
+-- PostgreSQL only. Idempotent / re-run safe (CREATE … IF NOT EXISTS; REVOKE is
+-- idempotent).
+
+CREATE TABLE IF NOT EXISTS _fraiseql_observer_replay (
+    replay_id          UUID        PRIMARY KEY,
+    entity_type        TEXT        NOT NULL,
+    window_start       TIMESTAMPTZ,
+    window_end         TIMESTAMPTZ,
+    tenant_id          TEXT,
+    requested_by       TEXT        NOT NULL,
+    reason             TEXT        NOT NULL,
+    status             TEXT        NOT NULL CHECK (status IN ('running', 'completed', 'failed')),
+    events_read        BIGINT      NOT NULL DEFAULT 0,
+    events_processed   BIGINT      NOT NULL DEFAULT 0,
+    events_failed      BIGINT      NOT NULL DEFAULT 0,
+    actions_succeeded  BIGINT      NOT NULL DEFAULT 0,
+    actions_failed     BIGINT      NOT NULL DEFAULT 0,
+    actions_skipped    BIGINT      NOT NULL DEFAULT 0,
+    last_event_id      UUID,
+    last_event_at      TIMESTAMPTZ,
+    started_at         TIMESTAMPTZ NOT NULL,
+    finished_at        TIMESTAMPTZ,
+    error              TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_fraiseql_observer_replay_started
+    ON _fraiseql_observer_replay (started_at DESC);
+
+-- Least-privilege baseline: the audit trail is never world-readable.
+REVOKE ALL ON _fraiseql_observer_replay FROM PUBLIC;
@@ -0,0 +1,34 @@
+-- FraiseQL Observer Pause — _fraiseql_observer_pause
+-- ============================================================================
+-- One row per paused observer. A paused observer is taken out of live dispatch
+-- but its events are not dropped: `pinned_checkpoint` is the
+-- `core.tb_entity_change_log` position it has been delivered up to, and every
+-- change-log entry after it is its backlog. Resuming replays the backlog,
+-- advancing the pin after each batch, and deletes the row once the observer has
+-- caught up with live processing.
+--
+-- Columns
+-- -------
+--   observer_name      — primary key, the `tb_observer.name` the runtime keys on.
+--   pinned_checkpoint  — last `pk_entity_change_log` delivered to the observer.
+--   max_backlog        — backlog size that raises an alert while paused.
+--   paused_by          — operator or service that paused the observer.
+--   reason             — free-text justification, if given.
+--   paused_at          — when the pause started.
+--   updated_at         — last pin advance.
+--
+-- PostgreSQL only. Idempotent / re-run safe (CREATE … IF NOT EXISTS; REVOKE is
+-- idempotent).
+
+CREATE TABLE IF NOT EXISTS _fraiseql_observer_pause (
+    observer_name      TEXT        PRIMARY KEY,
+    pinned_checkpoint  BIGINT      NOT NULL,
+    max_backlog        BIGINT      NOT NULL CHECK (max_backlog > 0),
+    paused_by          TEXT,
+    reason             TEXT,
+    paused_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Least-privilege baseline: pause state is never world-readable.
+REVOKE ALL ON _fraiseql_observer_pause FROM PUBLIC;
@@ -0,0 +1,38 @@
+-- FraiseQL Observer Region Lease — _fraiseql_observer_region_lease
+-- ============================================================================
+-- One row per lease group (a set of regions running the same observers). The
+-- row names the region whose listeners are active; every other region stands
+-- by. Arbitration runs under `pg_advisory_xact_lock(hashtext(lease_group))`, so
+-- regions contending for the same group serialize on the primary database and
+-- exactly one wins.
+--
+-- Columns
+-- -------
+--   lease_group   — primary key, the group the regions contend for.
+--   region        — region currently holding the lease.
+--   holder        — instance within that region that acquired it.
+--   priority      — holder region's rank in the priority list (0 = preferred).
+--   checkpoint    — last change-log position the holder reported processing;
+--                   the next holder resumes from here.
+--   epoch         — incremented on every change of holder (fencing token).
+--   acquired_at   — when the current holder took the lease.
+--   expires_at    — when the lease lapses unless renewed.
+--   updated_at    — last renewal.
+--
+-- PostgreSQL only. Idempotent / re-run safe (CREATE … IF NOT EXISTS; REVOKE is
+-- idempotent).
+
+CREATE TABLE IF NOT EXISTS _fraiseql_observer_region_lease (
+    lease_group  TEXT        PRIMARY KEY,
+    region       TEXT        NOT NULL,
+    holder       TEXT        NOT NULL,
+    priority     INT         NOT NULL CHECK (priority >= 0),
+    checkpoint   BIGINT      NOT NULL DEFAULT 0,
+    epoch        BIGINT      NOT NULL DEFAULT 1,
+    acquired_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at   TIMESTAMPTZ NOT NULL,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Least-privilege baseline: lease state is never world-readable.
+REVOKE ALL ON _fraiseql_observer_region_lease FROM PUBLIC;
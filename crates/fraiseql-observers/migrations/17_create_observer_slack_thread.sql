@@ -0,0 +1,26 @@
+-- FraiseQL Observer Slack Threads — _fraiseql_observer_slack_thread
+-- ============================================================================
+-- One row per Slack thread started by a `slack` action with
+-- `thread_by_entity`. Later messages about the same entity reply in the
+-- thread, so every instance of the observer runtime must see the same row.
+--
+-- Columns
+-- -------
+--   thread_key  — primary key, `{channel}:{entity_type}:{entity_id}`.
+--   thread_ts   — Slack `ts` of the message that started the thread.
+--   started_at  — when the thread was recorded; old rows can be pruned.
+--
+-- PostgreSQL only. Idempotent / re-run safe (CREATE … IF NOT EXISTS; REVOKE is
+-- idempotent).
+
+CREATE TABLE IF NOT EXISTS _fraiseql_observer_slack_thread (
+    thread_key  TEXT        PRIMARY KEY,
+    thread_ts   TEXT        NOT NULL,
+    started_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_fraiseql_observer_slack_thread_started_at
+    ON _fraiseql_observer_slack_thread (started_at);
+
+-- Least-privilege baseline: thread state is never world-readable.
+REVOKE ALL ON _fraiseql_observer_slack_thread FROM PUBLIC;
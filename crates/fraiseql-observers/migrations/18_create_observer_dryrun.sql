@@ -0,0 +1,50 @@
+-- FraiseQL Observer Dry-Run Captures — tb_observer_dryrun
+-- ============================================================================
+-- One row per action a dry-run observer would have run: the rendered payload
+-- that was captured instead of being sent. Observers run dry when their
+-- definition sets `dry_run = true` or the runtime runs in simulation mode.
+--
+-- Columns
+-- -------
+--   pk_observer_dryrun — surrogate key (Trinity pattern).
+--   id                 — capture id.
+--   observer_name      — observer that matched the event.
+--   action_index       — index of the action in the observer's action list.
+--   action_type        — webhook, slack, email, …
+--   event_id … tenant_id — the triggering event.
+--   payload            — what the action would have sent; NULL if it did not render.
+--   error              — why the action did not render, if it did not.
+--   captured_at        — when the capture was taken; old rows can be pruned.
+--
+-- Payloads never hold secrets (URLs are reduced to their host, env-sourced
+-- values appear by variable name), but they do hold rendered event data.
+--
+-- PostgreSQL only. Idempotent / re-run safe (CREATE … IF NOT EXISTS; REVOKE is
+-- idempotent).
+
+CREATE TABLE IF NOT EXISTS tb_observer_dryrun (
+    pk_observer_dryrun BIGSERIAL    PRIMARY KEY,
+    id                 UUID         NOT NULL UNIQUE,
+    observer_name      TEXT         NOT NULL,
+    action_index       INTEGER      NOT NULL,
+    action_type        VARCHAR(50)  NOT NULL,
+    event_id           UUID         NOT NULL,
+    entity_type        VARCHAR(255) NOT NULL,
+    entity_id          UUID         NOT NULL,
+    event_type         VARCHAR(50)  NOT NULL,
+    tenant_id          TEXT,
+    payload            JSONB,
+    error              TEXT,
+    captured_at        TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_observer_dryrun_outcome CHECK ((payload IS NULL) <> (error IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_observer_dryrun_observer
+    ON tb_observer_dryrun (observer_name, captured_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_observer_dryrun_captured_at
+    ON tb_observer_dryrun (captured_at);
+
+-- Least-privilege baseline: captured payloads carry event data.
+REVOKE ALL ON tb_observer_dryrun FROM PUBLIC;
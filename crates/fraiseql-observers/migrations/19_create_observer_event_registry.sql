@@ -0,0 +1,58 @@
+-- FraiseQL Observer Event Type Registry — tb_observer_event_type,
+-- tb_observer_event_quarantine
+-- ============================================================================
+-- tb_observer_event_type holds the event types declared at runtime (through
+-- the admin API), each with the JSON Schema its payloads must match. Types
+-- declared in `[observers.runtime.event_types]` are not stored here.
+--
+-- tb_observer_event_quarantine holds the events that failed validation and
+-- therefore reached no observer, with the reasons.
+--
+-- Columns (tb_observer_event_type)
+-- --------------------------------
+--   pk_observer_event_type — surrogate key (Trinity pattern).
+--   entity_type            — the declared type; one declaration per type.
+--   definition             — the declaration (event kinds, schema, description).
+--   updated_at             — when it was last registered.
+--
+-- Columns (tb_observer_event_quarantine)
+-- --------------------------------------
+--   pk_observer_event_quarantine — surrogate key (Trinity pattern).
+--   id                     — quarantine entry id.
+--   event_id … tenant_id   — the quarantined event.
+--   event                  — the event, as received.
+--   violations             — why it was quarantined: [{path, message}, …].
+--   quarantined_at         — when; old rows can be pruned.
+--
+-- PostgreSQL only. Idempotent / re-run safe (CREATE … IF NOT EXISTS; REVOKE is
+-- idempotent).
+
+CREATE TABLE IF NOT EXISTS tb_observer_event_type (
+    pk_observer_event_type BIGSERIAL    PRIMARY KEY,
+    entity_type            VARCHAR(255) NOT NULL UNIQUE,
+    definition             JSONB        NOT NULL,
+    updated_at             TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS tb_observer_event_quarantine (
+    pk_observer_event_quarantine BIGSERIAL    PRIMARY KEY,
+    id                           UUID         NOT NULL UNIQUE,
+    event_id                     UUID         NOT NULL,
+    entity_type                  VARCHAR(255) NOT NULL,
+    entity_id                    UUID         NOT NULL,
+    event_type                   VARCHAR(50)  NOT NULL,
+    tenant_id                    TEXT,
+    event                        JSONB        NOT NULL,
+    violations                   JSONB        NOT NULL,
+    quarantined_at               TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_observer_event_quarantine_entity
+    ON tb_observer_event_quarantine (entity_type, quarantined_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_observer_event_quarantine_at
+    ON tb_observer_event_quarantine (quarantined_at);
+
+-- Least-privilege baseline: quarantined events carry event data.
+REVOKE ALL ON tb_observer_event_type FROM PUBLIC;
+REVOKE ALL ON tb_observer_event_quarantine FROM PUBLIC;
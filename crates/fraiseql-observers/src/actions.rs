@@ -1,10 +1,12 @@
-//! Core action implementations (webhook, Slack, email).
+//! Core action implementations (webhook, email) and the per-channel action modules.
 //!
 //! This module implements the core action executors:
 //! - Webhook: POST to HTTP endpoint
-//! - Slack: Send messages to Slack webhook
 //! - Email: Send emails via SMTP
 //!
+//! Chat (Slack, Teams, Discord), push notifications and SMS live in
+//! [`slack`], [`teams`], [`discord`], [`push`] and [`sms`].
+//!
 //! Each action handles template rendering, retry logic, and error handling.
 
 use std::{
@@ -17,18 +19,31 @@ use lettre::{
     transport::smtp::authentication::Credentials,
 };
 use reqwest::Client;
-use serde_json::{Value, json};
+use serde_json::Value;
 use tracing::{debug, info};
 
 use crate::{
     config::{EmailSmtpConfig, SmtpTlsMode},
     error::{ObserverError, Result},
     event::EntityEvent,
+    signing::{
+        WEBHOOK_KEY_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WebhookSigningKey, webhook_signature,
+    },
 };
 
+pub(crate) mod chat;
+pub mod discord;
+pub mod push;
+pub mod slack;
+pub mod sms;
+pub mod teams;
 #[cfg(test)]
 mod tests;
 
+pub use discord::{DiscordAction, DiscordResponse};
+pub use slack::{SlackAction, SlackResponse};
+pub use teams::{TeamsAction, TeamsResponse};
+
 /// Default HTTP request timeout for outbound webhook calls.
 ///
 /// Prevents a slow or non-responsive endpoint from blocking the executor
@@ -36,42 +51,6 @@ mod tests;
 /// manually via [`WebhookAction::with_timeout`].
 pub(crate) const DEFAULT_WEBHOOK_TIMEOUT_SECS: u64 = 30;
 
-/// Header carrying the HMAC-SHA256 signature of the webhook body.
-///
-/// Stripe-compatible shape (`t=<unix_ts>,v1=<hex>`); verifiable with
-/// `fraiseql-webhooks`'s `StripeVerifier` (#345).
-pub(crate) const WEBHOOK_SIGNATURE_HEADER: &str = "X-FraiseQL-Signature-256";
-
-/// Compute the Stripe-shape signature header value for the exact `body_bytes`.
-///
-/// Returns `t=<ts>,v1=<hex>` where the HMAC-SHA256 is taken over the byte
-/// sequence `"<ts>.<body>"` — byte-identical to the signing base used by
-/// `fraiseql_webhooks::signature::stripe::StripeVerifier`
-/// (`format!("{t}.{}", String::from_utf8_lossy(body))`), so a receiver can
-/// verify the payload with that verifier.
-///
-/// `body_bytes` MUST be the exact bytes transmitted on the wire (see
-/// [`WebhookAction::execute`], which signs the same buffer it sends) — signing a
-/// re-serialization can diverge from the transmitted bytes and fail every
-/// external verification.
-pub(crate) fn webhook_signature(secret: &str, ts: i64, body_bytes: &[u8]) -> String {
-    use hmac::{Hmac, KeyInit, Mac};
-    use sha2::Sha256;
-
-    let ts_str = ts.to_string();
-    let mut signed = Vec::with_capacity(ts_str.len() + 1 + body_bytes.len());
-    signed.extend_from_slice(ts_str.as_bytes());
-    signed.push(b'.');
-    signed.extend_from_slice(body_bytes);
-
-    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
-        .expect("HMAC-SHA256 accepts a key of any length");
-    mac.update(&signed);
-    let hex = hex::encode(mac.finalize().into_bytes());
-
-    format!("t={ts},v1={hex}")
-}
-
 /// Validate an outbound URL for SSRF risk before sending a request.
 ///
 /// Rejects:
@@ -332,7 +311,7 @@ impl WebhookAction {
         method: Option<&str>,
         headers: &HashMap<String, String>,
         body_template: Option<&str>,
-        signing_secret: Option<&str>,
+        signing_key: Option<WebhookSigningKey<'_>>,
         event: &EntityEvent,
     ) -> Result<WebhookResponse> {
         let start = std::time::Instant::now();
@@ -415,10 +394,13 @@ impl WebhookAction {
         }
 
         // Sign the exact transmitted bytes if a signing secret is configured.
-        if let Some(secret) = signing_secret {
+        if let Some(key) = signing_key {
             let ts = chrono::Utc::now().timestamp();
-            let signature = webhook_signature(secret, ts, &body_bytes);
+            let signature = webhook_signature(key.secret, ts, &body_bytes);
             request = request.header(WEBHOOK_SIGNATURE_HEADER, signature);
+            if let Some(key_id) = key.key_id {
+                request = request.header(WEBHOOK_KEY_ID_HEADER, key_id);
+            }
         }
 
         info!(
@@ -452,31 +434,45 @@ impl WebhookAction {
 
     #[allow(clippy::unused_self)] // Reason: method is part of a public API / trait consistency
     pub(crate) fn render_body_template(&self, template: &str, data: &Value) -> Result<Value> {
-        let mut rendered = template.to_string();
-
-        if let Value::Object(map) = data {
-            for (key, value) in map {
-                let placeholder = format!("{{{{ {key} }}}}");
-                let replacement = match value {
-                    // H11: JSON-escape string values WITHOUT the wrapping quotes so the
-                    // substituted text stays inside its surrounding JSON string context.
-                    // An attacker-controlled field value can no longer break out of the
-                    // string and inject sibling keys into the (HMAC-signed) payload.
-                    Value::String(s) => json_escape_inner(s),
-                    // Numbers/bools/null/arrays/objects serialize to valid JSON tokens
-                    // with no breakout characters, preserving the unquoted-typed-slot
-                    // contract (e.g. `"amount": {{ total }}` -> `"amount": 150`).
-                    other => other.to_string(),
-                };
-                rendered = rendered.replace(&placeholder, &replacement);
-            }
-        }
+        Ok(render_body(template, data))
+    }
+}
+
+/// Render a webhook `body_template` against `data`.
+pub(crate) fn render_body(template: &str, data: &Value) -> Value {
+    let rendered = render_json_template(template, data);
 
-        // Every substituted value is now context-safe, so re-parsing yields the
-        // intended structure; non-JSON templates (plain-text bodies) fall back to a
-        // JSON string, exactly as before.
-        serde_json::from_str(&rendered).or(Ok(Value::String(rendered)))
+    // Every substituted value is now context-safe, so re-parsing yields the
+    // intended structure; non-JSON templates (plain-text bodies) fall back to a
+    // JSON string, exactly as before.
+    serde_json::from_str(&rendered).unwrap_or(Value::String(rendered))
+}
+
+/// Render a `{{ key }}` JSON template against a JSON object's top-level fields.
+///
+/// Shared by the webhook `body_template` and the Slack `blocks_template`.
+/// Unmatched placeholders are left as-is; the result is not parsed.
+pub(crate) fn render_json_template(template: &str, data: &Value) -> String {
+    let mut rendered = template.to_string();
+
+    if let Value::Object(map) = data {
+        for (key, value) in map {
+            let placeholder = format!("{{{{ {key} }}}}");
+            let replacement = match value {
+                // H11: JSON-escape string values WITHOUT the wrapping quotes so the
+                // substituted text stays inside its surrounding JSON string context.
+                // An attacker-controlled field value can no longer break out of the
+                // string and inject sibling keys into the (HMAC-signed) payload.
+                Value::String(s) => json_escape_inner(s),
+                // Numbers/bools/null/arrays/objects serialize to valid JSON tokens
+                // with no breakout characters, preserving the unquoted-typed-slot
+                // contract (e.g. `"amount": {{ total }}` -> `"amount": 150`).
+                other => other.to_string(),
+            };
+            rendered = rendered.replace(&placeholder, &replacement);
+        }
     }
+    rendered
 }
 
 /// JSON-escape `s` for embedding inside a surrounding JSON string literal,
@@ -511,118 +507,6 @@ pub struct WebhookResponse {
     pub duration_ms: f64,
 }
 
-/// Slack action executor
-pub struct SlackAction {
-    /// HTTP client for making requests
-    client: Client,
-}
-
-impl SlackAction {
-    /// Create a new Slack action executor
-    #[must_use]
-    pub fn new() -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(DEFAULT_WEBHOOK_TIMEOUT_SECS))
-            .build()
-            .unwrap_or_else(|e| {
-                tracing::error!(error = %e, "Failed to build HTTP client for SlackAction; falling back to no-timeout client");
-                Client::default()
-            });
-        Self { client }
-    }
-
-    /// Execute Slack action
-    ///
-    /// # Errors
-    ///
-    /// Returns `ObserverError` if the webhook URL is invalid, the HTTP request fails,
-    /// or the Slack API returns a non-success response.
-    pub async fn execute(
-        &self,
-        webhook_url: &str,
-        channel: Option<&str>,
-        message_template: Option<&str>,
-        event: &EntityEvent,
-    ) -> Result<SlackResponse> {
-        let start = std::time::Instant::now();
-
-        // Prepare message
-        let message = if let Some(template) = message_template {
-            self.render_message_template(template, &event.data)
-        } else {
-            format!(
-                "Event: {} on {} (ID: {})",
-                event.event_type.as_str(),
-                event.entity_type,
-                event.entity_id
-            )
-        };
-
-        // Build Slack payload
-        let mut payload = json!({
-            "text": message,
-            "type": "mrkdwn"
-        });
-
-        if let Some(ch) = channel {
-            payload["channel"] = Value::String(ch.to_string());
-        }
-
-        // SECURITY: Reject URLs that target private/loopback addresses (SSRF protection).
-        validate_outbound_url(webhook_url)?;
-        // SECURITY: DNS rebinding prevention — resolve and reject private IPs.
-        crate::ssrf::dns_resolve_and_check(webhook_url).await?;
-
-        // Send request
-        let response = self.client.post(webhook_url).json(&payload).send().await.map_err(|e| {
-            ObserverError::ActionExecutionFailed {
-                reason: format!("Slack webhook failed: {e}"),
-            }
-        })?;
-
-        let status = response.status();
-        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
-
-        if status.is_success() {
-            Ok(SlackResponse {
-                status_code: status.as_u16(),
-                success: true,
-                duration_ms,
-            })
-        } else if status.is_client_error() {
-            Err(ObserverError::ActionPermanentlyFailed {
-                reason: format!("Slack HTTP {status} (client error — will not retry)"),
-            })
-        } else {
-            Err(ObserverError::ActionExecutionFailed {
-                reason: format!("Slack HTTP {status} response"),
-            })
-        }
-    }
-
-    #[allow(clippy::unused_self)] // Reason: method is part of a public API / trait consistency
-    pub(crate) fn render_message_template(&self, template: &str, data: &Value) -> String {
-        render_text_template(template, data)
-    }
-}
-
-impl Default for SlackAction {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-/// Response from Slack execution
-#[derive(Debug, Clone)]
-pub struct SlackResponse {
-    /// HTTP status code
-    pub status_code: u16,
-    /// Whether execution was successful
-    pub success:     bool,
-    /// Duration in milliseconds
-    pub duration_ms: f64,
-}
-
 /// Email action executor: sends email over SMTP via `lettre` (#349).
 ///
 /// Constructed with an optional [`EmailSmtpConfig`]. When SMTP is configured,
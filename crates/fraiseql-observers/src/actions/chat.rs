@@ -0,0 +1,141 @@
+//! HTTP plumbing shared by the chat actions (Slack, Teams, Discord).
+//!
+//! All three services answer `429 Too Many Requests` with a `Retry-After`
+//! header. A [`RetryAfterGate`] remembers the deadline per endpoint, holds
+//! every request to that endpoint until it has passed, and retries, so
+//! concurrent events stop hitting the limit. A `Retry-After` longer than the
+//! gate's maximum wait fails the attempt as transient and leaves the delay to
+//! the executor's retry policy.
+
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use tracing::warn;
+
+use crate::error::{ObserverError, Result};
+
+/// Longest `Retry-After` waited out in-process by default.
+pub(crate) const DEFAULT_MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(30);
+
+/// Wait assumed when a 429 carries no usable `Retry-After`.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Longest `Retry-After` honored; larger values are clamped.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(3_600);
+
+/// Rate-limited retries of one send before it fails as transient.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Per-endpoint `Retry-After` bookkeeping for one chat service.
+pub(crate) struct RetryAfterGate {
+    /// Service name used in logs and errors (`Slack`, `Teams`, `Discord`).
+    service:       &'static str,
+    /// Longest `Retry-After` waited out before failing the attempt.
+    max_wait:      Duration,
+    /// When each rate-limited endpoint accepts requests again.
+    blocked_until: DashMap<String, Instant>,
+}
+
+impl RetryAfterGate {
+    /// A gate for `service` waiting out at most 30 s in-process.
+    pub(crate) fn new(service: &'static str) -> Self {
+        Self {
+            service,
+            max_wait: DEFAULT_MAX_RATE_LIMIT_WAIT,
+            blocked_until: DashMap::new(),
+        }
+    }
+
+    /// Set the longest `Retry-After` waited out in-process.
+    pub(crate) const fn set_max_wait(&mut self, wait: Duration) {
+        self.max_wait = wait;
+    }
+
+    /// Send the request built by `request`, waiting out and retrying
+    /// `429 Too Many Requests` from `endpoint`.
+    ///
+    /// `endpoint` only keys the bookkeeping and is never logged, so it may be
+    /// a secret webhook URL.
+    pub(crate) async fn send(
+        &self,
+        endpoint: &str,
+        request: impl Fn() -> RequestBuilder,
+    ) -> Result<Response> {
+        for attempt in 0..=MAX_RATE_LIMIT_RETRIES {
+            self.wait(endpoint).await?;
+            let response =
+                request().send().await.map_err(|e| ObserverError::ActionExecutionFailed {
+                    reason: format!("{} request failed: {e}", self.service),
+                })?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            let retry_after = retry_after(&response);
+            self.blocked_until.insert(endpoint.to_string(), Instant::now() + retry_after);
+            warn!(
+                service = self.service,
+                retry_after_ms = u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX),
+                attempt,
+                "chat rate limit hit; backing off"
+            );
+        }
+        Err(ObserverError::ActionExecutionFailed {
+            reason: format!(
+                "{} kept rate limiting after {MAX_RATE_LIMIT_RETRIES} retries (HTTP 429)",
+                self.service
+            ),
+        })
+    }
+
+    /// Sleep until `endpoint` accepts requests again.
+    async fn wait(&self, endpoint: &str) -> Result<()> {
+        let Some(until) = self.blocked_until.get(endpoint).map(|until| *until) else {
+            return Ok(());
+        };
+        let wait = until.saturating_duration_since(Instant::now());
+        if wait.is_zero() {
+            self.blocked_until.remove_if(endpoint, |_, until| *until <= Instant::now());
+            return Ok(());
+        }
+        if wait > self.max_wait {
+            return Err(ObserverError::ActionExecutionFailed {
+                reason: format!(
+                    "{} rate limited (HTTP 429); retry after {}s",
+                    self.service,
+                    wait.as_secs().max(1)
+                ),
+            });
+        }
+        tokio::time::sleep(wait).await;
+        Ok(())
+    }
+}
+
+/// The error for a non-success, non-429 HTTP status from `service`.
+pub(crate) fn http_status_error(service: &str, status: StatusCode) -> ObserverError {
+    if status.is_client_error() {
+        ObserverError::ActionPermanentlyFailed {
+            reason: format!("{service} HTTP {status} (client error — will not retry)"),
+        }
+    } else {
+        ObserverError::ActionExecutionFailed {
+            reason: format!("{service} HTTP {status} response"),
+        }
+    }
+}
+
+/// The wait a 429 response asks for: `Retry-After` in (possibly fractional)
+/// seconds, as Discord sends it.
+fn retry_after(response: &Response) -> Duration {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map_or(DEFAULT_RETRY_AFTER, |secs| {
+            Duration::try_from_secs_f64(secs).unwrap_or(MAX_RETRY_AFTER)
+        })
+        .min(MAX_RETRY_AFTER)
+}
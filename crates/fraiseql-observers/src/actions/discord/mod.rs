@@ -0,0 +1,231 @@
+//! Discord transport for the `discord` observer action.
+//!
+//! A [`DiscordAction`] executes a Discord webhook: `content` rendered from
+//! `message_template`, and embeds rendered from an `embed_template` — one
+//! embed object or an array of them, with placeholders substituted
+//! JSON-escaped so an event field cannot add embeds or fields of its own.
+//!
+//! Every message is sent with `allowed_mentions` empty, so text coming from
+//! event data can never ping `@everyone`, a role or a user.
+//!
+//! `429 Too Many Requests` is waited out per `Retry-After`, as for Slack; see
+//! [`chat`](crate::actions::chat).
+
+#[cfg(test)]
+mod tests;
+
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use serde_json::{Value, json};
+
+use crate::{
+    actions::{
+        DEFAULT_WEBHOOK_TIMEOUT_SECS,
+        chat::{RetryAfterGate, http_status_error},
+        render_json_template, render_text_template, validate_outbound_url,
+    },
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Longest `content` Discord accepts, in characters.
+const MAX_CONTENT_CHARS: usize = 2_000;
+
+/// Most embeds Discord accepts in one message.
+const MAX_EMBEDS: usize = 10;
+
+/// Discord action executor
+pub struct DiscordAction {
+    /// HTTP client for making requests
+    client:      Client,
+    /// `Retry-After` bookkeeping per webhook URL.
+    rate_limits: RetryAfterGate,
+}
+
+impl DiscordAction {
+    /// Create a new Discord action executor
+    #[must_use]
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_WEBHOOK_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::error!(error = %e, "Failed to build HTTP client for DiscordAction; falling back to no-timeout client");
+                Client::default()
+            });
+        Self {
+            client,
+            rate_limits: RetryAfterGate::new("Discord"),
+        }
+    }
+
+    /// Set the longest `Retry-After` waited out in-process (default 30 s).
+    #[must_use]
+    pub const fn with_max_rate_limit_wait(mut self, wait: Duration) -> Self {
+        self.rate_limits.set_max_wait(wait);
+        self
+    }
+
+    /// Post the event's message to `webhook_url`.
+    ///
+    /// The webhook is called with `wait=true`, so the response carries the
+    /// id of the created message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::TemplateRenderingFailed` if `embed_template`
+    /// does not render to embeds, `ObserverError::ActionPermanentlyFailed` if
+    /// the URL is rejected, the message exceeds Discord's limits or Discord
+    /// refuses it (4xx, e.g. a deleted webhook), and
+    /// `ObserverError::ActionExecutionFailed` for outages and rate limits.
+    pub async fn execute(
+        &self,
+        webhook_url: &str,
+        username: Option<&str>,
+        message_template: Option<&str>,
+        embed_template: Option<&str>,
+        event: &EntityEvent,
+    ) -> Result<DiscordResponse> {
+        let start = Instant::now();
+        let payload = render_payload(username, message_template, embed_template, event)?;
+
+        // SECURITY: Reject URLs that target private/loopback addresses (SSRF protection).
+        validate_outbound_url(webhook_url)?;
+        // SECURITY: DNS rebinding prevention — resolve and reject private IPs.
+        crate::ssrf::dns_resolve_and_check(webhook_url).await?;
+
+        let separator = if webhook_url.contains('?') { '&' } else { '?' };
+        let url = format!("{webhook_url}{separator}wait=true");
+        let response = self
+            .rate_limits
+            .send(webhook_url, || self.client.post(&url).json(&payload))
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(http_status_error("Discord", status));
+        }
+        let message_id = response
+            .json::<Value>()
+            .await
+            .ok()
+            .and_then(|body| body["id"].as_str().map(str::to_string));
+        Ok(DiscordResponse {
+            status_code: status.as_u16(),
+            success: true,
+            duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+            message_id,
+        })
+    }
+}
+
+impl Default for DiscordAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Response from Discord execution
+#[derive(Debug, Clone)]
+pub struct DiscordResponse {
+    /// HTTP status code
+    pub status_code: u16,
+    /// Whether execution was successful
+    pub success:     bool,
+    /// Duration in milliseconds
+    pub duration_ms: f64,
+    /// Id of the created message, when Discord returned it
+    pub message_id:  Option<String>,
+}
+
+/// Render an `embed_template` against `data` into a Discord `embeds` array.
+///
+/// # Errors
+///
+/// Returns `ObserverError::TemplateRenderingFailed` if the result is not an
+/// embed object or an array of them, and `ObserverError::ActionPermanentlyFailed`
+/// if it has more than 10 embeds.
+pub fn render_embeds(template: &str, data: &Value) -> Result<Value> {
+    let rendered = render_json_template(template, data);
+    let parsed: Value =
+        serde_json::from_str(&rendered).map_err(|e| ObserverError::TemplateRenderingFailed {
+            reason: format!("Discord embed_template did not render to JSON: {e}"),
+        })?;
+    let embeds = match parsed {
+        Value::Array(embeds) => embeds,
+        embed @ Value::Object(_) => vec![embed],
+        _ => {
+            return Err(ObserverError::TemplateRenderingFailed {
+                reason: "Discord embed_template must render to an embed object or an array"
+                    .to_string(),
+            });
+        },
+    };
+    if !embeds.iter().all(Value::is_object) {
+        return Err(ObserverError::TemplateRenderingFailed {
+            reason: "Discord embed_template array must contain only embed objects".to_string(),
+        });
+    }
+    if embeds.len() > MAX_EMBEDS {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "Discord message has {} embeds; Discord accepts at most {MAX_EMBEDS}",
+                embeds.len()
+            ),
+        });
+    }
+    Ok(Value::Array(embeds))
+}
+
+/// Build the webhook payload for `event`.
+///
+/// # Errors
+///
+/// Returns the errors of [`render_embeds`], and
+/// `ObserverError::ActionPermanentlyFailed` if the content is too long or the
+/// message would be empty.
+pub(crate) fn render_payload(
+    username: Option<&str>,
+    message_template: Option<&str>,
+    embed_template: Option<&str>,
+    event: &EntityEvent,
+) -> Result<Value> {
+    let content = match (message_template, embed_template) {
+        (Some(template), _) => render_text_template(template, &event.data),
+        // The embeds carry the message.
+        (None, Some(_)) => String::new(),
+        (None, None) => format!(
+            "Event: {} on {} (ID: {})",
+            event.event_type.as_str(),
+            event.entity_type,
+            event.entity_id
+        ),
+    };
+    let chars = content.chars().count();
+    if chars > MAX_CONTENT_CHARS {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "Discord message is {chars} characters; Discord accepts at most \
+                 {MAX_CONTENT_CHARS}"
+            ),
+        });
+    }
+
+    let has_content = !content.trim().is_empty();
+    let mut payload = json!({
+        "content": content,
+        "allowed_mentions": { "parse": [] },
+    });
+    if let Some(name) = username {
+        payload["username"] = Value::String(name.to_string());
+    }
+    if let Some(template) = embed_template {
+        payload["embeds"] = render_embeds(template, &event.data)?;
+    }
+    if !has_content && payload["embeds"].as_array().is_none_or(Vec::is_empty) {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: "Discord message rendered with neither content nor embeds".to_string(),
+        });
+    }
+    Ok(payload)
+}
@@ -0,0 +1,124 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::json;
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, query_param},
+};
+
+use super::*;
+use crate::{event::EventKind, insecure_guard::ALLOW_INSECURE_ENV};
+
+fn event(data: Value) -> EntityEvent {
+    EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::nil(), data)
+}
+
+#[test]
+fn embeds_render_from_an_object_or_an_array() {
+    let data = json!({ "id": 42, "note": "\"},{\"title\":\"injected" });
+    let template = r#"{"title": "Order {{ id }}", "description": "{{ note }}"}"#;
+
+    let embeds = render_embeds(template, &data).unwrap();
+    // The field value stays inside its string; it cannot add an embed.
+    assert_eq!(embeds.as_array().unwrap().len(), 1);
+    assert_eq!(embeds[0]["title"], "Order 42");
+    assert_eq!(embeds[0]["description"], data["note"]);
+
+    let array = render_embeds(r#"[{"title": "a"}, {"title": "b"}]"#, &data).unwrap();
+    assert_eq!(array.as_array().unwrap().len(), 2);
+
+    let not_embeds = render_embeds(r#"["a"]"#, &data);
+    assert!(matches!(not_embeds, Err(ObserverError::TemplateRenderingFailed { .. })));
+    let too_many = render_embeds(&json!(vec![json!({ "title": "a" }); 11]).to_string(), &data);
+    assert!(matches!(too_many, Err(ObserverError::ActionPermanentlyFailed { .. })));
+}
+
+#[test]
+fn payload_never_allows_mentions_and_enforces_limits() {
+    let ping = event(json!({ "who": "@everyone" }));
+    let payload = render_payload(Some("FraiseQL"), Some("hi {{ who }}"), None, &ping).unwrap();
+    assert_eq!(payload["content"], "hi @everyone");
+    assert_eq!(payload["allowed_mentions"], json!({ "parse": [] }));
+    assert_eq!(payload["username"], "FraiseQL");
+
+    let long = event(json!({ "note": "x".repeat(MAX_CONTENT_CHARS + 1) }));
+    let too_long = render_payload(None, Some("{{ note }}"), None, &long);
+    assert!(matches!(too_long, Err(ObserverError::ActionPermanentlyFailed { .. })));
+
+    let empty = render_payload(None, None, Some("[]"), &event(json!({})));
+    assert!(matches!(empty, Err(ObserverError::ActionPermanentlyFailed { .. })));
+}
+
+#[tokio::test]
+async fn execute_waits_for_the_message_and_returns_its_id() {
+    // wiremock binds to 127.0.0.1, which the SSRF guard blocks; allow the
+    // insecure bypass for this loopback test only.
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(query_param("wait", "true"))
+            .and(body_partial_json(json!({
+                "content": "",
+                "embeds": [{ "title": "Order 42" }],
+                "allowed_mentions": { "parse": [] },
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "id": "1100000000000000000" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = DiscordAction::new()
+            .execute(
+                &server.uri(),
+                None,
+                None,
+                Some(r#"{"title": "Order {{ id }}"}"#),
+                &event(json!({ "id": 42 })),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.message_id.as_deref(), Some("1100000000000000000"));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn errors_are_classified_and_rate_limits_retried() {
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let deleted = DiscordAction::new()
+            .execute(&server.uri(), None, None, None, &event(json!({})))
+            .await;
+        assert!(
+            matches!(deleted, Err(ObserverError::ActionPermanentlyFailed { .. })),
+            "{deleted:?}"
+        );
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0.5"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let start = Instant::now();
+        let response = DiscordAction::new()
+            .execute(&server.uri(), None, None, None, &event(json!({})))
+            .await;
+        assert_eq!(response.unwrap().status_code, 204);
+        assert!(start.elapsed() >= Duration::from_millis(500), "Retry-After was not honored");
+    })
+    .await;
+}
@@ -0,0 +1,680 @@
+//! Push notification transport for the `push` observer action.
+//!
+//! A [`PushSender`] delivers one notification per device token through
+//! Firebase Cloud Messaging's HTTP v1 API or the Apple Push Notification
+//! service, as configured by a [`PushProvider`]:
+//!
+//! - **FCM** authenticates with a service account: a JWT signed with the account's RSA key is
+//!   exchanged for an `OAuth2` access token at the account's `token_uri`.
+//! - **APNs** uses token-based auth: an ES256 JWT signed with the team's `.p8` key. Requests go
+//!   over HTTP/2, negotiated through TLS ALPN.
+//!
+//! Access tokens and JWTs are cached per sender for [`AUTH_TOKEN_TTL`].
+//!
+//! # Invalid tokens
+//!
+//! Device tokens go stale when an app is uninstalled. When the provider reports
+//! a token as unregistered or invalid (FCM `UNREGISTERED`, APNs `410` /
+//! `BadDeviceToken`), the token is not retried: it is reported to the action's
+//! `invalid_token_webhook` so the application can delete it, and the action
+//! still succeeds for the remaining tokens.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use parking_lot::RwLock;
+use reqwest::{Client, Response, StatusCode};
+use serde_json::{Value, json};
+use tracing::{debug, warn};
+
+use crate::{
+    actions::render_text_template,
+    config::PushProvider,
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// FCM API base URL.
+pub const FCM_API_BASE: &str = "https://fcm.googleapis.com";
+
+/// APNs production API base URL.
+pub const APNS_API_BASE: &str = "https://api.push.apple.com";
+
+/// APNs development API base URL.
+pub const APNS_SANDBOX_API_BASE: &str = "https://api.sandbox.push.apple.com";
+
+/// How long an access token or provider JWT is reused.
+///
+/// Google access tokens live an hour; APNs rejects JWTs older than an hour and
+/// throttles providers that refresh more often than every 20 minutes.
+pub const AUTH_TOKEN_TTL: Duration = Duration::from_secs(50 * 60);
+
+/// `OAuth2` scope for sending FCM messages.
+const FCM_SCOPE: &str = "https://www.googleapis.com/auth/firebase.messaging";
+
+/// Token endpoint used when a service account key names none.
+const GOOGLE_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// Longest device token accepted.
+const MAX_DEVICE_TOKEN_LEN: usize = 4096;
+
+/// Device tokens one action may send to per event.
+const MAX_DEVICE_TOKENS: usize = 500;
+
+/// Longest collapse key accepted (the APNs `apns-collapse-id` limit).
+const MAX_COLLAPSE_KEY_LEN: usize = 64;
+
+/// Timeout of one provider call.
+const PUSH_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Resolve the device tokens a `push` action sends to for `event`.
+///
+/// A literal `device_token` wins. Otherwise `template` is rendered against the
+/// event's data and split on whitespace; a template that is a lone
+/// `{{ field }}` naming an array of strings yields those strings. Duplicates
+/// are dropped. An empty result is not an error: the entity simply has no
+/// registered devices.
+///
+/// # Errors
+///
+/// Returns [`ObserverError::InvalidActionConfig`] if neither source is set,
+/// [`ObserverError::TemplateRenderingFailed`] if a placeholder has no matching
+/// field, or [`ObserverError::ActionPermanentlyFailed`] if the event yields
+/// more than 500 tokens.
+pub fn render_device_tokens(
+    device_token: Option<&str>,
+    template: Option<&str>,
+    event: &EntityEvent,
+) -> Result<Vec<String>> {
+    let candidates: Vec<String> = if let Some(token) = device_token {
+        vec![token.to_string()]
+    } else if let Some(template) = template {
+        let array = lone_placeholder(template).and_then(|field| event.data.get(field));
+        if let Some(Value::Array(items)) = array {
+            items.iter().filter_map(Value::as_str).map(str::to_string).collect()
+        } else {
+            let rendered = render_text_template(template, &event.data);
+            if rendered.contains("{{") {
+                return Err(ObserverError::TemplateRenderingFailed {
+                    reason: format!(
+                        "Push device_token_template {template:?} has an unresolved placeholder"
+                    ),
+                });
+            }
+            rendered.split_whitespace().map(str::to_string).collect()
+        }
+    } else {
+        return Err(ObserverError::InvalidActionConfig {
+            reason: "Push action requires 'device_token' or 'device_token_template'".to_string(),
+        });
+    };
+
+    let mut tokens: Vec<String> = Vec::new();
+    for token in candidates {
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
+    }
+    if tokens.len() > MAX_DEVICE_TOKENS {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "Push action resolved {} device tokens, more than the {MAX_DEVICE_TOKENS} allowed \
+                 per event",
+                tokens.len()
+            ),
+        });
+    }
+    Ok(tokens)
+}
+
+/// The field name of a template that is exactly one `{{ field }}` placeholder.
+fn lone_placeholder(template: &str) -> Option<&str> {
+    let inner = template.trim().strip_prefix("{{")?.strip_suffix("}}")?.trim();
+    (!inner.is_empty() && !inner.contains("{{") && !inner.contains("}}")).then_some(inner)
+}
+
+/// A rendered notification, sent identically to every device token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushMessage {
+    /// Notification title.
+    pub title:        Option<String>,
+    /// Notification body.
+    pub body:         Option<String>,
+    /// Key under which a newer notification replaces an undelivered older one.
+    pub collapse_key: Option<String>,
+    /// Custom data delivered to the app: the originating event's id, entity
+    /// type, entity id and event type.
+    pub data:         BTreeMap<String, String>,
+}
+
+impl PushMessage {
+    /// Render a notification for `event`.
+    ///
+    /// Title and body placeholders without a matching field are left as-is,
+    /// as in Slack and email messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::TemplateRenderingFailed`] if the collapse key
+    /// has an unresolved placeholder, or
+    /// [`ObserverError::ActionPermanentlyFailed`] if it renders to more than 64
+    /// bytes or to characters that cannot travel in an HTTP header.
+    pub fn render(
+        title_template: Option<&str>,
+        body_template: Option<&str>,
+        collapse_key: Option<&str>,
+        event: &EntityEvent,
+    ) -> Result<Self> {
+        let render = |template: &str| render_text_template(template, &event.data);
+        let collapse_key = match collapse_key {
+            Some(template) => {
+                let key = render(template);
+                if key.contains("{{") {
+                    return Err(ObserverError::TemplateRenderingFailed {
+                        reason: format!(
+                            "Push collapse_key {template:?} has an unresolved placeholder"
+                        ),
+                    });
+                }
+                if key.is_empty()
+                    || key.len() > MAX_COLLAPSE_KEY_LEN
+                    || !key.bytes().all(|b| b.is_ascii_graphic())
+                {
+                    return Err(ObserverError::ActionPermanentlyFailed {
+                        reason: format!(
+                            "Push collapse key {key:?} must be 1-{MAX_COLLAPSE_KEY_LEN} visible \
+                             ASCII characters"
+                        ),
+                    });
+                }
+                Some(key)
+            },
+            None => None,
+        };
+        let data = BTreeMap::from([
+            ("event_id".to_string(), event.id.to_string()),
+            ("entity_type".to_string(), event.entity_type.clone()),
+            ("entity_id".to_string(), event.entity_id.to_string()),
+            ("event_type".to_string(), event.event_type.as_str().to_string()),
+        ]);
+        Ok(Self {
+            title: title_template.map(render),
+            body: body_template.map(render),
+            collapse_key,
+            data,
+        })
+    }
+}
+
+/// What the provider did with one device token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delivery {
+    /// The provider accepted the notification.
+    Sent,
+    /// The token is unregistered or invalid; the reason is the provider's.
+    InvalidToken(String),
+}
+
+/// Outcome of sending one notification to all of an event's device tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PushReport {
+    /// Tokens the provider accepted the notification for.
+    pub sent:    usize,
+    /// Tokens reported as invalid, with the provider's reason.
+    pub invalid: Vec<(String, String)>,
+}
+
+/// Sends push notifications through one provider account.
+///
+/// Cheap to share behind an `Arc`; the cached access token is shared by every
+/// caller.
+pub struct PushSender {
+    http:   Client,
+    target: PushTarget,
+    auth:   RwLock<Option<(String, Instant)>>,
+}
+
+enum PushTarget {
+    Fcm {
+        send_url:     String,
+        token_uri:    String,
+        client_email: String,
+        key:          EncodingKey,
+    },
+    Apns {
+        api_base: String,
+        team_id:  String,
+        key_id:   String,
+        topic:    String,
+        key:      EncodingKey,
+    },
+}
+
+impl PushSender {
+    /// Build a sender for `provider`, resolving its credentials from the
+    /// configured environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if the config is invalid,
+    /// the env var is unset or empty, or the service account JSON or signing
+    /// key is malformed.
+    pub fn from_config(provider: &PushProvider) -> Result<Self> {
+        provider.validate()?;
+        match provider {
+            PushProvider::Fcm {
+                service_account_env,
+                ..
+            } => Self::build(provider, FCM_API_BASE, &resolve_secret(service_account_env)?),
+            PushProvider::Apns {
+                private_key_env,
+                sandbox,
+                ..
+            } => {
+                let api_base = if *sandbox {
+                    APNS_SANDBOX_API_BASE
+                } else {
+                    APNS_API_BASE
+                };
+                Self::build(provider, api_base, &resolve_secret(private_key_env)?)
+            },
+        }
+    }
+
+    /// Build a sender against `api_base` with explicit credentials (the
+    /// service account JSON for FCM, the `.p8` PEM for APNs), skipping env
+    /// resolution.
+    fn build(provider: &PushProvider, api_base: &str, secret: &str) -> Result<Self> {
+        let http = Client::builder()
+            .timeout(PUSH_REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| ObserverError::InvalidConfig {
+                message: format!("failed to build push HTTP client: {e}"),
+            })?;
+        let api_base = api_base.trim_end_matches('/');
+        let target = match provider {
+            PushProvider::Fcm { project_id, .. } => {
+                let account: Value =
+                    serde_json::from_str(secret).map_err(|e| invalid_credentials(&e))?;
+                let field = |name: &str| {
+                    account.get(name).and_then(Value::as_str).map(str::to_string).ok_or_else(|| {
+                        ObserverError::InvalidActionConfig {
+                            reason: format!("Push FCM service account JSON is missing {name:?}"),
+                        }
+                    })
+                };
+                let key = EncodingKey::from_rsa_pem(field("private_key")?.as_bytes())
+                    .map_err(|e| invalid_credentials(&e))?;
+                PushTarget::Fcm {
+                    send_url: format!("{api_base}/v1/projects/{project_id}/messages:send"),
+                    token_uri: field("token_uri").unwrap_or_else(|_| GOOGLE_TOKEN_URI.to_string()),
+                    client_email: field("client_email")?,
+                    key,
+                }
+            },
+            PushProvider::Apns {
+                team_id,
+                key_id,
+                topic,
+                ..
+            } => PushTarget::Apns {
+                api_base: api_base.to_string(),
+                team_id:  team_id.clone(),
+                key_id:   key_id.clone(),
+                topic:    topic.clone(),
+                key:      EncodingKey::from_ec_pem(secret.as_bytes())
+                    .map_err(|e| invalid_credentials(&e))?,
+            },
+        };
+        Ok(Self {
+            http,
+            target,
+            auth: RwLock::new(None),
+        })
+    }
+
+    /// Provider name, for results and logs.
+    #[must_use]
+    pub const fn provider(&self) -> &'static str {
+        match self.target {
+            PushTarget::Fcm { .. } => "fcm",
+            PushTarget::Apns { .. } => "apns",
+        }
+    }
+
+    /// Send `message` to every token, reporting invalid tokens to
+    /// `invalid_token_webhook` (when set) as they are found.
+    ///
+    /// Every token is attempted even if an earlier one failed, so one bad
+    /// token never blocks the rest. A retry of a failed action resends to all
+    /// tokens; set a collapse key so a repeat replaces rather than stacks.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first retryable error if any send failed transiently,
+    /// otherwise the first permanent error (see [`send`](Self::send)).
+    pub async fn send_all(
+        &self,
+        tokens: &[String],
+        message: &PushMessage,
+        event: &EntityEvent,
+        invalid_token_webhook: Option<&str>,
+    ) -> Result<PushReport> {
+        let mut report = PushReport::default();
+        let mut failure: Option<ObserverError> = None;
+        for token in tokens {
+            match self.send(token, message).await {
+                Ok(Delivery::Sent) => report.sent += 1,
+                Ok(Delivery::InvalidToken(reason)) => {
+                    self.report_invalid(invalid_token_webhook, token, &reason, event).await;
+                    report.invalid.push((token.clone(), reason));
+                },
+                Err(e) => {
+                    if failure.as_ref().is_none_or(|f| !f.is_transient() && e.is_transient()) {
+                        failure = Some(e);
+                    }
+                },
+            }
+        }
+        failure.map_or(Ok(report), Err)
+    }
+
+    /// Send `message` to one device token.
+    ///
+    /// A token that is not made of letters, digits, `-`, `_` and `:` (hex
+    /// digits for APNs) is reported invalid without being sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::ActionPermanentlyFailed`] when the provider
+    /// rejects the request (4xx other than 429 and auth failures) or a
+    /// credential is refused, and [`ObserverError::ActionExecutionFailed`]
+    /// (retryable) on network errors, 429, 5xx, or an expired provider token.
+    pub async fn send(&self, token: &str, message: &PushMessage) -> Result<Delivery> {
+        if !is_well_formed(token, matches!(self.target, PushTarget::Apns { .. })) {
+            return Ok(Delivery::InvalidToken("MalformedToken".to_string()));
+        }
+        let auth = self.auth_token().await?;
+        let request = match &self.target {
+            PushTarget::Fcm { send_url, .. } => {
+                self.http.post(send_url).bearer_auth(&auth).json(&fcm_body(token, message))
+            },
+            PushTarget::Apns {
+                api_base, topic, ..
+            } => {
+                let request = self
+                    .http
+                    .post(format!("{api_base}/3/device/{token}"))
+                    .header("authorization", format!("bearer {auth}"))
+                    .header("apns-topic", topic)
+                    .header("apns-push-type", "alert")
+                    .header("apns-priority", "10")
+                    .json(&apns_body(message));
+                match &message.collapse_key {
+                    Some(key) => request.header("apns-collapse-id", key),
+                    None => request,
+                }
+            },
+        };
+
+        let provider = self.provider();
+        let response = request.send().await.map_err(|e| ObserverError::ActionExecutionFailed {
+            reason: format!("Push via {provider} failed: {e}"),
+        })?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(Delivery::Sent);
+        }
+        let text = response.text().await.unwrap_or_default();
+        let body: Value = serde_json::from_str(&text).unwrap_or_default();
+        if let Some(reason) = self.invalid_token_reason(status, &body) {
+            return Ok(Delivery::InvalidToken(reason));
+        }
+        if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
+            // An expired or revoked token: mint a fresh one on the retry.
+            *self.auth.write() = None;
+            return Err(ObserverError::ActionExecutionFailed {
+                reason: format!("Push via {provider} refused credentials: HTTP {status}: {text}"),
+            });
+        }
+        Err(classify(provider, "send", status, &text))
+    }
+
+    /// The provider's reason when `status`/`body` mean the token itself is bad.
+    fn invalid_token_reason(&self, status: StatusCode, body: &Value) -> Option<String> {
+        match self.target {
+            PushTarget::Fcm { .. } => {
+                let error_code = body
+                    .pointer("/error/details")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .find_map(|detail| detail.get("errorCode").and_then(Value::as_str));
+                (status == StatusCode::NOT_FOUND || error_code == Some("UNREGISTERED"))
+                    .then(|| error_code.unwrap_or("UNREGISTERED").to_string())
+            },
+            PushTarget::Apns { .. } => {
+                let reason = body.get("reason").and_then(Value::as_str);
+                match (status, reason) {
+                    (StatusCode::GONE, _) => Some(reason.unwrap_or("Unregistered").to_string()),
+                    (
+                        StatusCode::BAD_REQUEST,
+                        Some(reason @ ("BadDeviceToken" | "DeviceTokenNotForTopic")),
+                    ) => Some(reason.to_string()),
+                    _ => None,
+                }
+            },
+        }
+    }
+
+    /// The cached access token or provider JWT, minted when missing or old.
+    async fn auth_token(&self) -> Result<String> {
+        if let Some((token, expires)) = self.auth.read().as_ref() {
+            if Instant::now() < *expires {
+                return Ok(token.clone());
+            }
+        }
+        let token = match &self.target {
+            PushTarget::Fcm {
+                token_uri,
+                client_email,
+                key,
+                ..
+            } => {
+                let now = unix_now()?;
+                let claims = json!({
+                    "iss": client_email,
+                    "scope": FCM_SCOPE,
+                    "aud": token_uri,
+                    "iat": now,
+                    "exp": now + 3600,
+                });
+                let assertion = sign(Header::new(Algorithm::RS256), &claims, key)?;
+                self.exchange_assertion(token_uri, &assertion).await?
+            },
+            PushTarget::Apns {
+                team_id,
+                key_id,
+                key,
+                ..
+            } => {
+                let mut header = Header::new(Algorithm::ES256);
+                header.kid = Some(key_id.clone());
+                sign(header, &json!({ "iss": team_id, "iat": unix_now()? }), key)?
+            },
+        };
+        *self.auth.write() = Some((token.clone(), Instant::now() + AUTH_TOKEN_TTL));
+        debug!(provider = self.provider(), "minted push provider token");
+        Ok(token)
+    }
+
+    /// Exchange a signed service-account JWT for an `OAuth2` access token.
+    async fn exchange_assertion(&self, token_uri: &str, assertion: &str) -> Result<String> {
+        let response = self
+            .http
+            .post(token_uri)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", assertion),
+            ])
+            .send()
+            .await
+            .map_err(|e| ObserverError::ActionExecutionFailed {
+                reason: format!("Push via fcm token exchange failed: {e}"),
+            })?;
+        let response = check_status("fcm", "token exchange", response).await?;
+        let body: Value = response.json().await.unwrap_or_default();
+        body.get("access_token")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| ObserverError::ActionExecutionFailed {
+                reason: "Push via fcm token exchange returned no access_token".to_string(),
+            })
+    }
+
+    /// `POST` an invalid token to the cleanup webhook. Failures are logged, not
+    /// returned: the notification itself was handled.
+    async fn report_invalid(
+        &self,
+        webhook: Option<&str>,
+        token: &str,
+        reason: &str,
+        event: &EntityEvent,
+    ) {
+        let provider = self.provider();
+        let Some(url) = webhook else {
+            warn!(provider, reason, event_id = %event.id, "push device token is invalid");
+            return;
+        };
+        let payload = json!({
+            "provider": provider,
+            "device_token": token,
+            "reason": reason,
+            "event_id": event.id,
+            "entity_type": event.entity_type,
+            "entity_id": event.entity_id,
+        });
+        let outcome = match self.http.post(url).json(&payload).send().await {
+            Ok(response) => {
+                check_status(provider, "invalid-token webhook", response).await.map(drop)
+            },
+            Err(e) => Err(ObserverError::ActionExecutionFailed {
+                reason: e.to_string(),
+            }),
+        };
+        if let Err(e) = outcome {
+            warn!(provider, reason, event_id = %event.id, error = %e, "push invalid-token webhook failed");
+        }
+    }
+}
+
+fn fcm_body(token: &str, message: &PushMessage) -> Value {
+    let mut notification = serde_json::Map::new();
+    if let Some(title) = &message.title {
+        notification.insert("title".to_string(), json!(title));
+    }
+    if let Some(body) = &message.body {
+        notification.insert("body".to_string(), json!(body));
+    }
+    let mut body = json!({
+        "message": {
+            "token": token,
+            "notification": notification,
+            "data": message.data,
+        }
+    });
+    if let Some(key) = &message.collapse_key {
+        body["message"]["android"] = json!({ "collapse_key": key });
+        body["message"]["apns"] = json!({ "headers": { "apns-collapse-id": key } });
+    }
+    body
+}
+
+fn apns_body(message: &PushMessage) -> Value {
+    let mut alert = serde_json::Map::new();
+    if let Some(title) = &message.title {
+        alert.insert("title".to_string(), json!(title));
+    }
+    if let Some(body) = &message.body {
+        alert.insert("body".to_string(), json!(body));
+    }
+    let mut body = json!({ "aps": { "alert": alert } });
+    for (key, value) in &message.data {
+        body[key] = json!(value);
+    }
+    body
+}
+
+/// Tokens travel in a URL path (APNs) or JSON (FCM); only the characters real
+/// tokens use are accepted.
+fn is_well_formed(token: &str, hex_only: bool) -> bool {
+    !token.is_empty()
+        && token.len() <= MAX_DEVICE_TOKEN_LEN
+        && token.bytes().all(|b| {
+            if hex_only {
+                b.is_ascii_hexdigit()
+            } else {
+                b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b':')
+            }
+        })
+}
+
+/// Classify a failed response like a webhook response: 4xx other than 429 is
+/// permanent, everything else is retryable.
+fn classify(provider: &str, what: &str, status: StatusCode, body: &str) -> ObserverError {
+    let detail: String = body.chars().take(256).collect();
+    if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS {
+        ObserverError::ActionPermanentlyFailed {
+            reason: format!("Push via {provider} {what} rejected: HTTP {status}: {detail}"),
+        }
+    } else {
+        ObserverError::ActionExecutionFailed {
+            reason: format!("Push via {provider} {what} failed: HTTP {status}: {detail}"),
+        }
+    }
+}
+
+async fn check_status(provider: &str, what: &str, response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    Err(classify(provider, what, status, &body))
+}
+
+fn sign(header: Header, claims: &Value, key: &EncodingKey) -> Result<String> {
+    jsonwebtoken::encode(&header, claims, key).map_err(|e| ObserverError::ActionPermanentlyFailed {
+        reason: format!("failed to sign push provider JWT: {e}"),
+    })
+}
+
+fn unix_now() -> Result<u64> {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).map_err(|e| {
+        ObserverError::ActionExecutionFailed {
+            reason: format!("system clock is before the UNIX epoch: {e}"),
+        }
+    })
+}
+
+fn resolve_secret(env_var: &str) -> Result<String> {
+    match std::env::var(env_var) {
+        Ok(secret) if !secret.is_empty() => Ok(secret),
+        _ => Err(ObserverError::InvalidActionConfig {
+            reason: format!("Push credentials env var {env_var} is not set"),
+        }),
+    }
+}
+
+fn invalid_credentials(err: &dyn std::fmt::Display) -> ObserverError {
+    ObserverError::InvalidActionConfig {
+        reason: format!("Push provider credentials are malformed: {err}"),
+    }
+}
@@ -0,0 +1,267 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::json;
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header, method, path},
+};
+
+use super::*;
+use crate::event::EventKind;
+
+const FCM_TEST_KEY: &str = include_str!("../../../test_data/push_fcm_test_key.pem");
+const APNS_TEST_KEY: &str = include_str!("../../../test_data/push_apns_test_key.p8");
+const APNS_TOKEN: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90";
+
+fn event(data: Value) -> EntityEvent {
+    EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::nil(), data)
+}
+
+fn message() -> PushMessage {
+    PushMessage::render(
+        Some("Order {{ id }}"),
+        Some("Now {{ status }}"),
+        Some("order-{{ id }}"),
+        &event(json!({ "id": 42, "status": "shipped" })),
+    )
+    .unwrap()
+}
+
+fn fcm_sender(server: &MockServer) -> PushSender {
+    let provider = PushProvider::Fcm {
+        project_id:          "my-app".to_string(),
+        service_account_env: "FCM_SERVICE_ACCOUNT".to_string(),
+    };
+    let account = json!({
+        "client_email": "push@my-app.iam.gserviceaccount.com",
+        "private_key": FCM_TEST_KEY,
+        "token_uri": format!("{}/token", server.uri()),
+    });
+    PushSender::build(&provider, &server.uri(), &account.to_string()).unwrap()
+}
+
+fn apns_sender(server: &MockServer) -> PushSender {
+    let provider = PushProvider::Apns {
+        team_id:         "TEAM123456".to_string(),
+        key_id:          "KEY1234567".to_string(),
+        private_key_env: "APNS_KEY".to_string(),
+        topic:           "com.example.app".to_string(),
+        sandbox:         true,
+    };
+    PushSender::build(&provider, &server.uri(), APNS_TEST_KEY).unwrap()
+}
+
+async fn mock_fcm_token(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "access_token": "at-1", "expires_in": 3599 })),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn device_tokens_come_from_a_literal_a_template_or_an_array_field() {
+    let data = event(json!({ "token": "tok-1", "tokens": ["tok-1", "tok-2", "tok-1"] }));
+
+    let literal = render_device_tokens(Some("fixed"), Some("{{ token }}"), &data).unwrap();
+    assert_eq!(literal, ["fixed"]);
+    let rendered = render_device_tokens(None, Some("{{ token }} extra"), &data).unwrap();
+    assert_eq!(rendered, ["tok-1", "extra"]);
+    let array = render_device_tokens(None, Some("{{ tokens }}"), &data).unwrap();
+    assert_eq!(array, ["tok-1", "tok-2"]);
+    assert!(
+        render_device_tokens(None, Some("{{ tokens }}"), &event(json!({ "tokens": [] })))
+            .unwrap()
+            .is_empty()
+    );
+
+    let missing = render_device_tokens(None, Some("{{ missing }}"), &data);
+    assert!(matches!(missing, Err(ObserverError::TemplateRenderingFailed { .. })));
+    let too_many: Vec<String> = (0..=MAX_DEVICE_TOKENS).map(|i| format!("t{i}")).collect();
+    let too_many = render_device_tokens(None, Some("{{ t }}"), &event(json!({ "t": too_many })));
+    assert!(matches!(too_many, Err(ObserverError::ActionPermanentlyFailed { .. })));
+}
+
+#[test]
+fn message_renders_templates_collapse_key_and_event_data() {
+    let message = message();
+    assert_eq!(message.title.as_deref(), Some("Order 42"));
+    assert_eq!(message.body.as_deref(), Some("Now shipped"));
+    assert_eq!(message.collapse_key.as_deref(), Some("order-42"));
+    assert_eq!(message.data["entity_type"], "Order");
+    assert_eq!(message.data["event_type"], "UPDATE");
+
+    let long = "x".repeat(MAX_COLLAPSE_KEY_LEN + 1);
+    let too_long = PushMessage::render(None, Some("b"), Some(&long), &event(json!({})));
+    assert!(matches!(too_long, Err(ObserverError::ActionPermanentlyFailed { .. })));
+    let unresolved = PushMessage::render(None, Some("b"), Some("{{ nope }}"), &event(json!({})));
+    assert!(matches!(unresolved, Err(ObserverError::TemplateRenderingFailed { .. })));
+}
+
+#[tokio::test]
+async fn fcm_exchanges_a_service_account_token_once_and_sends() {
+    let server = MockServer::start().await;
+    mock_fcm_token(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/v1/projects/my-app/messages:send"))
+        .and(header("authorization", "Bearer at-1"))
+        .and(body_partial_json(json!({
+            "message": {
+                "token": "fcm-token-1",
+                "notification": { "title": "Order 42", "body": "Now shipped" },
+                "android": { "collapse_key": "order-42" },
+                "apns": { "headers": { "apns-collapse-id": "order-42" } },
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "name": "m/1" })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let sender = fcm_sender(&server);
+    assert_eq!(sender.send("fcm-token-1", &message()).await.unwrap(), Delivery::Sent);
+    assert_eq!(sender.send("fcm-token-1", &message()).await.unwrap(), Delivery::Sent);
+}
+
+#[tokio::test]
+async fn fcm_unregistered_tokens_are_reported_to_the_cleanup_webhook() {
+    let server = MockServer::start().await;
+    mock_fcm_token(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/v1/projects/my-app/messages:send"))
+        .and(body_partial_json(json!({ "message": { "token": "stale" } })))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": {
+                "status": "NOT_FOUND",
+                "details": [{ "errorCode": "UNREGISTERED" }],
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/projects/my-app/messages:send"))
+        .and(body_partial_json(json!({ "message": { "token": "fresh" } })))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/cleanup"))
+        .and(body_partial_json(json!({
+            "provider": "fcm",
+            "device_token": "stale",
+            "reason": "UNREGISTERED",
+            "entity_type": "Order",
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sender = fcm_sender(&server);
+    let tokens = ["stale".to_string(), "fresh".to_string()];
+    let cleanup = format!("{}/cleanup", server.uri());
+    let report = sender
+        .send_all(&tokens, &message(), &event(json!({})), Some(&cleanup))
+        .await
+        .unwrap();
+    assert_eq!(report.sent, 1);
+    assert_eq!(report.invalid, [("stale".to_string(), "UNREGISTERED".to_string())]);
+}
+
+#[tokio::test]
+async fn apns_sends_with_a_provider_jwt_topic_and_collapse_id() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/3/device/{APNS_TOKEN}")))
+        .and(header("apns-topic", "com.example.app"))
+        .and(header("apns-push-type", "alert"))
+        .and(header("apns-collapse-id", "order-42"))
+        .and(body_partial_json(json!({
+            "aps": { "alert": { "title": "Order 42", "body": "Now shipped" } },
+            "entity_type": "Order",
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sender = apns_sender(&server);
+    assert_eq!(sender.send(APNS_TOKEN, &message()).await.unwrap(), Delivery::Sent);
+
+    let requests = server.received_requests().await.unwrap();
+    let auth = requests[0].headers.get("authorization").unwrap().to_str().unwrap();
+    let jwt = auth.strip_prefix("bearer ").unwrap();
+    let jwt_header = jsonwebtoken::decode_header(jwt).unwrap();
+    assert_eq!(jwt_header.alg, Algorithm::ES256);
+    assert_eq!(jwt_header.kid.as_deref(), Some("KEY1234567"));
+}
+
+#[tokio::test]
+async fn apns_responses_are_classified() {
+    let server = MockServer::start().await;
+    let token = |n: u8| format!("{n:02x}").repeat(32);
+    for (n, status, reason) in [
+        (1, 410, "Unregistered"),
+        (2, 400, "BadDeviceToken"),
+        (3, 400, "BadCollapseId"),
+        (4, 503, "ServiceUnavailable"),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(format!("/3/device/{}", token(n))))
+            .respond_with(ResponseTemplate::new(status).set_body_json(json!({ "reason": reason })))
+            .mount(&server)
+            .await;
+    }
+    let sender = apns_sender(&server);
+    let message = message();
+
+    assert_eq!(
+        sender.send(&token(1), &message).await.unwrap(),
+        Delivery::InvalidToken("Unregistered".to_string())
+    );
+    assert_eq!(
+        sender.send(&token(2), &message).await.unwrap(),
+        Delivery::InvalidToken("BadDeviceToken".to_string())
+    );
+    let permanent = sender.send(&token(3), &message).await.unwrap_err();
+    assert!(
+        matches!(permanent, ObserverError::ActionPermanentlyFailed { .. }),
+        "{permanent:?}"
+    );
+    let transient = sender.send(&token(4), &message).await.unwrap_err();
+    assert!(transient.is_transient(), "{transient:?}");
+
+    // A malformed token is never sent.
+    assert_eq!(
+        sender.send("not/hex", &message).await.unwrap(),
+        Delivery::InvalidToken("MalformedToken".to_string())
+    );
+
+    // One failure does not stop the rest, and a transient one wins so the
+    // action is retried.
+    let tokens = [token(3), token(4), token(1)];
+    let err = sender.send_all(&tokens, &message, &event(json!({})), None).await.unwrap_err();
+    assert!(err.is_transient(), "{err:?}");
+}
+
+#[test]
+fn malformed_credentials_fail_at_build() {
+    let provider = PushProvider::Fcm {
+        project_id:          "my-app".to_string(),
+        service_account_env: "FCM_SERVICE_ACCOUNT".to_string(),
+    };
+    let missing_key = json!({ "client_email": "a@b.c" }).to_string();
+    assert!(matches!(
+        PushSender::build(&provider, FCM_API_BASE, &missing_key),
+        Err(ObserverError::InvalidActionConfig { .. })
+    ));
+    assert!(matches!(
+        PushSender::build(&provider, FCM_API_BASE, "not json"),
+        Err(ObserverError::InvalidActionConfig { .. })
+    ));
+}
@@ -0,0 +1,332 @@
+//! Slack transport for the `slack` observer action.
+//!
+//! A [`SlackAction`] posts through one of two Slack endpoints:
+//!
+//! - **Incoming webhook** (`webhook_url`) — to the channel the webhook was created for.
+//! - **Web API** (`bot_token_env`) — `chat.postMessage` with a bot token, to any channel the bot is
+//!   a member of. Only this endpoint returns the message `ts`, so only it can thread.
+//!
+//! # Block Kit
+//!
+//! A `blocks_template` is Block Kit JSON: the `blocks` array itself or a Block
+//! Kit Builder payload (`{"blocks": [...]}`). Placeholders are substituted
+//! JSON-escaped, as in a webhook `body_template`, so an event field cannot
+//! inject blocks of its own. The rendered `message_template` is still sent as
+//! `text`, which Slack shows in notifications.
+//!
+//! # Threads
+//!
+//! With `thread_by_entity`, the first message about an entity starts a thread
+//! and later messages about the same entity reply in it. The thread's `ts` is
+//! kept in the [`SlackThreadStore`] wired into the executor, under
+//! [`thread_key`]. Two events about a new entity that arrive together may each
+//! start a thread; the one recorded last is replied to from then on.
+//!
+//! # Rate limits
+//!
+//! A `429 Too Many Requests` holds every request to that endpoint until its
+//! `Retry-After` has passed, then retries; see [`chat`](crate::actions::chat).
+//! A `Retry-After` longer than
+//! [`with_max_rate_limit_wait`](SlackAction::with_max_rate_limit_wait) fails the
+//! attempt as transient and leaves the delay to the executor's retry policy.
+
+#[cfg(test)]
+mod tests;
+pub mod threads;
+
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use serde_json::{Value, json};
+use tracing::warn;
+
+pub use self::threads::{InMemorySlackThreadStore, SlackThreadStore, thread_key};
+use crate::{
+    actions::{
+        DEFAULT_WEBHOOK_TIMEOUT_SECS,
+        chat::{RetryAfterGate, http_status_error},
+        render_json_template, render_text_template, validate_outbound_url,
+    },
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Slack Web API base URL.
+pub const SLACK_API_BASE: &str = "https://slack.com/api";
+
+/// Most blocks Slack accepts in one message.
+const MAX_BLOCKS: usize = 50;
+
+/// Web API `error` codes worth retrying; every other error is permanent.
+const TRANSIENT_API_ERRORS: &[&str] = &[
+    "internal_error",
+    "fatal_error",
+    "service_unavailable",
+    "request_timeout",
+    "ratelimited",
+];
+
+/// Slack action executor
+pub struct SlackAction {
+    /// HTTP client for making requests
+    client:      Client,
+    /// Web API base URL ([`SLACK_API_BASE`] outside tests).
+    api_base:    String,
+    /// `Retry-After` bookkeeping per webhook URL or channel.
+    rate_limits: RetryAfterGate,
+}
+
+impl SlackAction {
+    /// Create a new Slack action executor
+    #[must_use]
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_WEBHOOK_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::error!(error = %e, "Failed to build HTTP client for SlackAction; falling back to no-timeout client");
+                Client::default()
+            });
+        Self {
+            client,
+            api_base: SLACK_API_BASE.to_string(),
+            rate_limits: RetryAfterGate::new("Slack"),
+        }
+    }
+
+    /// Set the longest `Retry-After` waited out in-process (default 30 s).
+    #[must_use]
+    pub const fn with_max_rate_limit_wait(mut self, wait: Duration) -> Self {
+        self.rate_limits.set_max_wait(wait);
+        self
+    }
+
+    /// Point the Web API at `api_base` (a mock server).
+    #[cfg(test)]
+    pub(crate) fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+
+    /// Execute Slack action through an incoming webhook
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError` if the webhook URL is invalid, a template does
+    /// not render, the HTTP request fails, or the Slack API returns a
+    /// non-success response.
+    pub async fn execute(
+        &self,
+        webhook_url: &str,
+        channel: Option<&str>,
+        message_template: Option<&str>,
+        blocks_template: Option<&str>,
+        event: &EntityEvent,
+    ) -> Result<SlackResponse> {
+        let start = Instant::now();
+        let payload = render_payload(channel, message_template, blocks_template, event)?;
+
+        // SECURITY: Reject URLs that target private/loopback addresses (SSRF protection).
+        validate_outbound_url(webhook_url)?;
+        // SECURITY: DNS rebinding prevention — resolve and reject private IPs.
+        crate::ssrf::dns_resolve_and_check(webhook_url).await?;
+
+        let response = self
+            .rate_limits
+            .send(webhook_url, || self.client.post(webhook_url).json(&payload))
+            .await?;
+
+        let status = response.status();
+        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+        if status.is_success() {
+            Ok(SlackResponse {
+                status_code: status.as_u16(),
+                success: true,
+                duration_ms,
+                ts: None,
+                thread_ts: None,
+            })
+        } else {
+            Err(http_status_error("Slack", status))
+        }
+    }
+
+    /// Post a message with the Web API (`chat.postMessage`), replying in
+    /// `thread_ts` when given.
+    ///
+    /// A thread Slack no longer knows (`thread_not_found`) is abandoned and
+    /// the message posted at the top of the channel; the response then has no
+    /// `thread_ts`, so the caller records the new thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::TemplateRenderingFailed` if `blocks_template`
+    /// does not render to Block Kit JSON, `ObserverError::ActionExecutionFailed`
+    /// for outages, rate limits and transient Slack errors, and
+    /// `ObserverError::ActionPermanentlyFailed` for every other Slack error
+    /// (unknown channel, revoked token, invalid blocks).
+    pub async fn post_message(
+        &self,
+        bot_token: &str,
+        channel: &str,
+        message_template: Option<&str>,
+        blocks_template: Option<&str>,
+        thread_ts: Option<&str>,
+        event: &EntityEvent,
+    ) -> Result<SlackResponse> {
+        let start = Instant::now();
+        let url = format!("{}/chat.postMessage", self.api_base);
+        let endpoint = format!("chat.postMessage {channel}");
+        let mut payload = render_payload(Some(channel), message_template, blocks_template, event)?;
+        let mut thread_ts = thread_ts;
+
+        loop {
+            if let Some(ts) = thread_ts {
+                payload["thread_ts"] = Value::String(ts.to_string());
+            }
+            let response = self
+                .rate_limits
+                .send(&endpoint, || self.client.post(&url).bearer_auth(bot_token).json(&payload))
+                .await?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(http_status_error("Slack", status));
+            }
+            let body: Value =
+                response.json().await.map_err(|e| ObserverError::ActionExecutionFailed {
+                    reason: format!("Slack chat.postMessage returned an unreadable body: {e}"),
+                })?;
+
+            if body["ok"].as_bool() == Some(true) {
+                return Ok(SlackResponse {
+                    status_code: status.as_u16(),
+                    success:     true,
+                    duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+                    ts:          body["ts"].as_str().map(str::to_string),
+                    thread_ts:   thread_ts.map(str::to_string),
+                });
+            }
+            let error = body["error"].as_str().unwrap_or("unknown_error");
+            if error == "thread_not_found" && thread_ts.is_some() {
+                warn!(event_id = %event.id, "Slack thread no longer exists; starting a new one");
+                thread_ts = None;
+                if let Value::Object(map) = &mut payload {
+                    map.remove("thread_ts");
+                }
+                continue;
+            }
+            let reason = format!("Slack chat.postMessage failed: {error}");
+            return Err(if TRANSIENT_API_ERRORS.contains(&error) {
+                ObserverError::ActionExecutionFailed { reason }
+            } else {
+                ObserverError::ActionPermanentlyFailed { reason }
+            });
+        }
+    }
+
+    #[cfg(test)]
+    #[allow(clippy::unused_self)] // Reason: method is part of a public API / trait consistency
+    pub(crate) fn render_message_template(&self, template: &str, data: &Value) -> String {
+        render_text_template(template, data)
+    }
+}
+
+impl Default for SlackAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Response from Slack execution
+#[derive(Debug, Clone)]
+pub struct SlackResponse {
+    /// HTTP status code
+    pub status_code: u16,
+    /// Whether execution was successful
+    pub success:     bool,
+    /// Duration in milliseconds
+    pub duration_ms: f64,
+    /// Timestamp (id) of the posted message; incoming webhooks return none
+    pub ts:          Option<String>,
+    /// Thread the message replied in, if any
+    pub thread_ts:   Option<String>,
+}
+
+/// Build the message payload: `text` (with `channel`) and, from
+/// `blocks_template`, `blocks`.
+///
+/// # Errors
+///
+/// Returns the errors of [`render_blocks`].
+pub(crate) fn render_payload(
+    channel: Option<&str>,
+    message_template: Option<&str>,
+    blocks_template: Option<&str>,
+    event: &EntityEvent,
+) -> Result<Value> {
+    let message = message_template.map_or_else(
+        || {
+            format!(
+                "Event: {} on {} (ID: {})",
+                event.event_type.as_str(),
+                event.entity_type,
+                event.entity_id
+            )
+        },
+        |template| render_text_template(template, &event.data),
+    );
+
+    let mut payload = json!({
+        "text": message,
+        "type": "mrkdwn"
+    });
+
+    if let Some(ch) = channel {
+        payload["channel"] = Value::String(ch.to_string());
+    }
+    if let Some(template) = blocks_template {
+        payload["blocks"] = render_blocks(template, &event.data)?;
+    }
+    Ok(payload)
+}
+
+/// Render a `blocks_template` against `data` into a Block Kit `blocks` array.
+///
+/// # Errors
+///
+/// Returns `ObserverError::TemplateRenderingFailed` if the result is not JSON,
+/// or neither an array nor an object with a `blocks` array, and
+/// `ObserverError::ActionPermanentlyFailed` if it has more than 50 blocks.
+pub fn render_blocks(template: &str, data: &Value) -> Result<Value> {
+    let rendered = render_json_template(template, data);
+    let parsed: Value =
+        serde_json::from_str(&rendered).map_err(|e| ObserverError::TemplateRenderingFailed {
+            reason: format!("Slack blocks_template did not render to JSON: {e}"),
+        })?;
+    let blocks = match parsed {
+        Value::Array(blocks) => blocks,
+        Value::Object(mut map) => match map.remove("blocks") {
+            Some(Value::Array(blocks)) => blocks,
+            _ => {
+                return Err(ObserverError::TemplateRenderingFailed {
+                    reason: "Slack blocks_template object has no 'blocks' array".to_string(),
+                });
+            },
+        },
+        _ => {
+            return Err(ObserverError::TemplateRenderingFailed {
+                reason: "Slack blocks_template must render to a blocks array".to_string(),
+            });
+        },
+    };
+    if blocks.len() > MAX_BLOCKS {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "Slack message has {} blocks; Slack accepts at most {MAX_BLOCKS}",
+                blocks.len()
+            ),
+        });
+    }
+    Ok(Value::Array(blocks))
+}
@@ -0,0 +1,188 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::json;
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header, method, path},
+};
+
+use super::*;
+use crate::event::EventKind;
+
+const CHANNEL: &str = "C0123456789";
+
+fn event(data: Value) -> EntityEvent {
+    EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::nil(), data)
+}
+
+fn slack(server: &MockServer) -> SlackAction {
+    SlackAction::new().with_api_base(server.uri())
+}
+
+async fn post(action: &SlackAction, thread_ts: Option<&str>) -> Result<SlackResponse> {
+    action
+        .post_message("xoxb-test", CHANNEL, Some("hi"), None, thread_ts, &event(json!({})))
+        .await
+}
+
+async fn mock_api(server: &MockServer, body: Value) {
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn blocks_render_from_an_array_or_a_builder_payload() {
+    let data = json!({ "id": 42, "note": "\"}]},{\"type\":\"divider" });
+    let template = r#"[{"type": "section", "text": {"type": "mrkdwn", "text": "Order {{ id }}: {{ note }}"}}]"#;
+
+    let blocks = render_blocks(template, &data).unwrap();
+    // The field value stays inside its string; it cannot add a block.
+    assert_eq!(blocks.as_array().unwrap().len(), 1);
+    assert_eq!(blocks[0]["text"]["text"], "Order 42: \"}]},{\"type\":\"divider");
+
+    let builder = render_blocks(r#"{"blocks": [{"type": "divider"}]}"#, &data).unwrap();
+    assert_eq!(builder, json!([{ "type": "divider" }]));
+
+    let not_json = render_blocks("Order {{ id }}", &data);
+    assert!(matches!(not_json, Err(ObserverError::TemplateRenderingFailed { .. })));
+    let no_blocks = render_blocks(r#"{"text": "hi"}"#, &data);
+    assert!(matches!(no_blocks, Err(ObserverError::TemplateRenderingFailed { .. })));
+    let too_many = render_blocks(&json!(vec![json!({ "type": "divider" }); 51]).to_string(), &data);
+    assert!(matches!(too_many, Err(ObserverError::ActionPermanentlyFailed { .. })));
+}
+
+#[tokio::test]
+async fn post_message_sends_blocks_in_the_thread_and_returns_the_ts() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .and(header("authorization", "Bearer xoxb-test"))
+        .and(body_partial_json(json!({
+            "channel": CHANNEL,
+            "text": "Order 42 shipped",
+            "thread_ts": "1700000000.000100",
+            "blocks": [{ "type": "header", "text": { "type": "plain_text", "text": "42" } }],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ok": true,
+            "channel": CHANNEL,
+            "ts": "1700000001.000200",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let response = slack(&server)
+        .post_message(
+            "xoxb-test",
+            CHANNEL,
+            Some("Order {{ id }} shipped"),
+            Some(r#"[{"type": "header", "text": {"type": "plain_text", "text": "{{ id }}"}}]"#),
+            Some("1700000000.000100"),
+            &event(json!({ "id": "42" })),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.ts.as_deref(), Some("1700000001.000200"));
+    assert_eq!(response.thread_ts.as_deref(), Some("1700000000.000100"));
+}
+
+#[tokio::test]
+async fn a_lost_thread_is_replaced_by_a_new_one() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .and(body_partial_json(json!({ "thread_ts": "1600000000.000100" })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "ok": false, "error": "thread_not_found" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    mock_api(&server, json!({ "ok": true, "ts": "1700000002.000300" })).await;
+
+    let response = post(&slack(&server), Some("1600000000.000100")).await.unwrap();
+    assert_eq!(response.ts.as_deref(), Some("1700000002.000300"));
+    assert_eq!(response.thread_ts, None);
+}
+
+#[tokio::test]
+async fn api_errors_are_classified() {
+    let send = |body: Value| async move {
+        let server = MockServer::start().await;
+        mock_api(&server, body).await;
+        post(&slack(&server), None).await
+    };
+
+    let unknown_channel = send(json!({ "ok": false, "error": "channel_not_found" })).await;
+    assert!(
+        matches!(unknown_channel, Err(ObserverError::ActionPermanentlyFailed { .. })),
+        "{unknown_channel:?}"
+    );
+    let outage = send(json!({ "ok": false, "error": "internal_error" })).await.unwrap_err();
+    assert!(outage.is_transient(), "{outage:?}");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+    let unavailable = post(&slack(&server), None).await.unwrap_err();
+    assert!(unavailable.is_transient(), "{unavailable:?}");
+}
+
+#[tokio::test]
+async fn rate_limits_are_waited_out_and_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    mock_api(&server, json!({ "ok": true, "ts": "1700000003.000400" })).await;
+
+    let start = Instant::now();
+    let response = post(&slack(&server), None).await.unwrap();
+    assert_eq!(response.ts.as_deref(), Some("1700000003.000400"));
+    assert!(start.elapsed() >= Duration::from_secs(1), "Retry-After was not honored");
+}
+
+#[tokio::test]
+async fn a_long_retry_after_fails_transient_and_holds_later_sends() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "120"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let action = slack(&server).with_max_rate_limit_wait(Duration::from_secs(5));
+
+    let first = post(&action, None).await.unwrap_err();
+    assert!(first.is_transient(), "{first:?}");
+    // Still inside the window: fails without calling Slack again.
+    let second = post(&action, None).await.unwrap_err();
+    assert!(second.is_transient(), "{second:?}");
+}
+
+#[tokio::test]
+async fn in_memory_thread_store_keys_threads_by_channel_and_entity() {
+    let store = InMemorySlackThreadStore::new();
+    let event = event(json!({}));
+    let key = thread_key(CHANNEL, &event);
+    assert_eq!(key, format!("{CHANNEL}:Order:{}", Uuid::nil()));
+    assert_ne!(key, thread_key("C9876543210", &event));
+
+    assert_eq!(store.thread_ts(&key).await.unwrap(), None);
+    store.record_thread_ts(&key, "1700000000.000100").await.unwrap();
+    store.record_thread_ts(&key, "1700000004.000500").await.unwrap();
+    assert_eq!(store.thread_ts(&key).await.unwrap().as_deref(), Some("1700000004.000500"));
+    assert_eq!(store.len(), 1);
+}
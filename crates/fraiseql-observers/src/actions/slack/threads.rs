@@ -0,0 +1,195 @@
+//! Where Slack threads are remembered between events.
+//!
+//! A `slack` action with `thread_by_entity` replies in one thread per entity.
+//! The thread is identified by the `ts` of the message that started it, kept
+//! in a [`SlackThreadStore`] under [`thread_key`]:
+//!
+//! - [`InMemorySlackThreadStore`] — per process; the executor's default.
+//! - `RedisSlackThreadStore` (feature `redis-slack-threads`) — shared, with a TTL.
+//! - `PostgresSlackThreadStore` (feature `postgres`) — shared, on the
+//!   `_fraiseql_observer_slack_thread` table (DDL:
+//!   [`observer_slack_thread_sql`](crate::migrations::observer_slack_thread_sql)).
+//!
+//! Observers running on several instances need a shared store, or each
+//! instance starts its own thread for the same entity.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use parking_lot::RwLock;
+
+use crate::{error::Result, event::EntityEvent};
+
+/// The thread key of `event`'s entity in `channel`.
+#[must_use]
+pub fn thread_key(channel: &str, event: &EntityEvent) -> String {
+    format!("{channel}:{}:{}", event.entity_type, event.entity_id)
+}
+
+/// Thread timestamps of Slack threads, by [`thread_key`].
+#[async_trait]
+pub trait SlackThreadStore: Send + Sync {
+    /// The `ts` of the thread started for `key`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the store is unreachable; the action is retried
+    /// rather than posted outside its thread.
+    async fn thread_ts(&self, key: &str) -> Result<Option<String>>;
+
+    /// Remember that the thread for `key` starts at `ts`, replacing any
+    /// earlier thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the store cannot be written; it is logged, not
+    /// retried, since the message was already posted.
+    async fn record_thread_ts(&self, key: &str, ts: &str) -> Result<()>;
+}
+
+/// An in-process [`SlackThreadStore`].
+///
+/// Keeps every thread for the life of the process.
+#[derive(Debug, Default)]
+pub struct InMemorySlackThreadStore {
+    threads: RwLock<HashMap<String, String>>,
+}
+
+impl InMemorySlackThreadStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of threads remembered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.threads.read().len()
+    }
+
+    /// Whether no thread is remembered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.threads.read().is_empty()
+    }
+}
+
+#[async_trait]
+impl SlackThreadStore for InMemorySlackThreadStore {
+    async fn thread_ts(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.threads.read().get(key).cloned())
+    }
+
+    async fn record_thread_ts(&self, key: &str, ts: &str) -> Result<()> {
+        self.threads.write().insert(key.to_string(), ts.to_string());
+        Ok(())
+    }
+}
+
+/// [`SlackThreadStore`] on Redis keys with a TTL.
+///
+/// A thread is forgotten `ttl_seconds` after it was started; the next message
+/// about the entity then starts a new one.
+#[cfg(feature = "redis-slack-threads")]
+#[derive(Clone)]
+pub struct RedisSlackThreadStore {
+    conn:        redis::aio::ConnectionManager,
+    ttl_seconds: u64,
+}
+
+#[cfg(feature = "redis-slack-threads")]
+impl RedisSlackThreadStore {
+    /// Create a store on `conn` keeping threads for `ttl_seconds`.
+    #[must_use]
+    pub const fn new(conn: redis::aio::ConnectionManager, ttl_seconds: u64) -> Self {
+        Self { conn, ttl_seconds }
+    }
+
+    /// Redis key of a thread key.
+    pub(crate) fn redis_key(key: &str) -> String {
+        format!("fraiseql:slack_thread:v1:{key}")
+    }
+}
+
+#[cfg(feature = "redis-slack-threads")]
+#[async_trait]
+impl SlackThreadStore for RedisSlackThreadStore {
+    async fn thread_ts(&self, key: &str) -> Result<Option<String>> {
+        let ts: Option<String> = redis::cmd("GET")
+            .arg(Self::redis_key(key))
+            .query_async(&mut self.conn.clone())
+            .await?;
+        Ok(ts)
+    }
+
+    async fn record_thread_ts(&self, key: &str, ts: &str) -> Result<()> {
+        let () = redis::cmd("SET")
+            .arg(Self::redis_key(key))
+            .arg(ts)
+            .arg("EX")
+            .arg(self.ttl_seconds.cast_signed())
+            .query_async(&mut self.conn.clone())
+            .await?;
+        Ok(())
+    }
+}
+
+/// [`SlackThreadStore`] on the `_fraiseql_observer_slack_thread` table.
+#[cfg(feature = "postgres")]
+#[derive(Clone)]
+pub struct PostgresSlackThreadStore {
+    pool: sqlx::PgPool,
+}
+
+#[cfg(feature = "postgres")]
+impl PostgresSlackThreadStore {
+    /// Create a store on `pool`.
+    #[must_use]
+    pub const fn new(pool: sqlx::PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Forget threads started more than `older_than` ago. Returns how many.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::DatabaseError` if the delete fails.
+    pub async fn prune(&self, older_than: std::time::Duration) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM _fraiseql_observer_slack_thread \
+             WHERE started_at < NOW() - make_interval(secs => $1)",
+        )
+        .bind(older_than.as_secs_f64())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[async_trait]
+impl SlackThreadStore for PostgresSlackThreadStore {
+    async fn thread_ts(&self, key: &str) -> Result<Option<String>> {
+        Ok(sqlx::query_scalar(
+            "SELECT thread_ts FROM _fraiseql_observer_slack_thread WHERE thread_key = $1",
+        )
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    async fn record_thread_ts(&self, key: &str, ts: &str) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO _fraiseql_observer_slack_thread (thread_key, thread_ts) \
+             VALUES ($1, $2) \
+             ON CONFLICT (thread_key) \
+             DO UPDATE SET thread_ts = EXCLUDED.thread_ts, started_at = NOW()",
+        )
+        .bind(key)
+        .bind(ts)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
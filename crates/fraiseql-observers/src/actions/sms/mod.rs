@@ -0,0 +1,431 @@
+//! SMS transport for the `sms` observer action.
+//!
+//! An [`SmsAction`] sends text messages through one [`SmsProvider`] account:
+//!
+//! - **Twilio** — Programmable Messaging, HTTP basic auth with the account SID and auth token.
+//! - **Vonage** — the SMS API, authenticated with the API key and secret.
+//!
+//! The `From` number comes from the action's [`SmsSenderPool`](crate::config::SmsSenderPool),
+//! chosen by the recipient's country.
+//!
+//! # Opt-outs
+//!
+//! Before every send the recipient is looked up in the [`SmsOptOutList`] wired
+//! into the executor, and an opted-out recipient is skipped (the action
+//! succeeds without sending). When the provider itself refuses a recipient who
+//! unsubscribed (Twilio `21610`, Vonage status `7`), the number is recorded in
+//! the list so later events skip it without a round trip.
+//!
+//! # Delivery status
+//!
+//! Acceptance by the provider is not delivery. Providers post the final
+//! outcome to the configured `status_callback_url`; see [`status`] for turning
+//! those callbacks into observer metrics.
+
+pub mod status;
+#[cfg(test)]
+mod tests;
+
+use std::{collections::HashSet, time::Duration};
+
+use parking_lot::RwLock;
+use reqwest::{Client, StatusCode};
+use serde_json::Value;
+use tracing::debug;
+
+use crate::{
+    actions::render_text_template,
+    config::{SmsProvider, sms::normalize_e164},
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Twilio API base URL.
+pub const TWILIO_API_BASE: &str = "https://api.twilio.com";
+
+/// Vonage SMS API base URL.
+pub const VONAGE_API_BASE: &str = "https://rest.nexmo.com";
+
+/// Longest message accepted, in characters (Twilio's limit; longer texts are
+/// rejected rather than split into more than 10 segments).
+const MAX_MESSAGE_CHARS: usize = 1600;
+
+/// Twilio error code for a recipient who replied STOP.
+const TWILIO_UNSUBSCRIBED_RECIPIENT: u64 = 21_610;
+
+/// Timeout of one provider call.
+const SMS_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Numbers that must not receive SMS.
+///
+/// Consulted before every send. Implement it over the application's consent
+/// store; [`InMemoryOptOutList`] covers tests and single-process setups.
+#[async_trait::async_trait]
+pub trait SmsOptOutList: Send + Sync {
+    /// Whether `phone` (E.164) opted out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::ActionExecutionFailed` when the store is
+    /// unreachable; the action is retried rather than sent unchecked.
+    async fn is_opted_out(&self, phone: &str) -> Result<bool>;
+
+    /// Record that the provider refused `phone` because it unsubscribed.
+    ///
+    /// The default keeps no record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the store cannot be written; it is logged, not
+    /// retried.
+    async fn record_opt_out(&self, _phone: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// An in-process [`SmsOptOutList`].
+#[derive(Debug, Default)]
+pub struct InMemoryOptOutList {
+    numbers: RwLock<HashSet<String>>,
+}
+
+impl InMemoryOptOutList {
+    /// Create an empty list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opt `phone` out.
+    pub fn insert(&self, phone: impl Into<String>) {
+        self.numbers.write().insert(phone.into());
+    }
+
+    /// Opt `phone` back in. Returns whether it was opted out.
+    pub fn remove(&self, phone: &str) -> bool {
+        self.numbers.write().remove(phone)
+    }
+
+    /// Whether `phone` is opted out.
+    #[must_use]
+    pub fn contains(&self, phone: &str) -> bool {
+        self.numbers.read().contains(phone)
+    }
+}
+
+#[async_trait::async_trait]
+impl SmsOptOutList for InMemoryOptOutList {
+    async fn is_opted_out(&self, phone: &str) -> Result<bool> {
+        Ok(self.contains(phone))
+    }
+
+    async fn record_opt_out(&self, phone: &str) -> Result<()> {
+        self.insert(phone);
+        Ok(())
+    }
+}
+
+/// Resolve the E.164 number an `sms` action sends to for `event`.
+///
+/// A literal `phone` wins over `template`.
+///
+/// # Errors
+///
+/// Returns [`ObserverError::InvalidActionConfig`] if neither source is set,
+/// [`ObserverError::TemplateRenderingFailed`] if a placeholder has no matching
+/// field, or [`ObserverError::ActionPermanentlyFailed`] if the result is not a
+/// phone number.
+pub fn render_recipient(
+    phone: Option<&str>,
+    template: Option<&str>,
+    event: &EntityEvent,
+) -> Result<String> {
+    let rendered = match (phone, template) {
+        (Some(phone), _) => phone.to_string(),
+        (None, Some(template)) => {
+            let rendered = render_text_template(template, &event.data);
+            if rendered.contains("{{") {
+                return Err(ObserverError::TemplateRenderingFailed {
+                    reason: format!(
+                        "SMS phone_template {template:?} has an unresolved placeholder"
+                    ),
+                });
+            }
+            rendered
+        },
+        (None, None) => {
+            return Err(ObserverError::InvalidActionConfig {
+                reason: "SMS action requires 'phone' or 'phone_template'".to_string(),
+            });
+        },
+    };
+    normalize_e164(&rendered).ok_or_else(|| ObserverError::ActionPermanentlyFailed {
+        reason: format!("SMS recipient {rendered:?} is not an E.164 phone number"),
+    })
+}
+
+/// Render the message text for `event`.
+///
+/// Placeholders without a matching field are left as-is, as in Slack and email
+/// messages.
+///
+/// # Errors
+///
+/// Returns [`ObserverError::InvalidActionConfig`] if `template` is not set,
+/// or [`ObserverError::ActionPermanentlyFailed`] if the text renders empty or
+/// longer than 1600 characters.
+pub fn render_message(template: Option<&str>, event: &EntityEvent) -> Result<String> {
+    let template = template.ok_or_else(|| ObserverError::InvalidActionConfig {
+        reason: "SMS action requires a 'message_template'".to_string(),
+    })?;
+    let text = render_text_template(template, &event.data);
+    let chars = text.chars().count();
+    if text.trim().is_empty() || chars > MAX_MESSAGE_CHARS {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "SMS text must be 1-{MAX_MESSAGE_CHARS} characters, rendered to {chars}"
+            ),
+        });
+    }
+    Ok(text)
+}
+
+/// What the provider did with one message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmsDelivery {
+    /// The provider accepted the message under this id (Twilio `SM…` SID,
+    /// Vonage message id); delivery-status callbacks carry the same id.
+    Accepted {
+        /// Provider message id.
+        message_id: String,
+    },
+    /// The provider refused the recipient because they unsubscribed.
+    Unsubscribed,
+}
+
+/// Sends SMS through one provider account.
+///
+/// Cheap to share behind an `Arc`.
+pub struct SmsAction {
+    http:   Client,
+    target: SmsTarget,
+}
+
+enum SmsTarget {
+    Twilio {
+        messages_url:    String,
+        account_sid:     String,
+        auth_token:      String,
+        status_callback: Option<String>,
+    },
+    Vonage {
+        send_url:        String,
+        api_key:         String,
+        api_secret:      String,
+        status_callback: Option<String>,
+    },
+}
+
+impl SmsAction {
+    /// Build a sender for `provider`, resolving its secret from the configured
+    /// environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if the config is invalid
+    /// or the env var is unset or empty.
+    pub fn from_config(provider: &SmsProvider) -> Result<Self> {
+        provider.validate()?;
+        match provider {
+            SmsProvider::Twilio { auth_token_env, .. } => {
+                Self::build(provider, TWILIO_API_BASE, &resolve_secret(auth_token_env)?)
+            },
+            SmsProvider::Vonage { api_secret_env, .. } => {
+                Self::build(provider, VONAGE_API_BASE, &resolve_secret(api_secret_env)?)
+            },
+        }
+    }
+
+    /// Build a sender against `api_base` with an explicit secret (the Twilio
+    /// auth token or Vonage API secret), skipping env resolution.
+    fn build(provider: &SmsProvider, api_base: &str, secret: &str) -> Result<Self> {
+        let http = Client::builder()
+            .timeout(SMS_REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| ObserverError::InvalidConfig {
+                message: format!("failed to build SMS HTTP client: {e}"),
+            })?;
+        let api_base = api_base.trim_end_matches('/');
+        let status_callback = provider.status_callback_url().map(str::to_string);
+        let target = match provider {
+            SmsProvider::Twilio { account_sid, .. } => SmsTarget::Twilio {
+                messages_url: format!("{api_base}/2010-04-01/Accounts/{account_sid}/Messages.json"),
+                account_sid: account_sid.clone(),
+                auth_token: secret.to_string(),
+                status_callback,
+            },
+            SmsProvider::Vonage { api_key, .. } => SmsTarget::Vonage {
+                send_url: format!("{api_base}/sms/json"),
+                api_key: api_key.clone(),
+                api_secret: secret.to_string(),
+                status_callback,
+            },
+        };
+        Ok(Self { http, target })
+    }
+
+    /// Provider name, for results, logs and metrics.
+    #[must_use]
+    pub const fn provider(&self) -> &'static str {
+        match self.target {
+            SmsTarget::Twilio { .. } => "twilio",
+            SmsTarget::Vonage { .. } => "vonage",
+        }
+    }
+
+    /// Send `text` from `from` to `to` (both E.164, or an alphanumeric sender
+    /// id for `from`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::ActionPermanentlyFailed`] when the provider
+    /// rejects the request (bad credentials, invalid number, 4xx other than
+    /// 429), and [`ObserverError::ActionExecutionFailed`] (retryable) on
+    /// network errors, throttling, or provider outages.
+    pub async fn send(
+        &self,
+        to: &str,
+        from: &str,
+        text: &str,
+        event: &EntityEvent,
+    ) -> Result<SmsDelivery> {
+        let provider = self.provider();
+        debug!(provider, event_id = %event.id, "SMS dispatch starting");
+        match &self.target {
+            SmsTarget::Twilio {
+                messages_url,
+                account_sid,
+                auth_token,
+                status_callback,
+            } => {
+                let mut form = vec![("To", to), ("From", from), ("Body", text)];
+                if let Some(url) = status_callback {
+                    form.push(("StatusCallback", url.as_str()));
+                }
+                let response = self
+                    .http
+                    .post(messages_url)
+                    .basic_auth(account_sid, Some(auth_token))
+                    .form(&form)
+                    .send()
+                    .await
+                    .map_err(|e| transport_error(provider, &e))?;
+                let status = response.status();
+                let raw = response.text().await.unwrap_or_default();
+                let body: Value = serde_json::from_str(&raw).unwrap_or_default();
+                if status.is_success() {
+                    return accepted(provider, body.get("sid"));
+                }
+                if body.get("code").and_then(Value::as_u64) == Some(TWILIO_UNSUBSCRIBED_RECIPIENT) {
+                    return Ok(SmsDelivery::Unsubscribed);
+                }
+                Err(classify(provider, status, &raw))
+            },
+            SmsTarget::Vonage {
+                send_url,
+                api_key,
+                api_secret,
+                status_callback,
+            } => {
+                let client_ref = event.id.to_string();
+                let kind = if text.is_ascii() { "text" } else { "unicode" };
+                let mut form = vec![
+                    ("api_key", api_key.as_str()),
+                    ("api_secret", api_secret.as_str()),
+                    ("to", to.trim_start_matches('+')),
+                    ("from", from.trim_start_matches('+')),
+                    ("text", text),
+                    ("type", kind),
+                    ("client-ref", client_ref.as_str()),
+                ];
+                if let Some(url) = status_callback {
+                    form.extend([("callback", url.as_str()), ("status-report-req", "1")]);
+                }
+                let response = self
+                    .http
+                    .post(send_url)
+                    .form(&form)
+                    .send()
+                    .await
+                    .map_err(|e| transport_error(provider, &e))?;
+                let status = response.status();
+                let raw = response.text().await.unwrap_or_default();
+                if !status.is_success() {
+                    return Err(classify(provider, status, &raw));
+                }
+                let body: Value = serde_json::from_str(&raw).unwrap_or_default();
+                let message = body.pointer("/messages/0").unwrap_or(&Value::Null);
+                let detail = message.get("error-text").and_then(Value::as_str).unwrap_or("");
+                // The SMS API answers 200 and reports the outcome per message.
+                match message.get("status").and_then(Value::as_str) {
+                    Some("0") => accepted(provider, message.get("message-id")),
+                    // Number barred: the recipient unsubscribed.
+                    Some("7") => Ok(SmsDelivery::Unsubscribed),
+                    // Throttled, internal error.
+                    Some(code @ ("1" | "5")) => Err(ObserverError::ActionExecutionFailed {
+                        reason: format!("SMS via {provider} failed: status {code}: {detail}"),
+                    }),
+                    code => Err(ObserverError::ActionPermanentlyFailed {
+                        reason: format!(
+                            "SMS via {provider} rejected: status {}: {detail}",
+                            code.unwrap_or("missing")
+                        ),
+                    }),
+                }
+            },
+        }
+    }
+}
+
+fn accepted(provider: &str, message_id: Option<&Value>) -> Result<SmsDelivery> {
+    let message_id =
+        message_id
+            .and_then(Value::as_str)
+            .ok_or_else(|| ObserverError::ActionExecutionFailed {
+                reason: format!("SMS via {provider} returned no message id"),
+            })?;
+    Ok(SmsDelivery::Accepted {
+        message_id: message_id.to_string(),
+    })
+}
+
+/// Classify a failed response like a webhook response: 4xx other than 429 is
+/// permanent, everything else is retryable.
+fn classify(provider: &str, status: StatusCode, body: &str) -> ObserverError {
+    let detail: String = body.chars().take(256).collect();
+    if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS {
+        ObserverError::ActionPermanentlyFailed {
+            reason: format!("SMS via {provider} rejected: HTTP {status}: {detail}"),
+        }
+    } else {
+        ObserverError::ActionExecutionFailed {
+            reason: format!("SMS via {provider} failed: HTTP {status}: {detail}"),
+        }
+    }
+}
+
+fn transport_error(provider: &str, err: &reqwest::Error) -> ObserverError {
+    ObserverError::ActionExecutionFailed {
+        reason: format!("SMS via {provider} failed: {err}"),
+    }
+}
+
+fn resolve_secret(env_var: &str) -> Result<String> {
+    match std::env::var(env_var) {
+        Ok(secret) if !secret.is_empty() => Ok(secret),
+        _ => Err(ObserverError::InvalidActionConfig {
+            reason: format!("SMS credentials env var {env_var} is not set"),
+        }),
+    }
+}
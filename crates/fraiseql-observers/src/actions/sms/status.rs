@@ -0,0 +1,196 @@
+//! Delivery-status callbacks from SMS providers.
+//!
+//! A provider accepting a message is not the message reaching a phone. Twilio
+//! posts `StatusCallback` requests and Vonage posts delivery receipts to the
+//! provider's `status_callback_url` as the message moves through carriers.
+//! Whatever receives them — typically the server's `POST /webhooks/{provider}`
+//! route, which verifies Twilio signatures with `fraiseql-webhooks` — hands the
+//! verified body to [`SmsStatusReport::parse`] and calls
+//! [`record`](SmsStatusReport::record):
+//!
+//! ```text
+//! fraiseql_observer_sms_delivery_status_total{provider="twilio",status="delivered"}
+//! ```
+//!
+//! Bodies may be form-encoded (Twilio, Vonage `POST`) or JSON (Vonage `POST-JSON`).
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use crate::error::{ObserverError, Result};
+
+/// Where a message stands, normalized across providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SmsDeliveryStatus {
+    /// Accepted by the provider, not yet handed to a carrier.
+    Queued,
+    /// Handed to the carrier.
+    Sent,
+    /// Delivered to the handset.
+    Delivered,
+    /// The carrier could not deliver it.
+    Undelivered,
+    /// The provider could not send it.
+    Failed,
+    /// Refused by the carrier or the provider (spam filter, barred number).
+    Rejected,
+    /// Not delivered before its validity period ran out.
+    Expired,
+    /// A status this version does not know.
+    Unknown,
+}
+
+impl SmsDeliveryStatus {
+    /// Metric label value.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Sent => "sent",
+            Self::Delivered => "delivered",
+            Self::Undelivered => "undelivered",
+            Self::Failed => "failed",
+            Self::Rejected => "rejected",
+            Self::Expired => "expired",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// Whether the message will not be delivered.
+    #[must_use]
+    pub const fn is_failure(self) -> bool {
+        matches!(self, Self::Undelivered | Self::Failed | Self::Rejected | Self::Expired)
+    }
+
+    /// Map a Twilio `MessageStatus`.
+    fn from_twilio(status: &str) -> Self {
+        match status {
+            "accepted" | "scheduled" | "queued" | "sending" => Self::Queued,
+            "sent" => Self::Sent,
+            "delivered" | "read" => Self::Delivered,
+            "undelivered" => Self::Undelivered,
+            "failed" | "canceled" => Self::Failed,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Map a Vonage delivery receipt `status`.
+    fn from_vonage(status: &str) -> Self {
+        match status {
+            "accepted" | "buffered" => Self::Queued,
+            "delivered" => Self::Delivered,
+            "failed" => Self::Failed,
+            "rejected" => Self::Rejected,
+            "expired" => Self::Expired,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// One delivery-status callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmsStatusReport {
+    /// Provider name (`twilio`, `vonage`).
+    pub provider:   &'static str,
+    /// Provider message id, as returned when the message was accepted.
+    pub message_id: String,
+    /// Normalized status.
+    pub status:     SmsDeliveryStatus,
+    /// The provider's error code, when it reported one.
+    pub error_code: Option<String>,
+}
+
+impl SmsStatusReport {
+    /// Parse a callback body from `provider` (`twilio` or `vonage`).
+    ///
+    /// The body must already be authenticated; this only reads it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] for an unknown provider, and
+    /// [`ObserverError::DeserializationError`] if the body lacks the message id
+    /// or status.
+    pub fn parse(provider: &str, body: &[u8]) -> Result<Self> {
+        let fields = fields(body);
+        let field = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| fields.get(*name))
+                .filter(|value| !value.is_empty())
+        };
+        let (provider, id, status, error_code) = match provider {
+            "twilio" => (
+                "twilio",
+                field(&["MessageSid", "SmsSid"]),
+                field(&["MessageStatus", "SmsStatus"]).map(|s| SmsDeliveryStatus::from_twilio(s)),
+                field(&["ErrorCode"]),
+            ),
+            "vonage" => (
+                "vonage",
+                field(&["messageId", "message-id"]),
+                field(&["status"]).map(|s| SmsDeliveryStatus::from_vonage(s)),
+                // `0` means no error.
+                field(&["err-code"]).filter(|code| code.as_str() != "0"),
+            ),
+            other => {
+                return Err(ObserverError::InvalidConfig {
+                    message: format!("unknown SMS provider {other:?}"),
+                });
+            },
+        };
+        let (Some(id), Some(status)) = (id, status) else {
+            return Err(ObserverError::DeserializationError {
+                raw:    body.to_vec(),
+                reason: format!("{provider} SMS status callback lacks a message id or status"),
+            });
+        };
+        Ok(Self {
+            provider,
+            message_id: id.clone(),
+            status,
+            error_code: error_code.cloned(),
+        })
+    }
+
+    /// Count this report in the observer metrics and log it.
+    pub fn record(&self) {
+        if self.status.is_failure() {
+            warn!(
+                provider = self.provider,
+                message_id = %self.message_id,
+                status = self.status.as_str(),
+                error_code = self.error_code.as_deref(),
+                "SMS not delivered"
+            );
+        } else {
+            debug!(
+                provider = self.provider,
+                message_id = %self.message_id,
+                status = self.status.as_str(),
+                "SMS delivery status"
+            );
+        }
+        #[cfg(feature = "metrics")]
+        if let Ok(metrics) = crate::metrics::MetricsRegistry::global() {
+            metrics.sms_delivery_status(self.provider, self.status.as_str());
+        }
+    }
+}
+
+/// The body's top-level fields as strings, from JSON or form encoding.
+fn fields(body: &[u8]) -> BTreeMap<String, String> {
+    if let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(body) {
+        return map
+            .into_iter()
+            .filter_map(|(key, value)| match value {
+                Value::String(s) => Some((key, s)),
+                Value::Number(n) => Some((key, n.to_string())),
+                _ => None,
+            })
+            .collect();
+    }
+    url::form_urlencoded::parse(body).into_owned().collect()
+}
@@ -0,0 +1,229 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use serde_json::json;
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_string_contains, header, method, path},
+};
+
+use super::{
+    status::{SmsDeliveryStatus, SmsStatusReport},
+    *,
+};
+use crate::event::EventKind;
+
+const ACCOUNT_SID: &str = "AC0123456789abcdef0123456789abcdef";
+const MESSAGES_PATH: &str = "/2010-04-01/Accounts/AC0123456789abcdef0123456789abcdef/Messages.json";
+
+fn event(data: Value) -> EntityEvent {
+    EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::nil(), data)
+}
+
+fn twilio(server: &MockServer) -> SmsAction {
+    let provider = SmsProvider::Twilio {
+        account_sid:         ACCOUNT_SID.to_string(),
+        auth_token_env:      "TWILIO_AUTH_TOKEN".to_string(),
+        status_callback_url: Some("https://api.example.com/webhooks/twilio".to_string()),
+    };
+    SmsAction::build(&provider, &server.uri(), "secret-token").unwrap()
+}
+
+fn vonage(server: &MockServer) -> SmsAction {
+    let provider = SmsProvider::Vonage {
+        api_key:             "abcd1234".to_string(),
+        api_secret_env:      "VONAGE_API_SECRET".to_string(),
+        status_callback_url: Some("https://api.example.com/webhooks/vonage".to_string()),
+    };
+    SmsAction::build(&provider, &server.uri(), "vonage-secret").unwrap()
+}
+
+async fn mock_twilio(server: &MockServer, status: u16, body: Value) {
+    Mock::given(method("POST"))
+        .and(path(MESSAGES_PATH))
+        .respond_with(ResponseTemplate::new(status).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+async fn mock_vonage(server: &MockServer, status: &str) {
+    Mock::given(method("POST"))
+        .and(path("/sms/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "message-count": "1",
+            "messages": [{ "status": status, "message-id": "0A0000000123ABCD1", "error-text": "" }],
+        })))
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn recipient_renders_and_normalizes_to_e164() {
+    let data = event(json!({ "phone": "+1 (555) 123-4567", "bad": "555-0100" }));
+
+    assert_eq!(render_recipient(Some("+447700900123"), None, &data).unwrap(), "+447700900123");
+    assert_eq!(render_recipient(None, Some("{{ phone }}"), &data).unwrap(), "+15551234567");
+
+    let unresolved = render_recipient(None, Some("{{ missing }}"), &data);
+    assert!(matches!(unresolved, Err(ObserverError::TemplateRenderingFailed { .. })));
+    let not_a_number = render_recipient(None, Some("{{ bad }}"), &data);
+    assert!(matches!(not_a_number, Err(ObserverError::ActionPermanentlyFailed { .. })));
+    let neither = render_recipient(None, None, &data);
+    assert!(matches!(neither, Err(ObserverError::InvalidActionConfig { .. })));
+}
+
+#[test]
+fn message_renders_within_the_length_limit() {
+    let data = event(json!({ "id": 42, "long": "x".repeat(MAX_MESSAGE_CHARS) }));
+
+    assert_eq!(
+        render_message(Some("Order {{ id }} shipped"), &data).unwrap(),
+        "Order 42 shipped"
+    );
+    let too_long = render_message(Some("!{{ long }}"), &data);
+    assert!(matches!(too_long, Err(ObserverError::ActionPermanentlyFailed { .. })));
+    let empty = render_message(Some("  "), &data);
+    assert!(matches!(empty, Err(ObserverError::ActionPermanentlyFailed { .. })));
+}
+
+#[tokio::test]
+async fn twilio_sends_with_basic_auth_and_status_callback() {
+    let server = MockServer::start().await;
+    let credentials = STANDARD.encode(format!("{ACCOUNT_SID}:secret-token"));
+    Mock::given(method("POST"))
+        .and(path(MESSAGES_PATH))
+        .and(header("authorization", format!("Basic {credentials}").as_str()))
+        .and(body_string_contains("To=%2B15551234567"))
+        .and(body_string_contains("From=%2B15005550006"))
+        .and(body_string_contains("Body=Order+42+shipped"))
+        .and(body_string_contains("StatusCallback=https%3A%2F%2Fapi.example.com"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(json!({ "sid": "SM123", "status": "queued" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let delivery = twilio(&server)
+        .send("+15551234567", "+15005550006", "Order 42 shipped", &event(json!({})))
+        .await
+        .unwrap();
+    assert_eq!(
+        delivery,
+        SmsDelivery::Accepted {
+            message_id: "SM123".to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn twilio_responses_are_classified() {
+    let send = |status: u16, body: Value| async move {
+        let server = MockServer::start().await;
+        mock_twilio(&server, status, body).await;
+        twilio(&server)
+            .send("+15551234567", "+15005550006", "hi", &event(json!({})))
+            .await
+    };
+
+    let unsubscribed = send(400, json!({ "code": 21610, "message": "unsubscribed" })).await;
+    assert_eq!(unsubscribed.unwrap(), SmsDelivery::Unsubscribed);
+    let invalid = send(400, json!({ "code": 21211, "message": "invalid To" })).await;
+    assert!(
+        matches!(invalid, Err(ObserverError::ActionPermanentlyFailed { .. })),
+        "{invalid:?}"
+    );
+    let throttled = send(429, json!({ "code": 20429 })).await.unwrap_err();
+    assert!(throttled.is_transient(), "{throttled:?}");
+    let outage = send(503, json!({})).await.unwrap_err();
+    assert!(outage.is_transient(), "{outage:?}");
+}
+
+#[tokio::test]
+async fn vonage_sends_with_client_ref_and_delivery_receipts() {
+    let server = MockServer::start().await;
+    let event = event(json!({}));
+    Mock::given(method("POST"))
+        .and(path("/sms/json"))
+        .and(body_string_contains("api_key=abcd1234"))
+        .and(body_string_contains("to=15551234567"))
+        .and(body_string_contains("from=Acme"))
+        .and(body_string_contains("type=unicode"))
+        .and(body_string_contains(format!("client-ref={}", event.id).as_str()))
+        .and(body_string_contains("status-report-req=1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "message-count": "1",
+            "messages": [{ "status": "0", "message-id": "0A0000000123ABCD1" }],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let delivery = vonage(&server).send("+15551234567", "Acme", "Café ready", &event).await;
+    assert_eq!(
+        delivery.unwrap(),
+        SmsDelivery::Accepted {
+            message_id: "0A0000000123ABCD1".to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn vonage_message_statuses_are_classified() {
+    let send = |status: &'static str| async move {
+        let server = MockServer::start().await;
+        mock_vonage(&server, status).await;
+        vonage(&server).send("+15551234567", "Acme", "hi", &event(json!({}))).await
+    };
+
+    assert_eq!(send("7").await.unwrap(), SmsDelivery::Unsubscribed);
+    let throttled = send("1").await.unwrap_err();
+    assert!(throttled.is_transient(), "{throttled:?}");
+    let invalid = send("3").await;
+    assert!(
+        matches!(invalid, Err(ObserverError::ActionPermanentlyFailed { .. })),
+        "{invalid:?}"
+    );
+}
+
+#[tokio::test]
+async fn in_memory_opt_out_list_records_unsubscribes() {
+    let list = InMemoryOptOutList::new();
+    assert!(!list.is_opted_out("+15551234567").await.unwrap());
+    list.record_opt_out("+15551234567").await.unwrap();
+    assert!(list.is_opted_out("+15551234567").await.unwrap());
+    assert!(list.remove("+15551234567"));
+    assert!(!list.contains("+15551234567"));
+}
+
+#[test]
+fn status_callbacks_parse_from_form_and_json() {
+    let twilio = SmsStatusReport::parse(
+        "twilio",
+        b"MessageSid=SM123&MessageStatus=undelivered&ErrorCode=30003&To=%2B15551234567",
+    )
+    .unwrap();
+    assert_eq!(twilio.message_id, "SM123");
+    assert_eq!(twilio.status, SmsDeliveryStatus::Undelivered);
+    assert!(twilio.status.is_failure());
+    assert_eq!(twilio.error_code.as_deref(), Some("30003"));
+
+    let vonage_json =
+        br#"{"msisdn":"15551234567","messageId":"0A01","status":"delivered","err-code":"0"}"#;
+    let vonage = SmsStatusReport::parse("vonage", vonage_json).unwrap();
+    assert_eq!(vonage.status, SmsDeliveryStatus::Delivered);
+    assert_eq!(vonage.error_code, None);
+
+    let vonage_form =
+        SmsStatusReport::parse("vonage", b"messageId=0A02&status=expired&err-code=5").unwrap();
+    assert_eq!(vonage_form.status, SmsDeliveryStatus::Expired);
+    assert_eq!(vonage_form.error_code.as_deref(), Some("5"));
+
+    let unknown = SmsStatusReport::parse("twilio", b"MessageSid=SM1&MessageStatus=teleported");
+    assert_eq!(unknown.unwrap().status, SmsDeliveryStatus::Unknown);
+    let missing = SmsStatusReport::parse("twilio", b"MessageStatus=sent");
+    assert!(matches!(missing, Err(ObserverError::DeserializationError { .. })));
+    let provider = SmsStatusReport::parse("carrier-pigeon", b"{}");
+    assert!(matches!(provider, Err(ObserverError::InvalidConfig { .. })));
+}
@@ -0,0 +1,227 @@
+//! Microsoft Teams transport for the `teams` observer action.
+//!
+//! A [`TeamsAction`] posts an Adaptive Card to a Teams incoming webhook — a
+//! Workflows ("Post to a channel when a webhook request is received") URL or a
+//! legacy Office 365 connector URL; both accept the same message envelope.
+//!
+//! The card is built from `title_template` and `message_template`, or rendered
+//! from a `card_template`: Adaptive Card JSON whose placeholders are
+//! substituted JSON-escaped, as in a webhook `body_template`, so an event field
+//! cannot add card elements of its own.
+//!
+//! `429 Too Many Requests` is waited out per `Retry-After`, as for Slack; see
+//! [`chat`](crate::actions::chat).
+
+#[cfg(test)]
+mod tests;
+
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use serde_json::{Value, json};
+
+use crate::{
+    actions::{
+        DEFAULT_WEBHOOK_TIMEOUT_SECS,
+        chat::{RetryAfterGate, http_status_error},
+        render_json_template, render_text_template, validate_outbound_url,
+    },
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// `contentType` of an Adaptive Card attachment.
+const ADAPTIVE_CARD_CONTENT_TYPE: &str = "application/vnd.microsoft.card.adaptive";
+
+/// Adaptive Card schema version used for generated cards and templates that
+/// omit one (the highest Teams renders everywhere).
+const ADAPTIVE_CARD_VERSION: &str = "1.4";
+
+/// Largest message Teams accepts, in bytes.
+const MAX_PAYLOAD_BYTES: usize = 28 * 1024;
+
+/// Microsoft Teams action executor
+pub struct TeamsAction {
+    /// HTTP client for making requests
+    client:      Client,
+    /// `Retry-After` bookkeeping per webhook URL.
+    rate_limits: RetryAfterGate,
+}
+
+impl TeamsAction {
+    /// Create a new Teams action executor
+    #[must_use]
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_WEBHOOK_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::error!(error = %e, "Failed to build HTTP client for TeamsAction; falling back to no-timeout client");
+                Client::default()
+            });
+        Self {
+            client,
+            rate_limits: RetryAfterGate::new("Teams"),
+        }
+    }
+
+    /// Set the longest `Retry-After` waited out in-process (default 30 s).
+    #[must_use]
+    pub const fn with_max_rate_limit_wait(mut self, wait: Duration) -> Self {
+        self.rate_limits.set_max_wait(wait);
+        self
+    }
+
+    /// Post the event's card to `webhook_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::TemplateRenderingFailed` if `card_template`
+    /// does not render to an Adaptive Card, `ObserverError::ActionPermanentlyFailed`
+    /// if the URL is rejected, the message is too large or Teams refuses it
+    /// (4xx), and `ObserverError::ActionExecutionFailed` for outages and
+    /// rate limits.
+    pub async fn execute(
+        &self,
+        webhook_url: &str,
+        title_template: Option<&str>,
+        message_template: Option<&str>,
+        card_template: Option<&str>,
+        event: &EntityEvent,
+    ) -> Result<TeamsResponse> {
+        let start = Instant::now();
+        let payload = render_message(title_template, message_template, card_template, event)?;
+
+        // SECURITY: Reject URLs that target private/loopback addresses (SSRF protection).
+        validate_outbound_url(webhook_url)?;
+        // SECURITY: DNS rebinding prevention — resolve and reject private IPs.
+        crate::ssrf::dns_resolve_and_check(webhook_url).await?;
+
+        let response = self
+            .rate_limits
+            .send(webhook_url, || self.client.post(webhook_url).json(&payload))
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(http_status_error("Teams", status));
+        }
+        Ok(TeamsResponse {
+            status_code: status.as_u16(),
+            success:     true,
+            duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+        })
+    }
+}
+
+impl Default for TeamsAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Response from Teams execution
+#[derive(Debug, Clone)]
+pub struct TeamsResponse {
+    /// HTTP status code
+    pub status_code: u16,
+    /// Whether execution was successful
+    pub success:     bool,
+    /// Duration in milliseconds
+    pub duration_ms: f64,
+}
+
+/// Build the webhook message for `event`: one Adaptive Card attachment.
+///
+/// # Errors
+///
+/// Returns the errors of [`render_card`], and
+/// `ObserverError::ActionPermanentlyFailed` if the message is too large.
+pub(crate) fn render_message(
+    title_template: Option<&str>,
+    message_template: Option<&str>,
+    card_template: Option<&str>,
+    event: &EntityEvent,
+) -> Result<Value> {
+    let card = match card_template {
+        Some(template) => render_card(template, &event.data)?,
+        None => default_card(title_template, message_template, event),
+    };
+    let payload = json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": ADAPTIVE_CARD_CONTENT_TYPE,
+            "contentUrl": null,
+            "content": card,
+        }],
+    });
+    let size = payload.to_string().len();
+    if size > MAX_PAYLOAD_BYTES {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "Teams message is {size} bytes; Teams accepts at most {MAX_PAYLOAD_BYTES}"
+            ),
+        });
+    }
+    Ok(payload)
+}
+
+/// Render a `card_template` against `data` into an Adaptive Card.
+///
+/// A template without a `version` gets the default one.
+///
+/// # Errors
+///
+/// Returns `ObserverError::TemplateRenderingFailed` if the result is not a
+/// JSON object of `"type": "AdaptiveCard"`.
+pub fn render_card(template: &str, data: &Value) -> Result<Value> {
+    let rendered = render_json_template(template, data);
+    let mut card: Value =
+        serde_json::from_str(&rendered).map_err(|e| ObserverError::TemplateRenderingFailed {
+            reason: format!("Teams card_template did not render to JSON: {e}"),
+        })?;
+    let Value::Object(map) = &mut card else {
+        return Err(ObserverError::TemplateRenderingFailed {
+            reason: "Teams card_template must render to an Adaptive Card object".to_string(),
+        });
+    };
+    if map.get("type").and_then(Value::as_str) != Some("AdaptiveCard") {
+        return Err(ObserverError::TemplateRenderingFailed {
+            reason: "Teams card_template must have \"type\": \"AdaptiveCard\"".to_string(),
+        });
+    }
+    map.entry("version")
+        .or_insert_with(|| Value::String(ADAPTIVE_CARD_VERSION.to_string()));
+    Ok(card)
+}
+
+/// A card with the rendered title (bold) above the rendered message.
+fn default_card(
+    title_template: Option<&str>,
+    message_template: Option<&str>,
+    event: &EntityEvent,
+) -> Value {
+    let title = title_template.map_or_else(
+        || format!("{} {}", event.entity_type, event.event_type.as_str()),
+        |template| render_text_template(template, &event.data),
+    );
+    let message = message_template.map_or_else(
+        || {
+            format!(
+                "Event: {} on {} (ID: {})",
+                event.event_type.as_str(),
+                event.entity_type,
+                event.entity_id
+            )
+        },
+        |template| render_text_template(template, &event.data),
+    );
+    json!({
+        "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+        "type": "AdaptiveCard",
+        "version": ADAPTIVE_CARD_VERSION,
+        "body": [
+            { "type": "TextBlock", "text": title, "weight": "Bolder", "size": "Medium", "wrap": true },
+            { "type": "TextBlock", "text": message, "wrap": true },
+        ],
+    })
+}
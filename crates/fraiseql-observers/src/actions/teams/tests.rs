@@ -0,0 +1,128 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::json;
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method},
+};
+
+use super::*;
+use crate::{event::EventKind, insecure_guard::ALLOW_INSECURE_ENV};
+
+fn event(data: Value) -> EntityEvent {
+    EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::nil(), data)
+}
+
+#[test]
+fn cards_render_with_escaped_placeholders_and_a_default_version() {
+    let data = json!({ "note": "\"},{\"type\":\"Image\",\"url\":\"https://evil.example" });
+    let template =
+        r#"{"type": "AdaptiveCard", "body": [{"type": "TextBlock", "text": "{{ note }}"}]}"#;
+
+    let card = render_card(template, &data).unwrap();
+    // The field value stays inside its string; it cannot add an element.
+    assert_eq!(card["body"].as_array().unwrap().len(), 1);
+    assert_eq!(card["body"][0]["text"], data["note"]);
+    assert_eq!(card["version"], ADAPTIVE_CARD_VERSION);
+
+    let pinned = render_card(r#"{"type": "AdaptiveCard", "version": "1.2"}"#, &data).unwrap();
+    assert_eq!(pinned["version"], "1.2");
+
+    let not_a_card = render_card(r#"{"type": "MessageCard"}"#, &data);
+    assert!(matches!(not_a_card, Err(ObserverError::TemplateRenderingFailed { .. })));
+    let not_json = render_card("Order {{ id }}", &data);
+    assert!(matches!(not_json, Err(ObserverError::TemplateRenderingFailed { .. })));
+}
+
+#[tokio::test]
+async fn execute_posts_the_card_as_an_adaptive_card_attachment() {
+    // wiremock binds to 127.0.0.1, which the SSRF guard blocks; allow the
+    // insecure bypass for this loopback test only.
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "type": "message",
+                "attachments": [{
+                    "contentType": ADAPTIVE_CARD_CONTENT_TYPE,
+                    "content": {
+                        "type": "AdaptiveCard",
+                        "body": [
+                            { "type": "TextBlock", "text": "Order 42", "weight": "Bolder" },
+                            { "type": "TextBlock", "text": "Order 42 shipped" },
+                        ],
+                    },
+                }],
+            })))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = TeamsAction::new()
+            .execute(
+                &server.uri(),
+                Some("Order {{ id }}"),
+                Some("Order {{ id }} shipped"),
+                None,
+                &event(json!({ "id": 42 })),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 202);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn rejected_and_oversized_messages_fail_permanently() {
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+        let action = TeamsAction::new();
+
+        let rejected = action.execute(&server.uri(), None, None, None, &event(json!({}))).await;
+        assert!(
+            matches!(rejected, Err(ObserverError::ActionPermanentlyFailed { .. })),
+            "{rejected:?}"
+        );
+
+        let huge = event(json!({ "note": "x".repeat(MAX_PAYLOAD_BYTES) }));
+        let oversized = action.execute(&server.uri(), None, Some("{{ note }}"), None, &huge).await;
+        assert!(
+            matches!(oversized, Err(ObserverError::ActionPermanentlyFailed { .. })),
+            "{oversized:?}"
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn rate_limits_are_waited_out_and_retried() {
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let start = Instant::now();
+        TeamsAction::new()
+            .execute(&server.uri(), None, None, None, &event(json!({})))
+            .await
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_secs(1), "Retry-After was not honored");
+    })
+    .await;
+}
@@ -13,10 +13,11 @@ use fraiseql_webhooks::{SignatureVerifier, signature::stripe::StripeVerifier};
 use uuid::Uuid;
 use wiremock::{Mock, MockServer, ResponseTemplate, matchers::method};
 
-use super::{WEBHOOK_SIGNATURE_HEADER, WebhookAction, webhook_signature};
+use super::{WEBHOOK_KEY_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WebhookAction, webhook_signature};
 use crate::{
     event::{EntityEvent, EventKind},
     insecure_guard::ALLOW_INSECURE_ENV,
+    signing::{DEFAULT_SIGNATURE_TOLERANCE, WebhookSigningKey, verify_webhook_signature},
 };
 
 fn test_event() -> EntityEvent {
@@ -88,8 +89,12 @@ async fn execute_signs_the_exact_transmitted_bytes() {
             .await;
 
         let secret = "whsec_gate";
+        let key = WebhookSigningKey {
+            secret,
+            key_id: None,
+        };
         WebhookAction::new()
-            .execute(&server.uri(), None, &HashMap::new(), None, Some(secret), &test_event())
+            .execute(&server.uri(), None, &HashMap::new(), None, Some(key), &test_event())
             .await
             .expect("webhook dispatch should succeed");
 
@@ -115,6 +120,34 @@ async fn execute_signs_the_exact_transmitted_bytes() {
     .await;
 }
 
+#[tokio::test]
+async fn execute_sends_key_id_and_verifies_with_exported_helper() {
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let key = WebhookSigningKey {
+            secret: "whsec_rotated",
+            key_id: Some("2026-10"),
+        };
+        WebhookAction::new()
+            .execute(&server.uri(), None, &HashMap::new(), None, Some(key), &test_event())
+            .await
+            .expect("webhook dispatch should succeed");
+
+        let requests = server.received_requests().await.expect("recorded requests");
+        let req = &requests[0];
+        assert_eq!(req.headers.get(WEBHOOK_KEY_ID_HEADER).unwrap(), "2026-10");
+        let sig = req.headers.get(WEBHOOK_SIGNATURE_HEADER).unwrap().to_str().unwrap();
+        verify_webhook_signature("whsec_rotated", sig, &req.body, DEFAULT_SIGNATURE_TOLERANCE)
+            .expect("the exported verifier accepts the transmitted bytes");
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn execute_without_secret_sends_no_signature_header() {
     temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
@@ -135,6 +168,7 @@ async fn execute_without_secret_sends_no_signature_header() {
             requests[0].headers.get(WEBHOOK_SIGNATURE_HEADER).is_none(),
             "no signature header when signing is not configured"
         );
+        assert!(requests[0].headers.get(WEBHOOK_KEY_ID_HEADER).is_none());
     })
     .await;
 }
@@ -1,16 +1,33 @@
 //! NATS → Arrow Flight bridge for observer events.
 //!
 //! This module provides conversion from `EntityEvent` to Arrow `RecordBatch`es
-//! for high-performance event streaming and analytics.
+//! for high-performance event streaming and analytics, and evicts the Flight
+//! server's cached Arrow results for entities that change.
 
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use arrow::{
     array::{Array, RecordBatch, StringBuilder, TimestampMicrosecondBuilder},
     error::ArrowError,
 };
+use async_trait::async_trait;
+use fraiseql_arrow::{
+    QueryCache,
+    delivery_storage::{ArrowDeliveryStorage, DeliveryQuery, DlqRecord, JobAttemptRecord},
+};
+
+#[cfg(feature = "queue")]
+use crate::job_queue::dlq::DeadLetterQueueManager;
+use crate::{
+    event::EntityEvent,
+    traits::{DeadLetterQueue, DlqItem},
+};
 
-use crate::event::EntityEvent;
+/// Most DLQ items read per `DlqItems` export.
+///
+/// The [`DeadLetterQueue`] trait cannot filter by action type or date, so items are
+/// filtered after reading; this bounds the read.
+pub const MAX_DLQ_EXPORT_SCAN: i64 = 100_000;
 
 /// Convert a batch of `EntityEvent`s to Arrow `RecordBatch`.
 ///
@@ -128,6 +145,199 @@ impl EventToArrowConverter {
     }
 }
 
+/// Evicts cached Flight query results for entity types that changed.
+///
+/// Share the Flight service's cache (`FraiseQLFlightService::cache`) and feed every
+/// batch of events the bridge forwards through [`invalidate`](Self::invalidate);
+/// cached results tagged with a changed entity type, or with a view registered for it
+/// via [`QueryCache::register_entity_views`], are dropped so the next `DoGet` reads
+/// fresh rows.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use fraiseql_arrow::QueryCache;
+/// use fraiseql_observers::arrow_bridge::CacheInvalidator;
+///
+/// let cache = Arc::new(QueryCache::new(60));
+/// cache.register_entity_views("Order", ["va_orders"]);
+///
+/// let invalidator = CacheInvalidator::new(cache);
+/// let removed = invalidator.invalidate(&[]);
+/// assert_eq!(removed, 0);
+/// ```
+pub struct CacheInvalidator {
+    cache: Arc<QueryCache>,
+}
+
+impl CacheInvalidator {
+    /// Create an invalidator for the given cache.
+    #[must_use]
+    pub const fn new(cache: Arc<QueryCache>) -> Self {
+        Self { cache }
+    }
+
+    /// Evict cached results for every entity type in `events`.
+    ///
+    /// Returns the number of cache entries removed.
+    #[must_use]
+    pub fn invalidate(&self, events: &[EntityEvent]) -> usize {
+        let entity_types: HashSet<&str> =
+            events.iter().map(|event| event.entity_type.as_str()).collect();
+        entity_types
+            .into_iter()
+            .map(|entity_type| self.cache.invalidate_entity(entity_type))
+            .sum()
+    }
+}
+
+/// Delivery history of the observer runtime for the Arrow Flight `DlqItems` and
+/// `JobHistory` tickets.
+///
+/// DLQ items are read from the runtime's [`DeadLetterQueue`]; they carry no
+/// enqueue timestamp, so the failed event's timestamp is exported as `created_at`.
+/// Job attempts are read from the job queue's [`DeadLetterQueueManager`] (requires
+/// the `queue` feature): the queue drops acknowledged jobs, so only dead-lettered
+/// jobs keep their attempt history.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use fraiseql_arrow::FraiseQLFlightService;
+/// use fraiseql_observers::arrow_bridge::DeliveryHistoryStorage;
+/// use fraiseql_observers::traits::DeadLetterQueue;
+///
+/// # fn example(dlq: Arc<dyn DeadLetterQueue>) {
+/// let mut service = FraiseQLFlightService::new();
+/// service.set_delivery_storage(Arc::new(DeliveryHistoryStorage::new(dlq)));
+/// # }
+/// ```
+pub struct DeliveryHistoryStorage {
+    dlq:     Arc<dyn DeadLetterQueue>,
+    #[cfg(feature = "queue")]
+    job_dlq: Option<Arc<DeadLetterQueueManager>>,
+}
+
+impl DeliveryHistoryStorage {
+    /// Create a storage over the runtime's dead letter queue.
+    #[must_use]
+    pub fn new(dlq: Arc<dyn DeadLetterQueue>) -> Self {
+        Self {
+            dlq,
+            #[cfg(feature = "queue")]
+            job_dlq: None,
+        }
+    }
+
+    /// Serve `JobHistory` tickets from the job queue's dead letter queue.
+    #[cfg(feature = "queue")]
+    #[must_use]
+    pub fn with_job_dlq(mut self, job_dlq: Arc<DeadLetterQueueManager>) -> Self {
+        self.job_dlq = Some(job_dlq);
+        self
+    }
+}
+
+/// The `OBnnn` code an [`ObserverError`](crate::error::ObserverError) message starts
+/// with, if any.
+fn error_code(message: &str) -> Option<String> {
+    let code = message.get(..5)?;
+    let well_formed = code.starts_with("OB")
+        && code[2..].bytes().all(|b| b.is_ascii_digit())
+        && message[5..].starts_with(':');
+    well_formed.then(|| code.to_string())
+}
+
+fn dlq_record(item: DlqItem) -> DlqRecord {
+    DlqRecord {
+        id:            item.id,
+        action_type:   item.action.action_type().to_string(),
+        event_id:      item.event.id,
+        event_type:    item.event.event_type.as_str().to_string(),
+        entity_type:   item.event.entity_type,
+        error_code:    error_code(&item.error_message),
+        error_message: item.error_message,
+        attempts:      item.attempts,
+        tenant_id:     item.event.tenant_id,
+        created_at:    item.event.timestamp,
+    }
+}
+
+/// Apply `limit` to records already sorted most recent first.
+fn truncate_to<T>(records: &mut Vec<T>, limit: Option<usize>) {
+    if let Some(limit) = limit {
+        records.truncate(limit);
+    }
+}
+
+#[async_trait]
+impl ArrowDeliveryStorage for DeliveryHistoryStorage {
+    async fn query_dlq_items(&self, query: &DeliveryQuery) -> Result<Vec<DlqRecord>, String> {
+        let items = self
+            .dlq
+            .get_pending(MAX_DLQ_EXPORT_SCAN)
+            .await
+            .map_err(|e| format!("failed to read the dead letter queue: {e}"))?;
+        let mut records: Vec<DlqRecord> = items
+            .into_iter()
+            .map(dlq_record)
+            .filter(|record| query.matches(&record.action_type, record.created_at))
+            .collect();
+        records.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        truncate_to(&mut records, query.limit);
+        Ok(records)
+    }
+
+    #[cfg(feature = "queue")]
+    async fn query_job_attempts(
+        &self,
+        query: &DeliveryQuery,
+        state: Option<&str>,
+    ) -> Result<Vec<JobAttemptRecord>, String> {
+        let job_dlq = self.job_dlq.as_ref().ok_or_else(|| {
+            "job history is not configured: no job queue dead letter queue attached".to_string()
+        })?;
+        let jobs = job_dlq
+            .list_all()
+            .await
+            .map_err(|e| format!("failed to read the job queue dead letter queue: {e}"))?;
+        let mut records: Vec<JobAttemptRecord> = jobs
+            .iter()
+            .filter(|job| state.is_none_or(|state| job.state.as_str() == state))
+            .flat_map(|job| {
+                job.attempts.iter().map(move |attempt| JobAttemptRecord {
+                    job_id:         job.id,
+                    event_id:       job.event_id,
+                    action_type:    job.action.action_type().to_string(),
+                    state:          job.state.as_str().to_string(),
+                    attempt:        attempt.attempt,
+                    max_attempts:   job.max_attempts,
+                    success:        attempt.success,
+                    error_code:     attempt.error.as_deref().and_then(error_code),
+                    error_message:  attempt.error.clone(),
+                    job_created_at: job.created_at,
+                    started_at:     attempt.started_at,
+                })
+            })
+            .filter(|record| query.matches(&record.action_type, record.started_at))
+            .collect();
+        records.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+        truncate_to(&mut records, query.limit);
+        Ok(records)
+    }
+
+    #[cfg(not(feature = "queue"))]
+    async fn query_job_attempts(
+        &self,
+        _query: &DeliveryQuery,
+        _state: Option<&str>,
+    ) -> Result<Vec<JobAttemptRecord>, String> {
+        Err("job history requires the `queue` feature".to_string())
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)] // Reason: test code
 mod tests;
@@ -114,3 +114,71 @@ fn test_complex_json_data() {
     assert_eq!(batch.num_rows(), 1);
     // Complex JSON should be serialized to string
 }
+
+#[test]
+fn test_cache_invalidator_evicts_changed_entities() {
+    let cache = Arc::new(QueryCache::new(60));
+    cache.register_entity_views("Order", ["va_orders"]);
+    cache.put_tagged("SELECT * FROM va_orders", Arc::new(vec![]), &["va_orders"]);
+    cache.put_tagged("SELECT * FROM va_users", Arc::new(vec![]), &["va_users"]);
+
+    let order_changed =
+        EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::new_v4(), json!({}));
+    let invalidator = CacheInvalidator::new(Arc::clone(&cache));
+
+    assert_eq!(invalidator.invalidate(&[order_changed.clone(), order_changed]), 1);
+    assert!(cache.get("SELECT * FROM va_orders").is_none());
+    assert!(cache.get("SELECT * FROM va_users").is_some());
+}
+
+#[test]
+fn test_error_code_is_parsed_from_observer_errors() {
+    assert_eq!(error_code("OB006: Action execution failed").as_deref(), Some("OB006"));
+    assert_eq!(error_code("connection refused"), None);
+    assert_eq!(error_code("OBSERVER: down"), None);
+    assert_eq!(error_code("OB00"), None);
+}
+
+#[tokio::test]
+async fn test_delivery_history_exports_filtered_dlq_items() {
+    let dlq = Arc::new(crate::testing::mocks::MockDeadLetterQueue::new());
+    let webhook: crate::config::ActionConfig =
+        serde_json::from_value(json!({"type": "webhook", "url": "https://example.com/hook"}))
+            .unwrap();
+    let cache: crate::config::ActionConfig = serde_json::from_value(
+        json!({"type": "cache", "key_pattern": "order:*", "action": "invalidate"}),
+    )
+    .unwrap();
+    let event = |hours_ago: i64| {
+        let mut event =
+            EntityEvent::new(EventKind::Created, "Order".to_string(), Uuid::new_v4(), json!({}));
+        event.timestamp -= chrono::Duration::hours(hours_ago);
+        event
+    };
+    dlq.push(event(3), webhook.clone(), "OB006: Action execution failed: 503".to_string())
+        .await
+        .unwrap();
+    dlq.push(event(1), webhook, "timeout".to_string()).await.unwrap();
+    dlq.push(event(2), cache, "OB006: Action execution failed".to_string())
+        .await
+        .unwrap();
+
+    let storage = DeliveryHistoryStorage::new(dlq);
+    let query = DeliveryQuery {
+        action_type: Some("webhook".to_string()),
+        ..DeliveryQuery::default()
+    };
+    let records = storage.query_dlq_items(&query).await.unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert!(records[0].created_at > records[1].created_at, "most recent first");
+    assert_eq!(records[0].error_code, None);
+    assert_eq!(records[1].error_code.as_deref(), Some("OB006"));
+    assert_eq!(records[1].event_type, "INSERT");
+
+    let limited = DeliveryQuery {
+        limit: Some(1),
+        ..DeliveryQuery::default()
+    };
+    assert_eq!(storage.query_dlq_items(&limited).await.unwrap().len(), 1);
+}
@@ -0,0 +1,409 @@
+//! CDN purge transport for the `cache` observer action.
+//!
+//! A `cache` action with a [`CdnPurgeConfig`] purges edge caches by surrogate
+//! key (Fastly), cache tag (Cloudflare), or `BAN` (Varnish) instead of deleting
+//! Redis keys. The action's `key_pattern` is rendered per event (see
+//! [`render_purge_keys`]) and handed to a [`CdnPurger`].
+//!
+//! # Coalescing
+//!
+//! A bulk update fires one action per row, and CDN purge APIs are rate limited.
+//! Each [`CdnPurger`] therefore funnels purges through one background task:
+//! the first purge opens a window of `coalesce_ms`, every purge arriving in the
+//! window joins it, and the de-duplicated keys go out in as few provider calls
+//! as the per-request key limit allows. Each caller waits for the call carrying
+//! its keys and receives that call's outcome, so a failed purge is retried by
+//! the action's own retry policy rather than reported as a success.
+
+#[cfg(test)]
+mod tests;
+
+use std::{collections::BTreeSet, sync::OnceLock, time::Duration};
+
+use reqwest::{Client, Method, Response};
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::Instant,
+};
+use tracing::debug;
+
+use crate::{
+    actions::render_text_template,
+    config::{CdnProvider, CdnPurgeConfig},
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Fastly API base URL.
+pub const FASTLY_API_BASE: &str = "https://api.fastly.com";
+
+/// Cloudflare API base URL.
+pub const CLOUDFLARE_API_BASE: &str = "https://api.cloudflare.com/client/v4";
+
+/// Surrogate keys accepted by one Fastly bulk purge.
+const FASTLY_MAX_KEYS_PER_REQUEST: usize = 256;
+
+/// Cache tags accepted by one Cloudflare purge.
+const CLOUDFLARE_MAX_TAGS_PER_REQUEST: usize = 30;
+
+/// Keys per Varnish `BAN`, bounding the header size.
+const VARNISH_MAX_KEYS_PER_REQUEST: usize = 100;
+
+/// Longest purge key accepted (Fastly's surrogate-key limit).
+const MAX_PURGE_KEY_LEN: usize = 1024;
+
+/// Keys collected before a window is flushed early.
+const MAX_COALESCED_KEYS: usize = 10_000;
+
+/// Purges waiting for the coalescing task.
+const PURGE_QUEUE_CAPACITY: usize = 1024;
+
+/// Timeout of one provider call.
+const PURGE_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Render `key_pattern` into the purge keys for `event`.
+///
+/// `{{ event.entity_type }}`, `{{ event.entity_id }}`, `{{ event.event_type }}`
+/// and `{{ event.tenant_id }}` are substituted first, then `{{ field }}`
+/// placeholders from the event's data. The result is split on whitespace into
+/// individual keys and de-duplicated, so `"order-{{ id }} orders"` purges two
+/// keys. A data value containing whitespace therefore yields several keys.
+///
+/// # Errors
+///
+/// Returns [`ObserverError::TemplateRenderingFailed`] if a placeholder has no
+/// matching field, or [`ObserverError::ActionPermanentlyFailed`] if a key is
+/// longer than 1024 bytes or contains a character that cannot travel in an
+/// HTTP header.
+pub fn render_purge_keys(key_pattern: &str, event: &EntityEvent) -> Result<Vec<String>> {
+    let rendered = key_pattern
+        .replace("{{ event.entity_type }}", &event.entity_type)
+        .replace("{{ event.entity_id }}", &event.entity_id.to_string())
+        .replace("{{ event.event_type }}", event.event_type.as_str())
+        .replace("{{ event.tenant_id }}", event.tenant_id.as_deref().unwrap_or_default());
+    let rendered = render_text_template(&rendered, &event.data);
+    if rendered.contains("{{") {
+        return Err(ObserverError::TemplateRenderingFailed {
+            reason: format!("CDN purge key pattern {key_pattern:?} has an unresolved placeholder"),
+        });
+    }
+
+    let mut keys: Vec<String> = Vec::new();
+    for key in rendered.split_whitespace() {
+        if key.len() > MAX_PURGE_KEY_LEN || !key.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(ObserverError::ActionPermanentlyFailed {
+                reason: format!(
+                    "CDN purge key {key:?} must be at most {MAX_PURGE_KEY_LEN} visible ASCII \
+                     characters"
+                ),
+            });
+        }
+        if !keys.iter().any(|k| k == key) {
+            keys.push(key.to_string());
+        }
+    }
+    Ok(keys)
+}
+
+/// Purges one CDN target, coalescing concurrent purges into few API calls.
+///
+/// Cheap to share behind an `Arc`; the coalescing task is spawned on the first
+/// [`purge`](Self::purge) and stops when the purger is dropped.
+pub struct CdnPurger {
+    client:   PurgeClient,
+    coalesce: Duration,
+    queue:    OnceLock<mpsc::Sender<PendingPurge>>,
+}
+
+impl CdnPurger {
+    /// Build a purger for `config`, resolving the API token from its env var.
+    ///
+    /// A Varnish URL is checked against the same SSRF guard as webhook URLs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if the config is invalid
+    /// or the token env var is unset or empty, and
+    /// [`ObserverError::ActionPermanentlyFailed`] if the Varnish URL is blocked
+    /// by the SSRF guard.
+    pub fn from_config(config: &CdnPurgeConfig) -> Result<Self> {
+        config.validate()?;
+        let (api_base, token) = match &config.provider {
+            CdnProvider::Fastly { api_token_env, .. } => {
+                (FASTLY_API_BASE, resolve_token(api_token_env)?)
+            },
+            CdnProvider::Cloudflare { api_token_env, .. } => {
+                (CLOUDFLARE_API_BASE, resolve_token(api_token_env)?)
+            },
+            CdnProvider::Varnish { url, .. } => {
+                crate::actions::validate_outbound_url(url)?;
+                ("", String::new())
+            },
+        };
+        Self::build(config, api_base, token)
+    }
+
+    /// Build a purger against `api_base` with an explicit token, skipping env
+    /// resolution and the SSRF guard.
+    fn build(config: &CdnPurgeConfig, api_base: &str, token: String) -> Result<Self> {
+        let http = Client::builder()
+            .timeout(PURGE_REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| ObserverError::InvalidConfig {
+                message: format!("failed to build CDN purge HTTP client: {e}"),
+            })?;
+        let api_base = api_base.trim_end_matches('/');
+        let target = match &config.provider {
+            CdnProvider::Fastly {
+                service_id,
+                soft_purge,
+                ..
+            } => PurgeTarget::Fastly {
+                url:  format!("{api_base}/service/{service_id}/purge"),
+                soft: *soft_purge,
+            },
+            CdnProvider::Cloudflare { zone_id, .. } => PurgeTarget::Cloudflare {
+                url: format!("{api_base}/zones/{zone_id}/purge_cache"),
+            },
+            CdnProvider::Varnish { url, header } => PurgeTarget::Varnish {
+                url:    url.clone(),
+                header: header.clone(),
+            },
+        };
+        Ok(Self {
+            client:   PurgeClient {
+                http,
+                token,
+                target,
+            },
+            coalesce: Duration::from_millis(config.coalesce_ms),
+            queue:    OnceLock::new(),
+        })
+    }
+
+    /// Provider name, for results and logs.
+    #[must_use]
+    pub const fn provider(&self) -> &'static str {
+        self.client.target.provider()
+    }
+
+    /// Purge `keys`, together with every other purge of the current window.
+    ///
+    /// Resolves once the provider call(s) carrying these keys complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::ActionPermanentlyFailed`] when the provider
+    /// rejects the purge (4xx other than 429, or a Cloudflare `success: false`),
+    /// and [`ObserverError::ActionExecutionFailed`] (retryable) on network
+    /// errors, 429, or 5xx.
+    pub async fn purge(&self, keys: Vec<String>) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        let queue = self.queue.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel(PURGE_QUEUE_CAPACITY);
+            tokio::spawn(coalesce(self.client.clone(), self.coalesce, receiver));
+            sender
+        });
+        let (done, outcome) = oneshot::channel();
+        queue.send(PendingPurge { keys, done }).await.map_err(|_| queue_closed())?;
+        outcome.await.map_err(|_| queue_closed())?
+    }
+}
+
+/// One caller's keys and where to report the outcome of their purge.
+struct PendingPurge {
+    keys: Vec<String>,
+    done: oneshot::Sender<Result<()>>,
+}
+
+/// Collect purges for `window` after the first one arrives, then send the
+/// de-duplicated keys and report the outcome to every caller in the window.
+async fn coalesce(client: PurgeClient, window: Duration, mut queue: mpsc::Receiver<PendingPurge>) {
+    while let Some(first) = queue.recv().await {
+        let deadline = Instant::now() + window;
+        let mut key_count = first.keys.len();
+        let mut batch = vec![first];
+        while key_count < MAX_COALESCED_KEYS {
+            match tokio::time::timeout_at(deadline, queue.recv()).await {
+                Ok(Some(next)) => {
+                    key_count += next.keys.len();
+                    batch.push(next);
+                },
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        let keys: BTreeSet<&str> =
+            batch.iter().flat_map(|p| p.keys.iter().map(String::as_str)).collect();
+        let keys: Vec<&str> = keys.into_iter().collect();
+        let outcome = client.purge(&keys).await;
+        debug!(
+            provider = client.target.provider(),
+            purges = batch.len(),
+            keys = keys.len(),
+            ok = outcome.is_ok(),
+            "coalesced CDN purge"
+        );
+        for pending in batch {
+            // The caller may have timed out and gone away; nothing to report to.
+            let _ = pending.done.send(outcome.clone());
+        }
+    }
+}
+
+#[derive(Clone)]
+struct PurgeClient {
+    http:   Client,
+    token:  String,
+    target: PurgeTarget,
+}
+
+#[derive(Clone)]
+enum PurgeTarget {
+    Fastly { url: String, soft: bool },
+    Cloudflare { url: String },
+    Varnish { url: String, header: String },
+}
+
+impl PurgeTarget {
+    const fn provider(&self) -> &'static str {
+        match self {
+            Self::Fastly { .. } => "fastly",
+            Self::Cloudflare { .. } => "cloudflare",
+            Self::Varnish { .. } => "varnish",
+        }
+    }
+
+    const fn max_keys_per_request(&self) -> usize {
+        match self {
+            Self::Fastly { .. } => FASTLY_MAX_KEYS_PER_REQUEST,
+            Self::Cloudflare { .. } => CLOUDFLARE_MAX_TAGS_PER_REQUEST,
+            Self::Varnish { .. } => VARNISH_MAX_KEYS_PER_REQUEST,
+        }
+    }
+}
+
+impl PurgeClient {
+    /// Send `keys` in as many calls as the provider's per-request limit needs,
+    /// stopping at the first failure.
+    async fn purge(&self, keys: &[&str]) -> Result<()> {
+        for chunk in keys.chunks(self.target.max_keys_per_request()) {
+            self.purge_chunk(chunk).await?;
+        }
+        Ok(())
+    }
+
+    async fn purge_chunk(&self, keys: &[&str]) -> Result<()> {
+        let provider = self.target.provider();
+        let request = match &self.target {
+            PurgeTarget::Fastly { url, soft } => {
+                let request = self
+                    .http
+                    .post(url)
+                    .header("Fastly-Key", &self.token)
+                    .header("Surrogate-Key", keys.join(" "))
+                    .header("Accept", "application/json");
+                if *soft {
+                    request.header("Fastly-Soft-Purge", "1")
+                } else {
+                    request
+                }
+            },
+            PurgeTarget::Cloudflare { url } => self
+                .http
+                .post(url)
+                .bearer_auth(&self.token)
+                .json(&serde_json::json!({ "tags": keys })),
+            PurgeTarget::Varnish { url, header } => {
+                let ban = Method::from_bytes(b"BAN").map_err(|e| {
+                    ObserverError::ActionPermanentlyFailed {
+                        reason: format!("invalid BAN method: {e}"),
+                    }
+                })?;
+                self.http.request(ban, url).header(header.as_str(), varnish_ban_regex(keys))
+            },
+        };
+
+        let response = request.send().await.map_err(|e| ObserverError::ActionExecutionFailed {
+            reason: format!("CDN purge via {provider} failed: {e}"),
+        })?;
+        let response = check_status(provider, response).await?;
+        if matches!(self.target, PurgeTarget::Cloudflare { .. }) {
+            check_cloudflare_result(response).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Regex matching any of `keys` as a whole word of a space-separated header,
+/// for `ban("obj.http.Surrogate-Key ~ " + req.http.X-Ban-Keys)`.
+pub(crate) fn varnish_ban_regex(keys: &[&str]) -> String {
+    let alternatives: Vec<String> = keys.iter().map(|key| escape_regex(key)).collect();
+    format!("(^|\\s)({})(\\s|$)", alternatives.join("|"))
+}
+
+fn escape_regex(key: &str) -> String {
+    let mut escaped = String::with_capacity(key.len());
+    for ch in key.chars() {
+        if matches!(
+            ch,
+            '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Classify a provider response like a webhook response: 2xx passes, 4xx other
+/// than 429 is permanent, everything else is retryable.
+async fn check_status(provider: &str, response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    let detail: String = body.chars().take(256).collect();
+    if status.is_client_error() && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!("CDN purge via {provider} rejected: HTTP {status}: {detail}"),
+        })
+    } else {
+        Err(ObserverError::ActionExecutionFailed {
+            reason: format!("CDN purge via {provider} failed: HTTP {status}: {detail}"),
+        })
+    }
+}
+
+/// Cloudflare reports some failures as `200 {"success": false, "errors": [...]}`.
+async fn check_cloudflare_result(response: Response) -> Result<()> {
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+    if body.get("success").and_then(serde_json::Value::as_bool) == Some(false) {
+        return Err(ObserverError::ActionPermanentlyFailed {
+            reason: format!(
+                "CDN purge via cloudflare rejected: {}",
+                body.get("errors").map(ToString::to_string).unwrap_or_default()
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn resolve_token(env_var: &str) -> Result<String> {
+    match std::env::var(env_var) {
+        Ok(token) if !token.is_empty() => Ok(token),
+        _ => Err(ObserverError::InvalidActionConfig {
+            reason: format!("CDN purge API token env var {env_var} is not set"),
+        }),
+    }
+}
+
+fn queue_closed() -> ObserverError {
+    ObserverError::ActionExecutionFailed {
+        reason: "CDN purge queue closed".to_string(),
+    }
+}
@@ -0,0 +1,211 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::sync::Arc;
+
+use serde_json::json;
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, header, method, path},
+};
+
+use super::*;
+use crate::event::EventKind;
+
+fn event(data: serde_json::Value) -> EntityEvent {
+    EntityEvent::new(EventKind::Updated, "Order".to_string(), Uuid::nil(), data)
+        .with_tenant_id("acme")
+}
+
+fn fastly(coalesce_ms: u64) -> CdnPurgeConfig {
+    CdnPurgeConfig {
+        provider: CdnProvider::Fastly {
+            service_id:    "svc123".to_string(),
+            api_token_env: "FASTLY_API_TOKEN".to_string(),
+            soft_purge:    true,
+        },
+        coalesce_ms,
+    }
+}
+
+fn cloudflare() -> CdnPurgeConfig {
+    CdnPurgeConfig {
+        provider:    CdnProvider::Cloudflare {
+            zone_id:       "zone42".to_string(),
+            api_token_env: "CF_API_TOKEN".to_string(),
+        },
+        coalesce_ms: 0,
+    }
+}
+
+/// Fire one purge per key concurrently and collect each caller's outcome.
+async fn purge_each(purger: Arc<CdnPurger>, keys: &[&str]) -> Vec<Result<()>> {
+    let tasks: Vec<_> = keys
+        .iter()
+        .map(|key| {
+            let purger = Arc::clone(&purger);
+            let keys = vec![(*key).to_string()];
+            tokio::spawn(async move { purger.purge(keys).await })
+        })
+        .collect();
+    let mut outcomes = Vec::new();
+    for task in tasks {
+        outcomes.push(task.await.unwrap());
+    }
+    outcomes
+}
+
+#[test]
+fn keys_render_event_fields_and_data_then_split_and_dedupe() {
+    let keys = render_purge_keys(
+        "order-{{ id }} {{ event.entity_type }} tenant-{{ event.tenant_id }} order-{{ id }}",
+        &event(json!({ "id": 42 })),
+    )
+    .unwrap();
+    assert_eq!(keys, ["order-42", "Order", "tenant-acme"]);
+}
+
+#[test]
+fn unresolved_placeholder_or_unsafe_key_is_rejected() {
+    let missing = render_purge_keys("order-{{ missing }}", &event(json!({ "id": 1 })));
+    assert!(matches!(missing, Err(ObserverError::TemplateRenderingFailed { .. })));
+
+    let non_ascii = render_purge_keys("order-{{ name }}", &event(json!({ "name": "café" })));
+    assert!(matches!(non_ascii, Err(ObserverError::ActionPermanentlyFailed { .. })));
+}
+
+#[test]
+fn varnish_regex_matches_whole_keys_literally() {
+    assert_eq!(varnish_ban_regex(&["order-1", "a.b"]), r"(^|\s)(order-1|a\.b)(\s|$)");
+}
+
+#[tokio::test]
+async fn concurrent_purges_in_one_window_become_one_fastly_call() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/service/svc123/purge"))
+        .and(header("Fastly-Key", "token"))
+        .and(header("Fastly-Soft-Purge", "1"))
+        .and(header("Surrogate-Key", "order-1 order-2 order-3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "ok" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let purger =
+        Arc::new(CdnPurger::build(&fastly(200), &server.uri(), "token".to_string()).unwrap());
+
+    let outcomes =
+        purge_each(purger, &["order-2", "order-1", "order-3", "order-1", "order-2"]).await;
+
+    assert!(outcomes.iter().all(Result::is_ok), "{outcomes:?}");
+}
+
+#[tokio::test]
+async fn keys_beyond_the_provider_limit_are_split_across_calls() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone42/purge_cache"))
+        .and(header("Authorization", "Bearer token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(2)
+        .mount(&server)
+        .await;
+    let purger = CdnPurger::build(&cloudflare(), &server.uri(), "token".to_string()).unwrap();
+    let keys: Vec<String> =
+        (0..CLOUDFLARE_MAX_TAGS_PER_REQUEST + 1).map(|i| format!("t{i}")).collect();
+
+    purger.purge(keys).await.unwrap();
+}
+
+#[tokio::test]
+async fn cloudflare_sends_tags_and_reports_success_false() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone42/purge_cache"))
+        .and(body_json(json!({ "tags": ["order-7"] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": false,
+            "errors": [{ "code": 1134, "message": "Purge by tag is not available" }]
+        })))
+        .mount(&server)
+        .await;
+    let purger = CdnPurger::build(&cloudflare(), &server.uri(), "token".to_string()).unwrap();
+
+    let err = purger.purge(vec!["order-7".to_string()]).await.unwrap_err();
+
+    assert!(matches!(err, ObserverError::ActionPermanentlyFailed { .. }), "{err:?}");
+    assert!(err.to_string().contains("Purge by tag"));
+}
+
+#[tokio::test]
+async fn varnish_bans_with_the_key_regex_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("BAN"))
+        .and(path("/"))
+        .and(header("X-Ban-Keys", r"(^|\s)(order-9)(\s|$)"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let config = CdnPurgeConfig {
+        provider:    CdnProvider::Varnish {
+            url:    format!("{}/", server.uri()),
+            header: "X-Ban-Keys".to_string(),
+        },
+        coalesce_ms: 0,
+    };
+    let purger = CdnPurger::build(&config, "", String::new()).unwrap();
+
+    purger.purge(vec!["order-9".to_string()]).await.unwrap();
+}
+
+#[tokio::test]
+async fn a_failed_call_is_reported_to_every_caller_in_the_window() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let purger =
+        Arc::new(CdnPurger::build(&fastly(200), &server.uri(), "token".to_string()).unwrap());
+
+    let outcomes = purge_each(purger, &["a", "b"]).await;
+
+    assert!(
+        outcomes
+            .iter()
+            .all(|o| matches!(o, Err(ObserverError::ActionExecutionFailed { .. }))),
+        "{outcomes:?}"
+    );
+}
+
+#[tokio::test]
+async fn rejected_credentials_fail_permanently() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("bad token"))
+        .mount(&server)
+        .await;
+    let purger = CdnPurger::build(&fastly(0), &server.uri(), "wrong".to_string()).unwrap();
+
+    let err = purger.purge(vec!["a".to_string()]).await.unwrap_err();
+
+    assert!(matches!(err, ObserverError::ActionPermanentlyFailed { .. }), "{err:?}");
+}
+
+#[test]
+fn missing_token_env_fails_at_construction() {
+    let config = CdnPurgeConfig {
+        provider:    CdnProvider::Fastly {
+            service_id:    "svc123".to_string(),
+            api_token_env: "FRAISEQL_TEST_CDN_TOKEN_MISSING_VAR".to_string(),
+            soft_purge:    false,
+        },
+        coalesce_ms: 0,
+    };
+    assert!(matches!(
+        CdnPurger::from_config(&config),
+        Err(ObserverError::InvalidActionConfig { .. })
+    ));
+}
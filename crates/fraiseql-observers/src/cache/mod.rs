@@ -47,11 +47,12 @@
 //! - Hash prevents sensitive data in keys
 //! - Entity info in key for visibility/debugging
 
+pub mod cdn;
 /// Pure Redis glob-pattern helpers for the cache-invalidation action (#428).
 ///
 /// Always compiled (the Redis transport that consumes them is `caching`-gated) so
 /// the security-critical escape-then-substitute logic is unit-tested on every push.
-mod glob;
+pub(crate) mod glob;
 #[cfg(feature = "caching")]
 pub mod redis;
 
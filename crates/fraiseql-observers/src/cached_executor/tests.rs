@@ -145,6 +145,8 @@ fn test_cache_key_generation() {
         body_template:      Some("{}".to_string()),
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     };
 
     let key = CachedActionExecutor::<TestExecutor, InMemoryCache>::cache_key(&event, &action);
@@ -32,29 +32,69 @@
 //! - **Per-Action Timeout**: Each action has configurable timeout
 //! - **Result Aggregation**: Collects success/failure for each action
 //! - **Transparent Integration**: Drop-in replacement for sequential executor
+//!
+//! # Ordered delivery and per-observer limits
+//!
+//! [`ConcurrentActionExecutor::submit`] runs an observer's actions for one event
+//! in the background, honouring the observer's
+//! [`ordering`](crate::config::ObserverDefinition::ordering) and
+//! [`max_concurrency`](crate::config::ObserverDefinition::max_concurrency):
+//!
+//! ```text
+//! submit(observer, event)
+//!     ↓ hash(observer, entity_type, entity_id) % lanes   (per-entity ordering)
+//! lane 0: e1(order-7) → e3(order-7) → …      one at a time, in submit order
+//! lane 1: e2(user-3) → …                     lanes run in parallel
+//!     ↓
+//! observer semaphore (max_concurrency) → execute_all(event, actions)
+//! ```
+//!
+//! Lanes are keyed by observer as well as entity, so a slow webhook on one
+//! observer never holds back another observer's events for the same entity.
 
 use std::{
-    sync::Arc,
+    future::Future,
+    hash::{DefaultHasher, Hash, Hasher},
+    num::NonZeroUsize,
+    pin::Pin,
+    sync::{Arc, OnceLock},
     time::{Duration, Instant},
 };
 
+use dashmap::DashMap;
 use futures::stream::{FuturesUnordered, StreamExt};
-use tokio::time::timeout;
+use tokio::{
+    sync::{Semaphore, mpsc, oneshot},
+    time::timeout,
+};
 
 use crate::{
-    config::ActionConfig,
+    config::{ActionConfig, DeliveryOrdering, ObserverDefinition},
     event::EntityEvent,
     traits::{ActionExecutor, ActionResult},
 };
 
+/// Default number of serial lanes used for per-entity ordering.
+pub const DEFAULT_ORDERED_LANES: usize = 64;
+
+/// A queued unit of work on a serial lane.
+type LaneJob = Pin<Box<dyn Future<Output = ()> + Send>>;
+
 /// Concurrent action execution wrapper.
 ///
 /// Wraps an `ActionExecutor` to execute multiple actions in parallel.
 /// Significantly reduces latency by eliminating sequential waiting.
+///
+/// Clones share their serial lanes and per-observer limits.
 #[derive(Clone)]
 pub struct ConcurrentActionExecutor<E: ActionExecutor + Clone> {
     inner:             E,
     action_timeout_ms: u64,
+    /// Serial lanes for per-entity ordering, each drained by one task spawned
+    /// on first use.
+    lanes:             Arc<[OnceLock<mpsc::UnboundedSender<LaneJob>>]>,
+    /// Per-observer concurrency limit and the semaphore enforcing it.
+    observer_limits:   Arc<DashMap<String, (NonZeroUsize, Arc<Semaphore>)>>,
 }
 
 impl<E: ActionExecutor + Clone + Send + Sync + 'static> ConcurrentActionExecutor<E> {
@@ -64,13 +104,139 @@ impl<E: ActionExecutor + Clone + Send + Sync + 'static> ConcurrentActionExecutor
     ///
     /// * `inner` - The underlying action executor
     /// * `action_timeout_ms` - Timeout per action in milliseconds (default: 30000)
-    pub const fn new(inner: E, action_timeout_ms: u64) -> Self {
+    pub fn new(inner: E, action_timeout_ms: u64) -> Self {
         Self {
             inner,
             action_timeout_ms,
+            lanes: new_lanes(DEFAULT_ORDERED_LANES),
+            observer_limits: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Use `lanes` serial lanes for per-entity ordering (default:
+    /// [`DEFAULT_ORDERED_LANES`]).
+    ///
+    /// More lanes mean fewer unrelated entities queued behind one another.
+    /// Set this before the first [`submit`](Self::submit); it replaces the
+    /// lanes of this executor and its future clones.
+    #[must_use]
+    pub fn with_lanes(mut self, lanes: NonZeroUsize) -> Self {
+        self.lanes = new_lanes(lanes.get());
+        self
+    }
+
+    /// Number of serial lanes used for per-entity ordering.
+    #[must_use]
+    pub fn lane_count(&self) -> usize {
+        self.lanes.len()
+    }
+
+    /// Run `observer`'s actions for `event` in the background.
+    ///
+    /// The work is queued before this returns, so the order of `submit` calls is
+    /// the delivery order:
+    ///
+    /// - [`DeliveryOrdering::PerEntity`]: the event joins the serial lane of its `(observer_name,
+    ///   entity_type, entity_id)` and runs after every earlier submission on that lane has
+    ///   finished.
+    /// - [`DeliveryOrdering::Unordered`]: the event runs as soon as it is scheduled.
+    ///
+    /// Either way, at most `observer.max_concurrency` submissions of
+    /// `observer_name` execute at once. The returned future resolves to the
+    /// results of [`execute_all`](Self::execute_all); dropping it does not
+    /// cancel the work.
+    ///
+    /// Lane queues are unbounded: callers bound the number of in-flight
+    /// submissions (e.g. with the runtime's channel capacity).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    pub fn submit(
+        &self,
+        observer_name: &str,
+        observer: &ObserverDefinition,
+        event: &EntityEvent,
+    ) -> impl Future<Output = Vec<ActionResult>> + Send + 'static {
+        let permits =
+            observer.max_concurrency.map(|limit| self.observer_limit(observer_name, limit));
+        let executor = self.clone();
+        let event = event.clone();
+        let actions = observer.actions.clone();
+        let (done, outcome) = oneshot::channel();
+
+        let job = async move {
+            let _permit = match permits {
+                Some(semaphore) => semaphore.acquire_owned().await.ok(),
+                None => None,
+            };
+            let results = executor.execute_all(&event, &actions).await;
+            // The submitter may have stopped waiting; the work still counted.
+            let _ = done.send(results);
+        };
+        match observer.ordering {
+            DeliveryOrdering::PerEntity => {
+                let lane =
+                    self.lane_for(observer_name, event.entity_type.as_str(), &event.entity_id);
+                self.enqueue(lane, Box::pin(job));
+            },
+            DeliveryOrdering::Unordered => {
+                tokio::spawn(job);
+            },
+        }
+
+        let actions = observer.actions.clone();
+        async move {
+            outcome.await.unwrap_or_else(|_| {
+                actions
+                    .iter()
+                    .map(|action| failure(action, "Action dropped before completion", 0.0))
+                    .collect()
+            })
         }
     }
 
+    /// Lane of an observer/entity pair.
+    fn lane_for(&self, observer_name: &str, entity_type: &str, entity_id: &uuid::Uuid) -> usize {
+        let mut hasher = DefaultHasher::new();
+        (observer_name, entity_type, entity_id).hash(&mut hasher);
+        // Truncation is fine: only the residue modulo the lane count matters.
+        #[allow(clippy::cast_possible_truncation)] // Reason: hash bucket selection
+        let hash = hasher.finish() as usize;
+        hash % self.lanes.len()
+    }
+
+    /// Queue `job` on `lane`, spawning the lane's task on first use.
+    fn enqueue(&self, lane: usize, job: LaneJob) {
+        let sender = self.lanes[lane].get_or_init(|| {
+            let (sender, mut jobs) = mpsc::unbounded_channel::<LaneJob>();
+            tokio::spawn(async move {
+                while let Some(job) = jobs.recv().await {
+                    job.await;
+                }
+            });
+            sender
+        });
+        if let Err(mpsc::error::SendError(job)) = sender.send(job) {
+            // The lane task only stops when every sender is gone, so this is
+            // unreachable in practice; run the job unordered rather than drop it.
+            tokio::spawn(job);
+        }
+    }
+
+    /// Semaphore enforcing `limit` for `observer_name`, replaced when the
+    /// observer's limit changes (e.g. after a hot reload).
+    fn observer_limit(&self, observer_name: &str, limit: NonZeroUsize) -> Arc<Semaphore> {
+        let mut entry = self
+            .observer_limits
+            .entry(observer_name.to_string())
+            .or_insert_with(|| (limit, Arc::new(Semaphore::new(limit.get()))));
+        if entry.0 != limit {
+            *entry = (limit, Arc::new(Semaphore::new(limit.get())));
+        }
+        Arc::clone(&entry.1)
+    }
+
     /// Execute multiple actions concurrently.
     ///
     /// Collects all action futures into `FuturesUnordered`, then waits
@@ -115,20 +281,10 @@ impl<E: ActionExecutor + Clone + Send + Sync + 'static> ConcurrentActionExecutor
                         action_result.duration_ms = duration_ms;
                         action_result
                     },
-                    Ok(Err(err)) => ActionResult {
-                        action_type: format!("{action:?}"),
-                        success: false,
-                        message: format!("Execution error: {err}"),
-                        duration_ms,
-                        status_code: None,
-                    },
-                    Err(_) => ActionResult {
-                        action_type: format!("{action:?}"),
-                        success: false,
-                        message: "Action timeout".to_string(),
-                        duration_ms,
-                        status_code: None,
+                    Ok(Err(err)) => {
+                        failure(&action, &format!("Execution error: {err}"), duration_ms)
                     },
+                    Err(_) => failure(&action, "Action timeout", duration_ms),
                 }
             });
         }
@@ -153,5 +309,20 @@ impl<E: ActionExecutor + Clone + Send + Sync + 'static> ConcurrentActionExecutor
     }
 }
 
+fn new_lanes(count: usize) -> Arc<[OnceLock<mpsc::UnboundedSender<LaneJob>>]> {
+    (0..count.max(1)).map(|_| OnceLock::new()).collect()
+}
+
+/// A failed result for `action`.
+fn failure(action: &ActionConfig, message: &str, duration_ms: f64) -> ActionResult {
+    ActionResult {
+        action_type: format!("{action:?}"),
+        success: false,
+        message: message.to_string(),
+        duration_ms,
+        status_code: None,
+    }
+}
+
 #[cfg(test)]
 mod tests;
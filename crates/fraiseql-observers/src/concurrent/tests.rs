@@ -3,14 +3,24 @@
 
 #[cfg(test)]
 mod concurrent_tests {
-    use std::time::Duration;
+    use std::{
+        num::NonZeroUsize,
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
 
     use serde_json::json;
     use uuid::Uuid;
 
     use crate::{
         concurrent::*,
-        config::ActionConfig,
+        config::{
+            ActionConfig, DeliveryOrdering, FailurePolicy, ObserverDefinition, ReplayPolicy,
+            RetryConfig,
+        },
         error::Result,
         event::EntityEvent,
         traits::{ActionExecutor, ActionResult},
@@ -204,4 +214,139 @@ mod concurrent_tests {
         concurrent.set_action_timeout_ms(2500);
         assert_eq!(concurrent.action_timeout_ms(), 2500);
     }
+
+    // --- Ordered delivery and per-observer limits ---
+
+    /// Records the `seq` of each event it runs, sleeping `delay_ms` first, and
+    /// tracks the peak number of executions in flight.
+    #[derive(Clone, Default)]
+    struct RecordingExecutor {
+        order:     Arc<Mutex<Vec<u64>>>,
+        in_flight: Arc<AtomicUsize>,
+        peak:      Arc<AtomicUsize>,
+    }
+
+    impl ActionExecutor for RecordingExecutor {
+        async fn execute(
+            &self,
+            event: &EntityEvent,
+            _action: &ActionConfig,
+        ) -> Result<ActionResult> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            let delay_ms = event.data["delay_ms"].as_u64().unwrap_or(0);
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            self.order.lock().unwrap().push(event.data["seq"].as_u64().unwrap());
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(ActionResult {
+                action_type: "recorded".to_string(),
+                success:     true,
+                message:     String::new(),
+                duration_ms: 0.0,
+                status_code: None,
+            })
+        }
+    }
+
+    fn observer(ordering: DeliveryOrdering, max_concurrency: Option<usize>) -> ObserverDefinition {
+        ObserverDefinition {
+            event_type: "UPDATE".to_string(),
+            entity: "Order".to_string(),
+            condition: None,
+            actions: vec![email_action()],
+            retry: RetryConfig::default(),
+            on_failure: FailurePolicy::Log,
+            replay: ReplayPolicy::default(),
+            ordering,
+            max_concurrency: max_concurrency.and_then(NonZeroUsize::new),
+            dedup: None,
+            dry_run: false,
+        }
+    }
+
+    fn update(entity_id: Uuid, seq: u64, delay_ms: u64) -> EntityEvent {
+        EntityEvent::new(
+            crate::event::EventKind::Updated,
+            "Order".to_string(),
+            entity_id,
+            json!({ "seq": seq, "delay_ms": delay_ms }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_per_entity_ordering_keeps_submit_order() {
+        let recorder = RecordingExecutor::default();
+        let concurrent = ConcurrentActionExecutor::new(recorder.clone(), 5000);
+        let observer = observer(DeliveryOrdering::PerEntity, None);
+        let order_id = Uuid::new_v4();
+
+        // The first update is slow; without ordering the second would land first.
+        let first = concurrent.submit("orders", &observer, &update(order_id, 1, 50));
+        let second = concurrent.submit("orders", &observer, &update(order_id, 2, 0));
+        assert!(second.await[0].success);
+        assert!(first.await[0].success);
+
+        assert_eq!(*recorder.order.lock().unwrap(), [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_unordered_delivery_lets_later_events_overtake() {
+        let recorder = RecordingExecutor::default();
+        let concurrent = ConcurrentActionExecutor::new(recorder.clone(), 5000);
+        let observer = observer(DeliveryOrdering::Unordered, None);
+        let order_id = Uuid::new_v4();
+
+        let first = concurrent.submit("orders", &observer, &update(order_id, 1, 50));
+        let second = concurrent.submit("orders", &observer, &update(order_id, 2, 0));
+        second.await;
+        first.await;
+
+        assert_eq!(*recorder.order.lock().unwrap(), [2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_entities_on_different_lanes_run_in_parallel() {
+        let recorder = RecordingExecutor::default();
+        let concurrent = ConcurrentActionExecutor::new(recorder.clone(), 5000);
+        let observer = observer(DeliveryOrdering::PerEntity, None);
+        let a = Uuid::new_v4();
+        let b = std::iter::repeat_with(Uuid::new_v4)
+            .find(|b| {
+                concurrent.lane_for("orders", "Order", b)
+                    != concurrent.lane_for("orders", "Order", &a)
+            })
+            .unwrap();
+
+        let first = concurrent.submit("orders", &observer, &update(a, 1, 50));
+        let second = concurrent.submit("orders", &observer, &update(b, 2, 0));
+        second.await;
+        first.await;
+
+        assert_eq!(*recorder.order.lock().unwrap(), [2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_observer_max_concurrency_caps_in_flight_events() {
+        let recorder = RecordingExecutor::default();
+        let concurrent = ConcurrentActionExecutor::new(recorder.clone(), 5000);
+        let observer = observer(DeliveryOrdering::Unordered, Some(2));
+
+        let pending: Vec<_> = (0..6)
+            .map(|seq| concurrent.submit("orders", &observer, &update(Uuid::new_v4(), seq, 20)))
+            .collect();
+        for results in pending {
+            assert!(results.await[0].success);
+        }
+
+        assert_eq!(recorder.order.lock().unwrap().len(), 6);
+        assert_eq!(recorder.peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_lane_count_is_configurable() {
+        let concurrent = ConcurrentActionExecutor::new(TestExecutor, 1000);
+        assert_eq!(concurrent.lane_count(), DEFAULT_ORDERED_LANES);
+        let concurrent = concurrent.with_lanes(NonZeroUsize::new(4).unwrap());
+        assert_eq!(concurrent.lane_count(), 4);
+    }
 }
@@ -0,0 +1,189 @@
+//! CDN purge target for the `cache` observer action.
+//!
+//! A `cache` action with a `cdn` block purges edge caches instead of the Redis
+//! application keyspace. The action's `key_pattern` renders to one or more
+//! whitespace-separated surrogate keys (Fastly), cache tags (Cloudflare), or
+//! ban keys (Varnish):
+//!
+//! ```toml
+//! [[observers.actions]]
+//! type        = "cache"
+//! action      = "invalidate"
+//! key_pattern = "order-{{ id }} orders {{ event.entity_type }}"
+//!
+//! [observers.actions.cdn]
+//! provider      = "fastly"            # fastly | cloudflare | varnish
+//! service_id    = "SU1Z0isxPaozGVKXdv0eY"
+//! api_token_env = "FASTLY_API_TOKEN"  # env var NAME, not the value
+//! coalesce_ms   = 250
+//! ```
+//!
+//! Credentials are supplied via environment-variable *names*, never as
+//! literals, because action configs are stored in `tb_observer.actions` and
+//! echoed by the admin API.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ObserverError, Result};
+
+/// Upper bound on [`CdnPurgeConfig::coalesce_ms`].
+pub const MAX_CDN_COALESCE_MS: u64 = 60_000;
+
+/// Where a `cache` action purges, and how long purges are coalesced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CdnPurgeConfig {
+    /// CDN provider and its credentials.
+    #[serde(flatten)]
+    pub provider:    CdnProvider,
+    /// How long (milliseconds) purges are collected before one coalesced call
+    /// is sent (default: 250). Every event's action waits at most this long plus
+    /// the provider round-trip; `0` sends whatever is already queued at once.
+    #[serde(default = "default_coalesce_ms")]
+    pub coalesce_ms: u64,
+}
+
+/// A CDN purge API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CdnProvider {
+    /// Fastly surrogate-key purge (`POST /service/{id}/purge`).
+    Fastly {
+        /// Fastly service id.
+        service_id:    String,
+        /// Name of the environment variable holding the Fastly API token.
+        api_token_env: String,
+        /// Mark content stale instead of evicting it (`Fastly-Soft-Purge: 1`).
+        #[serde(default)]
+        soft_purge:    bool,
+    },
+    /// Cloudflare purge by cache tag (`POST /zones/{id}/purge_cache`).
+    Cloudflare {
+        /// Cloudflare zone id.
+        zone_id:       String,
+        /// Name of the environment variable holding a Cloudflare API token with
+        /// the `Zone.Cache Purge` permission.
+        api_token_env: String,
+    },
+    /// Varnish `BAN` request carrying the keys in a header.
+    ///
+    /// The header value is a ready-made regex matching any of the keys as a
+    /// whole word, so the VCL only has to forward it:
+    ///
+    /// ```vcl
+    /// if (req.method == "BAN") {
+    ///     ban("obj.http.Surrogate-Key ~ " + req.http.X-Ban-Keys);
+    ///     return (synth(200, "Banned"));
+    /// }
+    /// ```
+    Varnish {
+        /// URL the `BAN` request is sent to (e.g. `https://varnish.internal/`).
+        url:    String,
+        /// Header carrying the key regex (default: `X-Ban-Keys`).
+        #[serde(default = "default_varnish_header")]
+        header: String,
+    },
+}
+
+impl CdnProvider {
+    /// Provider name as written in config (`fastly`, `cloudflare`, `varnish`).
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Fastly { .. } => "fastly",
+            Self::Cloudflare { .. } => "cloudflare",
+            Self::Varnish { .. } => "varnish",
+        }
+    }
+}
+
+impl CdnPurgeConfig {
+    /// Validate the configuration structurally.
+    ///
+    /// Credentials are not resolved here; a missing token env var fails loud
+    /// when the purge client is first built at dispatch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if an id, env var name,
+    /// URL, or header name is empty or malformed, or `coalesce_ms` exceeds
+    /// [`MAX_CDN_COALESCE_MS`].
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| Err(ObserverError::InvalidActionConfig { reason });
+
+        if self.coalesce_ms > MAX_CDN_COALESCE_MS {
+            return invalid(format!(
+                "Cache action cdn.coalesce_ms must be at most {MAX_CDN_COALESCE_MS}"
+            ));
+        }
+        match &self.provider {
+            CdnProvider::Fastly {
+                service_id,
+                api_token_env,
+                ..
+            } => {
+                if !is_path_segment(service_id) {
+                    return invalid(
+                        "Cache action cdn.service_id must be a non-empty alphanumeric Fastly \
+                         service id"
+                            .to_string(),
+                    );
+                }
+                validate_env_name(api_token_env)
+            },
+            CdnProvider::Cloudflare {
+                zone_id,
+                api_token_env,
+            } => {
+                if !is_path_segment(zone_id) {
+                    return invalid(
+                        "Cache action cdn.zone_id must be a non-empty alphanumeric Cloudflare \
+                         zone id"
+                            .to_string(),
+                    );
+                }
+                validate_env_name(api_token_env)
+            },
+            CdnProvider::Varnish { url, header } => {
+                let lower = url.to_ascii_lowercase();
+                if !lower.starts_with("http://") && !lower.starts_with("https://") {
+                    return invalid(format!(
+                        "Cache action cdn.url must be an http:// or https:// URL: {url:?}"
+                    ));
+                }
+                if header.is_empty()
+                    || !header.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                {
+                    return invalid(format!(
+                        "Cache action cdn.header {header:?} is not a valid HTTP header name"
+                    ));
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// An id spliced into a provider API path: ASCII alphanumerics only.
+fn is_path_segment(id: &str) -> bool {
+    !id.is_empty() && id.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn validate_env_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return Err(ObserverError::InvalidActionConfig {
+            reason: format!(
+                "Cache action cdn.api_token_env {name:?} must name an environment variable"
+            ),
+        });
+    }
+    Ok(())
+}
+
+const fn default_coalesce_ms() -> u64 {
+    250
+}
+
+fn default_varnish_header() -> String {
+    "X-Ban-Keys".to_string()
+}
@@ -0,0 +1,99 @@
+//! Declared event types (`[observers.runtime.event_types]`).
+//!
+//! Each declared type gives the JSON Schema its events' payloads must match.
+//! Events that fail it — and, with `undeclared = "quarantine"`, events of a
+//! type nobody declared — are quarantined instead of reaching any observer.
+//! See [`registry`](crate::registry).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::{ObserverError, Result},
+    event::EventKind,
+    registry::compile_definition,
+};
+
+/// What happens to an event whose entity type is not declared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UndeclaredEventPolicy {
+    /// Process it unvalidated (default)
+    #[default]
+    Accept,
+    /// Quarantine it
+    Quarantine,
+}
+
+/// One declared event type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventTypeDefinition {
+    /// Entity type the events are about (e.g. `"Order"`)
+    pub entity_type: String,
+
+    /// Event kinds the schema applies to; empty for every kind
+    #[serde(default)]
+    pub event_kinds: Vec<EventKind>,
+
+    /// JSON Schema of the event payload (`data`)
+    pub schema: Value,
+
+    /// What the events mean
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl EventTypeDefinition {
+    /// Whether the schema applies to events of `kind`.
+    #[must_use]
+    pub fn covers(&self, kind: EventKind) -> bool {
+        self.event_kinds.is_empty() || self.event_kinds.contains(&kind)
+    }
+}
+
+/// Event type registry configuration (`[observers.runtime.event_types]`)
+///
+/// Strict (`deny_unknown_fields`): a misspelt `undeclared` would otherwise
+/// silently accept every undeclared event.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventTypesConfig {
+    /// What happens to events of undeclared types (default: accept)
+    #[serde(default)]
+    pub undeclared: UndeclaredEventPolicy,
+
+    /// Declared event types
+    #[serde(default)]
+    pub types: Vec<EventTypeDefinition>,
+}
+
+impl EventTypesConfig {
+    /// Whether events are validated at all.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        !self.types.is_empty() || self.undeclared == UndeclaredEventPolicy::Quarantine
+    }
+
+    /// Validate the declarations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if a type is declared twice or
+    /// its schema does not compile.
+    pub fn validate(&self) -> Result<()> {
+        for (i, definition) in self.types.iter().enumerate() {
+            if self.types[..i].iter().any(|d| d.entity_type == definition.entity_type) {
+                return Err(ObserverError::InvalidConfig {
+                    message: format!(
+                        "event_types: '{}' is declared more than once",
+                        definition.entity_type
+                    ),
+                });
+            }
+            compile_definition(definition)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+//! Label policy for the per-observer Prometheus series.
+//!
+//! Action metrics are labeled by observer, action type, entity type and tenant
+//! so dashboards can break failures down per integration. Every distinct label
+//! combination is a separate time series, so the number of combinations is
+//! capped: once `max_label_series` is reached, new combinations are recorded
+//! under a single overflow series instead.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ObserverError, Result};
+
+/// Default cap on distinct label combinations of the action series.
+pub const DEFAULT_MAX_LABEL_SERIES: usize = 1_000;
+
+/// Observer metrics labels (`[observers.runtime.metrics]`)
+///
+/// Strict (`deny_unknown_fields`): a misspelt key would otherwise silently
+/// leave the default cap in place.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsConfig {
+    /// Maximum distinct `(observer, action_type, entity_type, tenant)`
+    /// combinations exported (default: 1000)
+    #[serde(default = "default_max_label_series")]
+    pub max_label_series: usize,
+
+    /// Label action series with the event's tenant (default: true).
+    ///
+    /// Disable on multi-tenant deployments with many tenants; the `tenant`
+    /// label is then left empty.
+    #[serde(default = "default_tenant_labels")]
+    pub tenant_labels: bool,
+}
+
+const fn default_max_label_series() -> usize {
+    DEFAULT_MAX_LABEL_SERIES
+}
+
+const fn default_tenant_labels() -> bool {
+    true
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            max_label_series: DEFAULT_MAX_LABEL_SERIES,
+            tenant_labels:    true,
+        }
+    }
+}
+
+impl MetricsConfig {
+    /// Validate the label policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if `max_label_series` is zero.
+    pub fn validate(&self) -> Result<()> {
+        if self.max_label_series == 0 {
+            return Err(ObserverError::InvalidConfig {
+                message: "metrics.max_label_series must be greater than zero".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -14,26 +14,36 @@
 //!
 //! Environment variable precedence: `FRAISEQL_*` > TOML > defaults
 
+pub mod cdn;
 pub mod clickhouse;
 pub mod email;
+pub mod event_types;
 pub mod job_queue;
+pub mod metrics;
 pub mod performance;
+pub mod push;
 pub mod redis;
 pub mod runtime;
+pub mod sms;
 pub mod transport;
 
 #[cfg(test)]
 mod tests;
 
+pub use cdn::{CdnProvider, CdnPurgeConfig};
 pub use clickhouse::ClickHouseConfig;
 pub use email::{EmailSmtpConfig, SmtpTlsMode};
+pub use event_types::{EventTypeDefinition, EventTypesConfig, UndeclaredEventPolicy};
 pub use job_queue::JobQueueConfig;
+pub use metrics::MetricsConfig;
 pub use performance::PerformanceConfig;
+pub use push::PushProvider;
 pub use redis::RedisConfig;
 pub use runtime::{
-    ActionConfig, BackoffStrategy, FailurePolicy, MultiListenerConfig, ObserverDefinition,
-    ObserverRuntimeConfig, OverflowPolicy, RetryConfig,
+    ActionConfig, BackoffStrategy, DeliveryOrdering, FailurePolicy, MultiListenerConfig,
+    ObserverDefinition, ObserverRuntimeConfig, OverflowPolicy, ReplayPolicy, RetryConfig,
 };
+pub use sms::{SmsProvider, SmsSenderPool};
 pub use transport::{
     BridgeTransportConfig, JetStreamConfig, NatsTransportConfig, TransportConfig, TransportKind,
 };
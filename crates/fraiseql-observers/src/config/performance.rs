@@ -4,7 +4,10 @@ use std::env;
 
 use serde::{Deserialize, Serialize};
 
-use crate::error::{ObserverError, Result};
+use crate::{
+    dedup::DedupStrategy,
+    error::{ObserverError, Result},
+};
 
 /// Performance optimization features
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +31,10 @@ pub struct PerformanceConfig {
     /// Concurrent execution timeout in milliseconds (default: 30000)
     #[serde(default = "default_concurrent_timeout_ms")]
     pub concurrent_timeout_ms: u64,
+
+    /// What counts as a duplicate when dedup is enabled (default: event id)
+    #[serde(default)]
+    pub dedup_strategy: DedupStrategy,
 }
 
 const fn default_true() -> bool {
@@ -50,6 +57,7 @@ impl Default for PerformanceConfig {
             enable_concurrent:      true,
             max_concurrent_actions: default_max_concurrent_actions(),
             concurrent_timeout_ms:  default_concurrent_timeout_ms(),
+            dedup_strategy:         DedupStrategy::default(),
         }
     }
 }
@@ -85,7 +93,8 @@ impl PerformanceConfig {
     /// # Errors
     ///
     /// Returns [`ObserverError::InvalidConfig`] if dedup or caching is enabled without
-    /// Redis, or if `max_concurrent_actions` or `concurrent_timeout_ms` is 0.
+    /// Redis, if `max_concurrent_actions` or `concurrent_timeout_ms` is 0, or if
+    /// `dedup_strategy` has an out-of-range window.
     pub fn validate(&self, redis_configured: bool) -> Result<()> {
         // Dedup requires Redis
         if self.enable_dedup && !redis_configured {
@@ -109,6 +118,7 @@ impl PerformanceConfig {
                 message: "performance.concurrent_timeout_ms must be > 0".to_string(),
             });
         }
+        self.dedup_strategy.validate()?;
         Ok(())
     }
 }
@@ -0,0 +1,132 @@
+//! Push notification provider for the `push` observer action.
+//!
+//! A `push` action sends one notification per device token through Firebase
+//! Cloud Messaging (HTTP v1 API, authenticated with a service account) or the
+//! Apple Push Notification service (token-based JWT auth over HTTP/2):
+//!
+//! ```toml
+//! [[observers.actions]]
+//! type                  = "push"
+//! device_token_template = "{{ device_tokens }}"   # a string, or an array field
+//! title_template        = "Order {{ id }} shipped"
+//! body_template         = "It is on its way."
+//! collapse_key          = "order-{{ id }}"
+//! invalid_token_webhook = "https://api.example.com/hooks/push-token-cleanup"
+//!
+//! [observers.actions.provider]
+//! kind                = "fcm"                    # fcm | apns
+//! project_id          = "my-firebase-project"
+//! service_account_env = "FCM_SERVICE_ACCOUNT"    # env var NAME holding the JSON key
+//! ```
+//!
+//! Credentials are supplied via environment-variable *names*, never as
+//! literals, because action configs are stored in `tb_observer.actions` and
+//! echoed by the admin API.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ObserverError, Result};
+
+/// A push notification service and its credentials.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PushProvider {
+    /// Firebase Cloud Messaging HTTP v1 (`POST /v1/projects/{id}/messages:send`).
+    Fcm {
+        /// Firebase project id.
+        project_id:          String,
+        /// Name of the environment variable holding the service account JSON
+        /// key (its `client_email`, `private_key` and `token_uri` are used).
+        service_account_env: String,
+    },
+    /// Apple Push Notification service (`POST /3/device/{token}`).
+    Apns {
+        /// Apple developer team id (the JWT issuer).
+        team_id:         String,
+        /// Id of the `.p8` signing key.
+        key_id:          String,
+        /// Name of the environment variable holding the `.p8` key (PEM).
+        private_key_env: String,
+        /// App bundle id, sent as `apns-topic`.
+        topic:           String,
+        /// Send through the development environment instead of production.
+        #[serde(default)]
+        sandbox:         bool,
+    },
+}
+
+impl PushProvider {
+    /// Provider name as written in config (`fcm`, `apns`).
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Fcm { .. } => "fcm",
+            Self::Apns { .. } => "apns",
+        }
+    }
+
+    /// Validate the configuration structurally.
+    ///
+    /// Credentials are not resolved here; a missing env var or malformed key
+    /// fails loud when the sender is first built at dispatch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if an id, topic, or env
+    /// var name is empty or malformed.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Fcm {
+                project_id,
+                service_account_env,
+            } => {
+                // Firebase project ids are lowercase letters, digits and hyphens.
+                if project_id.is_empty()
+                    || !project_id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                {
+                    return invalid(format!(
+                        "Push action provider.project_id {project_id:?} is not a valid Firebase \
+                         project id"
+                    ));
+                }
+                validate_env_name("service_account_env", service_account_env)
+            },
+            Self::Apns {
+                team_id,
+                key_id,
+                private_key_env,
+                topic,
+                ..
+            } => {
+                for (field, id) in [("team_id", team_id), ("key_id", key_id)] {
+                    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                        return invalid(format!(
+                            "Push action provider.{field} must be a non-empty alphanumeric Apple \
+                             id"
+                        ));
+                    }
+                }
+                if topic.is_empty() || !topic.bytes().all(|b| b.is_ascii_graphic()) {
+                    return invalid(format!(
+                        "Push action provider.topic {topic:?} must be the app's bundle id"
+                    ));
+                }
+                validate_env_name("private_key_env", private_key_env)
+            },
+        }
+    }
+}
+
+fn validate_env_name(field: &str, name: &str) -> Result<()> {
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return invalid(format!(
+            "Push action provider.{field} {name:?} must name an environment variable"
+        ));
+    }
+    Ok(())
+}
+
+const fn invalid(reason: String) -> Result<()> {
+    Err(ObserverError::InvalidActionConfig { reason })
+}
@@ -1,11 +1,18 @@
 //! Observer runtime configuration: top-level config, observer definitions, actions.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroUsize};
 
 use serde::{Deserialize, Serialize};
 
-use super::{ClickHouseConfig, JobQueueConfig, PerformanceConfig, RedisConfig, TransportConfig};
-use crate::error::{ObserverError, Result};
+use super::{
+    CdnPurgeConfig, ClickHouseConfig, EventTypesConfig, JobQueueConfig, MetricsConfig,
+    PerformanceConfig, PushProvider, RedisConfig, SmsProvider, SmsSenderPool, TransportConfig,
+    sms::normalize_e164,
+};
+use crate::{
+    dedup::DedupStrategy,
+    error::{ObserverError, Result},
+};
 
 // ============================================================================
 // Observer Runtime Configuration
@@ -34,6 +41,10 @@ pub struct ObserverRuntimeConfig {
     #[serde(default)]
     pub performance: PerformanceConfig,
 
+    /// Labels of the per-observer action metrics
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
     /// Channel buffer size for incoming events (default: 1000)
     #[serde(default = "default_channel_capacity")]
     pub channel_capacity: usize,
@@ -67,6 +78,15 @@ pub struct ObserverRuntimeConfig {
     #[serde(default)]
     pub max_dlq_size: Option<usize>,
 
+    /// Simulation mode: every observer runs dry, capturing its rendered
+    /// actions instead of sending them (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Declared event types whose payloads are validated before matching
+    #[serde(default)]
+    pub event_types: EventTypesConfig,
+
     /// Observer definitions
     #[serde(default)]
     pub observers: HashMap<String, ObserverDefinition>,
@@ -79,6 +99,8 @@ impl ObserverRuntimeConfig {
     ///
     /// Returns `ObserverError::InvalidConfig` if any field has an invalid value.
     pub fn validate(&self) -> crate::error::Result<()> {
+        self.metrics.validate()?;
+        self.event_types.validate()?;
         if let Some(max) = self.max_dlq_size {
             if max == 0 {
                 return Err(ObserverError::InvalidConfig {
@@ -86,6 +108,11 @@ impl ObserverRuntimeConfig {
                 });
             }
         }
+        for observer in self.observers.values() {
+            if let Some(strategy) = &observer.dedup {
+                strategy.validate()?;
+            }
+        }
         Ok(())
     }
 }
@@ -151,6 +178,29 @@ pub struct ObserverDefinition {
     /// Failure handling policy
     #[serde(default)]
     pub on_failure: FailurePolicy,
+
+    /// Which actions run when an event is replayed from storage
+    #[serde(default)]
+    pub replay: ReplayPolicy,
+
+    /// Delivery order of one entity's events under concurrent execution
+    #[serde(default)]
+    pub ordering: DeliveryOrdering,
+
+    /// Maximum events this observer handles at once under concurrent
+    /// execution (`None` = no per-observer limit)
+    #[serde(default)]
+    pub max_concurrency: Option<NonZeroUsize>,
+
+    /// Dedup strategy for this observer, overriding
+    /// `performance.dedup_strategy` (`None` = the global strategy)
+    #[serde(default)]
+    pub dedup: Option<DedupStrategy>,
+
+    /// Capture this observer's rendered actions instead of sending them
+    /// (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl ObserverDefinition {
@@ -306,6 +356,64 @@ pub enum FailurePolicy {
     Dlq,
 }
 
+/// Which of an observer's actions run when an event is replayed from storage
+///
+/// Replay re-delivers historical events (e.g. to backfill a new search index),
+/// so by default only idempotent, system-facing actions run: re-sending an
+/// order confirmation email for a week-old order is never what the operator
+/// wants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ReplayPolicy {
+    /// Run webhook, search, and cache actions; skip user-facing notifications
+    /// (email, Slack, SMS, push) (default)
+    #[default]
+    Auto,
+    /// Run every action on replay
+    Always,
+    /// Run no actions on replay
+    Never,
+}
+
+impl ReplayPolicy {
+    /// Whether `action` runs for a replayed event under this policy.
+    #[must_use]
+    pub const fn runs(self, action: &ActionConfig) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => !matches!(
+                action,
+                ActionConfig::Email { .. }
+                    | ActionConfig::Slack { .. }
+                    | ActionConfig::Teams { .. }
+                    | ActionConfig::Discord { .. }
+                    | ActionConfig::Sms { .. }
+                    | ActionConfig::Push { .. }
+            ),
+        }
+    }
+}
+
+/// How events of one entity are delivered to an observer when events are
+/// executed concurrently
+///
+/// Two updates to the same order arriving back to back must not reach a
+/// webhook as "paid" then "pending". Per-entity ordering hashes each entity to
+/// a serial lane: events of one entity run one after another in arrival order,
+/// while different entities still run in parallel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeliveryOrdering {
+    /// Events of one entity run in arrival order (default)
+    #[default]
+    PerEntity,
+    /// Events run as soon as a slot is free, in any order
+    Unordered,
+}
+
 // ============================================================================
 // Action Configuration
 // ============================================================================
@@ -384,20 +492,79 @@ pub enum ActionConfig {
         /// dispatch fails loud rather than sending an unsigned payload (#345).
         #[serde(default)]
         signing_secret_env: Option<String>,
+        /// Name of the HMAC signing secret in the server's secrets manager.
+        ///
+        /// Resolved through the configured `SecretsManager` (Vault, file, or
+        /// environment backend) on every dispatch, so a rotated secret is picked
+        /// up without reloading observers. Mutually exclusive with
+        /// `signing_secret` and `signing_secret_env`. Dispatch fails loud when no
+        /// secrets manager is configured or the secret is missing or empty.
+        #[serde(default)]
+        signing_secret_ref: Option<String>,
+        /// Identifier of the signing key, sent in
+        /// `X-FraiseQL-Signature-Key-Id` so receivers holding several secrets
+        /// can pick the right one. Requires a signing secret.
+        #[serde(default)]
+        signing_key_id:     Option<String>,
     },
 
-    /// Send message to Slack webhook
+    /// Send message to Slack, through an incoming webhook or the Web API
     Slack {
         /// Slack webhook URL
         webhook_url:      Option<String>,
         /// Environment variable containing webhook URL
         webhook_url_env:  Option<String>,
+        /// Environment variable containing a bot token; posts with
+        /// `chat.postMessage` instead of a webhook (requires `channel`)
+        #[serde(default)]
+        bot_token_env:    Option<String>,
         /// Channel to send to (if not in webhook URL)
         #[serde(default)]
         channel:          Option<String>,
-        /// Message template
+        /// Message template, sent as the notification text
+        #[serde(default)]
+        message_template: Option<String>,
+        /// Block Kit JSON template: a `blocks` array or `{"blocks": [...]}`
+        #[serde(default)]
+        blocks_template:  Option<String>,
+        /// Reply in one thread per entity (requires `bot_token_env`)
+        #[serde(default)]
+        thread_by_entity: bool,
+    },
+
+    /// Post an Adaptive Card to a Microsoft Teams incoming webhook
+    Teams {
+        /// Teams webhook URL
+        webhook_url:      Option<String>,
+        /// Environment variable containing webhook URL
+        webhook_url_env:  Option<String>,
+        /// Card title template
+        #[serde(default)]
+        title_template:   Option<String>,
+        /// Message template, shown below the title
+        #[serde(default)]
+        message_template: Option<String>,
+        /// Adaptive Card JSON template (`{"type": "AdaptiveCard", ...}`);
+        /// replaces the title/message card
+        #[serde(default)]
+        card_template:    Option<String>,
+    },
+
+    /// Post a message with embeds to a Discord webhook
+    Discord {
+        /// Discord webhook URL
+        webhook_url:      Option<String>,
+        /// Environment variable containing webhook URL
+        webhook_url_env:  Option<String>,
+        /// Name shown instead of the webhook's own
+        #[serde(default)]
+        username:         Option<String>,
+        /// Message template, sent as the message content
         #[serde(default)]
         message_template: Option<String>,
+        /// Embed JSON template: one embed object or an array of up to 10
+        #[serde(default)]
+        embed_template:   Option<String>,
     },
 
     /// Send email via SMTP
@@ -417,24 +584,45 @@ pub enum ActionConfig {
         reply_to:         Option<String>,
     },
 
-    /// Send SMS (stub for, full implementation later)
+    /// Send an SMS through Twilio or Vonage
     Sms {
-        /// Phone number to send to
+        /// Phone number to send to (E.164)
         phone:            Option<String>,
-        /// Template for phone number
+        /// Template rendering to the phone number
         phone_template:   Option<String>,
         /// Message template
         message_template: Option<String>,
+        /// SMS service and its credentials (see [`SmsProvider`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        provider:         Option<SmsProvider>,
+        /// Numbers the message is sent from, per destination country
+        #[serde(default, skip_serializing_if = "SmsSenderPool::is_empty")]
+        senders:          SmsSenderPool,
     },
 
-    /// Send push notification (stub for)
+    /// Send a push notification through FCM or APNs
     Push {
         /// Device token
-        device_token:   Option<String>,
+        device_token:          Option<String>,
+        /// Template rendering to whitespace-separated device tokens; a lone
+        /// `{{ field }}` naming an array of strings sends to each of them
+        #[serde(default)]
+        device_token_template: Option<String>,
         /// Title template
-        title_template: Option<String>,
+        title_template:        Option<String>,
         /// Body template
-        body_template:  Option<String>,
+        body_template:         Option<String>,
+        /// Template for the collapse key: a newer notification with the same
+        /// key replaces an undelivered older one
+        #[serde(default)]
+        collapse_key:          Option<String>,
+        /// Push service and its credentials (see [`PushProvider`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        provider:              Option<PushProvider>,
+        /// URL notified (`POST`) with each device token the provider reports as
+        /// unregistered or invalid, so the application can delete it
+        #[serde(default)]
+        invalid_token_webhook: Option<String>,
     },
 
     /// Update search index (stub for)
@@ -445,15 +633,45 @@ pub enum ActionConfig {
         id_template: Option<String>,
     },
 
-    /// Invalidate cache (stub for)
+    /// Invalidate cache (Redis application keys, or a CDN when `cdn` is set)
     Cache {
         /// Cache key pattern
         key_pattern: String,
         /// Action: "invalidate" or "refresh"
         action:      String,
+        /// Purge this CDN instead of Redis.
+        ///
+        /// `key_pattern` then renders to whitespace-separated surrogate keys or
+        /// cache tags, and purges from many events are coalesced into a few
+        /// provider calls (see [`CdnPurgeConfig`]).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cdn:         Option<CdnPurgeConfig>,
     },
 }
 
+/// Maximum length of a webhook `signing_key_id`.
+const MAX_SIGNING_KEY_ID_LEN: usize = 128;
+
+/// Longest `username` override Discord accepts, in characters.
+const MAX_DISCORD_USERNAME_CHARS: usize = 80;
+
+/// A key id travels verbatim in a request header, so it is restricted to
+/// visible ASCII and a bounded length.
+fn validate_signing_key_id(key_id: &str) -> Result<()> {
+    if key_id.is_empty()
+        || key_id.len() > MAX_SIGNING_KEY_ID_LEN
+        || !key_id.bytes().all(|b| b.is_ascii_graphic())
+    {
+        return Err(ObserverError::InvalidActionConfig {
+            reason: format!(
+                "Webhook signing_key_id must be 1-{MAX_SIGNING_KEY_ID_LEN} visible ASCII \
+                 characters"
+            ),
+        });
+    }
+    Ok(())
+}
+
 impl ActionConfig {
     /// Get the action type name
     #[must_use]
@@ -461,6 +679,8 @@ impl ActionConfig {
         match self {
             Self::Webhook { .. } => "webhook",
             Self::Slack { .. } => "slack",
+            Self::Teams { .. } => "teams",
+            Self::Discord { .. } => "discord",
             Self::Email { .. } => "email",
             Self::Sms { .. } => "sms",
             Self::Push { .. } => "push",
@@ -476,7 +696,7 @@ impl ActionConfig {
     /// Returns [`ObserverError::InvalidActionConfig`] if required fields such as
     /// `url`, `webhook_url`, or `to` are absent or empty for the given action variant,
     /// or [`ObserverError::UnsupportedActionType`] for action types with no wired
-    /// transport (`sms`, `push`, `search`, `cache`).
+    /// transport (`search`).
     pub fn validate(&self) -> Result<()> {
         match self {
             Self::Webhook {
@@ -485,6 +705,8 @@ impl ActionConfig {
                 body_template,
                 signing_secret,
                 signing_secret_env,
+                signing_secret_ref,
+                signing_key_id,
                 ..
             } => {
                 if url.is_none() && url_env.is_none() {
@@ -518,19 +740,137 @@ impl ActionConfig {
                             .to_string(),
                     });
                 }
+                if signing_secret_ref.as_ref().is_some_and(std::string::String::is_empty) {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Webhook signing_secret_ref cannot be empty (it is the secret \
+                                 NAME in the secrets manager, or omit it for unsigned delivery)"
+                            .to_string(),
+                    });
+                }
+                if signing_secret_ref.is_some()
+                    && (signing_secret.is_some() || signing_secret_env.is_some())
+                {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Webhook action sets 'signing_secret_ref' together with \
+                                 'signing_secret' or 'signing_secret_env'; set exactly one"
+                            .to_string(),
+                    });
+                }
+                if let Some(key_id) = signing_key_id {
+                    validate_signing_key_id(key_id)?;
+                    if signing_secret.is_none()
+                        && signing_secret_env.is_none()
+                        && signing_secret_ref.is_none()
+                    {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: "Webhook signing_key_id is set but no signing secret is \
+                                     configured"
+                                .to_string(),
+                        });
+                    }
+                }
                 Ok(())
             },
             Self::Slack {
                 webhook_url,
                 webhook_url_env,
+                bot_token_env,
+                channel,
+                blocks_template,
+                thread_by_entity,
+                ..
+            } => {
+                let has_webhook = webhook_url.is_some() || webhook_url_env.is_some();
+                let invalid = |reason: &str| {
+                    Err(ObserverError::InvalidActionConfig {
+                        reason: reason.to_string(),
+                    })
+                };
+                match bot_token_env {
+                    None if !has_webhook => {
+                        return invalid(
+                            "Slack action requires 'webhook_url', 'webhook_url_env' or \
+                             'bot_token_env'",
+                        );
+                    },
+                    Some(_) if has_webhook => {
+                        return invalid(
+                            "Slack action sets both a webhook and 'bot_token_env'; set exactly \
+                             one",
+                        );
+                    },
+                    Some(name)
+                        if name.is_empty()
+                            || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') =>
+                    {
+                        return invalid(
+                            "Slack action 'bot_token_env' must name an environment variable",
+                        );
+                    },
+                    Some(_) if channel.as_deref().is_none_or(str::is_empty) => {
+                        return invalid("Slack action with 'bot_token_env' requires 'channel'");
+                    },
+                    _ => {},
+                }
+                if *thread_by_entity && bot_token_env.is_none() {
+                    return invalid(
+                        "Slack 'thread_by_entity' requires 'bot_token_env': incoming webhooks do \
+                         not return the message ts a thread is keyed by",
+                    );
+                }
+                if blocks_template.as_deref().is_some_and(|t| t.trim().is_empty()) {
+                    return invalid("Slack action 'blocks_template' must not be empty");
+                }
+                Ok(())
+            },
+            Self::Teams {
+                webhook_url,
+                webhook_url_env,
+                card_template,
+                ..
+            } => {
+                if webhook_url.is_none() && webhook_url_env.is_none() {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Teams action requires 'webhook_url' or 'webhook_url_env'"
+                            .to_string(),
+                    });
+                }
+                if card_template.as_deref().is_some_and(|t| t.trim().is_empty()) {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Teams action 'card_template' must not be empty".to_string(),
+                    });
+                }
+                Ok(())
+            },
+            Self::Discord {
+                webhook_url,
+                webhook_url_env,
+                username,
+                embed_template,
                 ..
             } => {
                 if webhook_url.is_none() && webhook_url_env.is_none() {
                     return Err(ObserverError::InvalidActionConfig {
-                        reason: "Slack action requires 'webhook_url' or 'webhook_url_env'"
+                        reason: "Discord action requires 'webhook_url' or 'webhook_url_env'"
                             .to_string(),
                     });
                 }
+                if let Some(name) = username {
+                    let chars = name.trim().chars().count();
+                    if chars == 0 || chars > MAX_DISCORD_USERNAME_CHARS {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: format!(
+                                "Discord action 'username' must be 1-{MAX_DISCORD_USERNAME_CHARS} \
+                                 characters"
+                            ),
+                        });
+                    }
+                }
+                if embed_template.as_deref().is_some_and(|t| t.trim().is_empty()) {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Discord action 'embed_template' must not be empty".to_string(),
+                    });
+                }
                 Ok(())
             },
             Self::Email {
@@ -557,14 +897,16 @@ impl ActionConfig {
                 }
                 Ok(())
             },
-            // Cache invalidation has a real Redis transport (#428). Validate it
-            // structurally here (non-empty pattern, supported sub-action); the
-            // transport's availability is enforced at dispatch, exactly like the
-            // email action with no SMTP backend. `"refresh"` is not implemented
-            // yet, so it fails loud at config-load.
+            // Cache invalidation has a real Redis transport (#428) and CDN purge
+            // transports. Validate it structurally here (non-empty pattern,
+            // supported sub-action, well-formed CDN target); the transport's
+            // availability is enforced at dispatch, exactly like the email
+            // action with no SMTP backend. `"refresh"` is not implemented yet, so
+            // it fails loud at config-load.
             Self::Cache {
                 key_pattern,
                 action,
+                cdn,
             } => {
                 if key_pattern.is_empty() {
                     return Err(ObserverError::InvalidActionConfig {
@@ -579,18 +921,87 @@ impl ActionConfig {
                         ),
                     });
                 }
-                Ok(())
+                cdn.as_ref().map_or(Ok(()), CdnPurgeConfig::validate)
+            },
+            Self::Push {
+                device_token,
+                device_token_template,
+                title_template,
+                body_template,
+                provider,
+                invalid_token_webhook,
+                ..
+            } => {
+                let Some(provider) = provider else {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Push action requires a 'provider' (fcm or apns)".to_string(),
+                    });
+                };
+                if device_token.is_none() && device_token_template.is_none() {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Push action requires 'device_token' or 'device_token_template'"
+                            .to_string(),
+                    });
+                }
+                if title_template.is_none() && body_template.is_none() {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "Push action requires 'title_template' or 'body_template'"
+                            .to_string(),
+                    });
+                }
+                if let Some(url) = invalid_token_webhook {
+                    let lower = url.to_ascii_lowercase();
+                    if !lower.starts_with("http://") && !lower.starts_with("https://") {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: format!(
+                                "Push action invalid_token_webhook must be an http:// or \
+                                 https:// URL: {url:?}"
+                            ),
+                        });
+                    }
+                }
+                provider.validate()
             },
-            // Not implemented: no real transport is wired for these action types.
-            // They previously fabricated `success: true` at dispatch and sent
-            // nothing (H24). Reject them at config-load time so a misconfigured
-            // observer refuses to start rather than silently no-op. Real
-            // transports are tracked as follow-up work.
-            Self::Sms { .. } | Self::Push { .. } | Self::Search { .. } => {
-                Err(ObserverError::UnsupportedActionType {
-                    action_type: self.action_type().to_string(),
-                })
+            Self::Sms {
+                phone,
+                phone_template,
+                message_template,
+                provider,
+                senders,
+            } => {
+                let Some(provider) = provider else {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "SMS action requires a 'provider' (twilio or vonage)".to_string(),
+                    });
+                };
+                match phone {
+                    Some(phone) if normalize_e164(phone).is_none() => {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: format!("SMS action phone {phone:?} is not an E.164 number"),
+                        });
+                    },
+                    None if phone_template.is_none() => {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: "SMS action requires 'phone' or 'phone_template'".to_string(),
+                        });
+                    },
+                    _ => {},
+                }
+                if message_template.as_deref().is_none_or(str::is_empty) {
+                    return Err(ObserverError::InvalidActionConfig {
+                        reason: "SMS action requires a non-empty 'message_template'".to_string(),
+                    });
+                }
+                provider.validate()?;
+                senders.validate()
             },
+            // Not implemented: no real transport is wired for search. It
+            // previously fabricated `success: true` at dispatch and sent nothing
+            // (H24). Reject it at config-load time so a misconfigured observer
+            // refuses to start rather than silently no-op.
+            Self::Search { .. } => Err(ObserverError::UnsupportedActionType {
+                action_type: self.action_type().to_string(),
+            }),
         }
     }
 }
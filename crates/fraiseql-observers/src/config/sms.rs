@@ -0,0 +1,272 @@
+//! SMS provider and sender-number pool for the `sms` observer action.
+//!
+//! An `sms` action sends one text message through Twilio (Programmable
+//! Messaging) or Vonage (SMS API). The `From` number is picked from a sender
+//! pool, per destination country when the pool has numbers for it:
+//!
+//! ```toml
+//! [[observers.actions]]
+//! type             = "sms"
+//! phone_template   = "{{ phone }}"
+//! message_template = "Your order {{ id }} has shipped."
+//!
+//! [observers.actions.provider]
+//! kind                = "twilio"                      # twilio | vonage
+//! account_sid         = "AC0123456789abcdef0123456789abcdef"
+//! auth_token_env      = "TWILIO_AUTH_TOKEN"           # env var NAME
+//! status_callback_url = "https://api.example.com/webhooks/twilio"
+//!
+//! [observers.actions.senders]
+//! default = ["+15005550006", "+15005550007"]
+//!
+//! [observers.actions.senders.by_country]
+//! "44" = ["+447700900123"]                            # E.164 calling code
+//! ```
+//!
+//! Credentials are supplied via environment-variable *names*, never as
+//! literals, because action configs are stored in `tb_observer.actions` and
+//! echoed by the admin API.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ObserverError, Result};
+
+/// Longest alphanumeric sender id accepted by carriers.
+const MAX_ALPHANUMERIC_SENDER_LEN: usize = 11;
+
+/// An SMS service and its credentials.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SmsProvider {
+    /// Twilio Programmable Messaging
+    /// (`POST /2010-04-01/Accounts/{sid}/Messages.json`).
+    Twilio {
+        /// Account SID (`AC…`).
+        account_sid:         String,
+        /// Name of the environment variable holding the auth token.
+        auth_token_env:      String,
+        /// URL Twilio posts delivery-status callbacks to.
+        #[serde(default)]
+        status_callback_url: Option<String>,
+    },
+    /// Vonage SMS API (`POST /sms/json`).
+    Vonage {
+        /// API key.
+        api_key:             String,
+        /// Name of the environment variable holding the API secret.
+        api_secret_env:      String,
+        /// URL Vonage posts delivery receipts to. Without it, receipts go to
+        /// the URL set on the Vonage account.
+        #[serde(default)]
+        status_callback_url: Option<String>,
+    },
+}
+
+impl SmsProvider {
+    /// Provider name as written in config (`twilio`, `vonage`).
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Twilio { .. } => "twilio",
+            Self::Vonage { .. } => "vonage",
+        }
+    }
+
+    /// URL the provider posts delivery-status callbacks to, if configured.
+    #[must_use]
+    pub fn status_callback_url(&self) -> Option<&str> {
+        match self {
+            Self::Twilio {
+                status_callback_url,
+                ..
+            }
+            | Self::Vonage {
+                status_callback_url,
+                ..
+            } => status_callback_url.as_deref(),
+        }
+    }
+
+    /// Validate the configuration structurally.
+    ///
+    /// Credentials are not resolved here; a missing env var fails loud when
+    /// the sender is first built at dispatch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if an account id or env
+    /// var name is empty or malformed, or the callback URL is not http(s).
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Twilio {
+                account_sid,
+                auth_token_env,
+                ..
+            } => {
+                if !account_sid.starts_with("AC")
+                    || account_sid.len() != 34
+                    || !account_sid[2..].bytes().all(|b| b.is_ascii_hexdigit())
+                {
+                    return invalid(format!(
+                        "SMS action provider.account_sid {account_sid:?} is not a Twilio account \
+                         SID (AC followed by 32 hex digits)"
+                    ));
+                }
+                validate_env_name("auth_token_env", auth_token_env)?;
+            },
+            Self::Vonage {
+                api_key,
+                api_secret_env,
+                ..
+            } => {
+                if api_key.is_empty() || !api_key.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                    return invalid(
+                        "SMS action provider.api_key must be a non-empty alphanumeric Vonage key"
+                            .to_string(),
+                    );
+                }
+                validate_env_name("api_secret_env", api_secret_env)?;
+            },
+        }
+        if let Some(url) = self.status_callback_url() {
+            let lower = url.to_ascii_lowercase();
+            if !lower.starts_with("http://") && !lower.starts_with("https://") {
+                return invalid(format!(
+                    "SMS action provider.status_callback_url must be an http:// or https:// URL: \
+                     {url:?}"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The numbers (or alphanumeric sender ids) an `sms` action sends from.
+///
+/// A recipient whose number starts with a calling code listed in
+/// `by_country` is sent from that country's pool (longest code wins, so `1242`
+/// beats `1`); everyone else from `default`. Within a pool the sender is chosen
+/// by a stable hash of the recipient, so a recipient always hears from the same
+/// number and replies land in one conversation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SmsSenderPool {
+    /// Senders for recipients in countries without a dedicated pool.
+    #[serde(default)]
+    pub default:    Vec<String>,
+    /// Senders per E.164 country calling code (`"1"`, `"44"`, `"1242"`).
+    #[serde(default)]
+    pub by_country: BTreeMap<String, Vec<String>>,
+}
+
+impl SmsSenderPool {
+    /// Whether no sender is configured at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.default.is_empty() && self.by_country.values().all(Vec::is_empty)
+    }
+
+    /// The sender for `recipient`, an E.164 number (`+` and digits).
+    ///
+    /// Returns `None` when neither the recipient's country nor the default
+    /// pool has a sender.
+    #[must_use]
+    pub fn select(&self, recipient: &str) -> Option<&str> {
+        let digits = recipient.strip_prefix('+').unwrap_or(recipient);
+        let pool = self
+            .by_country
+            .iter()
+            .filter(|(code, senders)| !senders.is_empty() && digits.starts_with(code.as_str()))
+            .max_by_key(|(code, _)| code.len())
+            .map_or(&self.default, |(_, senders)| senders);
+        if pool.is_empty() {
+            return None;
+        }
+        // FNV-1a: stable across processes and releases, unlike `DefaultHasher`.
+        let hash = digits.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+        let index = usize::try_from(hash % pool.len() as u64).unwrap_or(0);
+        pool.get(index).map(String::as_str)
+    }
+
+    /// Validate the pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidActionConfig`] if the pool is empty, a
+    /// country key is not a 1-4 digit calling code, or a sender is neither an
+    /// E.164 number nor an alphanumeric sender id of at most 11 characters.
+    pub fn validate(&self) -> Result<()> {
+        if self.is_empty() {
+            return invalid(
+                "SMS action requires at least one sender in 'senders.default' or \
+                 'senders.by_country'"
+                    .to_string(),
+            );
+        }
+        for code in self.by_country.keys() {
+            if code.is_empty() || code.len() > 4 || !code.bytes().all(|b| b.is_ascii_digit()) {
+                return invalid(format!(
+                    "SMS action senders.by_country key {code:?} is not an E.164 country calling \
+                     code"
+                ));
+            }
+        }
+        for sender in self.default.iter().chain(self.by_country.values().flatten()) {
+            if !is_valid_sender(sender) {
+                return invalid(format!(
+                    "SMS action sender {sender:?} is neither an E.164 number nor an alphanumeric \
+                     sender id of at most {MAX_ALPHANUMERIC_SENDER_LEN} characters"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Normalize `phone` to E.164 (`+` and 7-15 digits), dropping spaces, dashes,
+/// dots and parentheses.
+#[must_use]
+pub fn normalize_e164(phone: &str) -> Option<String> {
+    let phone = phone.trim();
+    let digits = phone.strip_prefix('+')?;
+    if digits
+        .bytes()
+        .any(|b| !b.is_ascii_digit() && !matches!(b, b' ' | b'-' | b'.' | b'(' | b')'))
+    {
+        return None;
+    }
+    let digits: String = digits.chars().filter(char::is_ascii_digit).collect();
+    if !(7..=15).contains(&digits.len()) || digits.starts_with('0') {
+        return None;
+    }
+    Some(format!("+{digits}"))
+}
+
+fn is_valid_sender(sender: &str) -> bool {
+    if sender.starts_with('+') {
+        return normalize_e164(sender).is_some_and(|n| n == sender);
+    }
+    // Alphanumeric sender ids must contain a letter, or carriers read them as
+    // a (malformed) number.
+    !sender.is_empty()
+        && sender.len() <= MAX_ALPHANUMERIC_SENDER_LEN
+        && sender.bytes().all(|b| b.is_ascii_alphanumeric() || b == b' ')
+        && sender.bytes().any(|b| b.is_ascii_alphabetic())
+}
+
+fn validate_env_name(field: &str, name: &str) -> Result<()> {
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return invalid(format!(
+            "SMS action provider.{field} {name:?} must name an environment variable"
+        ));
+    }
+    Ok(())
+}
+
+const fn invalid(reason: String) -> Result<()> {
+    Err(ObserverError::InvalidActionConfig { reason })
+}
@@ -34,13 +34,125 @@
 //! - Configurable per deduplication store
 //! - TTL automatically expires old dedup keys
 //! - Zero manual cleanup needed
+//!
+//! # Strategies
+//!
+//! [`DedupStrategy`] decides what counts as "the same event":
+//!
+//! - `event_id` (default): the transport-level event id — catches redelivery of one message.
+//! - `entity_event`: entity id + event kind within a window — catches a burst of updates to one
+//!   entity.
+//! - `content_hash`: a hash of the entity's post-change data (`after`) — catches a replayed
+//!   change-log entry, which arrives with a fresh event id but identical content.
+//!
+//! A strategy is set globally with `performance.dedup_strategy` and can be overridden per
+//! observer with `dedup`, so an email observer can dedup by content while a search indexer
+//! keeps the default.
 
 #[cfg(feature = "dedup")]
 pub mod redis;
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Longest window a [`DedupStrategy`] may configure (24 hours).
+pub const MAX_DEDUP_WINDOW_SECS: u64 = 86_400;
+
+/// What makes two events duplicates of each other.
+///
+/// Configured as an inline table tagged by `strategy`:
+///
+/// ```toml
+/// [performance]
+/// enable_dedup = true
+/// dedup_strategy = { strategy = "content_hash", window_secs = 3600 }
+/// ```
+///
+/// `window_secs` overrides the store's default window for keys built by that
+/// strategy; `event_id` always uses the store's window.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DedupStrategy {
+    /// Dedup on the event's id (default)
+    #[default]
+    EventId,
+    /// Dedup on entity type, entity id, and event kind within the window
+    EntityEvent {
+        /// Window in seconds (`None` = the store's window)
+        #[serde(default)]
+        window_secs: Option<u64>,
+    },
+    /// Dedup on entity type, entity id, event kind, and a SHA-256 hash of the
+    /// entity's post-change data within the window
+    ContentHash {
+        /// Window in seconds (`None` = the store's window)
+        #[serde(default)]
+        window_secs: Option<u64>,
+    },
+}
+
+impl DedupStrategy {
+    /// The dedup key for `event` under this strategy.
+    ///
+    /// Content hashes are stable across replays: `serde_json` serializes
+    /// object keys in sorted order, so the same data always hashes the same.
+    #[must_use]
+    pub fn key(&self, event: &EntityEvent) -> String {
+        match self {
+            Self::EventId => format!("event:{}", event.id),
+            Self::EntityEvent { .. } => format!(
+                "entity:{}:{}:{}",
+                event.entity_type,
+                event.entity_id,
+                event.event_type.as_str()
+            ),
+            Self::ContentHash { .. } => {
+                let data = serde_json::to_vec(&event.data).unwrap_or_default();
+                format!(
+                    "content:{}:{}:{}:{}",
+                    event.entity_type,
+                    event.entity_id,
+                    event.event_type.as_str(),
+                    hex::encode(Sha256::digest(data))
+                )
+            },
+        }
+    }
 
-use crate::error::Result;
+    /// The window this strategy configures, if it overrides the store's.
+    #[must_use]
+    pub const fn window_secs(&self) -> Option<u64> {
+        match self {
+            Self::EventId => None,
+            Self::EntityEvent { window_secs } | Self::ContentHash { window_secs } => *window_secs,
+        }
+    }
+
+    /// Validate the configured window.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] if `window_secs` is 0 or longer
+    /// than [`MAX_DEDUP_WINDOW_SECS`].
+    pub fn validate(&self) -> Result<()> {
+        match self.window_secs() {
+            Some(secs) if !(1..=MAX_DEDUP_WINDOW_SECS).contains(&secs) => {
+                Err(ObserverError::InvalidConfig {
+                    message: format!(
+                        "dedup window_secs must be between 1 and {MAX_DEDUP_WINDOW_SECS}, got {secs}"
+                    ),
+                })
+            },
+            _ => Ok(()),
+        }
+    }
+}
 
 /// Deduplication store abstraction.
 ///
@@ -74,6 +186,19 @@ pub trait DeduplicationStore: Send + Sync + Clone {
     /// dropping events.
     async fn claim_event(&self, event_key: &str) -> Result<bool>;
 
+    /// Atomically claim a key for an explicit window instead of the store's.
+    ///
+    /// Used by [`DedupStrategy`] variants that configure their own window.
+    /// The default implementation ignores `window_seconds` and delegates to
+    /// [`Self::claim_event`]; stores that support per-key TTLs override it.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::claim_event`].
+    async fn claim_event_for(&self, event_key: &str, _window_seconds: u64) -> Result<bool> {
+        self.claim_event(event_key).await
+    }
+
     /// Check if event was recently processed (is duplicate).
     ///
     /// **Prefer [`Self::claim_event`]** for new code — this method is provided
@@ -54,6 +54,10 @@ impl DeduplicationStore for RedisDeduplicationStore {
     /// Redis `SET NX` is atomic: only one concurrent caller will receive `OK`;
     /// all others receive `nil` and must skip the event.
     async fn claim_event(&self, event_key: &str) -> Result<bool> {
+        self.claim_event_for(event_key, self.window_seconds).await
+    }
+
+    async fn claim_event_for(&self, event_key: &str, window_seconds: u64) -> Result<bool> {
         let key = Self::dedup_key(event_key);
         // SET key "1" NX EX <ttl> → "OK" if we claimed it, nil if already claimed.
         let result: Option<String> = redis::cmd("SET")
@@ -61,7 +65,7 @@ impl DeduplicationStore for RedisDeduplicationStore {
             .arg("1")
             .arg("NX")
             .arg("EX")
-            .arg(self.window_seconds.cast_signed())
+            .arg(window_seconds.cast_signed())
             .query_async(&mut self.conn.clone())
             .await?;
         Ok(result.is_some())
@@ -203,6 +203,73 @@ mod dedup_tests {
     }
 }
 
+mod strategy_tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::{
+        dedup::*,
+        event::{EntityEvent, EventKind},
+    };
+
+    fn event(kind: EventKind, entity_id: Uuid, data: serde_json::Value) -> EntityEvent {
+        EntityEvent::new(kind, "Order".to_string(), entity_id, data)
+    }
+
+    #[test]
+    fn test_event_id_key_differs_per_event() {
+        let id = Uuid::new_v4();
+        let a = event(EventKind::Created, id, json!({}));
+        let b = event(EventKind::Created, id, json!({}));
+        assert_eq!(DedupStrategy::EventId.key(&a), format!("event:{}", a.id));
+        assert_ne!(DedupStrategy::EventId.key(&a), DedupStrategy::EventId.key(&b));
+    }
+
+    #[test]
+    fn test_entity_event_key_ignores_event_id_and_data() {
+        let strategy = DedupStrategy::EntityEvent { window_secs: None };
+        let id = Uuid::new_v4();
+        let a = event(EventKind::Updated, id, json!({"status": "paid"}));
+        let b = event(EventKind::Updated, id, json!({"status": "shipped"}));
+        assert_eq!(strategy.key(&a), strategy.key(&b));
+        assert_eq!(strategy.key(&a), format!("entity:Order:{id}:UPDATE"));
+        assert_ne!(strategy.key(&a), strategy.key(&event(EventKind::Deleted, id, json!({}))));
+    }
+
+    #[test]
+    fn test_content_hash_key_is_stable_across_key_order() {
+        let strategy = DedupStrategy::ContentHash { window_secs: None };
+        let id = Uuid::new_v4();
+        let a = event(EventKind::Updated, id, json!({"status": "paid", "total": 10}));
+        let b = event(
+            EventKind::Updated,
+            id,
+            serde_json::from_str(r#"{"total": 10, "status": "paid"}"#).unwrap(),
+        );
+        let c = event(EventKind::Updated, id, json!({"status": "paid", "total": 11}));
+        assert_eq!(strategy.key(&a), strategy.key(&b));
+        assert_ne!(strategy.key(&a), strategy.key(&c));
+        assert!(strategy.key(&a).starts_with(&format!("content:Order:{id}:UPDATE:")));
+    }
+
+    #[test]
+    fn test_strategy_window_validation() {
+        assert_eq!(DedupStrategy::default(), DedupStrategy::EventId);
+        assert!(DedupStrategy::EventId.validate().is_ok());
+        for (secs, ok) in [
+            (None, true),
+            (Some(1), true),
+            (Some(MAX_DEDUP_WINDOW_SECS), true),
+            (Some(0), false),
+            (Some(MAX_DEDUP_WINDOW_SECS + 1), false),
+        ] {
+            let strategy = DedupStrategy::ContentHash { window_secs: secs };
+            assert_eq!(strategy.validate().is_ok(), ok, "{secs:?}");
+            assert_eq!(strategy.window_secs(), secs);
+        }
+    }
+}
+
 #[cfg(feature = "dedup")]
 mod redis_tests {
     use crate::dedup::redis::*;
@@ -26,10 +26,11 @@
 //! If VIOLATION → push raw bytes to DLQ, increment counter, return early
 //! If PASS      → continue
 //!     ↓
-//! Check Redis: is event.id processed? (UUIDv4)
+//! Claim the strategy's key (event.id by default), plus one key per observer
+//! that overrides the strategy
 //!     ↓
-//! If YES → Skip (return early with duplicate_skipped=true)
-//! If NO  → Process event
+//! If nothing claimed → Skip (return early with duplicate_skipped=true)
+//! Otherwise          → Process event through the observers that claimed it
 //!     ↓
 //! If all actions succeeded → Mark event.id as processed (TTL = 5 min)
 //! If any action failed → Don't mark (allow retry)
@@ -63,7 +64,7 @@
 //! }
 //! ```
 
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use tracing::{debug, error, warn};
 
@@ -102,7 +103,7 @@ pub enum TenantScope {
 }
 
 #[cfg(feature = "dedup")]
-use crate::dedup::DeduplicationStore;
+use crate::dedup::{DedupStrategy, DeduplicationStore};
 #[cfg(feature = "metrics")]
 use crate::metrics::MetricsRegistry;
 use crate::{
@@ -122,7 +123,8 @@ use crate::{
 /// # Key Decisions
 ///
 /// - **Tenant check**: Before dedup — violated events never touch the dedup store
-/// - **Dedup key**: event.id (`UUIDv4`, globally unique across all transports)
+/// - **Dedup key**: built by the [`DedupStrategy`] — event.id by default; observers with their own
+///   `dedup` strategy claim a separate, observer-scoped key
 /// - **Check timing**: Before processing (early return for duplicates)
 /// - **Mark timing**: After successful processing (only if all actions succeeded)
 /// - **TTL**: Configurable window (default 5 minutes)
@@ -142,6 +144,8 @@ pub struct DedupedObserverExecutor<D: DeduplicationStore> {
     dedup_store:  D,
     /// Tenant scope policy applied before dedup check
     tenant_scope: TenantScope,
+    /// Strategy for observers without their own `dedup` override
+    strategy:     DedupStrategy,
     /// Prometheus metrics registry
     #[cfg(feature = "metrics")]
     metrics:      MetricsRegistry,
@@ -201,11 +205,38 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
             inner: Arc::new(executor),
             dedup_store,
             tenant_scope,
+            strategy: DedupStrategy::default(),
             #[cfg(feature = "metrics")]
             metrics: MetricsRegistry::global().unwrap_or_default(),
         }
     }
 
+    /// Set the dedup strategy for observers without their own `dedup`
+    /// override (default: [`DedupStrategy::EventId`]).
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: DedupStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Claim `key` for the window `strategy` configures.
+    ///
+    /// Fails open: if the store is unavailable the key counts as claimed so the
+    /// event is processed rather than dropped.
+    async fn claim(&self, key: &str, strategy: &DedupStrategy, event: &EntityEvent) -> bool {
+        let result = match strategy.window_secs() {
+            Some(window) => self.dedup_store.claim_event_for(key, window).await,
+            None => self.dedup_store.claim_event(key).await,
+        };
+        result.unwrap_or_else(|e| {
+            warn!(
+                "Dedup claim failed for event {}: {}. Processing anyway (fail-open).",
+                event.id, e
+            );
+            true
+        })
+    }
+
     /// Returns `true` if `event.tenant_id` satisfies the configured [`TenantScope`].
     fn tenant_allowed(&self, event_tenant: Option<&str>) -> bool {
         match &self.tenant_scope {
@@ -233,11 +264,12 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
     /// 1. Validate `event.tenant_id` against the configured [`TenantScope`]
     ///    - Violation → serialize event, push raw bytes to DLQ, increment metric, return
     ///      `Ok(summary { tenant_rejected: true })`
-    /// 2. Generate dedup key from `event.id` (`UUIDv4`)
+    /// 2. Build the dedup key with the configured [`DedupStrategy`] (`event.id` by default)
     /// 3. `claim_event()` — atomic `SET NX EX`: only one worker wins the claim
-    /// 4. If not claimed (duplicate) → return early with `duplicate_skipped=true`
-    /// 5. If claimed → process event
-    /// 6. If processing failed → `remove()` the claim key so the event can be retried
+    /// 4. Each matching observer with its own `dedup` strategy claims its own key
+    /// 5. If nothing was claimed (duplicate) → return early with `duplicate_skipped=true`
+    /// 6. Otherwise → process event through the observers whose claim succeeded
+    /// 7. If processing failed → `remove()` the claimed keys so the event can be retried
     ///
     /// Using a single atomic claim eliminates the race condition that existed when
     /// `is_duplicate` and `mark_processed` were called as separate operations.
@@ -246,6 +278,7 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
     ///
     /// Returns error if the inner executor fails. A failed `claim_event` causes
     /// fail-open behaviour (event is processed anyway) to avoid silent event loss.
+    #[allow(clippy::cognitive_complexity)] // Reason: tenant check, per-observer claims, and un-claim on failure form one flow
     pub async fn process_event(&self, event: &EntityEvent) -> Result<ExecutionSummary> {
         // --- Tenant boundary check (must happen before dedup claim) ---
         if !self.tenant_allowed(event.tenant_id.as_deref()) {
@@ -284,25 +317,40 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
             });
         }
 
-        let event_key = format!("event:{}", event.id);
+        let event_key = self.strategy.key(event);
+        let claimed = self.claim(&event_key, &self.strategy, event).await;
 
-        // Atomically claim the event; fail-open if the store is unavailable.
-        let claimed = match self.dedup_store.claim_event(&event_key).await {
-            Ok(claimed) => claimed,
-            Err(e) => {
-                warn!(
-                    "Dedup claim failed for event {}: {}. Processing anyway (fail-open).",
-                    event.id, e
-                );
-                true // treat as claimed so we proceed
-            },
-        };
+        // Observers with their own strategy claim an observer-scoped key, so a
+        // replayed change-log entry can be skipped by an email observer deduping
+        // on content while an indexer deduping on event id still runs.
+        let mut claimed_keys = Vec::new();
+        if claimed {
+            claimed_keys.push(event_key);
+        }
+        let mut run = HashSet::new();
+        let mut overridden = false;
+        for (name, observer) in self.inner.matching_observers(event) {
+            let Some(strategy) = &observer.dedup else {
+                if claimed {
+                    run.insert(name);
+                }
+                continue;
+            };
+            overridden = true;
+            let key = format!("observer:{name}:{}", strategy.key(event));
+            if self.claim(&key, strategy, event).await {
+                run.insert(name);
+                claimed_keys.push(key);
+            } else {
+                debug!("Event {} already claimed for observer {}, skipping it", event.id, name);
+            }
+        }
 
-        if !claimed {
+        if claimed_keys.is_empty() {
             debug!(
                 "Event {} already claimed (within {}-second window), skipping",
                 event.id,
-                self.dedup_store.window_seconds()
+                self.strategy.window_secs().unwrap_or_else(|| self.dedup_store.window_seconds())
             );
 
             #[cfg(feature = "metrics")]
@@ -320,15 +368,22 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
                 errors:             Vec::new(),
                 duplicate_skipped:  true,
                 tenant_rejected:    false,
+                quarantined:        false,
                 cache_hits:         0,
                 cache_misses:       0,
+                replay_skipped:     0,
+                dry_run_captured:   0,
                 // A deduplicated event runs no actions (#468).
                 action_details:     Vec::new(),
             });
         }
 
         debug!("Event {} claimed, processing", event.id);
-        let summary = self.inner.process_event(event).await?;
+        let summary = if overridden {
+            self.inner.process_event_for(event, &run).await?
+        } else {
+            self.inner.process_event(event).await?
+        };
 
         // Un-claim on failure so the event can be retried.
         if summary.failed_actions > 0 || summary.dlq_errors > 0 {
@@ -336,8 +391,10 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
                 "Event {} had {} failed actions and {} DLQ errors — un-claiming for retry.",
                 event.id, summary.failed_actions, summary.dlq_errors
             );
-            if let Err(e) = self.dedup_store.remove(&event_key).await {
-                warn!("Failed to un-claim event {} after failure: {}", event.id, e);
+            for key in &claimed_keys {
+                if let Err(e) = self.dedup_store.remove(key).await {
+                    warn!("Failed to un-claim event {} after failure: {}", event.id, e);
+                }
             }
         }
 
@@ -359,6 +416,11 @@ impl<D: DeduplicationStore> DedupedObserverExecutor<D> {
         self.dedup_store.window_seconds()
     }
 
+    /// Get the dedup strategy for observers without their own override.
+    pub const fn strategy(&self) -> &DedupStrategy {
+        &self.strategy
+    }
+
     /// Get a reference to the configured tenant scope.
     pub const fn tenant_scope(&self) -> &TenantScope {
         &self.tenant_scope
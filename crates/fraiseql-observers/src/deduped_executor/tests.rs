@@ -320,3 +320,103 @@ fn test_tenant_scope_accessor() {
     let deduped = make_deduped(TenantScope::Single("acme".to_string()));
     assert!(matches!(deduped.tenant_scope(), TenantScope::Single(s) if s == "acme"));
 }
+
+// -------------------------------------------------------------------
+// Dedup strategies
+// -------------------------------------------------------------------
+
+fn webhook_observer(dedup: Option<DedupStrategy>) -> crate::config::ObserverDefinition {
+    use crate::config::{
+        ActionConfig, DeliveryOrdering, FailurePolicy, ObserverDefinition, ReplayPolicy,
+        RetryConfig,
+    };
+
+    ObserverDefinition {
+        event_type: "INSERT".to_string(),
+        entity: "Order".to_string(),
+        condition: None,
+        actions: vec![ActionConfig::Webhook {
+            url:                Some("https://example.com/hook".to_string()),
+            url_env:            None,
+            method:             None,
+            headers:            std::collections::HashMap::new(),
+            body_template:      None,
+            signing_secret:     None,
+            signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
+        }],
+        retry: RetryConfig {
+            max_attempts: 1,
+            initial_delay_ms: 0,
+            ..RetryConfig::default()
+        },
+        on_failure: FailurePolicy::Log,
+        replay: ReplayPolicy::default(),
+        ordering: DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup,
+        dry_run: false,
+    }
+}
+
+/// A replayed change-log entry: a fresh event id carrying the same change.
+fn replayed(event: &EntityEvent) -> EntityEvent {
+    EntityEvent::new(
+        event.event_type,
+        event.entity_type.clone(),
+        event.entity_id,
+        event.data.clone(),
+    )
+}
+
+#[tokio::test]
+async fn test_content_hash_strategy_skips_replayed_entry() {
+    let deduped = DedupedObserverExecutor::new(make_executor(), InMemoryDedupStore::new(300))
+        .with_strategy(DedupStrategy::ContentHash { window_secs: None });
+
+    let event =
+        EntityEvent::new(EventKind::Created, "Order".to_string(), Uuid::new_v4(), json!({"n": 1}));
+    assert!(!deduped.process_event(&event).await.unwrap().duplicate_skipped);
+    assert!(deduped.process_event(&replayed(&event)).await.unwrap().duplicate_skipped);
+
+    // A different change to the same entity is not a duplicate.
+    let mut changed = replayed(&event);
+    changed.data = json!({"n": 2});
+    assert!(!deduped.process_event(&changed).await.unwrap().duplicate_skipped);
+}
+
+#[tokio::test]
+async fn test_observer_override_dedups_independently() {
+    let dispatcher = Arc::new(crate::testing::mocks::MockActionDispatcher::new());
+    dispatcher.expect_ok("webhook", 1.0);
+    let mut observers = std::collections::HashMap::new();
+    observers.insert(
+        "email".to_string(),
+        webhook_observer(Some(DedupStrategy::ContentHash {
+            window_secs: Some(3600),
+        })),
+    );
+    observers.insert("index".to_string(), webhook_observer(None));
+    let executor = ObserverExecutor::with_dispatcher(
+        EventMatcher::build(observers).unwrap(),
+        Arc::new(MockDeadLetterQueue::new()),
+        Arc::clone(&dispatcher),
+    );
+    let deduped = DedupedObserverExecutor::new(executor, InMemoryDedupStore::new(300));
+
+    let event =
+        EntityEvent::new(EventKind::Created, "Order".to_string(), Uuid::new_v4(), json!({"n": 1}));
+    let first = deduped.process_event(&event).await.unwrap();
+    assert_eq!(first.successful_actions, 2);
+
+    // The replay has a new event id, so only the content-hash observer skips it.
+    let replay = deduped.process_event(&replayed(&event)).await.unwrap();
+    assert!(!replay.duplicate_skipped);
+    assert_eq!(replay.successful_actions, 1);
+    assert_eq!(dispatcher.call_count(), 3);
+
+    // Redelivery of the original event is skipped by both.
+    assert!(deduped.process_event(&event).await.unwrap().duplicate_skipped);
+    assert_eq!(dispatcher.call_count(), 3);
+}
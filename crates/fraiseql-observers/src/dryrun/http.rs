@@ -0,0 +1,61 @@
+//! Dry-run sink POSTing each capture to an HTTP endpoint.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use super::{DryRunCapture, DryRunSink};
+use crate::{
+    actions::{DEFAULT_WEBHOOK_TIMEOUT_SECS, validate_outbound_url},
+    error::{ObserverError, Result},
+};
+
+/// A [`DryRunSink`] POSTing every [`DryRunCapture`] as JSON to `url`.
+///
+/// The endpoint is held to the same SSRF rules as a webhook action.
+pub struct HttpDryRunSink {
+    client: Client,
+    url:    String,
+}
+
+impl HttpDryRunSink {
+    /// Create a sink posting to `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::ActionPermanentlyFailed` if `url` is not an
+    /// HTTP(S) URL or targets a blocked address range.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let url = url.into();
+        validate_outbound_url(&url)?;
+        let client = Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_WEBHOOK_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::error!(error = %e, "Failed to build HTTP client for HttpDryRunSink; falling back to no-timeout client");
+                Client::default()
+            });
+        Ok(Self { client, url })
+    }
+}
+
+#[async_trait]
+impl DryRunSink for HttpDryRunSink {
+    async fn capture(&self, capture: &DryRunCapture) -> Result<()> {
+        // SECURITY: DNS rebinding prevention — resolve and reject private IPs.
+        crate::ssrf::dns_resolve_and_check(&self.url).await?;
+        let response = self.client.post(&self.url).json(capture).send().await.map_err(|e| {
+            ObserverError::ActionExecutionFailed {
+                reason: format!("dry-run capture {}: request failed: {e}", capture.capture_id),
+            }
+        })?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ObserverError::ActionExecutionFailed {
+                reason: format!("dry-run capture {}: HTTP {status}", capture.capture_id),
+            });
+        }
+        Ok(())
+    }
+}
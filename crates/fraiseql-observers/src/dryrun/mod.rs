@@ -0,0 +1,364 @@
+//! Dry-run (simulation) mode: evaluate observers against live events without
+//! sending anything.
+//!
+//! An observer with `dry_run = true` — or every observer, when the runtime's
+//! `dry_run` simulation mode is on — goes through matching, its condition and
+//! its templates as usual, but each action's rendered payload is handed to a
+//! [`DryRunSink`] as a [`DryRunCapture`] instead of being dispatched. That lets
+//! a new observer be tried against production traffic before it can email a
+//! customer or call a partner's webhook.
+//!
+//! A capture never carries a secret: webhook URLs are reduced to their host,
+//! URLs and tokens read from the environment appear by variable name only, and
+//! secret-looking headers are redacted. Rendering errors are captured too, so
+//! a template that does not render shows up before the observer goes live.
+//!
+//! Sinks:
+//!
+//! - [`InMemoryDryRunSink`] — the executor default; keeps the latest captures.
+//! - `PostgresDryRunSink` (feature `postgres`) — the `tb_observer_dryrun` table (DDL:
+//!   [`observer_dryrun_sql`](crate::migrations::observer_dryrun_sql)).
+//! - [`HttpDryRunSink`] — POSTs each capture as JSON to an endpoint.
+
+mod http;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(test)]
+mod tests;
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+pub use http::HttpDryRunSink;
+use parking_lot::Mutex;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresDryRunSink;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::{
+    actions::{
+        discord,
+        push::{PushMessage, render_device_tokens},
+        redact_secret_headers, render_body, render_text_template, slack, sms, teams, url_host_only,
+    },
+    cache::{cdn::render_purge_keys, glob::render_key_pattern},
+    config::ActionConfig,
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Captures kept by [`InMemoryDryRunSink::new`].
+pub const DEFAULT_IN_MEMORY_CAPTURES: usize = 1_000;
+
+/// One action an observer would have run, with its rendered payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DryRunCapture {
+    /// Unique id of this capture.
+    pub capture_id:   Uuid,
+    /// Name of the observer.
+    pub observer:     String,
+    /// Index of the action within the observer's action list.
+    pub action_index: usize,
+    /// Action type (`"webhook"`, `"slack"`, `"email"`, …).
+    pub action_type:  String,
+    /// Id of the triggering event.
+    pub event_id:     Uuid,
+    /// Entity type of the triggering event.
+    pub entity_type:  String,
+    /// Entity id of the triggering event.
+    pub entity_id:    Uuid,
+    /// Event type of the triggering event (`"INSERT"`, …).
+    pub event_type:   String,
+    /// Tenant of the triggering event, if any.
+    pub tenant_id:    Option<String>,
+    /// What the action would have sent; `None` if it did not render.
+    pub payload:      Option<Value>,
+    /// Why the action did not render, if it did not.
+    pub error:        Option<String>,
+    /// When the capture was taken.
+    pub captured_at:  DateTime<Utc>,
+}
+
+impl DryRunCapture {
+    /// Render `action` of `observer` for `event` into a capture.
+    #[must_use]
+    pub fn render(
+        observer: &str,
+        action_index: usize,
+        action: &ActionConfig,
+        event: &EntityEvent,
+    ) -> Self {
+        let (payload, error) = match render_action(action, event) {
+            Ok(payload) => (Some(payload), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Self {
+            capture_id: Uuid::new_v4(),
+            observer: observer.to_string(),
+            action_index,
+            action_type: action.action_type().to_string(),
+            event_id: event.id,
+            entity_type: event.entity_type.clone(),
+            entity_id: event.entity_id,
+            event_type: event.event_type.as_str().to_string(),
+            tenant_id: event.tenant_id.clone(),
+            payload,
+            error,
+            captured_at: Utc::now(),
+        }
+    }
+}
+
+/// Where dry-run captures go.
+#[async_trait]
+pub trait DryRunSink: Send + Sync {
+    /// Record `capture`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capture cannot be recorded; the executor logs
+    /// it and moves on, since nothing was sent either way.
+    async fn capture(&self, capture: &DryRunCapture) -> Result<()>;
+}
+
+/// An in-process [`DryRunSink`] keeping the most recent captures.
+#[derive(Debug)]
+pub struct InMemoryDryRunSink {
+    capacity: usize,
+    captures: Mutex<VecDeque<DryRunCapture>>,
+}
+
+impl InMemoryDryRunSink {
+    /// Create a sink keeping the latest 1000 captures.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_IN_MEMORY_CAPTURES)
+    }
+
+    /// Create a sink keeping the latest `capacity` captures.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            captures: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// The captures kept, oldest first.
+    #[must_use]
+    pub fn captures(&self) -> Vec<DryRunCapture> {
+        self.captures.lock().iter().cloned().collect()
+    }
+
+    /// Number of captures kept.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.captures.lock().len()
+    }
+
+    /// Whether no capture is kept.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.captures.lock().is_empty()
+    }
+}
+
+impl Default for InMemoryDryRunSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DryRunSink for InMemoryDryRunSink {
+    async fn capture(&self, capture: &DryRunCapture) -> Result<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+        let mut captures = self.captures.lock();
+        while captures.len() >= self.capacity {
+            captures.pop_front();
+        }
+        captures.push_back(capture.clone());
+        Ok(())
+    }
+}
+
+/// Render what `action` would send for `event`, without sending it.
+///
+/// Nothing is resolved from the environment and no network call is made, so
+/// an action whose URL or credentials are only set in production still
+/// renders.
+///
+/// # Errors
+///
+/// Returns the error the action itself would fail with for a template that
+/// does not render, a missing recipient or an over-long message, and
+/// `ObserverError::UnsupportedActionType` for a `search` action.
+pub fn render_action(action: &ActionConfig, event: &EntityEvent) -> Result<Value> {
+    match action {
+        ActionConfig::Webhook {
+            url,
+            url_env,
+            method,
+            headers,
+            body_template,
+            signing_secret,
+            signing_secret_env,
+            signing_secret_ref,
+            ..
+        } => Ok(json!({
+            "destination": destination(url.as_deref(), url_env.as_deref()),
+            "method": method.as_deref().unwrap_or("POST"),
+            "headers": redact_secret_headers(headers),
+            "body": body_template
+                .as_deref()
+                .map_or_else(|| event.data.clone(), |template| render_body(template, &event.data)),
+            "signed": signing_secret.is_some()
+                || signing_secret_env.is_some()
+                || signing_secret_ref.is_some(),
+        })),
+        ActionConfig::Slack {
+            webhook_url,
+            webhook_url_env,
+            bot_token_env,
+            channel,
+            message_template,
+            blocks_template,
+            thread_by_entity,
+        } => Ok(json!({
+            "destination": if bot_token_env.is_some() {
+                Value::String("chat.postMessage".to_string())
+            } else {
+                destination(webhook_url.as_deref(), webhook_url_env.as_deref())
+            },
+            "thread_by_entity": thread_by_entity,
+            "body": slack::render_payload(
+                channel.as_deref(),
+                message_template.as_deref(),
+                blocks_template.as_deref(),
+                event,
+            )?,
+        })),
+        ActionConfig::Teams {
+            webhook_url,
+            webhook_url_env,
+            title_template,
+            message_template,
+            card_template,
+        } => Ok(json!({
+            "destination": destination(webhook_url.as_deref(), webhook_url_env.as_deref()),
+            "body": teams::render_message(
+                title_template.as_deref(),
+                message_template.as_deref(),
+                card_template.as_deref(),
+                event,
+            )?,
+        })),
+        ActionConfig::Discord {
+            webhook_url,
+            webhook_url_env,
+            username,
+            message_template,
+            embed_template,
+        } => Ok(json!({
+            "destination": destination(webhook_url.as_deref(), webhook_url_env.as_deref()),
+            "body": discord::render_payload(
+                username.as_deref(),
+                message_template.as_deref(),
+                embed_template.as_deref(),
+                event,
+            )?,
+        })),
+        ActionConfig::Email {
+            to,
+            subject,
+            body_template,
+            ..
+        } => {
+            let to = to.as_ref().ok_or(ObserverError::InvalidActionConfig {
+                reason: "Email 'to' not provided".to_string(),
+            })?;
+            let subject = subject.as_ref().ok_or(ObserverError::InvalidActionConfig {
+                reason: "Email 'subject' not provided".to_string(),
+            })?;
+            Ok(json!({
+                "to": to,
+                "subject": subject,
+                "body": body_template
+                    .as_deref()
+                    .map_or_else(String::new, |template| render_text_template(template, &event.data)),
+            }))
+        },
+        ActionConfig::Push {
+            device_token,
+            device_token_template,
+            title_template,
+            body_template,
+            collapse_key,
+            ..
+        } => {
+            let tokens = render_device_tokens(
+                device_token.as_deref(),
+                device_token_template.as_deref(),
+                event,
+            )?;
+            let message = PushMessage::render(
+                title_template.as_deref(),
+                body_template.as_deref(),
+                collapse_key.as_deref(),
+                event,
+            )?;
+            Ok(json!({
+                "device_tokens": tokens,
+                "title": message.title,
+                "body": message.body,
+                "collapse_key": message.collapse_key,
+                "data": message.data,
+            }))
+        },
+        ActionConfig::Sms {
+            phone,
+            phone_template,
+            message_template,
+            ..
+        } => Ok(json!({
+            "to": sms::render_recipient(phone.as_deref(), phone_template.as_deref(), event)?,
+            "message": sms::render_message(message_template.as_deref(), event)?,
+        })),
+        ActionConfig::Cache {
+            key_pattern,
+            action: cache_action,
+            cdn: Some(_),
+        } => Ok(json!({
+            "target": "cdn",
+            "action": cache_action,
+            "keys": render_purge_keys(key_pattern, event)?,
+        })),
+        ActionConfig::Cache {
+            key_pattern,
+            action: cache_action,
+            cdn: None,
+        } => Ok(json!({
+            "target": "redis",
+            "action": cache_action,
+            "pattern": render_key_pattern(key_pattern, &event.data, false),
+        })),
+        ActionConfig::Search { .. } => Err(ObserverError::UnsupportedActionType {
+            action_type: action.action_type().to_string(),
+        }),
+    }
+}
+
+/// Where a URL-addressed action would send: the host of a literal URL (whose
+/// path and query may hold a secret), or the name of the variable holding it.
+fn destination(url: Option<&str>, url_env: Option<&str>) -> Value {
+    match (url, url_env) {
+        (Some(url), _) => Value::String(url_host_only(url)),
+        (None, Some(var)) => Value::String(format!("${var}")),
+        (None, None) => Value::Null,
+    }
+}
@@ -0,0 +1,94 @@
+//! PostgreSQL-backed dry-run sink (`tb_observer_dryrun`).
+
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::PgPool;
+
+use super::{DryRunCapture, DryRunSink};
+use crate::error::{ObserverError, Result};
+
+/// Dry-run sink writing to the `tb_observer_dryrun` table installed by
+/// [`observer_dryrun_sql`](crate::migrations::observer_dryrun_sql).
+#[derive(Clone)]
+pub struct PostgresDryRunSink {
+    pool: PgPool,
+}
+
+impl PostgresDryRunSink {
+    /// Create a sink over an existing pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create the `tb_observer_dryrun` table (idempotent). Call once on
+    /// startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the DDL fails.
+    pub async fn init(&self) -> Result<()> {
+        sqlx::raw_sql(crate::migrations::observer_dryrun_sql())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("dry-run sink: init: {e}"),
+            })?;
+        Ok(())
+    }
+
+    /// Delete captures taken more than `older_than` ago. Returns how many.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the delete fails.
+    pub async fn prune(&self, older_than: std::time::Duration) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM tb_observer_dryrun \
+             WHERE captured_at < NOW() - make_interval(secs => $1)",
+        )
+        .bind(older_than.as_secs_f64())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("dry-run sink: prune: {e}"),
+        })?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait]
+impl DryRunSink for PostgresDryRunSink {
+    async fn capture(&self, capture: &DryRunCapture) -> Result<()> {
+        // Serialize to text and cast `$10::jsonb`, so binding needs only the text codec.
+        let payload = capture.payload.as_ref().map(Value::to_string);
+        sqlx::query(
+            r"
+            INSERT INTO tb_observer_dryrun (
+                id, observer_name, action_index, action_type,
+                event_id, entity_type, entity_id, event_type, tenant_id,
+                payload, error, captured_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10::jsonb, $11, $12)
+            ",
+        )
+        .bind(capture.capture_id)
+        .bind(&capture.observer)
+        .bind(i32::try_from(capture.action_index).unwrap_or(i32::MAX))
+        .bind(&capture.action_type)
+        .bind(capture.event_id)
+        .bind(&capture.entity_type)
+        .bind(capture.entity_id)
+        .bind(&capture.event_type)
+        .bind(&capture.tenant_id)
+        .bind(payload)
+        .bind(&capture.error)
+        .bind(capture.captured_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("dry-run sink: capture {}: {e}", capture.capture_id),
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::{collections::HashMap, sync::Arc};
+
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method},
+};
+
+use super::*;
+use crate::{event::EventKind, insecure_guard::ALLOW_INSECURE_ENV};
+
+fn event() -> EntityEvent {
+    EntityEvent::new(
+        EventKind::Created,
+        "Order".to_string(),
+        Uuid::new_v4(),
+        json!({ "id": 42, "status": "shipped", "phone": "+15005550006" }),
+    )
+}
+
+#[test]
+fn webhook_captures_never_hold_secrets() {
+    let headers = HashMap::from([
+        ("Authorization".to_string(), "Bearer s3cret".to_string()),
+        ("X-Source".to_string(), "fraiseql".to_string()),
+    ]);
+    let action = ActionConfig::Webhook {
+        url:                Some("https://hooks.example.com/T0/B0/s3cret?token=s3cret".to_string()),
+        url_env:            None,
+        method:             None,
+        headers:            headers.clone(),
+        body_template:      Some(r#"{"order": {{ id }}, "status": "{{ status }}"}"#.to_string()),
+        signing_secret:     Some("whsec_s3cret".to_string()),
+        signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
+    };
+
+    let payload = render_action(&action, &event()).unwrap();
+    assert_eq!(payload["destination"], "hooks.example.com");
+    assert_eq!(payload["method"], "POST");
+    assert_eq!(payload["headers"]["Authorization"], "<redacted>");
+    assert_eq!(payload["headers"]["X-Source"], "fraiseql");
+    assert_eq!(payload["body"], json!({ "order": 42, "status": "shipped" }));
+    assert_eq!(payload["signed"], true);
+    assert!(!payload.to_string().contains("s3cret"), "{payload}");
+
+    let from_env = ActionConfig::Webhook {
+        url:                None,
+        url_env:            Some("ORDERS_WEBHOOK_URL".to_string()),
+        method:             Some("PUT".to_string()),
+        headers:            HashMap::new(),
+        body_template:      None,
+        signing_secret:     None,
+        signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
+    };
+    let payload = render_action(&from_env, &event()).unwrap();
+    assert_eq!(payload["destination"], "$ORDERS_WEBHOOK_URL");
+    assert_eq!(payload["body"], event().data);
+}
+
+#[test]
+fn chat_and_sms_actions_render_what_they_would_send() {
+    let slack = ActionConfig::Slack {
+        webhook_url:      None,
+        webhook_url_env:  None,
+        bot_token_env:    Some("SLACK_BOT_TOKEN".to_string()),
+        channel:          Some("C0123456789".to_string()),
+        message_template: Some("Order {{ id }} {{ status }}".to_string()),
+        blocks_template:  None,
+        thread_by_entity: true,
+    };
+    let payload = render_action(&slack, &event()).unwrap();
+    assert_eq!(payload["destination"], "chat.postMessage");
+    assert_eq!(payload["body"]["text"], "Order 42 shipped");
+    assert_eq!(payload["body"]["channel"], "C0123456789");
+
+    let sms = ActionConfig::Sms {
+        phone:            None,
+        phone_template:   Some("{{ phone }}".to_string()),
+        message_template: Some("Order {{ id }} {{ status }}".to_string()),
+        provider:         None,
+        senders:          crate::config::SmsSenderPool::default(),
+    };
+    let payload = render_action(&sms, &event()).unwrap();
+    assert_eq!(payload, json!({ "to": "+15005550006", "message": "Order 42 shipped" }));
+}
+
+#[test]
+fn a_template_that_does_not_render_is_captured_as_an_error() {
+    let discord = ActionConfig::Discord {
+        webhook_url:      None,
+        webhook_url_env:  Some("DISCORD_WEBHOOK_URL".to_string()),
+        username:         None,
+        message_template: None,
+        embed_template:   Some("not json {{ id }}".to_string()),
+    };
+
+    let capture = DryRunCapture::render("orders", 2, &discord, &event());
+    assert_eq!(capture.action_index, 2);
+    assert_eq!(capture.action_type, "discord");
+    assert_eq!(capture.payload, None);
+    assert!(capture.error.as_deref().unwrap().contains("embed_template"), "{capture:?}");
+}
+
+#[tokio::test]
+async fn in_memory_sink_keeps_the_latest_captures() {
+    let sink = InMemoryDryRunSink::with_capacity(2);
+    let action = ActionConfig::Cache {
+        key_pattern: "order:{{ id }}".to_string(),
+        action:      "invalidate".to_string(),
+        cdn:         None,
+    };
+    for index in 0..3 {
+        sink.capture(&DryRunCapture::render("orders", index, &action, &event()))
+            .await
+            .unwrap();
+    }
+
+    let captures = sink.captures();
+    assert_eq!(captures.iter().map(|c| c.action_index).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(captures[0].payload.as_ref().unwrap()["pattern"], "order:42");
+}
+
+#[tokio::test]
+async fn http_sink_posts_each_capture() {
+    // wiremock binds to 127.0.0.1, which the SSRF guard blocks; allow the
+    // insecure bypass for this loopback test only.
+    temp_env::async_with_vars([(ALLOW_INSECURE_ENV, Some("true"))], async {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "observer": "orders", "action_type": "email" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let action = ActionConfig::Email {
+            to:               Some("ops@example.com".to_string()),
+            to_template:      None,
+            subject:          Some("Order shipped".to_string()),
+            subject_template: None,
+            body_template:    Some("Order {{ id }}".to_string()),
+            reply_to:         None,
+        };
+
+        let sink: Arc<dyn DryRunSink> = Arc::new(HttpDryRunSink::new(server.uri()).unwrap());
+        sink.capture(&DryRunCapture::render("orders", 0, &action, &event()))
+            .await
+            .unwrap();
+    })
+    .await;
+}
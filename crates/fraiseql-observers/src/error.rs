@@ -263,7 +263,8 @@ impl From<sqlx::Error> for ObserverError {
     feature = "dedup",
     feature = "caching",
     feature = "queue",
-    feature = "redis-lease"
+    feature = "redis-lease",
+    feature = "redis-slack-threads"
 ))]
 impl From<redis::RedisError> for ObserverError {
     fn from(err: redis::RedisError) -> Self {
@@ -56,10 +56,12 @@ impl ObserverExecutor {
         result
     }
 
-    /// Handle action failure based on failure policy
+    /// Handle a failure of an action of observer `observer_name` based on the
+    /// failure policy
     #[allow(clippy::cognitive_complexity)] // Reason: failure policy dispatch with per-policy logging and DLQ routing
     pub(crate) async fn handle_action_failure(
         &self,
+        observer_name: &str,
         action: &ActionConfig,
         event: &EntityEvent,
         error: &ObserverError,
@@ -80,18 +82,31 @@ impl ObserverExecutor {
                 crate::error::ObserverErrorCode::CircuitBreakerOpen => "circuit_breaker_open",
                 _ => "other_error",
             };
-            self.metrics.action_error(action.action_type(), error_type);
+            let labels = crate::metrics::ActionLabels {
+                observer:    observer_name,
+                action_type: action.action_type(),
+                entity_type: &event.entity_type,
+                tenant:      event.tenant_id.as_deref(),
+            };
+            self.metrics.action_error(&labels, error_type);
         }
 
         match failure_policy {
             FailurePolicy::Log => {
-                error!("Action {} failed for event {}: {}", action.action_type(), event.id, error);
+                error!(
+                    "Action {} of observer {} failed for event {}: {}",
+                    action.action_type(),
+                    observer_name,
+                    event.id,
+                    error
+                );
                 summary.failed_actions += 1;
             },
             FailurePolicy::Alert => {
                 error!(
-                    "ALERT: Action {} failed for event {}: {}",
+                    "ALERT: Action {} of observer {} failed for event {}: {}",
                     action.action_type(),
+                    observer_name,
                     event.id,
                     error
                 );
@@ -104,6 +119,7 @@ impl ObserverExecutor {
                     if current >= max {
                         warn!(
                             max_dlq_size = max,
+                            observer = observer_name,
                             action_type = action.action_type(),
                             event_id = %event.id,
                             "DLQ full; dropping failed action entry"
@@ -6,13 +6,21 @@
 
 use std::sync::Arc;
 
-use tracing::debug;
+use parking_lot::RwLock;
+use tracing::{debug, warn};
 
 use crate::{
-    actions::{EmailAction, SlackAction, WebhookAction},
-    config::ActionConfig,
+    actions::{
+        DiscordAction, EmailAction, SlackAction, TeamsAction, WebhookAction,
+        push::{PushMessage, PushSender, render_device_tokens},
+        slack::{SlackThreadStore, thread_key},
+        sms::{SmsAction, SmsDelivery, SmsOptOutList, render_message, render_recipient},
+    },
+    cache::cdn::{CdnPurger, render_purge_keys},
+    config::{ActionConfig, CdnPurgeConfig, PushProvider, SmsProvider, SmsSenderPool},
     error::{ObserverError, Result},
     event::EntityEvent,
+    signing::{SigningSecretResolver, WebhookSigningKey},
     traits::ActionResult,
 };
 
@@ -28,19 +36,37 @@ pub trait ActionDispatcher: Send + Sync {
         action: &'a ActionConfig,
         event: &'a EntityEvent,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ActionResult>> + Send + 'a>>;
+
+    /// Install the opt-out list consulted before every SMS send.
+    ///
+    /// Dispatchers without an SMS transport ignore it.
+    fn set_sms_opt_out_list(&self, _opt_outs: Arc<dyn SmsOptOutList>) {}
+
+    /// Install the store remembering Slack threads for `thread_by_entity`.
+    ///
+    /// Dispatchers without a Slack transport ignore it.
+    fn set_slack_thread_store(&self, _threads: Arc<dyn SlackThreadStore>) {}
 }
 
 /// Production action dispatcher that delegates to the concrete action structs.
 ///
-/// Webhook / Slack / Email / Cache (the last only with the `caching` feature and
-/// a wired Redis invalidator) have real transports. SMS / Push / Search remain
-/// rejected as unsupported (H24), so they have no executor.
+/// Webhook / Slack / Teams / Discord / Email / Push / SMS / Cache (Redis invalidation only with
+/// the `caching` feature and a wired invalidator; CDN purges always) have real
+/// transports. Search remains rejected as unsupported (H24), so it has no
+/// executor.
 #[allow(clippy::struct_field_names)] // Reason: `_action` postfix clarifies executor vs config fields
 pub(super) struct DefaultActionDispatcher {
     /// Webhook action executor
     pub(super) webhook_action:    Arc<WebhookAction>,
     /// Slack action executor
     pub(super) slack_action:      Arc<SlackAction>,
+    /// Thread timestamps for Slack actions with `thread_by_entity`
+    /// (in-process unless a shared store is wired).
+    pub(super) slack_threads:     RwLock<Arc<dyn SlackThreadStore>>,
+    /// Microsoft Teams action executor
+    pub(super) teams_action:      Arc<TeamsAction>,
+    /// Discord action executor
+    pub(super) discord_action:    Arc<DiscordAction>,
     /// Email action executor
     pub(super) email_action:      Arc<EmailAction>,
     /// Redis cache-invalidation transport (#428).
@@ -50,6 +76,27 @@ pub(super) struct DefaultActionDispatcher {
     /// with no SMTP backend.
     #[cfg(feature = "caching")]
     pub(super) cache_invalidator: Option<Arc<crate::cache::redis::RedisCacheInvalidator>>,
+    /// CDN purgers for `cache` actions with a `cdn` target, one per distinct
+    /// target, built on first use.
+    ///
+    /// Sharing one purger per target is what lets purges from many events be
+    /// coalesced into a few provider calls.
+    pub(super) cdn_purgers:       dashmap::DashMap<CdnPurgeConfig, Arc<CdnPurger>>,
+    /// Push senders, one per distinct provider account, built on first use so
+    /// the provider's access token is minted once and shared.
+    pub(super) push_senders:      dashmap::DashMap<PushProvider, Arc<PushSender>>,
+    /// SMS senders, one per distinct provider account, built on first use.
+    pub(super) sms_actions:       dashmap::DashMap<SmsProvider, Arc<SmsAction>>,
+    /// Recipients who must not receive SMS.
+    ///
+    /// `None` means no list was wired: every recipient is sent to, and
+    /// provider-reported unsubscribes are only logged.
+    pub(super) sms_opt_outs:      RwLock<Option<Arc<dyn SmsOptOutList>>>,
+    /// Secrets-manager lookup for webhook `signing_secret_ref`.
+    ///
+    /// `None` means no secrets manager was wired: an action naming a
+    /// `signing_secret_ref` then fails loud instead of sending unsigned.
+    pub(super) signing_secrets:   Option<Arc<dyn SigningSecretResolver>>,
 }
 
 /// Maximum byte length accepted for a webhook URL.
@@ -174,16 +221,41 @@ impl ActionDispatcher for DefaultActionDispatcher {
                     body_template,
                     signing_secret,
                     signing_secret_env,
+                    signing_secret_ref,
+                    signing_key_id,
                 } => {
                     debug!("Webhook action: url={:?}, url_env={:?}", url, url_env);
                     let webhook_url = resolve_url(url.as_deref(), url_env.as_deref(), "Webhook")?;
                     // DNS-rebinding guard: re-resolve at dispatch time and reject
                     // any host whose addresses fall in a private/reserved range.
                     crate::ssrf::dns_resolve_and_check(&webhook_url).await?;
-                    let resolved_secret = resolve_signing_secret(
-                        signing_secret_env.as_deref(),
-                        signing_secret.as_deref(),
-                    )?;
+                    let resolved_secret = if let Some(name) = signing_secret_ref.as_deref() {
+                        if signing_secret.is_some() || signing_secret_env.is_some() {
+                            return Err(ObserverError::InvalidActionConfig {
+                                reason: "Webhook action sets `signing_secret_ref` together with \
+                                         `signing_secret` or `signing_secret_env`; set exactly \
+                                         one"
+                                .to_string(),
+                            });
+                        }
+                        Some(self.resolve_signing_secret_ref(name).await?)
+                    } else {
+                        resolve_signing_secret(
+                            signing_secret_env.as_deref(),
+                            signing_secret.as_deref(),
+                        )?
+                    };
+                    if signing_key_id.is_some() && resolved_secret.is_none() {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: "Webhook `signing_key_id` is set but no signing secret is \
+                                     configured"
+                                .to_string(),
+                        });
+                    }
+                    let signing_key = resolved_secret.as_deref().map(|secret| WebhookSigningKey {
+                        secret,
+                        key_id: signing_key_id.as_deref(),
+                    });
 
                     match self
                         .webhook_action
@@ -192,7 +264,7 @@ impl ActionDispatcher for DefaultActionDispatcher {
                             method.as_deref(),
                             headers,
                             body_template.as_deref(),
-                            resolved_secret.as_deref(),
+                            signing_key,
                             event,
                         )
                         .await
@@ -210,9 +282,29 @@ impl ActionDispatcher for DefaultActionDispatcher {
                 ActionConfig::Slack {
                     webhook_url,
                     webhook_url_env,
+                    bot_token_env,
                     channel,
                     message_template,
+                    blocks_template,
+                    thread_by_entity,
                 } => {
+                    if let Some(token_env) = bot_token_env.as_deref() {
+                        return self
+                            .dispatch_slack_api(
+                                token_env,
+                                channel.as_deref(),
+                                message_template.as_deref(),
+                                blocks_template.as_deref(),
+                                *thread_by_entity,
+                                event,
+                            )
+                            .await;
+                    }
+                    if *thread_by_entity {
+                        return Err(ObserverError::InvalidActionConfig {
+                            reason: "Slack 'thread_by_entity' requires 'bot_token_env'".to_string(),
+                        });
+                    }
                     let slack_url =
                         resolve_url(webhook_url.as_deref(), webhook_url_env.as_deref(), "Slack")?;
                     // DNS-rebinding guard: re-resolve at dispatch time.
@@ -220,7 +312,13 @@ impl ActionDispatcher for DefaultActionDispatcher {
 
                     match self
                         .slack_action
-                        .execute(&slack_url, channel.as_deref(), message_template.as_deref(), event)
+                        .execute(
+                            &slack_url,
+                            channel.as_deref(),
+                            message_template.as_deref(),
+                            blocks_template.as_deref(),
+                            event,
+                        )
                         .await
                     {
                         Ok(response) => Ok(ActionResult {
@@ -233,6 +331,69 @@ impl ActionDispatcher for DefaultActionDispatcher {
                         Err(e) => Err(e),
                     }
                 },
+                ActionConfig::Teams {
+                    webhook_url,
+                    webhook_url_env,
+                    title_template,
+                    message_template,
+                    card_template,
+                } => {
+                    let teams_url =
+                        resolve_url(webhook_url.as_deref(), webhook_url_env.as_deref(), "Teams")?;
+                    // DNS-rebinding guard: re-resolve at dispatch time.
+                    crate::ssrf::dns_resolve_and_check(&teams_url).await?;
+
+                    let response = self
+                        .teams_action
+                        .execute(
+                            &teams_url,
+                            title_template.as_deref(),
+                            message_template.as_deref(),
+                            card_template.as_deref(),
+                            event,
+                        )
+                        .await?;
+                    Ok(ActionResult {
+                        action_type: "teams".to_string(),
+                        success:     true,
+                        message:     format!("HTTP {}", response.status_code),
+                        duration_ms: response.duration_ms,
+                        status_code: Some(response.status_code),
+                    })
+                },
+                ActionConfig::Discord {
+                    webhook_url,
+                    webhook_url_env,
+                    username,
+                    message_template,
+                    embed_template,
+                } => {
+                    let discord_url =
+                        resolve_url(webhook_url.as_deref(), webhook_url_env.as_deref(), "Discord")?;
+                    // DNS-rebinding guard: re-resolve at dispatch time.
+                    crate::ssrf::dns_resolve_and_check(&discord_url).await?;
+
+                    let response = self
+                        .discord_action
+                        .execute(
+                            &discord_url,
+                            username.as_deref(),
+                            message_template.as_deref(),
+                            embed_template.as_deref(),
+                            event,
+                        )
+                        .await?;
+                    Ok(ActionResult {
+                        action_type: "discord".to_string(),
+                        success:     true,
+                        message:     response.message_id.map_or_else(
+                            || format!("HTTP {}", response.status_code),
+                            |id| format!("posted {id}"),
+                        ),
+                        duration_ms: response.duration_ms,
+                        status_code: Some(response.status_code),
+                    })
+                },
                 ActionConfig::Email {
                     to,
                     to_template: _,
@@ -267,30 +428,325 @@ impl ActionDispatcher for DefaultActionDispatcher {
                         Err(e) => Err(e),
                     }
                 },
-                // Cache invalidation has a real Redis transport (#428) when the
-                // `caching` feature is compiled and an invalidator is wired;
-                // otherwise it fails loud (never a fabricated success).
+                // A `cdn` target purges the CDN. Otherwise cache invalidation
+                // has a real Redis transport (#428) when the `caching` feature
+                // is compiled and an invalidator is wired; without one it fails
+                // loud (never a fabricated success).
+                ActionConfig::Cache {
+                    key_pattern,
+                    action: cache_action,
+                    cdn: Some(cdn),
+                } => self.dispatch_cdn_purge(key_pattern, cache_action, cdn, event).await,
                 ActionConfig::Cache {
                     key_pattern,
                     action: cache_action,
+                    cdn: None,
                 } => self.dispatch_cache(key_pattern, cache_action, event).await,
-                // SMS / Push / Search have no real transport wired. They
-                // previously delegated to stub actions that fabricated
-                // `success: true` and sent nothing (H24). They now fail loud here
-                // too (belt-and-suspenders with `ActionConfig::validate`, which
-                // rejects them at config-load). Real transports are tracked as
-                // follow-up work.
-                ActionConfig::Sms { .. }
-                | ActionConfig::Push { .. }
-                | ActionConfig::Search { .. } => Err(ObserverError::UnsupportedActionType {
+                ActionConfig::Push {
+                    device_token,
+                    device_token_template,
+                    title_template,
+                    body_template,
+                    collapse_key,
+                    provider,
+                    invalid_token_webhook,
+                } => {
+                    let provider =
+                        provider.as_ref().ok_or_else(|| ObserverError::InvalidActionConfig {
+                            reason: "Push action requires a 'provider' (fcm or apns)".to_string(),
+                        })?;
+                    let tokens = render_device_tokens(
+                        device_token.as_deref(),
+                        device_token_template.as_deref(),
+                        event,
+                    )?;
+                    let message = PushMessage::render(
+                        title_template.as_deref(),
+                        body_template.as_deref(),
+                        collapse_key.as_deref(),
+                        event,
+                    )?;
+                    self.dispatch_push(
+                        provider,
+                        &tokens,
+                        &message,
+                        invalid_token_webhook.as_deref(),
+                        event,
+                    )
+                    .await
+                },
+                ActionConfig::Sms {
+                    phone,
+                    phone_template,
+                    message_template,
+                    provider,
+                    senders,
+                } => {
+                    let provider =
+                        provider.as_ref().ok_or_else(|| ObserverError::InvalidActionConfig {
+                            reason: "SMS action requires a 'provider' (twilio or vonage)"
+                                .to_string(),
+                        })?;
+                    let to = render_recipient(phone.as_deref(), phone_template.as_deref(), event)?;
+                    let text = render_message(message_template.as_deref(), event)?;
+                    self.dispatch_sms(provider, senders, &to, &text, event).await
+                },
+                // Search has no real transport wired. It previously delegated to
+                // a stub action that fabricated `success: true` and sent nothing
+                // (H24). It now fails loud here too (belt-and-suspenders with
+                // `ActionConfig::validate`, which rejects it at config-load).
+                ActionConfig::Search { .. } => Err(ObserverError::UnsupportedActionType {
                     action_type: action.action_type().to_string(),
                 }),
             }
         })
     }
+
+    fn set_sms_opt_out_list(&self, opt_outs: Arc<dyn SmsOptOutList>) {
+        *self.sms_opt_outs.write() = Some(opt_outs);
+    }
+
+    fn set_slack_thread_store(&self, threads: Arc<dyn SlackThreadStore>) {
+        *self.slack_threads.write() = threads;
+    }
 }
 
 impl DefaultActionDispatcher {
+    /// Look up a webhook `signing_secret_ref` in the wired secrets manager.
+    ///
+    /// Like the literal and env-var sources, every failure is an error rather
+    /// than an unsigned delivery: no resolver or an empty value is a config
+    /// error, and a backend failure is whatever the resolver reports
+    /// (transient when the backend is unreachable).
+    pub(super) async fn resolve_signing_secret_ref(&self, name: &str) -> Result<String> {
+        let Some(resolver) = self.signing_secrets.as_ref() else {
+            return Err(ObserverError::InvalidActionConfig {
+                reason: format!(
+                    "Webhook signing_secret_ref {name:?} is set but no secrets manager is \
+                     configured"
+                ),
+            });
+        };
+        let secret = resolver.resolve(name).await?;
+        if secret.is_empty() {
+            return Err(ObserverError::InvalidActionConfig {
+                reason: format!("Webhook signing secret {name:?} is empty in the secrets manager"),
+            });
+        }
+        Ok(secret)
+    }
+
+    /// Dispatch a `cache` action with a `cdn` target.
+    ///
+    /// The rendered keys join the target's current coalescing window; the
+    /// result reflects the provider call that carried them.
+    async fn dispatch_cdn_purge(
+        &self,
+        key_pattern: &str,
+        cache_action: &str,
+        cdn: &CdnPurgeConfig,
+        event: &EntityEvent,
+    ) -> Result<ActionResult> {
+        if cache_action != "invalidate" {
+            return Err(ObserverError::InvalidActionConfig {
+                reason: format!(
+                    "Cache action {cache_action:?} is not supported; only \"invalidate\" is \
+                     implemented (#428)"
+                ),
+            });
+        }
+        let keys = render_purge_keys(key_pattern, event)?;
+        let purger = self.cdn_purger(cdn)?;
+
+        let start = std::time::Instant::now();
+        let count = keys.len();
+        purger.purge(keys).await?;
+        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+        Ok(ActionResult {
+            action_type: "cache".to_string(),
+            success: true,
+            message: format!("purged {count} key(s) via {}", purger.provider()),
+            duration_ms,
+            status_code: None,
+        })
+    }
+
+    /// The shared purger for `cdn`, built on first use.
+    fn cdn_purger(&self, cdn: &CdnPurgeConfig) -> Result<Arc<CdnPurger>> {
+        if let Some(purger) = self.cdn_purgers.get(cdn) {
+            return Ok(Arc::clone(&purger));
+        }
+        let purger = Arc::new(CdnPurger::from_config(cdn)?);
+        Ok(Arc::clone(self.cdn_purgers.entry(cdn.clone()).or_insert(purger).value()))
+    }
+
+    /// Dispatch a `push` action to its rendered device tokens.
+    ///
+    /// The invalid-token webhook passes the same SSRF guards as a webhook
+    /// action's URL before anything is sent.
+    async fn dispatch_push(
+        &self,
+        provider: &PushProvider,
+        tokens: &[String],
+        message: &PushMessage,
+        invalid_token_webhook: Option<&str>,
+        event: &EntityEvent,
+    ) -> Result<ActionResult> {
+        let cleanup = match invalid_token_webhook {
+            Some(url) => {
+                let url = resolve_url(Some(url), None, "Push invalid_token_webhook")?;
+                crate::ssrf::dns_resolve_and_check(&url).await?;
+                Some(url)
+            },
+            None => None,
+        };
+        let sender = self.push_sender(provider)?;
+
+        let start = std::time::Instant::now();
+        let report = sender.send_all(tokens, message, event, cleanup.as_deref()).await?;
+        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+        Ok(ActionResult {
+            action_type: "push".to_string(),
+            success: true,
+            message: format!(
+                "sent to {} of {} device(s) via {}, {} invalid",
+                report.sent,
+                tokens.len(),
+                sender.provider(),
+                report.invalid.len()
+            ),
+            duration_ms,
+            status_code: None,
+        })
+    }
+
+    /// The shared sender for `provider`, built on first use.
+    fn push_sender(&self, provider: &PushProvider) -> Result<Arc<PushSender>> {
+        if let Some(sender) = self.push_senders.get(provider) {
+            return Ok(Arc::clone(&sender));
+        }
+        let sender = Arc::new(PushSender::from_config(provider)?);
+        Ok(Arc::clone(self.push_senders.entry(provider.clone()).or_insert(sender).value()))
+    }
+
+    /// Dispatch a `slack` action through the Web API, in the entity's thread
+    /// when `thread_by_entity` is set.
+    async fn dispatch_slack_api(
+        &self,
+        token_env: &str,
+        channel: Option<&str>,
+        message_template: Option<&str>,
+        blocks_template: Option<&str>,
+        thread_by_entity: bool,
+        event: &EntityEvent,
+    ) -> Result<ActionResult> {
+        let channel = channel.filter(|c| !c.is_empty()).ok_or_else(|| {
+            ObserverError::InvalidActionConfig {
+                reason: "Slack 'bot_token_env' requires 'channel'".to_string(),
+            }
+        })?;
+        let token = std::env::var(token_env).ok().filter(|t| !t.is_empty()).ok_or_else(|| {
+            ObserverError::InvalidActionConfig {
+                reason: format!("Slack bot token env var {token_env} not found"),
+            }
+        })?;
+        let threads = Arc::clone(&*self.slack_threads.read());
+        let key = thread_by_entity.then(|| thread_key(channel, event));
+        let thread_ts = match &key {
+            Some(key) => threads.thread_ts(key).await?,
+            None => None,
+        };
+
+        let response = self
+            .slack_action
+            .post_message(
+                &token,
+                channel,
+                message_template,
+                blocks_template,
+                thread_ts.as_deref(),
+                event,
+            )
+            .await?;
+        let ts = response.ts.as_deref().unwrap_or("?");
+        if let (Some(key), None) = (&key, &response.thread_ts) {
+            if let Some(ts) = &response.ts {
+                if let Err(e) = threads.record_thread_ts(key, ts).await {
+                    warn!(event_id = %event.id, error = %e, "failed to record Slack thread");
+                }
+            }
+        }
+        Ok(ActionResult {
+            action_type: "slack".to_string(),
+            success:     true,
+            message:     match &response.thread_ts {
+                Some(thread) => format!("posted {ts} in thread {thread}"),
+                None => format!("posted {ts}"),
+            },
+            duration_ms: response.duration_ms,
+            status_code: Some(response.status_code),
+        })
+    }
+
+    /// Dispatch an `sms` action to its rendered recipient.
+    ///
+    /// An opted-out recipient is skipped, not failed: the event was handled.
+    async fn dispatch_sms(
+        &self,
+        provider: &SmsProvider,
+        senders: &SmsSenderPool,
+        to: &str,
+        text: &str,
+        event: &EntityEvent,
+    ) -> Result<ActionResult> {
+        let start = std::time::Instant::now();
+        let skipped = |reason: &str| ActionResult {
+            action_type: "sms".to_string(),
+            success:     true,
+            message:     format!("not sent: {reason}"),
+            duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+            status_code: None,
+        };
+        let opt_outs = self.sms_opt_outs.read().clone();
+        if let Some(opt_outs) = &opt_outs {
+            if opt_outs.is_opted_out(to).await? {
+                debug!(event_id = %event.id, "SMS recipient opted out; skipping");
+                return Ok(skipped("recipient opted out"));
+            }
+        }
+        let from = senders.select(to).ok_or_else(|| ObserverError::ActionPermanentlyFailed {
+            reason: "SMS action has no sender for the recipient's country".to_string(),
+        })?;
+        let action = self.sms_action(provider)?;
+
+        match action.send(to, from, text, event).await? {
+            SmsDelivery::Accepted { message_id } => Ok(ActionResult {
+                action_type: "sms".to_string(),
+                success:     true,
+                message:     format!("accepted as {message_id} via {}", action.provider()),
+                duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+                status_code: None,
+            }),
+            SmsDelivery::Unsubscribed => {
+                if let Some(opt_outs) = &opt_outs {
+                    if let Err(e) = opt_outs.record_opt_out(to).await {
+                        warn!(event_id = %event.id, error = %e, "failed to record SMS opt-out");
+                    }
+                }
+                Ok(skipped(&format!("recipient unsubscribed at {}", action.provider())))
+            },
+        }
+    }
+
+    /// The shared SMS sender for `provider`, built on first use.
+    fn sms_action(&self, provider: &SmsProvider) -> Result<Arc<SmsAction>> {
+        if let Some(action) = self.sms_actions.get(provider) {
+            return Ok(Arc::clone(&action));
+        }
+        let action = Arc::new(SmsAction::from_config(provider)?);
+        Ok(Arc::clone(self.sms_actions.entry(provider.clone()).or_insert(action).value()))
+    }
+
     /// Dispatch a `cache` action.
     ///
     /// Only `action = "invalidate"` is implemented; `"refresh"` (and any other
@@ -0,0 +1,45 @@
+//! Capture of dry-run observer actions.
+
+use tracing::{debug, error};
+
+use super::{ExecutionSummary, ObserverExecutor, summary};
+use crate::{config::ActionConfig, dryrun::DryRunCapture, event::EntityEvent};
+
+impl ObserverExecutor {
+    /// Capture what `action` of dry-run observer `observer_name` would send
+    /// for `event`, instead of running it.
+    ///
+    /// A capture that cannot be recorded is logged and counted in
+    /// `summary.errors`; it is never retried or sent to the DLQ.
+    pub(super) async fn capture_dry_run(
+        &self,
+        observer_name: &str,
+        action_index: usize,
+        action: &ActionConfig,
+        event: &EntityEvent,
+        summary: &mut ExecutionSummary,
+    ) {
+        let capture = DryRunCapture::render(observer_name, action_index, action, event);
+        debug!(
+            observer = observer_name,
+            action_type = action.action_type(),
+            event_id = %event.id,
+            rendered = capture.error.is_none(),
+            "Capturing dry-run action"
+        );
+        match self.dry_run_sink.capture(&capture).await {
+            Ok(()) => summary.dry_run_captured += 1,
+            Err(e) => {
+                error!(
+                    observer = observer_name,
+                    action_type = action.action_type(),
+                    event_id = %event.id,
+                    "Failed to record dry-run capture: {e}"
+                );
+                if summary.errors.len() < summary::MAX_ERROR_STRINGS {
+                    summary.errors.push(e.to_string());
+                }
+            },
+        }
+    }
+}
@@ -1,22 +1,27 @@
 //! Main observer executor engine with retry logic and orchestration.
 //!
 //! This module implements the core execution engine that:
-//! 1. Receives events from the listener
+//! 1. Receives events from the listener, quarantining those that fail their event type's schema
 //! 2. Matches events to observers using the matcher
 //! 3. Evaluates conditions for each observer
-//! 4. Executes actions with retry logic
+//! 4. Executes actions with retry logic, or captures them for dry-run observers
 //! 5. Handles failures via Dead Letter Queue
 
 mod actions;
 #[cfg(feature = "caching")]
 mod cache;
 mod dispatch;
+mod dry_run;
+mod quarantine;
 mod retry;
 mod summary;
 #[cfg(test)]
 mod tests;
 
-use std::sync::{Arc, atomic::AtomicUsize};
+use std::{
+    collections::HashSet,
+    sync::{Arc, atomic::AtomicUsize},
+};
 
 pub(crate) use dispatch::ActionDispatcher;
 use dispatch::DefaultActionDispatcher;
@@ -28,11 +33,18 @@ use crate::cache::CacheBackendDyn;
 #[cfg(feature = "metrics")]
 use crate::metrics::MetricsRegistry;
 use crate::{
-    actions::{EmailAction, SlackAction, WebhookAction},
+    actions::{
+        DiscordAction, EmailAction, SlackAction, TeamsAction, WebhookAction,
+        slack::{InMemorySlackThreadStore, SlackThreadStore},
+        sms::SmsOptOutList,
+    },
     config::EmailSmtpConfig,
+    dryrun::{DryRunSink, InMemoryDryRunSink},
     error::Result,
     event::EntityEvent,
     matcher::EventMatcher,
+    registry::{EventQuarantine, EventSchemaRegistry, InMemoryEventQuarantine},
+    signing::SigningSecretResolver,
     traits::DeadLetterQueue,
 };
 
@@ -70,6 +82,15 @@ pub struct ObserverExecutor {
     /// returns a transient `ActionExecutionFailed` error so the retry loop
     /// can back off and retry.  `None` disables the timeout (default).
     pub(super) action_timeout_ms: Option<u64>,
+    /// Simulation mode: every observer runs dry, whatever its `dry_run`.
+    pub(super) simulation:        bool,
+    /// Where the actions of dry-run observers are captured.
+    pub(super) dry_run_sink:      Arc<dyn DryRunSink>,
+    /// Declared event types events are validated against (`None`: no
+    /// validation).
+    pub(super) event_registry:    Option<Arc<EventSchemaRegistry>>,
+    /// Where events failing validation are kept.
+    pub(super) quarantine:        Arc<dyn EventQuarantine>,
     /// Optional cache backend for action result caching
     #[cfg(feature = "caching")]
     pub(super) cache_backend:     Option<Arc<dyn CacheBackendDyn>>,
@@ -81,14 +102,25 @@ pub struct ObserverExecutor {
 /// Build the production action dispatcher with the given (configured) email action.
 ///
 /// Factored out so the `new` / `with_cache` / `new_with_email` constructors share
-/// a single action set and only the email action varies.
-fn make_dispatcher(email_action: EmailAction) -> Arc<DefaultActionDispatcher> {
+/// a single action set and only the email action and secrets resolver vary.
+fn make_dispatcher(
+    email_action: EmailAction,
+    signing_secrets: Option<Arc<dyn SigningSecretResolver>>,
+) -> Arc<DefaultActionDispatcher> {
     Arc::new(DefaultActionDispatcher {
         webhook_action: Arc::new(WebhookAction::new()),
         slack_action: Arc::new(SlackAction::new()),
+        slack_threads: parking_lot::RwLock::new(Arc::new(InMemorySlackThreadStore::new())),
+        teams_action: Arc::new(TeamsAction::new()),
+        discord_action: Arc::new(DiscordAction::new()),
         email_action: Arc::new(email_action),
         #[cfg(feature = "caching")]
         cache_invalidator: None,
+        cdn_purgers: dashmap::DashMap::new(),
+        push_senders: dashmap::DashMap::new(),
+        sms_actions: dashmap::DashMap::new(),
+        sms_opt_outs: parking_lot::RwLock::new(None),
+        signing_secrets,
     })
 }
 
@@ -101,8 +133,16 @@ fn make_dispatcher_with_invalidator(
     Arc::new(DefaultActionDispatcher {
         webhook_action: Arc::new(WebhookAction::new()),
         slack_action: Arc::new(SlackAction::new()),
+        slack_threads: parking_lot::RwLock::new(Arc::new(InMemorySlackThreadStore::new())),
+        teams_action: Arc::new(TeamsAction::new()),
+        discord_action: Arc::new(DiscordAction::new()),
         email_action: Arc::new(email_action),
         cache_invalidator,
+        cdn_purgers: dashmap::DashMap::new(),
+        push_senders: dashmap::DashMap::new(),
+        sms_actions: dashmap::DashMap::new(),
+        sms_opt_outs: parking_lot::RwLock::new(None),
+        signing_secrets: None,
     })
 }
 
@@ -117,11 +157,15 @@ impl ObserverExecutor {
             matcher: Arc::new(matcher),
             condition_parser: Arc::new(crate::condition::ConditionParser::new()),
             condition_cache: dashmap::DashMap::new(),
-            dispatcher: make_dispatcher(EmailAction::new()),
+            dispatcher: make_dispatcher(EmailAction::new(), None),
             dlq,
             max_dlq_size: None,
             dlq_push_count: Arc::new(AtomicUsize::new(0)),
             action_timeout_ms: None,
+            simulation: false,
+            dry_run_sink: Arc::new(InMemoryDryRunSink::new()),
+            event_registry: None,
+            quarantine: Arc::new(InMemoryEventQuarantine::new()),
             #[cfg(feature = "caching")]
             cache_backend: None,
             #[cfg(feature = "metrics")]
@@ -143,17 +187,39 @@ impl ObserverExecutor {
         matcher: EventMatcher,
         dlq: Arc<dyn DeadLetterQueue>,
         email_config: Option<&EmailSmtpConfig>,
+    ) -> Result<Self> {
+        Self::new_with_email_and_secrets(matcher, dlq, email_config, None)
+    }
+
+    /// Like [`new_with_email`](ObserverExecutor::new_with_email), additionally
+    /// resolving webhook `signing_secret_ref`s through `signing_secrets`.
+    ///
+    /// With `None`, a webhook action naming a `signing_secret_ref` fails loud.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ObserverError`](crate::error::ObserverError) from
+    /// [`EmailAction::from_smtp_config`] when the SMTP config is invalid.
+    pub fn new_with_email_and_secrets(
+        matcher: EventMatcher,
+        dlq: Arc<dyn DeadLetterQueue>,
+        email_config: Option<&EmailSmtpConfig>,
+        signing_secrets: Option<Arc<dyn SigningSecretResolver>>,
     ) -> Result<Self> {
         let email_action = EmailAction::from_smtp_config(email_config)?;
         Ok(Self {
             matcher: Arc::new(matcher),
             condition_parser: Arc::new(crate::condition::ConditionParser::new()),
             condition_cache: dashmap::DashMap::new(),
-            dispatcher: make_dispatcher(email_action),
+            dispatcher: make_dispatcher(email_action, signing_secrets),
             dlq,
             max_dlq_size: None,
             dlq_push_count: Arc::new(AtomicUsize::new(0)),
             action_timeout_ms: None,
+            simulation: false,
+            dry_run_sink: Arc::new(InMemoryDryRunSink::new()),
+            event_registry: None,
+            quarantine: Arc::new(InMemoryEventQuarantine::new()),
             #[cfg(feature = "caching")]
             cache_backend: None,
             #[cfg(feature = "metrics")]
@@ -189,11 +255,15 @@ impl ObserverExecutor {
             matcher: Arc::new(matcher),
             condition_parser: Arc::new(crate::condition::ConditionParser::new()),
             condition_cache: dashmap::DashMap::new(),
-            dispatcher: make_dispatcher(EmailAction::new()),
+            dispatcher: make_dispatcher(EmailAction::new(), None),
             dlq,
             max_dlq_size: None,
             dlq_push_count: Arc::new(AtomicUsize::new(0)),
             action_timeout_ms: None,
+            simulation: false,
+            dry_run_sink: Arc::new(InMemoryDryRunSink::new()),
+            event_registry: None,
+            quarantine: Arc::new(InMemoryEventQuarantine::new()),
             cache_backend,
             #[cfg(feature = "metrics")]
             metrics: MetricsRegistry::global().unwrap_or_default(),
@@ -225,6 +295,10 @@ impl ObserverExecutor {
             max_dlq_size: None,
             dlq_push_count: Arc::new(AtomicUsize::new(0)),
             action_timeout_ms: None,
+            simulation: false,
+            dry_run_sink: Arc::new(InMemoryDryRunSink::new()),
+            event_registry: None,
+            quarantine: Arc::new(InMemoryEventQuarantine::new()),
             cache_backend: None,
             #[cfg(feature = "metrics")]
             metrics: MetricsRegistry::global().unwrap_or_default(),
@@ -250,6 +324,10 @@ impl ObserverExecutor {
             max_dlq_size: None,
             dlq_push_count: Arc::new(AtomicUsize::new(0)),
             action_timeout_ms: None,
+            simulation: false,
+            dry_run_sink: Arc::new(InMemoryDryRunSink::new()),
+            event_registry: None,
+            quarantine: Arc::new(InMemoryEventQuarantine::new()),
             #[cfg(feature = "caching")]
             cache_backend: None,
             #[cfg(feature = "metrics")]
@@ -257,6 +335,61 @@ impl ObserverExecutor {
         }
     }
 
+    /// Consult `opt_outs` before every SMS send.
+    ///
+    /// Opted-out recipients are skipped, and recipients the SMS provider
+    /// reports as unsubscribed are recorded in the list.
+    #[must_use]
+    pub fn with_sms_opt_out_list(self, opt_outs: Arc<dyn SmsOptOutList>) -> Self {
+        self.dispatcher.set_sms_opt_out_list(opt_outs);
+        self
+    }
+
+    /// Remember Slack threads for `thread_by_entity` in `threads`.
+    ///
+    /// The default store is per process; observers running on several
+    /// instances need a shared one to reply in the same thread.
+    #[must_use]
+    pub fn with_slack_thread_store(self, threads: Arc<dyn SlackThreadStore>) -> Self {
+        self.dispatcher.set_slack_thread_store(threads);
+        self
+    }
+
+    /// Run in simulation mode: every observer runs dry, as if its definition
+    /// set `dry_run`.
+    #[must_use]
+    pub const fn with_simulation(mut self, enabled: bool) -> Self {
+        self.simulation = enabled;
+        self
+    }
+
+    /// Capture the actions of dry-run observers in `sink`.
+    ///
+    /// The default sink keeps the latest captures in memory.
+    #[must_use]
+    pub fn with_dry_run_sink(mut self, sink: Arc<dyn DryRunSink>) -> Self {
+        self.dry_run_sink = sink;
+        self
+    }
+
+    /// Validate every event against the event types declared in `registry`.
+    ///
+    /// Events that fail are quarantined and reach no observer.
+    #[must_use]
+    pub fn with_event_registry(mut self, registry: Arc<EventSchemaRegistry>) -> Self {
+        self.event_registry = Some(registry);
+        self
+    }
+
+    /// Keep events failing validation in `quarantine`.
+    ///
+    /// The default quarantine keeps the latest events in memory.
+    #[must_use]
+    pub fn with_event_quarantine(mut self, quarantine: Arc<dyn EventQuarantine>) -> Self {
+        self.quarantine = quarantine;
+        self
+    }
+
     /// Get a shared reference to the dead letter queue.
     ///
     /// Used by wrappers (e.g. `DedupedObserverExecutor`) to route violation
@@ -268,7 +401,10 @@ impl ObserverExecutor {
 
     /// Process an event through all matching observers
     ///
-    /// This is the main entry point. For each matching observer:
+    /// This is the main entry point. An event failing validation against the
+    /// [event registry](Self::with_event_registry) is quarantined and
+    /// reported in [`ExecutionSummary::quarantined`]. Otherwise, for each
+    /// matching observer:
     /// 1. Evaluate condition (if present)
     /// 2. Execute actions with retry logic
     /// 3. Handle failures via DLQ
@@ -278,14 +414,69 @@ impl ObserverExecutor {
     /// Propagates errors from the condition parser if a condition expression is invalid.
     /// Action execution failures are recorded in the `ExecutionSummary` rather than
     /// returned as errors.
-    #[allow(clippy::cognitive_complexity)] // Reason: event processing with condition evaluation, action dispatch, and summary collection
     pub async fn process_event(&self, event: &EntityEvent) -> Result<ExecutionSummary> {
+        self.process(event, false, None).await
+    }
+
+    /// Process an event through only the named subset of its matching observers.
+    ///
+    /// Used by the dedup wrapper when per-observer dedup strategies let some
+    /// observers claim an event that others already handled.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`process_event`](Self::process_event).
+    #[cfg(feature = "dedup")]
+    pub(crate) async fn process_event_for(
+        &self,
+        event: &EntityEvent,
+        observers: &HashSet<&str>,
+    ) -> Result<ExecutionSummary> {
+        self.process(event, false, Some(observers)).await
+    }
+
+    /// The observers whose event type and entity match `event`, by name.
+    #[cfg(feature = "dedup")]
+    pub(crate) fn matching_observers(
+        &self,
+        event: &EntityEvent,
+    ) -> Vec<(&str, &crate::config::ObserverDefinition)> {
+        self.matcher.find_named_matches(event)
+    }
+
+    /// Process an event re-delivered from storage by a replay.
+    ///
+    /// Identical to [`process_event`](Self::process_event) except that each
+    /// matching observer's [`ReplayPolicy`](crate::config::ReplayPolicy) decides
+    /// which of its actions run. Actions the policy excludes are counted in
+    /// [`ExecutionSummary::replay_skipped`] and never dispatched.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`process_event`](Self::process_event).
+    pub async fn process_replayed_event(&self, event: &EntityEvent) -> Result<ExecutionSummary> {
+        self.process(event, true, None).await
+    }
+
+    #[allow(clippy::cognitive_complexity)] // Reason: event processing with condition evaluation, action dispatch, and summary collection
+    async fn process(
+        &self,
+        event: &EntityEvent,
+        replay: bool,
+        only: Option<&HashSet<&str>>,
+    ) -> Result<ExecutionSummary> {
         // Record metrics
         #[cfg(feature = "metrics")]
         self.metrics.event_processed();
 
         let mut summary = ExecutionSummary::new();
-        let matching_observers = self.matcher.find_matches(event);
+        if let Some(registry) = &self.event_registry {
+            if let Err(violations) = registry.validate(event) {
+                self.quarantine_event(event, &violations, &mut summary).await;
+                return Ok(summary);
+            }
+        }
+        let matching_observers = self.matcher.find_named_matches(event);
 
         debug!(
             "Processing event {} (entity_type: {}, event_type: {:?})",
@@ -293,7 +484,11 @@ impl ObserverExecutor {
         );
         debug!("Found {} matching observers for this event", matching_observers.len());
 
-        for observer in matching_observers {
+        for (observer_name, observer) in matching_observers {
+            if only.is_some_and(|names| !names.contains(observer_name)) {
+                continue;
+            }
+
             // Skip if condition is not met.
             // The parsed AST is cached in `condition_cache` so we only lex/parse
             // the condition string once per unique condition across all events.
@@ -336,8 +531,25 @@ impl ObserverExecutor {
             // Execute actions for this observer. The index identifies the
             // action within this observer's list so the per-action detail can
             // be attributed in a durable execution log (#468).
+            let dry_run = self.simulation || observer.dry_run;
             for (action_index, action) in observer.actions.iter().enumerate() {
+                if replay && !observer.replay.runs(action) {
+                    debug!(
+                        "Skipping {} action on replayed event {} (replay policy {:?})",
+                        action.action_type(),
+                        event.id,
+                        observer.replay
+                    );
+                    summary.replay_skipped += 1;
+                    continue;
+                }
+                if dry_run {
+                    self.capture_dry_run(observer_name, action_index, action, event, &mut summary)
+                        .await;
+                    continue;
+                }
                 self.execute_action_with_retry(
+                    observer_name,
                     action,
                     event,
                     &observer.retry,
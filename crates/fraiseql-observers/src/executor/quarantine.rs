@@ -0,0 +1,38 @@
+//! Quarantine of events failing their event type's schema.
+
+use tracing::{error, warn};
+
+use super::{ExecutionSummary, ObserverExecutor, summary};
+use crate::{
+    event::EntityEvent,
+    registry::{QuarantinedEvent, SchemaViolation},
+};
+
+impl ObserverExecutor {
+    /// Hand `event` to the quarantine instead of any observer.
+    ///
+    /// An entry that cannot be stored is logged and counted in
+    /// `summary.errors`; the event is still not processed.
+    pub(super) async fn quarantine_event(
+        &self,
+        event: &EntityEvent,
+        violations: &[SchemaViolation],
+        summary: &mut ExecutionSummary,
+    ) {
+        summary.quarantined = true;
+        warn!(
+            event_id = %event.id,
+            entity_type = %event.entity_type,
+            violations = violations.len(),
+            first = %violations.first().map(ToString::to_string).unwrap_or_default(),
+            "Quarantining event that failed validation"
+        );
+        let entry = QuarantinedEvent::new(event, violations);
+        if let Err(e) = self.quarantine.quarantine(&entry).await {
+            error!(event_id = %event.id, "Failed to quarantine event: {e}");
+            if summary.errors.len() < summary::MAX_ERROR_STRINGS {
+                summary.errors.push(e.to_string());
+            }
+        }
+    }
+}
@@ -13,15 +13,17 @@ use crate::{
 };
 
 impl ObserverExecutor {
-    /// Execute a single action with retry logic.
+    /// Execute a single action of observer `observer_name` with retry logic.
     ///
     /// `action_index` is the action's position within its observer's action
     /// list; it is recorded on the [`ActionExecutionDetail`] pushed onto
     /// `summary.action_details` so embedders can attribute a durable execution
     /// log entry to the right action (#468).
     #[allow(clippy::cognitive_complexity)] // Reason: retry loop with backoff, jitter, and per-attempt logging — inherently sequential
+    #[allow(clippy::too_many_arguments)] // Reason: the observer name, action and its policies are all per-call inputs
     pub(crate) async fn execute_action_with_retry(
         &self,
+        observer_name: &str,
         action: &ActionConfig,
         event: &EntityEvent,
         retry_config: &RetryConfig,
@@ -45,7 +47,15 @@ impl ObserverExecutor {
                     info!("Action {} succeeded in {}ms", action.action_type(), result.duration_ms);
                     // Record metrics for successful action execution
                     #[cfg(feature = "metrics")]
-                    self.metrics.action_executed(&result.action_type, result.duration_ms / 1000.0);
+                    self.metrics.action_executed(
+                        &crate::metrics::ActionLabels {
+                            observer:    observer_name,
+                            action_type: &result.action_type,
+                            entity_type: &event.entity_type,
+                            tenant:      event.tenant_id.as_deref(),
+                        },
+                        result.duration_ms / 1000.0,
+                    );
 
                     summary.successful_actions += 1;
                     summary.total_duration_ms += result.duration_ms;
@@ -66,8 +76,15 @@ impl ObserverExecutor {
                     if !is_transient {
                         // Permanent error, don't retry
                         warn!("Permanent error in action {}: {}", action.action_type(), e);
-                        self.handle_action_failure(action, event, &e, failure_policy, summary)
-                            .await;
+                        self.handle_action_failure(
+                            observer_name,
+                            action,
+                            event,
+                            &e,
+                            failure_policy,
+                            summary,
+                        )
+                        .await;
                         Self::record_failure_detail(summary, action, action_index, &e);
                         return;
                     }
@@ -75,8 +92,15 @@ impl ObserverExecutor {
                     if attempt >= retry_config.max_attempts {
                         // Retries exhausted
                         error!("Action {} failed after {} attempts", action.action_type(), attempt);
-                        self.handle_action_failure(action, event, &e, failure_policy, summary)
-                            .await;
+                        self.handle_action_failure(
+                            observer_name,
+                            action,
+                            event,
+                            &e,
+                            failure_policy,
+                            summary,
+                        )
+                        .await;
                         Self::record_failure_detail(summary, action, action_index, &e);
                         return;
                     }
@@ -54,10 +54,19 @@ pub struct ExecutionSummary {
     pub duplicate_skipped:  bool,
     /// Whether this event was rejected due to a tenant scope violation
     pub tenant_rejected:    bool,
+    /// Whether this event was quarantined because it failed its event type's
+    /// schema
+    pub quarantined:        bool,
     /// Number of cache hits during action execution
     pub cache_hits:         usize,
     /// Number of cache misses during action execution
     pub cache_misses:       usize,
+    /// Number of actions not run because the event was replayed and the
+    /// observer's [`ReplayPolicy`](crate::config::ReplayPolicy) excludes them
+    pub replay_skipped:     usize,
+    /// Number of actions captured instead of run because their observer is
+    /// in dry-run mode
+    pub dry_run_captured:   usize,
     /// Per-action execution details, in dispatch order (#468).
     ///
     /// Populated by [`process_event`](super::ObserverExecutor::process_event)
@@ -5,7 +5,9 @@ use serde_json::json;
 
 use super::*;
 use crate::{
-    config::{ActionConfig, BackoffStrategy, FailurePolicy, RetryConfig},
+    config::{
+        ActionConfig, BackoffStrategy, DeliveryOrdering, FailurePolicy, ReplayPolicy, RetryConfig,
+    },
     error::ObserverError,
     event::{EntityEvent, EventKind},
     matcher::EventMatcher,
@@ -112,8 +114,11 @@ fn test_execution_summary_success() {
         errors:             vec![],
         duplicate_skipped:  false,
         tenant_rejected:    false,
+        quarantined:        false,
         cache_hits:         0,
         cache_misses:       0,
+        replay_skipped:     0,
+        dry_run_captured:   0,
         action_details:     Vec::new(),
     };
 
@@ -132,8 +137,11 @@ fn test_execution_summary_failure() {
         errors:             vec![],
         duplicate_skipped:  false,
         tenant_rejected:    false,
+        quarantined:        false,
         cache_hits:         0,
         cache_misses:       0,
+        replay_skipped:     0,
+        dry_run_captured:   0,
         action_details:     Vec::new(),
     };
 
@@ -350,6 +358,8 @@ fn webhook_action() -> ActionConfig {
         body_template:      None,
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     }
 }
 
@@ -391,7 +401,15 @@ async fn test_retry_happy_path_succeeds_first_attempt() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &failure_policy, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &failure_policy,
+            &mut summary,
+            0,
+        )
         .await;
 
     assert_eq!(summary.successful_actions, 1, "expected 1 success");
@@ -414,7 +432,15 @@ async fn test_retry_total_duration_accumulated_on_success() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &FailurePolicy::Log, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &FailurePolicy::Log,
+            &mut summary,
+            0,
+        )
         .await;
 
     assert!((summary.total_duration_ms - 42.0).abs() < f64::EPSILON);
@@ -472,7 +498,15 @@ async fn test_retry_transient_then_success() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &FailurePolicy::Log, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &FailurePolicy::Log,
+            &mut summary,
+            0,
+        )
         .await;
 
     assert_eq!(summary.successful_actions, 1);
@@ -500,7 +534,15 @@ async fn test_retry_permanent_error_no_retry() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &FailurePolicy::Log, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &FailurePolicy::Log,
+            &mut summary,
+            0,
+        )
         .await;
 
     // Called exactly once — no retry for permanent errors
@@ -530,7 +572,15 @@ async fn test_retry_exhausted_after_max_attempts() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &FailurePolicy::Log, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &FailurePolicy::Log,
+            &mut summary,
+            0,
+        )
         .await;
 
     // Should have been called max_attempts times (3) then failed
@@ -560,7 +610,15 @@ async fn test_retry_single_attempt_max_no_retry() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &FailurePolicy::Log, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &FailurePolicy::Log,
+            &mut summary,
+            0,
+        )
         .await;
 
     assert_eq!(dispatcher.call_count(), 1);
@@ -611,6 +669,7 @@ async fn test_retry_two_transient_then_success() {
     let mut summary = ExecutionSummary::new();
     executor
         .execute_action_with_retry(
+            "orders",
             &webhook_action(),
             &test_event(),
             &make_retry(5, 0),
@@ -639,7 +698,7 @@ async fn test_failure_policy_log_increments_failed_actions() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .handle_action_failure(&action, &event, &error, &FailurePolicy::Log, &mut summary)
+        .handle_action_failure("orders", &action, &event, &error, &FailurePolicy::Log, &mut summary)
         .await;
 
     assert_eq!(summary.failed_actions, 1);
@@ -657,7 +716,14 @@ async fn test_failure_policy_alert_increments_failed_actions() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .handle_action_failure(&action, &event, &error, &FailurePolicy::Alert, &mut summary)
+        .handle_action_failure(
+            "orders",
+            &action,
+            &event,
+            &error,
+            &FailurePolicy::Alert,
+            &mut summary,
+        )
         .await;
 
     assert_eq!(summary.failed_actions, 1);
@@ -678,7 +744,7 @@ async fn test_failure_policy_dlq_success_no_dlq_error() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .handle_action_failure(&action, &event, &error, &FailurePolicy::Dlq, &mut summary)
+        .handle_action_failure("orders", &action, &event, &error, &FailurePolicy::Dlq, &mut summary)
         .await;
 
     assert_eq!(summary.failed_actions, 1);
@@ -733,7 +799,7 @@ async fn test_failure_policy_dlq_error_counted() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .handle_action_failure(&action, &event, &error, &FailurePolicy::Dlq, &mut summary)
+        .handle_action_failure("orders", &action, &event, &error, &FailurePolicy::Dlq, &mut summary)
         .await;
 
     assert_eq!(summary.dlq_errors, 1);
@@ -749,6 +815,7 @@ async fn test_failure_policy_log_does_not_touch_dlq() {
     let mut summary = ExecutionSummary::new();
     executor
         .handle_action_failure(
+            "orders",
             &webhook_action(),
             &test_event(),
             &ObserverError::ActionExecutionFailed {
@@ -779,6 +846,8 @@ async fn test_dispatch_webhook_missing_url_returns_invalid_config() {
         body_template:      None,
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     };
     let event = test_event();
 
@@ -801,6 +870,8 @@ async fn test_dispatch_webhook_url_env_var_missing_returns_error_with_var_name()
         body_template:      None,
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     };
     let event = test_event();
 
@@ -823,8 +894,11 @@ async fn test_dispatch_slack_missing_webhook_url_returns_invalid_config() {
     let action = ActionConfig::Slack {
         webhook_url:      None,
         webhook_url_env:  None,
+        bot_token_env:    None,
         channel:          None,
         message_template: None,
+        blocks_template:  None,
+        thread_by_entity: false,
     };
     let event = test_event();
 
@@ -836,6 +910,67 @@ async fn test_dispatch_slack_missing_webhook_url_returns_invalid_config() {
     );
 }
 
+#[tokio::test]
+async fn test_dispatch_slack_thread_by_entity_requires_bot_token() {
+    let executor = create_test_executor();
+    let webhook_thread = ActionConfig::Slack {
+        webhook_url:      Some("https://hooks.slack.com/services/T00/B00/XX".to_string()),
+        webhook_url_env:  None,
+        bot_token_env:    None,
+        channel:          None,
+        message_template: None,
+        blocks_template:  None,
+        thread_by_entity: true,
+    };
+    let missing_token = ActionConfig::Slack {
+        webhook_url:      None,
+        webhook_url_env:  None,
+        bot_token_env:    Some("FRAISEQL_TEST_UNSET_SLACK_BOT_TOKEN".to_string()),
+        channel:          Some("C0123456789".to_string()),
+        message_template: None,
+        blocks_template:  None,
+        thread_by_entity: true,
+    };
+    let event = test_event();
+
+    // Neither case may fall back to an unthreaded webhook post.
+    for action in [webhook_thread, missing_token] {
+        let result = executor.execute_action_internal(&action, &event).await;
+        assert!(
+            matches!(result, Err(ObserverError::InvalidActionConfig { .. })),
+            "misconfigured slack threading should return InvalidActionConfig: {result:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_dispatch_chat_webhook_missing_url_returns_invalid_config() {
+    let executor = create_test_executor();
+    let teams = ActionConfig::Teams {
+        webhook_url:      None,
+        webhook_url_env:  Some("FRAISEQL_TEST_UNSET_TEAMS_WEBHOOK_URL".to_string()),
+        title_template:   None,
+        message_template: None,
+        card_template:    None,
+    };
+    let discord = ActionConfig::Discord {
+        webhook_url:      None,
+        webhook_url_env:  Some("FRAISEQL_TEST_UNSET_DISCORD_WEBHOOK_URL".to_string()),
+        username:         None,
+        message_template: None,
+        embed_template:   None,
+    };
+    let event = test_event();
+
+    for action in [teams, discord] {
+        let result = executor.execute_action_internal(&action, &event).await;
+        assert!(
+            matches!(result, Err(ObserverError::InvalidActionConfig { .. })),
+            "an unset webhook URL env var should return InvalidActionConfig: {result:?}"
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_dispatch_email_missing_to_returns_invalid_config() {
     let executor = create_test_executor();
@@ -878,44 +1013,78 @@ async fn test_dispatch_email_missing_subject_returns_invalid_config() {
     );
 }
 
-// H24: SMS / Push / Search have no real transport. They previously fabricated
-// `success: true` at dispatch and sent nothing; dispatch now fails loud with
-// `UnsupportedActionType` even for well-formed configs. Cache gained a real
+// H24: Search has no real transport. It previously fabricated `success: true`
+// at dispatch and sent nothing; dispatch now fails loud with
+// `UnsupportedActionType` even for well-formed configs. SMS and Push gained
+// provider transports but fail loud without a provider. Cache gained a real
 // Redis transport (#428) but still fails loud when no backend is wired.
 
+fn sms_action(provider: Option<crate::config::SmsProvider>) -> ActionConfig {
+    ActionConfig::Sms {
+        phone: Some("+15551234567".to_string()),
+        phone_template: None,
+        message_template: Some("Order {{ id }} shipped".to_string()),
+        provider,
+        senders: crate::config::SmsSenderPool {
+            default:    vec!["+15005550006".to_string()],
+            by_country: std::collections::BTreeMap::new(),
+        },
+    }
+}
+
 #[tokio::test]
-async fn test_dispatch_sms_returns_unsupported() {
+async fn test_dispatch_sms_without_provider_fails_loud() {
     let executor = create_test_executor();
-    let action = ActionConfig::Sms {
-        phone:            Some("+15551234567".to_string()),
-        phone_template:   None,
-        message_template: Some("Hi".to_string()),
-    };
     let event = test_event();
 
-    let result = executor.execute_action_internal(&action, &event).await;
+    let result = executor.execute_action_internal(&sms_action(None), &event).await;
 
     assert!(
-        matches!(result, Err(ObserverError::UnsupportedActionType { .. })),
-        "SMS dispatch must fail loud as unsupported, never fabricate success: {result:?}"
+        matches!(result, Err(ObserverError::InvalidActionConfig { .. })),
+        "SMS dispatch without a provider must fail loud, never fabricate success: {result:?}"
     );
 }
 
 #[tokio::test]
-async fn test_dispatch_push_returns_unsupported() {
+async fn test_dispatch_sms_skips_opted_out_recipient() {
+    let opt_outs = Arc::new(crate::actions::sms::InMemoryOptOutList::new());
+    opt_outs.insert("+15551234567");
+    let executor = create_test_executor().with_sms_opt_out_list(opt_outs);
+    // The credentials env var is unset: the opt-out check must short-circuit
+    // before the provider is ever contacted.
+    let provider = crate::config::SmsProvider::Twilio {
+        account_sid:         "AC0123456789abcdef0123456789abcdef".to_string(),
+        auth_token_env:      "FRAISEQL_TEST_UNSET_TWILIO_TOKEN".to_string(),
+        status_callback_url: None,
+    };
+    let event = test_event();
+
+    let result = executor.execute_action_internal(&sms_action(Some(provider)), &event).await;
+
+    let result = result.unwrap();
+    assert!(result.success);
+    assert!(result.message.contains("opted out"), "{}", result.message);
+}
+
+#[tokio::test]
+async fn test_dispatch_push_without_provider_fails_loud() {
     let executor = create_test_executor();
     let action = ActionConfig::Push {
-        device_token:   Some("token".to_string()),
-        title_template: Some("title".to_string()),
-        body_template:  Some("body".to_string()),
+        device_token:          Some("token".to_string()),
+        device_token_template: None,
+        title_template:        Some("title".to_string()),
+        body_template:         Some("body".to_string()),
+        collapse_key:          None,
+        provider:              None,
+        invalid_token_webhook: None,
     };
     let event = test_event();
 
     let result = executor.execute_action_internal(&action, &event).await;
 
     assert!(
-        matches!(result, Err(ObserverError::UnsupportedActionType { .. })),
-        "Push dispatch must fail loud as unsupported: {result:?}"
+        matches!(result, Err(ObserverError::InvalidActionConfig { .. })),
+        "Push dispatch without a provider must fail loud: {result:?}"
     );
 }
 
@@ -946,6 +1115,7 @@ async fn test_dispatch_cache_without_backend_fails_loud() {
     let action = ActionConfig::Cache {
         key_pattern: "app:user:{{ id }}".to_string(),
         action:      "invalidate".to_string(),
+        cdn:         None,
     };
     let event = test_event();
 
@@ -963,8 +1133,11 @@ async fn test_dispatch_slack_url_env_var_missing_error() {
     let action = ActionConfig::Slack {
         webhook_url:      None,
         webhook_url_env:  Some("FRAISEQL_TEST_SLACK_URL_MISSING_VAR".to_string()),
+        bot_token_env:    None,
         channel:          None,
         message_template: None,
+        blocks_template:  None,
+        thread_by_entity: false,
     };
     let event = test_event();
 
@@ -1008,16 +1181,21 @@ async fn test_process_event_with_mock_dispatcher_success() {
     dispatcher.expect_ok("webhook", 10.0);
 
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![webhook_action()],
-        retry:      RetryConfig {
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![webhook_action()],
+        retry:           RetryConfig {
             max_attempts: 1,
             initial_delay_ms: 0,
             ..RetryConfig::default()
         },
-        on_failure: FP::Log,
+        on_failure:      FP::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = std::collections::HashMap::new();
     observers.insert("obs".to_string(), observer);
@@ -1047,16 +1225,21 @@ async fn test_process_event_mock_dispatcher_failure_goes_to_log_policy() {
     );
 
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![webhook_action()],
-        retry:      RetryConfig {
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![webhook_action()],
+        retry:           RetryConfig {
             max_attempts: 1,
             initial_delay_ms: 0,
             ..RetryConfig::default()
         },
-        on_failure: FP::Log,
+        on_failure:      FP::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = std::collections::HashMap::new();
     observers.insert("obs".to_string(), observer);
@@ -1071,6 +1254,122 @@ async fn test_process_event_mock_dispatcher_failure_goes_to_log_policy() {
     assert_eq!(summary.successful_actions, 0);
 }
 
+fn dry_run_executor(
+    dry_run: bool,
+    dispatcher: Arc<crate::testing::mocks::MockActionDispatcher>,
+) -> ObserverExecutor {
+    use crate::config::ObserverDefinition;
+
+    let observer = ObserverDefinition {
+        event_type: "INSERT".to_string(),
+        entity: "Order".to_string(),
+        condition: Some("id == 42".to_string()),
+        actions: vec![webhook_action()],
+        retry: make_retry(1, 0),
+        on_failure: FailurePolicy::Dlq,
+        replay: ReplayPolicy::default(),
+        ordering: DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup: None,
+        dry_run,
+    };
+    let mut observers = std::collections::HashMap::new();
+    observers.insert("orders".to_string(), observer);
+    let matcher = EventMatcher::build(observers).unwrap();
+    let dlq = Arc::new(crate::testing::mocks::MockDeadLetterQueue::new());
+    make_mock_executor_with_matcher(matcher, dispatcher, dlq)
+}
+
+#[tokio::test]
+async fn test_dry_run_observer_captures_instead_of_dispatching() {
+    let dispatcher = Arc::new(crate::testing::mocks::MockActionDispatcher::new());
+    let sink = Arc::new(crate::dryrun::InMemoryDryRunSink::new());
+    let executor = dry_run_executor(true, Arc::clone(&dispatcher)).with_dry_run_sink(sink.clone());
+
+    let event = test_event();
+    let summary = executor.process_event(&event).await.unwrap();
+
+    assert_eq!(dispatcher.call_count(), 0, "a dry-run observer must not dispatch");
+    assert_eq!(summary.dry_run_captured, 1);
+    assert_eq!(summary.total_actions(), 0);
+    let captures = sink.captures();
+    assert_eq!(captures.len(), 1);
+    assert_eq!(captures[0].observer, "orders");
+    assert_eq!(captures[0].event_id, event.id);
+    assert_eq!(captures[0].payload.as_ref().unwrap()["body"], json!({"id": 42}));
+}
+
+#[tokio::test]
+async fn test_simulation_mode_runs_every_observer_dry() {
+    let dispatcher = Arc::new(crate::testing::mocks::MockActionDispatcher::new());
+    dispatcher.expect_ok("webhook", 1.0);
+    let sink = Arc::new(crate::dryrun::InMemoryDryRunSink::new());
+    let executor = dry_run_executor(false, Arc::clone(&dispatcher))
+        .with_simulation(true)
+        .with_dry_run_sink(sink.clone());
+
+    let summary = executor.process_event(&test_event()).await.unwrap();
+
+    assert_eq!(dispatcher.call_count(), 0);
+    assert_eq!(summary.dry_run_captured, 1);
+    assert_eq!(sink.len(), 1);
+
+    // Conditions still apply: an event failing one captures nothing.
+    let other = crate::event::EntityEvent::new(
+        EventKind::Created,
+        "Order".to_string(),
+        uuid::Uuid::new_v4(),
+        json!({"id": 7}),
+    );
+    let summary = executor.process_event(&other).await.unwrap();
+    assert_eq!(summary.conditions_skipped, 1);
+    assert_eq!(sink.len(), 1);
+}
+
+#[tokio::test]
+async fn test_event_failing_its_schema_is_quarantined_not_dispatched() {
+    use crate::registry::{EventSchemaRegistry, EventTypeDefinition, InMemoryEventQuarantine};
+
+    let dispatcher = Arc::new(crate::testing::mocks::MockActionDispatcher::new());
+    dispatcher.expect_ok("webhook", 1.0);
+    let registry = Arc::new(EventSchemaRegistry::default());
+    registry
+        .register(EventTypeDefinition {
+            entity_type: "Order".to_string(),
+            event_kinds: Vec::new(),
+            schema:      json!({
+                "type": "object",
+                "required": ["id"],
+                "properties": { "id": { "type": "integer", "minimum": 1 } },
+            }),
+            description: None,
+        })
+        .unwrap();
+    let quarantine = Arc::new(InMemoryEventQuarantine::new());
+    let executor = dry_run_executor(false, Arc::clone(&dispatcher))
+        .with_event_registry(registry)
+        .with_event_quarantine(quarantine.clone());
+
+    let invalid = crate::event::EntityEvent::new(
+        EventKind::Created,
+        "Order".to_string(),
+        uuid::Uuid::new_v4(),
+        json!({"id": "42"}),
+    );
+    let summary = executor.process_event(&invalid).await.unwrap();
+    assert!(summary.quarantined);
+    assert_eq!(dispatcher.call_count(), 0, "a quarantined event must reach no observer");
+    let entries = quarantine.list(None, 10).await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].event.id, invalid.id);
+    assert_eq!(entries[0].violations[0].path, "/id");
+
+    let summary = executor.process_event(&test_event()).await.unwrap();
+    assert!(!summary.quarantined);
+    assert_eq!(dispatcher.call_count(), 1);
+    assert_eq!(quarantine.len(), 1);
+}
+
 #[tokio::test]
 async fn test_process_event_condition_false_skips_action() {
     use crate::config::{FailurePolicy as FP, ObserverDefinition, RetryConfig};
@@ -1081,12 +1380,17 @@ async fn test_process_event_condition_false_skips_action() {
     // Condition always false: id == 99999 won't match json({"id":42})
     // Using a numeric field that exists so eval_comparison returns Ok(false)
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  Some("id == 99999".to_string()),
-        actions:    vec![webhook_action()],
-        retry:      RetryConfig::default(),
-        on_failure: FP::Log,
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       Some("id == 99999".to_string()),
+        actions:         vec![webhook_action()],
+        retry:           RetryConfig::default(),
+        on_failure:      FP::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = std::collections::HashMap::new();
     observers.insert("obs".to_string(), observer);
@@ -1114,16 +1418,21 @@ async fn test_process_event_multiple_observers_all_succeed() {
     let mut observers_map = std::collections::HashMap::new();
     for i in 0..3usize {
         let observer = ObserverDefinition {
-            event_type: "INSERT".to_string(),
-            entity:     "Order".to_string(),
-            condition:  None,
-            actions:    vec![webhook_action()],
-            retry:      RetryConfig {
+            event_type:      "INSERT".to_string(),
+            entity:          "Order".to_string(),
+            condition:       None,
+            actions:         vec![webhook_action()],
+            retry:           RetryConfig {
                 max_attempts: 1,
                 initial_delay_ms: 0,
                 ..RetryConfig::default()
             },
-            on_failure: FP::Log,
+            on_failure:      FP::Log,
+            replay:          ReplayPolicy::default(),
+            ordering:        DeliveryOrdering::default(),
+            max_concurrency: None,
+            dedup:           None,
+            dry_run:         false,
         };
         observers_map.insert(format!("obs_{i}"), observer);
     }
@@ -1147,22 +1456,28 @@ async fn test_process_event_multiple_actions_in_one_observer() {
     dispatcher.expect_ok("cache", 2.0);
 
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![
             webhook_action(),
             ActionConfig::Cache {
                 key_pattern: "orders:*".to_string(),
                 action:      "invalidate".to_string(),
+                cdn:         None,
             },
         ],
-        retry:      RetryConfig {
+        retry:           RetryConfig {
             max_attempts: 1,
             initial_delay_ms: 0,
             ..RetryConfig::default()
         },
-        on_failure: FP::Log,
+        on_failure:      FP::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = std::collections::HashMap::new();
     observers.insert("obs".to_string(), observer);
@@ -1190,16 +1505,21 @@ async fn test_process_event_dlq_policy_pushes_on_failure() {
     );
 
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![webhook_action()],
-        retry:      RetryConfig {
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![webhook_action()],
+        retry:           RetryConfig {
             max_attempts: 1,
             initial_delay_ms: 0,
             ..RetryConfig::default()
         },
-        on_failure: FP::Dlq,
+        on_failure:      FP::Dlq,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = std::collections::HashMap::new();
     observers.insert("obs".to_string(), observer);
@@ -1223,6 +1543,7 @@ async fn test_failure_policy_log_does_not_increment_dlq_errors() {
 
     executor
         .handle_action_failure(
+            "orders",
             &webhook_action(),
             &test_event(),
             &ObserverError::ActionExecutionFailed {
@@ -1244,6 +1565,7 @@ async fn test_failure_policy_alert_does_not_increment_dlq_errors() {
 
     executor
         .handle_action_failure(
+            "orders",
             &webhook_action(),
             &test_event(),
             &ObserverError::ActionExecutionFailed {
@@ -1274,6 +1596,7 @@ async fn test_mock_dispatcher_call_log_records_action_type() {
         ActionConfig::Cache {
             key_pattern: "k:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         },
     ];
 
@@ -1281,6 +1604,7 @@ async fn test_mock_dispatcher_call_log_records_action_type() {
         let mut s = ExecutionSummary::new();
         executor
             .execute_action_with_retry(
+                "orders",
                 action,
                 &test_event(),
                 &make_retry(1, 0),
@@ -1307,6 +1631,7 @@ async fn test_execution_summary_is_success_with_mock() {
     let mut summary = ExecutionSummary::new();
     executor
         .execute_action_with_retry(
+            "orders",
             &webhook_action(),
             &test_event(),
             &make_retry(1, 0),
@@ -1337,6 +1662,7 @@ async fn test_execution_summary_not_success_on_failure() {
     let mut summary = ExecutionSummary::new();
     executor
         .execute_action_with_retry(
+            "orders",
             &webhook_action(),
             &test_event(),
             &make_retry(1, 0),
@@ -1368,6 +1694,7 @@ async fn test_dlq_size_limit_allows_push_when_under_cap() {
 
     executor
         .handle_action_failure(
+            "orders",
             &webhook_action(),
             &test_event(),
             &error,
@@ -1397,6 +1724,7 @@ async fn test_dlq_size_limit_drops_entry_when_at_cap() {
         let mut summary = ExecutionSummary::new();
         executor
             .handle_action_failure(
+                "orders",
                 &webhook_action(),
                 &test_event(),
                 &error,
@@ -1412,6 +1740,7 @@ async fn test_dlq_size_limit_drops_entry_when_at_cap() {
     let mut summary = ExecutionSummary::new();
     executor
         .handle_action_failure(
+            "orders",
             &webhook_action(),
             &test_event(),
             &error,
@@ -1442,6 +1771,7 @@ async fn test_dlq_size_limit_none_allows_unbounded_pushes() {
         let mut summary = ExecutionSummary::new();
         executor
             .handle_action_failure(
+                "orders",
                 &webhook_action(),
                 &test_event(),
                 &error,
@@ -1460,12 +1790,17 @@ async fn test_dlq_size_limit_none_allows_unbounded_pushes() {
 fn test_compile_condition_returns_ast_for_valid_condition() {
     use crate::config::runtime::ObserverDefinition;
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  Some("total > 100".to_string()),
-        actions:    vec![],
-        retry:      crate::config::RetryConfig::default(),
-        on_failure: crate::config::FailurePolicy::Log,
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       Some("total > 100".to_string()),
+        actions:         vec![],
+        retry:           crate::config::RetryConfig::default(),
+        on_failure:      crate::config::FailurePolicy::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let ast = observer
         .compile_condition()
@@ -1477,12 +1812,17 @@ fn test_compile_condition_returns_ast_for_valid_condition() {
 fn test_compile_condition_returns_none_when_no_condition() {
     use crate::config::runtime::ObserverDefinition;
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![],
-        retry:      crate::config::RetryConfig::default(),
-        on_failure: crate::config::FailurePolicy::Log,
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![],
+        retry:           crate::config::RetryConfig::default(),
+        on_failure:      crate::config::FailurePolicy::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let ast = observer
         .compile_condition()
@@ -1494,12 +1834,17 @@ fn test_compile_condition_returns_none_when_no_condition() {
 fn test_compile_condition_returns_error_for_invalid_dsl() {
     use crate::config::runtime::ObserverDefinition;
     let observer = ObserverDefinition {
-        event_type: "INSERT".to_string(),
-        entity:     "Order".to_string(),
-        condition:  Some("@@@invalid$$$".to_string()),
-        actions:    vec![],
-        retry:      crate::config::RetryConfig::default(),
-        on_failure: crate::config::FailurePolicy::Log,
+        event_type:      "INSERT".to_string(),
+        entity:          "Order".to_string(),
+        condition:       Some("@@@invalid$$$".to_string()),
+        actions:         vec![],
+        retry:           crate::config::RetryConfig::default(),
+        on_failure:      crate::config::FailurePolicy::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let result = observer.compile_condition();
     assert!(
@@ -1558,8 +1903,76 @@ async fn test_action_timeout_fires_when_dispatcher_is_slow() {
 mod dispatch_tests {
     #![allow(clippy::unwrap_used)] // Reason: test code — panics are intentional
 
-    use super::dispatch::{resolve_signing_secret, resolve_url};
-    use crate::error::ObserverError;
+    use std::sync::Arc;
+
+    use super::dispatch::{DefaultActionDispatcher, resolve_signing_secret, resolve_url};
+    use crate::{
+        actions::{
+            DiscordAction, EmailAction, SlackAction, TeamsAction, WebhookAction,
+            slack::InMemorySlackThreadStore,
+        },
+        error::{ObserverError, Result},
+        signing::SigningSecretResolver,
+    };
+
+    struct StaticSecrets;
+
+    #[async_trait::async_trait]
+    impl SigningSecretResolver for StaticSecrets {
+        async fn resolve(&self, name: &str) -> Result<String> {
+            match name {
+                "webhooks/orders" => Ok("whsec_from_vault".to_string()),
+                "webhooks/empty" => Ok(String::new()),
+                _ => Err(ObserverError::InvalidActionConfig {
+                    reason: format!("secret {name} not found"),
+                }),
+            }
+        }
+    }
+
+    fn dispatcher(
+        signing_secrets: Option<Arc<dyn SigningSecretResolver>>,
+    ) -> DefaultActionDispatcher {
+        DefaultActionDispatcher {
+            webhook_action: Arc::new(WebhookAction::new()),
+            slack_action: Arc::new(SlackAction::new()),
+            slack_threads: parking_lot::RwLock::new(Arc::new(InMemorySlackThreadStore::new())),
+            teams_action: Arc::new(TeamsAction::new()),
+            discord_action: Arc::new(DiscordAction::new()),
+            email_action: Arc::new(EmailAction::new()),
+            #[cfg(feature = "caching")]
+            cache_invalidator: None,
+            cdn_purgers: dashmap::DashMap::new(),
+            push_senders: dashmap::DashMap::new(),
+            sms_actions: dashmap::DashMap::new(),
+            sms_opt_outs: parking_lot::RwLock::new(None),
+            signing_secrets,
+        }
+    }
+
+    #[tokio::test]
+    async fn signing_secret_ref_resolves_through_the_secrets_manager() {
+        let dispatcher = dispatcher(Some(Arc::new(StaticSecrets)));
+        assert_eq!(
+            dispatcher.resolve_signing_secret_ref("webhooks/orders").await.unwrap(),
+            "whsec_from_vault"
+        );
+    }
+
+    #[tokio::test]
+    async fn signing_secret_ref_fails_loud_without_a_secrets_manager() {
+        let result = dispatcher(None).resolve_signing_secret_ref("webhooks/orders").await;
+        assert!(matches!(result, Err(ObserverError::InvalidActionConfig { .. })), "{result:?}");
+    }
+
+    #[tokio::test]
+    async fn signing_secret_ref_empty_or_missing_fails_loud() {
+        let dispatcher = dispatcher(Some(Arc::new(StaticSecrets)));
+        for name in ["webhooks/empty", "webhooks/missing"] {
+            let result = dispatcher.resolve_signing_secret_ref(name).await;
+            assert!(matches!(result, Err(ObserverError::InvalidActionConfig { .. })), "{result:?}");
+        }
+    }
 
     #[test]
     fn signing_secret_none_when_unconfigured() {
@@ -1872,7 +2285,15 @@ async fn email_action_reports_failure_not_silent_success() {
     let mut summary = ExecutionSummary::new();
 
     executor
-        .execute_action_with_retry(&action, &event, &retry, &failure_policy, &mut summary, 0)
+        .execute_action_with_retry(
+            "orders",
+            &action,
+            &event,
+            &retry,
+            &failure_policy,
+            &mut summary,
+            0,
+        )
         .await;
 
     assert_eq!(summary.successful_actions, 0, "a non-sending email must NOT count as success");
@@ -61,6 +61,7 @@ use crate::{
     error::{ObserverError, Result},
     executor::ObserverExecutor,
     matcher::EventMatcher,
+    registry::EventSchemaRegistry,
     traits::DeadLetterQueue,
 };
 
@@ -71,7 +72,7 @@ impl ExecutorFactory {
     /// Build the complete executor stack based on configuration.
     ///
     /// This is the main entry point. It:
-    /// 1. Creates the base `ObserverExecutor`
+    /// 1. Creates the base `ObserverExecutor`, validating events against the declared `event_types`
     /// 2. Wraps with `DedupedObserverExecutor` if `performance.enable_dedup = true`
     /// 3. Configures action caching if `performance.enable_caching = true`
     ///
@@ -117,12 +118,17 @@ impl ExecutorFactory {
             redis_config.validate()?;
         }
         config.performance.validate(config.redis.is_some())?;
+        config.metrics.validate()?;
+        crate::metrics::configure_labels(&config.metrics);
 
         // Create event matcher
         let matcher = EventMatcher::build(config.observers.clone())?;
 
         // Build base executor
-        let base_executor = ObserverExecutor::new(matcher, dlq);
+        let mut base_executor = ObserverExecutor::new(matcher, dlq).with_simulation(config.dry_run);
+        if let Some(registry) = Self::build_event_registry(config)? {
+            base_executor = base_executor.with_event_registry(registry);
+        }
 
         // Wrap with deduplication if enabled
         if config.performance.enable_dedup {
@@ -131,8 +137,15 @@ impl ExecutorFactory {
                     message: "enable_dedup=true requires redis configuration".to_string(),
                 })?;
 
+            for observer in config.observers.values() {
+                if let Some(strategy) = &observer.dedup {
+                    strategy.validate()?;
+                }
+            }
+
             let dedup_store = Self::build_dedup_store(redis_config).await?;
-            let deduped_executor = DedupedObserverExecutor::new(base_executor, dedup_store);
+            let deduped_executor = DedupedObserverExecutor::new(base_executor, dedup_store)
+                .with_strategy(config.performance.dedup_strategy.clone());
 
             Ok(Arc::new(deduped_executor))
         } else {
@@ -158,14 +171,32 @@ impl ExecutorFactory {
         config: &ObserverRuntimeConfig,
         dlq: Arc<dyn DeadLetterQueue>,
     ) -> Result<Arc<dyn ProcessEvent>> {
+        config.metrics.validate()?;
+        crate::metrics::configure_labels(&config.metrics);
+
         // Create event matcher
         let matcher = EventMatcher::build(config.observers.clone())?;
 
         // Build base executor (no wrapping)
-        let base_executor = ObserverExecutor::new(matcher, dlq);
+        let mut base_executor = ObserverExecutor::new(matcher, dlq).with_simulation(config.dry_run);
+        if let Some(registry) = Self::build_event_registry(config)? {
+            base_executor = base_executor.with_event_registry(registry);
+        }
         Ok(Arc::new(base_executor))
     }
 
+    /// The registry of the event types declared in `config`, when events are
+    /// validated at all.
+    fn build_event_registry(
+        config: &ObserverRuntimeConfig,
+    ) -> Result<Option<Arc<EventSchemaRegistry>>> {
+        if !config.event_types.is_enabled() {
+            return Ok(None);
+        }
+        config.event_types.validate()?;
+        Ok(Some(Arc::new(EventSchemaRegistry::from_config(&config.event_types)?)))
+    }
+
     /// Build Redis deduplication store from config
     #[cfg(feature = "dedup")]
     async fn build_dedup_store(redis_config: &RedisConfig) -> Result<RedisDeduplicationStore> {
@@ -407,7 +438,11 @@ impl ExecutorFactory {
         let job_queue = Self::build_job_queue(job_queue_config).await?;
 
         // Create queued executor
-        let queued_executor = QueuedObserverExecutor::new(matcher, job_queue);
+        let mut queued_executor =
+            QueuedObserverExecutor::new(matcher, job_queue).with_simulation(config.dry_run);
+        if let Some(registry) = Self::build_event_registry(config)? {
+            queued_executor = queued_executor.with_event_registry(registry);
+        }
 
         Ok(Arc::new(queued_executor))
     }
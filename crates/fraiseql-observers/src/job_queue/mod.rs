@@ -54,6 +54,18 @@ pub enum JobState {
 }
 
 impl JobState {
+    /// Convert to string representation (matches the serialized form)
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            JobState::Pending => "pending",
+            JobState::Running => "running",
+            JobState::Completed => "completed",
+            JobState::Failed => "failed",
+            JobState::DeadLettered => "dead_lettered",
+        }
+    }
+
     /// Returns true if this is a terminal state
     #[must_use]
     pub const fn is_terminal(self) -> bool {
@@ -218,6 +230,8 @@ impl Job {
         match &self.action {
             ActionConfig::Webhook { .. } => "webhook",
             ActionConfig::Slack { .. } => "slack",
+            ActionConfig::Teams { .. } => "teams",
+            ActionConfig::Discord { .. } => "discord",
             ActionConfig::Email { .. } => "email",
             ActionConfig::Sms { .. } => "sms",
             ActionConfig::Push { .. } => "push",
@@ -31,6 +31,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
@@ -49,6 +50,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
@@ -66,6 +68,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let mut job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
@@ -84,6 +87,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let mut job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
@@ -103,6 +107,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let mut job = Job::new(event_id, action, 2, crate::config::BackoffStrategy::Exponential);
@@ -120,6 +125,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let mut job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
@@ -136,6 +142,7 @@ mod job_queue_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
 
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
@@ -158,6 +165,7 @@ mod job_queue_tests {
             ActionConfig::Cache {
                 key_pattern: "test:*".to_string(),
                 action:      "invalidate".to_string(),
+                cdn:         None,
             },
             3,
             crate::config::BackoffStrategy::Exponential,
@@ -174,6 +182,8 @@ mod job_queue_tests {
                 body_template:      None,
                 signing_secret:     None,
                 signing_secret_env: None,
+                signing_secret_ref: None,
+                signing_key_id:     None,
             },
             3,
             crate::config::BackoffStrategy::Exponential,
@@ -392,6 +402,7 @@ mod redis_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
 
@@ -431,6 +442,7 @@ mod traits_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
         let job_id = job.id;
@@ -451,6 +463,7 @@ mod traits_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
 
@@ -468,6 +481,7 @@ mod traits_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
         let job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
 
@@ -491,6 +505,7 @@ mod traits_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
         let mut job = Job::new(event_id, action, 3, crate::config::BackoffStrategy::Exponential);
 
@@ -512,6 +527,7 @@ mod traits_tests {
         let action = ActionConfig::Cache {
             key_pattern: "test:*".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         };
         let mut job = Job::new(event_id, action, 1, crate::config::BackoffStrategy::Exponential);
 
@@ -30,12 +30,15 @@
 //!
 //! # Key Features
 //!
-//! - **Flexible Actions**: Webhook, email, Slack, SMS, push notifications, cache invalidation,
-//!   search indexing
+//! - **Flexible Actions**: Webhook, email, Slack, Teams, Discord, SMS, push notifications, cache
+//!   invalidation, search indexing
 //! - **Conditions**: DSL for conditional action execution (e.g., `status_changed_to('shipped') &&
 //!   total > 100`)
 //! - **Reliability**: Retry logic with exponential/linear/fixed backoff
 //! - **Dead Letter Queue**: Failed actions stored for manual retry
+//! - **Dry Run**: Per-observer or global simulation capturing rendered payloads instead of sending
+//! - **Event Types**: Declared event types with JSON Schema payloads; invalid events are
+//!   quarantined
 //! - **Backpressure**: Configurable overflow policies (drop, block, drop-oldest)
 //! - **Observable**: Structured logging, Prometheus metrics
 //! - **Testable**: All external dependencies abstracted as traits with mock implementations
@@ -56,6 +59,7 @@ pub mod dedup;
 #[cfg(feature = "dedup")]
 pub mod deduped_executor;
 pub mod dispatch;
+pub mod dryrun;
 pub mod insecure_guard;
 
 #[cfg(feature = "arrow")]
@@ -70,15 +74,17 @@ pub mod job_queue;
 pub mod listener;
 pub mod logging;
 pub mod matcher;
-#[cfg(feature = "metrics")]
 pub mod metrics;
 pub mod migrations;
 pub mod queue;
 #[cfg(feature = "queue")]
 pub mod queued_executor;
+pub mod registry;
+pub mod replay;
 pub mod resilience;
 #[cfg(feature = "search")]
 pub mod search;
+pub mod signing;
 pub mod source;
 pub(crate) mod ssrf;
 pub mod storage;
@@ -92,10 +98,18 @@ pub mod testing;
 mod tests;
 
 // Re-export common types at crate level
-pub use actions::{ActionExecutionResult, EmailAction, SlackAction, WebhookAction};
+#[cfg(feature = "postgres")]
+pub use actions::slack::threads::PostgresSlackThreadStore;
+#[cfg(feature = "redis-slack-threads")]
+pub use actions::slack::threads::RedisSlackThreadStore;
+pub use actions::{
+    ActionExecutionResult, DiscordAction, EmailAction, SlackAction, TeamsAction, WebhookAction,
+    slack::{InMemorySlackThreadStore, SlackThreadStore},
+    sms::{InMemoryOptOutList, SmsOptOutList},
+};
 #[cfg(feature = "caching")]
 pub use cache::redis::{RedisCacheBackend, RedisCacheInvalidator};
-pub use cache::{CacheBackend, CacheStats, CachedActionResult};
+pub use cache::{CacheBackend, CacheStats, CachedActionResult, cdn::CdnPurger};
 #[cfg(feature = "checkpoint")]
 pub use checkpoint::{
     CheckpointMode, CheckpointState, CheckpointStore, CheckpointStrategy, InMemoryCheckpointStore,
@@ -104,17 +118,21 @@ pub use checkpoint::{
 pub use concurrent::ConcurrentActionExecutor;
 pub use condition::{ConditionAst, ConditionParser};
 pub use config::{
-    ActionConfig, BackoffStrategy, EmailSmtpConfig, FailurePolicy, MultiListenerConfig,
-    ObserverDefinition, ObserverRuntimeConfig, OverflowPolicy, RedisConfig, RetryConfig,
-    SmtpTlsMode,
+    ActionConfig, BackoffStrategy, CdnProvider, CdnPurgeConfig, DeliveryOrdering, EmailSmtpConfig,
+    EventTypeDefinition, EventTypesConfig, FailurePolicy, MultiListenerConfig, ObserverDefinition,
+    ObserverRuntimeConfig, OverflowPolicy, PushProvider, RedisConfig, ReplayPolicy, RetryConfig,
+    SmsProvider, SmsSenderPool, SmtpTlsMode, UndeclaredEventPolicy,
 };
 #[cfg(feature = "dedup")]
 pub use dedup::redis::RedisDeduplicationStore;
-pub use dedup::{DeduplicationStats, DeduplicationStore};
+pub use dedup::{DedupStrategy, DeduplicationStats, DeduplicationStore};
 pub use dispatch::{
     DispatchPolicy, DispatchSource, FunctionDispatchRecord, RetryDecision, derive_address_hash_key,
     derive_idempotency_subkey, derive_idempotency_token, hash_address, run_with_retry,
 };
+#[cfg(feature = "postgres")]
+pub use dryrun::PostgresDryRunSink;
+pub use dryrun::{DryRunCapture, DryRunSink, HttpDryRunSink, InMemoryDryRunSink};
 pub use elasticsearch_sink::{ElasticsearchSink, ElasticsearchSinkConfig};
 pub use error::{ObserverError, ObserverErrorCode, Result};
 pub use event::{EntityEvent, EventKind, FieldChanges};
@@ -130,10 +148,13 @@ pub use job_queue::{Job as JobQueueItem, JobState};
 #[cfg(feature = "postgres")]
 pub use listener::{
     ChangeLogEntry, ChangeLogListener, ChangeLogListenerConfig, EventListener, ListenerConfig,
+    PostgresRegionLeaseStore,
 };
 pub use listener::{
-    CheckpointLease, FailoverEvent, FailoverManager, ListenerHandle, ListenerHealth, ListenerState,
-    ListenerStateMachine, MultiListenerCoordinator,
+    CheckpointLease, FailoverEvent, FailoverManager, InMemoryRegionLeaseStore, ListenerHandle,
+    ListenerHealth, ListenerState, ListenerStateMachine, MultiListenerCoordinator,
+    MultiRegionFailover, RegionConfig, RegionFailoverEvent, RegionLease, RegionLeaseStore,
+    RegionRole,
 };
 pub use logging::{
     StructuredLogger, TraceIdExtractor, correlation::TraceContext, get_current_trace_id,
@@ -153,6 +174,18 @@ pub use queue::{
 };
 #[cfg(feature = "queue")]
 pub use queued_executor::{QueuedExecutionSummary, QueuedObserverExecutor};
+pub use registry::{
+    EventQuarantine, EventSchema, EventSchemaRegistry, InMemoryEventQuarantine, QuarantinedEvent,
+    SchemaViolation,
+};
+#[cfg(feature = "postgres")]
+pub use registry::{PostgresEventQuarantine, PostgresEventTypeStore};
+#[cfg(feature = "postgres")]
+pub use replay::PostgresReplayAudit;
+pub use replay::{
+    InMemoryReplayAudit, ReplayAuditRecord, ReplayAuditStore, ReplayEngine, ReplayRequest,
+    ReplayStatus,
+};
 pub use resilience::{
     CircuitBreaker, CircuitBreakerConfig, CircuitState, DegradationLevel, GracefulDegradation,
     PerEndpointCircuitBreaker, ResilienceStrategy, ResilientExecutor,
@@ -160,7 +193,18 @@ pub use resilience::{
 #[cfg(feature = "search")]
 pub use search::http::HttpSearchBackend;
 #[cfg(feature = "search")]
-pub use search::{IndexedEvent, SearchBackend, SearchStats};
+pub use search::meilisearch::MeilisearchBackend;
+#[cfg(feature = "search")]
+pub use search::reindex::{ReindexCursor, ReindexRequest, ReindexRow, ReindexSource, Reindexer};
+#[cfg(feature = "search")]
+pub use search::typesense::TypesenseBackend;
+#[cfg(feature = "search")]
+pub use search::{IndexedEvent, SearchBackend, SearchStats, TypoTolerance};
+pub use signing::{
+    DEFAULT_SIGNATURE_TOLERANCE, SigningSecretResolver, WEBHOOK_KEY_ID_HEADER,
+    WEBHOOK_SIGNATURE_HEADER, WebhookSignatureError, WebhookSigningKey, verify_webhook_signature,
+    verify_webhook_signature_at,
+};
 pub use source::{
     CursorSnapshot, LeaseGuardedRunner, PostgresSourceCursorStore, RunOutcome, SourceCursorStore,
     lock_id,
@@ -220,6 +264,8 @@ mod integration_tests {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         };
 
         assert!(
@@ -236,6 +282,8 @@ mod integration_tests {
             body_template:      Some("{}".to_string()),
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         };
 
         valid
@@ -284,9 +284,13 @@ impl ChangeLogEntry {
             Utc.from_utc_datetime(&ndt)
         };
 
-        // Get entity data (use "after" values, or "before" for DELETE)
+        // Get entity data (use "after" values, or "before" for DELETE). A soft
+        // delete is an UPDATE that keeps the row, so a DELETE without a pre-image
+        // falls back to its after-image.
         let data = if event_kind == EventKind::Deleted {
-            self.before_values().unwrap_or(Value::Object(serde_json::Map::default()))
+            self.before_values()
+                .or_else(|| self.object_data.is_object().then(|| self.object_data.clone()))
+                .unwrap_or(Value::Object(serde_json::Map::default()))
         } else {
             self.after_values()?
         };
@@ -190,6 +190,12 @@ impl FailoverManager {
         self.shutdown.store(true, Ordering::SeqCst);
     }
 
+    /// The coordinator whose listeners this manager fails over.
+    #[must_use]
+    pub const fn coordinator(&self) -> &Arc<MultiListenerCoordinator> {
+        &self.coordinator
+    }
+
     /// Get health check interval
     #[must_use]
     pub const fn health_check_interval_ms(&self) -> u64 {
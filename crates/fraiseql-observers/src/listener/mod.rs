@@ -9,12 +9,14 @@
 //! - `lease.rs`: Distributed checkpoint leasing
 //! - `coordinator.rs`: Multi-listener coordination
 //! - `failover.rs`: Automatic failover management
+//! - `region.rs`: Multi-region failover via a shared region lease
 
 #[cfg(feature = "postgres")]
 pub mod change_log;
 pub mod coordinator;
 pub mod failover;
 pub mod lease;
+pub mod region;
 pub mod state;
 
 #[cfg(feature = "postgres")]
@@ -29,6 +31,12 @@ pub use coordinator::{ListenerHandle, ListenerHealth, MultiListenerCoordinator};
 pub use failover::{FailoverEvent, FailoverManager};
 pub use lease::CheckpointLease;
 #[cfg(feature = "postgres")]
+pub use region::PostgresRegionLeaseStore;
+pub use region::{
+    InMemoryRegionLeaseStore, MultiRegionFailover, RegionConfig, RegionFailoverEvent, RegionLease,
+    RegionLeaseStore, RegionRole,
+};
+#[cfg(feature = "postgres")]
 use sqlx::postgres::{PgListener, PgPool};
 pub use state::{ListenerState, ListenerStateMachine};
 #[cfg(feature = "postgres")]
@@ -0,0 +1,780 @@
+//! Multi-region failover for observer listeners.
+//!
+//! [`FailoverManager`] moves work between listeners of one cluster. Across
+//! regions, exactly one region of a *lease group* runs its listeners at a time;
+//! the others stand by. The active region is decided by a shared
+//! [`RegionLeaseStore`]:
+//!
+//! - Regions are ranked by a priority list ([`RegionConfig`]); the first is preferred.
+//! - The holder renews the lease every tick, reporting the change-log checkpoint its listeners have
+//!   reached. A lease that is not renewed lapses after its TTL.
+//! - A lapsed lease goes to the first region to ask — but a region of rank `r` only asks `r ×
+//!   takeover_stagger` after expiry, so when several regions are healthy the best-ranked one wins.
+//! - With `failback` enabled, a better-ranked region takes the lease back from a worse-ranked
+//!   holder even while it is live.
+//!
+//! Taking the lease hands the checkpoint over: the new region's local leader
+//! resumes from it ([`FailoverManager::resume_from_checkpoint`]). Every change of
+//! holder bumps the lease `epoch` (a fencing token), is logged at `warn`, counted
+//! in the `fraiseql_observer_region_failovers_total` metric (feature `metrics`),
+//! and sent as a [`RegionFailoverEvent`] for alerting.
+//!
+//! [`PostgresRegionLeaseStore`] arbitrates through the
+//! `_fraiseql_observer_region_lease` table under a transaction-scoped advisory
+//! lock (DDL: [`observer_region_lease_sql`](crate::migrations::observer_region_lease_sql));
+//! [`InMemoryRegionLeaseStore`] serves tests and single-process setups.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::{Mutex, mpsc};
+use tracing::{info, warn};
+
+use super::failover::FailoverManager;
+use crate::error::{ObserverError, Result};
+
+/// Region priorities and lease timing for one region's view of a lease group.
+#[derive(Debug, Clone)]
+pub struct RegionConfig {
+    /// Lease group the regions contend for.
+    pub group:            String,
+    /// This region's name.
+    pub local_region:     String,
+    /// All regions of the group, most preferred first.
+    pub priorities:       Vec<String>,
+    /// How long a lease lasts without renewal.
+    pub lease_ttl:        Duration,
+    /// Extra wait per rank before a region takes over a lapsed lease.
+    pub takeover_stagger: Duration,
+    /// Whether a better-ranked region reclaims the lease from a live holder.
+    pub failback:         bool,
+}
+
+impl RegionConfig {
+    /// A configuration with a 30 s lease, a 5 s stagger and failback enabled.
+    #[must_use]
+    pub fn new(
+        group: impl Into<String>,
+        local_region: impl Into<String>,
+        priorities: Vec<String>,
+    ) -> Self {
+        Self {
+            group: group.into(),
+            local_region: local_region.into(),
+            priorities,
+            lease_ttl: Duration::from_secs(30),
+            takeover_stagger: Duration::from_secs(5),
+            failback: true,
+        }
+    }
+
+    /// Set the lease TTL.
+    #[must_use]
+    pub const fn with_lease_ttl(mut self, ttl: Duration) -> Self {
+        self.lease_ttl = ttl;
+        self
+    }
+
+    /// Set the per-rank takeover stagger.
+    #[must_use]
+    pub const fn with_takeover_stagger(mut self, stagger: Duration) -> Self {
+        self.takeover_stagger = stagger;
+        self
+    }
+
+    /// Enable or disable failback to better-ranked regions.
+    #[must_use]
+    pub const fn with_failback(mut self, failback: bool) -> Self {
+        self.failback = failback;
+        self
+    }
+
+    /// Rank of the local region (0 = most preferred).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] if the local region is not in
+    /// the priority list.
+    pub fn local_rank(&self) -> Result<u32> {
+        self.priorities
+            .iter()
+            .position(|r| r == &self.local_region)
+            .and_then(|rank| u32::try_from(rank).ok())
+            .ok_or_else(|| ObserverError::InvalidConfig {
+                message: format!(
+                    "region '{}' is not in the priority list {:?} of lease group '{}'",
+                    self.local_region, self.priorities, self.group
+                ),
+            })
+    }
+}
+
+/// The lease of a group, as stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionLease {
+    /// Lease group.
+    pub group:       String,
+    /// Region holding the lease.
+    pub region:      String,
+    /// Instance within the region that acquired it.
+    pub holder:      String,
+    /// Holder region's rank.
+    pub priority:    u32,
+    /// Last checkpoint the holder reported.
+    pub checkpoint:  i64,
+    /// Incremented on every change of holder.
+    pub epoch:       i64,
+    /// When the current holder took the lease.
+    pub acquired_at: DateTime<Utc>,
+    /// When the lease lapses unless renewed.
+    pub expires_at:  DateTime<Utc>,
+}
+
+/// One region's bid for the lease of a group.
+#[derive(Debug, Clone)]
+pub struct LeaseRequest {
+    /// Lease group.
+    pub group:            String,
+    /// Requesting region.
+    pub region:           String,
+    /// Requesting instance.
+    pub holder:           String,
+    /// Requesting region's rank.
+    pub priority:         u32,
+    /// Checkpoint the requester's listeners have reached, reported on renewal.
+    pub checkpoint:       i64,
+    /// Lease TTL.
+    pub ttl:              Duration,
+    /// Per-rank takeover stagger.
+    pub takeover_stagger: Duration,
+    /// Whether to reclaim the lease from a worse-ranked live holder.
+    pub failback:         bool,
+}
+
+/// Why the lease changed hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailoverReason {
+    /// The group had no lease yet.
+    Initial,
+    /// The holder stopped renewing.
+    Expired,
+    /// The holder released it.
+    Released,
+    /// A better-ranked region reclaimed it.
+    Failback,
+}
+
+impl FailoverReason {
+    /// Metric label / log value.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Initial => "initial",
+            Self::Expired => "expired",
+            Self::Released => "released",
+            Self::Failback => "failback",
+        }
+    }
+}
+
+/// Outcome of one arbitration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Arbitration {
+    /// The requester already held the lease and renewed it.
+    Renewed(RegionLease),
+    /// The requester took the lease.
+    Acquired {
+        /// The new lease.
+        lease:    RegionLease,
+        /// Previous holder region, if any.
+        previous: Option<String>,
+        /// Why the lease changed hands.
+        reason:   FailoverReason,
+    },
+    /// Another region holds the lease.
+    Standby(RegionLease),
+}
+
+impl Arbitration {
+    /// The lease after arbitration.
+    #[must_use]
+    pub const fn lease(&self) -> &RegionLease {
+        match self {
+            Self::Renewed(lease) | Self::Acquired { lease, .. } | Self::Standby(lease) => lease,
+        }
+    }
+}
+
+/// Decide `request` against the `current` lease at time `now`.
+///
+/// Pure; both stores apply it inside their own critical section. A released
+/// lease is stored with `expires_at` in the past and an empty holder.
+#[must_use]
+pub fn arbitrate(
+    current: Option<&RegionLease>,
+    request: &LeaseRequest,
+    now: DateTime<Utc>,
+) -> Arbitration {
+    let expires_at = after(now, request.ttl);
+    let grant = |checkpoint: i64, epoch: i64| RegionLease {
+        group: request.group.clone(),
+        region: request.region.clone(),
+        holder: request.holder.clone(),
+        priority: request.priority,
+        checkpoint,
+        epoch,
+        acquired_at: now,
+        expires_at,
+    };
+
+    let Some(current) = current else {
+        return Arbitration::Acquired {
+            lease:    grant(request.checkpoint, 1),
+            previous: None,
+            reason:   FailoverReason::Initial,
+        };
+    };
+
+    // A holder renews even a lapsed lease nobody has taken yet.
+    if current.holder == request.holder {
+        return Arbitration::Renewed(RegionLease {
+            checkpoint: current.checkpoint.max(request.checkpoint),
+            expires_at,
+            ..current.clone()
+        });
+    }
+
+    let takeover_at =
+        after(current.expires_at, request.takeover_stagger.saturating_mul(request.priority));
+    let released = current.holder.is_empty();
+    let reason = if released {
+        Some(FailoverReason::Released)
+    } else if now >= takeover_at {
+        Some(FailoverReason::Expired)
+    } else if request.failback && request.priority < current.priority {
+        Some(FailoverReason::Failback)
+    } else {
+        None
+    };
+
+    match reason {
+        // The handed-over checkpoint is the holder's, never the requester's:
+        // the requester was standing by and has processed nothing.
+        Some(reason) => Arbitration::Acquired {
+            lease: grant(current.checkpoint, current.epoch + 1),
+            previous: Some(current.region.clone()),
+            reason,
+        },
+        None => Arbitration::Standby(current.clone()),
+    }
+}
+
+/// `at + delay`, saturating at the end of time.
+fn after(at: DateTime<Utc>, delay: Duration) -> DateTime<Utc> {
+    chrono::TimeDelta::from_std(delay)
+        .ok()
+        .and_then(|delay| at.checked_add_signed(delay))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Shared storage for region leases.
+// Reason: used as dyn Trait (Arc<dyn RegionLeaseStore>); async_trait ensures Send bounds and
+// dyn-compatibility
+#[async_trait]
+pub trait RegionLeaseStore: Send + Sync {
+    /// Atomically read the group's lease, apply [`arbitrate`], and persist the
+    /// result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the backing store fails.
+    async fn arbitrate(&self, request: &LeaseRequest) -> Result<Arbitration>;
+
+    /// Give up the lease if `holder` holds it, recording its final checkpoint
+    /// for the next holder. A no-op otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the backing store fails.
+    async fn release(&self, group: &str, holder: &str, checkpoint: i64) -> Result<()>;
+
+    /// The group's current lease, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the backing store fails.
+    async fn current(&self, group: &str) -> Result<Option<RegionLease>>;
+}
+
+/// Mark `lease` released by `holder`, keeping the checkpoint for the next one.
+fn release_lease(lease: &mut RegionLease, holder: &str, checkpoint: i64, now: DateTime<Utc>) {
+    if lease.holder == holder {
+        lease.holder.clear();
+        lease.checkpoint = lease.checkpoint.max(checkpoint);
+        lease.expires_at = now;
+    }
+}
+
+/// Process-local [`RegionLeaseStore`], for tests and single-process setups.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryRegionLeaseStore {
+    leases: Arc<Mutex<HashMap<String, RegionLease>>>,
+}
+
+impl InMemoryRegionLeaseStore {
+    /// An empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RegionLeaseStore for InMemoryRegionLeaseStore {
+    async fn arbitrate(&self, request: &LeaseRequest) -> Result<Arbitration> {
+        let mut leases = self.leases.lock().await;
+        let outcome = arbitrate(leases.get(&request.group), request, Utc::now());
+        leases.insert(request.group.clone(), outcome.lease().clone());
+        Ok(outcome)
+    }
+
+    async fn release(&self, group: &str, holder: &str, checkpoint: i64) -> Result<()> {
+        if let Some(lease) = self.leases.lock().await.get_mut(group) {
+            release_lease(lease, holder, checkpoint, Utc::now());
+        }
+        Ok(())
+    }
+
+    async fn current(&self, group: &str) -> Result<Option<RegionLease>> {
+        Ok(self.leases.lock().await.get(group).cloned())
+    }
+}
+
+/// [`RegionLeaseStore`] on the `_fraiseql_observer_region_lease` table.
+///
+/// Each arbitration runs in one transaction holding
+/// `pg_advisory_xact_lock(hashtext(lease_group))`, and uses the database clock,
+/// so regions with skewed clocks still agree on expiry.
+#[cfg(feature = "postgres")]
+#[derive(Clone)]
+pub struct PostgresRegionLeaseStore {
+    pool: sqlx::PgPool,
+}
+
+#[cfg(feature = "postgres")]
+type LeaseRow = (String, String, String, i32, i64, i64, DateTime<Utc>, DateTime<Utc>);
+
+#[cfg(feature = "postgres")]
+impl PostgresRegionLeaseStore {
+    const SELECT: &'static str = "SELECT lease_group, region, holder, priority, checkpoint, \
+                                  epoch, acquired_at, expires_at \
+                                  FROM _fraiseql_observer_region_lease WHERE lease_group = $1";
+
+    /// A store on `pool`, which must reach the primary all regions share.
+    #[must_use]
+    pub const fn new(pool: sqlx::PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn from_row(row: LeaseRow) -> RegionLease {
+        let (group, region, holder, priority, checkpoint, epoch, acquired_at, expires_at) = row;
+        RegionLease {
+            group,
+            region,
+            holder,
+            priority: u32::try_from(priority).unwrap_or(0),
+            checkpoint,
+            epoch,
+            acquired_at,
+            expires_at,
+        }
+    }
+
+    /// Open a transaction holding the group's advisory lock; returns it with
+    /// the database time and the locked row.
+    async fn lock(
+        &self,
+        group: &str,
+    ) -> Result<(sqlx::Transaction<'static, sqlx::Postgres>, DateTime<Utc>, Option<RegionLease>)>
+    {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+            .bind(group)
+            .execute(&mut *tx)
+            .await?;
+        let now: DateTime<Utc> = sqlx::query_scalar("SELECT now()").fetch_one(&mut *tx).await?;
+        let current = sqlx::query_as::<_, LeaseRow>(Self::SELECT)
+            .bind(group)
+            .fetch_optional(&mut *tx)
+            .await?
+            .map(Self::from_row);
+        Ok((tx, now, current))
+    }
+
+    async fn write(
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+        lease: &RegionLease,
+    ) -> Result<()> {
+        sqlx::query(
+            r"
+            INSERT INTO _fraiseql_observer_region_lease
+                (lease_group, region, holder, priority, checkpoint, epoch, acquired_at, expires_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW())
+            ON CONFLICT (lease_group) DO UPDATE SET
+                region = EXCLUDED.region,
+                holder = EXCLUDED.holder,
+                priority = EXCLUDED.priority,
+                checkpoint = EXCLUDED.checkpoint,
+                epoch = EXCLUDED.epoch,
+                acquired_at = EXCLUDED.acquired_at,
+                expires_at = EXCLUDED.expires_at,
+                updated_at = NOW()
+            ",
+        )
+        .bind(&lease.group)
+        .bind(&lease.region)
+        .bind(&lease.holder)
+        .bind(i32::try_from(lease.priority).unwrap_or(i32::MAX))
+        .bind(lease.checkpoint)
+        .bind(lease.epoch)
+        .bind(lease.acquired_at)
+        .bind(lease.expires_at)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[async_trait]
+impl RegionLeaseStore for PostgresRegionLeaseStore {
+    async fn arbitrate(&self, request: &LeaseRequest) -> Result<Arbitration> {
+        let (mut tx, now, current) = self.lock(&request.group).await?;
+        let outcome = arbitrate(current.as_ref(), request, now);
+        if !matches!(outcome, Arbitration::Standby(_)) {
+            Self::write(&mut tx, outcome.lease()).await?;
+        }
+        tx.commit().await?;
+        Ok(outcome)
+    }
+
+    async fn release(&self, group: &str, holder: &str, checkpoint: i64) -> Result<()> {
+        let (mut tx, now, current) = self.lock(group).await?;
+        if let Some(mut lease) = current.filter(|l| l.holder == holder) {
+            release_lease(&mut lease, holder, checkpoint, now);
+            Self::write(&mut tx, &lease).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn current(&self, group: &str) -> Result<Option<RegionLease>> {
+        Ok(sqlx::query_as::<_, LeaseRow>(Self::SELECT)
+            .bind(group)
+            .fetch_optional(&self.pool)
+            .await?
+            .map(Self::from_row))
+    }
+}
+
+/// Emitted when the lease of a group changes hands, as seen by one region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionFailoverEvent {
+    /// Lease group.
+    pub group:       String,
+    /// Region that held the lease before, if any.
+    pub from_region: Option<String>,
+    /// Region holding it now.
+    pub to_region:   String,
+    /// Checkpoint handed over to the new holder.
+    pub checkpoint:  i64,
+    /// New lease epoch.
+    pub epoch:       i64,
+    /// Why the lease changed hands.
+    pub reason:      FailoverReason,
+    /// When the new holder took the lease.
+    pub at:          DateTime<Utc>,
+}
+
+/// Whether this region's listeners should run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionRole {
+    /// This region holds the lease.
+    Active,
+    /// Another region holds the lease.
+    Standby,
+}
+
+/// Coordinates one region's listeners with the other regions of its group.
+///
+/// Call [`tick`](Self::tick) periodically (or [`start`](Self::start) a
+/// background loop) and run listeners only while the role is
+/// [`RegionRole::Active`].
+#[derive(Clone)]
+pub struct MultiRegionFailover {
+    config:   RegionConfig,
+    rank:     u32,
+    holder:   String,
+    store:    Arc<dyn RegionLeaseStore>,
+    manager:  FailoverManager,
+    /// Last lease this region observed.
+    observed: Arc<Mutex<Option<RegionLease>>>,
+    shutdown: Arc<AtomicBool>,
+    #[cfg(feature = "metrics")]
+    metrics:  crate::metrics::MetricsRegistry,
+}
+
+impl MultiRegionFailover {
+    /// Coordinate `manager`'s listeners as instance `holder` of the local region.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] if the local region is not in
+    /// the priority list or `holder` is empty.
+    pub fn new(
+        config: RegionConfig,
+        holder: impl Into<String>,
+        store: Arc<dyn RegionLeaseStore>,
+        manager: FailoverManager,
+    ) -> Result<Self> {
+        let rank = config.local_rank()?;
+        let holder = holder.into();
+        if holder.is_empty() {
+            return Err(ObserverError::InvalidConfig {
+                message: "region failover holder id must not be empty".to_string(),
+            });
+        }
+        Ok(Self {
+            config,
+            rank,
+            holder,
+            store,
+            manager,
+            observed: Arc::new(Mutex::new(None)),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            #[cfg(feature = "metrics")]
+            metrics: crate::metrics::MetricsRegistry::global().unwrap_or_default(),
+        })
+    }
+
+    /// The region configuration.
+    #[must_use]
+    pub const fn config(&self) -> &RegionConfig {
+        &self.config
+    }
+
+    /// The intra-cluster failover manager.
+    #[must_use]
+    pub const fn manager(&self) -> &FailoverManager {
+        &self.manager
+    }
+
+    /// Checkpoint the local leader has reached.
+    async fn local_checkpoint(&self, leader: &str) -> Result<i64> {
+        Ok(self
+            .manager
+            .coordinator()
+            .check_listener_health()
+            .await?
+            .into_iter()
+            .find(|h| h.listener_id == leader)
+            .map_or(0, |h| h.last_checkpoint))
+    }
+
+    /// Run one arbitration round: renew the lease if held, otherwise bid for it.
+    ///
+    /// A region without a healthy local listener does not bid, and releases
+    /// the lease if it holds it, so another region takes over at once. On
+    /// acquiring the lease the handed-over checkpoint is applied to the local
+    /// leader. Returns the role and, when the lease changed hands since the
+    /// last round, the failover.
+    ///
+    /// # Errors
+    ///
+    /// Propagates store errors and errors from resuming the local leader from
+    /// the handed-over checkpoint (the lease is released again in that case).
+    pub async fn tick(&self) -> Result<(RegionRole, Option<RegionFailoverEvent>)> {
+        let Ok(leader) = self.manager.coordinator().elect_leader().await else {
+            if self.is_active().await {
+                warn!(
+                    group = %self.config.group,
+                    region = %self.config.local_region,
+                    "no healthy local listener; releasing the region lease"
+                );
+                self.release().await?;
+            }
+            #[cfg(feature = "metrics")]
+            self.metrics.set_region_active(&self.config.group, false);
+            return Ok((RegionRole::Standby, None));
+        };
+
+        let request = LeaseRequest {
+            group:            self.config.group.clone(),
+            region:           self.config.local_region.clone(),
+            holder:           self.holder.clone(),
+            priority:         self.rank,
+            checkpoint:       self.local_checkpoint(&leader).await?,
+            ttl:              self.config.lease_ttl,
+            takeover_stagger: self.config.takeover_stagger,
+            failback:         self.config.failback,
+        };
+        let outcome = self.store.arbitrate(&request).await?;
+
+        if let Arbitration::Acquired { lease, .. } = &outcome {
+            if let Err(e) = self.manager.resume_from_checkpoint(&leader, lease.checkpoint).await {
+                self.store.release(&self.config.group, &self.holder, lease.checkpoint).await?;
+                *self.observed.lock().await = None;
+                return Err(e);
+            }
+        }
+
+        let mut observed = self.observed.lock().await;
+        let event = match &outcome {
+            Arbitration::Acquired {
+                lease,
+                previous,
+                reason,
+            } => Some(RegionFailoverEvent {
+                group:       lease.group.clone(),
+                from_region: previous.clone(),
+                to_region:   lease.region.clone(),
+                checkpoint:  lease.checkpoint,
+                epoch:       lease.epoch,
+                reason:      *reason,
+                at:          lease.acquired_at,
+            }),
+            // Seen from the losing side: the holder changed since last round.
+            Arbitration::Standby(lease)
+                if observed.as_ref().is_some_and(|prev| prev.epoch != lease.epoch) =>
+            {
+                let previous = observed.as_ref().map(|prev| prev.region.clone());
+                let reason = if previous.as_deref() == Some(self.config.local_region.as_str())
+                    && lease.priority < self.rank
+                {
+                    FailoverReason::Failback
+                } else {
+                    FailoverReason::Expired
+                };
+                Some(RegionFailoverEvent {
+                    group: lease.group.clone(),
+                    from_region: previous,
+                    to_region: lease.region.clone(),
+                    checkpoint: lease.checkpoint,
+                    epoch: lease.epoch,
+                    reason,
+                    at: lease.acquired_at,
+                })
+            },
+            Arbitration::Renewed(_) | Arbitration::Standby(_) => None,
+        };
+        *observed = Some(outcome.lease().clone());
+        drop(observed);
+
+        let role = if matches!(outcome, Arbitration::Standby(_)) {
+            RegionRole::Standby
+        } else {
+            RegionRole::Active
+        };
+        if let Some(event) = &event {
+            self.record(event);
+        }
+        #[cfg(feature = "metrics")]
+        self.metrics.set_region_active(&self.config.group, role == RegionRole::Active);
+        Ok((role, event))
+    }
+
+    /// Log a failover, and count it on the winning side only, so a failover
+    /// seen by both regions is counted once.
+    fn record(&self, event: &RegionFailoverEvent) {
+        let from = event.from_region.as_deref().unwrap_or("none");
+        if event.reason == FailoverReason::Initial {
+            info!(
+                group = %event.group,
+                region = %event.to_region,
+                "region lease acquired"
+            );
+        } else {
+            warn!(
+                group = %event.group,
+                local_region = %self.config.local_region,
+                from_region = from,
+                to_region = %event.to_region,
+                reason = event.reason.as_str(),
+                checkpoint = event.checkpoint,
+                epoch = event.epoch,
+                "observer region failover"
+            );
+        }
+        #[cfg(feature = "metrics")]
+        if event.to_region == self.config.local_region {
+            self.metrics.region_failover(from, &event.to_region, event.reason.as_str());
+        }
+    }
+
+    /// Give up the lease, handing the local checkpoint to the next region.
+    /// Call on graceful shutdown so standby regions need not wait for expiry.
+    ///
+    /// # Errors
+    ///
+    /// Propagates store errors.
+    pub async fn release(&self) -> Result<()> {
+        let checkpoint = match self.manager.coordinator().get_leader().await {
+            Some(leader) => self.local_checkpoint(&leader).await?,
+            None => 0,
+        };
+        self.store.release(&self.config.group, &self.holder, checkpoint).await?;
+        *self.observed.lock().await = None;
+        Ok(())
+    }
+
+    /// Whether this region held the lease at the last round.
+    pub async fn is_active(&self) -> bool {
+        self.observed.lock().await.as_ref().is_some_and(|l| l.holder == self.holder)
+    }
+
+    /// Spawn a loop running [`tick`](Self::tick) every `interval`, sending
+    /// failovers on the returned channel. The loop exits when
+    /// [`stop`](Self::stop) is called or the receiver is dropped; arbitration
+    /// errors are logged and retried next round.
+    #[must_use]
+    pub fn start(&self, interval: Duration) -> mpsc::Receiver<RegionFailoverEvent> {
+        self.shutdown.store(false, Ordering::SeqCst);
+        let (tx, rx) = mpsc::channel(100);
+        let failover = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                if failover.shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                match failover.tick().await {
+                    Ok((_, Some(event))) => {
+                        if tx.send(event).await.is_err() {
+                            return;
+                        }
+                    },
+                    Ok((_, None)) => {},
+                    Err(e) => {
+                        warn!(group = %failover.config.group, error = %e, "region lease arbitration failed")
+                    },
+                }
+                tokio::time::sleep(interval).await;
+            }
+        });
+
+        rx
+    }
+
+    /// Stop the loop started by [`start`](Self::start) after its current round.
+    pub fn stop(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+    }
+}
@@ -246,6 +246,39 @@ mod change_log_tests {
         assert_eq!(event.user_id, None);
     }
 
+    #[test]
+    fn test_soft_delete_to_entity_event_uses_after_image() {
+        let entity_id = Uuid::new_v4();
+        let entry = ChangeLogEntry {
+            id:                   3,
+            pk_entity_change_log: Uuid::new_v4().to_string(),
+            fk_customer_org:      "org".to_string(),
+            tenant_id:            None,
+            duration_ms:          None,
+            seq:                  None,
+            actor_type:           None,
+            acting_for:           None,
+            schema_version:       None,
+            fk_contact:           None,
+            object_type:          "Post".to_string(),
+            object_id:            entity_id.to_string(),
+            modification_type:    "DELETE".to_string(),
+            change_status:        "success".to_string(),
+            object_data:          json!({
+                "id": entity_id.to_string(),
+                "deleted_at": "2026-01-22T10:40:00Z",
+            }),
+            object_data_before:   None,
+            extra_metadata:       None,
+            created_at:           "2026-01-22T10:40:00+00:00".to_string(),
+        };
+
+        let event = entry.to_entity_event().unwrap();
+
+        assert_eq!(event.event_type, EventKind::Deleted);
+        assert_eq!(event.data["deleted_at"], "2026-01-22T10:40:00Z");
+    }
+
     #[test]
     fn test_field_changes_new_field() {
         let entity_id = Uuid::new_v4();
@@ -999,3 +1032,170 @@ mod state_tests {
         assert_eq!(state_machine.listener_id(), "my-listener");
     }
 }
+
+#[allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+mod region_tests {
+    use std::{sync::Arc, time::Duration};
+
+    use chrono::{TimeDelta, Utc};
+
+    use super::super::{
+        coordinator::MultiListenerCoordinator, failover::FailoverManager, region::*,
+        state::ListenerState,
+    };
+
+    fn request(region: &str, priority: u32, checkpoint: i64) -> LeaseRequest {
+        LeaseRequest {
+            group: "orders".to_string(),
+            region: region.to_string(),
+            holder: format!("{region}-1"),
+            priority,
+            checkpoint,
+            ttl: Duration::from_secs(30),
+            takeover_stagger: Duration::from_secs(5),
+            failback: false,
+        }
+    }
+
+    #[test]
+    fn arbitration_grants_renews_and_waits_for_expiry() {
+        let now = Utc::now();
+        let east = request("us-east", 0, 10);
+        let west = request("eu-west", 1, 0);
+
+        let Arbitration::Acquired { lease, reason, .. } = arbitrate(None, &east, now) else {
+            panic!("the first bid takes the lease");
+        };
+        assert_eq!((reason, lease.epoch, lease.checkpoint), (FailoverReason::Initial, 1, 10));
+
+        let renewed = arbitrate(Some(&lease), &request("us-east", 0, 25), now);
+        assert_eq!(renewed.lease().checkpoint, 25);
+        assert!(matches!(renewed, Arbitration::Renewed(_)));
+
+        // Live, then lapsed but within eu-west's one-rank stagger: stand by.
+        assert!(matches!(arbitrate(Some(&lease), &west, now), Arbitration::Standby(_)));
+        let lapsed = lease.expires_at + TimeDelta::seconds(2);
+        assert!(matches!(arbitrate(Some(&lease), &west, lapsed), Arbitration::Standby(_)));
+
+        let later = lease.expires_at + TimeDelta::seconds(5);
+        let Arbitration::Acquired {
+            lease: taken,
+            previous,
+            reason,
+        } = arbitrate(Some(&lease), &west, later)
+        else {
+            panic!("a lapsed lease is taken after the stagger");
+        };
+        assert_eq!(reason, FailoverReason::Expired);
+        assert_eq!(previous.as_deref(), Some("us-east"));
+        assert_eq!((taken.region.as_str(), taken.epoch, taken.checkpoint), ("eu-west", 2, 10));
+    }
+
+    #[test]
+    fn failback_reclaims_a_live_lease_from_a_worse_ranked_region() {
+        let now = Utc::now();
+        let held = arbitrate(None, &request("eu-west", 1, 7), now).lease().clone();
+
+        let east = request("us-east", 0, 0);
+        assert!(matches!(arbitrate(Some(&held), &east, now), Arbitration::Standby(_)));
+        let outcome = arbitrate(
+            Some(&held),
+            &LeaseRequest {
+                failback: true,
+                ..east
+            },
+            now,
+        );
+        let Arbitration::Acquired { lease, reason, .. } = outcome else {
+            panic!("failback takes the lease");
+        };
+        assert_eq!((reason, lease.checkpoint), (FailoverReason::Failback, 7));
+
+        // A worse-ranked region never preempts.
+        let south = LeaseRequest {
+            failback: true,
+            ..request("ap-south", 2, 0)
+        };
+        assert!(matches!(arbitrate(Some(&lease), &south, now), Arbitration::Standby(_)));
+    }
+
+    #[test]
+    fn the_local_region_must_be_ranked() {
+        let config = RegionConfig::new("orders", "sa-east", vec!["us-east".into()]);
+        assert!(config.local_rank().is_err());
+        let config =
+            RegionConfig::new("orders", "eu-west", vec!["us-east".into(), "eu-west".into()]);
+        assert_eq!(config.local_rank().unwrap(), 1);
+    }
+
+    async fn region(
+        name: &str,
+        store: &Arc<InMemoryRegionLeaseStore>,
+    ) -> (MultiRegionFailover, Arc<MultiListenerCoordinator>) {
+        let coordinator = Arc::new(MultiListenerCoordinator::new());
+        start_listener(&coordinator, &format!("{name}-listener")).await;
+        let config = RegionConfig::new("orders", name, vec!["us-east".into(), "eu-west".into()]);
+        let store: Arc<dyn RegionLeaseStore> = store.clone();
+        let failover = MultiRegionFailover::new(
+            config,
+            format!("{name}-1"),
+            store,
+            FailoverManager::new(Arc::clone(&coordinator)),
+        )
+        .unwrap();
+        (failover, coordinator)
+    }
+
+    async fn start_listener(coordinator: &MultiListenerCoordinator, id: &str) {
+        coordinator.register_listener(id.to_string()).await.unwrap();
+        coordinator
+            .transition_listener_state(id, ListenerState::Connecting)
+            .await
+            .unwrap();
+        coordinator.transition_listener_state(id, ListenerState::Running).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn an_unhealthy_region_hands_its_checkpoint_to_the_standby() {
+        let store = Arc::new(InMemoryRegionLeaseStore::new());
+        let (east, east_listeners) = region("us-east", &store).await;
+        let (west, west_listeners) = region("eu-west", &store).await;
+
+        let (role, event) = east.tick().await.unwrap();
+        assert_eq!(role, RegionRole::Active);
+        assert_eq!(event.unwrap().reason, FailoverReason::Initial);
+        assert_eq!(west.tick().await.unwrap(), (RegionRole::Standby, None));
+
+        east_listeners.update_checkpoint("us-east-listener", 42).unwrap();
+        assert_eq!(east.tick().await.unwrap(), (RegionRole::Active, None));
+
+        // us-east loses its only listener: it releases, and eu-west takes over
+        // from us-east's checkpoint.
+        east_listeners
+            .transition_listener_state("us-east-listener", ListenerState::Stopped)
+            .await
+            .unwrap();
+        assert_eq!(east.tick().await.unwrap(), (RegionRole::Standby, None));
+        assert!(!east.is_active().await);
+
+        let (role, event) = west.tick().await.unwrap();
+        let event = event.unwrap();
+        assert_eq!(role, RegionRole::Active);
+        assert_eq!(event.reason, FailoverReason::Released);
+        assert_eq!(event.from_region.as_deref(), Some("us-east"));
+        assert_eq!(event.checkpoint, 42);
+        let health = west_listeners.check_listener_health().await.unwrap();
+        assert_eq!(health[0].last_checkpoint, 42);
+
+        // us-east recovers and fails back; eu-west sees the lease go.
+        start_listener(&east_listeners, "us-east-listener-2").await;
+        let (role, event) = east.tick().await.unwrap();
+        assert_eq!(role, RegionRole::Active);
+        assert_eq!(event.unwrap().reason, FailoverReason::Failback);
+        let (role, event) = west.tick().await.unwrap();
+        assert_eq!(role, RegionRole::Standby);
+        let event = event.unwrap();
+        assert_eq!(event.reason, FailoverReason::Failback);
+        assert_eq!(event.to_region, "us-east");
+    }
+}
@@ -19,11 +19,11 @@ use crate::{
 
 /// Index for fast O(1) event-to-observer matching
 ///
-/// Structure: `{ event_type -> { entity_type -> [observer_definitions] } }`
+/// Structure: `{ event_type -> { entity_type -> [(name, observer_definition)] } }`
 #[derive(Debug, Clone)]
 pub struct EventMatcher {
-    // Two-level index: event_type -> entity_type -> observers
-    index: HashMap<String, HashMap<String, Vec<ObserverDefinition>>>,
+    // Two-level index: event_type -> entity_type -> named observers
+    index: HashMap<String, HashMap<String, Vec<(String, ObserverDefinition)>>>,
 }
 
 impl EventMatcher {
@@ -50,15 +50,20 @@ impl EventMatcher {
     pub fn build(observers: HashMap<String, ObserverDefinition>) -> Result<Self> {
         let mut matcher = Self::new();
 
-        for (_name, definition) in observers {
-            matcher.add_observer(definition);
+        for (name, definition) in observers {
+            matcher.add_named_observer(name, definition);
         }
 
         Ok(matcher)
     }
 
-    /// Add a single observer to the matcher
+    /// Add a single unnamed observer to the matcher
     pub(crate) fn add_observer(&mut self, observer: ObserverDefinition) {
+        self.add_named_observer(String::new(), observer);
+    }
+
+    /// Add a single observer to the matcher under `name`
+    pub(crate) fn add_named_observer(&mut self, name: String, observer: ObserverDefinition) {
         let event_type = observer.event_type.to_uppercase();
         let entity_type = observer.entity.clone();
 
@@ -67,7 +72,7 @@ impl EventMatcher {
             .or_default()
             .entry(entity_type)
             .or_default()
-            .push(observer);
+            .push((name, observer));
     }
 
     /// Find all observers that match an event
@@ -79,6 +84,18 @@ impl EventMatcher {
     /// Vector of matching observer definitions (empty if no matches)
     #[must_use]
     pub fn find_matches(&self, event: &EntityEvent) -> Vec<&ObserverDefinition> {
+        self.find_named_matches(event)
+            .into_iter()
+            .map(|(_, observer)| observer)
+            .collect()
+    }
+
+    /// Find all observers that match an event, with their names
+    ///
+    /// Each name is the key the observer was registered under in
+    /// [`build`](Self::build).
+    #[must_use]
+    pub fn find_named_matches(&self, event: &EntityEvent) -> Vec<(&str, &ObserverDefinition)> {
         let event_type_str = event.event_type.as_str().to_uppercase();
 
         let mut results = Vec::new();
@@ -86,12 +103,14 @@ impl EventMatcher {
         if let Some(entity_index) = self.index.get(&event_type_str) {
             // Try exact entity type match first
             if let Some(observers) = entity_index.get(&event.entity_type) {
-                results.extend(observers.iter());
+                results.extend(observers.iter().map(|(name, observer)| (name.as_str(), observer)));
             }
 
             // Also try wildcard "*" match for observers that match all entities
             if let Some(wildcard_observers) = entity_index.get("*") {
-                results.extend(wildcard_observers.iter());
+                results.extend(
+                    wildcard_observers.iter().map(|(name, observer)| (name.as_str(), observer)),
+                );
             }
         }
 
@@ -112,12 +131,12 @@ impl EventMatcher {
         if let Some(entity_index) = self.index.get(&event_type_str) {
             // Try exact entity type match first
             if let Some(observers) = entity_index.get(entity_type) {
-                results.extend(observers.iter());
+                results.extend(observers.iter().map(|(_, observer)| observer));
             }
 
             // Also try wildcard "*" match for observers that match all entities
             if let Some(wildcard_observers) = entity_index.get("*") {
-                results.extend(wildcard_observers.iter());
+                results.extend(wildcard_observers.iter().map(|(_, observer)| observer));
             }
         }
 
@@ -129,7 +148,9 @@ impl EventMatcher {
     pub fn all_observers(&self) -> Vec<&ObserverDefinition> {
         self.index
             .values()
-            .flat_map(|entity_map| entity_map.values().flat_map(|obs| obs.iter()))
+            .flat_map(|entity_map| {
+                entity_map.values().flat_map(|obs| obs.iter().map(|(_, observer)| observer))
+            })
             .collect()
     }
 
@@ -0,0 +1,106 @@
+//! Label values of the per-observer action series and their cardinality cap.
+//!
+//! The policy is process-wide, like the series themselves: the embedder calls
+//! [`configure_labels`] once at boot with the runtime's
+//! [`MetricsConfig`], and every executor in the process
+//! records through it. This module is compiled without the `metrics` feature so
+//! embedders configure the policy unconditionally.
+
+use std::{
+    collections::HashSet,
+    sync::{LazyLock, atomic::AtomicBool},
+};
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::config::MetricsConfig;
+
+/// Label value every label of an over-cap combination is recorded under.
+pub const OVERFLOW_LABEL: &str = "_overflow";
+
+/// Labels identifying one action execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionLabels<'a> {
+    /// Name of the observer the action belongs to
+    pub observer:    &'a str,
+    /// Action type (`webhook`, `slack`, …)
+    pub action_type: &'a str,
+    /// Entity type of the triggering event
+    pub entity_type: &'a str,
+    /// Tenant of the triggering event, if any
+    pub tenant:      Option<&'a str>,
+}
+
+/// Process-wide label policy and the combinations exported so far.
+#[cfg_attr(not(feature = "metrics"), allow(dead_code))] // Reason: read only by the `metrics`-gated registry; the policy itself is configured unconditionally.
+pub(crate) struct LabelLimiter {
+    config:     RwLock<MetricsConfig>,
+    series:     Mutex<HashSet<[String; 4]>>,
+    /// Set once the first over-cap combination is seen, so the warning is
+    /// logged once rather than per event.
+    overflowed: AtomicBool,
+}
+
+static LIMITER: LazyLock<LabelLimiter> =
+    LazyLock::new(|| LabelLimiter::new(MetricsConfig::default()));
+
+/// Apply `config` to the action series of every executor in this process.
+///
+/// Combinations already exported keep their series; a lower cap only stops new
+/// ones from being added.
+pub fn configure_labels(config: &MetricsConfig) {
+    *LIMITER.config.write() = config.clone();
+}
+
+#[cfg_attr(not(feature = "metrics"), allow(dead_code))] // Reason: read only by the `metrics`-gated registry; the policy itself is configured unconditionally.
+impl LabelLimiter {
+    pub(crate) fn new(config: MetricsConfig) -> Self {
+        Self {
+            config:     RwLock::new(config),
+            series:     Mutex::new(HashSet::new()),
+            overflowed: AtomicBool::new(false),
+        }
+    }
+
+    /// The process-wide limiter configured by [`configure_labels`].
+    pub(crate) fn global() -> &'static Self {
+        &LIMITER
+    }
+
+    /// Label values for `labels`, in `[observer, action_type, entity_type,
+    /// tenant]` order.
+    ///
+    /// Returns `None` when the combination is new and the cap is reached; the
+    /// caller records it under [`OVERFLOW_LABEL`] instead.
+    pub(crate) fn values(&self, labels: &ActionLabels<'_>) -> Option<[String; 4]> {
+        let config = self.config.read();
+        let tenant = if config.tenant_labels {
+            labels.tenant.unwrap_or("")
+        } else {
+            ""
+        };
+        let values = [
+            labels.observer.to_string(),
+            labels.action_type.to_string(),
+            labels.entity_type.to_string(),
+            tenant.to_string(),
+        ];
+
+        let mut series = self.series.lock();
+        if series.contains(&values) {
+            return Some(values);
+        }
+        if series.len() >= config.max_label_series {
+            if !self.overflowed.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                tracing::warn!(
+                    max_label_series = config.max_label_series,
+                    "Observer metrics label cap reached; new observer/action/entity/tenant \
+                     combinations are recorded under \"{OVERFLOW_LABEL}\""
+                );
+            }
+            return None;
+        }
+        series.insert(values.clone());
+        Some(values)
+    }
+}
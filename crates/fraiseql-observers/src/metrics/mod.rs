@@ -6,60 +6,20 @@
 //! - Deduplication effectiveness
 //! - Action execution times
 //! - Queue/backlog monitoring
+//!
+//! Action series are labeled per observer, action type, entity type and tenant
+//! (see [`labels`]). The label policy is always compiled so embedders can apply
+//! it whether or not the `metrics` feature is enabled.
 
 #[cfg(feature = "metrics")]
 pub mod handler;
+pub mod labels;
 #[cfg(feature = "metrics")]
 pub mod registry;
 
+pub use labels::{ActionLabels, OVERFLOW_LABEL, configure_labels};
 #[cfg(feature = "metrics")]
 pub use registry::MetricsRegistry;
 
-// No-op metrics when feature is disabled
-#[cfg(not(feature = "metrics"))]
-pub struct MetricsRegistry;
-
-#[cfg(not(feature = "metrics"))]
-impl MetricsRegistry {
-    /// Create a no-op metrics registry when metrics feature is disabled
-    pub fn new() -> Self {
-        MetricsRegistry
-    }
-
-    /// Increment a counter (no-op)
-    pub fn event_processed(&self) {}
-
-    /// Record cache hit (no-op)
-    pub fn cache_hit(&self) {}
-
-    /// Record cache miss (no-op)
-    pub fn cache_miss(&self) {}
-
-    /// Record dedup detection (no-op)
-    pub fn dedup_detected(&self) {}
-
-    /// Record action duration (no-op)
-    pub fn action_executed(&self, _action_type: &str, _duration_secs: f64) {}
-
-    /// Record action error (no-op)
-    pub fn action_error(&self, _action_type: &str, _error_type: &str) {}
-
-    /// Update backlog size (no-op)
-    pub fn set_backlog_size(&self, _size: usize) {}
-
-    /// Get current metric registry (no-op)
-    pub fn registry(&self) -> Option<&'static prometheus::Registry> {
-        None
-    }
-}
-
-#[cfg(not(feature = "metrics"))]
-impl Default for MetricsRegistry {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[cfg(feature = "metrics")]
 #[cfg(test)]
 mod tests;
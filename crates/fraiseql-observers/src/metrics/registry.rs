@@ -14,14 +14,27 @@
 //! metrics today, mount this crate's `/metrics` handler. Bridging the two
 //! registries so the server's `/metrics` also exposes them is tracked at
 //! <https://github.com/fraiseql/fraiseql/issues/634>.
+//!
+//! # Action labels
+//!
+//! The action series carry `observer`, `action_type`, `entity_type` and
+//! `tenant` labels. Distinct combinations are capped by
+//! [`MetricsConfig::max_label_series`](crate::config::MetricsConfig); past the
+//! cap every label reads [`OVERFLOW_LABEL`] and
+//! `fraiseql_observer_metric_label_overflow_total` is incremented.
 
 use std::sync::OnceLock;
 
 use prometheus::{
-    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts,
+    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts,
     Result as PrometheusResult,
 };
 
+use super::labels::{ActionLabels, LabelLimiter, OVERFLOW_LABEL};
+
+/// Label names of the action series, in [`LabelLimiter::values`] order.
+const ACTION_LABELS: [&str; 4] = ["observer", "action_type", "entity_type", "tenant"];
+
 /// Lazy-initialized global metrics registry.
 ///
 /// Initialized once per process on first call to the registry accessor.
@@ -57,6 +70,7 @@ pub struct MetricsRegistry {
     pub(crate) action_executed_total:   IntCounterVec,
     pub(crate) action_duration_seconds: HistogramVec,
     pub(crate) action_errors_total:     IntCounterVec,
+    pub(crate) label_overflow_total:    IntCounter,
 
     // Queue metrics
     pub(crate) backlog_size:       IntGauge,
@@ -71,6 +85,13 @@ pub struct MetricsRegistry {
     pub(crate) job_retry_attempts:   IntCounterVec,
     pub(crate) job_queue_depth:      IntGauge,
     pub(crate) job_dlq_items:        IntGauge,
+
+    // Region failover metrics
+    pub(crate) region_failovers_total: IntCounterVec,
+    pub(crate) region_active:          IntGaugeVec,
+
+    // SMS metrics
+    pub(crate) sms_delivery_status_total: IntCounterVec,
 }
 
 impl MetricsRegistry {
@@ -171,7 +192,7 @@ impl MetricsRegistry {
         // Action execution metrics
         let action_executed_total = IntCounterVec::new(
             Opts::new("fraiseql_observer_action_executed_total", "Total actions executed"),
-            &["action_type"],
+            &ACTION_LABELS,
         )?;
         registry.register(Box::new(action_executed_total.clone()))?;
 
@@ -181,16 +202,28 @@ impl MetricsRegistry {
                 "Action execution duration in seconds",
             )
             .buckets(vec![0.001, 0.01, 0.1, 1.0, 5.0, 10.0, 30.0, 60.0]),
-            &["action_type"],
+            &ACTION_LABELS,
         )?;
         registry.register(Box::new(action_duration_seconds.clone()))?;
 
         let action_errors_total = IntCounterVec::new(
             Opts::new("fraiseql_observer_action_errors_total", "Total action execution errors"),
-            &["action_type", "error_type"],
+            &[
+                "observer",
+                "action_type",
+                "entity_type",
+                "tenant",
+                "error_type",
+            ],
         )?;
         registry.register(Box::new(action_errors_total.clone()))?;
 
+        let label_overflow_total = IntCounter::new(
+            "fraiseql_observer_metric_label_overflow_total",
+            "Total action recordings folded into the overflow series by the label cap",
+        )?;
+        registry.register(Box::new(label_overflow_total.clone()))?;
+
         // Queue metrics
         let backlog_size = IntGauge::new(
             "fraiseql_observer_backlog_size",
@@ -257,6 +290,33 @@ impl MetricsRegistry {
         )?;
         registry.register(Box::new(job_dlq_items.clone()))?;
 
+        let region_failovers_total = IntCounterVec::new(
+            Opts::new(
+                "fraiseql_observer_region_failovers_total",
+                "Total changes of the region holding an observer lease group",
+            ),
+            &["from_region", "to_region", "reason"],
+        )?;
+        registry.register(Box::new(region_failovers_total.clone()))?;
+
+        let region_active = IntGaugeVec::new(
+            Opts::new(
+                "fraiseql_observer_region_active",
+                "Whether this region holds the lease of the observer lease group (1) or stands by (0)",
+            ),
+            &["group"],
+        )?;
+        registry.register(Box::new(region_active.clone()))?;
+
+        let sms_delivery_status_total = IntCounterVec::new(
+            Opts::new(
+                "fraiseql_observer_sms_delivery_status_total",
+                "Total SMS delivery-status callbacks received, by provider and status",
+            ),
+            &["provider", "status"],
+        )?;
+        registry.register(Box::new(sms_delivery_status_total.clone()))?;
+
         Ok(MetricsRegistry {
             events_processed_total,
             events_failed_total,
@@ -270,6 +330,7 @@ impl MetricsRegistry {
             action_executed_total,
             action_duration_seconds,
             action_errors_total,
+            label_overflow_total,
             backlog_size,
             dlq_items,
             dlq_overflow_total,
@@ -280,6 +341,9 @@ impl MetricsRegistry {
             job_retry_attempts,
             job_queue_depth,
             job_dlq_items,
+            region_failovers_total,
+            region_active,
+            sms_delivery_status_total,
         })
     }
 
@@ -329,16 +393,33 @@ impl MetricsRegistry {
     }
 
     /// Record an action was executed
-    pub fn action_executed(&self, action_type: &str, duration_secs: f64) {
-        self.action_executed_total.with_label_values(&[action_type]).inc();
-        self.action_duration_seconds
-            .with_label_values(&[action_type])
-            .observe(duration_secs);
+    pub fn action_executed(&self, labels: &ActionLabels<'_>, duration_secs: f64) {
+        let values = self.action_label_values(labels);
+        self.action_executed_total.with_label_values(&values).inc();
+        self.action_duration_seconds.with_label_values(&values).observe(duration_secs);
     }
 
     /// Record an action execution error
-    pub fn action_error(&self, action_type: &str, error_type: &str) {
-        self.action_errors_total.with_label_values(&[action_type, error_type]).inc();
+    pub fn action_error(&self, labels: &ActionLabels<'_>, error_type: &str) {
+        let [observer, action_type, entity_type, tenant] = self.action_label_values(labels);
+        self.action_errors_total
+            .with_label_values(&[
+                observer.as_str(),
+                action_type.as_str(),
+                entity_type.as_str(),
+                tenant.as_str(),
+                error_type,
+            ])
+            .inc();
+    }
+
+    /// Label values for `labels` under the process-wide cap, counting the
+    /// recordings that fall into the overflow series.
+    fn action_label_values(&self, labels: &ActionLabels<'_>) -> [String; 4] {
+        LabelLimiter::global().values(labels).unwrap_or_else(|| {
+            self.label_overflow_total.inc();
+            [OVERFLOW_LABEL; 4].map(str::to_string)
+        })
     }
 
     /// Update the current backlog size
@@ -395,6 +476,23 @@ impl MetricsRegistry {
         self.job_dlq_items.set(count as i64);
     }
 
+    /// Record the lease of a region group changing hands
+    pub fn region_failover(&self, from_region: &str, to_region: &str, reason: &str) {
+        self.region_failovers_total
+            .with_label_values(&[from_region, to_region, reason])
+            .inc();
+    }
+
+    /// Update whether this region holds the lease of `group`
+    pub fn set_region_active(&self, group: &str, active: bool) {
+        self.region_active.with_label_values(&[group]).set(i64::from(active));
+    }
+
+    /// Record an SMS delivery-status callback
+    pub fn sms_delivery_status(&self, provider: &str, status: &str) {
+        self.sms_delivery_status_total.with_label_values(&[provider, status]).inc();
+    }
+
     /// Get cache hit rate as percentage (0-100)
     #[must_use]
     #[allow(clippy::cast_precision_loss)] // Reason: f64 precision is acceptable for metrics counters
@@ -1,3 +1,5 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
 #[cfg(feature = "metrics")]
 mod handler_tests {
     use super::super::handler::*;
@@ -12,7 +14,7 @@ mod handler_tests {
 
 #[cfg(feature = "metrics")]
 mod registry_tests {
-    use super::super::registry::*;
+    use super::super::{labels::ActionLabels, registry::*};
 
     #[test]
     fn test_global_metrics_registry_initialization() {
@@ -67,17 +69,60 @@ mod registry_tests {
         let metrics = MetricsRegistry::global().expect("Failed to get global metrics");
 
         // Record some action executions
-        metrics.action_executed("webhook", 0.5);
-        metrics.action_executed("slack", 0.1);
-
-        // Verify they were recorded
-        let webhook_count = metrics.action_executed_total.with_label_values(&["webhook"]).get();
-        let slack_count = metrics.action_executed_total.with_label_values(&["slack"]).get();
+        let webhook = ActionLabels {
+            observer:    "order_webhook",
+            action_type: "webhook",
+            entity_type: "Order",
+            tenant:      Some("acme"),
+        };
+        let slack = ActionLabels {
+            observer:    "order_slack",
+            action_type: "slack",
+            entity_type: "Order",
+            tenant:      None,
+        };
+        metrics.action_executed(&webhook, 0.5);
+        metrics.action_executed(&slack, 0.1);
+
+        // Verify they were recorded per observer, entity and tenant
+        let webhook_count = metrics
+            .action_executed_total
+            .with_label_values(&["order_webhook", "webhook", "Order", "acme"])
+            .get();
+        let slack_count = metrics
+            .action_executed_total
+            .with_label_values(&["order_slack", "slack", "Order", ""])
+            .get();
 
         assert!(webhook_count >= 1);
         assert!(slack_count >= 1);
     }
 
+    #[test]
+    fn test_action_error_tracking_by_observer() {
+        let metrics = MetricsRegistry::global().expect("Failed to get global metrics");
+
+        let labels = ActionLabels {
+            observer:    "invoice_webhook",
+            action_type: "webhook",
+            entity_type: "Invoice",
+            tenant:      Some("acme"),
+        };
+        metrics.action_error(&labels, "execution_failed");
+
+        let errors = metrics
+            .action_errors_total
+            .with_label_values(&[
+                "invoice_webhook",
+                "webhook",
+                "Invoice",
+                "acme",
+                "execution_failed",
+            ])
+            .get();
+        assert!(errors >= 1);
+    }
+
     #[test]
     fn test_backlog_gauge_tracking() {
         let metrics = MetricsRegistry::global().expect("Failed to get global metrics");
@@ -187,4 +232,104 @@ mod registry_tests {
         metrics.set_job_dlq_items(15);
         assert_eq!(metrics.job_dlq_items.get(), 15);
     }
+
+    #[test]
+    fn test_region_failover_tracking() {
+        let metrics = MetricsRegistry::global().expect("Failed to get global metrics");
+
+        let failovers = || {
+            metrics
+                .region_failovers_total
+                .with_label_values(&["us-east", "eu-west", "expired"])
+                .get()
+        };
+        let before = failovers();
+        metrics.region_failover("us-east", "eu-west", "expired");
+        assert_eq!(failovers(), before + 1);
+
+        metrics.set_region_active("orders", true);
+        assert_eq!(metrics.region_active.with_label_values(&["orders"]).get(), 1);
+        metrics.set_region_active("orders", false);
+        assert_eq!(metrics.region_active.with_label_values(&["orders"]).get(), 0);
+    }
+
+    #[test]
+    fn test_sms_delivery_status_tracking() {
+        use crate::actions::sms::status::SmsStatusReport;
+
+        let metrics = MetricsRegistry::global().expect("Failed to get global metrics");
+
+        let delivered = || {
+            metrics
+                .sms_delivery_status_total
+                .with_label_values(&["twilio", "delivered"])
+                .get()
+        };
+        let before = delivered();
+        SmsStatusReport::parse("twilio", b"MessageSid=SM1&MessageStatus=delivered")
+            .expect("valid callback")
+            .record();
+        assert_eq!(delivered(), before + 1);
+    }
+}
+
+mod labels_tests {
+    use super::super::labels::{ActionLabels, LabelLimiter};
+    use crate::config::MetricsConfig;
+
+    fn labels<'a>(observer: &'a str, tenant: Option<&'a str>) -> ActionLabels<'a> {
+        ActionLabels {
+            observer,
+            action_type: "webhook",
+            entity_type: "Order",
+            tenant,
+        }
+    }
+
+    #[test]
+    fn values_follow_label_order_and_blank_a_missing_tenant() {
+        let limiter = LabelLimiter::new(MetricsConfig::default());
+
+        let values = limiter.values(&labels("order_webhook", Some("acme"))).unwrap();
+        assert_eq!(values, ["order_webhook", "webhook", "Order", "acme"]);
+
+        let values = limiter.values(&labels("order_webhook", None)).unwrap();
+        assert_eq!(values[3], "");
+    }
+
+    #[test]
+    fn disabling_tenant_labels_blanks_the_tenant() {
+        let limiter = LabelLimiter::new(MetricsConfig {
+            tenant_labels: false,
+            ..MetricsConfig::default()
+        });
+
+        let values = limiter.values(&labels("order_webhook", Some("acme"))).unwrap();
+        assert_eq!(values[3], "");
+    }
+
+    #[test]
+    fn new_combinations_past_the_cap_overflow_but_known_ones_still_record() {
+        let limiter = LabelLimiter::new(MetricsConfig {
+            max_label_series: 2,
+            ..MetricsConfig::default()
+        });
+
+        assert!(limiter.values(&labels("a", Some("t1"))).is_some());
+        assert!(limiter.values(&labels("b", Some("t1"))).is_some());
+        assert!(limiter.values(&labels("c", Some("t1"))).is_none());
+        assert!(limiter.values(&labels("a", Some("t2"))).is_none());
+        assert!(limiter.values(&labels("a", Some("t1"))).is_some());
+    }
+
+    #[test]
+    fn tenants_collapse_into_one_series_when_tenant_labels_are_off() {
+        let limiter = LabelLimiter::new(MetricsConfig {
+            max_label_series: 1,
+            tenant_labels:    false,
+        });
+
+        assert!(limiter.values(&labels("a", Some("t1"))).is_some());
+        assert!(limiter.values(&labels("a", Some("t2"))).is_some());
+    }
 }
@@ -111,6 +111,123 @@ pub const fn source_cursor_sql() -> &'static str {
     include_str!("../../migrations/13_create_source_cursor.sql")
 }
 
+/// SQL DDL that installs the `_fraiseql_observer_replay` audit table.
+///
+/// One row per event replay records who replayed which entity type over which
+/// window and why, how many events and actions ran, and the keyset cursor of the
+/// last processed event. Written by
+/// [`PostgresReplayAudit`](crate::replay::PostgresReplayAudit).
+///
+/// PostgreSQL only; idempotent (`CREATE … IF NOT EXISTS`).
+///
+/// # Example
+///
+/// ```
+/// let sql = fraiseql_observers::migrations::observer_replay_audit_sql();
+/// assert!(sql.contains("_fraiseql_observer_replay"));
+/// ```
+#[must_use]
+pub const fn observer_replay_audit_sql() -> &'static str {
+    include_str!("../../migrations/14_create_observer_replay_audit.sql")
+}
+
+/// SQL DDL that installs the `_fraiseql_observer_pause` table.
+///
+/// One row per paused observer pins the change-log position it has been
+/// delivered up to, so its events accumulate instead of being dropped while it
+/// is paused, and records the backlog size that raises an alert. Written by the
+/// server's observer runtime on `pause` / `resume`.
+///
+/// PostgreSQL only; idempotent (`CREATE … IF NOT EXISTS`).
+///
+/// # Example
+///
+/// ```
+/// let sql = fraiseql_observers::migrations::observer_pause_sql();
+/// assert!(sql.contains("_fraiseql_observer_pause"));
+/// ```
+#[must_use]
+pub const fn observer_pause_sql() -> &'static str {
+    include_str!("../../migrations/15_create_observer_pause.sql")
+}
+
+/// SQL DDL that installs the `_fraiseql_observer_region_lease` table.
+///
+/// One row per lease group names the region whose listeners are active and the
+/// change-log position they have reached, so a standby region taking over
+/// resumes where the failed one stopped. Written by the listener's
+/// `PostgresRegionLeaseStore`.
+///
+/// PostgreSQL only; idempotent (`CREATE … IF NOT EXISTS`).
+///
+/// # Example
+///
+/// ```
+/// let sql = fraiseql_observers::migrations::observer_region_lease_sql();
+/// assert!(sql.contains("_fraiseql_observer_region_lease"));
+/// ```
+#[must_use]
+pub const fn observer_region_lease_sql() -> &'static str {
+    include_str!("../../migrations/16_create_observer_region_lease.sql")
+}
+
+/// SQL DDL that installs the `_fraiseql_observer_slack_thread` table.
+///
+/// One row per entity thread maps the entity to the Slack `ts` of the message
+/// that started its thread, so every instance replies in the same thread.
+/// Written by `PostgresSlackThreadStore`.
+///
+/// PostgreSQL only; idempotent (`CREATE … IF NOT EXISTS`).
+///
+/// # Example
+///
+/// ```
+/// let sql = fraiseql_observers::migrations::observer_slack_thread_sql();
+/// assert!(sql.contains("_fraiseql_observer_slack_thread"));
+/// ```
+#[must_use]
+pub const fn observer_slack_thread_sql() -> &'static str {
+    include_str!("../../migrations/17_create_observer_slack_thread.sql")
+}
+
+/// SQL DDL that installs the `tb_observer_dryrun` table.
+///
+/// One row per action a dry-run observer would have run, holding the rendered
+/// payload captured instead of being sent. Written by `PostgresDryRunSink`.
+///
+/// PostgreSQL only; idempotent (`CREATE … IF NOT EXISTS`).
+///
+/// # Example
+///
+/// ```
+/// let sql = fraiseql_observers::migrations::observer_dryrun_sql();
+/// assert!(sql.contains("tb_observer_dryrun"));
+/// ```
+#[must_use]
+pub const fn observer_dryrun_sql() -> &'static str {
+    include_str!("../../migrations/18_create_observer_dryrun.sql")
+}
+
+/// SQL DDL that installs the event type registry tables (`tb_observer_event_type`,
+/// `tb_observer_event_quarantine`).
+///
+/// The first holds event types declared at runtime with their payload JSON
+/// Schemas; the second, the events that failed validation with their
+/// violations. Written by `PostgresEventTypeStore` and `PostgresEventQuarantine`.
+///
+/// PostgreSQL only; idempotent (`CREATE … IF NOT EXISTS`).
+///
+/// # Example
+///
+/// ```
+/// let sql = fraiseql_observers::migrations::observer_event_registry_sql();
+/// assert!(sql.contains("tb_observer_event_quarantine"));
+/// ```
+#[must_use]
+pub const fn observer_event_registry_sql() -> &'static str {
+    include_str!("../../migrations/19_create_observer_event_registry.sql")
+}
+
 /// One column of the `core.tb_entity_change_log` contract: its name and the
 /// canonical PostgreSQL base type the migration installs it as.
 ///
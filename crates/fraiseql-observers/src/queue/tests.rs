@@ -25,6 +25,8 @@ mod queue_tests {
                 body_template:      None,
                 signing_secret:     None,
                 signing_secret_env: None,
+                signing_secret_ref: None,
+                signing_key_id:     None,
             },
             attempt:       1,
             created_at:    chrono::Utc::now().timestamp(),
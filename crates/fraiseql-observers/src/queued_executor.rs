@@ -1,8 +1,10 @@
 //! Queued observer executor that asynchronously processes actions via job queue.
 //!
 //! This module implements a wrapper around `ObserverExecutor` that:
-//! 1. Evaluates event matching and conditions (fast, in-memory)
-//! 2. Queues actions as jobs instead of executing immediately
+//! 1. Validates events against the event registry, when one is wired, and evaluates event matching
+//!    and conditions (fast, in-memory)
+//! 2. Queues actions as jobs instead of executing immediately (or captures them, for dry-run
+//!    observers)
 //! 3. Returns job IDs for status tracking
 //! 4. Enables background job workers to execute actions with retry logic
 //!
@@ -31,8 +33,13 @@ use crate::job_queue::{Job, JobQueue};
 #[cfg(feature = "metrics")]
 use crate::metrics::MetricsRegistry;
 use crate::{
-    condition::ConditionParser, error::Result, event::EntityEvent, executor::ExecutionSummary,
+    condition::ConditionParser,
+    dryrun::{DryRunCapture, DryRunSink, InMemoryDryRunSink},
+    error::Result,
+    event::EntityEvent,
+    executor::ExecutionSummary,
     matcher::EventMatcher,
+    registry::{EventQuarantine, EventSchemaRegistry, InMemoryEventQuarantine, QuarantinedEvent},
 };
 
 #[cfg(feature = "queue")]
@@ -47,6 +54,19 @@ pub struct QueuedObserverExecutor {
     /// Job queue for asynchronous action execution
     job_queue: Arc<dyn JobQueue>,
 
+    /// Simulation mode: every observer runs dry, whatever its `dry_run`
+    simulation: bool,
+
+    /// Where the actions of dry-run observers are captured
+    dry_run_sink: Arc<dyn DryRunSink>,
+
+    /// Declared event types events are validated against (`None`: no
+    /// validation)
+    event_registry: Option<Arc<EventSchemaRegistry>>,
+
+    /// Where events failing validation are kept
+    quarantine: Arc<dyn EventQuarantine>,
+
     /// Prometheus metrics registry
     #[cfg(feature = "metrics")]
     metrics: MetricsRegistry,
@@ -66,6 +86,10 @@ impl QueuedObserverExecutor {
             matcher: Arc::new(matcher),
             condition_parser: Arc::new(ConditionParser::new()),
             job_queue,
+            simulation: false,
+            dry_run_sink: Arc::new(InMemoryDryRunSink::new()),
+            event_registry: None,
+            quarantine: Arc::new(InMemoryEventQuarantine::new()),
             #[cfg(feature = "metrics")]
             metrics: MetricsRegistry::global().unwrap_or_else(|e| {
                 tracing::warn!(error = %e, "MetricsRegistry::global() failed; metrics disabled");
@@ -74,6 +98,37 @@ impl QueuedObserverExecutor {
         }
     }
 
+    /// Run in simulation mode: every observer runs dry, as if its definition
+    /// set `dry_run`.
+    #[must_use]
+    pub const fn with_simulation(mut self, enabled: bool) -> Self {
+        self.simulation = enabled;
+        self
+    }
+
+    /// Capture the actions of dry-run observers in `sink` instead of
+    /// queuing them.
+    #[must_use]
+    pub fn with_dry_run_sink(mut self, sink: Arc<dyn DryRunSink>) -> Self {
+        self.dry_run_sink = sink;
+        self
+    }
+
+    /// Validate every event against the event types declared in `registry`;
+    /// events that fail are quarantined instead of queued.
+    #[must_use]
+    pub fn with_event_registry(mut self, registry: Arc<EventSchemaRegistry>) -> Self {
+        self.event_registry = Some(registry);
+        self
+    }
+
+    /// Keep events failing validation in `quarantine`.
+    #[must_use]
+    pub fn with_event_quarantine(mut self, quarantine: Arc<dyn EventQuarantine>) -> Self {
+        self.quarantine = quarantine;
+        self
+    }
+
     /// Process an event by queuing matching actions as jobs
     ///
     /// Unlike the synchronous `ObserverExecutor::process_event()`, this:
@@ -97,7 +152,19 @@ impl QueuedObserverExecutor {
     /// Propagates errors from the condition parser if a condition expression is invalid.
     pub async fn process_event(&self, event: &EntityEvent) -> Result<QueuedExecutionSummary> {
         let mut summary = QueuedExecutionSummary::new();
-        let matching_observers = self.matcher.find_matches(event);
+        if let Some(registry) = &self.event_registry {
+            if let Err(violations) = registry.validate(event) {
+                tracing::warn!(event_id = %event.id, "Quarantining event that failed validation");
+                summary.quarantined = true;
+                let entry = QuarantinedEvent::new(event, &violations);
+                if let Err(e) = self.quarantine.quarantine(&entry).await {
+                    tracing::error!("Failed to quarantine event: {e}");
+                    summary.errors.push(format!("Failed to quarantine event: {e}"));
+                }
+                return Ok(summary);
+            }
+        }
+        let matching_observers = self.matcher.find_named_matches(event);
 
         tracing::debug!(
             "Processing event {} for queuing (entity_type: {}, event_type: {:?})",
@@ -107,7 +174,7 @@ impl QueuedObserverExecutor {
         );
         tracing::debug!("Found {} matching observers for queuing", matching_observers.len());
 
-        for observer in matching_observers {
+        for (observer_name, observer) in matching_observers {
             // Skip if condition is not met
             if let Some(condition) = &observer.condition {
                 match self.condition_parser.parse_and_evaluate(condition, event) {
@@ -127,6 +194,20 @@ impl QueuedObserverExecutor {
                 }
             }
 
+            if self.simulation || observer.dry_run {
+                for (action_index, action) in observer.actions.iter().enumerate() {
+                    let capture = DryRunCapture::render(observer_name, action_index, action, event);
+                    match self.dry_run_sink.capture(&capture).await {
+                        Ok(()) => summary.dry_run_captured += 1,
+                        Err(e) => {
+                            tracing::error!("Failed to record dry-run capture: {e}");
+                            summary.errors.push(format!("Failed to record dry-run capture: {e}"));
+                        },
+                    }
+                }
+                continue;
+            }
+
             // Queue actions for this observer
             for action in &observer.actions {
                 let job = Job::with_config(
@@ -182,6 +263,14 @@ pub struct QueuedExecutionSummary {
     /// Number of observers skipped due to condition
     pub conditions_skipped: usize,
 
+    /// Number of actions captured instead of queued because their observer
+    /// is in dry-run mode
+    pub dry_run_captured: usize,
+
+    /// Whether the event was quarantined because it failed its event type's
+    /// schema
+    pub quarantined: bool,
+
     /// List of queued job IDs
     pub job_ids: Vec<Uuid>,
 
@@ -220,8 +309,11 @@ impl QueuedExecutionSummary {
             errors:             self.errors.clone(),
             duplicate_skipped:  false,
             tenant_rejected:    false,
+            quarantined:        self.quarantined,
             cache_hits:         0,
             cache_misses:       0,
+            replay_skipped:     0,
+            dry_run_captured:   self.dry_run_captured,
             // Queued execution defers dispatch, so no per-action detail is
             // available at queue time (#468).
             action_details:     Vec::new(),
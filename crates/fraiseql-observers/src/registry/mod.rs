@@ -0,0 +1,178 @@
+//! Event type registry: payload validation and quarantine.
+//!
+//! An [`EventSchemaRegistry`] holds the declared event types, each with a JSON
+//! Schema for its payload (see [`schema`] for the supported subset). An
+//! executor wired with a registry validates every event before matching it:
+//! an event whose payload fails its schema — or whose type is undeclared,
+//! under [`UndeclaredEventPolicy::Quarantine`] — reaches no observer and is
+//! handed to an [`EventQuarantine`] with its violations instead.
+//!
+//! Types come from `[observers.runtime.event_types]` and can be registered,
+//! replaced and removed while running. Quarantines:
+//!
+//! - [`InMemoryEventQuarantine`] — the latest quarantined events, per process (default).
+//! - [`PostgresEventQuarantine`] — the `tb_observer_event_quarantine` table.
+//!
+//! Declarations registered at runtime persist in `tb_observer_event_type`
+//! through [`PostgresEventTypeStore`].
+
+#[cfg(feature = "postgres")]
+mod postgres;
+mod quarantine;
+pub mod schema;
+#[cfg(test)]
+mod tests;
+
+use std::{collections::HashMap, sync::Arc};
+
+use parking_lot::RwLock;
+#[cfg(feature = "postgres")]
+pub use postgres::{PostgresEventQuarantine, PostgresEventTypeStore};
+pub use quarantine::{
+    DEFAULT_IN_MEMORY_QUARANTINE, EventQuarantine, InMemoryEventQuarantine, QuarantinedEvent,
+};
+pub use schema::{EventSchema, SchemaViolation};
+
+pub use crate::config::{EventTypeDefinition, EventTypesConfig, UndeclaredEventPolicy};
+use crate::{
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// A declared event type with its compiled schema.
+struct RegisteredEventType {
+    definition: EventTypeDefinition,
+    schema:     EventSchema,
+}
+
+/// The declared event types, by entity type.
+pub struct EventSchemaRegistry {
+    undeclared: UndeclaredEventPolicy,
+    types:      RwLock<HashMap<String, Arc<RegisteredEventType>>>,
+}
+
+impl EventSchemaRegistry {
+    /// An empty registry applying `undeclared` to every event type.
+    #[must_use]
+    pub fn new(undeclared: UndeclaredEventPolicy) -> Self {
+        Self {
+            undeclared,
+            types: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// A registry holding the types declared in `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if a schema does not compile.
+    pub fn from_config(config: &EventTypesConfig) -> Result<Self> {
+        let registry = Self::new(config.undeclared);
+        for definition in &config.types {
+            registry.register(definition.clone())?;
+        }
+        Ok(registry)
+    }
+
+    /// What happens to events of undeclared types.
+    #[must_use]
+    pub const fn undeclared_policy(&self) -> UndeclaredEventPolicy {
+        self.undeclared
+    }
+
+    /// Declare `definition`, replacing any declaration of its entity type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if the entity type is empty or
+    /// the schema does not compile; the registry is then left unchanged.
+    pub fn register(&self, definition: EventTypeDefinition) -> Result<()> {
+        let schema = compile_definition(&definition)?;
+        self.types.write().insert(
+            definition.entity_type.clone(),
+            Arc::new(RegisteredEventType { definition, schema }),
+        );
+        Ok(())
+    }
+
+    /// Remove the declaration of `entity_type`, returning it.
+    pub fn unregister(&self, entity_type: &str) -> Option<EventTypeDefinition> {
+        self.types
+            .write()
+            .remove(entity_type)
+            .map(|registered| registered.definition.clone())
+    }
+
+    /// The declaration of `entity_type`.
+    #[must_use]
+    pub fn get(&self, entity_type: &str) -> Option<EventTypeDefinition> {
+        self.types
+            .read()
+            .get(entity_type)
+            .map(|registered| registered.definition.clone())
+    }
+
+    /// Every declaration, by entity type.
+    #[must_use]
+    pub fn list(&self) -> Vec<EventTypeDefinition> {
+        let mut definitions: Vec<_> = self
+            .types
+            .read()
+            .values()
+            .map(|registered| registered.definition.clone())
+            .collect();
+        definitions.sort_by(|a, b| a.entity_type.cmp(&b.entity_type));
+        definitions
+    }
+
+    /// Check `event` against its type's schema.
+    ///
+    /// Events of a declared type whose schema does not cover the event's kind
+    /// pass unvalidated.
+    ///
+    /// # Errors
+    ///
+    /// Returns the violations if the event must be quarantined.
+    pub fn validate(&self, event: &EntityEvent) -> std::result::Result<(), Vec<SchemaViolation>> {
+        let registered = self.types.read().get(&event.entity_type).cloned();
+        let Some(registered) = registered else {
+            return match self.undeclared {
+                UndeclaredEventPolicy::Accept => Ok(()),
+                UndeclaredEventPolicy::Quarantine => Err(vec![SchemaViolation {
+                    path:    String::new(),
+                    message: format!("event type '{}' is not declared", event.entity_type),
+                }]),
+            };
+        };
+        if !registered.definition.covers(event.event_type) {
+            return Ok(());
+        }
+        let violations = registered.schema.validate(&event.data);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl Default for EventSchemaRegistry {
+    fn default() -> Self {
+        Self::new(UndeclaredEventPolicy::Accept)
+    }
+}
+
+/// Compile the schema of `definition`, naming its type in errors.
+pub(crate) fn compile_definition(definition: &EventTypeDefinition) -> Result<EventSchema> {
+    if definition.entity_type.trim().is_empty() {
+        return Err(ObserverError::InvalidConfig {
+            message: "event_types: entity_type must not be empty".to_string(),
+        });
+    }
+    EventSchema::compile(&definition.schema).map_err(|e| match e {
+        ObserverError::InvalidConfig { message } => ObserverError::InvalidConfig {
+            message: format!("event_types: '{}': {message}", definition.entity_type),
+        },
+        other => other,
+    })
+}
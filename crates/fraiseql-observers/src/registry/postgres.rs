@@ -0,0 +1,221 @@
+//! PostgreSQL-backed event type declarations (`tb_observer_event_type`) and
+//! quarantine (`tb_observer_event_quarantine`).
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::{EventQuarantine, EventTypeDefinition, QuarantinedEvent};
+use crate::error::{ObserverError, Result};
+
+/// Create the registry tables installed by
+/// [`observer_event_registry_sql`](crate::migrations::observer_event_registry_sql).
+async fn init(pool: &PgPool, what: &str) -> Result<()> {
+    sqlx::raw_sql(crate::migrations::observer_event_registry_sql())
+        .execute(pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("{what}: init: {e}"),
+        })?;
+    Ok(())
+}
+
+/// Event type declarations stored in `tb_observer_event_type`.
+#[derive(Clone)]
+pub struct PostgresEventTypeStore {
+    pool: PgPool,
+}
+
+impl PostgresEventTypeStore {
+    /// Create a store over an existing pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create the registry tables (idempotent). Call once on startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the DDL fails.
+    pub async fn init(&self) -> Result<()> {
+        init(&self.pool, "event type store").await
+    }
+
+    /// Every stored declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the query fails or a stored
+    /// declaration does not parse.
+    pub async fn load(&self) -> Result<Vec<EventTypeDefinition>> {
+        // Read as text, so decoding needs only the text codec.
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT definition::text FROM tb_observer_event_type ORDER BY entity_type",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("event type store: load: {e}"),
+        })?;
+        rows.into_iter()
+            .map(|(definition,)| {
+                serde_json::from_str(&definition).map_err(|e| ObserverError::DatabaseError {
+                    reason: format!("event type store: stored declaration does not parse: {e}"),
+                })
+            })
+            .collect()
+    }
+
+    /// Store `definition`, replacing any declaration of its entity type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the upsert fails.
+    pub async fn save(&self, definition: &EventTypeDefinition) -> Result<()> {
+        let json = serde_json::to_string(definition)
+            .map_err(|e| ObserverError::SerializationError(e.to_string()))?;
+        sqlx::query(
+            r"
+            INSERT INTO tb_observer_event_type (entity_type, definition, updated_at)
+            VALUES ($1, $2::jsonb, NOW())
+            ON CONFLICT (entity_type) DO UPDATE SET
+                definition = EXCLUDED.definition,
+                updated_at = EXCLUDED.updated_at
+            ",
+        )
+        .bind(&definition.entity_type)
+        .bind(json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("event type store: save '{}': {e}", definition.entity_type),
+        })?;
+        Ok(())
+    }
+
+    /// Delete the declaration of `entity_type`. Returns whether one existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the delete fails.
+    pub async fn delete(&self, entity_type: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM tb_observer_event_type WHERE entity_type = $1")
+            .bind(entity_type)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("event type store: delete '{entity_type}': {e}"),
+            })?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Quarantine writing to `tb_observer_event_quarantine`.
+#[derive(Clone)]
+pub struct PostgresEventQuarantine {
+    pool: PgPool,
+}
+
+impl PostgresEventQuarantine {
+    /// Create a quarantine over an existing pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create the registry tables (idempotent). Call once on startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the DDL fails.
+    pub async fn init(&self) -> Result<()> {
+        init(&self.pool, "event quarantine").await
+    }
+
+    /// Delete entries quarantined more than `older_than` ago. Returns how many.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the delete fails.
+    pub async fn prune(&self, older_than: std::time::Duration) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM tb_observer_event_quarantine \
+             WHERE quarantined_at < NOW() - make_interval(secs => $1)",
+        )
+        .bind(older_than.as_secs_f64())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("event quarantine: prune: {e}"),
+        })?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait]
+impl EventQuarantine for PostgresEventQuarantine {
+    async fn quarantine(&self, entry: &QuarantinedEvent) -> Result<()> {
+        // Serialize to text and cast to jsonb, so binding needs only the text codec.
+        let serialize_err = |e: serde_json::Error| ObserverError::SerializationError(e.to_string());
+        let event = serde_json::to_string(&entry.event).map_err(serialize_err)?;
+        let violations = serde_json::to_string(&entry.violations).map_err(serialize_err)?;
+        sqlx::query(
+            r"
+            INSERT INTO tb_observer_event_quarantine (
+                id, event_id, entity_type, entity_id, event_type, tenant_id,
+                event, violations, quarantined_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7::jsonb, $8::jsonb, $9)
+            ",
+        )
+        .bind(entry.id)
+        .bind(entry.event.id)
+        .bind(&entry.event.entity_type)
+        .bind(entry.event.entity_id)
+        .bind(entry.event.event_type.as_str())
+        .bind(&entry.event.tenant_id)
+        .bind(event)
+        .bind(violations)
+        .bind(entry.quarantined_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("event quarantine: quarantine event {}: {e}", entry.event.id),
+        })?;
+        Ok(())
+    }
+
+    async fn list(&self, entity_type: Option<&str>, limit: usize) -> Result<Vec<QuarantinedEvent>> {
+        let rows: Vec<(Uuid, String, String, DateTime<Utc>)> = sqlx::query_as(
+            r"
+            SELECT id, event::text, violations::text, quarantined_at
+            FROM tb_observer_event_quarantine
+            WHERE $1::text IS NULL OR entity_type = $1
+            ORDER BY quarantined_at DESC
+            LIMIT $2
+            ",
+        )
+        .bind(entity_type)
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("event quarantine: list: {e}"),
+        })?;
+        let parse_err = |e: serde_json::Error| ObserverError::DatabaseError {
+            reason: format!("event quarantine: stored entry does not parse: {e}"),
+        };
+        rows.into_iter()
+            .map(|(id, event, violations, quarantined_at)| {
+                Ok(QuarantinedEvent {
+                    id,
+                    event: serde_json::from_str(&event).map_err(parse_err)?,
+                    violations: serde_json::from_str(&violations).map_err(parse_err)?,
+                    quarantined_at,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,126 @@
+//! Where events failing validation are kept.
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SchemaViolation;
+use crate::{error::Result, event::EntityEvent};
+
+/// Quarantined events kept by [`InMemoryEventQuarantine::new`].
+pub const DEFAULT_IN_MEMORY_QUARANTINE: usize = 1_000;
+
+/// An event held back because it failed validation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantinedEvent {
+    /// Quarantine entry id
+    pub id:             Uuid,
+    /// The event, as received
+    pub event:          EntityEvent,
+    /// Why it was quarantined
+    pub violations:     Vec<SchemaViolation>,
+    /// When it was quarantined
+    pub quarantined_at: DateTime<Utc>,
+}
+
+impl QuarantinedEvent {
+    /// A new entry for `event`, quarantined now.
+    #[must_use]
+    pub fn new(event: &EntityEvent, violations: &[SchemaViolation]) -> Self {
+        Self {
+            id:             Uuid::new_v4(),
+            event:          event.clone(),
+            violations:     violations.to_vec(),
+            quarantined_at: Utc::now(),
+        }
+    }
+}
+
+/// Storage for quarantined events.
+#[async_trait]
+pub trait EventQuarantine: Send + Sync {
+    /// Record `entry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be stored.
+    async fn quarantine(&self, entry: &QuarantinedEvent) -> Result<()>;
+
+    /// The most recent entries, newest first, optionally only those about
+    /// `entity_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entries cannot be read.
+    async fn list(&self, entity_type: Option<&str>, limit: usize) -> Result<Vec<QuarantinedEvent>>;
+}
+
+/// Keeps the latest quarantined events in memory; the oldest are dropped once
+/// it is full.
+pub struct InMemoryEventQuarantine {
+    capacity: usize,
+    entries:  Mutex<VecDeque<QuarantinedEvent>>,
+}
+
+impl InMemoryEventQuarantine {
+    /// A quarantine keeping the latest 1,000 events.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_IN_MEMORY_QUARANTINE)
+    }
+
+    /// A quarantine keeping the latest `capacity` events.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries:  Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Number of entries held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Whether no entry is held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+}
+
+impl Default for InMemoryEventQuarantine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EventQuarantine for InMemoryEventQuarantine {
+    async fn quarantine(&self, entry: &QuarantinedEvent) -> Result<()> {
+        let mut entries = self.entries.lock();
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry.clone());
+        Ok(())
+    }
+
+    async fn list(&self, entity_type: Option<&str>, limit: usize) -> Result<Vec<QuarantinedEvent>> {
+        Ok(self
+            .entries
+            .lock()
+            .iter()
+            .rev()
+            .filter(|entry| entity_type.is_none_or(|t| entry.event.entity_type == t))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,516 @@
+//! The JSON Schema subset event payloads are validated against.
+//!
+//! A schema is compiled once, when its event type is registered, so keyword
+//! typos and unsupported keywords are rejected up front instead of silently
+//! accepting every payload.
+//!
+//! Supported keywords:
+//!
+//! - any value: `type`, `enum`, `const`, `allOf`, `anyOf`, `oneOf`, `not`
+//! - objects: `properties`, `required`, `additionalProperties`, `minProperties`, `maxProperties`
+//! - arrays: `items`, `minItems`, `maxItems`, `uniqueItems`
+//! - strings: `minLength`, `maxLength`, `pattern`
+//! - numbers: `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum`
+//!
+//! Annotations (`$schema`, `$id`, `title`, `description`, `default`,
+//! `examples`, `$comment`, `format`) are accepted and ignored. Anything else —
+//! notably `$ref` — is a compile error.
+
+use std::fmt;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::{ObserverError, Result};
+
+/// Most violations reported for one payload; the rest are dropped.
+const MAX_VIOLATIONS: usize = 20;
+
+/// Keywords that carry no validation.
+const ANNOTATIONS: &[&str] = &[
+    "$schema",
+    "$id",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "$comment",
+    "format",
+];
+
+/// One way a payload fails its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaViolation {
+    /// JSON Pointer to the offending value (`""` for the payload itself).
+    pub path:    String,
+    /// What is wrong with it.
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// A compiled payload schema.
+#[derive(Debug, Clone)]
+pub struct EventSchema {
+    root: Node,
+}
+
+impl EventSchema {
+    /// Compile `schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if `schema` is not a schema,
+    /// uses an unsupported keyword, or gives a keyword an invalid value.
+    pub fn compile(schema: &Value) -> Result<Self> {
+        Ok(Self {
+            root: Node::compile(schema, "")?,
+        })
+    }
+
+    /// Validate `payload`, returning every violation (up to 20).
+    #[must_use]
+    pub fn validate(&self, payload: &Value) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        self.root.validate(payload, &mut String::new(), &mut violations);
+        violations.truncate(MAX_VIOLATIONS);
+        violations
+    }
+
+    /// Whether `payload` satisfies the schema.
+    #[must_use]
+    pub fn is_valid(&self, payload: &Value) -> bool {
+        self.root.is_valid(payload)
+    }
+}
+
+/// A JSON Schema primitive type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonType {
+    Null,
+    Boolean,
+    Object,
+    Array,
+    Number,
+    Integer,
+    String,
+}
+
+impl JsonType {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "null" => Self::Null,
+            "boolean" => Self::Boolean,
+            "object" => Self::Object,
+            "array" => Self::Array,
+            "number" => Self::Number,
+            "integer" => Self::Integer,
+            "string" => Self::String,
+            _ => return None,
+        })
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::String => "string",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Null => value.is_null(),
+            Self::Boolean => value.is_boolean(),
+            Self::Object => value.is_object(),
+            Self::Array => value.is_array(),
+            Self::Number => value.is_number(),
+            Self::Integer => {
+                value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+            },
+            Self::String => value.is_string(),
+        }
+    }
+}
+
+/// What `additionalProperties` allows.
+#[derive(Debug, Clone, Default)]
+enum Additional {
+    #[default]
+    Any,
+    None,
+    Schema(Box<Node>),
+}
+
+/// One compiled (sub)schema.
+#[derive(Debug, Clone)]
+enum Node {
+    /// The boolean schema `true` (or `{}`).
+    Any,
+    /// The boolean schema `false`.
+    Nothing,
+    Keywords(Box<Keywords>),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Keywords {
+    types:          Option<Vec<JsonType>>,
+    enum_values:    Option<Vec<Value>>,
+    const_value:    Option<Value>,
+    all_of:         Vec<Node>,
+    any_of:         Vec<Node>,
+    one_of:         Vec<Node>,
+    not:            Option<Node>,
+    properties:     Vec<(String, Node)>,
+    required:       Vec<String>,
+    additional:     Additional,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+    items:          Option<Node>,
+    min_items:      Option<usize>,
+    max_items:      Option<usize>,
+    unique_items:   bool,
+    min_length:     Option<usize>,
+    max_length:     Option<usize>,
+    pattern:        Option<Regex>,
+    minimum:        Option<f64>,
+    maximum:        Option<f64>,
+    exclusive_min:  Option<f64>,
+    exclusive_max:  Option<f64>,
+}
+
+impl Node {
+    fn compile(schema: &Value, at: &str) -> Result<Self> {
+        let map = match schema {
+            Value::Bool(true) => return Ok(Self::Any),
+            Value::Bool(false) => return Ok(Self::Nothing),
+            Value::Object(map) if map.is_empty() => return Ok(Self::Any),
+            Value::Object(map) => map,
+            _ => return Err(invalid(at, "a schema must be an object or a boolean")),
+        };
+
+        let mut keywords = Keywords::default();
+        for (keyword, value) in map {
+            keywords.apply(keyword, value, at)?;
+        }
+        Ok(Self::Keywords(Box::new(keywords)))
+    }
+
+    fn is_valid(&self, value: &Value) -> bool {
+        let mut violations = Vec::new();
+        self.validate(value, &mut String::new(), &mut violations);
+        violations.is_empty()
+    }
+
+    fn validate(&self, value: &Value, path: &mut String, out: &mut Vec<SchemaViolation>) {
+        let keywords = match self {
+            Self::Any => return,
+            Self::Nothing => {
+                violation(out, path, "no value is allowed here".to_string());
+                return;
+            },
+            Self::Keywords(keywords) => keywords,
+        };
+        if out.len() >= MAX_VIOLATIONS {
+            return;
+        }
+
+        if let Some(types) = &keywords.types {
+            if !types.iter().any(|t| t.matches(value)) {
+                let expected: Vec<_> = types.iter().map(|t| t.name()).collect();
+                let message =
+                    format!("expected {}, got {}", expected.join(" or "), value_type(value));
+                violation(out, path, message);
+                // Other keywords would only repeat the mismatch.
+                return;
+            }
+        }
+        if let Some(values) = &keywords.enum_values {
+            if !values.contains(value) {
+                violation(out, path, format!("{value} is not one of the allowed values"));
+            }
+        }
+        if let Some(expected) = &keywords.const_value {
+            if expected != value {
+                violation(out, path, format!("expected {expected}"));
+            }
+        }
+        for node in &keywords.all_of {
+            node.validate(value, path, out);
+        }
+        if !keywords.any_of.is_empty() && !keywords.any_of.iter().any(|n| n.is_valid(value)) {
+            violation(out, path, "matches none of the anyOf schemas".to_string());
+        }
+        if !keywords.one_of.is_empty() {
+            let matched = keywords.one_of.iter().filter(|n| n.is_valid(value)).count();
+            if matched != 1 {
+                violation(out, path, format!("matches {matched} of the oneOf schemas, not 1"));
+            }
+        }
+        if keywords.not.as_ref().is_some_and(|n| n.is_valid(value)) {
+            violation(out, path, "matches the schema in 'not'".to_string());
+        }
+
+        match value {
+            Value::Object(map) => keywords.validate_object(map, path, out),
+            Value::Array(items) => keywords.validate_array(items, path, out),
+            Value::String(s) => keywords.validate_string(s, path, out),
+            Value::Number(n) => {
+                if let Some(n) = n.as_f64() {
+                    keywords.validate_number(n, path, out);
+                }
+            },
+            Value::Null | Value::Bool(_) => {},
+        }
+    }
+}
+
+impl Keywords {
+    /// Compile one keyword of the schema at `at`.
+    fn apply(&mut self, keyword: &str, value: &Value, at: &str) -> Result<()> {
+        let here = format!("{at}/{keyword}");
+        match keyword {
+            "type" => self.types = Some(compile_types(value, &here)?),
+            "enum" => {
+                let Value::Array(values) = value else {
+                    return Err(invalid(&here, "must be an array"));
+                };
+                self.enum_values = Some(values.clone());
+            },
+            "const" => self.const_value = Some(value.clone()),
+            "allOf" => self.all_of = compile_list(value, &here)?,
+            "anyOf" => self.any_of = compile_list(value, &here)?,
+            "oneOf" => self.one_of = compile_list(value, &here)?,
+            "not" => self.not = Some(Node::compile(value, &here)?),
+            "properties" => {
+                let Value::Object(properties) = value else {
+                    return Err(invalid(&here, "must be an object"));
+                };
+                self.properties = properties
+                    .iter()
+                    .map(|(name, schema)| {
+                        Ok((name.clone(), Node::compile(schema, &format!("{here}/{name}"))?))
+                    })
+                    .collect::<Result<_>>()?;
+            },
+            "required" => self.required = compile_required(value, &here)?,
+            "additionalProperties" => {
+                self.additional = match Node::compile(value, &here)? {
+                    Node::Any => Additional::Any,
+                    Node::Nothing => Additional::None,
+                    node => Additional::Schema(Box::new(node)),
+                };
+            },
+            "minProperties" => self.min_properties = Some(count(value, &here)?),
+            "maxProperties" => self.max_properties = Some(count(value, &here)?),
+            "items" => self.items = Some(Node::compile(value, &here)?),
+            "minItems" => self.min_items = Some(count(value, &here)?),
+            "maxItems" => self.max_items = Some(count(value, &here)?),
+            "uniqueItems" => {
+                self.unique_items =
+                    value.as_bool().ok_or_else(|| invalid(&here, "must be a boolean"))?;
+            },
+            "minLength" => self.min_length = Some(count(value, &here)?),
+            "maxLength" => self.max_length = Some(count(value, &here)?),
+            "pattern" => {
+                let pattern = value.as_str().ok_or_else(|| invalid(&here, "must be a string"))?;
+                self.pattern = Some(
+                    Regex::new(pattern)
+                        .map_err(|e| invalid(&here, &format!("invalid regex: {e}")))?,
+                );
+            },
+            "minimum" => self.minimum = Some(number(value, &here)?),
+            "maximum" => self.maximum = Some(number(value, &here)?),
+            "exclusiveMinimum" => self.exclusive_min = Some(number(value, &here)?),
+            "exclusiveMaximum" => self.exclusive_max = Some(number(value, &here)?),
+            annotation if ANNOTATIONS.contains(&annotation) => {},
+            other => return Err(invalid(at, &format!("unsupported keyword '{other}'"))),
+        }
+        Ok(())
+    }
+
+    fn validate_object(
+        &self,
+        map: &Map<String, Value>,
+        path: &mut String,
+        out: &mut Vec<SchemaViolation>,
+    ) {
+        for name in &self.required {
+            if !map.contains_key(name) {
+                violation(out, path, format!("missing required property '{name}'"));
+            }
+        }
+        if self.min_properties.is_some_and(|min| map.len() < min) {
+            violation(out, path, format!("has {} properties, fewer than allowed", map.len()));
+        }
+        if self.max_properties.is_some_and(|max| map.len() > max) {
+            violation(out, path, format!("has {} properties, more than allowed", map.len()));
+        }
+        for (name, value) in map {
+            let declared = self.properties.iter().find(|(declared, _)| declared == name);
+            let len = path.len();
+            push_segment(path, name);
+            match (declared, &self.additional) {
+                (Some((_, node)), _) => node.validate(value, path, out),
+                (None, Additional::Any) => {},
+                (None, Additional::None) => {
+                    violation(out, path, "property is not allowed".to_string());
+                },
+                (None, Additional::Schema(node)) => node.validate(value, path, out),
+            }
+            path.truncate(len);
+        }
+    }
+
+    fn validate_array(&self, items: &[Value], path: &mut String, out: &mut Vec<SchemaViolation>) {
+        if self.min_items.is_some_and(|min| items.len() < min) {
+            violation(out, path, format!("has {} items, fewer than allowed", items.len()));
+        }
+        if self.max_items.is_some_and(|max| items.len() > max) {
+            violation(out, path, format!("has {} items, more than allowed", items.len()));
+        }
+        if self.unique_items {
+            let duplicate = items.iter().enumerate().any(|(i, a)| items[..i].contains(a));
+            if duplicate {
+                violation(out, path, "items are not unique".to_string());
+            }
+        }
+        if let Some(node) = &self.items {
+            for (index, item) in items.iter().enumerate() {
+                let len = path.len();
+                push_segment(path, &index.to_string());
+                node.validate(item, path, out);
+                path.truncate(len);
+            }
+        }
+    }
+
+    fn validate_string(&self, s: &str, path: &str, out: &mut Vec<SchemaViolation>) {
+        let chars = s.chars().count();
+        if self.min_length.is_some_and(|min| chars < min) {
+            violation(out, path, format!("is {chars} characters, shorter than allowed"));
+        }
+        if self.max_length.is_some_and(|max| chars > max) {
+            violation(out, path, format!("is {chars} characters, longer than allowed"));
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(s) {
+                violation(out, path, format!("does not match pattern '{}'", pattern.as_str()));
+            }
+        }
+    }
+
+    fn validate_number(&self, n: f64, path: &str, out: &mut Vec<SchemaViolation>) {
+        if let Some(min) = self.minimum.filter(|min| n < *min) {
+            violation(out, path, format!("{n} is less than the minimum {min}"));
+        }
+        if let Some(max) = self.maximum.filter(|max| n > *max) {
+            violation(out, path, format!("{n} is greater than the maximum {max}"));
+        }
+        if let Some(min) = self.exclusive_min.filter(|min| n <= *min) {
+            violation(out, path, format!("{n} is not greater than {min}"));
+        }
+        if let Some(max) = self.exclusive_max.filter(|max| n >= *max) {
+            violation(out, path, format!("{n} is not less than {max}"));
+        }
+    }
+}
+
+fn compile_types(value: &Value, at: &str) -> Result<Vec<JsonType>> {
+    let names: Vec<&str> = match value {
+        Value::String(name) => vec![name.as_str()],
+        Value::Array(names) => names
+            .iter()
+            .map(|name| name.as_str().ok_or_else(|| invalid(at, "must hold type names")))
+            .collect::<Result<_>>()?,
+        _ => return Err(invalid(at, "must be a type name or an array of them")),
+    };
+    names
+        .into_iter()
+        .map(|name| {
+            JsonType::parse(name).ok_or_else(|| invalid(at, &format!("unknown type '{name}'")))
+        })
+        .collect()
+}
+
+fn compile_list(value: &Value, at: &str) -> Result<Vec<Node>> {
+    match value {
+        Value::Array(schemas) if !schemas.is_empty() => schemas
+            .iter()
+            .enumerate()
+            .map(|(i, schema)| Node::compile(schema, &format!("{at}/{i}")))
+            .collect(),
+        _ => Err(invalid(at, "must be a non-empty array of schemas")),
+    }
+}
+
+fn compile_required(value: &Value, at: &str) -> Result<Vec<String>> {
+    let Value::Array(names) = value else {
+        return Err(invalid(at, "must be an array of property names"));
+    };
+    names
+        .iter()
+        .map(|name| {
+            name.as_str()
+                .map(str::to_string)
+                .ok_or_else(|| invalid(at, "must be an array of property names"))
+        })
+        .collect()
+}
+
+fn count(value: &Value, at: &str) -> Result<usize> {
+    value
+        .as_u64()
+        .and_then(|n| usize::try_from(n).ok())
+        .ok_or_else(|| invalid(at, "must be a non-negative integer"))
+}
+
+fn number(value: &Value, at: &str) -> Result<f64> {
+    value.as_f64().ok_or_else(|| invalid(at, "must be a number"))
+}
+
+fn invalid(at: &str, reason: &str) -> ObserverError {
+    let at = if at.is_empty() { "/" } else { at };
+    ObserverError::InvalidConfig {
+        message: format!("invalid event schema at {at}: {reason}"),
+    }
+}
+
+fn violation(out: &mut Vec<SchemaViolation>, path: &str, message: String) {
+    if out.len() < MAX_VIOLATIONS {
+        out.push(SchemaViolation {
+            path: path.to_string(),
+            message,
+        });
+    }
+}
+
+/// Append `segment` to a JSON Pointer, escaping `~` and `/`.
+fn push_segment(path: &mut String, segment: &str) {
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+}
+
+const fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+    }
+}
@@ -0,0 +1,205 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use super::*;
+use crate::event::EventKind;
+
+fn order_schema() -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "required": ["id", "status", "lines"],
+        "additionalProperties": false,
+        "properties": {
+            "id": { "type": "integer", "minimum": 1 },
+            "status": { "enum": ["pending", "shipped"] },
+            "email": { "type": "string", "pattern": "^[^@]+@[^@]+$" },
+            "lines": {
+                "type": "array",
+                "minItems": 1,
+                "items": {
+                    "type": "object",
+                    "required": ["sku"],
+                    "properties": { "sku": { "type": "string", "minLength": 3 } },
+                },
+            },
+        },
+    })
+}
+
+fn order(kind: EventKind, data: Value) -> EntityEvent {
+    EntityEvent::new(kind, "Order".to_string(), Uuid::new_v4(), data)
+}
+
+fn definition(event_kinds: Vec<EventKind>) -> EventTypeDefinition {
+    EventTypeDefinition {
+        entity_type: "Order".to_string(),
+        event_kinds,
+        schema: order_schema(),
+        description: Some("A customer order".to_string()),
+    }
+}
+
+fn paths(violations: &[SchemaViolation]) -> Vec<&str> {
+    violations.iter().map(|v| v.path.as_str()).collect()
+}
+
+#[test]
+fn schema_reports_every_violation_with_its_path() {
+    let schema = EventSchema::compile(&order_schema()).unwrap();
+    let valid = json!({ "id": 42, "status": "shipped", "lines": [{ "sku": "A-100" }] });
+    assert!(schema.is_valid(&valid));
+
+    let invalid = json!({
+        "id": 0,
+        "status": "lost",
+        "email": "nobody",
+        "lines": [{ "sku": "A-100" }, { "sku": "B" }, {}],
+        "note": "extra",
+    });
+    let violations = schema.validate(&invalid);
+    let mut found = paths(&violations);
+    found.sort_unstable();
+    assert_eq!(
+        found,
+        [
+            "/email",
+            "/id",
+            "/lines/1/sku",
+            "/lines/2",
+            "/note",
+            "/status"
+        ]
+    );
+    assert!(
+        violations
+            .iter()
+            .any(|v| v.to_string() == "/lines/2: missing required property 'sku'")
+    );
+
+    let wrong_type = schema.validate(&json!("order"));
+    assert_eq!(wrong_type.len(), 1);
+    assert_eq!(wrong_type[0].to_string(), "expected object, got string");
+}
+
+#[test]
+fn schema_composition_keywords() {
+    let schema = EventSchema::compile(&json!({
+        "anyOf": [{ "type": "string" }, { "type": "integer" }],
+        "not": { "const": 13 },
+    }))
+    .unwrap();
+    assert!(schema.is_valid(&json!("thirteen")));
+    assert!(schema.is_valid(&json!(12)));
+    assert!(!schema.is_valid(&json!(13)));
+    assert!(!schema.is_valid(&json!(1.5)));
+
+    let one_of = EventSchema::compile(&json!({
+        "oneOf": [{ "type": "number" }, { "type": "integer" }],
+    }))
+    .unwrap();
+    assert!(one_of.is_valid(&json!(1.5)));
+    assert!(!one_of.is_valid(&json!(2)), "an integer is also a number");
+}
+
+#[test]
+fn schemas_with_unsupported_or_invalid_keywords_do_not_compile() {
+    for schema in [
+        json!({ "$ref": "#/definitions/order" }),
+        json!({ "type": "object", "propertes": {} }),
+        json!({ "type": "decimal" }),
+        json!({ "minLength": -1 }),
+        json!({ "pattern": "(" }),
+        json!({ "anyOf": [] }),
+        json!("object"),
+    ] {
+        let result = EventSchema::compile(&schema);
+        assert!(
+            matches!(result, Err(ObserverError::InvalidConfig { .. })),
+            "{schema} should not compile"
+        );
+    }
+}
+
+#[test]
+fn registry_validates_declared_kinds_and_applies_the_undeclared_policy() {
+    let registry = EventSchemaRegistry::new(UndeclaredEventPolicy::Quarantine);
+    registry
+        .register(definition(vec![EventKind::Created, EventKind::Updated]))
+        .unwrap();
+
+    let violations = registry.validate(&order(EventKind::Created, json!({ "id": 1 }))).unwrap_err();
+    assert_eq!(paths(&violations), ["", ""], "status and lines are missing");
+    // Deleted orders are not covered by the schema.
+    assert!(registry.validate(&order(EventKind::Deleted, json!({}))).is_ok());
+
+    let user = EntityEvent::new(EventKind::Created, "User".to_string(), Uuid::new_v4(), json!({}));
+    let violations = registry.validate(&user).unwrap_err();
+    assert_eq!(violations[0].message, "event type 'User' is not declared");
+    assert!(EventSchemaRegistry::default().validate(&user).is_ok());
+}
+
+#[test]
+fn registry_register_replace_and_unregister() {
+    let registry = EventSchemaRegistry::default();
+    let mut bad = definition(Vec::new());
+    bad.schema = json!({ "type": "object", "$ref": "#/x" });
+    let err = registry.register(bad).unwrap_err();
+    assert!(err.to_string().contains("'Order'"), "{err}");
+    assert!(registry.list().is_empty(), "a failed registration changes nothing");
+
+    registry.register(definition(Vec::new())).unwrap();
+    let mut relaxed = definition(Vec::new());
+    relaxed.schema = json!({ "type": "object" });
+    registry.register(relaxed.clone()).unwrap();
+    assert_eq!(registry.list(), [relaxed.clone()]);
+    assert!(registry.validate(&order(EventKind::Created, json!({ "id": 1 }))).is_ok());
+
+    assert_eq!(registry.unregister("Order"), Some(relaxed));
+    assert_eq!(registry.get("Order"), None);
+}
+
+#[test]
+fn config_rejects_duplicate_and_invalid_declarations() {
+    let config: EventTypesConfig = serde_json::from_value(json!({
+        "undeclared": "quarantine",
+        "types": [{ "entity_type": "Order", "event_kinds": ["INSERT"], "schema": order_schema() }],
+    }))
+    .unwrap();
+    config.validate().unwrap();
+    assert!(config.is_enabled());
+    let registry = EventSchemaRegistry::from_config(&config).unwrap();
+    assert_eq!(registry.undeclared_policy(), UndeclaredEventPolicy::Quarantine);
+    assert_eq!(registry.get("Order").unwrap().event_kinds, [EventKind::Created]);
+
+    let mut duplicate = config.clone();
+    duplicate.types.push(config.types[0].clone());
+    assert!(duplicate.validate().is_err());
+
+    let mut invalid = config;
+    invalid.types[0].schema = json!({ "type": 7 });
+    assert!(invalid.validate().is_err());
+    assert!(!EventTypesConfig::default().is_enabled());
+}
+
+#[tokio::test]
+async fn in_memory_quarantine_lists_newest_first_and_drops_the_oldest() {
+    let quarantine = InMemoryEventQuarantine::with_capacity(2);
+    let violation = [SchemaViolation {
+        path:    "/id".to_string(),
+        message: "expected integer, got string".to_string(),
+    }];
+    let events: Vec<_> = (0..3).map(|i| order(EventKind::Created, json!({ "n": i }))).collect();
+    for event in &events {
+        quarantine.quarantine(&QuarantinedEvent::new(event, &violation)).await.unwrap();
+    }
+
+    let entries = quarantine.list(None, 10).await.unwrap();
+    let ids: Vec<_> = entries.iter().map(|entry| entry.event.id).collect();
+    assert_eq!(ids, [events[2].id, events[1].id]);
+    assert_eq!(entries[0].violations, violation);
+    assert_eq!(quarantine.list(Some("Order"), 1).await.unwrap().len(), 1);
+    assert!(quarantine.list(Some("User"), 10).await.unwrap().is_empty());
+}
@@ -0,0 +1,534 @@
+//! Point-in-time replay of stored events through the observer pipeline.
+//!
+//! [`EventStorage`] keeps every event an observer saw; replay re-delivers a
+//! slice of that history — one entity type over a time window — to the
+//! [`ObserverExecutor`] as if the events were arriving again. The typical use is
+//! backfilling a search index or cache that was added after the events were
+//! written, or re-driving a webhook consumer that was down.
+//!
+//! # Replay semantics
+//!
+//! - **Order**: events are delivered oldest first, paged by a `(timestamp, id)` keyset cursor
+//!   ([`EventStorage::query_events_after`]), so a window larger than memory is walked in stable
+//!   order without skips or repeats at page boundaries.
+//! - **Opt-out**: events go through [`ObserverExecutor::process_replayed_event`], so each
+//!   observer's [`ReplayPolicy`](crate::config::ReplayPolicy) decides which of its actions run. By
+//!   default user-facing notifications (email, Slack, SMS, push) are skipped.
+//! - **Throttle**: [`ReplayRequest::with_rate_limit`] caps events per second so a large backfill
+//!   does not flood downstream systems.
+//! - **Audit**: every replay gets a [`ReplayAuditRecord`] that is written before the first event is
+//!   delivered and updated after every page. A replay whose audit record cannot be written is not
+//!   started. An interrupted replay leaves its last cursor in the record, and
+//!   [`ReplayRequest::with_resume_after`] continues from it.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use fraiseql_observers::{
+//!     ObserverExecutor, PostgresEventStorage,
+//!     replay::{PostgresReplayAudit, ReplayEngine, ReplayRequest},
+//! };
+//! use sqlx::PgPool;
+//!
+//! # async fn example(pool: PgPool, executor: Arc<ObserverExecutor>) -> Result<(), Box<dyn std::error::Error>> {
+//! let audit = PostgresReplayAudit::new(pool.clone());
+//! audit.init().await?;
+//!
+//! let engine = ReplayEngine::new(PostgresEventStorage::new(pool), executor)
+//!     .with_audit(Arc::new(audit));
+//! let request = ReplayRequest::new("Order", "ops@example.com", "backfill orders search index")
+//!     .with_start("2026-01-01T00:00:00Z".parse()?)
+//!     .with_rate_limit(200);
+//! let record = engine.run(&request).await?;
+//! println!("replayed {} events", record.events_processed);
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(test)]
+mod tests;
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresReplayAudit;
+use serde::{Deserialize, Serialize};
+use tokio::time::{Interval, MissedTickBehavior};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    error::{ObserverError, Result},
+    executor::ObserverExecutor,
+    storage::EventStorage,
+};
+
+/// Default number of events fetched from storage per page.
+pub const DEFAULT_REPLAY_BATCH_SIZE: usize = 500;
+
+/// Upper bound on [`ReplayRequest::batch_size`].
+pub const MAX_REPLAY_BATCH_SIZE: usize = 10_000;
+
+/// What to replay, how fast, and on whose authority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayRequest {
+    /// Entity type whose events are replayed (e.g. `"Order"`).
+    pub entity_type:       String,
+    /// Start of the window (inclusive); `None` = from the oldest stored event.
+    pub start:             Option<DateTime<Utc>>,
+    /// End of the window (inclusive); `None` = up to the newest stored event.
+    pub end:               Option<DateTime<Utc>>,
+    /// Only replay events stamped with this tenant; `None` = every tenant.
+    pub tenant_id:         Option<String>,
+    /// Stop after delivering this many events; `None` = the whole window.
+    pub max_events:        Option<usize>,
+    /// Maximum events delivered per second; `None` = unthrottled.
+    pub events_per_second: Option<u32>,
+    /// Events fetched from storage per page.
+    pub batch_size:        usize,
+    /// Continue after this `(timestamp, id)` cursor, as recorded in
+    /// [`ReplayAuditRecord::last_event_at`] / [`ReplayAuditRecord::last_event_id`].
+    pub resume_after:      Option<(DateTime<Utc>, Uuid)>,
+    /// Operator or service that requested the replay.
+    pub requested_by:      String,
+    /// Why the replay is being run.
+    pub reason:            String,
+}
+
+impl ReplayRequest {
+    /// Replay every stored event of `entity_type`, unthrottled.
+    #[must_use]
+    pub fn new(
+        entity_type: impl Into<String>,
+        requested_by: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            entity_type:       entity_type.into(),
+            start:             None,
+            end:               None,
+            tenant_id:         None,
+            max_events:        None,
+            events_per_second: None,
+            batch_size:        DEFAULT_REPLAY_BATCH_SIZE,
+            resume_after:      None,
+            requested_by:      requested_by.into(),
+            reason:            reason.into(),
+        }
+    }
+
+    /// Only replay events at or after `start`.
+    #[must_use]
+    pub const fn with_start(mut self, start: DateTime<Utc>) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Only replay events at or before `end`.
+    #[must_use]
+    pub const fn with_end(mut self, end: DateTime<Utc>) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Only replay events stamped with `tenant_id`.
+    #[must_use]
+    pub fn with_tenant(mut self, tenant_id: impl Into<String>) -> Self {
+        self.tenant_id = Some(tenant_id.into());
+        self
+    }
+
+    /// Stop after delivering `max_events` events.
+    #[must_use]
+    pub const fn with_max_events(mut self, max_events: usize) -> Self {
+        self.max_events = Some(max_events);
+        self
+    }
+
+    /// Deliver at most `events_per_second` events per second.
+    #[must_use]
+    pub const fn with_rate_limit(mut self, events_per_second: u32) -> Self {
+        self.events_per_second = Some(events_per_second);
+        self
+    }
+
+    /// Fetch `batch_size` events from storage per page.
+    #[must_use]
+    pub const fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Continue a previous replay after its last delivered event.
+    #[must_use]
+    pub const fn with_resume_after(mut self, timestamp: DateTime<Utc>, event_id: Uuid) -> Self {
+        self.resume_after = Some((timestamp, event_id));
+        self
+    }
+
+    /// Validate the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] if the entity type, requester,
+    /// or reason is blank, the window is inverted, a limit is zero, or the batch
+    /// size exceeds [`MAX_REPLAY_BATCH_SIZE`].
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(ObserverError::InvalidConfig { message });
+
+        if self.entity_type.trim().is_empty() {
+            return invalid("replay entity_type must not be empty".to_string());
+        }
+        if self.requested_by.trim().is_empty() {
+            return invalid("replay requested_by must not be empty".to_string());
+        }
+        if self.reason.trim().is_empty() {
+            return invalid("replay reason must not be empty".to_string());
+        }
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if start > end {
+                return invalid(format!("replay window starts ({start}) after it ends ({end})"));
+            }
+        }
+        if self.max_events == Some(0) {
+            return invalid("replay max_events must be at least 1".to_string());
+        }
+        if self.events_per_second == Some(0) {
+            return invalid("replay events_per_second must be at least 1".to_string());
+        }
+        if self.batch_size == 0 || self.batch_size > MAX_REPLAY_BATCH_SIZE {
+            return invalid(format!(
+                "replay batch_size must be between 1 and {MAX_REPLAY_BATCH_SIZE}, got {}",
+                self.batch_size
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Lifecycle state of a replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ReplayStatus {
+    /// Events are being delivered (or the process died mid-replay).
+    Running,
+    /// Every event in the window (or up to `max_events`) was delivered.
+    Completed,
+    /// The replay stopped early; see [`ReplayAuditRecord::error`].
+    Failed,
+}
+
+impl ReplayStatus {
+    /// The status as stored in the audit table.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// Audit trail entry for one replay.
+///
+/// Event counters are cumulative for this run: `events_read` counts every
+/// event fetched from storage, `events_processed` those delivered to the
+/// executor (after the tenant filter), and `events_failed` the delivered events
+/// with at least one failed action or a processing error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayAuditRecord {
+    /// Unique id of this replay run.
+    pub replay_id:         Uuid,
+    /// Replayed entity type.
+    pub entity_type:       String,
+    /// Requested window start, if bounded.
+    pub window_start:      Option<DateTime<Utc>>,
+    /// Requested window end, if bounded.
+    pub window_end:        Option<DateTime<Utc>>,
+    /// Tenant filter, if any.
+    pub tenant_id:         Option<String>,
+    /// Who requested the replay.
+    pub requested_by:      String,
+    /// Why the replay was run.
+    pub reason:            String,
+    /// Current lifecycle state.
+    pub status:            ReplayStatus,
+    /// Events fetched from storage.
+    pub events_read:       usize,
+    /// Events delivered to the executor.
+    pub events_processed:  usize,
+    /// Delivered events with a failed action or processing error.
+    pub events_failed:     usize,
+    /// Actions that ran successfully.
+    pub actions_succeeded: usize,
+    /// Actions that failed after retries.
+    pub actions_failed:    usize,
+    /// Actions skipped by their observer's replay policy.
+    pub actions_skipped:   usize,
+    /// Id of the last delivered event (the resume cursor).
+    pub last_event_id:     Option<Uuid>,
+    /// Timestamp of the last delivered event (the resume cursor).
+    pub last_event_at:     Option<DateTime<Utc>>,
+    /// When the replay started.
+    pub started_at:        DateTime<Utc>,
+    /// When the replay completed or failed.
+    pub finished_at:       Option<DateTime<Utc>>,
+    /// Failure detail for a [`ReplayStatus::Failed`] replay.
+    pub error:             Option<String>,
+}
+
+impl ReplayAuditRecord {
+    /// A fresh [`ReplayStatus::Running`] record for `request`.
+    #[must_use]
+    pub fn start(request: &ReplayRequest) -> Self {
+        Self {
+            replay_id:         Uuid::new_v4(),
+            entity_type:       request.entity_type.clone(),
+            window_start:      request.start,
+            window_end:        request.end,
+            tenant_id:         request.tenant_id.clone(),
+            requested_by:      request.requested_by.clone(),
+            reason:            request.reason.clone(),
+            status:            ReplayStatus::Running,
+            events_read:       0,
+            events_processed:  0,
+            events_failed:     0,
+            actions_succeeded: 0,
+            actions_failed:    0,
+            actions_skipped:   0,
+            last_event_id:     None,
+            last_event_at:     None,
+            started_at:        Utc::now(),
+            finished_at:       None,
+            error:             None,
+        }
+    }
+
+    /// The `(timestamp, id)` cursor of the last delivered event, suitable for
+    /// [`ReplayRequest::with_resume_after`].
+    #[must_use]
+    pub fn resume_cursor(&self) -> Option<(DateTime<Utc>, Uuid)> {
+        self.last_event_at.zip(self.last_event_id)
+    }
+}
+
+/// Durable sink for [`ReplayAuditRecord`]s.
+///
+/// `record` is an upsert keyed by [`ReplayAuditRecord::replay_id`]: the engine
+/// calls it once when the replay starts, after every page, and once more when
+/// it finishes.
+#[async_trait::async_trait]
+pub trait ReplayAuditStore: Send + Sync {
+    /// Insert or replace the record for `record.replay_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be persisted.
+    async fn record(&self, record: &ReplayAuditRecord) -> Result<()>;
+}
+
+/// In-memory replay audit trail, for tests and single-process tooling.
+///
+/// Records do not survive a restart; use
+/// [`PostgresReplayAudit`](crate::replay::PostgresReplayAudit) in production.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryReplayAudit {
+    records: Arc<dashmap::DashMap<Uuid, ReplayAuditRecord>>,
+}
+
+impl InMemoryReplayAudit {
+    /// Create an empty audit trail.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The latest state of replay `replay_id`, if recorded.
+    #[must_use]
+    pub fn get(&self, replay_id: Uuid) -> Option<ReplayAuditRecord> {
+        self.records.get(&replay_id).map(|entry| entry.value().clone())
+    }
+
+    /// Every recorded replay, oldest first.
+    #[must_use]
+    pub fn records(&self) -> Vec<ReplayAuditRecord> {
+        let mut records: Vec<_> = self.records.iter().map(|entry| entry.value().clone()).collect();
+        records.sort_by_key(|record| record.started_at);
+        records
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplayAuditStore for InMemoryReplayAudit {
+    async fn record(&self, record: &ReplayAuditRecord) -> Result<()> {
+        self.records.insert(record.replay_id, record.clone());
+        Ok(())
+    }
+}
+
+/// Re-delivers stored events to an [`ObserverExecutor`].
+///
+/// Generic over the storage backend because [`EventStorage`] is used through
+/// concrete types only.
+pub struct ReplayEngine<S> {
+    storage:  S,
+    executor: Arc<ObserverExecutor>,
+    audit:    Arc<dyn ReplayAuditStore>,
+}
+
+impl<S: EventStorage> ReplayEngine<S> {
+    /// Create an engine that records its audit trail in memory.
+    ///
+    /// Call [`with_audit`](Self::with_audit) to persist the trail.
+    #[must_use]
+    pub fn new(storage: S, executor: Arc<ObserverExecutor>) -> Self {
+        Self {
+            storage,
+            executor,
+            audit: Arc::new(InMemoryReplayAudit::new()),
+        }
+    }
+
+    /// Record the audit trail in `audit`.
+    #[must_use]
+    pub fn with_audit(mut self, audit: Arc<dyn ReplayAuditStore>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Run `request` to completion and return its final audit record.
+    ///
+    /// Action failures do not stop the replay; they are counted in the record
+    /// (and routed to the DLQ by the executor as usual). The replay stops early
+    /// only if storage or the audit store fails.
+    ///
+    /// # Errors
+    ///
+    /// - [`ObserverError::InvalidConfig`] if the request does not
+    ///   [validate](ReplayRequest::validate).
+    /// - The audit store's error if the initial or final record cannot be written; no events are
+    ///   delivered without an initial audit record.
+    /// - The storage or audit error that stopped the replay; the record is then marked
+    ///   [`ReplayStatus::Failed`] on a best-effort basis.
+    pub async fn run(&self, request: &ReplayRequest) -> Result<ReplayAuditRecord> {
+        request.validate()?;
+
+        let mut record = ReplayAuditRecord::start(request);
+        self.audit.record(&record).await?;
+        info!(
+            replay_id = %record.replay_id,
+            entity_type = %request.entity_type,
+            requested_by = %request.requested_by,
+            reason = %request.reason,
+            "Starting event replay"
+        );
+
+        let outcome = self.deliver(request, &mut record).await;
+        record.finished_at = Some(Utc::now());
+
+        match outcome {
+            Ok(()) => {
+                record.status = ReplayStatus::Completed;
+                self.audit.record(&record).await?;
+                info!(
+                    replay_id = %record.replay_id,
+                    events = record.events_processed,
+                    failed = record.events_failed,
+                    actions_skipped = record.actions_skipped,
+                    "Event replay completed"
+                );
+                Ok(record)
+            },
+            Err(e) => {
+                record.status = ReplayStatus::Failed;
+                record.error = Some(e.to_string());
+                if let Err(audit_err) = self.audit.record(&record).await {
+                    warn!(
+                        replay_id = %record.replay_id,
+                        "Failed to record replay failure: {audit_err}"
+                    );
+                }
+                Err(e)
+            },
+        }
+    }
+
+    /// Page through the window, delivering events and updating `record`.
+    async fn deliver(&self, request: &ReplayRequest, record: &mut ReplayAuditRecord) -> Result<()> {
+        let mut throttle = request.events_per_second.map(throttle_interval);
+        let mut cursor = request.resume_after;
+
+        loop {
+            let page = self
+                .storage
+                .query_events_after(
+                    &request.entity_type,
+                    request.start,
+                    request.end,
+                    cursor,
+                    request.batch_size,
+                )
+                .await?;
+            let exhausted = page.len() < request.batch_size;
+
+            for event in &page {
+                record.events_read += 1;
+                cursor = Some((event.timestamp, event.id));
+
+                if let Some(tenant) = &request.tenant_id {
+                    if event.tenant_id.as_deref() != Some(tenant.as_str()) {
+                        continue;
+                    }
+                }
+                if let Some(interval) = throttle.as_mut() {
+                    interval.tick().await;
+                }
+
+                match self.executor.process_replayed_event(event).await {
+                    Ok(summary) => {
+                        record.actions_succeeded += summary.successful_actions;
+                        record.actions_failed += summary.failed_actions;
+                        record.actions_skipped += summary.replay_skipped;
+                        if !summary.is_success() {
+                            record.events_failed += 1;
+                        }
+                    },
+                    Err(e) => {
+                        warn!(
+                            replay_id = %record.replay_id,
+                            event_id = %event.id,
+                            "Replayed event failed: {e}"
+                        );
+                        record.events_failed += 1;
+                    },
+                }
+                record.events_processed += 1;
+                record.last_event_id = Some(event.id);
+                record.last_event_at = Some(event.timestamp);
+
+                if request.max_events == Some(record.events_processed) {
+                    return Ok(());
+                }
+            }
+
+            if exhausted {
+                return Ok(());
+            }
+            self.audit.record(record).await?;
+        }
+    }
+}
+
+/// A ticker that fires at most `events_per_second` times per second.
+fn throttle_interval(events_per_second: u32) -> Interval {
+    let mut interval = tokio::time::interval(Duration::from_secs(1) / events_per_second.max(1));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    interval
+}
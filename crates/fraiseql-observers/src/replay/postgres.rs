@@ -0,0 +1,97 @@
+//! PostgreSQL-backed replay audit trail (`_fraiseql_observer_replay`).
+
+use sqlx::PgPool;
+
+use super::{ReplayAuditRecord, ReplayAuditStore};
+use crate::error::{ObserverError, Result};
+
+/// Replay audit store writing to the `_fraiseql_observer_replay` table
+/// installed by [`observer_replay_audit_sql`](crate::migrations::observer_replay_audit_sql).
+#[derive(Clone)]
+pub struct PostgresReplayAudit {
+    pool: PgPool,
+}
+
+impl PostgresReplayAudit {
+    /// Create an audit store over an existing pool.
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create the `_fraiseql_observer_replay` table (idempotent). Call once on
+    /// startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::DatabaseError`] if the DDL fails.
+    pub async fn init(&self) -> Result<()> {
+        sqlx::raw_sql(crate::migrations::observer_replay_audit_sql())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("replay audit: init: {e}"),
+            })?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplayAuditStore for PostgresReplayAudit {
+    async fn record(&self, record: &ReplayAuditRecord) -> Result<()> {
+        sqlx::query(
+            r"
+            INSERT INTO _fraiseql_observer_replay (
+                replay_id, entity_type, window_start, window_end, tenant_id,
+                requested_by, reason, status,
+                events_read, events_processed, events_failed,
+                actions_succeeded, actions_failed, actions_skipped,
+                last_event_id, last_event_at, started_at, finished_at, error
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            ON CONFLICT (replay_id) DO UPDATE SET
+                status            = EXCLUDED.status,
+                events_read       = EXCLUDED.events_read,
+                events_processed  = EXCLUDED.events_processed,
+                events_failed     = EXCLUDED.events_failed,
+                actions_succeeded = EXCLUDED.actions_succeeded,
+                actions_failed    = EXCLUDED.actions_failed,
+                actions_skipped   = EXCLUDED.actions_skipped,
+                last_event_id     = EXCLUDED.last_event_id,
+                last_event_at     = EXCLUDED.last_event_at,
+                finished_at       = EXCLUDED.finished_at,
+                error             = EXCLUDED.error
+            ",
+        )
+        .bind(record.replay_id)
+        .bind(&record.entity_type)
+        .bind(record.window_start)
+        .bind(record.window_end)
+        .bind(&record.tenant_id)
+        .bind(&record.requested_by)
+        .bind(&record.reason)
+        .bind(record.status.as_str())
+        .bind(count(record.events_read))
+        .bind(count(record.events_processed))
+        .bind(count(record.events_failed))
+        .bind(count(record.actions_succeeded))
+        .bind(count(record.actions_failed))
+        .bind(count(record.actions_skipped))
+        .bind(record.last_event_id)
+        .bind(record.last_event_at)
+        .bind(record.started_at)
+        .bind(record.finished_at)
+        .bind(&record.error)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ObserverError::DatabaseError {
+            reason: format!("replay audit: record {}: {e}", record.replay_id),
+        })?;
+        Ok(())
+    }
+}
+
+/// Saturating `usize` → `BIGINT` conversion for the counter columns.
+fn count(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
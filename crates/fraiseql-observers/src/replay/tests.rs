@@ -0,0 +1,333 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::*;
+use crate::{
+    config::{
+        ActionConfig, DeliveryOrdering, FailurePolicy, ObserverDefinition, ReplayPolicy,
+        RetryConfig,
+    },
+    event::{EntityEvent, EventKind},
+    matcher::EventMatcher,
+    testing::mocks::{MockActionDispatcher, MockDeadLetterQueue},
+};
+
+/// Minimal `EventStorage` over a vector; exercises the default
+/// `query_events_after` implementation.
+struct VecStorage {
+    events: Vec<EntityEvent>,
+}
+
+impl EventStorage for VecStorage {
+    async fn query_events(
+        &self,
+        entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+        limit: Option<usize>,
+    ) -> Result<Vec<EntityEvent>> {
+        let mut events: Vec<_> = self
+            .events
+            .iter()
+            .filter(|e| e.entity_type == entity_type)
+            .filter(|e| start_date.is_none_or(|start| e.timestamp >= start))
+            .filter(|e| end_date.is_none_or(|end| e.timestamp <= end))
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
+        Ok(events)
+    }
+
+    async fn count_events(
+        &self,
+        entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+    ) -> Result<usize> {
+        Ok(self.query_events(entity_type, start_date, end_date, None).await?.len())
+    }
+}
+
+/// Audit store that always fails, to prove no event is delivered unaudited.
+struct FailingAudit;
+
+#[async_trait::async_trait]
+impl ReplayAuditStore for FailingAudit {
+    async fn record(&self, _record: &ReplayAuditRecord) -> Result<()> {
+        Err(ObserverError::DatabaseError {
+            reason: "audit unavailable".to_string(),
+        })
+    }
+}
+
+fn base_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+}
+
+fn order_event(minute: i64) -> EntityEvent {
+    let mut event =
+        EntityEvent::new(EventKind::Created, "Order".to_string(), Uuid::new_v4(), json!({"id": 1}));
+    event.timestamp = base_time() + Duration::minutes(minute);
+    event
+}
+
+fn orders(count: i64) -> Vec<EntityEvent> {
+    (0..count).map(order_event).collect()
+}
+
+fn webhook_action() -> ActionConfig {
+    ActionConfig::Webhook {
+        url:                Some("https://example.com/hook".to_string()),
+        url_env:            None,
+        method:             None,
+        headers:            HashMap::new(),
+        body_template:      None,
+        signing_secret:     None,
+        signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
+    }
+}
+
+fn email_action() -> ActionConfig {
+    ActionConfig::Email {
+        to:               Some("customer@example.com".to_string()),
+        to_template:      None,
+        subject:          Some("Your order".to_string()),
+        subject_template: None,
+        body_template:    Some("Thanks!".to_string()),
+        reply_to:         None,
+    }
+}
+
+fn engine_with(
+    events: Vec<EntityEvent>,
+    actions: Vec<ActionConfig>,
+    replay: ReplayPolicy,
+) -> (ReplayEngine<VecStorage>, Arc<MockActionDispatcher>) {
+    let observer = ObserverDefinition {
+        event_type: "INSERT".to_string(),
+        entity: "Order".to_string(),
+        condition: None,
+        actions,
+        retry: RetryConfig {
+            max_attempts: 1,
+            initial_delay_ms: 0,
+            ..RetryConfig::default()
+        },
+        on_failure: FailurePolicy::Log,
+        replay,
+        ordering: DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup: None,
+        dry_run: false,
+    };
+    let matcher = EventMatcher::build(HashMap::from([("obs".to_string(), observer)])).unwrap();
+    let dispatcher = Arc::new(MockActionDispatcher::new());
+    dispatcher.expect_ok("webhook", 1.0);
+    dispatcher.expect_ok("email", 1.0);
+    let executor = ObserverExecutor::with_dispatcher(
+        matcher,
+        Arc::new(MockDeadLetterQueue::new()),
+        Arc::clone(&dispatcher) as _,
+    );
+    (ReplayEngine::new(VecStorage { events }, Arc::new(executor)), dispatcher)
+}
+
+fn request() -> ReplayRequest {
+    ReplayRequest::new("Order", "ops@example.com", "backfill search index")
+}
+
+#[test]
+fn auto_policy_skips_user_facing_notifications() {
+    assert!(ReplayPolicy::Auto.runs(&webhook_action()));
+    assert!(!ReplayPolicy::Auto.runs(&email_action()));
+    assert!(ReplayPolicy::Always.runs(&email_action()));
+    assert!(!ReplayPolicy::Never.runs(&webhook_action()));
+}
+
+#[test]
+fn replay_policy_defaults_to_auto_when_absent() {
+    let observer: ObserverDefinition = serde_json::from_value(json!({
+        "event_type": "INSERT",
+        "entity": "Order",
+        "actions": [],
+    }))
+    .unwrap();
+    assert_eq!(observer.replay, ReplayPolicy::Auto);
+
+    let observer: ObserverDefinition = serde_json::from_value(json!({
+        "event_type": "INSERT",
+        "entity": "Order",
+        "actions": [],
+        "replay": "never",
+    }))
+    .unwrap();
+    assert_eq!(observer.replay, ReplayPolicy::Never);
+}
+
+#[test]
+fn request_validation_rejects_bad_input() {
+    assert!(request().validate().is_ok());
+    assert!(ReplayRequest::new("Order", "ops", " ").validate().is_err());
+    assert!(ReplayRequest::new("Order", "", "reason").validate().is_err());
+    assert!(
+        request()
+            .with_start(base_time())
+            .with_end(base_time() - Duration::days(1))
+            .validate()
+            .is_err()
+    );
+    assert!(request().with_rate_limit(0).validate().is_err());
+    assert!(request().with_max_events(0).validate().is_err());
+    assert!(request().with_batch_size(0).validate().is_err());
+    assert!(request().with_batch_size(MAX_REPLAY_BATCH_SIZE + 1).validate().is_err());
+}
+
+#[tokio::test]
+async fn default_query_events_after_pages_oldest_first_through_timestamp_ties() {
+    let mut events = orders(2);
+    // Three events sharing one timestamp straddle a page boundary.
+    events.extend((0..3).map(|_| order_event(5)));
+    let storage = VecStorage { events };
+
+    let mut cursor = None;
+    let mut seen = Vec::new();
+    loop {
+        let page = storage.query_events_after("Order", None, None, cursor, 2).await.unwrap();
+        if page.is_empty() {
+            break;
+        }
+        let last = page.last().unwrap();
+        cursor = Some((last.timestamp, last.id));
+        seen.extend(page.into_iter().map(|e| (e.timestamp, e.id)));
+    }
+
+    assert_eq!(seen.len(), 5);
+    assert!(
+        seen.windows(2).all(|pair| pair[0] < pair[1]),
+        "pages must be strictly ascending"
+    );
+}
+
+#[tokio::test]
+async fn replays_every_event_across_pages_and_audits_completion() {
+    let (engine, dispatcher) = engine_with(orders(5), vec![webhook_action()], ReplayPolicy::Auto);
+    let audit = Arc::new(InMemoryReplayAudit::new());
+    let engine = engine.with_audit(Arc::clone(&audit) as _);
+
+    let record = engine.run(&request().with_batch_size(2)).await.unwrap();
+
+    assert_eq!(dispatcher.call_count(), 5);
+    assert_eq!(record.status, ReplayStatus::Completed);
+    assert_eq!(record.events_read, 5);
+    assert_eq!(record.events_processed, 5);
+    assert_eq!(record.actions_succeeded, 5);
+    assert_eq!(record.last_event_at, Some(base_time() + Duration::minutes(4)));
+    assert!(record.finished_at.is_some());
+    assert_eq!(audit.get(record.replay_id), Some(record));
+}
+
+#[tokio::test]
+async fn replay_skips_actions_excluded_by_policy() {
+    let (engine, dispatcher) =
+        engine_with(orders(2), vec![webhook_action(), email_action()], ReplayPolicy::Auto);
+
+    let record = engine.run(&request()).await.unwrap();
+
+    assert_eq!(dispatcher.calls(), vec!["webhook", "webhook"]);
+    assert_eq!(record.actions_succeeded, 2);
+    assert_eq!(record.actions_skipped, 2);
+}
+
+#[tokio::test]
+async fn never_policy_runs_no_actions() {
+    let (engine, dispatcher) = engine_with(orders(3), vec![webhook_action()], ReplayPolicy::Never);
+
+    let record = engine.run(&request()).await.unwrap();
+
+    assert_eq!(dispatcher.call_count(), 0);
+    assert_eq!(record.events_processed, 3);
+    assert_eq!(record.actions_skipped, 3);
+}
+
+#[tokio::test]
+async fn live_processing_ignores_replay_policy() {
+    let (engine, dispatcher) = engine_with(Vec::new(), vec![email_action()], ReplayPolicy::Never);
+
+    let summary = engine.executor.process_event(&order_event(0)).await.unwrap();
+
+    assert_eq!(dispatcher.call_count(), 1);
+    assert_eq!(summary.replay_skipped, 0);
+}
+
+#[tokio::test]
+async fn window_tenant_and_max_events_narrow_the_replay() {
+    let mut events = orders(10);
+    for event in events.iter_mut().step_by(2) {
+        event.tenant_id = Some("tenant-a".to_string());
+    }
+    let (engine, dispatcher) = engine_with(events, vec![webhook_action()], ReplayPolicy::Auto);
+
+    // Minutes 2..=8 hold tenant-a events at 2, 4, 6, 8; stop after three.
+    let record = engine
+        .run(
+            &request()
+                .with_start(base_time() + Duration::minutes(2))
+                .with_end(base_time() + Duration::minutes(8))
+                .with_tenant("tenant-a")
+                .with_max_events(3),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(dispatcher.call_count(), 3);
+    assert_eq!(record.events_processed, 3);
+    assert_eq!(record.events_read, 5);
+    assert_eq!(record.tenant_id.as_deref(), Some("tenant-a"));
+    assert_eq!(record.last_event_at, Some(base_time() + Duration::minutes(6)));
+}
+
+#[tokio::test]
+async fn resume_after_continues_from_the_recorded_cursor() {
+    let (engine, dispatcher) = engine_with(orders(6), vec![webhook_action()], ReplayPolicy::Auto);
+
+    let first = engine.run(&request().with_max_events(4)).await.unwrap();
+    let (at, id) = first.resume_cursor().unwrap();
+    let second = engine.run(&request().with_resume_after(at, id)).await.unwrap();
+
+    assert_eq!(second.events_processed, 2);
+    assert_eq!(dispatcher.call_count(), 6);
+    assert_eq!(second.last_event_at, Some(base_time() + Duration::minutes(5)));
+}
+
+#[tokio::test]
+async fn no_event_is_delivered_without_an_audit_record() {
+    let (engine, dispatcher) = engine_with(orders(3), vec![webhook_action()], ReplayPolicy::Auto);
+    let engine = engine.with_audit(Arc::new(FailingAudit));
+
+    let err = engine.run(&request()).await.unwrap_err();
+
+    assert!(matches!(err, ObserverError::DatabaseError { .. }));
+    assert_eq!(dispatcher.call_count(), 0);
+}
+
+#[tokio::test]
+async fn invalid_request_is_rejected_before_auditing() {
+    let (engine, _dispatcher) = engine_with(orders(1), vec![webhook_action()], ReplayPolicy::Auto);
+    let audit = Arc::new(InMemoryReplayAudit::new());
+    let engine = engine.with_audit(Arc::clone(&audit) as _);
+
+    let err = engine.run(&request().with_batch_size(0)).await.unwrap_err();
+
+    assert!(matches!(err, ObserverError::InvalidConfig { .. }));
+    assert!(audit.records().is_empty());
+}
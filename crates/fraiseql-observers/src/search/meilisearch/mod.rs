@@ -0,0 +1,368 @@
+//! Meilisearch search backend.
+//!
+//! All events live in a single index keyed by [`IndexedEvent::document_id`].
+//! On first use the backend creates the index and pushes its settings
+//! (searchable, filterable and sortable attributes plus typo tolerance),
+//! waiting for Meilisearch to finish applying them before any document is
+//! written. Document batches use the native `POST /indexes/{uid}/documents`
+//! endpoint and are applied asynchronously by Meilisearch.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use reqwest::{Client, Method, RequestBuilder};
+use serde_json::{Value, json};
+use tokio::sync::OnceCell;
+
+use super::{
+    BULK_CHUNK_SIZE, IndexedEvent, SearchBackend, SearchDocument, TypoTolerance, read_json,
+    validate_index_name,
+};
+use crate::{
+    error::{ObserverError, Result},
+    ssrf::validate_outbound_url,
+};
+
+/// Engine name used in error messages.
+const ENGINE: &str = "Meilisearch";
+
+/// Default timeout for all Meilisearch HTTP requests.
+const MEILI_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait for an index-creation or settings task to complete.
+const MEILI_TASK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delay between task status polls.
+const MEILI_TASK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Default index UID.
+pub const DEFAULT_MEILISEARCH_INDEX: &str = "fraiseql_events";
+
+/// Meilisearch search backend.
+///
+/// Supports full-text search with typo tolerance, tenant/entity/time filters,
+/// batched indexing and filter-based retention deletes.
+#[derive(Clone)]
+pub struct MeilisearchBackend {
+    client:         Client,
+    base_url:       String,
+    index_uid:      String,
+    api_key:        Option<String>,
+    typo_tolerance: TypoTolerance,
+    index_ready:    Arc<OnceCell<()>>,
+}
+
+impl MeilisearchBackend {
+    /// Create a new Meilisearch backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Meilisearch base URL (e.g., `https://search.example.com`)
+    /// * `index_uid` - Index to store events in (see [`DEFAULT_MEILISEARCH_INDEX`])
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if the URL targets a private/loopback
+    /// address (SSRF protection), if the index UID is not 1–255 characters of
+    /// `[A-Za-z0-9_-]`, or if the HTTP client cannot be built.
+    pub fn new(url: String, index_uid: impl Into<String>) -> Result<Self> {
+        validate_outbound_url(&url)?;
+        let index_uid = index_uid.into();
+        validate_index_name(ENGINE, &index_uid)?;
+        let client = Client::builder().timeout(MEILI_REQUEST_TIMEOUT).build().map_err(|e| {
+            ObserverError::InvalidConfig {
+                message: format!("Failed to build HTTP client: {e}"),
+            }
+        })?;
+        Ok(Self::with_client(client, url, index_uid))
+    }
+
+    /// Create a backend without SSRF validation — for use in tests only.
+    #[cfg(test)]
+    fn new_unchecked(url: String) -> Self {
+        let client = Client::builder().timeout(MEILI_REQUEST_TIMEOUT).build().unwrap_or_default();
+        Self::with_client(client, url, DEFAULT_MEILISEARCH_INDEX.to_string())
+    }
+
+    fn with_client(client: Client, url: String, index_uid: String) -> Self {
+        Self {
+            client,
+            base_url: url.trim_end_matches('/').to_string(),
+            index_uid,
+            api_key: None,
+            typo_tolerance: TypoTolerance::default(),
+            index_ready: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Authenticate with an API key (sent as a bearer token).
+    #[must_use]
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the typo-tolerance settings pushed to the index.
+    #[must_use]
+    pub fn with_typo_tolerance(mut self, typo_tolerance: TypoTolerance) -> Self {
+        self.typo_tolerance = typo_tolerance;
+        self
+    }
+
+    /// Check if Meilisearch is reachable and healthy.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Meilisearch is not responding
+    pub async fn health_check(&self) -> Result<bool> {
+        let response = self.request(Method::GET, "/health").send().await.map_err(|e| {
+            ObserverError::DatabaseError {
+                reason: format!("Meilisearch connection failed: {e}"),
+            }
+        })?;
+        Ok(response.status().is_success())
+    }
+
+    /// Create the index and apply its settings, once per backend.
+    ///
+    /// Called lazily by every [`SearchBackend`] method; call it on startup to
+    /// surface configuration errors early. Waits for Meilisearch to finish
+    /// applying the settings so that filters and sorts work immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if the typo-tolerance settings are
+    /// invalid, or `ObserverError::DatabaseError` if Meilisearch rejects the
+    /// index or its settings, or does not apply them within 60 seconds.
+    pub async fn ensure_index(&self) -> Result<()> {
+        self.index_ready.get_or_try_init(|| self.configure_index()).await?;
+        Ok(())
+    }
+
+    /// Index settings derived from the [`IndexedEvent`] schema.
+    fn index_settings(&self) -> Result<Value> {
+        self.typo_tolerance.validate()?;
+        Ok(json!({
+            "searchableAttributes": IndexedEvent::SEARCHABLE_FIELDS,
+            "filterableAttributes": IndexedEvent::FILTERABLE_FIELDS,
+            "sortableAttributes": IndexedEvent::SORTABLE_FIELDS,
+            "typoTolerance": {
+                "enabled": self.typo_tolerance.enabled,
+                "minWordSizeForTypos": {
+                    "oneTypo": self.typo_tolerance.min_word_size_for_one_typo,
+                    "twoTypos": self.typo_tolerance.min_word_size_for_two_typos
+                },
+                "disableOnAttributes": self.typo_tolerance.disable_on_fields
+            }
+        }))
+    }
+
+    async fn configure_index(&self) -> Result<()> {
+        let settings = self.index_settings()?;
+
+        let create = json!({ "uid": self.index_uid, "primaryKey": "id" });
+        let response =
+            self.request(Method::POST, "/indexes").json(&create).send().await.map_err(|e| {
+                ObserverError::DatabaseError {
+                    reason: format!("Failed to create Meilisearch index: {e}"),
+                }
+            })?;
+        let task = read_json(response, ENGINE, "index creation").await?;
+        let outcome = self.wait_for_task(&task).await?;
+        if outcome["status"] != "succeeded" && outcome["error"]["code"] != "index_already_exists" {
+            return Err(task_error(&outcome, "index creation"));
+        }
+
+        let path = format!("/indexes/{}/settings", self.index_uid);
+        let response =
+            self.request(Method::PATCH, &path).json(&settings).send().await.map_err(|e| {
+                ObserverError::DatabaseError {
+                    reason: format!("Failed to update Meilisearch index settings: {e}"),
+                }
+            })?;
+        let task = read_json(response, ENGINE, "settings update").await?;
+        let outcome = self.wait_for_task(&task).await?;
+        if outcome["status"] != "succeeded" {
+            return Err(task_error(&outcome, "settings update"));
+        }
+        Ok(())
+    }
+
+    /// Poll an enqueued task until it reaches a terminal status.
+    async fn wait_for_task(&self, task: &Value) -> Result<Value> {
+        let task_uid = task["taskUid"].as_u64().ok_or_else(|| ObserverError::DatabaseError {
+            reason: format!("Meilisearch response has no taskUid: {task}"),
+        })?;
+        let path = format!("/tasks/{task_uid}");
+        let started = Instant::now();
+        loop {
+            let response = self.request(Method::GET, &path).send().await.map_err(|e| {
+                ObserverError::DatabaseError {
+                    reason: format!("Failed to poll Meilisearch task {task_uid}: {e}"),
+                }
+            })?;
+            let status = read_json(response, ENGINE, "task status").await?;
+            if matches!(status["status"].as_str(), Some("succeeded" | "failed" | "canceled")) {
+                return Ok(status);
+            }
+            if started.elapsed() >= MEILI_TASK_TIMEOUT {
+                return Err(ObserverError::DatabaseError {
+                    reason: format!(
+                        "Meilisearch task {task_uid} did not complete within {}s",
+                        MEILI_TASK_TIMEOUT.as_secs()
+                    ),
+                });
+            }
+            tokio::time::sleep(MEILI_TASK_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn search_documents(&self, query: Value, what: &str) -> Result<Vec<IndexedEvent>> {
+        self.ensure_index().await?;
+        let path = format!("/indexes/{}/search", self.index_uid);
+        let response =
+            self.request(Method::POST, &path).json(&query).send().await.map_err(|e| {
+                ObserverError::DatabaseError {
+                    reason: format!("Meilisearch {what} failed: {e}"),
+                }
+            })?;
+        let body = read_json(response, ENGINE, what).await?;
+
+        let mut results = Vec::new();
+        if let Some(hits) = body["hits"].as_array() {
+            for hit in hits {
+                if let Ok(event) = serde_json::from_value(hit.clone()) {
+                    results.push(event);
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let builder = self.client.request(method, format!("{}{path}", self.base_url));
+        match &self.api_key {
+            Some(key) => builder.bearer_auth(key),
+            None => builder,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchBackend for MeilisearchBackend {
+    async fn index_event(&self, event: &IndexedEvent) -> Result<()> {
+        self.index_batch(std::slice::from_ref(event)).await
+    }
+
+    async fn index_batch(&self, events: &[IndexedEvent]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.ensure_index().await?;
+
+        let path = format!("/indexes/{}/documents?primaryKey=id", self.index_uid);
+        for chunk in events.chunks(BULK_CHUNK_SIZE) {
+            let documents: Vec<SearchDocument<'_>> =
+                chunk.iter().map(SearchDocument::new).collect();
+            let response =
+                self.request(Method::POST, &path).json(&documents).send().await.map_err(|e| {
+                    ObserverError::DatabaseError {
+                        reason: format!("Failed to index events in Meilisearch: {e}"),
+                    }
+                })?;
+            read_json(response, ENGINE, "document batch").await?;
+        }
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        tenant_id: &str,
+        limit: usize,
+    ) -> Result<Vec<IndexedEvent>> {
+        let query = json!({
+            "q": query,
+            "filter": format!("tenant_id = {}", filter_value(tenant_id)),
+            "limit": limit
+        });
+        self.search_documents(query, "search").await
+    }
+
+    async fn search_entity(
+        &self,
+        entity_type: &str,
+        entity_id: &str,
+        tenant_id: &str,
+    ) -> Result<Vec<IndexedEvent>> {
+        let query = json!({
+            "q": "",
+            "filter": format!(
+                "entity_type = {} AND entity_id = {} AND tenant_id = {}",
+                filter_value(entity_type),
+                filter_value(entity_id),
+                filter_value(tenant_id)
+            ),
+            "sort": ["timestamp:desc"],
+            "limit": 100
+        });
+        self.search_documents(query, "entity search").await
+    }
+
+    async fn search_time_range(
+        &self,
+        start_timestamp: i64,
+        end_timestamp: i64,
+        tenant_id: &str,
+        limit: usize,
+    ) -> Result<Vec<IndexedEvent>> {
+        let query = json!({
+            "q": "",
+            "filter": format!(
+                "timestamp {start_timestamp} TO {end_timestamp} AND tenant_id = {}",
+                filter_value(tenant_id)
+            ),
+            "sort": ["timestamp:desc"],
+            "limit": limit
+        });
+        self.search_documents(query, "time range search").await
+    }
+
+    async fn delete_old_events(&self, days_old: u32) -> Result<()> {
+        self.ensure_index().await?;
+        let cutoff_date = chrono::Utc::now() - chrono::Duration::days(i64::from(days_old));
+        let cutoff_timestamp = cutoff_date.timestamp();
+
+        let path = format!("/indexes/{}/documents/delete", self.index_uid);
+        let response = self
+            .request(Method::POST, &path)
+            .json(&json!({ "filter": format!("timestamp <= {cutoff_timestamp}") }))
+            .send()
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("Failed to delete old events from Meilisearch: {e}"),
+            })?;
+        read_json(response, ENGINE, "delete").await?;
+        Ok(())
+    }
+}
+
+/// Quote a value for a Meilisearch filter expression.
+fn filter_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn task_error(task: &Value, what: &str) -> ObserverError {
+    ObserverError::DatabaseError {
+        reason: format!(
+            "Meilisearch {what} task {}: {}",
+            task["status"].as_str().unwrap_or("unknown"),
+            task["error"]["message"].as_str().unwrap_or("no error message")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,292 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, body_partial_json, header, method, path, query_param},
+};
+
+use super::*;
+
+fn indexed(entity_id: &str, timestamp: i64) -> IndexedEvent {
+    IndexedEvent {
+        event_type: "Created".to_string(),
+        entity_type: "Order".to_string(),
+        entity_id: entity_id.to_string(),
+        tenant_id: "tenant-1".to_string(),
+        timestamp,
+        actions_executed: vec!["webhook".to_string()],
+        success_count: 1,
+        failure_count: 0,
+        event_data: r#"{"total":100}"#.to_string(),
+        search_text: "Created Order".to_string(),
+    }
+}
+
+fn enqueued(task_uid: u64) -> ResponseTemplate {
+    ResponseTemplate::new(202).set_body_json(json!({ "taskUid": task_uid, "status": "enqueued" }))
+}
+
+/// Mount index creation, settings and task endpoints so `ensure_index` succeeds.
+async fn mount_ready_index(mock: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/indexes"))
+        .respond_with(enqueued(1))
+        .mount(mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "status": "failed",
+            "error": { "code": "index_already_exists", "message": "exists" }
+        })))
+        .mount(mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/indexes/fraiseql_events/settings"))
+        .respond_with(enqueued(2))
+        .mount(mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "uid": 2, "status": "succeeded" })),
+        )
+        .mount(mock)
+        .await;
+}
+
+#[test]
+fn new_rejects_loopback_url() {
+    let result = MeilisearchBackend::new("http://localhost:7700".to_string(), "events");
+    assert!(result.is_err(), "loopback must be rejected");
+}
+
+#[test]
+fn new_rejects_index_uid_outside_url_safe_charset() {
+    let result = MeilisearchBackend::new("https://meili.example.com".to_string(), "events/../keys");
+    assert!(matches!(result, Err(ObserverError::InvalidConfig { .. })));
+}
+
+#[test]
+fn settings_are_derived_from_the_indexed_event_schema() {
+    let backend = MeilisearchBackend::new_unchecked("http://meili:7700".to_string())
+        .with_typo_tolerance(TypoTolerance {
+            min_word_size_for_one_typo: 4,
+            disable_on_fields: vec!["event_data".to_string()],
+            ..TypoTolerance::default()
+        });
+
+    let settings = backend.index_settings().unwrap();
+
+    assert_eq!(settings["searchableAttributes"], json!(IndexedEvent::SEARCHABLE_FIELDS));
+    assert_eq!(settings["filterableAttributes"], json!(IndexedEvent::FILTERABLE_FIELDS));
+    assert_eq!(settings["sortableAttributes"], json!(["timestamp"]));
+    assert_eq!(
+        settings["typoTolerance"],
+        json!({
+            "enabled": true,
+            "minWordSizeForTypos": { "oneTypo": 4, "twoTypos": 9 },
+            "disableOnAttributes": ["event_data"]
+        })
+    );
+}
+
+#[test]
+fn invalid_typo_tolerance_is_rejected() {
+    let backend = MeilisearchBackend::new_unchecked("http://meili:7700".to_string())
+        .with_typo_tolerance(TypoTolerance {
+            disable_on_fields: vec!["tenant_id".to_string()],
+            ..TypoTolerance::default()
+        });
+    assert!(matches!(backend.index_settings(), Err(ObserverError::InvalidConfig { .. })));
+}
+
+#[test]
+fn filter_values_are_quoted_and_escaped() {
+    assert_eq!(filter_value("tenant-1"), r#""tenant-1""#);
+    assert_eq!(filter_value(r#"a" OR tenant_id = "b"#), r#""a\" OR tenant_id = \"b""#);
+    assert_eq!(filter_value(r"a\"), r#""a\\""#);
+}
+
+#[tokio::test]
+async fn ensure_index_tolerates_existing_index_and_runs_once() {
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/indexes"))
+        .and(body_json(json!({ "uid": "fraiseql_events", "primaryKey": "id" })))
+        .respond_with(enqueued(1))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "failed",
+            "error": { "code": "index_already_exists", "message": "exists" }
+        })))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/indexes/fraiseql_events/settings"))
+        .and(body_partial_json(json!({ "sortableAttributes": ["timestamp"] })))
+        .respond_with(enqueued(2))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "succeeded" })))
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri());
+    backend.ensure_index().await.unwrap();
+    backend.clone().ensure_index().await.unwrap();
+}
+
+#[tokio::test]
+async fn failed_settings_task_is_reported() {
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/indexes"))
+        .respond_with(enqueued(1))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "succeeded" })))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/indexes/fraiseql_events/settings"))
+        .respond_with(enqueued(2))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "failed",
+            "error": { "code": "invalid_settings", "message": "bad attribute" }
+        })))
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri());
+    let err = backend.ensure_index().await.unwrap_err();
+    assert!(err.to_string().contains("bad attribute"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn index_batch_sends_documents_with_ids_and_api_key() {
+    let mock = MockServer::start().await;
+    mount_ready_index(&mock).await;
+    Mock::given(method("POST"))
+        .and(path("/indexes/fraiseql_events/documents"))
+        .and(query_param("primaryKey", "id"))
+        .and(header("Authorization", "Bearer master-key"))
+        .and(body_partial_json(json!([
+            { "id": "order-1-Created-10", "entity_id": "order-1" },
+            { "id": "order-2-Created-20", "entity_id": "order-2" }
+        ])))
+        .respond_with(enqueued(3))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri()).with_api_key("master-key");
+    backend
+        .index_batch(&[indexed("order-1", 10), indexed("order-2", 20)])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn index_batch_empty_is_noop() {
+    let backend = MeilisearchBackend::new_unchecked("http://localhost:19999".to_string());
+    assert!(backend.index_batch(&[]).await.is_ok());
+}
+
+#[tokio::test]
+async fn search_filters_by_tenant_and_parses_hits() {
+    let mock = MockServer::start().await;
+    mount_ready_index(&mock).await;
+    Mock::given(method("POST"))
+        .and(path("/indexes/fraiseql_events/search"))
+        .and(body_json(json!({
+            "q": "order",
+            "filter": "tenant_id = \"tenant-1\"",
+            "limit": 10
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "hits": [
+                serde_json::to_value(SearchDocument::new(&indexed("order-1", 10))).unwrap()
+            ]
+        })))
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri());
+    let results = backend.search("order", "tenant-1", 10).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].entity_id, "order-1");
+}
+
+#[tokio::test]
+async fn time_range_search_uses_inclusive_range_and_sort() {
+    let mock = MockServer::start().await;
+    mount_ready_index(&mock).await;
+    Mock::given(method("POST"))
+        .and(path("/indexes/fraiseql_events/search"))
+        .and(body_partial_json(json!({
+            "filter": "timestamp 100 TO 200 AND tenant_id = \"tenant-1\"",
+            "sort": ["timestamp:desc"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "hits": [] })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri());
+    let results = backend.search_time_range(100, 200, "tenant-1", 5).await.unwrap();
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn search_error_status_is_surfaced() {
+    let mock = MockServer::start().await;
+    mount_ready_index(&mock).await;
+    Mock::given(method("POST"))
+        .and(path("/indexes/fraiseql_events/search"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "message": "Attribute `tenant_id` is not filterable",
+            "code": "invalid_search_filter"
+        })))
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri());
+    let err = backend.search("order", "tenant-1", 10).await.unwrap_err();
+    assert!(err.to_string().contains("not filterable"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn delete_old_events_uses_timestamp_filter() {
+    let mock = MockServer::start().await;
+    mount_ready_index(&mock).await;
+    Mock::given(method("POST"))
+        .and(path("/indexes/fraiseql_events/documents/delete"))
+        .respond_with(enqueued(4))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = MeilisearchBackend::new_unchecked(mock.uri());
+    backend.delete_old_events(30).await.unwrap();
+
+    let requests = mock.received_requests().await.unwrap();
+    let delete = requests.iter().find(|r| r.url.path().ends_with("/documents/delete")).unwrap();
+    let body: Value = serde_json::from_slice(&delete.body).unwrap();
+    assert!(body["filter"].as_str().unwrap().starts_with("timestamp <= "));
+}
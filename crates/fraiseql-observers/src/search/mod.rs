@@ -50,12 +50,39 @@
 //! - `events-2026-01-22`: All events from January 22, 2026
 //! - `events-2026-01-21`: All events from January 21, 2026
 //! - Enables: purging old indices, time-range queries, retention policies
+//!
+//! # Backends
+//!
+//! - [`http::HttpSearchBackend`]: Elasticsearch over its REST API
+//! - [`meilisearch::MeilisearchBackend`]: Meilisearch, single index, native document batches
+//! - [`typesense::TypesenseBackend`]: Typesense, single collection, JSONL import
+//!
+//! Meilisearch and Typesense keep every event in one index (filtered by
+//! `timestamp`) instead of daily indices. Their searchable, filterable and
+//! sortable attributes are derived from [`IndexedEvent::SEARCHABLE_FIELDS`],
+//! [`IndexedEvent::FILTERABLE_FIELDS`] and [`IndexedEvent::SORTABLE_FIELDS`].
 
 pub mod http;
+pub mod meilisearch;
+pub mod reindex;
+pub mod typesense;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{error::Result, event::EntityEvent};
+use crate::{
+    error::{ObserverError, Result},
+    event::EntityEvent,
+};
+
+/// Maximum byte size for a Meilisearch or Typesense response body.
+///
+/// Mirrors the Elasticsearch cap: result sizes are bounded by the request's
+/// limit, but the HTTP layer is not.
+const MAX_SEARCH_RESPONSE_BYTES: usize = 50 * 1024 * 1024; // 50 MiB
+
+/// Number of documents sent per bulk request by the Meilisearch and Typesense
+/// backends.
+const BULK_CHUNK_SIZE: usize = 1_000;
 
 /// Indexed event representation for search systems.
 ///
@@ -85,6 +112,20 @@ pub struct IndexedEvent {
 }
 
 impl IndexedEvent {
+    /// Fields usable in exact-match and range filters.
+    pub const FILTERABLE_FIELDS: &'static [&'static str] = &[
+        "event_type",
+        "entity_type",
+        "entity_id",
+        "tenant_id",
+        "timestamp",
+    ];
+    /// Fields matched by full-text queries, in ranking order.
+    pub const SEARCHABLE_FIELDS: &'static [&'static str] =
+        &["search_text", "event_data", "actions_executed"];
+    /// Fields results can be sorted by.
+    pub const SORTABLE_FIELDS: &'static [&'static str] = &["timestamp"];
+
     /// Create an indexed event from an entity event.
     ///
     /// # Arguments
@@ -126,6 +167,37 @@ impl IndexedEvent {
         }
     }
 
+    /// Create an indexed event from an entity's current state rather than from a
+    /// change event, as written by a [full reindex](reindex).
+    ///
+    /// The event type is [`SNAPSHOT_EVENT_TYPE`](reindex::SNAPSHOT_EVENT_TYPE)
+    /// and no actions are recorded.
+    #[must_use]
+    pub fn snapshot(
+        entity_type: &str,
+        entity_id: String,
+        tenant_id: String,
+        data: &serde_json::Value,
+        timestamp: i64,
+    ) -> Self {
+        let event_data = data.to_string();
+        let search_text =
+            format!("{} {entity_type} {entity_id} {event_data}", reindex::SNAPSHOT_EVENT_TYPE);
+
+        Self {
+            event_type: reindex::SNAPSHOT_EVENT_TYPE.to_string(),
+            entity_type: entity_type.to_string(),
+            entity_id,
+            tenant_id,
+            timestamp,
+            actions_executed: Vec::new(),
+            success_count: 0,
+            failure_count: 0,
+            event_data,
+            search_text,
+        }
+    }
+
     /// Get the index name for this event (date-based sharding).
     ///
     /// Returns format: `events-YYYY-MM-DD`
@@ -135,6 +207,175 @@ impl IndexedEvent {
             .unwrap_or_else(chrono::Utc::now);
         format!("events-{}", datetime.format("%Y-%m-%d"))
     }
+
+    /// Stable document identifier for engines that require a primary key.
+    ///
+    /// Built from the entity ID, event type and timestamp, restricted to
+    /// `[A-Za-z0-9_-]` so it is valid as both a Meilisearch and a Typesense ID.
+    /// Re-indexing the same event overwrites the existing document.
+    #[must_use]
+    pub fn document_id(&self) -> String {
+        format!("{}-{}-{}", self.entity_id, self.event_type, self.timestamp)
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
+
+/// An [`IndexedEvent`] together with its [`document_id`](IndexedEvent::document_id),
+/// as sent to engines that need an explicit `id` field.
+#[derive(Serialize)]
+struct SearchDocument<'a> {
+    id:    String,
+    #[serde(flatten)]
+    event: &'a IndexedEvent,
+}
+
+impl<'a> SearchDocument<'a> {
+    fn new(event: &'a IndexedEvent) -> Self {
+        Self {
+            id: event.document_id(),
+            event,
+        }
+    }
+}
+
+/// Typo-tolerance settings shared by the Meilisearch and Typesense backends.
+///
+/// Meilisearch stores these as index settings; Typesense applies them per
+/// query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TypoTolerance {
+    /// Whether typo-tolerant matching is enabled at all (default: true).
+    pub enabled:                     bool,
+    /// Minimum word length before one typo is tolerated (default: 5).
+    pub min_word_size_for_one_typo:  u8,
+    /// Minimum word length before two typos are tolerated (default: 9).
+    pub min_word_size_for_two_typos: u8,
+    /// Searchable fields that must match exactly (e.g. `event_data`, where
+    /// identifiers and amounts should not fuzzy-match).
+    pub disable_on_fields:           Vec<String>,
+}
+
+impl Default for TypoTolerance {
+    fn default() -> Self {
+        Self {
+            enabled:                     true,
+            min_word_size_for_one_typo:  5,
+            min_word_size_for_two_typos: 9,
+            disable_on_fields:           Vec::new(),
+        }
+    }
+}
+
+impl TypoTolerance {
+    /// Disable typo tolerance entirely.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Validate the word-size thresholds and field names.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] if the one-typo threshold
+    /// exceeds the two-typo threshold, or if a field in `disable_on_fields` is
+    /// not one of [`IndexedEvent::SEARCHABLE_FIELDS`].
+    pub fn validate(&self) -> Result<()> {
+        if self.min_word_size_for_one_typo > self.min_word_size_for_two_typos {
+            return Err(ObserverError::InvalidConfig {
+                message: format!(
+                    "typo_tolerance.min_word_size_for_one_typo ({}) must not exceed \
+                     min_word_size_for_two_typos ({})",
+                    self.min_word_size_for_one_typo, self.min_word_size_for_two_typos
+                ),
+            });
+        }
+        if let Some(field) = self
+            .disable_on_fields
+            .iter()
+            .find(|f| !IndexedEvent::SEARCHABLE_FIELDS.contains(&f.as_str()))
+        {
+            return Err(ObserverError::InvalidConfig {
+                message: format!(
+                    "typo_tolerance.disable_on_fields: '{field}' is not a searchable field \
+                     (expected one of {:?})",
+                    IndexedEvent::SEARCHABLE_FIELDS
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether typos are tolerated on `field`.
+    fn applies_to(&self, field: &str) -> bool {
+        self.enabled && !self.disable_on_fields.iter().any(|f| f == field)
+    }
+}
+
+/// Read a search-engine response body, rejecting error statuses and bodies
+/// over [`MAX_SEARCH_RESPONSE_BYTES`].
+async fn read_body(response: reqwest::Response, engine: &str, what: &str) -> Result<String> {
+    let status = response.status();
+    let bytes = response.bytes().await.map_err(|e| ObserverError::DatabaseError {
+        reason: format!("Failed to read {engine} {what} response: {e}"),
+    })?;
+    if bytes.len() > MAX_SEARCH_RESPONSE_BYTES {
+        return Err(ObserverError::DatabaseError {
+            reason: format!(
+                "{engine} {what} response too large ({} bytes, max {MAX_SEARCH_RESPONSE_BYTES})",
+                bytes.len()
+            ),
+        });
+    }
+    let body = String::from_utf8_lossy(&bytes).into_owned();
+    if !status.is_success() {
+        let detail: String = body.chars().take(512).collect();
+        return Err(ObserverError::DatabaseError {
+            reason: format!("{engine} rejected {what} ({status}): {detail}"),
+        });
+    }
+    Ok(body)
+}
+
+/// Check that an index or collection name is 1–255 characters of
+/// `[A-Za-z0-9_-]`, so it can be interpolated into a URL path as-is.
+fn validate_index_name(engine: &str, name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 255
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(ObserverError::InvalidConfig {
+            message: format!(
+                "{engine} index name '{name}' must be 1-255 characters of [A-Za-z0-9_-]"
+            ),
+        })
+    }
+}
+
+/// [`read_body`], parsed as JSON.
+async fn read_json(
+    response: reqwest::Response,
+    engine: &str,
+    what: &str,
+) -> Result<serde_json::Value> {
+    let body = read_body(response, engine, what).await?;
+    serde_json::from_str(&body).map_err(|e| ObserverError::DatabaseError {
+        reason: format!("Failed to parse {engine} {what} response: {e}"),
+    })
 }
 
 /// Search backend abstraction for event indexing and querying.
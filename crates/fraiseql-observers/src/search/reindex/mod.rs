@@ -0,0 +1,257 @@
+//! Full reindex of an entity's current state into a [`SearchBackend`].
+//!
+//! Live indexing only sees entities that change after the search backend was
+//! wired up. A reindex bootstraps (or rebuilds) the index from the entity's
+//! backing view instead: rows are read from a [`ReindexSource`] in id order,
+//! converted with [`IndexedEvent::snapshot`] and written with
+//! [`SearchBackend::index_batch`].
+//!
+//! # Semantics
+//!
+//! - **Snapshot time**: every document of one reindex carries the same timestamp, fixed when the
+//!   run starts and kept in the [`ReindexCursor`]. Re-sending a batch after an interruption
+//!   therefore overwrites the same documents instead of adding duplicates.
+//! - **Resume**: the cursor is handed to a callback after every indexed batch. Persist it and pass
+//!   it back with [`ReindexRequest::with_resume`] to continue after the last indexed row.
+//! - **Throttle**: [`ReindexRequest::with_rate_limit`] caps documents per second, applied between
+//!   batches.
+
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+use tracing::info;
+
+use super::{IndexedEvent, SearchBackend};
+use crate::error::{ObserverError, Result};
+
+/// Event type recorded on documents written by a reindex.
+pub const SNAPSHOT_EVENT_TYPE: &str = "Snapshot";
+
+/// Default number of rows read from the source per batch.
+pub const DEFAULT_REINDEX_BATCH_SIZE: usize = 500;
+
+/// Upper bound on [`ReindexRequest::batch_size`].
+pub const MAX_REINDEX_BATCH_SIZE: usize = 10_000;
+
+/// One row of an entity's backing view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReindexRow {
+    /// Entity id, rendered as text.
+    pub id:        String,
+    /// Tenant the row belongs to, if the view has a tenant column.
+    pub tenant_id: Option<String>,
+    /// The entity's JSON representation.
+    pub data:      serde_json::Value,
+}
+
+/// Where a reindex reads entity rows from.
+#[async_trait::async_trait]
+pub trait ReindexSource: Send + Sync {
+    /// Fetch up to `limit` rows whose id sorts strictly after `after` (all rows
+    /// when `None`), in ascending id order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be read.
+    async fn fetch_after(&self, after: Option<&str>, limit: usize) -> Result<Vec<ReindexRow>>;
+}
+
+/// Progress of a reindex, sufficient to resume it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReindexCursor {
+    /// Entity type being reindexed.
+    pub entity_type: String,
+    /// Timestamp stamped on every document of this reindex (Unix seconds).
+    pub snapshot_at: i64,
+    /// Id of the last row indexed; `None` before the first batch.
+    pub last_id:     Option<String>,
+    /// Rows indexed so far, across resumed runs.
+    pub indexed:     usize,
+}
+
+impl ReindexCursor {
+    /// A cursor at the start of a new reindex of `entity_type`, snapshotted now.
+    #[must_use]
+    pub fn start(entity_type: impl Into<String>) -> Self {
+        Self {
+            entity_type: entity_type.into(),
+            snapshot_at: chrono::Utc::now().timestamp(),
+            last_id:     None,
+            indexed:     0,
+        }
+    }
+}
+
+/// What to reindex and how fast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexRequest {
+    /// Entity type recorded on the indexed documents (e.g. `"Order"`).
+    pub entity_type:     String,
+    /// Tenant recorded on rows the source returns without one.
+    pub default_tenant:  String,
+    /// Rows read and indexed per batch.
+    pub batch_size:      usize,
+    /// Maximum documents indexed per second; `None` = unthrottled.
+    pub docs_per_second: Option<u32>,
+    /// Continue a previous reindex from this cursor.
+    pub resume:          Option<ReindexCursor>,
+}
+
+impl ReindexRequest {
+    /// Reindex every row of `entity_type`, unthrottled.
+    #[must_use]
+    pub fn new(entity_type: impl Into<String>, default_tenant: impl Into<String>) -> Self {
+        Self {
+            entity_type:     entity_type.into(),
+            default_tenant:  default_tenant.into(),
+            batch_size:      DEFAULT_REINDEX_BATCH_SIZE,
+            docs_per_second: None,
+            resume:          None,
+        }
+    }
+
+    /// Read and index `batch_size` rows per batch.
+    #[must_use]
+    pub const fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Index at most `docs_per_second` documents per second.
+    #[must_use]
+    pub const fn with_rate_limit(mut self, docs_per_second: u32) -> Self {
+        self.docs_per_second = Some(docs_per_second);
+        self
+    }
+
+    /// Continue a previous reindex after its last indexed row.
+    #[must_use]
+    pub fn with_resume(mut self, cursor: ReindexCursor) -> Self {
+        self.resume = Some(cursor);
+        self
+    }
+
+    /// Validate the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] if the entity type or default
+    /// tenant is blank, the batch size is zero or exceeds
+    /// [`MAX_REINDEX_BATCH_SIZE`], the rate limit is zero, or the resume cursor
+    /// belongs to another entity type.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(ObserverError::InvalidConfig { message });
+
+        if self.entity_type.trim().is_empty() {
+            return invalid("reindex entity_type must not be empty".to_string());
+        }
+        if self.default_tenant.trim().is_empty() {
+            return invalid("reindex default_tenant must not be empty".to_string());
+        }
+        if self.batch_size == 0 || self.batch_size > MAX_REINDEX_BATCH_SIZE {
+            return invalid(format!(
+                "reindex batch_size must be between 1 and {MAX_REINDEX_BATCH_SIZE}"
+            ));
+        }
+        if self.docs_per_second == Some(0) {
+            return invalid(
+                "reindex rate limit must be at least 1 document per second".to_string(),
+            );
+        }
+        if let Some(cursor) = &self.resume {
+            if cursor.entity_type != self.entity_type {
+                return invalid(format!(
+                    "reindex resume cursor is for '{}', not '{}'",
+                    cursor.entity_type, self.entity_type
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads an entity's rows from a [`ReindexSource`] and bulk-indexes them.
+pub struct Reindexer<S, B> {
+    source:  S,
+    backend: B,
+}
+
+impl<S: ReindexSource, B: SearchBackend> Reindexer<S, B> {
+    /// Create a reindexer from `source` into `backend`.
+    #[must_use]
+    pub const fn new(source: S, backend: B) -> Self {
+        Self { source, backend }
+    }
+
+    /// Run the reindex to completion.
+    ///
+    /// `on_batch` is called with the updated cursor after every batch the
+    /// backend accepted; an error from it stops the run. Returns the final
+    /// cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObserverError::InvalidConfig`] for an invalid request, or the
+    /// first error from the source, the backend, or `on_batch`. Rows of a batch
+    /// the backend rejected are not counted, so resuming from the last cursor
+    /// reported to `on_batch` re-sends them.
+    pub async fn run<F>(&self, request: &ReindexRequest, mut on_batch: F) -> Result<ReindexCursor>
+    where
+        F: FnMut(&ReindexCursor) -> Result<()>,
+    {
+        request.validate()?;
+        let mut cursor = request
+            .resume
+            .clone()
+            .unwrap_or_else(|| ReindexCursor::start(&request.entity_type));
+        let mut next_batch_at = Instant::now();
+
+        loop {
+            let rows =
+                self.source.fetch_after(cursor.last_id.as_deref(), request.batch_size).await?;
+            let Some(last_id) = rows.last().map(|row| row.id.clone()) else {
+                break;
+            };
+            let fetched = rows.len();
+            let events: Vec<IndexedEvent> = rows
+                .into_iter()
+                .map(|row| {
+                    IndexedEvent::snapshot(
+                        &request.entity_type,
+                        row.id,
+                        row.tenant_id.unwrap_or_else(|| request.default_tenant.clone()),
+                        &row.data,
+                        cursor.snapshot_at,
+                    )
+                })
+                .collect();
+
+            if let Some(docs_per_second) = request.docs_per_second {
+                tokio::time::sleep_until(next_batch_at).await;
+                let batch_len = u32::try_from(fetched).unwrap_or(u32::MAX);
+                next_batch_at =
+                    Instant::now() + Duration::from_secs(1) * batch_len / docs_per_second;
+            }
+            self.backend.index_batch(&events).await?;
+
+            cursor.indexed += fetched;
+            cursor.last_id = Some(last_id);
+            on_batch(&cursor)?;
+
+            if fetched < request.batch_size {
+                break;
+            }
+        }
+
+        info!(
+            entity_type = %cursor.entity_type,
+            indexed = cursor.indexed,
+            "search reindex complete"
+        );
+        Ok(cursor)
+    }
+}
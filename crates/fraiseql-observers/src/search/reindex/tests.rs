@@ -0,0 +1,245 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::json;
+
+use super::*;
+use crate::search::SearchBackend;
+
+/// Rows held in id order; `fetch_after` pages them like a keyset query.
+struct VecSource {
+    rows: Vec<ReindexRow>,
+}
+
+impl VecSource {
+    fn with_ids(ids: &[&str]) -> Self {
+        Self {
+            rows: ids
+                .iter()
+                .map(|id| ReindexRow {
+                    id:        (*id).to_string(),
+                    tenant_id: None,
+                    data:      json!({ "id": id, "status": "paid" }),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReindexSource for VecSource {
+    async fn fetch_after(&self, after: Option<&str>, limit: usize) -> Result<Vec<ReindexRow>> {
+        Ok(self
+            .rows
+            .iter()
+            .filter(|row| after.is_none_or(|after| row.id.as_str() > after))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Backend recording each `index_batch` call; optionally fails on one call.
+#[derive(Clone, Default)]
+struct RecordingBackend {
+    batches: Arc<Mutex<Vec<Vec<IndexedEvent>>>>,
+    fail_on: Option<usize>,
+}
+
+impl RecordingBackend {
+    fn batches(&self) -> Vec<Vec<IndexedEvent>> {
+        self.batches.lock().unwrap().clone()
+    }
+
+    fn ids(&self) -> Vec<String> {
+        self.batches().into_iter().flatten().map(|e| e.entity_id).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchBackend for RecordingBackend {
+    async fn index_event(&self, event: &IndexedEvent) -> Result<()> {
+        self.index_batch(std::slice::from_ref(event)).await
+    }
+
+    async fn index_batch(&self, events: &[IndexedEvent]) -> Result<()> {
+        let mut batches = self.batches.lock().unwrap();
+        if self.fail_on == Some(batches.len()) {
+            return Err(ObserverError::DatabaseError {
+                reason: "search backend unavailable".to_string(),
+            });
+        }
+        batches.push(events.to_vec());
+        Ok(())
+    }
+
+    async fn search(&self, _: &str, _: &str, _: usize) -> Result<Vec<IndexedEvent>> {
+        Ok(Vec::new())
+    }
+
+    async fn search_entity(&self, _: &str, _: &str, _: &str) -> Result<Vec<IndexedEvent>> {
+        Ok(Vec::new())
+    }
+
+    async fn search_time_range(
+        &self,
+        _: i64,
+        _: i64,
+        _: &str,
+        _: usize,
+    ) -> Result<Vec<IndexedEvent>> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_old_events(&self, _: u32) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn request() -> ReindexRequest {
+    ReindexRequest::new("Order", "default")
+}
+
+#[test]
+fn request_validation_rejects_bad_input() {
+    assert!(request().validate().is_ok());
+    assert!(ReindexRequest::new(" ", "default").validate().is_err());
+    assert!(ReindexRequest::new("Order", "").validate().is_err());
+    assert!(request().with_batch_size(0).validate().is_err());
+    assert!(request().with_batch_size(MAX_REINDEX_BATCH_SIZE + 1).validate().is_err());
+    assert!(request().with_rate_limit(0).validate().is_err());
+    assert!(request().with_resume(ReindexCursor::start("User")).validate().is_err());
+}
+
+#[tokio::test]
+async fn indexes_every_row_in_batches_and_reports_each_cursor() {
+    let backend = RecordingBackend::default();
+    let reindexer =
+        Reindexer::new(VecSource::with_ids(&["a", "b", "c", "d", "e"]), backend.clone());
+    let mut reported = Vec::new();
+
+    let cursor = reindexer
+        .run(&request().with_batch_size(2), |c| {
+            reported.push((c.last_id.clone().unwrap(), c.indexed));
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(backend.batches().len(), 3);
+    assert_eq!(backend.ids(), ["a", "b", "c", "d", "e"]);
+    assert_eq!(
+        reported,
+        [
+            ("b".to_string(), 2),
+            ("d".to_string(), 4),
+            ("e".to_string(), 5)
+        ]
+    );
+    assert_eq!(cursor.indexed, 5);
+    assert_eq!(cursor.last_id.as_deref(), Some("e"));
+}
+
+#[tokio::test]
+async fn documents_are_snapshots_sharing_one_timestamp() {
+    let backend = RecordingBackend::default();
+    let mut source = VecSource::with_ids(&["a", "b"]);
+    source.rows[1].tenant_id = Some("tenant-b".to_string());
+    let reindexer = Reindexer::new(source, backend.clone());
+
+    let cursor = reindexer.run(&request(), |_| Ok(())).await.unwrap();
+
+    let events: Vec<IndexedEvent> = backend.batches().into_iter().flatten().collect();
+    assert!(events.iter().all(|e| e.event_type == SNAPSHOT_EVENT_TYPE));
+    assert!(events.iter().all(|e| e.entity_type == "Order"));
+    assert!(events.iter().all(|e| e.timestamp == cursor.snapshot_at));
+    assert_eq!(events[0].tenant_id, "default");
+    assert_eq!(events[1].tenant_id, "tenant-b");
+    assert!(events[0].search_text.contains("paid"));
+}
+
+#[tokio::test]
+async fn resume_continues_after_the_cursor_with_the_same_snapshot_time() {
+    let backend = RecordingBackend::default();
+    let reindexer = Reindexer::new(VecSource::with_ids(&["a", "b", "c"]), backend.clone());
+    let previous = ReindexCursor {
+        entity_type: "Order".to_string(),
+        snapshot_at: 1_700_000_000,
+        last_id:     Some("a".to_string()),
+        indexed:     1,
+    };
+
+    let cursor = reindexer.run(&request().with_resume(previous), |_| Ok(())).await.unwrap();
+
+    assert_eq!(backend.ids(), ["b", "c"]);
+    assert_eq!(cursor.indexed, 3);
+    assert!(backend.batches()[0].iter().all(|e| e.timestamp == 1_700_000_000));
+}
+
+#[tokio::test]
+async fn rejected_batch_does_not_advance_the_cursor() {
+    let backend = RecordingBackend {
+        fail_on: Some(1),
+        ..RecordingBackend::default()
+    };
+    let reindexer = Reindexer::new(VecSource::with_ids(&["a", "b", "c", "d"]), backend.clone());
+    let mut last_reported = None;
+
+    let err = reindexer
+        .run(&request().with_batch_size(2), |c| {
+            last_reported = Some(c.clone());
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ObserverError::DatabaseError { .. }));
+    let last_reported = last_reported.unwrap();
+    assert_eq!(last_reported.last_id.as_deref(), Some("b"));
+    assert_eq!(last_reported.indexed, 2);
+}
+
+#[tokio::test]
+async fn callback_error_stops_the_run() {
+    let backend = RecordingBackend::default();
+    let reindexer = Reindexer::new(VecSource::with_ids(&["a", "b", "c"]), backend.clone());
+
+    let result = reindexer
+        .run(&request().with_batch_size(1), |_| {
+            Err(ObserverError::DatabaseError {
+                reason: "cannot persist cursor".to_string(),
+            })
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(backend.batches().len(), 1);
+}
+
+#[tokio::test]
+async fn empty_source_indexes_nothing() {
+    let backend = RecordingBackend::default();
+    let reindexer = Reindexer::new(VecSource::with_ids(&[]), backend.clone());
+
+    let cursor = reindexer.run(&request(), |_| Ok(())).await.unwrap();
+
+    assert!(backend.batches().is_empty());
+    assert_eq!(cursor.indexed, 0);
+    assert_eq!(cursor.last_id, None);
+}
+
+#[tokio::test]
+async fn rate_limit_spaces_batches() {
+    let backend = RecordingBackend::default();
+    let reindexer = Reindexer::new(VecSource::with_ids(&["a", "b", "c"]), backend);
+    let started = std::time::Instant::now();
+
+    // One document per batch at 20 docs/s: two 50 ms waits between three batches.
+    reindexer
+        .run(&request().with_batch_size(1).with_rate_limit(20), |_| Ok(()))
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() >= Duration::from_millis(100));
+}
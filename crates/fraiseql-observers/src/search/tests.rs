@@ -96,4 +96,52 @@ mod search_tests {
         assert_eq!(stats.failed_indexes, 0);
         assert_eq!(stats.success_rate(), 0.0);
     }
+
+    #[test]
+    fn test_document_id_is_stable_and_url_safe() {
+        let event = EntityEvent::new(
+            crate::event::EventKind::Updated,
+            "Order".to_string(),
+            Uuid::nil(),
+            serde_json::json!({}),
+        );
+        let mut indexed = IndexedEvent::from_event(&event, "t".to_string(), vec![], 0, 0);
+        indexed.entity_id = "order 1/a".to_string();
+        indexed.timestamp = -5;
+
+        assert_eq!(indexed.document_id(), indexed.clone().document_id());
+        assert_eq!(indexed.document_id(), "order_1_a-Updated--5");
+    }
+
+    #[test]
+    fn test_typo_tolerance_validation() {
+        assert!(TypoTolerance::default().validate().is_ok());
+        assert!(
+            TypoTolerance {
+                min_word_size_for_one_typo: 10,
+                ..TypoTolerance::default()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            TypoTolerance {
+                disable_on_fields: vec!["tenant_id".to_string()],
+                ..TypoTolerance::default()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_typo_tolerance_deserializes_with_defaults() {
+        let typo: TypoTolerance =
+            serde_json::from_value(serde_json::json!({ "disable_on_fields": ["event_data"] }))
+                .unwrap();
+        assert!(typo.enabled);
+        assert_eq!(typo.min_word_size_for_one_typo, 5);
+        assert!(!typo.applies_to("event_data"));
+        assert!(typo.applies_to("search_text"));
+    }
 }
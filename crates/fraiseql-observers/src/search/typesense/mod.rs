@@ -0,0 +1,430 @@
+//! Typesense search backend.
+//!
+//! All events live in a single collection keyed by [`IndexedEvent::document_id`].
+//! On first use the backend creates the collection from a schema derived from
+//! [`IndexedEvent`]: searchable and filterable fields are indexed, filterable
+//! fields are faceted and `timestamp` is the default sorting field. Batches use
+//! the native JSONL `documents/import` endpoint with `action=upsert`.
+//!
+//! Typesense configures typo tolerance per query rather than per collection,
+//! so [`TypoTolerance`] is translated into `num_typos` / `min_len_*typo`
+//! parameters on every search.
+
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use serde_json::{Value, json};
+use tokio::sync::OnceCell;
+
+use super::{
+    BULK_CHUNK_SIZE, IndexedEvent, SearchBackend, SearchDocument, TypoTolerance, read_body,
+    read_json, validate_index_name,
+};
+use crate::{
+    error::{ObserverError, Result},
+    ssrf::validate_outbound_url,
+};
+
+/// Engine name used in error messages.
+const ENGINE: &str = "Typesense";
+
+/// Default timeout for all Typesense HTTP requests.
+const TYPESENSE_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Typesense caps `per_page` at 250; larger limits are clamped.
+const TYPESENSE_MAX_PER_PAGE: usize = 250;
+
+/// Default collection name.
+pub const DEFAULT_TYPESENSE_COLLECTION: &str = "fraiseql_events";
+
+/// Typesense search backend.
+///
+/// Supports full-text search with typo tolerance, tenant/entity/time filters,
+/// JSONL bulk upserts and filter-based retention deletes.
+#[derive(Clone)]
+pub struct TypesenseBackend {
+    client:           Client,
+    base_url:         String,
+    collection:       String,
+    api_key:          String,
+    typo_tolerance:   TypoTolerance,
+    collection_ready: Arc<OnceCell<()>>,
+}
+
+impl TypesenseBackend {
+    /// Create a new Typesense backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Typesense base URL (e.g., `https://search.example.com:8108`)
+    /// * `collection` - Collection to store events in (see [`DEFAULT_TYPESENSE_COLLECTION`])
+    /// * `api_key` - API key sent as `X-TYPESENSE-API-KEY`
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if the URL targets a private/loopback
+    /// address (SSRF protection), if the collection name is not 1–255 characters
+    /// of `[A-Za-z0-9_-]`, or if the HTTP client cannot be built.
+    pub fn new(
+        url: String,
+        collection: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Result<Self> {
+        validate_outbound_url(&url)?;
+        let collection = collection.into();
+        validate_index_name(ENGINE, &collection)?;
+        let client = Client::builder().timeout(TYPESENSE_REQUEST_TIMEOUT).build().map_err(|e| {
+            ObserverError::InvalidConfig {
+                message: format!("Failed to build HTTP client: {e}"),
+            }
+        })?;
+        Ok(Self::with_client(client, url, collection, api_key.into()))
+    }
+
+    /// Create a backend without SSRF validation — for use in tests only.
+    #[cfg(test)]
+    fn new_unchecked(url: String) -> Self {
+        let client =
+            Client::builder().timeout(TYPESENSE_REQUEST_TIMEOUT).build().unwrap_or_default();
+        Self::with_client(
+            client,
+            url,
+            DEFAULT_TYPESENSE_COLLECTION.to_string(),
+            "test-key".to_string(),
+        )
+    }
+
+    fn with_client(client: Client, url: String, collection: String, api_key: String) -> Self {
+        Self {
+            client,
+            base_url: url.trim_end_matches('/').to_string(),
+            collection,
+            api_key,
+            typo_tolerance: TypoTolerance::default(),
+            collection_ready: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Set the typo-tolerance parameters applied to full-text searches.
+    #[must_use]
+    pub fn with_typo_tolerance(mut self, typo_tolerance: TypoTolerance) -> Self {
+        self.typo_tolerance = typo_tolerance;
+        self
+    }
+
+    /// Check if Typesense is reachable and healthy.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Typesense is not responding
+    pub async fn health_check(&self) -> Result<bool> {
+        let response = self.request(Method::GET, "/health").send().await.map_err(|e| {
+            ObserverError::DatabaseError {
+                reason: format!("Typesense connection failed: {e}"),
+            }
+        })?;
+        Ok(response.status().is_success())
+    }
+
+    /// Create the collection if it does not exist, once per backend.
+    ///
+    /// Called lazily by every [`SearchBackend`] method; call it on startup to
+    /// surface configuration errors early. An existing collection is left
+    /// untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::InvalidConfig` if the typo-tolerance settings are
+    /// invalid, or `ObserverError::DatabaseError` if Typesense rejects the
+    /// collection schema.
+    pub async fn ensure_collection(&self) -> Result<()> {
+        self.collection_ready.get_or_try_init(|| self.create_collection()).await?;
+        Ok(())
+    }
+
+    /// Collection schema derived from the [`IndexedEvent`] schema.
+    ///
+    /// Only searchable, filterable and sortable fields are declared; Typesense
+    /// stores the remaining fields without indexing them.
+    fn collection_schema(&self) -> Value {
+        let mut fields: Vec<&str> = IndexedEvent::SEARCHABLE_FIELDS.to_vec();
+        for field in IndexedEvent::FILTERABLE_FIELDS.iter().chain(IndexedEvent::SORTABLE_FIELDS) {
+            if !fields.contains(field) {
+                fields.push(*field);
+            }
+        }
+        let fields: Vec<Value> = fields
+            .into_iter()
+            .map(|name| {
+                json!({
+                    "name": name,
+                    "type": field_type(name),
+                    "facet": IndexedEvent::FILTERABLE_FIELDS.contains(&name),
+                    "sort": IndexedEvent::SORTABLE_FIELDS.contains(&name)
+                })
+            })
+            .collect();
+        json!({
+            "name": self.collection,
+            "fields": fields,
+            "default_sorting_field": "timestamp"
+        })
+    }
+
+    async fn create_collection(&self) -> Result<()> {
+        self.typo_tolerance.validate()?;
+        let response = self
+            .request(Method::POST, "/collections")
+            .json(&self.collection_schema())
+            .send()
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("Failed to create Typesense collection: {e}"),
+            })?;
+        if response.status() == StatusCode::CONFLICT {
+            return Ok(());
+        }
+        read_json(response, ENGINE, "collection creation").await?;
+        Ok(())
+    }
+
+    /// Typo-tolerance query parameters for a full-text search over
+    /// [`IndexedEvent::SEARCHABLE_FIELDS`].
+    fn typo_params(&self) -> Vec<(&'static str, String)> {
+        let num_typos = IndexedEvent::SEARCHABLE_FIELDS
+            .iter()
+            .map(|field| {
+                if self.typo_tolerance.applies_to(field) {
+                    "2"
+                } else {
+                    "0"
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        vec![
+            ("num_typos", num_typos),
+            ("min_len_1typo", self.typo_tolerance.min_word_size_for_one_typo.to_string()),
+            ("min_len_2typo", self.typo_tolerance.min_word_size_for_two_typos.to_string()),
+        ]
+    }
+
+    async fn search_documents(
+        &self,
+        mut params: Vec<(&'static str, String)>,
+        what: &str,
+    ) -> Result<Vec<IndexedEvent>> {
+        self.ensure_collection().await?;
+        params.push(("query_by", IndexedEvent::SEARCHABLE_FIELDS.join(",")));
+        let path = format!("/collections/{}/documents/search", self.collection);
+        let response =
+            self.request(Method::GET, &path).query(&params).send().await.map_err(|e| {
+                ObserverError::DatabaseError {
+                    reason: format!("Typesense {what} failed: {e}"),
+                }
+            })?;
+        let body = read_json(response, ENGINE, what).await?;
+
+        let mut results = Vec::new();
+        if let Some(hits) = body["hits"].as_array() {
+            for hit in hits {
+                if let Ok(event) = serde_json::from_value(hit["document"].clone()) {
+                    results.push(event);
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{}{path}", self.base_url))
+            .header("X-TYPESENSE-API-KEY", &self.api_key)
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchBackend for TypesenseBackend {
+    async fn index_event(&self, event: &IndexedEvent) -> Result<()> {
+        self.ensure_collection().await?;
+        let path = format!("/collections/{}/documents", self.collection);
+        let response = self
+            .request(Method::POST, &path)
+            .query(&[("action", "upsert")])
+            .json(&SearchDocument::new(event))
+            .send()
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("Failed to index event in Typesense: {e}"),
+            })?;
+        read_json(response, ENGINE, "document upsert").await?;
+        Ok(())
+    }
+
+    async fn index_batch(&self, events: &[IndexedEvent]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.ensure_collection().await?;
+
+        let path = format!("/collections/{}/documents/import", self.collection);
+        for chunk in events.chunks(BULK_CHUNK_SIZE) {
+            let mut body = String::new();
+            for event in chunk {
+                let line = serde_json::to_string(&SearchDocument::new(event)).map_err(|e| {
+                    ObserverError::SerializationError(format!(
+                        "Failed to serialize event for Typesense: {e}"
+                    ))
+                })?;
+                body.push_str(&line);
+                body.push('\n');
+            }
+
+            let response = self
+                .request(Method::POST, &path)
+                .query(&[("action", "upsert")])
+                .header("Content-Type", "text/plain")
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| ObserverError::DatabaseError {
+                    reason: format!("Failed to import events into Typesense: {e}"),
+                })?;
+            let results = read_body(response, ENGINE, "document import").await?;
+            check_import_results(&results)?;
+        }
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        tenant_id: &str,
+        limit: usize,
+    ) -> Result<Vec<IndexedEvent>> {
+        let mut params = self.typo_params();
+        params.extend([
+            ("q", query.to_string()),
+            ("filter_by", format!("tenant_id:={}", filter_value(tenant_id)?)),
+            ("per_page", per_page(limit)),
+        ]);
+        self.search_documents(params, "search").await
+    }
+
+    async fn search_entity(
+        &self,
+        entity_type: &str,
+        entity_id: &str,
+        tenant_id: &str,
+    ) -> Result<Vec<IndexedEvent>> {
+        let params = vec![
+            ("q", "*".to_string()),
+            (
+                "filter_by",
+                format!(
+                    "entity_type:={} && entity_id:={} && tenant_id:={}",
+                    filter_value(entity_type)?,
+                    filter_value(entity_id)?,
+                    filter_value(tenant_id)?
+                ),
+            ),
+            ("sort_by", "timestamp:desc".to_string()),
+            ("per_page", per_page(100)),
+        ];
+        self.search_documents(params, "entity search").await
+    }
+
+    async fn search_time_range(
+        &self,
+        start_timestamp: i64,
+        end_timestamp: i64,
+        tenant_id: &str,
+        limit: usize,
+    ) -> Result<Vec<IndexedEvent>> {
+        let params = vec![
+            ("q", "*".to_string()),
+            (
+                "filter_by",
+                format!(
+                    "timestamp:[{start_timestamp}..{end_timestamp}] && tenant_id:={}",
+                    filter_value(tenant_id)?
+                ),
+            ),
+            ("sort_by", "timestamp:desc".to_string()),
+            ("per_page", per_page(limit)),
+        ];
+        self.search_documents(params, "time range search").await
+    }
+
+    async fn delete_old_events(&self, days_old: u32) -> Result<()> {
+        self.ensure_collection().await?;
+        let cutoff_date = chrono::Utc::now() - chrono::Duration::days(i64::from(days_old));
+        let cutoff_timestamp = cutoff_date.timestamp();
+
+        let path = format!("/collections/{}/documents", self.collection);
+        let response = self
+            .request(Method::DELETE, &path)
+            .query(&[("filter_by", format!("timestamp:<={cutoff_timestamp}"))])
+            .send()
+            .await
+            .map_err(|e| ObserverError::DatabaseError {
+                reason: format!("Failed to delete old events from Typesense: {e}"),
+            })?;
+        read_json(response, ENGINE, "delete").await?;
+        Ok(())
+    }
+}
+
+/// Typesense field type for an [`IndexedEvent`] field.
+fn field_type(field: &str) -> &'static str {
+    match field {
+        "timestamp" => "int64",
+        "success_count" | "failure_count" => "int32",
+        "actions_executed" => "string[]",
+        _ => "string",
+    }
+}
+
+/// Quote a value for a Typesense `filter_by` expression.
+///
+/// Typesense quotes with backticks and has no escape for a backtick inside the
+/// value, so such values are rejected rather than passed through.
+fn filter_value(value: &str) -> Result<String> {
+    if value.contains('`') {
+        return Err(ObserverError::DatabaseError {
+            reason: format!("Typesense filter value must not contain a backtick: {value:?}"),
+        });
+    }
+    Ok(format!("`{value}`"))
+}
+
+fn per_page(limit: usize) -> String {
+    limit.min(TYPESENSE_MAX_PER_PAGE).to_string()
+}
+
+/// Fail if any line of a JSONL import response reports `"success": false`.
+fn check_import_results(results: &str) -> Result<()> {
+    let mut failed = 0_usize;
+    let mut first_error = None;
+    for line in results.lines().filter(|l| !l.trim().is_empty()) {
+        let result: Value =
+            serde_json::from_str(line).map_err(|e| ObserverError::DatabaseError {
+                reason: format!("Failed to parse Typesense import result: {e}"),
+            })?;
+        if result["success"] != true {
+            failed += 1;
+            if first_error.is_none() {
+                first_error = Some(result["error"].as_str().unwrap_or("unknown").to_string());
+            }
+        }
+    }
+    match first_error {
+        Some(error) => Err(ObserverError::DatabaseError {
+            reason: format!("Typesense rejected {failed} document(s) in import: {error}"),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests;
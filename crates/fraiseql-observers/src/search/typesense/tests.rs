@@ -0,0 +1,267 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, body_string, header, method, path, query_param},
+};
+
+use super::*;
+
+fn indexed(entity_id: &str, timestamp: i64) -> IndexedEvent {
+    IndexedEvent {
+        event_type: "Created".to_string(),
+        entity_type: "Order".to_string(),
+        entity_id: entity_id.to_string(),
+        tenant_id: "tenant-1".to_string(),
+        timestamp,
+        actions_executed: vec!["webhook".to_string()],
+        success_count: 1,
+        failure_count: 0,
+        event_data: r#"{"total":100}"#.to_string(),
+        search_text: "Created Order".to_string(),
+    }
+}
+
+/// Mount a collection-creation endpoint reporting that the collection exists.
+async fn mount_existing_collection(mock: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+            "message": "A collection with name `fraiseql_events` already exists."
+        })))
+        .mount(mock)
+        .await;
+}
+
+#[test]
+fn new_rejects_loopback_url() {
+    let result = TypesenseBackend::new("http://127.0.0.1:8108".to_string(), "events", "key");
+    assert!(result.is_err(), "loopback must be rejected");
+}
+
+#[test]
+fn new_rejects_collection_outside_url_safe_charset() {
+    let result = TypesenseBackend::new("https://typesense.example.com".to_string(), "a/b", "key");
+    assert!(matches!(result, Err(ObserverError::InvalidConfig { .. })));
+}
+
+#[test]
+fn schema_is_derived_from_the_indexed_event_schema() {
+    let backend = TypesenseBackend::new_unchecked("http://typesense:8108".to_string());
+
+    let schema = backend.collection_schema();
+
+    assert_eq!(schema["name"], "fraiseql_events");
+    assert_eq!(schema["default_sorting_field"], "timestamp");
+    let fields = schema["fields"].as_array().unwrap();
+    let names: Vec<&str> = fields.iter().map(|f| f["name"].as_str().unwrap()).collect();
+    assert_eq!(
+        names,
+        [
+            "search_text",
+            "event_data",
+            "actions_executed",
+            "event_type",
+            "entity_type",
+            "entity_id",
+            "tenant_id",
+            "timestamp"
+        ]
+    );
+    let field = |name: &str| fields.iter().find(|f| f["name"] == name).unwrap();
+    assert_eq!(field("tenant_id")["facet"], true);
+    assert_eq!(field("search_text")["facet"], false);
+    assert_eq!(field("actions_executed")["type"], "string[]");
+    assert_eq!(field("timestamp")["type"], "int64");
+    assert_eq!(field("timestamp")["sort"], true);
+}
+
+#[test]
+fn typo_tolerance_maps_to_per_field_query_params() {
+    let backend = TypesenseBackend::new_unchecked("http://typesense:8108".to_string())
+        .with_typo_tolerance(TypoTolerance {
+            min_word_size_for_one_typo: 4,
+            min_word_size_for_two_typos: 8,
+            disable_on_fields: vec!["event_data".to_string()],
+            ..TypoTolerance::default()
+        });
+    assert_eq!(
+        backend.typo_params(),
+        vec![
+            ("num_typos", "2,0,2".to_string()),
+            ("min_len_1typo", "4".to_string()),
+            ("min_len_2typo", "8".to_string()),
+        ]
+    );
+
+    let disabled = backend.with_typo_tolerance(TypoTolerance::disabled());
+    assert_eq!(disabled.typo_params()[0], ("num_typos", "0,0,0".to_string()));
+}
+
+#[test]
+fn filter_values_with_backticks_are_rejected() {
+    assert_eq!(filter_value("tenant-1").unwrap(), "`tenant-1`");
+    assert!(filter_value("a` || tenant_id:=`b").is_err());
+}
+
+#[test]
+fn import_results_report_failed_documents() {
+    assert!(check_import_results("{\"success\":true}\n{\"success\":true}\n").is_ok());
+    let err = check_import_results(
+        "{\"success\":true}\n{\"success\":false,\"error\":\"Field `timestamp` must be an int64.\"}\n",
+    )
+    .unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("1 document(s)"), "unexpected error: {message}");
+    assert!(message.contains("int64"), "unexpected error: {message}");
+}
+
+#[tokio::test]
+async fn ensure_collection_creates_schema_once() {
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .and(header("X-TYPESENSE-API-KEY", "test-key"))
+        .and(body_partial_json(json!({ "name": "fraiseql_events" })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(json!({ "name": "fraiseql_events" })),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    backend.ensure_collection().await.unwrap();
+    backend.clone().ensure_collection().await.unwrap();
+}
+
+#[tokio::test]
+async fn rejected_schema_is_reported() {
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(
+            ResponseTemplate::new(400).set_body_json(json!({ "message": "Bad field type" })),
+        )
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    let err = backend.ensure_collection().await.unwrap_err();
+    assert!(err.to_string().contains("Bad field type"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn index_batch_imports_jsonl_with_upsert() {
+    let mock = MockServer::start().await;
+    mount_existing_collection(&mock).await;
+    let events = [indexed("order-1", 10), indexed("order-2", 20)];
+    let expected: String = events
+        .iter()
+        .map(|e| serde_json::to_string(&SearchDocument::new(e)).unwrap() + "\n")
+        .collect();
+    Mock::given(method("POST"))
+        .and(path("/collections/fraiseql_events/documents/import"))
+        .and(query_param("action", "upsert"))
+        .and(body_string(expected))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("{\"success\":true}\n{\"success\":true}"),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    backend.index_batch(&events).await.unwrap();
+}
+
+#[tokio::test]
+async fn index_batch_surfaces_partial_import_failure() {
+    let mock = MockServer::start().await;
+    mount_existing_collection(&mock).await;
+    Mock::given(method("POST"))
+        .and(path("/collections/fraiseql_events/documents/import"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("{\"success\":true}\n{\"success\":false,\"error\":\"Bad JSON.\"}"),
+        )
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    let err = backend
+        .index_batch(&[indexed("order-1", 10), indexed("order-2", 20)])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Bad JSON"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn index_batch_empty_is_noop() {
+    let backend = TypesenseBackend::new_unchecked("http://localhost:19999".to_string());
+    assert!(backend.index_batch(&[]).await.is_ok());
+}
+
+#[tokio::test]
+async fn search_sends_tenant_filter_and_typo_params_and_parses_hits() {
+    let mock = MockServer::start().await;
+    mount_existing_collection(&mock).await;
+    Mock::given(method("GET"))
+        .and(path("/collections/fraiseql_events/documents/search"))
+        .and(query_param("q", "order"))
+        .and(query_param("query_by", "search_text,event_data,actions_executed"))
+        .and(query_param("filter_by", "tenant_id:=`tenant-1`"))
+        .and(query_param("num_typos", "2,2,2"))
+        .and(query_param("per_page", "250"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "hits": [
+                { "document": serde_json::to_value(SearchDocument::new(&indexed("order-1", 10))).unwrap() }
+            ]
+        })))
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    let results = backend.search("order", "tenant-1", 1_000).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].entity_id, "order-1");
+}
+
+#[tokio::test]
+async fn time_range_search_uses_range_filter_and_sort() {
+    let mock = MockServer::start().await;
+    mount_existing_collection(&mock).await;
+    Mock::given(method("GET"))
+        .and(path("/collections/fraiseql_events/documents/search"))
+        .and(query_param("filter_by", "timestamp:[100..200] && tenant_id:=`tenant-1`"))
+        .and(query_param("sort_by", "timestamp:desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "hits": [] })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    let results = backend.search_time_range(100, 200, "tenant-1", 5).await.unwrap();
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn delete_old_events_uses_timestamp_filter() {
+    let mock = MockServer::start().await;
+    mount_existing_collection(&mock).await;
+    Mock::given(method("DELETE"))
+        .and(path("/collections/fraiseql_events/documents"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "num_deleted": 3 })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let backend = TypesenseBackend::new_unchecked(mock.uri());
+    backend.delete_old_events(30).await.unwrap();
+
+    let requests = mock.received_requests().await.unwrap();
+    let delete = requests.iter().find(|r| r.method.as_str() == "DELETE").unwrap();
+    let filter = delete.url.query_pairs().find(|(k, _)| k == "filter_by").unwrap().1;
+    assert!(filter.starts_with("timestamp:<="), "unexpected filter: {filter}");
+}
@@ -0,0 +1,185 @@
+//! HMAC-SHA256 signing of outbound webhook payloads, and the matching verifier.
+//!
+//! A webhook action with a signing secret attaches
+//! `X-FraiseQL-Signature-256: t=<unix_ts>,v1=<hex>`, where the HMAC is taken
+//! over `"<ts>.<body>"` (the Stripe shape, so `fraiseql-webhooks`'s
+//! `StripeVerifier` accepts it too). When the action also names a
+//! `signing_key_id`, it is sent in `X-FraiseQL-Signature-Key-Id` so a receiver
+//! holding several secrets — typically during a rotation — knows which one to
+//! verify with.
+//!
+//! Receivers written in Rust can call [`verify_webhook_signature`] instead of
+//! re-implementing the parsing and the replay window.
+
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::error::Result;
+
+/// Header carrying the HMAC-SHA256 signature of the webhook body.
+///
+/// Stripe-compatible shape (`t=<unix_ts>,v1=<hex>`); verifiable with
+/// [`verify_webhook_signature`] or `fraiseql-webhooks`'s `StripeVerifier` (#345).
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-FraiseQL-Signature-256";
+
+/// Header naming the key the signature was produced with.
+///
+/// Only sent when the webhook action sets `signing_key_id`.
+pub const WEBHOOK_KEY_ID_HEADER: &str = "X-FraiseQL-Signature-Key-Id";
+
+/// Default replay window accepted by [`verify_webhook_signature`].
+pub const DEFAULT_SIGNATURE_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Resolves a named webhook signing secret from a secrets manager.
+///
+/// A webhook action's `signing_secret_ref` is looked up through this trait at
+/// dispatch time, so a rotated secret takes effect without reloading
+/// observers. The server implements it over its configured `SecretsManager`
+/// (Vault, file, or environment backend).
+#[async_trait::async_trait]
+pub trait SigningSecretResolver: Send + Sync {
+    /// Return the current value of the secret called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObserverError::ActionExecutionFailed` when the backend is
+    /// unreachable (retried like any transient delivery failure), or
+    /// `ObserverError::InvalidActionConfig` when the secret does not exist.
+    async fn resolve(&self, name: &str) -> Result<String>;
+}
+
+/// The secret a webhook is signed with, and the key id advertised for it.
+#[derive(Clone, Copy)]
+pub struct WebhookSigningKey<'a> {
+    /// HMAC secret.
+    pub secret: &'a str,
+    /// Sent in [`WEBHOOK_KEY_ID_HEADER`] when set.
+    pub key_id: Option<&'a str>,
+}
+
+impl std::fmt::Debug for WebhookSigningKey<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookSigningKey")
+            .field("secret", &"[REDACTED]")
+            .field("key_id", &self.key_id)
+            .finish()
+    }
+}
+
+/// Why [`verify_webhook_signature`] rejected a delivery.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum WebhookSignatureError {
+    /// The header is not `t=<unix_ts>,v1=<hex>`.
+    #[error("malformed webhook signature header")]
+    Malformed,
+    /// The timestamp lies outside the accepted replay window.
+    #[error("webhook signature timestamp is outside the {tolerance_secs}s tolerance window")]
+    Expired {
+        /// The tolerance the timestamp was checked against, in seconds.
+        tolerance_secs: u64,
+    },
+    /// No `v1` signature matches the body under the given secret.
+    #[error("webhook signature does not match the payload")]
+    Mismatch,
+}
+
+/// Compute the Stripe-shape signature header value for the exact `body_bytes`.
+///
+/// Returns `t=<ts>,v1=<hex>` where the HMAC-SHA256 is taken over the byte
+/// sequence `"<ts>.<body>"` — byte-identical to the signing base used by
+/// `fraiseql_webhooks::signature::stripe::StripeVerifier`
+/// (`format!("{t}.{}", String::from_utf8_lossy(body))`), so a receiver can
+/// verify the payload with that verifier.
+///
+/// `body_bytes` MUST be the exact bytes transmitted on the wire (see
+/// [`WebhookAction::execute`](crate::WebhookAction::execute), which signs the
+/// same buffer it sends) — signing a re-serialization can diverge from the
+/// transmitted bytes and fail every external verification.
+pub(crate) fn webhook_signature(secret: &str, ts: i64, body_bytes: &[u8]) -> String {
+    let hex = hex::encode(signing_mac(secret, ts, body_bytes).finalize().into_bytes());
+    format!("t={ts},v1={hex}")
+}
+
+/// Verify an `X-FraiseQL-Signature-256` header against the received body.
+///
+/// `body` must be the raw request bytes, before any JSON parsing. The
+/// timestamp must be within `tolerance` of the current time in either
+/// direction, which bounds how long a captured delivery can be replayed.
+/// Several `v1=` entries are accepted, and any one matching is enough.
+///
+/// # Errors
+///
+/// Returns [`WebhookSignatureError`] describing why the delivery must be
+/// rejected.
+pub fn verify_webhook_signature(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    tolerance: Duration,
+) -> std::result::Result<(), WebhookSignatureError> {
+    verify_webhook_signature_at(secret, header, body, tolerance, chrono::Utc::now().timestamp())
+}
+
+/// [`verify_webhook_signature`] against an explicit current Unix time.
+///
+/// # Errors
+///
+/// Returns [`WebhookSignatureError`] describing why the delivery must be
+/// rejected.
+pub fn verify_webhook_signature_at(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    tolerance: Duration,
+    now: i64,
+) -> std::result::Result<(), WebhookSignatureError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => {
+                timestamp =
+                    Some(value.parse::<i64>().map_err(|_| WebhookSignatureError::Malformed)?);
+            },
+            Some(("v1", value)) => signatures.push(value),
+            _ => {},
+        }
+    }
+    let Some(ts) = timestamp else {
+        return Err(WebhookSignatureError::Malformed);
+    };
+    if signatures.is_empty() {
+        return Err(WebhookSignatureError::Malformed);
+    }
+
+    let tolerance_secs = tolerance.as_secs();
+    if now.abs_diff(ts) > tolerance_secs {
+        return Err(WebhookSignatureError::Expired { tolerance_secs });
+    }
+
+    let mac = signing_mac(secret, ts, body);
+    let matched = signatures.into_iter().any(|signature| {
+        hex::decode(signature).is_ok_and(|bytes| mac.clone().verify_slice(&bytes).is_ok())
+    });
+    if matched {
+        Ok(())
+    } else {
+        Err(WebhookSignatureError::Mismatch)
+    }
+}
+
+fn signing_mac(secret: &str, ts: i64, body_bytes: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC-SHA256 accepts a key of any length");
+    mac.update(ts.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body_bytes);
+    mac
+}
@@ -0,0 +1,100 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
+
+use std::time::Duration;
+
+use fraiseql_webhooks::{SignatureVerifier, signature::stripe::StripeVerifier};
+
+use super::{
+    DEFAULT_SIGNATURE_TOLERANCE, WebhookSignatureError, WebhookSigningKey,
+    verify_webhook_signature, verify_webhook_signature_at, webhook_signature,
+};
+
+const NOW: i64 = 1_800_000_000;
+const BODY: &[u8] = br#"{"id":"ord_1","amount":4200}"#;
+
+#[test]
+fn signed_header_verifies() {
+    let header = webhook_signature("whsec", NOW, BODY);
+    assert_eq!(
+        verify_webhook_signature_at("whsec", &header, BODY, DEFAULT_SIGNATURE_TOLERANCE, NOW),
+        Ok(())
+    );
+}
+
+#[test]
+fn verify_uses_the_current_clock() {
+    let header = webhook_signature("whsec", chrono::Utc::now().timestamp(), BODY);
+    assert_eq!(
+        verify_webhook_signature("whsec", &header, BODY, DEFAULT_SIGNATURE_TOLERANCE),
+        Ok(())
+    );
+}
+
+#[test]
+fn wrong_secret_or_body_is_a_mismatch() {
+    let header = webhook_signature("whsec", NOW, BODY);
+    let tolerance = DEFAULT_SIGNATURE_TOLERANCE;
+    assert_eq!(
+        verify_webhook_signature_at("other", &header, BODY, tolerance, NOW),
+        Err(WebhookSignatureError::Mismatch)
+    );
+    assert_eq!(
+        verify_webhook_signature_at("whsec", &header, br#"{"amount":1}"#, tolerance, NOW),
+        Err(WebhookSignatureError::Mismatch)
+    );
+}
+
+#[test]
+fn timestamp_outside_tolerance_is_rejected_in_both_directions() {
+    let tolerance = Duration::from_secs(60);
+    let header = webhook_signature("whsec", NOW, BODY);
+    assert_eq!(verify_webhook_signature_at("whsec", &header, BODY, tolerance, NOW + 60), Ok(()));
+    assert_eq!(
+        verify_webhook_signature_at("whsec", &header, BODY, tolerance, NOW + 61),
+        Err(WebhookSignatureError::Expired { tolerance_secs: 60 })
+    );
+    assert_eq!(
+        verify_webhook_signature_at("whsec", &header, BODY, tolerance, NOW - 61),
+        Err(WebhookSignatureError::Expired { tolerance_secs: 60 })
+    );
+}
+
+#[test]
+fn any_matching_v1_entry_is_accepted() {
+    let current = webhook_signature("whsec", NOW, BODY);
+    let v1 = current.split_once(",v1=").unwrap().1;
+    let header = format!("t={NOW},v1=deadbeef,v1={v1}");
+    assert_eq!(
+        verify_webhook_signature_at("whsec", &header, BODY, DEFAULT_SIGNATURE_TOLERANCE, NOW),
+        Ok(())
+    );
+}
+
+#[test]
+fn malformed_headers_are_rejected() {
+    for header in ["", "v1=abcd", "t=notanumber,v1=abcd", &format!("t={NOW}")] {
+        assert_eq!(
+            verify_webhook_signature_at("whsec", header, BODY, DEFAULT_SIGNATURE_TOLERANCE, NOW),
+            Err(WebhookSignatureError::Malformed),
+            "header {header:?}"
+        );
+    }
+}
+
+#[test]
+fn stripe_verifier_accepts_our_signature() {
+    let ts = chrono::Utc::now().timestamp();
+    let header = webhook_signature("whsec", ts, BODY);
+    assert!(StripeVerifier::new().verify(BODY, &header, "whsec", None, None).unwrap());
+}
+
+#[test]
+fn signing_key_debug_redacts_the_secret() {
+    let key = WebhookSigningKey {
+        secret: "whsec_hidden",
+        key_id: Some("k1"),
+    };
+    let debug = format!("{key:?}");
+    assert!(!debug.contains("whsec_hidden"));
+    assert!(debug.contains("k1"));
+}
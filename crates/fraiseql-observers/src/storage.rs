@@ -4,6 +4,7 @@
 //! from persistent storage (PostgreSQL, etc.).
 
 use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
 use crate::{error::Result, event::EntityEvent};
 
@@ -47,6 +48,39 @@ pub trait EventStorage: Send + Sync {
         start_date: Option<DateTime<Utc>>,
         end_date: Option<DateTime<Utc>>,
     ) -> Result<usize>;
+
+    /// Page through events oldest first, resuming after a keyset cursor.
+    ///
+    /// Used by [`ReplayEngine`](crate::replay::ReplayEngine) to walk a time
+    /// window in stable order. Events are sorted by `(timestamp, id)` ascending;
+    /// when `after` is set only events strictly after that `(timestamp, id)`
+    /// pair are returned, so a page boundary that splits events sharing a
+    /// timestamp neither skips nor repeats any of them.
+    ///
+    /// The default implementation loads the whole window through
+    /// [`query_events`](Self::query_events) on every call; backends with an
+    /// index on `(entity_type, timestamp)` should override it with a keyset
+    /// query.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if database query fails or events cannot be deserialized.
+    async fn query_events_after(
+        &self,
+        entity_type: &str,
+        start_date: Option<DateTime<Utc>>,
+        end_date: Option<DateTime<Utc>>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: usize,
+    ) -> Result<Vec<EntityEvent>> {
+        let mut events = self.query_events(entity_type, start_date, end_date, None).await?;
+        events.sort_by_key(|e| (e.timestamp, e.id));
+        if let Some(cursor) = after {
+            events.retain(|e| (e.timestamp, e.id) > cursor);
+        }
+        events.truncate(limit);
+        Ok(events)
+    }
 }
 
 #[cfg(feature = "postgres")]
@@ -94,6 +128,49 @@ pub mod postgres {
         }
     }
 
+    #[derive(sqlx::FromRow)]
+    struct EventRow {
+        id:          Uuid,
+        event_type:  String,
+        entity_type: String,
+        entity_id:   Uuid,
+        timestamp:   DateTime<Utc>,
+        data:        serde_json::Value,
+        user_id:     Option<String>,
+        tenant_id:   Option<String>,
+    }
+
+    impl EventRow {
+        fn into_event(self) -> EntityEvent {
+            let event_type = match self.event_type.as_str() {
+                "INSERT" => EventKind::Created,
+                "UPDATE" => EventKind::Updated,
+                "DELETE" => EventKind::Deleted,
+                _ => EventKind::Custom,
+            };
+
+            EntityEvent {
+                id: self.id,
+                event_type,
+                entity_type: self.entity_type,
+                entity_id: self.entity_id,
+                data: self.data,
+                changes: None,
+                user_id: self.user_id,
+                tenant_id: self.tenant_id,
+                timestamp: self.timestamp,
+                // The search-storage schema does not persist the
+                // Change-Spine perf/envelope columns.
+                duration_ms: None,
+                seq: None,
+                actor_type: None,
+                acting_for: None,
+                schema_version: None,
+                cdc_source: None,
+            }
+        }
+    }
+
     impl EventStorage for PostgresEventStorage {
         async fn query_events(
             &self,
@@ -136,19 +213,6 @@ pub mod postgres {
             }
 
             // Execute query
-            #[allow(clippy::items_after_statements)] // Reason: helper type defined close to use site for readability
-            #[derive(sqlx::FromRow)]
-            struct EventRow {
-                id:          Uuid,
-                event_type:  String,
-                entity_type: String,
-                entity_id:   Uuid,
-                timestamp:   DateTime<Utc>,
-                data:        serde_json::Value,
-                user_id:     Option<String>,
-                tenant_id:   Option<String>,
-            }
-
             let mut query = sqlx::query_as::<_, EventRow>(&query_str);
 
             query = query.bind(entity_type);
@@ -166,40 +230,7 @@ pub mod postgres {
                     reason: format!("Failed to query events: {e}"),
                 })?;
 
-            // Convert rows to EntityEvents
-            let events = rows
-                .into_iter()
-                .map(|row| {
-                    let event_type = match row.event_type.as_str() {
-                        "INSERT" => EventKind::Created,
-                        "UPDATE" => EventKind::Updated,
-                        "DELETE" => EventKind::Deleted,
-                        _ => EventKind::Custom,
-                    };
-
-                    EntityEvent {
-                        id: row.id,
-                        event_type,
-                        entity_type: row.entity_type,
-                        entity_id: row.entity_id,
-                        data: row.data,
-                        changes: None,
-                        user_id: row.user_id,
-                        tenant_id: row.tenant_id,
-                        timestamp: row.timestamp,
-                        // The search-storage schema does not persist the
-                        // Change-Spine perf/envelope columns.
-                        duration_ms: None,
-                        seq: None,
-                        actor_type: None,
-                        acting_for: None,
-                        schema_version: None,
-                        cdc_source: None,
-                    }
-                })
-                .collect();
-
-            Ok(events)
+            Ok(rows.into_iter().map(EventRow::into_event).collect())
         }
 
         #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)] // Reason: COUNT(*) result is non-negative and fits in usize
@@ -245,5 +276,42 @@ pub mod postgres {
 
             Ok(count as usize)
         }
+
+        async fn query_events_after(
+            &self,
+            entity_type: &str,
+            start_date: Option<DateTime<Utc>>,
+            end_date: Option<DateTime<Utc>>,
+            after: Option<(DateTime<Utc>, Uuid)>,
+            limit: usize,
+        ) -> Result<Vec<EntityEvent>> {
+            // Keyset pagination: a NULL bound disables its predicate, so one
+            // statement serves every combination of window and cursor.
+            let rows = sqlx::query_as::<_, EventRow>(
+                r"
+                SELECT id, event_type, entity_type, entity_id, timestamp, data, user_id, tenant_id
+                FROM fraiseql_events
+                WHERE entity_type = $1
+                  AND ($2::timestamptz IS NULL OR timestamp >= $2)
+                  AND ($3::timestamptz IS NULL OR timestamp <= $3)
+                  AND ($4::timestamptz IS NULL OR (timestamp, id) > ($4, $5::uuid))
+                ORDER BY timestamp ASC, id ASC
+                LIMIT $6
+                ",
+            )
+            .bind(entity_type)
+            .bind(start_date)
+            .bind(end_date)
+            .bind(after.map(|(ts, _)| ts))
+            .bind(after.map(|(_, id)| id))
+            .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ObserverError::StorageError {
+                reason: format!("Failed to page events: {e}"),
+            })?;
+
+            Ok(rows.into_iter().map(EventRow::into_event).collect())
+        }
     }
 }
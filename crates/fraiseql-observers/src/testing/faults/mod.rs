@@ -0,0 +1,484 @@
+//! Deterministic fault injection for resilience tests.
+//!
+//! Wrap a real (or mock) [`ActionExecutor`], `JobQueue` or [`EventTransport`]
+//! in [`FaultyActionExecutor`], `FaultyJobQueue` (feature `queue`) or
+//! [`FaultyTransport`], all
+//! sharing one [`FaultInjector`] built from a [`FaultPlan`]. Each call then
+//! suffers, according to the plan:
+//!
+//! - **errors** — transient ones (retried by workers, counted by circuit breakers) and permanent
+//!   ones (dead-lettered);
+//! - **latency spikes** — a fixed delay before the call, enough to trip job timeouts;
+//! - **poison events** — events whose every action fails permanently, and which a transport
+//!   delivers as an undecodable message.
+//!
+//! Decisions are a pure function of the plan's seed, the operation, the call's
+//! key (event id and action type, job id, …) and how many times that key has
+//! been seen — never of wall-clock time or task scheduling. The same seed
+//! therefore replays the same faults on every run, even with concurrent
+//! workers, as long as each key's own calls happen in order (as retries do).
+//! [`FaultInjector::injected`] lists what was injected, for assertions.
+//!
+//! ```ignore
+//! let faults = Arc::new(FaultInjector::new(
+//!     FaultPlan::new(42).error_rate(0.3).latency(0.1, Duration::from_millis(500)),
+//! ));
+//! let executor = FaultyActionExecutor::new(MockActionExecutor::new(), Arc::clone(&faults));
+//! let queue = FaultyJobQueue::new(queue, Arc::clone(&faults));
+//! let worker = JobWorker::new(queue, executor, policy, 200);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::StreamExt as _;
+use uuid::Uuid;
+
+#[cfg(feature = "queue")]
+use crate::queue::{Job, JobQueue, JobResult, QueueStats};
+use crate::{
+    config::ActionConfig,
+    error::{ObserverError, Result},
+    event::EntityEvent,
+    traits::{ActionExecutor, ActionResult},
+    transport::{EventFilter, EventStream, EventTransport, TransportHealth, TransportType},
+};
+
+/// Which faults to inject, and how often.
+///
+/// Rates are probabilities in `0.0..=1.0` (out-of-range values are clamped),
+/// rolled independently per call.
+#[derive(Debug, Clone)]
+pub struct FaultPlan {
+    seed:                 u64,
+    error_rate:           f64,
+    permanent_error_rate: f64,
+    latency_rate:         f64,
+    latency:              Duration,
+    fail_first:           u32,
+    poison:               HashSet<Uuid>,
+}
+
+impl FaultPlan {
+    /// A plan that injects nothing until configured.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            error_rate: 0.0,
+            permanent_error_rate: 0.0,
+            latency_rate: 0.0,
+            latency: Duration::ZERO,
+            fail_first: 0,
+            poison: HashSet::new(),
+        }
+    }
+
+    /// Fail this fraction of calls with a transient error.
+    #[must_use]
+    pub const fn error_rate(mut self, rate: f64) -> Self {
+        self.error_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Fail this fraction of calls with a permanent error.
+    #[must_use]
+    pub const fn permanent_error_rate(mut self, rate: f64) -> Self {
+        self.permanent_error_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay this fraction of calls by `delay`.
+    #[must_use]
+    pub const fn latency(mut self, rate: f64, delay: Duration) -> Self {
+        self.latency_rate = rate.clamp(0.0, 1.0);
+        self.latency = delay;
+        self
+    }
+
+    /// Fail the first `attempts` calls for every key with a transient error,
+    /// whatever the rates — for "succeeds on the third try" tests.
+    #[must_use]
+    pub const fn fail_first(mut self, attempts: u32) -> Self {
+        self.fail_first = attempts;
+        self
+    }
+
+    /// Treat the event with this id as poison.
+    #[must_use]
+    pub fn poison_event(mut self, event_id: Uuid) -> Self {
+        self.poison.insert(event_id);
+        self
+    }
+}
+
+/// How a faulted call fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// A retryable error.
+    Transient,
+    /// A non-retryable error.
+    Permanent,
+    /// The call concerns a poison event.
+    Poison,
+}
+
+/// What the injector decided for one call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaultDecision {
+    /// Delay before the call (zero for none).
+    pub delay:   Duration,
+    /// Failure instead of the call, if any.
+    pub failure: Option<Failure>,
+}
+
+impl FaultDecision {
+    /// Whether the call goes through untouched.
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.delay.is_zero() && self.failure.is_none()
+    }
+}
+
+/// One injected fault, as recorded by [`FaultInjector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InjectedFault {
+    /// Operation the fault hit (`action`, `enqueue`, `publish`, …).
+    pub operation: &'static str,
+    /// Key of the call (event id and action type, job id, …).
+    pub key:       String,
+    /// 1-based count of calls seen for this operation and key.
+    pub attempt:   u32,
+    /// What was injected.
+    pub decision:  FaultDecision,
+}
+
+/// Seeded source of fault decisions, shared by the `Faulty*` wrappers.
+#[derive(Debug)]
+pub struct FaultInjector {
+    plan:     FaultPlan,
+    attempts: Mutex<HashMap<(&'static str, String), u32>>,
+    injected: Mutex<Vec<InjectedFault>>,
+}
+
+impl FaultInjector {
+    /// An injector following `plan`.
+    #[must_use]
+    pub fn new(plan: FaultPlan) -> Self {
+        Self {
+            plan,
+            attempts: Mutex::new(HashMap::new()),
+            injected: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Decide the fault for the next call of `operation` on `key`; `event_id`
+    /// makes poison events fail. Faulted calls are recorded.
+    pub fn decide(
+        &self,
+        operation: &'static str,
+        key: &str,
+        event_id: Option<Uuid>,
+    ) -> FaultDecision {
+        let attempt = {
+            let mut attempts = lock(&self.attempts);
+            let attempt = attempts.entry((operation, key.to_string())).or_insert(0);
+            *attempt += 1;
+            *attempt
+        };
+        let plan = &self.plan;
+        let roll = |salt: &str| unit_interval(plan.seed, operation, key, attempt, salt);
+
+        let failure = if event_id.is_some_and(|id| plan.poison.contains(&id)) {
+            Some(Failure::Poison)
+        } else if attempt <= plan.fail_first || roll("error") < plan.error_rate {
+            Some(Failure::Transient)
+        } else if roll("permanent") < plan.permanent_error_rate {
+            Some(Failure::Permanent)
+        } else {
+            None
+        };
+        let delay = if roll("latency") < plan.latency_rate {
+            plan.latency
+        } else {
+            Duration::ZERO
+        };
+
+        let decision = FaultDecision { delay, failure };
+        if !decision.is_clean() {
+            lock(&self.injected).push(InjectedFault {
+                operation,
+                key: key.to_string(),
+                attempt,
+                decision,
+            });
+        }
+        decision
+    }
+
+    /// Every fault injected so far, in order.
+    #[must_use]
+    pub fn injected(&self) -> Vec<InjectedFault> {
+        lock(&self.injected).clone()
+    }
+
+    /// Number of failures (not mere delays) injected into `operation`.
+    #[must_use]
+    pub fn failures(&self, operation: &str) -> usize {
+        lock(&self.injected)
+            .iter()
+            .filter(|f| f.operation == operation && f.decision.failure.is_some())
+            .count()
+    }
+
+    /// Decide, sleep through any latency, and turn a failure into an error.
+    async fn inject(
+        &self,
+        operation: &'static str,
+        key: &str,
+        event_id: Option<Uuid>,
+        error: impl FnOnce(Failure, String) -> ObserverError,
+    ) -> Result<()> {
+        let decision = self.decide(operation, key, event_id);
+        if !decision.delay.is_zero() {
+            tokio::time::sleep(decision.delay).await;
+        }
+        match decision.failure {
+            Some(failure) => {
+                Err(error(failure, format!("injected {failure:?} fault in {operation}")))
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+/// Lock a mutex, ignoring poisoning: the guarded maps stay consistent.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// A uniform value in `[0, 1)` derived from the inputs alone (FNV-1a, then a
+/// splitmix64 finaliser).
+fn unit_interval(seed: u64, operation: &str, key: &str, attempt: u32, salt: &str) -> f64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325 ^ seed;
+    for bytes in [
+        operation.as_bytes(),
+        key.as_bytes(),
+        &attempt.to_le_bytes(),
+        salt.as_bytes(),
+    ] {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        // Field separator, so ("ab", "c") and ("a", "bc") differ.
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3) ^ 0xff;
+    }
+    hash = hash.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^= hash >> 31;
+    let high = u32::try_from(hash >> 32).unwrap_or(u32::MAX);
+    f64::from(high) / (f64::from(u32::MAX) + 1.0)
+}
+
+/// An [`ActionExecutor`] that injects faults before delegating.
+///
+/// Transient failures surface as [`ObserverError::ActionExecutionFailed`];
+/// permanent ones and poison events as [`ObserverError::ActionPermanentlyFailed`].
+#[derive(Clone)]
+pub struct FaultyActionExecutor<E> {
+    inner:  E,
+    faults: Arc<FaultInjector>,
+}
+
+impl<E: ActionExecutor> FaultyActionExecutor<E> {
+    /// Wrap `inner`.
+    pub const fn new(inner: E, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+
+    /// The wrapped executor.
+    pub const fn inner(&self) -> &E {
+        &self.inner
+    }
+}
+
+impl<E: ActionExecutor> ActionExecutor for FaultyActionExecutor<E> {
+    async fn execute(&self, event: &EntityEvent, action: &ActionConfig) -> Result<ActionResult> {
+        let key = format!("{}:{}", event.id, action.action_type());
+        self.faults
+            .inject("action", &key, Some(event.id), |failure, reason| match failure {
+                Failure::Transient => ObserverError::ActionExecutionFailed { reason },
+                Failure::Permanent | Failure::Poison => {
+                    ObserverError::ActionPermanentlyFailed { reason }
+                },
+            })
+            .await?;
+        self.inner.execute(event, action).await
+    }
+}
+
+/// A [`JobQueue`] whose backend calls fail or stall.
+///
+/// Every failure surfaces as [`ObserverError::DatabaseError`], as a backend
+/// outage would. Enqueueing a job for a poison event fails.
+#[cfg(feature = "queue")]
+#[derive(Clone)]
+pub struct FaultyJobQueue<Q> {
+    inner:  Q,
+    faults: Arc<FaultInjector>,
+}
+
+#[cfg(feature = "queue")]
+impl<Q: JobQueue> FaultyJobQueue<Q> {
+    /// Wrap `inner`.
+    pub const fn new(inner: Q, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+
+    /// The wrapped queue.
+    pub const fn inner(&self) -> &Q {
+        &self.inner
+    }
+
+    async fn inject(
+        &self,
+        operation: &'static str,
+        key: &str,
+        event_id: Option<Uuid>,
+    ) -> Result<()> {
+        self.faults
+            .inject(operation, key, event_id, |_, reason| ObserverError::DatabaseError { reason })
+            .await
+    }
+}
+
+// Reason: JobQueue is defined with #[async_trait]; implementations must match its
+// transformed method signatures
+#[cfg(feature = "queue")]
+#[async_trait]
+impl<Q: JobQueue> JobQueue for FaultyJobQueue<Q> {
+    async fn enqueue(&self, job: &Job) -> Result<String> {
+        self.inject("enqueue", &job.id, Some(job.event.id)).await?;
+        self.inner.enqueue(job).await
+    }
+
+    async fn dequeue(&self, worker_id: &str) -> Result<Option<Job>> {
+        self.inject("dequeue", worker_id, None).await?;
+        self.inner.dequeue(worker_id).await
+    }
+
+    async fn mark_processing(&self, job_id: &str) -> Result<()> {
+        self.inject("mark_processing", job_id, None).await?;
+        self.inner.mark_processing(job_id).await
+    }
+
+    async fn mark_success(&self, job_id: &str, result: &JobResult) -> Result<()> {
+        self.inject("mark_success", job_id, None).await?;
+        self.inner.mark_success(job_id, result).await
+    }
+
+    async fn mark_retry(&self, job_id: &str, next_retry_at: i64) -> Result<()> {
+        self.inject("mark_retry", job_id, None).await?;
+        self.inner.mark_retry(job_id, next_retry_at).await
+    }
+
+    async fn mark_deadletter(&self, job_id: &str, reason: &str) -> Result<()> {
+        self.inject("mark_deadletter", job_id, None).await?;
+        self.inner.mark_deadletter(job_id, reason).await
+    }
+
+    async fn get_stats(&self) -> Result<QueueStats> {
+        self.inner.get_stats().await
+    }
+}
+
+/// An [`EventTransport`] with unreliable publish, subscribe and delivery.
+///
+/// `publish` and `subscribe` fail with [`ObserverError::TransportPublishFailed`]
+/// and [`ObserverError::TransportSubscribeFailed`]. On the subscribed stream,
+/// each event may be delayed; a transient fault yields an
+/// [`ObserverError::TransportConnectionFailed`] item before the event (which is
+/// still delivered), and a permanent fault or poison event replaces the event
+/// with an [`ObserverError::DeserializationError`] carrying its JSON.
+pub struct FaultyTransport<T> {
+    inner:  T,
+    faults: Arc<FaultInjector>,
+}
+
+impl<T: EventTransport> FaultyTransport<T> {
+    /// Wrap `inner`.
+    pub const fn new(inner: T, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+
+    /// The wrapped transport.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+// Reason: EventTransport is defined with #[async_trait]; implementations must match its
+// transformed method signatures
+#[async_trait]
+impl<T: EventTransport> EventTransport for FaultyTransport<T> {
+    async fn subscribe(&self, filter: EventFilter) -> Result<EventStream> {
+        self.faults
+            .inject("subscribe", "subscribe", None, |_, reason| {
+                ObserverError::TransportSubscribeFailed { reason }
+            })
+            .await?;
+        let faults = Arc::clone(&self.faults);
+        let stream = self.inner.subscribe(filter).await?.then(move |item| {
+            let faults = Arc::clone(&faults);
+            async move {
+                let Ok(event) = item else {
+                    return vec![item];
+                };
+                let decision = faults.decide("deliver", &event.id.to_string(), Some(event.id));
+                if !decision.delay.is_zero() {
+                    tokio::time::sleep(decision.delay).await;
+                }
+                match decision.failure {
+                    None => vec![Ok(event)],
+                    Some(Failure::Transient) => vec![
+                        Err(ObserverError::TransportConnectionFailed {
+                            reason: "injected Transient fault in deliver".to_string(),
+                        }),
+                        Ok(event),
+                    ],
+                    Some(failure) => vec![Err(ObserverError::DeserializationError {
+                        raw:    serde_json::to_vec(&event).unwrap_or_default(),
+                        reason: format!("injected {failure:?} fault in deliver"),
+                    })],
+                }
+            }
+        });
+        Ok(Box::pin(stream.flat_map(futures::stream::iter)))
+    }
+
+    async fn publish(&self, event: EntityEvent) -> Result<()> {
+        self.faults
+            .inject("publish", &event.id.to_string(), Some(event.id), |_, reason| {
+                ObserverError::TransportPublishFailed { reason }
+            })
+            .await?;
+        self.inner.publish(event).await
+    }
+
+    fn transport_type(&self) -> TransportType {
+        self.inner.transport_type()
+    }
+
+    async fn health_check(&self) -> Result<TransportHealth> {
+        self.inner.health_check().await
+    }
+}
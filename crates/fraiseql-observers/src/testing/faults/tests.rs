@@ -0,0 +1,177 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test module
+
+use std::{sync::Arc, time::Duration};
+
+use futures::StreamExt as _;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{Failure, FaultInjector, FaultPlan, FaultyActionExecutor, FaultyTransport};
+use crate::{
+    config::ActionConfig,
+    error::ObserverError,
+    event::{EntityEvent, EventKind},
+    testing::mocks::MockActionExecutor,
+    traits::ActionExecutor,
+    transport::{EventFilter, EventTransport, InMemoryTransport},
+};
+
+fn event() -> EntityEvent {
+    EntityEvent::new(EventKind::Created, "Order".to_string(), Uuid::new_v4(), json!({}))
+}
+
+fn email() -> ActionConfig {
+    ActionConfig::Email {
+        to:               Some("user@example.com".to_string()),
+        to_template:      None,
+        subject:          Some("Test".to_string()),
+        subject_template: None,
+        body_template:    Some("Body".to_string()),
+        reply_to:         None,
+    }
+}
+
+fn failures(plan: &FaultPlan, keys: &[String]) -> Vec<Option<Failure>> {
+    let faults = FaultInjector::new(plan.clone());
+    keys.iter().map(|key| faults.decide("action", key, None).failure).collect()
+}
+
+#[test]
+fn the_same_seed_replays_the_same_faults() {
+    let keys: Vec<String> = (0..200).map(|i| format!("key-{i}")).collect();
+    let plan = FaultPlan::new(7).error_rate(0.3);
+
+    let first = failures(&plan, &keys);
+    assert_eq!(first, failures(&plan, &keys));
+    assert_ne!(first, failures(&FaultPlan::new(8).error_rate(0.3), &keys));
+
+    // Roughly the configured rate.
+    let failed = first.iter().filter(|f| f.is_some()).count();
+    assert!((30..90).contains(&failed), "{failed} of 200 failed");
+}
+
+#[test]
+fn decisions_do_not_depend_on_call_interleaving() {
+    let plan = FaultPlan::new(3).error_rate(0.5);
+    let forward = FaultInjector::new(plan.clone());
+    let backward = FaultInjector::new(plan);
+    let keys: Vec<String> = (0..50).map(|i| format!("key-{i}")).collect();
+
+    let a: Vec<_> = keys.iter().map(|k| forward.decide("action", k, None)).collect();
+    let mut b: Vec<_> = keys.iter().rev().map(|k| backward.decide("action", k, None)).collect();
+    b.reverse();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn rates_of_zero_and_one_are_exact() {
+    let keys: Vec<String> = (0..100).map(|i| format!("key-{i}")).collect();
+    assert!(failures(&FaultPlan::new(1), &keys).iter().all(Option::is_none));
+    assert!(
+        failures(&FaultPlan::new(1).error_rate(1.0), &keys)
+            .iter()
+            .all(|f| *f == Some(Failure::Transient))
+    );
+    assert!(
+        failures(&FaultPlan::new(1).permanent_error_rate(2.0), &keys)
+            .iter()
+            .all(|f| *f == Some(Failure::Permanent))
+    );
+}
+
+#[test]
+fn fail_first_fails_each_key_a_fixed_number_of_times() {
+    let faults = FaultInjector::new(FaultPlan::new(0).fail_first(2));
+    for key in ["a", "b"] {
+        assert_eq!(faults.decide("action", key, None).failure, Some(Failure::Transient));
+        assert_eq!(faults.decide("action", key, None).failure, Some(Failure::Transient));
+        assert!(faults.decide("action", key, None).is_clean());
+    }
+    let injected = faults.injected();
+    assert_eq!(injected.len(), 4);
+    assert_eq!((injected[1].key.as_str(), injected[1].attempt), ("a", 2));
+    assert_eq!(faults.failures("action"), 4);
+    assert_eq!(faults.failures("publish"), 0);
+}
+
+#[tokio::test]
+async fn the_executor_retries_through_transient_faults() {
+    let faults = Arc::new(FaultInjector::new(FaultPlan::new(0).fail_first(2)));
+    let executor = FaultyActionExecutor::new(MockActionExecutor::new(), Arc::clone(&faults));
+    let (event, action) = (event(), email());
+
+    for _ in 0..2 {
+        let err = executor.execute(&event, &action).await.unwrap_err();
+        assert!(matches!(err, ObserverError::ActionExecutionFailed { .. }), "{err:?}");
+    }
+    assert!(executor.execute(&event, &action).await.unwrap().success);
+    assert_eq!(executor.inner().execution_count(), 1);
+}
+
+#[tokio::test]
+async fn poison_events_always_fail_permanently() {
+    let poison = event();
+    let faults = Arc::new(FaultInjector::new(FaultPlan::new(0).poison_event(poison.id)));
+    let executor = FaultyActionExecutor::new(MockActionExecutor::new(), faults);
+
+    for _ in 0..3 {
+        let err = executor.execute(&poison, &email()).await.unwrap_err();
+        assert!(matches!(err, ObserverError::ActionPermanentlyFailed { .. }), "{err:?}");
+    }
+    assert!(executor.execute(&event(), &email()).await.is_ok());
+    assert_eq!(executor.inner().execution_count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn latency_delays_the_call() {
+    let faults =
+        Arc::new(FaultInjector::new(FaultPlan::new(0).latency(1.0, Duration::from_secs(30))));
+    let executor = FaultyActionExecutor::new(MockActionExecutor::new(), faults);
+
+    let start = tokio::time::Instant::now();
+    executor.execute(&event(), &email()).await.unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn the_transport_corrupts_poison_events_in_delivery() {
+    let poison = event();
+    let clean = event();
+    let faults = Arc::new(FaultInjector::new(FaultPlan::new(0).poison_event(poison.id)));
+    let transport = FaultyTransport::new(InMemoryTransport::new(), Arc::clone(&faults));
+
+    let mut stream = transport.subscribe(EventFilter::default()).await.unwrap();
+    // Publishing a poison event fails too, so push it past the wrapper.
+    transport.inner().publish(poison.clone()).await.unwrap();
+    transport.publish(clean.clone()).await.unwrap();
+
+    match stream.next().await.unwrap() {
+        Err(ObserverError::DeserializationError { raw, .. }) => {
+            let decoded: EntityEvent = serde_json::from_slice(&raw).unwrap();
+            assert_eq!(decoded.id, poison.id);
+        },
+        other => panic!("expected a deserialization error, got {other:?}"),
+    }
+    assert_eq!(stream.next().await.unwrap().unwrap().id, clean.id);
+    assert!(transport.publish(poison).await.is_err());
+    assert_eq!(faults.failures("deliver"), 1);
+}
+
+#[tokio::test]
+async fn transient_delivery_faults_still_deliver_the_event() {
+    let faults = Arc::new(FaultInjector::new(FaultPlan::new(0).fail_first(1)));
+    let transport = FaultyTransport::new(InMemoryTransport::new(), faults);
+    let sent = event();
+
+    // The first subscribe attempt fails; the second succeeds.
+    let err = transport.subscribe(EventFilter::default()).await.err().unwrap();
+    assert!(matches!(err, ObserverError::TransportSubscribeFailed { .. }), "{err:?}");
+    let mut stream = transport.subscribe(EventFilter::default()).await.unwrap();
+    transport.inner().publish(sent.clone()).await.unwrap();
+
+    assert!(matches!(
+        stream.next().await.unwrap(),
+        Err(ObserverError::TransportConnectionFailed { .. })
+    ));
+    assert_eq!(stream.next().await.unwrap().unwrap().id, sent.id);
+}
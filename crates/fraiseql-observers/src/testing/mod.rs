@@ -1,6 +1,9 @@
 #![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
 #![allow(clippy::missing_panics_doc)] // Reason: test helper functions; all panics are from mutex poisoning
-//! Mock implementations of traits for testing without external dependencies.
+//! Mock implementations of traits for testing without external dependencies,
+//! and seeded fault injection around real ones ([`faults`]).
+
+pub mod faults;
 
 #[cfg(any(test, feature = "testing"))]
 pub mod mocks {
@@ -1030,6 +1030,7 @@ mod factory_tests {
                 enable_concurrent: true,
                 ..Default::default()
             },
+            metrics:                 Default::default(),
             observers:               HashMap::new(),
             channel_capacity:        1000,
             max_concurrency:         50,
@@ -1037,6 +1038,8 @@ mod factory_tests {
             backlog_alert_threshold: 500,
             shutdown_timeout:        "30s".to_string(),
             max_dlq_size:            None,
+            dry_run:                 false,
+            event_types:             Default::default(),
         };
 
         let dlq = Arc::new(MockDeadLetterQueue::new());
@@ -1055,6 +1058,7 @@ mod factory_tests {
                 enable_dedup: true, // Invalid!
                 ..Default::default()
             },
+            metrics:                 Default::default(),
             observers:               HashMap::new(),
             channel_capacity:        1000,
             max_concurrency:         50,
@@ -1062,6 +1066,8 @@ mod factory_tests {
             backlog_alert_threshold: 500,
             shutdown_timeout:        "30s".to_string(),
             max_dlq_size:            None,
+            dry_run:                 false,
+            event_types:             Default::default(),
         };
 
         let dlq = Arc::new(MockDeadLetterQueue::new());
@@ -1119,6 +1125,7 @@ mod factory_tests {
                 enable_concurrent: true,
                 ..Default::default()
             },
+            metrics:                 Default::default(),
             observers:               HashMap::new(),
             channel_capacity:        1000,
             max_concurrency:         50,
@@ -1126,6 +1133,8 @@ mod factory_tests {
             backlog_alert_threshold: 500,
             shutdown_timeout:        "30s".to_string(),
             max_dlq_size:            None,
+            dry_run:                 false,
+            event_types:             Default::default(),
         };
 
         let dlq = Arc::new(MockDeadLetterQueue::new());
@@ -1216,17 +1225,17 @@ mod matcher_tests {
 
     use crate::{
         ObserverDefinition,
-        config::{ActionConfig, FailurePolicy, RetryConfig},
+        config::{ActionConfig, DeliveryOrdering, FailurePolicy, ReplayPolicy, RetryConfig},
         event::{EntityEvent, EventKind},
         matcher::*,
     };
 
     fn create_observer(event_type: &str, entity: &str) -> ObserverDefinition {
         ObserverDefinition {
-            event_type: event_type.to_string(),
-            entity:     entity.to_string(),
-            condition:  None,
-            actions:    vec![ActionConfig::Webhook {
+            event_type:      event_type.to_string(),
+            entity:          entity.to_string(),
+            condition:       None,
+            actions:         vec![ActionConfig::Webhook {
                 url:                Some("https://example.com".to_string()),
                 url_env:            None,
                 method:             None,
@@ -1234,9 +1243,16 @@ mod matcher_tests {
                 body_template:      Some("{}".to_string()),
                 signing_secret:     None,
                 signing_secret_env: None,
+                signing_secret_ref: None,
+                signing_key_id:     None,
             }],
-            retry:      RetryConfig::default(),
-            on_failure: FailurePolicy::Log,
+            retry:           RetryConfig::default(),
+            on_failure:      FailurePolicy::Log,
+            replay:          ReplayPolicy::default(),
+            ordering:        DeliveryOrdering::default(),
+            max_concurrency: None,
+            dedup:           None,
+            dry_run:         false,
         }
     }
 
@@ -1524,6 +1540,8 @@ mod queued_executor_tests {
             jobs_queued:        5,
             queueing_errors:    0,
             conditions_skipped: 0,
+            dry_run_captured:   0,
+            quarantined:        false,
             job_ids:            vec![],
             errors:             vec![],
         };
@@ -206,8 +206,8 @@ async fn cache_action_through_executor_invalidates_for_real() {
     use std::{collections::HashMap, sync::Arc};
 
     use fraiseql_observers::{
-        ActionConfig, EventMatcher, FailurePolicy, ObserverDefinition, ObserverExecutor,
-        RetryConfig, testing::mocks::MockDeadLetterQueue,
+        ActionConfig, DeliveryOrdering, EventMatcher, FailurePolicy, ObserverDefinition,
+        ObserverExecutor, ReplayPolicy, RetryConfig, testing::mocks::MockDeadLetterQueue,
     };
 
     let Some(url) = redis_url().await else {
@@ -221,19 +221,25 @@ async fn cache_action_through_executor_invalidates_for_real() {
 
     let inv = RedisCacheInvalidator::connect(&invalidator_config(&url)).await.unwrap();
     let observer = ObserverDefinition {
-        event_type: "UPDATE".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![ActionConfig::Cache {
+        event_type:      "UPDATE".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![ActionConfig::Cache {
             key_pattern: format!("it428:{p}:app:order:{ID_PH}"),
             action:      "invalidate".to_string(),
+            cdn:         None,
         }],
-        retry:      RetryConfig {
+        retry:           RetryConfig {
             max_attempts: 1,
             initial_delay_ms: 0,
             ..RetryConfig::default()
         },
-        on_failure: FailurePolicy::Log,
+        on_failure:      FailurePolicy::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = HashMap::new();
     observers.insert("obs".to_string(), observer);
@@ -259,24 +265,30 @@ async fn cache_action_without_backend_reports_failure_not_success() {
     use std::{collections::HashMap, sync::Arc};
 
     use fraiseql_observers::{
-        ActionConfig, EventMatcher, FailurePolicy, ObserverDefinition, ObserverExecutor,
-        RetryConfig, testing::mocks::MockDeadLetterQueue,
+        ActionConfig, DeliveryOrdering, EventMatcher, FailurePolicy, ObserverDefinition,
+        ObserverExecutor, ReplayPolicy, RetryConfig, testing::mocks::MockDeadLetterQueue,
     };
 
     let observer = ObserverDefinition {
-        event_type: "UPDATE".to_string(),
-        entity:     "Order".to_string(),
-        condition:  None,
-        actions:    vec![ActionConfig::Cache {
+        event_type:      "UPDATE".to_string(),
+        entity:          "Order".to_string(),
+        condition:       None,
+        actions:         vec![ActionConfig::Cache {
             key_pattern: "app:order:{{ id }}".to_string(),
             action:      "invalidate".to_string(),
+            cdn:         None,
         }],
-        retry:      RetryConfig {
+        retry:           RetryConfig {
             max_attempts: 1,
             initial_delay_ms: 0,
             ..RetryConfig::default()
         },
-        on_failure: FailurePolicy::Log,
+        on_failure:      FailurePolicy::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     };
     let mut observers = HashMap::new();
     observers.insert("obs".to_string(), observer);
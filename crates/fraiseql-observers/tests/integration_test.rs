@@ -88,7 +88,9 @@ fn test_runtime_config() -> ObserverRuntimeConfig {
             enable_concurrent:      true,
             max_concurrent_actions: 10,
             concurrent_timeout_ms:  5000,
+            dedup_strategy:         Default::default(),
         },
+        metrics:                 Default::default(),
         observers:               HashMap::new(),
         channel_capacity:        100,
         max_concurrency:         50,
@@ -96,6 +98,8 @@ fn test_runtime_config() -> ObserverRuntimeConfig {
         backlog_alert_threshold: 1000,
         overflow_policy:         OverflowPolicy::Drop,
         max_dlq_size:            None,
+        dry_run:                 false,
+        event_types:             Default::default(),
     }
 }
 
@@ -121,6 +125,8 @@ fn create_http_action(url: &str) -> ActionConfig {
         ),
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     }
 }
 
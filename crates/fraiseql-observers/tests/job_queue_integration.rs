@@ -118,6 +118,8 @@ fn test_job_creation_with_fixed_backoff() -> Result<()> {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         },
         3,
         BackoffStrategy::Fixed,
@@ -145,6 +147,8 @@ fn test_job_creation_with_linear_backoff() -> Result<()> {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         },
         5,
         BackoffStrategy::Linear,
@@ -170,6 +174,8 @@ fn test_job_creation_with_exponential_backoff() -> Result<()> {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         },
         10,
         BackoffStrategy::Exponential,
@@ -198,6 +204,8 @@ fn test_job_retry_counting() -> Result<()> {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         },
         3,
         BackoffStrategy::Fixed,
@@ -248,6 +256,8 @@ fn test_job_with_webhook_action() -> Result<()> {
             body_template:      Some(r#"{"event": "{{ event.kind }}"}"#.to_string()),
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         },
         3,
         BackoffStrategy::Exponential,
@@ -267,8 +277,11 @@ fn test_job_with_slack_action() -> Result<()> {
         ActionConfig::Slack {
             webhook_url:      Some("https://hooks.slack.com/services/T00/B00/XX".to_string()),
             webhook_url_env:  None,
+            bot_token_env:    None,
             channel:          Some("#alerts".to_string()),
             message_template: Some("Event occurred: {{ event.kind }}".to_string()),
+            blocks_template:  None,
+            thread_by_entity: false,
         },
         3,
         BackoffStrategy::Linear,
@@ -363,6 +376,8 @@ fn test_backoff_strategies() -> Result<()> {
                 body_template:      None,
                 signing_secret:     None,
                 signing_secret_env: None,
+                signing_secret_ref: None,
+                signing_key_id:     None,
             },
             3,
             strategy,
@@ -392,6 +407,8 @@ fn test_job_lifecycle() -> Result<()> {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         },
         3,
         BackoffStrategy::Linear,
@@ -438,6 +455,8 @@ fn test_job_config_combinations() -> Result<()> {
                 body_template:      None,
                 signing_secret:     None,
                 signing_secret_env: None,
+                signing_secret_ref: None,
+                signing_key_id:     None,
             },
         ),
         (
@@ -445,8 +464,11 @@ fn test_job_config_combinations() -> Result<()> {
             ActionConfig::Slack {
                 webhook_url:      Some("https://hooks.slack.com/services/T00/B00/XX".to_string()),
                 webhook_url_env:  None,
+                bot_token_env:    None,
                 channel:          None,
                 message_template: None,
+                blocks_template:  None,
+                thread_by_entity: false,
             },
         ),
     ];
@@ -15,7 +15,10 @@ use std::{collections::HashMap, sync::Arc};
 
 use fraiseql_observers::{
     condition::ConditionParser,
-    config::{ActionConfig, FailurePolicy, ObserverDefinition, RetryConfig},
+    config::{
+        ActionConfig, DeliveryOrdering, FailurePolicy, ObserverDefinition, ReplayPolicy,
+        RetryConfig,
+    },
     event::{EntityEvent, EventKind},
     matcher::EventMatcher,
     transport::{EventFilter, EventTransport, HealthStatus, InMemoryTransport, TransportType},
@@ -28,10 +31,10 @@ use uuid::Uuid;
 
 fn make_observer(event_type: &str, entity: &str, condition: Option<&str>) -> ObserverDefinition {
     ObserverDefinition {
-        event_type: event_type.to_string(),
-        entity:     entity.to_string(),
-        condition:  condition.map(str::to_string),
-        actions:    vec![ActionConfig::Webhook {
+        event_type:      event_type.to_string(),
+        entity:          entity.to_string(),
+        condition:       condition.map(str::to_string),
+        actions:         vec![ActionConfig::Webhook {
             url:                Some("https://example.com/hook".to_string()),
             url_env:            None,
             method:             None,
@@ -39,9 +42,16 @@ fn make_observer(event_type: &str, entity: &str, condition: Option<&str>) -> Obs
             body_template:      Some("{}".to_string()),
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         }],
-        retry:      RetryConfig::default(),
-        on_failure: FailurePolicy::Log,
+        retry:           RetryConfig::default(),
+        on_failure:      FailurePolicy::Log,
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
     }
 }
 
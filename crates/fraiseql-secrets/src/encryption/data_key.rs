@@ -0,0 +1,551 @@
+//! Envelope encryption with a cached, periodically rotated data key.
+//!
+//! [`DataKeyManager`] holds one plaintext data key in memory, generated under a
+//! KMS master key with [`BaseKmsProvider::generate_data_key`]. Values are sealed
+//! with AES-256-GCM under that key, and each resulting [`EncryptedData`] carries
+//! the wrapped data key and its version, so it stays readable after the key
+//! rotates or the process restarts.
+//!
+//! [`DataKeyRotationTask`] rotates the key on an interval. Rotation never
+//! rewrites stored data: [`DataKeyManager::decrypt`] returns values sealed
+//! under an older key together with a re-encrypted replacement, which the
+//! caller writes back (lazy re-encryption). Every rotation attempt is recorded
+//! as a [`SecretRotationAudit`] entry and counted in [`RotationMetrics`].
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use fraiseql_core::security::kms::{ArcKmsProvider, EncryptedData, KmsError};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use zeroize::Zeroizing;
+
+use super::{FieldEncryption, KeyVersion, credential_rotation::RotationMetrics};
+use crate::secrets_manager::SecretsError;
+
+/// Algorithm recorded on values sealed by [`DataKeyManager`].
+pub const ENVELOPE_ALGORITHM: &str = "aes-256-gcm+envelope";
+
+/// Table name of the [`SecretRotationAudit`] schema.
+pub const SECRET_ROTATION_AUDIT_TABLE: &str = "tb_secret_rotation_audit";
+
+/// PostgreSQL DDL for persisting [`SecretRotationAudit`] entries.
+///
+/// `fraiseql-secrets` has no database dependency; a [`RotationAuditSink`]
+/// backed by a database creates this table and inserts one row per entry.
+pub const SECRET_ROTATION_AUDIT_DDL: &str = "CREATE TABLE IF NOT EXISTS tb_secret_rotation_audit (
+    pk_secret_rotation_audit BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    master_key_id            TEXT NOT NULL,
+    provider                 TEXT NOT NULL,
+    previous_version         INTEGER,
+    new_version              INTEGER,
+    trigger                  TEXT NOT NULL,
+    rotated_at               TIMESTAMPTZ NOT NULL,
+    duration_ms              BIGINT NOT NULL,
+    success                  BOOLEAN NOT NULL,
+    error                    TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_tb_secret_rotation_audit_key_time
+    ON tb_secret_rotation_audit (master_key_id, rotated_at DESC);";
+
+/// Default interval between scheduled data key rotations.
+pub const DEFAULT_ROTATION_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Default number of retired data keys kept unwrapped in memory.
+const DEFAULT_RETAINED_KEYS: usize = 4;
+
+/// What caused a data key rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RotationTrigger {
+    /// First data key generated by this manager.
+    Initial,
+    /// The rotation interval elapsed.
+    Scheduled,
+    /// [`DataKeyManager::rotate`] was called directly.
+    Manual,
+}
+
+impl std::fmt::Display for RotationTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Initial => write!(f, "initial"),
+            Self::Scheduled => write!(f, "scheduled"),
+            Self::Manual => write!(f, "manual"),
+        }
+    }
+}
+
+/// One data key rotation attempt (the `SecretRotationAudit` schema).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretRotationAudit {
+    /// Master key the data key is wrapped by.
+    pub master_key_id:    String,
+    /// KMS provider name (e.g. `vault`).
+    pub provider:         String,
+    /// Version replaced by this rotation, if any.
+    pub previous_version: Option<KeyVersion>,
+    /// Version now used for encryption; `None` when the rotation failed.
+    pub new_version:      Option<KeyVersion>,
+    /// What caused the rotation.
+    pub trigger:          RotationTrigger,
+    /// When the rotation finished.
+    pub rotated_at:       DateTime<Utc>,
+    /// Time spent generating the new key.
+    pub duration_ms:      u64,
+    /// Whether a new key is in use.
+    pub success:          bool,
+    /// Failure reason when `success` is false.
+    pub error:            Option<String>,
+}
+
+/// Destination for [`SecretRotationAudit`] entries.
+#[async_trait::async_trait]
+pub trait RotationAuditSink: Send + Sync {
+    /// Persist one rotation attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::BackendError` if the entry cannot be stored.
+    async fn record(&self, entry: &SecretRotationAudit) -> Result<(), SecretsError>;
+
+    /// The highest successfully rotated version for `master_key_id`.
+    ///
+    /// Seeds the version counter so versions keep increasing across restarts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::BackendError` if the audit store cannot be read.
+    async fn latest_version(&self, master_key_id: &str)
+    -> Result<Option<KeyVersion>, SecretsError>;
+}
+
+/// [`RotationAuditSink`] keeping entries in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryRotationAudit {
+    entries: Mutex<Vec<SecretRotationAudit>>,
+}
+
+impl InMemoryRotationAudit {
+    /// Create an empty audit log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All recorded entries, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> Vec<SecretRotationAudit> {
+        self.entries.lock().map(|e| e.clone()).unwrap_or_default()
+    }
+}
+
+#[async_trait::async_trait]
+impl RotationAuditSink for InMemoryRotationAudit {
+    async fn record(&self, entry: &SecretRotationAudit) -> Result<(), SecretsError> {
+        self.entries
+            .lock()
+            .map_err(|e| SecretsError::BackendError(format!("audit log lock poisoned: {e}")))?
+            .push(entry.clone());
+        Ok(())
+    }
+
+    async fn latest_version(
+        &self,
+        master_key_id: &str,
+    ) -> Result<Option<KeyVersion>, SecretsError> {
+        let entries = self
+            .entries
+            .lock()
+            .map_err(|e| SecretsError::BackendError(format!("audit log lock poisoned: {e}")))?;
+        Ok(entries
+            .iter()
+            .filter(|e| e.success && e.master_key_id == master_key_id)
+            .filter_map(|e| e.new_version)
+            .max())
+    }
+}
+
+/// Configuration for [`DataKeyManager`].
+#[derive(Debug, Clone)]
+pub struct DataKeyConfig {
+    /// KMS master key that wraps each data key.
+    pub master_key_id:     String,
+    /// Interval between scheduled rotations.
+    pub rotation_interval: Duration,
+    /// Retired keys kept unwrapped in memory; older ones are unwrapped through
+    /// the KMS again on read.
+    pub retained_keys:     usize,
+    /// Context passed to the KMS when wrapping and unwrapping data keys.
+    pub kms_context:       HashMap<String, String>,
+}
+
+impl DataKeyConfig {
+    /// Configuration for `master_key_id` with the default interval.
+    #[must_use]
+    pub fn new(master_key_id: impl Into<String>) -> Self {
+        Self {
+            master_key_id:     master_key_id.into(),
+            rotation_interval: DEFAULT_ROTATION_INTERVAL,
+            retained_keys:     DEFAULT_RETAINED_KEYS,
+            kms_context:       HashMap::new(),
+        }
+    }
+
+    /// Set the interval between scheduled rotations.
+    #[must_use]
+    pub const fn with_rotation_interval(mut self, interval: Duration) -> Self {
+        self.rotation_interval = interval;
+        self
+    }
+
+    /// Set how many retired keys stay unwrapped in memory.
+    #[must_use]
+    pub const fn with_retained_keys(mut self, retained_keys: usize) -> Self {
+        self.retained_keys = retained_keys;
+        self
+    }
+
+    /// Set the KMS context used when wrapping data keys.
+    #[must_use]
+    pub fn with_kms_context(mut self, context: HashMap<String, String>) -> Self {
+        self.kms_context = context;
+        self
+    }
+}
+
+/// Result of [`DataKeyManager::decrypt`].
+#[derive(Debug)]
+pub struct Decrypted {
+    /// The decrypted value.
+    pub plaintext:   Zeroizing<String>,
+    /// Set when the value was sealed under a retired data key: the same
+    /// plaintext sealed under the current key, to be written back.
+    pub reencrypted: Option<EncryptedData>,
+}
+
+/// An unwrapped data key.
+struct DataKey {
+    version: KeyVersion,
+    cipher:  FieldEncryption,
+    wrapped: EncryptedData,
+}
+
+/// Envelope encryption under a cached, rotated data key.
+pub struct DataKeyManager {
+    provider:      ArcKmsProvider,
+    config:        DataKeyConfig,
+    current:       RwLock<Option<Arc<DataKey>>>,
+    retired:       Mutex<VecDeque<Arc<DataKey>>>,
+    rotation_lock: tokio::sync::Mutex<()>,
+    audit:         Arc<dyn RotationAuditSink>,
+    metrics:       Arc<RotationMetrics>,
+    reencryptions: AtomicU64,
+}
+
+impl std::fmt::Debug for DataKeyManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataKeyManager")
+            .field("provider", &self.provider.provider_name())
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DataKeyManager {
+    /// Create a manager; the first data key is generated on first use.
+    #[must_use]
+    pub fn new(
+        provider: ArcKmsProvider,
+        config: DataKeyConfig,
+        audit: Arc<dyn RotationAuditSink>,
+    ) -> Self {
+        Self {
+            provider,
+            config,
+            current: RwLock::new(None),
+            retired: Mutex::new(VecDeque::new()),
+            rotation_lock: tokio::sync::Mutex::new(()),
+            audit,
+            metrics: Arc::new(RotationMetrics::new()),
+            reencryptions: AtomicU64::new(0),
+        }
+    }
+
+    /// Rotation metrics (successes, failures, last duration).
+    #[must_use]
+    pub fn metrics(&self) -> Arc<RotationMetrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    /// Number of values re-encrypted on read since startup.
+    #[must_use]
+    pub fn reencryptions(&self) -> u64 {
+        self.reencryptions.load(Ordering::Relaxed)
+    }
+
+    /// Version of the data key currently used for encryption, if one exists.
+    pub async fn current_version(&self) -> Option<KeyVersion> {
+        self.current.read().await.as_ref().map(|k| k.version)
+    }
+
+    /// The configured rotation interval.
+    #[must_use]
+    pub const fn rotation_interval(&self) -> Duration {
+        self.config.rotation_interval
+    }
+
+    /// Seal `plaintext` under the current data key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError` if no data key can be generated or encryption
+    /// fails.
+    pub async fn encrypt(&self, plaintext: &str) -> Result<EncryptedData, SecretsError> {
+        let key = self.current_key().await?;
+        self.seal(&key, plaintext)
+    }
+
+    /// Open a value sealed by [`Self::encrypt`].
+    ///
+    /// When the value was sealed under a retired key, [`Decrypted::reencrypted`]
+    /// holds it sealed under the current key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` for a value not produced by this
+    /// manager, or `SecretsError::EncryptionError` if it cannot be decrypted.
+    pub async fn decrypt(&self, data: &EncryptedData) -> Result<Decrypted, SecretsError> {
+        let wrapped = data.data_key.as_deref().ok_or_else(|| {
+            SecretsError::ValidationError("Value has no wrapped data key".to_string())
+        })?;
+        if data.key_reference.key_id != self.config.master_key_id {
+            return Err(SecretsError::ValidationError(format!(
+                "Value is wrapped by master key '{}', not '{}'",
+                data.key_reference.key_id, self.config.master_key_id
+            )));
+        }
+        let ciphertext = hex::decode(&data.ciphertext).map_err(|e| {
+            SecretsError::EncryptionError(format!("Ciphertext is not valid hex: {e}"))
+        })?;
+
+        // The AAD is built from the stored version, so a tampered
+        // `key_version` fails authentication instead of being trusted.
+        let version = data.key_version.unwrap_or(0);
+        let current = self.current_key().await?;
+        let key = if current.wrapped.ciphertext == wrapped.ciphertext {
+            Arc::clone(&current)
+        } else {
+            self.retired_key(wrapped, version).await?
+        };
+        let plaintext =
+            Zeroizing::new(key.cipher.decrypt_with_context(&ciphertext, &self.aad(version))?);
+
+        let reencrypted = if Arc::ptr_eq(&key, &current) {
+            None
+        } else {
+            self.reencryptions.fetch_add(1, Ordering::Relaxed);
+            Some(self.seal(&current, &plaintext)?)
+        };
+        Ok(Decrypted {
+            plaintext,
+            reencrypted,
+        })
+    }
+
+    /// Replace the data key used for encryption.
+    ///
+    /// The previous key is retired but stays usable for decryption. The
+    /// attempt is recorded in the audit sink and the metrics whether or not it
+    /// succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::RotationError` if the KMS cannot generate a key.
+    pub async fn rotate(&self, trigger: RotationTrigger) -> Result<KeyVersion, SecretsError> {
+        let _guard = self.rotation_lock.lock().await;
+        self.rotate_locked(trigger).await
+    }
+
+    async fn rotate_locked(&self, trigger: RotationTrigger) -> Result<KeyVersion, SecretsError> {
+        let start = Instant::now();
+        let previous = self.current.read().await.clone();
+        let previous_version = match &previous {
+            Some(key) => Some(key.version),
+            None => self.audit.latest_version(&self.config.master_key_id).await?,
+        };
+        // Version 0 means "unversioned" (see `KeyVersion`), so wrap to 1.
+        let version = previous_version.map_or(1, |v| v.checked_add(1).unwrap_or(1));
+
+        let result = self.generate(version).await;
+        let duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        // `total_rotations` counts attempts: `success_rate_percent` subtracts
+        // failures from it.
+        self.metrics.record_rotation(duration_ms);
+        let entry = SecretRotationAudit {
+            master_key_id: self.config.master_key_id.clone(),
+            provider: self.provider.provider_name().to_string(),
+            previous_version,
+            new_version: result.as_ref().ok().map(|k| k.version),
+            trigger,
+            rotated_at: Utc::now(),
+            duration_ms,
+            success: result.is_ok(),
+            error: result.as_ref().err().map(ToString::to_string),
+        };
+        if let Err(e) = self.audit.record(&entry).await {
+            warn!(error = %e, master_key_id = %entry.master_key_id, "Failed to record data key rotation");
+        }
+
+        let key = result.inspect_err(|_| self.metrics.record_failure())?;
+        *self.current.write().await = Some(Arc::clone(&key));
+        if let Some(previous) = previous {
+            self.retire(previous);
+        }
+        info!(
+            master_key_id = %self.config.master_key_id,
+            version,
+            trigger = %trigger,
+            "Data key rotated"
+        );
+        Ok(version)
+    }
+
+    async fn current_key(&self) -> Result<Arc<DataKey>, SecretsError> {
+        if let Some(key) = self.current.read().await.as_ref() {
+            return Ok(Arc::clone(key));
+        }
+        let _guard = self.rotation_lock.lock().await;
+        if self.current.read().await.is_none() {
+            self.rotate_locked(RotationTrigger::Initial).await?;
+        }
+        self.current
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| SecretsError::RotationError("No data key available".to_string()))
+    }
+
+    async fn generate(&self, version: KeyVersion) -> Result<Arc<DataKey>, SecretsError> {
+        let pair = self
+            .provider
+            .generate_data_key(&self.config.master_key_id, Some(self.config.kms_context.clone()))
+            .await
+            .map_err(|e| SecretsError::RotationError(format!("Data key generation failed: {e}")))?;
+        Ok(Arc::new(DataKey {
+            version,
+            cipher: FieldEncryption::new(&pair.plaintext_key)?,
+            wrapped: pair.encrypted_key,
+        }))
+    }
+
+    /// Find a retired key by its wrapped form, unwrapping it through the KMS
+    /// when it is no longer cached.
+    async fn retired_key(
+        &self,
+        wrapped: &EncryptedData,
+        version: KeyVersion,
+    ) -> Result<Arc<DataKey>, SecretsError> {
+        let cached = self.retired.lock().ok().and_then(|retired| {
+            retired.iter().find(|k| k.wrapped.ciphertext == wrapped.ciphertext).cloned()
+        });
+        if let Some(key) = cached {
+            return Ok(key);
+        }
+        let plaintext_key =
+            Zeroizing::new(self.provider.decrypt(wrapped, None).await.map_err(map_unwrap_error)?);
+        let key = Arc::new(DataKey {
+            version,
+            cipher: FieldEncryption::new(&plaintext_key)?,
+            wrapped: wrapped.clone(),
+        });
+        self.retire(Arc::clone(&key));
+        Ok(key)
+    }
+
+    fn retire(&self, key: Arc<DataKey>) {
+        if let Ok(mut retired) = self.retired.lock() {
+            retired.push_front(key);
+            retired.truncate(self.config.retained_keys);
+        }
+    }
+
+    fn seal(&self, key: &DataKey, plaintext: &str) -> Result<EncryptedData, SecretsError> {
+        let ciphertext = key.cipher.encrypt_with_context(plaintext, &self.aad(key.version))?;
+        Ok(EncryptedData::new(
+            hex::encode(ciphertext),
+            key.wrapped.key_reference.clone(),
+            ENVELOPE_ALGORITHM.to_string(),
+            Utc::now().timestamp(),
+            HashMap::new(),
+        )
+        .with_data_key(key.wrapped.clone(), key.version))
+    }
+
+    /// AAD binding a sealed value to its master key and data key version.
+    fn aad(&self, version: KeyVersion) -> String {
+        format!("{}:{version}", self.config.master_key_id)
+    }
+}
+
+fn map_unwrap_error(error: KmsError) -> SecretsError {
+    match error {
+        KmsError::ProviderConnectionError { message } => SecretsError::ConnectionError(message),
+        other => SecretsError::EncryptionError(format!("Data key unwrap failed: {other}")),
+    }
+}
+
+/// Background task that rotates a [`DataKeyManager`]'s data key on its
+/// configured interval.
+pub struct DataKeyRotationTask {
+    manager:   Arc<DataKeyManager>,
+    cancel_rx: tokio::sync::watch::Receiver<bool>,
+}
+
+impl DataKeyRotationTask {
+    /// Create a new rotation task.
+    ///
+    /// Returns the task and a sender to trigger cancellation (send `true` to stop).
+    #[must_use]
+    pub fn new(manager: Arc<DataKeyManager>) -> (Self, tokio::sync::watch::Sender<bool>) {
+        let (cancel_tx, cancel_rx) = tokio::sync::watch::channel(false);
+        (Self { manager, cancel_rx }, cancel_tx)
+    }
+
+    /// Run the rotation loop.
+    ///
+    /// Blocks until the cancel sender sends `true` or is dropped. A failed
+    /// rotation is logged and retried at the next interval; the current key
+    /// stays in use meanwhile.
+    pub async fn run(mut self) {
+        let interval = self.manager.rotation_interval();
+        info!(interval_secs = interval.as_secs(), "Data key rotation task started");
+        loop {
+            tokio::select! {
+                result = self.cancel_rx.changed() => {
+                    if result.is_err() || *self.cancel_rx.borrow() {
+                        info!("Data key rotation task stopped");
+                        break;
+                    }
+                },
+                () = tokio::time::sleep(interval) => {
+                    if let Err(e) = self.manager.rotate(RotationTrigger::Scheduled).await {
+                        warn!(error = %e, "Scheduled data key rotation failed");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use chrono::Utc;
+use fraiseql_core::security::kms::{
+    BaseKmsProvider, KmsError, KmsResult,
+    base::{KeyInfo, RotationPolicyInfo},
+};
+
+use super::{
+    DataKeyConfig, DataKeyManager, DataKeyRotationTask, InMemoryRotationAudit, RotationAuditSink,
+    RotationTrigger, SecretRotationAudit,
+};
+use crate::secrets_manager::SecretsError;
+
+/// KMS double: "wraps" a data key by hex-encoding it with a prefix.
+#[derive(Default)]
+struct FakeKms {
+    generated: AtomicUsize,
+    unwraps:   AtomicUsize,
+    fail:      AtomicBool,
+}
+
+#[async_trait::async_trait]
+impl BaseKmsProvider for FakeKms {
+    fn provider_name(&self) -> &str {
+        "fake"
+    }
+
+    async fn do_encrypt(
+        &self,
+        _plaintext: &[u8],
+        _key_id: &str,
+        _context: &HashMap<String, String>,
+    ) -> KmsResult<(String, String)> {
+        Err(KmsError::EncryptionFailed {
+            message: "the data key manager never encrypts through the KMS".to_string(),
+        })
+    }
+
+    async fn do_decrypt(
+        &self,
+        ciphertext: &str,
+        _key_id: &str,
+        _context: &HashMap<String, String>,
+    ) -> KmsResult<Vec<u8>> {
+        self.unwraps.fetch_add(1, Ordering::SeqCst);
+        let hex = ciphertext.strip_prefix("fake:").ok_or(KmsError::DecryptionFailed {
+            message: "not a fake key".to_string(),
+        })?;
+        hex::decode(hex).map_err(|e| KmsError::DecryptionFailed {
+            message: e.to_string(),
+        })
+    }
+
+    async fn do_generate_data_key(
+        &self,
+        _key_id: &str,
+        _context: &HashMap<String, String>,
+    ) -> KmsResult<(Vec<u8>, String)> {
+        if self.fail.load(Ordering::SeqCst) {
+            return Err(KmsError::ProviderConnectionError {
+                message: "kms down".to_string(),
+            });
+        }
+        let n = self.generated.fetch_add(1, Ordering::SeqCst);
+        let key = vec![u8::try_from(n % 256).unwrap(); 32];
+        let wrapped = format!("fake:{}", hex::encode(&key));
+        Ok((key, wrapped))
+    }
+
+    async fn do_rotate_key(&self, _key_id: &str) -> KmsResult<()> {
+        Ok(())
+    }
+
+    async fn do_get_key_info(&self, _key_id: &str) -> KmsResult<KeyInfo> {
+        Ok(KeyInfo {
+            alias:      None,
+            created_at: 0,
+        })
+    }
+
+    async fn do_get_rotation_policy(&self, _key_id: &str) -> KmsResult<RotationPolicyInfo> {
+        Ok(RotationPolicyInfo {
+            enabled:              false,
+            rotation_period_days: 0,
+            last_rotation:        None,
+            next_rotation:        None,
+        })
+    }
+}
+
+struct Fixture {
+    kms:     Arc<FakeKms>,
+    audit:   Arc<InMemoryRotationAudit>,
+    manager: Arc<DataKeyManager>,
+}
+
+fn fixture(config: DataKeyConfig) -> Fixture {
+    fixture_with_audit(config, Arc::new(InMemoryRotationAudit::new()))
+}
+
+fn fixture_with_audit(config: DataKeyConfig, audit: Arc<InMemoryRotationAudit>) -> Fixture {
+    let kms = Arc::new(FakeKms::default());
+    let manager = Arc::new(DataKeyManager::new(
+        Arc::clone(&kms) as Arc<dyn BaseKmsProvider>,
+        config,
+        Arc::clone(&audit) as Arc<dyn RotationAuditSink>,
+    ));
+    Fixture {
+        kms,
+        audit,
+        manager,
+    }
+}
+
+#[tokio::test]
+async fn roundtrip_under_the_current_key_needs_no_reencryption() {
+    let f = fixture(DataKeyConfig::new("master"));
+    let sealed = f.manager.encrypt("4111-1111").await.unwrap();
+    assert_eq!(sealed.key_version, Some(1));
+    assert_eq!(sealed.key_reference.key_id, "master");
+    assert!(sealed.data_key.is_some());
+
+    let opened = f.manager.decrypt(&sealed).await.unwrap();
+    assert_eq!(opened.plaintext.as_str(), "4111-1111");
+    assert!(opened.reencrypted.is_none());
+
+    let entries = f.audit.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].trigger, RotationTrigger::Initial);
+    assert_eq!(entries[0].new_version, Some(1));
+}
+
+#[tokio::test]
+async fn values_under_a_retired_key_are_reencrypted_on_read() {
+    let f = fixture(DataKeyConfig::new("master"));
+    let old = f.manager.encrypt("secret").await.unwrap();
+    assert_eq!(f.manager.rotate(RotationTrigger::Manual).await.unwrap(), 2);
+
+    let opened = f.manager.decrypt(&old).await.unwrap();
+    assert_eq!(opened.plaintext.as_str(), "secret");
+    let fresh = opened.reencrypted.unwrap();
+    assert_eq!(fresh.key_version, Some(2));
+    assert_eq!(f.manager.reencryptions(), 1);
+    // The retired key was still cached: no unwrap round-trip to the KMS.
+    assert_eq!(f.kms.unwraps.load(Ordering::SeqCst), 0);
+
+    let reopened = f.manager.decrypt(&fresh).await.unwrap();
+    assert_eq!(reopened.plaintext.as_str(), "secret");
+    assert!(reopened.reencrypted.is_none());
+
+    let audit = f.audit.entries();
+    assert_eq!(audit[1].previous_version, Some(1));
+    assert_eq!(audit[1].trigger, RotationTrigger::Manual);
+    assert_eq!(f.manager.metrics().total_rotations(), 2);
+}
+
+#[tokio::test]
+async fn evicted_keys_are_unwrapped_through_the_kms() {
+    let f = fixture(DataKeyConfig::new("master").with_retained_keys(0));
+    let old = f.manager.encrypt("secret").await.unwrap();
+    f.manager.rotate(RotationTrigger::Manual).await.unwrap();
+
+    let opened = f.manager.decrypt(&old).await.unwrap();
+    assert_eq!(opened.plaintext.as_str(), "secret");
+    assert!(opened.reencrypted.is_some());
+    assert_eq!(f.kms.unwraps.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn failed_rotation_keeps_the_current_key_and_is_audited() {
+    let f = fixture(DataKeyConfig::new("master"));
+    f.manager.encrypt("x").await.unwrap();
+    f.kms.fail.store(true, Ordering::SeqCst);
+
+    assert!(matches!(
+        f.manager.rotate(RotationTrigger::Scheduled).await,
+        Err(SecretsError::RotationError(_))
+    ));
+    assert_eq!(f.manager.current_version().await, Some(1));
+    let metrics = f.manager.metrics();
+    assert_eq!(metrics.failed_rotations(), 1);
+    assert_eq!(metrics.success_rate_percent(), 50);
+
+    let failed = f.audit.entries().pop().unwrap();
+    assert!(!failed.success);
+    assert_eq!(failed.new_version, None);
+    assert!(failed.error.unwrap().contains("kms down"));
+}
+
+#[tokio::test]
+async fn versions_continue_from_the_audit_log() {
+    let audit = Arc::new(InMemoryRotationAudit::new());
+    audit
+        .record(&SecretRotationAudit {
+            master_key_id:    "master".to_string(),
+            provider:         "fake".to_string(),
+            previous_version: Some(6),
+            new_version:      Some(7),
+            trigger:          RotationTrigger::Scheduled,
+            rotated_at:       Utc::now(),
+            duration_ms:      1,
+            success:          true,
+            error:            None,
+        })
+        .await
+        .unwrap();
+    let f = fixture_with_audit(DataKeyConfig::new("master"), audit);
+    assert_eq!(f.manager.encrypt("x").await.unwrap().key_version, Some(8));
+}
+
+#[tokio::test]
+async fn tampered_or_foreign_values_are_rejected() {
+    let f = fixture(DataKeyConfig::new("master"));
+    let sealed = f.manager.encrypt("secret").await.unwrap();
+
+    let mut wrong_version = sealed.clone();
+    wrong_version.key_version = Some(9);
+    f.manager.rotate(RotationTrigger::Manual).await.unwrap();
+    assert!(matches!(
+        f.manager.decrypt(&wrong_version).await,
+        Err(SecretsError::EncryptionError(_))
+    ));
+
+    let mut no_key = sealed.clone();
+    no_key.data_key = None;
+    assert!(matches!(
+        f.manager.decrypt(&no_key).await,
+        Err(SecretsError::ValidationError(_))
+    ));
+
+    let mut other_master = sealed;
+    other_master.key_reference.key_id = "other".to_string();
+    assert!(matches!(
+        f.manager.decrypt(&other_master).await,
+        Err(SecretsError::ValidationError(_))
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn rotation_task_rotates_on_its_interval() {
+    let f = fixture(DataKeyConfig::new("master").with_rotation_interval(Duration::from_millis(50)));
+    f.manager.encrypt("x").await.unwrap();
+
+    let (task, cancel_tx) = DataKeyRotationTask::new(Arc::clone(&f.manager));
+    let handle = tokio::spawn(task.run());
+    tokio::task::yield_now().await;
+    tokio::time::advance(Duration::from_millis(60)).await;
+    // Let the rotation finish before cancelling (the select! is not biased).
+    for _ in 0..5 {
+        tokio::task::yield_now().await;
+    }
+    cancel_tx.send(true).unwrap();
+    tokio::time::timeout(Duration::from_secs(2), handle)
+        .await
+        .expect("task should exit quickly after cancellation")
+        .unwrap();
+
+    assert!(f.manager.current_version().await.unwrap() >= 2);
+    assert!(f.audit.entries().iter().any(|e| e.trigger == RotationTrigger::Scheduled));
+}
@@ -3,14 +3,19 @@
 //! Integrates with the GraphQL execution pipeline to:
 //! - Decrypt encrypted fields in query responses (after executor)
 //! - Encrypt fields in mutation variables (before executor)
+//! - Reject filters on encrypted fields (ciphertext cannot be compared)
 //!
 //! Uses the compiled schema's `FieldEncryptionConfig` metadata to determine
 //! which fields require encryption, and `DatabaseFieldAdapter` for the
 //! actual AES-256-GCM operations via `SecretsManager`.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use fraiseql_core::graphql::{FieldSelection, FragmentResolver, ParsedQuery};
 
 use super::{
     credential_rotation::CredentialRotationManager,
@@ -34,6 +39,8 @@ pub struct FieldEncryptionService {
     encrypted_fields: HashMap<String, Vec<String>>,
     /// Map: operation name (query/mutation) -> return type name
     operation_types:  HashMap<String, String>,
+    /// Map: `type_name` -> object-typed field name -> referenced type name
+    object_fields:    HashMap<String, HashMap<String, String>>,
     /// Adapter for encrypt/decrypt operations (fetches keys from `SecretsManager`)
     adapter:          Arc<DatabaseFieldAdapter>,
     /// Optional key rotation manager for versioned encryption
@@ -51,6 +58,7 @@ impl FieldEncryptionService {
         Self {
             encrypted_fields,
             operation_types,
+            object_fields: HashMap::new(),
             adapter,
             rotation_manager: None,
         }
@@ -67,6 +75,7 @@ impl FieldEncryptionService {
         Self {
             encrypted_fields,
             operation_types,
+            object_fields: HashMap::new(),
             adapter,
             rotation_manager: Some(rotation_manager),
         }
@@ -75,7 +84,9 @@ impl FieldEncryptionService {
     /// Build from a compiled schema.
     ///
     /// Scans all type definitions for fields with `encryption` config,
-    /// and maps query/mutation names to their return types.
+    /// records which fields reference other object types (so nested
+    /// selections can be decrypted), and maps query/mutation names to their
+    /// return types.
     #[must_use]
     pub fn from_schema(
         schema: &fraiseql_core::schema::CompiledSchema,
@@ -83,8 +94,9 @@ impl FieldEncryptionService {
     ) -> Self {
         let mut encrypted_fields: HashMap<String, Vec<String>> = HashMap::new();
         let mut operation_types: HashMap<String, String> = HashMap::new();
+        let mut object_fields: HashMap<String, HashMap<String, String>> = HashMap::new();
 
-        // Scan types for encrypted fields
+        // Scan types for encrypted fields and object references
         for type_def in &schema.types {
             let enc: Vec<String> = type_def
                 .fields
@@ -95,6 +107,18 @@ impl FieldEncryptionService {
             if !enc.is_empty() {
                 encrypted_fields.insert(type_def.name.to_string(), enc);
             }
+
+            let refs: HashMap<String, String> = type_def
+                .fields
+                .iter()
+                .filter_map(|f| {
+                    let target = f.field_type.inner_type().unwrap_or(&f.field_type);
+                    target.type_name().map(|name| (f.name.to_string(), name.to_string()))
+                })
+                .collect();
+            if !refs.is_empty() {
+                object_fields.insert(type_def.name.to_string(), refs);
+            }
         }
 
         // Map query names to return types
@@ -108,6 +132,7 @@ impl FieldEncryptionService {
         Self {
             encrypted_fields,
             operation_types,
+            object_fields,
             adapter,
             rotation_manager: None,
         }
@@ -168,20 +193,7 @@ impl FieldEncryptionService {
             serde_json::Value::Object(obj) => {
                 for field_name in encrypted_fields {
                     if let Some(field_value) = obj.get_mut(field_name.as_str()) {
-                        if let Some(encoded) = field_value.as_str() {
-                            if encoded.is_empty() {
-                                continue;
-                            }
-                            let ciphertext = BASE64.decode(encoded).map_err(|e| {
-                                SecretsError::EncryptionError(format!(
-                                    "Failed to base64-decode field '{}': {}",
-                                    field_name, e
-                                ))
-                            })?;
-                            let plaintext =
-                                self.adapter.decrypt_value(field_name, &ciphertext).await?;
-                            *field_value = serde_json::Value::String(plaintext);
-                        }
+                        self.decrypt_field(field_name, field_value).await?;
                     }
                 }
             },
@@ -196,6 +208,128 @@ impl FieldEncryptionService {
         Ok(())
     }
 
+    /// Decrypt encrypted fields in the response to a parsed operation.
+    ///
+    /// Unlike [`Self::decrypt_response`], this follows the operation's
+    /// selection set: response keys are matched through aliases, fragments
+    /// are expanded, and encrypted fields on nested objects are decrypted
+    /// using the type each selected field references.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::EncryptionError` if base64 decoding or decryption
+    /// fails, and `SecretsError::ValidationError` if a fragment spread cannot
+    /// be resolved.
+    pub async fn decrypt_operation_response(
+        &self,
+        query: &ParsedQuery,
+        response: &mut serde_json::Value,
+    ) -> Result<(), SecretsError> {
+        let Some(data) = response.get_mut("data").and_then(serde_json::Value::as_object_mut) else {
+            return Ok(());
+        };
+
+        let selections = resolve_fragments(query)?;
+        for selection in &selections {
+            let Some(type_name) = self.operation_types.get(&selection.name) else {
+                continue;
+            };
+            if let Some(value) = data.get_mut(response_key(selection)) {
+                self.decrypt_selection(value, type_name, &selection.nested_fields).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt the selected fields of a value of type `type_name`.
+    async fn decrypt_selection(
+        &self,
+        value: &mut serde_json::Value,
+        type_name: &str,
+        selections: &[FieldSelection],
+    ) -> Result<(), SecretsError> {
+        match value {
+            serde_json::Value::Object(obj) => {
+                self.decrypt_object(obj, type_name, selections).await?;
+            },
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    Box::pin(self.decrypt_selection(item, type_name, selections)).await?;
+                }
+            },
+            _ => {},
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt the selected fields of one object of type `type_name`.
+    async fn decrypt_object(
+        &self,
+        obj: &mut serde_json::Map<String, serde_json::Value>,
+        type_name: &str,
+        selections: &[FieldSelection],
+    ) -> Result<(), SecretsError> {
+        for selection in selections {
+            // Inline fragments narrow the type; their fields live on the same object.
+            if let Some(condition) = selection.name.strip_prefix("...on") {
+                let condition = condition.trim();
+                let fragment_type = if condition.is_empty() {
+                    type_name
+                } else {
+                    condition
+                };
+                Box::pin(self.decrypt_object(obj, fragment_type, &selection.nested_fields)).await?;
+                continue;
+            }
+
+            let Some(field_value) = obj.get_mut(response_key(selection)) else {
+                continue;
+            };
+            if self.is_encrypted(type_name, &selection.name) {
+                self.decrypt_field(&selection.name, field_value).await?;
+            } else if let Some(target) =
+                self.object_fields.get(type_name).and_then(|m| m.get(&selection.name))
+            {
+                Box::pin(self.decrypt_selection(field_value, target, &selection.nested_fields))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt one encrypted field value (a base64 string, or a list of them).
+    ///
+    /// Nulls and empty strings pass through unchanged.
+    async fn decrypt_field(
+        &self,
+        field_name: &str,
+        field_value: &mut serde_json::Value,
+    ) -> Result<(), SecretsError> {
+        match field_value {
+            serde_json::Value::String(encoded) if !encoded.is_empty() => {
+                let ciphertext = BASE64.decode(encoded.as_str()).map_err(|e| {
+                    SecretsError::EncryptionError(format!(
+                        "Failed to base64-decode field '{}': {}",
+                        field_name, e
+                    ))
+                })?;
+                let plaintext = self.adapter.decrypt_value(field_name, &ciphertext).await?;
+                *field_value = serde_json::Value::String(plaintext);
+            },
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    Box::pin(self.decrypt_field(field_name, item)).await?;
+                }
+            },
+            _ => {},
+        }
+
+        Ok(())
+    }
+
     /// Encrypt fields in mutation variables before execution.
     ///
     /// Walks the variables object and encrypts fields that match the
@@ -222,13 +356,111 @@ impl FieldEncryptionService {
         value: &mut serde_json::Value,
         encrypted_fields: &[String],
     ) -> Result<(), SecretsError> {
-        if let Some(obj) = value.as_object_mut() {
-            for field_name in encrypted_fields {
-                if let Some(field_value) = obj.get_mut(field_name.as_str()) {
-                    if let Some(plaintext) = field_value.as_str() {
-                        let ciphertext = self.adapter.encrypt_value(field_name, plaintext).await?;
-                        *field_value = serde_json::Value::String(BASE64.encode(&ciphertext));
-                    }
+        self.encrypt_nested(value, encrypted_fields).await.map(|_| ())
+    }
+
+    /// Encrypt the values a mutation writes to encrypted fields.
+    ///
+    /// For every root mutation field, the encrypted fields of its return type
+    /// are located in the arguments. Values bound through variables are
+    /// encrypted in place in `variables` as base64 ciphertext, at any depth of
+    /// an input object. An encrypted field given as an inline literal is
+    /// rejected: the query text is executed as written, so the plaintext would
+    /// reach the database.
+    ///
+    /// Queries and subscriptions are left untouched. Returns the number of
+    /// values encrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` for an inline literal or a
+    /// non-string value on an encrypted field, and
+    /// `SecretsError::EncryptionError` if encryption fails.
+    pub async fn encrypt_mutation_input(
+        &self,
+        query: &ParsedQuery,
+        variables: &mut serde_json::Value,
+    ) -> Result<usize, SecretsError> {
+        if query.operation_type != "mutation" {
+            return Ok(0);
+        }
+
+        let declared: HashSet<&str> = query.variables.iter().map(|v| v.name.as_str()).collect();
+        let mut bindings = Vec::new();
+        for selection in &query.selections {
+            let Some(enc_fields) = self
+                .operation_types
+                .get(&selection.name)
+                .and_then(|type_name| self.encrypted_fields.get(type_name))
+            else {
+                continue;
+            };
+            for argument in &selection.arguments {
+                let value = argument_value(argument)?;
+                collect_bindings(&argument.name, &value, enc_fields, &declared, &mut bindings)?;
+            }
+        }
+
+        let mut encrypted = 0;
+        let mut seen = HashSet::new();
+        for binding in bindings {
+            if !seen.insert(binding.variable.clone()) {
+                continue;
+            }
+            let Some(bound) = variables.get_mut(&binding.variable) else {
+                continue;
+            };
+            encrypted += match binding.field {
+                Some(field_name) => self.encrypt_field(&field_name, bound).await?,
+                None => self.encrypt_nested(bound, binding.encrypted_fields).await?,
+            };
+        }
+
+        Ok(encrypted)
+    }
+
+    /// Reject filters that compare encrypted fields.
+    ///
+    /// Encrypted columns hold randomized ciphertext, so an equality argument or
+    /// a `where` condition on one can never match and would silently return
+    /// nothing. The compiler rejects such arguments in the schema; this catches
+    /// `where` filters supplied at request time, including ones nested under
+    /// `_and` / `_or` / `_not` and related objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` naming the encrypted field.
+    pub fn check_filters(
+        &self,
+        query: &ParsedQuery,
+        variables: Option<&serde_json::Value>,
+    ) -> Result<(), SecretsError> {
+        for selection in &query.selections {
+            let Some(type_name) = self.operation_types.get(&selection.name) else {
+                continue;
+            };
+            for argument in &selection.arguments {
+                let field = if argument.name == "where" {
+                    let value = argument_value(argument)?;
+                    let value = match value.as_str().and_then(|s| s.strip_prefix('$')) {
+                        Some(variable) => {
+                            variables.and_then(|v| v.get(variable)).cloned().unwrap_or_default()
+                        },
+                        None => value,
+                    };
+                    self.encrypted_filter_field(&value, type_name)
+                } else if query.operation_type == "query"
+                    && self.is_encrypted(type_name, &argument.name)
+                {
+                    Some(format!("{type_name}.{}", argument.name))
+                } else {
+                    None
+                };
+                if let Some(field) = field {
+                    return Err(SecretsError::ValidationError(format!(
+                        "Cannot filter on encrypted field '{field}': its stored value is \
+                         ciphertext and never matches a plaintext comparison"
+                    )));
                 }
             }
         }
@@ -236,6 +468,86 @@ impl FieldEncryptionService {
         Ok(())
     }
 
+    /// Find an encrypted field referenced by a `where` object of type `type_name`.
+    fn encrypted_filter_field(&self, value: &serde_json::Value, type_name: &str) -> Option<String> {
+        let obj = value.as_object()?;
+        obj.iter().find_map(|(key, condition)| match key.as_str() {
+            "_and" | "_or" => condition
+                .as_array()?
+                .iter()
+                .find_map(|item| self.encrypted_filter_field(item, type_name)),
+            "_not" => self.encrypted_filter_field(condition, type_name),
+            field_name if self.is_encrypted(type_name, field_name) => {
+                Some(format!("{type_name}.{field_name}"))
+            },
+            field_name => {
+                let target = self.object_fields.get(type_name)?.get(field_name)?;
+                self.encrypted_filter_field(condition, target)
+            },
+        })
+    }
+
+    /// Whether `field_name` on `type_name` is encrypted.
+    fn is_encrypted(&self, type_name: &str, field_name: &str) -> bool {
+        self.encrypted_fields
+            .get(type_name)
+            .is_some_and(|fields| fields.iter().any(|f| f == field_name))
+    }
+
+    /// Encrypt encrypted-field values anywhere inside an input value.
+    async fn encrypt_nested(
+        &self,
+        value: &mut serde_json::Value,
+        encrypted_fields: &[String],
+    ) -> Result<usize, SecretsError> {
+        let mut encrypted = 0;
+        match value {
+            serde_json::Value::Object(obj) => {
+                for (key, field_value) in obj.iter_mut() {
+                    encrypted += if encrypted_fields.contains(key) {
+                        self.encrypt_field(key, field_value).await?
+                    } else {
+                        Box::pin(self.encrypt_nested(field_value, encrypted_fields)).await?
+                    };
+                }
+            },
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    encrypted += Box::pin(self.encrypt_nested(item, encrypted_fields)).await?;
+                }
+            },
+            _ => {},
+        }
+
+        Ok(encrypted)
+    }
+
+    /// Encrypt one encrypted-field value (a string, or a list of them).
+    async fn encrypt_field(
+        &self,
+        field_name: &str,
+        field_value: &mut serde_json::Value,
+    ) -> Result<usize, SecretsError> {
+        match field_value {
+            serde_json::Value::Null => Ok(0),
+            serde_json::Value::String(plaintext) => {
+                let ciphertext = self.adapter.encrypt_value(field_name, plaintext).await?;
+                *field_value = serde_json::Value::String(BASE64.encode(&ciphertext));
+                Ok(1)
+            },
+            serde_json::Value::Array(items) => {
+                let mut encrypted = 0;
+                for item in items.iter_mut() {
+                    encrypted += Box::pin(self.encrypt_field(field_name, item)).await?;
+                }
+                Ok(encrypted)
+            },
+            _ => Err(SecretsError::ValidationError(format!(
+                "Encrypted field '{field_name}' must be a String"
+            ))),
+        }
+    }
+
     /// Check if key rotation is needed (80% of TTL consumed).
     #[must_use]
     pub fn needs_rotation(&self) -> bool {
@@ -276,5 +588,87 @@ impl FieldEncryptionService {
     }
 }
 
+/// A mutation variable that carries encrypted-field data.
+struct VariableBinding<'a> {
+    /// Variable name, without the `$`.
+    variable:         String,
+    /// The encrypted field the variable holds directly, if any; otherwise the
+    /// variable holds an input object searched for encrypted fields.
+    field:            Option<String>,
+    /// Encrypted fields of the mutation's return type.
+    encrypted_fields: &'a [String],
+}
+
+/// Record the variables an argument value binds, rejecting inline literals on
+/// encrypted fields.
+fn collect_bindings<'a>(
+    key: &str,
+    value: &serde_json::Value,
+    encrypted_fields: &'a [String],
+    declared: &HashSet<&str>,
+    bindings: &mut Vec<VariableBinding<'a>>,
+) -> Result<(), SecretsError> {
+    let is_encrypted = encrypted_fields.iter().any(|f| f == key);
+    let variable = value
+        .as_str()
+        .and_then(|s| s.strip_prefix('$'))
+        .filter(|name| declared.contains(name));
+    if let Some(variable) = variable {
+        bindings.push(VariableBinding {
+            variable: variable.to_string(),
+            field: is_encrypted.then(|| key.to_string()),
+            encrypted_fields,
+        });
+        return Ok(());
+    }
+
+    match value {
+        serde_json::Value::Object(obj) => {
+            for (field_name, field_value) in obj {
+                collect_bindings(field_name, field_value, encrypted_fields, declared, bindings)?;
+            }
+        },
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_bindings(key, item, encrypted_fields, declared, bindings)?;
+            }
+        },
+        serde_json::Value::Null => {},
+        _ if is_encrypted => {
+            return Err(SecretsError::ValidationError(format!(
+                "Encrypted field '{key}' must be passed as a variable so it can be encrypted \
+                 before it is written"
+            )));
+        },
+        _ => {},
+    }
+
+    Ok(())
+}
+
+/// Parse a GraphQL argument's serialized value.
+fn argument_value(
+    argument: &fraiseql_core::graphql::GraphQLArgument,
+) -> Result<serde_json::Value, SecretsError> {
+    serde_json::from_str(&argument.value_json).map_err(|e| {
+        SecretsError::ValidationError(format!(
+            "Cannot inspect argument '{}' for encrypted fields: {e}",
+            argument.name
+        ))
+    })
+}
+
+/// Expand named fragment spreads in an operation's root selections.
+fn resolve_fragments(query: &ParsedQuery) -> Result<Vec<FieldSelection>, SecretsError> {
+    FragmentResolver::new(&query.fragments)
+        .resolve_spreads(&query.selections)
+        .map_err(|e| SecretsError::ValidationError(format!("Cannot resolve fragments: {e}")))
+}
+
+/// The key a selection occupies in the response object.
+fn response_key(selection: &FieldSelection) -> &str {
+    selection.alias.as_deref().unwrap_or(&selection.name)
+}
+
 #[cfg(test)]
 mod tests;
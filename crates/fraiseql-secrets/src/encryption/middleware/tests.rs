@@ -68,10 +68,17 @@ fn test_service() -> FieldEncryptionService {
     let mut operation_types = HashMap::new();
     operation_types.insert("users".to_string(), "User".to_string());
     operation_types.insert("createUser".to_string(), "User".to_string());
+    operation_types.insert("posts".to_string(), "Post".to_string());
+
+    let mut post_fields = HashMap::new();
+    post_fields.insert("author".to_string(), "User".to_string());
+    let mut object_fields = HashMap::new();
+    object_fields.insert("Post".to_string(), post_fields);
 
     FieldEncryptionService {
         encrypted_fields,
         operation_types,
+        object_fields,
         adapter: Arc::new(create_test_database_adapter()),
         rotation_manager: None,
     }
@@ -253,6 +260,140 @@ async fn test_decrypt_skips_empty_string() {
     assert_eq!(response["data"]["users"]["email"], "");
 }
 
+// =========================================================================
+// Operation-aware Tests
+// =========================================================================
+
+fn parse(query: &str) -> ParsedQuery {
+    fraiseql_core::graphql::parse_query(query).unwrap()
+}
+
+async fn encrypt_for_test(plaintext: &str) -> String {
+    let ciphertext =
+        create_test_database_adapter().encrypt_value("email", plaintext).await.unwrap();
+    BASE64.encode(&ciphertext)
+}
+
+#[tokio::test]
+async fn test_encrypt_mutation_input_object_variable() {
+    let service = test_service();
+    let query =
+        parse("mutation Create($input: CreateUserInput!) { createUser(input: $input) { id } }");
+    let mut variables = serde_json::json!({
+        "input": {"name": "Ada", "email": "ada@example.com"}
+    });
+
+    let count = service.encrypt_mutation_input(&query, &mut variables).await.unwrap();
+
+    assert_eq!(count, 1);
+    assert_eq!(variables["input"]["name"], "Ada");
+    let encoded = variables["input"]["email"].as_str().unwrap();
+    let ciphertext = BASE64.decode(encoded).unwrap();
+    let decrypted = create_test_database_adapter()
+        .decrypt_value("email", &ciphertext)
+        .await
+        .unwrap();
+    assert_eq!(decrypted, "ada@example.com");
+}
+
+#[tokio::test]
+async fn test_encrypt_mutation_input_scalar_variable() {
+    let service = test_service();
+    let query = parse("mutation($e: String!) { createUser(name: \"Ada\", email: $e) { id } }");
+    let mut variables = serde_json::json!({"e": "ada@example.com"});
+
+    let count = service.encrypt_mutation_input(&query, &mut variables).await.unwrap();
+
+    assert_eq!(count, 1);
+    assert_ne!(variables["e"], "ada@example.com");
+}
+
+#[tokio::test]
+async fn test_encrypt_mutation_input_rejects_inline_literal() {
+    let service = test_service();
+    let query = parse("mutation { createUser(input: {email: \"ada@example.com\"}) { id } }");
+    let mut variables = serde_json::json!({});
+
+    let result = service.encrypt_mutation_input(&query, &mut variables).await;
+
+    assert!(
+        matches!(&result, Err(SecretsError::ValidationError(msg)) if msg.contains("'email'")),
+        "an inline plaintext literal must be rejected: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_encrypt_mutation_input_ignores_queries() {
+    let service = test_service();
+    let query = parse("query($e: String) { users(email: $e) { id } }");
+    let mut variables = serde_json::json!({"e": "ada@example.com"});
+
+    let count = service.encrypt_mutation_input(&query, &mut variables).await.unwrap();
+
+    assert_eq!(count, 0);
+    assert_eq!(variables["e"], "ada@example.com");
+}
+
+#[tokio::test]
+async fn test_decrypt_operation_response_follows_aliases_and_nesting() {
+    let service = test_service();
+    let encrypted = encrypt_for_test("ada@example.com").await;
+    let query = parse("{ people: users { mail: email } posts { author { email } } }");
+    let mut response = serde_json::json!({
+        "data": {
+            "people": [{"mail": encrypted.clone()}],
+            "posts": [{"author": {"email": encrypted}}]
+        }
+    });
+
+    service.decrypt_operation_response(&query, &mut response).await.unwrap();
+
+    assert_eq!(response["data"]["people"][0]["mail"], "ada@example.com");
+    assert_eq!(response["data"]["posts"][0]["author"]["email"], "ada@example.com");
+}
+
+#[tokio::test]
+async fn test_decrypt_operation_response_expands_fragments() {
+    let service = test_service();
+    let encrypted = encrypt_for_test("ada@example.com").await;
+    let query = parse("query { users { ...Contact } } fragment Contact on User { email }");
+    let mut response = serde_json::json!({"data": {"users": {"email": encrypted}}});
+
+    service.decrypt_operation_response(&query, &mut response).await.unwrap();
+
+    assert_eq!(response["data"]["users"]["email"], "ada@example.com");
+}
+
+#[test]
+fn test_check_filters_rejects_where_on_encrypted_field() {
+    let service = test_service();
+    let query = parse("query($w: UserWhereInput) { users(where: $w) { id } }");
+    let variables = serde_json::json!({"w": {"_or": [{"email": {"eq": "ada@example.com"}}]}});
+
+    let result = service.check_filters(&query, Some(&variables));
+
+    assert!(
+        matches!(&result, Err(SecretsError::ValidationError(msg)) if msg.contains("User.email")),
+        "a where filter on an encrypted field must be rejected: {result:?}"
+    );
+}
+
+#[test]
+fn test_check_filters_follows_related_objects() {
+    let service = test_service();
+    let query = parse("{ posts(where: {author: {email: {eq: \"a\"}}}) { id } }");
+
+    assert!(service.check_filters(&query, None).is_err());
+}
+
+#[test]
+fn test_check_filters_allows_plain_fields() {
+    let service = test_service();
+    let query = parse("{ users(where: {name: {eq: \"Ada\"}}) { id } }");
+
+    assert!(service.check_filters(&query, None).is_ok());
+}
+
 // =========================================================================
 // Key Rotation Tests
 // =========================================================================
@@ -272,6 +413,7 @@ fn test_service_with_rotation() -> FieldEncryptionService {
     FieldEncryptionService {
         encrypted_fields,
         operation_types,
+        object_fields: HashMap::new(),
         adapter: Arc::new(create_test_database_adapter()),
         rotation_manager: Some(rotation_manager),
     }
@@ -1,10 +1,9 @@
 //! AES-256-GCM encryption primitives for sensitive database fields.
 //!
-//! **End-to-end transparent field encryption is not supported in this release.** The
-//! write path does not encrypt, so the server refuses to boot when any schema field is
-//! marked for encryption (see `fraiseql_core::schema::FieldDefinition`). The primitives
-//! and read path here are retained for the in-progress implementation and must not be
-//! relied on for at-rest confidentiality.
+//! Fields marked `encrypted` in the schema carry a `FieldEncryptionConfig`. The server
+//! builds a [`middleware::FieldEncryptionService`] from the compiled schema, which encrypts
+//! mutation variables before execution and decrypts the selected fields of each response.
+//! Keys are fetched from the `SecretsManager` by the field's key reference.
 
 use aes_gcm::{
     Aes256Gcm, Nonce,
@@ -15,6 +14,7 @@ use rand::RngCore as _;
 use crate::secrets_manager::SecretsError;
 
 pub mod credential_rotation;
+pub mod data_key;
 pub mod database_adapter;
 pub mod middleware;
 
@@ -2,19 +2,20 @@
 //!
 //! This crate provides the secrets management implementation directly:
 //!
-//! - Multiple secrets backends (Vault, environment variables, files)
-//! - Key rotation utilities
+//! - Multiple secrets backends (Vault, AWS Secrets Manager, GCP Secret Manager, environment
+//!   variables, files)
+//! - `${secret:path}` reference resolution for configuration values
+//! - Key rotation utilities, including envelope encryption under a rotated data key
 //!
-//! **Field-level at-rest encryption is not supported in this release** (the write path
-//! does not encrypt; the server refuses to boot when a field is marked for encryption).
-//! The AES-256-GCM primitives are retained for the in-progress implementation.
+//! - Transparent field-level at-rest encryption: mutation variables are encrypted before they are
+//!   written and encrypted fields are decrypted when a response is projected
 //!
 //! # Crate structure
 //!
-//! - [`secrets_manager`] — Vault, environment, and file backends; lease renewal;
-//!   `create_secrets_manager` factory
-//! - [`encryption`] — `FieldEncryption` (AES-256-GCM) and `VersionedFieldEncryption` for encrypted
-//!   database column storage
+//! - [`secrets_manager`] — Vault, AWS, GCP, environment, and file backends; lease renewal;
+//!   `${secret:...}` references; `create_secrets_manager` factory
+//! - [`encryption`] — `FieldEncryption` (AES-256-GCM), `VersionedFieldEncryption`, and the
+//!   `FieldEncryptionService` middleware for encrypted database column storage
 //!
 //! # Integration with `fraiseql-server`
 //!
@@ -31,10 +32,16 @@ pub mod encryption;
 pub mod secrets_manager;
 
 // Re-exports for convenience
-pub use encryption::{FieldEncryption, VersionedFieldEncryption};
+pub use encryption::{
+    FieldEncryption, VersionedFieldEncryption,
+    data_key::{DataKeyConfig, DataKeyManager, DataKeyRotationTask},
+};
+#[cfg(feature = "aws")]
+pub use secrets_manager::backends::AwsSecretsManagerBackend;
 pub use secrets_manager::{
-    LeaseRenewalTask, SecretsBackendConfig, SecretsError, SecretsManager, VaultAuth,
-    backends::{EnvBackend, FileBackend, VaultBackend},
+    LeaseRenewalTask, SecretReference, SecretReferenceResolver, SecretsBackendConfig, SecretsError,
+    SecretsManager, VaultAuth,
+    backends::{EnvBackend, FileBackend, GcpSecretManagerBackend, VaultBackend},
     create_secrets_manager,
     types::{Secret, SecretsBackend},
 };
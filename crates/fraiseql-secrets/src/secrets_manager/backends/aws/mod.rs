@@ -0,0 +1,122 @@
+//! Backend for AWS Secrets Manager.
+//!
+//! Credentials and region come from the standard AWS provider chain
+//! (environment, shared profile, IMDS/ECS task role). A secret name may be a
+//! secret name or ARN; the `AWSCURRENT` version is read.
+
+#[cfg(test)]
+mod tests;
+
+use aws_sdk_secretsmanager::{
+    Client, error::SdkError, operation::get_secret_value::GetSecretValueError,
+};
+use chrono::{Duration, Utc};
+
+use crate::secrets_manager::{SecretsBackend, SecretsError};
+
+/// How long a fetched value is reported as valid. Secrets Manager values do
+/// not expire; the TTL only tells callers when to re-read `AWSCURRENT`.
+const VALUE_TTL_SECS: i64 = 300;
+
+/// Secrets backend reading from AWS Secrets Manager.
+#[derive(Debug, Clone)]
+pub struct AwsSecretsManagerBackend {
+    client: Client,
+}
+
+#[async_trait::async_trait]
+impl SecretsBackend for AwsSecretsManagerBackend {
+    fn name(&self) -> &'static str {
+        "aws"
+    }
+
+    async fn health_check(&self) -> Result<(), SecretsError> {
+        self.client
+            .list_secrets()
+            .max_results(1)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| SecretsError::ConnectionError(format!("Secrets Manager unhealthy: {e}")))
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<String, SecretsError> {
+        if name.is_empty() {
+            return Err(SecretsError::ValidationError("Secret name cannot be empty".to_string()));
+        }
+        let output = self
+            .client
+            .get_secret_value()
+            .secret_id(name)
+            .send()
+            .await
+            .map_err(|e| map_get_error(name, e))?;
+        secret_from_parts(output.secret_string(), output.secret_binary().map(AsRef::as_ref), name)
+    }
+
+    async fn get_secret_with_expiry(
+        &self,
+        name: &str,
+    ) -> Result<(String, chrono::DateTime<Utc>), SecretsError> {
+        let secret = self.get_secret(name).await?;
+        Ok((secret, Utc::now() + Duration::seconds(VALUE_TTL_SECS)))
+    }
+
+    async fn rotate_secret(&self, name: &str) -> Result<String, SecretsError> {
+        // Rotation runs in Secrets Manager's own rotation Lambda; re-reading
+        // `AWSCURRENT` picks up its result.
+        self.get_secret(name).await
+    }
+}
+
+impl AwsSecretsManagerBackend {
+    /// Create a backend from the default AWS configuration, optionally
+    /// overriding the region and the endpoint (e.g. `LocalStack`).
+    pub async fn new(region: Option<&str>, endpoint: Option<&str>) -> Self {
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        if let Some(region) = region {
+            loader = loader.region(aws_config::Region::new(region.to_owned()));
+        }
+        if let Some(endpoint) = endpoint {
+            loader = loader.endpoint_url(endpoint);
+        }
+        Self::from_client(Client::new(&loader.load().await))
+    }
+
+    /// Wrap an already configured client.
+    #[must_use]
+    pub const fn from_client(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+fn map_get_error(name: &str, error: SdkError<GetSecretValueError>) -> SecretsError {
+    match error.as_service_error() {
+        Some(e) if e.is_resource_not_found_exception() => {
+            SecretsError::NotFound(format!("Secret {name} not found"))
+        },
+        Some(e) if e.is_decryption_failure() => {
+            SecretsError::EncryptionError(format!("Secret {name} could not be decrypted: {e}"))
+        },
+        Some(e) if e.is_invalid_parameter_exception() || e.is_invalid_request_exception() => {
+            SecretsError::ValidationError(format!("Secret {name}: {e}"))
+        },
+        Some(e) => SecretsError::BackendError(format!("Secret {name}: {e}")),
+        None => SecretsError::ConnectionError(format!("Secrets Manager unreachable: {error}")),
+    }
+}
+
+/// A secret's value: `SecretString` when present, else UTF-8 `SecretBinary`.
+pub(super) fn secret_from_parts(
+    string: Option<&str>,
+    binary: Option<&[u8]>,
+    name: &str,
+) -> Result<String, SecretsError> {
+    if let Some(string) = string {
+        return Ok(string.to_string());
+    }
+    let binary =
+        binary.ok_or_else(|| SecretsError::BackendError(format!("Secret {name} has no value")))?;
+    String::from_utf8(binary.to_vec())
+        .map_err(|_| SecretsError::BackendError(format!("Secret {name} is not valid UTF-8")))
+}
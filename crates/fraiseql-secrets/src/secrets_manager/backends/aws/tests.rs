@@ -0,0 +1,23 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use super::secret_from_parts;
+use crate::secrets_manager::SecretsError;
+
+#[test]
+fn secret_string_wins_over_binary() {
+    assert_eq!(secret_from_parts(Some("text"), Some(b"bin"), "s").unwrap(), "text");
+}
+
+#[test]
+fn binary_secret_is_decoded_as_utf8() {
+    assert_eq!(secret_from_parts(None, Some(b"bin"), "s").unwrap(), "bin");
+    assert!(matches!(
+        secret_from_parts(None, Some(&[0xff, 0xfe]), "s"),
+        Err(SecretsError::BackendError(_))
+    ));
+}
+
+#[test]
+fn missing_value_is_an_error() {
+    assert!(matches!(secret_from_parts(None, None, "s"), Err(SecretsError::BackendError(_))));
+}
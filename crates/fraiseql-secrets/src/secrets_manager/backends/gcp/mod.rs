@@ -0,0 +1,273 @@
+//! Backend for Google Cloud Secret Manager.
+//!
+//! Secrets are read with the `versions/{v}:access` REST call. A name is either
+//! a bare secret id (`db-password`, resolved to the `latest` version in the
+//! configured project), a secret id with a version (`db-password/versions/3`),
+//! or a full resource name (`projects/p/secrets/db-password/versions/latest`).
+//!
+//! Authentication uses, in order:
+//! 1. `GOOGLE_CLOUD_TOKEN` — a static bearer token;
+//! 2. the GCE/GKE metadata server's default service-account token, cached until shortly before it
+//!    expires.
+
+#[cfg(test)]
+mod tests;
+
+use std::time::{Duration as StdDuration, Instant};
+
+use base64::Engine as _;
+use chrono::{Duration, Utc};
+use reqwest::StatusCode;
+use tokio::sync::RwLock;
+use zeroize::Zeroizing;
+
+use crate::secrets_manager::{SecretsBackend, SecretsError};
+
+/// Production Secret Manager API base URL.
+const DEFAULT_API_BASE: &str = "https://secretmanager.googleapis.com";
+
+/// Metadata-server endpoint for the default service account's access token.
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// How long a fetched value is reported as valid. Secret Manager versions do
+/// not expire; the TTL only tells callers when to re-read `latest`.
+const VALUE_TTL_SECS: i64 = 300;
+
+/// Refresh a metadata token this long before its reported expiry.
+const TOKEN_REFRESH_MARGIN: StdDuration = StdDuration::from_secs(60);
+
+enum GcpAuth {
+    Static(Zeroizing<String>),
+    Metadata {
+        token: RwLock<Option<(Zeroizing<String>, Instant)>>,
+    },
+}
+
+/// Secrets backend reading from Google Cloud Secret Manager.
+pub struct GcpSecretManagerBackend {
+    project:  String,
+    api_base: String,
+    auth:     GcpAuth,
+    client:   reqwest::Client,
+}
+
+impl std::fmt::Debug for GcpSecretManagerBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpSecretManagerBackend")
+            .field("project", &self.project)
+            .field("api_base", &self.api_base)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsBackend for GcpSecretManagerBackend {
+    fn name(&self) -> &'static str {
+        "gcp"
+    }
+
+    async fn health_check(&self) -> Result<(), SecretsError> {
+        let url = format!("{}/v1/projects/{}/secrets?pageSize=1", self.api_base, self.project);
+        let resp = self.get(&url).await?;
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            Err(SecretsError::ConnectionError(format!(
+                "Secret Manager unhealthy: {}",
+                resp.status()
+            )))
+        }
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<String, SecretsError> {
+        let resource = self.resource_name(name)?;
+        let url = format!("{}/v1/{resource}:access", self.api_base);
+        let resp = self.get(&url).await?;
+        match resp.status() {
+            status if status.is_success() => {},
+            StatusCode::NOT_FOUND => {
+                return Err(SecretsError::NotFound(format!("Secret {resource} not found")));
+            },
+            status if status.is_server_error() => {
+                return Err(SecretsError::ConnectionError(format!(
+                    "Secret Manager returned {status} for {resource}"
+                )));
+            },
+            status => {
+                return Err(SecretsError::BackendError(format!(
+                    "Secret Manager returned {status} for {resource}"
+                )));
+            },
+        }
+        let body: serde_json::Value = resp.json().await.map_err(|e| {
+            SecretsError::BackendError(format!("Invalid Secret Manager response: {e}"))
+        })?;
+        decode_payload(&body, &resource)
+    }
+
+    async fn get_secret_with_expiry(
+        &self,
+        name: &str,
+    ) -> Result<(String, chrono::DateTime<Utc>), SecretsError> {
+        let secret = self.get_secret(name).await?;
+        Ok((secret, Utc::now() + Duration::seconds(VALUE_TTL_SECS)))
+    }
+
+    async fn rotate_secret(&self, name: &str) -> Result<String, SecretsError> {
+        // Rotation in Secret Manager is adding a new version; re-reading picks
+        // it up when the name tracks `latest`.
+        self.get_secret(name).await
+    }
+}
+
+impl GcpSecretManagerBackend {
+    /// Create a backend for `project`, authenticating with `GOOGLE_CLOUD_TOKEN`
+    /// when set and the metadata server otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` if `project` is empty or not a
+    /// valid project id.
+    pub fn new(project: impl Into<String>) -> Result<Self, SecretsError> {
+        let auth = match std::env::var("GOOGLE_CLOUD_TOKEN") {
+            Ok(token) if !token.is_empty() => GcpAuth::Static(Zeroizing::new(token)),
+            _ => GcpAuth::Metadata {
+                token: RwLock::new(None),
+            },
+        };
+        Self::with_auth(project.into(), auth)
+    }
+
+    /// Create a backend for `project` that authenticates with a fixed bearer token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` if `project` is empty or not a
+    /// valid project id.
+    pub fn with_token(
+        project: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Result<Self, SecretsError> {
+        Self::with_auth(project.into(), GcpAuth::Static(Zeroizing::new(token.into())))
+    }
+
+    /// Override the API base URL (emulators and tests).
+    #[must_use]
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The project bare secret ids resolve in.
+    #[must_use]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+
+    fn with_auth(project: String, auth: GcpAuth) -> Result<Self, SecretsError> {
+        if project.is_empty() || !project.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+            return Err(SecretsError::ValidationError(format!(
+                "Invalid GCP project id '{project}'"
+            )));
+        }
+        Ok(Self {
+            project,
+            api_base: DEFAULT_API_BASE.to_string(),
+            auth,
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Full `projects/*/secrets/*/versions/*` resource name for `name`.
+    pub(super) fn resource_name(&self, name: &str) -> Result<String, SecretsError> {
+        let valid = !name.is_empty()
+            && !name.split('/').any(|segment| segment.is_empty() || segment == "..")
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'/'));
+        if !valid {
+            return Err(SecretsError::ValidationError(format!("Invalid GCP secret name '{name}'")));
+        }
+        let qualified = if name.starts_with("projects/") {
+            name.to_string()
+        } else {
+            format!("projects/{}/secrets/{name}", self.project)
+        };
+        if qualified.contains("/versions/") {
+            Ok(qualified)
+        } else {
+            Ok(format!("{qualified}/versions/latest"))
+        }
+    }
+
+    async fn get(&self, url: &str) -> Result<reqwest::Response, SecretsError> {
+        let token = self.access_token().await?;
+        self.client
+            .get(url)
+            .bearer_auth(token.as_str())
+            .send()
+            .await
+            .map_err(|e| SecretsError::ConnectionError(format!("Secret Manager unreachable: {e}")))
+    }
+
+    async fn access_token(&self) -> Result<Zeroizing<String>, SecretsError> {
+        let token = match &self.auth {
+            GcpAuth::Static(token) => return Ok(token.clone()),
+            GcpAuth::Metadata { token } => token,
+        };
+        if let Some((cached, expires_at)) = token.read().await.as_ref() {
+            if Instant::now() < *expires_at {
+                return Ok(cached.clone());
+            }
+        }
+
+        let mut guard = token.write().await;
+        let resp = self
+            .client
+            .get(METADATA_TOKEN_URL)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await
+            .map_err(|e| {
+                SecretsError::ConnectionError(format!(
+                    "GCP metadata server unreachable (set GOOGLE_CLOUD_TOKEN outside GCP): {e}"
+                ))
+            })?;
+        if !resp.status().is_success() {
+            return Err(SecretsError::ConnectionError(format!(
+                "GCP metadata server returned {}",
+                resp.status()
+            )));
+        }
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| SecretsError::BackendError(format!("Invalid metadata response: {e}")))?;
+        let access_token = body["access_token"].as_str().ok_or_else(|| {
+            SecretsError::BackendError("Metadata response has no access_token".to_string())
+        })?;
+        let expires_in = StdDuration::from_secs(body["expires_in"].as_u64().unwrap_or(0));
+        let access_token = Zeroizing::new(access_token.to_string());
+        *guard = Some((
+            access_token.clone(),
+            Instant::now() + expires_in.saturating_sub(TOKEN_REFRESH_MARGIN),
+        ));
+        Ok(access_token)
+    }
+}
+
+/// Decode the base64 `payload.data` of an `:access` response as UTF-8.
+pub(super) fn decode_payload(
+    body: &serde_json::Value,
+    resource: &str,
+) -> Result<String, SecretsError> {
+    let data = body["payload"]["data"].as_str().ok_or_else(|| {
+        SecretsError::BackendError(format!("Secret {resource} response has no payload"))
+    })?;
+    let bytes = base64::engine::general_purpose::STANDARD.decode(data).map_err(|e| {
+        SecretsError::BackendError(format!("Secret {resource} payload is not base64: {e}"))
+    })?;
+    String::from_utf8(bytes)
+        .map_err(|_| SecretsError::BackendError(format!("Secret {resource} is not valid UTF-8")))
+}
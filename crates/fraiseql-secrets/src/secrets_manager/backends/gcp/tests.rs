@@ -0,0 +1,87 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+use super::{GcpSecretManagerBackend, decode_payload};
+use crate::secrets_manager::{SecretsBackend, SecretsError};
+
+fn backend() -> GcpSecretManagerBackend {
+    GcpSecretManagerBackend::with_token("my-project", "tok").unwrap()
+}
+
+#[test]
+fn bare_secret_id_resolves_to_latest_version() {
+    assert_eq!(
+        backend().resource_name("db-password").unwrap(),
+        "projects/my-project/secrets/db-password/versions/latest"
+    );
+}
+
+#[test]
+fn explicit_version_and_full_names_are_kept() {
+    let backend = backend();
+    assert_eq!(
+        backend.resource_name("db-password/versions/3").unwrap(),
+        "projects/my-project/secrets/db-password/versions/3"
+    );
+    assert_eq!(
+        backend.resource_name("projects/other/secrets/api-key").unwrap(),
+        "projects/other/secrets/api-key/versions/latest"
+    );
+}
+
+#[test]
+fn traversal_and_odd_characters_are_rejected() {
+    let backend = backend();
+    for name in ["", "../x", "a//b", "a b", "a?b"] {
+        assert!(
+            matches!(backend.resource_name(name), Err(SecretsError::ValidationError(_))),
+            "{name:?} must be rejected"
+        );
+    }
+}
+
+#[test]
+fn invalid_project_is_rejected() {
+    assert!(GcpSecretManagerBackend::with_token("", "tok").is_err());
+    assert!(GcpSecretManagerBackend::with_token("a/b", "tok").is_err());
+}
+
+#[test]
+fn payload_is_base64_decoded() {
+    let body = serde_json::json!({ "payload": { "data": "aHVudGVyMg==" } });
+    assert_eq!(decode_payload(&body, "r").unwrap(), "hunter2");
+    assert!(decode_payload(&serde_json::json!({}), "r").is_err());
+}
+
+#[tokio::test]
+async fn get_secret_calls_the_access_endpoint() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/projects/my-project/secrets/db-password/versions/latest:access"))
+        .and(header("authorization", "Bearer tok"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "payload": { "data": "aHVudGVyMg==" } })),
+        )
+        .mount(&server)
+        .await;
+
+    let backend = backend().with_api_base(server.uri());
+    assert_eq!(backend.get_secret("db-password").await.unwrap(), "hunter2");
+}
+
+#[tokio::test]
+async fn missing_secret_is_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let backend = backend().with_api_base(server.uri());
+    assert!(matches!(backend.get_secret("absent").await, Err(SecretsError::NotFound(_))));
+}
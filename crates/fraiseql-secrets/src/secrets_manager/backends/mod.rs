@@ -1,9 +1,15 @@
 //! Multiple backend implementations for secrets management
 
+#[cfg(feature = "aws")]
+pub mod aws;
 pub mod env;
 pub mod file;
+pub mod gcp;
 pub mod vault;
 
+#[cfg(feature = "aws")]
+pub use aws::AwsSecretsManagerBackend;
 pub use env::EnvBackend;
 pub use file::FileBackend;
+pub use gcp::GcpSecretManagerBackend;
 pub use vault::VaultBackend;
@@ -1,9 +1,14 @@
-//! Abstraction layer for multiple secrets backends (Vault, Environment Variables, File)
+//! Abstraction layer for multiple secrets backends (Vault, AWS, GCP, Environment Variables, File)
 //!
 //! This module provides a unified interface to manage secrets from different sources:
-//! - `HashiCorp` Vault for dynamic credentials
+//! - `HashiCorp` Vault (KV v2 and dynamic credentials)
+//! - AWS Secrets Manager (behind the `aws` feature)
+//! - Google Cloud Secret Manager
 //! - Environment variables for configuration
 //! - Local files for development/testing
+//!
+//! [`references`] resolves `${secret:path}` placeholders in configuration
+//! against any of these backends.
 
 use std::{fmt, path::PathBuf, sync::Arc, time::Duration};
 
@@ -12,9 +17,13 @@ use tracing::{info, warn};
 use zeroize::Zeroizing;
 
 pub mod backends;
+pub mod references;
 pub mod types;
 
-pub use backends::{EnvBackend, FileBackend, VaultBackend};
+#[cfg(feature = "aws")]
+pub use backends::AwsSecretsManagerBackend;
+pub use backends::{EnvBackend, FileBackend, GcpSecretManagerBackend, VaultBackend};
+pub use references::{SecretReference, SecretReferenceResolver};
 pub use types::{Secret, SecretsBackend};
 
 /// Configuration for selecting and initializing a secrets backend.
@@ -39,6 +48,87 @@ pub enum SecretsBackendConfig {
         /// Whether to verify TLS certificates.
         tls_verify: bool,
     },
+    /// Read secrets from AWS Secrets Manager (requires the `aws` feature).
+    Aws {
+        /// Region override; the default provider chain is used when `None`.
+        region:   Option<String>,
+        /// Endpoint override (e.g. `LocalStack`).
+        endpoint: Option<String>,
+    },
+    /// Read secrets from Google Cloud Secret Manager.
+    Gcp {
+        /// Project that bare secret ids resolve in.
+        project: String,
+    },
+}
+
+impl SecretsBackendConfig {
+    /// Build a backend configuration from `FRAISEQL_SECRETS_BACKEND`.
+    ///
+    /// | `FRAISEQL_SECRETS_BACKEND` | Other variables |
+    /// |---|---|
+    /// | `env` | — |
+    /// | `file` | `FRAISEQL_SECRETS_DIR` (required) |
+    /// | `vault` | `VAULT_ADDR`, and `VAULT_TOKEN` or `VAULT_ROLE_ID` + `VAULT_SECRET_ID`; optional `VAULT_NAMESPACE`, `VAULT_SKIP_VERIFY` |
+    /// | `aws` | optional `AWS_REGION`, `FRAISEQL_SECRETS_AWS_ENDPOINT` |
+    /// | `gcp` | `GOOGLE_CLOUD_PROJECT` |
+    ///
+    /// Returns `Ok(None)` when `FRAISEQL_SECRETS_BACKEND` is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` for an unknown backend name or
+    /// when a variable the backend requires is missing.
+    pub fn from_env() -> Result<Option<Self>, SecretsError> {
+        let Some(backend) = non_empty_env("FRAISEQL_SECRETS_BACKEND") else {
+            return Ok(None);
+        };
+        let config = match backend.to_ascii_lowercase().as_str() {
+            "env" => Self::Env,
+            "file" => Self::File {
+                path: PathBuf::from(required_env("FRAISEQL_SECRETS_DIR")?),
+            },
+            "vault" => {
+                let auth = if let Some(token) = non_empty_env("VAULT_TOKEN") {
+                    VaultAuth::Token(Zeroizing::new(token))
+                } else {
+                    VaultAuth::AppRole {
+                        role_id:   required_env("VAULT_ROLE_ID")?,
+                        secret_id: Zeroizing::new(required_env("VAULT_SECRET_ID")?),
+                    }
+                };
+                Self::Vault {
+                    addr: required_env("VAULT_ADDR")?,
+                    auth,
+                    namespace: non_empty_env("VAULT_NAMESPACE"),
+                    tls_verify: !non_empty_env("VAULT_SKIP_VERIFY")
+                        .is_some_and(|v| matches!(v.as_str(), "1" | "true")),
+                }
+            },
+            "aws" => Self::Aws {
+                region:   non_empty_env("AWS_REGION"),
+                endpoint: non_empty_env("FRAISEQL_SECRETS_AWS_ENDPOINT"),
+            },
+            "gcp" => Self::Gcp {
+                project: required_env("GOOGLE_CLOUD_PROJECT")?,
+            },
+            other => {
+                return Err(SecretsError::ValidationError(format!(
+                    "Unknown FRAISEQL_SECRETS_BACKEND '{other}' (expected env, file, vault, aws \
+                     or gcp)"
+                )));
+            },
+        };
+        Ok(Some(config))
+    }
+}
+
+fn non_empty_env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+fn required_env(name: &str) -> Result<String, SecretsError> {
+    non_empty_env(name).ok_or_else(|| SecretsError::ValidationError(format!("{name} must be set")))
 }
 
 /// Vault authentication methods.
@@ -78,7 +168,7 @@ impl fmt::Debug for VaultAuth {
 /// # Errors
 ///
 /// Returns `SecretsError` if the backend cannot be initialized (e.g., Vault
-/// `AppRole` login fails).
+/// `AppRole` login fails, or `Aws` is requested without the `aws` feature).
 pub async fn create_secrets_manager(
     config: SecretsBackendConfig,
 ) -> Result<Arc<SecretsManager>, SecretsError> {
@@ -110,6 +200,21 @@ pub async fn create_secrets_manager(
             vault = vault.with_tls_verify(tls_verify);
             Arc::new(vault)
         },
+        #[cfg(feature = "aws")]
+        SecretsBackendConfig::Aws { region, endpoint } => {
+            info!(region = ?region, "Initializing AWS Secrets Manager backend");
+            Arc::new(AwsSecretsManagerBackend::new(region.as_deref(), endpoint.as_deref()).await)
+        },
+        #[cfg(not(feature = "aws"))]
+        SecretsBackendConfig::Aws { .. } => {
+            return Err(SecretsError::BackendError(
+                "AWS Secrets Manager backend requires the `aws` feature".to_string(),
+            ));
+        },
+        SecretsBackendConfig::Gcp { project } => {
+            info!(project = %project, "Initializing GCP Secret Manager backend");
+            Arc::new(GcpSecretManagerBackend::new(project)?)
+        },
     };
     Ok(Arc::new(SecretsManager::new(backend)))
 }
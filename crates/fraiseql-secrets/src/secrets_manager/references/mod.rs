@@ -0,0 +1,202 @@
+//! `${secret:path}` references in configuration values.
+//!
+//! A configuration string may embed one or more references:
+//!
+//! - `${secret:db/password}` — the whole value of secret `db/password`;
+//! - `${secret:db/creds#password}` — field `password` of a secret whose value is a JSON object
+//!   (Vault KV v2 entries and AWS key/value secrets are stored this way).
+//!
+//! [`SecretReferenceResolver`] substitutes them through a [`SecretsManager`],
+//! caching each value for a TTL — or until the backend reports it expires, if
+//! sooner — so configuration reloads pick up rotated secrets without hitting
+//! the backend on every read.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+use zeroize::Zeroizing;
+
+use super::{SecretsError, SecretsManager};
+
+/// Opening delimiter of a secret reference.
+const PREFIX: &str = "${secret:";
+
+/// A parsed `${secret:path#field}` reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretReference {
+    /// Secret name passed to the backend.
+    pub path:  String,
+    /// JSON field selected from the secret's value, if any.
+    pub field: Option<String>,
+}
+
+impl SecretReference {
+    /// Parse the inside of a reference (`path` or `path#field`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` if the path or field is empty.
+    pub fn parse(inner: &str) -> Result<Self, SecretsError> {
+        let (path, field) = match inner.split_once('#') {
+            Some((path, field)) => (path, Some(field)),
+            None => (inner, None),
+        };
+        if path.is_empty() || field.is_some_and(str::is_empty) {
+            return Err(SecretsError::ValidationError(format!(
+                "Invalid secret reference '{PREFIX}{inner}}}'"
+            )));
+        }
+        Ok(Self {
+            path:  path.to_string(),
+            field: field.map(str::to_string),
+        })
+    }
+
+    /// Whether `value` contains at least one reference.
+    #[must_use]
+    pub fn is_referenced(value: &str) -> bool {
+        value.contains(PREFIX)
+    }
+
+    /// Select this reference's field from a fetched secret value.
+    fn select(&self, value: &str) -> Result<String, SecretsError> {
+        let Some(field) = &self.field else {
+            return Ok(value.to_string());
+        };
+        let json: serde_json::Value = serde_json::from_str(value).map_err(|_| {
+            SecretsError::ValidationError(format!(
+                "Secret {} is not a JSON object, cannot select field '{field}'",
+                self.path
+            ))
+        })?;
+        match json.get(field) {
+            Some(serde_json::Value::String(s)) => Ok(s.clone()),
+            Some(other) => Ok(other.to_string()),
+            None => {
+                Err(SecretsError::NotFound(format!("Secret {} has no field '{field}'", self.path)))
+            },
+        }
+    }
+}
+
+/// Resolves `${secret:...}` references with a per-secret TTL cache.
+pub struct SecretReferenceResolver {
+    manager: Arc<SecretsManager>,
+    ttl:     Duration,
+    cache:   DashMap<String, (Zeroizing<String>, Instant)>,
+}
+
+impl std::fmt::Debug for SecretReferenceResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretReferenceResolver")
+            .field("backend", &self.manager.backend_name())
+            .field("ttl", &self.ttl)
+            .field("cached", &self.cache.len())
+            .finish()
+    }
+}
+
+impl SecretReferenceResolver {
+    /// Create a resolver that re-reads a secret once it is older than `ttl`.
+    #[must_use]
+    pub fn new(manager: Arc<SecretsManager>, ttl: Duration) -> Self {
+        Self {
+            manager,
+            ttl,
+            cache: DashMap::new(),
+        }
+    }
+
+    /// Substitute every reference in `value`.
+    ///
+    /// Returns `Ok(None)` when `value` contains no reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::ValidationError` for a malformed reference, or
+    /// the backend's error when a referenced secret cannot be read.
+    pub async fn resolve(&self, value: &str) -> Result<Option<String>, SecretsError> {
+        if !SecretReference::is_referenced(value) {
+            return Ok(None);
+        }
+        let mut out = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find(PREFIX) {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + PREFIX.len()..];
+            let end = after.find('}').ok_or_else(|| {
+                SecretsError::ValidationError(format!("Unterminated secret reference in '{value}'"))
+            })?;
+            let reference = SecretReference::parse(&after[..end])?;
+            out.push_str(&self.lookup(&reference).await?);
+            rest = &after[end + 1..];
+        }
+        out.push_str(rest);
+        Ok(Some(out))
+    }
+
+    /// Substitute references in every string of a JSON document, in place.
+    ///
+    /// Returns the number of strings that were rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Fails on the first reference that cannot be resolved; `value` may then
+    /// be partially rewritten.
+    pub async fn resolve_json(&self, value: &mut serde_json::Value) -> Result<usize, SecretsError> {
+        let mut strings = Vec::new();
+        collect_json_strings(value, &mut strings);
+        let mut resolved = 0;
+        for s in strings {
+            if let Some(replacement) = self.resolve(s).await? {
+                *s = replacement;
+                resolved += 1;
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Drop every cached value, forcing the next lookup to hit the backend.
+    pub fn invalidate(&self) {
+        self.cache.clear();
+    }
+
+    async fn lookup(&self, reference: &SecretReference) -> Result<String, SecretsError> {
+        if let Some(entry) = self.cache.get(&reference.path) {
+            let (value, fresh_until) = entry.value();
+            if Instant::now() < *fresh_until {
+                return reference.select(value);
+            }
+        }
+        let (value, expires_at) = self.manager.get_secret_with_expiry(&reference.path).await?;
+        let backend_ttl = (expires_at - chrono::Utc::now()).to_std().unwrap_or(Duration::ZERO);
+        let value = Zeroizing::new(value);
+        let selected = reference.select(&value)?;
+        self.cache
+            .insert(reference.path.clone(), (value, Instant::now() + self.ttl.min(backend_ttl)));
+        Ok(selected)
+    }
+}
+
+fn collect_json_strings<'a>(value: &'a mut serde_json::Value, out: &mut Vec<&'a mut String>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_json_strings(item, out);
+            }
+        },
+        serde_json::Value::Object(map) => {
+            for item in map.values_mut() {
+                collect_json_strings(item, out);
+            }
+        },
+        _ => {},
+    }
+}
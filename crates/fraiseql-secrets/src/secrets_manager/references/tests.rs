@@ -0,0 +1,150 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+
+use super::{SecretReference, SecretReferenceResolver};
+use crate::secrets_manager::{SecretsBackend, SecretsError, SecretsManager};
+
+/// Backend serving fixed values and counting reads.
+struct CountingBackend {
+    values: HashMap<&'static str, &'static str>,
+    reads:  Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl SecretsBackend for CountingBackend {
+    fn name(&self) -> &'static str {
+        "counting"
+    }
+
+    async fn health_check(&self) -> Result<(), SecretsError> {
+        Ok(())
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<String, SecretsError> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.values
+            .get(name)
+            .map(|v| (*v).to_string())
+            .ok_or_else(|| SecretsError::NotFound(name.to_string()))
+    }
+
+    async fn get_secret_with_expiry(
+        &self,
+        name: &str,
+    ) -> Result<(String, DateTime<Utc>), SecretsError> {
+        Ok((self.get_secret(name).await?, Utc::now() + chrono::Duration::hours(1)))
+    }
+
+    async fn rotate_secret(&self, name: &str) -> Result<String, SecretsError> {
+        self.get_secret(name).await
+    }
+}
+
+fn resolver(ttl: Duration) -> (SecretReferenceResolver, Arc<AtomicUsize>) {
+    let reads = Arc::new(AtomicUsize::new(0));
+    let backend = CountingBackend {
+        values: HashMap::from([
+            ("db/password", "hunter2"),
+            ("db/creds", r#"{"user":"app","password":"s3cret","port":5432}"#),
+        ]),
+        reads:  Arc::clone(&reads),
+    };
+    let manager = Arc::new(SecretsManager::new(Arc::new(backend)));
+    (SecretReferenceResolver::new(manager, ttl), reads)
+}
+
+#[test]
+fn parse_splits_path_and_field() {
+    assert_eq!(
+        SecretReference::parse("db/creds#password").unwrap(),
+        SecretReference {
+            path:  "db/creds".to_string(),
+            field: Some("password".to_string()),
+        }
+    );
+    assert_eq!(SecretReference::parse("db/password").unwrap().field, None);
+    assert!(SecretReference::parse("").is_err());
+    assert!(SecretReference::parse("db/creds#").is_err());
+}
+
+#[tokio::test]
+async fn plain_values_are_left_alone() {
+    let (resolver, reads) = resolver(Duration::from_secs(60));
+    assert_eq!(resolver.resolve("postgres://localhost/app").await.unwrap(), None);
+    assert_eq!(reads.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn embedded_references_and_fields_are_substituted() {
+    let (resolver, _) = resolver(Duration::from_secs(60));
+    let resolved = resolver
+        .resolve("postgres://${secret:db/creds#user}:${secret:db/password}@db:${secret:db/creds#port}/app")
+        .await
+        .unwrap();
+    assert_eq!(resolved.as_deref(), Some("postgres://app:hunter2@db:5432/app"));
+}
+
+#[tokio::test]
+async fn malformed_and_missing_references_fail() {
+    let (resolver, _) = resolver(Duration::from_secs(60));
+    assert!(matches!(
+        resolver.resolve("${secret:db/password").await,
+        Err(SecretsError::ValidationError(_))
+    ));
+    assert!(matches!(
+        resolver.resolve("${secret:absent}").await,
+        Err(SecretsError::NotFound(_))
+    ));
+    assert!(matches!(
+        resolver.resolve("${secret:db/creds#absent}").await,
+        Err(SecretsError::NotFound(_))
+    ));
+    assert!(matches!(
+        resolver.resolve("${secret:db/password#field}").await,
+        Err(SecretsError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn values_are_cached_for_the_ttl() {
+    let (resolver, reads) = resolver(Duration::from_secs(60));
+    resolver.resolve("${secret:db/creds#user}").await.unwrap();
+    resolver.resolve("${secret:db/creds#password}").await.unwrap();
+    assert_eq!(reads.load(Ordering::SeqCst), 1);
+
+    resolver.invalidate();
+    resolver.resolve("${secret:db/creds#user}").await.unwrap();
+    assert_eq!(reads.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn expired_entries_are_refetched() {
+    let (resolver, reads) = resolver(Duration::ZERO);
+    resolver.resolve("${secret:db/password}").await.unwrap();
+    resolver.resolve("${secret:db/password}").await.unwrap();
+    assert_eq!(reads.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn json_documents_are_rewritten_in_place() {
+    let (resolver, _) = resolver(Duration::from_secs(60));
+    let mut doc = serde_json::json!({
+        "url": "https://hooks.example.com",
+        "headers": { "Authorization": "Bearer ${secret:db/password}" },
+        "targets": ["${secret:db/creds#user}", 3],
+    });
+    assert_eq!(resolver.resolve_json(&mut doc).await.unwrap(), 2);
+    assert_eq!(doc["headers"]["Authorization"], "Bearer hunter2");
+    assert_eq!(doc["targets"][0], "app");
+    assert_eq!(doc["url"], "https://hooks.example.com");
+}
@@ -184,3 +184,73 @@ async fn test_create_secrets_manager_env_backend() {
     })
     .await;
 }
+
+#[test]
+fn test_backend_config_from_env() {
+    temp_env::with_vars_unset(["FRAISEQL_SECRETS_BACKEND"], || {
+        assert!(SecretsBackendConfig::from_env().unwrap().is_none());
+    });
+    temp_env::with_vars(
+        [
+            ("FRAISEQL_SECRETS_BACKEND", Some("gcp")),
+            ("GOOGLE_CLOUD_PROJECT", Some("my-project")),
+        ],
+        || {
+            assert!(matches!(
+                SecretsBackendConfig::from_env().unwrap(),
+                Some(SecretsBackendConfig::Gcp { project }) if project == "my-project"
+            ));
+        },
+    );
+    temp_env::with_vars(
+        [
+            ("FRAISEQL_SECRETS_BACKEND", Some("vault")),
+            ("VAULT_ADDR", Some("https://vault:8200")),
+            ("VAULT_TOKEN", Some("s.token")),
+            ("VAULT_SKIP_VERIFY", Some("true")),
+        ],
+        || {
+            assert!(matches!(
+                SecretsBackendConfig::from_env().unwrap(),
+                Some(SecretsBackendConfig::Vault {
+                    tls_verify: false,
+                    auth: VaultAuth::Token(_),
+                    ..
+                })
+            ));
+        },
+    );
+}
+
+#[test]
+fn test_backend_config_from_env_rejects_unknown_or_incomplete() {
+    temp_env::with_vars([("FRAISEQL_SECRETS_BACKEND", Some("keychain"))], || {
+        assert!(matches!(
+            SecretsBackendConfig::from_env(),
+            Err(SecretsError::ValidationError(_))
+        ));
+    });
+    temp_env::with_vars(
+        [
+            ("FRAISEQL_SECRETS_BACKEND", Some("file")),
+            ("FRAISEQL_SECRETS_DIR", None),
+        ],
+        || {
+            assert!(matches!(
+                SecretsBackendConfig::from_env(),
+                Err(SecretsError::ValidationError(_))
+            ));
+        },
+    );
+}
+
+#[cfg(not(feature = "aws"))]
+#[tokio::test]
+async fn test_create_secrets_manager_aws_requires_feature() {
+    let result = create_secrets_manager(SecretsBackendConfig::Aws {
+        region:   None,
+        endpoint: None,
+    })
+    .await;
+    assert!(matches!(result, Err(SecretsError::BackendError(_))));
+}
@@ -0,0 +1,262 @@
+//! Append-only audit trail of admin API mutations.
+//!
+//! [`admin_audit_middleware`] wraps the state-changing admin routers (the
+//! `/api/v1/admin/*` write tiers, the studio admin API and the observer admin API).
+//! Every request that is not a plain read is recorded with the caller's
+//! [`AdminActor`], the action (`METHOD /path`), the response status and — when the
+//! handler attaches an [`AuditedChange`] to its response — the before/after state of
+//! whatever it changed.
+//!
+//! Records always go to the `fraiseql::admin_audit` tracing target. When the server
+//! has a PostgreSQL pool they are also inserted into `_fraiseql_admin_audit`, which a
+//! trigger keeps append-only: `UPDATE`, `DELETE` and `TRUNCATE` are rejected.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{OriginalUri, State},
+    http::{Method, Request, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::middleware::{AdminActor, AdminRole};
+
+#[cfg(test)]
+mod tests;
+
+/// Idempotent DDL for the admin audit table and its append-only guard.
+const ADMIN_AUDIT_SCHEMA_SQL: &str = "\
+CREATE TABLE IF NOT EXISTS _fraiseql_admin_audit (
+    id           BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    occurred_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    actor        TEXT NOT NULL,
+    role         TEXT,
+    action       TEXT NOT NULL,
+    status       SMALLINT NOT NULL,
+    outcome      TEXT NOT NULL,
+    before_state JSONB,
+    after_state  JSONB
+);
+CREATE INDEX IF NOT EXISTS idx_fraiseql_admin_audit_occurred
+    ON _fraiseql_admin_audit (occurred_at);
+CREATE OR REPLACE FUNCTION _fraiseql_admin_audit_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION '_fraiseql_admin_audit is append-only';
+END;
+$$ LANGUAGE plpgsql;
+DROP TRIGGER IF EXISTS _fraiseql_admin_audit_no_update ON _fraiseql_admin_audit;
+CREATE TRIGGER _fraiseql_admin_audit_no_update
+    BEFORE UPDATE OR DELETE ON _fraiseql_admin_audit
+    FOR EACH ROW EXECUTE FUNCTION _fraiseql_admin_audit_append_only();
+DROP TRIGGER IF EXISTS _fraiseql_admin_audit_no_truncate ON _fraiseql_admin_audit;
+CREATE TRIGGER _fraiseql_admin_audit_no_truncate
+    BEFORE TRUNCATE ON _fraiseql_admin_audit
+    FOR EACH STATEMENT EXECUTE FUNCTION _fraiseql_admin_audit_append_only();";
+
+/// Before/after state of an admin mutation, attached by the handler to its response
+/// for the audit layer to record.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditedChange {
+    /// State before the mutation; `None` when the target did not exist.
+    pub before: Option<Value>,
+    /// State after the mutation; `None` when the target no longer exists.
+    pub after:  Option<Value>,
+}
+
+impl AuditedChange {
+    /// Capture `before` and `after`. A value that serializes to JSON `null` (e.g. a
+    /// `None`) is recorded as absent.
+    #[must_use]
+    pub fn new(before: impl Serialize, after: impl Serialize) -> Self {
+        let capture = |value: Value| if value.is_null() { None } else { Some(value) };
+        Self {
+            before: serde_json::to_value(before).ok().and_then(capture),
+            after:  serde_json::to_value(after).ok().and_then(capture),
+        }
+    }
+
+    /// Attach this change to `response`.
+    #[must_use]
+    pub fn attach(self, mut response: Response) -> Response {
+        response.extensions_mut().insert(self);
+        response
+    }
+}
+
+/// How an audited admin request ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The handler completed the action (2xx/3xx).
+    Succeeded,
+    /// The caller was not allowed to perform the action (401/403/429).
+    Denied,
+    /// The action was attempted but failed (any other status).
+    Failed,
+}
+
+impl AuditOutcome {
+    /// Classify a response status.
+    #[must_use]
+    pub fn from_status(status: StatusCode) -> Self {
+        if status.is_success() || status.is_redirection() {
+            Self::Succeeded
+        } else if matches!(
+            status,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+        ) {
+            Self::Denied
+        } else {
+            Self::Failed
+        }
+    }
+
+    /// Stable lowercase name, as stored in the audit table.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Denied => "denied",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// One admin audit record.
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminAuditEntry {
+    /// Caller identity, or `anonymous` when authentication failed.
+    pub actor:   String,
+    /// Caller role, when authenticated.
+    pub role:    Option<AdminRole>,
+    /// `METHOD /path` of the request.
+    pub action:  String,
+    /// Response status code.
+    pub status:  u16,
+    /// How the request ended.
+    pub outcome: AuditOutcome,
+    /// State before the mutation, when the handler captured it.
+    pub before:  Option<Value>,
+    /// State after the mutation, when the handler captured it.
+    pub after:   Option<Value>,
+}
+
+/// Sink for admin audit records.
+///
+/// Without a pool, records are only emitted as tracing events.
+#[derive(Debug, Clone, Default)]
+pub struct AdminAuditLog {
+    pool: Option<sqlx::PgPool>,
+}
+
+impl AdminAuditLog {
+    /// Create an audit log writing to `pool` when one is given.
+    #[must_use]
+    pub const fn new(pool: Option<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+
+    /// Create the audit table and its append-only triggers (idempotent). A no-op
+    /// without a pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns the database error if the schema cannot be created.
+    pub async fn init(&self) -> Result<(), sqlx::Error> {
+        if let Some(ref pool) = self.pool {
+            sqlx::raw_sql(ADMIN_AUDIT_SCHEMA_SQL).execute(pool).await?;
+        }
+        Ok(())
+    }
+
+    /// Record `entry`. A failed insert is logged, never surfaced to the caller: the
+    /// action has already happened by the time it is audited.
+    pub async fn record(&self, entry: &AdminAuditEntry) {
+        tracing::info!(
+            target: "fraiseql::admin_audit",
+            actor = %entry.actor,
+            role = entry.role.map(AdminRole::as_str),
+            action = %entry.action,
+            status = entry.status,
+            outcome = entry.outcome.as_str(),
+            before = entry.before.as_ref().map(tracing::field::display),
+            after = entry.after.as_ref().map(tracing::field::display),
+            "admin action"
+        );
+
+        let Some(ref pool) = self.pool else {
+            return;
+        };
+        let result = sqlx::query(
+            "INSERT INTO _fraiseql_admin_audit \
+             (actor, role, action, status, outcome, before_state, after_state) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(&entry.actor)
+        .bind(entry.role.map(AdminRole::as_str))
+        .bind(&entry.action)
+        .bind(i16::try_from(entry.status).unwrap_or(i16::MAX))
+        .bind(entry.outcome.as_str())
+        .bind(&entry.before)
+        .bind(&entry.after)
+        .execute(pool)
+        .await;
+        if let Err(e) = result {
+            tracing::error!(
+                error = %e,
+                action = %entry.action,
+                actor = %entry.actor,
+                "Failed to write admin audit record"
+            );
+        }
+    }
+}
+
+/// Record every non-read request passing through the admin router it wraps.
+///
+/// Layer it outside the admin authentication middleware so denied attempts are
+/// recorded too.
+pub async fn admin_audit_middleware(
+    State(log): State<Arc<AdminAuditLog>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS) {
+        return next.run(request).await;
+    }
+
+    let path = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map_or_else(|| request.uri().path().to_string(), |uri| uri.path().to_string());
+    let action = format!("{} {path}", request.method());
+    let mut response = next.run(request).await;
+    let entry = audit_entry(action, &mut response);
+    log.record(&entry).await;
+    response
+}
+
+/// Build the audit record for `action` from its response, taking the actor and the
+/// captured change out of the response extensions.
+///
+/// The authentication layer runs inside the audit layer and inserts the actor into
+/// the request it forwards, which the audit layer no longer sees, so the actor is
+/// carried back on the response instead.
+fn audit_entry(action: String, response: &mut Response) -> AdminAuditEntry {
+    let actor = response.extensions_mut().remove::<AdminActor>();
+    let change = response.extensions_mut().remove::<AuditedChange>().unwrap_or_default();
+    let status = response.status();
+    AdminAuditEntry {
+        actor: actor.as_ref().map_or_else(|| "anonymous".to_string(), |a| a.id.clone()),
+        role: actor.map(|a| a.role),
+        action,
+        status: status.as_u16(),
+        outcome: AuditOutcome::from_status(status),
+        before: change.before,
+        after: change.after,
+    }
+}
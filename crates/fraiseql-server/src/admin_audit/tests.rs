@@ -0,0 +1,116 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use serde_json::json;
+use tower::ServiceExt;
+
+use super::{AdminAuditLog, AuditOutcome, AuditedChange, admin_audit_middleware, audit_entry};
+use crate::middleware::{AdminActor, AdminRole, AdminTokenAuthState, admin_token_role_middleware};
+
+const ADMIN_TOKEN: &str = "admin-token-that-is-long-enough-000000";
+const VIEWER_TOKEN: &str = "viewer-token-that-is-long-enough-00000";
+
+#[test]
+fn null_state_is_recorded_as_absent() {
+    let change = AuditedChange::new(None::<u32>, json!({ "enabled": false }));
+
+    assert_eq!(change.before, None);
+    assert_eq!(change.after, Some(json!({ "enabled": false })));
+}
+
+#[test]
+fn outcome_follows_the_response_status() {
+    assert_eq!(AuditOutcome::from_status(StatusCode::ACCEPTED), AuditOutcome::Succeeded);
+    assert_eq!(AuditOutcome::from_status(StatusCode::FORBIDDEN), AuditOutcome::Denied);
+    assert_eq!(AuditOutcome::from_status(StatusCode::TOO_MANY_REQUESTS), AuditOutcome::Denied);
+    assert_eq!(AuditOutcome::from_status(StatusCode::NOT_FOUND), AuditOutcome::Failed);
+}
+
+#[test]
+fn entry_takes_actor_and_change_from_the_response() {
+    let mut response =
+        AuditedChange::new(json!({ "state": "running" }), json!({ "state": "paused" }))
+            .attach(StatusCode::OK.into_response());
+    response.extensions_mut().insert(AdminActor {
+        id:   "user-42".to_string(),
+        role: AdminRole::Operator,
+    });
+
+    let entry = audit_entry(
+        "POST /api/observers/runtime/observers/orders/pause".to_string(),
+        &mut response,
+    );
+
+    assert_eq!(entry.actor, "user-42");
+    assert_eq!(entry.role, Some(AdminRole::Operator));
+    assert_eq!(entry.outcome, AuditOutcome::Succeeded);
+    assert_eq!(entry.before, Some(json!({ "state": "running" })));
+    assert_eq!(entry.after, Some(json!({ "state": "paused" })));
+    assert!(response.extensions().get::<AdminActor>().is_none(), "actor is consumed");
+    assert!(response.extensions().get::<AuditedChange>().is_none(), "change is consumed");
+}
+
+#[test]
+fn unauthenticated_attempts_are_recorded_as_anonymous() {
+    let mut response = StatusCode::UNAUTHORIZED.into_response();
+
+    let entry = audit_entry("POST /api/v1/admin/cache/clear".to_string(), &mut response);
+
+    assert_eq!(entry.actor, "anonymous");
+    assert_eq!(entry.role, None);
+    assert_eq!(entry.outcome, AuditOutcome::Denied);
+    assert_eq!(entry.status, 401);
+}
+
+async fn pause() -> Response {
+    AuditedChange::new(None::<u32>, json!({ "state": "paused" }))
+        .attach(StatusCode::OK.into_response())
+}
+
+fn audited_router() -> Router {
+    let auth = AdminTokenAuthState::new(AdminRole::Operator, 10)
+        .with_token(ADMIN_TOKEN, AdminRole::Admin)
+        .with_token(VIEWER_TOKEN, AdminRole::Viewer);
+    Router::new()
+        .route("/pause", post(pause))
+        .route_layer(middleware::from_fn_with_state(auth, admin_token_role_middleware))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::new(AdminAuditLog::default()),
+            admin_audit_middleware,
+        ))
+}
+
+fn request(token: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/pause")
+        .header("Authorization", format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn audit_layer_consumes_what_the_inner_layers_attached() {
+    let response = audited_router().oneshot(request(ADMIN_TOKEN)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.extensions().get::<AdminActor>().is_none());
+    assert!(response.extensions().get::<AuditedChange>().is_none());
+}
+
+#[tokio::test]
+async fn denied_role_is_audited_with_the_actor() {
+    let response = audited_router().oneshot(request(VIEWER_TOKEN)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(response.extensions().get::<AdminActor>().is_none());
+}
@@ -167,38 +167,55 @@ impl ApiKeyAuthenticator {
 
     /// Authenticate a request using the API key header.
     pub async fn authenticate(&self, headers: &HeaderMap) -> ApiKeyResult {
-        let raw_key = match headers.get(&self.header_name) {
-            Some(v) => match v.to_str() {
-                Ok(s) if !s.is_empty() => s,
-                _ => return ApiKeyResult::NotPresent,
-            },
-            None => return ApiKeyResult::NotPresent,
+        let Some(key) = self.presented_key(headers) else {
+            return ApiKeyResult::NotPresent;
         };
 
+        if let Some(static_key) = self.find_key(key) {
+            debug!(name = %static_key.name, "API key authenticated (static)");
+            let ctx = build_security_context(&static_key.name, &static_key.scopes);
+            return ApiKeyResult::Authenticated(Box::new(ctx));
+        }
+
+        warn!("API key authentication failed: key not found");
+        ApiKeyResult::Invalid
+    }
+
+    /// The API key of a request, if it is present and valid.
+    ///
+    /// Checks the key like [`authenticate`](Self::authenticate) without building a
+    /// security context or logging a rejection, for callers that only need to know
+    /// whether the key can be trusted (e.g. to pick its rate-limit bucket).
+    #[must_use]
+    pub fn verified_key<'a>(&self, headers: &'a HeaderMap) -> Option<&'a str> {
+        self.presented_key(headers).filter(|key| self.find_key(key).is_some())
+    }
+
+    /// The key in the API key header, without its optional `ApiKey ` prefix.
+    fn presented_key<'a>(&self, headers: &'a HeaderMap) -> Option<&'a str> {
+        let raw_key = headers
+            .get(&self.header_name)
+            .and_then(|v| v.to_str().ok())
+            .filter(|s| !s.is_empty())?;
+
         // Strip optional "ApiKey " prefix (case-insensitive, for Authorization
         // header usage). Compare on bytes, not a `str` slice: `raw_key[..7]`
         // panics when byte 7 lands inside a multi-byte UTF-8 char in the
         // attacker-controlled header value (audit H20 class). A matched prefix
         // is ASCII, so the `[7..]` slice that follows is on a char boundary.
-        let key = if raw_key.len() > 7 && raw_key.as_bytes()[..7].eq_ignore_ascii_case(b"apikey ") {
-            &raw_key[7..]
+        if raw_key.len() > 7 && raw_key.as_bytes()[..7].eq_ignore_ascii_case(b"apikey ") {
+            Some(&raw_key[7..])
         } else {
-            raw_key
-        };
-
-        let key_hash = sha256_hash(key.as_bytes());
-
-        // Check static keys with constant-time comparison.
-        for static_key in &self.static_keys {
-            if bool::from(key_hash.ct_eq(&static_key.hash)) {
-                debug!(name = %static_key.name, "API key authenticated (static)");
-                let ctx = build_security_context(&static_key.name, &static_key.scopes);
-                return ApiKeyResult::Authenticated(Box::new(ctx));
-            }
+            Some(raw_key)
         }
+    }
 
-        warn!("API key authentication failed: key not found");
-        ApiKeyResult::Invalid
+    /// The static key whose hash matches `key`, compared in constant time.
+    fn find_key(&self, key: &str) -> Option<&ResolvedStaticKey> {
+        let key_hash = sha256_hash(key.as_bytes());
+        self.static_keys
+            .iter()
+            .find(|static_key| bool::from(key_hash.ct_eq(&static_key.hash)))
     }
 }
 
@@ -127,6 +127,27 @@ impl ArrowDatabaseAdapter for FlightDatabaseAdapter {
             .await
             .map_err(|e: fraiseql_core::error::FraiseQLError| DatabaseError::new(e.to_string()))
     }
+
+    /// Fetches through a server-side cursor, so the next chunk is only read from
+    /// PostgreSQL once the previous one has been taken off `chunks`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError`] if the underlying PostgreSQL query fails.
+    async fn execute_raw_query_chunked(
+        &self,
+        sql: &str,
+        chunk_rows: usize,
+        chunks: tokio::sync::mpsc::Sender<Vec<HashMap<String, serde_json::Value>>>,
+    ) -> Result<(), DatabaseError> {
+        self.inner
+            .execute_raw_query_in_chunks(sql, chunk_rows, |chunk| {
+                let chunks = chunks.clone();
+                async move { chunks.send(chunk).await.is_ok() }
+            })
+            .await
+            .map_err(|e: fraiseql_core::error::FraiseQLError| DatabaseError::new(e.to_string()))
+    }
 }
 
 #[cfg(all(feature = "arrow", feature = "wire-backend"))]
@@ -26,6 +26,8 @@
 //!   `fraiseql_arrow::ArrowDatabaseAdapter`
 //! - [`ExecutorQueryAdapter`]: Wraps `Executor<A>` to implement `fraiseql_arrow::QueryExecutor`
 //!   (type erasure)
+//! - [`FlightRateLimitAdapter`]: Wraps the HTTP `RateLimiter` to implement
+//!   `fraiseql_arrow::FlightRateLimiter`, so Flight calls share the HTTP rate limits
 //! - [`create_flight_service`]: Factory that assembles a configured `FraiseQLFlightService` from
 //!   core adapters
 //!
@@ -37,6 +39,8 @@
 pub mod database_adapter;
 #[cfg(feature = "arrow")]
 pub mod executor_wrapper;
+#[cfg(feature = "arrow")]
+pub mod rate_limit;
 
 #[cfg(test)]
 mod tests;
@@ -54,6 +58,8 @@ use fraiseql_arrow::FraiseQLFlightService;
 use fraiseql_core::db::FraiseWireAdapter;
 #[cfg(all(feature = "arrow", not(feature = "wire-backend")))]
 use fraiseql_core::db::postgres::PostgresAdapter;
+#[cfg(feature = "arrow")]
+pub use rate_limit::FlightRateLimitAdapter;
 
 /// Create an Arrow Flight service with a real database adapter.
 ///
@@ -0,0 +1,52 @@
+//! Per-peer rate limiting of Flight calls with the HTTP server's [`RateLimiter`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use fraiseql_arrow::{FlightPeer, FlightRateLimiter};
+
+use crate::middleware::RateLimiter;
+
+/// Wrapper that adapts the HTTP [`RateLimiter`] to the `FlightRateLimiter` trait.
+///
+/// Flight calls draw from the same token buckets as HTTP requests: a call whose
+/// bearer token authenticates a user takes from that user's `rps_per_user` bucket,
+/// any other call from its remote address's `rps_per_ip` bucket. A call with neither
+/// (an in-process transport) is admitted.
+pub struct FlightRateLimitAdapter {
+    /// The server's rate limiter
+    limiter: Arc<RateLimiter>,
+}
+
+impl FlightRateLimitAdapter {
+    /// Create a new rate limit adapter.
+    ///
+    /// # Arguments
+    /// * `limiter` - The rate limiter of the HTTP server
+    #[must_use]
+    pub const fn new(limiter: Arc<RateLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+// Reason: FlightRateLimiter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl FlightRateLimiter for FlightRateLimitAdapter {
+    /// # Errors
+    ///
+    /// Returns the bucket's `Retry-After` seconds when the peer is over its limit.
+    async fn check(&self, peer: &FlightPeer) -> Result<(), u32> {
+        let result = match (&peer.subject, peer.addr) {
+            (Some(subject), _) => self.limiter.check_user_limit(subject, None).await,
+            (None, Some(addr)) => self.limiter.check_ip_limit(&addr.to_string(), None).await,
+            (None, None) => return Ok(()),
+        };
+        if result.allowed {
+            Ok(())
+        } else {
+            Err(result.retry_after_secs)
+        }
+    }
+}
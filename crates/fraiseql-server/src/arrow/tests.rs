@@ -14,3 +14,55 @@ mod database_adapter_tests {
         // If this compiles, the struct is properly defined
     }
 }
+
+#[cfg(feature = "arrow")]
+mod rate_limit_tests {
+    #![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+    use std::sync::Arc;
+
+    use fraiseql_arrow::{FlightPeer, FlightRateLimiter};
+
+    use super::super::rate_limit::FlightRateLimitAdapter;
+    use crate::middleware::{RateLimitConfig, RateLimiter};
+
+    fn adapter() -> FlightRateLimitAdapter {
+        FlightRateLimitAdapter::new(Arc::new(RateLimiter::new(RateLimitConfig {
+            rps_per_ip: 1,
+            rps_per_user: 1,
+            burst_size: 1,
+            ..RateLimitConfig::default()
+        })))
+    }
+
+    /// Each subject and each address has its own bucket; the second call of a peer
+    /// within the same second is refused.
+    #[tokio::test]
+    async fn test_buckets_are_per_peer() {
+        let adapter = adapter();
+        let user = |subject: &str| FlightPeer {
+            subject: Some(subject.to_string()),
+            addr:    None,
+        };
+        let (alice, bob) = (user("alice"), user("bob"));
+        let anonymous = FlightPeer {
+            subject: None,
+            addr:    Some("10.0.0.1".parse().unwrap()),
+        };
+
+        assert!(adapter.check(&alice).await.is_ok());
+        assert!(adapter.check(&bob).await.is_ok());
+        assert!(adapter.check(&anonymous).await.is_ok());
+        assert!(adapter.check(&alice).await.is_err());
+        assert!(adapter.check(&anonymous).await.is_err());
+    }
+
+    /// A peer with neither a subject nor an address is never limited.
+    #[tokio::test]
+    async fn test_unidentified_peer_is_admitted() {
+        let adapter = adapter();
+        for _ in 0..3 {
+            assert!(adapter.check(&FlightPeer::default()).await.is_ok());
+        }
+    }
+}
@@ -0,0 +1,1189 @@
+//! Scheduled logical backups of the query-side schemas.
+//!
+//! A [`BackupRunner`] takes one backup: it runs `pg_dump --format=custom` over
+//! the configured schemas, optionally seals the archive with AES-256-GCM,
+//! uploads it to the `[backup.storage]` backend, and writes a JSON
+//! [`BackupManifest`] next to it. The manifest is uploaded last, so an archive
+//! without one is an interrupted run and is never offered for restore.
+//!
+//! Archives never sit in memory whole. `pg_dump` output is sealed chunk by
+//! chunk as it is read into a temporary file, which is then uploaded from
+//! disk; restores download to, and decrypt into, temporary files that
+//! `pg_restore` reads. Temporary files go to the system temp directory
+//! (`TMPDIR`), which needs room for about twice the archive.
+//!
+//! [`BackupScheduler`] repeats that every `interval_secs` and prunes archives
+//! past the retention limits. Replicas contend for one Postgres advisory lock,
+//! so a fleet takes one backup per tick rather than one per replica.
+//!
+//! Restores go through [`BackupRunner::restore`] (`fraiseql backup restore`):
+//! the archive is downloaded, checked against the manifest's size and SHA-256,
+//! decrypted, and read back with `pg_restore --list`. A dry run stops there; a
+//! real restore then applies the archive with `pg_restore --clean --if-exists`
+//! in a single transaction.
+//!
+//! # Sealed archive format
+//!
+//! ```text
+//! magic (8) || nonce prefix (7) || chunk 0 || chunk 1 || … || final chunk
+//! ```
+//!
+//! Each chunk seals up to 64 KiB of the dump with AES-256-GCM under the nonce
+//! `prefix || index (u32 BE) || last flag`, so chunks cannot be reordered or
+//! dropped, and a truncated archive lacks its final chunk.
+//!
+//! # Storage layout
+//!
+//! ```text
+//! <prefix>/<id>.dump            pg_dump custom-format archive
+//! <prefix>/<id>.dump.enc        … sealed with the backup key
+//! <prefix>/<id>.manifest.json   BackupManifest
+//! ```
+//!
+//! `<id>` is the UTC start time as `YYYYMMDDTHHMMSSZ`, so ids sort
+//! chronologically.
+
+use std::{path::Path, process::Stdio, sync::Arc, time::Duration};
+
+use aes_gcm::{
+    Aes256Gcm,
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use fraiseql_storage::StorageBackend;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tempfile::NamedTempFile;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncWriteExt},
+    process::{ChildStdout, Command},
+    time::MissedTickBehavior,
+};
+use tracing::{debug, error, info, warn};
+use zeroize::Zeroizing;
+
+use crate::config::BackupConfig;
+
+#[cfg(test)]
+mod tests;
+
+/// Leading bytes of a sealed archive: format tag plus a version byte.
+const SEALED_MAGIC: &[u8; 8] = b"FQLBKP\x00\x02";
+
+/// AES-GCM nonce length in bytes.
+const NONCE_LEN: usize = 12;
+
+/// Random per-archive part of every chunk nonce, stored after the magic.
+const NONCE_PREFIX_LEN: usize = 7;
+
+/// Sealed archive header: magic plus nonce prefix.
+const HEADER_LEN: usize = SEALED_MAGIC.len() + NONCE_PREFIX_LEN;
+
+/// AES-GCM authentication tag length in bytes.
+const TAG_LEN: usize = 16;
+
+/// Dump bytes sealed per chunk; also the read size when streaming a dump.
+const SEAL_CHUNK_LEN: usize = 64 * 1024;
+
+/// Size of a sealed chunk that is not the final one.
+const SEALED_CHUNK_LEN: usize = SEAL_CHUNK_LEN + TAG_LEN;
+
+/// Leading bytes of every `pg_dump --format=custom` archive.
+const PG_DUMP_MAGIC: &[u8; 5] = b"PGDMP";
+
+/// Advisory-lock key shared by every replica's scheduler (`"FQLBKP"` in ASCII).
+const BACKUP_LOCK_KEY: i64 = 0x4651_4C42_4B50;
+
+/// Manifest format version written by this build.
+const MANIFEST_VERSION: u32 = 1;
+
+/// `strftime` layout of a backup id.
+const ID_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+const MANIFEST_SUFFIX: &str = ".manifest.json";
+
+const LIST_PAGE_SIZE: usize = 1000;
+
+/// Selector accepted by [`BackupRunner::resolve`] for the most recent backup.
+pub const LATEST: &str = "latest";
+
+/// Backup subsystem error.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BackupError {
+    /// The `[backup]` section or the backup key is unusable.
+    #[error("backup configuration error: {0}")]
+    Config(String),
+
+    /// `pg_dump` or `pg_restore` could not be run or exited non-zero.
+    #[error("{tool} failed: {message}")]
+    Tool {
+        /// Binary that failed.
+        tool:    String,
+        /// Spawn error or the tool's stderr.
+        message: String,
+    },
+
+    /// The object-storage backend rejected an operation.
+    #[error("backup storage error: {0}")]
+    Storage(String),
+
+    /// Sealing or opening an archive failed (wrong key or tampered data).
+    #[error("backup encryption error: {0}")]
+    Encryption(String),
+
+    /// A downloaded archive does not match its manifest.
+    #[error("backup integrity check failed: {0}")]
+    Integrity(String),
+
+    /// A temporary archive file could not be created, written, or read.
+    #[error("backup temporary file error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// No backup matches the requested selector.
+    #[error("backup not found: {0}")]
+    NotFound(String),
+}
+
+/// Metadata stored next to every archive as `<id>.manifest.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Manifest format version.
+    pub version:           u32,
+    /// Backup id (`YYYYMMDDTHHMMSSZ`).
+    pub id:                String,
+    /// Storage key of the archive.
+    pub archive_key:       String,
+    /// When the dump started.
+    pub created_at:        DateTime<Utc>,
+    /// Schemas included in the dump.
+    pub schemas:           Vec<String>,
+    /// `pg_dump` compression level used.
+    pub compression_level: u8,
+    /// Whether the archive is sealed with the backup key.
+    pub encrypted:         bool,
+    /// Size of the stored archive in bytes.
+    pub size_bytes:        u64,
+    /// Hex SHA-256 of the stored archive.
+    pub sha256:            String,
+}
+
+/// Outcome of [`BackupRunner::restore`].
+#[derive(Debug, Clone)]
+pub struct RestoreReport {
+    /// The restored (or verified) backup.
+    pub manifest:    BackupManifest,
+    /// Number of table-of-contents entries `pg_restore --list` reported.
+    pub toc_entries: usize,
+    /// `false` for a dry run: the archive was verified but not applied.
+    pub applied:     bool,
+}
+
+/// A 256-bit AES-GCM key for sealing archives.
+pub struct BackupKey(Zeroizing<[u8; 32]>);
+
+impl BackupKey {
+    /// Parse a hex-encoded 32-byte key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Config`] if the value is not 64 hex characters.
+    pub fn from_hex(value: &str) -> Result<Self, BackupError> {
+        let bytes = Zeroizing::new(hex::decode(value.trim()).map_err(|_| {
+            BackupError::Config("backup encryption key is not valid hex".to_string())
+        })?);
+        let key: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+            BackupError::Config(format!(
+                "backup encryption key must be 32 bytes (64 hex characters), got {} bytes",
+                bytes.len()
+            ))
+        })?;
+        Ok(Self(Zeroizing::new(key)))
+    }
+
+    /// Read a hex-encoded key from the environment variable `var`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Config`] if the variable is unset or malformed.
+    pub fn from_env(var: &str) -> Result<Self, BackupError> {
+        let value = Zeroizing::new(std::env::var(var).map_err(|_| {
+            BackupError::Config(format!("backup encryption key variable `{var}` is not set"))
+        })?);
+        Self::from_hex(&value)
+    }
+}
+
+impl std::fmt::Debug for BackupKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BackupKey(..)")
+    }
+}
+
+/// AES-256-GCM over the chunks of one sealed archive.
+struct ChunkCipher {
+    cipher:  Aes256Gcm,
+    prefix:  [u8; NONCE_PREFIX_LEN],
+    /// Index of the next chunk.
+    counter: u32,
+}
+
+impl ChunkCipher {
+    fn new(key: &BackupKey, prefix: [u8; NONCE_PREFIX_LEN]) -> Result<Self, BackupError> {
+        let cipher = Aes256Gcm::new_from_slice(key.0.as_slice())
+            .map_err(|_| BackupError::Encryption("invalid key length".to_string()))?;
+        Ok(Self {
+            cipher,
+            prefix,
+            counter: 0,
+        })
+    }
+
+    /// The nonce of the next chunk; advances the chunk index.
+    fn next_nonce(&mut self, last: bool) -> Result<[u8; NONCE_LEN], BackupError> {
+        let mut nonce = [0_u8; NONCE_LEN];
+        nonce[..NONCE_PREFIX_LEN].copy_from_slice(&self.prefix);
+        nonce[NONCE_PREFIX_LEN..NONCE_LEN - 1].copy_from_slice(&self.counter.to_be_bytes());
+        nonce[NONCE_LEN - 1] = u8::from(last);
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or_else(|| BackupError::Encryption("archive has too many chunks".to_string()))?;
+        Ok(nonce)
+    }
+
+    fn seal(&mut self, plain: &[u8], last: bool) -> Result<Vec<u8>, BackupError> {
+        let nonce = self.next_nonce(last)?;
+        self.cipher
+            .encrypt(
+                aes_gcm::Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plain,
+                    aad: SEALED_MAGIC,
+                },
+            )
+            .map_err(|_| BackupError::Encryption("AES-256-GCM encryption failed".to_string()))
+    }
+
+    fn open(&mut self, sealed: &[u8], last: bool) -> Result<Vec<u8>, BackupError> {
+        let nonce = self.next_nonce(last)?;
+        self.cipher
+            .decrypt(
+                aes_gcm::Nonce::from_slice(&nonce),
+                Payload {
+                    msg: sealed,
+                    aad: SEALED_MAGIC,
+                },
+            )
+            .map_err(|_| {
+                BackupError::Encryption(
+                    "archive authentication failed (wrong key or tampered data)".to_string(),
+                )
+            })
+    }
+}
+
+/// Seals a dump incrementally into the chunked archive format.
+struct Sealer {
+    cipher:  ChunkCipher,
+    /// Plaintext not yet sealed; a full chunk is held back until more data
+    /// shows it is not the final one.
+    pending: Vec<u8>,
+}
+
+impl Sealer {
+    /// Start an archive, appending its header to `out`.
+    fn new(key: &BackupKey, out: &mut Vec<u8>) -> Result<Self, BackupError> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let mut prefix = [0_u8; NONCE_PREFIX_LEN];
+        prefix.copy_from_slice(&nonce[..NONCE_PREFIX_LEN]);
+        out.extend_from_slice(SEALED_MAGIC);
+        out.extend_from_slice(&prefix);
+        Ok(Self {
+            cipher:  ChunkCipher::new(key, prefix)?,
+            pending: Vec::with_capacity(SEAL_CHUNK_LEN),
+        })
+    }
+
+    /// Seal the next bytes of the dump, appending complete chunks to `out`.
+    fn push(&mut self, mut data: &[u8], out: &mut Vec<u8>) -> Result<(), BackupError> {
+        while !data.is_empty() {
+            if self.pending.len() == SEAL_CHUNK_LEN {
+                out.extend(self.cipher.seal(&self.pending, false)?);
+                self.pending.clear();
+            }
+            let take = (SEAL_CHUNK_LEN - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..take]);
+            data = &data[take..];
+        }
+        Ok(())
+    }
+
+    /// Seal the final chunk (possibly empty) into `out`.
+    fn finish(mut self, out: &mut Vec<u8>) -> Result<(), BackupError> {
+        out.extend(self.cipher.seal(&self.pending, true)?);
+        Ok(())
+    }
+}
+
+/// Opens a sealed archive incrementally.
+struct Opener {
+    cipher:  ChunkCipher,
+    header:  Vec<u8>,
+    /// Sealed bytes not yet opened; a full chunk is held back until more data
+    /// shows it is not the final one.
+    pending: Vec<u8>,
+}
+
+impl Opener {
+    fn new(key: &BackupKey) -> Result<Self, BackupError> {
+        Ok(Self {
+            cipher:  ChunkCipher::new(key, [0; NONCE_PREFIX_LEN])?,
+            header:  Vec::with_capacity(HEADER_LEN),
+            pending: Vec::with_capacity(SEALED_CHUNK_LEN),
+        })
+    }
+
+    /// Open the next bytes of the archive, appending the dump bytes of every
+    /// complete chunk to `out`.
+    fn push(&mut self, mut data: &[u8], out: &mut Vec<u8>) -> Result<(), BackupError> {
+        if self.header.len() < HEADER_LEN {
+            let take = (HEADER_LEN - self.header.len()).min(data.len());
+            self.header.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if !SEALED_MAGIC.starts_with(&self.header[..self.header.len().min(SEALED_MAGIC.len())])
+            {
+                return Err(BackupError::Encryption("not a sealed backup archive".to_string()));
+            }
+            if self.header.len() < HEADER_LEN {
+                return Ok(());
+            }
+            self.cipher.prefix.copy_from_slice(&self.header[SEALED_MAGIC.len()..]);
+        }
+        while !data.is_empty() {
+            if self.pending.len() == SEALED_CHUNK_LEN {
+                out.extend(self.cipher.open(&self.pending, false)?);
+                self.pending.clear();
+            }
+            let take = (SEALED_CHUNK_LEN - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..take]);
+            data = &data[take..];
+        }
+        Ok(())
+    }
+
+    /// Open the final chunk into `out`.
+    fn finish(mut self, out: &mut Vec<u8>) -> Result<(), BackupError> {
+        if self.header.len() < HEADER_LEN || self.pending.len() < TAG_LEN {
+            return Err(BackupError::Encryption("sealed archive is truncated".to_string()));
+        }
+        out.extend(self.cipher.open(&self.pending, true)?);
+        Ok(())
+    }
+}
+
+/// Seal `plain` in memory in the chunked archive format.
+///
+/// Backups stream through [`ArchiveWriter`] instead; this is for small
+/// payloads and tooling.
+///
+/// # Errors
+///
+/// Returns [`BackupError::Encryption`] on an internal cipher failure.
+pub fn seal(plain: &[u8], key: &BackupKey) -> Result<Vec<u8>, BackupError> {
+    let mut sealed = Vec::with_capacity(HEADER_LEN + plain.len() + TAG_LEN);
+    let mut sealer = Sealer::new(key, &mut sealed)?;
+    sealer.push(plain, &mut sealed)?;
+    sealer.finish(&mut sealed)?;
+    Ok(sealed)
+}
+
+/// Open an archive produced by [`seal`] in memory.
+///
+/// # Errors
+///
+/// Returns [`BackupError::Encryption`] if the data is not a sealed archive, was
+/// sealed with another key, or has been modified or truncated.
+pub fn open(sealed: &[u8], key: &BackupKey) -> Result<Vec<u8>, BackupError> {
+    let mut plain = Vec::with_capacity(sealed.len());
+    let mut opener = Opener::new(key)?;
+    opener.push(sealed, &mut plain)?;
+    opener.finish(&mut plain)?;
+    Ok(plain)
+}
+
+/// Decrypt the sealed archive at `src` into `dest`, one read at a time.
+async fn open_file(src: &Path, dest: &Path, key: &BackupKey) -> Result<(), BackupError> {
+    let mut input = File::open(src).await?;
+    let mut output = File::create(dest).await?;
+    let mut opener = Opener::new(key)?;
+    let mut buf = vec![0; SEALED_CHUNK_LEN];
+    let mut plain = Vec::with_capacity(SEAL_CHUNK_LEN);
+    loop {
+        let read = input.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        opener.push(&buf[..read], &mut plain)?;
+        output.write_all(&plain).await?;
+        plain.clear();
+    }
+    opener.finish(&mut plain)?;
+    output.write_all(&plain).await?;
+    output.flush().await?;
+    Ok(())
+}
+
+/// Hex SHA-256 of the file at `path`, read in chunks.
+async fn file_sha256(path: &Path) -> Result<String, BackupError> {
+    let mut file = File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; SEALED_CHUNK_LEN];
+    loop {
+        let read = file.read(&mut buf).await?;
+        if read == 0 {
+            return Ok(hex::encode(hasher.finalize()));
+        }
+        hasher.update(&buf[..read]);
+    }
+}
+
+/// Whether the file at `path` starts with the `pg_dump` custom-format magic.
+async fn is_pg_dump(path: &Path) -> Result<bool, BackupError> {
+    let mut head = [0_u8; PG_DUMP_MAGIC.len()];
+    match File::open(path).await?.read_exact(&mut head).await {
+        Ok(_) => Ok(&head == PG_DUMP_MAGIC),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// A new temporary file for an archive in flight; deleted when dropped.
+fn temp_archive() -> Result<NamedTempFile, BackupError> {
+    Ok(tempfile::Builder::new().prefix("fraiseql-backup-").tempfile()?)
+}
+
+/// Where a [`DumpTool`] writes a dump as it is produced.
+///
+/// The dump is sealed when a backup key is configured and written to a
+/// temporary file, hashing the stored bytes on the way, so only one chunk is
+/// held in memory at a time.
+pub struct ArchiveWriter {
+    file:   File,
+    sealer: Option<Sealer>,
+    sha256: Sha256,
+    size:   u64,
+}
+
+impl ArchiveWriter {
+    /// Start an archive in the file at `path`, sealed with `key` if given.
+    async fn create(path: &Path, key: Option<&BackupKey>) -> Result<Self, BackupError> {
+        let mut writer = Self {
+            file:   File::create(path).await?,
+            sealer: None,
+            sha256: Sha256::new(),
+            size:   0,
+        };
+        if let Some(key) = key {
+            let mut header = Vec::with_capacity(HEADER_LEN);
+            writer.sealer = Some(Sealer::new(key, &mut header)?);
+            writer.store(&header).await?;
+        }
+        Ok(writer)
+    }
+
+    /// Append the next bytes of the dump.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Encryption`] if sealing fails and
+    /// [`BackupError::Io`] if the temporary file cannot be written.
+    pub async fn write(&mut self, data: &[u8]) -> Result<(), BackupError> {
+        match &mut self.sealer {
+            Some(sealer) => {
+                let mut sealed = Vec::with_capacity(data.len() + TAG_LEN);
+                sealer.push(data, &mut sealed)?;
+                self.store(&sealed).await
+            },
+            None => self.store(data).await,
+        }
+    }
+
+    /// Complete the archive; returns its size and hex SHA-256.
+    async fn finish(mut self) -> Result<(u64, String), BackupError> {
+        if let Some(sealer) = self.sealer.take() {
+            let mut last = Vec::with_capacity(SEALED_CHUNK_LEN);
+            sealer.finish(&mut last)?;
+            self.store(&last).await?;
+        }
+        self.file.flush().await?;
+        Ok((self.size, hex::encode(self.sha256.finalize())))
+    }
+
+    async fn store(&mut self, bytes: &[u8]) -> Result<(), BackupError> {
+        self.file.write_all(bytes).await?;
+        self.sha256.update(bytes);
+        self.size += u64::try_from(bytes.len()).unwrap_or(u64::MAX);
+        Ok(())
+    }
+}
+
+/// The backup id for a dump started at `at`.
+#[must_use]
+pub fn backup_id(at: DateTime<Utc>) -> String {
+    at.format(ID_FORMAT).to_string()
+}
+
+/// Parse a backup id back into its start time.
+#[must_use]
+pub fn parse_backup_id(id: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(id, ID_FORMAT).ok().map(|t| t.and_utc())
+}
+
+fn archive_key(prefix: &str, id: &str, encrypted: bool) -> String {
+    let suffix = if encrypted { ".dump.enc" } else { ".dump" };
+    format!("{prefix}/{id}{suffix}")
+}
+
+fn manifest_key(prefix: &str, id: &str) -> String {
+    format!("{prefix}/{id}{MANIFEST_SUFFIX}")
+}
+
+/// Select the backups the retention policy expires.
+///
+/// Backups are ranked newest first; one is expired when its rank is at or past
+/// `retain_count` or it is older than `retain_days`. The newest backup is never
+/// expired, so a stalled schedule cannot age out the last good copy.
+#[must_use]
+pub fn expired_backups(
+    manifests: &[BackupManifest],
+    now: DateTime<Utc>,
+    retain_count: Option<usize>,
+    retain_days: Option<u32>,
+) -> Vec<&BackupManifest> {
+    let mut ranked: Vec<&BackupManifest> = manifests.iter().collect();
+    ranked.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    let cutoff = retain_days.map(|days| now - chrono::Duration::days(i64::from(days)));
+    ranked
+        .into_iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(rank, manifest)| {
+            retain_count.is_some_and(|count| *rank >= count)
+                || cutoff.is_some_and(|cutoff| manifest.created_at < cutoff)
+        })
+        .map(|(_, manifest)| manifest)
+        .collect()
+}
+
+/// The external tools a backup shells out to.
+///
+/// [`PgTools`] is the production implementation; tests substitute a fake so
+/// the runner can be exercised without a Postgres installation.
+#[async_trait]
+pub trait DumpTool: Send + Sync {
+    /// Dump `schemas` from `database_url` as a custom-format archive, passing
+    /// it to `out` as it is produced.
+    async fn dump(
+        &self,
+        database_url: &str,
+        schemas: &[String],
+        compression_level: u8,
+        out: &mut ArchiveWriter,
+    ) -> Result<(), BackupError>;
+
+    /// List the table of contents of the archive file (`pg_restore --list`).
+    async fn list_contents(&self, archive: &Path) -> Result<Vec<String>, BackupError>;
+
+    /// Restore the archive file into `database_url`.
+    async fn restore(&self, database_url: &str, archive: &Path) -> Result<(), BackupError>;
+}
+
+/// [`DumpTool`] backed by the `pg_dump` / `pg_restore` binaries.
+#[derive(Debug, Clone)]
+pub struct PgTools {
+    pg_dump:    String,
+    pg_restore: String,
+}
+
+impl PgTools {
+    /// Use the binaries named in the `[backup]` section.
+    #[must_use]
+    pub fn from_config(config: &BackupConfig) -> Self {
+        Self {
+            pg_dump:    config.pg_dump_path.clone(),
+            pg_restore: config.pg_restore_path.clone(),
+        }
+    }
+
+    fn command(
+        program: &str,
+        args: Vec<String>,
+        database_url: Option<&str>,
+        archive: Option<&Path>,
+    ) -> Command {
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(database_url) = database_url {
+            let (url, password) = split_password(database_url);
+            command.arg(format!("--dbname={url}"));
+            if let Some(password) = password {
+                command.env("PGPASSWORD", password);
+            }
+        }
+        if let Some(archive) = archive {
+            command.arg(archive);
+        }
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        command
+    }
+
+    fn tool_error(program: &str, message: String) -> BackupError {
+        BackupError::Tool {
+            tool: program.to_string(),
+            message,
+        }
+    }
+
+    fn exit_error(program: &str, status: std::process::ExitStatus, stderr: &[u8]) -> BackupError {
+        let stderr = String::from_utf8_lossy(stderr);
+        Self::tool_error(program, format!("{} ({status})", stderr.trim()))
+    }
+
+    /// Run a tool to completion and return its stdout.
+    async fn run(
+        program: &str,
+        args: Vec<String>,
+        database_url: Option<&str>,
+        archive: Option<&Path>,
+    ) -> Result<Vec<u8>, BackupError> {
+        let output = Self::command(program, args, database_url, archive)
+            .output()
+            .await
+            .map_err(|e| Self::tool_error(program, e.to_string()))?;
+        if !output.status.success() {
+            return Err(Self::exit_error(program, output.status, &output.stderr));
+        }
+        Ok(output.stdout)
+    }
+
+    /// Pass everything `stdout` yields to `out`, one chunk at a time.
+    async fn copy_dump(
+        mut stdout: ChildStdout,
+        out: &mut ArchiveWriter,
+    ) -> Result<(), BackupError> {
+        let mut buf = vec![0; SEAL_CHUNK_LEN];
+        loop {
+            let read = stdout.read(&mut buf).await?;
+            if read == 0 {
+                return Ok(());
+            }
+            out.write(&buf[..read]).await?;
+        }
+    }
+}
+/// `pg_dump` arguments for a custom-format dump of `schemas` (connection excluded).
+#[must_use]
+pub fn pg_dump_args(schemas: &[String], compression_level: u8) -> Vec<String> {
+    let mut args = vec![
+        "--format=custom".to_string(),
+        format!("--compress={compression_level}"),
+        "--no-owner".to_string(),
+        "--no-privileges".to_string(),
+    ];
+    args.extend(schemas.iter().map(|schema| format!("--schema={schema}")));
+    args
+}
+
+/// `pg_restore` arguments for applying an archive file (connection and file excluded).
+///
+/// Objects are dropped and recreated inside one transaction, so a failed
+/// restore leaves the target untouched.
+#[must_use]
+pub fn pg_restore_args() -> Vec<String> {
+    [
+        "--clean",
+        "--if-exists",
+        "--no-owner",
+        "--no-privileges",
+        "--single-transaction",
+        "--exit-on-error",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
+/// Split the password out of a connection URL.
+///
+/// The password travels to `pg_dump` / `pg_restore` through `PGPASSWORD`
+/// rather than the command line, where any local user could read it.
+fn split_password(database_url: &str) -> (String, Option<String>) {
+    let Ok(mut url) = url::Url::parse(database_url) else {
+        return (database_url.to_string(), None);
+    };
+    let password = url
+        .password()
+        .map(|p| urlencoding::decode(p).map_or_else(|_| p.to_string(), |p| p.into_owned()));
+    if password.is_some() && url.set_password(None).is_err() {
+        return (database_url.to_string(), None);
+    }
+    (url.to_string(), password)
+}
+
+#[async_trait]
+impl DumpTool for PgTools {
+    async fn dump(
+        &self,
+        database_url: &str,
+        schemas: &[String],
+        compression_level: u8,
+        out: &mut ArchiveWriter,
+    ) -> Result<(), BackupError> {
+        let program = self.pg_dump.as_str();
+        let mut child = Self::command(
+            program,
+            pg_dump_args(schemas, compression_level),
+            Some(database_url),
+            None,
+        )
+        .spawn()
+        .map_err(|e| Self::tool_error(program, e.to_string()))?;
+        // Drain stderr alongside stdout: a chatty pg_dump would otherwise block
+        // on a full stderr pipe.
+        let stderr = child.stderr.take();
+        let stderr = tokio::spawn(async move {
+            let mut buf = Vec::new();
+            if let Some(mut stderr) = stderr {
+                let _ = stderr.read_to_end(&mut buf).await;
+            }
+            buf
+        });
+        let copied = match child.stdout.take() {
+            Some(stdout) => Self::copy_dump(stdout, out).await,
+            None => Ok(()),
+        };
+        if copied.is_err() {
+            let _ = child.start_kill();
+        }
+        let status = child.wait().await.map_err(|e| Self::tool_error(program, e.to_string()))?;
+        let stderr = stderr.await.unwrap_or_default();
+        copied?;
+        if !status.success() {
+            return Err(Self::exit_error(program, status, &stderr));
+        }
+        Ok(())
+    }
+
+    async fn list_contents(&self, archive: &Path) -> Result<Vec<String>, BackupError> {
+        let listing =
+            Self::run(&self.pg_restore, vec!["--list".to_string()], None, Some(archive)).await?;
+        Ok(String::from_utf8_lossy(&listing)
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with(';'))
+            .map(str::to_string)
+            .collect())
+    }
+
+    async fn restore(&self, database_url: &str, archive: &Path) -> Result<(), BackupError> {
+        Self::run(&self.pg_restore, pg_restore_args(), Some(database_url), Some(archive))
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Takes, lists, prunes, and restores backups for one `[backup]` section.
+pub struct BackupRunner {
+    config:       BackupConfig,
+    database_url: String,
+    storage:      Arc<StorageBackend>,
+    tools:        Arc<dyn DumpTool>,
+    key:          Option<BackupKey>,
+}
+
+impl BackupRunner {
+    /// Build a runner from the `[backup]` section: validate it, connect the
+    /// storage backend, and load the encryption key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Config`] if the section is invalid, the storage
+    /// backend cannot be created, or the key variable is unset or malformed.
+    pub async fn from_config(
+        config: BackupConfig,
+        database_url: impl Into<String>,
+    ) -> Result<Self, BackupError> {
+        config.validate().map_err(BackupError::Config)?;
+        let storage = fraiseql_storage::create_backend(&config.storage)
+            .await
+            .map_err(|e| BackupError::Config(format!("[backup.storage]: {e}")))?;
+        let key = config.encryption_key_env.as_deref().map(BackupKey::from_env).transpose()?;
+        let tools = Arc::new(PgTools::from_config(&config));
+        Ok(Self::new(config, database_url, Arc::new(storage), tools, key))
+    }
+
+    /// Assemble a runner from already-built parts.
+    #[must_use]
+    pub fn new(
+        config: BackupConfig,
+        database_url: impl Into<String>,
+        storage: Arc<StorageBackend>,
+        tools: Arc<dyn DumpTool>,
+        key: Option<BackupKey>,
+    ) -> Self {
+        Self {
+            config,
+            database_url: database_url.into(),
+            storage,
+            tools,
+            key,
+        }
+    }
+
+    /// The `[backup]` section this runner was built from.
+    #[must_use]
+    pub const fn config(&self) -> &BackupConfig {
+        &self.config
+    }
+
+    /// Take one backup and upload it.
+    ///
+    /// The dump is staged in a temporary file, sealed as it is written, and
+    /// uploaded from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pg_dump` fails, sealing or staging fails, or either
+    /// upload is rejected. A failed manifest upload leaves an orphan archive
+    /// that [`Self::list`] ignores.
+    pub async fn run_once(&self) -> Result<BackupManifest, BackupError> {
+        let created_at = Utc::now();
+        let id = backup_id(created_at);
+        let prefix = self.config.normalized_prefix();
+        let staged = temp_archive()?;
+        let mut writer = ArchiveWriter::create(staged.path(), self.key.as_ref()).await?;
+        self.tools
+            .dump(
+                &self.database_url,
+                &self.config.schemas,
+                self.config.compression_level,
+                &mut writer,
+            )
+            .await?;
+        let (size_bytes, sha256) = writer.finish().await?;
+        let manifest = BackupManifest {
+            version: MANIFEST_VERSION,
+            archive_key: archive_key(prefix, &id, self.key.is_some()),
+            id,
+            created_at,
+            schemas: self.config.schemas.clone(),
+            compression_level: self.config.compression_level,
+            encrypted: self.key.is_some(),
+            size_bytes,
+            sha256,
+        };
+        self.storage
+            .upload_file(&manifest.archive_key, staged.path(), "application/octet-stream")
+            .await
+            .map_err(|e| BackupError::Storage(e.to_string()))?;
+        let manifest_json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| BackupError::Storage(format!("cannot encode manifest: {e}")))?;
+        self.storage
+            .upload(&manifest_key(prefix, &manifest.id), &manifest_json, "application/json")
+            .await
+            .map_err(|e| BackupError::Storage(e.to_string()))?;
+        Ok(manifest)
+    }
+
+    /// All complete backups, newest first.
+    ///
+    /// Manifests that cannot be downloaded or parsed are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Storage`] if the backend cannot be listed.
+    pub async fn list(&self) -> Result<Vec<BackupManifest>, BackupError> {
+        let prefix = format!("{}/", self.config.normalized_prefix());
+        let mut manifests = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self
+                .storage
+                .list(&prefix, cursor.as_deref(), LIST_PAGE_SIZE)
+                .await
+                .map_err(|e| BackupError::Storage(e.to_string()))?;
+            for object in page.objects.iter().filter(|o| o.key.ends_with(MANIFEST_SUFFIX)) {
+                match self.read_manifest(&object.key).await {
+                    Ok(manifest) => manifests.push(manifest),
+                    Err(e) => warn!(key = %object.key, error = %e, "Backup: skipping manifest"),
+                }
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        manifests.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(manifests)
+    }
+
+    async fn read_manifest(&self, key: &str) -> Result<BackupManifest, BackupError> {
+        let bytes = self
+            .storage
+            .download(key)
+            .await
+            .map_err(|e| BackupError::Storage(e.to_string()))?;
+        serde_json::from_slice(&bytes)
+            .map_err(|e| BackupError::Integrity(format!("malformed manifest `{key}`: {e}")))
+    }
+
+    /// Delete the backups the retention policy expires; returns their ids.
+    ///
+    /// The manifest goes first so a half-pruned backup is never offered for
+    /// restore.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Storage`] on the first listing or delete failure.
+    pub async fn prune(&self) -> Result<Vec<String>, BackupError> {
+        if self.config.retain_count.is_none() && self.config.retain_days.is_none() {
+            return Ok(Vec::new());
+        }
+        let manifests = self.list().await?;
+        let prefix = self.config.normalized_prefix();
+        let mut pruned = Vec::new();
+        for manifest in expired_backups(
+            &manifests,
+            Utc::now(),
+            self.config.retain_count,
+            self.config.retain_days,
+        ) {
+            for key in [
+                manifest_key(prefix, &manifest.id),
+                manifest.archive_key.clone(),
+            ] {
+                self.storage
+                    .delete(&key)
+                    .await
+                    .map_err(|e| BackupError::Storage(e.to_string()))?;
+            }
+            pruned.push(manifest.id.clone());
+        }
+        Ok(pruned)
+    }
+
+    /// Find a backup by id, or the newest one for [`LATEST`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::NotFound`] if nothing matches.
+    pub async fn resolve(&self, selector: &str) -> Result<BackupManifest, BackupError> {
+        if selector == LATEST {
+            return self.list().await?.into_iter().next().ok_or_else(|| {
+                BackupError::NotFound(format!(
+                    "no backups under `{}/`",
+                    self.config.normalized_prefix()
+                ))
+            });
+        }
+        if parse_backup_id(selector).is_none() {
+            return Err(BackupError::NotFound(format!(
+                "`{selector}` is not a backup id (expected YYYYMMDDTHHMMSSZ or `latest`)"
+            )));
+        }
+        let key = manifest_key(self.config.normalized_prefix(), selector);
+        if !self
+            .storage
+            .exists(&key)
+            .await
+            .map_err(|e| BackupError::Storage(e.to_string()))?
+        {
+            return Err(BackupError::NotFound(selector.to_string()));
+        }
+        self.read_manifest(&key).await
+    }
+
+    /// Download a backup, verify it against its manifest, and decrypt it.
+    ///
+    /// Returns the temporary file holding the verified `pg_dump` archive; it is
+    /// deleted when the handle is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackupError::Integrity`] on a size, checksum, or format
+    /// mismatch, [`BackupError::Config`] for an encrypted backup without a
+    /// configured key, [`BackupError::Encryption`] if it cannot be opened, and
+    /// [`BackupError::Io`] if a temporary file cannot be written.
+    pub async fn fetch(&self, manifest: &BackupManifest) -> Result<NamedTempFile, BackupError> {
+        let key = if manifest.encrypted {
+            Some(self.key.as_ref().ok_or_else(|| {
+                BackupError::Config(format!(
+                    "backup `{}` is encrypted but [backup] encryption_key_env is not set",
+                    manifest.id
+                ))
+            })?)
+        } else {
+            None
+        };
+        let archive = temp_archive()?;
+        let size = self
+            .storage
+            .download_file(&manifest.archive_key, archive.path())
+            .await
+            .map_err(|e| BackupError::Storage(e.to_string()))?;
+        if size != manifest.size_bytes {
+            return Err(BackupError::Integrity(format!(
+                "`{}` is {size} bytes, manifest records {}",
+                manifest.archive_key, manifest.size_bytes
+            )));
+        }
+        if file_sha256(archive.path()).await? != manifest.sha256 {
+            return Err(BackupError::Integrity(format!(
+                "`{}` does not match the manifest SHA-256",
+                manifest.archive_key
+            )));
+        }
+        let dump = match key {
+            Some(key) => {
+                let dump = temp_archive()?;
+                open_file(archive.path(), dump.path(), key).await?;
+                dump
+            },
+            None => archive,
+        };
+        if !is_pg_dump(dump.path()).await? {
+            return Err(BackupError::Integrity(format!(
+                "`{}` is not a pg_dump custom-format archive",
+                manifest.archive_key
+            )));
+        }
+        Ok(dump)
+    }
+
+    /// Restore a backup into `target_database_url`.
+    ///
+    /// The archive is always fetched, verified, and listed with
+    /// `pg_restore --list`; with `dry_run` nothing is written to the target.
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`Self::resolve`] / [`Self::fetch`] error, or
+    /// [`BackupError::Tool`] if `pg_restore` rejects the archive.
+    pub async fn restore(
+        &self,
+        selector: &str,
+        target_database_url: &str,
+        dry_run: bool,
+    ) -> Result<RestoreReport, BackupError> {
+        let manifest = self.resolve(selector).await?;
+        let dump = self.fetch(&manifest).await?;
+        let toc_entries = self.tools.list_contents(dump.path()).await?.len();
+        if !dry_run {
+            self.tools.restore(target_database_url, dump.path()).await?;
+        }
+        Ok(RestoreReport {
+            manifest,
+            toc_entries,
+            applied: !dry_run,
+        })
+    }
+}
+
+/// Runs a [`BackupRunner`] every `interval_secs`, followed by retention pruning.
+pub struct BackupScheduler {
+    runner:    Arc<BackupRunner>,
+    lock_pool: Option<sqlx::PgPool>,
+}
+
+impl BackupScheduler {
+    /// Schedule `runner`. With a `lock_pool`, each tick first takes a Postgres
+    /// advisory lock so only one replica backs up; without one, every tick runs.
+    #[must_use]
+    pub const fn new(runner: Arc<BackupRunner>, lock_pool: Option<sqlx::PgPool>) -> Self {
+        Self { runner, lock_pool }
+    }
+
+    /// Tick forever. The first backup runs one interval after startup, so a
+    /// crash-looping server does not dump on every restart.
+    pub async fn run_forever(self) {
+        let mut ticker =
+            tokio::time::interval(Duration::from_secs(self.runner.config().interval_secs));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        ticker.tick().await; // skip immediate first tick
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.tick().await {
+                error!(error = %e, "Backup: scheduled backup failed");
+            }
+        }
+    }
+
+    /// Take one backup and prune, unless another replica holds the lock.
+    ///
+    /// Returns `None` when the tick was skipped. Pruning failures are logged,
+    /// not returned: the backup itself succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the backup error, or [`BackupError::Storage`] if the advisory
+    /// lock cannot be queried.
+    pub async fn tick(&self) -> Result<Option<BackupManifest>, BackupError> {
+        let lock_err = |e: sqlx::Error| BackupError::Storage(format!("advisory lock: {e}"));
+        let conn = match &self.lock_pool {
+            Some(pool) => {
+                let mut conn = pool.acquire().await.map_err(lock_err)?;
+                let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+                    .bind(BACKUP_LOCK_KEY)
+                    .fetch_one(&mut *conn)
+                    .await
+                    .map_err(lock_err)?;
+                if !acquired {
+                    debug!("Backup: another replica holds the backup lock — skipping tick");
+                    return Ok(None);
+                }
+                Some(conn)
+            },
+            None => None,
+        };
+
+        let result = self.backup_and_prune().await;
+
+        if let Some(mut held) = conn {
+            let unlocked = sqlx::query("SELECT pg_advisory_unlock($1)")
+                .bind(BACKUP_LOCK_KEY)
+                .execute(&mut *held)
+                .await;
+            if let Err(e) = unlocked {
+                // Closing the session releases its advisory locks.
+                warn!(error = %e, "Backup: advisory unlock failed — closing the connection");
+                drop(held.detach());
+            }
+        }
+        result.map(Some)
+    }
+
+    async fn backup_and_prune(&self) -> Result<BackupManifest, BackupError> {
+        let manifest = self.runner.run_once().await?;
+        info!(
+            id = %manifest.id,
+            key = %manifest.archive_key,
+            size_bytes = manifest.size_bytes,
+            encrypted = manifest.encrypted,
+            "Backup: uploaded"
+        );
+        match self.runner.prune().await {
+            Ok(pruned) if !pruned.is_empty() => {
+                info!(count = pruned.len(), ids = ?pruned, "Backup: pruned expired backups");
+            },
+            Ok(_) => {},
+            Err(e) => warn!(error = %e, "Backup: retention pruning failed"),
+        }
+        Ok(manifest)
+    }
+}
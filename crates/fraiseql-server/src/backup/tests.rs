@@ -0,0 +1,440 @@
+//! Tests for scheduled logical backups.
+//!
+//! The runner is driven through a fake [`DumpTool`] over a local storage
+//! backend, so no Postgres installation is needed.
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Reason: test module
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use fraiseql_storage::{LocalBackend, StorageBackend, StorageConfig};
+
+use super::*;
+
+const KEY_HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+fn config(root: &std::path::Path) -> BackupConfig {
+    BackupConfig {
+        enabled:            true,
+        interval_secs:      3600,
+        schemas:            vec!["public".to_string(), "analytics".to_string()],
+        compression_level:  6,
+        encryption_key_env: None,
+        storage:            StorageConfig {
+            backend:      "local".to_string(),
+            path:         Some(root.to_string_lossy().into_owned()),
+            bucket:       None,
+            region:       None,
+            endpoint:     None,
+            project_id:   None,
+            account_name: None,
+        },
+        prefix:             "backups".to_string(),
+        retain_count:       None,
+        retain_days:        None,
+        pg_dump_path:       "pg_dump".to_string(),
+        pg_restore_path:    "pg_restore".to_string(),
+    }
+}
+
+#[derive(Default)]
+struct FakeTools {
+    /// Bytes appended to the dump after its header, written 1000 at a time.
+    padding:  usize,
+    restored: Mutex<Vec<(String, Vec<u8>)>>,
+}
+
+#[async_trait]
+impl DumpTool for FakeTools {
+    async fn dump(
+        &self,
+        _database_url: &str,
+        schemas: &[String],
+        _compression_level: u8,
+        out: &mut ArchiveWriter,
+    ) -> Result<(), BackupError> {
+        out.write(format!("PGDMP{}", schemas.join(",")).as_bytes()).await?;
+        let padding = padding(self.padding);
+        for piece in padding.chunks(1000) {
+            out.write(piece).await?;
+        }
+        Ok(())
+    }
+
+    async fn list_contents(&self, archive: &Path) -> Result<Vec<String>, BackupError> {
+        let archive = tokio::fs::read(archive).await?;
+        Ok(vec![String::from_utf8_lossy(&archive[..archive.len().min(64)]).into_owned(); 3])
+    }
+
+    async fn restore(&self, database_url: &str, archive: &Path) -> Result<(), BackupError> {
+        let archive = tokio::fs::read(archive).await?;
+        self.restored.lock().unwrap().push((database_url.to_string(), archive));
+        Ok(())
+    }
+}
+
+fn padding(len: usize) -> Vec<u8> {
+    (0..len).map(|i| u8::try_from(i % 251).unwrap()).collect()
+}
+
+fn runner(
+    config: BackupConfig,
+    key: Option<BackupKey>,
+) -> (BackupRunner, Arc<FakeTools>, Arc<StorageBackend>) {
+    runner_with_tools(config, key, FakeTools::default())
+}
+
+fn runner_with_tools(
+    config: BackupConfig,
+    key: Option<BackupKey>,
+    tools: FakeTools,
+) -> (BackupRunner, Arc<FakeTools>, Arc<StorageBackend>) {
+    let root = config.storage.path.clone().unwrap();
+    let storage = Arc::new(StorageBackend::Local(LocalBackend::new(&root)));
+    let tools = Arc::new(tools);
+    let runner = BackupRunner::new(
+        config,
+        "postgres://app@localhost/app",
+        Arc::clone(&storage),
+        tools.clone(),
+        key,
+    );
+    (runner, tools, storage)
+}
+
+fn manifest(id: &str) -> BackupManifest {
+    BackupManifest {
+        version:           MANIFEST_VERSION,
+        id:                id.to_string(),
+        archive_key:       format!("backups/{id}.dump"),
+        created_at:        parse_backup_id(id).unwrap(),
+        schemas:           vec!["public".to_string()],
+        compression_level: 6,
+        encrypted:         false,
+        size_bytes:        0,
+        sha256:            String::new(),
+    }
+}
+
+// ── Config ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn config_parses_with_defaults() {
+    let cfg: BackupConfig = toml::from_str(
+        r#"
+        [storage]
+        backend = "local"
+        path = "/var/backups"
+        "#,
+    )
+    .unwrap();
+    assert!(cfg.enabled);
+    assert_eq!(cfg.interval_secs, 86_400);
+    assert_eq!(cfg.schemas, vec!["public"]);
+    assert_eq!(cfg.compression_level, 6);
+    assert_eq!(cfg.prefix, "backups");
+    assert!(cfg.validate().is_ok());
+}
+
+#[test]
+fn config_rejects_bad_values() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = config(dir.path());
+
+    let mut cfg = base.clone();
+    cfg.compression_level = 10;
+    assert!(cfg.validate().unwrap_err().contains("compression_level"));
+
+    let mut cfg = base.clone();
+    cfg.schemas = vec!["public; DROP".to_string()];
+    assert!(cfg.validate().unwrap_err().contains("not a plain identifier"));
+
+    let mut cfg = base.clone();
+    cfg.prefix = "backups/../etc".to_string();
+    assert!(cfg.validate().unwrap_err().contains("prefix"));
+
+    let mut cfg = base;
+    cfg.retain_count = Some(0);
+    assert!(cfg.validate().unwrap_err().contains("retain_count"));
+}
+
+// ── Archive format ───────────────────────────────────────────────────────────
+
+#[test]
+fn seal_round_trips_and_detects_tampering() {
+    let key = BackupKey::from_hex(KEY_HEX).unwrap();
+    let sealed = seal(b"PGDMP payload", &key).unwrap();
+    assert!(sealed.starts_with(SEALED_MAGIC));
+    assert_eq!(open(&sealed, &key).unwrap(), b"PGDMP payload");
+
+    let mut tampered = sealed.clone();
+    *tampered.last_mut().unwrap() ^= 0x01;
+    assert!(matches!(open(&tampered, &key), Err(BackupError::Encryption(_))));
+
+    let other = BackupKey::from_hex(&"ab".repeat(32)).unwrap();
+    assert!(matches!(open(&sealed, &other), Err(BackupError::Encryption(_))));
+}
+
+#[test]
+fn seal_round_trips_across_chunk_boundaries() {
+    let key = BackupKey::from_hex(KEY_HEX).unwrap();
+    for len in [
+        0,
+        1,
+        SEAL_CHUNK_LEN - 1,
+        SEAL_CHUNK_LEN,
+        SEAL_CHUNK_LEN + 1,
+        3 * SEAL_CHUNK_LEN,
+    ] {
+        let plain = padding(len);
+        let sealed = seal(&plain, &key).unwrap();
+        assert_eq!(open(&sealed, &key).unwrap(), plain, "{len} bytes");
+    }
+}
+
+#[test]
+fn open_rejects_truncated_and_reordered_chunks() {
+    let key = BackupKey::from_hex(KEY_HEX).unwrap();
+    let sealed = seal(&padding(2 * SEAL_CHUNK_LEN + 5), &key).unwrap();
+
+    // Cut at a chunk boundary: every remaining chunk authenticates, but the
+    // final one is missing.
+    let truncated = &sealed[..HEADER_LEN + SEALED_CHUNK_LEN];
+    assert!(matches!(open(truncated, &key), Err(BackupError::Encryption(_))));
+    assert!(matches!(open(&sealed[..HEADER_LEN], &key), Err(BackupError::Encryption(_))));
+
+    let mut swapped = sealed[..HEADER_LEN].to_vec();
+    swapped.extend_from_slice(
+        &sealed[HEADER_LEN + SEALED_CHUNK_LEN..HEADER_LEN + 2 * SEALED_CHUNK_LEN],
+    );
+    swapped.extend_from_slice(&sealed[HEADER_LEN..HEADER_LEN + SEALED_CHUNK_LEN]);
+    swapped.extend_from_slice(&sealed[HEADER_LEN + 2 * SEALED_CHUNK_LEN..]);
+    assert!(matches!(open(&swapped, &key), Err(BackupError::Encryption(_))));
+
+    assert!(matches!(open(b"PGDMP not sealed", &key), Err(BackupError::Encryption(_))));
+}
+
+#[test]
+fn backup_key_rejects_wrong_length_and_non_hex() {
+    assert!(matches!(BackupKey::from_hex("abcd"), Err(BackupError::Config(_))));
+    assert!(matches!(BackupKey::from_hex(&"zz".repeat(32)), Err(BackupError::Config(_))));
+    assert_eq!(format!("{:?}", BackupKey::from_hex(KEY_HEX).unwrap()), "BackupKey(..)");
+}
+
+#[test]
+fn backup_id_round_trips() {
+    let at = Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+    assert_eq!(backup_id(at), "20260304T050607Z");
+    assert_eq!(parse_backup_id("20260304T050607Z"), Some(at));
+    assert_eq!(parse_backup_id("latest"), None);
+}
+
+// ── Tool arguments ───────────────────────────────────────────────────────────
+
+#[test]
+fn pg_dump_args_select_schemas_and_compression() {
+    let args = pg_dump_args(&["public".to_string(), "analytics".to_string()], 9);
+    assert_eq!(
+        args,
+        vec![
+            "--format=custom",
+            "--compress=9",
+            "--no-owner",
+            "--no-privileges",
+            "--schema=public",
+            "--schema=analytics",
+        ]
+    );
+    let restore = pg_restore_args();
+    assert!(restore.contains(&"--single-transaction".to_string()));
+    assert!(restore.contains(&"--clean".to_string()));
+}
+
+#[test]
+fn split_password_moves_password_out_of_the_url() {
+    let (url, password) = split_password("postgres://app:s%40cret@db:5432/app");
+    assert_eq!(url, "postgres://app@db:5432/app");
+    assert_eq!(password.as_deref(), Some("s@cret"));
+
+    let (url, password) = split_password("postgres://app@db/app");
+    assert_eq!(url, "postgres://app@db/app");
+    assert!(password.is_none());
+}
+
+// ── Retention ────────────────────────────────────────────────────────────────
+
+#[test]
+fn retention_expires_by_count_and_age_but_keeps_newest() {
+    let manifests = vec![
+        manifest("20260101T000000Z"),
+        manifest("20260110T000000Z"),
+        manifest("20260105T000000Z"),
+    ];
+    let now = Utc.with_ymd_and_hms(2026, 1, 10, 12, 0, 0).unwrap();
+
+    let by_count: Vec<_> = expired_backups(&manifests, now, Some(2), None)
+        .iter()
+        .map(|m| m.id.clone())
+        .collect();
+    assert_eq!(by_count, vec!["20260101T000000Z"]);
+
+    let by_age: Vec<_> = expired_backups(&manifests, now, None, Some(7))
+        .iter()
+        .map(|m| m.id.clone())
+        .collect();
+    assert_eq!(by_age, vec!["20260101T000000Z"]);
+
+    // Everything is past the age limit, yet the newest backup survives.
+    let later = Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(expired_backups(&manifests, later, None, Some(1)).len(), 2);
+    assert!(expired_backups(&manifests, now, None, None).is_empty());
+}
+
+// ── Runner ───────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn run_once_uploads_archive_and_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let (runner, _, storage) = runner(config(dir.path()), None);
+
+    let manifest = runner.run_once().await.unwrap();
+    assert!(!manifest.encrypted);
+    assert_eq!(manifest.archive_key, format!("backups/{}.dump", manifest.id));
+    assert_eq!(manifest.schemas, vec!["public", "analytics"]);
+
+    let archive = storage.download(&manifest.archive_key).await.unwrap();
+    assert_eq!(archive, b"PGDMPpublic,analytics");
+    assert_eq!(manifest.sha256, hex::encode(Sha256::digest(&archive)));
+
+    assert_eq!(runner.list().await.unwrap(), vec![manifest.clone()]);
+    assert_eq!(runner.resolve(LATEST).await.unwrap(), manifest);
+}
+
+#[tokio::test]
+async fn encrypted_backup_restores_with_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let key = BackupKey::from_hex(KEY_HEX).unwrap();
+    let (runner, tools, storage) = runner(config(dir.path()), Some(key));
+
+    let manifest = runner.run_once().await.unwrap();
+    assert!(manifest.encrypted);
+    assert!(manifest.archive_key.ends_with(".dump.enc"));
+    let stored = storage.download(&manifest.archive_key).await.unwrap();
+    assert!(stored.starts_with(SEALED_MAGIC));
+
+    let report = runner.restore(&manifest.id, "postgres://target/db", false).await.unwrap();
+    assert!(report.applied);
+    assert_eq!(report.toc_entries, 3);
+    let restored = tools.restored.lock().unwrap();
+    assert_eq!(restored.len(), 1);
+    assert_eq!(restored[0].0, "postgres://target/db");
+    assert_eq!(restored[0].1, b"PGDMPpublic,analytics");
+}
+
+#[tokio::test]
+async fn multi_chunk_encrypted_backup_streams_through_temp_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let key = BackupKey::from_hex(KEY_HEX).unwrap();
+    let tools = FakeTools {
+        padding: 3 * SEAL_CHUNK_LEN + 17,
+        ..FakeTools::default()
+    };
+    let (runner, tools, storage) = runner_with_tools(config(dir.path()), Some(key), tools);
+
+    let manifest = runner.run_once().await.unwrap();
+    let stored = storage.download(&manifest.archive_key).await.unwrap();
+    assert_eq!(manifest.size_bytes, u64::try_from(stored.len()).unwrap());
+    assert_eq!(manifest.sha256, hex::encode(Sha256::digest(&stored)));
+
+    runner.restore(&manifest.id, "postgres://target/db", false).await.unwrap();
+    let mut expected = b"PGDMPpublic,analytics".to_vec();
+    expected.extend(padding(3 * SEAL_CHUNK_LEN + 17));
+    assert_eq!(tools.restored.lock().unwrap()[0].1, expected);
+}
+
+#[tokio::test]
+async fn dry_run_verifies_without_restoring() {
+    let dir = tempfile::tempdir().unwrap();
+    let (runner, tools, _) = runner(config(dir.path()), None);
+    runner.run_once().await.unwrap();
+
+    let report = runner.restore(LATEST, "postgres://target/db", true).await.unwrap();
+    assert!(!report.applied);
+    assert_eq!(report.toc_entries, 3);
+    assert!(tools.restored.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn fetch_rejects_a_modified_archive() {
+    let dir = tempfile::tempdir().unwrap();
+    let (runner, _, storage) = runner(config(dir.path()), None);
+    let manifest = runner.run_once().await.unwrap();
+
+    storage
+        .upload(&manifest.archive_key, b"PGDMPpublic,analyticX", "application/octet-stream")
+        .await
+        .unwrap();
+    assert!(matches!(runner.fetch(&manifest).await, Err(BackupError::Integrity(_))));
+}
+
+#[tokio::test]
+async fn encrypted_backup_without_key_is_a_config_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let key = BackupKey::from_hex(KEY_HEX).unwrap();
+    let (sealed_runner, _, _) = runner(config(dir.path()), Some(key));
+    let manifest = sealed_runner.run_once().await.unwrap();
+
+    let (plain_runner, _, _) = runner(config(dir.path()), None);
+    assert!(matches!(plain_runner.fetch(&manifest).await, Err(BackupError::Config(_))));
+}
+
+#[tokio::test]
+async fn resolve_reports_unknown_backups() {
+    let dir = tempfile::tempdir().unwrap();
+    let (runner, _, _) = runner(config(dir.path()), None);
+    assert!(matches!(runner.resolve(LATEST).await, Err(BackupError::NotFound(_))));
+    assert!(matches!(
+        runner.resolve("20200101T000000Z").await,
+        Err(BackupError::NotFound(_))
+    ));
+    assert!(matches!(runner.resolve("../secrets").await, Err(BackupError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn prune_deletes_archive_and_manifest_past_retention() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cfg = config(dir.path());
+    cfg.retain_count = Some(1);
+    let (runner, _, storage) = runner(cfg, None);
+
+    let old = manifest("20200101T000000Z");
+    storage
+        .upload(&old.archive_key, b"PGDMP", "application/octet-stream")
+        .await
+        .unwrap();
+    storage
+        .upload(
+            "backups/20200101T000000Z.manifest.json",
+            &serde_json::to_vec(&old).unwrap(),
+            "application/json",
+        )
+        .await
+        .unwrap();
+    let current = runner.run_once().await.unwrap();
+
+    assert_eq!(runner.prune().await.unwrap(), vec!["20200101T000000Z"]);
+    assert!(!storage.exists(&old.archive_key).await.unwrap());
+    assert_eq!(runner.list().await.unwrap(), vec![current]);
+}
+
+#[tokio::test]
+async fn scheduler_tick_without_lock_pool_backs_up() {
+    let dir = tempfile::tempdir().unwrap();
+    let (runner, _, _) = runner(config(dir.path()), None);
+    let runner = Arc::new(runner);
+    let scheduler = BackupScheduler::new(Arc::clone(&runner), None);
+
+    let manifest = scheduler.tick().await.unwrap().expect("tick should back up");
+    assert_eq!(runner.list().await.unwrap(), vec![manifest]);
+}
@@ -0,0 +1,190 @@
+//! Canary schema rollout: serve a candidate compiled schema next to the current one.
+//!
+//! With `[canary]` configured, the server loads the candidate schema at serve time
+//! into a second executor that shares the current one's database adapter. Each
+//! GraphQL request is routed to one of the two:
+//!
+//! - a request carrying the canary header picks its side explicitly (`1`/`true`/`candidate` or
+//!   `0`/`false`/`stable`);
+//! - otherwise `traffic_percent` of every hundred requests go to the candidate, spread evenly by a
+//!   request counter.
+//!
+//! Requests, errors and total latency are counted per side and exported on
+//! `/metrics` (`fraiseql_canary_*{variant="stable|candidate"}`), so error rates and
+//! mean latencies can be compared before the candidate is promoted by a normal
+//! schema reload.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use arc_swap::{ArcSwap, Guard};
+use axum::http::{HeaderMap, HeaderName};
+use fraiseql_core::{db::traits::DatabaseAdapter, runtime::Executor};
+
+use crate::config::CanaryConfig;
+
+#[cfg(test)]
+mod tests;
+
+/// Which schema served a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaVariant {
+    /// The current schema.
+    Stable,
+    /// The candidate schema under canary.
+    Candidate,
+}
+
+impl SchemaVariant {
+    /// Label used for this variant in metrics.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Candidate => "candidate",
+        }
+    }
+
+    /// Parse a canary header value; `None` for anything unrecognised.
+    fn from_header(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "candidate" => Some(Self::Candidate),
+            "0" | "false" | "stable" => Some(Self::Stable),
+            _ => None,
+        }
+    }
+}
+
+/// Snapshot of one variant's request counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VariantStats {
+    /// Requests routed to the variant
+    pub requests:         u64,
+    /// Requests that failed
+    pub errors:           u64,
+    /// Summed request latency, in microseconds
+    pub latency_us_total: u64,
+}
+
+#[derive(Debug, Default)]
+struct VariantCounters {
+    requests:   AtomicU64,
+    errors:     AtomicU64,
+    latency_us: AtomicU64,
+}
+
+impl VariantCounters {
+    fn record(&self, elapsed: Duration, success: bool) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        self.latency_us.fetch_add(micros, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> VariantStats {
+        VariantStats {
+            requests:         self.requests.load(Ordering::Relaxed),
+            errors:           self.errors.load(Ordering::Relaxed),
+            latency_us_total: self.latency_us.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A candidate schema executor and the traffic split between it and the current one.
+pub struct CanaryRollout<A: DatabaseAdapter> {
+    candidate:          ArcSwap<Executor<A>>,
+    traffic_percent:    u8,
+    header:             HeaderName,
+    routed:             AtomicU64,
+    stable_counters:    VariantCounters,
+    candidate_counters: VariantCounters,
+}
+
+impl<A: DatabaseAdapter> CanaryRollout<A> {
+    /// Create a rollout serving `candidate` as configured by `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `config` is invalid.
+    pub fn new(candidate: Arc<Executor<A>>, config: &CanaryConfig) -> Result<Self, String> {
+        config.validate()?;
+        let header = HeaderName::try_from(config.header.as_str())
+            .map_err(|e| format!("canary.header: {e}"))?;
+        Ok(Self {
+            candidate: ArcSwap::new(candidate),
+            traffic_percent: config.traffic_percent,
+            header,
+            routed: AtomicU64::new(0),
+            stable_counters: VariantCounters::default(),
+            candidate_counters: VariantCounters::default(),
+        })
+    }
+
+    /// Pick the schema variant serving a request with these headers.
+    #[must_use]
+    pub fn route(&self, headers: &HeaderMap) -> SchemaVariant {
+        if let Some(variant) = headers
+            .get(&self.header)
+            .and_then(|v| v.to_str().ok())
+            .and_then(SchemaVariant::from_header)
+        {
+            return variant;
+        }
+        if self.traffic_percent == 0 {
+            return SchemaVariant::Stable;
+        }
+        let slot = self.routed.fetch_add(1, Ordering::Relaxed) % 100;
+        if slot < u64::from(self.traffic_percent) {
+            SchemaVariant::Candidate
+        } else {
+            SchemaVariant::Stable
+        }
+    }
+
+    /// The candidate executor.
+    #[must_use]
+    pub fn candidate(&self) -> Guard<Arc<Executor<A>>> {
+        self.candidate.load()
+    }
+
+    /// Percentage of requests routed to the candidate without a header.
+    #[must_use]
+    pub const fn traffic_percent(&self) -> u8 {
+        self.traffic_percent
+    }
+
+    /// Record the outcome of a request served by `variant`.
+    pub fn record(&self, variant: SchemaVariant, elapsed: Duration, success: bool) {
+        match variant {
+            SchemaVariant::Stable => self.stable_counters.record(elapsed, success),
+            SchemaVariant::Candidate => self.candidate_counters.record(elapsed, success),
+        }
+    }
+
+    /// Counters of `variant`.
+    #[must_use]
+    pub fn stats(&self, variant: SchemaVariant) -> VariantStats {
+        match variant {
+            SchemaVariant::Stable => self.stable_counters.snapshot(),
+            SchemaVariant::Candidate => self.candidate_counters.snapshot(),
+        }
+    }
+}
+
+impl<A: DatabaseAdapter> std::fmt::Debug for CanaryRollout<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CanaryRollout")
+            .field("traffic_percent", &self.traffic_percent)
+            .field("header", &self.header)
+            .field("stable", &self.stable_counters.snapshot())
+            .field("candidate", &self.candidate_counters.snapshot())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::{sync::Arc, time::Duration};
+
+use axum::http::{HeaderMap, HeaderValue};
+use fraiseql_core::{runtime::Executor, schema::CompiledSchema};
+use fraiseql_test_utils::failing_adapter::FailingAdapter;
+
+use super::{CanaryRollout, SchemaVariant, VariantStats};
+use crate::config::CanaryConfig;
+
+fn config(traffic_percent: u8) -> CanaryConfig {
+    CanaryConfig {
+        schema_path: "schema.candidate.compiled.json".into(),
+        traffic_percent,
+        header: "x-fraiseql-canary".to_string(),
+    }
+}
+
+fn rollout(traffic_percent: u8) -> CanaryRollout<FailingAdapter> {
+    let candidate = Arc::new(Executor::new(CompiledSchema::new(), Arc::new(FailingAdapter::new())));
+    CanaryRollout::new(candidate, &config(traffic_percent)).unwrap()
+}
+
+fn candidate_share(rollout: &CanaryRollout<FailingAdapter>, requests: usize) -> usize {
+    let headers = HeaderMap::new();
+    (0..requests)
+        .filter(|_| rollout.route(&headers) == SchemaVariant::Candidate)
+        .count()
+}
+
+#[test]
+fn routes_configured_share_to_candidate() {
+    assert_eq!(candidate_share(&rollout(5), 1_000), 50);
+    assert_eq!(candidate_share(&rollout(0), 1_000), 0);
+    assert_eq!(candidate_share(&rollout(100), 1_000), 1_000);
+}
+
+#[test]
+fn header_forces_variant() {
+    let all_candidate = rollout(100);
+    let all_stable = rollout(0);
+    let mut headers = HeaderMap::new();
+
+    headers.insert("x-fraiseql-canary", HeaderValue::from_static("stable"));
+    assert_eq!(all_candidate.route(&headers), SchemaVariant::Stable);
+    headers.insert("x-fraiseql-canary", HeaderValue::from_static("0"));
+    assert_eq!(all_candidate.route(&headers), SchemaVariant::Stable);
+
+    headers.insert("x-fraiseql-canary", HeaderValue::from_static("TRUE"));
+    assert_eq!(all_stable.route(&headers), SchemaVariant::Candidate);
+}
+
+#[test]
+fn unrecognised_header_value_falls_back_to_split() {
+    let rollout = rollout(0);
+    let mut headers = HeaderMap::new();
+    headers.insert("x-fraiseql-canary", HeaderValue::from_static("maybe"));
+    assert_eq!(rollout.route(&headers), SchemaVariant::Stable);
+}
+
+#[test]
+fn records_outcomes_per_variant() {
+    let rollout = rollout(10);
+    rollout.record(SchemaVariant::Candidate, Duration::from_micros(300), false);
+    rollout.record(SchemaVariant::Candidate, Duration::from_micros(100), true);
+    rollout.record(SchemaVariant::Stable, Duration::from_micros(50), true);
+
+    assert_eq!(
+        rollout.stats(SchemaVariant::Candidate),
+        VariantStats {
+            requests:         2,
+            errors:           1,
+            latency_us_total: 400,
+        }
+    );
+    assert_eq!(
+        rollout.stats(SchemaVariant::Stable),
+        VariantStats {
+            requests:         1,
+            errors:           0,
+            latency_us_total: 50,
+        }
+    );
+}
+
+#[test]
+fn rejects_invalid_config() {
+    let candidate = Arc::new(Executor::new(CompiledSchema::new(), Arc::new(FailingAdapter::new())));
+    assert!(CanaryRollout::new(candidate.clone(), &config(101)).is_err());
+
+    let bad_header = CanaryConfig {
+        header: "not a header".to_string(),
+        ..config(5)
+    };
+    assert!(CanaryRollout::new(candidate, &bad_header).is_err());
+}
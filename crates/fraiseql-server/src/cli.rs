@@ -72,12 +72,23 @@ pub struct ServerArgs {
     #[arg(long, env = "FRAISEQL_SCHEMA_PATH")]
     pub schema_path: Option<String>,
 
+    /// Ed25519 public key (PEM or base64) compiled schemas must be signed with.
+    /// Overrides the `schema_public_key` config key.
+    #[arg(long, env = "FRAISEQL_SCHEMA_PUBLIC_KEY")]
+    pub schema_public_key: Option<String>,
+
     /// Fail boot if any declared `sql_source` (query view / mutation function) is
     /// not backed by the database, printing a precise list. Default OFF;
     /// Postgres-only. Overrides the `validate_sql_sources` config key. (#487)
     #[arg(long, env = "FRAISEQL_VALIDATE_SQL_SOURCES", value_parser = BoolishValueParser::new(), num_args = 0..=1, default_missing_value = "true")]
     pub validate_sql_sources: Option<bool>,
 
+    /// Fail boot if views, columns, column types or mutation functions in the
+    /// database have drifted from the compiled schema, printing every difference.
+    /// Default OFF; Postgres-only. Overrides the `validate_schema_drift` config key.
+    #[arg(long, env = "FRAISEQL_VALIDATE_SCHEMA_DRIFT", value_parser = BoolishValueParser::new(), num_args = 0..=1, default_missing_value = "true")]
+    pub validate_schema_drift: Option<bool>,
+
     // ── Metrics ──────────────────────────────────────────────────────────
     /// Enable Prometheus metrics endpoint.
     #[arg(long, env = "FRAISEQL_METRICS_ENABLED", value_parser = BoolishValueParser::new(), num_args = 0..=1, default_missing_value = "true")]
@@ -142,6 +153,10 @@ pub struct ServerArgs {
     #[arg(long, env = "FRAISEQL_RATE_LIMIT_BURST_SIZE")]
     pub rate_limit_burst_size: Option<u32>,
 
+    /// Rate limit: Redis URL for buckets shared across replicas.
+    #[arg(long, env = "FRAISEQL_RATE_LIMIT_REDIS_URL")]
+    pub rate_limit_redis_url: Option<String>,
+
     // ── Logging ──────────────────────────────────────────────────────────
     /// Log output format: `json` for structured JSON, `text` for
     /// human-readable (default).
@@ -167,7 +182,9 @@ impl ServerArgs {
                 .ok()
                 .and_then(|v| v.parse().ok()),
             schema_path:                std::env::var("FRAISEQL_SCHEMA_PATH").ok(),
+            schema_public_key:          std::env::var("FRAISEQL_SCHEMA_PUBLIC_KEY").ok(),
             validate_sql_sources:       parse_bool_env_opt("FRAISEQL_VALIDATE_SQL_SOURCES"),
+            validate_schema_drift:      parse_bool_env_opt("FRAISEQL_VALIDATE_SCHEMA_DRIFT"),
             metrics_enabled:            parse_bool_env_opt("FRAISEQL_METRICS_ENABLED"),
             metrics_token:              std::env::var("FRAISEQL_METRICS_TOKEN").ok(),
             admin_api_enabled:          parse_bool_env_opt("FRAISEQL_ADMIN_API_ENABLED"),
@@ -188,6 +205,7 @@ impl ServerArgs {
             rate_limit_burst_size:      std::env::var("FRAISEQL_RATE_LIMIT_BURST_SIZE")
                 .ok()
                 .and_then(|v| v.parse().ok()),
+            rate_limit_redis_url:       std::env::var("FRAISEQL_RATE_LIMIT_REDIS_URL").ok(),
             log_format:                 std::env::var("FRAISEQL_LOG_FORMAT").ok(),
         }
     }
@@ -208,11 +226,17 @@ impl ServerArgs {
         if let Some(ref path) = self.schema_path {
             config.schema_path = path.into();
         }
+        if self.schema_public_key.is_some() {
+            config.schema_public_key.clone_from(&self.schema_public_key);
+        }
         // #487: the CLI flag / FRAISEQL_VALIDATE_SQL_SOURCES env var (both surface
         // here as `Some`) override the `validate_sql_sources` config key.
         if let Some(enabled) = self.validate_sql_sources {
             config.validate_sql_sources = enabled;
         }
+        if let Some(enabled) = self.validate_schema_drift {
+            config.validate_schema_drift = enabled;
+        }
 
         // Metrics
         if let Some(enabled) = self.metrics_enabled {
@@ -250,7 +274,7 @@ impl ServerArgs {
             config.subscription_require_auth = Some(require_auth);
         }
 
-        // Rate limiting — apply all four overrides atomically.
+        // Rate limiting — apply all overrides atomically.
         self.apply_rate_limit_overrides(config);
     }
 
@@ -260,6 +284,7 @@ impl ServerArgs {
             && self.rate_limit_rps_per_ip.is_none()
             && self.rate_limit_rps_per_user.is_none()
             && self.rate_limit_burst_size.is_none()
+            && self.rate_limit_redis_url.is_none()
         {
             return;
         }
@@ -278,6 +303,9 @@ impl ServerArgs {
         if let Some(v) = self.rate_limit_burst_size {
             rate_config.burst_size = v;
         }
+        if let Some(ref url) = self.rate_limit_redis_url {
+            rate_config.redis_url = Some(url.clone());
+        }
 
         config.rate_limiting = Some(rate_config);
     }
@@ -0,0 +1,163 @@
+//! Scheduled logical backup configuration (`[backup]`).
+
+use fraiseql_storage::StorageConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for scheduled logical backups of the query-side schemas.
+///
+/// Add a `[backup]` section to `fraiseql.toml` (or `ServerConfig`) to have the
+/// server run `pg_dump` on a fixed interval and upload each archive to object
+/// storage:
+///
+/// ```toml
+/// [backup]
+/// interval_secs = 86400
+/// schemas = ["public"]
+/// encryption_key_env = "FRAISEQL_BACKUP_KEY"
+/// retain_count = 14
+/// retain_days = 30
+///
+/// [backup.storage]
+/// backend = "s3"
+/// bucket = "acme-fraiseql-backups"
+/// region = "eu-west-1"
+/// ```
+///
+/// Archives are restored with `fraiseql backup restore`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupConfig {
+    /// Run the backup scheduler. Defaults to `true` when the section is present,
+    /// so `enabled = false` keeps the section (for `fraiseql backup restore`)
+    /// without taking scheduled backups.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Seconds between scheduled backups. Defaults to `86400` (daily).
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+
+    /// Schemas passed to `pg_dump --schema`. Defaults to `["public"]`.
+    #[serde(default = "default_schemas")]
+    pub schemas: Vec<String>,
+
+    /// `pg_dump` compression level (`0` = uncompressed, `9` = smallest).
+    /// Defaults to `6`.
+    #[serde(default = "default_compression_level")]
+    pub compression_level: u8,
+
+    /// Environment variable holding a hex-encoded 32-byte AES-256-GCM key.
+    ///
+    /// When set, archives are encrypted before upload. When unset, archives
+    /// are stored as plain `pg_dump` custom-format files.
+    #[serde(default)]
+    pub encryption_key_env: Option<String>,
+
+    /// Object-storage backend the archives are uploaded to.
+    pub storage: StorageConfig,
+
+    /// Key prefix under which archives and manifests are stored.
+    /// Defaults to `"backups"`.
+    #[serde(default = "default_prefix")]
+    pub prefix: String,
+
+    /// Keep at most this many backups (newest first). `None` = no count limit.
+    #[serde(default)]
+    pub retain_count: Option<usize>,
+
+    /// Delete backups older than this many days. `None` = no age limit.
+    #[serde(default)]
+    pub retain_days: Option<u32>,
+
+    /// Path to the `pg_dump` binary. Defaults to `"pg_dump"` (resolved on `PATH`).
+    #[serde(default = "default_pg_dump_path")]
+    pub pg_dump_path: String,
+
+    /// Path to the `pg_restore` binary. Defaults to `"pg_restore"` (resolved on `PATH`).
+    #[serde(default = "default_pg_restore_path")]
+    pub pg_restore_path: String,
+}
+
+impl BackupConfig {
+    /// Validate the section.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first problem found: a zero interval, an
+    /// out-of-range compression level, an empty or non-identifier schema list,
+    /// an unsafe prefix, or a zero retention limit.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.interval_secs == 0 {
+            return Err("[backup] interval_secs must be greater than 0".to_string());
+        }
+        if self.compression_level > 9 {
+            return Err(format!(
+                "[backup] compression_level must be between 0 and 9, got {}",
+                self.compression_level
+            ));
+        }
+        if self.schemas.is_empty() {
+            return Err("[backup] schemas must name at least one schema".to_string());
+        }
+        if let Some(bad) = self.schemas.iter().find(|s| !is_schema_identifier(s)) {
+            return Err(format!(
+                "[backup] schema `{bad}` is not a plain identifier (letters, digits, `_`)"
+            ));
+        }
+        let prefix = self.prefix.trim_matches('/');
+        if prefix.is_empty()
+            || prefix.split('/').any(|segment| segment.is_empty() || segment == "..")
+        {
+            return Err(format!(
+                "[backup] prefix `{}` must be a non-empty relative path without `..`",
+                self.prefix
+            ));
+        }
+        if self.retain_count == Some(0) {
+            return Err("[backup] retain_count must be at least 1".to_string());
+        }
+        if self.retain_days == Some(0) {
+            return Err("[backup] retain_days must be at least 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// The storage prefix without leading or trailing slashes.
+    #[must_use]
+    pub fn normalized_prefix(&self) -> &str {
+        self.prefix.trim_matches('/')
+    }
+}
+
+fn is_schema_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+const fn default_enabled() -> bool {
+    true
+}
+
+const fn default_interval_secs() -> u64 {
+    86_400
+}
+
+fn default_schemas() -> Vec<String> {
+    vec!["public".to_string()]
+}
+
+const fn default_compression_level() -> u8 {
+    6
+}
+
+fn default_prefix() -> String {
+    "backups".to_string()
+}
+
+fn default_pg_dump_path() -> String {
+    "pg_dump".to_string()
+}
+
+fn default_pg_restore_path() -> String {
+    "pg_restore".to_string()
+}
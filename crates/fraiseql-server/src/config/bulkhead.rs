@@ -0,0 +1,139 @@
+//! Per-operation concurrency limiting (bulkhead) configuration.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for capping concurrent executions per GraphQL operation or per
+/// cost class (`[bulkhead]`).
+///
+/// Each named operation and each cost class is its own compartment with its own
+/// concurrency limit, so a stampede on one expensive query waits in (or is turned
+/// away from) its compartment instead of taking every database connection. A
+/// request that finds its compartment full waits in a bounded queue for up to
+/// `queue_timeout_ms`; beyond `max_queue` waiters, or after the timeout, it is
+/// rejected with `429 Too Many Requests`.
+///
+/// A request takes a slot in the compartment of its `operationName`, if listed,
+/// and in the cost class with the highest `min_cost` its estimated query cost
+/// reaches; it must get both to run. Requests matching neither are not limited.
+///
+/// # Example (TOML)
+///
+/// ```toml
+/// [bulkhead]
+/// max_queue = 16
+/// queue_timeout_ms = 1000
+///
+/// [bulkhead.operations]
+/// MonthlyRevenueReport = 4
+///
+/// [[bulkhead.cost_classes]]
+/// name = "heavy"
+/// min_cost = 1000
+/// max_concurrent = 8
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BulkheadConfig {
+    /// Maximum concurrent executions keyed by GraphQL operation name.
+    #[serde(default)]
+    pub operations: HashMap<String, usize>,
+
+    /// Maximum concurrent executions by estimated query cost.
+    #[serde(default)]
+    pub cost_classes: Vec<CostClassConfig>,
+
+    /// Requests allowed to wait for a slot in each compartment; further requests
+    /// are rejected immediately. Default: `16`.
+    #[serde(default = "default_max_queue")]
+    pub max_queue: usize,
+
+    /// Time a queued request waits for a slot, in milliseconds. Default: `1000`.
+    #[serde(default = "default_queue_timeout_ms")]
+    pub queue_timeout_ms: u64,
+}
+
+/// A cost class of [`BulkheadConfig`]: queries whose estimated cost is at least
+/// `min_cost` (and below the next class) share `max_concurrent` slots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CostClassConfig {
+    /// Class name, used in logs and as the `compartment` metric label.
+    pub name: String,
+
+    /// Lowest estimated query cost in this class.
+    pub min_cost: usize,
+
+    /// Maximum concurrent executions of queries in this class.
+    pub max_concurrent: usize,
+}
+
+const fn default_max_queue() -> usize {
+    16
+}
+
+const fn default_queue_timeout_ms() -> u64 {
+    1_000
+}
+
+/// Whether `name` is a legal GraphQL name: `[_A-Za-z][_0-9A-Za-z]*`.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        },
+        _ => false,
+    }
+}
+
+impl BulkheadConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if no operation or cost class is configured, a name
+    /// is not a GraphQL name, a limit is zero, two cost classes share a name or a
+    /// `min_cost`, or `queue_timeout_ms` is zero.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.operations.is_empty() && self.cost_classes.is_empty() {
+            return Err("[bulkhead] requires at least one operation or cost class".to_string());
+        }
+        for (name, &max_concurrent) in &self.operations {
+            if !is_valid_name(name) {
+                return Err(format!("bulkhead.operations key `{name}` is not a GraphQL name"));
+            }
+            if max_concurrent == 0 {
+                return Err(format!("bulkhead.operations.{name} must be greater than 0"));
+            }
+        }
+        for (i, class) in self.cost_classes.iter().enumerate() {
+            if !is_valid_name(&class.name) {
+                return Err(format!(
+                    "bulkhead.cost_classes name `{}` must match [_A-Za-z][_0-9A-Za-z]*",
+                    class.name
+                ));
+            }
+            if class.max_concurrent == 0 {
+                return Err(format!(
+                    "bulkhead cost class `{}`: max_concurrent must be greater than 0",
+                    class.name
+                ));
+            }
+            if let Some(other) = self.cost_classes[..i]
+                .iter()
+                .find(|other| other.name == class.name || other.min_cost == class.min_cost)
+            {
+                return Err(format!(
+                    "bulkhead cost classes `{}` and `{}` must have distinct names and min_cost",
+                    other.name, class.name
+                ));
+            }
+        }
+        if self.queue_timeout_ms == 0 {
+            return Err("bulkhead.queue_timeout_ms must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! Canary schema rollout configuration.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for serving a candidate compiled schema next to the current
+/// one (`[canary]`).
+///
+/// The candidate is loaded at serve time and shares the database pool with the
+/// current schema. `traffic_percent` of GraphQL requests are routed to it; a
+/// request carrying the `header` forces a side (`1`/`true`/`candidate` for the
+/// candidate, `0`/`false`/`stable` for the current schema). Request counts, errors
+/// and latencies are exported per side on `/metrics` so the two can be compared
+/// before the candidate is promoted.
+///
+/// # Example (TOML)
+///
+/// ```toml
+/// [canary]
+/// schema_path = "schema.candidate.compiled.json"
+/// traffic_percent = 5
+/// header = "x-fraiseql-canary"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CanaryConfig {
+    /// Path to the candidate compiled schema.
+    pub schema_path: PathBuf,
+
+    /// Percentage of requests (0–100) routed to the candidate.  Default: `0`
+    /// (only requests that opt in through the header).
+    #[serde(default)]
+    pub traffic_percent: u8,
+
+    /// Request header that forces the stable or candidate schema.
+    /// Default: `x-fraiseql-canary`.
+    #[serde(default = "default_canary_header")]
+    pub header: String,
+}
+
+fn default_canary_header() -> String {
+    "x-fraiseql-canary".to_string()
+}
+
+impl CanaryConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `traffic_percent` exceeds 100 or `header` is not
+    /// a valid HTTP header name.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.traffic_percent > 100 {
+            return Err(format!(
+                "canary.traffic_percent must be between 0 and 100, got {}",
+                self.traffic_percent
+            ));
+        }
+        axum::http::HeaderName::try_from(self.header.as_str()).map_err(|e| {
+            format!("canary.header `{}` is not a valid header name: {e}", self.header)
+        })?;
+        Ok(())
+    }
+}
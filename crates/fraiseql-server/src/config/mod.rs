@@ -10,6 +10,9 @@ use std::{collections::HashMap, path::PathBuf};
 
 use serde::Deserialize;
 
+pub mod backup;
+pub mod bulkhead;
+pub mod canary;
 pub mod cors;
 pub mod env;
 pub mod error_sanitization;
@@ -17,18 +20,25 @@ pub mod loader;
 pub mod metrics;
 pub mod pool_tuning;
 pub mod rate_limiting;
+pub mod readiness;
+pub mod shadow;
 #[cfg(test)]
 mod tests;
 pub mod tracing;
 pub mod validation;
 
 // Re-export config types
+pub use backup::BackupConfig;
+pub use bulkhead::{BulkheadConfig, CostClassConfig};
+pub use canary::CanaryConfig;
 pub use cors::CorsConfig;
 pub use error_sanitization::{ErrorSanitizationConfig, ErrorSanitizer};
 pub use metrics::{LatencyTargets, MetricsConfig, SloConfig};
 #[allow(deprecated)] // Reason: re-export deprecated alias for backwards compatibility
 pub use pool_tuning::{PoolPressureMonitorConfig, PoolTuningConfig};
 pub use rate_limiting::{BackpressureConfig, RateLimitRule, RateLimitingConfig};
+pub use readiness::ReadinessConfig;
+pub use shadow::ShadowConfig;
 pub use tracing::TracingConfig;
 
 /// Configuration for durable usage counter persistence.
@@ -57,6 +67,67 @@ const fn default_flush_interval_secs() -> u64 {
     60
 }
 
+/// Configuration for per-field selection analytics.
+///
+/// Add a `[field_usage]` section to `fraiseql.toml` (or `ServerConfig`) to
+/// count which schema fields each client actually selects:
+///
+/// ```toml
+/// [field_usage]
+/// flush_interval_secs = 60
+/// max_clients = 1000
+/// ```
+///
+/// Counters are flushed to the `fraiseql_field_usage` table, read back by
+/// `fraiseql analyze --usage`, and exposed live via
+/// `GET /api/v1/admin/field-usage`.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct FieldUsageConfig {
+    /// How often (in seconds) to flush in-memory counters to PostgreSQL.
+    ///
+    /// Defaults to `60` seconds.
+    #[serde(default = "default_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+
+    /// Maximum number of distinct client ids tracked.
+    ///
+    /// Client ids come from a request header and are therefore caller-chosen;
+    /// requests from clients beyond this cap are counted under `"other"`.
+    /// Defaults to `1000`.
+    #[serde(default = "default_field_usage_max_clients")]
+    pub max_clients: usize,
+}
+
+const fn default_field_usage_max_clients() -> usize {
+    1000
+}
+
+impl Default for FieldUsageConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval_secs: default_flush_interval_secs(),
+            max_clients:         default_field_usage_max_clients(),
+        }
+    }
+}
+
+impl FieldUsageConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first invalid setting.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.flush_interval_secs == 0 {
+            return Err("field_usage.flush_interval_secs must be greater than 0".to_string());
+        }
+        if self.max_clients == 0 {
+            return Err("field_usage.max_clients must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
 /// Root configuration structure loaded from `fraiseql.toml`.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RuntimeConfig {
@@ -302,12 +373,56 @@ fn default_ready_path() -> String {
 #[derive(Debug, Clone, serde::Serialize, Deserialize)]
 pub struct WebhookRouteConfig {
     /// Name of the environment variable that holds the webhook signing secret.
-    pub secret_env: String,
+    pub secret_env:  String,
     /// Webhook provider identifier (e.g. `"github"`, `"stripe"`).
-    pub provider:   String,
+    pub provider:    String,
     /// URL path override; if absent, the route name is used as the path segment.
     #[serde(default)]
-    pub path:       Option<String>,
+    pub path:        Option<String>,
+    /// Shape a verified payload must have. A delivery that does not conform is
+    /// rejected with `400` and nothing is persisted, so the sender's corrected
+    /// redelivery is processed normally.
+    #[serde(default)]
+    pub schema:      Option<WebhookPayloadSchema>,
+    /// Entity type under which verified deliveries are published to observers.
+    /// When set, each newly processed delivery is also written to
+    /// `core.tb_entity_change_log` as a `CUSTOM` event on this entity type,
+    /// inside the delivery transaction, so observers watching
+    /// `(entity_type, CUSTOM)` fire on it.
+    #[serde(default)]
+    pub entity_type: Option<String>,
+}
+
+/// Declarative payload schema for an inbound webhook route.
+///
+/// Paths are dotted (`data.object.id`) and resolve through JSON objects only.
+///
+/// ```toml
+/// [webhooks.stripe.schema]
+/// required    = ["id", "type", "data.object"]
+/// event_types = ["payment_intent.succeeded", "charge.refunded"]
+///
+/// [webhooks.stripe.schema.types]
+/// "data.object.amount" = "integer"
+/// ```
+#[derive(Debug, Clone, Default, serde::Serialize, Deserialize)]
+pub struct WebhookPayloadSchema {
+    /// Paths that must be present and non-null.
+    #[serde(default)]
+    pub required:    Vec<String>,
+    /// Expected JSON type per path: `string`, `number`, `integer`, `boolean`,
+    /// `object`, or `array`. A path that is absent is not type-checked.
+    #[serde(default)]
+    pub types:       std::collections::BTreeMap<String, String>,
+    /// Accepted event types; empty accepts every event type.
+    #[serde(default)]
+    pub event_types: Vec<String>,
+}
+
+impl WebhookPayloadSchema {
+    /// JSON type names accepted in [`types`](Self::types).
+    pub const TYPE_NAMES: &'static [&'static str] =
+        &["string", "number", "integer", "boolean", "object", "array"];
 }
 
 /// Configuration for a file-upload route.
@@ -0,0 +1,71 @@
+//! Readiness probing and startup traffic gating configuration.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the readiness probe and the startup traffic gate
+/// (`[readiness]`).
+///
+/// `GET /readiness` probes every dependency whether or not this section is
+/// present; the section tunes the probes and opts into gating. With
+/// `gate_traffic = true`, every route except the health, readiness and metrics
+/// endpoints answers `503 Service Unavailable` until all critical dependencies
+/// have passed a probe once, so a replica that boots ahead of its database or
+/// secrets backend never serves a request it cannot complete.
+///
+/// # Example (TOML)
+///
+/// ```toml
+/// [readiness]
+/// gate_traffic = true
+/// probe_timeout_ms = 2000
+/// gate_poll_interval_ms = 1000
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReadinessConfig {
+    /// Hold traffic until all critical dependencies are ready.  Default: `false`.
+    #[serde(default)]
+    pub gate_traffic: bool,
+
+    /// Time allowed for each dependency probe.  Default: 2000 ms.
+    #[serde(default = "default_probe_timeout_ms")]
+    pub probe_timeout_ms: u64,
+
+    /// Interval between probes while the gate is closed.  Default: 1000 ms.
+    #[serde(default = "default_gate_poll_interval_ms")]
+    pub gate_poll_interval_ms: u64,
+}
+
+const fn default_probe_timeout_ms() -> u64 {
+    2_000
+}
+
+const fn default_gate_poll_interval_ms() -> u64 {
+    1_000
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        Self {
+            gate_traffic:          false,
+            probe_timeout_ms:      default_probe_timeout_ms(),
+            gate_poll_interval_ms: default_gate_poll_interval_ms(),
+        }
+    }
+}
+
+impl ReadinessConfig {
+    /// Time allowed for each dependency probe.
+    #[must_use]
+    pub const fn probe_timeout(&self) -> Duration {
+        Duration::from_millis(self.probe_timeout_ms)
+    }
+
+    /// Interval between probes while the gate is closed.
+    #[must_use]
+    pub const fn gate_poll_interval(&self) -> Duration {
+        Duration::from_millis(self.gate_poll_interval_ms)
+    }
+}
@@ -0,0 +1,90 @@
+//! Shadow traffic mirroring configuration.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for mirroring a sample of read queries to a shadow executor
+/// (`[shadow]`).
+///
+/// Sampled queries that succeed against the current schema are re-executed in the
+/// background against a secondary schema (`schema_path`), a secondary database
+/// (`database_url`), or both. The two results are compared ignoring array order,
+/// and mismatches are logged with the path of the first difference. The response
+/// returned to the client is never affected.
+///
+/// # Example (TOML)
+///
+/// ```toml
+/// [shadow]
+/// schema_path = "schema.refactored.compiled.json"
+/// sample_percent = 10
+/// max_in_flight = 16
+/// timeout_ms = 5000
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShadowConfig {
+    /// Compiled schema the shadow executor runs. Default: the current schema.
+    #[serde(default)]
+    pub schema_path: Option<PathBuf>,
+
+    /// Database the shadow executor queries. Default: the current database pool.
+    /// Only supported by the PostgreSQL server.
+    #[serde(default)]
+    pub database_url: Option<String>,
+
+    /// Percentage of read queries (0–100) mirrored. Default: `1`.
+    #[serde(default = "default_sample_percent")]
+    pub sample_percent: u8,
+
+    /// Maximum number of mirrored queries running at once; samples beyond it are
+    /// dropped. Default: `16`.
+    #[serde(default = "default_max_in_flight")]
+    pub max_in_flight: usize,
+
+    /// Time allowed for a mirrored query, in milliseconds. Default: `5000`.
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+const fn default_sample_percent() -> u8 {
+    1
+}
+
+const fn default_max_in_flight() -> usize {
+    16
+}
+
+const fn default_timeout_ms() -> u64 {
+    5_000
+}
+
+impl ShadowConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if neither `schema_path` nor `database_url` is set,
+    /// `sample_percent` exceeds 100, or `max_in_flight` or `timeout_ms` is zero.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.schema_path.is_none() && self.database_url.is_none() {
+            return Err("[shadow] requires schema_path, database_url, or both; \
+                 mirroring to the current schema and database compares nothing"
+                .to_string());
+        }
+        if self.sample_percent > 100 {
+            return Err(format!(
+                "shadow.sample_percent must be between 0 and 100, got {}",
+                self.sample_percent
+            ));
+        }
+        if self.max_in_flight == 0 {
+            return Err("shadow.max_in_flight must be greater than 0".to_string());
+        }
+        if self.timeout_ms == 0 {
+            return Err("shadow.timeout_ms must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
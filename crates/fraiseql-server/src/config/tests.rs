@@ -163,6 +163,71 @@ fn test_validation_cross_field() {
     });
 }
 
+#[test]
+fn test_parse_webhook_route_schema_and_entity_type() {
+    let toml = r#"
+        [server]
+        port = 4000
+
+        [database]
+        url_env = "DATABASE_URL"
+
+        [webhooks.stripe]
+        provider = "stripe"
+        secret_env = "STRIPE_WEBHOOK_SECRET"
+        entity_type = "StripeEvent"
+
+        [webhooks.stripe.schema]
+        required = ["id", "data.object"]
+        event_types = ["charge.succeeded"]
+
+        [webhooks.stripe.schema.types]
+        "data.object.amount" = "integer"
+    "#;
+
+    let config: RuntimeConfig = toml::from_str(toml).unwrap();
+    let route = &config.webhooks["stripe"];
+    assert_eq!(route.entity_type.as_deref(), Some("StripeEvent"));
+    let schema = route.schema.as_ref().unwrap();
+    assert_eq!(schema.required, vec!["id", "data.object"]);
+    assert_eq!(schema.event_types, vec!["charge.succeeded"]);
+    assert_eq!(schema.types.get("data.object.amount").map(String::as_str), Some("integer"));
+}
+
+#[test]
+fn test_validation_rejects_unknown_webhook_schema_type() {
+    temp_env::with_vars(
+        [
+            ("DATABASE_URL", Some("postgres://localhost/test")),
+            ("STRIPE_WEBHOOK_SECRET", Some("whsec_test")),
+        ],
+        || {
+            let toml = r#"
+                [server]
+                port = 4000
+
+                [database]
+                url_env = "DATABASE_URL"
+
+                [webhooks.stripe]
+                provider = "stripe"
+                secret_env = "STRIPE_WEBHOOK_SECRET"
+
+                [webhooks.stripe.schema.types]
+                amount = "decimal"
+            "#;
+
+            let config: RuntimeConfig = toml::from_str(toml).unwrap();
+            let result = ConfigValidator::new(&config).validate();
+
+            assert!(result.errors.iter().any(|e| matches!(
+                e,
+                ConfigError::ValidationError { field, .. } if field == "webhooks.stripe.schema.types.amount"
+            )));
+        },
+    );
+}
+
 // ── env_tests ─────────────────────────────────────────────────────────────────
 
 mod env_tests {
@@ -375,6 +440,7 @@ mod error_sanitization_tests {
             request_id:       None,
             retry_after_secs: None,
             detail:           Some("panic at line 42".to_string()),
+            limit:            None,
         });
         let out = s.sanitize(err);
         assert!(
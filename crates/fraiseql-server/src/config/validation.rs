@@ -9,7 +9,7 @@ use std::{collections::HashSet, env};
 
 use fraiseql_error::ConfigError;
 
-use crate::config::RuntimeConfig;
+use crate::config::{RuntimeConfig, WebhookPayloadSchema};
 
 /// Validation result with all errors collected
 pub struct ValidationResult {
@@ -294,6 +294,27 @@ impl<'a> ConfigValidator<'a> {
                     webhook.provider, name
                 ));
             }
+
+            if let Some(schema) = &webhook.schema {
+                for (path, kind) in &schema.types {
+                    if !WebhookPayloadSchema::TYPE_NAMES.contains(&kind.as_str()) {
+                        self.result.add_error(ConfigError::ValidationError {
+                            field:   format!("webhooks.{name}.schema.types.{path}"),
+                            message: format!(
+                                "Unknown JSON type '{kind}'; expected one of: {}",
+                                WebhookPayloadSchema::TYPE_NAMES.join(", ")
+                            ),
+                        });
+                    }
+                }
+            }
+
+            if webhook.entity_type.as_deref().is_some_and(str::is_empty) {
+                self.result.add_error(ConfigError::ValidationError {
+                    field:   format!("webhooks.{name}.entity_type"),
+                    message: "Webhook entity type must not be empty".to_string(),
+                });
+            }
         }
     }
 
@@ -0,0 +1,355 @@
+//! Runtime configuration reload.
+//!
+//! On SIGHUP (or `POST /api/v1/admin/config/reload`) the server re-reads its
+//! TOML configuration through the [`ConfigSource`] it was given, diffs it
+//! against the active configuration, and applies the changes that are safe
+//! while serving:
+//!
+//! | Field           | Effect                                                        |
+//! |-----------------|---------------------------------------------------------------|
+//! | `cors_origins`  | allowed origins swapped for the next request                  |
+//! | `rate_limiting` | in-memory limiter replaced (buckets start full)               |
+//! | `log_level`     | tracing filter replaced                                       |
+//!
+//! Observer definitions live in the database and are re-read on every reload.
+//! Cache TTLs are set per query in the compiled schema, so they follow a schema
+//! reload (SIGUSR1), not a config reload.
+//!
+//! Every other changed field (`bind_addr`, `database_url`, TLS, auth, …) is
+//! bound at startup: it is left untouched and listed in the report's `rejected`
+//! entries with the reason, so the active configuration never drifts into a
+//! state the running server does not reflect. A new configuration that fails
+//! to load or validate is rejected as a whole.
+
+use std::{future::Future, sync::Arc};
+
+use arc_swap::ArcSwap;
+use axum::http::HeaderValue;
+use futures::future::BoxFuture;
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::{
+    ServerConfig, ServerError,
+    middleware::{RateLimiter, cors::DEFAULT_DEV_ORIGIN},
+};
+
+#[cfg(test)]
+mod tests;
+
+type LoadFn = dyn Fn() -> BoxFuture<'static, Result<ServerConfig, String>> + Send + Sync;
+type LogFilterFn = dyn Fn(Option<&str>) -> Result<(), String> + Send + Sync;
+
+/// Where a reload reads the new configuration from.
+///
+/// The binary loads the config file the same way it did at startup (secret
+/// references resolved, CLI and environment overrides re-applied) and passes
+/// the tracing filter's reload handle so `log_level` can change.
+#[derive(Clone)]
+pub struct ConfigSource {
+    load:           Arc<LoadFn>,
+    set_log_filter: Option<Arc<LogFilterFn>>,
+}
+
+impl ConfigSource {
+    /// Read the new configuration with `load`.
+    ///
+    /// `load` should validate the configuration; a reload re-validates it
+    /// regardless.
+    #[must_use]
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ServerConfig, String>> + Send + 'static,
+    {
+        Self {
+            load:           Arc::new(move || Box::pin(load())),
+            set_log_filter: None,
+        }
+    }
+
+    /// Apply `log_level` changes with `set`.
+    ///
+    /// `set` receives the new filter directives, or `None` when `log_level` was
+    /// removed and the startup default should be restored. Without it,
+    /// `log_level` changes are rejected.
+    #[must_use]
+    pub fn with_log_filter<F>(mut self, set: F) -> Self
+    where
+        F: Fn(Option<&str>) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.set_log_filter = Some(Arc::new(set));
+        self
+    }
+}
+
+/// A configuration change that was not applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RejectedChange {
+    /// Top-level configuration field.
+    pub field:  String,
+    /// Why the change was not applied.
+    pub reason: String,
+}
+
+/// Outcome of one configuration reload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ConfigReloadReport {
+    /// Fields whose new value is in effect.
+    pub applied:            Vec<String>,
+    /// Changed fields left at their startup value.
+    pub rejected:           Vec<RejectedChange>,
+    /// Number of observer definitions re-read from the database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observers_reloaded: Option<usize>,
+}
+
+impl ConfigReloadReport {
+    /// Whether every changed field was applied.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.rejected.is_empty()
+    }
+
+    fn reject(&mut self, field: &str, reason: impl Into<String>) {
+        self.rejected.push(RejectedChange {
+            field:  field.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    /// Log the report: `info` when clean, one `warn` per rejected change
+    /// otherwise.
+    pub fn log(&self) {
+        for change in &self.rejected {
+            warn!(field = %change.field, reason = %change.reason, "Config change not applied");
+        }
+        info!(
+            applied = %self.applied.join(", "),
+            rejected = self.rejected.len(),
+            observers_reloaded = self.observers_reloaded,
+            "Configuration reloaded"
+        );
+    }
+}
+
+/// Applies reloaded configuration to the running server.
+///
+/// Owns the runtime-swappable state: the CORS origin list and, when rate limits
+/// come from `[rate_limiting]` with the in-memory backend, the limiter.
+pub struct ConfigReloader {
+    source:           ConfigSource,
+    active:           Mutex<ServerConfig>,
+    cors_origins:     ArcSwap<Vec<HeaderValue>>,
+    rate_limiter:     Option<ArcSwap<RateLimiter>>,
+    #[cfg(feature = "observers")]
+    observer_runtime: Option<Arc<tokio::sync::RwLock<crate::observers::ObserverRuntime>>>,
+}
+
+impl ConfigReloader {
+    /// Create a reloader for a server started with `config`.
+    #[must_use]
+    pub fn new(source: ConfigSource, config: ServerConfig) -> Self {
+        // Like the startup layer, skip entries that are not valid header values.
+        let origins = parse_origins(&config.cors_origins).unwrap_or_else(|_| {
+            config.cors_origins.iter().filter_map(|origin| origin.parse().ok()).collect()
+        });
+        Self {
+            source,
+            active: Mutex::new(config),
+            cors_origins: ArcSwap::from_pointee(origins),
+            rate_limiter: None,
+            #[cfg(feature = "observers")]
+            observer_runtime: None,
+        }
+    }
+
+    /// Make `[rate_limiting]` reloadable, starting from `limiter`.
+    ///
+    /// Only for the in-memory limiter built from `[rate_limiting]`: limits
+    /// compiled into the schema or enforced in Redis are shared with other
+    /// components and replicas, so changing them here would diverge.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(ArcSwap::new(limiter));
+        self
+    }
+
+    /// Re-read observer definitions from the database on every reload.
+    #[cfg(feature = "observers")]
+    #[must_use]
+    pub fn with_observer_runtime(
+        mut self,
+        runtime: Arc<tokio::sync::RwLock<crate::observers::ObserverRuntime>>,
+    ) -> Self {
+        self.observer_runtime = Some(runtime);
+        self
+    }
+
+    /// Whether a request from `origin` passes CORS.
+    #[must_use]
+    pub fn allows_origin(&self, origin: &HeaderValue) -> bool {
+        self.cors_origins.load().contains(origin)
+    }
+
+    /// The current rate limiter, when rate limits are reloadable.
+    #[must_use]
+    pub fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.as_ref().map(ArcSwap::load_full)
+    }
+
+    /// Re-read the configuration and apply the safe changes.
+    ///
+    /// Concurrent reloads are serialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::ConfigError` when the new configuration cannot be
+    /// loaded or is invalid; nothing is applied in that case.
+    pub async fn reload(&self) -> Result<ConfigReloadReport, ServerError> {
+        let mut active = self.active.lock().await;
+        let next = (self.source.load)()
+            .await
+            .map_err(|e| ServerError::ConfigError(format!("Config reload failed: {e}")))?;
+        next.validate()
+            .map_err(|e| ServerError::ConfigError(format!("Config reload rejected: {e}")))?;
+
+        #[allow(unused_mut)] // Reason: only mutated with the `observers` feature
+        let mut report = self.apply(&mut active, &next)?;
+        drop(active);
+
+        #[cfg(feature = "observers")]
+        if let Some(ref runtime) = self.observer_runtime {
+            match runtime.read().await.reload_observers().await {
+                Ok(count) => report.observers_reloaded = Some(count),
+                Err(e) => report.reject("observer_definitions", e.to_string()),
+            }
+        }
+
+        report.log();
+        Ok(report)
+    }
+
+    /// Apply the safe differences between `active` and `next`, updating
+    /// `active` to match only for the fields that were applied.
+    fn apply(
+        &self,
+        active: &mut ServerConfig,
+        next: &ServerConfig,
+    ) -> Result<ConfigReloadReport, ServerError> {
+        let mut report = ConfigReloadReport::default();
+        for field in changed_fields(active, next)? {
+            match field.as_str() {
+                "cors_origins" => match self.apply_cors_origins(active, next) {
+                    Ok(()) => {
+                        active.cors_origins.clone_from(&next.cors_origins);
+                        report.applied.push(field);
+                    },
+                    Err(reason) => report.reject(&field, reason),
+                },
+                "rate_limiting" => match self.apply_rate_limits(next) {
+                    Ok(()) => {
+                        active.rate_limiting.clone_from(&next.rate_limiting);
+                        report.applied.push(field);
+                    },
+                    Err(reason) => report.reject(&field, reason),
+                },
+                "log_level" => match self.apply_log_level(next) {
+                    Ok(()) => {
+                        active.log_level.clone_from(&next.log_level);
+                        report.applied.push(field);
+                    },
+                    Err(reason) => report.reject(&field, reason),
+                },
+                other => report.reject(other, restart_reason(other)),
+            }
+        }
+        Ok(report)
+    }
+
+    fn apply_cors_origins(&self, active: &ServerConfig, next: &ServerConfig) -> Result<(), String> {
+        if !active.cors_enabled {
+            return Err("CORS is disabled; enabling it requires a restart".to_string());
+        }
+        self.cors_origins.store(Arc::new(parse_origins(&next.cors_origins)?));
+        Ok(())
+    }
+
+    fn apply_rate_limits(&self, next: &ServerConfig) -> Result<(), String> {
+        let Some(ref slot) = self.rate_limiter else {
+            return Err("rate limits were not taken from an enabled in-memory [rate_limiting] \
+                        section at startup (schema-defined, Redis-backed or disabled); a restart \
+                        is required"
+                .to_string());
+        };
+        let mut limits = next.rate_limiting.clone().unwrap_or_default();
+        if next.rate_limiting.is_none() {
+            limits.enabled = false;
+        }
+        slot.store(Arc::new(RateLimiter::new(limits)));
+        Ok(())
+    }
+
+    fn apply_log_level(&self, next: &ServerConfig) -> Result<(), String> {
+        let Some(ref set) = self.source.set_log_filter else {
+            return Err("this server was started without a reloadable log filter".to_string());
+        };
+        set(next.log_level.as_deref())
+    }
+}
+
+/// Top-level configuration fields whose value differs, in name order.
+fn changed_fields(active: &ServerConfig, next: &ServerConfig) -> Result<Vec<String>, ServerError> {
+    let to_map = |config: &ServerConfig| match serde_json::to_value(config) {
+        Ok(serde_json::Value::Object(map)) => Ok(map),
+        Ok(_) => Err(ServerError::ConfigError("configuration is not a table".to_string())),
+        Err(e) => Err(ServerError::ConfigError(format!("cannot compare configurations: {e}"))),
+    };
+    let (before, after) = (to_map(active)?, to_map(next)?);
+    let mut fields: Vec<String> = before
+        .keys()
+        .chain(after.keys().filter(|key| !before.contains_key(*key)))
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect();
+    fields.sort();
+    Ok(fields)
+}
+
+/// Parse configured CORS origins, falling back to the development origin as
+/// the startup layer does when none are listed.
+fn parse_origins(origins: &[String]) -> Result<Vec<HeaderValue>, String> {
+    if origins.is_empty() {
+        return Ok(vec![HeaderValue::from_static(DEFAULT_DEV_ORIGIN)]);
+    }
+    origins
+        .iter()
+        .map(|origin| {
+            origin
+                .parse()
+                .map_err(|_| format!("`{origin}` is not a valid origin header value"))
+        })
+        .collect()
+}
+
+/// Why a change to `field` needs a restart.
+fn restart_reason(field: &str) -> &'static str {
+    match field {
+        "bind_addr" | "flight_bind_addr" => {
+            "the listener is bound at startup; a restart is required"
+        },
+        "database_url" | "pool_min_size" | "pool_max_size" | "pool_timeout_secs" => {
+            "database connection pools are created at startup; a restart is required"
+        },
+        "cache_enabled" => {
+            "the query cache is wired into the executor at startup; a restart is required \
+             (cache TTLs are set in the compiled schema and follow a schema reload)"
+        },
+        "observers" => {
+            "[observers] runtime settings are read at startup; observer definitions are \
+             re-read from the database on every reload"
+        },
+        _ => "not reloadable at runtime; a restart is required",
+    }
+}
@@ -0,0 +1,187 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::sync::Arc;
+
+use axum::http::HeaderValue;
+use parking_lot::Mutex;
+
+use super::{ConfigReloader, ConfigSource};
+use crate::{
+    ServerConfig,
+    middleware::{RateLimitConfig, RateLimiter},
+};
+
+/// A source that returns whatever config the test last put in the slot.
+fn source(next: &Arc<Mutex<ServerConfig>>) -> ConfigSource {
+    let next = next.clone();
+    ConfigSource::new(move || {
+        let config = next.lock().clone();
+        async move { Ok(config) }
+    })
+}
+
+fn config_with_origins(origins: &[&str]) -> ServerConfig {
+    ServerConfig {
+        cors_origins: origins.iter().map(ToString::to_string).collect(),
+        ..ServerConfig::default()
+    }
+}
+
+fn limits(rps_per_ip: u32) -> RateLimitConfig {
+    RateLimitConfig {
+        enabled: true,
+        rps_per_ip,
+        ..RateLimitConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn cors_origins_are_swapped_in_place() {
+    let startup = config_with_origins(&["https://old.example.com"]);
+    let next = Arc::new(Mutex::new(config_with_origins(&["https://new.example.com"])));
+    let reloader = ConfigReloader::new(source(&next), startup);
+
+    assert!(reloader.allows_origin(&HeaderValue::from_static("https://old.example.com")));
+
+    let report = reloader.reload().await.unwrap();
+
+    assert_eq!(report.applied, vec!["cors_origins"]);
+    assert!(report.is_clean());
+    assert!(reloader.allows_origin(&HeaderValue::from_static("https://new.example.com")));
+    assert!(!reloader.allows_origin(&HeaderValue::from_static("https://old.example.com")));
+}
+
+#[tokio::test]
+async fn empty_origins_fall_back_to_the_development_origin() {
+    let next = Arc::new(Mutex::new(config_with_origins(&[])));
+    let reloader = ConfigReloader::new(source(&next), config_with_origins(&["https://a.example"]));
+
+    reloader.reload().await.unwrap();
+
+    assert!(reloader.allows_origin(&HeaderValue::from_static("http://localhost:3000")));
+}
+
+#[tokio::test]
+async fn unsafe_changes_are_rejected_and_stay_pending() {
+    let mut changed = ServerConfig::default();
+    changed.bind_addr = "127.0.0.1:9999".parse().unwrap();
+    changed.database_url = "postgresql://elsewhere/db".to_string();
+    let next = Arc::new(Mutex::new(changed));
+    let reloader = ConfigReloader::new(source(&next), ServerConfig::default());
+
+    let report = reloader.reload().await.unwrap();
+
+    assert!(report.applied.is_empty());
+    let fields: Vec<&str> = report.rejected.iter().map(|r| r.field.as_str()).collect();
+    assert_eq!(fields, vec!["bind_addr", "database_url"]);
+    assert!(report.rejected[0].reason.contains("listener is bound at startup"));
+    assert!(report.rejected[1].reason.contains("connection pools"));
+
+    // The active config kept the startup values, so the next reload reports them again.
+    let again = reloader.reload().await.unwrap();
+    assert_eq!(again.rejected.len(), 2);
+}
+
+#[tokio::test]
+async fn rate_limits_need_a_reloadable_limiter() {
+    let startup = ServerConfig {
+        rate_limiting: Some(limits(100)),
+        ..ServerConfig::default()
+    };
+    let next = Arc::new(Mutex::new(ServerConfig {
+        rate_limiting: Some(limits(5)),
+        ..ServerConfig::default()
+    }));
+
+    let fixed = ConfigReloader::new(source(&next), startup.clone());
+    let report = fixed.reload().await.unwrap();
+    assert_eq!(report.rejected[0].field, "rate_limiting");
+    assert!(fixed.rate_limiter().is_none());
+
+    let reloadable = ConfigReloader::new(source(&next), startup)
+        .with_rate_limiter(Arc::new(RateLimiter::new(limits(100))));
+    let report = reloadable.reload().await.unwrap();
+    assert_eq!(report.applied, vec!["rate_limiting"]);
+    assert_eq!(reloadable.rate_limiter().unwrap().config().rps_per_ip, 5);
+}
+
+#[tokio::test]
+async fn removing_rate_limiting_disables_the_limiter() {
+    let startup = ServerConfig {
+        rate_limiting: Some(limits(100)),
+        ..ServerConfig::default()
+    };
+    let next = Arc::new(Mutex::new(ServerConfig::default()));
+    let reloader = ConfigReloader::new(source(&next), startup)
+        .with_rate_limiter(Arc::new(RateLimiter::new(limits(100))));
+
+    reloader.reload().await.unwrap();
+
+    assert!(!reloader.rate_limiter().unwrap().config().enabled);
+}
+
+#[tokio::test]
+async fn log_level_goes_through_the_log_filter() {
+    let next = Arc::new(Mutex::new(ServerConfig {
+        log_level: Some("fraiseql_server=debug".to_string()),
+        ..ServerConfig::default()
+    }));
+
+    let without_filter = ConfigReloader::new(source(&next), ServerConfig::default());
+    let report = without_filter.reload().await.unwrap();
+    assert_eq!(report.rejected[0].field, "log_level");
+
+    let applied = Arc::new(Mutex::new(None));
+    let recorder = applied.clone();
+    let with_filter = ConfigReloader::new(
+        source(&next).with_log_filter(move |directives| {
+            *recorder.lock() = directives.map(ToString::to_string);
+            Ok(())
+        }),
+        ServerConfig::default(),
+    );
+    let report = with_filter.reload().await.unwrap();
+    assert_eq!(report.applied, vec!["log_level"]);
+    assert_eq!(applied.lock().as_deref(), Some("fraiseql_server=debug"));
+}
+
+#[tokio::test]
+async fn invalid_config_is_rejected_as_a_whole() {
+    let next = Arc::new(Mutex::new(ServerConfig {
+        cors_origins: vec!["https://new.example.com".to_string()],
+        log_level: Some("fraiseql_server=[".to_string()),
+        ..ServerConfig::default()
+    }));
+    let reloader =
+        ConfigReloader::new(source(&next), config_with_origins(&["https://old.example.com"]));
+
+    let err = reloader.reload().await.unwrap_err();
+
+    assert!(err.to_string().contains("log_level"), "{err}");
+    assert!(reloader.allows_origin(&HeaderValue::from_static("https://old.example.com")));
+}
+
+#[tokio::test]
+async fn load_failure_is_reported() {
+    let source = ConfigSource::new(|| async { Err("failed to parse config file".to_string()) });
+    let reloader = ConfigReloader::new(source, ServerConfig::default());
+
+    let err = reloader.reload().await.unwrap_err();
+
+    assert!(err.to_string().contains("failed to parse config file"), "{err}");
+}
+
+#[tokio::test]
+async fn report_serializes_rejections() {
+    let mut changed = ServerConfig::default();
+    changed.cache_enabled = !changed.cache_enabled;
+    let next = Arc::new(Mutex::new(changed));
+    let reloader = ConfigReloader::new(source(&next), ServerConfig::default());
+
+    let report = reloader.reload().await.unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["applied"], serde_json::json!([]));
+    assert_eq!(json["rejected"][0]["field"], "cache_enabled");
+    assert!(json.get("observers_reloaded").is_none());
+}
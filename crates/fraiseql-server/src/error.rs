@@ -4,13 +4,35 @@
 //! - Error codes for client-side handling
 //! - Location tracking in queries
 //! - Extensions for custom error data
+//!
+//! # Extension contract
+//!
+//! Every error carries a stable `extensions` object, whatever produced it:
+//!
+//! ```json
+//! {
+//!   "message": "Query exceeded timeout",
+//!   "code": "TIMEOUT",
+//!   "extensions": {
+//!     "code": "TIMEOUT",
+//!     "docs_url": "https://docs.fraiseql.dev/errors#TIMEOUT",
+//!     "retryable": true,
+//!     "request_id": "req-5f0c…"
+//!   }
+//! }
+//! ```
+//!
+//! `request_id` is the correlation id: the caller's `X-Request-ID` when one was
+//! sent, otherwise a generated id. It is echoed in the `X-Request-ID` header of
+//! error responses and recorded on the request's tracing span. Optional keys (`category`,
+//! `status`, `retry_after_secs`, `detail`, `limit`) follow when set.
 
 use axum::{
     Json,
     http::StatusCode,
     response::{IntoResponse, Response},
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 
 /// GraphQL error code enumeration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -65,6 +87,11 @@ pub enum ErrorCode {
     /// authenticated users). A well-formed GraphQL request, so it returns
     /// 200 + `errors[]`.
     IntrospectionDisabled,
+    /// Operation cancelled before it completed (e.g. the caller went away or a
+    /// statement was cancelled server-side).
+    Cancelled,
+    /// Operation not supported by the configured database backend (HTTP 501).
+    UnsupportedOperation,
 }
 
 impl ErrorCode {
@@ -101,14 +128,68 @@ impl ErrorCode {
             Self::NotFound => StatusCode::NOT_FOUND,
             Self::Conflict => StatusCode::CONFLICT,
             Self::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
-            Self::Timeout => StatusCode::REQUEST_TIMEOUT,
+            Self::Timeout | Self::Cancelled => StatusCode::REQUEST_TIMEOUT,
             Self::InternalServerError | Self::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
             Self::CircuitBreakerOpen | Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
             // GraphQL-over-HTTP: a mutation sent over GET is rejected at the transport
             // layer (405), not returned as a 2xx GraphQL error.
             Self::MethodNotAllowed => StatusCode::METHOD_NOT_ALLOWED,
+            Self::UnsupportedOperation => StatusCode::NOT_IMPLEMENTED,
+        }
+    }
+
+    /// Stable machine-readable form of this code, as serialized in responses.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ValidationError => "VALIDATION_ERROR",
+            Self::ParseError => "PARSE_ERROR",
+            Self::RequestError => "REQUEST_ERROR",
+            Self::Unauthenticated => "UNAUTHENTICATED",
+            Self::Forbidden => "FORBIDDEN",
+            Self::InternalServerError => "INTERNAL_SERVER_ERROR",
+            Self::DatabaseError => "DATABASE_ERROR",
+            Self::BadUserInput => "BAD_USER_INPUT",
+            Self::ConstraintViolation => "CONSTRAINT_VIOLATION",
+            Self::Timeout => "TIMEOUT",
+            Self::RateLimitExceeded => "RATE_LIMIT_EXCEEDED",
+            Self::NotFound => "NOT_FOUND",
+            Self::Conflict => "CONFLICT",
+            Self::CircuitBreakerOpen => "CIRCUIT_BREAKER_OPEN",
+            Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            Self::PersistedQueryNotFound => "PERSISTED_QUERY_NOT_FOUND",
+            Self::PersistedQueryMismatch => "PERSISTED_QUERY_MISMATCH",
+            Self::ForbiddenQuery => "FORBIDDEN_QUERY",
+            Self::DocumentNotFound => "DOCUMENT_NOT_FOUND",
+            Self::MethodNotAllowed => "METHOD_NOT_ALLOWED",
+            Self::IntrospectionDisabled => "INTROSPECTION_DISABLED",
+            Self::Cancelled => "CANCELLED",
+            Self::UnsupportedOperation => "UNSUPPORTED_OPERATION",
         }
     }
+
+    /// Whether an error with this code may succeed if the same request is sent
+    /// again unchanged (after `retry_after_secs`, when present).
+    ///
+    /// Individual errors can override this via [`GraphQLError::with_retryable`]
+    /// when the underlying failure is known to be transient.
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::Timeout
+                | Self::Cancelled
+                | Self::RateLimitExceeded
+                | Self::CircuitBreakerOpen
+                | Self::ServiceUnavailable
+        )
+    }
+
+    /// Documentation URL for this code (see [`fraiseql_error::error_docs_url`]).
+    #[must_use]
+    pub fn docs_url(self) -> String {
+        fraiseql_error::error_docs_url(self.as_str())
+    }
 }
 
 /// A Postgres SQLSTATE class that indicates a **client-input** fault (HTTP 4xx)
@@ -150,7 +231,11 @@ pub struct ErrorLocation {
 }
 
 /// GraphQL error following spec.
-#[derive(Debug, Clone, Serialize)]
+///
+/// Serializes with the extension contract described in the [module docs](self):
+/// `extensions.code`, `extensions.docs_url` and `extensions.retryable` are
+/// always present.
+#[derive(Debug, Clone)]
 pub struct GraphQLError {
     /// Error message.
     pub message: String,
@@ -159,16 +244,16 @@ pub struct GraphQLError {
     pub code: ErrorCode,
 
     /// Location in query where error occurred.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub locations: Option<Vec<ErrorLocation>>,
 
     /// Path to field that caused error.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<Vec<String>>,
 
     /// Additional error information.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub extensions: Option<ErrorExtensions>,
+
+    /// Retryability override; `None` defers to [`ErrorCode::is_retryable`].
+    pub retryable: Option<bool>,
 }
 
 /// Additional error context and debugging information.
@@ -182,7 +267,7 @@ pub struct ErrorExtensions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<u16>,
 
-    /// Request ID for tracking.
+    /// Correlation id for tracking the request across client and server logs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
 
@@ -195,6 +280,21 @@ pub struct ErrorExtensions {
     /// Stripped from responses when error sanitization is enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
+
+    /// The query limit the request violated (set for limit validation errors).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<LimitViolation>,
+}
+
+/// A violated query limit, reported as `extensions.limit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LimitViolation {
+    /// Configuration key of the limit (e.g. `max_query_depth`, `max_root_fields`).
+    pub name:   String,
+    /// Configured maximum in effect for the caller.
+    pub max:    usize,
+    /// Value measured on the rejected query.
+    pub actual: usize,
 }
 
 /// GraphQL response with errors.
@@ -213,9 +313,23 @@ impl GraphQLError {
             locations: None,
             path: None,
             extensions: None,
+            retryable: None,
         }
     }
 
+    /// Whether the client may retry the request unchanged.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.retryable.unwrap_or(self.code.is_retryable())
+    }
+
+    /// Override the retryability implied by [`code`](Self::code).
+    #[must_use]
+    pub const fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
     /// Add location to error.
     #[must_use]
     pub fn with_location(mut self, line: usize, column: usize) -> Self {
@@ -247,6 +361,7 @@ impl GraphQLError {
             request_id:       None,
             retry_after_secs: None,
             detail:           None,
+            limit:            None,
         });
 
         self.extensions = Some(ErrorExtensions {
@@ -261,6 +376,30 @@ impl GraphQLError {
         Self::new(message, ErrorCode::ValidationError)
     }
 
+    /// Validation error for a query that exceeds a configured limit.
+    ///
+    /// The violated limit is carried in `extensions.limit` so clients can tell
+    /// which limit to stay under without parsing the message.
+    pub fn limit_exceeded(
+        message: impl Into<String>,
+        name: impl Into<String>,
+        max: usize,
+        actual: usize,
+    ) -> Self {
+        Self::validation(message).with_extensions(ErrorExtensions {
+            category:         None,
+            status:           None,
+            request_id:       None,
+            retry_after_secs: None,
+            detail:           None,
+            limit:            Some(LimitViolation {
+                name: name.into(),
+                max,
+                actual,
+            }),
+        })
+    }
+
     /// Parse error with hint for common syntax issues.
     pub fn parse(message: impl Into<String>) -> Self {
         Self::new(message, ErrorCode::ParseError)
@@ -335,7 +474,7 @@ impl GraphQLError {
     #[must_use]
     pub fn from_fraiseql_error(err: &fraiseql_core::error::FraiseQLError) -> Self {
         use fraiseql_core::error::FraiseQLError as E;
-        match err {
+        let error = match err {
             // Classify client-input DB faults (SQLSTATE 22xxx/23xxx) as 400 rather than
             // 500 (#413); genuine server faults (other classes, no SQLSTATE, connection
             // pool) stay 500 / DATABASE_ERROR.
@@ -360,10 +499,16 @@ impl GraphQLError {
             E::Authorization { .. } => Self::forbidden(),
             E::Authentication { .. } => Self::unauthenticated(),
             E::Timeout { .. } => Self::new(err.to_string(), ErrorCode::Timeout),
+            E::Cancelled { .. } => Self::new(err.to_string(), ErrorCode::Cancelled),
             E::RateLimited { message, .. } => Self::rate_limited(message.clone()),
-            // Cancelled, Configuration, Internal, and any future variants
+            E::ServiceUnavailable { retry_after, .. } => {
+                Self::service_unavailable(err.to_string(), *retry_after)
+            },
+            E::Unsupported { .. } => Self::new(err.to_string(), ErrorCode::UnsupportedOperation),
+            // Configuration, Internal, and any future variants
             _ => Self::internal(err.to_string()),
-        }
+        };
+        error.with_retryable(is_transient(err))
     }
 
     /// Persisted query not found — client must re-send the full query body.
@@ -419,6 +564,7 @@ impl GraphQLError {
             request_id:       None,
             retry_after_secs: Some(retry_after_secs),
             detail:           None,
+            limit:            None,
         })
     }
 
@@ -434,10 +580,63 @@ impl GraphQLError {
             request_id: None,
             retry_after_secs,
             detail: None,
+            limit: None,
         })
     }
 }
 
+/// Whether a core error is transient, so the same request may succeed on retry.
+///
+/// Extends [`FraiseQLError::is_retryable`](fraiseql_core::error::FraiseQLError::is_retryable)
+/// with SQLSTATE class `40` (transaction rollback: serialization failure,
+/// deadlock), where the statement is known to have had no effect.
+fn is_transient(err: &fraiseql_core::error::FraiseQLError) -> bool {
+    use fraiseql_core::error::FraiseQLError as E;
+    match err {
+        E::Database { sql_state, .. } => sql_state.as_deref().is_some_and(|s| s.starts_with("40")),
+        E::RateLimited { .. } => true,
+        _ => err.is_retryable(),
+    }
+}
+
+impl Serialize for GraphQLError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Wire<'a> {
+            message:    &'a str,
+            code:       ErrorCode,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            locations:  Option<&'a Vec<ErrorLocation>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            path:       Option<&'a Vec<String>>,
+            extensions: WireExtensions<'a>,
+        }
+
+        #[derive(Serialize)]
+        struct WireExtensions<'a> {
+            code:      ErrorCode,
+            docs_url:  String,
+            retryable: bool,
+            #[serde(flatten)]
+            extra:     Option<&'a ErrorExtensions>,
+        }
+
+        Wire {
+            message:    &self.message,
+            code:       self.code,
+            locations:  self.locations.as_ref(),
+            path:       self.path.as_ref(),
+            extensions: WireExtensions {
+                code:      self.code,
+                docs_url:  self.code.docs_url(),
+                retryable: self.is_retryable(),
+                extra:     self.extensions.as_ref(),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
 impl ErrorResponse {
     /// Create new error response.
     #[must_use]
@@ -452,6 +651,23 @@ impl ErrorResponse {
             errors: vec![error],
         }
     }
+
+    /// Stamp the request's correlation id on every error that does not carry one yet.
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: &str) -> Self {
+        self.errors = self
+            .errors
+            .into_iter()
+            .map(|error| {
+                if error.extensions.as_ref().is_some_and(|ext| ext.request_id.is_some()) {
+                    error
+                } else {
+                    error.with_request_id(request_id)
+                }
+            })
+            .collect();
+        self
+    }
 }
 
 impl IntoResponse for ErrorResponse {
@@ -467,6 +683,13 @@ impl IntoResponse for ErrorResponse {
             .and_then(|e| e.extensions.as_ref())
             .and_then(|ext| ext.retry_after_secs);
 
+        let request_id = self
+            .errors
+            .first()
+            .and_then(|e| e.extensions.as_ref())
+            .and_then(|ext| ext.request_id.as_deref())
+            .and_then(|id| axum::http::HeaderValue::from_str(id).ok());
+
         let mut response = (status, Json(self)).into_response();
 
         if let Some(secs) = retry_after {
@@ -474,6 +697,9 @@ impl IntoResponse for ErrorResponse {
                 response.headers_mut().insert(axum::http::header::RETRY_AFTER, value);
             }
         }
+        if let Some(value) = request_id {
+            response.headers_mut().insert("x-request-id", value);
+        }
 
         response
     }
@@ -15,6 +15,16 @@
 //! together. A persisted message then fires its `after:ingest` functions on the
 //! I/O-capable host, including the `fraiseql_query` bridge under each function's
 //! `run_as` ceiling (#594).
+//!
+//! Per route, two optional stages run inside the same transaction once the
+//! signature is trusted: the payload is checked against the route's
+//! [`schema`](crate::config::WebhookPayloadSchema) (a non-conforming delivery is
+//! `400` and rolls back), and a route with an `entity_type` publishes the
+//! delivery to the observer pipeline as a `CUSTOM` `EntityEvent` through the
+//! change-log outbox.
+
+mod entity_event;
+mod schema;
 
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -31,12 +41,16 @@ use fraiseql_functions::{
 };
 use fraiseql_webhooks::{
     Delivery, Disposition, EventHandler, PostgresIdempotencyStore, Result as WebhookResult,
-    StaticSecretProvider, WebhookError, WebhookPipeline, signature::ProviderRegistry,
+    StaticSecretProvider, WebhookError, WebhookPipeline,
+    signature::{ProviderRegistry, generic::HmacSha256Verifier},
 };
 use serde_json::{Value, json};
 use sqlx::{PgPool, Postgres, Transaction};
 
-use crate::{config::WebhookRouteConfig, inbound::spine::emit_in_tx};
+use crate::{
+    config::{WebhookPayloadSchema, WebhookRouteConfig},
+    inbound::spine::{Emitted, emit_in_tx},
+};
 
 /// A push [`Source`] for one webhook provider.
 ///
@@ -91,25 +105,51 @@ impl PushSource for WebhookSource {
 ///
 /// The route pre-normalizes the delivery and passes the [`InboundMessage`] as the
 /// delivery params; this handler runs inside the pipeline's transaction, so its
-/// spine write is atomic with the pipeline's idempotency claim.
-struct SpineEventHandler;
+/// spine write is atomic with the pipeline's idempotency claim. The delivery's
+/// function name is the route's path segment, which selects the route's schema
+/// and observer entity type.
+struct SpineEventHandler {
+    routes: Arc<BTreeMap<String, ResolvedRoute>>,
+}
 
 impl EventHandler for SpineEventHandler {
     async fn handle(
         &self,
-        _function_name: &str,
+        function_name: &str,
         params: Value,
         tx: &mut Transaction<'_, Postgres>,
     ) -> WebhookResult<Value> {
         let message: InboundMessage = serde_json::from_value(params)?;
-        emit_in_tx(tx, &message)
+        let route = self.routes.get(function_name);
+
+        if let Some(schema) = route.and_then(|r| r.schema.as_ref()) {
+            let payload = message.payload.as_ref().unwrap_or(&Value::Null);
+            schema::validate(schema, payload, message.subject.as_deref().unwrap_or_default())
+                .map_err(|violations| WebhookError::InvalidPayload(violations.join("; ")))?;
+        }
+
+        let emitted = emit_in_tx(tx, &message)
             .await
             .map_err(|error| WebhookError::Database(error.to_string()))?;
+
+        // Publish to observers only on a fresh spine write, so a redelivery that
+        // slipped past the idempotency ledger still yields one event.
+        if let (Emitted::New(message_id), Some(entity_type)) =
+            (emitted, route.and_then(|r| r.entity_type.as_deref()))
+        {
+            entity_event::publish_in_tx(tx, entity_type, message_id, &message)
+                .await
+                .map_err(|error| WebhookError::Database(error.to_string()))?;
+        }
+
         // Hand the normalized message back so the route can dispatch `after:ingest`.
         serde_json::to_value(&message).map_err(Into::into)
     }
 }
 
+/// Configuration name of the generic HMAC-SHA256 provider.
+const GENERIC_HMAC_PROVIDER: &str = "generic_hmac";
+
 /// A configured inbound webhook route: which provider verifier to use and which
 /// named secret resolves its signing key.
 #[derive(Debug, Clone)]
@@ -118,6 +158,10 @@ struct ResolvedRoute {
     provider:    String,
     /// Secret name resolved by the pipeline's secret provider.
     secret_name: String,
+    /// Payload schema checked after verification, if configured.
+    schema:      Option<WebhookPayloadSchema>,
+    /// Observer entity type the delivery is published under, if configured.
+    entity_type: Option<String>,
 }
 
 /// The concrete pipeline used by the inbound webhook adapter.
@@ -149,7 +193,8 @@ impl WebhookInboundState {
     /// `get_env` resolves each route's `secret_env` to its signing secret (in
     /// production, `std::env::var`); a route whose secret is absent is skipped
     /// with a warning rather than mounted without a key. The path segment is the
-    /// route's `path` override or, failing that, its config key.
+    /// route's `path` override or, failing that, its config key. `generic_hmac`
+    /// names the HMAC-SHA256 verifier (`X-Signature` header).
     #[must_use]
     pub fn new(
         pool: PgPool,
@@ -177,18 +222,37 @@ impl WebhookInboundState {
                 ResolvedRoute {
                     provider:    config.provider.clone(),
                     secret_name: config.secret_env.clone(),
+                    schema:      config.schema.clone(),
+                    entity_type: config.entity_type.clone(),
                 },
             );
         }
 
+        let mut registry = ProviderRegistry::new();
+        registry.register(GENERIC_HMAC_PROVIDER, Arc::new(HmacSha256Verifier));
+        for (segment, route) in &resolved {
+            if registry.get(&route.provider).is_none() {
+                tracing::error!(
+                    route = %segment,
+                    provider = %route.provider,
+                    "inbound webhook route has no signature verifier for its provider; \
+                     every delivery will be rejected"
+                );
+            }
+        }
+
+        let routes = Arc::new(resolved);
         let store = PostgresIdempotencyStore::new(pool.clone());
-        let pipeline = WebhookPipeline::new(pool, secrets, store, SpineEventHandler);
+        let handler = SpineEventHandler {
+            routes: Arc::clone(&routes),
+        };
+        let pipeline = WebhookPipeline::new(pool, secrets, store, handler);
 
         Self {
-            pipeline:               Arc::new(pipeline),
-            registry:               Arc::new(ProviderRegistry::new()),
-            routes:                 Arc::new(resolved),
-            hooks:                  None,
+            pipeline: Arc::new(pipeline),
+            registry: Arc::new(registry),
+            routes,
+            hooks: None,
             query_executor_factory: None,
         }
     }
@@ -280,8 +344,9 @@ fn json_status(status: StatusCode, body: &Value) -> Response {
 /// `POST /webhooks/{provider}` — verify, normalize, and persist an inbound delivery.
 ///
 /// On success returns `200` with `{"status":"processed"|"duplicate"}`. A forged
-/// signature is `401`, a malformed payload `400`, a server-side misconfiguration
-/// `500` — routed by the pipeline's error mapping.
+/// signature is `401`, a malformed payload or one violating the route's schema
+/// `400`, a server-side misconfiguration `500` — routed by the pipeline's error
+/// mapping.
 pub async fn webhook_handler(
     State(state): State<WebhookInboundState>,
     Path(provider): Path<String>,
@@ -0,0 +1,70 @@
+//! Publication of verified webhook deliveries to the observer pipeline.
+//!
+//! A route with an `entity_type` turns each newly processed delivery into an
+//! `EntityEvent` by writing it to the change-log outbox
+//! (`core.tb_entity_change_log`) inside the delivery transaction. The observer
+//! runtime's change-log listener then converts the row into an `EntityEvent`
+//! exactly as it does for a mutation's outbox row, so observer matching,
+//! retries, the DLQ and the subscription bridge apply unchanged. Writing through
+//! the outbox rather than handing the event to the runtime in memory keeps the
+//! publication atomic with the idempotency claim: a delivery is either persisted
+//! and published, or neither.
+//!
+//! The row is a `CUSTOM` event whose entity id is the delivery's durable spine
+//! message id and whose data is the provider payload. Provenance is recorded in
+//! `extra_metadata`.
+
+#[cfg(test)]
+mod tests;
+
+use fraiseql_functions::InboundMessage;
+use serde_json::{Value, json};
+use sqlx::{Postgres, Transaction};
+
+/// The change-log `modification_type` of a published webhook delivery; the
+/// listener maps it to `EventKind::Custom`.
+pub(super) const WEBHOOK_MODIFICATION_TYPE: &str = "CUSTOM";
+
+/// The `extra_metadata` recorded on a published delivery's change-log row.
+pub(super) fn provenance(message: &InboundMessage) -> Value {
+    json!({
+        "inbound_source": message.source.as_key(),
+        "idempotency_key": message.idempotency_key,
+        "event_type": message.subject,
+    })
+}
+
+/// Write a verified delivery to the change-log outbox as a `CUSTOM` event on
+/// `entity_type`, within the delivery transaction.
+///
+/// # Errors
+///
+/// Returns [`FraiseQLError::Database`](fraiseql_error::FraiseQLError::Database)
+/// if the insert fails — most often because the change-log contract table has
+/// not been installed.
+pub(super) async fn publish_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    entity_type: &str,
+    entity_id: uuid::Uuid,
+    message: &InboundMessage,
+) -> fraiseql_error::Result<()> {
+    let data = message.payload.clone().unwrap_or(Value::Null);
+    sqlx::query(
+        "INSERT INTO core.tb_entity_change_log \
+             (object_type, modification_type, object_id, object_data, extra_metadata) \
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(entity_type)
+    .bind(WEBHOOK_MODIFICATION_TYPE)
+    .bind(entity_id)
+    .bind(sqlx::types::Json(data))
+    .bind(sqlx::types::Json(provenance(message)))
+    .execute(&mut **tx)
+    .await
+    .map_err(|error| {
+        fraiseql_error::FraiseQLError::database(format!(
+            "inbound webhook: publish {entity_type} event to the change log: {error}"
+        ))
+    })?;
+    Ok(())
+}
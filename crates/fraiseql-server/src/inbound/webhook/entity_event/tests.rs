@@ -0,0 +1,89 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+#![allow(clippy::print_stderr)] // Reason: skip message when no backing Postgres is available
+
+use fraiseql_functions::{InboundMessage, IngestSource};
+use serde_json::{Value, json};
+use sqlx::{PgPool, Row};
+
+use super::{WEBHOOK_MODIFICATION_TYPE, provenance, publish_in_tx};
+
+fn message() -> InboundMessage {
+    let mut message = InboundMessage::new(
+        IngestSource::Webhook {
+            provider: "stripe".to_string(),
+        },
+        "evt_1",
+        chrono::Utc::now(),
+    );
+    message.subject = Some("charge.succeeded".to_string());
+    message.payload = Some(json!({ "id": "evt_1", "amount": 1200 }));
+    message
+}
+
+#[test]
+fn provenance_records_source_key_and_event_type() {
+    assert_eq!(
+        provenance(&message()),
+        json!({
+            "inbound_source": "webhook:stripe",
+            "idempotency_key": "evt_1",
+            "event_type": "charge.succeeded",
+        })
+    );
+}
+
+/// The published row carries the shape the change-log listener turns into a
+/// `CUSTOM` `EntityEvent`: entity type, UUID entity id, and the payload as data.
+#[tokio::test]
+async fn publish_writes_a_custom_change_log_row_in_the_transaction() {
+    let Some(svc) = fraiseql_test_support::postgres().await else {
+        eprintln!(
+            "SKIP publish_writes_a_custom_change_log_row_in_the_transaction: no postgres (set DATABASE_URL or enable fraiseql-test-support/local-testcontainers)"
+        );
+        return;
+    };
+    let pool = PgPool::connect(svc.url()).await.unwrap();
+    sqlx::raw_sql(
+        "CREATE SCHEMA IF NOT EXISTS core;
+         CREATE TABLE IF NOT EXISTS core.tb_entity_change_log (
+             pk_entity_change_log BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+             object_type          TEXT NOT NULL,
+             modification_type    TEXT NOT NULL,
+             object_id            UUID,
+             object_data          JSONB,
+             extra_metadata       JSONB
+         );",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let entity_id = uuid::Uuid::new_v4();
+    let mut tx = pool.begin().await.unwrap();
+    publish_in_tx(&mut tx, "StripeEvent", entity_id, &message()).await.unwrap();
+
+    let row = sqlx::query(
+        "SELECT object_type, modification_type, object_data, extra_metadata \
+         FROM core.tb_entity_change_log WHERE object_id = $1",
+    )
+    .bind(entity_id)
+    .fetch_one(&mut *tx)
+    .await
+    .unwrap();
+    assert_eq!(row.get::<String, _>("object_type"), "StripeEvent");
+    assert_eq!(row.get::<String, _>("modification_type"), WEBHOOK_MODIFICATION_TYPE);
+    let data: sqlx::types::Json<Value> = row.get("object_data");
+    assert_eq!(data.0["amount"], 1200);
+    let meta: sqlx::types::Json<Value> = row.get("extra_metadata");
+    assert_eq!(meta.0["inbound_source"], "webhook:stripe");
+
+    // Nothing is published unless the delivery transaction commits.
+    tx.rollback().await.unwrap();
+    let count: i64 =
+        sqlx::query_scalar("SELECT count(*) FROM core.tb_entity_change_log WHERE object_id = $1")
+            .bind(entity_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,66 @@
+//! Payload schema validation for inbound webhook routes.
+//!
+//! Runs inside the delivery transaction, after the signature is verified, so an
+//! unauthenticated caller learns nothing about a route's schema and a rejected
+//! delivery rolls back its idempotency claim (the sender's corrected redelivery
+//! is processed normally).
+
+#[cfg(test)]
+mod tests;
+
+use serde_json::Value;
+
+use crate::config::WebhookPayloadSchema;
+
+/// Resolve a dotted path through nested JSON objects.
+fn lookup<'a>(payload: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(payload, |value, segment| value.get(segment))
+}
+
+/// Whether `value` has the JSON type named `kind`.
+fn has_type(value: &Value, kind: &str) -> bool {
+    match kind {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        _ => false,
+    }
+}
+
+/// Check a verified payload against a route's schema.
+///
+/// Returns every violation rather than the first, in a stable order (event
+/// type, then required paths, then typed paths), so a sender fixing its payload
+/// sees the whole picture at once.
+pub(super) fn validate(
+    schema: &WebhookPayloadSchema,
+    payload: &Value,
+    event_type: &str,
+) -> Result<(), Vec<String>> {
+    let mut violations = Vec::new();
+
+    if !schema.event_types.is_empty() && !schema.event_types.iter().any(|t| t == event_type) {
+        violations.push(format!("event type '{event_type}' is not accepted by this route"));
+    }
+    for path in &schema.required {
+        if lookup(payload, path).is_none_or(Value::is_null) {
+            violations.push(format!("missing required field '{path}'"));
+        }
+    }
+    for (path, kind) in &schema.types {
+        if let Some(value) = lookup(payload, path).filter(|v| !v.is_null()) {
+            if !has_type(value, kind) {
+                violations.push(format!("field '{path}' must be of type {kind}"));
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
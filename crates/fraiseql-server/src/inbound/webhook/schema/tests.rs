@@ -0,0 +1,84 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use serde_json::json;
+
+use super::validate;
+use crate::config::WebhookPayloadSchema;
+
+fn schema() -> WebhookPayloadSchema {
+    WebhookPayloadSchema {
+        required:    vec!["id".to_string(), "data.object.id".to_string()],
+        types:       [
+            ("data.object.amount".to_string(), "integer".to_string()),
+            ("livemode".to_string(), "boolean".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+        event_types: vec!["charge.succeeded".to_string()],
+    }
+}
+
+#[test]
+fn conforming_payload_passes() {
+    let payload = json!({
+        "id": "evt_1",
+        "livemode": false,
+        "data": { "object": { "id": "ch_1", "amount": 1200 } }
+    });
+    assert!(validate(&schema(), &payload, "charge.succeeded").is_ok());
+}
+
+#[test]
+fn empty_schema_accepts_anything() {
+    let schema = WebhookPayloadSchema::default();
+    assert!(validate(&schema, &json!(null), "").is_ok());
+    assert!(validate(&schema, &json!({ "any": 1 }), "whatever").is_ok());
+}
+
+#[test]
+fn missing_and_null_required_paths_are_reported() {
+    let payload = json!({ "id": null, "data": { "object": {} } });
+    let violations = validate(&schema(), &payload, "charge.succeeded").unwrap_err();
+    assert_eq!(
+        violations,
+        vec![
+            "missing required field 'id'".to_string(),
+            "missing required field 'data.object.id'".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn mistyped_fields_are_reported_but_absent_ones_are_not() {
+    let payload = json!({
+        "id": "evt_1",
+        "data": { "object": { "id": "ch_1", "amount": 12.5 } }
+    });
+    let violations = validate(&schema(), &payload, "charge.succeeded").unwrap_err();
+    assert_eq!(
+        violations,
+        vec!["field 'data.object.amount' must be of type integer".to_string()]
+    );
+}
+
+#[test]
+fn unaccepted_event_type_is_reported_first() {
+    let payload = json!({ "data": { "object": { "id": "ch_1" } } });
+    let violations = validate(&schema(), &payload, "charge.refunded").unwrap_err();
+    assert_eq!(
+        violations,
+        vec![
+            "event type 'charge.refunded' is not accepted by this route".to_string(),
+            "missing required field 'id'".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn paths_do_not_descend_into_arrays() {
+    let schema = WebhookPayloadSchema {
+        required: vec!["items.0".to_string()],
+        ..WebhookPayloadSchema::default()
+    };
+    assert!(validate(&schema, &json!({ "items": [1] }), "").is_err());
+}
@@ -61,6 +61,7 @@ pub mod middleware;
 pub mod query_bridge;
 pub mod routes;
 pub mod schema;
+pub mod schema_drift;
 pub mod server;
 pub mod server_config;
 #[cfg(feature = "sources")]
@@ -242,9 +243,30 @@ pub mod inbound;
 // Connection pool management and auto-tuning
 pub mod pool;
 
+// Dependency readiness probes and the startup traffic gate
+pub mod readiness;
+
+// Canary schema rollout (candidate schema served to a share of traffic)
+pub mod canary;
+
+// Shadow traffic mirroring (sampled read queries re-run and diffed)
+pub mod shadow;
+
+// Runtime config reload (SIGHUP / admin endpoint)
+pub mod config_reload;
+
+// Append-only audit trail of admin API mutations
+pub mod admin_audit;
+
+// Graceful shutdown coordination (HTTP drain, observers, Flight, close hooks)
+pub mod shutdown;
+
 // Object storage backends (local, S3, GCS, Azure Blob)
 pub mod storage;
 
+// Scheduled logical backups (pg_dump → object storage) and restore
+pub mod backup;
+
 // Server subsystem assembly and lifecycle management
 pub mod subsystems;
 
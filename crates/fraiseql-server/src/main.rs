@@ -7,10 +7,11 @@ use clap::Parser;
 #[cfg(feature = "wire-backend")]
 use fraiseql_core::db::FraiseWireAdapter;
 #[cfg(not(feature = "wire-backend"))]
-use fraiseql_core::db::postgres::PostgresAdapter;
+use fraiseql_core::db::postgres::{PostgresAdapter, PostgresIntrospector};
 use fraiseql_core::schema::CompiledSchema;
 use fraiseql_server::{
     Cli, CompiledSchemaLoader, Server, ServerConfig,
+    config_reload::ConfigSource,
     usage::{aggregator::global_aggregator, layer::MutationAuditLayer},
 };
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -19,15 +20,27 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Load configuration from file or use defaults.
 ///
+/// `${secret:path}` references in the file are resolved through the secrets
+/// backend selected by `FRAISEQL_SECRETS_BACKEND` before deserialization.
+///
 /// # Errors
 ///
-/// Returns an error if the config file cannot be read or is not valid TOML.
-fn load_config(config_path: Option<&str>) -> anyhow::Result<ServerConfig> {
+/// Returns an error if the config file cannot be read, is not valid TOML, or
+/// contains a secret reference that cannot be resolved.
+async fn load_config(config_path: Option<&str>) -> anyhow::Result<ServerConfig> {
     if let Some(path) = config_path {
         tracing::info!(path = %path, "Loading configuration from file");
         let contents = std::fs::read_to_string(path)?;
-        let config: ServerConfig = toml::from_str(&contents)
-            .map_err(|e| anyhow::anyhow!("failed to parse config file `{path}`: {e}"))?;
+        let parse_error =
+            |e: toml::de::Error| anyhow::anyhow!("failed to parse config file `{path}`: {e}");
+        let raw: toml::Value = toml::from_str(&contents).map_err(parse_error)?;
+        let config: ServerConfig =
+            if fraiseql_server::server_config::secret_refs::contains_secret_references(&raw) {
+                resolve_config_secrets(raw, path).await?.try_into().map_err(parse_error)?
+            } else {
+                // Deserialize from the text, not the tree, to keep line numbers in errors.
+                toml::from_str(&contents).map_err(parse_error)?
+            };
         #[cfg(feature = "observers")]
         check_observer_config_layout(&config, path)?;
         Ok(config)
@@ -37,6 +50,40 @@ fn load_config(config_path: Option<&str>) -> anyhow::Result<ServerConfig> {
     }
 }
 
+/// Substitute `${secret:...}` references in the raw config tree.
+///
+/// # Errors
+///
+/// Fails loud when no secrets backend is configured or a reference cannot be
+/// resolved — starting with a literal `${secret:...}` as a password is never
+/// what the operator meant.
+#[cfg(feature = "secrets")]
+async fn resolve_config_secrets(mut raw: toml::Value, path: &str) -> anyhow::Result<toml::Value> {
+    let Some((_, resolver)) = build_secrets_manager().await? else {
+        anyhow::bail!(
+            "config file `{path}` contains ${{secret:...}} references but no secrets backend is \
+             configured; set FRAISEQL_SECRETS_BACKEND"
+        );
+    };
+    let resolved =
+        fraiseql_server::server_config::secret_refs::resolve_secret_references(&mut raw, &resolver)
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!("config file `{path}`: failed to resolve secret reference: {e}")
+            })?;
+    tracing::info!(count = resolved, "Resolved secret references in configuration");
+    Ok(raw)
+}
+
+#[cfg(not(feature = "secrets"))]
+#[allow(clippy::unused_async)] // Reason: mirrors the async `secrets` variant
+async fn resolve_config_secrets(_raw: toml::Value, path: &str) -> anyhow::Result<toml::Value> {
+    anyhow::bail!(
+        "config file `{path}` contains ${{secret:...}} references, but this binary was built \
+         without the `secrets` feature. Rebuild with `--features secrets`."
+    )
+}
+
 /// Reject the pre-#342 flat `[observers]` server-tuning layout with a clear
 /// migration message.
 ///
@@ -83,7 +130,17 @@ fn validate_schema_path(path: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Set up tracing subscriber with `RUST_LOG` env filter and optional OTLP export.
+/// Log filter used when neither `RUST_LOG` nor `log_level` is set.
+const DEFAULT_LOG_FILTER: &str = "fraiseql_server=info,tower_http=info,axum=info";
+
+/// Reload handle of the installed log filter, used to apply `log_level` on a
+/// config reload.
+static LOG_FILTER: std::sync::OnceLock<
+    tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+> = std::sync::OnceLock::new();
+
+/// Set up tracing subscriber with `RUST_LOG` (or `log_level`) env filter and
+/// optional OTLP export.
 ///
 /// When `FRAISEQL_LOG_FORMAT=json` (case-insensitive), logs are emitted as
 /// newline-delimited JSON — suitable for structured log aggregators such as
@@ -95,8 +152,16 @@ fn validate_schema_path(path: &Path) -> anyhow::Result<()> {
 /// exporter is added as an additional tracing layer.  When no endpoint is set,
 /// no gRPC connection is attempted and there is zero overhead.
 fn init_tracing(config: &ServerConfig, is_json: bool) {
-    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| "fraiseql_server=info,tower_http=info,axum=info".into());
+    // `validate()` has already checked `log_level`; fall back to the default on error anyway.
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        config
+            .log_level
+            .as_deref()
+            .and_then(|directives| tracing_subscriber::EnvFilter::try_new(directives).ok())
+            .unwrap_or_else(|| DEFAULT_LOG_FILTER.into())
+    });
+    let (env_filter, handle) = tracing_subscriber::reload::Layer::new(env_filter);
+    let _ = LOG_FILTER.set(handle);
 
     // Audit layer is always installed; it only records events with the
     // `fraiseql::mutation_audit` target and is otherwise a zero-cost no-op.
@@ -131,6 +196,34 @@ fn init_tracing(config: &ServerConfig, is_json: bool) {
     }
 }
 
+/// Replace the log filter on a config reload: `log_level`'s directives, or the
+/// default when it was removed.
+///
+/// # Errors
+///
+/// Fails when `RUST_LOG` is set (it takes precedence over `log_level`), when
+/// tracing is not initialized, or when the directives do not parse.
+fn set_log_filter(directives: Option<&str>) -> Result<(), String> {
+    if std::env::var_os("RUST_LOG").is_some() {
+        return Err("RUST_LOG is set and takes precedence over log_level".to_string());
+    }
+    let handle = LOG_FILTER.get().ok_or("tracing is not initialized")?;
+    let filter = tracing_subscriber::EnvFilter::try_new(directives.unwrap_or(DEFAULT_LOG_FILTER))
+        .map_err(|e| e.to_string())?;
+    handle.reload(filter).map_err(|e| e.to_string())
+}
+
+/// Re-read the configuration the way startup did (file, secret references,
+/// CLI/env overrides) for SIGHUP and `POST /api/v1/admin/config/reload`.
+fn config_source(cli: &Cli) -> ConfigSource {
+    let cli = cli.clone();
+    ConfigSource::new(move || {
+        let cli = cli.clone();
+        async move { load_and_validate_config(&cli).await.map_err(|e| e.to_string()) }
+    })
+    .with_log_filter(set_log_filter)
+}
+
 /// Redact credentials from an endpoint URL before logging.
 ///
 /// If the URL contains userinfo (`user:pass@host`), the credentials are replaced
@@ -218,8 +311,8 @@ where
 ///
 /// Returns an error if configuration loading fails (file I/O, parse errors) or
 /// if the resulting configuration is invalid.
-fn load_and_validate_config(cli: &Cli) -> anyhow::Result<ServerConfig> {
-    let mut config = load_config(cli.server.config.as_deref())?;
+async fn load_and_validate_config(cli: &Cli) -> anyhow::Result<ServerConfig> {
+    let mut config = load_config(cli.server.config.as_deref()).await?;
 
     // Apply all CLI flag and env var overrides in one pass.
     cli.server.apply_to_config(&mut config);
@@ -235,7 +328,10 @@ fn load_and_validate_config(cli: &Cli) -> anyhow::Result<ServerConfig> {
 /// Load and validate the compiled schema from the path in `config`.
 async fn load_schema(config: &ServerConfig) -> anyhow::Result<CompiledSchema> {
     validate_schema_path(&config.schema_path)?;
-    let schema_loader = CompiledSchemaLoader::new(&config.schema_path);
+    let mut schema_loader = CompiledSchemaLoader::new(&config.schema_path);
+    if let Some(key) = config.schema_verifying_key().map_err(anyhow::Error::msg)? {
+        schema_loader = schema_loader.with_public_key(key);
+    }
     let schema = schema_loader.load().await?;
     // Install the schema's casing acronyms (on top of the built-in defaults) so the
     // runtime's `to_snake_case` JSONB-key resolution matches the compiled surface.
@@ -280,6 +376,7 @@ async fn build_postgres_adapter(config: &ServerConfig) -> anyhow::Result<Arc<Pos
         pool_min_size = config.pool_min_size,
         pool_max_size = config.pool_max_size,
         pool_timeout_secs = config.pool_timeout_secs,
+        prepared_statement_capacity = config.prepared_statement_capacity,
         "Initializing PostgreSQL connection pool"
     );
     let adapter = PostgresAdapter::with_pool_config(
@@ -290,7 +387,8 @@ async fn build_postgres_adapter(config: &ServerConfig) -> anyhow::Result<Arc<Pos
             timeout_secs: Some(config.pool_timeout_secs),
         },
     )
-    .await?;
+    .await?
+    .with_prepared_statement_capacity(config.prepared_statement_capacity);
     tracing::info!("PostgreSQL adapter ready");
     Ok(Arc::new(adapter))
 }
@@ -350,19 +448,57 @@ async fn build_observer_pool(_config: &ServerConfig) -> anyhow::Result<Option<sq
     Ok(None)
 }
 
-/// Initialize the secrets manager backend if `--secrets-backend` / `FRAISEQL_SECRETS_BACKEND` is
-/// set.
+/// Secrets manager plus the `${secret:...}` resolver sharing its cache.
+#[cfg(feature = "secrets")]
+type SecretsHandles = (
+    Arc<fraiseql_server::secrets_manager::SecretsManager>,
+    Arc<fraiseql_server::secrets_manager::SecretReferenceResolver>,
+);
+
+/// Default cache lifetime of resolved `${secret:...}` values.
+#[cfg(feature = "secrets")]
+const DEFAULT_SECRETS_TTL_SECS: u64 = 300;
+
+/// Initialize the secrets manager backend selected by `FRAISEQL_SECRETS_BACKEND`
+/// (`env`, `file`, `vault`, `aws` or `gcp`), if set.
+///
+/// Built once: config loading and server startup share the same manager.
+/// Resolved references are cached for `FRAISEQL_SECRETS_TTL_SECS` (default
+/// 300) before the backend is read again.
+#[cfg(feature = "secrets")]
+async fn build_secrets_manager() -> anyhow::Result<Option<SecretsHandles>> {
+    static HANDLES: tokio::sync::OnceCell<Option<SecretsHandles>> =
+        tokio::sync::OnceCell::const_new();
+    HANDLES.get_or_try_init(init_secrets_manager).await.cloned()
+}
+
 #[cfg(feature = "secrets")]
-async fn build_secrets_manager()
--> anyhow::Result<Option<Arc<fraiseql_server::secrets_manager::SecretsManager>>> {
-    if std::env::var("FRAISEQL_SECRETS_BACKEND").is_err() {
+async fn init_secrets_manager() -> anyhow::Result<Option<SecretsHandles>> {
+    use fraiseql_server::secrets_manager::{
+        SecretReferenceResolver, SecretsBackendConfig, create_secrets_manager,
+    };
+
+    let Some(cfg) = SecretsBackendConfig::from_env()
+        .map_err(|e| anyhow::anyhow!("Invalid secrets backend configuration: {e}"))?
+    else {
         tracing::debug!("Secrets manager disabled (set FRAISEQL_SECRETS_BACKEND to enable)");
         return Ok(None);
-    }
+    };
+    let ttl_secs = match std::env::var("FRAISEQL_SECRETS_TTL_SECS") {
+        Ok(v) => v.parse::<u64>().map_err(|_| {
+            anyhow::anyhow!("FRAISEQL_SECRETS_TTL_SECS must be an integer, got `{v}`")
+        })?,
+        Err(_) => DEFAULT_SECRETS_TTL_SECS,
+    };
     tracing::info!("Initializing secrets manager from environment configuration");
-    let cfg = fraiseql_server::secrets_manager::SecretsBackendConfig::Env;
-    match fraiseql_server::secrets_manager::create_secrets_manager(cfg).await {
-        Ok(manager) => Ok(Some(manager)),
+    match create_secrets_manager(cfg).await {
+        Ok(manager) => {
+            let resolver = Arc::new(SecretReferenceResolver::new(
+                Arc::clone(&manager),
+                std::time::Duration::from_secs(ttl_secs),
+            ));
+            Ok(Some((manager, resolver)))
+        },
         Err(e) => {
             tracing::error!(error = %e, "Failed to initialize secrets manager");
             anyhow::bail!("Secrets manager initialization failed: {}", e)
@@ -537,7 +673,7 @@ async fn main() -> anyhow::Result<()> {
     // Load config first so tracing can include the OTLP layer if configured.
     // Tracing calls in load_and_validate_config are silently discarded (no
     // subscriber yet); critical errors surface via the Result return.
-    let config = load_and_validate_config(&cli)?;
+    let config = load_and_validate_config(&cli).await?;
     init_tracing(&config, cli.server.is_json_log_format());
     tracing::info!("FraiseQL Server v{}", env!("CARGO_PKG_VERSION"));
     tracing::info!(
@@ -643,6 +779,19 @@ async fn run_postgres(
         );
     }
 
+    // Opt-in drift check: the column-level `compile --database` checks, run against
+    // the database being served, so a migrated-away view or column fails boot with
+    // the full list instead of failing its operations one request at a time.
+    if config.validate_schema_drift {
+        let introspector = PostgresIntrospector::new(adapter.pool().clone());
+        let drift =
+            fraiseql_server::schema_drift::find_schema_drift(&schema, &introspector).await?;
+        if !drift.is_empty() {
+            anyhow::bail!("{}", fraiseql_server::schema_drift::format_drift_report(&drift));
+        }
+        tracing::info!("schema drift validation passed: the database matches the compiled schema");
+    }
+
     let db_pool = build_observer_pool(&config).await?;
 
     // Wire `[storage.<name>]` into a mounted /storage/v1/* route group. Built
@@ -675,9 +824,11 @@ async fn run_postgres(
     // `CachedDatabaseAdapter` (via `Server::new`/`with_relay_pagination`), while the
     // arrow path keeps the raw `PostgresAdapter` (via `Server::with_flight_service`,
     // which never caches). Both implement `FromPoolConfig`, so each branch below builds
-    // its factory with the matching type. Capture the flag here, before `config` is
-    // moved into the constructor.
-    let tenancy_runtime_enabled = config.tenancy.runtime.enabled;
+    // its factory with the matching type. A `[shadow] database_url` opens its pool
+    // through the same factory. Capture the flag here, before `config` is moved into
+    // the constructor.
+    let needs_executor_factory = config.tenancy.runtime.enabled
+        || config.shadow.as_ref().is_some_and(|shadow| shadow.database_url.is_some());
 
     // Arrow Flight path: only available with the `arrow` feature, only on PG.
     #[cfg(feature = "arrow")]
@@ -690,7 +841,7 @@ async fn run_postgres(
                 .await?;
         // Arrow path: the server holds the raw `PostgresAdapter`, so the tenant
         // factory must produce `PostgresAdapter` executors to match its adapter type.
-        let tenant_factory = tenancy_runtime_enabled
+        let tenant_factory = needs_executor_factory
             .then(fraiseql_server::tenancy::make_executor_factory::<PostgresAdapter>);
         let server = match storage_state {
             Some(state) => server.with_storage_state(state),
@@ -720,7 +871,7 @@ async fn run_postgres(
         // Non-arrow path: `Server::new`/`with_relay_pagination` wrap the adapter in
         // `CachedDatabaseAdapter`, so the tenant factory must produce cached executors
         // to match the server's adapter type.
-        let tenant_factory = tenancy_runtime_enabled.then(
+        let tenant_factory = needs_executor_factory.then(
             fraiseql_server::tenancy::make_executor_factory::<
                 fraiseql_core::cache::CachedDatabaseAdapter<PostgresAdapter>,
             >,
@@ -859,12 +1010,15 @@ async fn finish_server<X>(server: Server<X>, cli: &Cli, with_arrow: bool) -> any
 where
     X: fraiseql_core::db::DatabaseAdapter + Clone + Send + Sync + 'static,
 {
+    let server = server.with_config_reload(config_source(cli));
+
     // Attach secrets manager if configured.
     #[cfg(feature = "secrets")]
     let mut server = server;
     #[cfg(feature = "secrets")]
-    if let Some(mgr) = build_secrets_manager().await? {
+    if let Some((mgr, resolver)) = build_secrets_manager().await? {
         server.set_secrets_manager(mgr);
+        server.set_secret_reference_resolver(resolver);
     }
     #[cfg(not(feature = "secrets"))]
     let _ = build_secrets_manager().await?;
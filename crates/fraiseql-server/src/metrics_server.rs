@@ -131,6 +131,9 @@ pub struct MetricsCollector {
 
     /// Total failed schema reload attempts
     pub schema_reload_errors_total: AtomicU64,
+
+    /// Requests whose client disconnected while the operation was executing
+    pub client_disconnects_total: AtomicU64,
 }
 
 impl MetricsCollector {
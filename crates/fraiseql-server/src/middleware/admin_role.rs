@@ -0,0 +1,225 @@
+//! Role-based access control for the admin API.
+//!
+//! Admin callers hold one of three roles, each of which includes the ones below it:
+//!
+//! - **viewer** (`admin_readonly_token`, scope `fraiseql:admin:viewer`): read-only endpoints.
+//! - **operator** (`admin_operator_token`, scope `fraiseql:admin:operator`): operational actions
+//!   such as cache clear, config reload, DLQ retry and observer pause/resume.
+//! - **admin** (`admin_token`, scope `fraiseql:admin`): everything, including schema reload, tenant
+//!   writes and observer/DLQ deletion.
+//!
+//! The authentication middlewares ([`admin_token_role_middleware`] for the static-token
+//! plane, [`admin_role_auth_middleware`](super::oidc_auth::admin_role_auth_middleware)
+//! for the OIDC plane) resolve the caller's role and insert an [`AdminActor`] into the
+//! request extensions (and onto the response, for the audit layer wrapped around them).
+//! [`require_admin_role`] raises the bar for individual routes.
+
+use std::{fmt, sync::Arc};
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{Method, Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use super::{
+    admin_scope::ADMIN_SCOPE,
+    auth::{FailureLimiter, constant_time_compare, extract_bearer_token, peer_key},
+};
+
+/// JWT scope granting the operator role on the admin API.
+pub const OPERATOR_SCOPE: &str = "fraiseql:admin:operator";
+
+/// JWT scope granting the read-only viewer role on the admin API.
+pub const VIEWER_SCOPE: &str = "fraiseql:admin:viewer";
+
+/// Admin API role. Ordered so that a higher role satisfies any lower requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminRole {
+    /// Read-only access: stats, config, explain, DLQ and observer listings.
+    Viewer,
+    /// Operational actions that change runtime state but not definitions.
+    Operator,
+    /// Full access, including destructive and definition-changing operations.
+    Admin,
+}
+
+impl AdminRole {
+    /// Stable lowercase name, as used in audit records and error messages.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Viewer => "viewer",
+            Self::Operator => "operator",
+            Self::Admin => "admin",
+        }
+    }
+
+    /// Highest role granted by a JWT's scopes, or `None` when no admin scope is present.
+    #[must_use]
+    pub fn from_scopes(scopes: &[String]) -> Option<Self> {
+        let has = |wanted: &str| scopes.iter().any(|s| s == wanted);
+        if has(ADMIN_SCOPE) {
+            Some(Self::Admin)
+        } else if has(OPERATOR_SCOPE) {
+            Some(Self::Operator)
+        } else if has(VIEWER_SCOPE) {
+            Some(Self::Viewer)
+        } else {
+            None
+        }
+    }
+
+    /// Role a route requires when it declares nothing stricter: viewer for reads,
+    /// operator for anything that may change state.
+    #[must_use]
+    pub fn required_for(method: &Method) -> Self {
+        if method == Method::GET || method == Method::HEAD || method == Method::OPTIONS {
+            Self::Viewer
+        } else {
+            Self::Operator
+        }
+    }
+}
+
+impl fmt::Display for AdminRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The authenticated admin caller, inserted into request extensions by the admin
+/// authentication middlewares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminActor {
+    /// Who made the request: the JWT subject, or `token:<role>` for static tokens.
+    pub id:   String,
+    /// The role the caller was granted.
+    pub role: AdminRole,
+}
+
+/// Shared state for [`admin_token_role_middleware`].
+#[derive(Clone)]
+pub struct AdminTokenAuthState {
+    tokens:          Arc<Vec<(String, AdminRole)>>,
+    required:        AdminRole,
+    failure_limiter: FailureLimiter,
+}
+
+impl AdminTokenAuthState {
+    /// Create state for routes requiring at least `required`.
+    ///
+    /// After `max_failures` unknown tokens from the same IP within a 60-second
+    /// window, further requests receive **429 Too Many Requests**.
+    #[must_use]
+    pub fn new(required: AdminRole, max_failures: u32) -> Self {
+        Self {
+            tokens: Arc::new(Vec::new()),
+            required,
+            failure_limiter: FailureLimiter::new(max_failures),
+        }
+    }
+
+    /// Accept `token` as a credential for `role`.
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>, role: AdminRole) -> Self {
+        Arc::make_mut(&mut self.tokens).push((token.into(), role));
+        self
+    }
+
+    /// Role granted by `token`. Every configured token is compared in constant time so
+    /// the response time does not reveal which one matched.
+    fn role_of(&self, token: &str) -> Option<AdminRole> {
+        self.tokens.iter().fold(None, |found, (candidate, role)| {
+            if constant_time_compare(token, candidate) {
+                Some(found.map_or(*role, |f: AdminRole| f.max(*role)))
+            } else {
+                found
+            }
+        })
+    }
+}
+
+/// Static-token admin authentication with role enforcement.
+///
+/// # Response
+///
+/// - **401 Unauthorized**: missing or malformed `Authorization` header
+/// - **403 Forbidden**: unknown token, or a token whose role is below the route's
+/// - **429 Too Many Requests**: too many unknown tokens from this peer
+pub async fn admin_token_role_middleware(
+    State(auth_state): State<AdminTokenAuthState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let peer_key = peer_key(&request);
+    if auth_state.failure_limiter.is_blocked(&peer_key) {
+        return (StatusCode::TOO_MANY_REQUESTS, "Too many failed auth attempts").into_response();
+    }
+
+    let Some(token) = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(extract_bearer_token)
+    else {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "Missing or malformed Authorization header. Expected: Bearer <token>",
+        )
+            .into_response();
+    };
+
+    let Some(role) = auth_state.role_of(token) else {
+        if auth_state.failure_limiter.record_failure(&peer_key) {
+            return (StatusCode::TOO_MANY_REQUESTS, "Too many failed auth attempts")
+                .into_response();
+        }
+        return (StatusCode::FORBIDDEN, "Invalid token").into_response();
+    };
+    auth_state.failure_limiter.record_success(&peer_key);
+
+    let actor = AdminActor {
+        id: format!("token:{role}"),
+        role,
+    };
+    let response = if role < auth_state.required {
+        insufficient_role(auth_state.required)
+    } else {
+        request.extensions_mut().insert(actor.clone());
+        next.run(request).await
+    };
+    with_actor(response, actor)
+}
+
+/// Per-route role requirement, layered inside one of the admin authentication
+/// middlewares.
+///
+/// Fails closed: a request with no [`AdminActor`] (the route was mounted without
+/// admin authentication) is rejected with 403.
+pub async fn require_admin_role(
+    State(required): State<AdminRole>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    match request.extensions().get::<AdminActor>() {
+        Some(actor) if actor.role >= required => next.run(request).await,
+        _ => insufficient_role(required),
+    }
+}
+
+/// Carry `actor` back on `response` so an outer audit layer can attribute it.
+pub(crate) fn with_actor(mut response: Response, actor: AdminActor) -> Response {
+    response.extensions_mut().insert(actor);
+    response
+}
+
+/// 403 response for a caller whose role is below `required`.
+pub(crate) fn insufficient_role(required: AdminRole) -> Response {
+    (StatusCode::FORBIDDEN, format!("Admin API requires the '{required}' role")).into_response()
+}
@@ -147,20 +147,7 @@ pub async fn bearer_auth_middleware(
     request: Request<Body>,
     next: Next,
 ) -> Response {
-    // Derive the peer key for the brute-force limiter from the validated transport peer
-    // only. ConnectInfo is the real socket address (present in the shipped binary, which
-    // starts with `into_make_service_with_connect_info`). We deliberately do NOT fall back
-    // to `X-Forwarded-For`: that header is attacker-controlled, so keying on it would let a
-    // caller rotate it to mint a fresh failure budget per value, defeating the limiter
-    // (M-xff-limiter). When ConnectInfo is absent (some library embeddings), all callers
-    // share the single `unknown` bucket — fail-closed (more restrictive), not bypassable.
-    use std::net::SocketAddr;
-
-    use axum::extract::ConnectInfo;
-    let peer_key = request
-        .extensions()
-        .get::<ConnectInfo<SocketAddr>>()
-        .map_or_else(|| "unknown".to_string(), |ci| ci.0.ip().to_string());
+    let peer_key = peer_key(&request);
 
     // Reject immediately if already rate-limited (avoids any header work).
     if auth_state.failure_limiter.is_blocked(&peer_key) {
@@ -215,6 +202,25 @@ pub async fn bearer_auth_middleware(
     next.run(request).await
 }
 
+/// Derive the peer key for the brute-force limiter from the validated transport peer
+/// only.
+///
+/// `ConnectInfo` is the real socket address (present in the shipped binary, which
+/// starts with `into_make_service_with_connect_info`). We deliberately do NOT fall back
+/// to `X-Forwarded-For`: that header is attacker-controlled, so keying on it would let a
+/// caller rotate it to mint a fresh failure budget per value, defeating the limiter
+/// (M-xff-limiter). When `ConnectInfo` is absent (some library embeddings), all callers
+/// share the single `unknown` bucket — fail-closed (more restrictive), not bypassable.
+pub(crate) fn peer_key(request: &Request<Body>) -> String {
+    use std::net::SocketAddr;
+
+    use axum::extract::ConnectInfo;
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map_or_else(|| "unknown".to_string(), |ci| ci.0.ip().to_string())
+}
+
 /// Extract the bearer token from an `Authorization` header value.
 ///
 /// Returns `Some(token)` if the header has the `Bearer ` prefix (with trailing space),
@@ -1,7 +1,10 @@
 //! CORS and security headers middleware.
 
-use axum::{middleware::Next, response::IntoResponse};
-use tower_http::cors::{Any, CorsLayer};
+use axum::{http::HeaderValue, middleware::Next, response::IntoResponse};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+
+/// Origin allowed when CORS is enabled without any `cors_origins`.
+pub const DEFAULT_DEV_ORIGIN: &str = "http://localhost:3000";
 
 /// Create CORS layer for development only.
 ///
@@ -67,6 +70,28 @@ pub fn cors_layer_restricted(allowed_origins: &[String]) -> CorsLayer {
         ])
 }
 
+/// Create a restricted CORS layer whose allowed origins can change at runtime.
+///
+/// Same methods and headers as [`cors_layer_restricted`], but `is_allowed` is
+/// asked about each request's `Origin`, so a config reload that changes
+/// `cors_origins` takes effect on the next request.
+pub fn cors_layer_dynamic<F>(is_allowed: F) -> CorsLayer
+where
+    F: Fn(&HeaderValue) -> bool + Send + Sync + 'static,
+{
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(move |origin, _| is_allowed(origin)))
+        .allow_methods([
+            axum::http::Method::GET,
+            axum::http::Method::POST,
+            axum::http::Method::OPTIONS,
+        ])
+        .allow_headers([
+            axum::http::header::CONTENT_TYPE,
+            axum::http::header::AUTHORIZATION,
+        ])
+}
+
 /// Security headers middleware.
 ///
 /// Adds security-related HTTP response headers to protect against:
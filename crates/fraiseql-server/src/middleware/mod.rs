@@ -1,5 +1,6 @@
 //! HTTP middleware.
 
+pub mod admin_role;
 pub mod admin_scope;
 pub mod auth;
 pub mod content_type;
@@ -12,15 +13,20 @@ pub mod rate_limit;
 pub mod tenant;
 pub mod trace;
 
+pub use admin_role::{
+    AdminActor, AdminRole, AdminTokenAuthState, admin_token_role_middleware, require_admin_role,
+};
 pub use auth::{BearerAuthState, bearer_auth_middleware};
 pub use content_type::require_json_content_type;
-pub use cors::{cors_layer, cors_layer_restricted, security_headers_middleware};
+pub use cors::{
+    cors_layer, cors_layer_dynamic, cors_layer_restricted, security_headers_middleware,
+};
 pub use header_limits::header_limits_middleware;
 pub use hs256_auth::{Hs256AuthState, hs256_auth_middleware};
 pub use metrics::metrics_middleware;
 pub use oidc_auth::{
-    AuthUser, OidcAuthState, SessionJti, admin_auth_middleware, oidc_auth_middleware,
-    required_auth_middleware,
+    AuthUser, OidcAuthState, SessionJti, admin_auth_middleware, admin_role_auth_middleware,
+    oidc_auth_middleware, required_auth_middleware,
 };
 pub use rate_limit::{
     RateLimitConfig, RateLimiter, RateLimitingSecurityConfig, rate_limit_middleware,
@@ -15,7 +15,10 @@ use axum::{
 use fraiseql_core::security::{AuthenticatedUser, OidcValidator};
 
 use crate::{
-    middleware::admin_scope::ADMIN_SCOPE,
+    middleware::{
+        admin_role::{AdminActor, AdminRole, insufficient_role, with_actor},
+        admin_scope::ADMIN_SCOPE,
+    },
     token_revocation::{TokenRejection, TokenRevocationManager},
 };
 
@@ -420,6 +423,43 @@ pub async fn admin_auth_middleware(
     }
 }
 
+/// Role-aware admin-plane authentication middleware.
+///
+/// Like [`admin_auth_middleware`] it always requires a valid token, but instead of a
+/// single all-or-nothing scope it resolves the caller's [`AdminRole`] from the
+/// `fraiseql:admin`, `fraiseql:admin:operator` and `fraiseql:admin:viewer` scopes.
+/// Reads need the viewer role and anything else the operator role; routes that need
+/// more layer [`require_admin_role`](super::admin_role::require_admin_role) on top.
+/// The resolved [`AdminActor`] is inserted into the request extensions and onto the
+/// response.
+pub async fn admin_role_auth_middleware(
+    State(auth_state): State<OidcAuthState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let user = match authenticate_required(&auth_state, &mut request).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+    let required = AdminRole::required_for(request.method());
+    let Some(role) = AdminRole::from_scopes(&user.scopes) else {
+        tracing::debug!(user_id = %user.user_id, "Admin scopes missing — denying");
+        return insufficient_role(required);
+    };
+    let actor = AdminActor {
+        id: user.user_id.as_str().to_string(),
+        role,
+    };
+    let response = if role < required {
+        tracing::debug!(user_id = %user.user_id, %role, %required, "Admin role too low — denying");
+        insufficient_role(required)
+    } else {
+        request.extensions_mut().insert(actor.clone());
+        next.run(request).await
+    };
+    with_actor(response, actor)
+}
+
 /// Mandatory-authentication middleware (Phase 03 C3).
 ///
 /// Requires a valid bearer token (any scope). Unlike [`oidc_auth_middleware`] it never
@@ -56,7 +56,8 @@ pub struct RateLimitingSecurityConfig {
     /// Defaults to 10× `requests_per_second` if not set.
     #[serde(default)]
     pub requests_per_second_per_user: Option<u32>,
-    /// Redis URL for distributed rate limiting (not yet implemented).
+    /// Redis URL for rate limiting shared across replicas (requires the
+    /// `redis-rate-limiting` feature).
     pub redis_url: Option<String>,
     /// Trust `X-Real-IP` / `X-Forwarded-For` headers for the client IP.
     ///
@@ -127,6 +128,16 @@ pub struct RateLimitConfig {
     /// ~20 `MiB` of tracking state per map before enforcement kicks in.
     #[serde(default = "default_max_buckets")]
     pub max_buckets: usize,
+
+    /// Redis URL for buckets shared by every replica (requires the
+    /// `redis-rate-limiting` Cargo feature).
+    ///
+    /// When set, buckets live in Redis under `fraiseql:rl:*` keys, so N replicas
+    /// enforce one limit instead of N. While Redis is unreachable, each replica
+    /// falls back to its own in-memory buckets. `None` (default) keeps all buckets
+    /// in memory.
+    #[serde(default)]
+    pub redis_url: Option<String>,
 }
 
 const fn default_max_buckets() -> usize {
@@ -144,6 +155,7 @@ impl Default for RateLimitConfig {
             trust_proxy_headers:   false,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         }
     }
 }
@@ -185,6 +197,7 @@ impl RateLimitConfig {
             trust_proxy_headers: sec.trust_proxy_headers,
             trusted_proxy_cidrs,
             max_buckets: default_max_buckets(),
+            redis_url: sec.redis_url.clone(),
         }
     }
 }
@@ -93,7 +93,7 @@ impl RateLimiter {
         match self {
             Self::InMemory(rl) => rl.check_ip_limit(ip, tenant_id).await,
             #[cfg(feature = "redis-rate-limiting")]
-            Self::Redis(rl) => rl.check_ip_limit(ip).await,
+            Self::Redis(rl) => rl.check_ip_limit(ip, tenant_id).await,
         }
     }
 
@@ -103,7 +103,7 @@ impl RateLimiter {
         match self {
             Self::InMemory(rl) => rl.check_user_limit(user_id, tenant_id).await,
             #[cfg(feature = "redis-rate-limiting")]
-            Self::Redis(rl) => rl.check_user_limit(user_id).await,
+            Self::Redis(rl) => rl.check_user_limit(user_id, tenant_id).await,
         }
     }
 
@@ -123,27 +123,36 @@ impl RateLimiter {
     /// Check the per-tenant rate limit for a request to tenant `key`.
     ///
     /// Each tenant gets its own token bucket with the specified `rps` and `burst`
-    /// from the tenant's quota configuration.
-    ///
-    /// # Note
-    ///
-    /// Redis backend does not support per-tenant limits (falls back to allow-all).
+    /// from the tenant's quota configuration; with the Redis backend the bucket is
+    /// shared by every replica.
     pub async fn check_tenant_limit(&self, tenant_key: &str, rps: u32, burst: u32) -> CheckResult {
         match self {
             Self::InMemory(rl) => rl.check_tenant_limit(tenant_key, rps, burst).await,
             #[cfg(feature = "redis-rate-limiting")]
-            Self::Redis(_) => CheckResult::allow(f64::from(burst)),
+            Self::Redis(rl) => rl.check_tenant_limit(tenant_key, rps, burst).await,
         }
     }
 
     /// Evict stale in-memory buckets.
     ///
-    /// No-op for the Redis backend — Redis handles expiry via `PEXPIRE`.
+    /// For the Redis backend this only sweeps the local fallback buckets — Redis
+    /// handles expiry of its own via `PEXPIRE`.
     pub async fn cleanup(&self) {
         match self {
             Self::InMemory(rl) => rl.cleanup().await,
             #[cfg(feature = "redis-rate-limiting")]
-            Self::Redis(_) => {},
+            Self::Redis(rl) => rl.cleanup().await,
+        }
+    }
+
+    /// Ping the Redis backend.
+    ///
+    /// Returns `None` for the in-memory backend, which has nothing to reach.
+    #[cfg(feature = "redis-rate-limiting")]
+    pub async fn ping(&self) -> Option<Result<(), redis::RedisError>> {
+        match self {
+            Self::InMemory(_) => None,
+            Self::Redis(rl) => Some(rl.ping().await),
         }
     }
 
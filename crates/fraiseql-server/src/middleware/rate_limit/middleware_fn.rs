@@ -1,7 +1,8 @@
 //! Rate limit middleware function and supporting helpers.
 //!
-//! Contains the axum middleware entry-point, IP extraction logic, and the
-//! JWT subject parser used for per-user rate limiting.
+//! Contains the axum middleware entry-point, IP extraction logic, the
+//! JWT subject and verified API key identities used for per-user rate limiting,
+//! and the [`RateLimitCharge`] handlers use to charge batched operations.
 
 use std::{
     net::{IpAddr, SocketAddr},
@@ -15,9 +16,11 @@ use axum::{
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use sha2::{Digest, Sha256};
 use tracing::warn;
 
 use super::{config::RateLimitConfig, dispatch::RateLimiter, key::is_private_or_loopback};
+use crate::api_key::ApiKeyAuthenticator;
 
 /// Rate limit middleware response.
 ///
@@ -51,6 +54,58 @@ impl IntoResponse for RateLimitExceeded {
     }
 }
 
+/// The per-user or per-IP bucket a request was charged against.
+///
+/// [`rate_limit_middleware`] charges one token per HTTP request and leaves this
+/// in the request extensions, so a handler that runs several operations for one
+/// request (a batched GraphQL POST) can charge the others to the same bucket.
+#[derive(Clone)]
+pub struct RateLimitCharge {
+    limiter:   Arc<RateLimiter>,
+    user_id:   Option<String>,
+    ip:        String,
+    tenant_id: Option<String>,
+    /// Tokens left in the bucket after the request was admitted.
+    remaining: f64,
+}
+
+impl RateLimitCharge {
+    /// Charge `operations` more tokens to the request's bucket.
+    ///
+    /// Fails without charging when the bucket had fewer tokens left than
+    /// `operations` when the request was admitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RateLimitExceeded` when the bucket cannot cover every operation.
+    pub async fn charge(&self, operations: usize) -> Result<(), RateLimitExceeded> {
+        if !self.limiter.config().enabled || operations == 0 {
+            return Ok(());
+        }
+        #[allow(clippy::cast_precision_loss)]
+        // Reason: batch sizes are small, well inside f64's exact integer range
+        if self.remaining < operations as f64 {
+            return Err(RateLimitExceeded {
+                retry_after_secs: self.limiter.retry_after_secs(),
+            });
+        }
+        for _ in 0..operations {
+            let result = match self.user_id {
+                Some(ref uid) => {
+                    self.limiter.check_user_limit(uid, self.tenant_id.as_deref()).await
+                },
+                None => self.limiter.check_ip_limit(&self.ip, self.tenant_id.as_deref()).await,
+            };
+            if !result.allowed {
+                return Err(RateLimitExceeded {
+                    retry_after_secs: result.retry_after_secs,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Emitted at most once when the server appears to be behind a proxy but
 /// `trust_proxy_headers` is `false` — rate limiting would bucket all requests
 /// under the proxy's IP in that configuration.
@@ -129,14 +184,28 @@ pub(super) fn extract_jwt_subject(authorization: &str) -> Option<String> {
     json.get("sub").and_then(|v| v.as_str()).map(String::from)
 }
 
+/// Rate-limit identity of an API key: `api_key:` followed by the first 16 bytes of
+/// its SHA-256, hex-encoded, so the secret itself never becomes a bucket key (in
+/// memory or in Redis).
+pub(super) fn api_key_identity(api_key: &str) -> String {
+    let digest = Sha256::digest(api_key.as_bytes());
+    format!("api_key:{}", hex::encode(&digest[..16]))
+}
+
 /// Rate limiting middleware for GraphQL requests.
 ///
 /// Decision order:
 /// 1. Per-path limit (auth endpoints) — always checked, uses path-specific window.
-/// 2. Per-user limit (authenticated requests) — checked when a JWT `sub` claim is present in the
-///    `Authorization` header; authenticated users get `rps_per_user` (default 10× `rps_per_ip`)
-///    instead of the shared IP bucket.
-/// 3. Per-IP limit (unauthenticated or no bearer token) — fallback.
+/// 2. Per-user limit (identified requests) — checked when a JWT `sub` claim is present in the
+///    `Authorization` header, or else a valid API key (keyed by [`api_key_identity`]); identified
+///    callers get `rps_per_user` (default 10× `rps_per_ip`) instead of the shared IP bucket. API
+///    keys are checked against the request's [`ApiKeyAuthenticator`] extension, so an unknown key
+///    stays in the IP bucket rather than opening a fresh one per key.
+/// 3. Per-IP limit (anonymous requests) — fallback.
+///
+/// Every request costs one token; the admitted bucket is left in the request
+/// extensions as a [`RateLimitCharge`] for handlers that run more than one
+/// operation per request.
 ///
 /// # Errors
 ///
@@ -144,7 +213,7 @@ pub(super) fn extract_jwt_subject(authorization: &str) -> Option<String> {
 #[allow(clippy::cognitive_complexity)] // Reason: multi-dimension rate limiting (per-path, per-user, per-IP) with config lookups
 pub async fn rate_limit_middleware(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
-    req: Request<Body>,
+    mut req: Request<Body>,
     next: Next,
 ) -> Result<Response, RateLimitExceeded> {
     // Get or create rate limiter from state
@@ -162,12 +231,19 @@ pub async fn rate_limit_middleware(
     );
     let path = req.uri().path().to_string();
 
-    // Extract JWT subject for per-user limiting (no signature verification needed here).
+    // Extract JWT subject (no signature verification needed here), or else the API
+    // key once it is known to be valid, for per-user limiting.
     let user_id = req
         .headers()
         .get(axum::http::header::AUTHORIZATION)
         .and_then(|v| v.to_str().ok())
-        .and_then(extract_jwt_subject);
+        .and_then(extract_jwt_subject)
+        .or_else(|| {
+            req.extensions()
+                .get::<Arc<ApiKeyAuthenticator>>()
+                .and_then(|authenticator| authenticator.verified_key(req.headers()))
+                .map(api_key_identity)
+        });
 
     // Extract tenant for tenant-aware limiting.
     let tenant_id = req
@@ -206,6 +282,13 @@ pub async fn rate_limit_middleware(
     }
 
     let remaining = limit_result.remaining;
+    req.extensions_mut().insert(RateLimitCharge {
+        limiter: Arc::clone(&limiter),
+        user_id: user_id.clone(),
+        ip,
+        tenant_id,
+        remaining,
+    });
 
     let response = next.run(req).await;
 
@@ -21,7 +21,7 @@ pub use config::{CheckResult, RateLimitConfig, RateLimitingSecurityConfig};
 pub(crate) use config::{DEFAULT_FAILED_LOGIN_LOCKOUT_SECS, DEFAULT_FAILED_LOGIN_MAX_ATTEMPTS};
 pub use dispatch::RateLimiter;
 pub use key::build_rate_limit_key;
-pub use middleware_fn::{RateLimitExceeded, rate_limit_middleware};
+pub use middleware_fn::{RateLimitCharge, RateLimitExceeded, rate_limit_middleware};
 // Re-export redis metrics for use by the metrics endpoint
 #[cfg(feature = "redis-rate-limiting")]
 pub use redis::{REDIS_RATE_LIMIT_ERRORS, redis_error_count_total};
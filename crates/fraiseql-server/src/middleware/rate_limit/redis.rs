@@ -6,7 +6,9 @@ use tracing::{debug, warn};
 #[cfg(feature = "redis-rate-limiting")]
 use super::config::{CheckResult, RateLimitConfig, RateLimitingSecurityConfig};
 #[cfg(feature = "redis-rate-limiting")]
-use super::key::{PathRateLimit, path_matches_rule};
+use super::in_memory::InMemoryRateLimiter;
+#[cfg(feature = "redis-rate-limiting")]
+use super::key::{PathRateLimit, build_rate_limit_key, path_matches_rule};
 
 /// Convert a Redis Lua `retry_after_ms` value to a `Retry-After` header value
 /// in whole seconds.
@@ -23,14 +25,15 @@ fn retry_after_ms_to_secs(ms: u64) -> u32 {
 
 // ─── Redis backend ────────────────────────────────────────────────────────────
 
-/// Cumulative count of Redis rate limiter errors (fail-open events).
+/// Cumulative count of Redis rate limiter errors (checks answered by the local
+/// fallback buckets instead).
 ///
 /// Exposed via `/metrics` as `fraiseql_rate_limit_redis_errors_total`.
 #[cfg(feature = "redis-rate-limiting")]
 pub static REDIS_RATE_LIMIT_ERRORS: std::sync::atomic::AtomicU64 =
     std::sync::atomic::AtomicU64::new(0);
 
-/// Return the total number of Redis rate-limit fail-open events observed so far.
+/// Return the total number of Redis rate-limit errors observed so far.
 #[cfg(feature = "redis-rate-limiting")]
 pub fn redis_error_count_total() -> u64 {
     REDIS_RATE_LIMIT_ERRORS.load(std::sync::atomic::Ordering::Relaxed)
@@ -90,9 +93,13 @@ end
 /// Uses an atomic Lua token-bucket script (`EVALSHA`) to prevent race
 /// conditions when multiple server replicas share a rate limit.
 ///
-/// **Fail-open**: on Redis errors requests are allowed and a warning is
-/// logged. The cumulative error count is tracked in [`REDIS_RATE_LIMIT_ERRORS`]
-/// and exposed in the `/metrics` endpoint.
+/// Buckets are keyed by [`build_rate_limit_key`] (`fraiseql:rl:{ip|user|path|tenant}:…`),
+/// so every replica pointed at the same Redis draws from the same budget.
+///
+/// **Local fallback**: on a Redis error the check is answered by an in-process
+/// [`InMemoryRateLimiter`] with the same limits, so limiting degrades to
+/// per-replica instead of switching off. Each error is logged and counted in
+/// [`REDIS_RATE_LIMIT_ERRORS`], exposed in the `/metrics` endpoint.
 #[cfg(feature = "redis-rate-limiting")]
 pub struct RedisRateLimiter {
     pool:       redis::aio::ConnectionManager,
@@ -101,6 +108,8 @@ pub struct RedisRateLimiter {
     /// Cached SHA of the loaded Lua script.  Cleared on `NOSCRIPT` errors so
     /// the script is transparently reloaded (e.g. after a Redis restart).
     script_sha: tokio::sync::RwLock<Option<String>>,
+    /// Per-replica buckets consulted while Redis is unreachable.
+    fallback:   InMemoryRateLimiter,
 }
 
 #[cfg(feature = "redis-rate-limiting")]
@@ -115,6 +124,7 @@ impl RedisRateLimiter {
         let pool = redis::aio::ConnectionManager::new(client).await?;
         Ok(Self {
             pool,
+            fallback: InMemoryRateLimiter::new(config.clone()),
             config,
             path_rules: Vec::new(),
             script_sha: tokio::sync::RwLock::new(None),
@@ -158,6 +168,7 @@ impl RedisRateLimiter {
         }
 
         self.path_rules = rules;
+        self.fallback = self.fallback.with_path_rules_from_security(sec);
         self
     }
 
@@ -185,6 +196,13 @@ impl RedisRateLimiter {
         1
     }
 
+    /// Check that Redis answers `PING`.
+    pub(super) async fn ping(&self) -> Result<(), redis::RedisError> {
+        let mut conn = self.pool.clone();
+        let _pong: String = redis::cmd("PING").query_async(&mut conn).await?;
+        Ok(())
+    }
+
     /// Load the Lua script into Redis and cache its SHA for subsequent calls.
     async fn load_script(&self) -> Result<String, redis::RedisError> {
         let cached_sha = self.script_sha.read().await.as_ref().cloned();
@@ -264,48 +282,63 @@ impl RedisRateLimiter {
         })
     }
 
-    /// Check a key against the token bucket, failing open on Redis error.
+    /// Check a key against its Redis token bucket.
     ///
-    /// Returns `(allowed, remaining_tokens, retry_after_ms)`.
-    async fn check_key(&self, key: &str, capacity: u32, rate_per_sec: f64) -> (bool, f64, u64) {
-        if !self.config.enabled {
-            return (true, f64::from(self.config.burst_size), 0);
-        }
+    /// Returns `None` on a Redis error; the caller then answers from the local
+    /// fallback buckets.
+    async fn check_key(&self, key: &str, capacity: u32, rate_per_sec: f64) -> Option<CheckResult> {
         match self.check_and_decrement(key, capacity, rate_per_sec).await {
-            Ok(r) => (r.allowed, r.remaining_tokens, r.retry_after_ms),
+            Ok(r) if r.allowed => Some(CheckResult::allow(r.remaining_tokens)),
+            Ok(r) => Some(CheckResult::deny(retry_after_ms_to_secs(r.retry_after_ms))),
             Err(e) => {
                 REDIS_RATE_LIMIT_ERRORS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-                warn!(error = %e, "Redis rate limiter error — failing open");
-                (true, f64::from(self.config.burst_size), 0)
+                warn!(error = %e, key = key, "Redis rate limiter error — using local fallback");
+                None
             },
         }
     }
 
     /// Check IP limit using the Redis token bucket.
-    pub(super) async fn check_ip_limit(&self, ip: &str) -> CheckResult {
-        let key = format!("fraiseql:rl:ip:{ip}");
-        let (allowed, remaining, retry_after_ms) = self
+    pub(super) async fn check_ip_limit(&self, ip: &str, tenant_id: Option<&str>) -> CheckResult {
+        if !self.config.enabled {
+            return CheckResult::allow(f64::from(self.config.burst_size));
+        }
+        let key = build_rate_limit_key("ip", ip, tenant_id);
+        match self
             .check_key(&key, self.config.burst_size, f64::from(self.config.rps_per_ip))
-            .await;
-        if allowed {
-            CheckResult::allow(remaining)
-        } else {
-            debug!(ip = ip, "Redis rate limit exceeded for IP");
-            CheckResult::deny(retry_after_ms_to_secs(retry_after_ms))
+            .await
+        {
+            Some(result) => {
+                if !result.allowed {
+                    debug!(ip = ip, "Redis rate limit exceeded for IP");
+                }
+                result
+            },
+            None => self.fallback.check_ip_limit(ip, tenant_id).await,
         }
     }
 
     /// Check user limit using the Redis token bucket.
-    pub(super) async fn check_user_limit(&self, user_id: &str) -> CheckResult {
-        let key = format!("fraiseql:rl:user:{user_id}");
-        let (allowed, remaining, retry_after_ms) = self
+    pub(super) async fn check_user_limit(
+        &self,
+        user_id: &str,
+        tenant_id: Option<&str>,
+    ) -> CheckResult {
+        if !self.config.enabled {
+            return CheckResult::allow(f64::from(self.config.burst_size));
+        }
+        let key = build_rate_limit_key("user", user_id, tenant_id);
+        match self
             .check_key(&key, self.config.burst_size, f64::from(self.config.rps_per_user))
-            .await;
-        if allowed {
-            CheckResult::allow(remaining)
-        } else {
-            debug!(user_id = user_id, "Redis rate limit exceeded for user");
-            CheckResult::deny(retry_after_ms_to_secs(retry_after_ms))
+            .await
+        {
+            Some(result) => {
+                if !result.allowed {
+                    debug!(user_id = user_id, "Redis rate limit exceeded for user");
+                }
+                result
+            },
+            None => self.fallback.check_user_limit(user_id, tenant_id).await,
         }
     }
 
@@ -320,19 +353,46 @@ impl RedisRateLimiter {
         let Some(rule) = rule else {
             return CheckResult::allow(f64::from(self.config.burst_size));
         };
-        let key = format!("fraiseql:rl:path:{}:{ip}", rule.path_prefix);
+        let key = build_rate_limit_key("path", ip, Some(&rule.path_prefix));
         // Capacity must be >= 1 (milli-token precision handles sub-1 rates).
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         // Reason: burst is a small positive config value; truncation/sign loss impossible in
         // practice
         let capacity = (rule.burst as u32).max(1);
-        let (allowed, remaining, retry_after_ms) =
-            self.check_key(&key, capacity, rule.tokens_per_sec).await;
-        if allowed {
-            CheckResult::allow(remaining)
-        } else {
-            debug!(ip = ip, path = path, "Redis per-path rate limit exceeded");
-            CheckResult::deny(retry_after_ms_to_secs(retry_after_ms))
+        match self.check_key(&key, capacity, rule.tokens_per_sec).await {
+            Some(result) => {
+                if !result.allowed {
+                    debug!(ip = ip, path = path, "Redis per-path rate limit exceeded");
+                }
+                result
+            },
+            None => self.fallback.check_path_limit(path, ip).await,
         }
     }
+
+    /// Check the per-tenant limit for tenant `tenant_key` using a Redis token bucket
+    /// with the tenant's own `rps` and `burst`.
+    pub(super) async fn check_tenant_limit(
+        &self,
+        tenant_key: &str,
+        rps: u32,
+        burst: u32,
+    ) -> CheckResult {
+        let key = build_rate_limit_key("tenant", tenant_key, None);
+        match self.check_key(&key, burst.max(1), f64::from(rps)).await {
+            Some(result) => {
+                if !result.allowed {
+                    debug!(tenant_key = tenant_key, "Redis per-tenant rate limit exceeded");
+                }
+                result
+            },
+            None => self.fallback.check_tenant_limit(tenant_key, rps, burst).await,
+        }
+    }
+
+    /// Evict stale buckets of the local fallback. Redis expires its own buckets
+    /// via `PEXPIRE`.
+    pub(super) async fn cleanup(&self) {
+        self.fallback.cleanup().await;
+    }
 }
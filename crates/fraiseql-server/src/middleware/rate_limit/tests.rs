@@ -11,7 +11,7 @@
 #![allow(clippy::items_after_statements)] // Reason: test helpers defined near use site
 
 use super::{
-    middleware_fn::{extract_jwt_subject, extract_real_ip},
+    middleware_fn::{api_key_identity, extract_jwt_subject, extract_real_ip},
     *,
 };
 
@@ -639,6 +639,16 @@ fn test_extract_jwt_subject_returns_sub_claim() {
     assert_eq!(extract_jwt_subject(&token), Some("user-42".to_string()));
 }
 
+#[test]
+fn test_api_key_identity_is_stable_and_hides_the_key() {
+    let identity = api_key_identity("fk_live_secret");
+    assert_eq!(identity, api_key_identity("fk_live_secret"));
+    assert_ne!(identity, api_key_identity("fk_live_other"));
+    assert!(identity.starts_with("api_key:"));
+    assert_eq!(identity.len(), "api_key:".len() + 32);
+    assert!(!identity.contains("secret"));
+}
+
 #[test]
 fn test_extract_jwt_subject_no_bearer_prefix_returns_none() {
     assert_eq!(extract_jwt_subject("Basic dXNlcjpwYXNz"), None);
@@ -855,6 +865,7 @@ async fn test_redis_rate_limiter_allows_up_to_capacity() {
         trust_proxy_headers:   false,
         trusted_proxy_cidrs:   Vec::new(),
         max_buckets:           100_000,
+        redis_url:             None,
     };
     let rl = RateLimiter::new_redis(&url, config).await.expect("Redis connection failed");
     let ip = format!("test_allow:{}", uuid::Uuid::new_v4());
@@ -878,6 +889,7 @@ async fn test_redis_two_instances_share_bucket() {
         trust_proxy_headers:   false,
         trusted_proxy_cidrs:   Vec::new(),
         max_buckets:           100_000,
+        redis_url:             None,
     };
     let suffix = uuid::Uuid::new_v4();
     let a = RateLimiter::new_redis(&url, config.clone())
@@ -59,6 +59,142 @@ mod admin_scope_tests {
     }
 }
 
+mod admin_role_tests {
+    #![allow(clippy::unwrap_used)] // Reason: test module
+
+    use axum::{
+        Extension, Router,
+        body::Body,
+        http::{Method, Request, StatusCode},
+        middleware,
+        routing::post,
+    };
+    use tower::ServiceExt;
+
+    use super::super::admin_role::{
+        AdminActor, AdminRole, AdminTokenAuthState, OPERATOR_SCOPE, VIEWER_SCOPE,
+        admin_token_role_middleware, require_admin_role,
+    };
+
+    const ADMIN: &str = "admin-token-that-is-long-enough-000000";
+    const OPERATOR: &str = "operator-token-that-is-long-enough-000";
+    const VIEWER: &str = "viewer-token-that-is-long-enough-00000";
+
+    async fn whoami(Extension(actor): Extension<AdminActor>) -> String {
+        actor.id
+    }
+
+    fn app(required: AdminRole) -> Router {
+        let auth = AdminTokenAuthState::new(required, 10)
+            .with_token(ADMIN, AdminRole::Admin)
+            .with_token(OPERATOR, AdminRole::Operator)
+            .with_token(VIEWER, AdminRole::Viewer);
+        Router::new()
+            .route("/action", post(whoami))
+            .route_layer(middleware::from_fn_with_state(auth, admin_token_role_middleware))
+    }
+
+    async fn call(app: Router, token: &str) -> (StatusCode, String) {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/action")
+            .header("Authorization", format!("Bearer {token}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn highest_scope_wins() {
+        let scopes = |s: &[&str]| s.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert_eq!(
+            AdminRole::from_scopes(&scopes(&[VIEWER_SCOPE, "fraiseql:admin"])),
+            Some(AdminRole::Admin)
+        );
+        assert_eq!(
+            AdminRole::from_scopes(&scopes(&["read", OPERATOR_SCOPE, VIEWER_SCOPE])),
+            Some(AdminRole::Operator)
+        );
+        assert_eq!(AdminRole::from_scopes(&scopes(&[VIEWER_SCOPE])), Some(AdminRole::Viewer));
+        assert_eq!(AdminRole::from_scopes(&scopes(&["read", "write"])), None);
+    }
+
+    #[test]
+    fn reads_need_viewer_and_writes_need_operator() {
+        assert_eq!(AdminRole::required_for(&Method::GET), AdminRole::Viewer);
+        assert_eq!(AdminRole::required_for(&Method::POST), AdminRole::Operator);
+        assert_eq!(AdminRole::required_for(&Method::DELETE), AdminRole::Operator);
+        assert!(AdminRole::Admin > AdminRole::Operator && AdminRole::Operator > AdminRole::Viewer);
+    }
+
+    #[tokio::test]
+    async fn higher_roles_satisfy_lower_requirements() {
+        assert_eq!(
+            call(app(AdminRole::Operator), ADMIN).await,
+            (StatusCode::OK, "token:admin".to_string())
+        );
+        assert_eq!(
+            call(app(AdminRole::Operator), OPERATOR).await,
+            (StatusCode::OK, "token:operator".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn lower_roles_are_forbidden() {
+        let (status, body) = call(app(AdminRole::Operator), VIEWER).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(body.contains("'operator' role"), "{body}");
+
+        let (status, _) = call(app(AdminRole::Admin), OPERATOR).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn unknown_and_missing_tokens_are_rejected() {
+        let (status, body) = call(app(AdminRole::Viewer), "not-a-configured-token").await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body, "Invalid token");
+
+        let response = app(AdminRole::Viewer)
+            .oneshot(Request::builder().method("POST").uri("/action").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn route_requirement_escalates_within_the_router() {
+        let auth = AdminTokenAuthState::new(AdminRole::Viewer, 10)
+            .with_token(ADMIN, AdminRole::Admin)
+            .with_token(OPERATOR, AdminRole::Operator);
+        let app = Router::new()
+            .route(
+                "/action",
+                post(whoami)
+                    .layer(middleware::from_fn_with_state(AdminRole::Admin, require_admin_role)),
+            )
+            .route_layer(middleware::from_fn_with_state(auth, admin_token_role_middleware));
+
+        assert_eq!(call(app.clone(), OPERATOR).await.0, StatusCode::FORBIDDEN);
+        assert_eq!(call(app, ADMIN).await.0, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn route_requirement_fails_closed_without_an_actor() {
+        let app = Router::new().route(
+            "/action",
+            post(|| async { "ok" })
+                .layer(middleware::from_fn_with_state(AdminRole::Viewer, require_admin_role)),
+        );
+
+        assert_eq!(call(app, ADMIN).await.0, StatusCode::FORBIDDEN);
+    }
+}
+
 mod auth_tests {
     #![allow(clippy::unwrap_used)]
     #![allow(clippy::cast_precision_loss)]
@@ -16,6 +16,7 @@ use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use super::runtime::{InMemoryDlq, ObserverRuntime};
+use crate::admin_audit::AuditedChange;
 
 // ── State ────────────────────────────────────────────────────────────────────
 
@@ -301,7 +302,9 @@ pub async fn dlq_retry_handler(
             .into_response();
     };
 
-    match claim_and_process(runtime.dlq(), executor, id).await {
+    let snapshot = || runtime.dlq().get(id).map(|item| DlqItemResponse::from(&item));
+    let before = snapshot();
+    let response = match claim_and_process(runtime.dlq(), executor, id).await {
         RetryOutcome::NotFound => (
             StatusCode::NOT_FOUND,
             Json(RetryResponse {
@@ -329,7 +332,10 @@ pub async fn dlq_retry_handler(
             }),
         )
             .into_response(),
-    }
+    };
+    // A successful retry removes the item; a failed one re-inserts it with the
+    // attempt counted.
+    AuditedChange::new(before, snapshot()).attach(response)
 }
 
 /// `POST /api/observers/dlq/retry-all`
@@ -407,8 +413,9 @@ pub async fn dlq_delete_handler(
     let runtime = state.runtime.read().await;
     let dlq = runtime.dlq();
 
-    if dlq.try_claim(id).is_some() {
-        (StatusCode::OK, Json(serde_json::json!({ "deleted": id }))).into_response()
+    if let Some(item) = dlq.try_claim(id) {
+        AuditedChange::new(DlqItemResponse::from(&item), None::<DlqItemResponse>)
+            .attach((StatusCode::OK, Json(serde_json::json!({ "deleted": id }))).into_response())
     } else {
         (
             StatusCode::NOT_FOUND,
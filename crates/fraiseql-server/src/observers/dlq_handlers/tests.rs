@@ -99,6 +99,8 @@ async fn concurrent_retry_dispatches_at_most_once() {
         body_template:      None,
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     };
     let id = runtime.dlq().push(event, action, "boom".to_string()).await.expect("push");
 
@@ -160,6 +162,8 @@ mod dlq_endpoints {
             body_template:      None,
             signing_secret:     None,
             signing_secret_env: None,
+            signing_secret_ref: None,
+            signing_key_id:     None,
         }
     }
 
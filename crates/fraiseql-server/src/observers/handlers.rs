@@ -13,7 +13,7 @@ use super::{
     ActionConfig, CreateObserverRequest, ListObserverLogsQuery, ListObserversQuery,
     ObserverRepository, PaginatedResponse, UpdateObserverRequest,
 };
-use crate::extractors::OptionalSecurityContext;
+use crate::{admin_audit::AuditedChange, extractors::OptionalSecurityContext};
 
 /// Reject observer actions whose `type` the runtime cannot dispatch (#612 item 10).
 ///
@@ -367,11 +367,14 @@ pub async fn enable_observer(
 
     let customer_org: Option<i64> = None;
     let updated_by: Option<&str> = None;
+    let before = enabled_state(&state, id, customer_org).await;
 
     match state.repository.update(id, &request, customer_org, updated_by).await {
         Ok(Some(observer)) => {
             refresh_runtime_after_write(&state).await;
-            (StatusCode::OK, Json(observer.with_redacted_secrets())).into_response()
+            let change =
+                AuditedChange::new(before, serde_json::json!({ "enabled": observer.enabled }));
+            change.attach((StatusCode::OK, Json(observer.with_redacted_secrets())).into_response())
         },
         Ok(None) => (
             StatusCode::NOT_FOUND,
@@ -404,11 +407,14 @@ pub async fn disable_observer(
 
     let customer_org: Option<i64> = None;
     let updated_by: Option<&str> = None;
+    let before = enabled_state(&state, id, customer_org).await;
 
     match state.repository.update(id, &request, customer_org, updated_by).await {
         Ok(Some(observer)) => {
             refresh_runtime_after_write(&state).await;
-            (StatusCode::OK, Json(observer.with_redacted_secrets())).into_response()
+            let change =
+                AuditedChange::new(before, serde_json::json!({ "enabled": observer.enabled }));
+            change.attach((StatusCode::OK, Json(observer.with_redacted_secrets())).into_response())
         },
         Ok(None) => (
             StatusCode::NOT_FOUND,
@@ -427,6 +433,16 @@ pub async fn disable_observer(
     }
 }
 
+/// The observer's `enabled` flag as audit state, or `None` when it cannot be read.
+async fn enabled_state(
+    state: &ObserverState,
+    id: Uuid,
+    customer_org: Option<i64>,
+) -> Option<serde_json::Value> {
+    let observer = state.repository.get_by_id(id, customer_org).await.ok().flatten()?;
+    Some(serde_json::json!({ "enabled": observer.enabled }))
+}
+
 // ============================================================================
 // Runtime Health Check Handlers
 // ============================================================================
@@ -494,6 +510,173 @@ pub async fn reload_observers(State(state): State<RuntimeHealthState>) -> impl I
     }
 }
 
+/// List paused observers with their pins and backlogs.
+///
+/// GET /api/observers/runtime/paused
+pub async fn list_paused_observers(State(state): State<RuntimeHealthState>) -> impl IntoResponse {
+    let runtime = state.runtime.read().await;
+    (StatusCode::OK, Json(runtime.paused_observers())).into_response()
+}
+
+/// Pause an observer; its events accumulate in the change log until it resumes.
+///
+/// POST /api/observers/runtime/observers/{name}/pause
+///
+/// Body: [`PauseObserverRequest`](super::PauseObserverRequest) — `{}` for the
+/// defaults.
+pub async fn pause_observer(
+    State(state): State<RuntimeHealthState>,
+    OptionalSecurityContext(security_context): OptionalSecurityContext,
+    Path(name): Path<String>,
+    Json(request): Json<super::PauseObserverRequest>,
+) -> impl IntoResponse {
+    let paused_by = extract_user_id(security_context.as_ref());
+    let runtime = state.runtime.read().await;
+    let before = pause_state(&runtime, &name);
+
+    match runtime.pause_observer(&name, &request, paused_by).await {
+        Ok(status) => AuditedChange::new(before, &status)
+            .attach((StatusCode::OK, Json(status)).into_response()),
+        Err(e) => pause_error_response("pause", &name, &e),
+    }
+}
+
+/// Resume a paused observer. Its backlog is delivered in the background; the
+/// response reports the observer as `resuming`.
+///
+/// POST /api/observers/runtime/observers/{name}/resume
+pub async fn resume_observer(
+    State(state): State<RuntimeHealthState>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    let before = pause_state(&*state.runtime.read().await, &name);
+    match super::ObserverRuntime::resume_observer(&state.runtime, &name).await {
+        Ok(status) => AuditedChange::new(before, &status)
+            .attach((StatusCode::ACCEPTED, Json(status)).into_response()),
+        Err(e) => pause_error_response("resume", &name, &e),
+    }
+}
+
+/// The observer's pause status, or `None` when it is running normally.
+fn pause_state(runtime: &super::ObserverRuntime, name: &str) -> Option<super::PauseStatus> {
+    runtime.paused_observers().into_iter().find(|status| status.observer == name)
+}
+
+/// Map a pause/resume failure to its HTTP status.
+fn pause_error_response(op: &str, name: &str, error: &crate::ServerError) -> Response {
+    let status = match error {
+        crate::ServerError::Validation(_) => StatusCode::BAD_REQUEST,
+        crate::ServerError::NotFound(_) => StatusCode::NOT_FOUND,
+        crate::ServerError::Conflict(_) => StatusCode::CONFLICT,
+        _ => {
+            tracing::error!("Failed to {} observer {}: {}", op, name, error);
+            StatusCode::INTERNAL_SERVER_ERROR
+        },
+    };
+    (status, Json(serde_json::json!({ "error": error.to_string() }))).into_response()
+}
+
+/// Most quarantined events one request returns.
+const MAX_QUARANTINE_LIMIT: usize = 1_000;
+
+/// List declared event types.
+///
+/// GET /api/observers/runtime/event-types
+pub async fn list_event_types(State(state): State<RuntimeHealthState>) -> impl IntoResponse {
+    let runtime = state.runtime.read().await;
+    (StatusCode::OK, Json(runtime.event_types())).into_response()
+}
+
+/// Get the declaration of an event type.
+///
+/// GET /api/observers/runtime/event-types/{entity_type}
+pub async fn get_event_type(
+    State(state): State<RuntimeHealthState>,
+    Path(entity_type): Path<String>,
+) -> impl IntoResponse {
+    let runtime = state.runtime.read().await;
+    match runtime.event_type(&entity_type) {
+        Some(definition) => (StatusCode::OK, Json(definition)).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("event type '{entity_type}' is not declared")
+            })),
+        )
+            .into_response(),
+    }
+}
+
+/// Declare an event type, replacing any previous declaration.
+///
+/// PUT /api/observers/runtime/event-types/{entity_type}
+///
+/// Body: [`PutEventTypeRequest`](super::PutEventTypeRequest).
+pub async fn put_event_type(
+    State(state): State<RuntimeHealthState>,
+    Path(entity_type): Path<String>,
+    Json(request): Json<super::PutEventTypeRequest>,
+) -> impl IntoResponse {
+    let runtime = state.runtime.read().await;
+    let before = runtime.event_type(&entity_type);
+    match runtime.register_event_type(request.into_definition(entity_type.clone())).await {
+        Ok(definition) => AuditedChange::new(before, &definition)
+            .attach((StatusCode::OK, Json(definition)).into_response()),
+        Err(e) => event_type_error_response("declare", &entity_type, &e),
+    }
+}
+
+/// Remove the declaration of an event type.
+///
+/// DELETE /api/observers/runtime/event-types/{entity_type}
+pub async fn delete_event_type(
+    State(state): State<RuntimeHealthState>,
+    Path(entity_type): Path<String>,
+) -> impl IntoResponse {
+    let runtime = state.runtime.read().await;
+    match runtime.unregister_event_type(&entity_type).await {
+        Ok(removed) => {
+            AuditedChange::new(&removed, None::<()>).attach(StatusCode::NO_CONTENT.into_response())
+        },
+        Err(e) => event_type_error_response("remove", &entity_type, &e),
+    }
+}
+
+/// List quarantined events, newest first.
+///
+/// GET /api/observers/runtime/quarantine?entity_type=&limit=
+pub async fn list_quarantined_events(
+    State(state): State<RuntimeHealthState>,
+    Query(query): Query<super::ListQuarantineQuery>,
+) -> impl IntoResponse {
+    let runtime = state.runtime.read().await;
+    let limit = query.limit.clamp(1, MAX_QUARANTINE_LIMIT);
+    match runtime.quarantined_events(query.entity_type.as_deref(), limit).await {
+        Ok(entries) => (StatusCode::OK, Json(entries)).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to list quarantined events: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+                .into_response()
+        },
+    }
+}
+
+/// Map an event type declaration failure to its HTTP status.
+fn event_type_error_response(op: &str, entity_type: &str, error: &crate::ServerError) -> Response {
+    let status = match error {
+        crate::ServerError::Validation(_) => StatusCode::BAD_REQUEST,
+        crate::ServerError::NotFound(_) => StatusCode::NOT_FOUND,
+        _ => {
+            tracing::error!("Failed to {} event type {}: {}", op, entity_type, error);
+            StatusCode::INTERNAL_SERVER_ERROR
+        },
+    };
+    (status, Json(serde_json::json!({ "error": error.to_string() }))).into_response()
+}
+
 // ============================================================================
 // Authentication Context Extraction
 // ============================================================================
@@ -29,6 +29,7 @@ pub mod changelog_handlers;
 pub mod config;
 pub mod dlq_handlers;
 pub mod handlers;
+pub mod pause;
 /// Postgres-backed durable function-dispatch dead-letter store (#598).
 ///
 /// Only meaningful when function dispatch is compiled in, so gated on
@@ -39,6 +40,9 @@ pub mod pg_function_dlq;
 pub mod repository;
 pub mod routes;
 pub mod runtime;
+/// Webhook signing secrets resolved through the `SecretsManager`.
+#[cfg(feature = "secrets")]
+pub mod signing_secrets;
 
 #[cfg(test)]
 mod tests;
@@ -48,6 +52,7 @@ use chrono::{DateTime, Utc};
 pub use config::ObserverManagementConfig;
 pub use dlq_handlers::DlqState;
 pub use handlers::{ObserverState, RuntimeHealthState};
+pub use pause::{PauseObserverRequest, PauseState, PauseStatus};
 pub use repository::ObserverRepository;
 pub use routes::{
     observer_changelog_routes, observer_dlq_routes, observer_routes, observer_runtime_routes,
@@ -298,6 +303,15 @@ pub enum ActionConfig {
         /// runtime's `fraiseql_observers::ActionConfig::Webhook`.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         signing_secret_env: Option<String>,
+        /// Name of the HMAC signing secret in the server's secrets manager.
+        ///
+        /// Mutually exclusive with `signing_secret` and `signing_secret_env`.
+        /// Only a *name*, so it is left visible in admin-API responses.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signing_secret_ref: Option<String>,
+        /// Key id sent in `X-FraiseQL-Signature-Key-Id` alongside the signature.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signing_key_id:     Option<String>,
     },
 
     /// Email notification action
@@ -565,6 +579,50 @@ pub struct ListObserverLogsQuery {
     pub page_size: i64,
 }
 
+/// Request body declaring an event type.
+///
+/// `PUT /api/observers/runtime/event-types/{entity_type}` — the entity type
+/// comes from the path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PutEventTypeRequest {
+    /// Event kinds the schema applies to; empty for every kind
+    #[serde(default)]
+    pub event_kinds: Vec<fraiseql_observers::EventKind>,
+
+    /// JSON Schema of the event payload
+    pub schema: serde_json::Value,
+
+    /// What the events mean
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl PutEventTypeRequest {
+    /// The declaration of `entity_type` this request makes.
+    #[must_use]
+    pub fn into_definition(self, entity_type: String) -> fraiseql_observers::EventTypeDefinition {
+        fraiseql_observers::EventTypeDefinition {
+            entity_type,
+            event_kinds: self.event_kinds,
+            schema: self.schema,
+            description: self.description,
+        }
+    }
+}
+
+/// Query parameters for listing quarantined events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListQuarantineQuery {
+    /// Filter by entity type
+    #[serde(default)]
+    pub entity_type: Option<String>,
+
+    /// Maximum entries, newest first (default 100, at most 1000)
+    #[serde(default = "default_quarantine_limit")]
+    pub limit: usize,
+}
+
 /// Paginated response wrapper.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedResponse<T> {
@@ -629,3 +687,6 @@ const fn default_page() -> i64 {
 const fn default_page_size() -> i64 {
     20
 }
+const fn default_quarantine_limit() -> usize {
+    100
+}
@@ -0,0 +1,416 @@
+//! Pausing observers without dropping their events.
+//!
+//! A paused observer is taken out of live dispatch, but its events are not
+//! dropped: the change-log position it has been delivered up to is *pinned* in
+//! `_fraiseql_observer_pause` (DDL:
+//! [`observer_pause_sql`](fraiseql_observers::migrations::observer_pause_sql)).
+//! Every other observer keeps running, and the paused observer's backlog is the
+//! change-log entries after its pin — they stay in `core.tb_entity_change_log`
+//! until it resumes.
+//!
+//! Resuming replays the backlog through an executor holding only that observer,
+//! persisting the pin after each batch so a crash mid-way loses no progress. The
+//! bulk runs alongside live processing; the last stretch is delivered while the
+//! live loop is held between batches, and the observer rejoins the live matcher
+//! in the same critical section. The handoff therefore neither skips an event
+//! nor delivers one twice.
+//!
+//! While an observer is paused the runtime counts the events it would have
+//! matched. Reaching `max_backlog` logs an alert (once per crossing) and sets
+//! `backlog_exceeded` in `GET /api/observers/runtime/paused`. The count is
+//! approximate: it starts from zero when the server restarts, and it ignores
+//! observer conditions, which are evaluated only at delivery.
+//!
+//! Pausing needs the PostgreSQL change-log transport. Other transports own their
+//! delivery state, so there is no change-log position to pin.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+    },
+};
+
+use chrono::{DateTime, Utc};
+use fraiseql_observers::{
+    ChangeLogListener, ChangeLogListenerConfig, EntityEvent, EventMatcher, ObserverDefinition,
+    ObserverExecutor,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{debug, warn};
+
+use crate::ServerError;
+
+#[cfg(test)]
+mod tests;
+
+/// Backlog size that raises an alert when a pause request gives none.
+pub const DEFAULT_MAX_PAUSED_BACKLOG: u64 = 10_000;
+
+/// Request body for `POST /api/observers/runtime/observers/{name}/pause`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PauseObserverRequest {
+    /// Backlog size that raises an alert (default:
+    /// [`DEFAULT_MAX_PAUSED_BACKLOG`]).
+    #[serde(default)]
+    pub max_backlog: Option<u64>,
+
+    /// Why the observer is paused, recorded with the pause.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Lifecycle state of a paused observer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PauseState {
+    /// Out of live dispatch; events accumulate after the pin.
+    Paused,
+    /// Replaying the backlog before rejoining live dispatch.
+    Resuming,
+}
+
+/// A paused observer as reported by the admin API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PauseStatus {
+    /// Observer name.
+    pub observer:          String,
+    /// Whether the observer is still paused or catching up.
+    pub state:             PauseState,
+    /// Last change-log id delivered to the observer.
+    pub pinned_checkpoint: i64,
+    /// Events matching the observer's entity and event type seen since the
+    /// pause (approximate; see the module docs).
+    pub backlog:           u64,
+    /// Backlog size that raises an alert.
+    pub max_backlog:       u64,
+    /// Whether the backlog has reached `max_backlog`.
+    pub backlog_exceeded:  bool,
+    /// Who paused the observer.
+    pub paused_by:         Option<String>,
+    /// Why the observer was paused.
+    pub reason:            Option<String>,
+    /// When the pause started.
+    pub paused_at:         DateTime<Utc>,
+}
+
+/// Resolve a requested alert threshold, applying the default.
+///
+/// # Errors
+///
+/// Returns `ServerError::Validation` for `0`, which would alert immediately,
+/// or a value that does not fit the `BIGINT` column.
+pub fn resolve_max_backlog(requested: Option<u64>) -> Result<u64, ServerError> {
+    match requested {
+        None => Ok(DEFAULT_MAX_PAUSED_BACKLOG),
+        Some(0) => Err(ServerError::Validation("max_backlog must be at least 1".to_string())),
+        Some(n) if i64::try_from(n).is_err() => {
+            Err(ServerError::Validation(format!("max_backlog must be at most {}", i64::MAX)))
+        },
+        Some(n) => Ok(n),
+    }
+}
+
+/// One row of `_fraiseql_observer_pause`.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct PauseRow {
+    pub(crate) observer_name:     String,
+    pub(crate) pinned_checkpoint: i64,
+    pub(crate) max_backlog:       i64,
+    pub(crate) paused_by:         Option<String>,
+    pub(crate) reason:            Option<String>,
+    pub(crate) paused_at:         DateTime<Utc>,
+}
+
+/// Persistence for pause rows.
+#[derive(Clone)]
+pub(crate) struct PauseRepository {
+    pool: PgPool,
+}
+
+/// Map a driver error without leaking the query text.
+fn db_err(op: &str, error: &sqlx::Error) -> ServerError {
+    ServerError::Database(format!("observer pause {op}: {error}"))
+}
+
+impl PauseRepository {
+    pub(crate) const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create `_fraiseql_observer_pause` (idempotent DDL).
+    pub(crate) async fn init(&self) -> Result<(), ServerError> {
+        sqlx::raw_sql(fraiseql_observers::migrations::observer_pause_sql())
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| db_err("init", &e))
+    }
+
+    pub(crate) async fn list(&self) -> Result<Vec<PauseRow>, ServerError> {
+        sqlx::query_as(
+            "SELECT observer_name, pinned_checkpoint, max_backlog, paused_by, reason, paused_at \
+             FROM _fraiseql_observer_pause ORDER BY observer_name",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_err("list", &e))
+    }
+
+    /// Pin `name` at `pinned_checkpoint`.
+    ///
+    /// Returns `false` — and writes nothing — when no enabled observer has that
+    /// name or it already has a pause row.
+    pub(crate) async fn insert(
+        &self,
+        name: &str,
+        pinned_checkpoint: i64,
+        max_backlog: u64,
+        paused_by: Option<&str>,
+        reason: Option<&str>,
+    ) -> Result<bool, ServerError> {
+        let max_backlog = i64::try_from(max_backlog).unwrap_or(i64::MAX);
+        let inserted = sqlx::query(
+            "INSERT INTO _fraiseql_observer_pause \
+                 (observer_name, pinned_checkpoint, max_backlog, paused_by, reason) \
+             SELECT $1, $2, $3, $4, $5 \
+             WHERE EXISTS (SELECT 1 FROM tb_observer \
+                           WHERE name = $1 AND enabled AND deleted_at IS NULL) \
+             ON CONFLICT (observer_name) DO NOTHING",
+        )
+        .bind(name)
+        .bind(pinned_checkpoint)
+        .bind(max_backlog)
+        .bind(paused_by)
+        .bind(reason)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_err("insert", &e))?;
+        Ok(inserted.rows_affected() == 1)
+    }
+
+    pub(crate) async fn advance_pin(
+        &self,
+        name: &str,
+        pinned_checkpoint: i64,
+    ) -> Result<(), ServerError> {
+        sqlx::query(
+            "UPDATE _fraiseql_observer_pause \
+             SET pinned_checkpoint = $2, updated_at = NOW() \
+             WHERE observer_name = $1",
+        )
+        .bind(name)
+        .bind(pinned_checkpoint)
+        .execute(&self.pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| db_err("advance pin", &e))
+    }
+
+    pub(crate) async fn remove(&self, name: &str) -> Result<(), ServerError> {
+        sqlx::query("DELETE FROM _fraiseql_observer_pause WHERE observer_name = $1")
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| db_err("remove", &e))
+    }
+}
+
+/// A paused observer tracked by the running runtime.
+pub(crate) struct PausedObserver {
+    name:              String,
+    definition:        ObserverDefinition,
+    /// The observer alone, to tell which live events belong to its backlog.
+    matcher:           EventMatcher,
+    pinned_checkpoint: AtomicI64,
+    max_backlog:       u64,
+    backlog:           AtomicU64,
+    alerted:           AtomicBool,
+    resuming:          AtomicBool,
+    paused_by:         Option<String>,
+    reason:            Option<String>,
+    paused_at:         DateTime<Utc>,
+}
+
+impl PausedObserver {
+    /// Track `row`, carrying the backlog count and resume state of the entry
+    /// it replaces on reload.
+    pub(crate) fn new(
+        row: PauseRow,
+        definition: ObserverDefinition,
+        previous: Option<&Self>,
+    ) -> Result<Self, ServerError> {
+        let matcher =
+            EventMatcher::build(HashMap::from([(row.observer_name.clone(), definition.clone())]))
+                .map_err(|e| {
+                ServerError::ConfigError(format!(
+                    "Failed to build matcher for paused observer {}: {e}",
+                    row.observer_name
+                ))
+            })?;
+        Ok(Self {
+            name: row.observer_name,
+            definition,
+            matcher,
+            pinned_checkpoint: AtomicI64::new(row.pinned_checkpoint),
+            max_backlog: u64::try_from(row.max_backlog).unwrap_or(DEFAULT_MAX_PAUSED_BACKLOG),
+            backlog: AtomicU64::new(previous.map_or(0, Self::backlog)),
+            alerted: AtomicBool::new(previous.is_some_and(|p| p.alerted.load(Ordering::SeqCst))),
+            resuming: AtomicBool::new(previous.is_some_and(Self::is_resuming)),
+            paused_by: row.paused_by,
+            reason: row.reason,
+            paused_at: row.paused_at,
+        })
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) const fn definition(&self) -> &ObserverDefinition {
+        &self.definition
+    }
+
+    pub(crate) fn pinned_checkpoint(&self) -> i64 {
+        self.pinned_checkpoint.load(Ordering::SeqCst)
+    }
+
+    fn backlog(&self) -> u64 {
+        self.backlog.load(Ordering::SeqCst)
+    }
+
+    fn is_resuming(&self) -> bool {
+        self.resuming.load(Ordering::SeqCst)
+    }
+
+    fn matches(&self, event: &EntityEvent) -> bool {
+        !self.matcher.find_matches(event).is_empty()
+    }
+
+    /// Count a live change-log entry the observer missed, alerting when the
+    /// backlog reaches `max_backlog`.
+    pub(crate) fn record(&self, change_log_id: i64, event: &EntityEvent) {
+        if change_log_id <= self.pinned_checkpoint() || !self.matches(event) {
+            return;
+        }
+        let backlog = self.backlog.fetch_add(1, Ordering::SeqCst) + 1;
+        if backlog >= self.max_backlog && !self.alerted.swap(true, Ordering::SeqCst) {
+            warn!(
+                observer = %self.name,
+                backlog,
+                max_backlog = self.max_backlog,
+                pinned_checkpoint = self.pinned_checkpoint(),
+                "Paused observer backlog reached its threshold; resume it or raise max_backlog"
+            );
+        }
+    }
+
+    /// Count one backlog event as delivered, re-arming the alert once the
+    /// backlog is back under the threshold.
+    fn drain_one(&self) {
+        let _ = self
+            .backlog
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| Some(n.saturating_sub(1)));
+        if self.backlog() < self.max_backlog {
+            self.alerted.store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// Mark the observer as resuming; `false` if it already was.
+    pub(crate) fn begin_resume(&self) -> bool {
+        !self.resuming.swap(true, Ordering::SeqCst)
+    }
+
+    /// Return to plain `Paused` after a failed resume.
+    pub(crate) fn abort_resume(&self) {
+        self.resuming.store(false, Ordering::SeqCst);
+    }
+
+    pub(crate) fn status(&self) -> PauseStatus {
+        let backlog = self.backlog();
+        PauseStatus {
+            observer: self.name.clone(),
+            state: if self.is_resuming() {
+                PauseState::Resuming
+            } else {
+                PauseState::Paused
+            },
+            pinned_checkpoint: self.pinned_checkpoint(),
+            backlog,
+            max_backlog: self.max_backlog,
+            backlog_exceeded: backlog >= self.max_backlog,
+            paused_by: self.paused_by.clone(),
+            reason: self.reason.clone(),
+            paused_at: self.paused_at,
+        }
+    }
+}
+
+/// A resume in progress: replays one observer's backlog from its pin.
+pub(crate) struct Resume {
+    pub(crate) observer:   Arc<PausedObserver>,
+    /// Executor holding only the resuming observer.
+    pub(crate) executor:   Arc<ObserverExecutor>,
+    pub(crate) pool:       PgPool,
+    pub(crate) pauses:     PauseRepository,
+    pub(crate) batch_size: usize,
+}
+
+impl Resume {
+    /// Deliver the backlog entries up to change-log id `upper`, persisting the
+    /// pin after each batch.
+    ///
+    /// Entries past `upper` are left to the live loop, which has not dispatched
+    /// them yet.
+    pub(crate) async fn catch_up(&self, upper: i64) -> Result<(), ServerError> {
+        let config = ChangeLogListenerConfig::new(self.pool.clone())
+            .with_batch_size(self.batch_size)
+            .with_resume_from(self.observer.pinned_checkpoint());
+        let mut listener = ChangeLogListener::new(config);
+
+        while self.observer.pinned_checkpoint() < upper {
+            let entries = listener
+                .next_batch()
+                .await
+                .map_err(|e| ServerError::Database(format!("observer resume: {e}")))?;
+            if entries.is_empty() {
+                break;
+            }
+
+            let mut pin = self.observer.pinned_checkpoint();
+            for entry in entries.iter().take_while(|entry| entry.id <= upper) {
+                match entry.to_entity_event() {
+                    Ok(event) => {
+                        if self.observer.matches(&event) {
+                            self.observer.drain_one();
+                        }
+                        if let Err(e) = self.executor.process_event(&event).await {
+                            warn!(
+                                observer = %self.observer.name,
+                                event_id = %event.id,
+                                "Failed to deliver backlog event on resume: {e}"
+                            );
+                        }
+                    },
+                    Err(e) => {
+                        warn!("Failed to convert change log entry {} on resume: {e}", entry.id);
+                    },
+                }
+                pin = entry.id;
+            }
+
+            self.pauses.advance_pin(&self.observer.name, pin).await?;
+            self.observer.pinned_checkpoint.store(pin, Ordering::SeqCst);
+            debug!(observer = %self.observer.name, pin, "Observer resume advanced its pin");
+
+            if entries.last().is_some_and(|entry| entry.id > upper) {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Tests for observer pause bookkeeping.
+//!
+//! Backlog counting, the threshold alert and status reporting are in-memory, so
+//! they are covered here without a database. Pinning and catch-up go through
+//! `_fraiseql_observer_pause` and the change log.
+
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use chrono::Utc;
+use fraiseql_observers::{
+    DeliveryOrdering, EntityEvent, EventKind, FailurePolicy, ObserverDefinition, ReplayPolicy,
+    RetryConfig,
+};
+use uuid::Uuid;
+
+use super::{
+    DEFAULT_MAX_PAUSED_BACKLOG, PauseObserverRequest, PauseRow, PauseState, PauseStatus,
+    PausedObserver, resolve_max_backlog,
+};
+use crate::ServerError;
+
+fn definition(entity: &str, event_type: &str) -> ObserverDefinition {
+    ObserverDefinition {
+        event_type:      event_type.to_string(),
+        entity:          entity.to_string(),
+        condition:       None,
+        actions:         vec![],
+        retry:           RetryConfig::default(),
+        on_failure:      FailurePolicy::default(),
+        replay:          ReplayPolicy::default(),
+        ordering:        DeliveryOrdering::default(),
+        max_concurrency: None,
+        dedup:           None,
+        dry_run:         false,
+    }
+}
+
+fn row(pinned_checkpoint: i64, max_backlog: i64) -> PauseRow {
+    PauseRow {
+        observer_name: "order_webhook".to_string(),
+        pinned_checkpoint,
+        max_backlog,
+        paused_by: Some("ops@example.com".to_string()),
+        reason: Some("partner maintenance".to_string()),
+        paused_at: Utc::now(),
+    }
+}
+
+fn paused(pinned_checkpoint: i64, max_backlog: i64) -> PausedObserver {
+    PausedObserver::new(row(pinned_checkpoint, max_backlog), definition("Order", "UPDATE"), None)
+        .unwrap()
+}
+
+fn event(entity: &str, kind: EventKind) -> EntityEvent {
+    EntityEvent::new(kind, entity.to_string(), Uuid::new_v4(), serde_json::json!({}))
+}
+
+#[test]
+fn max_backlog_defaults_and_rejects_zero_or_oversized() {
+    assert_eq!(resolve_max_backlog(None).unwrap(), DEFAULT_MAX_PAUSED_BACKLOG);
+    assert_eq!(resolve_max_backlog(Some(5)).unwrap(), 5);
+    assert!(matches!(resolve_max_backlog(Some(0)), Err(ServerError::Validation(_))));
+    assert!(matches!(resolve_max_backlog(Some(u64::MAX)), Err(ServerError::Validation(_))));
+}
+
+#[test]
+fn pause_request_fields_are_optional() {
+    let request: PauseObserverRequest = serde_json::from_str("{}").unwrap();
+    assert_eq!(request.max_backlog, None);
+    assert_eq!(request.reason, None);
+}
+
+#[test]
+fn only_matching_events_after_the_pin_count_towards_the_backlog() {
+    let observer = paused(100, 10);
+
+    observer.record(90, &event("Order", EventKind::Updated));
+    observer.record(100, &event("Order", EventKind::Updated));
+    observer.record(101, &event("Invoice", EventKind::Updated));
+    observer.record(102, &event("Order", EventKind::Created));
+    observer.record(103, &event("Order", EventKind::Updated));
+
+    assert_eq!(observer.status().backlog, 1);
+}
+
+#[test]
+fn reaching_max_backlog_flags_the_status_until_drained_below_it() {
+    let observer = paused(0, 2);
+
+    observer.record(1, &event("Order", EventKind::Updated));
+    assert!(!observer.status().backlog_exceeded);
+    observer.record(2, &event("Order", EventKind::Updated));
+    assert!(observer.status().backlog_exceeded);
+    assert!(observer.alerted.load(std::sync::atomic::Ordering::SeqCst));
+
+    observer.drain_one();
+    assert!(!observer.status().backlog_exceeded);
+    assert!(!observer.alerted.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[test]
+fn only_one_resume_runs_at_a_time() {
+    let observer = paused(0, 10);
+
+    assert!(observer.begin_resume());
+    assert!(!observer.begin_resume());
+    assert_eq!(observer.status().state, PauseState::Resuming);
+
+    observer.abort_resume();
+    assert_eq!(observer.status().state, PauseState::Paused);
+    assert!(observer.begin_resume());
+}
+
+#[test]
+fn reload_carries_backlog_and_resume_state_but_takes_the_stored_pin() {
+    let before = paused(10, 10);
+    before.record(11, &event("Order", EventKind::Updated));
+    before.record(12, &event("Order", EventKind::Updated));
+    assert!(before.begin_resume());
+
+    let after =
+        PausedObserver::new(row(11, 10), definition("Order", "UPDATE"), Some(&before)).unwrap();
+
+    let status = after.status();
+    assert_eq!(status.backlog, 2);
+    assert_eq!(status.state, PauseState::Resuming);
+    assert_eq!(status.pinned_checkpoint, 11);
+}
+
+#[test]
+fn status_serializes_state_in_snake_case() {
+    let status: PauseStatus = paused(7, 10).status();
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["observer"], "order_webhook");
+    assert_eq!(json["state"], "paused");
+    assert_eq!(json["pinned_checkpoint"], 7);
+    assert_eq!(json["max_backlog"], 10);
+    assert_eq!(json["reason"], "partner maintenance");
+}
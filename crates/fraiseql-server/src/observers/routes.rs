@@ -2,6 +2,8 @@
 
 use axum::{
     Router,
+    handler::Handler as _,
+    middleware,
     routing::{get, post},
 };
 
@@ -14,16 +16,29 @@ use super::{
         dlq_retry_all_handler, dlq_retry_handler, dlq_stats_handler,
     },
     handlers::{
-        ObserverState, RuntimeHealthState, create_observer, delete_observer, disable_observer,
-        enable_observer, get_observer, get_observer_stats, get_runtime_health, list_observer_logs,
-        list_observers, reload_observers, update_observer,
+        ObserverState, RuntimeHealthState, create_observer, delete_event_type, delete_observer,
+        disable_observer, enable_observer, get_event_type, get_observer, get_observer_stats,
+        get_runtime_health, list_event_types, list_observer_logs, list_observers,
+        list_paused_observers, list_quarantined_events, pause_observer, put_event_type,
+        reload_observers, resume_observer, update_observer,
     },
 };
+use crate::middleware::{AdminRole, require_admin_role};
+
+/// Restrict a single route to the `admin` role. The admin auth layer mounted around
+/// these routers already requires `viewer` for reads and `operator` for writes.
+macro_rules! admin_only {
+    ($handler:expr) => {
+        $handler.layer(middleware::from_fn_with_state(AdminRole::Admin, require_admin_role))
+    };
+}
 
 /// Create the observer management router.
 ///
 /// # Routes
 ///
+/// Creating, updating and deleting observers requires the `admin` role.
+///
 /// - `GET    /`           - List all observers
 /// - `POST   /`           - Create a new observer
 /// - `GET    /stats`      - Get statistics for all observers
@@ -38,11 +53,16 @@ use super::{
 pub fn observer_routes(state: ObserverState) -> Router {
     Router::new()
         // Collection routes
-        .route("/", get(list_observers).post(create_observer))
+        .route("/", get(list_observers).post(admin_only!(create_observer)))
         .route("/stats", get(get_all_stats))
         .route("/logs", get(list_all_logs))
         // Individual observer routes
-        .route("/{id}", get(get_observer).patch(update_observer).delete(delete_observer))
+        .route(
+            "/{id}",
+            get(get_observer)
+                .patch(admin_only!(update_observer))
+                .delete(admin_only!(delete_observer)),
+        )
         .route("/{id}/enable", post(enable_observer))
         .route("/{id}/disable", post(disable_observer))
         .route("/{id}/stats", get(get_single_stats))
@@ -86,12 +106,33 @@ async fn list_single_logs(
 ///
 /// # Routes
 ///
-/// - `GET  /runtime/health` - Get runtime health status
-/// - `POST /runtime/reload` - Reload observers from database
+/// Declaring and removing event types requires the `admin` role.
+///
+/// - `GET    /runtime/health`                     - Get runtime health status
+/// - `POST   /runtime/reload`                     - Reload observers from database
+/// - `GET    /runtime/paused`                     - List paused observers and their backlogs
+/// - `POST   /runtime/observers/{name}/pause`     - Pause an observer, pinning its checkpoint
+/// - `POST   /runtime/observers/{name}/resume`    - Deliver the backlog, then resume live dispatch
+/// - `GET    /runtime/event-types`                - List declared event types
+/// - `GET    /runtime/event-types/{entity_type}`  - Get an event type's declaration
+/// - `PUT    /runtime/event-types/{entity_type}`  - Declare or replace an event type
+/// - `DELETE /runtime/event-types/{entity_type}`  - Remove an event type's declaration
+/// - `GET    /runtime/quarantine`                 - List events that failed validation
 pub fn observer_runtime_routes(state: RuntimeHealthState) -> Router {
     Router::new()
         .route("/runtime/health", get(get_runtime_health))
         .route("/runtime/reload", post(reload_observers))
+        .route("/runtime/paused", get(list_paused_observers))
+        .route("/runtime/observers/{name}/pause", post(pause_observer))
+        .route("/runtime/observers/{name}/resume", post(resume_observer))
+        .route("/runtime/event-types", get(list_event_types))
+        .route(
+            "/runtime/event-types/{entity_type}",
+            get(get_event_type)
+                .put(admin_only!(put_event_type))
+                .delete(admin_only!(delete_event_type)),
+        )
+        .route("/runtime/quarantine", get(list_quarantined_events))
         .with_state(state)
 }
 
@@ -99,6 +140,8 @@ pub fn observer_runtime_routes(state: RuntimeHealthState) -> Router {
 ///
 /// # Routes
 ///
+/// Removing a DLQ item requires the `admin` role.
+///
 /// - `GET    /delivery/health`     - Observer delivery health summary
 /// - `GET    /dlq`                 - List DLQ items (paginated, filterable)
 /// - `GET    /dlq/stats`           - Aggregate DLQ statistics
@@ -113,7 +156,7 @@ pub fn observer_dlq_routes(state: DlqState) -> Router {
         .route("/dlq/stats", get(dlq_stats_handler))
         .route("/dlq/retry-all", post(dlq_retry_all_handler))
         // Static `/dlq/stats` above takes priority over this capture in axum.
-        .route("/dlq/{id}", get(dlq_get_handler).delete(dlq_delete_handler))
+        .route("/dlq/{id}", get(dlq_get_handler).delete(admin_only!(dlq_delete_handler)))
         .route("/dlq/{id}/retry", post(dlq_retry_handler))
         .with_state(state)
 }
@@ -122,6 +165,9 @@ pub fn observer_dlq_routes(state: DlqState) -> Router {
 ///
 /// # Routes
 ///
+/// Saving a checkpoint moves a listener's replay position and requires the `admin`
+/// role.
+///
 /// - `GET /changelog`                  - Poll changelog entries (paginated, filterable)
 /// - `GET /checkpoint/{listener_id}`   - Read a listener's checkpoint
 /// - `PUT /checkpoint/{listener_id}`   - Save / update a listener's checkpoint
@@ -130,7 +176,7 @@ pub fn observer_changelog_routes(state: ChangelogState) -> Router {
         .route("/changelog", get(changelog_list_handler))
         .route(
             "/checkpoint/{listener_id}",
-            get(checkpoint_get_handler).put(checkpoint_save_handler),
+            get(checkpoint_get_handler).put(admin_only!(checkpoint_save_handler)),
         )
         .with_state(state)
 }
@@ -10,7 +10,7 @@ use std::{
     collections::HashMap,
     sync::{
         Arc,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicI64, Ordering},
     },
     time::Duration,
 };
@@ -19,22 +19,30 @@ use arc_swap::ArcSwap;
 use fraiseql_core::runtime::subscription::ChangeSpineEnvelope;
 use fraiseql_observers::{
     ActionConfig as ObserverActionConfig, ActionExecutionDetail, ChangeLogListener,
-    ChangeLogListenerConfig, EntityEvent as ObserverEntityEvent, EventMatcher, FailurePolicy,
-    InMemoryTransport, ObserverDefinition, ObserverExecutor, RetryConfig as ObserverRetryConfig,
-    config::{EmailSmtpConfig, TransportConfig, TransportKind},
+    ChangeLogListenerConfig, DeliveryOrdering, EntityEvent as ObserverEntityEvent, EventMatcher,
+    FailurePolicy, InMemoryTransport, ObserverDefinition, ObserverExecutor, ReplayPolicy,
+    RetryConfig as ObserverRetryConfig, SigningSecretResolver,
+    config::{EmailSmtpConfig, EventTypesConfig, TransportConfig, TransportKind},
+    registry::{
+        EventQuarantine, EventSchemaRegistry, EventTypeDefinition, InMemoryEventQuarantine,
+        PostgresEventQuarantine, PostgresEventTypeStore, QuarantinedEvent,
+    },
     transport::{EventFilter, EventTransport},
 };
 use futures::StreamExt;
 use sqlx::PgPool;
 use tokio::{
-    sync::{RwLock, mpsc, oneshot},
+    sync::{Mutex, RwLock, mpsc, oneshot},
     task::JoinHandle,
 };
 use tracing::{debug, error, info, warn};
 
 use crate::{
     ServerError,
-    observers::{Observer, ObserverRepository},
+    observers::{
+        Observer, ObserverRepository,
+        pause::{PauseObserverRequest, PauseRepository, PauseStatus, PausedObserver, Resume},
+    },
     subscriptions::event_bridge::EntityEvent as BridgeEntityEvent,
 };
 
@@ -144,6 +152,12 @@ pub struct ObserverRuntimeConfig {
     /// `[observers.runtime].log_payloads`. Large payloads are truncated to a
     /// marker regardless.
     pub log_payloads: bool,
+
+    /// Declared event types (`[observers.runtime.event_types]`).
+    ///
+    /// Registered when the runtime starts, before those stored through the
+    /// admin API, which therefore take precedence.
+    pub event_types: EventTypesConfig,
 }
 
 impl ObserverRuntimeConfig {
@@ -161,6 +175,7 @@ impl ObserverRuntimeConfig {
             transport: TransportConfig::default(),
             email: None,
             log_payloads: false,
+            event_types: EventTypesConfig::default(),
         }
     }
 
@@ -213,6 +228,13 @@ impl ObserverRuntimeConfig {
         self.log_payloads = log_payloads;
         self
     }
+
+    /// Set the declared event types (already validated).
+    #[must_use]
+    pub fn with_event_types(mut self, event_types: EventTypesConfig) -> Self {
+        self.event_types = event_types;
+        self
+    }
 }
 
 /// Runtime health status
@@ -247,7 +269,7 @@ pub struct ObserverRuntime {
     events_processed:    Arc<std::sync::atomic::AtomicU64>,
     errors:              Arc<std::sync::atomic::AtomicU64>,
     observer_count:      Arc<std::sync::atomic::AtomicUsize>,
-    last_checkpoint:     Arc<std::sync::atomic::AtomicI64>,
+    last_checkpoint:     Arc<AtomicI64>,
     /// Hot-swappable components for reload
     matcher:             Arc<RwLock<Option<EventMatcher>>>,
     executor:            Arc<RwLock<Option<Arc<ObserverExecutor>>>>,
@@ -263,6 +285,20 @@ pub struct ObserverRuntime {
     entity_type_index:   Arc<ArcSwap<HashMap<(String, String), Vec<i64>>>>,
     /// In-memory DLQ shared across reloads and exposed to HTTP handlers.
     dlq:                 Arc<InMemoryDlq>,
+    /// Paused observers: out of the live matcher, with their change-log
+    /// position pinned in `_fraiseql_observer_pause`. Republished on reload.
+    paused:              Arc<ArcSwap<Vec<Arc<PausedObserver>>>>,
+    /// Pause rows in `_fraiseql_observer_pause`.
+    pauses:              PauseRepository,
+    /// Declared event types, shared by every executor and kept across reloads.
+    event_registry:      Arc<EventSchemaRegistry>,
+    /// Event types declared through the admin API, in `tb_observer_event_type`.
+    event_types:         PostgresEventTypeStore,
+    /// Events failing their type's schema, in `tb_observer_event_quarantine`.
+    quarantine:          Arc<PostgresEventQuarantine>,
+    /// Held by the PostgreSQL loop while it dispatches a batch, so pausing and
+    /// the resume handoff happen between batches.
+    dispatch_gate:       Arc<Mutex<()>>,
     /// Optional sender to forward CDC events to `EventBridge` for GraphQL subscriptions
     event_bridge_sender: Option<mpsc::Sender<BridgeEntityEvent>>,
     /// Optional after:capture dispatch hook (#366). When set (the functions
@@ -270,6 +306,14 @@ pub struct ObserverRuntime {
     /// dispatches `after:capture` functions for genuinely-captured writes
     /// (`cdc_source == "fallback_trigger"`). `None` compiles the capture path out.
     capture_dispatch:    Option<CaptureDispatchFn>,
+    /// Secrets-manager lookup for webhook `signing_secret_ref`s. `None` makes
+    /// such actions fail loud at dispatch.
+    signing_secrets:     Option<Arc<dyn SigningSecretResolver>>,
+    /// Resolver for `${secret:path}` references inside observer action
+    /// configs. Values are cached for its TTL, so a reload after the TTL picks
+    /// up rotated secrets. `None` makes observers with references fail to load.
+    #[cfg(feature = "secrets")]
+    secret_references:   Option<Arc<crate::secrets_manager::SecretReferenceResolver>>,
 }
 
 /// A hook the observer runtime calls for each change-log event (#366).
@@ -283,6 +327,10 @@ impl ObserverRuntime {
     #[must_use]
     pub fn new(config: ObserverRuntimeConfig) -> Self {
         let repository = ObserverRepository::new(config.pool.clone());
+        let pauses = PauseRepository::new(config.pool.clone());
+        let event_registry = Arc::new(EventSchemaRegistry::new(config.event_types.undeclared));
+        let event_types = PostgresEventTypeStore::new(config.pool.clone());
+        let quarantine = Arc::new(PostgresEventQuarantine::new(config.pool.clone()));
         let max_dlq_size = config.max_dlq_size;
 
         Self {
@@ -294,13 +342,22 @@ impl ObserverRuntime {
             events_processed: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             errors: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             observer_count: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
-            last_checkpoint: Arc::new(std::sync::atomic::AtomicI64::new(0)),
+            last_checkpoint: Arc::new(AtomicI64::new(0)),
             matcher: Arc::new(RwLock::new(None)),
             executor: Arc::new(RwLock::new(None)),
             entity_type_index: Arc::new(ArcSwap::from_pointee(HashMap::new())),
             dlq: Arc::new(InMemoryDlq::new_with_max(max_dlq_size)),
+            paused: Arc::new(ArcSwap::from_pointee(Vec::new())),
+            pauses,
+            event_registry,
+            event_types,
+            quarantine,
+            dispatch_gate: Arc::new(Mutex::new(())),
             event_bridge_sender: None,
             capture_dispatch: None,
+            signing_secrets: None,
+            #[cfg(feature = "secrets")]
+            secret_references: None,
         }
     }
 
@@ -315,15 +372,30 @@ impl ObserverRuntime {
         self.capture_dispatch = Some(hook);
     }
 
+    /// Set the resolver for webhook `signing_secret_ref`s.
+    ///
+    /// Takes effect for executors built afterwards: the next `start` or
+    /// `reload_observers`.
+    pub fn set_signing_secret_resolver(&mut self, resolver: Arc<dyn SigningSecretResolver>) {
+        self.signing_secrets = Some(resolver);
+    }
+
+    /// Set the resolver for `${secret:path}` references in action configs.
+    ///
+    /// Takes effect on the next `start` or `reload_observers`.
+    #[cfg(feature = "secrets")]
+    pub fn set_secret_reference_resolver(
+        &mut self,
+        resolver: Arc<crate::secrets_manager::SecretReferenceResolver>,
+    ) {
+        self.secret_references = Some(resolver);
+    }
+
     /// Load observers from the database and convert to `ObserverDefinitions`.
-    /// Returns (definitions, `entity_type_index`) tuple.
-    /// `entity_type_index` maps (`entity_type`, `event_type`) -> `observer_id` for logging.
-    async fn load_observers(
-        &self,
-    ) -> Result<
-        (HashMap<String, ObserverDefinition>, HashMap<(String, String), Vec<i64>>),
-        ServerError,
-    > {
+    ///
+    /// Paused observers are set aside in [`LoadedObservers::paused`] instead of
+    /// joining the live definitions and logging index.
+    async fn load_observers(&self) -> Result<LoadedObservers, ServerError> {
         // Load all enabled observers
         let query = crate::observers::ListObserversQuery {
             page:            1,
@@ -335,13 +407,40 @@ impl ObserverRuntime {
         };
 
         let (observers, _total) = self.repository.list(&query, None).await?;
+        let pause_rows = if self.supports_pause() {
+            self.pauses.list().await?
+        } else {
+            Vec::new()
+        };
+        let previously_paused = self.paused.load_full();
 
         let mut definitions = HashMap::new();
         let mut entity_type_index: HashMap<(String, String), Vec<i64>> = HashMap::new();
+        let mut paused = Vec::new();
 
-        for observer in observers {
+        for mut observer in observers {
+            if let Err(e) = self.resolve_action_secrets(&mut observer).await {
+                warn!("Failed to resolve secrets for observer {}: {}", observer.name, e);
+                continue;
+            }
             match Self::convert_observer(&observer) {
                 Ok(definition) => {
+                    if let Some(row) = pause_rows.iter().find(|r| r.observer_name == observer.name)
+                    {
+                        let previous = previously_paused.iter().find(|p| p.name() == observer.name);
+                        match PausedObserver::new(
+                            row.clone(),
+                            definition,
+                            previous.map(Arc::as_ref),
+                        ) {
+                            Ok(entry) => paused.push(Arc::new(entry)),
+                            Err(e) => {
+                                warn!("Failed to track paused observer {}: {}", observer.name, e)
+                            },
+                        }
+                        continue;
+                    }
+
                     // Index by (entity_type, event_type) for reverse lookup during logging
                     let entity_type =
                         observer.entity_type.clone().unwrap_or_else(|| "*".to_string());
@@ -360,8 +459,43 @@ impl ObserverRuntime {
             }
         }
 
-        info!("Loaded {} observers from database", definitions.len());
-        Ok((definitions, entity_type_index))
+        info!("Loaded {} observers from database ({} paused)", definitions.len(), paused.len());
+        Ok(LoadedObservers {
+            definitions,
+            entity_type_index,
+            paused,
+        })
+    }
+
+    /// Substitute `${secret:path}` references in the observer's actions.
+    #[cfg(feature = "secrets")]
+    async fn resolve_action_secrets(&self, observer: &mut Observer) -> Result<(), ServerError> {
+        let Some(resolver) = &self.secret_references else {
+            return Self::reject_secret_references(observer);
+        };
+        resolver
+            .resolve_json(&mut observer.actions)
+            .await
+            .map(|_| ())
+            .map_err(|e| ServerError::ConfigError(format!("secret reference: {e}")))
+    }
+
+    #[cfg(not(feature = "secrets"))]
+    #[allow(clippy::unused_async, clippy::unused_self)] // Reason: mirrors the `secrets` variant
+    async fn resolve_action_secrets(&self, observer: &mut Observer) -> Result<(), ServerError> {
+        Self::reject_secret_references(observer)
+    }
+
+    /// Fail loud when references exist but no secrets manager can resolve them.
+    fn reject_secret_references(observer: &Observer) -> Result<(), ServerError> {
+        if observer.actions.to_string().contains("${secret:") {
+            return Err(ServerError::ConfigError(
+                "actions contain ${secret:...} references but no secrets manager is configured \
+                 (set FRAISEQL_SECRETS_BACKEND)"
+                    .to_string(),
+            ));
+        }
+        Ok(())
     }
 
     /// Convert database Observer to `ObserverDefinition`.
@@ -398,6 +532,11 @@ impl ObserverRuntime {
             actions,
             retry: retry_config,
             on_failure: FailurePolicy::default(),
+            replay: ReplayPolicy::default(),
+            ordering: DeliveryOrdering::default(),
+            max_concurrency: None,
+            dedup: None,
+            dry_run: false,
         })
     }
 
@@ -431,9 +570,18 @@ impl ObserverRuntime {
 
         info!("Starting observer runtime (PostgreSQL LISTEN/NOTIFY transport)...");
 
+        // Paused observers are read while loading, so the table must exist first.
+        self.pauses.init().await?;
+        self.load_event_types().await?;
+
         // Load initial observers with entity_type index for logging
-        let (observers, entity_type_index) = self.load_observers().await?;
+        let LoadedObservers {
+            definitions: observers,
+            entity_type_index,
+            paused,
+        } = self.load_observers().await?;
         self.observer_count.store(observers.len(), Ordering::SeqCst);
+        self.paused.store(Arc::new(paused));
 
         // Build event matcher
         let matcher = EventMatcher::build(observers).map_err(|e| {
@@ -444,14 +592,7 @@ impl ObserverRuntime {
         let matcher_for_logging = matcher.clone();
 
         // Create executor with the shared in-memory DLQ
-        let executor = Arc::new(
-            ObserverExecutor::new_with_email(
-                matcher.clone(),
-                self.dlq.clone(),
-                self.config.email.as_ref(),
-            )
-            .map_err(|e| ServerError::ConfigError(format!("invalid observer email config: {e}")))?,
-        );
+        let executor = Arc::new(self.build_executor(matcher.clone())?);
 
         // Store in shared references for hot reload
         {
@@ -491,6 +632,8 @@ impl ObserverRuntime {
         let matcher_ref = Arc::clone(&self.matcher);
         let executor_ref = Arc::clone(&self.executor);
         let entity_type_index_ref = Arc::clone(&self.entity_type_index);
+        let paused_ref = Arc::clone(&self.paused);
+        let dispatch_gate = Arc::clone(&self.dispatch_gate);
 
         // Clone optional EventBridge sender for forwarding CDC events to subscriptions
         let bridge_sender = self.event_bridge_sender.clone();
@@ -546,6 +689,10 @@ impl ObserverRuntime {
                         break;
                     }
                     result = listener.next_batch() => {
+                        // Pause and the resume handoff wait for this guard, so they
+                        // only ever change the matcher between batches.
+                        let batch_guard = dispatch_gate.lock().await;
+
                         // Refresh matcher/executor from shared slot in case a hot-reload occurred.
                         {
                             let m = matcher_ref.read().await;
@@ -560,10 +707,13 @@ impl ObserverRuntime {
                             }
                         }
 
+                        let paused = paused_ref.load_full();
+
                         match result {
                             Ok(entries) => {
                                 if entries.is_empty() {
                                     // No events, wait before polling again
+                                    drop(batch_guard);
                                     tokio::time::sleep(poll_interval).await;
                                     continue;
                                 }
@@ -594,6 +744,12 @@ impl ObserverRuntime {
                                     )
                                     .await;
 
+                                    // Paused observers miss this event; count it
+                                    // towards their backlog.
+                                    for observer in paused.iter() {
+                                        observer.record(entry.id, &event);
+                                    }
+
                                     // #366: after:capture — drive functions on
                                     // externally-captured writes. The hook is a
                                     // no-op for executor-written (non-captured)
@@ -643,6 +799,7 @@ impl ObserverRuntime {
                                 errors.fetch_add(1, Ordering::Relaxed);
                                 error!("Failed to fetch entries from change log: {}", e);
                                 // Back off on error
+                                drop(batch_guard);
                                 tokio::time::sleep(Duration::from_secs(1)).await;
                             }
                         }
@@ -727,20 +884,20 @@ impl ObserverRuntime {
             },
         };
 
+        self.load_event_types().await?;
+
         // Load initial observers + build matcher/executor (same as the PG path).
-        let (observers, entity_type_index) = self.load_observers().await?;
+        // Pausing is PostgreSQL-only, so nothing is set aside here.
+        let LoadedObservers {
+            definitions: observers,
+            entity_type_index,
+            ..
+        } = self.load_observers().await?;
         self.observer_count.store(observers.len(), Ordering::SeqCst);
         let matcher = EventMatcher::build(observers).map_err(|e| {
             ServerError::ConfigError(format!("Failed to build event matcher: {}", e))
         })?;
-        let executor = Arc::new(
-            ObserverExecutor::new_with_email(
-                matcher.clone(),
-                self.dlq.clone(),
-                self.config.email.as_ref(),
-            )
-            .map_err(|e| ServerError::ConfigError(format!("invalid observer email config: {e}")))?,
-        );
+        let executor = Arc::new(self.build_executor(matcher.clone())?);
         {
             let mut m = self.matcher.write().await;
             *m = Some(matcher.clone());
@@ -856,8 +1013,21 @@ impl ObserverRuntime {
     ///
     /// Returns `ServerError` if the shutdown signal fails to send.
     pub async fn stop(&mut self) -> Result<(), ServerError> {
+        self.stop_within(Duration::from_secs(10)).await.map(|_| ())
+    }
+
+    /// Stop the runtime, waiting up to `timeout` for the in-flight change-log
+    /// batch to finish and its checkpoint to be persisted.
+    ///
+    /// Returns `false` if the batch was still running at the timeout; its events
+    /// are then redelivered after a restart.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError` if the shutdown signal fails to send.
+    pub async fn stop_within(&mut self, timeout: Duration) -> Result<bool, ServerError> {
         if !self.running.load(Ordering::SeqCst) {
-            return Ok(());
+            return Ok(true);
         }
 
         info!("Stopping observer runtime...");
@@ -869,12 +1039,17 @@ impl ObserverRuntime {
         }
 
         // Wait for task to complete
-        if let Some(handle) = self.task_handle.take() {
-            let _ = tokio::time::timeout(Duration::from_secs(10), handle).await;
-        }
+        let drained = match self.task_handle.take() {
+            Some(handle) => tokio::time::timeout(timeout, handle).await.is_ok(),
+            None => true,
+        };
 
-        info!("Observer runtime stopped");
-        Ok(())
+        info!(
+            drained,
+            last_checkpoint = self.last_checkpoint.load(Ordering::SeqCst),
+            "Observer runtime stopped"
+        );
+        Ok(drained)
     }
 
     /// Check if the runtime is running
@@ -930,7 +1105,11 @@ impl ObserverRuntime {
         debug!("Reloading observers from database");
 
         // Load observers from database
-        let (observers, new_entity_type_index) = self.load_observers().await?;
+        let LoadedObservers {
+            definitions: observers,
+            entity_type_index: new_entity_type_index,
+            paused,
+        } = self.load_observers().await?;
         let count = observers.len();
 
         // Build new matcher
@@ -938,14 +1117,7 @@ impl ObserverRuntime {
             .map_err(|e| ServerError::ConfigError(format!("Failed to build matcher: {}", e)))?;
 
         // Build new executor sharing the existing DLQ
-        let new_executor = Arc::new(
-            ObserverExecutor::new_with_email(
-                new_matcher.clone(),
-                self.dlq.clone(),
-                self.config.email.as_ref(),
-            )
-            .map_err(|e| ServerError::ConfigError(format!("invalid observer email config: {e}")))?,
-        );
+        let new_executor = Arc::new(self.build_executor(new_matcher.clone())?);
 
         // Atomic swap - write locks block readers briefly
         debug!("Swapping matcher, executor, and entity_type_index atomically");
@@ -965,6 +1137,7 @@ impl ObserverRuntime {
         // fully-populated generation (pre-reload or post-reload), never a
         // partial index.
         self.entity_type_index.store(Arc::new(new_entity_type_index));
+        self.paused.store(Arc::new(paused));
 
         // Update count
         self.observer_count.store(count, Ordering::SeqCst);
@@ -972,6 +1145,341 @@ impl ObserverRuntime {
         info!("Reloaded {} observers successfully", count);
         Ok(count)
     }
+
+    /// Register the configured event types, then those stored through the
+    /// admin API, creating the registry tables first.
+    async fn load_event_types(&self) -> Result<(), ServerError> {
+        // Creates the quarantine table too.
+        self.event_types.init().await.map_err(|e| event_registry_error(&e))?;
+        for definition in &self.config.event_types.types {
+            self.event_registry.register(definition.clone()).map_err(|e| {
+                ServerError::ConfigError(format!("invalid observer event types config: {e}"))
+            })?;
+        }
+        for definition in self.event_types.load().await.map_err(|e| event_registry_error(&e))? {
+            let entity_type = definition.entity_type.clone();
+            if let Err(e) = self.event_registry.register(definition) {
+                warn!(entity_type = %entity_type, "Skipping stored event type: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Every declared event type.
+    #[must_use]
+    pub fn event_types(&self) -> Vec<EventTypeDefinition> {
+        self.event_registry.list()
+    }
+
+    /// The declaration of `entity_type`.
+    #[must_use]
+    pub fn event_type(&self, entity_type: &str) -> Option<EventTypeDefinition> {
+        self.event_registry.get(entity_type)
+    }
+
+    /// Declare `definition`, replacing any declaration of its entity type.
+    ///
+    /// Takes effect for the next event processed and persists across restarts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Validation` if the schema does not compile and
+    /// `ServerError::Database` if the declaration cannot be stored; the
+    /// registry is unchanged either way.
+    pub async fn register_event_type(
+        &self,
+        definition: EventTypeDefinition,
+    ) -> Result<EventTypeDefinition, ServerError> {
+        let entity_type = definition.entity_type.clone();
+        let previous = self.event_registry.get(&entity_type);
+        self.event_registry
+            .register(definition.clone())
+            .map_err(|e| ServerError::Validation(e.to_string()))?;
+        if let Err(e) = self.event_types.save(&definition).await {
+            // Restore the previous declaration; it compiled before.
+            match previous {
+                Some(previous) => {
+                    if let Err(restore) = self.event_registry.register(previous) {
+                        error!(entity_type = %entity_type, "Failed to restore event type: {restore}");
+                    }
+                },
+                None => {
+                    self.event_registry.unregister(&entity_type);
+                },
+            }
+            return Err(event_registry_error(&e));
+        }
+        info!(entity_type = %entity_type, "Event type declared");
+        Ok(definition)
+    }
+
+    /// Remove the declaration of `entity_type`.
+    ///
+    /// A type declared in `[observers.runtime.event_types]` is declared again
+    /// on the next start.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::NotFound` if the type is not declared and
+    /// `ServerError::Database` if the stored declaration cannot be deleted.
+    pub async fn unregister_event_type(
+        &self,
+        entity_type: &str,
+    ) -> Result<EventTypeDefinition, ServerError> {
+        if self.event_registry.get(entity_type).is_none() {
+            return Err(ServerError::NotFound(format!(
+                "event type '{entity_type}' is not declared"
+            )));
+        }
+        self.event_types
+            .delete(entity_type)
+            .await
+            .map_err(|e| event_registry_error(&e))?;
+        let removed = self.event_registry.unregister(entity_type).ok_or_else(|| {
+            ServerError::NotFound(format!("event type '{entity_type}' is not declared"))
+        })?;
+        info!(entity_type = %entity_type, "Event type removed");
+        Ok(removed)
+    }
+
+    /// The most recently quarantined events, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Database` if the quarantine cannot be read.
+    pub async fn quarantined_events(
+        &self,
+        entity_type: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<QuarantinedEvent>, ServerError> {
+        self.quarantine
+            .list(entity_type, limit)
+            .await
+            .map_err(|e| event_registry_error(&e))
+    }
+
+    /// Whether observers can be paused on this transport.
+    ///
+    /// A pause pins a change-log position, so it needs the PostgreSQL
+    /// change-log loop; stream transports own their delivery state.
+    fn supports_pause(&self) -> bool {
+        listener_selection(self.config.transport.transport) == ListenerSelection::PostgresChangeLog
+    }
+
+    fn ensure_pausable(&self) -> Result<(), ServerError> {
+        if !self.supports_pause() {
+            return Err(ServerError::Conflict(
+                "pausing observers requires the PostgreSQL change-log transport".to_string(),
+            ));
+        }
+        if !self.is_running() {
+            return Err(ServerError::Conflict("observer runtime is not running".to_string()));
+        }
+        Ok(())
+    }
+
+    fn find_paused(&self, name: &str) -> Option<Arc<PausedObserver>> {
+        self.paused.load().iter().find(|p| p.name() == name).cloned()
+    }
+
+    /// Paused observers with their pins and backlogs.
+    #[must_use]
+    pub fn paused_observers(&self) -> Vec<PauseStatus> {
+        self.paused.load().iter().map(|p| p.status()).collect()
+    }
+
+    /// Pause observer `name`.
+    ///
+    /// The observer leaves live dispatch at the next batch boundary and the
+    /// live checkpoint becomes its pin, so the events it misses stay in the
+    /// change log for [`resume_observer`](Self::resume_observer) to deliver.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Validation` for an invalid `max_backlog`,
+    /// `ServerError::Conflict` if the runtime is not running on the PostgreSQL
+    /// transport or the observer is already paused, `ServerError::NotFound` if
+    /// no enabled observer has that name, and `ServerError::Database` if the
+    /// pause cannot be recorded or the observers cannot be reloaded.
+    pub async fn pause_observer(
+        &self,
+        name: &str,
+        request: &PauseObserverRequest,
+        paused_by: Option<&str>,
+    ) -> Result<PauseStatus, ServerError> {
+        let max_backlog = crate::observers::pause::resolve_max_backlog(request.max_backlog)?;
+        self.ensure_pausable()?;
+
+        let _between_batches = self.dispatch_gate.lock().await;
+        if self.find_paused(name).is_some() {
+            return Err(ServerError::Conflict(format!("observer '{name}' is already paused")));
+        }
+        let pin = self.last_checkpoint.load(Ordering::SeqCst);
+        let recorded = self
+            .pauses
+            .insert(name, pin, max_backlog, paused_by, request.reason.as_deref())
+            .await?;
+        if !recorded {
+            return Err(ServerError::NotFound(format!("no enabled observer named '{name}'")));
+        }
+        if let Err(e) = self.reload_observers().await {
+            // The observer is still live; do not leave a pin behind for it.
+            if let Err(cleanup) = self.pauses.remove(name).await {
+                error!(observer = %name, "Failed to remove pause after a failed reload: {cleanup}");
+            }
+            return Err(e);
+        }
+
+        info!(observer = %name, pinned_checkpoint = pin, "Observer paused");
+        self.find_paused(name).map(|p| p.status()).ok_or_else(|| {
+            ServerError::NotFound(format!("observer '{name}' could not be loaded for pausing"))
+        })
+    }
+
+    /// Resume paused observer `name`.
+    ///
+    /// Returns once the resume has started; its backlog is delivered in the
+    /// background, after which the observer rejoins live dispatch. Its status
+    /// reads `resuming` until then. A failed resume leaves it paused at the
+    /// furthest pin reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Conflict` if the runtime is not running on the
+    /// PostgreSQL transport or a resume is already under way,
+    /// `ServerError::NotFound` if the observer is not paused, and
+    /// `ServerError::ConfigError` if its executor cannot be built.
+    pub async fn resume_observer(
+        runtime: &Arc<RwLock<Self>>,
+        name: &str,
+    ) -> Result<PauseStatus, ServerError> {
+        let resume = runtime.read().await.begin_resume(name)?;
+        let status = resume.observer.status();
+        tokio::spawn(Self::finish_resume(Arc::clone(runtime), resume));
+        Ok(status)
+    }
+
+    fn begin_resume(&self, name: &str) -> Result<Resume, ServerError> {
+        self.ensure_pausable()?;
+        let observer = self
+            .find_paused(name)
+            .ok_or_else(|| ServerError::NotFound(format!("observer '{name}' is not paused")))?;
+        if !observer.begin_resume() {
+            return Err(ServerError::Conflict(format!("observer '{name}' is already resuming")));
+        }
+
+        let executor = self
+            .single_observer_executor(name, observer.definition())
+            .inspect_err(|_| observer.abort_resume())?;
+
+        Ok(Resume {
+            observer,
+            executor: Arc::new(executor),
+            pool: self.config.pool.clone(),
+            pauses: self.pauses.clone(),
+            batch_size: self.config.batch_size,
+        })
+    }
+
+    /// An executor running only `definition`, sharing the runtime's DLQ.
+    fn single_observer_executor(
+        &self,
+        name: &str,
+        definition: &ObserverDefinition,
+    ) -> Result<ObserverExecutor, ServerError> {
+        let matcher = EventMatcher::build(HashMap::from([(name.to_string(), definition.clone())]))
+            .map_err(|e| ServerError::ConfigError(format!("Failed to build matcher: {e}")))?;
+        // The live loop already quarantined the backlog's invalid events; keep
+        // the replayed copies out of the quarantine table.
+        Ok(self
+            .build_executor(matcher)?
+            .with_event_quarantine(Arc::new(InMemoryEventQuarantine::new())))
+    }
+
+    /// An executor over `matcher`, sharing the runtime's DLQ and event registry.
+    fn build_executor(&self, matcher: EventMatcher) -> Result<ObserverExecutor, ServerError> {
+        let executor = ObserverExecutor::new_with_email_and_secrets(
+            matcher,
+            self.dlq.clone(),
+            self.config.email.as_ref(),
+            self.signing_secrets.clone(),
+        )
+        .map_err(|e| ServerError::ConfigError(format!("invalid observer email config: {e}")))?;
+        Ok(executor
+            .with_event_registry(Arc::clone(&self.event_registry))
+            .with_event_quarantine(self.quarantine.clone()))
+    }
+
+    /// Deliver a resuming observer's backlog, then hand it to the live loop.
+    async fn finish_resume(runtime: Arc<RwLock<Self>>, resume: Resume) {
+        let name = resume.observer.name().to_string();
+
+        let handed_off = async {
+            // The bulk of the backlog, alongside live processing.
+            let live_checkpoint = runtime.read().await.last_checkpoint.load(Ordering::SeqCst);
+            resume.catch_up(live_checkpoint).await?;
+
+            // The rest between batches: everything up to the live checkpoint
+            // comes from here, everything after it from the live loop.
+            let rt = runtime.read().await;
+            let _between_batches = rt.dispatch_gate.lock().await;
+            resume.catch_up(rt.last_checkpoint.load(Ordering::SeqCst)).await?;
+            rt.rejoin_live(&resume).await
+        }
+        .await;
+
+        match handed_off {
+            Ok(()) => info!(
+                observer = %name,
+                pinned_checkpoint = resume.observer.pinned_checkpoint(),
+                "Observer resumed"
+            ),
+            Err(e) => {
+                resume.observer.abort_resume();
+                error!(observer = %name, "Observer resume failed; it stays paused: {e}");
+            },
+        }
+    }
+
+    /// Drop the pause row and reload, restoring the row if the reload fails so
+    /// the observer stays paused rather than silently missing events.
+    async fn rejoin_live(&self, resume: &Resume) -> Result<(), ServerError> {
+        let name = resume.observer.name();
+        self.pauses.remove(name).await?;
+        if let Err(e) = self.reload_observers().await {
+            let status = resume.observer.status();
+            let restored = self
+                .pauses
+                .insert(
+                    name,
+                    status.pinned_checkpoint,
+                    status.max_backlog,
+                    status.paused_by.as_deref(),
+                    status.reason.as_deref(),
+                )
+                .await;
+            if let Err(restore) = restored {
+                error!(observer = %name, "Failed to restore pause after a failed reload: {restore}");
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+/// Map an event type store or quarantine failure.
+fn event_registry_error(error: &fraiseql_observers::ObserverError) -> ServerError {
+    ServerError::Database(format!("observer event types: {error}"))
+}
+
+/// Observers loaded from `tb_observer`, split into live and paused.
+struct LoadedObservers {
+    /// Live definitions keyed by observer name.
+    definitions:       HashMap<String, ObserverDefinition>,
+    /// (`entity_type`, `event_type`) -> live observer ids, for logging.
+    entity_type_index: HashMap<(String, String), Vec<i64>>,
+    /// Paused observers, kept out of live dispatch.
+    paused:            Vec<Arc<PausedObserver>>,
 }
 
 /// Process a single ready [`EntityEvent`](ObserverEntityEvent): match observers,
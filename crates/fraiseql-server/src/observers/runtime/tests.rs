@@ -30,6 +30,8 @@ fn test_action() -> ActionConfig {
         body_template:      None,
         signing_secret:     None,
         signing_secret_env: None,
+        signing_secret_ref: None,
+        signing_key_id:     None,
     }
 }
 
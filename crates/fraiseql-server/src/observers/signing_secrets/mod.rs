@@ -0,0 +1,51 @@
+//! Webhook signing secrets resolved from the server's `SecretsManager`.
+//!
+//! A webhook observer action may name its HMAC secret with
+//! `signing_secret_ref` instead of carrying a literal or an env-var name. The
+//! observer executor resolves that name through [`SecretsManagerSigningSecrets`]
+//! at dispatch time, so the secret lives in Vault (or the file/env backend)
+//! and a rotation takes effect on the next delivery.
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+
+use fraiseql_observers::{ObserverError, SigningSecretResolver};
+
+use crate::secrets_manager::{SecretsError, SecretsManager};
+
+/// [`SigningSecretResolver`] backed by a [`SecretsManager`].
+pub struct SecretsManagerSigningSecrets {
+    manager: Arc<SecretsManager>,
+}
+
+impl SecretsManagerSigningSecrets {
+    /// Resolve signing secrets through `manager`.
+    #[must_use]
+    pub const fn new(manager: Arc<SecretsManager>) -> Self {
+        Self { manager }
+    }
+}
+
+#[async_trait::async_trait]
+impl SigningSecretResolver for SecretsManagerSigningSecrets {
+    async fn resolve(&self, name: &str) -> fraiseql_observers::Result<String> {
+        self.manager.get_secret(name).await.map_err(|e| map_secrets_error(name, &e))
+    }
+}
+
+/// A missing or invalid secret name is a configuration error (dead-lettered);
+/// anything else is a backend failure and retried like a failed delivery.
+fn map_secrets_error(name: &str, error: &SecretsError) -> ObserverError {
+    match error {
+        SecretsError::NotFound(_) | SecretsError::ValidationError(_) => {
+            ObserverError::InvalidActionConfig {
+                reason: format!("Webhook signing secret {name:?}: {error}"),
+            }
+        },
+        _ => ObserverError::ActionExecutionFailed {
+            reason: format!("Failed to fetch webhook signing secret {name:?}: {error}"),
+        },
+    }
+}
@@ -0,0 +1,35 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
+
+use std::sync::Arc;
+
+use fraiseql_observers::{ObserverError, SigningSecretResolver};
+
+use super::{SecretsManagerSigningSecrets, map_secrets_error};
+use crate::secrets_manager::{EnvBackend, SecretsError, SecretsManager};
+
+fn resolver() -> SecretsManagerSigningSecrets {
+    SecretsManagerSigningSecrets::new(Arc::new(SecretsManager::new(Arc::new(EnvBackend::new()))))
+}
+
+#[tokio::test]
+async fn resolves_secret_from_the_backend() {
+    temp_env::async_with_vars([("FRAISEQL_TEST_OBSERVER_SIGNING", Some("whsec_env"))], async {
+        assert_eq!(
+            resolver().resolve("FRAISEQL_TEST_OBSERVER_SIGNING").await.unwrap(),
+            "whsec_env"
+        );
+    })
+    .await;
+}
+
+#[test]
+fn missing_secret_is_a_config_error() {
+    let err = map_secrets_error("orders", &SecretsError::NotFound("orders".to_string()));
+    assert!(matches!(err, ObserverError::InvalidActionConfig { .. }), "{err:?}");
+}
+
+#[test]
+fn unreachable_backend_is_transient() {
+    let err = map_secrets_error("orders", &SecretsError::ConnectionError("timeout".to_string()));
+    assert!(matches!(err, ObserverError::ActionExecutionFailed { .. }), "{err:?}");
+}
@@ -41,6 +41,22 @@ mod redaction_tests {
         assert_eq!(actions[0]["url"], "https://hook.example/path");
     }
 
+    #[test]
+    fn webhook_signing_secret_ref_and_key_id_are_preserved() {
+        // Both are names, not secrets: the value lives in the secrets manager.
+        let mut actions = serde_json::json!([
+            {
+                "type": "webhook",
+                "url": "https://hook.example/path",
+                "signing_secret_ref": "webhooks/orders",
+                "signing_key_id": "2026-10"
+            }
+        ]);
+        let before = actions.clone();
+        redact_action_secrets(&mut actions);
+        assert_eq!(actions, before);
+    }
+
     #[test]
     fn actions_without_headers_are_untouched() {
         let mut actions = serde_json::json!([
@@ -438,6 +454,26 @@ mod router_construction {
         let _ = observer_runtime_routes(state);
     }
 
+    #[tokio::test]
+    async fn event_type_declarations_are_validated_before_touching_the_store() {
+        let runtime = stub_runtime();
+        let runtime = runtime.read().await;
+        let request: crate::observers::PutEventTypeRequest = serde_json::from_value(
+            serde_json::json!({ "schema": { "type": "object", "$ref": "#/order" } }),
+        )
+        .unwrap();
+
+        let err = runtime
+            .register_event_type(request.into_definition("Order".to_string()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::ServerError::Validation(_)), "{err}");
+        assert!(runtime.event_types().is_empty());
+
+        let err = runtime.unregister_event_type("Order").await.unwrap_err();
+        assert!(matches!(err, crate::ServerError::NotFound(_)), "{err}");
+    }
+
     #[tokio::test]
     async fn observer_dlq_routes_constructs() {
         let state = DlqState {
@@ -0,0 +1,314 @@
+//! Dependency readiness probes and the startup traffic gate.
+//!
+//! `GET /readiness` probes every dependency the server needs to answer a
+//! request — the database pool, the compiled schema, the secrets/KMS backend,
+//! the observer listener connection and Redis — and reports each one:
+//!
+//! ```json
+//! {
+//!   "status": "not_ready",
+//!   "reason": "Critical dependencies not ready: secrets",
+//!   "dependencies": [
+//!     { "name": "database", "critical": true, "ready": true, "latency_ms": 2 },
+//!     { "name": "secrets", "critical": true, "ready": false, "latency_ms": 2000,
+//!       "error": "probe timed out after 2000 ms" }
+//!   ]
+//! }
+//! ```
+//!
+//! A failing *critical* dependency makes the replica not ready (503). A
+//! non-critical one is reported but does not: the Redis rate limiter, for
+//! instance, fails open, so the server keeps serving without it.
+//!
+//! With `[readiness] gate_traffic = true` a [`ReadinessGate`] additionally holds
+//! every other route at 503 until the critical dependencies have passed once.
+
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::{Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use fraiseql_core::db::traits::DatabaseAdapter;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::routes::graphql::AppState;
+
+#[cfg(test)]
+mod tests;
+
+/// Time allowed for each dependency probe when `[readiness]` is not configured.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// `Retry-After` sent while the gate is closed, in seconds.
+const GATE_RETRY_AFTER_SECS: u32 = 1;
+
+/// A dependency checked by the readiness probe.
+///
+/// The built-in dependencies are probed directly from [`AppState`]; implement
+/// this trait to add another one with [`AppState::with_readiness_probe`].
+#[async_trait]
+pub trait DependencyProbe: Send + Sync {
+    /// Name reported in the `dependencies` list.
+    fn name(&self) -> &str;
+
+    /// Whether the replica is not ready while this dependency fails.
+    fn critical(&self) -> bool {
+        true
+    }
+
+    /// Check the dependency.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason when the dependency is unavailable.
+    async fn check(&self) -> Result<(), String>;
+}
+
+/// Outcome of one dependency probe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyStatus {
+    /// Dependency name (`"database"`, `"schema"`, `"secrets"`, …).
+    pub name:       String,
+    /// Whether a failure of this dependency makes the replica not ready.
+    pub critical:   bool,
+    /// Whether the probe succeeded within the timeout.
+    pub ready:      bool,
+    /// Probe duration in milliseconds.
+    pub latency_ms: u64,
+    /// Failure reason when `ready = false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error:      Option<String>,
+}
+
+/// Run one probe under `timeout` and record its outcome.
+pub async fn run_probe<F>(
+    name: impl Into<String>,
+    critical: bool,
+    timeout: Duration,
+    check: F,
+) -> DependencyStatus
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(timeout, check)
+        .await
+        .unwrap_or_else(|_| Err(format!("probe timed out after {} ms", timeout.as_millis())));
+    #[allow(clippy::cast_possible_truncation)]
+    // Reason: probe latency is bounded by the timeout, far below u64::MAX ms
+    let latency_ms = started.elapsed().as_millis() as u64;
+    DependencyStatus {
+        name: name.into(),
+        critical,
+        ready: outcome.is_ok(),
+        latency_ms,
+        error: outcome.err(),
+    }
+}
+
+/// Names of the critical dependencies that are not ready.
+#[must_use]
+pub fn failing_critical(dependencies: &[DependencyStatus]) -> Vec<&str> {
+    dependencies
+        .iter()
+        .filter(|dep| dep.critical && !dep.ready)
+        .map(|dep| dep.name.as_str())
+        .collect()
+}
+
+/// Probe every dependency of `state` concurrently.
+///
+/// Observers and secrets are only probed when attached; each is critical when
+/// present, since a server configured with them cannot complete the requests
+/// that depend on them.
+pub async fn check_dependencies<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
+    state: &AppState<A>,
+) -> Vec<DependencyStatus> {
+    let timeout = state.readiness_probe_timeout;
+    // The executor guard must not be held across the probes' awaits.
+    let (adapter, schema_check) = {
+        let executor = state.executor();
+        (executor.adapter().clone(), executor.schema().validate_format_version())
+    };
+
+    let mut probes: Vec<std::pin::Pin<Box<dyn Future<Output = DependencyStatus> + Send + '_>>> = vec![
+        Box::pin(run_probe("database", true, timeout, async move {
+            adapter.health_check().await.map_err(|e| e.to_string())
+        })),
+        Box::pin(run_probe("schema", true, timeout, async move { schema_check })),
+    ];
+
+    #[cfg(feature = "secrets")]
+    if let Some(secrets) = state.secrets_manager.clone() {
+        probes.push(Box::pin(run_probe("secrets", true, timeout, async move {
+            secrets
+                .health_check()
+                .await
+                .map_err(|e| format!("{} backend unavailable: {e}", secrets.backend_name()))
+        })));
+    }
+
+    #[cfg(feature = "observers")]
+    if let Some(runtime) = state.observer_runtime.clone() {
+        probes.push(Box::pin(run_probe("observers", true, timeout, async move {
+            let (running, pool) = {
+                let rt = runtime.read().await;
+                (rt.health().running, rt.pool.clone())
+            };
+            if !running {
+                return Err("observer runtime is not running".to_string());
+            }
+            sqlx::query("SELECT 1")
+                .execute(&pool)
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("observer listener connection unavailable: {e}"))
+        })));
+    }
+
+    for probe in &state.readiness_probes {
+        probes.push(Box::pin(run_probe(
+            probe.name().to_string(),
+            probe.critical(),
+            timeout,
+            probe.check(),
+        )));
+    }
+
+    futures::future::join_all(probes).await
+}
+
+/// Probe for the Redis backend of the rate limiter.
+///
+/// Non-critical: the limiter fails open when Redis is unreachable, so the
+/// server keeps serving (unthrottled) and the failure is only reported.
+#[cfg(feature = "redis-rate-limiting")]
+pub struct RateLimiterProbe(pub Arc<crate::middleware::RateLimiter>);
+
+#[cfg(feature = "redis-rate-limiting")]
+#[async_trait]
+impl DependencyProbe for RateLimiterProbe {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    fn critical(&self) -> bool {
+        false
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.0
+            .ping()
+            .await
+            .unwrap_or(Ok(()))
+            .map_err(|e| format!("rate limiter Redis: {e}"))
+    }
+}
+
+/// Holds traffic at 503 until the critical dependencies are ready.
+///
+/// The gate only ever opens: once the replica has served, a later dependency
+/// failure is reported by `/readiness` (so the orchestrator drains it) rather
+/// than by rejecting requests already routed here.
+#[derive(Debug)]
+pub struct ReadinessGate {
+    open:         AtomicBool,
+    exempt_paths: Vec<String>,
+}
+
+impl ReadinessGate {
+    /// Create a closed gate; requests to `exempt_paths` (health, readiness,
+    /// metrics) are always let through.
+    #[must_use]
+    pub const fn new(exempt_paths: Vec<String>) -> Self {
+        Self {
+            open: AtomicBool::new(false),
+            exempt_paths,
+        }
+    }
+
+    /// Whether traffic is flowing.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.open.load(Ordering::Acquire)
+    }
+
+    /// Open the gate; returns `true` if this call opened it.
+    pub fn open(&self) -> bool {
+        let opened = !self.open.swap(true, Ordering::AcqRel);
+        if opened {
+            info!("All critical dependencies ready; readiness gate opened");
+        }
+        opened
+    }
+
+    /// Whether a request to `path` is let through.
+    #[must_use]
+    pub fn admits(&self, path: &str) -> bool {
+        self.is_open() || self.exempt_paths.iter().any(|exempt| exempt == path)
+    }
+}
+
+/// Reject requests with 503 while the readiness gate is closed.
+pub async fn readiness_gate_middleware(
+    State(gate): State<Arc<ReadinessGate>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if gate.admits(request.uri().path()) {
+        return next.run(request).await;
+    }
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        [(header::RETRY_AFTER, GATE_RETRY_AFTER_SECS.to_string())],
+        Json(serde_json::json!({
+            "errors": [{
+                "message": "Server is starting: waiting for critical dependencies",
+                "extensions": { "code": "SERVICE_UNAVAILABLE" }
+            }]
+        })),
+    )
+        .into_response()
+}
+
+/// Re-probe every `interval` until all critical dependencies are ready, then
+/// open `gate`.
+///
+/// Spawned at startup when `[readiness] gate_traffic = true`; returns once the
+/// gate is open (or was opened by a `/readiness` request in the meantime).
+pub async fn open_when_ready<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
+    state: AppState<A>,
+    gate: Arc<ReadinessGate>,
+    interval: Duration,
+) {
+    loop {
+        if gate.is_open() {
+            return;
+        }
+        let dependencies = check_dependencies(&state).await;
+        let failing = failing_critical(&dependencies);
+        if failing.is_empty() {
+            gate.open();
+            return;
+        }
+        warn!(
+            dependencies = %failing.join(", "),
+            "Readiness gate closed: critical dependencies not ready"
+        );
+        tokio::time::sleep(interval).await;
+    }
+}
@@ -0,0 +1,134 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    middleware,
+    routing::get,
+};
+use tower::ServiceExt;
+
+use super::{ReadinessGate, failing_critical, readiness_gate_middleware, run_probe};
+use crate::config::ReadinessConfig;
+
+#[tokio::test]
+async fn run_probe_records_success() {
+    let status = run_probe("database", true, Duration::from_secs(1), async { Ok(()) }).await;
+
+    assert_eq!(status.name, "database");
+    assert!(status.critical);
+    assert!(status.ready);
+    assert!(status.error.is_none());
+}
+
+#[tokio::test]
+async fn run_probe_records_failure_reason() {
+    let status =
+        run_probe("secrets", true, Duration::from_secs(1), async { Err("sealed".to_string()) })
+            .await;
+
+    assert!(!status.ready);
+    assert_eq!(status.error.as_deref(), Some("sealed"));
+}
+
+#[tokio::test]
+async fn run_probe_times_out() {
+    let status = run_probe("schema", true, Duration::from_millis(50), async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Ok(())
+    })
+    .await;
+
+    assert!(!status.ready);
+    assert_eq!(status.error.as_deref(), Some("probe timed out after 50 ms"));
+}
+
+#[tokio::test]
+async fn failing_critical_ignores_non_critical_dependencies() {
+    let timeout = Duration::from_secs(1);
+    let dependencies = vec![
+        run_probe("database", true, timeout, async { Ok(()) }).await,
+        run_probe("secrets", true, timeout, async { Err("down".to_string()) }).await,
+        run_probe("redis", false, timeout, async { Err("down".to_string()) }).await,
+    ];
+
+    assert_eq!(failing_critical(&dependencies), vec!["secrets"]);
+}
+
+#[test]
+fn dependency_status_omits_error_when_ready() {
+    let status = super::DependencyStatus {
+        name:       "database".to_string(),
+        critical:   true,
+        ready:      true,
+        latency_ms: 3,
+        error:      None,
+    };
+    let json = serde_json::to_value(&status).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({ "name": "database", "critical": true, "ready": true, "latency_ms": 3 })
+    );
+}
+
+#[test]
+fn gate_admits_exempt_paths_until_opened() {
+    let gate = ReadinessGate::new(vec!["/health".to_string(), "/readiness".to_string()]);
+
+    assert!(!gate.is_open());
+    assert!(gate.admits("/health"));
+    assert!(gate.admits("/readiness"));
+    assert!(!gate.admits("/graphql"));
+
+    assert!(gate.open());
+    assert!(!gate.open(), "second open is a no-op");
+    assert!(gate.admits("/graphql"));
+}
+
+fn gated_app(gate: Arc<ReadinessGate>) -> Router {
+    Router::new()
+        .route("/graphql", get(|| async { "ok" }))
+        .route("/health", get(|| async { "ok" }))
+        .layer(middleware::from_fn_with_state(gate, readiness_gate_middleware))
+}
+
+fn get_request(path: &str) -> Request<Body> {
+    Request::builder().uri(path).body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn gate_middleware_rejects_while_closed() {
+    let gate = Arc::new(ReadinessGate::new(vec!["/health".to_string()]));
+    let app = gated_app(gate.clone());
+
+    let response = app.clone().oneshot(get_request("/graphql")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+
+    let response = app.clone().oneshot(get_request("/health")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    gate.open();
+    let response = app.oneshot(get_request("/graphql")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[test]
+fn readiness_config_defaults() {
+    let config: ReadinessConfig = toml::from_str("").unwrap();
+
+    assert!(!config.gate_traffic);
+    assert_eq!(config.probe_timeout(), Duration::from_secs(2));
+    assert_eq!(config.gate_poll_interval(), Duration::from_secs(1));
+}
+
+#[test]
+fn readiness_config_rejects_unknown_fields() {
+    let result: Result<ReadinessConfig, _> = toml::from_str("gate_trafic = true");
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,220 @@
+//! Per-operation concurrency limiting (bulkhead pattern).
+//!
+//! [`Bulkhead`] splits GraphQL executions into compartments — one per configured
+//! operation name and one per cost class — each with its own semaphore. A request
+//! holds a slot in every compartment it belongs to; when one is full it waits in
+//! that compartment's bounded queue, and beyond it, or after the queue timeout, it
+//! is rejected so the handler can answer `429` instead of piling more work onto
+//! the database.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::config::BulkheadConfig;
+
+/// One concurrency-limited compartment of a [`Bulkhead`].
+#[derive(Debug)]
+pub struct Compartment {
+    name:           String,
+    max_concurrent: usize,
+    permits:        Arc<Semaphore>,
+    waiting:        AtomicUsize,
+    rejected_full:  AtomicU64,
+    rejected_wait:  AtomicU64,
+}
+
+impl Compartment {
+    fn new(name: String, max_concurrent: usize) -> Self {
+        Self {
+            name,
+            max_concurrent,
+            permits: Arc::new(Semaphore::new(max_concurrent)),
+            waiting: AtomicUsize::new(0),
+            rejected_full: AtomicU64::new(0),
+            rejected_wait: AtomicU64::new(0),
+        }
+    }
+
+    /// Compartment name: `operation:<name>` or `cost:<class>`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Snapshot of one compartment's counters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompartmentStats {
+    /// Compartment name
+    pub name:           String,
+    /// Configured concurrency limit
+    pub max_concurrent: usize,
+    /// Executions currently holding a slot
+    pub in_flight:      usize,
+    /// Requests currently waiting for a slot
+    pub waiting:        usize,
+    /// Requests rejected because the wait queue was full
+    pub rejected_full:  u64,
+    /// Requests rejected because no slot freed up within the queue timeout
+    pub rejected_wait:  u64,
+}
+
+/// Why a request was turned away from its compartment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkheadRejection {
+    /// The compartment's wait queue was full.
+    QueueFull,
+    /// No slot freed up within the queue timeout.
+    Timeout,
+}
+
+impl fmt::Display for BulkheadRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::QueueFull => f.write_str("queue full"),
+            Self::Timeout => f.write_str("queue timeout"),
+        }
+    }
+}
+
+/// RAII guard holding a compartment slot; the slot is released on drop.
+#[derive(Debug)]
+pub struct BulkheadPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Concurrency limits per operation name and per cost class.
+#[derive(Debug)]
+pub struct Bulkhead {
+    operations:    HashMap<String, Compartment>,
+    /// Cost classes sorted by descending `min_cost`.
+    cost_classes:  Vec<(usize, Compartment)>,
+    max_queue:     usize,
+    queue_timeout: Duration,
+}
+
+impl Bulkhead {
+    /// Build the compartments configured by `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `config` is invalid.
+    pub fn new(config: &BulkheadConfig) -> Result<Self, String> {
+        config.validate()?;
+        let operations = config
+            .operations
+            .iter()
+            .map(|(name, &max)| (name.clone(), Compartment::new(format!("operation:{name}"), max)))
+            .collect();
+        let mut cost_classes: Vec<_> = config
+            .cost_classes
+            .iter()
+            .map(|class| {
+                let compartment =
+                    Compartment::new(format!("cost:{}", class.name), class.max_concurrent);
+                (class.min_cost, compartment)
+            })
+            .collect();
+        cost_classes.sort_by_key(|(min_cost, _)| std::cmp::Reverse(*min_cost));
+        Ok(Self {
+            operations,
+            cost_classes,
+            max_queue: config.max_queue,
+            queue_timeout: Duration::from_millis(config.queue_timeout_ms),
+        })
+    }
+
+    /// The compartments a request must hold a slot in: its operation name's if
+    /// configured, then the highest cost class its cost reaches.
+    ///
+    /// The operation name is chosen by the client, so it never exempts a query
+    /// from its cost class. `cost` is only called when cost classes are
+    /// configured.
+    #[must_use]
+    pub fn compartments(
+        &self,
+        operation_name: Option<&str>,
+        cost: impl FnOnce() -> Option<usize>,
+    ) -> Vec<&Compartment> {
+        let mut compartments: Vec<_> =
+            operation_name.and_then(|name| self.operations.get(name)).into_iter().collect();
+        if self.cost_classes.is_empty() {
+            return compartments;
+        }
+        if let Some(cost) = cost() {
+            compartments.extend(
+                self.cost_classes
+                    .iter()
+                    .find(|(min_cost, _)| cost >= *min_cost)
+                    .map(|(_, compartment)| compartment),
+            );
+        }
+        compartments
+    }
+
+    /// Take a slot in `compartment`, waiting in its bounded queue when it is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BulkheadRejection::QueueFull`] when `max_queue` requests are
+    /// already waiting, and [`BulkheadRejection::Timeout`] when no slot frees up
+    /// within the queue timeout.
+    pub async fn acquire(
+        &self,
+        compartment: &Compartment,
+    ) -> Result<BulkheadPermit, BulkheadRejection> {
+        if let Ok(permit) = Arc::clone(&compartment.permits).try_acquire_owned() {
+            return Ok(BulkheadPermit { _permit: permit });
+        }
+
+        if compartment.waiting.fetch_add(1, Ordering::AcqRel) >= self.max_queue {
+            compartment.waiting.fetch_sub(1, Ordering::AcqRel);
+            compartment.rejected_full.fetch_add(1, Ordering::Relaxed);
+            return Err(BulkheadRejection::QueueFull);
+        }
+        let result = tokio::time::timeout(
+            self.queue_timeout,
+            Arc::clone(&compartment.permits).acquire_owned(),
+        )
+        .await;
+        compartment.waiting.fetch_sub(1, Ordering::AcqRel);
+
+        match result {
+            Ok(Ok(permit)) => Ok(BulkheadPermit { _permit: permit }),
+            // The semaphore is never closed, so an error here is the timeout.
+            _ => {
+                compartment.rejected_wait.fetch_add(1, Ordering::Relaxed);
+                Err(BulkheadRejection::Timeout)
+            },
+        }
+    }
+
+    /// Counters of every compartment, sorted by name.
+    #[must_use]
+    pub fn stats(&self) -> Vec<CompartmentStats> {
+        let mut stats: Vec<_> = self
+            .operations
+            .values()
+            .chain(self.cost_classes.iter().map(|(_, compartment)| compartment))
+            .map(|c| CompartmentStats {
+                name:           c.name.clone(),
+                max_concurrent: c.max_concurrent,
+                in_flight:      c.max_concurrent.saturating_sub(c.permits.available_permits()),
+                waiting:        c.waiting.load(Ordering::Relaxed),
+                rejected_full:  c.rejected_full.load(Ordering::Relaxed),
+                rejected_wait:  c.rejected_wait.load(Ordering::Relaxed),
+            })
+            .collect();
+        stats.sort_by(|a, b| a.name.cmp(&b.name));
+        stats
+    }
+}
@@ -1,7 +1,8 @@
 //! Resilience primitives for the FraiseQL server.
 //!
 //! Contains backpressure control that limits concurrent requests to prevent
-//! resource exhaustion under high load.
+//! resource exhaustion under high load, and the per-operation bulkhead
+//! (`[bulkhead]`) that keeps one expensive operation from starving the rest.
 //!
 //! # Wiring `AdmissionController`
 //!
@@ -15,6 +16,7 @@
 //! map via `axum::Router::layer` with a custom Tower middleware.
 
 pub mod backpressure;
+pub mod bulkhead;
 
 #[cfg(test)]
 mod tests;
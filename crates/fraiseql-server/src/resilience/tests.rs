@@ -1,3 +1,5 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
 mod backpressure_tests {
     use std::time::Duration;
 
@@ -89,3 +91,176 @@ mod backpressure_tests {
         assert!(result.is_some(), "must succeed when permit freed before timeout");
     }
 }
+
+mod bulkhead_tests {
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+
+    use super::super::bulkhead::{Bulkhead, BulkheadRejection};
+    use crate::config::{BulkheadConfig, CostClassConfig};
+
+    fn config(max_queue: usize, queue_timeout_ms: u64) -> BulkheadConfig {
+        BulkheadConfig {
+            operations: HashMap::from([("Report".to_string(), 1)]),
+            cost_classes: vec![
+                CostClassConfig {
+                    name:           "medium".to_string(),
+                    min_cost:       100,
+                    max_concurrent: 8,
+                },
+                CostClassConfig {
+                    name:           "heavy".to_string(),
+                    min_cost:       1_000,
+                    max_concurrent: 2,
+                },
+            ],
+            max_queue,
+            queue_timeout_ms,
+        }
+    }
+
+    #[test]
+    fn named_operation_also_takes_its_cost_class() {
+        let bulkhead = Bulkhead::new(&config(4, 100)).unwrap();
+        let names = |operation, cost| {
+            bulkhead
+                .compartments(operation, || Some(cost))
+                .into_iter()
+                .map(|c| c.name())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(Some("Report"), 5_000), ["operation:Report", "cost:heavy"]);
+        assert_eq!(names(Some("Report"), 10), ["operation:Report"]);
+    }
+
+    #[test]
+    fn cost_selects_highest_class_reached() {
+        let bulkhead = Bulkhead::new(&config(4, 100)).unwrap();
+        let name = |cost| {
+            let compartments = bulkhead.compartments(Some("Other"), || Some(cost));
+            assert!(compartments.len() <= 1);
+            compartments.first().map(|c| c.name())
+        };
+        assert_eq!(name(5_000), Some("cost:heavy"));
+        assert_eq!(name(1_000), Some("cost:heavy"));
+        assert_eq!(name(999), Some("cost:medium"));
+        assert_eq!(name(99), None);
+        assert!(bulkhead.compartments(None, || None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn high_cost_query_under_configured_name_is_limited_by_cost_class() {
+        let mut config = config(0, 100);
+        config.operations.insert("Cheap".to_string(), 100);
+        let bulkhead = Bulkhead::new(&config).unwrap();
+
+        // Fill the heavy class (2 slots) with anonymous heavy queries.
+        let heavy = bulkhead.compartments(None, || Some(5_000));
+        let _held = [
+            bulkhead.acquire(heavy[0]).await.unwrap(),
+            bulkhead.acquire(heavy[0]).await.unwrap(),
+        ];
+
+        // A heavy query labelled with a roomy configured operation name must
+        // still be turned away by the heavy class.
+        let compartments = bulkhead.compartments(Some("Cheap"), || Some(5_000));
+        assert_eq!(compartments[0].name(), "operation:Cheap");
+        let _named = bulkhead.acquire(compartments[0]).await.unwrap();
+        assert_eq!(compartments[1].name(), "cost:heavy");
+        let rejection = bulkhead.acquire(compartments[1]).await.unwrap_err();
+        assert_eq!(rejection, BulkheadRejection::QueueFull);
+    }
+
+    #[tokio::test]
+    async fn rejects_immediately_when_queue_full() {
+        let bulkhead = Bulkhead::new(&config(0, 100)).unwrap();
+        let compartment = bulkhead.compartments(Some("Report"), || None)[0];
+        let _held = bulkhead.acquire(compartment).await.unwrap();
+        let rejection = bulkhead.acquire(compartment).await.unwrap_err();
+        assert_eq!(rejection, BulkheadRejection::QueueFull);
+
+        let stats = bulkhead.stats();
+        let report = stats.iter().find(|c| c.name == "operation:Report").unwrap();
+        assert_eq!(report.in_flight, 1);
+        assert_eq!(report.rejected_full, 1);
+    }
+
+    #[tokio::test]
+    async fn queued_request_times_out() {
+        let bulkhead = Bulkhead::new(&config(4, 10)).unwrap();
+        let compartment = bulkhead.compartments(Some("Report"), || None)[0];
+        let _held = bulkhead.acquire(compartment).await.unwrap();
+        let rejection = bulkhead.acquire(compartment).await.unwrap_err();
+        assert_eq!(rejection, BulkheadRejection::Timeout);
+
+        let stats = bulkhead.stats();
+        let report = stats.iter().find(|c| c.name == "operation:Report").unwrap();
+        assert_eq!(report.waiting, 0, "waiter must leave the queue on timeout");
+        assert_eq!(report.rejected_wait, 1);
+    }
+
+    #[tokio::test]
+    async fn queued_request_is_admitted_when_slot_frees() {
+        let bulkhead = Arc::new(Bulkhead::new(&config(4, 1_000)).unwrap());
+        let compartment = bulkhead.compartments(Some("Report"), || None)[0];
+        let held = bulkhead.acquire(compartment).await.unwrap();
+
+        let waiter = {
+            let bulkhead = Arc::clone(&bulkhead);
+            tokio::spawn(async move {
+                let compartment = bulkhead.compartments(Some("Report"), || None)[0];
+                bulkhead.acquire(compartment).await.is_ok()
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(held);
+        assert!(waiter.await.unwrap(), "waiter must get the freed slot");
+    }
+
+    #[test]
+    fn config_validation() {
+        assert!(config(4, 100).validate().is_ok());
+
+        let empty = BulkheadConfig {
+            operations: HashMap::new(),
+            cost_classes: Vec::new(),
+            ..config(4, 100)
+        };
+        assert!(empty.validate().is_err());
+
+        let mut zero = config(4, 100);
+        zero.operations.insert("Zero".to_string(), 0);
+        assert!(zero.validate().is_err());
+
+        let mut bad_name = config(4, 100);
+        bad_name.operations.insert("not-a-name".to_string(), 1);
+        assert!(bad_name.validate().is_err());
+
+        let mut duplicate = config(4, 100);
+        duplicate.cost_classes[1].min_cost = 100;
+        assert!(duplicate.validate().is_err());
+
+        assert!(config(4, 0).validate().is_err());
+    }
+
+    #[test]
+    fn config_parses_from_toml() {
+        let config: BulkheadConfig = toml::from_str(
+            r#"
+            queue_timeout_ms = 250
+
+            [operations]
+            MonthlyRevenueReport = 4
+
+            [[cost_classes]]
+            name = "heavy"
+            min_cost = 1000
+            max_concurrent = 8
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.operations["MonthlyRevenueReport"], 4);
+        assert_eq!(config.cost_classes[0].max_concurrent, 8);
+        assert_eq!(config.max_queue, 16);
+        assert_eq!(config.queue_timeout_ms, 250);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Provides endpoints for:
 //! - Hot-reloading schema without restart
+//! - Reloading the server configuration (safe fields only)
 //! - Invalidating cache by scope (all, entity type, or pattern)
 //! - Inspecting runtime configuration (sanitized)
 
@@ -174,6 +175,8 @@ pub fn validate_schema_path(
 /// Returns `ApiError` with a validation error if `schema_path` is empty.
 /// Returns `ApiError` with a validation error if `schema_path` contains path traversal.
 /// Returns `ApiError` with a parse error if the schema file cannot be read or parsed.
+/// Returns `ApiError` with a validation error if a schema public key is configured and
+/// the schema is unsigned or its signature does not match.
 ///
 /// Requires admin token authentication.
 pub async fn reload_schema_handler<A: DatabaseAdapter>(
@@ -192,7 +195,10 @@ pub async fn reload_schema_handler<A: DatabaseAdapter>(
     let schema_json = fs::read_to_string(&req.schema_path)
         .map_err(|e| ApiError::parse_error(format!("Failed to read schema file: {}", e)))?;
 
-    // Step 2: Validate schema structure
+    // Step 2: Verify the signature and validate schema structure
+    state
+        .verify_schema_signature(&schema_json)
+        .map_err(ApiError::validation_error)?;
     let _validated_schema = CompiledSchema::from_json(&schema_json, false)
         .map_err(|e| ApiError::parse_error(format!("Invalid schema JSON: {}", e)))?;
 
@@ -271,6 +277,42 @@ pub struct CacheStatsResponse {
     pub message:       String,
 }
 
+/// Re-read the server configuration and apply the changes that are safe at
+/// runtime.
+///
+/// Responds with the reload report: the fields applied and the changed fields
+/// rejected (with the reason each needs a restart).
+///
+/// # Errors
+///
+/// Returns `ApiError` with a not-found error if the server was built without
+/// `Server::with_config_reload`.
+/// Returns `ApiError` with a validation error if the new configuration cannot
+/// be loaded or is invalid; nothing is applied in that case.
+///
+/// Requires admin token authentication.
+pub async fn config_reload_handler<A: DatabaseAdapter>(
+    State(state): State<AppState<A>>,
+) -> Result<Json<ApiResponse<crate::config_reload::ConfigReloadReport>>, ApiError> {
+    let Some(ref reloader) = state.config_reloader else {
+        return Err(ApiError::not_found("config reload is not enabled"));
+    };
+    let report = reloader.reload().await.map_err(|e| {
+        error!(operation = "admin.config_reload", error = %e, "Admin: config reload failed");
+        ApiError::validation_error(e)
+    })?;
+    info!(
+        operation = "admin.config_reload",
+        applied = report.applied.len(),
+        rejected = report.rejected.len(),
+        "Admin: configuration reloaded"
+    );
+    Ok(Json(ApiResponse {
+        status: "success".to_string(),
+        data:   report,
+    }))
+}
+
 /// Clear cache entries by scope.
 ///
 /// Supports three clearing scopes:
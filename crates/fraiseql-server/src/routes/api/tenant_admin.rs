@@ -192,7 +192,8 @@ pub struct DomainMapping {
 /// # Errors
 ///
 /// Returns `ApiError` with 404 if multi-tenant mode is disabled, 400 for invalid
-/// schema JSON, or 503 if the connection cannot be established.
+/// schema JSON or a schema whose signature does not verify against the configured
+/// `schema_public_key`, or 503 if the connection cannot be established.
 pub async fn upsert_tenant_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
     State(state): State<AppState<A>>,
     Path(key): Path<String>,
@@ -215,6 +216,9 @@ pub async fn upsert_tenant_handler<A: DatabaseAdapter + Clone + Send + Sync + 's
 
     let schema_json = serde_json::to_string(&body.schema)
         .map_err(|e| ApiError::validation_error(format!("invalid schema JSON: {e}")))?;
+    state
+        .verify_schema_signature(&schema_json)
+        .map_err(ApiError::validation_error)?;
 
     let executor =
         factory(key.clone(), schema_json, body.connection).await.map_err(|e| match &e {
@@ -1242,11 +1242,11 @@ mod usage_tests {
     };
     use tower::ServiceExt as _;
 
-    use super::super::usage::usage_handler;
+    use super::super::usage::{field_usage_handler, usage_handler};
     use crate::{
         middleware::{BearerAuthState, bearer_auth_middleware},
         routes::graphql::AppState,
-        usage::aggregator::UsageAggregator,
+        usage::{aggregator::UsageAggregator, fields::FieldUsageAggregator},
     };
 
     #[derive(Debug, Clone)]
@@ -1480,4 +1480,55 @@ mod usage_tests {
         let resp = router.oneshot(req).await.unwrap();
         assert_eq!(resp.status(), StatusCode::OK);
     }
+
+    fn make_field_usage_router(field_usage: Option<Arc<FieldUsageAggregator>>) -> Router {
+        let mut state = make_state_with_usage(Arc::new(UsageAggregator::new()));
+        if let Some(field_usage) = field_usage {
+            state = state.with_field_usage(field_usage);
+        }
+        Router::new()
+            .route("/api/v1/admin/field-usage", get(field_usage_handler::<StubAdapter>))
+            .with_state(state)
+    }
+
+    #[tokio::test]
+    async fn test_field_usage_disabled_reports_not_enabled() {
+        let router = make_field_usage_router(None);
+
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri("/api/v1/admin/field-usage")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = router.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json = body_json(resp).await;
+        assert_eq!(json["enabled"], false);
+        assert_eq!(json["fields"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_field_usage_filters_by_client() {
+        let field_usage = Arc::new(FieldUsageAggregator::new(10));
+        field_usage.record("web", ["User.email".to_string()]);
+        field_usage.record("web", ["User.email".to_string()]);
+        field_usage.record("ios", ["User.id".to_string()]);
+        let router = make_field_usage_router(Some(field_usage));
+
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri("/api/v1/admin/field-usage?client_id=web")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = router.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json = body_json(resp).await;
+        assert_eq!(json["enabled"], true);
+        assert_eq!(
+            json["fields"],
+            serde_json::json!([{ "client_id": "web", "coordinate": "User.email", "count": 2 }])
+        );
+    }
 }
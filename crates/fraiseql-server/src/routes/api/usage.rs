@@ -29,6 +29,25 @@
 //!
 //! Invalid `period` (not `YYYY-MM`) returns 400 with
 //! `{"error": "invalid period format"}`.
+//!
+//! ## Field usage
+//!
+//! ```text
+//! GET /api/v1/admin/field-usage[?client_id=<str>]
+//! ```
+//!
+//! Returns per-client selection counts for schema coordinates since process
+//! start, most-selected first. When `[field_usage]` is not configured the
+//! response has `"enabled": false` and no entries.
+//!
+//! ```json
+//! {
+//!   "enabled": true,
+//!   "fields": [
+//!     { "client_id": "web", "coordinate": "User.email", "count": 42 }
+//!   ]
+//! }
+//! ```
 
 use axum::{
     Json,
@@ -40,7 +59,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     routes::graphql::AppState,
-    usage::aggregator::{UsageSummary, validate_period},
+    usage::{
+        aggregator::{UsageSummary, validate_period},
+        fields::FieldUsageEntry,
+    },
 };
 
 // ── Query parameters ───────────────────────────────────────────────────────
@@ -55,6 +77,14 @@ pub struct UsageQueryParams {
     pub period:    String,
 }
 
+/// Query parameters for the field-usage endpoint.
+#[non_exhaustive]
+#[derive(Debug, Deserialize)]
+pub struct FieldUsageQueryParams {
+    /// Restrict the report to a single client id.
+    pub client_id: Option<String>,
+}
+
 // ── Response types ─────────────────────────────────────────────────────────
 
 /// Successful usage query response.
@@ -69,6 +99,16 @@ pub struct UsageResponse {
     pub usage:     UsageSummary,
 }
 
+/// Field-usage query response.
+#[non_exhaustive]
+#[derive(Debug, Serialize)]
+pub struct FieldUsageResponse {
+    /// Whether field usage tracking is configured.
+    pub enabled: bool,
+    /// Selection counts, most-selected first.
+    pub fields:  Vec<FieldUsageEntry>,
+}
+
 // ── Handler ────────────────────────────────────────────────────────────────
 
 /// Query mutation usage statistics for a tenant and period.
@@ -100,4 +140,24 @@ pub async fn usage_handler<A: DatabaseAdapter>(
     }))
 }
 
+/// Report per-client field selection counts.
+///
+/// Never fails: returns `enabled: false` with no entries when field usage
+/// tracking is off, and an empty list for unknown clients.
+pub async fn field_usage_handler<A: DatabaseAdapter>(
+    State(state): State<AppState<A>>,
+    Query(params): Query<FieldUsageQueryParams>,
+) -> Json<FieldUsageResponse> {
+    let Some(ref field_usage) = state.field_usage else {
+        return Json(FieldUsageResponse {
+            enabled: false,
+            fields:  Vec::new(),
+        });
+    };
+    Json(FieldUsageResponse {
+        enabled: true,
+        fields:  field_usage.snapshot(params.client_id.as_deref()),
+    })
+}
+
 // ── Tests ──────────────────────────────────────────────────────────────────
@@ -7,7 +7,7 @@ use fraiseql_core::{
     apq::{ApqMetrics, ArcApqStorage},
     db::traits::DatabaseAdapter,
     runtime::Executor,
-    schema::CompiledSchema,
+    schema::{CompiledSchema, SchemaVerifyingKey, verify_artifact_signature},
     security::IntrospectionPolicy,
 };
 use tracing::{info, warn};
@@ -16,8 +16,10 @@ use super::{tenant_key::DomainRegistry, tenant_registry::TenantExecutorRegistry}
 #[cfg(feature = "auth")]
 use crate::auth::rate_limiting::{AuthRateLimitConfig, KeyedRateLimiter};
 use crate::{
-    config::error_sanitization::ErrorSanitizer, error::GraphQLError,
-    metrics_server::MetricsCollector, usage::aggregator::UsageAggregator,
+    config::error_sanitization::ErrorSanitizer,
+    error::GraphQLError,
+    metrics_server::MetricsCollector,
+    usage::{aggregator::UsageAggregator, fields::FieldUsageAggregator},
 };
 
 /// Server state containing executor and configuration.
@@ -80,6 +82,19 @@ pub struct AppState<A: DatabaseAdapter> {
     /// Defaults to `100_000` (100 `KiB`).  Configurable via
     /// `ServerConfig::max_get_query_bytes`.
     pub max_get_query_bytes: usize,
+    /// Maximum operations accepted in one batched POST; `0` disables batching.
+    ///
+    /// Configurable via `ServerConfig::max_batch_operations`.
+    pub max_batch_operations: usize,
+    /// Maximum operations from one batch executed concurrently.
+    ///
+    /// Configurable via `ServerConfig::batch_concurrency`.
+    pub batch_concurrency: usize,
+    /// Per-request time budget handed to query execution; `None` = unbounded.
+    ///
+    /// Configurable via `ServerConfig::request_timeout_ms` /
+    /// `request_timeout_secs`.
+    pub request_timeout: Option<std::time::Duration>,
     /// Introspection policy for the GraphQL request path.
     ///
     /// Derived from `ServerConfig::introspection_enabled` /
@@ -93,12 +108,31 @@ pub struct AppState<A: DatabaseAdapter> {
     /// Observer runtime handle for health probes (optional, requires `observers` feature).
     #[cfg(feature = "observers")]
     pub observer_runtime: Option<Arc<tokio::sync::RwLock<crate::observers::ObserverRuntime>>>,
+    /// Additional dependencies probed by `/readiness` (e.g. the Redis rate limiter).
+    pub readiness_probes: Vec<Arc<dyn crate::readiness::DependencyProbe>>,
+    /// Time allowed for each readiness probe.
+    pub readiness_probe_timeout: std::time::Duration,
+    /// Startup traffic gate (optional, enabled via `[readiness] gate_traffic`).
+    pub readiness_gate: Option<Arc<crate::readiness::ReadinessGate>>,
+    /// Candidate schema under canary rollout (optional, enabled via `[canary]`).
+    pub canary: Option<Arc<crate::canary::CanaryRollout<A>>>,
+    /// Shadow executor for mirrored read queries (optional, enabled via `[shadow]`).
+    pub shadow: Option<Arc<crate::shadow::ShadowMirror<A>>>,
+    /// Per-operation concurrency limits (optional, enabled via `[bulkhead]`).
+    pub bulkhead: Option<Arc<crate::resilience::bulkhead::Bulkhead>>,
+    /// Runtime config reloader (optional, enabled via `Server::with_config_reload`).
+    pub config_reloader: Option<Arc<crate::config_reload::ConfigReloader>>,
     /// Schema file path for reload operations.
     pub schema_path: Option<PathBuf>,
     /// Database adapter reference for constructing new executors on reload.
     pub(crate) reload_adapter: Option<Arc<A>>,
     /// Reload mutex to serialize concurrent reload attempts.
     pub(crate) reload_lock: Arc<tokio::sync::Mutex<()>>,
+    /// Ed25519 public key (PEM or base64) reloaded schemas must be signed with.
+    ///
+    /// Kept as configured and parsed on each reload, so a malformed key refuses
+    /// the reload rather than skipping the check.
+    pub(crate) schema_public_key: Option<String>,
     /// Whether the adapter-level query result cache is active.
     ///
     /// Set to `true` when `ServerConfig::cache_enabled = true` and the server
@@ -126,6 +160,10 @@ pub struct AppState<A: DatabaseAdapter> {
     /// Always present (never `Option`): when audit logging is disabled the
     /// aggregator simply receives no events and every query returns empty counts.
     pub usage: Arc<UsageAggregator>,
+    /// Operation audit log (optional) — records every mutation to `tb_graphql_audit`.
+    pub operation_audit: Option<fraiseql_core::security::OperationAuditLog>,
+    /// Per-client field selection counters (optional, `[field_usage]`).
+    pub field_usage: Option<Arc<FieldUsageAggregator>>,
     /// Before-mutation hooks from the functions subsystem (optional).
     ///
     /// When `Some`, every GraphQL mutation is checked against the trigger registry
@@ -183,16 +221,29 @@ impl<A: DatabaseAdapter> AppState<A> {
             #[cfg(feature = "observers")]
             observer_runtime: None,
             max_get_query_bytes: 100_000,
+            max_batch_operations: 0,
+            batch_concurrency: 4,
+            request_timeout: None,
             introspection_policy: IntrospectionPolicy::Disabled,
+            readiness_probes: Vec::new(),
+            readiness_probe_timeout: crate::readiness::DEFAULT_PROBE_TIMEOUT,
+            readiness_gate: None,
+            canary: None,
+            shadow: None,
+            bulkhead: None,
+            config_reloader: None,
             schema_path: None,
             reload_adapter: None,
             reload_lock: Arc::new(tokio::sync::Mutex::new(())),
+            schema_public_key: None,
             adapter_cache_enabled: false,
             tenant_registry: None,
             tenant_executor_factory: None,
             domain_registry: Arc::new(DomainRegistry::new()),
             tenant_audit_log: None,
             usage: Arc::clone(crate::usage::aggregator::global_aggregator()),
+            operation_audit: None,
+            field_usage: None,
             before_mutation_hooks: None,
             #[cfg(feature = "auth")]
             identity_resolver: None,
@@ -298,6 +349,20 @@ impl<A: DatabaseAdapter> AppState<A> {
         self
     }
 
+    /// Attach the operation audit log that records every mutation.
+    #[must_use]
+    pub fn with_operation_audit(mut self, log: fraiseql_core::security::OperationAuditLog) -> Self {
+        self.operation_audit = Some(log);
+        self
+    }
+
+    /// Attach the per-client field selection counters.
+    #[must_use]
+    pub fn with_field_usage(mut self, field_usage: Arc<FieldUsageAggregator>) -> Self {
+        self.field_usage = Some(field_usage);
+        self
+    }
+
     /// Attach a tenant audit log for lifecycle event recording.
     #[must_use]
     pub fn with_tenant_audit_log(mut self, log: crate::tenancy::audit::AuditLogHandle) -> Self {
@@ -330,6 +395,31 @@ impl<A: DatabaseAdapter> AppState<A> {
         self
     }
 
+    /// Require reloaded schemas to be signed with `key` (PEM or base64 Ed25519
+    /// public key). `None` leaves signatures unchecked.
+    #[must_use]
+    pub fn with_schema_public_key(mut self, key: Option<String>) -> Self {
+        self.schema_public_key = key;
+        self
+    }
+
+    /// Check the signature of a schema about to be loaded, when a public key is
+    /// configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured key is invalid, or the schema is unsigned
+    /// or signed with another key.
+    pub fn verify_schema_signature(&self, json: &str) -> Result<(), String> {
+        let Some(key) = &self.schema_public_key else {
+            return Ok(());
+        };
+        let key = SchemaVerifyingKey::parse(key).map_err(|e| format!("schema_public_key: {e}"))?;
+        verify_artifact_signature(json, &key)
+            .map(|_| ())
+            .map_err(|e| format!("Schema signature verification failed: {e}"))
+    }
+
     /// Reload the compiled schema from a file path.
     ///
     /// Reads the schema file, validates it, constructs a new `Executor<A>`,
@@ -342,7 +432,8 @@ impl<A: DatabaseAdapter> AppState<A> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the file cannot be read, the JSON is invalid, or
+    /// Returns an error if the file cannot be read, the JSON is invalid, the
+    /// signature does not verify (see [`Self::with_schema_public_key`]), or
     /// schema validation fails. On error, the current executor is unchanged.
     pub async fn reload_schema(&self, path: &std::path::Path) -> Result<(), String> {
         // Serialize concurrent reloads
@@ -361,7 +452,8 @@ impl<A: DatabaseAdapter> AppState<A> {
             .await
             .map_err(|e| format!("Failed to read schema file {}: {e}", path.display()))?;
 
-        // 2. Parse and validate
+        // 2. Verify the signature, parse and validate
+        self.verify_schema_signature(&json)?;
         let schema = CompiledSchema::from_json(&json, false)
             .map_err(|e| format!("Invalid schema JSON: {e}"))?;
 
@@ -423,7 +515,8 @@ impl<A: DatabaseAdapter> AppState<A> {
             .as_ref()
             .ok_or_else(|| "Reload not configured: no adapter available".to_string())?;
 
-        // 1. Parse and validate
+        // 1. Verify the signature, parse and validate
+        self.verify_schema_signature(json)?;
         let schema = CompiledSchema::from_json(json, false)
             .map_err(|e| format!("Invalid schema JSON: {e}"))?;
 
@@ -641,6 +734,27 @@ impl<A: DatabaseAdapter> AppState<A> {
         self
     }
 
+    /// Enable POST batching with the given size cap and per-batch concurrency.
+    ///
+    /// `max_operations = 0` disables batching; `concurrency` is clamped to at
+    /// least one.
+    #[must_use]
+    pub const fn with_batching(mut self, max_operations: usize, concurrency: usize) -> Self {
+        self.max_batch_operations = max_operations;
+        self.batch_concurrency = if concurrency == 0 { 1 } else { concurrency };
+        self
+    }
+
+    /// Bound query execution by a per-request time budget.
+    ///
+    /// The budget is measured from when the handler starts, so time spent on
+    /// parsing, APQ lookup and validation is taken out of what execution gets.
+    #[must_use]
+    pub const fn with_request_timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
     /// Attach an adaptive connection pool auto-tuner.
     #[must_use]
     pub fn with_pool_tuner(mut self, tuner: Arc<crate::pool::PoolSizingAdvisor>) -> Self {
@@ -648,6 +762,66 @@ impl<A: DatabaseAdapter> AppState<A> {
         self
     }
 
+    /// Add a dependency to the `/readiness` probe.
+    #[must_use]
+    pub fn with_readiness_probe(
+        mut self,
+        probe: Arc<dyn crate::readiness::DependencyProbe>,
+    ) -> Self {
+        self.readiness_probes.push(probe);
+        self
+    }
+
+    /// Set the time allowed for each readiness probe.
+    #[must_use]
+    pub const fn with_readiness_probe_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.readiness_probe_timeout = timeout;
+        self
+    }
+
+    /// Attach the startup traffic gate; `/readiness` opens it once all
+    /// critical dependencies are ready.
+    #[must_use]
+    pub fn with_readiness_gate(mut self, gate: Arc<crate::readiness::ReadinessGate>) -> Self {
+        self.readiness_gate = Some(gate);
+        self
+    }
+
+    /// Attach a canary rollout; GraphQL requests it routes to the candidate run
+    /// against the candidate schema.
+    #[must_use]
+    pub fn with_canary(mut self, canary: Arc<crate::canary::CanaryRollout<A>>) -> Self {
+        self.canary = Some(canary);
+        self
+    }
+
+    /// Attach a shadow mirror; sampled successful read queries are re-run on its
+    /// executor in the background and their results compared.
+    #[must_use]
+    pub fn with_shadow(mut self, shadow: Arc<crate::shadow::ShadowMirror<A>>) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Attach a bulkhead; GraphQL executions of its operations and cost classes
+    /// are limited to their configured concurrency.
+    #[must_use]
+    pub fn with_bulkhead(mut self, bulkhead: Arc<crate::resilience::bulkhead::Bulkhead>) -> Self {
+        self.bulkhead = Some(bulkhead);
+        self
+    }
+
+    /// Attach the runtime config reloader used by SIGHUP and
+    /// `POST /api/v1/admin/config/reload`.
+    #[must_use]
+    pub fn with_config_reloader(
+        mut self,
+        reloader: Arc<crate::config_reload::ConfigReloader>,
+    ) -> Self {
+        self.config_reloader = Some(reloader);
+        self
+    }
+
     /// Set whether the adapter-level cache is active.
     ///
     /// Called from `build_router` to thread the cache state through to admin handlers.
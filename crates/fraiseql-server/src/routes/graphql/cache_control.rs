@@ -0,0 +1,69 @@
+//! HTTP caching headers for GraphQL GET responses.
+//!
+//! A GET response is shared-cacheable only when every root field it selects
+//! is a query with a positive `cache_ttl_seconds` hint in the compiled schema.
+//! The smallest hint wins, so the cached response never outlives the most
+//! volatile data it contains. Anything else — mutations, introspection,
+//! unhinted or `0`-TTL queries, responses carrying `errors` — is `no-store`.
+//!
+//! Authenticated requests get `private` so that browsers may reuse the
+//! response but CDNs never share it between users.
+
+use axum::http::HeaderValue;
+use fraiseql_core::schema::CompiledSchema;
+
+/// `Vary` sent with every cacheable GraphQL GET response.
+///
+/// `X-Tenant-ID` is included because tenant selection by header changes the
+/// result without changing the URL.
+pub(crate) const GRAPHQL_VARY: &str = "Authorization, Accept, X-Tenant-ID";
+
+/// Compute the `Cache-Control` value for a successful GraphQL GET response.
+pub(crate) fn graphql_cache_control(
+    schema: &CompiledSchema,
+    query: &str,
+    authenticated: bool,
+    response: &serde_json::Value,
+) -> HeaderValue {
+    let has_errors = response
+        .get("errors")
+        .and_then(serde_json::Value::as_array)
+        .is_some_and(|errors| !errors.is_empty());
+    if has_errors {
+        return HeaderValue::from_static("no-store");
+    }
+
+    match query_max_age(schema, query) {
+        Some(max_age) => {
+            let visibility = if authenticated { "private" } else { "public" };
+            HeaderValue::from_str(&format!("{visibility}, max-age={max_age}"))
+                .unwrap_or_else(|_| HeaderValue::from_static("no-store"))
+        },
+        None => HeaderValue::from_static("no-store"),
+    }
+}
+
+/// Smallest `cache_ttl_seconds` across the query's root fields, or `None` if
+/// any root field is uncacheable.
+///
+/// `__typename` is static and does not constrain the TTL, but a document that
+/// selects nothing else is not worth caching either.
+pub(crate) fn query_max_age(schema: &CompiledSchema, query: &str) -> Option<u64> {
+    let parsed = fraiseql_core::graphql::parse_query(query).ok()?;
+    if parsed.operation_type != "query" {
+        return None;
+    }
+
+    let mut max_age: Option<u64> = None;
+    for field in &parsed.selections {
+        if field.name == "__typename" {
+            continue;
+        }
+        let ttl = schema.find_query(&field.name)?.cache_ttl_seconds.filter(|&ttl| ttl > 0)?;
+        max_age = Some(max_age.map_or(ttl, |current| current.min(ttl)));
+    }
+    max_age
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,71 @@
+//! Tests for GraphQL GET `Cache-Control` derivation from query TTL hints.
+
+#![allow(clippy::unwrap_used)] // Reason: test code.
+
+use fraiseql_core::schema::{CompiledSchema, QueryDefinition};
+use serde_json::json;
+
+use super::{graphql_cache_control, query_max_age};
+
+fn schema() -> CompiledSchema {
+    let mut schema = CompiledSchema::new();
+    let mut countries = QueryDefinition::new("countries", "Country").returning_list();
+    countries.cache_ttl_seconds = Some(3600);
+    let mut prices = QueryDefinition::new("prices", "Price").returning_list();
+    prices.cache_ttl_seconds = Some(30);
+    let mut live = QueryDefinition::new("liveOrders", "Order").returning_list();
+    live.cache_ttl_seconds = Some(0);
+    let unhinted = QueryDefinition::new("users", "User").returning_list();
+    schema.queries = vec![countries, prices, live, unhinted];
+    schema
+}
+
+fn header(schema: &CompiledSchema, query: &str, authenticated: bool) -> String {
+    graphql_cache_control(schema, query, authenticated, &json!({ "data": {} }))
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn single_hinted_root_is_public() {
+    assert_eq!(header(&schema(), "{ countries { code } }", false), "public, max-age=3600");
+}
+
+#[test]
+fn smallest_ttl_across_roots_wins() {
+    let query = "query Both { countries { code } prices { amount } __typename }";
+    assert_eq!(query_max_age(&schema(), query), Some(30));
+}
+
+#[test]
+fn authenticated_response_is_private() {
+    assert_eq!(header(&schema(), "{ countries { code } }", true), "private, max-age=3600");
+}
+
+#[test]
+fn unhinted_or_zero_ttl_root_is_no_store() {
+    let schema = schema();
+    assert_eq!(header(&schema, "{ countries { code } users { id } }", false), "no-store");
+    assert_eq!(header(&schema, "{ liveOrders { id } }", false), "no-store");
+    assert_eq!(header(&schema, "{ __typename }", false), "no-store");
+    assert_eq!(header(&schema, "{ __schema { queryType { name } } }", false), "no-store");
+}
+
+#[test]
+fn mutations_and_parse_errors_are_no_store() {
+    let schema = schema();
+    assert_eq!(header(&schema, "mutation { countries { code } }", false), "no-store");
+    assert_eq!(header(&schema, "{ countries {", false), "no-store");
+}
+
+#[test]
+fn responses_with_errors_are_no_store() {
+    let value = graphql_cache_control(
+        &schema(),
+        "{ countries { code } }",
+        false,
+        &json!({ "data": null, "errors": [{ "message": "boom" }] }),
+    );
+    assert_eq!(value, "no-store");
+}
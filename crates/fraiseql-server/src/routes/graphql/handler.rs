@@ -3,7 +3,7 @@
 use std::{sync::atomic::Ordering, time::Instant};
 
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Query, State},
     http::HeaderMap,
 };
@@ -14,18 +14,59 @@ use fraiseql_core::{
     security::{IntrospectionEnforcer, SecurityContext, SecurityError},
 };
 use fraiseql_error::FraiseQLError;
+use futures::future::join_all;
+use tokio::sync::Semaphore;
 use tracing::{debug, error, warn};
 
 use super::{
     app_state::AppState,
-    request::{GraphQLGetParams, GraphQLRequest, GraphQLResponse},
+    request::{GraphQLBatchRequest, GraphQLGetParams, GraphQLRequest, GraphQLResponse},
 };
 use crate::{
-    error::{ErrorResponse, GraphQLError},
+    canary::SchemaVariant,
+    error::{ErrorExtensions, ErrorResponse, GraphQLError},
     extractors::{OptionalSecurityContext, PeerIp},
+    metrics_server::MetricsCollector,
+    middleware::rate_limit::RateLimitCharge,
+    shadow::MirroredQuery,
     tracing_utils,
 };
 
+/// Counts an operation abandoned because its client disconnected.
+///
+/// When a client goes away, hyper drops the handler future mid-execution, and
+/// with it every future the executor is awaiting. That drop is what reaches
+/// the database: the `PostgreSQL` adapter cancels a statement still running on
+/// a connection that is dropped, instead of letting it run to completion.
+/// This guard only makes the abandonment visible in metrics and logs.
+struct DisconnectGuard<'a> {
+    metrics:  &'a MetricsCollector,
+    finished: bool,
+}
+
+impl<'a> DisconnectGuard<'a> {
+    const fn new(metrics: &'a MetricsCollector) -> Self {
+        Self {
+            metrics,
+            finished: false,
+        }
+    }
+
+    /// Execution completed; the client is still waiting for the response.
+    fn finish(mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for DisconnectGuard<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.metrics.client_disconnects_total.fetch_add(1, Ordering::Relaxed);
+            debug!("client disconnected during execution, abandoning operation");
+        }
+    }
+}
+
 /// GraphQL HTTP handler for POST requests.
 ///
 /// Handles POST requests to the GraphQL endpoint:
@@ -35,23 +76,33 @@ use crate::{
 /// 4. Execute query via Executor with optional `SecurityContext`
 /// 5. Return GraphQL response with proper error formatting
 ///
+/// The body may also be a JSON array of requests when batching is enabled
+/// (`ServerConfig::max_batch_operations`); each gets its own response slot and
+/// costs one rate-limit token.
+///
 /// Tracks execution timing and operation name for monitoring.
 /// Provides GraphQL spec-compliant error responses.
 /// Supports W3C Trace Context for distributed tracing.
 /// Supports OIDC authentication for RLS policy evaluation.
 ///
+/// Every error in the response carries the request's correlation id in
+/// `extensions.request_id` (see [`crate::error`] for the extension contract).
+///
 /// # Errors
 ///
 /// Returns appropriate HTTP status codes based on error type.
-#[tracing::instrument(skip_all, fields(operation_name))]
+#[tracing::instrument(skip_all, fields(operation_name, request_id))]
 #[doc(hidden)] // Internal-pub: axum route handler wired via Server::route; downstream uses Server::serve(), not this fn directly.
 pub async fn graphql_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
     State(state): State<AppState<A>>,
     headers: HeaderMap,
     PeerIp(peer_ip): PeerIp,
     OptionalSecurityContext(security_context): OptionalSecurityContext,
-    Json(request): Json<GraphQLRequest>,
+    rate_limit: Option<Extension<RateLimitCharge>>,
+    Json(request): Json<GraphQLBatchRequest>,
 ) -> Result<GraphQLResponse, ErrorResponse> {
+    let request_id = correlation_id(&headers, security_context.as_ref());
+
     // Extract trace context from W3C headers
     let trace_context = tracing_utils::extract_trace_context(&headers);
     if trace_context.is_some() {
@@ -62,8 +113,152 @@ pub async fn graphql_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>
         debug!("Authenticated request with security context");
     }
 
-    execute_graphql_request(state, request, trace_context, security_context, &headers, &peer_ip)
-        .await
+    let result = match request {
+        GraphQLBatchRequest::Single(request) => {
+            execute_graphql_request(
+                state,
+                request,
+                trace_context,
+                security_context,
+                &headers,
+                &peer_ip,
+            )
+            .await
+        },
+        GraphQLBatchRequest::Batch(requests) => {
+            execute_graphql_batch(
+                state,
+                requests,
+                rate_limit.map(|Extension(charge)| charge),
+                trace_context,
+                security_context,
+                &headers,
+                &peer_ip,
+                &request_id,
+            )
+            .await
+        },
+    };
+    result.map_err(|e| e.with_request_id(&request_id))
+}
+
+/// Correlation id for a request, stamped on its errors and its tracing span.
+///
+/// Authenticated requests reuse the id already on their [`SecurityContext`]
+/// (taken from `X-Request-ID`, or generated) so audit logs and error responses
+/// agree; anonymous requests read `X-Request-ID` or get a fresh id.
+fn correlation_id(headers: &HeaderMap, security_context: Option<&SecurityContext>) -> String {
+    let request_id = security_context.map_or_else(
+        || crate::extractors::extract_request_id(headers),
+        |ctx| ctx.request_id.clone(),
+    );
+    tracing::Span::current().record("request_id", request_id.as_str());
+    request_id
+}
+
+/// Execute a batched POST body and answer with one response per operation.
+///
+/// Every operation goes through [`execute_graphql_request`] exactly as if it
+/// had been sent on its own — authentication, APQ, and validation apply per
+/// operation. The rate-limit middleware charged the request one token;
+/// `rate_limit` charges the remaining operations to the same bucket up front,
+/// so a batch costs as much as sending its operations one by one and is
+/// rejected whole when the quota cannot cover it. At most `AppState::batch_concurrency`
+/// operations run at once. A failing operation yields its own `{ "errors": [...] }`
+/// entry and never affects its siblings, so the HTTP status is always 200 once
+/// the batch itself is accepted.
+///
+/// # Errors
+///
+/// Returns a request error if batching is disabled, the batch is empty, or it
+/// exceeds `AppState::max_batch_operations`, and a rate-limit error if the
+/// caller's bucket cannot cover every operation.
+async fn execute_graphql_batch<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
+    state: AppState<A>,
+    requests: Vec<GraphQLRequest>,
+    rate_limit: Option<RateLimitCharge>,
+    #[cfg(feature = "federation")] trace_context: Option<
+        fraiseql_core::federation::FederationTraceContext,
+    >,
+    #[cfg(not(feature = "federation"))] trace_context: Option<()>,
+    security_context: Option<SecurityContext>,
+    headers: &HeaderMap,
+    peer_ip: &str,
+    request_id: &str,
+) -> Result<GraphQLResponse, ErrorResponse> {
+    let max = state.max_batch_operations;
+    if max == 0 {
+        return Err(ErrorResponse::from_error(GraphQLError::request(
+            "Batched requests are not enabled on this server",
+        )));
+    }
+    if requests.is_empty() {
+        return Err(ErrorResponse::from_error(GraphQLError::request(
+            "Batch must contain at least one operation",
+        )));
+    }
+    if requests.len() > max {
+        return Err(ErrorResponse::from_error(GraphQLError::request(format!(
+            "Batch of {} operations exceeds the maximum of {max}",
+            requests.len()
+        ))));
+    }
+    if let Some(charge) = rate_limit {
+        charge.charge(requests.len() - 1).await.map_err(|e| {
+            warn!(operations = requests.len(), "Batch exceeds the remaining rate limit");
+            let err = GraphQLError::rate_limited(format!(
+                "Rate limit exceeded: a batch of {} operations costs {} requests. Please retry \
+                 after {} second(s).",
+                requests.len(),
+                requests.len(),
+                e.retry_after_secs
+            ))
+            .with_extensions(ErrorExtensions {
+                category:         None,
+                status:           Some(429),
+                request_id:       None,
+                retry_after_secs: Some(u64::from(e.retry_after_secs)),
+                detail:           None,
+                limit:            None,
+            });
+            ErrorResponse::from_error(err)
+        })?;
+    }
+
+    debug!(operations = requests.len(), "Executing batched GraphQL request");
+
+    let semaphore = Semaphore::new(state.batch_concurrency.max(1));
+    let semaphore = &semaphore;
+    let responses = join_all(requests.into_iter().map(|request| {
+        let state = state.clone();
+        let trace_context = trace_context.clone();
+        let security_context = security_context.clone();
+        async move {
+            // The semaphore is never closed, so acquisition cannot fail.
+            let _permit = semaphore.acquire().await.ok();
+            match execute_graphql_request(
+                state,
+                request,
+                trace_context,
+                security_context,
+                headers,
+                peer_ip,
+            )
+            .await
+            {
+                Ok(response) => response.body,
+                Err(error) => serde_json::to_value(error.with_request_id(request_id)).unwrap_or_else(
+                    |_| serde_json::json!({ "errors": [{ "message": "Internal server error" }] }),
+                ),
+            }
+        }
+    }))
+    .await;
+
+    Ok(GraphQLResponse {
+        body:          serde_json::Value::Array(responses),
+        cache_control: None,
+    })
 }
 
 /// GraphQL HTTP handler for GET requests.
@@ -73,12 +268,19 @@ pub async fn graphql_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>
 /// - `query`: Required, the GraphQL query string (URL-encoded)
 /// - `variables`: Optional, JSON-encoded variables object (URL-encoded)
 /// - `operationName`: Optional, name of the operation to execute
+/// - `extensions`: Optional, JSON-encoded extensions (e.g. an APQ `persistedQuery` hash, in which
+///   case `query` may be omitted)
 ///
 /// Supports W3C Trace Context via traceparent header for distributed tracing.
 ///
+/// Successful responses carry `Cache-Control` derived from the selected queries'
+/// `cache_ttl_seconds` hints (`no-store` when any root field is unhinted), so
+/// public read-only queries can be cached by a CDN in front of the server.
+///
 /// Example:
 /// ```text
 /// GET /graphql?query={users{id,name}}&variables={"limit":10}
+/// GET /graphql?extensions={"persistedQuery":{"version":1,"sha256Hash":"…"}}
 /// ```
 ///
 /// # Errors
@@ -91,9 +293,9 @@ pub async fn graphql_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>
 /// # Note
 ///
 /// Per GraphQL over HTTP spec, GET requests should only be used for queries,
-/// not mutations (which should use POST). This handler does not enforce that
-/// restriction but logs a warning for mutation-like queries.
-#[tracing::instrument(skip_all, fields(operation_name))]
+/// not mutations (which should use POST). Mutations are rejected with
+/// `405 Method Not Allowed`, including those referenced by a persisted-query hash.
+#[tracing::instrument(skip_all, fields(operation_name, request_id))]
 #[doc(hidden)] // Internal-pub: axum route handler wired via Server::route; downstream uses Server::serve(), not this fn directly.
 pub async fn graphql_get_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
     State(state): State<AppState<A>>,
@@ -102,74 +304,85 @@ pub async fn graphql_get_handler<A: DatabaseAdapter + Clone + Send + Sync + 'sta
     OptionalSecurityContext(security_context): OptionalSecurityContext,
     Query(params): Query<GraphQLGetParams>,
 ) -> Result<GraphQLResponse, ErrorResponse> {
+    let request_id = correlation_id(&headers, security_context.as_ref());
+    let request = get_request_from_params(params, state.max_get_query_bytes)
+        .map_err(|e| e.with_request_id(&request_id))?;
+
+    let trace_context = tracing_utils::extract_trace_context(&headers);
+    if trace_context.is_some() {
+        debug!("Extracted W3C trace context from incoming request");
+    }
+
+    if security_context.is_some() {
+        debug!("Authenticated GET request with security context");
+    }
+
+    // Mutations over GET are rejected inside, once APQ / trusted documents have
+    // resolved the query body a hash-only GET refers to.
+    execute_operation(
+        state,
+        request,
+        trace_context,
+        security_context,
+        &headers,
+        &peer_ip,
+        HttpMethod::Get,
+    )
+    .await
+    .map_err(|e| e.with_request_id(&request_id))
+}
+
+/// Build a [`GraphQLRequest`] from GET query parameters, enforcing the size caps.
+fn get_request_from_params(
+    params: GraphQLGetParams,
+    max_get_bytes: usize,
+) -> Result<GraphQLRequest, ErrorResponse> {
     // Reject oversized GET queries early to prevent DoS via query parsing.
-    let max_get_bytes = state.max_get_query_bytes;
-    if params.query.len() > max_get_bytes {
+    if params.query.as_ref().is_some_and(|q| q.len() > max_get_bytes) {
         return Err(ErrorResponse::from_error(GraphQLError::request(format!(
             "GET query string exceeds maximum allowed length ({max_get_bytes} bytes)"
         ))));
     }
 
-    // Parse variables from JSON string.
-    // Apply the same size cap as the query string — the URL-length limit imposed
-    // by reverse proxies/OS is real but not enforced by axum itself, so we guard
-    // explicitly to prevent parser DoS from a very large variables value.
-    let variables = if let Some(vars_str) = params.variables {
-        if vars_str.len() > max_get_bytes {
-            return Err(ErrorResponse::from_error(GraphQLError::request(format!(
-                "GET variables string exceeds maximum allowed length ({max_get_bytes} bytes)"
-            ))));
-        }
-        match serde_json::from_str::<serde_json::Value>(&vars_str) {
-            Ok(v) => Some(v),
-            Err(e) => {
-                warn!(
-                    error = %e,
-                    variables = %vars_str,
-                    "Failed to parse variables JSON in GET request"
-                );
-                return Err(ErrorResponse::from_error(GraphQLError::request(format!(
-                    "Invalid variables JSON: {e}"
-                ))));
-            },
-        }
-    } else {
-        None
-    };
+    // Apply the same size cap as the query string to the JSON-encoded
+    // parameters — the URL-length limit imposed by reverse proxies/OS is real
+    // but not enforced by axum itself, so we guard explicitly to prevent parser
+    // DoS from a very large value.
+    let variables = parse_get_json_param(params.variables, "variables", max_get_bytes)?;
+    let extensions = parse_get_json_param(params.extensions, "extensions", max_get_bytes)?;
 
-    // Reject mutations over GET with 405 per the GraphQL-over-HTTP spec: GET is for
-    // queries only, and allowing mutations sidesteps the POST-only CSRF posture
-    // (M-get-mutations). Detection parses the operation (reliable) rather than matching a
-    // `mutation` string prefix (which a leading comment or named query defeats).
-    if detect_mutation_name(&params.query).is_some() {
-        warn!(
-            operation_name = ?params.operation_name,
-            "Mutation sent via GET request — rejected (use POST)"
-        );
-        return Err(ErrorResponse::from_error(GraphQLError::method_not_allowed(
-            "Mutations must be sent over POST, not GET",
-        )));
-    }
-
-    let trace_context = tracing_utils::extract_trace_context(&headers);
-    if trace_context.is_some() {
-        debug!("Extracted W3C trace context from incoming request");
-    }
-
-    let request = GraphQLRequest {
-        query: Some(params.query),
+    Ok(GraphQLRequest {
+        query: params.query,
         variables,
         operation_name: params.operation_name,
-        extensions: None,
+        extensions,
         document_id: None,
-    };
+    })
+}
 
-    if security_context.is_some() {
-        debug!("Authenticated GET request with security context");
+/// Decode a JSON-encoded GET parameter (`variables`, `extensions`).
+fn parse_get_json_param(
+    raw: Option<String>,
+    name: &str,
+    max_bytes: usize,
+) -> Result<Option<serde_json::Value>, ErrorResponse> {
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+    if raw.len() > max_bytes {
+        return Err(ErrorResponse::from_error(GraphQLError::request(format!(
+            "GET {name} string exceeds maximum allowed length ({max_bytes} bytes)"
+        ))));
+    }
+    match serde_json::from_str::<serde_json::Value>(&raw) {
+        Ok(v) => Ok(Some(v)),
+        Err(e) => {
+            warn!(error = %e, param = name, value = %raw, "Failed to parse JSON in GET request");
+            Err(ErrorResponse::from_error(GraphQLError::request(format!(
+                "Invalid {name} JSON: {e}"
+            ))))
+        },
     }
-
-    execute_graphql_request(state, request, trace_context, security_context, &headers, &peer_ip)
-        .await
 }
 
 /// Extract the mutation name from a GraphQL query string, if the operation is a mutation.
@@ -287,9 +500,41 @@ pub(crate) async fn resolve_apq(
     }
 }
 
-/// Shared GraphQL execution logic for both GET and POST handlers.
+/// Shared GraphQL execution logic for POST requests and other transports.
+pub(crate) async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
+    state: AppState<A>,
+    request: GraphQLRequest,
+    #[cfg(feature = "federation")] trace_context: Option<
+        fraiseql_core::federation::FederationTraceContext,
+    >,
+    #[cfg(not(feature = "federation"))] trace_context: Option<()>,
+    security_context: Option<SecurityContext>,
+    headers: &HeaderMap,
+    peer_ip: &str,
+) -> Result<GraphQLResponse, ErrorResponse> {
+    execute_operation(
+        state,
+        request,
+        trace_context,
+        security_context,
+        headers,
+        peer_ip,
+        HttpMethod::Post,
+    )
+    .await
+}
+
+/// HTTP method an operation arrived on.
+///
+/// GET operations must be read-only and are eligible for shared caching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HttpMethod {
+    Get,
+    Post,
+}
+
 #[tracing::instrument(skip_all, fields(operation_name = request.operation_name.as_deref().unwrap_or("anonymous")))]
-async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
+async fn execute_operation<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
     state: AppState<A>,
     mut request: GraphQLRequest,
     #[cfg(feature = "federation")] _trace_context: Option<
@@ -299,7 +544,12 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
     mut security_context: Option<SecurityContext>,
     headers: &HeaderMap,
     peer_ip: &str,
+    method: HttpMethod,
 ) -> Result<GraphQLResponse, ErrorResponse> {
+    // Time budget for execution: the request timeout counted from here, so
+    // auth, APQ lookup and validation below are taken out of it.
+    let deadline = state.request_timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+
     // Service-account auth (ADR-0018): a service account's `run_as` ceiling takes
     // precedence over the scopes-only static API key on the same header. A JWT principal
     // presented alongside a secret header is rejected as ambiguous (#602), never silently
@@ -440,6 +690,22 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
             .ok_or_else(|| ErrorResponse::from_error(GraphQLError::request("No query provided")))?
     };
 
+    // Reject mutations over GET with 405 per the GraphQL-over-HTTP spec: GET is for
+    // queries only, and allowing mutations sidesteps the POST-only CSRF posture
+    // (M-get-mutations). Checked on the resolved body so a persisted-query hash
+    // cannot smuggle a mutation onto GET. Detection parses the operation
+    // (reliable) rather than matching a `mutation` string prefix (which a
+    // leading comment or named query defeats).
+    if method == HttpMethod::Get && detect_mutation_name(&query).is_some() {
+        warn!(
+            operation_name = ?request.operation_name,
+            "Mutation sent via GET request — rejected (use POST)"
+        );
+        return Err(ErrorResponse::from_error(GraphQLError::method_not_allowed(
+            "Mutations must be sent over POST, not GET",
+        )));
+    }
+
     let start_time = Instant::now();
     let metrics = &state.metrics;
 
@@ -479,8 +745,9 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
         }
     }
 
-    // Validate request
-    let validator = &state.validator;
+    // Validate request against the limits in effect for the caller's roles.
+    let roles = security_context.as_ref().map_or(&[][..], |ctx| ctx.roles.as_slice());
+    let validator = state.validator.for_roles(roles);
 
     // Parse the GraphQL document exactly once at the handler boundary so the
     // validator can walk the AST without re-parsing. The matcher's downstream
@@ -518,18 +785,6 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
         }
 
         let graphql_error = match e {
-            crate::validation::ComplexityValidationError::QueryTooDeep {
-                max_depth,
-                actual_depth,
-            } => GraphQLError::validation(format!(
-                "Query exceeds maximum depth: {actual_depth} > {max_depth}"
-            )),
-            crate::validation::ComplexityValidationError::QueryTooComplex {
-                max_complexity,
-                actual_complexity,
-            } => GraphQLError::validation(format!(
-                "Query exceeds maximum complexity: {actual_complexity} > {max_complexity}"
-            )),
             crate::validation::ComplexityValidationError::MalformedQuery(msg) => {
                 metrics.parse_errors_total.fetch_add(1, Ordering::Relaxed);
                 GraphQLError::parse(msg)
@@ -537,14 +792,12 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
             crate::validation::ComplexityValidationError::InvalidVariables(msg) => {
                 GraphQLError::request(msg)
             },
-            crate::validation::ComplexityValidationError::TooManyAliases {
-                max_aliases,
-                actual_aliases,
-            } => GraphQLError::validation(format!(
-                "Query exceeds maximum alias count: {actual_aliases} > {max_aliases}"
-            )),
-            // Reason: non_exhaustive requires catch-all for cross-crate matches
-            _ => GraphQLError::validation("Validation error"),
+            ref limit_error => match limit_error.violated_limit() {
+                Some((name, max, actual)) => {
+                    GraphQLError::limit_exceeded(limit_error.to_string(), name, max, actual)
+                },
+                None => GraphQLError::validation("Validation error"),
+            },
         };
         return Err(ErrorResponse::from_error(graphql_error));
     }
@@ -674,10 +927,66 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
         request.variables
     };
 
-    // Execute query (defer error propagation to record circuit breaker outcome first)
-    let executor = state
-        .executor_for_tenant(tenant_key.as_deref())
-        .map_err(|e| ErrorResponse::from_error(tenant_dispatch_error(&e)))?;
+    // Field encryption (H12): reject filters on encrypted fields, then encrypt the values a
+    // mutation writes. Runs after the before-mutation hooks so they see plaintext. A query
+    // that fails to parse here is left for the executor to reject.
+    #[cfg(feature = "secrets")]
+    let encrypted_operation = match state.field_encryption {
+        Some(ref encryption) if encryption.has_encrypted_fields() => {
+            fraiseql_core::graphql::parse_query(&query).ok()
+        },
+        _ => None,
+    };
+    #[cfg(feature = "secrets")]
+    let variables = if let (Some(encryption), Some(parsed)) =
+        (state.field_encryption.as_ref(), encrypted_operation.as_ref())
+    {
+        let encryption_error = |e: crate::secrets_manager::SecretsError| {
+            let err = match e {
+                crate::secrets_manager::SecretsError::ValidationError(msg) => {
+                    GraphQLError::validation(msg)
+                },
+                other => {
+                    error!(error = %other, "Field encryption failed");
+                    state
+                        .error_sanitizer
+                        .sanitize(GraphQLError::internal("Field encryption failed".to_string()))
+                },
+            };
+            ErrorResponse::from_error(err)
+        };
+        encryption.check_filters(parsed, variables.as_ref()).map_err(encryption_error)?;
+        let mut variables = variables.unwrap_or(serde_json::Value::Null);
+        encryption
+            .encrypt_mutation_input(parsed, &mut variables)
+            .await
+            .map_err(encryption_error)?;
+        (!variables.is_null()).then_some(variables)
+    } else {
+        variables
+    };
+
+    // Statically configured tenants (`[tenancy.tenants]`) are loaded on first use.
+    if let (Some(key), Some(registry)) = (tenant_key.as_deref(), state.tenant_registry()) {
+        registry.ensure_loaded(key).await.map_err(|e| {
+            // The cause (schema path, connection error) stays in the server log.
+            error!(tenant = key, error = %e, "Failed to load configured tenant");
+            ErrorResponse::from_error(GraphQLError::service_unavailable(
+                format!("Tenant '{key}' is unavailable"),
+                None,
+            ))
+        })?;
+    }
+
+    // Execute query (defer error propagation to record circuit breaker outcome first).
+    // Under a canary rollout, the request may be routed to the candidate schema.
+    let canary = state.canary.as_ref().map(|canary| (canary, canary.route(headers)));
+    let executor = match canary {
+        Some((canary, SchemaVariant::Candidate)) => canary.candidate(),
+        _ => state
+            .executor_for_tenant(tenant_key.as_deref())
+            .map_err(|e| ErrorResponse::from_error(tenant_dispatch_error(&e)))?,
+    };
 
     // M-quotas: enforce the per-tenant concurrency limit. Only an explicit,
     // registered tenant key carries a limit — the default (`None`) executor is
@@ -719,14 +1028,136 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
         }
     }
 
+    // Bulkhead: cap concurrent executions of one operation and of each cost class
+    // so a stampede on a single expensive query cannot take every database
+    // connection. The operation name is client-controlled, so a named request
+    // also takes a slot in its cost class and must get both. The request waits in
+    // each compartment's bounded queue; beyond it, or after the queue timeout, it
+    // is rejected → HTTP 429. The permits are held until the request completes.
+    // The cost is only estimated when cost classes are configured.
+    let mut bulkhead_permits = Vec::new();
+    if let Some(bulkhead) = state.bulkhead.as_ref() {
+        let compartments = bulkhead.compartments(request.operation_name.as_deref(), || {
+            parse_graphql_document(&query)
+                .ok()
+                .map(|doc| estimate_query_cost(&doc, &executor.schema().operation_cost_weights))
+        });
+        for compartment in compartments {
+            bulkhead_permits.push(bulkhead.acquire(compartment).await.map_err(|rejection| {
+                warn!(
+                    compartment = compartment.name(),
+                    reason = %rejection,
+                    "Bulkhead rejected request"
+                );
+                ErrorResponse::from_error(GraphQLError::rate_limited(format!(
+                    "Too many concurrent executions of {}; retry later",
+                    compartment.name()
+                )))
+            })?);
+        }
+    }
+
+    // Whether the response may be shared between callers (GET cache headers).
+    let authenticated = security_context.is_some();
     // Preserve subject for audit logging before security_context is consumed.
     #[cfg(feature = "auth")]
     let audit_subject = security_context.as_ref().map(|ctx| ctx.user_id.to_string());
-    let exec_result = if let Some(sec_ctx) = security_context {
-        executor.execute_with_security(&query, variables.as_ref(), &sec_ctx).await
-    } else {
-        executor.execute(&query, variables.as_ref()).await
+    // Decide up front whether this request lands in the operation audit log, so
+    // unsampled requests and queries skip the extra parse entirely.
+    let audited_mutation = state
+        .operation_audit
+        .as_ref()
+        .filter(|audit| audit.should_sample())
+        .and_then(|_| detect_mutation_name(&query))
+        .map(|mutation_name| {
+            let caller = security_context.as_ref();
+            (
+                mutation_name,
+                caller.map(|ctx| ctx.user_id.to_string()),
+                caller.and_then(|ctx| ctx.tenant_id.as_ref().map(ToString::to_string)),
+            )
+        });
+    // `extensions.transaction: true` runs every top-level mutation of the
+    // operation in one database transaction (all-or-nothing).
+    let transactional = request
+        .extensions
+        .as_ref()
+        .and_then(|ext| ext.get("transaction"))
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    // Decide up front whether a successful result is mirrored to the `[shadow]`
+    // executor: only sampled read queries on the current schema of the default
+    // executor, so the shadow compares like with like.
+    let shadowed = state
+        .shadow
+        .as_ref()
+        .filter(|_| tenant_key.is_none() && !transactional)
+        .filter(|_| !matches!(canary, Some((_, SchemaVariant::Candidate))))
+        .filter(|shadow| shadow.should_sample())
+        .filter(|_| detect_mutation_name(&query).is_none())
+        .map(|shadow| (shadow, security_context.clone()));
+    let execution =
+        fraiseql_core::runtime::mutation_transaction::with_transaction(transactional, async {
+            if let Some(sec_ctx) = security_context {
+                executor.execute_with_security(&query, variables.as_ref(), &sec_ctx).await
+            } else {
+                executor.execute(&query, variables.as_ref()).await
+            }
+        });
+    let disconnect = DisconnectGuard::new(metrics);
+    let exec_result = match deadline {
+        Some(deadline) => fraiseql_core::runtime::budget::with_deadline(deadline, execution).await,
+        None => execution.await,
     };
+    disconnect.finish();
+
+    if let Some((canary, variant)) = canary {
+        canary.record(variant, start_time.elapsed(), exec_result.is_ok());
+    }
+
+    if let (Some((shadow, security_context)), Ok(primary)) = (shadowed, exec_result.as_ref()) {
+        shadow.mirror(MirroredQuery {
+            query: query.clone(),
+            variables: variables.clone(),
+            operation_name: request.operation_name.clone(),
+            security_context,
+            primary: primary.clone(),
+        });
+    }
+
+    if let (Some(audit), Some((mutation_name, user_id, tenant_id))) =
+        (state.operation_audit.as_ref(), audited_mutation)
+    {
+        use fraiseql_core::security::operation_audit::{
+            OperationAuditRecord, affected_entity_ids, hash_variables,
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        // Reason: millisecond latency cannot exceed u64 in any practical uptime
+        let latency_ms = start_time.elapsed().as_millis() as u64;
+        audit.record(OperationAuditRecord {
+            occurred_at: chrono::Utc::now(),
+            operation_name: request.operation_name.clone(),
+            mutation_name,
+            user_id,
+            tenant_id,
+            variables_hash: hash_variables(variables.as_ref()),
+            entity_ids: exec_result.as_ref().map(affected_entity_ids).unwrap_or_default(),
+            latency_ms,
+            success: exec_result.is_ok(),
+        });
+    }
+
+    // Count selected schema coordinates per client. Only successful requests
+    // count, so rejected probes for fields that no client relies on do not keep
+    // those fields looking alive.
+    if let (Some(field_usage), Ok(_)) = (state.field_usage.as_ref(), exec_result.as_ref()) {
+        if let Ok(doc) = parse_graphql_document(&query) {
+            field_usage.record(
+                &crate::usage::fields::client_id(headers),
+                crate::usage::fields::selected_coordinates(&doc, executor.schema()),
+            );
+        }
+    }
 
     // Record circuit breaker outcome for federation entity queries
     #[cfg(feature = "federation")]
@@ -817,21 +1248,24 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
     );
 
     #[allow(unused_mut)]
-    // Reason: mut is required by decrypt_response(&mut ...) when the secrets feature is enabled
+    // Reason: mut is required by decrypt_operation_response when the secrets feature is enabled
     let mut response_json = result;
 
-    // Decrypt encrypted fields if field encryption is configured
+    // Decrypt the selected encrypted fields if field encryption is configured
     #[cfg(feature = "secrets")]
-    if let Some(ref encryption) = state.field_encryption {
-        if encryption.has_encrypted_fields() {
-            encryption.decrypt_response(&mut response_json).await.map_err(|e| {
+    if let (Some(encryption), Some(parsed)) =
+        (state.field_encryption.as_ref(), encrypted_operation.as_ref())
+    {
+        encryption
+            .decrypt_operation_response(parsed, &mut response_json)
+            .await
+            .map_err(|e| {
                 error!(error = %e, "Field decryption failed");
                 let err = state
                     .error_sanitizer
                     .sanitize(GraphQLError::internal("Field decryption failed".to_string()));
                 ErrorResponse::from_error(err)
             })?;
-        }
     }
 
     // After-mutation function triggers (#460): once the mutation has committed,
@@ -865,8 +1299,21 @@ async fn execute_graphql_request<A: DatabaseAdapter + Clone + Send + Sync + 'sta
         }
     }
 
+    // GET responses carry Cache-Control derived from the queries' TTL hints so
+    // CDNs can serve public read-only queries (notably hash-only persisted
+    // queries, whose URL is short and stable).
+    let cache_control = (method == HttpMethod::Get).then(|| {
+        super::cache_control::graphql_cache_control(
+            executor.schema(),
+            &query,
+            authenticated,
+            &response_json,
+        )
+    });
+
     Ok(GraphQLResponse {
         body: response_json,
+        cache_control,
     })
 }
 
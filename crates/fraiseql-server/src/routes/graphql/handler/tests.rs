@@ -1,4 +1,4 @@
-//! Tests for tenant-dispatch error mapping (#332).
+//! Tests for tenant-dispatch error mapping (#332) and client-disconnect accounting.
 //!
 //! `executor_for_tenant` errors must map to the correct HTTP semantics: a
 //! suspended tenant (`ServiceUnavailable`) → 503 + `Retry-After`, and an unknown
@@ -92,3 +92,21 @@ fn detect_mutation_name_reliably_flags_mutations() {
         "a query selecting a field named like a mutation is not a mutation"
     );
 }
+
+// ── Client disconnect accounting ────────────────────────────────────────────
+
+#[test]
+fn abandoned_execution_counts_a_client_disconnect() {
+    use std::sync::atomic::Ordering;
+
+    use super::DisconnectGuard;
+    use crate::metrics_server::MetricsCollector;
+
+    let metrics = MetricsCollector::new();
+
+    DisconnectGuard::new(&metrics).finish();
+    assert_eq!(metrics.client_disconnects_total.load(Ordering::Relaxed), 0);
+
+    drop(DisconnectGuard::new(&metrics));
+    assert_eq!(metrics.client_disconnects_total.load(Ordering::Relaxed), 1);
+}
@@ -2,9 +2,14 @@
 //!
 //! Supports both POST and GET requests per the GraphQL over HTTP spec:
 //! - POST: JSON body with `query`, `variables`, `operationName`
-//! - GET: Query parameters `query`, `variables` (JSON-encoded), `operationName`
+//! - GET: Query parameters `query`, `variables` (JSON-encoded), `operationName`, `extensions`
+//!   (JSON-encoded; enables hash-only persisted queries)
+//!
+//! POST additionally accepts a JSON array of operations (batching) when
+//! `max_batch_operations` is non-zero.
 
 pub mod app_state;
+mod cache_control;
 pub mod handler;
 pub mod request;
 pub mod tenant_key;
@@ -15,6 +20,6 @@ mod tests;
 
 pub use app_state::AppState;
 pub use handler::{graphql_get_handler, graphql_handler};
-pub use request::{GraphQLGetParams, GraphQLRequest, GraphQLResponse};
+pub use request::{GraphQLBatchRequest, GraphQLGetParams, GraphQLRequest, GraphQLResponse};
 pub use tenant_key::{DomainRegistry, TenantKeyResolver};
-pub use tenant_registry::{TenantExecutorRegistry, TenantStatusSource};
+pub use tenant_registry::{LazyTenant, TenantExecutorRegistry, TenantStatusSource};
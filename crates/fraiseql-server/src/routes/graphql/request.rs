@@ -1,10 +1,19 @@
 //! GraphQL request and response types.
 
+use std::fmt;
+
 use axum::{
     Json,
+    http::{HeaderValue, header},
     response::{IntoResponse, Response},
 };
-use serde::{Deserialize, Serialize};
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{
+        MapAccess, SeqAccess, Visitor,
+        value::{MapAccessDeserializer, SeqAccessDeserializer},
+    },
+};
 
 /// GraphQL request payload (for POST requests).
 #[derive(Debug, Deserialize)]
@@ -23,6 +32,9 @@ pub struct GraphQLRequest {
     pub operation_name: Option<String>,
 
     /// Protocol extensions (APQ, tracing, etc.).
+    ///
+    /// `"transaction": true` runs all top-level mutations of the operation in a
+    /// single database transaction that rolls back if any of them fails.
     #[serde(default)]
     pub extensions: Option<serde_json::Value>,
 
@@ -31,17 +43,57 @@ pub struct GraphQLRequest {
     pub document_id: Option<String>,
 }
 
+/// POST body: either a single operation or a batch of operations.
+///
+/// Batching clients (e.g. Apollo `BatchHttpLink`) send a JSON array of
+/// request objects; everyone else sends a single object. The shape is decided
+/// from the first JSON token, so neither form is buffered or parsed twice.
+#[derive(Debug)]
+pub enum GraphQLBatchRequest {
+    /// A single `{ "query": ... }` object.
+    Single(GraphQLRequest),
+    /// A `[{ "query": ... }, ...]` array, answered by an array in the same order.
+    Batch(Vec<GraphQLRequest>),
+}
+
+impl<'de> Deserialize<'de> for GraphQLBatchRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BatchVisitor;
+
+        impl<'de> Visitor<'de> for BatchVisitor {
+            type Value = GraphQLBatchRequest;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a GraphQL request object or an array of request objects")
+            }
+
+            fn visit_map<M: MapAccess<'de>>(self, map: M) -> Result<Self::Value, M::Error> {
+                GraphQLRequest::deserialize(MapAccessDeserializer::new(map))
+                    .map(GraphQLBatchRequest::Single)
+            }
+
+            fn visit_seq<S: SeqAccess<'de>>(self, seq: S) -> Result<Self::Value, S::Error> {
+                Vec::deserialize(SeqAccessDeserializer::new(seq)).map(GraphQLBatchRequest::Batch)
+            }
+        }
+
+        deserializer.deserialize_any(BatchVisitor)
+    }
+}
+
 /// GraphQL GET request parameters.
 ///
 /// Per GraphQL over HTTP spec, GET requests encode parameters in the query string:
-/// - `query`: Required, the GraphQL query string
+/// - `query`: The GraphQL query string (optional for hash-only persisted queries)
 /// - `variables`: Optional, JSON-encoded object
 /// - `operationName`: Optional, name of the operation to execute
+/// - `extensions`: Optional, JSON-encoded object (APQ `persistedQuery`, etc.)
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GraphQLGetParams {
-    /// GraphQL query string (required).
-    pub query: String,
+    /// GraphQL query string (optional when using APQ with hash-only request).
+    #[serde(default)]
+    pub query: Option<String>,
 
     /// Query variables as JSON-encoded string (optional).
     #[serde(default)]
@@ -50,6 +102,10 @@ pub struct GraphQLGetParams {
     /// Operation name (optional).
     #[serde(default)]
     pub operation_name: Option<String>,
+
+    /// Protocol extensions as JSON-encoded string (optional).
+    #[serde(default)]
+    pub extensions: Option<String>,
 }
 
 /// GraphQL response payload.
@@ -58,10 +114,24 @@ pub struct GraphQLResponse {
     /// Response data or errors.
     #[serde(flatten)]
     pub body: serde_json::Value,
+
+    /// `Cache-Control` to send with the response (GET requests only).
+    ///
+    /// When set, `Vary` is sent alongside it so shared caches key on the
+    /// caller's credentials and tenant.
+    #[serde(skip)]
+    pub cache_control: Option<HeaderValue>,
 }
 
 impl IntoResponse for GraphQLResponse {
     fn into_response(self) -> Response {
-        Json(self.body).into_response()
+        let mut response = Json(self.body).into_response();
+        if let Some(cache_control) = self.cache_control {
+            let headers = response.headers_mut();
+            headers.insert(header::CACHE_CONTROL, cache_control);
+            headers
+                .insert(header::VARY, HeaderValue::from_static(super::cache_control::GRAPHQL_VARY));
+        }
+        response
     }
 }
@@ -3,21 +3,37 @@
 //! Maps tenant keys to individual `Executor<A>` instances, each holding its own
 //! compiled schema and database adapter. Reads are lock-free via `ArcSwap`;
 //! writes are serialized per-key via `DashMap`.
-
-use std::sync::{
-    Arc,
-    atomic::{AtomicBool, AtomicU8, Ordering},
+//!
+//! Tenants declared statically in `[tenancy.tenants]` are registered lazily:
+//! [`TenantExecutorRegistry::ensure_loaded`] builds the executor on the first
+//! request for the key and, when a cap is configured, unloads the
+//! least-recently-used configured tenant. When a schema public key is
+//! configured, each tenant's schema file must carry a valid signature.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering},
+    },
 };
 
 use arc_swap::ArcSwap;
 use dashmap::DashMap;
-use fraiseql_core::{db::traits::DatabaseAdapter, runtime::Executor};
+use fraiseql_core::{
+    db::traits::DatabaseAdapter,
+    runtime::Executor,
+    schema::{SchemaVerifyingKey, verify_artifact_signature},
+};
 use fraiseql_error::FraiseQLError;
 use serde::Deserialize;
 use tokio::sync::Semaphore;
+use tracing::info;
 
 #[cfg(feature = "auth")]
 use crate::auth::rate_limiting::{AuthRateLimitConfig, Clock, KeyedRateLimiter};
+use crate::tenancy::{TenantExecutorFactory, TenantPoolConfig};
 
 /// Tenant lifecycle status.
 ///
@@ -111,6 +127,11 @@ struct TenantEntry<A: DatabaseAdapter> {
     quota_exceeded: AtomicBool,
     /// Quota configuration (cloned from registration request).
     quota:          TenantQuota,
+    /// Registry clock value at the last dispatch (for LRU eviction).
+    last_used:      AtomicU64,
+    /// Loaded from `[tenancy.tenants]` and therefore evictable; cleared when
+    /// the admin API replaces the executor.
+    lazy:           AtomicBool,
 }
 
 impl<A: DatabaseAdapter> TenantEntry<A> {
@@ -123,6 +144,8 @@ impl<A: DatabaseAdapter> TenantEntry<A> {
             rps: None,
             quota_exceeded: AtomicBool::new(false),
             quota: TenantQuota::default(),
+            last_used: AtomicU64::new(0),
+            lazy: AtomicBool::new(false),
         }
     }
 
@@ -164,6 +187,24 @@ impl<A: DatabaseAdapter> TenantEntry<A> {
 /// Default retry hint (seconds) when a suspended tenant is accessed.
 const SUSPENDED_RETRY_AFTER_SECS: u64 = 60;
 
+/// A statically configured tenant, loaded on first use.
+#[derive(Debug, Clone)]
+pub struct LazyTenant {
+    /// Path to the tenant's compiled schema JSON.
+    pub schema_path: PathBuf,
+    /// Connection configuration for the tenant's pool.
+    pub pool:        TenantPoolConfig,
+}
+
+/// Lazily loaded tenants plus the factory that builds their executors.
+struct LazyCatalog<A: DatabaseAdapter> {
+    tenants:    HashMap<String, LazyTenant>,
+    factory:    TenantExecutorFactory<A>,
+    max_loaded: Option<usize>,
+    /// Per-key load locks so concurrent first requests build one executor.
+    loading:    DashMap<String, Arc<tokio::sync::Mutex<()>>>,
+}
+
 /// Registry mapping tenant keys to executors.
 ///
 /// Each tenant gets its own `TenantEntry` holding an `ArcSwap<Executor<A>>` and
@@ -178,9 +219,15 @@ const SUSPENDED_RETRY_AFTER_SECS: u64 = 60;
 /// serve the wrong tenant's data.
 pub struct TenantExecutorRegistry<A: DatabaseAdapter> {
     /// Default executor used when no tenant key is provided (single-tenant compat).
-    default: Arc<ArcSwap<Executor<A>>>,
+    default:           Arc<ArcSwap<Executor<A>>>,
     /// Per-tenant entries keyed by tenant identifier.
-    tenants: DashMap<String, TenantEntry<A>>,
+    tenants:           DashMap<String, TenantEntry<A>>,
+    /// Statically configured tenants loaded on demand (`None` when none are configured).
+    lazy:              Option<LazyCatalog<A>>,
+    /// Monotonic dispatch counter backing `TenantEntry::last_used`.
+    clock:             AtomicU64,
+    /// Public key configured tenant schemas must be signed with (PEM or base64).
+    schema_public_key: Option<String>,
 }
 
 impl<A: DatabaseAdapter> TenantExecutorRegistry<A> {
@@ -190,7 +237,134 @@ impl<A: DatabaseAdapter> TenantExecutorRegistry<A> {
         Self {
             default,
             tenants: DashMap::new(),
+            lazy: None,
+            clock: AtomicU64::new(0),
+            schema_public_key: None,
+        }
+    }
+
+    /// Require configured tenants' schema files to be signed with `key` (PEM or
+    /// base64 Ed25519 public key). `None` leaves signatures unchecked.
+    #[must_use]
+    pub fn with_schema_public_key(mut self, key: Option<String>) -> Self {
+        self.schema_public_key = key;
+        self
+    }
+
+    /// Register statically configured tenants that are loaded on first use.
+    ///
+    /// `factory` builds each executor from the tenant's schema file and pool
+    /// configuration. With `max_loaded`, loading a tenant beyond the cap
+    /// unloads the least-recently-used active configured tenant; tenants
+    /// registered through [`upsert`](Self::upsert) are never evicted.
+    #[must_use]
+    pub fn with_lazy_tenants(
+        mut self,
+        tenants: HashMap<String, LazyTenant>,
+        factory: TenantExecutorFactory<A>,
+        max_loaded: Option<usize>,
+    ) -> Self {
+        self.lazy = Some(LazyCatalog {
+            tenants,
+            factory,
+            max_loaded,
+            loading: DashMap::new(),
+        });
+        self
+    }
+
+    /// Returns `true` if `key` is a statically configured (lazily loaded) tenant.
+    #[must_use]
+    pub fn is_configured_tenant(&self, key: &str) -> bool {
+        self.lazy.as_ref().is_some_and(|c| c.tenants.contains_key(key))
+    }
+
+    /// Load a statically configured tenant if it is not registered yet.
+    ///
+    /// A no-op for registered tenants and for keys that are not configured —
+    /// [`executor_for`](Self::executor_for) then decides as usual. Concurrent
+    /// calls for the same key build a single executor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::Configuration` if the tenant's schema file cannot
+    /// be read or the schema public key is invalid, `FraiseQLError::Validation`
+    /// if the schema signature does not verify, or any error from the executor
+    /// factory (invalid schema, unreachable database).
+    pub async fn ensure_loaded(&self, key: &str) -> fraiseql_error::Result<()> {
+        if let Some(entry) = self.tenants.get(key) {
+            self.touch(entry.value());
+            return Ok(());
+        }
+        let Some(catalog) = self.lazy.as_ref() else {
+            return Ok(());
+        };
+        let Some(spec) = catalog.tenants.get(key) else {
+            return Ok(());
+        };
+
+        let lock = Arc::clone(catalog.loading.entry(key.to_string()).or_default().value());
+        let _guard = lock.lock().await;
+        if self.tenants.contains_key(key) {
+            return Ok(());
+        }
+
+        let schema_json = tokio::fs::read_to_string(&spec.schema_path).await.map_err(|e| {
+            FraiseQLError::config(format!(
+                "Cannot read schema for tenant '{key}' at {}: {e}",
+                spec.schema_path.display()
+            ))
+        })?;
+        if let Some(public_key) = &self.schema_public_key {
+            let public_key = SchemaVerifyingKey::parse(public_key)
+                .map_err(|e| FraiseQLError::config(format!("schema_public_key: {e}")))?;
+            let hash = verify_artifact_signature(&schema_json, &public_key)?;
+            info!(tenant = key, schema_hash = %hash, "Tenant schema signature verified");
+        }
+        let executor = (catalog.factory)(key.to_string(), schema_json, spec.pool.clone()).await?;
+        let entry = TenantEntry::new(executor);
+        entry.lazy.store(true, Ordering::Relaxed);
+        self.touch(&entry);
+        self.tenants.insert(key.to_string(), entry);
+        info!(tenant = key, "Loaded configured tenant");
+
+        if let Some(max_loaded) = catalog.max_loaded {
+            self.evict_lru(max_loaded);
         }
+        Ok(())
+    }
+
+    /// Unload least-recently-used configured tenants until at most
+    /// `max_loaded` remain. Suspended tenants are kept so their status
+    /// survives.
+    fn evict_lru(&self, max_loaded: usize) {
+        let mut loaded: Vec<(u64, String)> = self
+            .tenants
+            .iter()
+            .filter(|e| {
+                e.value().lazy.load(Ordering::Relaxed) && e.value().status() == TenantStatus::Active
+            })
+            .map(|e| (e.value().last_used.load(Ordering::Relaxed), e.key().clone()))
+            .collect();
+        if loaded.len() <= max_loaded {
+            return;
+        }
+        let excess = loaded.len() - max_loaded;
+        loaded.sort_unstable();
+        for (_, key) in loaded.into_iter().take(excess) {
+            // Re-check under the shard lock: a concurrent admin upsert may have
+            // taken ownership of the tenant since the scan.
+            if self.tenants.remove_if(&key, |_, e| e.lazy.load(Ordering::Relaxed)).is_some() {
+                info!(tenant = %key, max_loaded, "Evicted least-recently-used tenant");
+            }
+        }
+    }
+
+    /// Record a dispatch to `entry` for LRU bookkeeping.
+    fn touch(&self, entry: &TenantEntry<A>) {
+        entry
+            .last_used
+            .store(self.clock.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
     }
 
     /// Returns the executor for the given tenant key.
@@ -216,6 +390,7 @@ impl<A: DatabaseAdapter> TenantExecutorRegistry<A> {
                     FraiseQLError::unauthorized(format!("Tenant '{key}' is not registered"))
                 })?;
                 self.require_active(key, entry.value())?;
+                self.touch(entry.value());
                 Ok(entry.value().executor.load())
             },
         }
@@ -265,6 +440,7 @@ impl<A: DatabaseAdapter> TenantExecutorRegistry<A> {
         let key = key.into();
         if let Some(existing) = self.tenants.get(&key) {
             existing.value().executor.store(executor);
+            existing.value().lazy.store(false, Ordering::Relaxed);
             false
         } else {
             self.tenants.insert(key, TenantEntry::new(executor));
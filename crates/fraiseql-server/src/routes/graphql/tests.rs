@@ -7,7 +7,7 @@ use fraiseql_core::apq::ApqMetrics;
 use super::handler::extract_ip_from_headers;
 use super::{
     handler::{extract_apq_hash, resolve_apq},
-    request::{GraphQLGetParams, GraphQLRequest},
+    request::{GraphQLBatchRequest, GraphQLGetParams, GraphQLRequest},
 };
 #[cfg(feature = "auth")]
 use crate::auth::rate_limiting::{AuthRateLimitConfig, KeyedRateLimiter};
@@ -40,6 +40,38 @@ fn test_graphql_request_with_variables() {
     assert_eq!(request.variables, Some(serde_json::json!({"id": "123"})),);
 }
 
+#[test]
+fn test_batch_request_single_object() {
+    let json = r#"{"query": "{ users { id } }", "operationName": "Users"}"#;
+    let request: GraphQLBatchRequest = serde_json::from_str(json).unwrap();
+    let GraphQLBatchRequest::Single(request) = request else {
+        panic!("object body must parse as a single request");
+    };
+    assert_eq!(request.operation_name.as_deref(), Some("Users"));
+}
+
+#[test]
+fn test_batch_request_array() {
+    let json = r#"[{"query": "{ users { id } }"}, {"query": "{ posts { id } }"}]"#;
+    let request: GraphQLBatchRequest = serde_json::from_str(json).unwrap();
+    let GraphQLBatchRequest::Batch(requests) = request else {
+        panic!("array body must parse as a batch");
+    };
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].query.as_deref(), Some("{ posts { id } }"));
+}
+
+#[test]
+fn test_batch_request_rejects_scalar_body() {
+    let err = serde_json::from_str::<GraphQLBatchRequest>(r#""{ users { id } }""#).unwrap_err();
+    assert!(err.to_string().contains("GraphQL request object"), "{err}");
+}
+
+#[test]
+fn test_batch_request_rejects_non_object_element() {
+    assert!(serde_json::from_str::<GraphQLBatchRequest>(r#"[{"query": "{ a }"}, 42]"#).is_err());
+}
+
 #[test]
 fn test_graphql_get_params_deserialize() {
     // Simulate URL query params: ?query={users{id}}&operationName=GetUsers
@@ -49,7 +81,7 @@ fn test_graphql_get_params_deserialize() {
     }))
     .unwrap();
 
-    assert_eq!(params.query, "{ users { id } }");
+    assert_eq!(params.query.as_deref(), Some("{ users { id } }"));
     assert_eq!(params.operation_name, Some("GetUsers".to_string()));
     assert!(params.variables.is_none());
 }
@@ -68,6 +100,19 @@ fn test_graphql_get_params_with_variables() {
     assert_eq!(vars["id"], "123");
 }
 
+#[test]
+fn test_graphql_get_params_hash_only() {
+    // Persisted-query GET: no query, hash in JSON-encoded extensions.
+    let params: GraphQLGetParams = serde_json::from_value(serde_json::json!({
+        "extensions": r#"{"persistedQuery":{"version":1,"sha256Hash":"abc123"}}"#
+    }))
+    .unwrap();
+
+    assert!(params.query.is_none());
+    let ext: serde_json::Value = serde_json::from_str(&params.extensions.unwrap()).unwrap();
+    assert_eq!(extract_apq_hash(Some(&ext)), Some("abc123"));
+}
+
 #[test]
 fn test_graphql_get_params_camel_case() {
     // Test camelCase field names
@@ -943,8 +988,9 @@ mod tenant_registry_tests {
     use fraiseql_error::FraiseQLError;
 
     use super::super::tenant_registry::{
-        TenantExecutorRegistry, TenantQuota, TenantStatus, TenantStatusSource,
+        LazyTenant, TenantExecutorRegistry, TenantQuota, TenantStatus, TenantStatusSource,
     };
+    use crate::tenancy::{TenantExecutorFactory, TenantPoolConfig};
 
     /// Minimal no-op database adapter for unit tests.
     #[derive(Debug, Clone)]
@@ -1426,4 +1472,144 @@ mod tenant_registry_tests {
         let pb = registry.try_acquire_concurrency("tenant-b").unwrap();
         assert!(pb.is_some());
     }
+
+    // ── Lazily loaded configured tenants ([tenancy.tenants]) ──────────────
+
+    fn lazy_catalog(
+        dir: &tempfile::TempDir,
+        keys: &[&str],
+    ) -> std::collections::HashMap<String, LazyTenant> {
+        keys.iter()
+            .map(|key| {
+                let schema_path = dir.path().join(format!("{key}.compiled.json"));
+                std::fs::write(&schema_path, "{}").unwrap();
+                let tenant = LazyTenant {
+                    schema_path,
+                    pool: TenantPoolConfig::new(format!("postgres://localhost/{key}")),
+                };
+                ((*key).to_string(), tenant)
+            })
+            .collect()
+    }
+
+    fn stub_factory() -> TenantExecutorFactory<StubAdapter> {
+        Arc::new(|_key, _schema_json, _pool| Box::pin(async { Ok(tenant_executor("lazy")) }))
+    }
+
+    #[tokio::test]
+    async fn test_ensure_loaded_builds_configured_tenant_on_first_use() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = TenantExecutorRegistry::new(default_executor()).with_lazy_tenants(
+            lazy_catalog(&dir, &["acme"]),
+            stub_factory(),
+            None,
+        );
+        assert!(registry.is_configured_tenant("acme"));
+        assert!(registry.executor_for(Some("acme")).is_err(), "not loaded before first use");
+
+        registry.ensure_loaded("acme").await.unwrap();
+        assert_eq!(registry.executor_for(Some("acme")).unwrap().schema().queries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ensure_loaded_ignores_unconfigured_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = TenantExecutorRegistry::new(default_executor()).with_lazy_tenants(
+            lazy_catalog(&dir, &["acme"]),
+            stub_factory(),
+            None,
+        );
+        registry.ensure_loaded("unknown").await.unwrap();
+        assert!(!registry.is_configured_tenant("unknown"));
+        assert_eq!(registry.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ensure_loaded_reports_missing_schema_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = lazy_catalog(&dir, &["acme"]);
+        std::fs::remove_file(dir.path().join("acme.compiled.json")).unwrap();
+        let registry = TenantExecutorRegistry::new(default_executor()).with_lazy_tenants(
+            catalog,
+            stub_factory(),
+            None,
+        );
+        let err = registry.ensure_loaded("acme").await.unwrap_err();
+        assert!(
+            matches!(err, FraiseQLError::Configuration { .. }),
+            "Expected Configuration error, got: {err:?}"
+        );
+        assert_eq!(registry.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ensure_loaded_verifies_schema_signature_when_key_configured() {
+        use base64::Engine as _;
+        use fraiseql_core::schema::{SchemaSigningKey, seal_artifact};
+
+        let signing_key =
+            SchemaSigningKey::parse(&base64::engine::general_purpose::STANDARD.encode([1_u8; 32]))
+                .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = lazy_catalog(&dir, &["signed", "unsigned"]);
+        let (sealed, _) = seal_artifact(&serde_json::json!({}), Some(&signing_key)).unwrap();
+        std::fs::write(dir.path().join("signed.compiled.json"), sealed).unwrap();
+        let registry = TenantExecutorRegistry::new(default_executor())
+            .with_schema_public_key(Some(signing_key.verifying_key().to_base64()))
+            .with_lazy_tenants(catalog, stub_factory(), None);
+
+        let err = registry.ensure_loaded("unsigned").await.unwrap_err();
+        assert!(
+            matches!(err, FraiseQLError::Validation { .. }),
+            "Expected Validation error, got: {err:?}"
+        );
+        assert_eq!(registry.len(), 0, "an unsigned schema must not be loaded");
+
+        registry.ensure_loaded("signed").await.unwrap();
+        assert!(registry.executor_for(Some("signed")).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lazy_tenants_evicted_least_recently_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = TenantExecutorRegistry::new(default_executor()).with_lazy_tenants(
+            lazy_catalog(&dir, &["a", "b", "c"]),
+            stub_factory(),
+            Some(2),
+        );
+        registry.ensure_loaded("a").await.unwrap();
+        registry.ensure_loaded("b").await.unwrap();
+        // Dispatch to "a" so that "b" becomes the least recently used.
+        registry.executor_for(Some("a")).unwrap();
+        registry.ensure_loaded("c").await.unwrap();
+
+        assert_eq!(registry.len(), 2);
+        assert!(registry.executor_for(Some("a")).is_ok());
+        assert!(registry.executor_for(Some("b")).is_err(), "LRU tenant must be unloaded");
+        assert!(registry.executor_for(Some("c")).is_ok());
+
+        // An evicted tenant is reloaded on its next request.
+        registry.ensure_loaded("b").await.unwrap();
+        assert!(registry.executor_for(Some("b")).is_ok());
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_eviction_skips_admin_registered_and_suspended_tenants() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = TenantExecutorRegistry::new(default_executor()).with_lazy_tenants(
+            lazy_catalog(&dir, &["a", "b"]),
+            stub_factory(),
+            Some(1),
+        );
+        registry.upsert("admin", tenant_executor("admin"));
+        registry.ensure_loaded("a").await.unwrap();
+        registry.suspend("a").unwrap();
+        registry.ensure_loaded("b").await.unwrap();
+
+        assert_eq!(registry.len(), 3);
+        assert!(registry.executor_for(Some("admin")).is_ok());
+        assert!(registry.is_suspended("a"), "suspension must survive eviction pressure");
+        assert!(registry.executor_for(Some("b")).is_ok());
+    }
 }
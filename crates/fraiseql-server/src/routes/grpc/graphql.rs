@@ -0,0 +1,507 @@
+//! gRPC GraphQL endpoint — `Execute` and server-streaming `Subscribe`.
+//!
+//! Exposes the GraphQL surface to gRPC clients as the `fraiseql.graphql.v1.GraphQL`
+//! service. Unlike [`DynamicGrpcService`](super::DynamicGrpcService), which maps
+//! row-shaped views onto generated protobuf messages, these RPCs carry GraphQL
+//! documents and JSON-encoded results, so they need no descriptor file.
+//!
+//! Both RPCs are plain axum handlers mounted on the main router next to the HTTP
+//! GraphQL and `WebSocket` routes. They therefore run behind the same auth middleware
+//! and rate limiter, execute through the same executor path (APQ, tenancy, query
+//! limits, metrics), and share the subscription admission checks of the `/ws` route.
+//! gRPC requires HTTP/2; the HTTP listener accepts it over TLS (ALPN `h2`) or
+//! cleartext prior-knowledge (h2c).
+//!
+//! ```text
+//! syntax = "proto3";
+//! package fraiseql.graphql.v1;
+//!
+//! service GraphQL {
+//!   rpc Execute(ExecuteRequest) returns (ExecuteResponse);
+//!   rpc Subscribe(ExecuteRequest) returns (stream ExecuteResponse);
+//! }
+//!
+//! message ExecuteRequest {
+//!   string query          = 1;
+//!   string variables_json = 2; // JSON object; empty for none
+//!   string operation_name = 3;
+//! }
+//!
+//! message ExecuteResponse {
+//!   string data_json       = 1; // JSON value; empty when absent
+//!   string errors_json     = 2; // JSON array; empty when no errors
+//!   string extensions_json = 3; // JSON object; empty when absent
+//! }
+//! ```
+
+use std::{convert::Infallible, sync::Arc};
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderMap, HeaderValue, StatusCode},
+    response::Response,
+};
+use bytes::Bytes;
+use fraiseql_core::{
+    db::traits::DatabaseAdapter,
+    runtime::{SubscriptionId, SubscriptionPayload, protocol::GraphQLError},
+};
+use futures::stream;
+use http_body::Frame;
+use prost::Message as _;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::{
+    error::ErrorResponse,
+    extractors::{OptionalSecurityContext, PeerIp},
+    routes::{
+        graphql::{AppState, GraphQLRequest, handler::execute_graphql_request},
+        subscriptions::{
+            SubscriptionState, authorize_subscription, extract_subscription_name,
+            resolve_subscription_principal, resolve_subscription_tenant, start_local_subscription,
+        },
+    },
+    tracing_utils,
+};
+
+/// Fully-qualified gRPC service name.
+pub const GRAPHQL_SERVICE_NAME: &str = "fraiseql.graphql.v1.GraphQL";
+
+/// Route path of the unary `Execute` RPC.
+pub const EXECUTE_PATH: &str = "/fraiseql.graphql.v1.GraphQL/Execute";
+
+/// Route path of the server-streaming `Subscribe` RPC.
+pub const SUBSCRIBE_PATH: &str = "/fraiseql.graphql.v1.GraphQL/Subscribe";
+
+/// Request message shared by `Execute` and `Subscribe`.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct ExecuteRequest {
+    /// GraphQL document.
+    #[prost(string, tag = "1")]
+    pub query:          String,
+    /// JSON-encoded variables object (empty for none).
+    #[prost(string, tag = "2")]
+    pub variables_json: String,
+    /// Operation name (empty for none).
+    #[prost(string, tag = "3")]
+    pub operation_name: String,
+}
+
+/// Response message: one per `Execute` call, one per event on `Subscribe`.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct ExecuteResponse {
+    /// JSON-encoded `data` (empty when absent).
+    #[prost(string, tag = "1")]
+    pub data_json:       String,
+    /// JSON-encoded `errors` array (empty when there are no errors).
+    #[prost(string, tag = "2")]
+    pub errors_json:     String,
+    /// JSON-encoded `extensions` object (empty when absent).
+    #[prost(string, tag = "3")]
+    pub extensions_json: String,
+}
+
+impl ExecuteResponse {
+    /// Split a GraphQL response body into its JSON-encoded members.
+    pub(super) fn from_body(body: &serde_json::Value) -> Self {
+        let member = |key: &str| {
+            body.get(key)
+                .filter(|v| !v.is_null())
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        };
+        Self {
+            data_json:       member("data"),
+            errors_json:     member("errors"),
+            extensions_json: member("extensions"),
+        }
+    }
+}
+
+/// gRPC handler for `fraiseql.graphql.v1.GraphQL/Execute`.
+///
+/// Decodes the [`ExecuteRequest`] and runs it through the same execution path as
+/// `POST /graphql`. GraphQL errors the HTTP endpoint reports with `200 OK`
+/// (validation, parse, persisted-query-not-found) come back in `errors_json` with
+/// `grpc-status: 0`; transport-level failures map to the matching gRPC status.
+#[doc(hidden)] // Internal-pub: axum route handler wired via Server::build_router.
+pub async fn grpc_execute_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
+    State(state): State<AppState<A>>,
+    headers: HeaderMap,
+    PeerIp(peer_ip): PeerIp,
+    OptionalSecurityContext(security_context): OptionalSecurityContext,
+    body: Bytes,
+) -> Response {
+    let request = match decode_request(&body) {
+        Ok(request) => request,
+        Err(response) => return response,
+    };
+    let request = match into_graphql_request(request) {
+        Ok(request) => request,
+        Err(message) => return grpc_status_response(tonic::Code::InvalidArgument, &message),
+    };
+
+    let trace_context = tracing_utils::extract_trace_context(&headers);
+    match execute_graphql_request(
+        state,
+        request,
+        trace_context,
+        security_context,
+        &headers,
+        &peer_ip,
+    )
+    .await
+    {
+        Ok(response) => unary_response(&ExecuteResponse::from_body(&response.body)),
+        Err(error) => error_response(&error),
+    }
+}
+
+/// gRPC handler for `fraiseql.graphql.v1.GraphQL/Subscribe`.
+///
+/// Each call is one subscription on its own connection id. Admission mirrors a
+/// `subscribe` message on `/ws`: service-account principal, tenant resolution,
+/// lifecycle hooks, the operation authorizer, tenant suspension, and the
+/// server-owned row-visibility policy. Events stream back as [`ExecuteResponse`]
+/// messages until the client cancels or the event channel closes; subscriptions
+/// owned by a remote subgraph are not available over gRPC.
+#[doc(hidden)] // Internal-pub: axum route handler wired via Server::build_router.
+pub async fn grpc_subscribe_handler(
+    State(state): State<SubscriptionState>,
+    headers: HeaderMap,
+    OptionalSecurityContext(security_context): OptionalSecurityContext,
+    body: Bytes,
+) -> Response {
+    let request = match decode_request(&body) {
+        Ok(request) => request,
+        Err(response) => return response,
+    };
+    let variables = match parse_variables(&request.variables_json) {
+        Ok(variables) => variables.unwrap_or_else(|| serde_json::json!({})),
+        Err(message) => return grpc_status_response(tonic::Code::InvalidArgument, &message),
+    };
+
+    let Some(principal) = resolve_subscription_principal(security_context, &headers, &state) else {
+        warn!("gRPC subscription rejected: ambiguous or unmatched service-account secret");
+        return grpc_status_response(tonic::Code::Unauthenticated, "Authentication failed");
+    };
+    let tenant_id = match resolve_subscription_tenant(principal.as_ref(), &headers, &state) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return grpc_status_response(tonic::Code::InvalidArgument, &e.to_string()),
+    };
+
+    let Some(subscription_name) = extract_subscription_name(&request.query) else {
+        return grpc_status_response(
+            tonic::Code::InvalidArgument,
+            "Could not parse subscription query",
+        );
+    };
+    if state.remote_subscription_fields.contains_key(&subscription_name) {
+        return grpc_status_response(
+            tonic::Code::Unimplemented,
+            &format!("Subscription '{subscription_name}' is owned by a remote subgraph"),
+        );
+    }
+
+    let connection_id = uuid::Uuid::new_v4().to_string();
+    if let Err(reason) = state.lifecycle.on_connect(&serde_json::json!({}), &connection_id).await {
+        warn!(connection_id = %connection_id, reason = %reason, "Lifecycle on_connect rejected gRPC subscription");
+        return grpc_status_response(tonic::Code::PermissionDenied, &reason);
+    }
+    // From here on the guard runs `unsubscribe_connection` and `on_disconnect`,
+    // whether admission fails below or the client later drops the stream.
+    let guard = ConnectionGuard {
+        state:         state.clone(),
+        connection_id: connection_id.clone(),
+    };
+
+    // Subscribe to the broadcast before registering so no early event is missed.
+    let events = state.manager.receiver();
+
+    if let Err(error) = authorize_subscription(
+        &state,
+        &connection_id,
+        &subscription_name,
+        &variables,
+        principal.as_ref(),
+    )
+    .await
+    {
+        return admission_failure(&error);
+    }
+    let subscription_id = match start_local_subscription(
+        &state,
+        &connection_id,
+        &subscription_name,
+        variables,
+        tenant_id.as_deref(),
+        principal.as_ref(),
+    )
+    .await
+    {
+        Ok(subscription_id) => subscription_id,
+        Err(error) => return admission_failure(&error),
+    };
+
+    info!(
+        connection_id = %connection_id,
+        subscription = %subscription_name,
+        "gRPC subscription started"
+    );
+
+    let stream_state = SubscribeStream {
+        events,
+        subscription_id,
+        tenant_id,
+        done: false,
+        guard,
+    };
+    let frames = stream::unfold(stream_state, |mut s| async move {
+        if s.done {
+            return None;
+        }
+        loop {
+            match s.events.recv().await {
+                Ok(payload) => {
+                    if s.delivers(&payload) {
+                        let message = event_response(&payload);
+                        return Some((Ok(Frame::data(grpc_frame(&message.encode_to_vec()))), s));
+                    }
+                },
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(lagged = n, "gRPC subscription event receiver lagged");
+                },
+                Err(broadcast::error::RecvError::Closed) => {
+                    s.done = true;
+                    return Some((Ok(Frame::trailers(status_trailers(tonic::Code::Ok))), s));
+                },
+            }
+        }
+    });
+
+    grpc_body_response(Body::new(http_body_util::StreamBody::new(frames)))
+}
+
+/// Per-call state of a `Subscribe` stream.
+struct SubscribeStream {
+    events:          broadcast::Receiver<SubscriptionPayload>,
+    subscription_id: SubscriptionId,
+    tenant_id:       Option<String>,
+    done:            bool,
+    /// Held for the stream's lifetime; dropping it tears the subscription down.
+    guard:           ConnectionGuard,
+}
+
+impl SubscribeStream {
+    /// Whether `payload` belongs to this subscription and may be delivered now.
+    ///
+    /// Applies the same defense-in-depth tenant guard and suspension pause as
+    /// the `/ws` delivery loop.
+    fn delivers(&self, payload: &SubscriptionPayload) -> bool {
+        if payload.subscription_id != self.subscription_id {
+            return false;
+        }
+        let tenant_matches = match (self.tenant_id.as_deref(), payload.event.tenant_id.as_deref()) {
+            (Some(conn_tid), Some(evt_tid)) => conn_tid == evt_tid,
+            _ => true,
+        };
+        let tenant_active =
+            match (self.tenant_id.as_deref(), self.guard.state.tenant_status_source.as_ref()) {
+                (Some(tid), Some(src)) => !src.is_suspended(tid),
+                _ => true,
+            };
+        tenant_matches && tenant_active
+    }
+}
+
+/// Tears down a gRPC subscription's connection when the stream is dropped.
+struct ConnectionGuard {
+    state:         SubscriptionState,
+    connection_id: String,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.state.manager.unsubscribe_connection(&self.connection_id);
+        let lifecycle = Arc::clone(&self.state.lifecycle);
+        let connection_id = std::mem::take(&mut self.connection_id);
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                lifecycle.on_disconnect(&connection_id).await;
+                debug!(connection_id = %connection_id, "gRPC subscription closed");
+            });
+        }
+    }
+}
+
+/// Build the streamed message for one subscription event.
+///
+/// Matches the `/ws` `next` payload: `data` is keyed by the subscription field
+/// and a Change-Spine envelope (#425) rides in `extensions.changeSpine`.
+pub(super) fn event_response(payload: &SubscriptionPayload) -> ExecuteResponse {
+    let data = serde_json::json!({ payload.subscription_name.clone(): payload.data });
+    ExecuteResponse {
+        data_json:       data.to_string(),
+        errors_json:     String::new(),
+        extensions_json: payload
+            .event
+            .change_spine
+            .as_ref()
+            .map(|envelope| serde_json::json!({ "changeSpine": envelope }).to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// Decode a single length-prefixed, uncompressed [`ExecuteRequest`] message.
+pub(super) fn decode_request(body: &[u8]) -> Result<ExecuteRequest, Response> {
+    let Some((header, message)) = body.split_at_checked(5) else {
+        return Err(grpc_status_response(tonic::Code::InvalidArgument, "Request body too short"));
+    };
+    if header[0] != 0 {
+        return Err(grpc_status_response(
+            tonic::Code::Unimplemented,
+            "Compressed gRPC messages are not supported",
+        ));
+    }
+    let declared = u32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+    if usize::try_from(declared).ok() != Some(message.len()) {
+        return Err(grpc_status_response(
+            tonic::Code::InvalidArgument,
+            "gRPC message length does not match the request body",
+        ));
+    }
+    ExecuteRequest::decode(message).map_err(|e| {
+        grpc_status_response(
+            tonic::Code::InvalidArgument,
+            &format!("Failed to decode request: {e}"),
+        )
+    })
+}
+
+/// Parse `variables_json`; empty means no variables.
+pub(super) fn parse_variables(variables_json: &str) -> Result<Option<serde_json::Value>, String> {
+    if variables_json.trim().is_empty() {
+        return Ok(None);
+    }
+    match serde_json::from_str::<serde_json::Value>(variables_json) {
+        Ok(value @ serde_json::Value::Object(_)) => Ok(Some(value)),
+        Ok(_) => Err("variables_json must encode a JSON object".to_string()),
+        Err(e) => Err(format!("Invalid variables_json: {e}")),
+    }
+}
+
+/// Convert an [`ExecuteRequest`] into the HTTP endpoint's request type.
+fn into_graphql_request(request: ExecuteRequest) -> Result<GraphQLRequest, String> {
+    Ok(GraphQLRequest {
+        query:          Some(request.query).filter(|q| !q.is_empty()),
+        variables:      parse_variables(&request.variables_json)?,
+        operation_name: Some(request.operation_name).filter(|n| !n.is_empty()),
+        extensions:     None,
+        document_id:    None,
+    })
+}
+
+/// Map a failed execution onto a gRPC response.
+///
+/// Errors the HTTP endpoint reports with `200 OK` stay in-band; every other
+/// status becomes the equivalent gRPC status code.
+pub(super) fn error_response(error: &ErrorResponse) -> Response {
+    let status = error
+        .errors
+        .first()
+        .map_or(StatusCode::INTERNAL_SERVER_ERROR, |e| e.code.status_code());
+    if status == StatusCode::OK {
+        let errors = serde_json::to_string(&error.errors).unwrap_or_default();
+        return unary_response(&ExecuteResponse {
+            errors_json: errors,
+            ..ExecuteResponse::default()
+        });
+    }
+    let message = error.errors.first().map_or("Internal server error", |e| e.message.as_str());
+    grpc_status_response(http_status_to_grpc(status), message)
+}
+
+/// Map an HTTP status to the gRPC status code of the same meaning.
+///
+/// Follows the gRPC HTTP-to-gRPC status mapping, refined for the statuses the
+/// GraphQL endpoint produces.
+pub(super) const fn http_status_to_grpc(status: StatusCode) -> tonic::Code {
+    match status.as_u16() {
+        200 => tonic::Code::Ok,
+        400 | 405 | 413 => tonic::Code::InvalidArgument,
+        401 => tonic::Code::Unauthenticated,
+        403 => tonic::Code::PermissionDenied,
+        404 => tonic::Code::NotFound,
+        408 | 504 => tonic::Code::DeadlineExceeded,
+        409 => tonic::Code::Aborted,
+        429 => tonic::Code::ResourceExhausted,
+        501 => tonic::Code::Unimplemented,
+        502 | 503 => tonic::Code::Unavailable,
+        _ => tonic::Code::Internal,
+    }
+}
+
+/// Map a rejected subscription onto a gRPC status, keyed by the error code the
+/// `/ws` route reports for the same rejection.
+fn admission_failure(error: &GraphQLError) -> Response {
+    let code = error
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get("code"))
+        .and_then(serde_json::Value::as_str);
+    let status = match code {
+        Some("FORBIDDEN" | "SUBSCRIPTION_REJECTED" | "SUBSCRIPTION_REFUSED") => {
+            tonic::Code::PermissionDenied
+        },
+        Some("TENANT_MISMATCH" | "SUBSCRIPTION_ERROR") => tonic::Code::InvalidArgument,
+        Some("TENANT_SUSPENDED") => tonic::Code::Unavailable,
+        _ => tonic::Code::Internal,
+    };
+    grpc_status_response(status, &error.message)
+}
+
+/// Encode a protobuf message with gRPC framing (5-byte header).
+pub(super) fn grpc_frame(message: &[u8]) -> Bytes {
+    let mut framed = Vec::with_capacity(5 + message.len());
+    framed.push(0); // no compression
+    framed.extend_from_slice(&u32::try_from(message.len()).unwrap_or(u32::MAX).to_be_bytes());
+    framed.extend_from_slice(message);
+    Bytes::from(framed)
+}
+
+/// Trailers carrying a gRPC status.
+fn status_trailers(code: tonic::Code) -> HeaderMap {
+    let mut trailers = HeaderMap::new();
+    trailers.insert("grpc-status", HeaderValue::from(code as i32));
+    trailers
+}
+
+/// A unary success response: one message followed by `grpc-status: 0` trailers.
+fn unary_response(message: &ExecuteResponse) -> Response {
+    let frames: [Result<Frame<Bytes>, Infallible>; 2] = [
+        Ok(Frame::data(grpc_frame(&message.encode_to_vec()))),
+        Ok(Frame::trailers(status_trailers(tonic::Code::Ok))),
+    ];
+    grpc_body_response(Body::new(http_body_util::StreamBody::new(stream::iter(frames))))
+}
+
+/// Wrap a framed body in a `200 OK` `application/grpc` response.
+fn grpc_body_response(body: Body) -> Response {
+    let mut response = Response::new(body);
+    response
+        .headers_mut()
+        .insert("content-type", HeaderValue::from_static("application/grpc"));
+    response
+}
+
+/// A trailers-only gRPC response carrying a non-OK status.
+fn grpc_status_response(code: tonic::Code, message: &str) -> Response {
+    let mut response = grpc_body_response(Body::empty());
+    response.headers_mut().insert("grpc-status", HeaderValue::from(code as i32));
+    if let Ok(message) = HeaderValue::from_str(message) {
+        response.headers_mut().insert("grpc-message", message);
+    }
+    response
+}
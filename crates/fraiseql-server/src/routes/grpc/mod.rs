@@ -8,6 +8,7 @@
 //! The service is built dynamically from the compiled schema's descriptor pool
 //! at server startup — no generated Rust protobuf code is needed.
 
+pub mod graphql;
 pub mod handler;
 pub mod streaming;
 
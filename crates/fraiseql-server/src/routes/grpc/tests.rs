@@ -411,3 +411,214 @@ fn recase_keys_to_snake_leaves_scalars_and_snake_keys_untouched() {
         json!({ "already_snake": 1 })
     );
 }
+
+// ── GraphQL Execute / Subscribe service ─────────────────────────────
+
+mod graphql_service {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        response::Response,
+        routing::post,
+    };
+    use fraiseql_core::{
+        runtime::{
+            SubscriptionManager,
+            subscription::{SubscriptionEvent, SubscriptionId, SubscriptionOperation},
+        },
+        schema::{CompiledSchema, SubscriptionPolicy},
+    };
+    use http_body_util::BodyExt as _;
+    use prost::Message as _;
+    use serde_json::json;
+    use tower::ServiceExt as _;
+
+    use super::super::graphql::{
+        ExecuteRequest, ExecuteResponse, SUBSCRIBE_PATH, decode_request, error_response,
+        event_response, grpc_frame, grpc_subscribe_handler, http_status_to_grpc, parse_variables,
+    };
+    use crate::{
+        error::{ErrorCode, ErrorResponse, GraphQLError},
+        routes::subscriptions::SubscriptionState,
+    };
+
+    fn framed(request: &ExecuteRequest) -> Vec<u8> {
+        grpc_frame(&request.encode_to_vec()).to_vec()
+    }
+
+    fn grpc_status(response: &Response) -> Option<i32> {
+        response.headers().get("grpc-status")?.to_str().ok()?.parse().ok()
+    }
+
+    /// Collect a unary response: the decoded message and the trailer status.
+    async fn read_unary(response: Response) -> (ExecuteResponse, Option<i32>) {
+        let collected = response.into_body().collect().await.unwrap();
+        let status = collected
+            .trailers()
+            .and_then(|t| t.get("grpc-status"))
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+        let bytes = collected.to_bytes();
+        (ExecuteResponse::decode(&bytes[5..]).unwrap(), status)
+    }
+
+    async fn subscribe(state: SubscriptionState, query: &str) -> Response {
+        let router = axum::Router::new()
+            .route(SUBSCRIBE_PATH, post(grpc_subscribe_handler))
+            .with_state(state);
+        let request = ExecuteRequest {
+            query: query.to_string(),
+            ..ExecuteRequest::default()
+        };
+        router
+            .oneshot(
+                Request::post(SUBSCRIBE_PATH)
+                    .header("content-type", "application/grpc")
+                    .body(Body::from(framed(&request)))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    fn subscription_state() -> SubscriptionState {
+        let manager = Arc::new(SubscriptionManager::new(Arc::new(CompiledSchema::default())));
+        SubscriptionState::new(manager)
+    }
+
+    #[test]
+    fn decode_request_round_trips_a_framed_message() {
+        let request = ExecuteRequest {
+            query:          "{ users { id } }".to_string(),
+            variables_json: r#"{"limit":10}"#.to_string(),
+            operation_name: "Users".to_string(),
+        };
+        assert_eq!(decode_request(&framed(&request)).unwrap(), request);
+    }
+
+    #[test]
+    fn decode_request_rejects_malformed_frames() {
+        let short = decode_request(&[0, 0, 0]).unwrap_err();
+        assert_eq!(grpc_status(&short), Some(tonic::Code::InvalidArgument as i32));
+
+        let mut compressed = framed(&ExecuteRequest::default());
+        compressed[0] = 1;
+        let compressed = decode_request(&compressed).unwrap_err();
+        assert_eq!(grpc_status(&compressed), Some(tonic::Code::Unimplemented as i32));
+
+        let mut truncated = framed(&ExecuteRequest {
+            query: "{ a }".to_string(),
+            ..ExecuteRequest::default()
+        });
+        truncated.pop();
+        let truncated = decode_request(&truncated).unwrap_err();
+        assert_eq!(grpc_status(&truncated), Some(tonic::Code::InvalidArgument as i32));
+    }
+
+    #[test]
+    fn variables_must_be_a_json_object() {
+        assert_eq!(parse_variables("").unwrap(), None);
+        assert_eq!(parse_variables(r#"{"id":1}"#).unwrap(), Some(json!({ "id": 1 })));
+        assert!(parse_variables("[1]").is_err());
+        assert!(parse_variables("{not json").is_err());
+    }
+
+    #[test]
+    fn response_body_splits_into_json_members() {
+        let response = ExecuteResponse::from_body(&json!({
+            "data": { "users": [] },
+            "errors": [{ "message": "partial" }],
+        }));
+        assert_eq!(response.data_json, r#"{"users":[]}"#);
+        assert_eq!(response.errors_json, r#"[{"message":"partial"}]"#);
+        assert!(response.extensions_json.is_empty());
+    }
+
+    #[tokio::test]
+    async fn graphql_errors_reported_with_200_stay_in_band() {
+        let error = ErrorResponse::from_error(GraphQLError::new(
+            "Unknown field 'nope'",
+            ErrorCode::ValidationError,
+        ));
+        let response = error_response(&error);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(grpc_status(&response), None, "status travels in trailers");
+
+        let (message, status) = read_unary(response).await;
+        assert_eq!(status, Some(0));
+        assert!(message.data_json.is_empty());
+        assert!(message.errors_json.contains("Unknown field 'nope'"));
+    }
+
+    #[test]
+    fn transport_errors_map_to_grpc_status() {
+        let error = ErrorResponse::from_error(GraphQLError::new(
+            "Authentication required",
+            ErrorCode::Unauthenticated,
+        ));
+        let response = error_response(&error);
+        assert_eq!(grpc_status(&response), Some(tonic::Code::Unauthenticated as i32));
+        assert_eq!(response.headers()["grpc-message"], "Authentication required");
+
+        assert_eq!(http_status_to_grpc(StatusCode::FORBIDDEN), tonic::Code::PermissionDenied);
+        assert_eq!(
+            http_status_to_grpc(StatusCode::TOO_MANY_REQUESTS),
+            tonic::Code::ResourceExhausted
+        );
+        assert_eq!(http_status_to_grpc(StatusCode::SERVICE_UNAVAILABLE), tonic::Code::Unavailable);
+    }
+
+    #[test]
+    fn event_response_matches_ws_next_payload() {
+        let payload = fraiseql_core::runtime::SubscriptionPayload {
+            subscription_id:   SubscriptionId::new(),
+            subscription_name: "orderUpdated".to_string(),
+            event:             SubscriptionEvent::new(
+                "Order",
+                "ord_1",
+                SubscriptionOperation::Update,
+                json!({ "id": "ord_1" }),
+            ),
+            data:              json!({ "id": "ord_1", "status": "PAID" }),
+        };
+        let message = event_response(&payload);
+        let data: serde_json::Value = serde_json::from_str(&message.data_json).unwrap();
+        assert_eq!(data["orderUpdated"]["status"], "PAID");
+        assert!(message.errors_json.is_empty());
+        assert!(message.extensions_json.is_empty(), "no envelope → no extensions");
+    }
+
+    #[tokio::test]
+    async fn subscribe_rejects_unparseable_query() {
+        let response = subscribe(subscription_state(), "{ not a subscription").await;
+        assert_eq!(grpc_status(&response), Some(tonic::Code::InvalidArgument as i32));
+    }
+
+    #[tokio::test]
+    async fn subscribe_rejects_unknown_subscription() {
+        let response =
+            subscribe(subscription_state(), "subscription { orderUpdated { id } }").await;
+        assert_eq!(grpc_status(&response), Some(tonic::Code::InvalidArgument as i32));
+    }
+
+    #[tokio::test]
+    async fn subscribe_applies_row_visibility_policy_fail_closed() {
+        let mut policies = HashMap::new();
+        policies.insert(
+            "orderUpdated".to_string(),
+            SubscriptionPolicy {
+                owner_path:     "$.owner_id".to_string(),
+                identity_field: "user_id".to_string(),
+                bypass_roles:   vec!["admin".to_string()],
+            },
+        );
+        let state = subscription_state().with_subscription_policies(Arc::new(policies));
+
+        // An anonymous caller cannot resolve the owner identity → refused, exactly
+        // as a `subscribe` on `/ws` is.
+        let response = subscribe(state, "subscription { orderUpdated { id } }").await;
+        assert_eq!(grpc_status(&response), Some(tonic::Code::PermissionDenied as i32));
+    }
+}
@@ -5,7 +5,10 @@ use fraiseql_core::db::traits::DatabaseAdapter;
 use serde::Serialize;
 use tracing::{debug, error};
 
-use crate::routes::graphql::AppState;
+use crate::{
+    readiness::{DependencyStatus, check_dependencies, failing_critical},
+    routes::graphql::AppState,
+};
 
 /// Health check response.
 #[derive(Debug, Serialize)]
@@ -46,6 +49,10 @@ pub struct HealthResponse {
     /// Operators can compare this value across server instances to verify
     /// all instances are running the same schema. Different values indicate
     /// a schema mismatch (e.g. partial rollout or stale deployment).
+    ///
+    /// When the loaded `schema.compiled.json` carries a `_content_hash`, this is
+    /// that artifact hash — the value `fraiseql compile` printed — so a deploy
+    /// can be checked against the build that produced it.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_hash: Option<String>,
 }
@@ -91,14 +98,16 @@ pub struct SecretsHealth {
     pub backend:   String,
 }
 
-/// Readiness response (subset of `HealthResponse`).
+/// Readiness response with per-dependency probe results.
 #[derive(Debug, Serialize)]
 pub struct ReadinessResponse {
     /// `"ready"` or `"not_ready"`.
-    pub status: String,
+    pub status:       String,
     /// Human-readable reason when `status = "not_ready"`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+    pub reason:       Option<String>,
+    /// Outcome of each dependency probe.
+    pub dependencies: Vec<DependencyStatus>,
 }
 
 /// Database status.
@@ -174,7 +183,8 @@ pub async fn health_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
         }
     };
 
-    let schema_hash = Some(executor.schema().content_hash());
+    let schema = executor.schema();
+    let schema_hash = Some(schema.artifact_hash.clone().unwrap_or_else(|| schema.content_hash()));
 
     #[cfg(feature = "federation")]
     let federation = state.circuit_breaker.as_ref().map(|cb| FederationHealth {
@@ -257,8 +267,10 @@ pub async fn health_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>(
 
 /// Readiness probe handler.
 ///
-/// Returns `200 OK` when the server can serve traffic (database reachable),
-/// or `503 Service Unavailable` when it cannot.
+/// Probes every dependency (database, schema, secrets/KMS backend, observer
+/// listener connection, Redis) and returns `200 OK` when all critical ones are
+/// ready, or `503 Service Unavailable` listing those that are not. A passing
+/// probe also opens the startup traffic gate, if configured.
 ///
 /// Kubernetes usage:
 /// - `livenessProbe` → `GET /health` (always 200 while process is alive)
@@ -268,22 +280,29 @@ pub async fn readiness_handler<A: DatabaseAdapter + Clone + Send + Sync + 'stati
 ) -> impl IntoResponse {
     debug!("Readiness check requested");
 
-    let db_healthy = state.executor().adapter().health_check().await.is_ok();
+    let dependencies = check_dependencies(&state).await;
+    let failing = failing_critical(&dependencies);
 
-    if db_healthy {
+    if failing.is_empty() {
+        if let Some(ref gate) = state.readiness_gate {
+            gate.open();
+        }
         (
             StatusCode::OK,
             Json(ReadinessResponse {
                 status: "ready".to_string(),
                 reason: None,
+                dependencies,
             }),
         )
     } else {
+        let reason = format!("Critical dependencies not ready: {}", failing.join(", "));
         (
             StatusCode::SERVICE_UNAVAILABLE,
             Json(ReadinessResponse {
                 status: "not_ready".to_string(),
-                reason: Some("Database connection unavailable".to_string()),
+                reason: Some(reason),
+                dependencies,
             }),
         )
     }
@@ -1,7 +1,12 @@
 //! Schema introspection endpoint.
 
 use axum::{Json, extract::State, response::IntoResponse};
-use fraiseql_core::db::traits::DatabaseAdapter;
+use fraiseql_core::{
+    db::traits::DatabaseAdapter,
+    runtime::can_access_field,
+    schema::{CompiledSchema, FieldDefinition},
+    security::SecurityContext,
+};
 use serde::Serialize;
 use tracing::debug;
 
@@ -30,8 +35,28 @@ pub struct TypeInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Field count.
+    /// Number of fields visible to the caller.
     pub field_count: usize,
+
+    /// Fields visible to the caller.
+    pub fields: Vec<FieldInfo>,
+}
+
+/// Field information.
+#[derive(Debug, Serialize)]
+pub struct FieldInfo {
+    /// Field name.
+    pub name: String,
+
+    /// Field type, in GraphQL notation.
+    pub field_type: String,
+
+    /// Whether the field is nullable.
+    pub nullable: bool,
+
+    /// Field description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// Query information.
@@ -67,10 +92,8 @@ pub struct MutationInfo {
 
 /// Introspection handler.
 ///
-/// Returns schema structure for debugging and tooling.
-/// Types, queries, and mutations with `requires_role` are filtered based on
-/// the caller's roles — hidden types/queries/mutations never appear in
-/// introspection to prevent role enumeration.
+/// Returns schema structure for debugging and tooling, filtered for the caller
+/// by [`build_introspection`].
 ///
 /// # Security Note
 ///
@@ -82,21 +105,53 @@ pub async fn introspection_handler<A: DatabaseAdapter + Clone + Send + Sync + 's
     debug!("Introspection requested");
 
     let executor = state.executor();
-    let schema = executor.schema();
+    Json(build_introspection(executor.schema(), security_context.as_ref()))
+}
 
+/// Build the introspection response visible to `security_context`.
+///
+/// Types, queries, and mutations with `requires_role` are shown only to callers
+/// holding that role, and fields with `requires_scope` only to callers whose roles
+/// grant the scope — hidden entries never appear, so their names cannot be
+/// enumerated. Anonymous callers see only unannotated entries.
+#[must_use]
+pub fn build_introspection(
+    schema: &CompiledSchema,
+    security_context: Option<&SecurityContext>,
+) -> IntrospectionResponse {
     let user_roles: Vec<&str> = security_context
-        .as_ref()
         .map(|ctx| ctx.roles.iter().map(String::as_str).collect())
         .unwrap_or_default();
 
+    let field_visible = |field: &FieldDefinition| {
+        field.requires_scope.is_none()
+            || security_context
+                .zip(schema.security.as_ref())
+                .is_some_and(|(ctx, config)| can_access_field(ctx, config, field))
+    };
+
     let types: Vec<TypeInfo> = schema
         .types
         .iter()
         .filter(|t| t.requires_role.as_ref().is_none_or(|role| user_roles.contains(&role.as_str())))
-        .map(|t| TypeInfo {
-            name:        t.name.to_string(),
-            description: t.description.clone(),
-            field_count: t.fields.len(),
+        .map(|t| {
+            let fields: Vec<FieldInfo> = t
+                .fields
+                .iter()
+                .filter(|f| field_visible(f))
+                .map(|f| FieldInfo {
+                    name:        f.output_name().to_string(),
+                    field_type:  f.field_type.to_graphql_string(),
+                    nullable:    f.nullable,
+                    description: f.description.clone(),
+                })
+                .collect();
+            TypeInfo {
+                name: t.name.to_string(),
+                description: t.description.clone(),
+                field_count: fields.len(),
+                fields,
+            }
         })
         .collect();
 
@@ -123,9 +178,9 @@ pub async fn introspection_handler<A: DatabaseAdapter + Clone + Send + Sync + 's
         })
         .collect();
 
-    Json(IntrospectionResponse {
+    IntrospectionResponse {
         types,
         queries,
         mutations,
-    })
+    }
 }
@@ -149,10 +149,51 @@ pub async fn metrics_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>
                 "\n# HELP fraiseql_apq_stored_total Total APQ queries stored\n",
                 "# TYPE fraiseql_apq_stored_total counter\n",
                 "fraiseql_apq_stored_total {stored}\n",
+                "\n# HELP fraiseql_apq_registration_ratio ",
+                "Share of APQ resolutions that required registering the query\n",
+                "# TYPE fraiseql_apq_registration_ratio gauge\n",
+                "fraiseql_apq_registration_ratio {registration:.4}\n",
             ),
             hits = apq.get_hits(),
             misses = apq.get_misses(),
             stored = apq.get_stored(),
+            registration = apq.registration_rate(),
+        );
+    }
+
+    // Append the client-disconnect counter (operations abandoned mid-execution).
+    {
+        let _ = write!(
+            output,
+            concat!(
+                "\n# HELP fraiseql_client_disconnects_total ",
+                "Total operations abandoned because the client disconnected\n",
+                "# TYPE fraiseql_client_disconnects_total counter\n",
+                "fraiseql_client_disconnects_total {disconnects}\n",
+            ),
+            disconnects = state
+                .metrics
+                .client_disconnects_total
+                .load(std::sync::atomic::Ordering::Relaxed),
+        );
+    }
+
+    // Append PostgreSQL APQ backend counters.
+    {
+        let _ = write!(
+            output,
+            concat!(
+                "\n# HELP fraiseql_apq_postgres_errors_total ",
+                "Total PostgreSQL APQ fail-open events\n",
+                "# TYPE fraiseql_apq_postgres_errors_total counter\n",
+                "fraiseql_apq_postgres_errors_total {errors}\n",
+                "\n# HELP fraiseql_apq_postgres_purged_total ",
+                "Total expired APQ rows removed by TTL cleanup\n",
+                "# TYPE fraiseql_apq_postgres_purged_total counter\n",
+                "fraiseql_apq_postgres_purged_total {purged}\n",
+            ),
+            errors = fraiseql_core::apq::postgres_storage::postgres_apq_error_count_total(),
+            purged = fraiseql_core::apq::postgres_storage::postgres_apq_purged_count_total(),
         );
     }
 
@@ -242,6 +283,54 @@ pub async fn metrics_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>
         );
     }
 
+    // Query plan cache (absent when plan caching is disabled)
+    if let Some(plans) = state.executor().plan_cache_stats() {
+        let _ = write!(
+            output,
+            concat!(
+                "\n# HELP fraiseql_query_plan_cache_hits_total Queries bound from a cached plan\n",
+                "# TYPE fraiseql_query_plan_cache_hits_total counter\n",
+                "fraiseql_query_plan_cache_hits_total {hits}\n",
+                "\n# HELP fraiseql_query_plan_cache_misses_total Queries that prepared a new plan\n",
+                "# TYPE fraiseql_query_plan_cache_misses_total counter\n",
+                "fraiseql_query_plan_cache_misses_total {misses}\n",
+                "\n# HELP fraiseql_query_plan_cache_evictions_total Plans evicted by the LRU bound\n",
+                "# TYPE fraiseql_query_plan_cache_evictions_total counter\n",
+                "fraiseql_query_plan_cache_evictions_total {evictions}\n",
+                "\n# HELP fraiseql_query_plan_cache_entries Plans currently cached\n",
+                "# TYPE fraiseql_query_plan_cache_entries gauge\n",
+                "fraiseql_query_plan_cache_entries {entries}\n",
+            ),
+            hits = plans.hits,
+            misses = plans.misses,
+            evictions = plans.evictions,
+            entries = plans.entries,
+        );
+    }
+
+    // Arrow Flight record batch sizes
+    #[cfg(feature = "arrow")]
+    {
+        let batches = fraiseql_arrow::db_convert::batch_size_stats();
+        let _ = write!(
+            output,
+            concat!(
+                "\n# HELP fraiseql_flight_batches_total Arrow record batches produced by Flight\n",
+                "# TYPE fraiseql_flight_batches_total counter\n",
+                "fraiseql_flight_batches_total {batches}\n",
+                "\n# HELP fraiseql_flight_batch_rows_total Rows across produced Arrow record batches\n",
+                "# TYPE fraiseql_flight_batch_rows_total counter\n",
+                "fraiseql_flight_batch_rows_total {rows}\n",
+                "\n# HELP fraiseql_flight_batch_bytes_total Estimated bytes across produced Arrow record batches\n",
+                "# TYPE fraiseql_flight_batch_bytes_total counter\n",
+                "fraiseql_flight_batch_bytes_total {bytes}\n",
+            ),
+            batches = batches.batches,
+            rows = batches.rows,
+            bytes = batches.bytes,
+        );
+    }
+
     // Database query performance stats (top 5 from pg_stat_statements / equivalent)
     if let Ok(stats) = state.executor().adapter().query_stats(5).await {
         if !stats.is_empty() {
@@ -352,6 +441,130 @@ pub async fn metrics_handler<A: DatabaseAdapter + Clone + Send + Sync + 'static>
         );
     }
 
+    // Canary schema rollout: per-variant request, error and latency counters
+    if let Some(ref canary) = state.canary {
+        use crate::canary::SchemaVariant;
+
+        let stable = canary.stats(SchemaVariant::Stable);
+        let candidate = canary.stats(SchemaVariant::Candidate);
+        let _ = write!(
+            output,
+            concat!(
+                "\n# HELP fraiseql_canary_requests_total GraphQL requests served per schema variant\n",
+                "# TYPE fraiseql_canary_requests_total counter\n",
+                "fraiseql_canary_requests_total{{variant=\"stable\"}} {stable_requests}\n",
+                "fraiseql_canary_requests_total{{variant=\"candidate\"}} {candidate_requests}\n",
+                "\n# HELP fraiseql_canary_errors_total Failed GraphQL requests per schema variant\n",
+                "# TYPE fraiseql_canary_errors_total counter\n",
+                "fraiseql_canary_errors_total{{variant=\"stable\"}} {stable_errors}\n",
+                "fraiseql_canary_errors_total{{variant=\"candidate\"}} {candidate_errors}\n",
+                "\n# HELP fraiseql_canary_latency_microseconds_total ",
+                "Summed GraphQL request latency per schema variant\n",
+                "# TYPE fraiseql_canary_latency_microseconds_total counter\n",
+                "fraiseql_canary_latency_microseconds_total{{variant=\"stable\"}} {stable_latency}\n",
+                "fraiseql_canary_latency_microseconds_total{{variant=\"candidate\"}} ",
+                "{candidate_latency}\n",
+                "\n# HELP fraiseql_canary_traffic_percent ",
+                "Share of unlabelled requests routed to the candidate schema\n",
+                "# TYPE fraiseql_canary_traffic_percent gauge\n",
+                "fraiseql_canary_traffic_percent {traffic_percent}\n",
+            ),
+            stable_requests = stable.requests,
+            candidate_requests = candidate.requests,
+            stable_errors = stable.errors,
+            candidate_errors = candidate.errors,
+            stable_latency = stable.latency_us_total,
+            candidate_latency = candidate.latency_us_total,
+            traffic_percent = canary.traffic_percent(),
+        );
+    }
+
+    // Shadow traffic mirroring: mirrored queries by outcome, and dropped samples
+    if let Some(ref shadow) = state.shadow {
+        let stats = shadow.stats();
+        let _ = write!(
+            output,
+            concat!(
+                "\n# HELP fraiseql_shadow_mirrored_total Read queries re-executed on the shadow\n",
+                "# TYPE fraiseql_shadow_mirrored_total counter\n",
+                "fraiseql_shadow_mirrored_total {mirrored}\n",
+                "\n# HELP fraiseql_shadow_results_total ",
+                "Mirrored query results by comparison outcome\n",
+                "# TYPE fraiseql_shadow_results_total counter\n",
+                "fraiseql_shadow_results_total{{outcome=\"match\"}} {matched}\n",
+                "fraiseql_shadow_results_total{{outcome=\"mismatch\"}} {mismatched}\n",
+                "fraiseql_shadow_results_total{{outcome=\"error\"}} {errors}\n",
+                "\n# HELP fraiseql_shadow_dropped_total ",
+                "Sampled queries dropped because the shadow was saturated\n",
+                "# TYPE fraiseql_shadow_dropped_total counter\n",
+                "fraiseql_shadow_dropped_total {dropped}\n",
+                "\n# HELP fraiseql_shadow_sample_percent Share of read queries mirrored\n",
+                "# TYPE fraiseql_shadow_sample_percent gauge\n",
+                "fraiseql_shadow_sample_percent {sample_percent}\n",
+            ),
+            mirrored = stats.mirrored,
+            matched = stats.matched,
+            mismatched = stats.mismatched,
+            errors = stats.errors,
+            dropped = stats.dropped,
+            sample_percent = shadow.sample_percent(),
+        );
+    }
+
+    // Bulkhead: slots in use, queued requests and rejections per compartment
+    if let Some(ref bulkhead) = state.bulkhead {
+        let stats = bulkhead.stats();
+        output.push_str(concat!(
+            "\n# HELP fraiseql_bulkhead_in_flight Executions holding a bulkhead slot\n",
+            "# TYPE fraiseql_bulkhead_in_flight gauge\n",
+        ));
+        for c in &stats {
+            let _ = writeln!(
+                output,
+                "fraiseql_bulkhead_in_flight{{compartment=\"{}\"}} {}",
+                c.name, c.in_flight
+            );
+        }
+        output.push_str(concat!(
+            "\n# HELP fraiseql_bulkhead_max_concurrent Configured bulkhead concurrency limit\n",
+            "# TYPE fraiseql_bulkhead_max_concurrent gauge\n",
+        ));
+        for c in &stats {
+            let _ = writeln!(
+                output,
+                "fraiseql_bulkhead_max_concurrent{{compartment=\"{}\"}} {}",
+                c.name, c.max_concurrent
+            );
+        }
+        output.push_str(concat!(
+            "\n# HELP fraiseql_bulkhead_waiting Requests waiting for a bulkhead slot\n",
+            "# TYPE fraiseql_bulkhead_waiting gauge\n",
+        ));
+        for c in &stats {
+            let _ = writeln!(
+                output,
+                "fraiseql_bulkhead_waiting{{compartment=\"{}\"}} {}",
+                c.name, c.waiting
+            );
+        }
+        output.push_str(concat!(
+            "\n# HELP fraiseql_bulkhead_rejected_total Requests rejected by the bulkhead\n",
+            "# TYPE fraiseql_bulkhead_rejected_total counter\n",
+        ));
+        for c in &stats {
+            let _ = writeln!(
+                output,
+                "fraiseql_bulkhead_rejected_total{{compartment=\"{}\",reason=\"queue_full\"}} {}",
+                c.name, c.rejected_full
+            );
+            let _ = writeln!(
+                output,
+                "fraiseql_bulkhead_rejected_total{{compartment=\"{}\",reason=\"timeout\"}} {}",
+                c.name, c.rejected_wait
+            );
+        }
+    }
+
     // Append per-operation histogram metrics
     output.push_str(&state.metrics.operation_metrics.to_prometheus_format());
 
@@ -11,9 +11,13 @@ use crate::server_config::PlaygroundTool;
 #[derive(Clone)]
 pub struct PlaygroundState {
     /// GraphQL endpoint URL (relative to server root).
-    pub graphql_endpoint: String,
+    pub graphql_endpoint:      String,
     /// Which playground tool to use.
-    pub tool:             PlaygroundTool,
+    pub tool:                  PlaygroundTool,
+    /// `WebSocket` subscription endpoint (relative to server root), when
+    /// subscriptions are enabled. The IDE derives the `ws://`/`wss://` URL from
+    /// the page origin.
+    pub subscription_endpoint: Option<String>,
 }
 
 impl PlaygroundState {
@@ -23,8 +27,16 @@ impl PlaygroundState {
         Self {
             graphql_endpoint: graphql_endpoint.into(),
             tool,
+            subscription_endpoint: None,
         }
     }
+
+    /// Wire subscriptions in the IDE through the given `WebSocket` path.
+    #[must_use]
+    pub fn with_subscription_endpoint(mut self, path: impl Into<String>) -> Self {
+        self.subscription_endpoint = Some(path.into());
+        self
+    }
 }
 
 /// Relaxed `Content-Security-Policy` for the playground IDE pages.
@@ -40,22 +52,36 @@ const PLAYGROUND_CSP: &str = "default-src 'self'; \
      img-src 'self' data: https:; \
      font-src 'self' data: https://unpkg.com; \
      worker-src 'self' blob:; \
-     connect-src 'self' https://*.apollographql.com; \
+     connect-src 'self' ws: wss: https://*.apollographql.com; \
      frame-src https://sandbox.embed.apollographql.com";
 
 /// Playground HTTP handler.
 ///
 /// Serves the configured GraphQL IDE (`GraphiQL` or Apollo Sandbox).
 pub async fn playground_handler(State(state): State<PlaygroundState>) -> impl IntoResponse {
+    let subscriptions = state.subscription_endpoint.as_deref();
     let html = match state.tool {
-        PlaygroundTool::GraphiQL => graphiql_html(&state.graphql_endpoint),
-        PlaygroundTool::ApolloSandbox => apollo_sandbox_html(&state.graphql_endpoint),
+        PlaygroundTool::GraphiQL => graphiql_html(&state.graphql_endpoint, subscriptions),
+        PlaygroundTool::ApolloSandbox => {
+            apollo_sandbox_html(&state.graphql_endpoint, subscriptions)
+        },
     };
     ([(axum::http::header::CONTENT_SECURITY_POLICY, PLAYGROUND_CSP)], Html(html))
 }
 
+/// Render an optional root-relative path as a JavaScript literal (`null` when absent).
+fn js_path_literal(path: Option<&str>) -> String {
+    path.map_or_else(|| "null".to_string(), |p| format!("'{p}'"))
+}
+
 /// Generate `GraphiQL` HTML page.
-pub(crate) fn graphiql_html(endpoint: &str) -> String {
+///
+/// Request headers typed into the headers editor are persisted in local storage
+/// (`shouldPersistHeaders`) so an auth token survives page reloads. When a
+/// subscription endpoint is given, subscription operations run over
+/// `graphql-transport-ws` via the `graphql-ws` client.
+pub(crate) fn graphiql_html(endpoint: &str, subscription_endpoint: Option<&str>) -> String {
+    let subscription_path = js_path_literal(subscription_endpoint);
     format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -90,14 +116,25 @@ pub(crate) fn graphiql_html(endpoint: &str) -> String {
         crossorigin
         src="https://unpkg.com/graphiql/graphiql.min.js"
     ></script>
+    <script
+        crossorigin
+        src="https://unpkg.com/graphql-ws/umd/graphql-ws.min.js"
+    ></script>
     <script>
+        const subscriptionPath = {subscription_path};
+        const wsScheme = window.location.protocol === 'https:' ? 'wss://' : 'ws://';
         const fetcher = GraphiQL.createFetcher({{
             url: '{endpoint}',
+            wsClient: subscriptionPath
+                ? graphqlWs.createClient({{ url: wsScheme + window.location.host + subscriptionPath }})
+                : undefined,
         }});
         ReactDOM.createRoot(document.getElementById('graphiql')).render(
             React.createElement(GraphiQL, {{
                 fetcher,
                 defaultEditorToolsVisibility: true,
+                isHeadersEditorEnabled: true,
+                shouldPersistHeaders: true,
             }})
         );
     </script>
@@ -107,7 +144,11 @@ pub(crate) fn graphiql_html(endpoint: &str) -> String {
 }
 
 /// Generate Apollo Sandbox HTML page.
-pub(crate) fn apollo_sandbox_html(endpoint: &str) -> String {
+///
+/// Apollo Sandbox persists its own headers per endpoint; the subscription
+/// endpoint, when given, is pre-filled as the sandbox's `WebSocket` URL.
+pub(crate) fn apollo_sandbox_html(endpoint: &str, subscription_endpoint: Option<&str>) -> String {
+    let subscription_path = js_path_literal(subscription_endpoint);
     format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -132,9 +173,14 @@ pub(crate) fn apollo_sandbox_html(endpoint: &str) -> String {
     <div id="sandbox">Loading Apollo Sandbox...</div>
     <script src="https://embeddable-sandbox.cdn.apollographql.com/_latest/embeddable-sandbox.umd.production.min.js"></script>
     <script>
+        const subscriptionPath = {subscription_path};
+        const wsScheme = window.location.protocol === 'https:' ? 'wss://' : 'ws://';
         new window.EmbeddedSandbox({{
             target: '#sandbox',
             initialEndpoint: window.location.origin + '{endpoint}',
+            initialSubscriptionEndpoint: subscriptionPath
+                ? wsScheme + window.location.host + subscriptionPath
+                : undefined,
             includeCookies: false,
         }});
     </script>
@@ -433,20 +433,11 @@ pub async fn subscription_handler(
     // policy-scoped subscription. A JWT principal AND a secret on one upgrade is
     // ambiguous (#602) → 401; a present-but-unmatched secret → 401 (indistinguishable
     // from an unknown account, no oracle).
-    let mut security_context = security_context;
-    if let Some(sa_auth) = state.service_account_authenticator.as_ref() {
-        match sa_auth.resolve(&headers, security_context.is_some()) {
-            crate::service_account::SaAuth::NoSecret => {},
-            crate::service_account::SaAuth::Authenticated(ctx) => security_context = Some(*ctx),
-            crate::service_account::SaAuth::Ambiguous
-            | crate::service_account::SaAuth::Unmatched => {
-                warn!(
-                    "Subscription upgrade rejected: ambiguous or unmatched service-account secret"
-                );
-                return axum::http::StatusCode::UNAUTHORIZED.into_response();
-            },
-        }
-    }
+    let Some(security_context) = resolve_subscription_principal(security_context, &headers, &state)
+    else {
+        warn!("Subscription upgrade rejected: ambiguous or unmatched service-account secret");
+        return axum::http::StatusCode::UNAUTHORIZED.into_response();
+    };
 
     // Resolve the tenant key exactly as the GraphQL handler does: the JWT
     // `tenant_id` (trusted) takes precedence over the `X-Tenant-ID` header and
@@ -469,6 +460,28 @@ pub async fn subscription_handler(
         .into_response()
 }
 
+/// Resolve the subscription principal, letting a service account authenticate with
+/// its secret on the api-key header (ADR-0018).
+///
+/// Returns `None` when the secret is ambiguous (presented alongside a JWT principal)
+/// or unmatched; the caller rejects the request as unauthenticated.
+pub(crate) fn resolve_subscription_principal(
+    security_context: Option<SecurityContext>,
+    headers: &HeaderMap,
+    state: &SubscriptionState,
+) -> Option<Option<SecurityContext>> {
+    let Some(sa_auth) = state.service_account_authenticator.as_ref() else {
+        return Some(security_context);
+    };
+    match sa_auth.resolve(headers, security_context.is_some()) {
+        crate::service_account::SaAuth::NoSecret => Some(security_context),
+        crate::service_account::SaAuth::Authenticated(ctx) => Some(Some(*ctx)),
+        crate::service_account::SaAuth::Ambiguous | crate::service_account::SaAuth::Unmatched => {
+            None
+        },
+    }
+}
+
 /// Resolve the subscription's tenant key, mirroring the GraphQL handler's
 /// dispatch (`routes/graphql/handler.rs`): JWT `tenant_id` > `X-Tenant-ID`
 /// header > Host-domain registry, with cross-source conflict rejection when the
@@ -478,7 +491,7 @@ pub async fn subscription_handler(
 ///
 /// Returns `FraiseQLError::Validation` when the `X-Tenant-ID` header is invalid,
 /// or — under `strict_tenant_validation` — when the resolved sources disagree.
-fn resolve_subscription_tenant(
+pub(crate) fn resolve_subscription_tenant(
     security_context: Option<&SecurityContext>,
     headers: &HeaderMap,
     state: &SubscriptionState,
@@ -839,49 +852,16 @@ async fn handle_client_message(
             let variables_value = serde_json::to_value(&payload.variables)
                 .expect("HashMap<String, serde_json::Value> serialization is infallible");
 
-            // #422: operation-level authorization at subscription establishment.
-            // The per-event delivery does not route through the executor, so the
-            // subscription is authorized once, here, with the connection's principal
-            // (or `None` when anonymous). Fail-closed: a `Deny` or any policy error
-            // rejects the subscription with a `FORBIDDEN` GraphQL-WS error.
-            if let Some(authorizer) = state.authorizer.as_ref() {
-                let ops = [(OperationKind::Subscription, subscription_name.clone())];
-                if let Err(err) =
-                    enforce_authz(authorizer.as_ref(), principal, &ops, Some(&variables_value))
-                {
-                    warn!(
-                        connection_id = %connection_id,
-                        subscription = %subscription_name,
-                        "Operation authorizer denied the subscription"
-                    );
-                    WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
-                    let error = ServerMessage::error(
-                        &op_id,
-                        vec![GraphQLError::with_code(err.to_string(), "FORBIDDEN")],
-                    );
-                    if let Err(e) = send_server_message(codec, sender, error).await {
-                        debug!(connection_id = %connection_id, error = %e, "Could not send authorization denial to client");
-                    }
-                    return Ok(());
-                }
-            }
-
-            if let Err(reason) = state
-                .lifecycle
-                .on_subscribe(&subscription_name, &variables_value, connection_id)
-                .await
+            if let Err(error) = authorize_subscription(
+                state,
+                connection_id,
+                &subscription_name,
+                &variables_value,
+                principal,
+            )
+            .await
             {
-                warn!(
-                    connection_id = %connection_id,
-                    subscription = %subscription_name,
-                    reason = %reason,
-                    "Lifecycle on_subscribe rejected subscription"
-                );
-                WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
-                let error = ServerMessage::error(
-                    &op_id,
-                    vec![GraphQLError::with_code(reason, "SUBSCRIPTION_REJECTED")],
-                );
+                let error = ServerMessage::error(&op_id, vec![error]);
                 if let Err(e) = send_server_message(codec, sender, error).await {
                     debug!(connection_id = %connection_id, error = %e, "Could not send subscription rejection to client");
                 }
@@ -975,96 +955,18 @@ async fn handle_client_message(
                 }
             }
 
-            // Validate client-provided tenant variable against server-resolved
-            if let Some(server_tid) = tenant_id {
-                if let Some(client_tid) = variables_value.get("tenant_id").and_then(|v| v.as_str())
-                {
-                    if client_tid != server_tid {
-                        let error = ServerMessage::error(
-                            &op_id,
-                            vec![GraphQLError::with_code(
-                                format!(
-                                    "Tenant mismatch: client provided '{client_tid}', server resolved '{server_tid}'"
-                                ),
-                                "TENANT_MISMATCH",
-                            )],
-                        );
-                        if let Err(send_err) = send_server_message(codec, sender, error).await {
-                            debug!(connection_id = %connection_id, error = %send_err, "Could not send tenant mismatch error to client");
-                        }
-                        return Ok(());
-                    }
-                }
-            }
-
-            // M-tenant-ws-suspended: refuse to start a subscription whose tenant
-            // is suspended, mirroring the GraphQL data plane's 503 response.
-            if let (Some(tid), Some(src)) = (tenant_id, state.tenant_status_source.as_ref()) {
-                if src.is_suspended(tid) {
-                    WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
-                    let error = ServerMessage::error(
-                        &op_id,
-                        vec![GraphQLError::with_code(
-                            format!("Tenant '{tid}' is suspended"),
-                            "TENANT_SUSPENDED",
-                        )],
-                    );
-                    if let Err(send_err) = send_server_message(codec, sender, error).await {
-                        debug!(connection_id = %connection_id, error = %send_err, "Could not send tenant-suspended error to client");
-                    }
-                    return Ok(());
-                }
-            }
-
-            // #596: derive the server-owned row-visibility condition for this
-            // subscription from the target entity's `subscription_policy`. Fail-closed —
-            // a policy that applies but whose identity is unresolvable refuses the
-            // subscription rather than falling back to delivering every row.
-            let rls_conditions = match resolve_subscription_rls(
+            match start_local_subscription(
                 state,
+                connection_id,
                 &subscription_name,
+                variables_value,
+                tenant_id,
                 principal,
             )
             .await
             {
-                Ok(conditions) => conditions,
-                Err(reason) => {
-                    WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
-                    warn!(
-                        connection_id = %connection_id,
-                        subscription = %subscription_name,
-                        "Row-visibility policy refused the subscription (fail-closed)"
-                    );
-                    let error = ServerMessage::error(
-                        &op_id,
-                        vec![GraphQLError::with_code(reason, "SUBSCRIPTION_REFUSED")],
-                    );
-                    if let Err(send_err) = send_server_message(codec, sender, error).await {
-                        debug!(connection_id = %connection_id, error = %send_err, "Could not send row-visibility refusal to client");
-                    }
-                    return Ok(());
-                },
-            };
-
-            // Build context with server-resolved tenant_id
-            let mut context = serde_json::json!({});
-            if let Some(tid) = tenant_id {
-                context["tenant_id"] = serde_json::Value::String(tid.to_string());
-            }
-
-            // Subscribe locally (field is owned by this subgraph). The server-owned
-            // `rls_conditions` (#596) are enforced on every delivered event (AND
-            // semantics) and cannot be overridden by client-supplied variables/filters.
-            match state.manager.subscribe_with_rls(
-                &subscription_name,
-                context,
-                variables_value,
-                connection_id,
-                rls_conditions,
-            ) {
                 Ok(sub_id) => {
                     active_operations.insert(op_id.clone(), sub_id);
-                    WS_SUBSCRIPTIONS_ACCEPTED.fetch_add(1, Ordering::Relaxed);
                     info!(
                         connection_id = %connection_id,
                         operation_id = %op_id,
@@ -1072,11 +974,8 @@ async fn handle_client_message(
                         "Subscription started"
                     );
                 },
-                Err(e) => {
-                    let error = ServerMessage::error(
-                        &op_id,
-                        vec![GraphQLError::with_code(e.to_string(), "SUBSCRIPTION_ERROR")],
-                    );
+                Err(error) => {
+                    let error = ServerMessage::error(&op_id, vec![error]);
                     if let Err(send_err) = send_server_message(codec, sender, error).await {
                         debug!(connection_id = %connection_id, error = %send_err, "Could not send subscription error to client");
                     }
@@ -1120,6 +1019,124 @@ async fn handle_client_message(
     Ok(())
 }
 
+/// Authorize a subscription at establishment: the operation-level authorizer (#422)
+/// followed by the lifecycle `on_subscribe` hook.
+///
+/// Shared by the `WebSocket` and gRPC `Subscribe` transports. A rejection is counted
+/// in the subscription metrics and returned as the error to report to the client.
+pub(crate) async fn authorize_subscription(
+    state: &SubscriptionState,
+    connection_id: &str,
+    subscription_name: &str,
+    variables: &serde_json::Value,
+    principal: Option<&SecurityContext>,
+) -> Result<(), GraphQLError> {
+    // #422: the per-event delivery does not route through the executor, so the
+    // subscription is authorized once, here, with the connection's principal (or
+    // `None` when anonymous). Fail-closed: a `Deny` or any policy error rejects the
+    // subscription with a `FORBIDDEN` error.
+    if let Some(authorizer) = state.authorizer.as_ref() {
+        let ops = [(OperationKind::Subscription, subscription_name.to_string())];
+        if let Err(err) = enforce_authz(authorizer.as_ref(), principal, &ops, Some(variables)) {
+            warn!(
+                connection_id = %connection_id,
+                subscription = %subscription_name,
+                "Operation authorizer denied the subscription"
+            );
+            WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
+            return Err(GraphQLError::with_code(err.to_string(), "FORBIDDEN"));
+        }
+    }
+
+    if let Err(reason) =
+        state.lifecycle.on_subscribe(subscription_name, variables, connection_id).await
+    {
+        warn!(
+            connection_id = %connection_id,
+            subscription = %subscription_name,
+            reason = %reason,
+            "Lifecycle on_subscribe rejected subscription"
+        );
+        WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
+        return Err(GraphQLError::with_code(reason, "SUBSCRIPTION_REJECTED"));
+    }
+
+    Ok(())
+}
+
+/// Register a locally-owned subscription with the manager.
+///
+/// Validates a client-supplied `tenant_id` variable against the server-resolved
+/// tenant, refuses a suspended tenant, and derives the server-owned row-visibility
+/// conditions (#596) before subscribing. Shared by the `WebSocket` and gRPC
+/// `Subscribe` transports.
+pub(crate) async fn start_local_subscription(
+    state: &SubscriptionState,
+    connection_id: &str,
+    subscription_name: &str,
+    variables: serde_json::Value,
+    tenant_id: Option<&str>,
+    principal: Option<&SecurityContext>,
+) -> Result<SubscriptionId, GraphQLError> {
+    // Validate client-provided tenant variable against server-resolved
+    if let Some(server_tid) = tenant_id {
+        if let Some(client_tid) = variables.get("tenant_id").and_then(|v| v.as_str()) {
+            if client_tid != server_tid {
+                return Err(GraphQLError::with_code(
+                    format!(
+                        "Tenant mismatch: client provided '{client_tid}', server resolved '{server_tid}'"
+                    ),
+                    "TENANT_MISMATCH",
+                ));
+            }
+        }
+    }
+
+    // M-tenant-ws-suspended: refuse to start a subscription whose tenant
+    // is suspended, mirroring the GraphQL data plane's 503 response.
+    if let (Some(tid), Some(src)) = (tenant_id, state.tenant_status_source.as_ref()) {
+        if src.is_suspended(tid) {
+            WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
+            return Err(GraphQLError::with_code(
+                format!("Tenant '{tid}' is suspended"),
+                "TENANT_SUSPENDED",
+            ));
+        }
+    }
+
+    // #596: derive the server-owned row-visibility condition for this
+    // subscription from the target entity's `subscription_policy`. Fail-closed —
+    // a policy that applies but whose identity is unresolvable refuses the
+    // subscription rather than falling back to delivering every row.
+    let rls_conditions = match resolve_subscription_rls(state, subscription_name, principal).await {
+        Ok(conditions) => conditions,
+        Err(reason) => {
+            WS_SUBSCRIPTIONS_REJECTED.fetch_add(1, Ordering::Relaxed);
+            warn!(
+                connection_id = %connection_id,
+                subscription = %subscription_name,
+                "Row-visibility policy refused the subscription (fail-closed)"
+            );
+            return Err(GraphQLError::with_code(reason, "SUBSCRIPTION_REFUSED"));
+        },
+    };
+
+    // Build context with server-resolved tenant_id
+    let mut context = serde_json::json!({});
+    if let Some(tid) = tenant_id {
+        context["tenant_id"] = serde_json::Value::String(tid.to_string());
+    }
+
+    // The server-owned `rls_conditions` (#596) are enforced on every delivered event
+    // (AND semantics) and cannot be overridden by client-supplied variables/filters.
+    let sub_id = state
+        .manager
+        .subscribe_with_rls(subscription_name, context, variables, connection_id, rls_conditions)
+        .map_err(|e| GraphQLError::with_code(e.to_string(), "SUBSCRIPTION_ERROR"))?;
+    WS_SUBSCRIPTIONS_ACCEPTED.fetch_add(1, Ordering::Relaxed);
+    Ok(sub_id)
+}
+
 /// Send a server message through the codec, handling protocol translation.
 async fn send_server_message(
     codec: &ProtocolCodec,
@@ -758,19 +758,95 @@ mod introspection_tests {
     #![allow(clippy::missing_errors_doc)] // Reason: test helpers
     #![allow(missing_docs)] // Reason: test code
 
-    use super::super::introspection::TypeInfo;
+    use fraiseql_core::{
+        schema::{
+            CompiledSchema, FieldDefinition, FieldType, RoleDefinition, SecurityConfig,
+            TypeDefinition,
+        },
+        security::SecurityContext,
+    };
+
+    use super::super::introspection::{FieldInfo, TypeInfo, build_introspection};
 
     #[test]
     fn test_type_info_serialization() {
         let type_info = TypeInfo {
             name:        "User".to_string(),
             description: Some("A user in the system".to_string()),
-            field_count: 3,
+            field_count: 1,
+            fields:      vec![FieldInfo {
+                name:        "id".to_string(),
+                field_type:  "ID".to_string(),
+                nullable:    false,
+                description: None,
+            }],
         };
 
         let json = serde_json::to_string(&type_info).unwrap();
         assert!(json.contains("User"));
         assert!(json.contains("field_count"));
+        assert!(json.contains("\"fields\""));
+    }
+
+    fn schema_with_admin_field() -> CompiledSchema {
+        let mut user = TypeDefinition::new("User", "v_user");
+        user.fields = vec![
+            FieldDefinition::new("id", FieldType::Id),
+            FieldDefinition::new("email", FieldType::String),
+            FieldDefinition::new("internalNotes", FieldType::String)
+                .with_requires_scope("admin:User.internalNotes"),
+        ];
+        let mut audit = TypeDefinition::new("AuditEntry", "v_audit_entry");
+        audit.requires_role = Some("admin".to_string());
+
+        let mut security = SecurityConfig::new();
+        security
+            .add_role(RoleDefinition::new("admin", vec!["admin:User.internalNotes".to_string()]));
+        security.add_role(RoleDefinition::new("viewer", vec!["read:User.*".to_string()]));
+
+        let mut schema = CompiledSchema::new();
+        schema.types = vec![user, audit];
+        schema.security = Some(security);
+        schema
+    }
+
+    fn caller(role: &str) -> SecurityContext {
+        SecurityContext::system_job("introspection", "req-1", vec![role.to_string()], vec![], None)
+    }
+
+    fn user_fields(schema: &CompiledSchema, ctx: Option<&SecurityContext>) -> Vec<String> {
+        let response = build_introspection(schema, ctx);
+        let user = response.types.iter().find(|t| t.name == "User").unwrap();
+        assert_eq!(user.field_count, user.fields.len());
+        user.fields.iter().map(|f| f.name.clone()).collect()
+    }
+
+    #[test]
+    fn scoped_fields_are_hidden_from_callers_without_the_scope() {
+        let schema = schema_with_admin_field();
+
+        assert_eq!(user_fields(&schema, None), ["id", "email"]);
+        assert_eq!(user_fields(&schema, Some(&caller("viewer"))), ["id", "email"]);
+        assert_eq!(user_fields(&schema, Some(&caller("admin"))), ["id", "email", "internalNotes"]);
+    }
+
+    #[test]
+    fn role_gated_types_are_hidden_from_other_roles() {
+        let schema = schema_with_admin_field();
+        let type_names = |ctx: Option<&SecurityContext>| -> Vec<String> {
+            build_introspection(&schema, ctx).types.into_iter().map(|t| t.name).collect()
+        };
+
+        assert_eq!(type_names(Some(&caller("viewer"))), ["User"]);
+        assert_eq!(type_names(Some(&caller("admin"))), ["User", "AuditEntry"]);
+    }
+
+    #[test]
+    fn scoped_fields_stay_hidden_without_a_security_config() {
+        let mut schema = schema_with_admin_field();
+        schema.security = None;
+
+        assert_eq!(user_fields(&schema, Some(&caller("admin"))), ["id", "email"]);
     }
 }
 
@@ -838,15 +914,25 @@ mod playground_tests {
 
     #[test]
     fn test_graphiql_html_contains_endpoint() {
-        let html = graphiql_html("/graphql");
+        let html = graphiql_html("/graphql", None);
         assert!(html.contains("/graphql"));
         assert!(html.contains("GraphiQL"));
         assert!(html.contains("graphiql.min.js"));
+        assert!(html.contains("const subscriptionPath = null;"));
+    }
+
+    #[test]
+    fn test_graphiql_html_persists_headers_and_wires_subscriptions() {
+        let html = graphiql_html("/graphql", Some("/ws"));
+        assert!(html.contains("shouldPersistHeaders: true"));
+        assert!(html.contains("const subscriptionPath = '/ws';"));
+        assert!(html.contains("graphqlWs.createClient"));
     }
 
     #[test]
     fn test_apollo_sandbox_html_contains_endpoint() {
-        let html = apollo_sandbox_html("/graphql");
+        let html = apollo_sandbox_html("/graphql", Some("/ws"));
+        assert!(html.contains("initialSubscriptionEndpoint"));
         assert!(html.contains("/graphql"));
         assert!(html.contains("EmbeddedSandbox"));
         assert!(html.contains("embeddable-sandbox.umd.production.min.js"));
@@ -857,6 +943,10 @@ mod playground_tests {
         let state = PlaygroundState::new("/graphql", PlaygroundTool::ApolloSandbox);
         assert_eq!(state.graphql_endpoint, "/graphql");
         assert_eq!(state.tool, PlaygroundTool::ApolloSandbox);
+        assert_eq!(state.subscription_endpoint, None);
+
+        let state = state.with_subscription_endpoint("/ws");
+        assert_eq!(state.subscription_endpoint.as_deref(), Some("/ws"));
     }
 }
 
@@ -2,7 +2,7 @@
 
 use std::path::{Path, PathBuf};
 
-use fraiseql_core::schema::CompiledSchema;
+use fraiseql_core::schema::{CompiledSchema, SchemaVerifyingKey, verify_artifact_signature};
 use fraiseql_functions::FunctionDefinition;
 use serde::Deserialize;
 use tracing::{debug, info, warn};
@@ -26,6 +26,11 @@ pub enum SchemaLoadError {
     /// Schema validation error.
     #[error("Invalid schema: {0}")]
     ValidationError(String),
+
+    /// The schema is unsigned, or its signature does not match the configured
+    /// public key.
+    #[error("Schema signature verification failed: {0}")]
+    SignatureError(String),
 }
 
 /// Storage configuration extracted from the `"storage"` section of a compiled schema.
@@ -124,7 +129,9 @@ pub struct ExtendedCompiledSchema {
 #[derive(Debug, Clone)]
 pub struct CompiledSchemaLoader {
     /// Path to the compiled schema JSON file.
-    path: PathBuf,
+    path:       PathBuf,
+    /// Public key the schema must be signed with, if any.
+    public_key: Option<SchemaVerifyingKey>,
 }
 
 impl CompiledSchemaLoader {
@@ -148,10 +155,32 @@ impl CompiledSchemaLoader {
     #[must_use]
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
         Self {
-            path: path.as_ref().to_path_buf(),
+            path:       path.as_ref().to_path_buf(),
+            public_key: None,
         }
     }
 
+    /// Require the schema to carry a `_signature` made with `key`.
+    ///
+    /// Unsigned schemas and schemas signed with another key are refused with
+    /// [`SchemaLoadError::SignatureError`].
+    #[must_use]
+    pub fn with_public_key(mut self, key: SchemaVerifyingKey) -> Self {
+        self.public_key = Some(key);
+        self
+    }
+
+    /// Check the schema signature when a public key is configured.
+    fn verify_signature(&self, contents: &str) -> Result<(), SchemaLoadError> {
+        let Some(key) = &self.public_key else {
+            return Ok(());
+        };
+        let hash = verify_artifact_signature(contents, key)
+            .map_err(|e| SchemaLoadError::SignatureError(e.to_string()))?;
+        info!(path = %self.path.display(), schema_hash = %hash, "Schema signature verified");
+        Ok(())
+    }
+
     /// Load schema from file.
     ///
     /// Reads the schema JSON file, parses it, and returns a `CompiledSchema`.
@@ -162,6 +191,8 @@ impl CompiledSchemaLoader {
     /// Returns [`SchemaLoadError::IoError`] if the file cannot be read.
     /// Returns [`SchemaLoadError::ParseError`] if the JSON is malformed.
     /// Returns [`SchemaLoadError::ValidationError`] if schema validation fails.
+    /// Returns [`SchemaLoadError::SignatureError`] if a public key is configured and
+    /// the schema is unsigned or its signature does not match.
     ///
     /// # Example
     ///
@@ -194,6 +225,7 @@ impl CompiledSchemaLoader {
 
         // Parse JSON and validate it's valid JSON first
         serde_json::from_str::<serde_json::Value>(&contents)?;
+        self.verify_signature(&contents)?;
 
         // Create CompiledSchema from JSON string
         let schema = CompiledSchema::from_json(&contents, false)
@@ -220,6 +252,8 @@ impl CompiledSchemaLoader {
     /// Returns [`SchemaLoadError::ValidationError`] if any of the following fail:
     ///   - A storage bucket name contains whitespace or is empty.
     ///   - A function trigger string does not match a recognised pattern.
+    /// Returns [`SchemaLoadError::SignatureError`] if a public key is configured and
+    /// the schema is unsigned or its signature does not match.
     pub async fn load_extended(&self) -> Result<ExtendedCompiledSchema, SchemaLoadError> {
         info!(path = %self.path.display(), "Loading extended compiled schema");
 
@@ -239,6 +273,7 @@ impl CompiledSchemaLoader {
         // Parse once as a raw JSON value so we can extract platform sections without
         // touching the CompiledSchema deserialization path.
         let raw: serde_json::Value = serde_json::from_str(&contents)?;
+        self.verify_signature(&contents)?;
 
         // Core schema (always required).
         let schema = CompiledSchema::from_json(&contents, false)
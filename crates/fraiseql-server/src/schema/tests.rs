@@ -12,6 +12,7 @@
 
 use std::{io::Write as _, path::PathBuf};
 
+use fraiseql_core::schema::{SchemaSigningKey, seal_artifact};
 use tempfile::NamedTempFile;
 
 use super::loader::{CompiledSchemaLoader, SchemaLoadError};
@@ -48,6 +49,59 @@ async fn test_loader_invalid_json() {
     assert!(matches!(result, Err(SchemaLoadError::ParseError(_))));
 }
 
+// ── Signature verification ──────────────────────────────────────────────────
+
+fn signing_key(seed: u8) -> SchemaSigningKey {
+    use base64::Engine as _;
+    SchemaSigningKey::parse(&base64::engine::general_purpose::STANDARD.encode([seed; 32])).unwrap()
+}
+
+fn sealed_schema(key: Option<&SchemaSigningKey>) -> String {
+    let value: serde_json::Value = serde_json::from_str(minimal_schema()).unwrap();
+    seal_artifact(&value, key).unwrap().0
+}
+
+#[tokio::test]
+async fn test_loader_accepts_schema_signed_with_configured_key() {
+    let key = signing_key(1);
+    let file = write_schema(&sealed_schema(Some(&key)));
+
+    let schema = CompiledSchemaLoader::new(file.path())
+        .with_public_key(key.verifying_key())
+        .load()
+        .await
+        .unwrap();
+    assert!(schema.artifact_hash.is_some());
+}
+
+#[tokio::test]
+async fn test_loader_rejects_unsigned_schema_when_key_configured() {
+    let file = write_schema(&sealed_schema(None));
+
+    let result = CompiledSchemaLoader::new(file.path())
+        .with_public_key(signing_key(1).verifying_key())
+        .load()
+        .await;
+    assert!(matches!(result, Err(SchemaLoadError::SignatureError(_))));
+}
+
+#[tokio::test]
+async fn test_loader_rejects_schema_signed_with_other_key() {
+    let file = write_schema(&sealed_schema(Some(&signing_key(2))));
+
+    let result = CompiledSchemaLoader::new(file.path())
+        .with_public_key(signing_key(1).verifying_key())
+        .load_extended()
+        .await;
+    assert!(matches!(result, Err(SchemaLoadError::SignatureError(_))));
+}
+
+#[tokio::test]
+async fn test_loader_without_key_ignores_signature() {
+    let file = write_schema(&sealed_schema(Some(&signing_key(2))));
+    CompiledSchemaLoader::new(file.path()).load().await.unwrap();
+}
+
 // ── Storage config ────────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -0,0 +1,327 @@
+//! Opt-in boot-time schema-to-database drift validation.
+//!
+//! Where the [`sql_source_check`](crate::sql_source_check) boot gate only asks
+//! whether each declared `sql_source` exists, this check runs the column-level
+//! half of `fraiseql compile --database` against the database the server is about
+//! to serve: every query view must expose its `jsonb_column` as JSON, its relay
+//! cursor column and the native columns compiled into the schema, with types its
+//! arguments can still filter; every `additional_view` and mutation function must
+//! exist; every computed field expression must still evaluate. Any finding fails
+//! boot with a report listing all of them.
+//!
+//! The type rules are the CLI's own ([`is_json_column_type`],
+//! [`argument_column_compatible`]). Only findings that make an operation fail at
+//! request time are reported; the CLI's advisory warnings (JSON keys missing from
+//! sampled rows, JSONB-extraction fallbacks) are not.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use fraiseql_core::{
+    db::introspector::DatabaseIntrospector,
+    schema::{CompiledSchema, QueryDefinition, argument_column_compatible, is_json_column_type},
+};
+
+/// A difference between the compiled schema and the live database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDrift {
+    /// A query's `sql_source` relation does not exist.
+    MissingRelation {
+        /// Name of the query.
+        query_name: String,
+        /// The `sql_source` that was not found.
+        sql_source: String,
+    },
+    /// A query's `additional_view` does not exist.
+    MissingAdditionalView {
+        /// Name of the query.
+        query_name: String,
+        /// The view that was not found.
+        view_name:  String,
+    },
+    /// A mutation's `sql_source` function does not exist.
+    MissingFunction {
+        /// Name of the mutation.
+        mutation_name: String,
+        /// The `sql_source` function that was not found.
+        sql_source:    String,
+    },
+    /// A column the compiled query reads is missing from its relation.
+    MissingColumn {
+        /// Name of the query.
+        query_name:  String,
+        /// The `sql_source` relation.
+        sql_source:  String,
+        /// The missing column.
+        column_name: String,
+        /// What the query uses the column for (`jsonb`, `relay cursor`, `native`).
+        role:        &'static str,
+    },
+    /// A column exists but its type no longer matches how the query uses it.
+    IncompatibleColumnType {
+        /// Name of the query.
+        query_name:  String,
+        /// The `sql_source` relation.
+        sql_source:  String,
+        /// The column.
+        column_name: String,
+        /// The column's SQL type in the database.
+        actual_type: String,
+        /// The type the compiled query expects.
+        expected:    String,
+    },
+    /// A computed field's SQL expression is rejected by the database.
+    InvalidComputedField {
+        /// Name of the query.
+        query_name: String,
+        /// The computed field, as `Type.field`.
+        field:      String,
+        /// The `sql_source` relation the expression is evaluated against.
+        sql_source: String,
+        /// The database's error message.
+        message:    String,
+    },
+}
+
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRelation {
+                query_name,
+                sql_source,
+            } => write!(f, "query `{query_name}`: relation `{sql_source}` does not exist"),
+            Self::MissingAdditionalView {
+                query_name,
+                view_name,
+            } => write!(f, "query `{query_name}`: additional view `{view_name}` does not exist"),
+            Self::MissingFunction {
+                mutation_name,
+                sql_source,
+            } => write!(f, "mutation `{mutation_name}`: function `{sql_source}` does not exist"),
+            Self::MissingColumn {
+                query_name,
+                sql_source,
+                column_name,
+                role,
+            } => write!(
+                f,
+                "query `{query_name}`: {role} column `{column_name}` not found on `{sql_source}`"
+            ),
+            Self::IncompatibleColumnType {
+                query_name,
+                sql_source,
+                column_name,
+                actual_type,
+                expected,
+            } => write!(
+                f,
+                "query `{query_name}`: column `{sql_source}.{column_name}` is `{actual_type}`, \
+                 expected {expected}"
+            ),
+            Self::InvalidComputedField {
+                query_name,
+                field,
+                sql_source,
+                message,
+            } => write!(
+                f,
+                "query `{query_name}`: computed field `{field}` is not a valid expression on \
+                 `{sql_source}`: {message}"
+            ),
+        }
+    }
+}
+
+/// Relations visible to the introspector, for resolving bare and qualified names.
+struct Relations {
+    qualified: HashSet<(String, String)>,
+    bare:      HashSet<String>,
+}
+
+impl Relations {
+    async fn load(introspector: &impl DatabaseIntrospector) -> fraiseql_core::Result<Self> {
+        let relations = introspector.list_relations().await?;
+        Ok(Self {
+            bare:      relations.iter().map(|r| r.name.clone()).collect(),
+            qualified: relations.into_iter().map(|r| (r.schema, r.name)).collect(),
+        })
+    }
+
+    /// Resolve `source` the way the runtime does: a qualified name verbatim through
+    /// the introspector when it can probe, otherwise against the listed relations.
+    async fn contains(
+        &self,
+        introspector: &impl DatabaseIntrospector,
+        source: &str,
+    ) -> fraiseql_core::Result<bool> {
+        match source.split_once('.') {
+            Some((schema, name)) => {
+                Ok(introspector.qualified_relation_exists(schema, name).await?.unwrap_or_else(
+                    || self.qualified.contains(&(schema.to_string(), name.to_string())),
+                ))
+            },
+            None => Ok(self.bare.contains(source)),
+        }
+    }
+}
+
+/// Compare every query and mutation of `schema` against the live database and
+/// return the drift found, queries first, in declaration order — empty means the
+/// database serves the schema as compiled.
+///
+/// # Errors
+///
+/// Returns a [`fraiseql_core::FraiseQLError`] if an introspection query fails.
+pub async fn find_schema_drift(
+    schema: &CompiledSchema,
+    introspector: &impl DatabaseIntrospector,
+) -> fraiseql_core::Result<Vec<SchemaDrift>> {
+    let relations = Relations::load(introspector).await?;
+    let mut drift = Vec::new();
+
+    for query in &schema.queries {
+        let Some(ref source) = query.sql_source else {
+            continue;
+        };
+        if relations.contains(introspector, source).await? {
+            check_query_columns(schema, query, source, introspector, &mut drift).await?;
+        } else {
+            drift.push(SchemaDrift::MissingRelation {
+                query_name: query.name.clone(),
+                sql_source: source.clone(),
+            });
+        }
+        for view in &query.additional_views {
+            if !relations.contains(introspector, view).await? {
+                drift.push(SchemaDrift::MissingAdditionalView {
+                    query_name: query.name.clone(),
+                    view_name:  view.clone(),
+                });
+            }
+        }
+    }
+
+    for mutation in &schema.mutations {
+        let Some(ref source) = mutation.sql_source else {
+            continue;
+        };
+        let (fn_schema, name) = match source.split_once('.') {
+            Some((s, name)) => (Some(s), name),
+            None => (None, source.as_str()),
+        };
+        // `None`: the connector cannot probe functions, so the check is skipped.
+        if introspector.function_exists(fn_schema, name).await? == Some(false) {
+            drift.push(SchemaDrift::MissingFunction {
+                mutation_name: mutation.name.clone(),
+                sql_source:    source.clone(),
+            });
+        }
+    }
+
+    Ok(drift)
+}
+
+/// Column-level checks of one query whose relation exists.
+async fn check_query_columns(
+    schema: &CompiledSchema,
+    query: &QueryDefinition,
+    source: &str,
+    introspector: &impl DatabaseIntrospector,
+    drift: &mut Vec<SchemaDrift>,
+) -> fraiseql_core::Result<()> {
+    let columns: HashMap<String, String> = introspector
+        .get_columns(source)
+        .await?
+        .into_iter()
+        .map(|(name, data_type, _)| (name, data_type))
+        .collect();
+    let db_type = introspector.database_type();
+    let missing = |column: &str, role| SchemaDrift::MissingColumn {
+        query_name: query.name.clone(),
+        sql_source: source.to_string(),
+        column_name: column.to_string(),
+        role,
+    };
+    let incompatible =
+        |column: &str, actual: &str, expected: String| SchemaDrift::IncompatibleColumnType {
+            query_name: query.name.clone(),
+            sql_source: source.to_string(),
+            column_name: column.to_string(),
+            actual_type: actual.to_string(),
+            expected,
+        };
+
+    if !query.jsonb_column.is_empty() {
+        match columns.get(&query.jsonb_column) {
+            Some(actual) if !is_json_column_type(actual, db_type) => {
+                drift.push(incompatible(&query.jsonb_column, actual, "json/jsonb".to_string()));
+            },
+            Some(_) => {},
+            None => drift.push(missing(&query.jsonb_column, "jsonb")),
+        }
+    }
+
+    if query.relay {
+        if let Some(ref cursor) = query.relay_cursor_column {
+            if !columns.contains_key(cursor) {
+                drift.push(missing(cursor, "relay cursor"));
+            }
+        }
+    }
+
+    // Native columns were resolved by `compile --database`; the runtime filters them
+    // with `WHERE col = $N`, so each must still exist with a type its argument fits.
+    let mut native: Vec<_> = query.native_columns.keys().collect();
+    native.sort();
+    for column in native {
+        let Some(actual) = columns.get(column) else {
+            drift.push(missing(column, "native"));
+            continue;
+        };
+        if let Some(arg) = query.arguments.iter().find(|a| &a.name == column) {
+            if !argument_column_compatible(&arg.arg_type, actual) {
+                let expected = format!("a type `{}` can filter", arg.arg_type.to_graphql_string());
+                drift.push(incompatible(column, actual, expected));
+            }
+        }
+    }
+
+    if let Some(type_def) = schema.find_type(&query.return_type) {
+        for field in &type_def.fields {
+            let Some(ref expression) = field.sql_expression else {
+                continue;
+            };
+            if let Some(Some(message)) = introspector.expression_error(source, expression).await? {
+                drift.push(SchemaDrift::InvalidComputedField {
+                    query_name: query.name.clone(),
+                    field: format!("{}.{}", type_def.name, field.name),
+                    sql_source: source.to_string(),
+                    message,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Render drift findings as a boot diagnostic.
+#[must_use]
+pub fn format_drift_report(drift: &[SchemaDrift]) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = format!(
+        "schema drift validation failed — {} difference(s) between the compiled schema and \
+         the database:",
+        drift.len()
+    );
+    for finding in drift {
+        let _ = write!(out, "\n  - {finding}");
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "schema_drift_tests.rs"]
+mod schema_drift_tests;
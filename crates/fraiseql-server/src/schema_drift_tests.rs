@@ -0,0 +1,208 @@
+//! Unit tests for the boot-time schema drift check, against an in-memory catalog.
+
+#![allow(clippy::unwrap_used)] // Reason: test code
+
+use std::collections::{HashMap, HashSet};
+
+use fraiseql_core::{
+    db::{
+        DatabaseType,
+        introspector::{DatabaseIntrospector, RelationInfo, RelationKind},
+    },
+    schema::{ArgumentDefinition, CompiledSchema, FieldType, MutationDefinition, QueryDefinition},
+};
+
+use super::{SchemaDrift, find_schema_drift, format_drift_report};
+
+#[derive(Default)]
+struct Catalog {
+    relations: Vec<RelationInfo>,
+    columns:   HashMap<String, Vec<(String, String, bool)>>,
+    functions: HashSet<String>,
+}
+
+impl Catalog {
+    fn with_view(mut self, name: &str, columns: &[(&str, &str)]) -> Self {
+        self.relations.push(RelationInfo {
+            schema: "public".to_string(),
+            name:   name.to_string(),
+            kind:   RelationKind::View,
+        });
+        self.columns.insert(
+            name.to_string(),
+            columns
+                .iter()
+                .map(|(n, t)| ((*n).to_string(), (*t).to_string(), true))
+                .collect(),
+        );
+        self
+    }
+
+    fn with_function(mut self, name: &str) -> Self {
+        self.functions.insert(name.to_string());
+        self
+    }
+}
+
+impl DatabaseIntrospector for Catalog {
+    async fn list_fact_tables(&self) -> fraiseql_core::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_columns(
+        &self,
+        table_name: &str,
+    ) -> fraiseql_core::Result<Vec<(String, String, bool)>> {
+        Ok(self.columns.get(table_name).cloned().unwrap_or_default())
+    }
+
+    async fn get_indexed_columns(&self, _table_name: &str) -> fraiseql_core::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    fn database_type(&self) -> DatabaseType {
+        DatabaseType::PostgreSQL
+    }
+
+    async fn list_relations(&self) -> fraiseql_core::Result<Vec<RelationInfo>> {
+        Ok(self.relations.clone())
+    }
+
+    async fn function_exists(
+        &self,
+        _schema: Option<&str>,
+        name: &str,
+    ) -> fraiseql_core::Result<Option<bool>> {
+        Ok(Some(self.functions.contains(name)))
+    }
+}
+
+fn orders_query() -> QueryDefinition {
+    let mut query = QueryDefinition::new("orders", "Order")
+        .with_sql_source("v_order")
+        .returning_list();
+    query
+        .arguments
+        .push(ArgumentDefinition::optional("customerId", FieldType::Uuid));
+    query.native_columns.insert("customerId".to_string(), "uuid".to_string());
+    query
+}
+
+fn schema(queries: Vec<QueryDefinition>, mutations: Vec<MutationDefinition>) -> CompiledSchema {
+    CompiledSchema {
+        queries,
+        mutations,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn matching_database_has_no_drift() {
+    let mut create = MutationDefinition::new("createOrder", "Order");
+    create.sql_source = Some("fn_create_order".to_string());
+    let catalog = Catalog::default()
+        .with_view("v_order", &[("data", "jsonb"), ("customerId", "uuid")])
+        .with_function("fn_create_order");
+
+    let drift = find_schema_drift(&schema(vec![orders_query()], vec![create]), &catalog)
+        .await
+        .unwrap();
+    assert!(drift.is_empty(), "unexpected drift: {drift:?}");
+}
+
+#[tokio::test]
+async fn missing_sources_are_reported() {
+    let mut query = orders_query();
+    query.additional_views.push("v_order_line".to_string());
+    let mut create = MutationDefinition::new("createOrder", "Order");
+    create.sql_source = Some("fn_create_order".to_string());
+
+    let drift = find_schema_drift(&schema(vec![query], vec![create]), &Catalog::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        drift,
+        vec![
+            SchemaDrift::MissingRelation {
+                query_name: "orders".to_string(),
+                sql_source: "v_order".to_string(),
+            },
+            SchemaDrift::MissingAdditionalView {
+                query_name: "orders".to_string(),
+                view_name:  "v_order_line".to_string(),
+            },
+            SchemaDrift::MissingFunction {
+                mutation_name: "createOrder".to_string(),
+                sql_source:    "fn_create_order".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn column_type_changes_are_reported() {
+    // `data` became text and the native `customerId` column became an integer.
+    let catalog =
+        Catalog::default().with_view("v_order", &[("data", "text"), ("customerId", "bigint")]);
+
+    let drift = find_schema_drift(&schema(vec![orders_query()], Vec::new()), &catalog)
+        .await
+        .unwrap();
+    assert_eq!(drift.len(), 2, "got: {drift:?}");
+    assert!(matches!(
+        &drift[0],
+        SchemaDrift::IncompatibleColumnType { column_name, actual_type, .. }
+            if column_name == "data" && actual_type == "text"
+    ));
+    assert!(matches!(
+        &drift[1],
+        SchemaDrift::IncompatibleColumnType { column_name, actual_type, .. }
+            if column_name == "customerId" && actual_type == "bigint"
+    ));
+}
+
+#[tokio::test]
+async fn dropped_columns_are_reported() {
+    let mut query = orders_query();
+    query.relay = true;
+    query.relay_cursor_column = Some("pk_order".to_string());
+    let catalog = Catalog::default().with_view("v_order", &[("id", "uuid")]);
+
+    let drift = find_schema_drift(&schema(vec![query], Vec::new()), &catalog).await.unwrap();
+    let roles: Vec<_> = drift
+        .iter()
+        .filter_map(|d| match d {
+            SchemaDrift::MissingColumn {
+                column_name, role, ..
+            } => Some((column_name.as_str(), *role)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        roles,
+        vec![
+            ("data", "jsonb"),
+            ("pk_order", "relay cursor"),
+            ("customerId", "native")
+        ]
+    );
+}
+
+#[test]
+fn report_lists_every_finding() {
+    let report = format_drift_report(&[
+        SchemaDrift::MissingRelation {
+            query_name: "orders".to_string(),
+            sql_source: "v_order".to_string(),
+        },
+        SchemaDrift::MissingFunction {
+            mutation_name: "createOrder".to_string(),
+            sql_source:    "fn_create_order".to_string(),
+        },
+    ]);
+    assert!(report.contains("2 difference(s)"), "got: {report}");
+    assert!(report.contains("\n  - query `orders`: relation `v_order` does not exist"));
+    assert!(
+        report.contains("\n  - mutation `createOrder`: function `fn_create_order` does not exist")
+    );
+}
@@ -15,6 +15,60 @@ use tracing::{info, warn};
 
 use super::{RateLimiter, Result, Server, ServerConfig, ServerError};
 
+/// Interval between expired-row sweeps of the shared `PostgreSQL` APQ table.
+const APQ_CLEANUP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// Build the APQ store selected by `apq_backend`.
+///
+/// Shared backends let a hash registered on one replica resolve on every
+/// other. The `PostgreSQL` backend's TTL cleanup is spawned into `tasks` so
+/// graceful shutdown awaits it.
+///
+/// # Errors
+///
+/// Returns [`ServerError::ConfigError`] if the selected backend cannot be
+/// reached, is missing its URL, or was not compiled in.
+pub(super) async fn build_apq_store(
+    config: &ServerConfig,
+    tasks: &mut tokio::task::JoinSet<()>,
+) -> Result<fraiseql_core::apq::ArcApqStorage> {
+    use crate::server_config::ApqBackend;
+
+    match config.apq_backend {
+        ApqBackend::Memory => {
+            info!("APQ (Automatic Persisted Queries) enabled — in-memory backend");
+            Ok(Arc::new(fraiseql_core::apq::InMemoryApqStorage::default()))
+        },
+        ApqBackend::Postgres => {
+            let store = fraiseql_core::apq::PostgresApqStorage::connect(&config.database_url)
+                .await
+                .map_err(|e| ServerError::ConfigError(format!("apq_backend = postgres: {e}")))?;
+            let store = Arc::new(store);
+            tasks.spawn(Arc::clone(&store).run_cleanup(APQ_CLEANUP_INTERVAL));
+            info!(
+                table = store.table(),
+                "APQ (Automatic Persisted Queries) enabled — PostgreSQL backend"
+            );
+            Ok(store)
+        },
+        #[cfg(feature = "redis-apq")]
+        ApqBackend::Redis => {
+            let url = config.apq_redis_url.as_deref().ok_or_else(|| {
+                ServerError::ConfigError("apq_backend = redis requires apq_redis_url".to_string())
+            })?;
+            let store = fraiseql_core::apq::RedisApqStorage::new(url)
+                .await
+                .map_err(|e| ServerError::ConfigError(format!("apq_backend = redis: {e}")))?;
+            info!("APQ (Automatic Persisted Queries) enabled — Redis backend");
+            Ok(Arc::new(store))
+        },
+        #[cfg(not(feature = "redis-apq"))]
+        ApqBackend::Redis => Err(ServerError::ConfigError(
+            "apq_backend = redis requires the `redis-apq` feature".to_string(),
+        )),
+    }
+}
+
 /// Build an HS256 validator from the server config, if configured.
 pub(super) fn build_hs256_auth(config: &ServerConfig) -> Result<Option<Arc<AuthMiddleware>>> {
     let Some(ref hs) = config.auth_hs256 else {
@@ -99,10 +153,12 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<CachedDatabaseAd
             ServerError::ConfigError(format!("Incompatible compiled schema: {msg}"))
         })?;
 
-        // Refuse to boot if any field is marked for at-rest encryption: the write path does
-        // not encrypt (H12), so those fields would be stored in plaintext. Fail loud rather
-        // than silently storing sensitive data unencrypted.
-        crate::server::initialization::field_encryption_unsupported_check(&schema)?;
+        // Refuse to boot if any field is marked for at-rest encryption but the build has no
+        // secrets backend to encrypt it (H12). Fail loud rather than silently storing
+        // sensitive data unencrypted; serving re-checks once the manager is attached.
+        crate::server::initialization::field_encryption_check(&schema, cfg!(feature = "secrets"))?;
+        // Computed-field expressions are spliced into SELECT lists; reject unsafe ones.
+        crate::server::initialization::computed_field_expression_check(&schema)?;
 
         // Read security configs from compiled schema BEFORE schema is moved.
         #[cfg(feature = "federation")]
@@ -252,10 +308,7 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<CachedDatabaseAd
 
         // Initialize APQ store when enabled.
         if server.config.apq_enabled {
-            let apq_store: fraiseql_core::apq::ArcApqStorage =
-                Arc::new(fraiseql_core::apq::InMemoryApqStorage::default());
-            server.apq_store = Some(apq_store);
-            info!("APQ (Automatic Persisted Queries) enabled — in-memory backend");
+            server.apq_store = Some(build_apq_store(&server.config, &mut server.tasks).await?);
         }
 
         // Apply subscription lifecycle/limits from compiled schema.
@@ -335,7 +388,7 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                     rps_per_user = rate_config.rps_per_user,
                     "Initializing rate limiting from server config"
                 );
-                Some(Arc::new(RateLimiter::new(rate_config.clone())))
+                Some(Arc::new(Self::build_rate_limiter(rate_config.clone()).await))
             } else {
                 info!("Rate limiting disabled by configuration");
                 None
@@ -358,6 +411,17 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             } else {
                 info!("Arrow Flight initialized without authentication (dev mode)");
             }
+            // Flight tickets are authorized by the same operation-level authorizer as
+            // GraphQL requests, so a view denied over HTTP is denied over Flight too.
+            if let Some(ref authorizer) = executor.config().authorizer {
+                service = service.with_authorizer(authorizer.clone());
+            }
+            // Flight calls draw from the same per-user / per-IP buckets as HTTP requests.
+            if let Some(ref limiter) = rate_limiter {
+                service = service.with_rate_limiter(Arc::new(
+                    crate::arrow::FlightRateLimitAdapter::new(limiter.clone()),
+                ));
+            }
             Some(service)
         };
 
@@ -385,6 +449,10 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         //         feature = "auth" is enabled; without it they are legitimately unused.
         #[cfg(not(feature = "auth"))]
         let _ = (state_encryption, pkce_store, oidc_server_client);
+
+        let shutdown = Arc::new(crate::shutdown::ShutdownCoordinator::new(
+            std::time::Duration::from_secs(config.shutdown_timeout_secs),
+        ));
         Ok(Self {
             config,
             executor,
@@ -396,6 +464,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             rate_limiter,
             #[cfg(feature = "secrets")]
             secrets_manager: None,
+            #[cfg(feature = "secrets")]
+            secret_references: None,
             #[cfg(feature = "federation")]
             circuit_breaker,
             error_sanitizer,
@@ -418,6 +488,7 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             trusted_docs,
             #[cfg(feature = "observers")]
             observer_runtime,
+            admin_audit: Arc::new(crate::admin_audit::AdminAuditLog::new(db_pool.clone())),
             #[cfg(feature = "auth")]
             enrichment_pool: db_pool.clone(),
             #[cfg(feature = "observers")]
@@ -426,6 +497,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             #[cfg(feature = "functions-runtime")]
             functions_hooks: None,
             tenant_executor_factory: None,
+            canary: None,
+            shadow: None,
             #[cfg(feature = "arrow")]
             flight_service,
             #[cfg(feature = "mcp")]
@@ -439,7 +512,11 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             #[cfg(feature = "functions")]
             function_runtime: None,
             usage: Arc::clone(crate::usage::aggregator::global_aggregator()),
+            operation_audit: None,
+            field_usage: None,
             tasks,
+            shutdown,
+            config_source: None,
         })
     }
 
@@ -469,6 +546,15 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         }
     }
 
+    /// The coordinator that runs this server's shutdown phases.
+    ///
+    /// Register close hooks on it (e.g. for a ClickHouse sink task) before
+    /// calling [`serve`](Self::serve).
+    #[must_use]
+    pub fn shutdown_coordinator(&self) -> Arc<crate::shutdown::ShutdownCoordinator> {
+        Arc::clone(&self.shutdown)
+    }
+
     /// Set lifecycle hooks for `WebSocket` subscriptions.
     #[must_use]
     pub fn with_subscription_lifecycle(
@@ -486,6 +572,18 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         self
     }
 
+    /// Enable runtime config reload from `source`.
+    ///
+    /// On SIGHUP (Unix) or `POST /api/v1/admin/config/reload` the server
+    /// re-reads the configuration and applies the changes that are safe while
+    /// serving (CORS origins, `[rate_limiting]`, `log_level`, observer
+    /// definitions); see [`crate::config_reload`]. Call this before `serve`.
+    #[must_use]
+    pub fn with_config_reload(mut self, source: crate::config_reload::ConfigSource) -> Self {
+        self.config_source = Some(source);
+        self
+    }
+
     /// Attach the per-tenant executor factory used to provision tenants at
     /// runtime (`PUT /api/v1/admin/tenants/{key}`).
     ///
@@ -653,6 +751,17 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         info!("Secrets manager attached to server");
     }
 
+    /// Set the resolver for `${secret:path}` references in observer action configs.
+    ///
+    /// Share the resolver used for the config file so both see the same cache.
+    #[cfg(feature = "secrets")]
+    pub fn set_secret_reference_resolver(
+        &mut self,
+        resolver: Arc<crate::secrets_manager::SecretReferenceResolver>,
+    ) {
+        self.secret_references = Some(resolver);
+    }
+
     /// Serve MCP over stdio (stdin/stdout) instead of HTTP.
     ///
     /// This is used when `FRAISEQL_MCP_STDIO=1` is set.  The server reads JSON-RPC
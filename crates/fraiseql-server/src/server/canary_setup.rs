@@ -0,0 +1,62 @@
+//! Serve-time canary schema preparation.
+//!
+//! Loads the `[canary]` candidate compiled schema into a second executor that
+//! shares the current executor's adapter, so `build_app_state` can route a share
+//! of GraphQL traffic to it. Runs once at serve time (async, fail-loud).
+
+use std::sync::Arc;
+
+use fraiseql_core::{
+    db::traits::DatabaseAdapter,
+    runtime::{Executor, RuntimeConfig},
+};
+use tracing::info;
+
+use super::{Server, ServerError};
+use crate::{canary::CanaryRollout, schema::loader::CompiledSchemaLoader};
+
+impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
+    /// Load the candidate schema of a configured `[canary]` rollout.
+    ///
+    /// The candidate goes through the same loader checks as the current schema
+    /// (including the signature check when `schema_public_key` is set) and the same
+    /// runtime-config derivation. A no-op when no canary is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerError::ConfigError`] if the candidate schema cannot be
+    /// loaded, is incompatible, or the canary configuration is invalid.
+    pub(super) async fn prepare_canary(&mut self) -> Result<(), ServerError> {
+        let Some(config) = self.config.canary.clone() else {
+            return Ok(());
+        };
+
+        let mut loader = CompiledSchemaLoader::new(&config.schema_path);
+        if let Some(key) = self.config.schema_verifying_key().map_err(ServerError::ConfigError)? {
+            loader = loader.with_public_key(key);
+        }
+        let schema = loader.load().await.map_err(|error| {
+            ServerError::ConfigError(format!("failed to load canary schema: {error}"))
+        })?;
+        let runtime_config = RuntimeConfig::from_compiled_schema(&schema).map_err(|msg| {
+            ServerError::ConfigError(format!("Incompatible canary schema: {msg}"))
+        })?;
+
+        let candidate_hash = schema.artifact_hash.clone().unwrap_or_else(|| schema.content_hash());
+        let candidate = Arc::new(Executor::with_config(
+            schema,
+            self.executor.adapter().clone(),
+            runtime_config,
+        ));
+        let rollout = CanaryRollout::new(candidate, &config).map_err(ServerError::ConfigError)?;
+        info!(
+            path = %config.schema_path.display(),
+            schema_hash = %candidate_hash,
+            traffic_percent = config.traffic_percent,
+            header = %config.header,
+            "Canary schema rollout enabled"
+        );
+        self.canary = Some(Arc::new(rollout));
+        Ok(())
+    }
+}
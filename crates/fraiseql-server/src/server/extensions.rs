@@ -89,9 +89,9 @@ impl<A: DatabaseAdapter + RelayDatabaseAdapter + Clone + Send + Sync + 'static>
         }
 
         // Same boot gates as `Server::new` — these must not drift by constructor (H16).
-        // Refuse to boot if any field is marked for at-rest encryption (H12); the write
-        // path does not encrypt, so the data would be stored in plaintext.
-        crate::server::initialization::field_encryption_unsupported_check(&schema)?;
+        // Refuse to boot on at-rest-encryption-marked fields without a secrets build (H12).
+        crate::server::initialization::field_encryption_check(&schema, cfg!(feature = "secrets"))?;
+        crate::server::initialization::computed_field_expression_check(&schema)?;
         // Build the runtime config from the compiled schema (validates format version,
         // reads the audit flag, applies the #421 page-size ceiling + change-log toggle).
         let executor_config = RuntimeConfig::from_compiled_schema(&schema).map_err(|msg| {
@@ -183,10 +183,8 @@ impl<A: DatabaseAdapter + RelayDatabaseAdapter + Clone + Send + Sync + 'static>
 
         // Initialize APQ store when enabled.
         if server.config.apq_enabled {
-            let apq_store: fraiseql_core::apq::ArcApqStorage =
-                Arc::new(fraiseql_core::apq::InMemoryApqStorage::default());
-            server.apq_store = Some(apq_store);
-            info!("APQ (Automatic Persisted Queries) enabled — in-memory backend");
+            server.apq_store =
+                Some(super::builder::build_apq_store(&server.config, &mut server.tasks).await?);
         }
 
         Ok(server)
@@ -220,8 +218,9 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         flight_service: Option<FraiseQLFlightService>,
     ) -> Result<Self> {
         // Same boot gates as `Server::new` — these must not drift by constructor (H16).
-        // Refuse to boot on at-rest-encryption-marked fields (H12, plaintext write path).
-        crate::server::initialization::field_encryption_unsupported_check(&schema)?;
+        // Refuse to boot on at-rest-encryption-marked fields without a secrets build (H12).
+        crate::server::initialization::field_encryption_check(&schema, cfg!(feature = "secrets"))?;
+        crate::server::initialization::computed_field_expression_check(&schema)?;
         // Build the runtime config from the compiled schema (validates format version,
         // reads the audit flag, applies the #421 page-size ceiling + change-log toggle).
         let executor_config = RuntimeConfig::from_compiled_schema(&schema).map_err(|msg| {
@@ -294,7 +293,7 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                     rps_per_user = rate_config.rps_per_user,
                     "Initializing rate limiting from server config"
                 );
-                Some(Arc::new(RateLimiter::new(rate_config.clone())))
+                Some(Arc::new(Self::build_rate_limiter(rate_config.clone()).await))
             } else {
                 info!("Rate limiting disabled by configuration");
                 None
@@ -324,8 +323,15 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         #[cfg(feature = "auth")]
         Self::spawn_pkce_cleanup(pkce_store.as_ref(), &mut tasks);
 
-        let apq_enabled = config.apq_enabled;
+        let apq_store = if config.apq_enabled {
+            Some(super::builder::build_apq_store(&config, &mut tasks).await?)
+        } else {
+            None
+        };
 
+        let shutdown = Arc::new(crate::shutdown::ShutdownCoordinator::new(
+            std::time::Duration::from_secs(config.shutdown_timeout_secs),
+        ));
         Ok(Self {
             config,
             executor,
@@ -337,6 +343,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             rate_limiter,
             #[cfg(feature = "secrets")]
             secrets_manager: None,
+            #[cfg(feature = "secrets")]
+            secret_references: None,
             #[cfg(feature = "federation")]
             circuit_breaker,
             error_sanitizer,
@@ -355,18 +363,14 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             api_key_authenticator,
             service_account_authenticator,
             revocation_manager,
-            apq_store: if apq_enabled {
-                Some(Arc::new(fraiseql_core::apq::InMemoryApqStorage::default())
-                    as fraiseql_core::apq::ArcApqStorage)
-            } else {
-                None
-            },
+            apq_store,
             trusted_docs,
             #[cfg(feature = "mcp")]
             mcp_config: None,
             pool_tuning_config: None,
             #[cfg(feature = "observers")]
             observer_runtime,
+            admin_audit: Arc::new(crate::admin_audit::AdminAuditLog::new(db_pool.clone())),
             #[cfg(feature = "auth")]
             enrichment_pool: db_pool.clone(),
             #[cfg(feature = "observers")]
@@ -375,6 +379,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             #[cfg(feature = "functions-runtime")]
             functions_hooks: None,
             tenant_executor_factory: None,
+            canary: None,
+            shadow: None,
             #[cfg(feature = "arrow")]
             flight_service,
             adapter_cache_enabled: false,
@@ -385,7 +391,11 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             #[cfg(feature = "functions")]
             function_runtime: None,
             usage: Arc::clone(crate::usage::aggregator::global_aggregator()),
+            operation_audit: None,
+            field_usage: None,
             tasks,
+            shutdown,
+            config_source: None,
         })
     }
 
@@ -450,6 +460,15 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             crate::ServerError::ConfigError(format!("invalid observer transport config: {e}"))
         })?;
 
+        observer_config.runtime.metrics.validate().map_err(|e| {
+            crate::ServerError::ConfigError(format!("invalid observer metrics config: {e}"))
+        })?;
+        fraiseql_observers::metrics::configure_labels(&observer_config.runtime.metrics);
+
+        observer_config.runtime.event_types.validate().map_err(|e| {
+            crate::ServerError::ConfigError(format!("invalid observer event types config: {e}"))
+        })?;
+
         let runtime_config = ObserverRuntimeConfig::new(pool.clone())
             .with_poll_interval(observer_config.runtime.poll_interval_ms)
             .with_batch_size(observer_config.runtime.batch_size)
@@ -457,7 +476,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             .with_max_dlq_size(observer_config.runtime.max_dlq_size)
             .with_transport(transport)
             .with_email(observer_config.runtime.email.clone())
-            .with_log_payloads(observer_config.runtime.log_payloads);
+            .with_log_payloads(observer_config.runtime.log_payloads)
+            .with_event_types(observer_config.runtime.event_types.clone());
 
         let runtime = ObserverRuntime::new(runtime_config);
         Ok(Some(Arc::new(RwLock::new(runtime))))
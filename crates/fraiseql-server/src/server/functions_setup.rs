@@ -27,12 +27,13 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     /// declared function's module is missing/unreadable (fail-loud: a declared
     /// function that can never run is a misconfiguration).
     pub(super) async fn prepare_functions_runtime(&mut self) -> Result<(), ServerError> {
-        let extended = CompiledSchemaLoader::new(&self.config.schema_path)
-            .load_extended()
-            .await
-            .map_err(|error| {
-                ServerError::ConfigError(format!("failed to load functions config: {error}"))
-            })?;
+        let mut loader = CompiledSchemaLoader::new(&self.config.schema_path);
+        if let Some(key) = self.config.schema_verifying_key().map_err(ServerError::ConfigError)? {
+            loader = loader.with_public_key(key);
+        }
+        let extended = loader.load_extended().await.map_err(|error| {
+            ServerError::ConfigError(format!("failed to load functions config: {error}"))
+        })?;
 
         let Some(functions_config) = extended.functions else {
             return Ok(()); // no `functions` in the compiled schema
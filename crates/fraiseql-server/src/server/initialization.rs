@@ -198,6 +198,19 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         crate::auth::OidcServerClient::from_compiled_schema(&schema_json)
     }
 
+    /// The `security.rate_limiting` section of the compiled schema, when present
+    /// and `enabled = true`; it takes priority over `[rate_limiting]`.
+    pub(super) fn schema_rate_limiting(
+        schema: &CompiledSchema,
+    ) -> Option<crate::middleware::RateLimitingSecurityConfig> {
+        schema
+            .security
+            .as_ref()
+            .and_then(|s| s.additional.get("rate_limiting"))
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .filter(|sec: &crate::middleware::RateLimitingSecurityConfig| sec.enabled)
+    }
+
     /// Build a `RateLimiter` from the `security.rate_limiting` key embedded in the
     /// compiled schema, if present and `enabled = true`.
     ///
@@ -215,19 +228,10 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     pub(super) async fn rate_limiter_from_schema(
         schema: &CompiledSchema,
     ) -> crate::Result<Option<Arc<RateLimiter>>> {
-        let Some(sec): Option<crate::middleware::RateLimitingSecurityConfig> = schema
-            .security
-            .as_ref()
-            .and_then(|s| s.additional.get("rate_limiting"))
-            .and_then(|v| serde_json::from_value(v.clone()).ok())
-        else {
+        let Some(sec) = Self::schema_rate_limiting(schema) else {
             return Ok(None);
         };
 
-        if !sec.enabled {
-            return Ok(None);
-        }
-
         // SECURITY (#356): the binary performs no first-factor login, so it cannot
         // honour failed_login_max_attempts / failed_login_lockout_secs. Refuse to
         // boot in production when an operator has tuned them away from the defaults
@@ -250,48 +254,59 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
 
         let config = crate::middleware::RateLimitConfig::from_security_config(&sec);
 
-        let limiter: RateLimiter = if let Some(ref redis_url) = sec.redis_url {
-            #[cfg(feature = "redis-rate-limiting")]
-            {
-                match RateLimiter::new_redis(redis_url, config.clone()).await {
-                    Ok(rl) => {
-                        info!(
-                            url = redis_url.as_str(),
-                            rps_per_ip = config.rps_per_ip,
-                            burst_size = config.burst_size,
-                            "Rate limiting: using Redis distributed backend"
-                        );
-                        rl.with_path_rules_from_security(&sec)
-                    },
-                    Err(e) => {
-                        tracing::error!(
-                            error = %e,
-                            "Failed to connect to Redis for rate limiting — \
-                             falling back to in-memory backend"
-                        );
-                        RateLimiter::new(config).with_path_rules_from_security(&sec)
-                    },
-                }
-            }
-            #[cfg(not(feature = "redis-rate-limiting"))]
-            {
-                let _ = redis_url;
-                warn!(
-                    "rate_limiting.redis_url is set but the server was compiled without the \
-                     'redis-rate-limiting' feature. Using in-memory backend."
-                );
-                RateLimiter::new(config).with_path_rules_from_security(&sec)
-            }
-        } else {
+        let limiter = Self::build_rate_limiter(config).await.with_path_rules_from_security(&sec);
+
+        Ok(Some(Arc::new(limiter)))
+    }
+
+    /// Build a rate limiter for `config`: the Redis backend when `redis_url` is set
+    /// (so every replica shares the same buckets), otherwise the in-memory backend.
+    ///
+    /// An unreachable Redis at startup, or a build without the `redis-rate-limiting`
+    /// feature, falls back to the in-memory backend rather than refusing to boot.
+    pub(super) async fn build_rate_limiter(
+        config: crate::middleware::RateLimitConfig,
+    ) -> RateLimiter {
+        let Some(redis_url) = config.redis_url.clone() else {
             info!(
                 rps_per_ip = config.rps_per_ip,
                 burst_size = config.burst_size,
                 "Rate limiting: using in-memory backend"
             );
-            RateLimiter::new(config).with_path_rules_from_security(&sec)
+            return RateLimiter::new(config);
         };
 
-        Ok(Some(Arc::new(limiter)))
+        #[cfg(feature = "redis-rate-limiting")]
+        {
+            match RateLimiter::new_redis(&redis_url, config.clone()).await {
+                Ok(rl) => {
+                    info!(
+                        url = redis_url.as_str(),
+                        rps_per_ip = config.rps_per_ip,
+                        burst_size = config.burst_size,
+                        "Rate limiting: using Redis distributed backend"
+                    );
+                    rl
+                },
+                Err(e) => {
+                    tracing::error!(
+                        error = %e,
+                        "Failed to connect to Redis for rate limiting — \
+                         falling back to in-memory backend"
+                    );
+                    RateLimiter::new(config)
+                },
+            }
+        }
+        #[cfg(not(feature = "redis-rate-limiting"))]
+        {
+            let _ = redis_url;
+            warn!(
+                "rate_limiting.redis_url is set but the server was compiled without the \
+                 'redis-rate-limiting' feature. Using in-memory backend."
+            );
+            RateLimiter::new(config)
+        }
     }
 
     /// Build an `ErrorSanitizer` from the `security.error_sanitization` key in the
@@ -898,25 +913,84 @@ pub(super) fn is_manifest_url_ssrf_blocked(url: &str) -> bool {
     false
 }
 
-/// Refuse to boot when the compiled schema marks any field for at-rest encryption.
+/// Refuse to boot when fields are marked for at-rest encryption but nothing can encrypt them.
 ///
-/// Write-path field encryption is **not implemented** in this release (H12): the mutation
-/// executor never encrypts on write — `FieldEncryptionService::encrypt_variables` has no
-/// caller — so a field marked `encryption` is stored in **plaintext** while the read path
-/// attempts to decrypt it, returning a 500 (`Field decryption failed`) on every read. Worse,
-/// when the `secrets` feature is absent the field round-trips silently in plaintext, so an
-/// operator believes sensitive columns are encrypted at rest when they are not.
+/// Encrypted fields are encrypted on write and decrypted on read by the
+/// `FieldEncryptionService`, which fetches its keys from the `SecretsManager`. Without the
+/// `secrets` feature, or without a configured secrets backend, those fields would be
+/// stored in plaintext, so the server names the offending field(s) and refuses to start
+/// instead. Constructors run this with `secrets_available = cfg!(feature = "secrets")`;
+/// serving re-runs it once the secrets manager is (or is not) attached.
 ///
-/// Rather than silently storing sensitive data in plaintext, the server refuses to start and
-/// names the offending field(s). This is the honest interim until end-to-end field encryption
-/// (write-path call, array/nested recursion, `(type, field)` keying, ciphertext versioning,
-/// and key KDF/zeroize) is implemented.
+/// # Errors
+///
+/// Returns `ServerError::ConfigError` when any field declares `encryption` and
+/// `secrets_available` is `false`.
+pub(super) fn field_encryption_check(
+    schema: &CompiledSchema,
+    secrets_available: bool,
+) -> crate::Result<()> {
+    if secrets_available {
+        return Ok(());
+    }
+
+    let encrypted = encrypted_fields(schema);
+    if encrypted.is_empty() {
+        return Ok(());
+    }
+
+    Err(crate::ServerError::ConfigError(format!(
+        "Field-level at-rest encryption is configured for {} but no secrets backend is \
+         available to encrypt them, so they would be stored in plaintext. Build with the \
+         `secrets` feature and set FRAISEQL_SECRETS_BACKEND, or remove the `encrypted` \
+         marker from these field(s).",
+        encrypted.join(", ")
+    )))
+}
+
+/// Refuse to boot when encrypted fields are reachable through a transport that bypasses
+/// field encryption.
+///
+/// Encrypted fields are only encrypted and decrypted by the GraphQL HTTP handler. REST
+/// writes would store them in plaintext, and REST and Arrow Flight reads would return
+/// ciphertext, so the server names the fields and refuses to start while either
+/// transport is enabled.
 ///
 /// # Errors
 ///
-/// Returns `ServerError::ConfigError` when any field in the schema declares `encryption`.
-pub(super) fn field_encryption_unsupported_check(schema: &CompiledSchema) -> crate::Result<()> {
-    let encrypted: Vec<String> = schema
+/// Returns `ServerError::ConfigError` when any field declares `encryption` and
+/// `rest_enabled` or `flight_enabled` is `true`.
+pub(super) fn encrypted_field_transport_check(
+    schema: &CompiledSchema,
+    rest_enabled: bool,
+    flight_enabled: bool,
+) -> crate::Result<()> {
+    let transports: Vec<&str> = [("REST", rest_enabled), ("Arrow Flight", flight_enabled)]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect();
+    if transports.is_empty() {
+        return Ok(());
+    }
+
+    let encrypted = encrypted_fields(schema);
+    if encrypted.is_empty() {
+        return Ok(());
+    }
+
+    Err(crate::ServerError::ConfigError(format!(
+        "Field-level at-rest encryption is configured for {} but {} would serve them \
+         without encrypting writes or decrypting reads. Disable {} or remove the \
+         `encrypted` marker from these field(s).",
+        encrypted.join(", "),
+        transports.join(" and "),
+        transports.join(" and "),
+    )))
+}
+
+/// `Type.field` names of every field marked for at-rest encryption.
+fn encrypted_fields(schema: &CompiledSchema) -> Vec<String> {
+    schema
         .types
         .iter()
         .flat_map(|t| {
@@ -925,18 +999,43 @@ pub(super) fn field_encryption_unsupported_check(schema: &CompiledSchema) -> cra
                 .filter(|f| f.encryption.is_some())
                 .map(move |f| format!("{}.{}", t.name, f.name))
         })
+        .collect()
+}
+
+/// Refuse to boot when a computed field's `sql_expression` is not a single safe expression.
+///
+/// The compiler rejects these, but a hand-edited `schema.compiled.json` reaches the server
+/// unchecked, and the expression is interpolated verbatim into the `SELECT` list of every
+/// query returning its type.
+///
+/// # Errors
+///
+/// Returns `ServerError::ConfigError` naming every field whose expression contains a
+/// statement separator, a comment, an unterminated quote, or unbalanced parentheses.
+pub(super) fn computed_field_expression_check(schema: &CompiledSchema) -> crate::Result<()> {
+    let unsafe_fields: Vec<String> = schema
+        .types
+        .iter()
+        .flat_map(|t| {
+            t.fields
+                .iter()
+                .filter(|f| {
+                    f.sql_expression
+                        .as_deref()
+                        .is_some_and(|expr| !fraiseql_core::db::is_safe_sql_expression(expr))
+                })
+                .map(move |f| format!("{}.{}", t.name, f.name))
+        })
         .collect();
 
-    if encrypted.is_empty() {
+    if unsafe_fields.is_empty() {
         return Ok(());
     }
 
     Err(crate::ServerError::ConfigError(format!(
-        "Field-level at-rest encryption is configured for {} but is not supported in this \
-         release: the mutation path does not encrypt on write, so these field(s) would be \
-         stored in plaintext and then fail to decrypt on read (HTTP 500). Remove the \
-         `encryption` marker from these field(s) — and any `[security.field_encryption]` \
-         config — to start the server.",
-        encrypted.join(", ")
+        "Computed field(s) {} declare an unsafe `sql_expression`: an expression must not \
+         contain `;`, `--`, `/*`, unterminated quotes, or unbalanced parentheses. Recompile \
+         the schema with `fraiseql compile` to start the server.",
+        unsafe_fields.join(", ")
     )))
 }
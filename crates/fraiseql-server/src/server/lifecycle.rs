@@ -49,6 +49,14 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             }
         }
 
+        // Ensure the admin audit table exists before any admin mutation is served.
+        let observer_admin_api = cfg!(feature = "observers") && self.oidc_validator.is_some();
+        if self.config.admin_api_enabled || observer_admin_api {
+            self.admin_audit.init().await.map_err(|e| {
+                ServerError::ConfigError(format!("Failed to initialize admin audit schema: {e}"))
+            })?;
+        }
+
         // Ensure the inbound-ingestion tables exist before the router mounts
         // POST /webhooks/{provider}. Like RBAC, this must run here (async context)
         // rather than in the sync build_router(). The spine holds normalized
@@ -143,6 +151,99 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             }
         }
 
+        // Connect the operation audit log if configured. Fail-loud like the RBAC
+        // schema init: an operator who asked for an audit trail must not get a
+        // server that silently records nothing.
+        if let Some(audit_cfg) = self.config.operation_audit.clone() {
+            let (log, worker) = fraiseql_core::security::OperationAuditLog::connect(
+                &self.config.database_url,
+                &audit_cfg,
+            )
+            .await
+            .map_err(|e| {
+                ServerError::ConfigError(format!("Failed to initialize operation audit log: {e}"))
+            })?;
+            self.operation_audit = Some(log);
+            self.tasks.spawn(worker.run());
+            info!(
+                sample_rate = audit_cfg.sample_rate,
+                retention_days = audit_cfg.retention_days,
+                "Operation audit log: recording mutations to tb_graphql_audit"
+            );
+        }
+
+        // Field usage analytics: counting starts immediately; persistence is
+        // best-effort like usage metering — a database failure leaves the
+        // admin endpoint serving in-memory counts.
+        if let Some(field_cfg) = self.config.field_usage.clone() {
+            use std::time::Duration;
+
+            use sqlx::postgres::PgPoolOptions;
+            use tokio::time::MissedTickBehavior;
+
+            use crate::usage::fields::{FieldUsageAggregator, FieldUsageStore};
+
+            let aggregator = std::sync::Arc::new(FieldUsageAggregator::new(field_cfg.max_clients));
+            let store = match PgPoolOptions::new()
+                .max_connections(1) // periodic flushes only
+                .connect(&self.config.database_url)
+                .await
+            {
+                Ok(pool) => FieldUsageStore::new(pool).await,
+                Err(e) => Err(format!("failed to connect to PostgreSQL: {e}")),
+            };
+            match store {
+                Ok(store) => {
+                    let flush_interval = Duration::from_secs(field_cfg.flush_interval_secs);
+                    let agg = std::sync::Arc::clone(&aggregator);
+                    self.tasks.spawn(async move {
+                        let mut ticker = tokio::time::interval(flush_interval);
+                        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                        ticker.tick().await; // skip immediate first tick
+                        loop {
+                            ticker.tick().await;
+                            if let Err(e) = store.flush(&agg).await {
+                                warn!(error = %e, "Field usage: background flush failed");
+                            }
+                        }
+                    });
+                    info!(
+                        flush_interval_secs = field_cfg.flush_interval_secs,
+                        max_clients = field_cfg.max_clients,
+                        "Field usage: flushing selection counters to fraiseql_field_usage"
+                    );
+                },
+                Err(e) => {
+                    warn!(
+                        error = %e,
+                        "Field usage: persistence unavailable — counting in memory only"
+                    );
+                },
+            }
+            self.field_usage = Some(aggregator);
+        }
+
+        // Scheduled backups: fail-loud like the operation audit log — an operator
+        // who configured backups must not get a server that silently takes none.
+        if let Some(backup_cfg) = self.config.backup.clone().filter(|cfg| cfg.enabled) {
+            use crate::backup::{BackupRunner, BackupScheduler};
+
+            if !self.config.database_url.starts_with("postgres") {
+                return Err(ServerError::ConfigError(
+                    "[backup] requires a PostgreSQL database (backups use pg_dump)".to_string(),
+                ));
+            }
+            let interval_secs = backup_cfg.interval_secs;
+            let runner = BackupRunner::from_config(*backup_cfg, &self.config.database_url)
+                .await
+                .map_err(|e| {
+                    ServerError::ConfigError(format!("Failed to initialize backups: {e}"))
+                })?;
+            let scheduler = BackupScheduler::new(std::sync::Arc::new(runner), self.db_pool.clone());
+            self.tasks.spawn(scheduler.run_forever());
+            info!(interval_secs, "Backup: scheduled logical backups enabled");
+        }
+
         // Prepare functions-runtime dispatch (load modules, register runtimes,
         // attach the send_email wiring) before the router is built, so
         // `build_app_state` mounts the before-mutation hooks. Async + fail-loud,
@@ -151,8 +252,34 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         #[cfg(feature = "functions-runtime")]
         self.prepare_functions_runtime().await?;
 
+        // Load the `[canary]` candidate schema next to the current one, fail-loud like
+        // the functions runtime above; a no-op when no canary is configured.
+        self.prepare_canary().await?;
+
+        // Build the `[shadow]` executor; a no-op when no shadow is configured.
+        self.prepare_shadow().await?;
+
+        // The secrets manager is attached after construction, so the encrypted-field
+        // boot gate is re-run here against the final configuration.
+        self.check_field_encryption_ready()?;
+
         let (app, app_state) = self.build_router();
 
+        // Re-probe critical dependencies until they are ready, then open the
+        // readiness gate so traffic flows without waiting for a `/readiness` hit.
+        if let Some(ref gate) = app_state.readiness_gate {
+            let interval = self
+                .config
+                .readiness
+                .as_ref()
+                .map_or(std::time::Duration::from_secs(1), |r| r.gate_poll_interval());
+            self.tasks.spawn(crate::readiness::open_when_ready(
+                app_state.clone(),
+                gate.clone(),
+                interval,
+            ));
+        }
+
         // Start the poll-IMAP email workers.
         // Each configured `[mailbox.<name>.imap]` half runs a background poll loop
         // on the server's JoinSet, so graceful shutdown aborts them. The workers
@@ -374,6 +501,33 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             );
         }
 
+        // Spawn the SIGHUP config reload handler when config reload is enabled
+        // (`Server::with_config_reload`). Like SIGUSR1 it loops until the process
+        // exits; a failed reload keeps the active configuration.
+        #[cfg(unix)]
+        if let Some(ref reloader) = app_state.config_reloader {
+            let reloader = reloader.clone();
+            self.tasks.spawn(async move {
+                let mut sighup = match tokio::signal::unix::signal(
+                    tokio::signal::unix::SignalKind::hangup(),
+                ) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        warn!(error = %e, "Failed to install SIGHUP handler — config reload via signal disabled");
+                        return;
+                    },
+                };
+                loop {
+                    sighup.recv().await;
+                    info!("Received SIGHUP — reloading configuration");
+                    if let Err(e) = reloader.reload().await {
+                        error!(error = %e, "Config reload failed via SIGHUP — keeping previous configuration");
+                    }
+                }
+            });
+            info!("SIGHUP config reload handler installed");
+        }
+
         // Initialize TLS setup (database connection TLS; server-side TLS is unsupported).
         let tls_setup = TlsSetup::new(self.config.tls.clone(), self.config.database_tls.clone());
 
@@ -417,6 +571,21 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                 let mut guard = runtime.write().await;
                 guard.set_event_bridge_sender(sender);
 
+                // Webhook `signing_secret_ref`s resolve through the configured
+                // secrets manager; without one they fail loud at dispatch.
+                #[cfg(feature = "secrets")]
+                if let Some(ref manager) = self.secrets_manager {
+                    guard.set_signing_secret_resolver(std::sync::Arc::new(
+                        crate::observers::signing_secrets::SecretsManagerSigningSecrets::new(
+                            manager.clone(),
+                        ),
+                    ));
+                }
+                #[cfg(feature = "secrets")]
+                if let Some(ref resolver) = self.secret_references {
+                    guard.set_secret_reference_resolver(resolver.clone());
+                }
+
                 // #366: wire after:capture dispatch — externally-captured writes
                 // (from the change-log reader) drive `after:capture` functions on
                 // the phase-02 `run_as` host. The hook is a cheap no-op for
@@ -530,99 +699,125 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
 
         info!("Server listening on http://{}", self.config.bind_addr);
 
-        // Start both HTTP and gRPC servers concurrently if Arrow Flight is enabled
+        // SIGTERM starts the shutdown phases (see `crate::shutdown`): the
+        // coordinator refuses new requests and starts the deadline, and every
+        // phase below drains against it.
+        let coordinator = self.shutdown.clone();
+        let signal = {
+            let coordinator = coordinator.clone();
+            async move {
+                shutdown.await;
+                coordinator.begin();
+            }
+        };
+
+        // Start the Arrow Flight server alongside HTTP. It stops accepting calls
+        // when shutdown starts and its in-flight streams are drained below.
         #[cfg(feature = "arrow")]
-        if let Some(flight_service) = self.flight_service.take() {
+        let flight_handle = self.flight_service.take().map(|flight_service| {
             let flight_addr = self.config.flight_bind_addr;
             info!("Arrow Flight server listening on grpc://{}", flight_addr);
-
-            // Spawn Flight server in background, registered on the server's
-            // JoinSet. The set's `shutdown` step abort-then-awaits the gRPC
-            // server when the HTTP server exits.
-            self.tasks.spawn(async move {
+            let stop = coordinator.clone();
+            tokio::spawn(async move {
                 if let Err(e) = tonic::transport::Server::builder()
                     .add_service(flight_service.into_server())
-                    .serve(flight_addr)
+                    .serve_with_shutdown(flight_addr, async move { stop.requested().await })
                     .await
                 {
                     error!(error = %e, "Arrow Flight server terminated with error");
                 }
-            });
-
-            // Wrap the user-supplied shutdown future so we can also stop observer runtime
-            #[cfg(feature = "observers")]
-            let observer_runtime = self.observer_runtime.clone();
-
-            let shutdown_with_cleanup = async move {
-                shutdown.await;
-                #[cfg(feature = "observers")]
-                if let Some(ref runtime) = observer_runtime {
-                    info!("Shutting down observer runtime");
-                    let mut guard = runtime.write().await;
-                    if let Err(e) = guard.stop().await {
-                        #[cfg(feature = "observers")]
-                        error!("Error stopping runtime: {}", e);
-                    } else {
-                        info!("Runtime stopped cleanly");
-                    }
-                }
-            };
-
-            // Run HTTP server with graceful shutdown
-            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
-                .with_graceful_shutdown(shutdown_with_cleanup)
-                .await
-                .map_err(|e| ServerError::IoError(std::io::Error::other(e)))?;
-
-            // Abort and await every lifecycle task (Flight server, SIGUSR1
-            // handler, PKCE cleanup, trusted-docs reload, usage flush, …).
-            drain_lifecycle_tasks(self.tasks, self.config.shutdown_timeout_secs).await;
+            })
+        });
+
+        // Run HTTP server with graceful shutdown, bounded by the drain deadline.
+        let serve = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+            .with_graceful_shutdown(signal);
+        let drain_deadline = async {
+            coordinator.requested().await;
+            tokio::time::sleep(coordinator.remaining()).await;
+        };
+        tokio::select! {
+            result = std::future::IntoFuture::into_future(serve) => {
+                result.map_err(|e| ServerError::IoError(std::io::Error::other(e)))?;
+            },
+            () = drain_deadline => {
+                warn!(
+                    in_flight = coordinator.in_flight(),
+                    timeout_secs = self.config.shutdown_timeout_secs,
+                    "HTTP drain deadline reached; abandoning in-flight requests"
+                );
+            },
         }
+        let abandoned = coordinator.drain_requests().await;
+        info!("HTTP server stopped, draining remaining work");
 
-        // HTTP-only server (when arrow feature not enabled)
-        #[cfg(not(feature = "arrow"))]
-        {
-            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
-                .with_graceful_shutdown(shutdown)
-                .await
-                .map_err(|e| ServerError::IoError(std::io::Error::other(e)))?;
+        let mut steps = Vec::new();
 
-            let shutdown_timeout =
-                std::time::Duration::from_secs(self.config.shutdown_timeout_secs);
-            info!(
-                timeout_secs = self.config.shutdown_timeout_secs,
-                "HTTP server stopped, draining remaining work"
+        // Stop observers: finish the in-flight batch and persist its checkpoint.
+        #[cfg(feature = "observers")]
+        if let Some(ref runtime) = self.observer_runtime {
+            let budget = coordinator.remaining();
+            steps.push(
+                crate::shutdown::run_step("observers", budget, async {
+                    match runtime.write().await.stop_within(budget).await {
+                        Ok(true) => Ok(()),
+                        Ok(false) => {
+                            Err("in-flight batch did not finish; it will be redelivered"
+                                .to_string())
+                        },
+                        Err(e) => Err(e.to_string()),
+                    }
+                })
+                .await,
             );
+        }
 
-            let drain = tokio::time::timeout(shutdown_timeout, async {
-                #[cfg(feature = "observers")]
-                if let Some(ref runtime) = self.observer_runtime {
-                    let mut guard = runtime.write().await;
-                    match guard.stop().await {
-                        Ok(()) => info!("Observer runtime stopped cleanly"),
-                        Err(e) => warn!("Observer runtime shutdown error: {e}"),
-                    }
-                }
+        // Wait for in-flight Flight streams.
+        #[cfg(feature = "arrow")]
+        if let Some(handle) = flight_handle {
+            let abort = handle.abort_handle();
+            let step = crate::shutdown::run_step("flight", coordinator.remaining(), async {
+                handle.await.map_err(|e| e.to_string())
             })
             .await;
+            abort.abort();
+            steps.push(step);
+        }
 
-            if drain.is_err() {
-                warn!(
-                    timeout_secs = self.config.shutdown_timeout_secs,
-                    "Shutdown drain timed out; forcing exit"
-                );
-            } else {
-                info!("Graceful shutdown complete");
-            }
+        // Close sinks and other resources registered by the embedder.
+        steps.extend(coordinator.run_close_hooks().await);
 
-            // Abort and await every lifecycle task (SIGUSR1 handler, PKCE
-            // cleanup, trusted-docs reload, usage flush, …).
-            drain_lifecycle_tasks(self.tasks, self.config.shutdown_timeout_secs).await;
-        }
+        // Abort and await every lifecycle task (SIGUSR1 handler, PKCE cleanup,
+        // trusted-docs reload, usage flush, …).
+        drain_lifecycle_tasks(self.tasks, self.config.shutdown_timeout_secs).await;
 
+        coordinator.summary(abandoned, steps).log();
         Ok(())
     }
 
+    /// Refuse to serve encrypted fields without a secrets manager to encrypt them, or
+    /// over a transport (REST, Arrow Flight) that does not encrypt them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::ConfigError` naming the encrypted fields.
+    fn check_field_encryption_ready(&self) -> Result<()> {
+        let schema = self.executor.schema();
+        #[cfg(feature = "secrets")]
+        let secrets_available = self.secrets_manager.is_some();
+        #[cfg(not(feature = "secrets"))]
+        let secrets_available = false;
+        super::initialization::field_encryption_check(schema, secrets_available)?;
+
+        let rest_enabled =
+            cfg!(feature = "rest") && schema.rest_config.as_ref().is_some_and(|c| c.enabled);
+        #[cfg(feature = "arrow")]
+        let flight_enabled = self.flight_service.is_some();
+        #[cfg(not(feature = "arrow"))]
+        let flight_enabled = false;
+        super::initialization::encrypted_field_transport_check(schema, rest_enabled, flight_enabled)
+    }
+
     /// Start server on an externally created listener.
     ///
     /// Used in tests to discover the bound port before serving.
@@ -635,9 +830,14 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     where
         F: std::future::Future<Output = ()> + Send + 'static,
     {
+        self.check_field_encryption_ready()?;
         let (app, _app_state) = self.build_router();
+        let coordinator = self.shutdown.clone();
         axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
-            .with_graceful_shutdown(shutdown)
+            .with_graceful_shutdown(async move {
+                shutdown.await;
+                coordinator.begin();
+            })
             .await
             .map_err(|e| ServerError::IoError(std::io::Error::other(e)))?;
         // Abort and await any lifecycle tasks spawned during construction
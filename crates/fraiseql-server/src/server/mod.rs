@@ -34,12 +34,14 @@ use crate::{
 };
 
 mod builder;
+mod canary_setup;
 mod extensions;
 #[cfg(feature = "functions-runtime")]
 mod functions_setup;
 mod initialization;
 mod lifecycle;
 mod routing;
+mod shadow_setup;
 
 #[cfg(test)]
 mod routing_tests;
@@ -82,6 +84,9 @@ pub struct Server<A: DatabaseAdapter> {
     pub(super) rate_limiter: Option<Arc<RateLimiter>>,
     #[cfg(feature = "secrets")]
     pub(super) secrets_manager: Option<Arc<crate::secrets_manager::SecretsManager>>,
+    /// Resolves `${secret:path}` references in observer action configs.
+    #[cfg(feature = "secrets")]
+    pub(super) secret_references: Option<Arc<crate::secrets_manager::SecretReferenceResolver>>,
     #[cfg(feature = "federation")]
     pub(super) circuit_breaker:
         Option<Arc<crate::federation::circuit_breaker::FederationCircuitBreakerManager>>,
@@ -161,6 +166,14 @@ pub struct Server<A: DatabaseAdapter> {
     /// works).
     pub(super) tenant_executor_factory: Option<crate::tenancy::TenantExecutorFactory<A>>,
 
+    /// Candidate schema rollout, loaded from `[canary]` at serve time by
+    /// `prepare_canary`. `None` when no canary is configured.
+    pub(super) canary: Option<Arc<crate::canary::CanaryRollout<A>>>,
+
+    /// Shadow executor mirroring sampled read queries, built from `[shadow]` at
+    /// serve time by `prepare_shadow`. `None` when no shadow is configured.
+    pub(super) shadow: Option<Arc<crate::shadow::ShadowMirror<A>>>,
+
     /// Pool pressure monitoring configuration (loaded from `[pool_tuning]` in `fraiseql.toml`).
     pub(super) pool_tuning_config: Option<crate::config::pool_tuning::PoolPressureMonitorConfig>,
 
@@ -198,6 +211,14 @@ pub struct Server<A: DatabaseAdapter> {
     /// [`AppState::usage`]: crate::routes::graphql::AppState::usage
     pub(super) usage: Arc<crate::usage::aggregator::UsageAggregator>,
 
+    /// Operation audit log handle, connected in `serve_with_shutdown` when
+    /// `[operation_audit]` is configured.
+    pub(super) operation_audit: Option<fraiseql_core::security::OperationAuditLog>,
+
+    /// Per-client field selection counters, created in `serve_with_shutdown`
+    /// when `[field_usage]` is configured.
+    pub(super) field_usage: Option<Arc<crate::usage::fields::FieldUsageAggregator>>,
+
     /// Background lifecycle tasks owned by the server.
     ///
     /// Long-running tasks spawned during server construction or `serve_with_shutdown`
@@ -206,4 +227,18 @@ pub struct Server<A: DatabaseAdapter> {
     /// this [`tokio::task::JoinSet`]. On graceful shutdown the server aborts and
     /// awaits the set so per-process state is not abandoned mid-flight.
     pub(super) tasks: tokio::task::JoinSet<()>,
+
+    /// Shutdown phases: stop accepting, drain requests, stop observers and
+    /// Flight, run close hooks.
+    pub(super) shutdown: Arc<crate::shutdown::ShutdownCoordinator>,
+
+    /// Source re-read on SIGHUP or `POST /api/v1/admin/config/reload`.
+    ///
+    /// Set via [`Server::with_config_reload`]. When `None`, config reload is
+    /// unavailable (the signal is ignored and the endpoint is not mounted).
+    pub(super) config_source: Option<crate::config_reload::ConfigSource>,
+
+    /// Audit trail for admin API mutations, persisted to `_fraiseql_admin_audit`
+    /// when a database pool is available.
+    pub(super) admin_audit: Arc<crate::admin_audit::AdminAuditLog>,
 }
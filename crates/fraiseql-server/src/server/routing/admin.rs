@@ -18,7 +18,12 @@ use super::super::{
     metrics_json_handler, oidc_auth_middleware, playground_handler, readiness_handler,
     required_auth_middleware, subscription_handler,
 };
-use crate::routes::graphql::AppState;
+use crate::{
+    admin_audit::admin_audit_middleware,
+    middleware::{AdminRole, AdminTokenAuthState, admin_token_role_middleware},
+    routes::graphql::AppState,
+    server_config::PlaygroundTool,
+};
 
 impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     /// Mount base routes (health, readiness), studio, admin API, playground,
@@ -66,9 +71,16 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             }
         }
 
-        // Conditionally add playground route (with optional independent auth).
+        // Conditionally add playground / GraphiQL routes (with optional independent auth).
         if self.config.playground_enabled {
-            app = self.mount_playground(app, state);
+            app = self.mount_playground(
+                app,
+                &self.config.playground_path,
+                self.config.playground_tool,
+            );
+        }
+        if self.config.graphiql_enabled {
+            app = self.mount_playground(app, &self.config.graphiql_path, PlaygroundTool::GraphiQL);
         }
 
         // Conditionally add /.well-known/security.txt (RFC 9116)
@@ -140,10 +152,7 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                 delete_object_handler, list_buckets_handler, list_objects_handler, presign_handler,
             },
         };
-        let auth = BearerAuthState::with_max_failures(
-            token.to_string(),
-            self.config.admin_auth_max_failures,
-        );
+        let auth = self.admin_token_auth(token, AdminRole::Admin);
         let studio_admin_router = Router::new()
             // Schema + health
             .route("/admin/v1/schema", get(studio_schema_handler::<A>))
@@ -172,7 +181,11 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             )
             // Metrics summary
             .route("/admin/v1/metrics/summary", get(metrics_summary_handler::<A>))
-            .route_layer(middleware::from_fn_with_state(auth, bearer_auth_middleware))
+            .route_layer(middleware::from_fn_with_state(auth, admin_token_role_middleware))
+            .route_layer(middleware::from_fn_with_state(
+                Arc::clone(&self.admin_audit),
+                admin_audit_middleware,
+            ))
             .with_state(state.clone());
         info!("Studio admin API mounted at /admin/v1/* (bearer token required)");
         app.merge(studio_admin_router)
@@ -188,16 +201,17 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         token: &str,
         validator: &Arc<OidcValidator>,
     ) -> Router {
-        let auth = BearerAuthState::with_max_failures(
-            token.to_string(),
-            self.config.admin_auth_max_failures,
-        );
+        let auth = self.admin_token_auth(token, AdminRole::Admin);
         let router = Router::new()
             .route(
                 "/admin/v1/auth/refresh-jwks",
                 post(crate::routes::jwks_admin::refresh_jwks_handler),
             )
-            .route_layer(middleware::from_fn_with_state(auth, bearer_auth_middleware))
+            .route_layer(middleware::from_fn_with_state(auth, admin_token_role_middleware))
+            .route_layer(middleware::from_fn_with_state(
+                Arc::clone(&self.admin_audit),
+                admin_audit_middleware,
+            ))
             .with_state(Arc::clone(validator));
         info!(
             "JWKS refresh endpoint mounted: POST /admin/v1/auth/refresh-jwks (admin token required)"
@@ -205,43 +219,47 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         app.merge(router)
     }
 
-    fn mount_playground(&self, mut app: Router, _state: &AppState<A>) -> Router {
+    /// Mount a GraphQL IDE at `path`. Shared by the playground and the dedicated
+    /// `GraphiQL` route so both honor the same auth policy.
+    fn mount_playground(&self, mut app: Router, path: &str, tool: PlaygroundTool) -> Router {
         let playground_require_auth = self
             .config
             .playground_require_auth
             .unwrap_or(self.config.introspection_require_auth);
 
-        let playground_state =
-            PlaygroundState::new(self.config.graphql_path.clone(), self.config.playground_tool);
+        let mut playground_state = PlaygroundState::new(self.config.graphql_path.clone(), tool);
+        if self.config.subscriptions_enabled {
+            playground_state =
+                playground_state.with_subscription_endpoint(self.config.subscription_path.clone());
+        }
 
         if playground_require_auth {
             if let Some(ref validator) = self.oidc_validator {
                 info!(
-                    playground_path = %self.config.playground_path,
-                    playground_tool = ?self.config.playground_tool,
+                    playground_path = %path,
+                    playground_tool = ?tool,
                     "GraphQL playground enabled (OIDC auth required)"
                 );
                 let auth_state = self.oidc_auth_state(validator.clone());
                 let playground_router = Router::new()
-                    .route(&self.config.playground_path, get(playground_handler))
+                    .route(path, get(playground_handler))
                     .route_layer(middleware::from_fn_with_state(auth_state, oidc_auth_middleware))
                     .with_state(playground_state);
                 app = app.merge(playground_router);
             } else {
                 warn!(
-                    playground_path = %self.config.playground_path,
+                    playground_path = %path,
                     "playground_require_auth is true but no OIDC configured — playground disabled"
                 );
             }
         } else {
             info!(
-                playground_path = %self.config.playground_path,
-                playground_tool = ?self.config.playground_tool,
+                playground_path = %path,
+                playground_tool = ?tool,
                 "GraphQL playground enabled (no auth required)"
             );
-            let playground_router = Router::new()
-                .route(&self.config.playground_path, get(playground_handler))
-                .with_state(playground_state);
+            let playground_router =
+                Router::new().route(path, get(playground_handler)).with_state(playground_state);
             app = app.merge(playground_router);
         }
         app
@@ -329,8 +347,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                     "GraphQL subscriptions enabled (graphql-transport-ws + graphql-ws protocols, OIDC auth required)"
                 );
                 let auth_state = self.oidc_auth_state(validator.clone());
-                let subscription_router = Router::new()
-                    .route(&self.config.subscription_path, get(subscription_handler))
+                let subscription_router = self
+                    .subscription_routes()
                     .route_layer(middleware::from_fn_with_state(auth_state, oidc_auth_middleware))
                     .with_state(subscription_state);
                 app = app.merge(subscription_router);
@@ -345,14 +363,28 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                 subscription_path = %self.config.subscription_path,
                 "GraphQL subscriptions enabled (graphql-transport-ws + graphql-ws protocols)"
             );
-            let subscription_router = Router::new()
-                .route(&self.config.subscription_path, get(subscription_handler))
-                .with_state(subscription_state);
+            let subscription_router = self.subscription_routes().with_state(subscription_state);
             app = app.merge(subscription_router);
         }
         app
     }
 
+    /// The subscription transports: the `WebSocket` route and, with the `grpc`
+    /// feature, the gRPC `fraiseql.graphql.v1.GraphQL/Subscribe` RPC.
+    fn subscription_routes(&self) -> Router<SubscriptionState> {
+        #[allow(unused_mut)] // Reason: `mut` is needed when the grpc feature is enabled
+        let mut router =
+            Router::new().route(&self.config.subscription_path, get(subscription_handler));
+        #[cfg(feature = "grpc")]
+        {
+            router = router.route(
+                crate::routes::grpc::graphql::SUBSCRIBE_PATH,
+                axum::routing::post(crate::routes::grpc::graphql::grpc_subscribe_handler),
+            );
+        }
+        router
+    }
+
     fn mount_introspection(&self, mut app: Router, state: &AppState<A>) -> Router {
         let metadata_require_auth = self
             .config
@@ -478,20 +510,40 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         app
     }
 
+    /// Static-token admin authentication requiring at least `required`.
+    ///
+    /// `admin_token` carries the admin role, `admin_operator_token` the operator
+    /// role and `admin_readonly_token` the viewer role.
+    fn admin_token_auth(&self, admin_token: &str, required: AdminRole) -> AdminTokenAuthState {
+        let mut auth = AdminTokenAuthState::new(required, self.config.admin_auth_max_failures)
+            .with_token(admin_token, AdminRole::Admin);
+        if let Some(ref token) = self.config.admin_operator_token {
+            auth = auth.with_token(token.clone(), AdminRole::Operator);
+        }
+        if let Some(ref token) = self.config.admin_readonly_token {
+            auth = auth.with_token(token.clone(), AdminRole::Viewer);
+        }
+        auth
+    }
+
     fn mount_admin_api(&self, mut app: Router, state: &AppState<A>) -> Router {
-        if let Some(ref write_token) = self.config.admin_token {
-            // Destructive-operation router — always uses admin_token.
-            let write_auth = BearerAuthState::with_max_failures(
-                write_token.clone(),
-                self.config.admin_auth_max_failures,
-            );
-            let admin_write_router = Router::new()
-                .route("/api/v1/admin/reload-schema", post(api::admin::reload_schema_handler::<A>))
-                .route("/api/v1/admin/cache/clear", post(api::admin::cache_clear_handler::<A>))
-                .route(
-                    "/api/v1/admin/query-stats/reset",
-                    post(api::query_stats::query_stats_reset_handler::<A>),
+        if let Some(ref admin_token) = self.config.admin_token {
+            let audit_layer = || {
+                middleware::from_fn_with_state(
+                    Arc::clone(&self.admin_audit),
+                    admin_audit_middleware,
                 )
+            };
+            let auth_layer = |required| {
+                middleware::from_fn_with_state(
+                    self.admin_token_auth(admin_token, required),
+                    admin_token_role_middleware,
+                )
+            };
+
+            // Admin tier: schema and tenant definition changes.
+            let admin_router = Router::new()
+                .route("/api/v1/admin/reload-schema", post(api::admin::reload_schema_handler::<A>))
                 // Tenant management write endpoints (multi-tenant mode)
                 .route(
                     "/api/v1/admin/tenants/{key}",
@@ -512,33 +564,46 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                     put(api::tenant_admin::upsert_domain_handler::<A>)
                         .delete(api::tenant_admin::delete_domain_handler::<A>),
                 )
-                .route_layer(middleware::from_fn_with_state(write_auth, bearer_auth_middleware))
+                .route_layer(auth_layer(AdminRole::Admin))
+                .route_layer(audit_layer())
                 .with_state(state.clone());
-            app = app.merge(admin_write_router);
+            app = app.merge(admin_router);
 
-            // Read-only router
-            let read_token = self.config.admin_readonly_token.as_ref().unwrap_or(write_token);
+            // Operator tier: runtime actions that do not change definitions.
+            let operator_router = Router::new()
+                .route("/api/v1/admin/cache/clear", post(api::admin::cache_clear_handler::<A>))
+                .route("/api/v1/admin/config/reload", post(api::admin::config_reload_handler::<A>))
+                .route(
+                    "/api/v1/admin/query-stats/reset",
+                    post(api::query_stats::query_stats_reset_handler::<A>),
+                )
+                .route_layer(auth_layer(AdminRole::Operator))
+                .route_layer(audit_layer())
+                .with_state(state.clone());
+            app = app.merge(operator_router);
 
-            if self.config.admin_readonly_token.is_none() {
+            if self.config.admin_operator_token.is_none()
+                && self.config.admin_readonly_token.is_none()
+            {
                 warn!(
-                    admin_write_routes = "reload-schema, cache/clear",
+                    admin_write_routes = "reload-schema, cache/clear, config/reload",
                     admin_read_routes =
                         "cache/stats, config, explain, query/explain, grafana-dashboard",
                     "Admin API running in single-token mode: admin_token grants ALL operations \
-                     including destructive ones. Set admin_readonly_token to scope access."
+                     including destructive ones. Set admin_operator_token or \
+                     admin_readonly_token to scope access."
                 );
             } else {
                 info!(
-                    "Admin API running in split-token mode: \
-                     admin_token=write-only, admin_readonly_token=read-only"
+                    operator_token = self.config.admin_operator_token.is_some(),
+                    readonly_token = self.config.admin_readonly_token.is_some(),
+                    "Admin API running with role-scoped tokens"
                 );
             }
 
-            let read_auth = BearerAuthState::with_max_failures(
-                read_token.clone(),
-                self.config.admin_auth_max_failures,
-            );
-            let admin_read_router = Router::new()
+            // Viewer tier: read-only endpoints. `POST` explain endpoints do not change
+            // state, so this tier is not audited.
+            let viewer_router = Router::new()
                 .route("/api/v1/admin/cache/stats", get(api::admin::cache_stats_handler::<A>))
                 .route("/api/v1/admin/config", get(api::admin::config_handler::<A>))
                 .route("/api/v1/admin/explain", post(api::admin::explain_handler::<A>))
@@ -564,14 +629,15 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                     get(api::admin::grafana_dashboard_handler::<A>),
                 )
                 .route("/api/v1/admin/usage", get(api::usage::usage_handler::<A>))
+                .route("/api/v1/admin/field-usage", get(api::usage::field_usage_handler::<A>))
                 .route("/api/v1/admin/query-stats", get(api::query_stats::query_stats_handler::<A>))
                 .route(
                     "/api/v1/admin/query-stats/{queryid}",
                     get(api::query_stats::query_stats_detail_handler::<A>),
                 )
-                .route_layer(middleware::from_fn_with_state(read_auth, bearer_auth_middleware))
+                .route_layer(auth_layer(AdminRole::Viewer))
                 .with_state(state.clone());
-            app = app.merge(admin_read_router);
+            app = app.merge(viewer_router);
 
             info!("Admin API endpoints enabled (bearer token required)");
         } else {
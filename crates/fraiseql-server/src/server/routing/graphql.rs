@@ -1,5 +1,7 @@
 //! GraphQL endpoint route construction with optional authentication.
 
+#[cfg(feature = "grpc")]
+use axum::routing::post;
 use axum::{Router, middleware, routing::get};
 use fraiseql_core::db::traits::DatabaseAdapter;
 use tower_http::compression::{CompressionLayer, predicate::SizeAbove};
@@ -92,3 +94,33 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         }
     }
 }
+
+#[cfg(feature = "grpc")]
+impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
+    /// Build the gRPC `fraiseql.graphql.v1.GraphQL/Execute` router.
+    ///
+    /// Protected by the same authentication as the GraphQL endpoint. The JSON
+    /// Content-Type check does not apply: gRPC requests carry `application/grpc`.
+    pub(super) fn build_grpc_execute_router(&self, state: &AppState<A>) -> Router {
+        use crate::routes::grpc::graphql::{EXECUTE_PATH, grpc_execute_handler};
+
+        let router = Router::new().route(EXECUTE_PATH, post(grpc_execute_handler::<A>));
+        let router = if let Some(ref validator) = self.oidc_validator {
+            let auth_state = self.oidc_auth_state(validator.clone());
+            router.route_layer(middleware::from_fn_with_state(auth_state, oidc_auth_middleware))
+        } else if let Some(ref validator) = self.hs256_auth {
+            let realm = self
+                .config
+                .auth_hs256
+                .as_ref()
+                .and_then(|h| h.issuer.clone())
+                .unwrap_or_else(|| "fraiseql".to_string());
+            let auth_state = Hs256AuthState::new(validator.clone(), realm);
+            router.route_layer(middleware::from_fn_with_state(auth_state, hs256_auth_middleware))
+        } else {
+            router
+        };
+        info!(path = EXECUTE_PATH, "gRPC GraphQL Execute endpoint enabled");
+        router.with_state(state.clone())
+    }
+}
@@ -1,12 +1,19 @@
 //! Global middleware layers: metrics, tracing, CORS, body limits, header limits,
-//! timeout, and rate limiting.
+//! timeout, shutdown draining, readiness gating, and rate limiting.
 
-use axum::{Router, extract::DefaultBodyLimit, middleware};
+use axum::{
+    Router,
+    extract::{DefaultBodyLimit, Request},
+    middleware::{self, Next},
+};
 use fraiseql_core::db::traits::DatabaseAdapter;
 use tracing::info;
 
 use super::super::{Server, cors_layer_restricted, metrics_middleware, trace_layer};
-use crate::{middleware::security_headers_middleware, routes::graphql::AppState};
+use crate::{
+    middleware::{cors::DEFAULT_DEV_ORIGIN, cors_layer_dynamic, security_headers_middleware},
+    routes::graphql::AppState,
+};
 
 impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     /// Apply global middleware layers to the router.
@@ -33,11 +40,17 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
                     "CORS enabled but no origins configured. Using localhost:3000 as default. \
                      Set cors_origins in config for production."
                 );
-                vec!["http://localhost:3000".to_string()]
+                vec![DEFAULT_DEV_ORIGIN.to_string()]
             } else {
                 self.config.cors_origins.clone()
             };
-            app = app.layer(cors_layer_restricted(&origins));
+            // With config reload enabled, origins are read from the reloader per request.
+            if let Some(ref reloader) = state.config_reloader {
+                let reloader = reloader.clone();
+                app = app.layer(cors_layer_dynamic(move |origin| reloader.allows_origin(origin)));
+            } else {
+                app = app.layer(cors_layer_restricted(&origins));
+            }
         }
 
         // Add request body size limit (default 1 MB -- prevents memory exhaustion)
@@ -65,18 +78,30 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         }
 
         // Add per-request timeout (optional -- defence against runaway DB queries).
-        if let Some(timeout_secs) = self.config.request_timeout_secs {
-            use std::time::Duration;
-
+        if let Some(timeout) = self.config.request_timeout() {
             use tower_http::timeout::TimeoutLayer;
 
-            info!(timeout_secs, "Request timeout enabled");
+            info!(?timeout, "Request timeout enabled");
             app = app.layer(TimeoutLayer::with_status_code(
                 axum::http::StatusCode::REQUEST_TIMEOUT,
-                Duration::from_secs(timeout_secs),
+                timeout,
             ));
         }
 
+        // Refuse new requests once shutdown starts and count in-flight ones for the drain.
+        app = app.layer(middleware::from_fn_with_state(
+            self.shutdown.clone(),
+            crate::shutdown::shutdown_middleware,
+        ));
+
+        // Hold traffic until critical dependencies are ready (`[readiness] gate_traffic`).
+        if let Some(ref gate) = state.readiness_gate {
+            use crate::readiness::readiness_gate_middleware;
+
+            app =
+                app.layer(middleware::from_fn_with_state(gate.clone(), readiness_gate_middleware));
+        }
+
         // Add rate limiting middleware if configured.
         if let Some(ref limiter) = self.rate_limiter {
             use axum::Extension;
@@ -84,9 +109,24 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             use crate::middleware::rate_limit::rate_limit_middleware;
 
             info!("Enabling rate limiting middleware");
-            app = app
-                .layer(middleware::from_fn(rate_limit_middleware))
-                .layer(Extension(limiter.clone()));
+            app = app.layer(middleware::from_fn(rate_limit_middleware));
+            // API keys select a per-user bucket only once verified.
+            if let Some(ref authenticator) = state.api_key_authenticator {
+                app = app.layer(Extension(authenticator.clone()));
+            }
+            // A reloadable limiter is looked up per request so a config reload
+            // takes effect without rebuilding the router.
+            let reloadable = state.config_reloader.clone().filter(|r| r.rate_limiter().is_some());
+            if let Some(reloader) = reloadable {
+                app = app.layer(middleware::from_fn(move |mut req: Request, next: Next| {
+                    if let Some(limiter) = reloader.rate_limiter() {
+                        req.extensions_mut().insert(limiter);
+                    }
+                    next.run(req)
+                }));
+            } else {
+                app = app.layer(Extension(limiter.clone()));
+            }
         }
 
         app
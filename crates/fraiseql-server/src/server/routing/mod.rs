@@ -53,6 +53,10 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         // Build GraphQL route (possibly with auth + Content-Type enforcement).
         let graphql_router = self.build_graphql_router(&state);
 
+        // Mount the gRPC `Execute` RPC behind the same authentication as `/graphql`.
+        #[cfg(feature = "grpc")]
+        let graphql_router = graphql_router.merge(self.build_grpc_execute_router(&state));
+
         // Mount base routes, studio, admin, introspection, metrics, design audit.
         let mut app = Router::new();
         app = self.mount_base_and_admin_routes(app.merge(graphql_router), &state);
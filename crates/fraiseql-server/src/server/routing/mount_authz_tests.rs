@@ -73,6 +73,7 @@ fn minimal_storage_state() -> fraiseql_storage::StorageState {
         )),
         rls:      fraiseql_storage::StorageRlsEvaluator::new(),
         buckets:  Arc::new(HashMap::new()),
+        signer:   None,
     }
 }
 
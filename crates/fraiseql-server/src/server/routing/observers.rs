@@ -5,7 +5,7 @@ use fraiseql_core::db::traits::DatabaseAdapter;
 use tracing::info;
 
 use super::super::Server;
-use crate::middleware::admin_auth_middleware;
+use crate::{admin_audit::admin_audit_middleware, middleware::admin_role_auth_middleware};
 
 impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     /// Add observer-related routes to the router.
@@ -23,15 +23,17 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     /// The observer admin API — create / update / delete observers, reload runtime,
     /// inspect DLQ, read the changelog — exposes write-side cluster-state mutations
     /// and read-side endpoints that return bearer-token secrets stored in observer
-    /// `actions[].headers`.  All four routers are gated behind `admin_auth_middleware`,
-    /// which requires a valid token **and** the `fraiseql:admin` scope (Phase 03 C3):
-    /// this closes H5 (the routers were previously un-authed whenever the data plane
-    /// ran with optional auth, since `oidc_auth_middleware` defers to the global
-    /// `required` flag) and H6 (any authenticated end-user token could read the webhook
-    /// secrets or drive DLQ retry/delete). If no OIDC validator is configured (`[auth]`
-    /// absent in `fraiseql.toml`), the routes are *not* mounted and a `WARN` is logged
-    /// at startup, rather than mounting them open.  This closes the FW-21 class
-    /// anonymous-write primitive (issue #348).
+    /// `actions[].headers`.  All four routers are gated behind
+    /// `admin_role_auth_middleware`, which requires a valid token **and** an admin-role
+    /// scope — `fraiseql:admin:viewer` for reads, `fraiseql:admin:operator` for
+    /// operational writes, `fraiseql:admin` for definition changes and DLQ deletion
+    /// (Phase 03 C3). Every write is recorded in the admin audit trail. This closes H5 (the routers
+    /// were previously un-authed whenever the data plane ran with optional auth, since
+    /// `oidc_auth_middleware` defers to the global `required` flag) and H6 (any authenticated
+    /// end-user token could read the webhook secrets or drive DLQ retry/delete). If no OIDC
+    /// validator is configured (`[auth]` absent in `fraiseql.toml`), the routes are *not*
+    /// mounted and a `WARN` is logged at startup, rather than mounting them open.  This closes
+    /// the FW-21 class anonymous-write primitive (issue #348).
     #[cfg(feature = "observers")]
     pub(super) fn add_observer_routes(&self, app: Router) -> Router {
         use std::sync::Arc;
@@ -73,17 +75,19 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
 
         let changelog_state = ChangelogState { pool: db_pool };
 
-        let auth_layer = || {
+        let guarded = |router: Router| {
             let auth_state = self.oidc_auth_state(Arc::clone(validator));
-            middleware::from_fn_with_state(auth_state, admin_auth_middleware)
+            router
+                .route_layer(middleware::from_fn_with_state(auth_state, admin_role_auth_middleware))
+                .route_layer(middleware::from_fn_with_state(
+                    Arc::clone(&self.admin_audit),
+                    admin_audit_middleware,
+                ))
         };
 
         let app = app
-            .nest("/api/observers", observer_routes(observer_state).route_layer(auth_layer()))
-            .nest(
-                "/api/observers",
-                observer_changelog_routes(changelog_state).route_layer(auth_layer()),
-            );
+            .nest("/api/observers", guarded(observer_routes(observer_state)))
+            .nest("/api/observers", guarded(observer_changelog_routes(changelog_state)));
 
         if let Some(ref runtime) = self.observer_runtime {
             info!(
@@ -102,8 +106,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
 
             mount_observer_runtime_routes(
                 app,
-                observer_runtime_routes(runtime_state).route_layer(auth_layer()),
-                observer_dlq_routes(dlq_state).route_layer(auth_layer()),
+                guarded(observer_runtime_routes(runtime_state)),
+                guarded(observer_dlq_routes(dlq_state)),
             )
         } else {
             app
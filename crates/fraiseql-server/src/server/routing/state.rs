@@ -10,7 +10,8 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
     /// Build the shared `AppState` with all configured subsystems attached.
     pub(super) fn build_app_state(&self) -> AppState<A> {
         let mut state = AppState::new(self.executor.clone())
-            .with_reload_config(self.config.schema_path.clone(), self.executor.adapter().clone());
+            .with_reload_config(self.config.schema_path.clone(), self.executor.adapter().clone())
+            .with_schema_public_key(self.config.schema_public_key.clone());
 
         // Attach secrets manager if configured
         #[cfg(feature = "secrets")]
@@ -62,12 +63,95 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             info!("Observer runtime attached to AppState for health probes");
         }
 
+        // Readiness: probe timeout, the rate limiter's Redis backend, and the
+        // startup traffic gate (opened by `/readiness` or the lifecycle watcher).
+        if let Some(ref readiness) = self.config.readiness {
+            state = state.with_readiness_probe_timeout(readiness.probe_timeout());
+            if readiness.gate_traffic {
+                let exempt = vec![
+                    self.config.health_path.clone(),
+                    self.config.readiness_path.clone(),
+                    self.config.metrics_path.clone(),
+                    self.config.metrics_json_path.clone(),
+                ];
+                state = state.with_readiness_gate(std::sync::Arc::new(
+                    crate::readiness::ReadinessGate::new(exempt),
+                ));
+                info!("Readiness gate enabled: traffic held until critical dependencies are ready");
+            }
+        }
+        #[cfg(feature = "redis-rate-limiting")]
+        if let Some(ref limiter) = self.rate_limiter {
+            if matches!(**limiter, crate::middleware::RateLimiter::Redis(_)) {
+                state = state.with_readiness_probe(std::sync::Arc::new(
+                    crate::readiness::RateLimiterProbe(limiter.clone()),
+                ));
+            }
+        }
+
+        // Runtime config reload (SIGHUP / admin endpoint). `[rate_limiting]` is
+        // only reloadable when it is what the in-memory limiter was built from.
+        if let Some(ref source) = self.config_source {
+            let mut reloader =
+                crate::config_reload::ConfigReloader::new(source.clone(), self.config.clone());
+            if let Some(ref limiter) = self.rate_limiter {
+                let from_config = matches!(**limiter, crate::middleware::RateLimiter::InMemory(_))
+                    && Self::schema_rate_limiting(self.executor.schema()).is_none();
+                if from_config {
+                    reloader = reloader.with_rate_limiter(limiter.clone());
+                }
+            }
+            #[cfg(feature = "observers")]
+            if let Some(ref runtime) = self.observer_runtime {
+                reloader = reloader.with_observer_runtime(runtime.clone());
+            }
+            state = state.with_config_reloader(std::sync::Arc::new(reloader));
+            info!("Config reload enabled (SIGHUP / POST /api/v1/admin/config/reload)");
+        }
+
         // Thread adapter-level cache state through to admin handlers.
         state = state.with_adapter_cache_enabled(self.adapter_cache_enabled);
 
+        // Route a share of GraphQL traffic to the `[canary]` candidate schema.
+        if let Some(ref canary) = self.canary {
+            state = state.with_canary(canary.clone());
+        }
+
+        // Mirror sampled read queries to the `[shadow]` executor.
+        if let Some(ref shadow) = self.shadow {
+            state = state.with_shadow(shadow.clone());
+        }
+
+        // Limit concurrent executions per operation / cost class (`[bulkhead]`).
+        // The configuration was validated when the server config was loaded.
+        if let Some(ref bulkhead_cfg) = self.config.bulkhead {
+            match crate::resilience::bulkhead::Bulkhead::new(bulkhead_cfg) {
+                Ok(bulkhead) => {
+                    info!(
+                        operations = bulkhead_cfg.operations.len(),
+                        cost_classes = bulkhead_cfg.cost_classes.len(),
+                        max_queue = bulkhead_cfg.max_queue,
+                        "Bulkhead concurrency limits enabled"
+                    );
+                    state = state.with_bulkhead(std::sync::Arc::new(bulkhead));
+                },
+                Err(e) => {
+                    tracing::error!(error = %e, "Invalid [bulkhead] configuration; limits disabled");
+                },
+            }
+        }
+
         // Wire usage aggregator (shared with MutationAuditLayer tracing subscriber).
         state = state.with_usage(self.usage.clone());
 
+        // Attach the operation audit log (connected in serve_with_shutdown).
+        if let Some(ref audit) = self.operation_audit {
+            state = state.with_operation_audit(audit.clone());
+        }
+        if let Some(ref field_usage) = self.field_usage {
+            state = state.with_field_usage(std::sync::Arc::clone(field_usage));
+        }
+
         // Attach error sanitizer (always present; disabled by default)
         state = state.with_error_sanitizer(self.error_sanitizer.clone());
         if self.error_sanitizer.is_enabled() {
@@ -178,6 +262,37 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             };
             info!(max_query_complexity = complexity, source, "Query complexity limit configured");
         }
+        let pick = |limit: fn(&fraiseql_core::schema::ValidationConfig) -> Option<u32>| {
+            runtime_vc.and_then(limit).or_else(|| compiled_vc.and_then(limit))
+        };
+        if let Some(aliases) = pick(|v| v.max_aliases) {
+            validator = validator.with_max_aliases(aliases as usize);
+            info!(max_aliases = aliases, "Query alias limit configured");
+        }
+        if let Some(root_fields) = pick(|v| v.max_root_fields) {
+            validator = validator.with_max_root_fields(root_fields as usize);
+            info!(max_root_fields = root_fields, "Query root field limit configured");
+        }
+        if let Some(depth) = pick(|v| v.max_introspection_depth) {
+            validator = validator.with_max_introspection_depth(depth as usize);
+            info!(max_introspection_depth = depth, "Introspection depth limit configured");
+        }
+        // Role overrides are taken as a whole from one source, never merged.
+        let role_overrides =
+            runtime_vc.filter(|v| !v.roles.is_empty()).or(compiled_vc).map(|v| &v.roles);
+        for (role, limits) in role_overrides.into_iter().flatten() {
+            validator = validator.with_role_limits(
+                role.as_str(),
+                crate::validation::RoleLimits {
+                    max_depth:               limits.max_query_depth.map(|n| n as usize),
+                    max_complexity:          limits.max_query_complexity.map(|n| n as usize),
+                    max_aliases:             limits.max_aliases.map(|n| n as usize),
+                    max_root_fields:         limits.max_root_fields.map(|n| n as usize),
+                    max_introspection_depth: limits.max_introspection_depth.map(|n| n as usize),
+                },
+            );
+            info!(role = %role, "Per-role query limits configured");
+        }
         state = state.with_validator(validator);
 
         // Start pool auto-tuner if configured and enabled
@@ -204,6 +319,13 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
         // Apply GET query size limit from server config.
         state.max_get_query_bytes = self.config.max_get_query_bytes;
 
+        // Apply POST batching limits from server config.
+        state =
+            state.with_batching(self.config.max_batch_operations, self.config.batch_concurrency);
+
+        // Hand the request timeout to query execution as a time budget.
+        state = state.with_request_timeout(self.config.request_timeout());
+
         // Derive the introspection policy from the two server-config booleans.
         // This is the single source of truth shared with the REST
         // `/introspection` mount decision (`admin.rs`), so the GraphQL request
@@ -239,7 +361,56 @@ impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
             // Seed the registry with the default executor (the `ArcSwap`-wrapped
             // one `AppState` holds), so requests with no/unknown tenant fall back
             // to it exactly as the GraphQL handler expects.
-            let registry = TenantExecutorRegistry::new(state.executor.clone());
+            let mut registry = TenantExecutorRegistry::new(state.executor.clone())
+                .with_schema_public_key(self.config.schema_public_key.clone());
+
+            // `[tenancy.tenants]`: route each configured tenant's hosts to its key
+            // and hand the registry what it needs to build the executor on the
+            // first request. Building needs the adapter-specific factory.
+            let tenants = &self.config.tenancy.tenants;
+            if !tenants.is_empty() {
+                use crate::{routes::graphql::LazyTenant, tenancy::TenantPoolConfig};
+
+                for (key, tenant) in tenants {
+                    for host in &tenant.hosts {
+                        state.domain_registry().register(host.clone(), key.clone());
+                    }
+                }
+                match self.tenant_executor_factory {
+                    Some(ref factory) => {
+                        let lazy = tenants
+                            .iter()
+                            .map(|(key, tenant)| {
+                                let mut pool = TenantPoolConfig::new(tenant.database_url.clone());
+                                if let Some(max) = tenant.max_connections {
+                                    pool.max_connections = max;
+                                }
+                                let spec = LazyTenant {
+                                    schema_path: tenant.schema_path.clone(),
+                                    pool,
+                                };
+                                (key.clone(), spec)
+                            })
+                            .collect();
+                        registry = registry.with_lazy_tenants(
+                            lazy,
+                            factory.clone(),
+                            self.config.tenancy.runtime.max_loaded_tenants,
+                        );
+                        info!(
+                            tenants = tenants.len(),
+                            max_loaded = ?self.config.tenancy.runtime.max_loaded_tenants,
+                            "Configured tenants will be loaded on first request"
+                        );
+                    },
+                    None => tracing::warn!(
+                        tenants = tenants.len(),
+                        "[tenancy.tenants] is set but this database backend cannot build \
+                         tenant executors; requests for those tenants will be rejected"
+                    ),
+                }
+            }
+
             state = state
                 .with_tenant_registry(std::sync::Arc::new(registry))
                 .with_tenant_audit_log(std::sync::Arc::new(InMemoryAuditLog::new()));
@@ -56,6 +56,7 @@ async fn storage_state(bucket: &str) -> StorageState {
         metadata: Arc::new(StorageMetadataRepo::new(lazy_pool())),
         rls:      StorageRlsEvaluator::new(),
         buckets:  Arc::new(buckets),
+        signer:   None,
     }
 }
 
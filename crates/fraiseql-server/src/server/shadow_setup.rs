@@ -0,0 +1,99 @@
+//! Serve-time shadow executor preparation.
+//!
+//! Builds the `[shadow]` executor that sampled read queries are mirrored to: the
+//! secondary compiled schema on the current adapter, or — with `database_url` — an
+//! executor on its own pool built by the binary's executor factory. Runs once at
+//! serve time (async, fail-loud).
+
+use std::sync::Arc;
+
+use fraiseql_core::{
+    db::traits::DatabaseAdapter,
+    runtime::{Executor, RuntimeConfig},
+};
+use tracing::info;
+
+use super::{Server, ServerError};
+use crate::{
+    schema::loader::CompiledSchemaLoader, shadow::ShadowMirror, tenancy::TenantPoolConfig,
+};
+
+impl<A: DatabaseAdapter + Clone + Send + Sync + 'static> Server<A> {
+    /// Build the shadow executor of a configured `[shadow]` mirror.
+    ///
+    /// A secondary schema goes through the same loader checks as the current one
+    /// (including the signature check when `schema_public_key` is set). Without
+    /// `schema_path` the shadow runs the current schema. A no-op when no shadow is
+    /// configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerError::ConfigError`] if the shadow schema cannot be loaded or
+    /// is incompatible, the shadow database cannot be reached, `database_url` is set
+    /// on a server without an executor factory, or the configuration is invalid.
+    pub(super) async fn prepare_shadow(&mut self) -> Result<(), ServerError> {
+        let Some(config) = self.config.shadow.clone() else {
+            return Ok(());
+        };
+
+        let schema = match config.schema_path {
+            Some(ref path) => {
+                let mut loader = CompiledSchemaLoader::new(path);
+                if let Some(key) =
+                    self.config.schema_verifying_key().map_err(ServerError::ConfigError)?
+                {
+                    loader = loader.with_public_key(key);
+                }
+                loader.load().await.map_err(|error| {
+                    ServerError::ConfigError(format!("failed to load shadow schema: {error}"))
+                })?
+            },
+            None => self.executor.schema().clone(),
+        };
+        let shadow_hash = schema.artifact_hash.clone().unwrap_or_else(|| schema.content_hash());
+
+        let executor = match config.database_url {
+            Some(ref url) => {
+                // The factory is what knows how to open a pool for this adapter type;
+                // the binary installs one on its PostgreSQL paths.
+                let factory = self.tenant_executor_factory.as_ref().ok_or_else(|| {
+                    ServerError::ConfigError(
+                        "shadow.database_url is only supported by the PostgreSQL server"
+                            .to_string(),
+                    )
+                })?;
+                let schema_json = schema.to_json().map_err(|e| {
+                    ServerError::ConfigError(format!("failed to serialise shadow schema: {e}"))
+                })?;
+                factory("shadow".to_string(), schema_json, TenantPoolConfig::new(url.clone()))
+                    .await
+                    .map_err(|e| {
+                        ServerError::ConfigError(format!("failed to build shadow executor: {e}"))
+                    })?
+            },
+            None => {
+                let runtime_config =
+                    RuntimeConfig::from_compiled_schema(&schema).map_err(|msg| {
+                        ServerError::ConfigError(format!("Incompatible shadow schema: {msg}"))
+                    })?;
+                Arc::new(Executor::with_config(
+                    schema,
+                    self.executor.adapter().clone(),
+                    runtime_config,
+                ))
+            },
+        };
+
+        let mirror = ShadowMirror::new(executor, &config).map_err(ServerError::ConfigError)?;
+        info!(
+            schema_hash = %shadow_hash,
+            secondary_schema = config.schema_path.is_some(),
+            secondary_database = config.database_url.is_some(),
+            sample_percent = config.sample_percent,
+            max_in_flight = config.max_in_flight,
+            "Shadow traffic mirroring enabled"
+        );
+        self.shadow = Some(Arc::new(mirror));
+        Ok(())
+    }
+}
@@ -7,16 +7,11 @@
 mod initialization_tests {
     use super::super::initialization::is_manifest_url_ssrf_blocked;
 
-    /// H12: a field marked for at-rest encryption must refuse to boot — the write path does
-    /// not encrypt, so it would be stored in plaintext.
-    #[test]
-    fn field_encryption_marker_refuses_boot() {
+    fn encrypted_email_schema() -> fraiseql_core::schema::CompiledSchema {
         use fraiseql_core::schema::{
             CompiledSchema, FieldDefinition, FieldEncryptionConfig, FieldType, TypeDefinition,
         };
 
-        use super::super::initialization::field_encryption_unsupported_check;
-
         let mut user = TypeDefinition::new("User", "v_user");
         user.fields
             .push(FieldDefinition::new("email", FieldType::String).with_encryption(
@@ -25,23 +20,65 @@ mod initialization_tests {
                     algorithm:     "AES-256-GCM".to_string(),
                 },
             ));
-        let schema = CompiledSchema {
+        CompiledSchema {
             types: vec![user],
             ..CompiledSchema::default()
-        };
+        }
+    }
 
-        let result = field_encryption_unsupported_check(&schema);
+    /// H12: a field marked for at-rest encryption must refuse to boot when no secrets
+    /// backend can encrypt it — it would be stored in plaintext.
+    #[test]
+    fn field_encryption_without_secrets_refuses_boot() {
+        use super::super::initialization::field_encryption_check;
+
+        let result = field_encryption_check(&encrypted_email_schema(), false);
         assert!(
             matches!(&result, Err(crate::ServerError::ConfigError(msg)) if msg.contains("User.email")),
-            "a field marked for encryption must refuse to boot and name the field (H12): {result:?}"
+            "an encrypted field without secrets must refuse to boot and name the field (H12): {result:?}"
         );
     }
 
+    #[test]
+    fn field_encryption_with_secrets_boots() {
+        use super::super::initialization::field_encryption_check;
+
+        assert!(
+            field_encryption_check(&encrypted_email_schema(), true).is_ok(),
+            "encrypted fields boot once a secrets manager can encrypt them"
+        );
+    }
+
+    /// Encrypted fields must not be reachable through REST or Arrow Flight, which do not
+    /// encrypt writes or decrypt reads.
+    #[test]
+    fn encrypted_fields_over_rest_or_flight_refuse_boot() {
+        use super::super::initialization::encrypted_field_transport_check;
+
+        let schema = encrypted_email_schema();
+        assert!(
+            encrypted_field_transport_check(&schema, false, false).is_ok(),
+            "GraphQL-only servers encrypt the field in the handler"
+        );
+        for (rest, flight, transport) in [
+            (true, false, "REST"),
+            (false, true, "Arrow Flight"),
+            (true, true, "REST and Arrow Flight"),
+        ] {
+            let result = encrypted_field_transport_check(&schema, rest, flight);
+            assert!(
+                matches!(&result, Err(crate::ServerError::ConfigError(msg))
+                    if msg.contains("User.email") && msg.contains(transport)),
+                "an encrypted field reachable over {transport} must refuse to boot: {result:?}"
+            );
+        }
+    }
+
     #[test]
     fn no_field_encryption_boots_fine() {
         use fraiseql_core::schema::{CompiledSchema, FieldDefinition, FieldType, TypeDefinition};
 
-        use super::super::initialization::field_encryption_unsupported_check;
+        use super::super::initialization::field_encryption_check;
 
         let mut user = TypeDefinition::new("User", "v_user");
         user.fields.push(FieldDefinition::new("email", FieldType::String));
@@ -50,9 +87,46 @@ mod initialization_tests {
             ..CompiledSchema::default()
         };
         assert!(
-            field_encryption_unsupported_check(&schema).is_ok(),
+            field_encryption_check(&schema, false).is_ok(),
             "a schema with no encryption-marked fields boots normally"
         );
+        assert!(
+            super::super::initialization::encrypted_field_transport_check(&schema, true, true)
+                .is_ok(),
+            "REST and Flight boot normally without encryption-marked fields"
+        );
+    }
+
+    #[test]
+    fn unsafe_computed_field_expression_refuses_boot() {
+        use fraiseql_core::schema::{CompiledSchema, FieldDefinition, FieldType, TypeDefinition};
+
+        use super::super::initialization::computed_field_expression_check;
+
+        let mut user = TypeDefinition::new("User", "v_user");
+        user.fields.push(
+            FieldDefinition::new("fullName", FieldType::String)
+                .with_sql_expression("data->>'first' || ' ' || data->>'last'"),
+        );
+        let schema = CompiledSchema {
+            types: vec![user.clone()],
+            ..CompiledSchema::default()
+        };
+        assert!(computed_field_expression_check(&schema).is_ok());
+
+        user.fields.push(
+            FieldDefinition::new("pwned", FieldType::String)
+                .with_sql_expression("1); DROP TABLE users; --"),
+        );
+        let schema = CompiledSchema {
+            types: vec![user],
+            ..CompiledSchema::default()
+        };
+        let result = computed_field_expression_check(&schema);
+        assert!(
+            matches!(&result, Err(crate::ServerError::ConfigError(msg)) if msg.contains("User.pwned") && !msg.contains("User.fullName")),
+            "an unsafe computed expression must refuse to boot and name the field: {result:?}"
+        );
     }
 
     /// #379: `[security] persisted_queries_only = true` forces the trusted-document
@@ -40,6 +40,11 @@ pub const fn default_max_get_query_bytes() -> usize {
     100_000
 }
 
+/// Default number of operations from one batch executed concurrently.
+pub const fn default_batch_concurrency() -> usize {
+    4
+}
+
 /// Default maximum number of HTTP headers per request.
 ///
 /// Prevents header-flooding `DoS` attacks that exhaust memory by sending
@@ -88,6 +93,10 @@ pub fn default_playground_path() -> String {
     "/playground".to_string()
 }
 
+pub fn default_graphiql_path() -> String {
+    "/graphiql".to_string()
+}
+
 pub fn default_subscription_path() -> String {
     "/ws".to_string()
 }
@@ -104,6 +113,11 @@ pub const fn default_pool_timeout() -> u64 {
     30
 }
 
+pub const fn default_prepared_statement_capacity() -> usize {
+    // Matches `fraiseql_db::postgres::DEFAULT_PREPARED_STATEMENT_CAPACITY`.
+    256
+}
+
 pub fn default_tls_min_version() -> String {
     "1.2".to_string()
 }
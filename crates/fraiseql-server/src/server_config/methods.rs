@@ -25,6 +25,32 @@ impl ServerConfig {
         env != "development" && env != "dev"
     }
 
+    /// Effective per-request timeout: `request_timeout_ms` if set, otherwise
+    /// `request_timeout_secs`.
+    #[must_use]
+    pub const fn request_timeout(&self) -> Option<std::time::Duration> {
+        match (self.request_timeout_ms, self.request_timeout_secs) {
+            (Some(ms), _) => Some(std::time::Duration::from_millis(ms)),
+            (None, Some(secs)) => Some(std::time::Duration::from_secs(secs)),
+            (None, None) => None,
+        }
+    }
+
+    /// The parsed [`schema_public_key`](Self::schema_public_key), if configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if the key is not a valid Ed25519 public key.
+    pub fn schema_verifying_key(
+        &self,
+    ) -> Result<Option<fraiseql_core::schema::SchemaVerifyingKey>, String> {
+        self.schema_public_key
+            .as_deref()
+            .map(fraiseql_core::schema::SchemaVerifyingKey::parse)
+            .transpose()
+            .map_err(|e| format!("schema_public_key: {e}"))
+    }
+
     /// Validate configuration.
     ///
     /// # Errors
@@ -32,10 +58,12 @@ impl ServerConfig {
     /// Returns error if:
     /// - `metrics_enabled` is true but `metrics_token` is not set
     /// - `metrics_token` is set but too short (< 16 characters)
+    /// - `schema_public_key` is set but not a valid Ed25519 public key
     /// - `auth` config is set but invalid (e.g., empty issuer)
     /// - `tls` is enabled but cert or key path is missing
     /// - TLS minimum version is invalid
     /// - In production mode: `playground_enabled` is true
+    /// - In production mode: `graphiql_enabled` is true
     /// - In production mode: `cors_enabled` is true but `cors_origins` is empty
     pub fn validate(&self) -> Result<(), String> {
         if self.metrics_enabled {
@@ -54,6 +82,69 @@ impl ServerConfig {
             }
         }
 
+        self.schema_verifying_key()?;
+
+        if let Some(ref directives) = self.log_level {
+            tracing_subscriber::EnvFilter::try_new(directives)
+                .map_err(|e| format!("log_level `{directives}` is not a valid log filter: {e}"))?;
+        }
+
+        if let Some(ref audit) = self.operation_audit {
+            audit.validate()?;
+        }
+
+        if let Some(ref field_usage) = self.field_usage {
+            field_usage.validate()?;
+        }
+
+        if let Some(ref backup) = self.backup {
+            backup.validate()?;
+        }
+
+        if let Some(ref canary) = self.canary {
+            canary.validate()?;
+            if self.tenancy.runtime.enabled {
+                return Err("[canary] cannot be combined with [tenancy.runtime] enabled = true; \
+                     tenant executors are not canaried"
+                    .to_string());
+            }
+        }
+
+        if let Some(ref shadow) = self.shadow {
+            shadow.validate()?;
+        }
+
+        if let Some(ref bulkhead) = self.bulkhead {
+            bulkhead.validate()?;
+        }
+
+        if !self.tenancy.tenants.is_empty() && !self.tenancy.runtime.enabled {
+            return Err("[tenancy.tenants] requires [tenancy.runtime] enabled = true".to_string());
+        }
+        if self.tenancy.runtime.max_loaded_tenants == Some(0) {
+            return Err("tenancy.runtime.max_loaded_tenants must be greater than 0".to_string());
+        }
+        let mut routed_hosts = std::collections::HashMap::new();
+        for (key, tenant) in &self.tenancy.tenants {
+            crate::routes::graphql::tenant_key::validate_tenant_key(key)
+                .map_err(|e| format!("[tenancy.tenants.{key}]: invalid tenant key: {e}"))?;
+            if tenant.database_url.trim().is_empty() {
+                return Err(format!("[tenancy.tenants.{key}]: database_url must not be empty"));
+            }
+            if tenant.max_connections == Some(0) {
+                return Err(format!(
+                    "[tenancy.tenants.{key}]: max_connections must be greater than 0"
+                ));
+            }
+            for host in &tenant.hosts {
+                if let Some(other) = routed_hosts.insert(host.as_str(), key.as_str()) {
+                    return Err(format!(
+                        "host '{host}' is routed to both tenant '{other}' and tenant '{key}'"
+                    ));
+                }
+            }
+        }
+
         // Admin API validation
         if self.admin_api_enabled {
             match &self.admin_token {
@@ -82,6 +173,22 @@ impl ServerConfig {
                     return Err("admin_readonly_token must differ from admin_token.".to_string());
                 }
             }
+
+            if let Some(ref op_token) = self.admin_operator_token {
+                if op_token.len() < 32 {
+                    return Err(
+                        "admin_operator_token must be at least 32 characters for security."
+                            .to_string(),
+                    );
+                }
+                if Some(op_token) == self.admin_token.as_ref()
+                    || Some(op_token) == self.admin_readonly_token.as_ref()
+                {
+                    return Err("admin_operator_token must differ from admin_token and \
+                         admin_readonly_token."
+                        .to_string());
+                }
+            }
         }
 
         // Validate OIDC config if present
@@ -205,6 +312,12 @@ impl ServerConfig {
                      The playground exposes sensitive schema information."
                     .to_string());
             }
+            if self.graphiql_enabled {
+                return Err("graphiql_enabled is true in production mode. \
+                     Disable GraphiQL or set FRAISEQL_ENV=development. \
+                     GraphiQL exposes sensitive schema information."
+                    .to_string());
+            }
 
             // CORS origins must be explicitly configured in production
             if self.cors_enabled && self.cors_origins.is_empty() {
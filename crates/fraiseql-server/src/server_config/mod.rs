@@ -11,21 +11,26 @@ pub(crate) mod defaults;
 pub mod hs256;
 mod methods;
 pub mod observers;
+pub mod secret_refs;
 pub mod storage;
 pub mod tls;
 
 #[cfg(test)]
 mod tests;
 
-use std::{collections::HashMap, net::SocketAddr, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::SocketAddr,
+    path::PathBuf,
+};
 
 use defaults::{
     default_bind_addr, default_database_url, default_graphql_path, default_health_path,
     default_introspection_path, default_max_header_bytes, default_max_header_count,
     default_max_request_body_bytes, default_metrics_json_path, default_metrics_path,
     default_playground_path, default_pool_max_size, default_pool_min_size, default_pool_timeout,
-    default_readiness_path, default_schema_path, default_shutdown_timeout_secs,
-    default_subscription_path,
+    default_prepared_statement_capacity, default_readiness_path, default_schema_path,
+    default_shutdown_timeout_secs, default_subscription_path,
 };
 use fraiseql_core::security::OidcConfig;
 pub use hs256::Hs256Config;
@@ -38,6 +43,20 @@ pub use tls::{DatabaseTlsConfig, PlaygroundTool, TlsServerConfig};
 
 use crate::middleware::RateLimitConfig;
 
+/// Storage backend for APQ (Automatic Persisted Queries) registrations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ApqBackend {
+    /// In-process LRU store (default). Not shared between replicas.
+    #[default]
+    Memory,
+    /// Shared `PostgreSQL` table in the primary database.
+    Postgres,
+    /// Shared Redis keyspace (requires the `redis-apq` feature).
+    Redis,
+}
+
 /// Server configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
@@ -54,6 +73,27 @@ pub struct ServerConfig {
     #[serde(default)]
     pub validate_sql_sources: bool,
 
+    /// Fail boot if the database has drifted from the compiled schema: a query
+    /// view, column, additional view or mutation function is missing, or a column
+    /// type no longer fits how the schema uses it.
+    ///
+    /// Runs the column-level checks of `fraiseql compile --database` against the
+    /// database being served and reports every difference at once. Default
+    /// `false`. Postgres-only. Overridden by `--validate-schema-drift` /
+    /// `FRAISEQL_VALIDATE_SCHEMA_DRIFT`.
+    #[serde(default)]
+    pub validate_schema_drift: bool,
+
+    /// Ed25519 public key compiled schemas must be signed with
+    /// (`fraiseql compile --sign-key`).
+    ///
+    /// SPKI PEM or the base64 of the raw 32-byte key. When set, the server refuses
+    /// to load — at boot or on reload — any `schema.compiled.json` that is unsigned
+    /// or whose `_signature` does not match. Default `None`: signatures are not
+    /// checked. Overridden by `FRAISEQL_SCHEMA_PUBLIC_KEY`.
+    #[serde(default)]
+    pub schema_public_key: Option<String>,
+
     /// Database connection URL (PostgreSQL, MySQL, SQLite, SQL Server).
     #[serde(default = "defaults::default_database_url")]
     pub database_url: String,
@@ -108,10 +148,30 @@ pub struct ServerConfig {
     #[serde(default = "defaults::default_service_name")]
     pub tracing_service_name: String,
 
+    /// Log filter directives, in `RUST_LOG` syntax (e.g. `"fraiseql_server=debug,info"`).
+    ///
+    /// Used when `RUST_LOG` is unset. Applied without a restart on a config
+    /// reload (SIGHUP or `POST /api/v1/admin/config/reload`).
+    #[serde(default)]
+    pub log_level: Option<String>,
+
     /// Enable APQ (Automatic Persisted Queries).
     #[serde(default = "defaults::default_true")]
     pub apq_enabled: bool,
 
+    /// Where APQ registrations are stored (default: `memory`).
+    ///
+    /// `memory` is per-process, so every replica pays its own
+    /// `PersistedQueryNotFound` round trip. `postgres` shares registrations
+    /// through a table in `database_url`; `redis` shares them through
+    /// `apq_redis_url` and requires the `redis-apq` feature.
+    #[serde(default)]
+    pub apq_backend: ApqBackend,
+
+    /// Redis URL used when `apq_backend = "redis"`.
+    #[serde(default)]
+    pub apq_redis_url: Option<String>,
+
     /// Enable query caching.
     #[serde(default = "defaults::default_true")]
     pub cache_enabled: bool,
@@ -167,6 +227,19 @@ pub struct ServerConfig {
     #[serde(default)]
     pub playground_tool: PlaygroundTool,
 
+    /// Enable a dedicated `GraphiQL` IDE at `graphiql_path` (default: false).
+    ///
+    /// Independent of `playground_tool`: the playground may serve Apollo Sandbox
+    /// while `/graphiql` always serves `GraphiQL`. Both share the playground's auth
+    /// policy (`playground_require_auth`) and, like the playground, this is
+    /// rejected in production mode.
+    #[serde(default)]
+    pub graphiql_enabled: bool,
+
+    /// `GraphiQL` IDE endpoint path.
+    #[serde(default = "defaults::default_graphiql_path")]
+    pub graphiql_path: String,
+
     /// `WebSocket` endpoint path for GraphQL subscriptions.
     #[serde(default = "defaults::default_subscription_path")]
     pub subscription_path: String,
@@ -207,23 +280,31 @@ pub struct ServerConfig {
     /// `Authorization: Bearer <token>`
     ///
     /// **Security**: Use a strong, random token (minimum 32 characters).
-    /// This token grants access to **destructive** admin operations:
-    /// `reload-schema`, `cache/clear`.
-    ///
-    /// If `admin_readonly_token` is set, this token is restricted to write
-    /// operations only. If `admin_readonly_token` is not set, this token
-    /// also grants access to read-only endpoints (backwards-compatible).
+    /// This token carries the `admin` role and so grants every admin
+    /// operation, including destructive ones (`reload-schema`, tenant and
+    /// domain writes). Hand out `admin_operator_token` or
+    /// `admin_readonly_token` to callers that need less.
     #[serde(default)]
     pub admin_token: Option<String>,
 
-    /// Optional separate bearer token for read-only admin operations.
+    /// Optional bearer token carrying the `operator` admin role.
     ///
-    /// When set, restricts `admin_token` to destructive operations only
-    /// (`reload-schema`, `cache/clear`) and uses this token for read-only
-    /// endpoints (`config`, `cache/stats`, `explain`, `grafana-dashboard`).
+    /// Grants read-only endpoints plus operational actions that change runtime
+    /// state without touching definitions: `cache/clear`, `config/reload` and
+    /// `query-stats/reset`. Schema reload and tenant/domain writes still
+    /// require `admin_token`.
     ///
-    /// Operators and monitoring tools can use this token without gaining
-    /// the ability to modify server state or reload the schema.
+    /// **Security**: Must differ from the other admin tokens and be at least
+    /// 32 characters. Requires `admin_api_enabled = true` and `admin_token` set.
+    #[serde(default)]
+    pub admin_operator_token: Option<String>,
+
+    /// Optional bearer token carrying the read-only `viewer` admin role.
+    ///
+    /// Grants the read-only endpoints (`config`, `cache/stats`, `explain`,
+    /// `grafana-dashboard`, query stats, tenant listings). Monitoring tools
+    /// can use this token without gaining the ability to modify server state
+    /// or reload the schema.
     ///
     /// **Security**: Must be different from `admin_token` and at least 32
     /// characters. Requires `admin_api_enabled = true` and `admin_token` set.
@@ -301,6 +382,13 @@ pub struct ServerConfig {
     #[serde(default = "defaults::default_pool_timeout")]
     pub pool_timeout_secs: u64,
 
+    /// Number of frequently executed queries kept prepared as named statements
+    /// on each PostgreSQL connection, skipping re-planning on later executions.
+    /// Set to `0` behind a connection pooler that does not support named
+    /// statements (e.g. `PgBouncer` in transaction mode before 1.21).
+    #[serde(default = "defaults::default_prepared_statement_capacity")]
+    pub prepared_statement_capacity: usize,
+
     /// OIDC authentication configuration (optional).
     ///
     /// When set, enables JWT authentication using OIDC discovery.
@@ -433,6 +521,21 @@ pub struct ServerConfig {
     #[serde(default)]
     pub request_timeout_secs: Option<u64>,
 
+    /// Per-request processing timeout in milliseconds (default: `None`).
+    ///
+    /// Takes precedence over `request_timeout_secs` when both are set. The
+    /// remaining part of this budget is also passed down to query execution:
+    /// the executor never waits longer than the request has left, and on
+    /// `PostgreSQL` reads run with `statement_timeout` set to it.
+    ///
+    /// # Example (TOML)
+    ///
+    /// ```toml
+    /// request_timeout_ms = 2500
+    /// ```
+    #[serde(default)]
+    pub request_timeout_ms: Option<u64>,
+
     /// Maximum byte length for a query string delivered via HTTP GET.
     ///
     /// GET queries are URL-encoded and passed as a query parameter. Very long
@@ -447,6 +550,31 @@ pub struct ServerConfig {
     #[serde(default = "defaults::default_max_get_query_bytes")]
     pub max_get_query_bytes: usize,
 
+    /// Maximum number of operations accepted in one batched POST body
+    /// (`[{ "query": ... }, ...]`).
+    ///
+    /// Each operation is validated and executed as if it had arrived on its
+    /// own, and a failing operation only fails its own slot in the response
+    /// array. A batch costs one rate-limit token per operation and is rejected
+    /// whole when the caller's bucket cannot cover it. Operations run concurrently, so clients
+    /// that need mutations applied in order must not batch them. Default: `0`
+    /// (batching disabled; array bodies are rejected).
+    ///
+    /// # Example (TOML)
+    ///
+    /// ```toml
+    /// max_batch_operations = 10
+    /// ```
+    #[serde(default)]
+    pub max_batch_operations: usize,
+
+    /// Maximum number of operations from one batch executed at the same time.
+    ///
+    /// Bounds the database connections a single HTTP request can hold.
+    /// Default: `4`.
+    #[serde(default = "defaults::default_batch_concurrency")]
+    pub batch_concurrency: usize,
+
     /// Rate limiting configuration for GraphQL requests.
     ///
     /// When configured, enables per-IP and per-user rate limiting with token bucket algorithm.
@@ -460,6 +588,7 @@ pub struct ServerConfig {
     /// rps_per_ip = 100      # 100 requests/second per IP
     /// rps_per_user = 1000   # 1000 requests/second per authenticated user
     /// burst_size = 500      # Allow bursts up to 500 requests
+    /// redis_url = "redis://cache:6379"  # Share buckets across replicas (optional)
     /// ```
     #[serde(default)]
     pub rate_limiting: Option<RateLimitConfig>,
@@ -517,6 +646,62 @@ pub struct ServerConfig {
     #[serde(default)]
     pub admission_control: Option<AdmissionConfig>,
 
+    /// Readiness probe and startup traffic gate configuration.
+    ///
+    /// `/readiness` always probes the database, schema, secrets backend,
+    /// observer listener and Redis; this section tunes the probe timeout and,
+    /// with `gate_traffic = true`, holds all other routes at 503 until every
+    /// critical dependency is ready.
+    ///
+    /// # Example (TOML)
+    ///
+    /// ```toml
+    /// [readiness]
+    /// gate_traffic = true
+    /// probe_timeout_ms = 2000
+    /// ```
+    #[serde(default)]
+    pub readiness: Option<crate::config::readiness::ReadinessConfig>,
+
+    /// Canary schema rollout: serve a candidate compiled schema to a share of
+    /// traffic next to the current one.
+    ///
+    /// # Example (TOML)
+    ///
+    /// ```toml
+    /// [canary]
+    /// schema_path = "schema.candidate.compiled.json"
+    /// traffic_percent = 5
+    /// ```
+    #[serde(default)]
+    pub canary: Option<crate::config::CanaryConfig>,
+
+    /// Shadow traffic mirroring: re-run a sample of read queries against a
+    /// secondary schema or database and log result differences.
+    ///
+    /// # Example (TOML)
+    ///
+    /// ```toml
+    /// [shadow]
+    /// schema_path = "schema.refactored.compiled.json"
+    /// sample_percent = 10
+    /// ```
+    #[serde(default)]
+    pub shadow: Option<crate::config::ShadowConfig>,
+
+    /// Per-operation concurrency limits (bulkhead): cap concurrent executions of
+    /// a named operation or cost class, with a bounded wait queue and `429`
+    /// rejection beyond it.
+    ///
+    /// # Example (TOML)
+    ///
+    /// ```toml
+    /// [bulkhead.operations]
+    /// MonthlyRevenueReport = 4
+    /// ```
+    #[serde(default)]
+    pub bulkhead: Option<crate::config::BulkheadConfig>,
+
     /// Security contact email for `/.well-known/security.txt` (RFC 9116).
     ///
     /// When set, the server exposes a `/.well-known/security.txt` endpoint
@@ -584,7 +769,8 @@ pub struct ServerConfig {
     /// After a SIGTERM or Ctrl+C signal, the server stops accepting new connections and
     /// waits for in-flight requests and background runtimes (observers) to finish.
     /// If the drain takes longer than this value, the process logs a warning and exits
-    /// immediately instead of hanging indefinitely.
+    /// immediately instead of hanging indefinitely. The same deadline bounds the Arrow
+    /// Flight drain and the shutdown close hooks (see [`crate::shutdown`]).
     ///
     /// Set this to match `terminationGracePeriodSeconds` in your Kubernetes pod spec
     /// minus a small buffer (e.g., 25s when `terminationGracePeriodSeconds = 30`).
@@ -607,6 +793,58 @@ pub struct ServerConfig {
     #[serde(default)]
     pub usage: Option<crate::config::UsagePersistenceConfig>,
 
+    /// Operation-level audit log (optional).
+    ///
+    /// When set, every GraphQL mutation is recorded — operation name, caller,
+    /// tenant, variables hash, affected entity ids, latency — to the
+    /// month-partitioned `tb_graphql_audit` table. Writes are asynchronous and
+    /// batched; expired partitions are dropped after `retention_days`.
+    /// Requires a PostgreSQL database URL.
+    ///
+    /// ```toml
+    /// [operation_audit]
+    /// sample_rate = 1.0
+    /// retention_days = 90
+    /// ```
+    #[serde(default)]
+    pub operation_audit: Option<fraiseql_core::security::OperationAuditConfig>,
+
+    /// Per-field selection analytics (optional).
+    ///
+    /// When set, the schema coordinates each request selects are counted per
+    /// client (`x-fraiseql-client` / `apollographql-client-name` header),
+    /// served by `GET /api/v1/admin/field-usage`, and flushed to the
+    /// `fraiseql_field_usage` table read by `fraiseql analyze --usage`.
+    ///
+    /// ```toml
+    /// [field_usage]
+    /// flush_interval_secs = 60
+    /// ```
+    #[serde(default)]
+    pub field_usage: Option<crate::config::FieldUsageConfig>,
+
+    /// Scheduled logical backups (optional).
+    ///
+    /// When set, the server runs `pg_dump` over the configured schemas every
+    /// `interval_secs`, optionally encrypts the archive, uploads it to the
+    /// `[backup.storage]` backend, and prunes archives past the retention
+    /// limits. Restore with `fraiseql backup restore`. Requires a PostgreSQL
+    /// database URL and the PostgreSQL client tools on `PATH`.
+    ///
+    /// ```toml
+    /// [backup]
+    /// interval_secs = 86400
+    /// retain_count = 14
+    ///
+    /// [backup.storage]
+    /// backend = "local"
+    /// path = "/var/backups/fraiseql"
+    /// ```
+    ///
+    /// Boxed for the same reason as `sources`: read once at startup.
+    #[serde(default)]
+    pub backup: Option<Box<crate::config::BackupConfig>>,
+
     /// Named object-storage backend configurations, keyed by storage name.
     ///
     /// Each `[storage.<name>]` section is wired into a mounted `/storage/v1/*`
@@ -803,6 +1041,14 @@ pub struct StorageSectionConfig {
     /// always served as `attachment` regardless of this flag.
     #[serde(default)]
     pub serve_inline: Option<bool>,
+
+    /// Name of the environment variable holding the secret that signs
+    /// `fileUrl` download links (at least 32 bytes). Needed for signed URLs on
+    /// backends without native presigning (`local`, `gcs`, `azure`); when
+    /// unset those backends serve the download route to authorized callers
+    /// only.
+    #[serde(default)]
+    pub url_signing_secret_env: Option<String>,
 }
 
 /// A single `[files.<name>]` configuration section.
@@ -831,6 +1077,22 @@ pub struct TenancyServerConfig {
     /// Per-tenant executor runtime settings (`[tenancy.runtime]`).
     #[serde(default)]
     pub runtime: TenancyRuntimeConfig,
+
+    /// Statically routed tenants (`[tenancy.tenants.<key>]`), each with its own
+    /// compiled schema and database.
+    ///
+    /// A tenant is loaded on the first request that resolves to its key (JWT
+    /// `tenant_id` claim, `X-Tenant-ID` header, or one of its `hosts`) and gets
+    /// its own pool and query cache. Requires `[tenancy.runtime] enabled`.
+    ///
+    /// ```toml
+    /// [tenancy.tenants.acme]
+    /// schema_path = "schemas/acme.compiled.json"
+    /// database_url = "postgresql://localhost/acme"
+    /// hosts = ["acme.example.com"]
+    /// ```
+    #[serde(default)]
+    pub tenants: BTreeMap<String, TenantRouteConfig>,
 }
 
 /// Per-tenant executor runtime settings (`[tenancy.runtime]`).
@@ -840,6 +1102,35 @@ pub struct TenancyRuntimeConfig {
     /// `X-Tenant-ID` / JWT / Host dispatch). Defaults to `false`.
     #[serde(default)]
     pub enabled: bool,
+
+    /// Maximum number of `[tenancy.tenants]` entries kept loaded at once.
+    ///
+    /// When a lazy load exceeds the cap, the least-recently-used configured
+    /// tenant is unloaded (its pool closes once in-flight requests finish) and
+    /// is reloaded on its next request. Suspended tenants and tenants
+    /// registered through the admin API are never evicted. `None` (default)
+    /// keeps every loaded tenant.
+    #[serde(default)]
+    pub max_loaded_tenants: Option<usize>,
+}
+
+/// One statically routed tenant (`[tenancy.tenants.<key>]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantRouteConfig {
+    /// Path to the tenant's compiled schema (`schema.compiled.json`).
+    pub schema_path: PathBuf,
+
+    /// Database connection URL for the tenant.
+    pub database_url: String,
+
+    /// Host names routed to this tenant (matched against the `Host` header,
+    /// port ignored).
+    #[serde(default)]
+    pub hosts: Vec<String>,
+
+    /// Maximum connections in the tenant's pool. Defaults to `10`.
+    #[serde(default)]
+    pub max_connections: Option<u32>,
 }
 
 impl Default for ServerConfig {
@@ -847,6 +1138,8 @@ impl Default for ServerConfig {
         Self {
             schema_path: default_schema_path(),
             validate_sql_sources: false,
+            validate_schema_drift: false,
+            schema_public_key: None,
             database_url: default_database_url(),
             bind_addr: default_bind_addr(),
             #[cfg(feature = "arrow")]
@@ -858,7 +1151,10 @@ impl Default for ServerConfig {
             otlp_endpoint: None,
             otlp_export_timeout_secs: defaults::default_otlp_timeout_secs(),
             tracing_service_name: defaults::default_service_name(),
+            log_level: None,
             apq_enabled: true,
+            apq_backend: ApqBackend::default(),
+            apq_redis_url: None,
             cache_enabled: true,
             graphql_path: default_graphql_path(),
             health_path: default_health_path(),
@@ -869,12 +1165,15 @@ impl Default for ServerConfig {
             playground_path: default_playground_path(),
             playground_enabled: false, // Disabled by default for security
             playground_tool: PlaygroundTool::default(),
+            graphiql_enabled: false, // Disabled by default for security
+            graphiql_path: defaults::default_graphiql_path(),
             subscription_path: default_subscription_path(),
             subscriptions_enabled: true,
             metrics_enabled: false, // Disabled by default for security
             metrics_token: None,
             admin_api_enabled: false, // Disabled by default for security
             admin_token: None,
+            admin_operator_token: None,
             admin_readonly_token: None,
             introspection_enabled: false, // Disabled by default for security
             introspection_require_auth: true, // Require auth when enabled
@@ -886,6 +1185,7 @@ impl Default for ServerConfig {
             pool_min_size: default_pool_min_size(),
             pool_max_size: default_pool_max_size(),
             pool_timeout_secs: default_pool_timeout(),
+            prepared_statement_capacity: default_prepared_statement_capacity(),
             auth: None,            // No auth by default
             auth_hs256: None,      // No HS256 auth by default
             hmac_secret_env: None, // No HMAC secret → unsigned idempotency token
@@ -904,14 +1204,24 @@ impl Default for ServerConfig {
                                               * schema by default */
             pool_tuning: None,       // Pool pressure monitoring disabled by default
             admission_control: None, // Admission control disabled by default
+            readiness: None,         // Default probe timeout, no traffic gate
+            canary: None,            // No candidate schema
+            shadow: None,            // No shadow mirroring
+            bulkhead: None,          // No per-operation concurrency limits
             security_contact: None,  // No security.txt by default
             validation: None,        // Use compiled schema defaults
             shutdown_timeout_secs: default_shutdown_timeout_secs(),
             request_timeout_secs: None,
+            request_timeout_ms: None,
             max_get_query_bytes: defaults::default_max_get_query_bytes(),
+            max_batch_operations: 0,
+            batch_concurrency: defaults::default_batch_concurrency(),
             admin_auth_max_failures: defaults::default_admin_auth_max_failures(),
             storage_token: None,
             usage: None,             // Usage persistence disabled by default
+            operation_audit: None,   // Operation audit log disabled by default
+            field_usage: None,       // Field usage analytics disabled by default
+            backup: None,            // Scheduled backups disabled by default
             storage: HashMap::new(), // No storage backends wired by default
             files: HashMap::new(),   // No file-upload routes by default
             #[cfg(feature = "inbound")]
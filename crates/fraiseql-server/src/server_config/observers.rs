@@ -4,7 +4,9 @@
 mod tests;
 
 #[cfg(feature = "observers")]
-use fraiseql_observers::config::{EmailSmtpConfig, TransportConfig};
+use fraiseql_observers::config::{
+    EmailSmtpConfig, EventTypesConfig, MetricsConfig, TransportConfig,
+};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "observers")]
@@ -194,6 +196,23 @@ pub struct ObserverRuntimeSettings {
     /// truncated to a marker regardless (#468).
     #[serde(default)]
     pub log_payloads: bool,
+
+    /// Labels of the per-observer action metrics (`[observers.runtime.metrics]`).
+    ///
+    /// Action series are labeled by observer, action type, entity type and
+    /// tenant, capped at `max_label_series` distinct combinations; set
+    /// `tenant_labels = false` when the tenant count would dominate the cap.
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    /// Declared event types (`[observers.runtime.event_types]`).
+    ///
+    /// Events of a declared type are validated against its payload JSON
+    /// Schema before dispatch; failures land in `tb_observer_event_quarantine`
+    /// instead of reaching any observer. Types can also be managed through
+    /// `/api/observers/runtime/event-types`.
+    #[serde(default)]
+    pub event_types: EventTypesConfig,
 }
 
 #[cfg(feature = "observers")]
@@ -210,6 +229,8 @@ impl Default for ObserverRuntimeSettings {
             email:                None,
             pool:                 ObserverPoolConfig::default(),
             log_payloads:         false,
+            metrics:              MetricsConfig::default(),
+            event_types:          EventTypesConfig::default(),
         }
     }
 }
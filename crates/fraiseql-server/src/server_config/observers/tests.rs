@@ -42,6 +42,44 @@ fn runtime_pool_subtable_parses() {
     assert_eq!(cfg.runtime.pool.acquire_timeout_secs, 15);
 }
 
+#[test]
+fn runtime_metrics_subtable_parses() {
+    let cfg: ObserverConfig = toml::from_str(
+        "[runtime.metrics]\n\
+         max_label_series = 250\n\
+         tenant_labels = false\n",
+    )
+    .unwrap();
+
+    assert_eq!(cfg.runtime.metrics.max_label_series, 250);
+    assert!(!cfg.runtime.metrics.tenant_labels);
+
+    let default: ObserverConfig = toml::from_str("enabled = true\n").unwrap();
+    assert!(default.runtime.metrics.tenant_labels);
+}
+
+#[test]
+fn runtime_event_types_subtable_parses() {
+    let cfg: ObserverConfig = toml::from_str(
+        "[runtime.event_types]\n\
+         undeclared = \"quarantine\"\n\
+         [[runtime.event_types.types]]\n\
+         entity_type = \"Order\"\n\
+         event_kinds = [\"INSERT\"]\n\
+         schema = { type = \"object\", required = [\"id\"] }\n",
+    )
+    .unwrap();
+
+    let event_types = &cfg.runtime.event_types;
+    assert_eq!(event_types.undeclared, fraiseql_observers::UndeclaredEventPolicy::Quarantine);
+    assert_eq!(event_types.types[0].entity_type, "Order");
+    assert_eq!(event_types.types[0].schema["required"][0], "id");
+    event_types.validate().unwrap();
+
+    let default: ObserverConfig = toml::from_str("enabled = true\n").unwrap();
+    assert!(!default.runtime.event_types.is_enabled());
+}
+
 #[test]
 fn runtime_typo_is_rejected() {
     // A genuine typo under `[observers.runtime]` must fail loud (deny_unknown_fields),
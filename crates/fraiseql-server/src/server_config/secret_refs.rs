@@ -0,0 +1,62 @@
+//! `${secret:path}` references in `fraiseql.toml`.
+//!
+//! References are substituted in the raw TOML tree before it is deserialized
+//! into [`ServerConfig`](super::ServerConfig), so any string setting — a
+//! database URL, an API key, a webhook header — can name a secret held in the
+//! configured secrets backend instead of carrying it in plain text.
+
+#[cfg(test)]
+mod tests;
+
+/// Whether any string in `value` contains a `${secret:...}` reference.
+#[must_use]
+pub fn contains_secret_references(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::String(s) => s.contains("${secret:"),
+        toml::Value::Array(items) => items.iter().any(contains_secret_references),
+        toml::Value::Table(table) => table.values().any(contains_secret_references),
+        _ => false,
+    }
+}
+
+/// Substitute every `${secret:...}` reference in `value`, in place.
+///
+/// Returns the number of strings that were rewritten.
+///
+/// # Errors
+///
+/// Fails on the first reference the resolver cannot resolve.
+#[cfg(feature = "secrets")]
+pub async fn resolve_secret_references(
+    value: &mut toml::Value,
+    resolver: &crate::secrets_manager::SecretReferenceResolver,
+) -> Result<usize, crate::secrets_manager::SecretsError> {
+    let mut strings = Vec::new();
+    collect_strings(value, &mut strings);
+    let mut resolved = 0;
+    for s in strings {
+        if let Some(replacement) = resolver.resolve(s).await? {
+            *s = replacement;
+            resolved += 1;
+        }
+    }
+    Ok(resolved)
+}
+
+#[cfg(feature = "secrets")]
+fn collect_strings<'a>(value: &'a mut toml::Value, out: &mut Vec<&'a mut String>) {
+    match value {
+        toml::Value::String(s) => out.push(s),
+        toml::Value::Array(items) => {
+            for item in items {
+                collect_strings(item, out);
+            }
+        },
+        toml::Value::Table(table) => {
+            for (_, item) in table.iter_mut() {
+                collect_strings(item, out);
+            }
+        },
+        _ => {},
+    }
+}
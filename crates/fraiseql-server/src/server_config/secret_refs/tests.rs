@@ -0,0 +1,68 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+
+use super::contains_secret_references;
+
+#[test]
+fn detects_references_at_any_depth() {
+    let plain: toml::Value = toml::from_str(
+        r#"
+        database_url = "postgres://localhost/app"
+        [observers.runtime]
+        batch_size = 10
+        "#,
+    )
+    .unwrap();
+    assert!(!contains_secret_references(&plain));
+
+    let nested: toml::Value = toml::from_str(
+        r#"
+        [[files.uploads.hooks]]
+        headers = ["Authorization: Bearer ${secret:hooks/token}"]
+        "#,
+    )
+    .unwrap();
+    assert!(contains_secret_references(&nested));
+}
+
+#[cfg(feature = "secrets")]
+mod resolve {
+    use std::{sync::Arc, time::Duration};
+
+    use super::super::resolve_secret_references;
+    use crate::secrets_manager::{
+        EnvBackend, SecretReferenceResolver, SecretsError, SecretsManager,
+    };
+
+    fn resolver() -> SecretReferenceResolver {
+        let manager = Arc::new(SecretsManager::new(Arc::new(EnvBackend::new())));
+        SecretReferenceResolver::new(manager, Duration::from_secs(60))
+    }
+
+    #[tokio::test]
+    async fn references_are_substituted_in_place() {
+        temp_env::async_with_vars([("FRAISEQL_TEST_SECRET_REF_DB", Some("hunter2"))], async {
+            let mut value: toml::Value = toml::from_str(
+                r#"
+                database_url = "postgres://app:${secret:FRAISEQL_TEST_SECRET_REF_DB}@db/app"
+                graphql_path = "/graphql"
+                "#,
+            )
+            .unwrap();
+            assert_eq!(resolve_secret_references(&mut value, &resolver()).await.unwrap(), 1);
+            assert_eq!(value["database_url"].as_str(), Some("postgres://app:hunter2@db/app"));
+            assert_eq!(value["graphql_path"].as_str(), Some("/graphql"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn missing_secret_fails() {
+        let mut value: toml::Value =
+            toml::from_str(r#"database_url = "${secret:FRAISEQL_TEST_SECRET_REF_ABSENT}""#)
+                .unwrap();
+        assert!(matches!(
+            resolve_secret_references(&mut value, &resolver()).await,
+            Err(SecretsError::NotFound(_))
+        ));
+    }
+}
@@ -12,7 +12,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use fraiseql_storage::{
-    StorageMetadataRepo, StorageRlsEvaluator, StorageState,
+    StorageMetadataRepo, StorageRlsEvaluator, StorageState, UrlSigner,
     config::{BucketAccess, BucketConfig, StorageConfig},
 };
 use sqlx::postgres::PgPoolOptions;
@@ -29,10 +29,12 @@ const STORAGE_METADATA_POOL_MAX: u32 = 5;
 #[derive(Debug, Clone)]
 pub struct ResolvedStorage {
     /// Backend connection config passed to `fraiseql_storage::create_backend`.
-    pub backend: StorageConfig,
+    pub backend:                StorageConfig,
     /// Logical-bucket access policy. `bucket.name` is the section key and the
     /// bucket name used in the URL path.
-    pub bucket:  BucketConfig,
+    pub bucket:                 BucketConfig,
+    /// Environment variable holding the `fileUrl` signing secret, if any.
+    pub url_signing_secret_env: Option<String>,
 }
 
 /// Resolve the configured storage section into a [`ResolvedStorage`].
@@ -89,7 +91,11 @@ fn resolve_from_map(
         serve_inline: section.serve_inline.unwrap_or(false),
     };
 
-    Ok(Some(ResolvedStorage { backend, bucket }))
+    Ok(Some(ResolvedStorage {
+        backend,
+        bucket,
+        url_signing_secret_env: section.url_signing_secret_env.clone(),
+    }))
 }
 
 /// Build the storage runtime [`StorageState`] from the configured
@@ -104,13 +110,20 @@ fn resolve_from_map(
 ///
 /// Returns an error message when the storage section is invalid (see
 /// [`resolve_storage_section`]), the metadata database cannot be reached, the
-/// metadata table cannot be created, or the backend cannot be constructed (for
-/// example, a backend whose Cargo feature is not compiled in).
+/// metadata table cannot be created, the backend cannot be constructed (for
+/// example, a backend whose Cargo feature is not compiled in), or
+/// `url_signing_secret_env` names a variable that is unset or too short.
 pub async fn build_storage_state(config: &ServerConfig) -> Result<Option<StorageState>, String> {
     let Some(resolved) = resolve_storage_section(config)? else {
         return Ok(None);
     };
     let bucket_name = resolved.bucket.name.clone();
+    let signer = resolved
+        .url_signing_secret_env
+        .as_deref()
+        .map(url_signer_from_env)
+        .transpose()?
+        .map(Arc::new);
 
     let pool = PgPoolOptions::new()
         .max_connections(STORAGE_METADATA_POOL_MAX)
@@ -133,13 +146,28 @@ pub async fn build_storage_state(config: &ServerConfig) -> Result<Option<Storage
     buckets.insert(bucket_name, resolved.bucket);
 
     Ok(Some(StorageState {
-        backend:  Arc::new(backend),
+        backend: Arc::new(backend),
         metadata: Arc::new(StorageMetadataRepo::new(pool)),
-        rls:      StorageRlsEvaluator::new(),
-        buckets:  Arc::new(buckets),
+        rls: StorageRlsEvaluator::new(),
+        buckets: Arc::new(buckets),
+        signer,
     }))
 }
 
+/// Build the `fileUrl` signer from the secret in environment variable
+/// `env_name`.
+fn url_signer_from_env(env_name: &str) -> Result<UrlSigner, String> {
+    let secret = std::env::var(env_name)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "storage: url_signing_secret_env is set but the environment variable                  {env_name} is empty/unset"
+            )
+        })?;
+    UrlSigner::new(secret.into_bytes()).map_err(|e| format!("storage: {env_name}: {e}"))
+}
+
 /// Parse the optional per-bucket `access` policy. Defaults to the secure
 /// [`BucketAccess::Private`] policy when unset.
 fn parse_access(access: Option<&str>) -> Result<BucketAccess, String> {
@@ -62,6 +62,25 @@ fn test_config_with_custom_pool_size() {
     assert_eq!(config.pool_timeout_secs, 60);
 }
 
+#[test]
+fn test_request_timeout_ms_takes_precedence_over_secs() {
+    use std::time::Duration;
+
+    assert_eq!(ServerConfig::default().request_timeout(), None);
+
+    let secs_only = ServerConfig {
+        request_timeout_secs: Some(30),
+        ..ServerConfig::default()
+    };
+    assert_eq!(secs_only.request_timeout(), Some(Duration::from_secs(30)));
+
+    let both = ServerConfig {
+        request_timeout_ms: Some(2_500),
+        ..secs_only
+    };
+    assert_eq!(both.request_timeout(), Some(Duration::from_millis(2_500)));
+}
+
 #[test]
 fn test_validate_metrics_disabled_ok() {
     let config = ServerConfig {
@@ -410,6 +429,30 @@ fn test_validate_admin_readonly_token_valid_passes() {
     config.validate().unwrap_or_else(|e| panic!("expected Ok: {e}"));
 }
 
+#[test]
+fn test_validate_admin_operator_token_must_differ_from_other_tokens() {
+    let token = "admin-readonly-token-that-is-long-enough-5678".to_string();
+    let config = ServerConfig {
+        admin_api_enabled: true,
+        admin_token: Some("admin-write-token-that-is-long-enough-1234".to_string()),
+        admin_operator_token: Some(token.clone()),
+        admin_readonly_token: Some(token),
+        cors_enabled: false,
+        ..ServerConfig::default()
+    };
+    let err = config.validate().unwrap_err();
+    assert!(
+        err.contains("admin_operator_token must differ"),
+        "expected differ error, got: {err}"
+    );
+
+    let config = ServerConfig {
+        admin_operator_token: Some("admin-operator-token-that-is-long-enough-9012".to_string()),
+        ..config
+    };
+    config.validate().unwrap_or_else(|e| panic!("expected Ok: {e}"));
+}
+
 #[test]
 fn test_validate_admin_readonly_token_without_admin_enabled_is_ignored() {
     // admin_readonly_token with admin_api_enabled=false — validation skipped entirely.
@@ -575,6 +618,21 @@ fn resolve_storage_section_honors_public_read_and_policy_fields() {
         Some(["image/png".to_string(), "image/*".to_string()].as_slice()),
     );
     assert!(resolved.bucket.serve_inline);
+    assert!(resolved.url_signing_secret_env.is_none());
+}
+
+#[test]
+fn resolve_storage_section_carries_url_signing_secret_env() {
+    let toml_str = r#"
+        [storage.docs]
+        backend = "local"
+        path = "/tmp/docs"
+        url_signing_secret_env = "FRAISEQL_STORAGE_URL_SECRET"
+    "#;
+    let config: ServerConfig = toml::from_str(toml_str).unwrap();
+    let resolved = resolve_storage_section(&config).unwrap().unwrap();
+
+    assert_eq!(resolved.url_signing_secret_env.as_deref(), Some("FRAISEQL_STORAGE_URL_SECRET"));
 }
 
 #[test]
@@ -694,6 +752,88 @@ fn test_auth_hs256_fails_when_secret_env_unset() {
     });
 }
 
+// ── tenancy ──────────────────────────────────────────────────────────────────
+
+fn tenancy_config(toml_src: &str) -> ServerConfig {
+    let tenancy: TenancyServerConfig = toml::from_str(toml_src).unwrap();
+    ServerConfig {
+        tenancy,
+        cors_enabled: false,
+        ..ServerConfig::default()
+    }
+}
+
+#[test]
+fn test_tenancy_tenants_parse_and_validate() {
+    let config = tenancy_config(
+        r#"
+        [runtime]
+        enabled = true
+        max_loaded_tenants = 2
+
+        [tenants.acme]
+        schema_path = "schemas/acme.compiled.json"
+        database_url = "postgresql://localhost/acme"
+        hosts = ["acme.example.com"]
+        "#,
+    );
+    assert_eq!(config.tenancy.runtime.max_loaded_tenants, Some(2));
+    assert_eq!(config.tenancy.tenants["acme"].hosts, ["acme.example.com"]);
+    assert!(config.tenancy.tenants["acme"].max_connections.is_none());
+    config.validate().unwrap();
+}
+
+#[test]
+fn test_tenancy_tenants_require_runtime() {
+    let config = tenancy_config(
+        r#"
+        [tenants.acme]
+        schema_path = "acme.json"
+        database_url = "postgresql://localhost/acme"
+        "#,
+    );
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("[tenancy.runtime]"), "got: {err}");
+}
+
+#[test]
+fn test_tenancy_rejects_host_routed_to_two_tenants() {
+    let config = tenancy_config(
+        r#"
+        [runtime]
+        enabled = true
+
+        [tenants.acme]
+        schema_path = "acme.json"
+        database_url = "postgresql://localhost/acme"
+        hosts = ["shared.example.com"]
+
+        [tenants.globex]
+        schema_path = "globex.json"
+        database_url = "postgresql://localhost/globex"
+        hosts = ["shared.example.com"]
+        "#,
+    );
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("shared.example.com"), "got: {err}");
+}
+
+#[test]
+fn test_tenancy_rejects_invalid_tenant_key() {
+    let config = tenancy_config(
+        r#"
+        [runtime]
+        enabled = true
+
+        [tenants."acme-corp"]
+        schema_path = "acme.json"
+        database_url = "postgresql://localhost/acme"
+        "#,
+    );
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("acme-corp"), "got: {err}");
+}
+
 // ── observers_tests ───────────────────────────────────────────────────────────
 
 #[cfg(feature = "observers")]
@@ -0,0 +1,278 @@
+//! Shadow traffic mirroring: re-run sampled read queries against a second executor.
+//!
+//! With `[shadow]` configured, the server builds a shadow executor at serve time
+//! from a secondary compiled schema, a secondary database, or both. After a
+//! sampled read query succeeds on the current executor, the GraphQL handler hands
+//! it to [`ShadowMirror::mirror`], which re-executes it in the background under the
+//! same security context and compares the two JSON results:
+//!
+//! - arrays are compared as multisets, so a view that returns the same rows in a different order
+//!   still matches;
+//! - on a mismatch, the JSON pointer of the first difference is logged with the query text
+//!   (variables are never logged).
+//!
+//! Mirroring is bounded by `max_in_flight`; samples beyond it are dropped rather
+//! than queued, so a slow shadow never builds up work. Mirrored, matched,
+//! mismatched, failed and dropped counts are exported on `/metrics`
+//! (`fraiseql_shadow_*`). The response returned to the client is never affected.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use fraiseql_core::{db::traits::DatabaseAdapter, runtime::Executor, security::SecurityContext};
+use serde_json::Value;
+use tokio::sync::Semaphore;
+use tracing::{debug, warn};
+
+use crate::config::ShadowConfig;
+
+#[cfg(test)]
+mod tests;
+
+/// A read query that succeeded on the current executor, to be mirrored.
+#[derive(Debug, Clone)]
+pub struct MirroredQuery {
+    /// GraphQL query text
+    pub query:            String,
+    /// Query variables
+    pub variables:        Option<Value>,
+    /// Operation name, for logging
+    pub operation_name:   Option<String>,
+    /// Security context the query ran under
+    pub security_context: Option<SecurityContext>,
+    /// Result returned by the current executor
+    pub primary:          Value,
+}
+
+/// Snapshot of the mirroring counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShadowStats {
+    /// Queries re-executed on the shadow
+    pub mirrored:   u64,
+    /// Mirrored queries whose results matched
+    pub matched:    u64,
+    /// Mirrored queries whose results differed
+    pub mismatched: u64,
+    /// Mirrored queries that failed or timed out on the shadow
+    pub errors:     u64,
+    /// Samples dropped because `max_in_flight` queries were already running
+    pub dropped:    u64,
+}
+
+#[derive(Debug, Default)]
+struct ShadowCounters {
+    mirrored:   AtomicU64,
+    matched:    AtomicU64,
+    mismatched: AtomicU64,
+    errors:     AtomicU64,
+    dropped:    AtomicU64,
+}
+
+/// A shadow executor and the sampling that feeds it.
+pub struct ShadowMirror<A: DatabaseAdapter> {
+    executor:       Arc<Executor<A>>,
+    sample_percent: u8,
+    timeout:        Duration,
+    in_flight:      Arc<Semaphore>,
+    sampled:        AtomicU64,
+    counters:       ShadowCounters,
+}
+
+impl<A: DatabaseAdapter + 'static> ShadowMirror<A> {
+    /// Create a mirror re-executing samples on `executor` as configured by `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `config` is invalid.
+    pub fn new(executor: Arc<Executor<A>>, config: &ShadowConfig) -> Result<Self, String> {
+        config.validate()?;
+        Ok(Self {
+            executor,
+            sample_percent: config.sample_percent,
+            timeout: Duration::from_millis(config.timeout_ms),
+            in_flight: Arc::new(Semaphore::new(config.max_in_flight)),
+            sampled: AtomicU64::new(0),
+            counters: ShadowCounters::default(),
+        })
+    }
+
+    /// Whether the next read query should be mirrored. Spreads `sample_percent` of
+    /// every hundred calls evenly by a counter.
+    #[must_use]
+    pub fn should_sample(&self) -> bool {
+        if self.sample_percent == 0 {
+            return false;
+        }
+        let slot = self.sampled.fetch_add(1, Ordering::Relaxed) % 100;
+        slot < u64::from(self.sample_percent)
+    }
+
+    /// Percentage of read queries mirrored.
+    #[must_use]
+    pub const fn sample_percent(&self) -> u8 {
+        self.sample_percent
+    }
+
+    /// Re-execute `query` on the shadow in a background task and log any difference
+    /// from its primary result. Returns immediately; the sample is dropped when
+    /// `max_in_flight` mirrored queries are already running.
+    pub fn mirror(self: &Arc<Self>, query: MirroredQuery) {
+        let Ok(permit) = Arc::clone(&self.in_flight).try_acquire_owned() else {
+            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+            debug!("Shadow mirroring saturated, dropping sample");
+            return;
+        };
+        let mirror = Arc::clone(self);
+        tokio::spawn(async move {
+            mirror.compare(query).await;
+            drop(permit);
+        });
+    }
+
+    async fn compare(&self, query: MirroredQuery) {
+        let MirroredQuery {
+            query,
+            variables,
+            operation_name,
+            security_context,
+            primary,
+        } = query;
+        self.counters.mirrored.fetch_add(1, Ordering::Relaxed);
+
+        let execution = async {
+            match security_context {
+                Some(ref ctx) => {
+                    self.executor.execute_with_security(&query, variables.as_ref(), ctx).await
+                },
+                None => self.executor.execute(&query, variables.as_ref()).await,
+            }
+        };
+        let shadow = match tokio::time::timeout(self.timeout, execution).await {
+            Ok(Ok(shadow)) => shadow,
+            Ok(Err(error)) => {
+                self.counters.errors.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    operation = operation_name.as_deref().unwrap_or("<anonymous>"),
+                    error = %error,
+                    query = %query,
+                    "Shadow query failed where the primary succeeded"
+                );
+                return;
+            },
+            Err(_) => {
+                self.counters.errors.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    operation = operation_name.as_deref().unwrap_or("<anonymous>"),
+                    timeout_ms = u64::try_from(self.timeout.as_millis()).unwrap_or(u64::MAX),
+                    "Shadow query timed out"
+                );
+                return;
+            },
+        };
+
+        match first_difference(&primary, &shadow) {
+            None => {
+                self.counters.matched.fetch_add(1, Ordering::Relaxed);
+            },
+            Some(path) => {
+                self.counters.mismatched.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    operation = operation_name.as_deref().unwrap_or("<anonymous>"),
+                    path = %path,
+                    query = %query,
+                    "Shadow result differs from the primary result"
+                );
+            },
+        }
+    }
+
+    /// Mirroring counters.
+    #[must_use]
+    pub fn stats(&self) -> ShadowStats {
+        ShadowStats {
+            mirrored:   self.counters.mirrored.load(Ordering::Relaxed),
+            matched:    self.counters.matched.load(Ordering::Relaxed),
+            mismatched: self.counters.mismatched.load(Ordering::Relaxed),
+            errors:     self.counters.errors.load(Ordering::Relaxed),
+            dropped:    self.counters.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl<A: DatabaseAdapter> std::fmt::Debug for ShadowMirror<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShadowMirror")
+            .field("sample_percent", &self.sample_percent)
+            .field("timeout", &self.timeout)
+            .field("available_permits", &self.in_flight.available_permits())
+            .finish_non_exhaustive()
+    }
+}
+
+/// JSON pointer of the first difference between two results, ignoring object key
+/// order and array element order; `None` when they are equivalent.
+#[must_use]
+pub fn first_difference(primary: &Value, shadow: &Value) -> Option<String> {
+    diff_at(&normalize(primary), &normalize(shadow), String::new())
+}
+
+/// Sort object keys and array elements recursively, so equivalent values compare
+/// and serialise identically.
+fn normalize(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().map(|(k, v)| (k.clone(), normalize(v))).collect())
+        },
+        Value::Array(items) => {
+            let mut keyed: Vec<(String, Value)> = items
+                .iter()
+                .map(|item| {
+                    let item = normalize(item);
+                    (item.to_string(), item)
+                })
+                .collect();
+            keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Array(keyed.into_iter().map(|(_, item)| item).collect())
+        },
+        other => other.clone(),
+    }
+}
+
+/// Escape a key for use as a JSON pointer segment (RFC 6901).
+fn pointer_segment(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn diff_at(primary: &Value, shadow: &Value, path: String) -> Option<String> {
+    match (primary, shadow) {
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            keys.into_iter().find_map(|key| {
+                let child = format!("{path}/{}", pointer_segment(key));
+                match (a.get(key), b.get(key)) {
+                    (Some(x), Some(y)) => diff_at(x, y, child),
+                    _ => Some(child),
+                }
+            })
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            if a.len() != b.len() {
+                return Some(path);
+            }
+            a.iter()
+                .zip(b)
+                .enumerate()
+                .find_map(|(i, (x, y))| diff_at(x, y, format!("{path}/{i}")))
+        },
+        _ => (primary != shadow).then_some(path),
+    }
+}
@@ -0,0 +1,99 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::{sync::Arc, time::Duration};
+
+use fraiseql_core::{runtime::Executor, schema::CompiledSchema};
+use fraiseql_test_utils::failing_adapter::FailingAdapter;
+use serde_json::json;
+
+use super::{MirroredQuery, ShadowMirror, first_difference};
+use crate::config::ShadowConfig;
+
+fn config(sample_percent: u8) -> ShadowConfig {
+    ShadowConfig {
+        schema_path: Some("schema.shadow.compiled.json".into()),
+        database_url: None,
+        sample_percent,
+        max_in_flight: 4,
+        timeout_ms: 1_000,
+    }
+}
+
+fn mirror(sample_percent: u8) -> Arc<ShadowMirror<FailingAdapter>> {
+    let executor = Arc::new(Executor::new(CompiledSchema::new(), Arc::new(FailingAdapter::new())));
+    Arc::new(ShadowMirror::new(executor, &config(sample_percent)).unwrap())
+}
+
+#[test]
+fn array_order_is_ignored() {
+    let primary =
+        json!({"data": {"users": [{"id": 1, "tags": ["a", "b"]}, {"id": 2, "tags": []}]}});
+    let shadow = json!({"data": {"users": [{"tags": [], "id": 2}, {"id": 1, "tags": ["b", "a"]}]}});
+    assert_eq!(first_difference(&primary, &shadow), None);
+}
+
+#[test]
+fn reports_path_of_first_difference() {
+    let primary = json!({"data": {"user": {"id": 1, "name": "Ada"}}});
+    let shadow = json!({"data": {"user": {"id": 1, "name": "Grace"}}});
+    assert_eq!(first_difference(&primary, &shadow).as_deref(), Some("/data/user/name"));
+
+    let missing = json!({"data": {"user": {"id": 1}}});
+    assert_eq!(first_difference(&primary, &missing).as_deref(), Some("/data/user/name"));
+}
+
+#[test]
+fn differing_row_counts_are_reported_at_the_array() {
+    let primary = json!({"data": {"users": [{"id": 1}, {"id": 2}]}});
+    let shadow = json!({"data": {"users": [{"id": 1}]}});
+    assert_eq!(first_difference(&primary, &shadow).as_deref(), Some("/data/users"));
+}
+
+#[test]
+fn pointer_segments_are_escaped() {
+    let primary = json!({"a/b": {"c~d": 1}});
+    let shadow = json!({"a/b": {"c~d": 2}});
+    assert_eq!(first_difference(&primary, &shadow).as_deref(), Some("/a~1b/c~0d"));
+}
+
+#[test]
+fn samples_configured_share() {
+    let ten = mirror(10);
+    assert_eq!((0..1_000).filter(|_| ten.should_sample()).count(), 100);
+    let none = mirror(0);
+    assert_eq!((0..1_000).filter(|_| none.should_sample()).count(), 0);
+}
+
+#[test]
+fn config_requires_a_shadow_target() {
+    let mut untargeted = config(10);
+    untargeted.schema_path = None;
+    assert!(untargeted.validate().is_err());
+
+    let mut too_many = config(101);
+    too_many.database_url = Some("postgres://localhost/shadow".to_string());
+    assert!(too_many.validate().is_err());
+}
+
+#[tokio::test]
+async fn shadow_failure_is_counted() {
+    let mirror = mirror(100);
+    mirror.mirror(MirroredQuery {
+        query:            "{ users { id } }".to_string(),
+        variables:        None,
+        operation_name:   None,
+        security_context: None,
+        primary:          json!({"data": {"users": []}}),
+    });
+
+    for _ in 0..100 {
+        if mirror.stats().errors == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let stats = mirror.stats();
+    assert_eq!(stats.mirrored, 1);
+    assert_eq!(stats.errors, 1);
+    assert_eq!(stats.mismatched, 0);
+}
@@ -0,0 +1,325 @@
+//! Graceful shutdown coordination across the HTTP server, observers and the
+//! Arrow Flight service.
+//!
+//! On SIGTERM (or Ctrl+C) the server runs these phases against a single
+//! deadline, `shutdown_timeout_secs` after the signal:
+//!
+//! 1. **Stop accepting** — the HTTP listener stops accepting connections and requests arriving on
+//!    kept-alive ones get `503` with `Connection: close`; the Flight server stops accepting calls.
+//! 2. **Drain** — in-flight HTTP requests (GraphQL operations included) run to completion; those
+//!    still running at the deadline are abandoned.
+//! 3. **Observers** — the runtime finishes its in-flight change-log batch and persists the
+//!    checkpoint, so no event is redelivered after a restart.
+//! 4. **Flight** — in-flight Flight streams finish.
+//! 5. **Close hooks** — hooks registered with [`ShutdownCoordinator::on_shutdown`] run
+//!    concurrently: close ClickHouse/warehouse sinks (drop the batch sender and await the sink task
+//!    so it flushes its buffer), flush exporters, ….
+//!
+//! Each phase gets what is left of the deadline. A [`ShutdownSummary`] is logged
+//! once the phases complete.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::{Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::{Notify, watch};
+use tracing::{info, warn};
+
+#[cfg(test)]
+mod tests;
+
+type CloseFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Coordinates the shutdown phases of one server.
+///
+/// Obtain the server's coordinator with `Server::shutdown_coordinator` to
+/// register close hooks before calling `serve`.
+pub struct ShutdownCoordinator {
+    timeout:   Duration,
+    started:   OnceLock<(Instant, usize)>,
+    draining:  AtomicBool,
+    requested: watch::Sender<bool>,
+    in_flight: AtomicUsize,
+    idle:      Notify,
+    hooks:     Mutex<Vec<(String, CloseFuture)>>,
+}
+
+impl ShutdownCoordinator {
+    /// Create a coordinator whose phases must complete within `timeout` of the
+    /// shutdown signal.
+    #[must_use]
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            started: OnceLock::new(),
+            draining: AtomicBool::new(false),
+            requested: watch::Sender::new(false),
+            in_flight: AtomicUsize::new(0),
+            idle: Notify::new(),
+            hooks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Register a hook run in the close phase, after requests, observers and
+    /// Flight have drained.
+    ///
+    /// Use it for resources the server does not own, e.g. a `ClickHouseSink`
+    /// task: drop its sender and await the task so the buffered rows are
+    /// flushed. An `Err` or a hook still running at the deadline is reported in
+    /// the summary.
+    pub fn on_shutdown<F>(&self, name: impl Into<String>, close: F)
+    where
+        F: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.hooks.lock().push((name.into(), Box::pin(close)));
+    }
+
+    /// Start shutting down; returns `true` if this call started it.
+    ///
+    /// New requests are refused from here on and the deadline starts running.
+    pub fn begin(&self) -> bool {
+        let mut first = false;
+        self.started.get_or_init(|| {
+            first = true;
+            (Instant::now(), self.in_flight.load(Ordering::Acquire))
+        });
+        if first {
+            self.draining.store(true, Ordering::Release);
+            self.requested.send_replace(true);
+            info!(
+                in_flight = self.in_flight(),
+                timeout_secs = self.timeout.as_secs(),
+                "Shutdown started: refusing new requests, draining in-flight work"
+            );
+        }
+        first
+    }
+
+    /// Whether shutdown has started.
+    #[must_use]
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
+    /// Resolve once shutdown has started.
+    pub async fn requested(&self) {
+        let mut rx = self.requested.subscribe();
+        // The sender lives as long as `self`, so this only errs if it is dropped.
+        let _ = rx.wait_for(|requested| *requested).await;
+    }
+
+    /// Time left before the deadline; the full timeout before shutdown starts.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.started
+            .get()
+            .map_or(self.timeout, |(started, _)| self.timeout.saturating_sub(started.elapsed()))
+    }
+
+    /// Number of requests currently in flight.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Count a request as in flight for the guard's lifetime.
+    ///
+    /// Returns `None` once shutdown has started.
+    #[must_use]
+    pub fn track(self: &Arc<Self>) -> Option<InFlightGuard> {
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        let guard = InFlightGuard(Arc::clone(self));
+        if self.is_draining() {
+            return None;
+        }
+        Some(guard)
+    }
+
+    /// Wait until no request is in flight or the deadline passes; returns the
+    /// number of requests still in flight.
+    pub async fn drain_requests(&self) -> usize {
+        let _ = tokio::time::timeout(self.remaining(), async {
+            loop {
+                let idle = self.idle.notified();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                idle.await;
+            }
+        })
+        .await;
+        self.in_flight()
+    }
+
+    /// Run the registered close hooks concurrently within the remaining time.
+    pub async fn run_close_hooks(&self) -> Vec<StepReport> {
+        let hooks = std::mem::take(&mut *self.hooks.lock());
+        let budget = self.remaining();
+        futures::future::join_all(
+            hooks.into_iter().map(|(name, close)| run_step(name, budget, close)),
+        )
+        .await
+    }
+
+    /// Summarise the shutdown: `abandoned` requests and the phase reports.
+    #[must_use]
+    pub fn summary(&self, abandoned: usize, steps: Vec<StepReport>) -> ShutdownSummary {
+        let (elapsed, at_signal) = self
+            .started
+            .get()
+            .map_or((Duration::ZERO, 0), |(started, n)| (started.elapsed(), *n));
+        ShutdownSummary {
+            requests_drained: at_signal.saturating_sub(abandoned),
+            requests_abandoned: abandoned,
+            steps,
+            elapsed_ms: millis(elapsed),
+        }
+    }
+}
+
+/// Marks one request in flight; dropping it completes the request.
+pub struct InFlightGuard(Arc<ShutdownCoordinator>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+/// Refuse requests once shutdown has started and count the rest as in flight.
+pub async fn shutdown_middleware(
+    State(coordinator): State<Arc<ShutdownCoordinator>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(_in_flight) = coordinator.track() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::CONNECTION, "close")],
+            Json(serde_json::json!({
+                "errors": [{
+                    "message": "Server is shutting down",
+                    "extensions": { "code": "SERVICE_UNAVAILABLE" }
+                }]
+            })),
+        )
+            .into_response();
+    };
+    next.run(request).await
+}
+
+/// How a shutdown phase ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "status", content = "error")]
+pub enum StepOutcome {
+    /// The phase finished.
+    Completed,
+    /// The phase finished with an error.
+    Failed(String),
+    /// The phase was still running at the deadline.
+    TimedOut,
+}
+
+/// Outcome of one shutdown phase.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StepReport {
+    /// Phase name (`"observers"`, `"flight"`, or the close hook's name).
+    pub name:       String,
+    /// How the phase ended.
+    pub outcome:    StepOutcome,
+    /// Phase duration in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+/// Run one shutdown phase under `budget` and record its outcome.
+pub async fn run_step<F>(name: impl Into<String>, budget: Duration, step: F) -> StepReport
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let started = Instant::now();
+    let outcome = match tokio::time::timeout(budget, step).await {
+        Ok(Ok(())) => StepOutcome::Completed,
+        Ok(Err(e)) => StepOutcome::Failed(e),
+        Err(_) => StepOutcome::TimedOut,
+    };
+    StepReport {
+        name: name.into(),
+        outcome,
+        elapsed_ms: millis(started.elapsed()),
+    }
+}
+
+/// What a shutdown drained and what it had to abandon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShutdownSummary {
+    /// Requests in flight at the signal that completed.
+    pub requests_drained:   usize,
+    /// Requests still in flight at the deadline.
+    pub requests_abandoned: usize,
+    /// Observer, Flight and close-hook phases, in the order they ran.
+    pub steps:              Vec<StepReport>,
+    /// Time from the signal to the end of the last phase.
+    pub elapsed_ms:         u64,
+}
+
+impl ShutdownSummary {
+    /// Whether every request drained and every phase completed.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.requests_abandoned == 0
+            && self.steps.iter().all(|step| step.outcome == StepOutcome::Completed)
+    }
+
+    /// Log the summary: `info` when clean, `warn` otherwise.
+    pub fn log(&self) {
+        for step in &self.steps {
+            match step.outcome {
+                StepOutcome::Completed => {
+                    info!(step = %step.name, elapsed_ms = step.elapsed_ms, "Shutdown step completed");
+                },
+                StepOutcome::Failed(ref error) => {
+                    warn!(step = %step.name, elapsed_ms = step.elapsed_ms, %error, "Shutdown step failed");
+                },
+                StepOutcome::TimedOut => {
+                    warn!(step = %step.name, elapsed_ms = step.elapsed_ms, "Shutdown step timed out");
+                },
+            }
+        }
+        if self.is_clean() {
+            info!(
+                requests_drained = self.requests_drained,
+                elapsed_ms = self.elapsed_ms,
+                "Graceful shutdown complete"
+            );
+        } else {
+            warn!(
+                requests_drained = self.requests_drained,
+                requests_abandoned = self.requests_abandoned,
+                elapsed_ms = self.elapsed_ms,
+                "Shutdown finished with abandoned work"
+            );
+        }
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
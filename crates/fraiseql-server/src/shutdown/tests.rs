@@ -0,0 +1,169 @@
+#![allow(clippy::unwrap_used)] // Reason: test module
+
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    middleware,
+    routing::get,
+};
+use tower::ServiceExt;
+
+use super::{ShutdownCoordinator, StepOutcome, run_step, shutdown_middleware};
+
+fn coordinator(timeout: Duration) -> Arc<ShutdownCoordinator> {
+    Arc::new(ShutdownCoordinator::new(timeout))
+}
+
+#[test]
+fn begin_is_idempotent() {
+    let coordinator = coordinator(Duration::from_secs(5));
+
+    assert!(!coordinator.is_draining());
+    assert!(coordinator.begin());
+    assert!(!coordinator.begin());
+    assert!(coordinator.is_draining());
+}
+
+#[test]
+fn track_refuses_requests_after_begin() {
+    let coordinator = coordinator(Duration::from_secs(5));
+
+    let guard = coordinator.track();
+    assert!(guard.is_some());
+    assert_eq!(coordinator.in_flight(), 1);
+
+    coordinator.begin();
+    assert!(coordinator.track().is_none());
+    assert_eq!(coordinator.in_flight(), 1, "a refused request is not counted");
+
+    drop(guard);
+    assert_eq!(coordinator.in_flight(), 0);
+}
+
+#[tokio::test]
+async fn requested_resolves_after_begin() {
+    let coordinator = coordinator(Duration::from_secs(5));
+    let waiter = {
+        let coordinator = coordinator.clone();
+        tokio::spawn(async move { coordinator.requested().await })
+    };
+
+    coordinator.begin();
+    tokio::time::timeout(Duration::from_secs(1), waiter).await.unwrap().unwrap();
+    // Late waiters resolve immediately.
+    tokio::time::timeout(Duration::from_secs(1), coordinator.requested())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn drain_waits_for_in_flight_requests() {
+    let coordinator = coordinator(Duration::from_secs(5));
+    let guard = coordinator.track().unwrap();
+    coordinator.begin();
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(guard);
+    });
+
+    assert_eq!(coordinator.drain_requests().await, 0);
+    let summary = coordinator.summary(0, Vec::new());
+    assert_eq!(summary.requests_drained, 1);
+    assert!(summary.is_clean());
+}
+
+#[tokio::test]
+async fn drain_reports_requests_still_running_at_the_deadline() {
+    let coordinator = coordinator(Duration::from_millis(50));
+    let _stuck = coordinator.track().unwrap();
+    coordinator.begin();
+
+    let abandoned = coordinator.drain_requests().await;
+
+    assert_eq!(abandoned, 1);
+    let summary = coordinator.summary(abandoned, Vec::new());
+    assert_eq!(summary.requests_drained, 0);
+    assert!(!summary.is_clean());
+}
+
+#[tokio::test]
+async fn run_step_records_each_outcome() {
+    let budget = Duration::from_millis(50);
+
+    let completed = run_step("observers", budget, async { Ok(()) }).await;
+    let failed = run_step("flight", budget, async { Err("boom".to_string()) }).await;
+    let timed_out = run_step("sink", budget, async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Ok(())
+    })
+    .await;
+
+    assert_eq!(completed.outcome, StepOutcome::Completed);
+    assert_eq!(failed.outcome, StepOutcome::Failed("boom".to_string()));
+    assert_eq!(timed_out.outcome, StepOutcome::TimedOut);
+}
+
+#[tokio::test]
+async fn close_hooks_run_once_within_the_deadline() {
+    let coordinator = coordinator(Duration::from_millis(100));
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    coordinator.on_shutdown("clickhouse", async move {
+        tx.send(()).unwrap();
+        Ok(())
+    });
+    coordinator.on_shutdown("warehouse", async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Ok(())
+    });
+    coordinator.begin();
+
+    let steps = coordinator.run_close_hooks().await;
+
+    rx.await.unwrap();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].name, "clickhouse");
+    assert_eq!(steps[0].outcome, StepOutcome::Completed);
+    assert_eq!(steps[1].outcome, StepOutcome::TimedOut);
+    assert!(coordinator.run_close_hooks().await.is_empty(), "hooks run once");
+}
+
+#[test]
+fn summary_serializes_step_outcomes() {
+    let coordinator = coordinator(Duration::from_secs(5));
+    let summary = coordinator.summary(
+        0,
+        vec![super::StepReport {
+            name:       "observers".to_string(),
+            outcome:    StepOutcome::Failed("checkpoint not saved".to_string()),
+            elapsed_ms: 12,
+        }],
+    );
+    let json = serde_json::to_value(&summary).unwrap();
+
+    assert_eq!(
+        json["steps"][0]["outcome"],
+        serde_json::json!({ "status": "failed", "error": "checkpoint not saved" })
+    );
+}
+
+#[tokio::test]
+async fn middleware_refuses_requests_once_shutdown_starts() {
+    let coordinator = coordinator(Duration::from_secs(5));
+    let app = Router::new()
+        .route("/graphql", get(|| async { "ok" }))
+        .layer(middleware::from_fn_with_state(coordinator.clone(), shutdown_middleware));
+    let request = || Request::builder().uri("/graphql").body(Body::empty()).unwrap();
+
+    let response = app.clone().oneshot(request()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(coordinator.in_flight(), 0);
+
+    coordinator.begin();
+    let response = app.oneshot(request()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()[header::CONNECTION], "close");
+}
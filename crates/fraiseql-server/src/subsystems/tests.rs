@@ -51,6 +51,7 @@ async fn local_storage_state(bucket_name: &str) -> StorageState {
         metadata: Arc::new(StorageMetadataRepo::new(lazy_pool())),
         rls:      StorageRlsEvaluator::new(),
         buckets:  Arc::new(buckets),
+        signer:   None,
     }
 }
 
@@ -38,6 +38,19 @@ pub struct TenantPoolConfig {
     pub idle_timeout_secs:    u64,
 }
 
+impl TenantPoolConfig {
+    /// Pool configuration for `connection_string` with default limits and timeouts.
+    #[must_use]
+    pub fn new(connection_string: impl Into<String>) -> Self {
+        Self {
+            connection_string:    connection_string.into(),
+            max_connections:      default_max_connections(),
+            connect_timeout_secs: default_connect_timeout(),
+            idle_timeout_secs:    default_idle_timeout(),
+        }
+    }
+}
+
 const fn default_max_connections() -> u32 {
     10
 }
@@ -112,6 +125,10 @@ impl<A: FromPoolConfig> FromPoolConfig for CachedDatabaseAdapter<A> {
 /// also provisions the tenant's PostgreSQL schema (`CREATE SCHEMA IF NOT EXISTS
 /// tenant_{key}`) and configures the adapter's search path.
 ///
+/// The schema signature is not checked here: the callers that take schema JSON
+/// from outside the server (`TenantExecutorRegistry::ensure_loaded` and the
+/// tenant admin API) verify it against `schema_public_key` first.
+///
 /// # Arguments
 ///
 /// * `tenant_key` - The tenant identifier used for schema naming
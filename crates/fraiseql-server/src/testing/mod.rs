@@ -1,3 +1,6 @@
 //! Testing utilities for fraiseql-server
 
 pub mod runtime_testing;
+pub mod test_server;
+
+pub use test_server::{GraphQLError, GraphQLResponse, TestClient, TestServer, TestServerBuilder};
@@ -0,0 +1,721 @@
+//! An embedded server for integration tests of FraiseQL applications.
+//!
+//! [`TestServer`] starts a real [`Server`] on an ephemeral port against a
+//! PostgreSQL database, isolated in a schema of its own that is created for the
+//! test and dropped afterwards:
+//!
+//! 1. `CREATE SCHEMA fraiseql_test_<random>`;
+//! 2. the DDL added with [`sql`](TestServerBuilder::sql) /
+//!    [`sql_file`](TestServerBuilder::sql_file) / [`sql_dir`](TestServerBuilder::sql_dir) —
+//!    typically the project's `tb_*` tables and the generated `v_*` views — runs with that schema
+//!    first on the `search_path`, so unqualified names land there;
+//! 3. fixture rows are inserted, table by table, in the order given;
+//! 4. the server connects with the same `search_path`, and the returned [`TestClient`] posts
+//!    GraphQL operations and decodes the responses.
+//!
+//! ```text
+//! // Requires: the `testing` feature and a reachable PostgreSQL (DATABASE_URL).
+//! let server = TestServer::builder(std::env::var("DATABASE_URL")?)
+//!     .schema_file("schema.compiled.json")
+//!     .sql_dir("db/schema")
+//!     .fixtures(json!([{ "table": "tb_user", "rows": [{ "id": "…", "data": { "name": "Ada" } }] }]))
+//!     .start()
+//!     .await?;
+//! let names: Vec<String> = server
+//!     .client()
+//!     .query("{ users { name } }")
+//!     .await?
+//!     .data_at::<Vec<serde_json::Value>>("users")?
+//!     .iter()
+//!     .map(|u| u["name"].as_str().unwrap_or_default().to_string())
+//!     .collect();
+//! server.shutdown().await?;
+//! ```
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use fraiseql_core::{
+    db::{postgres::PostgresAdapter, quote_postgres_identifier},
+    schema::CompiledSchema,
+};
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use sqlx::{Connection, PgConnection};
+use tokio::{net::TcpListener, sync::oneshot, task::JoinHandle};
+
+use crate::{Result, Server, ServerError, server_config::ServerConfig};
+
+/// How long [`TestServerBuilder::start`] waits for the server to answer its
+/// health endpoint.
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Prefix of every per-test database schema.
+pub const SCHEMA_PREFIX: &str = "fraiseql_test_";
+
+/// A DDL script to apply before the server starts.
+enum SqlSource {
+    Inline(String),
+    File(PathBuf),
+    Dir(PathBuf),
+}
+
+/// Fixture rows to insert before the server starts.
+enum FixtureSource {
+    Inline(Value),
+    File(PathBuf),
+}
+
+/// The rows fixtures hold for one table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureTable {
+    /// Table name, bare or schema-qualified.
+    pub table: String,
+    /// Rows as JSON objects keyed by column name.
+    pub rows:  Vec<serde_json::Map<String, Value>>,
+}
+
+/// Builder for a [`TestServer`]; see the [module documentation](self).
+pub struct TestServerBuilder {
+    database_url:    String,
+    compiled_schema: Option<CompiledSchema>,
+    schema_file:     Option<PathBuf>,
+    sql:             Vec<SqlSource>,
+    fixtures:        Vec<FixtureSource>,
+    config:          ServerConfig,
+    keep_schema:     bool,
+}
+
+impl TestServerBuilder {
+    /// Use this compiled schema.
+    #[must_use]
+    pub fn compiled_schema(mut self, schema: CompiledSchema) -> Self {
+        self.compiled_schema = Some(schema);
+        self
+    }
+
+    /// Load the compiled schema from `schema.compiled.json` at `path`.
+    #[must_use]
+    pub fn schema_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.schema_file = Some(path.into());
+        self
+    }
+
+    /// Run a DDL script. Scripts run in the order they are added.
+    #[must_use]
+    pub fn sql(mut self, sql: impl Into<String>) -> Self {
+        self.sql.push(SqlSource::Inline(sql.into()));
+        self
+    }
+
+    /// Run the DDL script at `path`.
+    #[must_use]
+    pub fn sql_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.sql.push(SqlSource::File(path.into()));
+        self
+    }
+
+    /// Run every `*.sql` file directly under `dir`, in file-name order.
+    #[must_use]
+    pub fn sql_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.sql.push(SqlSource::Dir(dir.into()));
+        self
+    }
+
+    /// Insert fixture rows; see [`parse_fixtures`] for the accepted shapes.
+    #[must_use]
+    pub fn fixtures(mut self, fixtures: Value) -> Self {
+        self.fixtures.push(FixtureSource::Inline(fixtures));
+        self
+    }
+
+    /// Insert the fixture rows in the JSON file at `path`.
+    #[must_use]
+    pub fn fixtures_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.fixtures.push(FixtureSource::File(path.into()));
+        self
+    }
+
+    /// Server configuration (defaults to [`ServerConfig::default`]). The bind
+    /// address is ignored: the server always listens on an ephemeral port.
+    #[must_use]
+    pub fn config(mut self, config: ServerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Leave the database schema in place after the test, for debugging.
+    #[must_use]
+    pub const fn keep_schema(mut self, keep: bool) -> Self {
+        self.keep_schema = keep;
+        self
+    }
+
+    /// Create the database schema, apply DDL and fixtures, and start the server.
+    ///
+    /// The schema is dropped again if any step fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::ConfigError` when no compiled schema was given or it
+    /// cannot be read, `ServerError::Database` when the database is unreachable or
+    /// rejects a script or fixture row, and the server's own error when it fails
+    /// to start or to become ready in time.
+    pub async fn start(self) -> Result<TestServer> {
+        let schema = match (self.compiled_schema, &self.schema_file) {
+            (Some(schema), _) => schema,
+            (None, Some(path)) => load_compiled_schema(path)?,
+            (None, None) => {
+                return Err(ServerError::ConfigError(
+                    "TestServer needs a compiled schema: call compiled_schema() or schema_file()"
+                        .to_string(),
+                ));
+            },
+        };
+        let db_schema = new_schema_name();
+        let server_url = with_search_path(&self.database_url, &db_schema)?;
+
+        let mut conn = PgConnection::connect(&self.database_url)
+            .await
+            .map_err(|e| ServerError::Database(format!("failed to connect: {e}")))?;
+        execute(&mut conn, &format!("CREATE SCHEMA {db_schema}")).await?;
+        let prepared = async {
+            execute(&mut conn, &format!("SET search_path TO {db_schema}, public")).await?;
+            for source in &self.sql {
+                apply_sql(&mut conn, source).await?;
+            }
+            for source in &self.fixtures {
+                let fixtures = match source {
+                    FixtureSource::Inline(value) => value.clone(),
+                    FixtureSource::File(path) => read_json(path)?,
+                };
+                for table in parse_fixtures(&fixtures)? {
+                    insert_fixture(&mut conn, &table).await?;
+                }
+            }
+            Ok::<_, ServerError>(())
+        }
+        .await;
+        let started = match prepared {
+            Ok(()) => start_server(self.config, schema, &server_url).await,
+            Err(e) => Err(e),
+        };
+        match started {
+            Ok((url, graphql_url, shutdown, task)) => Ok(TestServer {
+                url,
+                graphql_url,
+                database_url: self.database_url,
+                server_database_url: server_url,
+                db_schema,
+                keep_schema: self.keep_schema,
+                shutdown: Some(shutdown),
+                task: Some(task),
+            }),
+            Err(e) => {
+                if !self.keep_schema {
+                    let _ = execute(&mut conn, &drop_schema_sql(&db_schema)).await;
+                }
+                Err(e)
+            },
+        }
+    }
+}
+
+/// A running server backed by its own database schema.
+///
+/// Prefer [`shutdown`](Self::shutdown), which stops the server and drops the
+/// schema before returning. Dropping a `TestServer` does the same on a helper
+/// thread, so a failing test does not leak its schema either.
+pub struct TestServer {
+    url:                 String,
+    graphql_url:         String,
+    database_url:        String,
+    server_database_url: String,
+    db_schema:           String,
+    keep_schema:         bool,
+    shutdown:            Option<oneshot::Sender<()>>,
+    task:                Option<JoinHandle<Result<()>>>,
+}
+
+impl TestServer {
+    /// Start building a test server against the database at `database_url`
+    /// (a `postgres://` URL; the role needs `CREATE` on the database).
+    #[must_use = "builder does nothing until .start() is called"]
+    pub fn builder(database_url: impl Into<String>) -> TestServerBuilder {
+        TestServerBuilder {
+            database_url:    database_url.into(),
+            compiled_schema: None,
+            schema_file:     None,
+            sql:             Vec::new(),
+            fixtures:        Vec::new(),
+            config:          ServerConfig::default(),
+            keep_schema:     false,
+        }
+    }
+
+    /// Base URL of the server (e.g. `http://127.0.0.1:41234`).
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// URL of the GraphQL endpoint.
+    #[must_use]
+    pub fn graphql_url(&self) -> &str {
+        &self.graphql_url
+    }
+
+    /// Name of the database schema the test runs in.
+    #[must_use]
+    pub fn db_schema(&self) -> &str {
+        &self.db_schema
+    }
+
+    /// Database URL that resolves unqualified names in the test's schema, for
+    /// asserting on rows directly.
+    #[must_use]
+    pub fn database_url(&self) -> &str {
+        &self.server_database_url
+    }
+
+    /// A client for the GraphQL endpoint.
+    #[must_use]
+    pub fn client(&self) -> TestClient {
+        TestClient {
+            http:     reqwest::Client::new(),
+            endpoint: self.graphql_url.clone(),
+            token:    None,
+        }
+    }
+
+    /// Stop the server and drop the database schema (unless kept).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error the server stopped with, or `ServerError::Database` if
+    /// the schema cannot be dropped.
+    pub async fn shutdown(mut self) -> Result<()> {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        let served = match self.task.take() {
+            Some(task) => task.await.map_err(|e| ServerError::IoError(std::io::Error::other(e)))?,
+            None => Ok(()),
+        };
+        if !self.keep_schema {
+            let mut conn = PgConnection::connect(&self.database_url)
+                .await
+                .map_err(|e| ServerError::Database(format!("failed to connect: {e}")))?;
+            execute(&mut conn, &drop_schema_sql(&self.db_schema)).await?;
+        }
+        // Nothing left for Drop to clean up.
+        self.keep_schema = true;
+        served
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+        if self.keep_schema {
+            return;
+        }
+        // Drop cannot await, and may run inside the test's runtime: clean up on a
+        // thread with a runtime of its own and wait for it.
+        let database_url = self.database_url.clone();
+        let sql = drop_schema_sql(&self.db_schema);
+        let cleanup = std::thread::spawn(move || {
+            let Ok(runtime) = tokio::runtime::Builder::new_current_thread().enable_all().build()
+            else {
+                return;
+            };
+            runtime.block_on(async {
+                if let Ok(mut conn) = PgConnection::connect(&database_url).await {
+                    let _ = execute(&mut conn, &sql).await;
+                }
+            });
+        });
+        let _ = cleanup.join();
+    }
+}
+
+/// A GraphQL client bound to a [`TestServer`].
+#[derive(Clone)]
+pub struct TestClient {
+    http:     reqwest::Client,
+    endpoint: String,
+    token:    Option<String>,
+}
+
+impl TestClient {
+    /// Send `token` as a bearer token with every request.
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Execute an operation without variables.
+    ///
+    /// # Errors
+    ///
+    /// See [`query_with_variables`](Self::query_with_variables).
+    pub async fn query(&self, query: &str) -> Result<GraphQLResponse> {
+        self.query_with_variables(query, Value::Null).await
+    }
+
+    /// Execute an operation. GraphQL errors are part of the response, not an
+    /// `Err`; check them with [`GraphQLResponse::errors`] or let
+    /// [`GraphQLResponse::data_at`] fail on them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::IoError` if the request fails or the body is not a
+    /// GraphQL response.
+    pub async fn query_with_variables(
+        &self,
+        query: &str,
+        variables: Value,
+    ) -> Result<GraphQLResponse> {
+        let mut body = json!({ "query": query });
+        if !variables.is_null() {
+            body["variables"] = variables;
+        }
+        let mut request = self.http.post(&self.endpoint).json(&body);
+        if let Some(ref token) = self.token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().await.map_err(|e| {
+            ServerError::IoError(std::io::Error::other(format!(
+                "request to {} failed: {e}",
+                self.endpoint
+            )))
+        })?;
+        let status = response.status().as_u16();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| ServerError::IoError(std::io::Error::other(e)))?;
+        GraphQLResponse::from_body(status, &text)
+    }
+}
+
+/// One entry of a response's `errors` array.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GraphQLError {
+    /// Error message.
+    pub message:    String,
+    /// Response path of the failing field, if any.
+    #[serde(default)]
+    pub path:       Vec<Value>,
+    /// Error extensions (`code` and friends), if any.
+    #[serde(default)]
+    pub extensions: Option<Value>,
+}
+
+/// A decoded GraphQL response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphQLResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// The `data` member (`null` when absent).
+    pub data:   Value,
+    /// The `errors` member (empty when absent).
+    pub errors: Vec<GraphQLError>,
+}
+
+impl GraphQLResponse {
+    /// Decode a response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::IoError` if the body is not a JSON object or its
+    /// `errors` are malformed.
+    pub fn from_body(status: u16, body: &str) -> Result<Self> {
+        let invalid = |reason: String| {
+            let snippet: String = body.chars().take(200).collect();
+            ServerError::IoError(std::io::Error::other(format!(
+                "HTTP {status}: {reason}: {snippet}"
+            )))
+        };
+        let mut value: Value =
+            serde_json::from_str(body).map_err(|e| invalid(format!("not JSON ({e})")))?;
+        let Some(object) = value.as_object_mut() else {
+            return Err(invalid("not a JSON object".to_string()));
+        };
+        let data = object.remove("data").unwrap_or(Value::Null);
+        let errors = match object.remove("errors") {
+            Some(errors) => serde_json::from_value(errors)
+                .map_err(|e| invalid(format!("malformed errors ({e})")))?,
+            None => Vec::new(),
+        };
+        Ok(Self {
+            status,
+            data,
+            errors,
+        })
+    }
+
+    /// Whether the response carries no errors.
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Deserialize the value at a dotted `path` under `data` (`users`,
+    /// `user.posts`), failing if the response has errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Validation` listing the GraphQL errors, or when the
+    /// path is missing or its value does not deserialize into `T`.
+    pub fn data_at<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        if !self.errors.is_empty() {
+            let messages: Vec<&str> = self.errors.iter().map(|e| e.message.as_str()).collect();
+            return Err(ServerError::Validation(format!(
+                "response has errors: {}",
+                messages.join("; ")
+            )));
+        }
+        let mut value = &self.data;
+        for segment in path.split('.').filter(|s| !s.is_empty()) {
+            value = value
+                .get(segment)
+                .ok_or_else(|| ServerError::Validation(format!("no `{path}` in data")))?;
+        }
+        T::deserialize(value)
+            .map_err(|e| ServerError::Validation(format!("`{path}` does not deserialize: {e}")))
+    }
+}
+
+/// Split fixtures into per-table rows, in load order.
+///
+/// Two shapes are accepted:
+///
+/// - an array of `{ "table": "tb_user", "rows": [ { "column": value, … } ] }`, loaded in array
+///   order — use it when tables reference each other;
+/// - an object mapping table names to row arrays, loaded in key order.
+///
+/// # Errors
+///
+/// Returns `ServerError::ConfigError` for any other shape.
+pub fn parse_fixtures(fixtures: &Value) -> Result<Vec<FixtureTable>> {
+    let invalid = |what: &str| ServerError::ConfigError(format!("invalid fixtures: {what}"));
+    let rows_of = |table: &str, rows: &Value| {
+        let Some(rows) = rows.as_array() else {
+            return Err(invalid(&format!("rows for `{table}` must be an array")));
+        };
+        rows.iter()
+            .map(|row| {
+                row.as_object()
+                    .cloned()
+                    .ok_or_else(|| invalid(&format!("rows for `{table}` must be objects")))
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+    match fixtures {
+        Value::Array(entries) => entries
+            .iter()
+            .map(|entry| {
+                let table = entry
+                    .get("table")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid("each entry needs a `table` string"))?;
+                let rows = entry.get("rows").unwrap_or(&Value::Null);
+                Ok(FixtureTable {
+                    table: table.to_string(),
+                    rows:  rows_of(table, rows)?,
+                })
+            })
+            .collect(),
+        Value::Object(tables) => tables
+            .iter()
+            .map(|(table, rows)| {
+                Ok(FixtureTable {
+                    table: table.clone(),
+                    rows:  rows_of(table, rows)?,
+                })
+            })
+            .collect(),
+        _ => Err(invalid("expected an array of {table, rows} or an object of tables")),
+    }
+}
+
+/// The columns named by any row, in first-seen order.
+#[must_use]
+pub fn fixture_columns(table: &FixtureTable) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for row in &table.rows {
+        for column in row.keys() {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+            }
+        }
+    }
+    columns
+}
+
+/// `INSERT` for a fixture table: the rows travel as one JSONB parameter expanded
+/// with `jsonb_populate_recordset`, so PostgreSQL casts each value to its
+/// column type and columns no row names keep their defaults.
+#[must_use]
+pub fn fixture_insert_sql(table: &str, columns: &[String]) -> String {
+    let table = quote_postgres_identifier(table);
+    let columns = columns
+        .iter()
+        .map(|c| format!("\"{}\"", c.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "INSERT INTO {table} ({columns}) SELECT {columns} \
+         FROM jsonb_populate_recordset(NULL::{table}, $1)"
+    )
+}
+
+/// `database_url` with `search_path` set to `db_schema, public` for every
+/// connection, through the libpq `options` parameter.
+///
+/// # Errors
+///
+/// Returns `ServerError::ConfigError` if `database_url` is not a URL.
+pub fn with_search_path(database_url: &str, db_schema: &str) -> Result<String> {
+    let mut url = url::Url::parse(database_url).map_err(|e| {
+        ServerError::ConfigError(format!("TestServer needs a postgres:// database URL: {e}"))
+    })?;
+    // `-cname=value` (no space): `+` would not decode to a space.
+    url.query_pairs_mut()
+        .append_pair("options", &format!("-csearch_path={db_schema},public"));
+    Ok(url.to_string())
+}
+
+/// A fresh schema name: [`SCHEMA_PREFIX`] plus 16 random hex digits.
+fn new_schema_name() -> String {
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    format!("{SCHEMA_PREFIX}{}", &id[..16])
+}
+
+fn drop_schema_sql(db_schema: &str) -> String {
+    format!("DROP SCHEMA IF EXISTS {db_schema} CASCADE")
+}
+
+fn load_compiled_schema(path: &Path) -> Result<CompiledSchema> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| ServerError::ConfigError(format!("failed to read {}: {e}", path.display())))?;
+    CompiledSchema::from_json(&text, false)
+        .map_err(|e| ServerError::ConfigError(format!("failed to parse {}: {e}", path.display())))
+}
+
+fn read_json(path: &Path) -> Result<Value> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| ServerError::ConfigError(format!("failed to read {}: {e}", path.display())))?;
+    serde_json::from_str(&text)
+        .map_err(|e| ServerError::ConfigError(format!("{} is not valid JSON: {e}", path.display())))
+}
+
+async fn execute(conn: &mut PgConnection, sql: &str) -> Result<()> {
+    sqlx::raw_sql(sql)
+        .execute(conn)
+        .await
+        .map(|_| ())
+        .map_err(|e| ServerError::Database(e.to_string()))
+}
+
+async fn apply_sql(conn: &mut PgConnection, source: &SqlSource) -> Result<()> {
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|e| {
+            ServerError::ConfigError(format!("failed to read {}: {e}", path.display()))
+        })
+    };
+    match source {
+        SqlSource::Inline(sql) => execute(conn, sql).await,
+        SqlSource::File(path) => {
+            let sql = read(path)?;
+            execute(conn, &sql)
+                .await
+                .map_err(|e| ServerError::Database(format!("{}: {e}", path.display())))
+        },
+        SqlSource::Dir(dir) => {
+            let entries = std::fs::read_dir(dir).map_err(|e| {
+                ServerError::ConfigError(format!("failed to read {}: {e}", dir.display()))
+            })?;
+            let mut files: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.is_file() && path.extension().is_some_and(|e| e == "sql"))
+                .collect();
+            files.sort();
+            for path in files {
+                let sql = read(&path)?;
+                execute(conn, &sql)
+                    .await
+                    .map_err(|e| ServerError::Database(format!("{}: {e}", path.display())))?;
+            }
+            Ok(())
+        },
+    }
+}
+
+async fn insert_fixture(conn: &mut PgConnection, table: &FixtureTable) -> Result<()> {
+    if table.rows.is_empty() {
+        return Ok(());
+    }
+    let sql = fixture_insert_sql(&table.table, &fixture_columns(table));
+    let rows = Value::Array(table.rows.iter().cloned().map(Value::Object).collect());
+    sqlx::query(&sql).bind(rows).execute(conn).await.map_err(|e| {
+        ServerError::Database(format!("failed to load fixtures into {}: {e}", table.table))
+    })?;
+    Ok(())
+}
+
+/// Build the server, serve it on an ephemeral port and wait until it answers
+/// its health endpoint. Returns the base URL, the GraphQL URL, the shutdown
+/// trigger and the serving task.
+async fn start_server(
+    config: ServerConfig,
+    schema: CompiledSchema,
+    database_url: &str,
+) -> Result<(String, String, oneshot::Sender<()>, JoinHandle<Result<()>>)> {
+    let adapter = PostgresAdapter::new(database_url).await?;
+    let graphql_path = config.graphql_path.clone();
+    let health_path = config.health_path.clone();
+    let server = Server::new(config, schema, Arc::new(adapter), None).await?;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    let (shutdown, stopped) = oneshot::channel::<()>();
+    let task = tokio::spawn(server.serve_on_listener(listener, async {
+        let _ = stopped.await;
+    }));
+
+    let health = format!("{url}{health_path}");
+    let http = reqwest::Client::new();
+    let deadline = tokio::time::Instant::now() + READY_TIMEOUT;
+    while http.get(&health).send().await.is_err() {
+        if task.is_finished() {
+            return match task.await {
+                Ok(Err(e)) => Err(e),
+                _ => Err(ServerError::BindError("test server stopped during startup".into())),
+            };
+        }
+        if tokio::time::Instant::now() >= deadline {
+            task.abort();
+            return Err(ServerError::BindError(format!(
+                "test server did not answer {health} within {READY_TIMEOUT:?}"
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let graphql_url = format!("{url}{graphql_path}");
+    Ok((url, graphql_url, shutdown, task))
+}
+
+#[cfg(test)]
+#[path = "test_server_tests.rs"]
+mod test_server_tests;
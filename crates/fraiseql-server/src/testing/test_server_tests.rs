@@ -0,0 +1,83 @@
+//! Unit tests for the embedded test server's database-free parts: fixtures,
+//! the search-path URL and response decoding.
+
+#![allow(clippy::unwrap_used)] // Reason: test code
+
+use serde_json::json;
+
+use super::{
+    GraphQLResponse, SCHEMA_PREFIX, fixture_columns, fixture_insert_sql, new_schema_name,
+    parse_fixtures, with_search_path,
+};
+
+#[test]
+fn fixtures_keep_array_order() {
+    let tables = parse_fixtures(&json!([
+        { "table": "tb_user", "rows": [{ "pk_user": 1, "data": { "name": "Ada" } }] },
+        { "table": "tb_post", "rows": [{ "fk_user": 1 }, { "fk_user": 1, "title": "x" }] },
+    ]))
+    .unwrap();
+    let names: Vec<&str> = tables.iter().map(|t| t.table.as_str()).collect();
+    assert_eq!(names, vec!["tb_user", "tb_post"]);
+    assert_eq!(fixture_columns(&tables[1]), vec!["fk_user", "title"]);
+
+    let by_key = parse_fixtures(&json!({ "tb_tag": [{ "name": "a" }] })).unwrap();
+    assert_eq!(by_key[0].table, "tb_tag");
+    assert_eq!(by_key[0].rows.len(), 1);
+}
+
+#[test]
+fn malformed_fixtures_are_rejected() {
+    assert!(parse_fixtures(&json!("tb_user")).is_err());
+    assert!(parse_fixtures(&json!([{ "rows": [] }])).is_err());
+    assert!(parse_fixtures(&json!({ "tb_user": { "name": "a" } })).is_err());
+    assert!(parse_fixtures(&json!({ "tb_user": [1, 2] })).is_err());
+}
+
+#[test]
+fn fixture_inserts_name_only_the_given_columns() {
+    assert_eq!(
+        fixture_insert_sql("app.tb_user", &["id".to_string(), "data".to_string()]),
+        "INSERT INTO \"app\".\"tb_user\" (\"id\", \"data\") SELECT \"id\", \"data\" \
+         FROM jsonb_populate_recordset(NULL::\"app\".\"tb_user\", $1)"
+    );
+}
+
+#[test]
+fn server_connections_resolve_names_in_the_test_schema() {
+    let url =
+        with_search_path("postgres://u:p@localhost:5432/app?sslmode=disable", "fraiseql_test_ab")
+            .unwrap();
+    assert_eq!(
+        url,
+        "postgres://u:p@localhost:5432/app?sslmode=disable&options=-csearch_path%3Dfraiseql_test_ab%2Cpublic"
+    );
+    assert!(with_search_path("host=localhost dbname=app", "s").is_err());
+
+    let name = new_schema_name();
+    assert!(name.starts_with(SCHEMA_PREFIX));
+    assert_eq!(name.len(), SCHEMA_PREFIX.len() + 16);
+    assert_ne!(name, new_schema_name());
+}
+
+#[test]
+fn responses_decode_data_and_errors() {
+    let ok = GraphQLResponse::from_body(200, r#"{"data":{"user":{"posts":[{"id":1}]}}}"#).unwrap();
+    assert!(ok.is_ok());
+    let ids: Vec<serde_json::Value> = ok.data_at("user.posts").unwrap();
+    assert_eq!(ids, vec![json!({ "id": 1 })]);
+    assert!(ok.data_at::<String>("user.name").is_err());
+
+    let failed = GraphQLResponse::from_body(
+        400,
+        r#"{"errors":[{"message":"denied","path":["user"],"extensions":{"code":"FORBIDDEN"}}]}"#,
+    )
+    .unwrap();
+    assert!(!failed.is_ok());
+    assert_eq!(failed.errors[0].message, "denied");
+    assert!(failed.data.is_null());
+    let err = failed.data_at::<serde_json::Value>("user").unwrap_err().to_string();
+    assert!(err.contains("denied"), "{err}");
+
+    assert!(GraphQLResponse::from_body(502, "<html>bad gateway</html>").is_err());
+}
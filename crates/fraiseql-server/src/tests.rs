@@ -72,6 +72,19 @@ mod api_key_tests {
         assert!(matches!(auth.authenticate(&headers).await, ApiKeyResult::Authenticated(_)));
     }
 
+    #[test]
+    fn verified_key_only_returns_valid_keys() {
+        let config = test_config("my-secret-key");
+        let auth = ApiKeyAuthenticator::from_config(&config).unwrap();
+
+        let mut headers = axum::http::HeaderMap::new();
+        assert_eq!(auth.verified_key(&headers), None);
+        headers.insert("x-api-key", "wrong-key".parse().unwrap());
+        assert_eq!(auth.verified_key(&headers), None);
+        headers.insert("x-api-key", "ApiKey my-secret-key".parse().unwrap());
+        assert_eq!(auth.verified_key(&headers), Some("my-secret-key"));
+    }
+
     #[test]
     fn disabled_config_returns_none() {
         let mut config = test_config("key");
@@ -244,6 +257,19 @@ mod cli_tests {
         assert!(config.metrics_enabled);
     }
 
+    #[test]
+    fn apply_overrides_schema_public_key() {
+        let args = ServerArgs {
+            schema_public_key: Some("not a key".into()),
+            ..Default::default()
+        };
+        let mut config = ServerConfig::default();
+        args.apply_to_config(&mut config);
+        assert_eq!(config.schema_public_key.as_deref(), Some("not a key"));
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("schema_public_key"), "{err}");
+    }
+
     #[test]
     fn apply_rate_limit_creates_config_when_absent() {
         let args = ServerArgs {
@@ -275,6 +301,7 @@ mod cli_tests {
             trust_proxy_headers:   true,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         });
         args.apply_to_config(&mut config);
         let rl = config.rate_limiting.unwrap();
@@ -284,6 +311,20 @@ mod cli_tests {
         assert!(rl.trust_proxy_headers);
     }
 
+    #[test]
+    fn apply_rate_limit_redis_url_override() {
+        let args = ServerArgs {
+            rate_limit_redis_url: Some("redis://cache:6379".into()),
+            ..Default::default()
+        };
+        let mut config = ServerConfig::default();
+        config.rate_limiting = None;
+        args.apply_to_config(&mut config);
+        let rl = config.rate_limiting.unwrap();
+        assert_eq!(rl.redis_url.as_deref(), Some("redis://cache:6379"));
+        assert_eq!(rl.rps_per_ip, 100);
+    }
+
     #[test]
     fn apply_introspection_overrides() {
         let args = ServerArgs {
@@ -513,6 +554,7 @@ mod error_tests {
             request_id:       Some("req-123".to_string()),
             retry_after_secs: None,
             detail:           None,
+            limit:            None,
         };
 
         let error = GraphQLError::validation("Invalid").with_extensions(extensions);
@@ -521,6 +563,17 @@ mod error_tests {
         assert!(json.contains("req-123"));
     }
 
+    #[test]
+    fn test_limit_exceeded_reports_violated_limit() {
+        let error = GraphQLError::limit_exceeded("too many root fields", "max_root_fields", 5, 9);
+        assert_eq!(error.code, ErrorCode::ValidationError);
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(
+            json["extensions"]["limit"],
+            serde_json::json!({ "name": "max_root_fields", "max": 5, "actual": 9 })
+        );
+    }
+
     #[test]
     fn test_all_error_codes_have_expected_status() {
         use axum::http::StatusCode;
@@ -664,6 +717,128 @@ mod error_tests {
             "GraphQL parse errors must return HTTP 200 per GraphQL-over-HTTP spec"
         );
     }
+
+    #[test]
+    fn test_every_error_carries_extension_contract() {
+        let json = serde_json::to_value(GraphQLError::timeout("Query")).unwrap();
+        assert_eq!(json["code"], "TIMEOUT");
+        assert_eq!(json["extensions"]["code"], "TIMEOUT");
+        assert_eq!(json["extensions"]["docs_url"], "https://docs.fraiseql.dev/errors#TIMEOUT");
+        assert_eq!(json["extensions"]["retryable"], true);
+
+        let json = serde_json::to_value(GraphQLError::validation("bad")).unwrap();
+        assert_eq!(json["extensions"]["code"], "VALIDATION_ERROR");
+        assert_eq!(json["extensions"]["retryable"], false);
+        assert!(json["extensions"].get("request_id").is_none());
+    }
+
+    #[test]
+    fn test_extension_contract_keeps_optional_extensions() {
+        let error = GraphQLError::circuit_breaker_open("User", 30);
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["extensions"]["code"], "CIRCUIT_BREAKER_OPEN");
+        assert_eq!(json["extensions"]["category"], "CIRCUIT_BREAKER");
+        assert_eq!(json["extensions"]["retry_after_secs"], 30);
+        assert_eq!(json["extensions"]["retryable"], true);
+    }
+
+    #[test]
+    fn test_error_code_as_str_matches_serialized_form() {
+        let codes = [
+            ErrorCode::ValidationError,
+            ErrorCode::ParseError,
+            ErrorCode::RequestError,
+            ErrorCode::Unauthenticated,
+            ErrorCode::Forbidden,
+            ErrorCode::InternalServerError,
+            ErrorCode::DatabaseError,
+            ErrorCode::BadUserInput,
+            ErrorCode::ConstraintViolation,
+            ErrorCode::Timeout,
+            ErrorCode::RateLimitExceeded,
+            ErrorCode::NotFound,
+            ErrorCode::Conflict,
+            ErrorCode::CircuitBreakerOpen,
+            ErrorCode::ServiceUnavailable,
+            ErrorCode::PersistedQueryNotFound,
+            ErrorCode::PersistedQueryMismatch,
+            ErrorCode::ForbiddenQuery,
+            ErrorCode::DocumentNotFound,
+            ErrorCode::MethodNotAllowed,
+            ErrorCode::IntrospectionDisabled,
+            ErrorCode::Cancelled,
+            ErrorCode::UnsupportedOperation,
+        ];
+        for code in codes {
+            assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+            assert!(code.docs_url().ends_with(&format!("#{}", code.as_str())));
+        }
+    }
+
+    #[test]
+    fn test_from_fraiseql_error_marks_transient_failures_retryable() {
+        use fraiseql_core::error::FraiseQLError;
+        let pool = GraphQLError::from_fraiseql_error(&FraiseQLError::ConnectionPool {
+            message: "pool exhausted".into(),
+        });
+        assert_eq!(pool.code, ErrorCode::DatabaseError);
+        assert!(pool.is_retryable());
+
+        let serialization_failure = GraphQLError::from_fraiseql_error(&FraiseQLError::Database {
+            message:   "could not serialize access".into(),
+            sql_state: Some("40001".into()),
+        });
+        assert!(serialization_failure.is_retryable());
+
+        let syntax = GraphQLError::from_fraiseql_error(&FraiseQLError::Database {
+            message:   "syntax error".into(),
+            sql_state: Some("42601".into()),
+        });
+        assert!(!syntax.is_retryable());
+    }
+
+    #[test]
+    fn test_from_fraiseql_error_maps_remaining_variants_to_stable_codes() {
+        use axum::http::StatusCode;
+        use fraiseql_core::error::FraiseQLError;
+
+        let cancelled = GraphQLError::from_fraiseql_error(&FraiseQLError::cancelled("q1", "gone"));
+        assert_eq!(cancelled.code, ErrorCode::Cancelled);
+        assert!(cancelled.is_retryable());
+
+        let unsupported = GraphQLError::from_fraiseql_error(&FraiseQLError::Unsupported {
+            message: "not on SQLite".into(),
+        });
+        assert_eq!(unsupported.code, ErrorCode::UnsupportedOperation);
+        assert_eq!(unsupported.code.status_code(), StatusCode::NOT_IMPLEMENTED);
+
+        let unavailable = GraphQLError::from_fraiseql_error(&FraiseQLError::ServiceUnavailable {
+            message:     "tenant suspended".into(),
+            retry_after: Some(120),
+        });
+        assert_eq!(unavailable.code, ErrorCode::ServiceUnavailable);
+        assert_eq!(unavailable.extensions.unwrap().retry_after_secs, Some(120));
+    }
+
+    #[test]
+    fn test_error_response_with_request_id_fills_missing_ids_only() {
+        use axum::response::IntoResponse;
+        let response = ErrorResponse::new(vec![
+            GraphQLError::validation("first"),
+            GraphQLError::validation("second").with_request_id("upstream-id"),
+        ])
+        .with_request_id("req-1");
+
+        let ids: Vec<_> = response
+            .errors
+            .iter()
+            .map(|e| e.extensions.as_ref().unwrap().request_id.clone().unwrap())
+            .collect();
+        assert_eq!(ids, ["req-1", "upstream-id"]);
+
+        let http = response.into_response();
+        assert_eq!(http.headers()["x-request-id"], "req-1");
+    }
 }
 
 // ── extractors_tests ──────────────────────────────────────────────────────────
@@ -0,0 +1,473 @@
+//! Per-field selection counters.
+//!
+//! Records which schema coordinates (`Type.field`) each client selects so that
+//! dead fields can be found before they are removed. Counters are keyed by
+//! `(client_id, coordinate)` and stored as lock-free [`AtomicU64`] values inside
+//! a [`DashMap`]; a coordinate is counted at most once per request.
+//!
+//! # Memory growth
+//!
+//! Bounded by `max_clients × #schema_coordinates`. Client ids beyond
+//! `max_clients` are folded into [`OVERFLOW_CLIENT`], and coordinates are only
+//! ever taken from the compiled schema — selections that do not resolve against
+//! it are ignored.
+//!
+//! # Persistence
+//!
+//! [`FieldUsageStore`] adds the counts accumulated since the previous flush to
+//! the `fraiseql_field_usage` table, which `fraiseql analyze --usage` reads.
+//! The in-memory totals served by `GET /api/v1/admin/field-usage` cover the
+//! current process only.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use axum::http::HeaderMap;
+use dashmap::DashMap;
+use fraiseql_core::schema::{CompiledSchema, FieldDefinition, FieldType};
+use graphql_parser::query::{
+    Definition, Document, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    TypeCondition,
+};
+use serde::Serialize;
+
+/// PostgreSQL table holding persisted field-usage counters.
+pub const FIELD_USAGE_TABLE: &str = "fraiseql_field_usage";
+
+/// Request headers consulted, in order, for the calling client's id.
+pub const CLIENT_ID_HEADERS: [&str; 2] = ["x-fraiseql-client", "apollographql-client-name"];
+
+/// Client id recorded when a request carries none of [`CLIENT_ID_HEADERS`].
+pub const ANONYMOUS_CLIENT: &str = "anonymous";
+
+/// Client id recorded for clients beyond the configured `max_clients` cap.
+pub const OVERFLOW_CLIENT: &str = "other";
+
+/// Longest client id kept verbatim; longer values are truncated.
+const MAX_CLIENT_ID_LEN: usize = 128;
+
+/// Fragment-spread hops followed before a selection walk gives up.
+const MAX_FRAGMENT_HOPS: usize = 32;
+
+// ── Client identification ──────────────────────────────────────────────────
+
+/// Resolve the calling client's id from the request headers.
+///
+/// Returns the first non-empty value among [`CLIENT_ID_HEADERS`], truncated to
+/// 128 characters, or [`ANONYMOUS_CLIENT`] when none is present.
+#[must_use]
+pub fn client_id(headers: &HeaderMap) -> String {
+    CLIENT_ID_HEADERS
+        .iter()
+        .filter_map(|name| headers.get(*name)?.to_str().ok())
+        .map(str::trim)
+        .find(|value| !value.is_empty())
+        .map_or_else(
+            || ANONYMOUS_CLIENT.to_string(),
+            |value| value.chars().take(MAX_CLIENT_ID_LEN).collect(),
+        )
+}
+
+// ── Coordinate extraction ──────────────────────────────────────────────────
+
+/// Collect the schema coordinates selected by every operation in `document`.
+///
+/// Root fields are reported as `Query.<name>` / `Mutation.<name>`; nested
+/// selections as `<Type>.<field>`, following each field's return type through
+/// the compiled schema. Introspection fields, unknown fields and selections
+/// below unknown types are skipped.
+#[must_use]
+pub fn selected_coordinates<'q>(
+    document: &'q Document<'q, String>,
+    schema: &CompiledSchema,
+) -> BTreeSet<String> {
+    let fragments: HashMap<&str, &FragmentDefinition<'q, String>> = document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Fragment(frag) => Some((frag.name.as_str(), frag)),
+            Definition::Operation(_) => None,
+        })
+        .collect();
+    let mut walker = CoordinateWalker {
+        schema,
+        fragments,
+        coordinates: BTreeSet::new(),
+        visiting: HashSet::new(),
+    };
+    for def in &document.definitions {
+        match def {
+            Definition::Operation(OperationDefinition::Query(q)) => {
+                walker.walk_root(RootKind::Query, &q.selection_set, MAX_FRAGMENT_HOPS);
+            },
+            Definition::Operation(OperationDefinition::SelectionSet(set)) => {
+                walker.walk_root(RootKind::Query, set, MAX_FRAGMENT_HOPS);
+            },
+            Definition::Operation(OperationDefinition::Mutation(m)) => {
+                walker.walk_root(RootKind::Mutation, &m.selection_set, MAX_FRAGMENT_HOPS);
+            },
+            Definition::Operation(OperationDefinition::Subscription(_))
+            | Definition::Fragment(_) => {},
+        }
+    }
+    walker.coordinates
+}
+
+#[derive(Clone, Copy)]
+enum RootKind {
+    Query,
+    Mutation,
+}
+
+struct CoordinateWalker<'a, 'q> {
+    schema:      &'a CompiledSchema,
+    fragments:   HashMap<&'q str, &'q FragmentDefinition<'q, String>>,
+    coordinates: BTreeSet<String>,
+    visiting:    HashSet<&'q str>,
+}
+
+impl<'a, 'q> CoordinateWalker<'a, 'q> {
+    fn walk_root(&mut self, kind: RootKind, set: &'q SelectionSet<'q, String>, budget: usize) {
+        for sel in &set.items {
+            match sel {
+                Selection::Field(field) => {
+                    let name = field.name.as_str();
+                    // Record the schema's canonical name: the lookups also accept
+                    // the camelCase spelling of a snake_case operation.
+                    let resolved = match kind {
+                        RootKind::Query => self
+                            .schema
+                            .find_query(name)
+                            .map(|q| ("Query", q.name.as_str(), q.return_type.as_str())),
+                        RootKind::Mutation => self
+                            .schema
+                            .find_mutation(name)
+                            .map(|m| ("Mutation", m.name.as_str(), m.return_type.as_str())),
+                    };
+                    if let Some((root, canonical, return_type)) = resolved {
+                        self.coordinates.insert(format!("{root}.{canonical}"));
+                        self.walk_type(return_type, &field.selection_set, budget);
+                    }
+                },
+                Selection::InlineFragment(inline) => {
+                    self.walk_root(kind, &inline.selection_set, budget);
+                },
+                Selection::FragmentSpread(spread) => {
+                    if let Some(frag) = self.enter_fragment(&spread.fragment_name, budget) {
+                        self.walk_root(kind, &frag.selection_set, budget - 1);
+                        self.visiting.remove(frag.name.as_str());
+                    }
+                },
+            }
+        }
+    }
+
+    fn walk_type(&mut self, type_name: &str, set: &'q SelectionSet<'q, String>, budget: usize) {
+        for sel in &set.items {
+            match sel {
+                Selection::Field(field) => {
+                    let name = field.name.as_str();
+                    if name.starts_with("__") {
+                        continue;
+                    }
+                    let Some(def) = self.find_field(type_name, name) else {
+                        continue;
+                    };
+                    self.coordinates.insert(format!("{type_name}.{name}"));
+                    if let Some(child) = named_type(&def.field_type) {
+                        self.walk_type(child, &field.selection_set, budget);
+                    }
+                },
+                Selection::InlineFragment(inline) => {
+                    let target = match &inline.type_condition {
+                        Some(TypeCondition::On(on)) => on.as_str(),
+                        None => type_name,
+                    };
+                    self.walk_type(target, &inline.selection_set, budget);
+                },
+                Selection::FragmentSpread(spread) => {
+                    if let Some(frag) = self.enter_fragment(&spread.fragment_name, budget) {
+                        let TypeCondition::On(target) = &frag.type_condition;
+                        self.walk_type(target, &frag.selection_set, budget - 1);
+                        self.visiting.remove(frag.name.as_str());
+                    }
+                },
+            }
+        }
+    }
+
+    /// Resolve a fragment spread, marking it as being visited.
+    ///
+    /// Returns `None` for unknown fragments, cycles and an exhausted hop budget;
+    /// the caller removes the fragment from `visiting` once it has been walked.
+    fn enter_fragment(
+        &mut self,
+        name: &'q str,
+        budget: usize,
+    ) -> Option<&'q FragmentDefinition<'q, String>> {
+        if budget == 0 || self.visiting.contains(name) {
+            return None;
+        }
+        let frag = self.fragments.get(name).copied()?;
+        self.visiting.insert(name);
+        Some(frag)
+    }
+
+    fn find_field(&self, type_name: &str, field_name: &str) -> Option<&'a FieldDefinition> {
+        self.schema.find_type(type_name).map_or_else(
+            || self.schema.find_interface(type_name)?.find_field(field_name),
+            |ty| ty.find_field_by_output_name(field_name),
+        )
+    }
+}
+
+/// The named (object, interface, union, enum) type behind a possibly-list type.
+fn named_type(field_type: &FieldType) -> Option<&str> {
+    let mut current = field_type;
+    while let Some(inner) = current.inner_type() {
+        current = inner;
+    }
+    current.type_name()
+}
+
+// ── FieldUsageEntry ────────────────────────────────────────────────────────
+
+/// Selection count for one schema coordinate and client.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldUsageEntry {
+    /// Client id (see [`client_id`]).
+    pub client_id:  String,
+    /// Schema coordinate, e.g. `"User.email"` or `"Query.users"`.
+    pub coordinate: String,
+    /// Number of requests that selected the coordinate.
+    pub count:      u64,
+}
+
+// ── FieldUsageAggregator ───────────────────────────────────────────────────
+
+#[derive(Debug, Default)]
+struct FieldCounter {
+    total:   AtomicU64,
+    flushed: AtomicU64,
+}
+
+/// Thread-safe, in-memory per-client field selection counters.
+///
+/// # Example
+///
+/// ```
+/// use fraiseql_server::usage::fields::FieldUsageAggregator;
+///
+/// let agg = FieldUsageAggregator::new(10);
+/// agg.record("web", ["Query.users".to_string(), "User.email".to_string()]);
+/// agg.record("web", ["Query.users".to_string()]);
+/// let entries = agg.snapshot(None);
+/// assert_eq!(entries[0].coordinate, "Query.users");
+/// assert_eq!(entries[0].count, 2);
+/// ```
+pub struct FieldUsageAggregator {
+    /// Key: `(client_id, coordinate)`.
+    counters:    DashMap<(String, String), FieldCounter>,
+    /// Distinct client ids admitted so far (at most `max_clients`).
+    clients:     DashMap<String, ()>,
+    max_clients: usize,
+}
+
+impl std::fmt::Debug for FieldUsageAggregator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldUsageAggregator")
+            .field("entry_count", &self.counters.len())
+            .field("client_count", &self.clients.len())
+            .field("max_clients", &self.max_clients)
+            .finish()
+    }
+}
+
+impl FieldUsageAggregator {
+    /// Create an empty aggregator tracking at most `max_clients` client ids.
+    #[must_use]
+    pub fn new(max_clients: usize) -> Self {
+        Self {
+            counters: DashMap::new(),
+            clients: DashMap::new(),
+            max_clients,
+        }
+    }
+
+    /// Record one request's selected coordinates for `client_id`.
+    pub fn record(&self, client_id: &str, coordinates: impl IntoIterator<Item = String>) {
+        let client = self.admit(client_id);
+        for coordinate in coordinates {
+            self.counters
+                .entry((client.clone(), coordinate))
+                .or_default()
+                .total
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Map `client_id` to itself when it is (or can become) a tracked client,
+    /// or to [`OVERFLOW_CLIENT`] once the cap is reached.
+    fn admit(&self, client_id: &str) -> String {
+        if self.clients.contains_key(client_id) {
+            return client_id.to_string();
+        }
+        if self.clients.len() < self.max_clients {
+            self.clients.insert(client_id.to_string(), ());
+            return client_id.to_string();
+        }
+        OVERFLOW_CLIENT.to_string()
+    }
+
+    /// Return current totals, optionally restricted to one client, sorted by
+    /// descending count then coordinate.
+    #[must_use]
+    pub fn snapshot(&self, client_id: Option<&str>) -> Vec<FieldUsageEntry> {
+        let mut entries: Vec<FieldUsageEntry> = self
+            .counters
+            .iter()
+            .filter(|entry| client_id.is_none_or(|c| entry.key().0 == c))
+            .map(|entry| FieldUsageEntry {
+                client_id:  entry.key().0.clone(),
+                coordinate: entry.key().1.clone(),
+                count:      entry.value().total.load(Ordering::Relaxed),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.coordinate.cmp(&b.coordinate))
+                .then_with(|| a.client_id.cmp(&b.client_id))
+        });
+        entries
+    }
+
+    /// Return the total number of distinct counter entries (for monitoring).
+    #[must_use]
+    pub fn entry_count(&self) -> usize {
+        self.counters.len()
+    }
+
+    /// Counts recorded since the last [`mark_flushed`](Self::mark_flushed),
+    /// skipping entries with nothing new.
+    #[must_use]
+    pub fn pending(&self) -> Vec<FieldUsageEntry> {
+        self.counters
+            .iter()
+            .filter_map(|entry| {
+                let counter = entry.value();
+                let delta = counter
+                    .total
+                    .load(Ordering::Relaxed)
+                    .saturating_sub(counter.flushed.load(Ordering::Relaxed));
+                (delta > 0).then(|| FieldUsageEntry {
+                    client_id:  entry.key().0.clone(),
+                    coordinate: entry.key().1.clone(),
+                    count:      delta,
+                })
+            })
+            .collect()
+    }
+
+    /// Acknowledge that `flushed` deltas (as returned by
+    /// [`pending`](Self::pending)) were persisted.
+    pub fn mark_flushed(&self, flushed: &[FieldUsageEntry]) {
+        for entry in flushed {
+            if let Some(counter) =
+                self.counters.get(&(entry.client_id.clone(), entry.coordinate.clone()))
+            {
+                counter.flushed.fetch_add(entry.count, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+// ── PostgreSQL store ───────────────────────────────────────────────────────
+
+/// PostgreSQL persistence for field-usage counters.
+///
+/// The table is created on [`FieldUsageStore::new`] if it does not exist:
+///
+/// ```sql
+/// CREATE TABLE fraiseql_field_usage (
+///     client_id     TEXT NOT NULL,
+///     coordinate    TEXT NOT NULL,
+///     count         BIGINT NOT NULL DEFAULT 0,
+///     first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+///     last_seen_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+///     PRIMARY KEY (client_id, coordinate)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FieldUsageStore {
+    pool: sqlx::PgPool,
+}
+
+impl FieldUsageStore {
+    /// Create a store, ensuring the table exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema migration fails.
+    pub async fn new(pool: sqlx::PgPool) -> Result<Self, String> {
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS fraiseql_field_usage (
+                client_id     TEXT NOT NULL,
+                coordinate    TEXT NOT NULL,
+                count         BIGINT NOT NULL DEFAULT 0,
+                first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+                last_seen_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+                PRIMARY KEY (client_id, coordinate)
+            )",
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("FieldUsageStore schema migration failed: {e}"))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Add the counts recorded since the previous flush to the table.
+    ///
+    /// Deltas are written in one transaction and only acknowledged on the
+    /// aggregator after it commits, so a failed flush is retried in full on the
+    /// next tick.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub async fn flush(&self, aggregator: &FieldUsageAggregator) -> Result<(), String> {
+        let pending = aggregator.pending();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("FieldUsageStore flush error: {e}"))?;
+        for entry in &pending {
+            sqlx::query(
+                "INSERT INTO fraiseql_field_usage (client_id, coordinate, count)
+                 VALUES ($1, $2, $3)
+                 ON CONFLICT (client_id, coordinate)
+                 DO UPDATE SET count = fraiseql_field_usage.count + EXCLUDED.count,
+                               last_seen_at = NOW()",
+            )
+            .bind(&entry.client_id)
+            .bind(&entry.coordinate)
+            .bind(entry.count.cast_signed())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("FieldUsageStore flush error: {e}"))?;
+        }
+        tx.commit().await.map_err(|e| format!("FieldUsageStore flush error: {e}"))?;
+
+        aggregator.mark_flushed(&pending);
+        Ok(())
+    }
+}
@@ -19,6 +19,10 @@
 //!   GET /api/v1/admin/usage?tenant_id=…&period=…
 //! ```
 //!
+//! [`fields`] keeps a separate, per-client count of the schema fields each
+//! request selects, exposed via `GET /api/v1/admin/field-usage` and persisted
+//! for `fraiseql analyze --usage` when `[field_usage]` is configured.
+//!
 //! # Limitations (v1)
 //!
 //! - **In-memory only**: counters reset to zero on process restart.
@@ -26,6 +30,7 @@
 
 pub mod aggregator;
 pub mod events;
+pub mod fields;
 pub mod layer;
 
 #[cfg(test)]
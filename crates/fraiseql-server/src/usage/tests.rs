@@ -375,3 +375,145 @@ mod layer_tests {
         assert!(Arc::ptr_eq(&aggregator, layer.aggregator()));
     }
 }
+
+mod field_usage_tests {
+    use axum::http::{HeaderMap, HeaderValue};
+    use fraiseql_core::{
+        graphql::parse_graphql_document,
+        schema::{
+            CompiledSchema, FieldDefinition, FieldType, MutationDefinition, QueryDefinition,
+            TypeDefinition,
+        },
+    };
+
+    use super::super::fields::*;
+
+    fn schema() -> CompiledSchema {
+        let mut schema = CompiledSchema::default();
+        schema.queries.push(QueryDefinition::new("users", "User").returning_list());
+        schema.mutations.push(MutationDefinition::new("createUser", "User"));
+        schema.types.push(
+            TypeDefinition::new("User", "v_user")
+                .with_field(FieldDefinition::new("id", FieldType::Id))
+                .with_field(FieldDefinition::new("email", FieldType::String))
+                .with_field(FieldDefinition::new(
+                    "posts",
+                    FieldType::List(Box::new(FieldType::Object("Post".to_string()))),
+                )),
+        );
+        schema.types.push(
+            TypeDefinition::new("Post", "v_post")
+                .with_field(FieldDefinition::new("title", FieldType::String)),
+        );
+        schema
+    }
+
+    fn coordinates(query: &str) -> Vec<String> {
+        let doc = parse_graphql_document(query).unwrap();
+        selected_coordinates(&doc, &schema()).into_iter().collect()
+    }
+
+    // ── coordinate extraction ──────────────────────────────────────────────
+
+    #[test]
+    fn test_nested_selections_follow_return_types() {
+        assert_eq!(
+            coordinates("{ users { id posts { title } } }"),
+            ["Post.title", "Query.users", "User.id", "User.posts"]
+        );
+    }
+
+    #[test]
+    fn test_fragments_and_aliases_resolve_to_schema_fields() {
+        let query = "query { a: users { ...U } b: users { ... on User { email } } }
+                     fragment U on User { id }";
+        assert_eq!(coordinates(query), ["Query.users", "User.email", "User.id"]);
+    }
+
+    #[test]
+    fn test_mutation_root_uses_mutation_prefix() {
+        assert_eq!(
+            coordinates("mutation { createUser { id } }"),
+            ["Mutation.createUser", "User.id"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_and_introspection_fields_are_skipped() {
+        assert_eq!(
+            coordinates(
+                "{ users { __typename nope } unknownRoot { x } __schema { types { name } } }"
+            ),
+            ["Query.users"]
+        );
+    }
+
+    #[test]
+    fn test_cyclic_fragments_terminate() {
+        let query = "{ users { ...A } } fragment A on User { id ...B } fragment B on User { ...A }";
+        assert_eq!(coordinates(query), ["Query.users", "User.id"]);
+    }
+
+    // ── client id ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_client_id_prefers_fraiseql_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert("apollographql-client-name", HeaderValue::from_static("apollo"));
+        headers.insert("x-fraiseql-client", HeaderValue::from_static("web"));
+        assert_eq!(client_id(&headers), "web");
+    }
+
+    #[test]
+    fn test_client_id_defaults_to_anonymous() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-fraiseql-client", HeaderValue::from_static("  "));
+        assert_eq!(client_id(&headers), ANONYMOUS_CLIENT);
+    }
+
+    // ── aggregator ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_record_counts_per_client() {
+        let agg = FieldUsageAggregator::new(10);
+        agg.record("web", ["User.id".to_string()]);
+        agg.record("web", ["User.id".to_string()]);
+        agg.record("ios", ["User.id".to_string()]);
+
+        let web = agg.snapshot(Some("web"));
+        assert_eq!(web.len(), 1);
+        assert_eq!(web[0].count, 2);
+        assert_eq!(agg.snapshot(None).len(), 2);
+    }
+
+    #[test]
+    fn test_clients_beyond_cap_fold_into_overflow() {
+        let agg = FieldUsageAggregator::new(1);
+        agg.record("web", ["User.id".to_string()]);
+        agg.record("ios", ["User.id".to_string()]);
+        agg.record("android", ["User.id".to_string()]);
+
+        let overflow = agg.snapshot(Some(OVERFLOW_CLIENT));
+        assert_eq!(overflow[0].count, 2);
+        assert!(agg.snapshot(Some("ios")).is_empty());
+    }
+
+    #[test]
+    fn test_pending_reports_only_unflushed_deltas() {
+        let agg = FieldUsageAggregator::new(10);
+        agg.record("web", ["User.id".to_string(), "User.email".to_string()]);
+
+        let pending = agg.pending();
+        assert_eq!(pending.len(), 2);
+        agg.mark_flushed(&pending);
+        assert!(agg.pending().is_empty());
+
+        agg.record("web", ["User.id".to_string()]);
+        let pending = agg.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].coordinate, "User.id");
+        assert_eq!(pending[0].count, 1);
+        // Totals keep counting across flushes.
+        assert_eq!(agg.snapshot(Some("web"))[0].count, 2);
+    }
+}
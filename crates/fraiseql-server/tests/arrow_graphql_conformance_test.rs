@@ -0,0 +1,334 @@
+//! Conformance tests: one GraphQL operation, the same rows over HTTP and as an
+//! Arrow Flight `GraphQLQuery` ticket.
+//!
+//! Both transports run the operation through the same core `Executor` — HTTP via
+//! `graphql_handler`, Flight via `ExecutorQueryAdapter` — so `where`, `orderBy`,
+//! `limit` and `offset` must select and order rows identically, and the Arrow
+//! columns must follow the selection set.
+//!
+//! **Execution engine:** in-memory `TableAdapter` (applies filters, sorting and paging)
+//! **Infrastructure:** none
+//! **Parallelism:** safe
+#![cfg(feature = "arrow")]
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+#![allow(missing_docs)] // Reason: test code
+
+use std::{cmp::Ordering, collections::HashMap, sync::Arc};
+
+use arrow::{
+    array::{AsArray, RecordBatch},
+    datatypes::{DataType, Int64Type},
+};
+use arrow_flight::{Ticket, flight_service_server::FlightService};
+use async_trait::async_trait;
+use axum::{Router, body::Body, routing::post};
+use fraiseql_arrow::{FraiseQLFlightService, ticket::FlightTicket};
+use fraiseql_core::{
+    db::{
+        DatabaseAdapter, DatabaseType, JsonbValue, OrderByClause, OrderDirection, PoolMetrics,
+        SupportsMutations, WhereClause, WhereOperator,
+    },
+    error::Result as FraiseQLResult,
+    runtime::Executor,
+    schema::{AutoParams, CompiledSchema, QueryDefinition, SqlProjectionHint},
+};
+use fraiseql_server::{
+    arrow::ExecutorQueryAdapter,
+    routes::graphql::{AppState, graphql_handler},
+};
+use futures::TryStreamExt;
+use http::{Request, StatusCode};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+const FLIGHT_SECRET: &str = "arrow-graphql-conformance-session-secret";
+
+/// `v_user` rows, applying `where`, `orderBy`, `limit` and `offset` the way the SQL
+/// adapters do. Projection hints are ignored: the executor projects full rows.
+#[derive(Debug, Clone)]
+struct TableAdapter {
+    rows: Arc<Vec<Value>>,
+}
+
+impl TableAdapter {
+    fn users() -> Self {
+        let rows = [
+            ("1", "Alice", 34, "a@test.com"),
+            ("2", "Bob", 27, "b@test.com"),
+            ("3", "Carol", 41, "c@test.com"),
+            ("4", "Dave", 34, "d@test.com"),
+            ("5", "Erin", 52, "e@test.com"),
+        ]
+        .into_iter()
+        .map(|(id, name, age, email)| json!({"id": id, "name": name, "age": age, "email": email}))
+        .collect();
+        Self {
+            rows: Arc::new(rows),
+        }
+    }
+
+    fn select(
+        &self,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> Vec<JsonbValue> {
+        let mut rows: Vec<&Value> = self
+            .rows
+            .iter()
+            .filter(|row| where_clause.is_none_or(|w| matches(row, w)))
+            .collect();
+        if let Some(clauses) = order_by {
+            rows.sort_by(|a, b| {
+                clauses.iter().fold(Ordering::Equal, |ordering, clause| {
+                    ordering.then_with(|| {
+                        let by_field = compare(&a[&clause.field], &b[&clause.field])
+                            .unwrap_or(Ordering::Equal);
+                        match clause.direction {
+                            OrderDirection::Desc => by_field.reverse(),
+                            _ => by_field,
+                        }
+                    })
+                })
+            });
+        }
+        rows.into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.map_or(usize::MAX, |l| l as usize))
+            .map(|row| JsonbValue::new(row.clone()))
+            .collect()
+    }
+}
+
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+fn matches(row: &Value, clause: &WhereClause) -> bool {
+    match clause {
+        WhereClause::And(clauses) => clauses.iter().all(|c| matches(row, c)),
+        WhereClause::Or(clauses) => clauses.iter().any(|c| matches(row, c)),
+        WhereClause::Not(inner) => !matches(row, inner),
+        WhereClause::Field {
+            path,
+            operator,
+            value,
+        } => {
+            let field = path.iter().try_fold(row, |v, key| v.get(key));
+            let ordering = field.and_then(|f| compare(f, value));
+            match operator {
+                WhereOperator::Eq => ordering == Some(Ordering::Equal),
+                WhereOperator::Neq => ordering != Some(Ordering::Equal),
+                WhereOperator::Gt => ordering == Some(Ordering::Greater),
+                WhereOperator::Gte => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+                WhereOperator::Lt => ordering == Some(Ordering::Less),
+                WhereOperator::Lte => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                other => panic!("TableAdapter does not evaluate {other:?}"),
+            }
+        },
+        other => panic!("TableAdapter does not evaluate {other:?}"),
+    }
+}
+
+// Reason: DatabaseAdapter is defined with #[async_trait]; all implementations must match
+// its transformed method signatures to satisfy the trait contract
+// async_trait: dyn-dispatch required; remove when RTN + Send is stable (RFC 3425)
+#[async_trait]
+impl DatabaseAdapter for TableAdapter {
+    async fn execute_where_query(
+        &self,
+        _view: &str,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> FraiseQLResult<Vec<JsonbValue>> {
+        Ok(self.select(where_clause, limit, offset, order_by))
+    }
+
+    async fn execute_with_projection(
+        &self,
+        _view: &str,
+        _projection: Option<&SqlProjectionHint>,
+        where_clause: Option<&WhereClause>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        order_by: Option<&[OrderByClause]>,
+    ) -> FraiseQLResult<Vec<JsonbValue>> {
+        Ok(self.select(where_clause, limit, offset, order_by))
+    }
+
+    fn database_type(&self) -> DatabaseType {
+        DatabaseType::PostgreSQL
+    }
+
+    async fn health_check(&self) -> FraiseQLResult<()> {
+        Ok(())
+    }
+
+    fn pool_metrics(&self) -> PoolMetrics {
+        PoolMetrics::default()
+    }
+
+    async fn execute_raw_query(&self, _sql: &str) -> FraiseQLResult<Vec<HashMap<String, Value>>> {
+        Ok(vec![])
+    }
+
+    async fn execute_parameterized_aggregate(
+        &self,
+        _sql: &str,
+        _params: &[Value],
+    ) -> FraiseQLResult<Vec<HashMap<String, Value>>> {
+        Ok(vec![])
+    }
+}
+
+impl SupportsMutations for TableAdapter {}
+
+fn executor() -> Arc<Executor<TableAdapter>> {
+    let mut users =
+        QueryDefinition::new("users", "User").returning_list().with_sql_source("v_user");
+    users.auto_params = AutoParams {
+        has_where: true,
+        has_order_by: true,
+        has_limit: true,
+        has_offset: true,
+        ..AutoParams::default()
+    };
+    let mut schema = CompiledSchema::new();
+    schema.queries.push(users);
+    Arc::new(Executor::new(schema, Arc::new(TableAdapter::users())))
+}
+
+/// The `data.users` rows of `query` over `POST /graphql`.
+async fn http_rows(executor: Arc<Executor<TableAdapter>>, query: &str) -> Vec<Value> {
+    let router = Router::new()
+        .route("/graphql", post(graphql_handler::<TableAdapter>))
+        .with_state(AppState::new(executor));
+    let response = router
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/graphql")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&json!({ "query": query })).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert!(body.get("errors").is_none(), "unexpected errors: {body}");
+    body["data"]["users"].as_array().unwrap().clone()
+}
+
+/// The batches of `query` as a Flight `GraphQLQuery` ticket.
+async fn flight_batches(executor: Arc<Executor<TableAdapter>>, query: &str) -> Vec<RecordBatch> {
+    let mut service = FraiseQLFlightService::new().with_session_secret(FLIGHT_SECRET);
+    service.set_executor(Arc::new(ExecutorQueryAdapter::new(executor)));
+
+    let now = chrono::Utc::now().timestamp();
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+        &json!({
+            "sub": "analyst",
+            "iat": now,
+            "exp": now + 300,
+            "scopes": ["read"],
+            "session_type": "flight",
+        }),
+        &jsonwebtoken::EncodingKey::from_secret(FLIGHT_SECRET.as_bytes()),
+    )
+    .unwrap();
+
+    let ticket = FlightTicket::GraphQLQuery {
+        query:     query.to_string(),
+        variables: None,
+    };
+    let mut request = tonic::Request::new(Ticket {
+        ticket: ticket.encode().unwrap().into(),
+    });
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+
+    let messages: Vec<_> =
+        service.do_get(request).await.unwrap().into_inner().try_collect().await.unwrap();
+    arrow_flight::utils::flight_data_to_batches(&messages).unwrap()
+}
+
+/// Arrow batches back as JSON rows, keys in column order.
+fn arrow_rows(batches: &[RecordBatch]) -> Vec<Value> {
+    let mut rows = Vec::new();
+    for batch in batches {
+        for row in 0..batch.num_rows() {
+            let mut object = serde_json::Map::new();
+            for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+                let value = match field.data_type() {
+                    DataType::Utf8 => json!(column.as_string::<i32>().value(row)),
+                    DataType::Int64 => json!(column.as_primitive::<Int64Type>().value(row)),
+                    other => panic!("unexpected column type {other}"),
+                };
+                object.insert(field.name().clone(), value);
+            }
+            rows.push(Value::Object(object));
+        }
+    }
+    rows
+}
+
+/// Run `query` over both transports and assert they return the same rows, in the
+/// same order, and that the Arrow columns are `columns`, the query's selection set.
+async fn assert_conformant(query: &str, columns: &[&str]) -> Vec<Value> {
+    let executor = executor();
+    let http = http_rows(Arc::clone(&executor), query).await;
+    let batches = flight_batches(executor, query).await;
+
+    assert_eq!(arrow_rows(&batches), http, "Arrow rows differ from HTTP for {query}");
+    for batch in &batches {
+        let schema = batch.schema();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, columns, "column order differs for {query}");
+    }
+    http
+}
+
+#[tokio::test]
+async fn order_by_limit_offset_match_http() {
+    let rows = assert_conformant(
+        "{ users(orderBy: { age: DESC }, limit: 3, offset: 1) { name id age } }",
+        &["name", "id", "age"],
+    )
+    .await;
+
+    let names: Vec<&str> = rows.iter().map(|r| r["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Carol", "Alice", "Dave"]);
+}
+
+#[tokio::test]
+async fn where_with_multi_key_order_matches_http() {
+    let rows = assert_conformant(
+        r#"{ users(where: { age: { lte: 41 } }, orderBy: [{ field: "age", direction: "ASC" }, { field: "name", direction: "DESC" }]) { id age } }"#,
+        &["id", "age"],
+    )
+    .await;
+
+    let ids: Vec<&str> = rows.iter().map(|r| r["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["2", "4", "1", "3"]);
+}
+
+#[tokio::test]
+async fn offset_past_the_end_is_an_empty_page_on_both() {
+    let rows = assert_conformant(
+        "{ users(orderBy: { age: ASC }, offset: 10) { id name } }",
+        &["id", "name"],
+    )
+    .await;
+    assert!(rows.is_empty());
+}
@@ -36,23 +36,30 @@ const MANIFEST: &[(&str, &str)] = &[
     ("playground_path", "GraphQL playground path"),
     ("playground_enabled", "playground toggle"),
     ("playground_tool", "playground UI selection (GraphiQL/…)"),
+    ("graphiql_enabled", "dedicated GraphiQL route toggle"),
+    ("graphiql_path", "dedicated GraphiQL route path"),
     ("metrics_json_path", "JSON metrics endpoint path"),
     // ── Feature toggles ──────────────────────────────────────────────────────
     ("apq_enabled", "automatic persisted queries"),
+    ("apq_backend", "APQ store selection (memory/postgres/redis)"),
+    ("apq_redis_url", "APQ Redis URL (Option; `redis-apq` feature)"),
     ("cache_enabled", "query result cache"),
     ("subscriptions_enabled", "subscriptions runtime toggle"),
     ("introspection_enabled", "introspection enforcer (#455)"),
     ("introspection_require_auth", "introspection auth gate"),
     ("validate_sql_sources", "compile-time SQL-source validation"),
+    ("validate_schema_drift", "boot-time schema-to-database drift validation"),
     // ── Metrics / tracing ────────────────────────────────────────────────────
     ("metrics*", "server metrics (metrics-exporter-prometheus; enabled/path/token)"),
     ("tracing_enabled", "OTLP tracing toggle"),
     ("tracing_service_name", "OTLP service name"),
+    ("log_level", "log filter directives (Option; reloadable)"),
     ("otlp_endpoint", "OTLP exporter endpoint (Option)"),
     ("otlp_export_timeout_secs", "OTLP export timeout"),
     // ── Admin API ────────────────────────────────────────────────────────────
     ("admin_api_enabled", "admin API mount toggle"),
     ("admin_token", "admin API bearer token (Option)"),
+    ("admin_operator_token", "admin API operator-role token (Option)"),
     ("admin_readonly_token", "admin API read-only token (Option)"),
     ("admin_auth_max_failures", "admin auth brute-force lockout threshold"),
     ("design_api_require_auth", "design API auth gate"),
@@ -72,9 +79,13 @@ const MANIFEST: &[(&str, &str)] = &[
     ("max_header_count", "request header count cap"),
     ("max_header_bytes", "request header size cap"),
     ("max_get_query_bytes", "GET query size cap"),
+    ("max_batch_operations", "POST operation batching size cap (0 = disabled)"),
+    ("batch_concurrency", "per-batch operation concurrency bound"),
     ("request_timeout_secs", "per-request timeout (Option)"),
+    ("request_timeout_ms", "per-request timeout in ms; also bounds SQL (Option)"),
     ("shutdown_timeout_secs", "graceful shutdown timeout"),
     ("admission_control", "admission-control / load-shed config (Option)"),
+    ("readiness", "readiness probe timeout / startup traffic gate (Option)"),
     // ── Optional subsystems ──────────────────────────────────────────────────
     ("rate_limiting", "rate-limit middleware config (Option; #609)"),
     ("observers", "observer runtime config (Option; `observers` feature)"),
@@ -82,6 +93,8 @@ const MANIFEST: &[(&str, &str)] = &[
     ("storage_token", "storage admin token (Option)"),
     ("files", "file-serving config (Option)"),
     ("usage", "usage-metering config (Option)"),
+    ("operation_audit", "operation audit log → tb_graphql_audit (Option)"),
+    ("field_usage", "field selection analytics → fraiseql_field_usage (Option)"),
     ("tenancy*", "multi-tenant runtime config (tenancy.runtime.enabled)"),
     (
         "validation",
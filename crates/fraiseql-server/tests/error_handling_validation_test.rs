@@ -52,6 +52,7 @@ fn test_database_connection_failure_response() {
             request_id:       Some("req-12345".to_string()),
             retry_after_secs: None,
             detail:           None,
+            limit:            None,
         });
 
     assert_eq!(error.message, "Failed to connect to database: Connection refused");
@@ -309,6 +310,7 @@ fn test_error_response_has_request_id() {
         request_id:       Some("req-unique-12368".to_string()),
         retry_after_secs: None,
         detail:           None,
+        limit:            None,
     };
 
     let error = GraphQLError::validation("Something went wrong").with_extensions(extensions);
@@ -341,6 +343,7 @@ fn test_error_response_with_extensions() {
         request_id:       Some("req-12371".to_string()),
         retry_after_secs: None,
         detail:           None,
+        limit:            None,
     };
 
     let error = GraphQLError::validation("Error occurred").with_extensions(extensions);
@@ -476,6 +479,7 @@ fn test_error_with_all_metadata() {
             request_id:       Some("req-db-001".to_string()),
             retry_after_secs: None,
             detail:           None,
+            limit:            None,
         });
 
     // Verify all components are present
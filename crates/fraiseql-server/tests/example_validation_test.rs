@@ -192,6 +192,7 @@ fn test_example_error_with_extensions() {
         request_id:       Some("req-db-001".to_string()),
         retry_after_secs: None,
         detail:           None,
+        limit:            None,
     };
 
     let error = GraphQLError::database("Connection pool exhausted").with_extensions(extensions);
@@ -533,6 +534,7 @@ fn test_example_batch_query_error_handling() {
         request_id:       Some("req-batch-001".to_string()),
         retry_after_secs: None,
         detail:           None,
+        limit:            None,
     });
     let ext3 = query3_error.extensions.unwrap_or(ErrorExtensions {
         category:         None,
@@ -540,6 +542,7 @@ fn test_example_batch_query_error_handling() {
         request_id:       Some("req-batch-001".to_string()),
         retry_after_secs: None,
         detail:           None,
+        limit:            None,
     });
 
     // Both reference same request ID
@@ -588,6 +591,7 @@ fn test_example_complete_error_context() {
             request_id:       Some("req-abc-xyz-123".to_string()),
             retry_after_secs: None,
             detail:           None,
+            limit:            None,
         });
 
     // All context present for debugging
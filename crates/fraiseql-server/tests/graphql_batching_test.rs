@@ -0,0 +1,137 @@
+//! End-to-end tests for POST operation batching: a JSON array of operations
+//! is answered by an array of responses in the same order, each operation
+//! fails in isolation, the configured size cap is enforced, and a batch costs
+//! one rate-limit token per operation.
+//!
+//! The router is built over an empty schema + a `FailingAdapter`. `__typename`
+//! resolves without a database round-trip, so it stands in for a succeeding
+//! operation; a query on an unknown field stands in for a failing one.
+
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+#![allow(missing_docs)] // Reason: test code
+
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{Extension, Router, body::Body, extract::ConnectInfo, middleware, routing::post};
+use fraiseql_core::{runtime::Executor, schema::CompiledSchema};
+use fraiseql_server::{
+    middleware::{RateLimitConfig, RateLimiter, rate_limit_middleware},
+    routes::graphql::{AppState, graphql_handler},
+};
+use fraiseql_test_utils::failing_adapter::FailingAdapter;
+use http::{Request, StatusCode};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn router(max_batch_operations: usize) -> Router {
+    let schema = CompiledSchema::new();
+    let adapter = Arc::new(FailingAdapter::new());
+    let state = AppState::new(Arc::new(Executor::new(schema, adapter)))
+        .with_batching(max_batch_operations, 2);
+    Router::new()
+        .route("/graphql", post(graphql_handler::<FailingAdapter>))
+        .with_state(state)
+}
+
+/// Batching router behind the rate-limit middleware, with a per-IP bucket of
+/// `burst` tokens that refills at one token per second.
+fn rate_limited_router(burst: u32) -> Router {
+    let limiter = RateLimiter::new(RateLimitConfig {
+        rps_per_ip: 1,
+        burst_size: burst,
+        ..RateLimitConfig::default()
+    });
+    router(10)
+        .layer(middleware::from_fn(rate_limit_middleware))
+        .layer(Extension(Arc::new(limiter)))
+}
+
+async fn post_graphql(router: Router, body: &Value) -> (StatusCode, Value) {
+    let mut req = Request::builder()
+        .method("POST")
+        .uri("/graphql")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(body).unwrap()))
+        .unwrap();
+    req.extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([203, 0, 113, 7], 4000))));
+    let response = router.oneshot(req).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: Value = serde_json::from_slice(&bytes).unwrap();
+    (status, json)
+}
+
+#[tokio::test]
+async fn batch_returns_one_response_per_operation_in_order() {
+    let (status, body) = post_graphql(
+        router(10),
+        &json!([
+            { "query": "{ __typename }" },
+            { "query": "{ nonexistentField { id } }" },
+            { "query": "{ __typename }" },
+        ]),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let responses = body.as_array().expect("batch response must be an array");
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0]["data"]["__typename"], "Query", "body: {body}");
+    assert!(responses[1]["errors"].is_array(), "failing operation must carry errors: {body}");
+    assert_eq!(responses[2]["data"]["__typename"], "Query", "body: {body}");
+}
+
+#[tokio::test]
+async fn batch_rejected_when_disabled() {
+    let (status, body) = post_graphql(router(0), &json!([{ "query": "{ __typename }" }])).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert!(body["errors"][0]["message"].as_str().unwrap().contains("not enabled"));
+}
+
+#[tokio::test]
+async fn batch_over_limit_rejected() {
+    let ops: Vec<Value> = (0..3).map(|_| json!({ "query": "{ __typename }" })).collect();
+    let (status, body) = post_graphql(router(2), &Value::Array(ops)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert!(body["errors"][0]["message"].as_str().unwrap().contains("maximum of 2"));
+}
+
+#[tokio::test]
+async fn empty_batch_rejected() {
+    let (status, _) = post_graphql(router(10), &json!([])).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn single_object_body_unchanged() {
+    let (status, body) = post_graphql(router(0), &json!({ "query": "{ __typename }" })).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(body.is_object(), "single request must get a single response: {body}");
+}
+
+#[tokio::test]
+async fn batch_within_remaining_quota_runs() {
+    let ops: Vec<Value> = (0..3).map(|_| json!({ "query": "{ __typename }" })).collect();
+    let (status, body) = post_graphql(rate_limited_router(3), &Value::Array(ops)).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body.as_array().map(Vec::len), Some(3), "body: {body}");
+}
+
+#[tokio::test]
+async fn batch_larger_than_remaining_quota_rejected() {
+    // Three tokens cover three single requests; a batch of five must not pass
+    // for the price of one.
+    let ops: Vec<Value> = (0..5).map(|_| json!({ "query": "{ __typename }" })).collect();
+    let (status, body) = post_graphql(rate_limited_router(3), &Value::Array(ops)).await;
+
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS, "body: {body}");
+    assert!(
+        body["errors"][0]["message"].as_str().unwrap().contains("batch of 5 operations"),
+        "body: {body}"
+    );
+}
@@ -0,0 +1,99 @@
+//! End-to-end tests for the GraphQL error extension contract: every error in a
+//! response carries `extensions.code`, `extensions.docs_url`,
+//! `extensions.retryable` and the request's correlation id.
+//!
+//! The router is built over an empty schema + a `FailingAdapter`; the errors
+//! exercised here are all raised before the adapter is reached.
+
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+#![allow(missing_docs)] // Reason: test code
+
+use std::sync::Arc;
+
+use axum::{Router, body::Body, routing::get};
+use fraiseql_core::{runtime::Executor, schema::CompiledSchema};
+use fraiseql_server::routes::graphql::{AppState, graphql_get_handler, graphql_handler};
+use fraiseql_test_utils::failing_adapter::FailingAdapter;
+use http::{Request, StatusCode};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn router() -> Router {
+    let schema = CompiledSchema::new();
+    let adapter = Arc::new(FailingAdapter::new());
+    let state = AppState::new(Arc::new(Executor::new(schema, adapter))).with_batching(10, 2);
+    Router::new()
+        .route(
+            "/graphql",
+            get(graphql_get_handler::<FailingAdapter>).post(graphql_handler::<FailingAdapter>),
+        )
+        .with_state(state)
+}
+
+async fn send(req: Request<Body>) -> (StatusCode, http::HeaderMap, Value) {
+    let response = router().oneshot(req).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn post_request(body: &Value, request_id: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method("POST")
+        .uri("/graphql")
+        .header("content-type", "application/json");
+    if let Some(id) = request_id {
+        builder = builder.header("x-request-id", id);
+    }
+    builder.body(Body::from(serde_json::to_vec(body).unwrap())).unwrap()
+}
+
+#[tokio::test]
+async fn error_carries_code_docs_url_retryable_and_request_id() {
+    let (status, headers, body) =
+        send(post_request(&json!({ "query": "{ unclosed" }), Some("trace-abc"))).await;
+
+    assert_eq!(status, StatusCode::OK, "parse errors stay 200: {body}");
+    let ext = &body["errors"][0]["extensions"];
+    let code = ext["code"].as_str().unwrap();
+    assert_eq!(body["errors"][0]["code"], code);
+    assert_eq!(ext["docs_url"], format!("https://docs.fraiseql.dev/errors#{code}"));
+    assert_eq!(ext["retryable"], false);
+    assert_eq!(ext["request_id"], "trace-abc");
+    assert_eq!(headers.get("x-request-id").unwrap(), "trace-abc");
+}
+
+#[tokio::test]
+async fn request_id_is_generated_when_absent() {
+    let (_, headers, body) = send(post_request(&json!({ "query": "{ unclosed" }), None)).await;
+
+    let id = body["errors"][0]["extensions"]["request_id"].as_str().unwrap();
+    assert!(id.starts_with("req-"), "generated id: {id}");
+    assert_eq!(headers.get("x-request-id").unwrap(), id);
+}
+
+#[tokio::test]
+async fn get_errors_carry_request_id() {
+    let req = Request::builder()
+        .method("GET")
+        .uri("/graphql?query=%7B%20__typename%20%7D&variables=not-json")
+        .header("x-request-id", "get-1")
+        .body(Body::empty())
+        .unwrap();
+    let (status, _, body) = send(req).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert_eq!(body["errors"][0]["extensions"]["code"], "REQUEST_ERROR");
+    assert_eq!(body["errors"][0]["extensions"]["request_id"], "get-1");
+}
+
+#[tokio::test]
+async fn batched_errors_share_the_request_id() {
+    let batch = json!([{ "query": "{ __typename }" }, { "query": "{ unclosed" }]);
+    let (status, _, body) = send(post_request(&batch, Some("batch-7"))).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body[0]["data"]["__typename"], "Query");
+    assert_eq!(body[1]["errors"][0]["extensions"]["request_id"], "batch-7");
+}
@@ -0,0 +1,138 @@
+//! End-to-end tests for CDN-cacheable GraphQL GET requests: hash-only
+//! persisted queries resolve over GET, responses carry `Cache-Control`, and a
+//! mutation cannot be smuggled onto GET behind a persisted-query hash.
+//!
+//! The router is built over an empty schema + a `FailingAdapter` with an
+//! in-memory APQ store. The empty schema has no TTL hints, so every response is
+//! `no-store`; TTL derivation itself is covered by the handler's unit tests.
+
+#![allow(clippy::unwrap_used)] // Reason: test code, panics are acceptable
+#![allow(missing_docs)] // Reason: test code
+
+use std::sync::Arc;
+
+use axum::{Router, body::Body, routing::get};
+use fraiseql_core::{
+    apq::{InMemoryApqStorage, hash_query},
+    runtime::Executor,
+    schema::CompiledSchema,
+};
+use fraiseql_server::routes::graphql::{AppState, graphql_get_handler, graphql_handler};
+use fraiseql_test_utils::failing_adapter::FailingAdapter;
+use http::{Request, StatusCode};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn router() -> Router {
+    let schema = CompiledSchema::new();
+    let adapter = Arc::new(FailingAdapter::new());
+    let state = AppState::new(Arc::new(Executor::new(schema, adapter)))
+        .with_apq_store(Arc::new(InMemoryApqStorage::default()));
+    Router::new()
+        .route(
+            "/graphql",
+            get(graphql_get_handler::<FailingAdapter>).post(graphql_handler::<FailingAdapter>),
+        )
+        .with_state(state)
+}
+
+fn persisted_query_extensions(hash: &str) -> String {
+    json!({ "persistedQuery": { "version": 1, "sha256Hash": hash } }).to_string()
+}
+
+fn encode(value: &str) -> String {
+    urlencoding::encode(value).into_owned()
+}
+
+async fn send(router: Router, req: Request<Body>) -> (StatusCode, http::HeaderMap, Value) {
+    let response = router.oneshot(req).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn get_request(uri: &str) -> Request<Body> {
+    Request::builder().method("GET").uri(uri).body(Body::empty()).unwrap()
+}
+
+fn post_request(body: &Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/graphql")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(body).unwrap()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn get_response_carries_cache_control() {
+    let uri = format!("/graphql?query={}", encode("{ __typename }"));
+    let (status, headers, body) = send(router(), get_request(&uri)).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(headers.get("cache-control").unwrap(), "no-store");
+    assert!(headers.get("vary").is_some());
+}
+
+#[tokio::test]
+async fn post_response_has_no_cache_control() {
+    let (status, headers, body) =
+        send(router(), post_request(&json!({ "query": "{ __typename }" }))).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(headers.get("cache-control").is_none());
+}
+
+#[tokio::test]
+async fn hash_only_get_resolves_registered_query() {
+    let router = router();
+    let query = "{ __typename }";
+    let hash = hash_query(query);
+
+    // Unknown hash: the client is told to register.
+    let uri = format!("/graphql?extensions={}", encode(&persisted_query_extensions(&hash)));
+    let (_, _, body) = send(router.clone(), get_request(&uri)).await;
+    assert!(body.to_string().contains("PersistedQueryNotFound"), "body: {body}");
+
+    // Register via POST, then the short, stable GET URL resolves.
+    send(
+        router.clone(),
+        post_request(&json!({
+            "query": query,
+            "extensions": { "persistedQuery": { "version": 1, "sha256Hash": hash } },
+        })),
+    )
+    .await;
+    let (status, _, body) = send(router, get_request(&uri)).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["data"]["__typename"], "Query", "body: {body}");
+}
+
+#[tokio::test]
+async fn hash_only_get_cannot_run_a_persisted_mutation() {
+    let router = router();
+    let mutation = "mutation { createUser(name: \"x\") { id } }";
+    let hash = hash_query(mutation);
+
+    // Registration over POST is allowed (execution fails on the empty schema,
+    // but the hash is stored before execution).
+    send(
+        router.clone(),
+        post_request(&json!({
+            "query": mutation,
+            "extensions": { "persistedQuery": { "version": 1, "sha256Hash": hash } },
+        })),
+    )
+    .await;
+
+    let uri = format!("/graphql?extensions={}", encode(&persisted_query_extensions(&hash)));
+    let (status, _, body) = send(router, get_request(&uri)).await;
+    assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED, "body: {body}");
+}
+
+#[tokio::test]
+async fn invalid_extensions_json_rejected() {
+    let (status, _, _) = send(router(), get_request("/graphql?extensions=%7Bnot-json")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
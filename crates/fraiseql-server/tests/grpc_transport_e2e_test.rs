@@ -1197,6 +1197,7 @@ async fn rate_limited_request_returns_resource_exhausted() {
         trust_proxy_headers:   false,
         trusted_proxy_cidrs:   Vec::new(),
         max_buckets:           100_000,
+        redis_url:             None,
     };
     let limiter = Arc::new(fraiseql_server::middleware::RateLimiter::new(config));
     let svc = build_service_with_rate_limiter(adapter, schema, limiter);
@@ -1242,6 +1243,7 @@ async fn rate_limiter_allows_requests_within_budget() {
         trust_proxy_headers:   false,
         trusted_proxy_cidrs:   Vec::new(),
         max_buckets:           100_000,
+        redis_url:             None,
     };
     let limiter = Arc::new(fraiseql_server::middleware::RateLimiter::new(config));
     let svc = build_service_with_rate_limiter(adapter, schema, limiter);
@@ -247,6 +247,7 @@ fn test_error_extensions() {
         request_id:       Some("req-12345".to_string()),
         retry_after_secs: None,
         detail:           None,
+        limit:            None,
     };
 
     let error = GraphQLError::validation("Invalid input").with_extensions(extensions);
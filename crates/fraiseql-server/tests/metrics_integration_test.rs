@@ -95,6 +95,18 @@ async fn metrics_endpoint_contains_db_pool_metrics() {
     assert!(body.contains("fraiseql_db_pool_requests_waiting"));
 }
 
+#[tokio::test]
+async fn metrics_endpoint_contains_query_plan_cache_metrics() {
+    let state = make_metrics_state();
+    let router = metrics_router(state);
+    let (_, body) = get_text(&router, "/metrics").await;
+
+    assert!(body.contains("fraiseql_query_plan_cache_hits_total 0"));
+    assert!(body.contains("fraiseql_query_plan_cache_misses_total 0"));
+    assert!(body.contains("# TYPE fraiseql_query_plan_cache_evictions_total counter"));
+    assert!(body.contains("fraiseql_query_plan_cache_entries 0"));
+}
+
 #[tokio::test]
 async fn metrics_endpoint_contains_apq_counters() {
     let state = make_metrics_state();
@@ -109,6 +121,12 @@ async fn metrics_endpoint_contains_apq_counters() {
     assert!(body.contains("fraiseql_apq_hits_total 2"), "APQ hits should be 2");
     assert!(body.contains("fraiseql_apq_misses_total 1"), "APQ misses should be 1");
     assert!(body.contains("fraiseql_apq_stored_total 1"), "APQ stored should be 1");
+    // One registration against two hits.
+    assert!(
+        body.contains("fraiseql_apq_registration_ratio 0.3333"),
+        "APQ registration ratio should be 1/3"
+    );
+    assert!(body.contains("fraiseql_apq_postgres_errors_total"));
 }
 
 #[tokio::test]
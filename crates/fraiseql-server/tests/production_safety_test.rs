@@ -1,7 +1,7 @@
 //! Production Safety Tests
 //!
 //! Tests for production mode safety validation:
-//! - Playground disabled by default
+//! - Playground and GraphiQL disabled by default
 //! - CORS must be explicitly configured in production
 //! - Production mode detection via `FRAISEQL_ENV`
 //!
@@ -49,6 +49,32 @@ fn test_playground_can_be_enabled() {
     assert!(config.playground_enabled);
 }
 
+#[test]
+fn test_graphiql_disabled_by_default() {
+    let config = ServerConfig::default();
+    assert!(!config.graphiql_enabled, "GraphiQL should be disabled by default for security");
+    assert_eq!(config.graphiql_path, "/graphiql");
+}
+
+#[test]
+fn test_graphiql_rejected_in_production() {
+    let original = std::env::var("FRAISEQL_ENV").ok();
+    std::env::remove_var("FRAISEQL_ENV");
+
+    let config = ServerConfig {
+        graphiql_enabled: true,
+        cors_origins: vec!["https://app.example.com".to_string()],
+        ..ServerConfig::default()
+    };
+    let err = config.validate().expect_err("GraphiQL must be rejected in production mode");
+    assert!(err.contains("graphiql_enabled"), "unexpected error: {err}");
+
+    match original {
+        Some(v) => std::env::set_var("FRAISEQL_ENV", v),
+        None => std::env::remove_var("FRAISEQL_ENV"),
+    }
+}
+
 // =============================================================================
 // Production Mode Detection Tests
 // =============================================================================
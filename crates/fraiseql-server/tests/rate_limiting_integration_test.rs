@@ -22,6 +22,7 @@ mod tests {
             trust_proxy_headers: false,
             trusted_proxy_cidrs: Vec::new(),
             max_buckets: 100_000,
+            redis_url: None,
         })
     }
 
@@ -76,6 +77,7 @@ mod tests {
             trust_proxy_headers:   false,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         });
 
         // Even with extremely low limits, should allow through when disabled
@@ -109,6 +111,7 @@ mod tests {
             trust_proxy_headers:   false,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         });
 
         // Should allow 3 requests for authenticated user
@@ -131,6 +134,7 @@ mod tests {
             trust_proxy_headers:   false,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         });
 
         // User 1 gets 2 requests
@@ -155,6 +159,7 @@ mod tests {
             trust_proxy_headers:   false,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         });
 
         let first = limiter.check_user_limit("user123", None).await;
@@ -189,6 +194,7 @@ mod tests {
             trust_proxy_headers:   false,
             trusted_proxy_cidrs:   Vec::new(),
             max_buckets:           100_000,
+            redis_url:             None,
         });
 
         // Should be able to get initial burst_size worth of tokens
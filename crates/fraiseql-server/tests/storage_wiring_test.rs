@@ -41,17 +41,18 @@ async fn build_storage_state_wires_local_backend_from_config() {
     storage.insert(
         "assets".to_string(),
         StorageSectionConfig {
-            backend:            "local".to_string(),
-            path:               Some(tmp.path().to_string_lossy().into_owned()),
-            bucket:             None,
-            region:             None,
-            endpoint:           None,
-            project_id:         None,
-            account_name:       None,
-            access:             Some("public_read".to_string()),
-            max_object_bytes:   Some(1024),
-            allowed_mime_types: None,
-            serve_inline:       None,
+            backend:                "local".to_string(),
+            path:                   Some(tmp.path().to_string_lossy().into_owned()),
+            bucket:                 None,
+            region:                 None,
+            endpoint:               None,
+            project_id:             None,
+            account_name:           None,
+            access:                 Some("public_read".to_string()),
+            max_object_bytes:       Some(1024),
+            allowed_mime_types:     None,
+            serve_inline:           None,
+            url_signing_secret_env: None,
         },
     );
 
@@ -3,7 +3,7 @@
 //! Authentication uses the `SharedKey` scheme: the storage account key is read
 //! from the `AZURE_STORAGE_KEY` environment variable (base64-encoded).
 
-use std::time::Duration;
+use std::{path::Path, time::Duration};
 
 use base64::{Engine as _, engine::general_purpose};
 use chrono::Utc;
@@ -11,7 +11,7 @@ use fraiseql_error::{FileError, FraiseQLError, Result};
 use hmac::{Hmac, KeyInit, Mac};
 use sha2::Sha256;
 
-use super::validate_key;
+use super::{validate_key, write_response_to_file};
 
 const AZURE_API_VERSION: &str = "2023-11-03";
 
@@ -276,10 +276,40 @@ impl AzureBackend {
     ///
     /// Returns `FraiseQLError::File` if the upload fails.
     pub async fn upload(&self, key: &str, data: &[u8], content_type: &str) -> Result<String> {
+        let content_length = u64::try_from(data.len()).unwrap_or(u64::MAX);
+        self.upload_body(key, content_type, data.to_vec().into(), content_length).await
+    }
+
+    /// Uploads the file at `path` and returns the storage key.
+    ///
+    /// The file is streamed from disk as the request body of a single
+    /// `Put Blob`, which Azure accepts up to 5000 MiB.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the file cannot be read or the upload fails.
+    pub async fn upload_file(&self, key: &str, path: &Path, content_type: &str) -> Result<String> {
+        let file =
+            tokio::fs::File::open(path).await.map_err(|e| azure_err_src("upload_file", e))?;
+        let size = file
+            .metadata()
+            .await
+            .map_err(|e| azure_err_src("upload_file metadata", e))?
+            .len();
+        self.upload_body(key, content_type, file.into(), size).await
+    }
+
+    async fn upload_body(
+        &self,
+        key: &str,
+        content_type: &str,
+        body: reqwest::Body,
+        content_length: u64,
+    ) -> Result<String> {
         validate_key(key)?;
         let url = self.blob_url(key);
         let date = Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string();
-        let content_length = data.len().to_string();
+        let content_length = content_length.to_string();
 
         let auth = self.sign_request(
             "PUT",
@@ -298,7 +328,8 @@ impl AzureBackend {
             .header("x-ms-version", AZURE_API_VERSION)
             .header("x-ms-blob-type", "BlockBlob")
             .header("Content-Type", content_type)
-            .body(data.to_vec())
+            .header("Content-Length", &content_length)
+            .body(body)
             .send()
             .await
             .map_err(|e| azure_err_src("upload", e))?;
@@ -317,6 +348,28 @@ impl AzureBackend {
     ///
     /// Returns `FraiseQLError::File` if the download fails or the key does not exist.
     pub async fn download(&self, key: &str) -> Result<Vec<u8>> {
+        self.get_blob(key)
+            .await?
+            .bytes()
+            .await
+            .map(|b| b.to_vec())
+            .map_err(|e| azure_err_src("download body", e))
+    }
+
+    /// Streams the object at `key` into the file at `path` and returns its size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the download fails, the key does not
+    /// exist, or the file cannot be written.
+    pub async fn download_file(&self, key: &str, path: &Path) -> Result<u64> {
+        let resp = self.get_blob(key).await?;
+        write_response_to_file(resp, path)
+            .await
+            .map_err(|e| azure_err_src("download body", e))
+    }
+
+    async fn get_blob(&self, key: &str) -> Result<reqwest::Response> {
         validate_key(key)?;
         let url = self.blob_url(key);
         let date = Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string();
@@ -343,11 +396,7 @@ impl AzureBackend {
             let body = resp.text().await.unwrap_or_default();
             return Err(azure_err("download response", body));
         }
-
-        resp.bytes()
-            .await
-            .map(|b| b.to_vec())
-            .map_err(|e| azure_err_src("download body", e))
+        Ok(resp)
     }
 
     /// Deletes the object at the given key from Azure Blob Storage.
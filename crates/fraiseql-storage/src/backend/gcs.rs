@@ -5,12 +5,15 @@
 //! 2. `GOOGLE_APPLICATION_CREDENTIALS` env var — path to a service account JSON file (tokens are
 //!    auto-refreshed via JWT exchange)
 
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 use fraiseql_error::{FileError, FraiseQLError, Result};
 use parking_lot::RwLock;
 
-use super::validate_key;
+use super::{validate_key, write_response_to_file};
 
 const GCS_DEFAULT_API_BASE: &str = "https://storage.googleapis.com";
 const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
@@ -276,6 +279,30 @@ impl GcsBackend {
     ///
     /// Returns `FraiseQLError::File` if the upload request fails.
     pub async fn upload(&self, key: &str, data: &[u8], content_type: &str) -> Result<String> {
+        self.upload_body(key, content_type, data.to_vec().into(), None).await
+    }
+
+    /// Uploads the file at `path` to GCS and returns the storage key.
+    ///
+    /// The file is streamed from disk as the request body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the file cannot be read or the upload
+    /// request fails.
+    pub async fn upload_file(&self, key: &str, path: &Path, content_type: &str) -> Result<String> {
+        let file = tokio::fs::File::open(path).await.map_err(|e| gcs_err_src("upload_file", e))?;
+        let size = file.metadata().await.map_err(|e| gcs_err_src("upload_file metadata", e))?.len();
+        self.upload_body(key, content_type, file.into(), Some(size)).await
+    }
+
+    async fn upload_body(
+        &self,
+        key: &str,
+        content_type: &str,
+        body: reqwest::Body,
+        content_length: Option<u64>,
+    ) -> Result<String> {
         validate_key(key)?;
         let token = self.get_token().await?;
         let base = self.api_base();
@@ -285,15 +312,12 @@ impl GcsBackend {
             urlencoding::encode(key)
         );
 
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&token)
-            .header("Content-Type", content_type)
-            .body(data.to_vec())
-            .send()
-            .await
-            .map_err(|e| gcs_err_src("upload", e))?;
+        let mut request =
+            self.client.post(&url).bearer_auth(&token).header("Content-Type", content_type);
+        if let Some(length) = content_length {
+            request = request.header("Content-Length", length);
+        }
+        let resp = request.body(body).send().await.map_err(|e| gcs_err_src("upload", e))?;
 
         if !resp.status().is_success() {
             let body = resp.text().await.unwrap_or_default();
@@ -309,6 +333,28 @@ impl GcsBackend {
     ///
     /// Returns `FraiseQLError::File` if the download fails or the key does not exist.
     pub async fn download(&self, key: &str) -> Result<Vec<u8>> {
+        self.get_media(key)
+            .await?
+            .bytes()
+            .await
+            .map(|b| b.to_vec())
+            .map_err(|e| gcs_err_src("download body", e))
+    }
+
+    /// Streams the object at `key` into the file at `path` and returns its size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the download fails, the key does not
+    /// exist, or the file cannot be written.
+    pub async fn download_file(&self, key: &str, path: &Path) -> Result<u64> {
+        let resp = self.get_media(key).await?;
+        write_response_to_file(resp, path)
+            .await
+            .map_err(|e| gcs_err_src("download body", e))
+    }
+
+    async fn get_media(&self, key: &str) -> Result<reqwest::Response> {
         validate_key(key)?;
         let token = self.get_token().await?;
         let base = self.api_base();
@@ -333,11 +379,7 @@ impl GcsBackend {
             let body = resp.text().await.unwrap_or_default();
             return Err(gcs_err("download response", body));
         }
-
-        resp.bytes()
-            .await
-            .map(|b| b.to_vec())
-            .map_err(|e| gcs_err_src("download body", e))
+        Ok(resp)
     }
 
     /// Deletes the object at the given key from GCS.
@@ -1,10 +1,16 @@
 //! Local filesystem storage backend.
 
-use std::{path::PathBuf, time::Duration};
+use std::{
+    io::SeekFrom,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use fraiseql_error::{FileError, FraiseQLError, Result};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
 use super::{
+    ObjectStream,
     types::{ListResult, ObjectInfo},
     validate_key,
 };
@@ -52,6 +58,43 @@ impl LocalBackend {
         Ok(key.to_string())
     }
 
+    /// Uploads the file at `path` and returns the storage key.
+    ///
+    /// The file is copied on disk, never read into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File(FileError::IoError)` if the copy fails.
+    pub async fn upload_file(&self, key: &str, path: &Path, _content_type: &str) -> Result<String> {
+        let dest = self.key_path(key)?;
+        if let Some(parent) = dest.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                FraiseQLError::File(FileError::IoError {
+                    message: format!("Failed to create directory: {e}"),
+                    source:  Some(Box::new(e)),
+                })
+            })?;
+        }
+        tokio::fs::copy(path, &dest).await.map_err(|e| {
+            FraiseQLError::File(FileError::IoError {
+                message: format!("Failed to write file: {e}"),
+                source:  Some(Box::new(e)),
+            })
+        })?;
+        Ok(key.to_string())
+    }
+
+    /// Copies the object at `key` into the file at `path` and returns its size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File(FileError::NotFound)` if the key does not exist,
+    /// or `FileError::IoError` on backend failures.
+    pub async fn download_file(&self, key: &str, path: &Path) -> Result<u64> {
+        let src = self.key_path(key)?;
+        tokio::fs::copy(&src, path).await.map_err(|e| read_error(key, e))
+    }
+
     /// Downloads the contents of the given key.
     ///
     /// # Errors
@@ -60,18 +103,23 @@ impl LocalBackend {
     /// or `FileError::IoError` on backend failures.
     pub async fn download(&self, key: &str) -> Result<Vec<u8>> {
         let path = self.key_path(key)?;
-        tokio::fs::read(&path).await.map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                FraiseQLError::File(FileError::NotFound {
-                    id: key.to_string(),
-                })
-            } else {
-                FraiseQLError::File(FileError::IoError {
-                    message: format!("Failed to read file: {e}"),
-                    source:  Some(Box::new(e)),
-                })
-            }
-        })
+        tokio::fs::read(&path).await.map_err(|e| read_error(key, e))
+    }
+
+    /// Streams `len` bytes of the object at `key`, starting at byte `offset`.
+    ///
+    /// The file is read as the stream is polled, so large objects and ranges
+    /// are never buffered in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File(FileError::NotFound)` if the key does not exist,
+    /// or `FileError::IoError` on backend failures.
+    pub async fn download_stream(&self, key: &str, offset: u64, len: u64) -> Result<ObjectStream> {
+        let path = self.key_path(key)?;
+        let mut file = tokio::fs::File::open(&path).await.map_err(|e| read_error(key, e))?;
+        file.seek(SeekFrom::Start(offset)).await.map_err(|e| read_error(key, e))?;
+        Ok(Box::pin(tokio_util::io::ReaderStream::new(file.take(len))))
     }
 
     /// Deletes the object at the given key.
@@ -241,6 +289,20 @@ impl LocalBackend {
     }
 }
 
+/// Map a failed read of `key` to `NotFound` or `IoError`.
+fn read_error(key: &str, e: std::io::Error) -> FraiseQLError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        FraiseQLError::File(FileError::NotFound {
+            id: key.to_string(),
+        })
+    } else {
+        FraiseQLError::File(FileError::IoError {
+            message: format!("Failed to read file: {e}"),
+            source:  Some(Box::new(e)),
+        })
+    }
+}
+
 /// Simple FNV-1a hash function
 fn fnv1a_hash(data: &str) -> u64 {
     const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
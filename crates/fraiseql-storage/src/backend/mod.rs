@@ -4,8 +4,9 @@
 //! Azure Blob Storage, and S3-compatible European providers (Hetzner, Scaleway, OVH,
 //! Exoscale, Backblaze B2, Cloudflare R2).
 
-use std::time::Duration;
+use std::{path::Path, pin::Pin, time::Duration};
 
+use bytes::Bytes;
 use chrono::{DateTime, Utc};
 #[cfg(feature = "aws-s3")]
 use fraiseql_error::FraiseQLError;
@@ -15,6 +16,9 @@ use serde::{Deserialize, Serialize};
 pub mod local;
 pub mod types;
 
+/// A stream over (part of) an object's contents.
+pub type ObjectStream = Pin<Box<dyn futures::Stream<Item = std::io::Result<Bytes>> + Send>>;
+
 /// Presigned URL for time-limited direct access to an object.
 ///
 /// Can be used for direct uploads (PUT) or downloads (GET) without going through
@@ -180,6 +184,57 @@ impl StorageBackend {
         }
     }
 
+    /// Uploads the file at `path` and returns the storage key.
+    ///
+    /// The file is streamed from disk rather than read into memory; S3 and
+    /// S3-compatible backends switch to a multipart upload for large files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the file cannot be read or the upload fails.
+    pub async fn upload_file(&self, key: &str, path: &Path, content_type: &str) -> Result<String> {
+        match self {
+            Self::Local(b) => b.upload_file(key, path, content_type).await,
+            #[cfg(feature = "aws-s3")]
+            Self::S3(b)
+            | Self::Hetzner(b)
+            | Self::Scaleway(b)
+            | Self::Ovh(b)
+            | Self::Exoscale(b)
+            | Self::Backblaze(b)
+            | Self::R2(b) => b.upload_file(key, path, content_type).await,
+            #[cfg(feature = "gcs")]
+            Self::Gcs(b) => b.upload_file(key, path, content_type).await,
+            #[cfg(feature = "azure-blob")]
+            Self::Azure(b) => b.upload_file(key, path, content_type).await,
+        }
+    }
+
+    /// Streams the object at `key` into the file at `path`, creating or
+    /// truncating it, and returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` with code `not_found` if the key does not exist,
+    /// or other error codes on backend or file failures.
+    pub async fn download_file(&self, key: &str, path: &Path) -> Result<u64> {
+        match self {
+            Self::Local(b) => b.download_file(key, path).await,
+            #[cfg(feature = "aws-s3")]
+            Self::S3(b)
+            | Self::Hetzner(b)
+            | Self::Scaleway(b)
+            | Self::Ovh(b)
+            | Self::Exoscale(b)
+            | Self::Backblaze(b)
+            | Self::R2(b) => b.download_file(key, path).await,
+            #[cfg(feature = "gcs")]
+            Self::Gcs(b) => b.download_file(key, path).await,
+            #[cfg(feature = "azure-blob")]
+            Self::Azure(b) => b.download_file(key, path).await,
+        }
+    }
+
     /// Downloads the contents of the given key.
     ///
     /// # Errors
@@ -204,6 +259,34 @@ impl StorageBackend {
         }
     }
 
+    /// Streams `len` bytes of the object at `key`, starting at byte `offset`.
+    ///
+    /// The local backend reads the requested range from disk as the stream is
+    /// polled. Remote backends currently download the whole object and yield
+    /// the requested range as a single chunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` with code `not_found` if the key does not exist,
+    /// or other error codes on backend failures.
+    pub async fn download_stream(&self, key: &str, offset: u64, len: u64) -> Result<ObjectStream> {
+        match self {
+            Self::Local(b) => b.download_stream(key, offset, len).await,
+            #[cfg(feature = "aws-s3")]
+            Self::S3(b)
+            | Self::Hetzner(b)
+            | Self::Scaleway(b)
+            | Self::Ovh(b)
+            | Self::Exoscale(b)
+            | Self::Backblaze(b)
+            | Self::R2(b) => Ok(buffered_stream(b.download(key).await?, offset, len)),
+            #[cfg(feature = "gcs")]
+            Self::Gcs(b) => Ok(buffered_stream(b.download(key).await?, offset, len)),
+            #[cfg(feature = "azure-blob")]
+            Self::Azure(b) => Ok(buffered_stream(b.download(key).await?, offset, len)),
+        }
+    }
+
     /// Deletes the object at the given key.
     ///
     /// # Errors
@@ -352,6 +435,36 @@ impl StorageBackend {
     }
 }
 
+/// Yield `len` bytes of `data` from `offset` as a one-chunk [`ObjectStream`].
+///
+/// The range is clamped to the data, so an out-of-range request yields an
+/// empty chunk instead of panicking.
+#[cfg(any(feature = "aws-s3", feature = "gcs", feature = "azure-blob", test))]
+#[allow(dead_code)] // Reason: only used when a remote backend feature is enabled
+fn buffered_stream(data: Vec<u8>, offset: u64, len: u64) -> ObjectStream {
+    let data = Bytes::from(data);
+    let start = usize::try_from(offset).map_or(data.len(), |o| o.min(data.len()));
+    let end = usize::try_from(len).map_or(data.len(), |l| start.saturating_add(l).min(data.len()));
+    let chunk = data.slice(start..end);
+    Box::pin(futures::stream::once(async move { Ok(chunk) }))
+}
+
+/// Write the body of `resp` to the file at `path` chunk by chunk and return
+/// the number of bytes written.
+#[cfg(any(feature = "gcs", feature = "azure-blob"))]
+async fn write_response_to_file(mut resp: reqwest::Response, path: &Path) -> std::io::Result<u64> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut file = tokio::fs::File::create(path).await?;
+    let mut written = 0_u64;
+    while let Some(chunk) = resp.chunk().await.map_err(std::io::Error::other)? {
+        file.write_all(&chunk).await?;
+        written += u64::try_from(chunk.len()).unwrap_or(u64::MAX);
+    }
+    file.flush().await?;
+    Ok(written)
+}
+
 /// Validates that a storage key is safe (no path traversal).
 ///
 /// # Errors
@@ -3,13 +3,23 @@
 //! Also supports Hetzner, Scaleway, OVH, Exoscale, Backblaze B2, and Cloudflare R2
 //! via the `endpoint` configuration option.
 
-use std::time::Duration;
+use std::{path::Path, time::Duration};
 
-use aws_sdk_s3::{Client, primitives::ByteStream};
+use aws_sdk_s3::{
+    Client,
+    operation::get_object::GetObjectOutput,
+    primitives::{ByteStream, Length},
+    types::{CompletedMultipartUpload, CompletedPart},
+};
 use fraiseql_error::{FileError, FraiseQLError, Result};
+use tokio::io::AsyncWriteExt;
 
 use super::validate_key;
 
+/// Part size of a multipart [`S3Backend::upload_file`]. Files up to this size
+/// are sent in a single `PutObject`.
+const MULTIPART_PART_SIZE: u64 = 64 * 1024 * 1024;
+
 #[cfg(test)]
 mod tests;
 
@@ -80,41 +90,187 @@ impl S3Backend {
         Ok(key.to_owned())
     }
 
-    /// Downloads the contents of the given key.
+    /// Uploads the file at `path` and returns the storage key.
+    ///
+    /// The file is streamed from disk: in one `PutObject` up to
+    /// [`MULTIPART_PART_SIZE`], otherwise as a multipart upload of that part
+    /// size, which is aborted if any part fails.
     ///
     /// # Errors
     ///
-    /// Returns `FraiseQLError::File` with code `not_found` if the key does not exist,
-    /// or other error codes on backend failures.
-    pub async fn download(&self, key: &str) -> Result<Vec<u8>> {
+    /// Returns `FraiseQLError::File` if the file cannot be read or the upload fails.
+    pub async fn upload_file(&self, key: &str, path: &Path, content_type: &str) -> Result<String> {
         validate_key(key)?;
-        let resp =
+        let size = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| storage_err_src("upload_file metadata", e))?
+            .len();
+        if size <= MULTIPART_PART_SIZE {
+            let body = ByteStream::from_path(path)
+                .await
+                .map_err(|e| storage_err_src("upload_file read", e))?;
             self.client
-                .get_object()
+                .put_object()
                 .bucket(&self.bucket)
                 .key(key)
+                .body(body)
+                .content_type(content_type)
                 .send()
                 .await
-                .map_err(|e| {
-                    // A missing key is a typed `NoSuchKey` service error. The
-                    // `SdkError` Display does not contain the code (it is just
-                    // "service error"), so detect it structurally on the typed
-                    // error rather than by string-matching (H40).
-                    if e.as_service_error().is_some_and(
-                        aws_sdk_s3::operation::get_object::GetObjectError::is_no_such_key,
-                    ) {
-                        FraiseQLError::File(FileError::NotFound {
-                            id: key.to_string(),
-                        })
-                    } else {
-                        storage_err_src("get_object", e)
-                    }
-                })?;
+                .map_err(|e| storage_err_src("put_object", e))?;
+            return Ok(key.to_owned());
+        }
 
+        let created = self
+            .client
+            .create_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .send()
+            .await
+            .map_err(|e| storage_err_src("create_multipart_upload", e))?;
+        let upload_id = created.upload_id().ok_or_else(|| {
+            FraiseQLError::File(FileError::Backend {
+                message: "S3 create_multipart_upload returned no upload id".to_string(),
+                source:  None,
+            })
+        })?;
+        let completed = match self.upload_parts(key, path, size, upload_id).await {
+            Ok(parts) => self
+                .client
+                .complete_multipart_upload()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .multipart_upload(
+                    CompletedMultipartUpload::builder().set_parts(Some(parts)).build(),
+                )
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| storage_err_src("complete_multipart_upload", e)),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = completed {
+            // Drop the uploaded parts so they stop accruing storage charges.
+            let _ = self
+                .client
+                .abort_multipart_upload()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .send()
+                .await;
+            return Err(e);
+        }
+        Ok(key.to_owned())
+    }
+
+    /// Stream `size` bytes of `path` as the parts of multipart upload `upload_id`.
+    async fn upload_parts(
+        &self,
+        key: &str,
+        path: &Path,
+        size: u64,
+        upload_id: &str,
+    ) -> Result<Vec<CompletedPart>> {
+        let mut parts = Vec::new();
+        let mut offset = 0;
+        let mut part_number = 1;
+        while offset < size {
+            let length = MULTIPART_PART_SIZE.min(size - offset);
+            let body = ByteStream::read_from()
+                .path(path)
+                .offset(offset)
+                .length(Length::Exact(length))
+                .build()
+                .await
+                .map_err(|e| storage_err_src("upload_file read", e))?;
+            let uploaded = self
+                .client
+                .upload_part()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .part_number(part_number)
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| storage_err_src("upload_part", e))?;
+            parts.push(
+                CompletedPart::builder()
+                    .part_number(part_number)
+                    .set_e_tag(uploaded.e_tag().map(str::to_owned))
+                    .build(),
+            );
+            offset += length;
+            part_number += 1;
+        }
+        Ok(parts)
+    }
+
+    /// Downloads the contents of the given key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` with code `not_found` if the key does not exist,
+    /// or other error codes on backend failures.
+    pub async fn download(&self, key: &str) -> Result<Vec<u8>> {
+        let resp = self.get_object(key).await?;
         let body = resp.body.collect().await.map_err(|e| storage_err_src("get_object body", e))?;
         Ok(body.into_bytes().to_vec())
     }
 
+    /// Streams the object at `key` into the file at `path` and returns its size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` with code `not_found` if the key does not exist,
+    /// or other error codes on backend or file failures.
+    pub async fn download_file(&self, key: &str, path: &Path) -> Result<u64> {
+        let mut body = self.get_object(key).await?.body;
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(|e| storage_err_src("download_file create", e))?;
+        let mut written = 0_u64;
+        while let Some(chunk) =
+            body.try_next().await.map_err(|e| storage_err_src("get_object body", e))?
+        {
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| storage_err_src("download_file write", e))?;
+            written += u64::try_from(chunk.len()).unwrap_or(u64::MAX);
+        }
+        file.flush().await.map_err(|e| storage_err_src("download_file write", e))?;
+        Ok(written)
+    }
+
+    async fn get_object(&self, key: &str) -> Result<GetObjectOutput> {
+        validate_key(key)?;
+        self.client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| {
+                // A missing key is a typed `NoSuchKey` service error. The
+                // `SdkError` Display does not contain the code (it is just
+                // "service error"), so detect it structurally on the typed
+                // error rather than by string-matching (H40).
+                if e.as_service_error()
+                    .is_some_and(aws_sdk_s3::operation::get_object::GetObjectError::is_no_such_key)
+                {
+                    FraiseQLError::File(FileError::NotFound {
+                        id: key.to_string(),
+                    })
+                } else {
+                    storage_err_src("get_object", e)
+                }
+            })
+    }
+
     /// Deletes the object at the given key.
     ///
     /// # Errors
@@ -19,6 +19,9 @@ use crate::config::BucketConfig;
 /// - A `TypeDefinition` for the storage object type
 /// - A `MutationDefinition` for presigned upload URL generation
 /// - A `QueryDefinition` for listing objects
+///
+/// When any bucket is configured, the `fileUrl(id)` query and its
+/// `SignedFileUrl` return type are emitted once, shared by all buckets.
 pub struct StorageSchemaTypes;
 
 /// All generated schema entries for a set of storage buckets.
@@ -37,6 +40,8 @@ impl StorageSchemaTypes {
     ///
     /// Returns empty vectors when `buckets` is empty — no storage types are
     /// emitted unless at least one bucket is configured.
+    /// Otherwise the bucket-independent `fileUrl` query and `SignedFileUrl`
+    /// type follow the per-bucket entries.
     ///
     /// # Example
     ///
@@ -54,9 +59,9 @@ impl StorageSchemaTypes {
     /// }];
     ///
     /// let entries = StorageSchemaTypes::generate(&buckets);
-    /// assert_eq!(entries.types.len(), 1);
+    /// assert_eq!(entries.types.len(), 2);
     /// assert_eq!(entries.mutations.len(), 1);
-    /// assert_eq!(entries.queries.len(), 1);
+    /// assert_eq!(entries.queries.len(), 2);
     /// ```
     #[must_use]
     pub fn generate(buckets: &[BucketConfig]) -> StorageSchemaEntries {
@@ -66,6 +71,10 @@ impl StorageSchemaTypes {
             entries.mutations.push(Self::upload_url_mutation(bucket));
             entries.queries.push(Self::list_query(bucket));
         }
+        if !buckets.is_empty() {
+            entries.types.push(Self::signed_file_url_type());
+            entries.queries.push(Self::file_url_query());
+        }
         entries
     }
 
@@ -199,6 +208,61 @@ impl StorageSchemaTypes {
         })
     }
 
+    /// Generate the `fileUrl` query.
+    ///
+    /// Creates a `QueryDefinition` JSON object that resolves a stored object id
+    /// to a time-limited download URL: a provider presigned URL on S3-style
+    /// backends, otherwise an HMAC-signed URL for the file download route. The
+    /// caller must be allowed to read the object.
+    #[must_use]
+    pub fn file_url_query() -> Value {
+        json!({
+            "name": "fileUrl",
+            "description": "Time-limited signed download URL for a stored file",
+            "return_type": "SignedFileUrl",
+            "returns_list": false,
+            "arguments": [
+                {
+                    "name": "id",
+                    "arg_type": "ID",
+                    "description": "Storage object id"
+                },
+                {
+                    "name": "expires_in",
+                    "arg_type": "Int",
+                    "nullable": true,
+                    "description": "URL validity in seconds (default 3600, max 86400)"
+                }
+            ]
+        })
+    }
+
+    /// Generate the `SignedFileUrl` type returned by `fileUrl`.
+    #[must_use]
+    pub fn signed_file_url_type() -> Value {
+        json!({
+            "name": "SignedFileUrl",
+            "description": "Time-limited download URL for a stored file",
+            "fields": [
+                {
+                    "name": "url",
+                    "field_type": "String",
+                    "description": "Signed download URL"
+                },
+                {
+                    "name": "expires_at",
+                    "field_type": "DateTime",
+                    "description": "When the URL stops being accepted"
+                },
+                {
+                    "name": "method",
+                    "field_type": "String",
+                    "description": "HTTP method the URL is valid for"
+                }
+            ]
+        })
+    }
+
     /// Convert a bucket name to a valid GraphQL type name.
     ///
     /// Converts `snake_case` or kebab-case bucket names to `PascalCase` for use in
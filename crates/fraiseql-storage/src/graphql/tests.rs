@@ -129,12 +129,42 @@ fn test_generate_produces_entries_per_bucket() {
     ];
 
     let entries = StorageSchemaTypes::generate(&buckets);
-    assert_eq!(entries.types.len(), 2);
+    assert_eq!(entries.types.len(), 3);
     assert_eq!(entries.mutations.len(), 2);
-    assert_eq!(entries.queries.len(), 2);
+    assert_eq!(entries.queries.len(), 3);
 
     assert_eq!(entries.types[0]["name"], "AvatarsStorageObject");
     assert_eq!(entries.types[1]["name"], "DocumentsStorageObject");
+    assert_eq!(entries.types[2]["name"], "SignedFileUrl");
+    assert_eq!(entries.queries[2]["name"], "fileUrl");
+}
+
+// --- fileUrl ---
+
+#[test]
+fn test_file_url_query_definition() {
+    let query = StorageSchemaTypes::file_url_query();
+
+    assert_eq!(query["name"], "fileUrl");
+    assert_eq!(query["return_type"], "SignedFileUrl");
+    assert!(!query["returns_list"].as_bool().unwrap());
+
+    let args = query["arguments"].as_array().unwrap();
+    let arg_names: Vec<_> = args.iter().filter_map(|a| a["name"].as_str()).collect();
+    assert_eq!(arg_names, &["id", "expires_in"]);
+    assert_eq!(args[0]["arg_type"], "ID");
+    assert!(args[0].get("nullable").is_none());
+    assert!(args[1]["nullable"].as_bool().unwrap());
+}
+
+#[test]
+fn test_signed_file_url_type_definition() {
+    let type_def = StorageSchemaTypes::signed_file_url_type();
+
+    assert_eq!(type_def["name"], "SignedFileUrl");
+    let fields = type_def["fields"].as_array().unwrap();
+    let field_names: Vec<_> = fields.iter().filter_map(|f| f["name"].as_str()).collect();
+    assert_eq!(field_names, &["url", "expires_at", "method"]);
 }
 
 // --- multi-bucket ---
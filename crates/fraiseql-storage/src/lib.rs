@@ -12,7 +12,8 @@
 //! - **Config**: Bucket configuration with size limits, MIME type restrictions
 //! - **Metadata**: SQL repository for object metadata (Postgres-only)
 //! - **RLS**: Row-level security enforcement for access control
-//! - **Routes**: HTTP handlers for `PUT`, `GET`, `DELETE`, `LIST`
+//! - **Routes**: HTTP handlers for `PUT`, `GET`, `DELETE`, `LIST`, and ranged file downloads
+//! - **Signing**: HMAC-signed, time-limited file URLs for backends without native presigning
 
 #![warn(missing_docs)]
 // Wave 9 (Q4): pilot crate #3 for the workspace `clippy::indexing_slicing`
@@ -28,6 +29,7 @@ pub mod migrations;
 pub mod rls;
 pub mod routes;
 pub mod service;
+pub mod signing;
 pub mod transforms;
 
 // Re-exports for convenience
@@ -40,7 +42,7 @@ pub use backend::PresignCapable;
 #[cfg(feature = "aws-s3")]
 pub use backend::S3Backend;
 pub use backend::{
-    LocalBackend, PresignedUrl, StorageBackend, create_backend,
+    LocalBackend, ObjectStream, PresignedUrl, StorageBackend, create_backend,
     types::{ListResult, ObjectInfo, ObjectMetadata, PutResult, StorageObject},
     validate_key,
 };
@@ -50,6 +52,7 @@ pub use metadata::{NewStorageObject, StorageMetadataRepo, StorageMetadataRow};
 pub use rls::{STORAGE_ADMIN_ROLE, StorageRlsEvaluator};
 pub use routes::{StorageState, StorageUser, storage_router};
 pub use service::BucketService;
+pub use signing::UrlSigner;
 #[cfg(feature = "transforms")]
 pub use transforms::{
     ImageTransformer, OutputFormat, TransformCache, TransformOutput, TransformParams,
@@ -136,6 +136,31 @@ impl StorageMetadataRepo {
         Ok(row.map(Into::into))
     }
 
+    /// Look up an object by its primary key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the database query fails.
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<StorageMetadataRow>, FraiseQLError> {
+        let row = sqlx::query_as::<_, MetadataQueryRow>(
+            "SELECT pk_storage_object, bucket, key, content_type, \
+                    size_bytes, etag, owner_id, created_at, updated_at \
+             FROM _fraiseql_storage_objects \
+             WHERE pk_storage_object = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            FraiseQLError::File(FileError::Backend {
+                message: e.to_string(),
+                source:  Some(Box::new(e)),
+            })
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
     /// Delete an object metadata row by bucket and key.
     ///
     /// Returns `true` if a row was actually deleted, `false` if no matching row existed.
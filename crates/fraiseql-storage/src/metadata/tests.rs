@@ -88,6 +88,19 @@ async fn test_get_metadata_by_bucket_and_key() {
     assert_eq!(row.owner_id.as_deref(), Some("user-1"));
 }
 
+#[tokio::test]
+async fn test_get_metadata_by_id() {
+    let (pool, _container) = setup_pg().await;
+    let repo = StorageMetadataRepo::new(pool);
+
+    let id = repo.insert(&sample_object("avatars", "photo.png")).await.unwrap();
+
+    let row = repo.get_by_id(id).await.unwrap().expect("should find the inserted row");
+    assert_eq!(row.pk_storage_object, id);
+    assert_eq!(row.key, "photo.png");
+    assert!(repo.get_by_id(id + 1).await.unwrap().is_none());
+}
+
 #[tokio::test]
 async fn test_delete_metadata_removes_row() {
     let (pool, _container) = setup_pg().await;
@@ -6,12 +6,15 @@
 //! - `DELETE /storage/v1/object/{bucket}/{*key}` — delete
 //! - `GET /storage/v1/list/{bucket}` — list
 //! - `POST /storage/v1/presign/{bucket}/{*key}` — presigned URL
+//! - `GET /storage/v1/file/{id}` — streaming download by object id (range requests)
+//! - `GET /storage/v1/file/{id}/url` — time-limited signed URL (the `fileUrl(id)` field)
 //! - `GET /storage/v1/render/{bucket}/{*key}` — image transform
 
+mod range;
 #[cfg(test)]
 mod tests;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use axum::{
     Extension, Router,
@@ -22,16 +25,18 @@ use axum::{
     routing::{get, post, put},
 };
 use bytes::Bytes;
+use chrono::Utc;
 use fraiseql_error::{FileError, FraiseQLError};
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "aws-s3")]
-use crate::PresignedUrl;
+use self::range::ByteRange;
 use crate::{
+    PresignedUrl,
     backend::StorageBackend,
     config::BucketConfig,
     metadata::{NewStorageObject, StorageMetadataRepo, StorageMetadataRow},
     rls::StorageRlsEvaluator,
+    signing::UrlSigner,
 };
 
 // ---------------------------------------------------------------------------
@@ -49,6 +54,68 @@ pub struct StorageState {
     pub rls:      StorageRlsEvaluator,
     /// Bucket configurations keyed by bucket name.
     pub buckets:  Arc<HashMap<String, BucketConfig>>,
+    /// Signer for file URLs on backends without native presigning. `None`
+    /// disables signed URLs for those backends.
+    pub signer:   Option<Arc<UrlSigner>>,
+}
+
+/// Longest validity a signed file URL may be issued for.
+pub const MAX_FILE_URL_EXPIRY: Duration = Duration::from_secs(86_400);
+
+impl StorageState {
+    /// Resolve a stored object id to a time-limited download URL.
+    ///
+    /// This backs the `fileUrl(id)` field. The caller must be allowed to read
+    /// the object under the bucket's RLS policy (for private buckets, its
+    /// owner or a storage admin). S3 and S3-compatible backends return their
+    /// own presigned URL; every other backend returns a URL to the file
+    /// download route signed by [`Self::signer`]. `expires_in` is capped at
+    /// [`MAX_FILE_URL_EXPIRY`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` with `NotFound` for an unknown id,
+    /// `PermissionDenied` when the caller may not read the object, and
+    /// `Unsupported` when the backend cannot presign and no signer is
+    /// configured.
+    pub async fn file_url(
+        &self,
+        user: &StorageUser,
+        id: i64,
+        expires_in: Duration,
+    ) -> Result<PresignedUrl, FraiseQLError> {
+        let not_found = || FraiseQLError::File(FileError::NotFound { id: id.to_string() });
+        let row = self.metadata.get_by_id(id).await?.ok_or_else(not_found)?;
+        let bucket = self.buckets.get(&row.bucket).ok_or_else(not_found)?;
+        if !self.rls.can_read(user.user_id.as_deref(), &user.roles, bucket, &row) {
+            return Err(FraiseQLError::File(FileError::PermissionDenied {
+                message: format!("user may not read storage object {id}"),
+                source:  None,
+            }));
+        }
+        let expires_in = expires_in.min(MAX_FILE_URL_EXPIRY);
+
+        #[cfg(feature = "aws-s3")]
+        match self
+            .backend
+            .presign_get(&backend_object_key(&row.bucket, &row.key), expires_in)
+            .await
+        {
+            Ok(url) => return Ok(url),
+            Err(FraiseQLError::File(FileError::Unsupported { .. })) => {},
+            Err(e) => return Err(e),
+        }
+
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            FraiseQLError::File(FileError::Unsupported {
+                message: "signed file URLs need a URL signing secret for this storage backend"
+                    .to_string(),
+            })
+        })?;
+        let expires_at = Utc::now()
+            + chrono::Duration::from_std(expires_in).unwrap_or_else(|_| chrono::Duration::zero());
+        Ok(signer.signed_url(id, expires_at))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -83,7 +150,6 @@ pub struct PresignResponse {
     pub method:     String,
 }
 
-#[cfg(feature = "aws-s3")]
 impl From<PresignedUrl> for PresignResponse {
     fn from(url: PresignedUrl) -> Self {
         Self {
@@ -105,6 +171,23 @@ pub struct ListQuery {
     pub offset: Option<u32>,
 }
 
+/// Query parameters for the file URL endpoint.
+#[derive(Debug, Deserialize)]
+pub struct FileUrlQuery {
+    /// URL validity duration in seconds (default: 3600, max: 86400).
+    #[serde(default = "default_expiry_secs")]
+    pub expires_in_secs: u64,
+}
+
+/// Query parameters carried by a signed file URL.
+#[derive(Debug, Deserialize)]
+pub struct FileDownloadQuery {
+    /// Expiry of the signature (Unix timestamp, seconds).
+    pub expires:   Option<i64>,
+    /// Hex-encoded signature issued by [`UrlSigner`].
+    pub signature: Option<String>,
+}
+
 /// User identity extracted from request (populated by auth middleware).
 #[derive(Debug, Clone, Default)]
 pub struct StorageUser {
@@ -165,6 +248,8 @@ pub fn storage_router(state: StorageState) -> Router {
         )
         .route("/storage/v1/list/{bucket}", get(list_handler))
         .route("/storage/v1/presign/{bucket}/{*key}", post(presign_handler))
+        .route("/storage/v1/file/{id}", get(file_download_handler))
+        .route("/storage/v1/file/{id}/url", get(file_url_handler))
         .layer(DefaultBodyLimit::max(body_limit))
         .with_state(state)
 }
@@ -320,45 +405,120 @@ async fn get_handler(
     // Download from backend (#336: bucket-scoped key).
     match state.backend.download(&backend_object_key(&bucket_name, &key)).await {
         Ok(data) => {
-            let mut headers = HeaderMap::new();
-            if let Ok(ct) = row.content_type.parse() {
-                headers.insert(header::CONTENT_TYPE, ct);
-            }
-            if let Some(ref etag) = row.etag {
-                if let Ok(val) = etag.parse() {
-                    headers.insert(header::ETAG, val);
-                }
-            }
-            // #608: branch the cache directive on the bucket's access mode. A
-            // `Private` object's per-request RLS decision (`can_read`, above) is
-            // per-row, so a URL-keyed shared cache (CDN/reverse/forward proxy)
-            // cannot represent the boundary — advertising `public` would let it
-            // store the private object and serve it to unauthenticated third
-            // parties, and would delay revocation for up to `max-age`. `no-store`
-            // is the conservative directive; `PublicRead` stays publicly cacheable.
-            let cache_control = match bucket.access {
-                crate::config::BucketAccess::Private => "private, no-store",
-                crate::config::BucketAccess::PublicRead => "public, max-age=3600",
-            };
-            headers.insert(header::CACHE_CONTROL, HeaderValue::from_static(cache_control));
-            // #337: defang stored content. `nosniff` stops browsers from
-            // MIME-sniffing the body into an executable type. The default
-            // `attachment` disposition forces a download rather than inline
-            // rendering; a bucket may opt into inline rendering, but content
-            // types that can execute as active content stay attachments.
-            headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
-            let disposition = if bucket.serve_inline && !is_inline_unsafe(&row.content_type) {
-                "inline"
+            (StatusCode::OK, download_headers(bucket, &row), Body::from(data)).into_response()
+        },
+        Err(e) => storage_error_response(&e),
+    }
+}
+
+/// Issue a time-limited URL for a stored object (REST form of `fileUrl(id)`).
+#[tracing::instrument(skip(state, user, query))]
+async fn file_url_handler(
+    State(state): State<StorageState>,
+    user: Option<Extension<StorageUser>>,
+    Path(id): Path<i64>,
+    Query(query): Query<FileUrlQuery>,
+) -> Response {
+    if query.expires_in_secs == 0 || query.expires_in_secs > MAX_FILE_URL_EXPIRY.as_secs() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "invalid_expiry",
+            "expires_in_secs must be between 1 and 86400",
+        );
+    }
+    let user = user.map(|Extension(u)| u).unwrap_or_default();
+    match state.file_url(&user, id, Duration::from_secs(query.expires_in_secs)).await {
+        Ok(url) => axum::Json(PresignResponse::from(url)).into_response(),
+        Err(FraiseQLError::File(FileError::PermissionDenied { .. })) => {
+            tracing::warn!(id, user_id = ?user.user_id, "Storage file URL denied");
+            if user.user_id.is_none() {
+                error_response(StatusCode::UNAUTHORIZED, "unauthorized", "Authentication required")
             } else {
-                "attachment"
-            };
-            headers.insert(header::CONTENT_DISPOSITION, HeaderValue::from_static(disposition));
-            (StatusCode::OK, headers, Body::from(data)).into_response()
+                error_response(StatusCode::FORBIDDEN, "forbidden", "Access denied")
+            }
         },
         Err(e) => storage_error_response(&e),
     }
 }
 
+/// Stream a stored object by id, honouring a single-range `Range` header.
+///
+/// The request is authorized either by a valid, unexpired signature from
+/// [`StorageState::file_url`] (`?expires=…&signature=…`) or, without one, by
+/// the caller's own read access under the bucket's RLS policy. A signature
+/// that is present but invalid is rejected outright rather than falling back
+/// to the caller's identity.
+#[tracing::instrument(skip(state, user, query, headers))]
+async fn file_download_handler(
+    State(state): State<StorageState>,
+    user: Option<Extension<StorageUser>>,
+    Path(id): Path<i64>,
+    Query(query): Query<FileDownloadQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let row = match state.metadata.get_by_id(id).await {
+        Ok(Some(row)) => row,
+        Ok(None) => return error_response(StatusCode::NOT_FOUND, "not_found", "Object not found"),
+        Err(e) => return storage_error_response(&e),
+    };
+    let Some(bucket) = state.buckets.get(&row.bucket) else {
+        return error_response(StatusCode::NOT_FOUND, "not_found", "Object not found");
+    };
+
+    let authorized = match (query.expires, query.signature.as_deref()) {
+        (None, None) => {
+            let user = user.map(|Extension(u)| u).unwrap_or_default();
+            state.rls.can_read(user.user_id.as_deref(), &user.roles, bucket, &row)
+        },
+        (Some(expires), Some(signature)) => state
+            .signer
+            .as_ref()
+            .is_some_and(|signer| signer.verify(id, expires, signature, Utc::now())),
+        _ => false,
+    };
+    if !authorized {
+        tracing::warn!(id, signed = query.signature.is_some(), "Storage file download denied");
+        return error_response(StatusCode::FORBIDDEN, "forbidden", "Access denied");
+    }
+
+    let total = u64::try_from(row.size_bytes).unwrap_or(0);
+    let requested = headers.get(header::RANGE).and_then(|v| v.to_str().ok());
+    let (status, start, len) = match ByteRange::resolve(requested, total) {
+        ByteRange::Full => (StatusCode::OK, 0, total),
+        ByteRange::Partial { start, end } => (StatusCode::PARTIAL_CONTENT, start, end - start + 1),
+        ByteRange::Unsatisfiable => {
+            let mut response = error_response(
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                "range_not_satisfiable",
+                "Requested range is outside the object",
+            );
+            if let Ok(val) = format!("bytes */{total}").parse() {
+                response.headers_mut().insert(header::CONTENT_RANGE, val);
+            }
+            return response;
+        },
+    };
+
+    let stream = match state
+        .backend
+        .download_stream(&backend_object_key(&row.bucket, &row.key), start, len)
+        .await
+    {
+        Ok(stream) => stream,
+        Err(e) => return storage_error_response(&e),
+    };
+
+    let mut response_headers = download_headers(bucket, &row);
+    response_headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(len));
+    if status == StatusCode::PARTIAL_CONTENT {
+        if let Ok(val) = format!("bytes {start}-{}/{total}", start + len - 1).parse() {
+            response_headers.insert(header::CONTENT_RANGE, val);
+        }
+    }
+    (status, response_headers, Body::from_stream(stream)).into_response()
+}
+
 /// Delete an object.
 #[tracing::instrument(skip(state, user), fields(bucket = %bucket_name, key = %key))]
 async fn delete_handler(
@@ -615,6 +775,45 @@ impl From<&StorageMetadataRow> for ListItem {
     }
 }
 
+/// Response headers for an object download: content type, entity tag, and
+/// the cache and content-safety directives for the object's bucket.
+fn download_headers(bucket: &BucketConfig, row: &StorageMetadataRow) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Ok(ct) = row.content_type.parse() {
+        headers.insert(header::CONTENT_TYPE, ct);
+    }
+    if let Some(ref etag) = row.etag {
+        if let Ok(val) = etag.parse() {
+            headers.insert(header::ETAG, val);
+        }
+    }
+    // #608: branch the cache directive on the bucket's access mode. A
+    // `Private` object's per-request RLS decision (`can_read`) is per-row, so a
+    // URL-keyed shared cache (CDN/reverse/forward proxy) cannot represent the
+    // boundary — advertising `public` would let it store the private object and
+    // serve it to unauthenticated third parties, and would delay revocation for
+    // up to `max-age`. `no-store` is the conservative directive; `PublicRead`
+    // stays publicly cacheable.
+    let cache_control = match bucket.access {
+        crate::config::BucketAccess::Private => "private, no-store",
+        crate::config::BucketAccess::PublicRead => "public, max-age=3600",
+    };
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static(cache_control));
+    // #337: defang stored content. `nosniff` stops browsers from MIME-sniffing
+    // the body into an executable type. The default `attachment` disposition
+    // forces a download rather than inline rendering; a bucket may opt into
+    // inline rendering, but content types that can execute as active content
+    // stay attachments.
+    headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    let disposition = if bucket.serve_inline && !is_inline_unsafe(&row.content_type) {
+        "inline"
+    } else {
+        "attachment"
+    };
+    headers.insert(header::CONTENT_DISPOSITION, HeaderValue::from_static(disposition));
+    headers
+}
+
 /// Build a JSON error response.
 fn error_response(status: StatusCode, code: &str, message: &str) -> Response {
     let body = serde_json::json!({
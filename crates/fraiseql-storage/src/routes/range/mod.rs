@@ -0,0 +1,71 @@
+//! HTTP `Range` request resolution for file downloads (RFC 9110 §14).
+//!
+//! Only a single `bytes` range is honoured. Multi-range requests, other units
+//! and malformed values fall back to the full object, which the RFC allows a
+//! server to do for any range it chooses not to serve.
+
+#[cfg(test)]
+mod tests;
+
+/// How to answer a download given its `Range` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ByteRange {
+    /// Serve the whole object (`200 OK`).
+    Full,
+    /// Serve bytes `start..=end` (`206 Partial Content`).
+    Partial {
+        /// First byte offset.
+        start: u64,
+        /// Last byte offset, inclusive.
+        end:   u64,
+    },
+    /// No requested byte exists (`416 Range Not Satisfiable`).
+    Unsatisfiable,
+}
+
+impl ByteRange {
+    /// Resolve a `Range` header value against an object of `total` bytes.
+    pub(crate) fn resolve(header: Option<&str>, total: u64) -> Self {
+        let Some(spec) = header.and_then(|h| h.trim().strip_prefix("bytes=")) else {
+            return Self::Full;
+        };
+        if spec.contains(',') {
+            return Self::Full;
+        }
+        let Some((first, last)) = spec.trim().split_once('-') else {
+            return Self::Full;
+        };
+        match (first.trim(), last.trim()) {
+            // Suffix range: the last `n` bytes.
+            ("", suffix) => match suffix.parse::<u64>() {
+                Ok(0) => Self::Unsatisfiable,
+                Ok(_) if total == 0 => Self::Unsatisfiable,
+                Ok(n) => Self::Partial {
+                    start: total.saturating_sub(n),
+                    end:   total - 1,
+                },
+                Err(_) => Self::Full,
+            },
+            (first, last) => {
+                let Ok(start) = first.parse::<u64>() else {
+                    return Self::Full;
+                };
+                let end = if last.is_empty() {
+                    u64::MAX
+                } else {
+                    match last.parse::<u64>() {
+                        Ok(end) if end >= start => end,
+                        _ => return Self::Full,
+                    }
+                };
+                if start >= total {
+                    return Self::Unsatisfiable;
+                }
+                Self::Partial {
+                    start,
+                    end: end.min(total - 1),
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::ByteRange;
+
+#[test]
+fn missing_or_foreign_unit_serves_full_object() {
+    assert_eq!(ByteRange::resolve(None, 10), ByteRange::Full);
+    assert_eq!(ByteRange::resolve(Some("items=0-1"), 10), ByteRange::Full);
+}
+
+#[test]
+fn bounded_range_is_clamped_to_object() {
+    assert_eq!(
+        ByteRange::resolve(Some("bytes=2-5"), 10),
+        ByteRange::Partial { start: 2, end: 5 }
+    );
+    assert_eq!(
+        ByteRange::resolve(Some("bytes=8-100"), 10),
+        ByteRange::Partial { start: 8, end: 9 }
+    );
+    assert_eq!(
+        ByteRange::resolve(Some("bytes=3-"), 10),
+        ByteRange::Partial { start: 3, end: 9 }
+    );
+}
+
+#[test]
+fn suffix_range_serves_the_tail() {
+    assert_eq!(
+        ByteRange::resolve(Some("bytes=-3"), 10),
+        ByteRange::Partial { start: 7, end: 9 }
+    );
+    assert_eq!(
+        ByteRange::resolve(Some("bytes=-50"), 10),
+        ByteRange::Partial { start: 0, end: 9 }
+    );
+    assert_eq!(ByteRange::resolve(Some("bytes=-0"), 10), ByteRange::Unsatisfiable);
+    assert_eq!(ByteRange::resolve(Some("bytes=-5"), 0), ByteRange::Unsatisfiable);
+}
+
+#[test]
+fn range_starting_past_the_end_is_unsatisfiable() {
+    assert_eq!(ByteRange::resolve(Some("bytes=10-"), 10), ByteRange::Unsatisfiable);
+    assert_eq!(ByteRange::resolve(Some("bytes=0-"), 0), ByteRange::Unsatisfiable);
+}
+
+#[test]
+fn malformed_and_multi_ranges_fall_back_to_full() {
+    assert_eq!(ByteRange::resolve(Some("bytes=5-2"), 10), ByteRange::Full);
+    assert_eq!(ByteRange::resolve(Some("bytes=a-b"), 10), ByteRange::Full);
+    assert_eq!(ByteRange::resolve(Some("bytes=0-1,4-5"), 10), ByteRange::Full);
+    assert_eq!(ByteRange::resolve(Some("bytes=7"), 10), ByteRange::Full);
+}
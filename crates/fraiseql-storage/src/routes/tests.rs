@@ -16,6 +16,7 @@ use crate::{
     config::{BucketAccess, BucketConfig},
     metadata::StorageMetadataRepo,
     rls::StorageRlsEvaluator,
+    signing::{MIN_SECRET_BYTES, UrlSigner},
 };
 
 /// Create a test state with a local backend and real metadata repo.
@@ -64,6 +65,7 @@ async fn test_state(bucket_name: &str, access: BucketAccess) -> (StorageState, i
         metadata: Arc::new(StorageMetadataRepo::new(pool)),
         rls:      StorageRlsEvaluator::new(),
         buckets:  Arc::new(buckets),
+        signer:   Some(Arc::new(UrlSigner::new(vec![7_u8; MIN_SECRET_BYTES]).unwrap())),
     };
 
     (state, (svc, tmp))
@@ -962,3 +964,173 @@ async fn test_presign_download_other_users_object_is_forbidden_on_private_bucket
     // Cross-user presign(download) on a Private bucket must yield 403.
     assert_eq!(resp.status(), StatusCode::FORBIDDEN);
 }
+
+// ---------------------------------------------------------------------------
+// Signed file URLs and ranged downloads by id
+// ---------------------------------------------------------------------------
+
+/// Upload `body` as `test-user` and return the new object's id.
+async fn upload_owned(state: &StorageState, bucket: &str, key: &str, body: &[u8]) -> i64 {
+    let resp = authenticated_router(state.clone())
+        .oneshot(put_req(bucket, key, body))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    state.metadata.get(bucket, key).await.unwrap().unwrap().pk_storage_object
+}
+
+fn get_req(uri: &str) -> Request<Body> {
+    Request::builder().method("GET").uri(uri).body(Body::empty()).unwrap()
+}
+
+fn ranged_get_req(uri: &str, range: &str) -> Request<Body> {
+    Request::builder()
+        .method("GET")
+        .uri(uri)
+        .header(header::RANGE, range)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn file_download_by_id_serves_full_object_and_ranges() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "digits.txt", b"0123456789").await;
+    let uri = format!("/storage/v1/file/{id}");
+
+    let resp = authenticated_router(state.clone()).oneshot(get_req(&uri)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get(header::ACCEPT_RANGES).unwrap(), "bytes");
+    assert_eq!(resp.headers().get(header::CONTENT_LENGTH).unwrap(), "10");
+    let body = axum::body::to_bytes(resp.into_body(), 1024).await.unwrap();
+    assert_eq!(&body[..], b"0123456789");
+
+    let resp = authenticated_router(state.clone())
+        .oneshot(ranged_get_req(&uri, "bytes=2-5"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(resp.headers().get(header::CONTENT_RANGE).unwrap(), "bytes 2-5/10");
+    assert_eq!(resp.headers().get(header::CONTENT_LENGTH).unwrap(), "4");
+    let body = axum::body::to_bytes(resp.into_body(), 1024).await.unwrap();
+    assert_eq!(&body[..], b"2345");
+
+    let resp = authenticated_router(state.clone())
+        .oneshot(ranged_get_req(&uri, "bytes=-3"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    let body = axum::body::to_bytes(resp.into_body(), 1024).await.unwrap();
+    assert_eq!(&body[..], b"789");
+}
+
+#[tokio::test]
+async fn file_download_range_past_end_is_416() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "digits.txt", b"0123456789").await;
+
+    let resp = authenticated_router(state)
+        .oneshot(ranged_get_req(&format!("/storage/v1/file/{id}"), "bytes=10-"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(resp.headers().get(header::CONTENT_RANGE).unwrap(), "bytes */10");
+}
+
+#[tokio::test]
+async fn file_download_by_id_is_owner_only_on_private_bucket() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "owned.txt", b"owned").await;
+    let uri = format!("/storage/v1/file/{id}");
+
+    let other = router_for(state.clone(), "other-user", &["user"]);
+    assert_eq!(other.oneshot(get_req(&uri)).await.unwrap().status(), StatusCode::FORBIDDEN);
+
+    let anon = anonymous_router(state.clone());
+    assert_eq!(anon.oneshot(get_req(&uri)).await.unwrap().status(), StatusCode::FORBIDDEN);
+
+    let missing = authenticated_router(state);
+    assert_eq!(
+        missing
+            .oneshot(get_req(&format!("/storage/v1/file/{}", id + 1)))
+            .await
+            .unwrap()
+            .status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn signed_file_url_downloads_without_authentication() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "report.txt", b"quarterly").await;
+
+    let resp = authenticated_router(state.clone())
+        .oneshot(get_req(&format!("/storage/v1/file/{id}/url?expires_in_secs=60")))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(resp.into_body(), 4096).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["method"], "GET");
+    let url = json["url"].as_str().unwrap().to_string();
+    assert!(url.starts_with(&format!("/storage/v1/file/{id}?expires=")), "url was {url}");
+
+    let resp = anonymous_router(state).oneshot(get_req(&url)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(resp.into_body(), 1024).await.unwrap();
+    assert_eq!(&body[..], b"quarterly");
+}
+
+#[tokio::test]
+async fn tampered_or_foreign_signature_is_rejected() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "a.txt", b"a").await;
+    let other_id = upload_owned(&state, "docs", "b.txt", b"b").await;
+
+    let signer = state.signer.clone().unwrap();
+    let expires = (chrono::Utc::now() + chrono::Duration::minutes(5)).timestamp();
+    let signature = signer.sign(id, expires);
+
+    // A signature for one id does not unlock another.
+    let uri = format!("/storage/v1/file/{other_id}?expires={expires}&signature={signature}");
+    let resp = anonymous_router(state.clone()).oneshot(get_req(&uri)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    // Extending the expiry invalidates the signature.
+    let uri = format!("/storage/v1/file/{id}?expires={}&signature={signature}", expires + 60);
+    let resp = anonymous_router(state.clone()).oneshot(get_req(&uri)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    // An invalid signature does not fall back to the owner's own access.
+    let uri = format!("/storage/v1/file/{id}?expires={expires}&signature=00");
+    let resp = authenticated_router(state).oneshot(get_req(&uri)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn file_url_for_foreign_object_is_forbidden() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "owned.txt", b"owned").await;
+    let uri = format!("/storage/v1/file/{id}/url");
+
+    let other = router_for(state.clone(), "other-user", &["user"]);
+    assert_eq!(other.oneshot(get_req(&uri)).await.unwrap().status(), StatusCode::FORBIDDEN);
+
+    let anon = anonymous_router(state);
+    assert_eq!(anon.oneshot(get_req(&uri)).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn file_url_rejects_out_of_range_expiry() {
+    let (state, _keep) = test_state("docs", BucketAccess::Private).await;
+    let id = upload_owned(&state, "docs", "owned.txt", b"owned").await;
+
+    for secs in [0, 86_401] {
+        let resp = authenticated_router(state.clone())
+            .oneshot(get_req(&format!("/storage/v1/file/{id}/url?expires_in_secs={secs}")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "expires_in_secs={secs}");
+    }
+}
@@ -0,0 +1,106 @@
+//! HMAC-signed, time-limited file URLs.
+//!
+//! S3 and the S3-compatible providers hand out their own presigned URLs. Every
+//! other backend is served through FraiseQL's file download route
+//! (`GET /storage/v1/file/{id}`), and [`UrlSigner`] makes those URLs
+//! self-authorizing: the route accepts a request carrying an unexpired
+//! signature for the object id without an authenticated caller, because a
+//! signature is only issued after the caller passed the read check.
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use fraiseql_error::{FileError, FraiseQLError, Result};
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+
+use crate::backend::PresignedUrl;
+
+/// Minimum accepted signing-secret length, in bytes.
+pub const MIN_SECRET_BYTES: usize = 32;
+
+/// Path of the file download route for an object id.
+#[must_use]
+pub fn file_download_path(id: i64) -> String {
+    format!("/storage/v1/file/{id}")
+}
+
+/// Signs and verifies download URLs for stored objects.
+pub struct UrlSigner {
+    secret: Vec<u8>,
+}
+
+impl fmt::Debug for UrlSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UrlSigner").field("secret", &"[REDACTED]").finish()
+    }
+}
+
+impl UrlSigner {
+    /// Create a signer from a shared secret.
+    ///
+    /// The secret must be stable across restarts and shared by every instance
+    /// serving the download route, or URLs signed by one will not verify on
+    /// another.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FraiseQLError::File` if the secret is shorter than
+    /// [`MIN_SECRET_BYTES`].
+    pub fn new(secret: impl Into<Vec<u8>>) -> Result<Self> {
+        let secret = secret.into();
+        if secret.len() < MIN_SECRET_BYTES {
+            return Err(FraiseQLError::File(FileError::Backend {
+                message: format!(
+                    "URL signing secret must be at least {MIN_SECRET_BYTES} bytes, got {}",
+                    secret.len()
+                ),
+                source:  None,
+            }));
+        }
+        Ok(Self { secret })
+    }
+
+    /// Hex-encoded signature over `id` and the `expires` Unix timestamp.
+    #[must_use]
+    pub fn sign(&self, id: i64, expires: i64) -> String {
+        hex::encode(self.mac(id, expires).finalize().into_bytes())
+    }
+
+    /// Whether `signature` is valid for `id` and `expires`, and `expires` has
+    /// not passed at `now`. The comparison is constant-time.
+    #[must_use]
+    pub fn verify(&self, id: i64, expires: i64, signature: &str, now: DateTime<Utc>) -> bool {
+        if expires < now.timestamp() {
+            return false;
+        }
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        self.mac(id, expires).verify_slice(&signature).is_ok()
+    }
+
+    /// A signed download URL for `id`, valid until `expires_at`.
+    ///
+    /// The URL is relative to the server's origin.
+    #[must_use]
+    pub fn signed_url(&self, id: i64, expires_at: DateTime<Utc>) -> PresignedUrl {
+        let expires = expires_at.timestamp();
+        let url = format!(
+            "{}?expires={expires}&signature={}",
+            file_download_path(id),
+            self.sign(id, expires)
+        );
+        PresignedUrl::new(url, expires_at, "GET")
+    }
+
+    fn mac(&self, id: i64, expires: i64) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts any key length");
+        mac.update(format!("GET {}\n{expires}", file_download_path(id)).as_bytes());
+        mac
+    }
+}
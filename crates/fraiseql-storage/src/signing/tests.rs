@@ -0,0 +1,59 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
+
+use chrono::{Duration, TimeZone, Utc};
+
+use super::{MIN_SECRET_BYTES, UrlSigner, file_download_path};
+
+fn signer() -> UrlSigner {
+    UrlSigner::new(vec![7_u8; MIN_SECRET_BYTES]).unwrap()
+}
+
+#[test]
+fn short_secret_is_rejected() {
+    assert!(UrlSigner::new(vec![7_u8; MIN_SECRET_BYTES - 1]).is_err());
+}
+
+#[test]
+fn signature_verifies_until_expiry() {
+    let signer = signer();
+    let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let expires = (now + Duration::seconds(60)).timestamp();
+    let signature = signer.sign(42, expires);
+
+    assert!(signer.verify(42, expires, &signature, now));
+    assert!(signer.verify(42, expires, &signature, now + Duration::seconds(60)));
+    assert!(!signer.verify(42, expires, &signature, now + Duration::seconds(61)));
+}
+
+#[test]
+fn signature_is_bound_to_id_expiry_and_secret() {
+    let signer = signer();
+    let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let expires = (now + Duration::seconds(60)).timestamp();
+    let signature = signer.sign(42, expires);
+
+    assert!(!signer.verify(43, expires, &signature, now));
+    assert!(!signer.verify(42, expires + 1, &signature, now));
+    assert!(!signer.verify(42, expires, "not-hex", now));
+
+    let other = UrlSigner::new(vec![8_u8; MIN_SECRET_BYTES]).unwrap();
+    assert!(!other.verify(42, expires, &signature, now));
+}
+
+#[test]
+fn signed_url_targets_the_download_route() {
+    let signer = signer();
+    let expires_at = Utc.with_ymd_and_hms(2026, 1, 1, 1, 0, 0).unwrap();
+    let url = signer.signed_url(42, expires_at);
+
+    let expected_prefix =
+        format!("{}?expires={}&signature=", file_download_path(42), expires_at.timestamp());
+    assert!(url.url.starts_with(&expected_prefix), "url was {}", url.url);
+    assert_eq!(url.method, "GET");
+    assert_eq!(url.expires_at, expires_at);
+}
+
+#[test]
+fn debug_redacts_the_secret() {
+    assert!(!format!("{:?}", signer()).contains('7'));
+}
@@ -0,0 +1,161 @@
+//! SCRAM channel binding (`SCRAM-SHA-256-PLUS`)
+//!
+//! Channel binding ties the SCRAM exchange to the TLS session it runs over, so
+//! a man-in-the-middle that terminates TLS with its own certificate cannot
+//! relay the authentication. Postgres only implements the
+//! `tls-server-end-point` binding type (RFC 5929 §4.1): a hash of the server's
+//! leaf certificate, which both sides can compute independently.
+
+use super::ScramError;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+/// Name of the only channel binding type Postgres supports.
+pub const TLS_SERVER_END_POINT: &str = "tls-server-end-point";
+
+/// Client channel binding policy, mirroring libpq's `channel_binding` option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChannelBindingMode {
+    /// Never use channel binding.
+    Disable,
+    /// Use channel binding when the connection is TLS and the server offers
+    /// `SCRAM-SHA-256-PLUS`.
+    #[default]
+    Prefer,
+    /// Fail the connection unless the server authenticates with
+    /// `SCRAM-SHA-256-PLUS` over TLS.
+    Require,
+}
+
+/// Channel binding state announced in the SCRAM GS2 header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChannelBinding {
+    /// The client does not support channel binding (`n`).
+    Unsupported,
+    /// The client supports channel binding but the server did not offer it
+    /// (`y`). Lets the server detect a downgrade of its mechanism list.
+    NotOffered,
+    /// Bind to the TLS session via `tls-server-end-point` with this
+    /// certificate hash (`p=tls-server-end-point`).
+    TlsServerEndPoint(Vec<u8>),
+}
+
+impl ChannelBinding {
+    /// SASL mechanism name matching this binding.
+    #[must_use]
+    pub const fn mechanism(&self) -> &'static str {
+        match self {
+            Self::TlsServerEndPoint(_) => "SCRAM-SHA-256-PLUS",
+            Self::Unsupported | Self::NotOffered => "SCRAM-SHA-256",
+        }
+    }
+
+    /// GS2 header that prefixes the client first message (RFC 5802 §7).
+    #[must_use]
+    pub fn gs2_header(&self) -> String {
+        match self {
+            Self::Unsupported => "n,,".to_string(),
+            Self::NotOffered => "y,,".to_string(),
+            Self::TlsServerEndPoint(_) => format!("p={TLS_SERVER_END_POINT},,"),
+        }
+    }
+
+    /// Raw `c=` attribute input: the GS2 header followed by the binding data.
+    #[must_use]
+    pub fn cbind_input(&self) -> Vec<u8> {
+        let mut input = self.gs2_header().into_bytes();
+        if let Self::TlsServerEndPoint(data) = self {
+            input.extend_from_slice(data);
+        }
+        input
+    }
+}
+
+/// Compute `tls-server-end-point` binding data for a DER-encoded certificate.
+///
+/// RFC 5929 §4.1: hash the certificate with the hash function of its
+/// signature algorithm, upgrading MD5 and SHA-1 to SHA-256. Certificates whose
+/// signature algorithm has no single associated hash (RSA-PSS, Ed25519) are
+/// rejected, as libpq does.
+///
+/// # Errors
+///
+/// Returns [`ScramError::ChannelBinding`] if the certificate cannot be parsed
+/// or uses an unsupported signature algorithm.
+pub fn tls_server_end_point(cert_der: &[u8]) -> Result<Vec<u8>, ScramError> {
+    let oid = signature_algorithm_oid(cert_der)?;
+    let digest = match oid {
+        // md5WithRSAEncryption, sha1WithRSAEncryption, sha256WithRSAEncryption
+        [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x04 | 0x05 | 0x0b]
+        // ecdsa-with-SHA1
+        | [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x01]
+        // ecdsa-with-SHA256
+        | [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02] => Sha256::digest(cert_der).to_vec(),
+        // sha384WithRSAEncryption, ecdsa-with-SHA384
+        [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c]
+        | [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03] => Sha384::digest(cert_der).to_vec(),
+        // sha512WithRSAEncryption, ecdsa-with-SHA512
+        [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d]
+        | [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04] => Sha512::digest(cert_der).to_vec(),
+        _ => {
+            return Err(ScramError::ChannelBinding(
+                "server certificate signature algorithm has no channel binding hash".into(),
+            ))
+        }
+    };
+    Ok(digest)
+}
+
+/// Extract the `signatureAlgorithm` OID from a DER X.509 certificate.
+///
+/// `Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm
+/// AlgorithmIdentifier, signatureValue }` with `AlgorithmIdentifier ::=
+/// SEQUENCE { algorithm OBJECT IDENTIFIER, parameters ANY OPTIONAL }`.
+fn signature_algorithm_oid(cert_der: &[u8]) -> Result<&[u8], ScramError> {
+    const SEQUENCE: u8 = 0x30;
+    const OBJECT_IDENTIFIER: u8 = 0x06;
+
+    let (certificate, _) = der_element(cert_der, SEQUENCE)?;
+    let (_, rest) = der_any(certificate)?; // tbsCertificate
+    let (algorithm_identifier, _) = der_element(rest, SEQUENCE)?;
+    let (oid, _) = der_element(algorithm_identifier, OBJECT_IDENTIFIER)?;
+    Ok(oid)
+}
+
+/// Read one DER element with the expected tag; returns (contents, remainder).
+fn der_element(data: &[u8], tag: u8) -> Result<(&[u8], &[u8]), ScramError> {
+    if data.first() != Some(&tag) {
+        return Err(malformed_certificate());
+    }
+    der_any(data)
+}
+
+/// Read one DER element of any tag; returns (contents, remainder).
+fn der_any(data: &[u8]) -> Result<(&[u8], &[u8]), ScramError> {
+    let first_len = *data.get(1).ok_or_else(malformed_certificate)?;
+    let (len, header) = if first_len & 0x80 == 0 {
+        (usize::from(first_len), 2)
+    } else {
+        let octets = usize::from(first_len & 0x7f);
+        if octets == 0 || octets > 4 {
+            return Err(malformed_certificate());
+        }
+        let bytes = data.get(2..2 + octets).ok_or_else(malformed_certificate)?;
+        let len = bytes
+            .iter()
+            .fold(0usize, |len, byte| (len << 8) | usize::from(*byte));
+        (len, 2 + octets)
+    };
+    let end = header.checked_add(len).ok_or_else(malformed_certificate)?;
+    let contents = data.get(header..end).ok_or_else(malformed_certificate)?;
+    let rest = data.get(end..).ok_or_else(malformed_certificate)?;
+    Ok((contents, rest))
+}
+
+fn malformed_certificate() -> ScramError {
+    ScramError::ChannelBinding("malformed server certificate".into())
+}
+
+#[cfg(test)]
+mod tests;
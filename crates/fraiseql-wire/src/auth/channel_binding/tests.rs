@@ -0,0 +1,84 @@
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
+use super::*;
+
+const SHA256_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+const SHA1_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x05];
+const ECDSA_WITH_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+const ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+
+fn der(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    if contents.len() < 0x80 {
+        out.push(contents.len() as u8);
+    } else {
+        out.push(0x82);
+        out.extend_from_slice(&(contents.len() as u16).to_be_bytes());
+    }
+    out.extend_from_slice(contents);
+    out
+}
+
+/// Skeleton certificate: only the fields the OID lookup walks are real.
+fn certificate(signature_oid: &[u8], tbs_len: usize) -> Vec<u8> {
+    let tbs = der(0x30, &vec![0u8; tbs_len]);
+    let algorithm = der(0x30, &[der(0x06, signature_oid), vec![0x05, 0x00]].concat());
+    let signature = der(0x03, &[0x00, 0x01, 0x02]);
+    der(0x30, &[tbs, algorithm, signature].concat())
+}
+
+#[test]
+fn sha256_signature_hashes_with_sha256() {
+    let cert = certificate(SHA256_WITH_RSA, 300); // long-form lengths
+    assert_eq!(
+        tls_server_end_point(&cert).unwrap(),
+        Sha256::digest(&cert).to_vec()
+    );
+}
+
+#[test]
+fn sha1_signature_is_upgraded_to_sha256() {
+    let cert = certificate(SHA1_WITH_RSA, 10);
+    assert_eq!(
+        tls_server_end_point(&cert).unwrap(),
+        Sha256::digest(&cert).to_vec()
+    );
+}
+
+#[test]
+fn sha384_signature_hashes_with_sha384() {
+    let cert = certificate(ECDSA_WITH_SHA384, 10);
+    assert_eq!(
+        tls_server_end_point(&cert).unwrap(),
+        Sha384::digest(&cert).to_vec()
+    );
+}
+
+#[test]
+fn algorithms_without_a_hash_are_rejected() {
+    let cert = certificate(ED25519, 10);
+    assert!(matches!(
+        tls_server_end_point(&cert),
+        Err(ScramError::ChannelBinding(_))
+    ));
+}
+
+#[test]
+fn malformed_certificates_are_rejected() {
+    let cert = certificate(SHA256_WITH_RSA, 10);
+    for bad in [&[][..], &[0x02, 0x01, 0x00], &cert[..cert.len() - 8]] {
+        assert!(matches!(
+            tls_server_end_point(bad),
+            Err(ScramError::ChannelBinding(_))
+        ));
+    }
+}
+
+#[test]
+fn gs2_headers_match_binding_state() {
+    assert_eq!(ChannelBinding::Unsupported.gs2_header(), "n,,");
+    assert_eq!(ChannelBinding::NotOffered.gs2_header(), "y,,");
+    assert_eq!(
+        ChannelBinding::TlsServerEndPoint(vec![1, 2]).cbind_input(),
+        b"p=tls-server-end-point,,\x01\x02".to_vec()
+    );
+}
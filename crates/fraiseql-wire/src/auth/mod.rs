@@ -1,11 +1,14 @@
 //! Wire-protocol SCRAM-SHA-256 authentication errors.
 //!
-//! Supports SCRAM-SHA-256 (Postgres 10+) as the primary authentication method.
+//! Supports SCRAM-SHA-256 (Postgres 10+) as the primary authentication method,
+//! and SCRAM-SHA-256-PLUS with `tls-server-end-point` channel binding over TLS.
 //! The `AuthError` here is specific to the Postgres wire-protocol handshake
 //! and is orthogonal to `fraiseql_auth::AuthError` (OIDC/JWT middleware).
 
+pub mod channel_binding;
 pub mod scram;
 
+pub use channel_binding::{ChannelBinding, ChannelBindingMode};
 pub use scram::{ScramClient, ScramError};
 
 use std::fmt;
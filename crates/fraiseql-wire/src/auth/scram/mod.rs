@@ -1,8 +1,10 @@
 //! SCRAM-SHA-256 authentication implementation
 //!
 //! Implements the SCRAM-SHA-256 (Salted Challenge Response Authentication Mechanism)
-//! as defined in RFC 5802 for PostgreSQL authentication (Postgres 10+).
+//! as defined in RFC 5802 for PostgreSQL authentication (Postgres 10+), with
+//! optional `SCRAM-SHA-256-PLUS` channel binding (see [`super::channel_binding`]).
 
+use super::channel_binding::ChannelBinding;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use hmac::{Hmac, KeyInit, Mac};
 use pbkdf2::pbkdf2;
@@ -35,6 +37,8 @@ pub enum ScramError {
     Base64Error(String),
     /// PBKDF2 key derivation failure (e.g. invalid output length)
     KeyDerivation(String),
+    /// Channel binding data could not be derived from the TLS session
+    ChannelBinding(String),
 }
 
 impl fmt::Display for ScramError {
@@ -45,6 +49,7 @@ impl fmt::Display for ScramError {
             ScramError::Utf8Error(msg) => write!(f, "UTF-8 error: {}", msg),
             ScramError::Base64Error(msg) => write!(f, "Base64 error: {}", msg),
             ScramError::KeyDerivation(msg) => write!(f, "key derivation error: {}", msg),
+            ScramError::ChannelBinding(msg) => write!(f, "channel binding error: {}", msg),
         }
     }
 }
@@ -67,6 +72,7 @@ pub struct ScramClient {
     /// overwritten with zeros when `ScramClient` is dropped (S38).
     password: Zeroizing<String>,
     nonce: String,
+    channel_binding: ChannelBinding,
 }
 
 impl ScramClient {
@@ -82,18 +88,41 @@ impl ScramClient {
             username,
             password: Zeroizing::new(password),
             nonce,
+            channel_binding: ChannelBinding::Unsupported,
         }
     }
 
+    /// Set the channel binding announced to the server (default: unsupported)
+    ///
+    /// Must be called before [`ScramClient::client_first`]; the binding is part
+    /// of both client messages and of the signed auth message.
+    #[must_use]
+    pub fn with_channel_binding(mut self, channel_binding: ChannelBinding) -> Self {
+        self.channel_binding = channel_binding;
+        self
+    }
+
+    /// SASL mechanism name to send in `SASLInitialResponse`
+    #[must_use]
+    pub const fn mechanism(&self) -> &'static str {
+        self.channel_binding.mechanism()
+    }
+
     /// Generate client first message (no proof)
     #[must_use]
     pub fn client_first(&self) -> String {
         // RFC 5802 format: gs2-header client-first-message-bare
-        // gs2-header = "n,," (n = no channel binding, empty authorization identity)
+        // gs2-header = "n,," / "y,," / "p=tls-server-end-point,," (channel binding
+        // flag, empty authorization identity)
         // client-first-message-bare = "n=<username>,r=<nonce>"
         // RFC 5802 §5.1: username must have ',' escaped as '=2C' and '=' escaped as '=3D'.
         let escaped_username = self.username.replace('=', "=3D").replace(',', "=2C");
-        format!("n,,n={},r={}", escaped_username, self.nonce)
+        format!(
+            "{}n={},r={}",
+            self.channel_binding.gs2_header(),
+            escaped_username,
+            self.nonce
+        )
     }
 
     /// Process server first message and generate client final message
@@ -133,8 +162,9 @@ impl ScramClient {
             )));
         }
 
-        // Build channel binding (no channel binding for SCRAM-SHA-256)
-        let channel_binding = BASE64.encode(b"n,,");
+        // Build channel binding: the GS2 header, followed by the certificate hash
+        // when binding to the TLS session (RFC 5802 §7, "c=" attribute)
+        let channel_binding = BASE64.encode(self.channel_binding.cbind_input());
 
         // Build client final without proof
         let client_final_without_proof = format!("c={},r={}", channel_binding, server_nonce);
@@ -77,6 +77,37 @@ fn test_scram_client_final_flow() {
     assert!(!state.auth_message.is_empty());
 }
 
+#[test]
+fn test_scram_plus_binds_certificate_hash() {
+    let cert_hash = vec![0xab; 32];
+    let mut client = ScramClient::new("alice".to_string(), "secret".to_string())
+        .with_channel_binding(ChannelBinding::TlsServerEndPoint(cert_hash.clone()));
+    assert_eq!(client.mechanism(), "SCRAM-SHA-256-PLUS");
+    assert!(client
+        .client_first()
+        .starts_with("p=tls-server-end-point,,n=alice,r="));
+
+    let server_nonce = format!("{}server_nonce_part", client.nonce);
+    let server_first = format!("r={},s={},i=4096", server_nonce, BASE64.encode(b"salty"));
+    let (client_final, _) = client.client_final(&server_first).unwrap();
+
+    let cbind = client_final
+        .strip_prefix("c=")
+        .and_then(|rest| rest.split(',').next())
+        .unwrap();
+    let mut expected = b"p=tls-server-end-point,,".to_vec();
+    expected.extend_from_slice(&cert_hash);
+    assert_eq!(BASE64.decode(cbind).unwrap(), expected);
+}
+
+#[test]
+fn test_scram_announces_unused_channel_binding_support() {
+    let client = ScramClient::new("alice".to_string(), "secret".to_string())
+        .with_channel_binding(ChannelBinding::NotOffered);
+    assert_eq!(client.mechanism(), "SCRAM-SHA-256");
+    assert!(client.client_first().starts_with("y,,n=alice,r="));
+}
+
 #[test]
 fn test_scram_iteration_count_too_high_is_rejected() {
     // H3: A server-supplied i= value above MAX_SCRAM_ITERATIONS must be rejected
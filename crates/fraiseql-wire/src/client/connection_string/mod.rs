@@ -2,8 +2,13 @@
 //!
 //! Supports formats:
 //! * postgres://[user[:password]@][host][:port][/database]
+//! * postgres://[user[:password]@]host1[:port1],host2[:port2][/database] (failover)
+//! * <postgres:///database?host=a,b,c&port=5432> (failover, libpq keyword form)
 //! * <postgres:///database> (Unix socket, local)
 //! * <postgres:///database?host=/path/to/socket> (Unix socket, custom directory)
+//!
+//! `target_session_attrs=read-write|any` selects which of several hosts is
+//! acceptable (see [`TargetSessionAttrs`]).
 
 use crate::connection::ConnectionConfig;
 use crate::{Result, WireError};
@@ -49,6 +54,40 @@ fn split_host_port(host_port: &str) -> Result<(String, u16)> {
     }
 }
 
+/// Parse libpq's keyword form: `host=a,b,c` with `port=` either empty (5432
+/// everywhere), a single port for every host, or one port per host.
+///
+/// # Errors
+///
+/// Returns `WireError::Config` if a port is invalid or the port list length
+/// matches neither 1 nor the number of hosts.
+fn parse_host_list(hosts: &str, ports: &str) -> Result<Vec<(String, u16)>> {
+    let hosts: Vec<&str> = hosts.split(',').collect();
+    let ports = if ports.is_empty() {
+        vec![5432; hosts.len()]
+    } else {
+        let ports = ports
+            .split(',')
+            .map(|port| {
+                port.parse::<u16>()
+                    .map_err(|_| WireError::Config(format!("invalid port '{}'", port)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        match ports.as_slice() {
+            [port] => vec![*port; hosts.len()],
+            _ if ports.len() == hosts.len() => ports,
+            _ => {
+                return Err(WireError::Config(format!(
+                    "{} ports given for {} hosts",
+                    ports.len(),
+                    hosts.len()
+                )))
+            }
+        }
+    };
+    Ok(hosts.into_iter().map(str::to_string).zip(ports).collect())
+}
+
 /// Decode one hex digit (`0-9`, `a-f`, `A-F`) into its 0–15 value.
 const fn hex_val(b: u8) -> Option<u8> {
     match b {
@@ -140,15 +179,58 @@ pub fn validate_socket_dir(dir: &str) -> Result<()> {
     Ok(())
 }
 
+/// Which server a multi-host connection may settle on (libpq
+/// `target_session_attrs`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TargetSessionAttrs {
+    /// Any server that accepts the connection
+    #[default]
+    Any,
+    /// Only a server that accepts writes (the primary)
+    ReadWrite,
+}
+
+impl TargetSessionAttrs {
+    /// Parse the libpq spelling (`any`, `read-write`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::Config`] for any other value.
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "any" => Ok(Self::Any),
+            "read-write" => Ok(Self::ReadWrite),
+            other => Err(WireError::Config(format!(
+                "unsupported target_session_attrs '{}': expected 'any' or 'read-write'",
+                other
+            ))),
+        }
+    }
+
+    /// The libpq spelling of this value
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::ReadWrite => "read-write",
+        }
+    }
+}
+
 /// Parsed connection info
 #[derive(Debug, Clone)]
 pub struct ConnectionInfo {
     /// Transport type
     pub transport: TransportType,
-    /// Host (for TCP)
+    /// Host (for TCP; the first of `hosts`)
     pub host: Option<String>,
-    /// Port (for TCP)
+    /// Port (for TCP; the first of `hosts`)
     pub port: Option<u16>,
+    /// Every TCP host to try, in order
+    pub hosts: Vec<(String, u16)>,
+    /// Which of `hosts` is acceptable
+    pub target_session_attrs: TargetSessionAttrs,
     /// Unix socket path
     pub unix_socket: Option<PathBuf>,
     /// Database name
@@ -256,6 +338,18 @@ impl ConnectionInfo {
             .and_then(|p| p.parse::<u16>().ok())
             .unwrap_or(5432);
 
+        // `host=a,b,c` names TCP hosts rather than a socket directory
+        if let Some(hosts) = parse_query_param(query_string, "host")
+            .filter(|hosts| !hosts.is_empty() && !hosts.contains('/'))
+        {
+            let ports = parse_query_param(query_string, "port").unwrap_or_default();
+            return Ok(Self {
+                user: whoami::username(),
+                password: None,
+                ..Self::tcp(parse_host_list(&hosts, &ports)?, database, query_string)?
+            });
+        }
+
         // Determine socket directory
         let socket_dir = if let Some(custom_dir) = parse_query_param(query_string, "host") {
             // Validate before use: must be absolute, no traversal, within length limit.
@@ -277,6 +371,8 @@ impl ConnectionInfo {
             transport: TransportType::Unix,
             host: None,
             port: Some(port),
+            hosts: Vec::new(),
+            target_session_attrs: TargetSessionAttrs::Any,
             unix_socket,
             database,
             user: whoami::username(),
@@ -314,6 +410,7 @@ impl ConnectionInfo {
             (whoami::username(), None)
         };
 
+        let (rest, query_string) = rest.split_at(rest.find('?').unwrap_or(rest.len()));
         let (host_port, database) = if let Some(pos) = rest.find('/') {
             let (hp, db) = rest.split_at(pos);
             (hp, db[1..].to_string())
@@ -321,16 +418,49 @@ impl ConnectionInfo {
             (rest, whoami::username())
         };
 
-        let (host, port) = split_host_port(host_port)?;
+        let hosts = match parse_query_param(query_string, "host") {
+            Some(hosts) if host_port.is_empty() => parse_host_list(
+                &hosts,
+                &parse_query_param(query_string, "port").unwrap_or_default(),
+            )?,
+            _ => host_port
+                .split(',')
+                .map(split_host_port)
+                .collect::<Result<Vec<_>>>()?,
+        };
+
+        Ok(Self {
+            user,
+            password,
+            ..Self::tcp(hosts, database, query_string)?
+        })
+    }
+
+    /// TCP connection info for `hosts`, reading `target_session_attrs` from the
+    /// query string. Credentials are left for the caller to fill in.
+    fn tcp(hosts: Vec<(String, u16)>, database: String, query_string: &str) -> Result<Self> {
+        let (host, port) = hosts
+            .first()
+            .cloned()
+            .ok_or_else(|| WireError::Config("connection string names no host".into()))?;
+        if hosts.iter().any(|(host, _)| host.is_empty()) {
+            return Err(WireError::Config("empty host in connection string".into()));
+        }
+        let target_session_attrs = parse_query_param(query_string, "target_session_attrs")
+            .map(|value| TargetSessionAttrs::parse(&value))
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(Self {
             transport: TransportType::Tcp,
             host: Some(host),
             port: Some(port),
+            hosts,
+            target_session_attrs,
             unix_socket: None,
             database,
-            user,
-            password,
+            user: String::new(),
+            password: None,
         })
     }
 
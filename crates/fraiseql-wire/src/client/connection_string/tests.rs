@@ -217,3 +217,65 @@ fn test_parse_ipv6_zone_id() {
     assert_eq!(info.host, Some("fe80::1%25eth0".to_string()));
     assert_eq!(info.port, Some(5432));
 }
+
+#[test]
+fn parse_tcp_multiple_hosts() {
+    let info = ConnectionInfo::parse("postgres://user@db-a:5433,db-b,[::1]:5434/mydb").unwrap();
+    assert_eq!(
+        info.hosts,
+        vec![
+            ("db-a".to_string(), 5433),
+            ("db-b".to_string(), 5432),
+            ("::1".to_string(), 5434),
+        ]
+    );
+    assert_eq!(info.host, Some("db-a".to_string()));
+    assert_eq!(info.port, Some(5433));
+    assert_eq!(info.database, "mydb");
+    assert_eq!(info.target_session_attrs, TargetSessionAttrs::Any);
+}
+
+#[test]
+fn parse_tcp_strips_query_string_from_database() {
+    let info =
+        ConnectionInfo::parse("postgres://user@db-a,db-b/mydb?target_session_attrs=read-write")
+            .unwrap();
+    assert_eq!(info.database, "mydb");
+    assert_eq!(info.target_session_attrs, TargetSessionAttrs::ReadWrite);
+}
+
+#[test]
+fn parse_host_keyword_list() {
+    let info = ConnectionInfo::parse("postgres:///mydb?host=db-a,db-b&port=5433").unwrap();
+    assert_eq!(info.transport, TransportType::Tcp);
+    assert_eq!(
+        info.hosts,
+        vec![("db-a".to_string(), 5433), ("db-b".to_string(), 5433)]
+    );
+
+    let info =
+        ConnectionInfo::parse("postgres://user@/mydb?host=db-a,db-b&port=5433,5434").unwrap();
+    assert_eq!(info.user, "user");
+    assert_eq!(
+        info.hosts,
+        vec![("db-a".to_string(), 5433), ("db-b".to_string(), 5434)]
+    );
+}
+
+#[test]
+fn parse_host_keyword_list_rejects_mismatched_ports() {
+    let result = ConnectionInfo::parse("postgres:///mydb?host=a,b,c&port=1,2");
+    assert!(matches!(result, Err(WireError::Config(_))));
+}
+
+#[test]
+fn parse_rejects_unknown_target_session_attrs() {
+    let result = ConnectionInfo::parse("postgres://db-a/mydb?target_session_attrs=standby");
+    assert!(matches!(result, Err(WireError::Config(_))));
+}
+
+#[test]
+fn parse_rejects_empty_host_in_list() {
+    let result = ConnectionInfo::parse("postgres://db-a,,db-b/mydb");
+    assert!(matches!(result, Err(WireError::Config(_))));
+}
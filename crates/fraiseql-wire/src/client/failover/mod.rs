@@ -0,0 +1,148 @@
+//! Multi-host connection and failover
+//!
+//! A connection string may name several hosts
+//! (`postgres://a:5432,b:5432/db?target_session_attrs=read-write`). They are
+//! tried in order; a host that refuses the connection, fails authentication
+//! or does not match `target_session_attrs` is skipped. The same walk runs
+//! again whenever the client has to reconnect, so after a primary switchover
+//! the client lands on the new primary.
+
+use super::connection_string::{ConnectionInfo, TargetSessionAttrs};
+use super::fraise_client::with_connect_timeout;
+use crate::connection::{Connection, ConnectionConfig, TlsConfig, Transport};
+use crate::protocol::BackendMessage;
+use crate::{Result, WireError};
+
+/// Everything needed to (re)establish a TCP connection to one of several hosts.
+pub(super) struct FailoverTarget {
+    hosts: Vec<(String, u16)>,
+    target_session_attrs: TargetSessionAttrs,
+    config: ConnectionConfig,
+    tls: Option<TlsConfig>,
+}
+
+impl FailoverTarget {
+    /// Failover target for the TCP hosts in `info`.
+    pub(super) fn new(
+        info: &ConnectionInfo,
+        config: ConnectionConfig,
+        tls: Option<TlsConfig>,
+    ) -> Self {
+        Self {
+            hosts: info.hosts.clone(),
+            target_session_attrs: info.target_session_attrs,
+            config,
+            tls,
+        }
+    }
+
+    /// Connect to the first acceptable host, in connection-string order.
+    ///
+    /// # Errors
+    ///
+    /// With a single host, returns that host's error unchanged. With several,
+    /// returns [`WireError::Connection`] listing why each host was rejected.
+    pub(super) async fn connect(&self) -> Result<Connection> {
+        let mut failures = Vec::new();
+        for (host, port) in &self.hosts {
+            match self.connect_host(host, *port).await {
+                Ok(conn) => return Ok(conn),
+                Err(e) if self.hosts.len() == 1 => return Err(e),
+                Err(e) => {
+                    tracing::warn!("skipping {}:{}: {}", host, port, e);
+                    crate::metrics::counters::connection_failed("failover", e.category());
+                    failures.push(format!("{}:{}: {}", host, port, e));
+                }
+            }
+        }
+        Err(WireError::Connection(format!(
+            "no acceptable host among {} (target_session_attrs={}): {}",
+            self.hosts.len(),
+            self.target_session_attrs.as_str(),
+            failures.join("; ")
+        )))
+    }
+
+    /// Check that an established connection still matches
+    /// `target_session_attrs` (e.g. the primary has not been demoted).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError`] if the probe query fails.
+    pub(super) async fn session_is_acceptable(&self, conn: &mut Connection) -> Result<bool> {
+        match self.target_session_attrs {
+            TargetSessionAttrs::Any => Ok(true),
+            TargetSessionAttrs::ReadWrite => {
+                let messages = conn.simple_query("SHOW transaction_read_only").await?;
+                Ok(!read_only_from_show(&messages)?)
+            }
+        }
+    }
+
+    async fn connect_host(&self, host: &str, port: u16) -> Result<Connection> {
+        let transport = match &self.tls {
+            Some(tls) => {
+                with_connect_timeout(
+                    self.config.connect_timeout,
+                    Transport::connect_tcp_tls(host, port, tls),
+                )
+                .await?
+            }
+            None => {
+                with_connect_timeout(
+                    self.config.connect_timeout,
+                    Transport::connect_tcp(host, port),
+                )
+                .await?
+            }
+        };
+
+        // Apply TCP keepalive when configured.
+        if let Some(idle) = self.config.keepalive_idle {
+            if let Err(e) = transport.apply_keepalive(idle) {
+                tracing::warn!("Failed to apply TCP keepalive (idle={idle:?}): {e}");
+            }
+        }
+
+        let mut conn = Connection::new(transport);
+        conn.startup(&self.config).await?;
+
+        if !self.session_is_acceptable(&mut conn).await? {
+            let _ = conn.close().await;
+            return Err(WireError::Connection(
+                "server is read-only, target_session_attrs=read-write".into(),
+            ));
+        }
+        Ok(conn)
+    }
+}
+
+/// Interpret the result of `SHOW transaction_read_only`.
+///
+/// # Errors
+///
+/// Returns [`WireError::Sql`] if the server answered with an error, or
+/// [`WireError::Protocol`] if no `on`/`off` row came back.
+pub(super) fn read_only_from_show(messages: &[BackendMessage]) -> Result<bool> {
+    for msg in messages {
+        match msg {
+            BackendMessage::ErrorResponse(err) => return Err(WireError::Sql(err.to_string())),
+            BackendMessage::DataRow(fields) => {
+                return match fields.first().and_then(Option::as_ref).map(|v| &v[..]) {
+                    Some(b"on") => Ok(true),
+                    Some(b"off") => Ok(false),
+                    _ => Err(WireError::Protocol(
+                        "unexpected transaction_read_only value".into(),
+                    )),
+                };
+            }
+            _ => {}
+        }
+    }
+    Err(WireError::Protocol(
+        "SHOW transaction_read_only returned no row".into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use super::*;
+use crate::protocol::ErrorFields;
+use bytes::Bytes;
+
+fn show_result(value: &'static [u8]) -> Vec<BackendMessage> {
+    vec![
+        BackendMessage::DataRow(vec![Some(Bytes::from_static(value))]),
+        BackendMessage::CommandComplete("SHOW".into()),
+    ]
+}
+
+#[test]
+fn read_only_from_show_parses_on_and_off() {
+    assert!(read_only_from_show(&show_result(b"on")).unwrap());
+    assert!(!read_only_from_show(&show_result(b"off")).unwrap());
+}
+
+#[test]
+fn read_only_from_show_rejects_errors_and_missing_rows() {
+    let error = vec![BackendMessage::ErrorResponse(ErrorFields {
+        message: Some("permission denied".into()),
+        ..ErrorFields::default()
+    })];
+    assert!(matches!(
+        read_only_from_show(&error),
+        Err(WireError::Sql(_))
+    ));
+    assert!(matches!(
+        read_only_from_show(&[BackendMessage::CommandComplete("SHOW".into())]),
+        Err(WireError::Protocol(_))
+    ));
+    assert!(matches!(
+        read_only_from_show(&show_result(b"maybe")),
+        Err(WireError::Protocol(_))
+    ));
+}
+
+fn target(connection_string: &str) -> FailoverTarget {
+    let info = ConnectionInfo::parse(connection_string).unwrap();
+    let config = info.to_config();
+    FailoverTarget::new(&info, config, None)
+}
+
+#[tokio::test]
+async fn single_host_error_is_returned_unchanged() {
+    // Port 1 on loopback refuses connections immediately.
+    let result = target("postgres://user@127.0.0.1:1/db").connect().await;
+    assert!(
+        matches!(result, Err(WireError::Io(_))),
+        "expected the raw I/O error"
+    );
+}
+
+#[tokio::test]
+async fn multi_host_error_lists_every_host() {
+    let result =
+        target("postgres://user@127.0.0.1:1,127.0.0.1:2/db?target_session_attrs=read-write")
+            .connect()
+            .await;
+    match result {
+        Err(WireError::Connection(msg)) => {
+            assert!(msg.contains("127.0.0.1:1"), "missing first host: {msg}");
+            assert!(msg.contains("127.0.0.1:2"), "missing second host: {msg}");
+            assert!(msg.contains("read-write"), "missing target attrs: {msg}");
+        }
+        Err(other) => panic!("expected a Connection error, got {other:?}"),
+        Ok(_) => panic!("nothing listens on ports 1 and 2"),
+    }
+}
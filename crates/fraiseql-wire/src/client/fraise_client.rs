@@ -1,17 +1,27 @@
 //! `FraiseClient` implementation
 
 use super::connection_string::{ConnectionInfo, TransportType};
+use super::failover::FailoverTarget;
 use super::query_builder::QueryBuilder;
-use crate::connection::{Connection, ConnectionConfig, Transport};
+use crate::connection::{Connection, ConnectionConfig, TlsConfig, Transport};
 #[allow(unused_imports)] // Reason: used only in doc links for `# Errors` sections
 use crate::error::WireError;
-use crate::stream::JsonStream;
+use crate::json::validate_row_description;
+use crate::protocol::BackendMessage;
+use crate::stream::{extract_json_bytes, parse_json, JsonStream};
 use crate::Result;
 use serde::de::DeserializeOwned;
 
 /// FraiseQL wire protocol client
+///
+/// A TCP client remembers how it connected. When its connection turns out to
+/// be dead before a query starts streaming, or fails [`FraiseClient::health_check`],
+/// it reconnects (walking every host of a multi-host connection string) and
+/// replays the query. Rows already streamed are never replayed: a connection
+/// lost mid-stream surfaces as an error on the stream.
 pub struct FraiseClient {
     conn: Connection,
+    failover: Option<FailoverTarget>,
 }
 
 impl FraiseClient {
@@ -35,6 +45,12 @@ impl FraiseClient {
     ///
     /// // Unix socket
     /// let client = FraiseClient::connect("postgres:///mydb").await?;
+    ///
+    /// // Several hosts: connect to whichever currently accepts writes
+    /// let client = FraiseClient::connect(
+    ///     "postgres://db-a:5432,db-b:5432/mydb?target_session_attrs=read-write",
+    /// )
+    /// .await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -44,32 +60,12 @@ impl FraiseClient {
     /// Returns [`WireError::Config`] if the connection string is malformed or missing
     /// required fields (host/port for TCP, path for Unix sockets).
     /// Returns [`WireError::Io`] if the underlying TCP or Unix socket connection fails.
+    /// Returns [`WireError::Connection`] if the string names several hosts and none of
+    /// them is reachable and matches `target_session_attrs`.
     pub async fn connect(connection_string: &str) -> Result<Self> {
         let info = ConnectionInfo::parse(connection_string)?;
-
-        let transport = match info.transport {
-            TransportType::Tcp => {
-                let host = info.host.as_ref().ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a host".into())
-                })?;
-                let port = info.port.ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a port".into())
-                })?;
-                Transport::connect_tcp(host, port).await?
-            }
-            TransportType::Unix => {
-                let path = info.unix_socket.as_ref().ok_or_else(|| {
-                    crate::WireError::Config("Unix transport requires a socket path".into())
-                })?;
-                Transport::connect_unix(path).await?
-            }
-        };
-
-        let mut conn = Connection::new(transport);
         let config = info.to_config();
-        conn.startup(&config).await?;
-
-        Ok(Self { conn })
+        Self::open(&info, config, None).await
     }
 
     /// Connect to Postgres with TLS encryption
@@ -106,29 +102,8 @@ impl FraiseClient {
         tls_config: crate::connection::TlsConfig,
     ) -> Result<Self> {
         let info = ConnectionInfo::parse(connection_string)?;
-
-        let transport = match info.transport {
-            TransportType::Tcp => {
-                let host = info.host.as_ref().ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a host".into())
-                })?;
-                let port = info.port.ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a port".into())
-                })?;
-                Transport::connect_tcp_tls(host, port, &tls_config).await?
-            }
-            TransportType::Unix => {
-                return Err(crate::WireError::Config(
-                    "TLS is only supported for TCP connections".into(),
-                ));
-            }
-        };
-
-        let mut conn = Connection::new(transport);
         let config = info.to_config();
-        conn.startup(&config).await?;
-
-        Ok(Self { conn })
+        Self::open(&info, config, Some(tls_config)).await
     }
 
     /// Connect to Postgres with custom connection configuration
@@ -169,37 +144,7 @@ impl FraiseClient {
         config: ConnectionConfig,
     ) -> Result<Self> {
         let info = ConnectionInfo::parse(connection_string)?;
-
-        let transport = match info.transport {
-            TransportType::Tcp => {
-                let host = info.host.as_ref().ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a host".into())
-                })?;
-                let port = info.port.ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a port".into())
-                })?;
-                with_connect_timeout(config.connect_timeout, Transport::connect_tcp(host, port))
-                    .await?
-            }
-            TransportType::Unix => {
-                let path = info.unix_socket.as_ref().ok_or_else(|| {
-                    crate::WireError::Config("Unix transport requires a socket path".into())
-                })?;
-                with_connect_timeout(config.connect_timeout, Transport::connect_unix(path)).await?
-            }
-        };
-
-        // Apply TCP keepalive when configured.
-        if let Some(idle) = config.keepalive_idle {
-            if let Err(e) = transport.apply_keepalive(idle) {
-                tracing::warn!("Failed to apply TCP keepalive (idle={idle:?}): {e}");
-            }
-        }
-
-        let mut conn = Connection::new(transport);
-        conn.startup(&config).await?;
-
-        Ok(Self { conn })
+        Self::open(&info, config, None).await
     }
 
     /// Connect to Postgres with both custom configuration and TLS encryption
@@ -247,39 +192,107 @@ impl FraiseClient {
         tls_config: crate::connection::TlsConfig,
     ) -> Result<Self> {
         let info = ConnectionInfo::parse(connection_string)?;
+        Self::open(&info, config, Some(tls_config)).await
+    }
 
-        let transport = match info.transport {
-            TransportType::Tcp => {
-                let host = info.host.as_ref().ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a host".into())
-                })?;
-                let port = info.port.ok_or_else(|| {
-                    crate::WireError::Config("TCP transport requires a port".into())
-                })?;
-                with_connect_timeout(
-                    config.connect_timeout,
-                    Transport::connect_tcp_tls(host, port, &tls_config),
-                )
-                .await?
-            }
-            TransportType::Unix => {
-                return Err(crate::WireError::Config(
-                    "TLS is only supported for TCP connections".into(),
-                ));
-            }
+    /// Check that the connection is alive and still acceptable, failing over if not
+    ///
+    /// Runs `SELECT 1` (and, for `target_session_attrs=read-write`, checks the
+    /// server still accepts writes). If the connection is dead, or the primary
+    /// has been demoted, the client reconnects to the first acceptable host.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError`] if the probe fails for a non-connection reason, or
+    /// if no acceptable host can be reached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// // Requires: live Postgres primary and standby.
+    /// # async fn example() -> fraiseql_wire::Result<()> {
+    /// use fraiseql_wire::FraiseClient;
+    ///
+    /// let mut client = FraiseClient::connect(
+    ///     "postgres://db-a:5432,db-b:5432/mydb?target_session_attrs=read-write",
+    /// )
+    /// .await?;
+    /// client.health_check().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&mut self) -> Result<()> {
+        let Some(failover) = &self.failover else {
+            self.conn.simple_query("SELECT 1").await?;
+            return Ok(());
         };
-
-        // Apply TCP keepalive when configured.
-        if let Some(idle) = config.keepalive_idle {
-            if let Err(e) = transport.apply_keepalive(idle) {
-                tracing::warn!("Failed to apply TCP keepalive (idle={idle:?}): {e}");
+        let healthy = match self.conn.simple_query("SELECT 1").await {
+            Ok(_) => failover.session_is_acceptable(&mut self.conn).await,
+            Err(e) => Err(e),
+        };
+        match healthy {
+            Ok(true) => Ok(()),
+            Ok(false) => {
+                tracing::warn!("server no longer matches target_session_attrs, failing over");
+                self.conn = failover.connect().await?;
+                Ok(())
+            }
+            Err(e) if is_connection_failure(&e) => {
+                tracing::warn!("health check failed ({}), reconnecting", e);
+                self.conn = failover.connect().await?;
+                Ok(())
             }
+            Err(e) => Err(e),
         }
+    }
 
-        let mut conn = Connection::new(transport);
-        conn.startup(&config).await?;
-
-        Ok(Self { conn })
+    /// Run several raw SQL queries in one round trip and collect their rows
+    ///
+    /// The queries are pipelined over the extended protocol and each one is
+    /// prepared once per connection, so repeating the same SQL skips parsing
+    /// and planning. Like [`FraiseClient::query`], every query must return a
+    /// single `data` column of type `json`/`jsonb`. The client is not consumed,
+    /// so consecutive batches reuse the connection and its prepared statements.
+    ///
+    /// Rows are buffered; use [`FraiseClient::query`] to stream large results.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::Sql`] with the first server error of the batch (the
+    /// rest of the batch is not run). Returns [`WireError::InvalidSchema`] if a
+    /// query does not return a single JSON `data` column. Returns [`WireError`]
+    /// on I/O or protocol errors that survive a reconnect.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// // Requires: live Postgres server.
+    /// # async fn example(mut client: fraiseql_wire::FraiseClient) -> fraiseql_wire::Result<()> {
+    /// let results = client
+    ///     .query_batch(&[
+    ///         "SELECT data FROM v_user WHERE data->>'id' = '1'",
+    ///         "SELECT data FROM v_project WHERE data->>'owner' = '1'",
+    ///     ])
+    ///     .await?;
+    /// assert_eq!(results.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_batch(&mut self, queries: &[&str]) -> Result<Vec<Vec<serde_json::Value>>> {
+        let results = match self.conn.pipelined_query(queries).await {
+            Err(e) if is_connection_failure(&e) => {
+                let Some(failover) = &self.failover else {
+                    return Err(e);
+                };
+                // Same replay rule as `execute_query`: nothing was returned yet.
+                tracing::warn!("connection lost before batch completed ({}), replaying", e);
+                crate::metrics::counters::query_replayed();
+                self.conn = failover.connect().await?;
+                self.conn.pipelined_query(queries).await?
+            }
+            result => result?,
+        };
+        results.iter().map(|messages| json_rows(messages)).collect()
     }
 
     /// Start building a query for an entity with automatic deserialization
@@ -363,7 +376,33 @@ impl FraiseClient {
         adaptive_min_chunk_size: Option<usize>,
         adaptive_max_chunk_size: Option<usize>,
     ) -> Result<JsonStream> {
-        self.conn
+        let result = self
+            .conn
+            .streaming_query(
+                sql,
+                chunk_size,
+                max_memory,
+                soft_limit_warn_threshold,
+                soft_limit_fail_threshold,
+                enable_adaptive_chunking,
+                adaptive_min_chunk_size,
+                adaptive_max_chunk_size,
+            )
+            .await;
+
+        // fraiseql-wire only runs reads, so a query that failed before
+        // streaming a single row can be replayed on a fresh connection.
+        let (Err(e), Some(failover)) = (&result, &self.failover) else {
+            return result;
+        };
+        if !is_connection_failure(e) {
+            return result;
+        }
+        tracing::warn!("connection lost before query started ({}), replaying", e);
+        crate::metrics::counters::query_replayed();
+        failover
+            .connect()
+            .await?
             .streaming_query(
                 sql,
                 chunk_size,
@@ -376,6 +415,68 @@ impl FraiseClient {
             )
             .await
     }
+
+    /// Connect over TCP (with failover across every host) or a Unix socket.
+    async fn open(
+        info: &ConnectionInfo,
+        config: ConnectionConfig,
+        tls_config: Option<TlsConfig>,
+    ) -> Result<Self> {
+        match info.transport {
+            TransportType::Tcp => {
+                let failover = FailoverTarget::new(info, config, tls_config);
+                let conn = failover.connect().await?;
+                Ok(Self {
+                    conn,
+                    failover: Some(failover),
+                })
+            }
+            TransportType::Unix => {
+                if tls_config.is_some() {
+                    return Err(crate::WireError::Config(
+                        "TLS is only supported for TCP connections".into(),
+                    ));
+                }
+                let path = info.unix_socket.as_ref().ok_or_else(|| {
+                    crate::WireError::Config("Unix transport requires a socket path".into())
+                })?;
+                let transport =
+                    with_connect_timeout(config.connect_timeout, Transport::connect_unix(path))
+                        .await?;
+
+                let mut conn = Connection::new(transport);
+                conn.startup(&config).await?;
+
+                Ok(Self {
+                    conn,
+                    failover: None,
+                })
+            }
+        }
+    }
+}
+
+/// Whether `e` means the connection itself is gone (worth reconnecting for).
+const fn is_connection_failure(e: &crate::WireError) -> bool {
+    e.is_retriable() || matches!(e, crate::WireError::Connection(_))
+}
+
+/// Decode one pipelined query's messages into its JSON rows.
+///
+/// # Errors
+///
+/// Returns [`crate::WireError::InvalidSchema`] if the query does not return a
+/// single JSON `data` column, or a JSON error if a row fails to parse.
+fn json_rows(messages: &[BackendMessage]) -> Result<Vec<serde_json::Value>> {
+    let mut rows = Vec::new();
+    for msg in messages {
+        match msg {
+            BackendMessage::RowDescription(_) => validate_row_description(msg)?,
+            BackendMessage::DataRow(_) => rows.push(parse_json(extract_json_bytes(msg)?)?),
+            _ => {}
+        }
+    }
+    Ok(rows)
 }
 
 /// Apply an optional connect timeout to a transport-connect future.
@@ -385,7 +486,10 @@ impl FraiseClient {
 /// to completion unbounded. The `connect_timeout` config field was parsed but never
 /// applied to the connect path (audit L-wire-timeout); the `connect_with_config*`
 /// methods now route their transport setup through this helper.
-async fn with_connect_timeout<F, T>(timeout: Option<std::time::Duration>, fut: F) -> Result<T>
+pub(super) async fn with_connect_timeout<F, T>(
+    timeout: Option<std::time::Duration>,
+    fut: F,
+) -> Result<T>
 where
     F: std::future::Future<Output = Result<T>>,
 {
@@ -1,8 +1,9 @@
 #![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
 
-//! Tests for the connect-timeout helper (audit L-wire-timeout).
+//! Tests for the connect-timeout helper (audit L-wire-timeout) and batch row decoding.
 
-use super::with_connect_timeout;
+use super::{json_rows, with_connect_timeout};
+use crate::protocol::{BackendMessage, FieldDescription};
 use crate::{Result, WireError};
 use std::time::Duration;
 
@@ -31,3 +32,54 @@ async fn no_timeout_passes_the_result_through() {
     let value = with_connect_timeout(None, ready).await.unwrap();
     assert_eq!(value, 7);
 }
+
+// ── Pipelined batch decoding ──────────────────────────────────────────────
+
+fn data_description() -> BackendMessage {
+    BackendMessage::RowDescription(vec![FieldDescription {
+        name: "data".to_string(),
+        table_oid: 0,
+        column_attr: 0,
+        type_oid: 3802, // jsonb
+        type_size: -1,
+        type_modifier: -1,
+        format_code: 0,
+    }])
+}
+
+#[test]
+fn json_rows_decodes_data_rows() {
+    let messages = vec![
+        data_description(),
+        BackendMessage::DataRow(vec![Some(bytes::Bytes::from_static(br#"{"id":1}"#))]),
+        BackendMessage::DataRow(vec![Some(bytes::Bytes::from_static(br#"{"id":2}"#))]),
+        BackendMessage::CommandComplete("SELECT 2".to_string()),
+    ];
+
+    let rows = json_rows(&messages).unwrap();
+    assert_eq!(
+        rows,
+        vec![serde_json::json!({"id": 1}), serde_json::json!({"id": 2})]
+    );
+}
+
+#[test]
+fn json_rows_rejects_non_json_result() {
+    let messages = vec![
+        BackendMessage::RowDescription(vec![FieldDescription {
+            name: "?column?".to_string(),
+            table_oid: 0,
+            column_attr: 0,
+            type_oid: 23, // int4
+            type_size: 4,
+            type_modifier: -1,
+            format_code: 0,
+        }]),
+        BackendMessage::CommandComplete("SELECT 0".to_string()),
+    ];
+
+    assert!(matches!(
+        json_rows(&messages),
+        Err(WireError::InvalidSchema(_))
+    ));
+}
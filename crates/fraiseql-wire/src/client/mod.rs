@@ -3,6 +3,7 @@
 //! This module provides the user-facing API for fraiseql-wire.
 
 mod connection_string;
+mod failover;
 mod fraise_client;
 mod query_builder;
 
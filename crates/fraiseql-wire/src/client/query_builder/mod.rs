@@ -5,7 +5,8 @@
 //! **IMPORTANT**: Type T is **consumer-side only**.
 //!
 //! Type T does NOT affect:
-//! - SQL generation (always `SELECT data FROM {entity}`)
+//! - SQL generation (always `SELECT data FROM {entity}`, optionally wrapped in
+//!   `COPY ... TO STDOUT` by [`QueryBuilder::copy_streaming`])
 //! - Filtering (`where_sql`, `where_rust`, `order_by`)
 //! - Wire protocol (identical for all T)
 //!
@@ -16,7 +17,7 @@
 use crate::client::FraiseClient;
 #[allow(unused_imports)] // Reason: used only in doc links for `# Errors` sections
 use crate::error::WireError;
-use crate::stream::QueryStream;
+use crate::stream::{copy_statement, CopyFormat, QueryStream};
 use crate::Result;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
@@ -78,6 +79,7 @@ pub struct QueryBuilder<T: DeserializeOwned + Unpin + 'static = serde_json::Valu
     adaptive_min_chunk_size: Option<usize>,
     adaptive_max_chunk_size: Option<usize>,
     custom_select: Option<String>, // Optional custom SELECT clause for SQL projection
+    copy_format: Option<CopyFormat>, // Stream via COPY TO STDOUT when the scan is unordered
     _phantom: PhantomData<T>,
 }
 
@@ -105,6 +107,7 @@ impl<T: DeserializeOwned + Unpin + 'static> QueryBuilder<T> {
             adaptive_min_chunk_size: None,
             adaptive_max_chunk_size: None,
             custom_select: None,
+            copy_format: None,
             _phantom: PhantomData,
         }
     }
@@ -178,6 +181,37 @@ impl<T: DeserializeOwned + Unpin + 'static> QueryBuilder<T> {
         self
     }
 
+    /// Stream the scan with `COPY ... TO STDOUT` instead of a plain `SELECT`
+    ///
+    /// `COPY` skips per-row `DataRow` framing on the server and is
+    /// substantially cheaper per row for large scans. It only applies to
+    /// unordered, unbounded scans: when `order_by`, `limit` or `offset` is set
+    /// the query silently falls back to the normal `SELECT` path. SQL and Rust
+    /// predicates and projections work on both paths.
+    ///
+    /// `CopyFormat::Binary` avoids the text format's backslash escaping and is
+    /// the better choice unless a proxy in between rejects binary `COPY`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// // Requires: live Postgres connection via FraiseClient.
+    /// # async fn example(client: fraiseql_wire::FraiseClient) -> fraiseql_wire::Result<()> {
+    /// use fraiseql_wire::stream::CopyFormat;
+    ///
+    /// let stream = client
+    ///     .query::<serde_json::Value>("events")
+    ///     .copy_streaming(CopyFormat::Binary)
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn copy_streaming(mut self, format: CopyFormat) -> Self {
+        self.copy_format = Some(format);
+        self
+    }
+
     /// Set LIMIT clause to restrict result set size
     ///
     /// # Example
@@ -474,11 +508,22 @@ impl<T: DeserializeOwned + Unpin + 'static> QueryBuilder<T> {
 
     /// Build SQL query
     fn build_sql(&self) -> Result<String> {
-        // Use custom SELECT clause if provided, otherwise default to "SELECT data"
-        let select_clause = if let Some(ref projection) = self.custom_select {
-            format!("SELECT {} as data", projection)
-        } else {
-            "SELECT data".to_string()
+        let copy_format = effective_copy_format(
+            self.copy_format,
+            self.order_by.is_some(),
+            self.limit.is_some() || self.offset.is_some(),
+        );
+        if self.copy_format.is_some() && copy_format.is_none() {
+            tracing::debug!("ORDER BY/LIMIT/OFFSET present, not streaming via COPY");
+        }
+
+        // Use custom SELECT clause if provided, otherwise default to "SELECT data".
+        // COPY streams the JSON as text (see `stream::copy_statement`).
+        let select_clause = match (&self.custom_select, copy_format) {
+            (Some(projection), None) => format!("SELECT {} as data", projection),
+            (Some(projection), Some(_)) => format!("SELECT ({})::text as data", projection),
+            (None, None) => "SELECT data".to_string(),
+            (None, Some(_)) => "SELECT data::text".to_string(),
         };
 
         let mut sql = format!("{} FROM {}", select_clause, self.entity);
@@ -501,7 +546,27 @@ impl<T: DeserializeOwned + Unpin + 'static> QueryBuilder<T> {
             sql.push_str(&format!(" OFFSET {}", offset));
         }
 
-        Ok(sql)
+        Ok(match copy_format {
+            Some(format) => copy_statement(&sql, format),
+            None => sql,
+        })
+    }
+}
+
+/// The COPY format to use, or `None` to fall back to a plain `SELECT`.
+///
+/// `COPY` only streams unordered, unbounded scans: ordering and LIMIT/OFFSET
+/// would be silently honoured inside the subquery, but those queries are the
+/// small, latency-bound ones where `COPY`'s setup cost is not worth paying.
+const fn effective_copy_format(
+    requested: Option<CopyFormat>,
+    has_order_by: bool,
+    has_limit_or_offset: bool,
+) -> Option<CopyFormat> {
+    if has_order_by || has_limit_or_offset {
+        None
+    } else {
+        requested
     }
 }
 
@@ -233,3 +233,30 @@ fn test_stream_pipeline_type_flow() {
     let _final_stream: Box<dyn futures::stream::Stream<Item = crate::Result<TestUser>> + Unpin> =
         Box::new(typed);
 }
+
+#[test]
+fn test_copy_streaming_applies_to_unordered_unbounded_scans() {
+    use super::effective_copy_format;
+    use crate::stream::CopyFormat;
+
+    assert_eq!(
+        effective_copy_format(Some(CopyFormat::Binary), false, false),
+        Some(CopyFormat::Binary)
+    );
+    assert_eq!(effective_copy_format(None, false, false), None);
+}
+
+#[test]
+fn test_copy_streaming_falls_back_with_order_by_or_limit() {
+    use super::effective_copy_format;
+    use crate::stream::CopyFormat;
+
+    assert_eq!(
+        effective_copy_format(Some(CopyFormat::Text), true, false),
+        None
+    );
+    assert_eq!(
+        effective_copy_format(Some(CopyFormat::Text), false, true),
+        None
+    );
+}
@@ -1,5 +1,7 @@
 //! Connection configuration types
 
+use crate::auth::ChannelBindingMode;
+use crate::connection::statement_cache::DEFAULT_STATEMENT_CACHE_CAPACITY;
 use std::collections::HashMap;
 use std::time::Duration;
 use zeroize::Zeroizing;
@@ -28,6 +30,11 @@ pub struct ConnectionConfig {
     pub application_name: Option<String>,
     /// Postgres `extra_float_digits` setting
     pub extra_float_digits: Option<i32>,
+    /// SCRAM channel binding policy (default: prefer)
+    pub channel_binding: ChannelBindingMode,
+    /// Prepared statements cached per connection for pipelined queries
+    /// (default: 100, 0 = use unnamed statements only)
+    pub statement_cache_capacity: usize,
 }
 
 impl ConnectionConfig {
@@ -45,6 +52,8 @@ impl ConnectionConfig {
     /// - `keepalive_idle`: None
     /// - `application_name`: None
     /// - `extra_float_digits`: None
+    /// - `channel_binding`: `Prefer`
+    /// - `statement_cache_capacity`: 100
     ///
     /// For configured timeouts and keepalive, use `builder()` instead.
     pub fn new(database: impl Into<String>, user: impl Into<String>) -> Self {
@@ -58,6 +67,8 @@ impl ConnectionConfig {
             keepalive_idle: None,
             application_name: None,
             extra_float_digits: None,
+            channel_binding: ChannelBindingMode::Prefer,
+            statement_cache_capacity: DEFAULT_STATEMENT_CACHE_CAPACITY,
         }
     }
 
@@ -89,6 +100,8 @@ impl ConnectionConfig {
             keepalive_idle: None,
             application_name: None,
             extra_float_digits: None,
+            channel_binding: ChannelBindingMode::Prefer,
+            statement_cache_capacity: DEFAULT_STATEMENT_CACHE_CAPACITY,
         }
     }
 
@@ -134,6 +147,8 @@ pub struct ConnectionConfigBuilder {
     pub(super) keepalive_idle: Option<Duration>,
     pub(super) application_name: Option<String>,
     pub(super) extra_float_digits: Option<i32>,
+    pub(super) channel_binding: ChannelBindingMode,
+    pub(super) statement_cache_capacity: usize,
 }
 
 impl ConnectionConfigBuilder {
@@ -209,6 +224,38 @@ impl ConnectionConfigBuilder {
         self
     }
 
+    /// Set the SCRAM channel binding policy
+    ///
+    /// Default: `Prefer` (use `SCRAM-SHA-256-PLUS` when connected over TLS and
+    /// the server offers it). `Require` fails the connection unless the server
+    /// authenticates with channel binding, which protects against a
+    /// man-in-the-middle holding a certificate the client would trust.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Channel binding policy
+    pub const fn channel_binding(mut self, mode: ChannelBindingMode) -> Self {
+        self.channel_binding = mode;
+        self
+    }
+
+    /// Set how many prepared statements each connection keeps
+    ///
+    /// Default: 100. Queries run through
+    /// [`Connection::pipelined_query`](crate::connection::Connection::pipelined_query)
+    /// are prepared once per connection and reused by SQL text; the least
+    /// recently used statement is closed when the cache is full. Use 0 behind
+    /// a transaction-mode pooler (e.g. `PgBouncer`), where named statements
+    /// do not survive between transactions.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum cached statements (0 disables caching)
+    pub const fn statement_cache_capacity(mut self, capacity: usize) -> Self {
+        self.statement_cache_capacity = capacity;
+        self
+    }
+
     /// Build the configuration
     #[must_use = "building a config that is not used has no effect"]
     pub fn build(self) -> ConnectionConfig {
@@ -222,6 +269,8 @@ impl ConnectionConfigBuilder {
             keepalive_idle: self.keepalive_idle,
             application_name: self.application_name,
             extra_float_digits: self.extra_float_digits,
+            channel_binding: self.channel_binding,
+            statement_cache_capacity: self.statement_cache_capacity,
         }
     }
 }
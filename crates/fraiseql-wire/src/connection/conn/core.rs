@@ -1,12 +1,15 @@
 //! Core `Connection` type and implementation
 
 use super::config::ConnectionConfig;
-use super::helpers::extract_entity_from_query;
-use crate::auth::ScramClient;
+use super::helpers::{extract_entity_from_query, select_channel_binding};
+use crate::auth::{ChannelBinding, ChannelBindingMode, ScramClient};
 use crate::connection::state::ConnectionState;
+use crate::connection::statement_cache::{Prepared, StatementCache};
 use crate::connection::transport::Transport;
+use crate::protocol::constants::target;
 use crate::protocol::{
-    decode_message, encode_message, AuthenticationMessage, BackendMessage, FrontendMessage,
+    decode_message, encode_message, encode_messages, AuthenticationMessage, BackendMessage,
+    FrontendMessage,
 };
 use crate::{Result, WireError};
 use bytes::{Buf, BytesMut};
@@ -24,6 +27,7 @@ pub struct Connection {
     read_buf: BytesMut,
     process_id: Option<i32>,
     secret_key: Option<i32>,
+    statements: StatementCache,
 }
 
 impl Connection {
@@ -35,6 +39,7 @@ impl Connection {
             read_buf: BytesMut::with_capacity(8192),
             process_id: None,
             secret_key: None,
+            statements: StatementCache::new(0),
         }
     }
 
@@ -95,6 +100,7 @@ impl Connection {
             self.authenticate(config).await?;
 
             self.state.transition(ConnectionState::Idle)?;
+            self.statements = StatementCache::new(config.statement_cache_capacity);
             tracing::info!("startup complete");
             Ok(())
         }
@@ -110,6 +116,8 @@ impl Connection {
     async fn authenticate(&mut self, config: &ConnectionConfig) -> Result<()> {
         let auth_start = std::time::Instant::now();
         let mut auth_mechanism = "unknown";
+        let mut channel_bound = false;
+        let binding_required = config.channel_binding == ChannelBindingMode::Require;
 
         loop {
             let msg = self.receive_message().await?;
@@ -117,6 +125,17 @@ impl Connection {
             match msg {
                 BackendMessage::Authentication(auth) => match auth {
                     AuthenticationMessage::Ok => {
+                        // A server (or impostor) may skip SASL entirely and
+                        // accept the client without proving anything.
+                        if binding_required && !channel_bound {
+                            crate::metrics::counters::auth_failed(
+                                auth_mechanism,
+                                "channel_binding",
+                            );
+                            return Err(WireError::Authentication(
+                                "channel binding required, but server skipped SASL".into(),
+                            ));
+                        }
                         tracing::debug!("authentication successful");
                         crate::metrics::counters::auth_successful(auth_mechanism);
                         crate::metrics::histograms::auth_duration(
@@ -128,6 +147,13 @@ impl Connection {
                     AuthenticationMessage::CleartextPassword => {
                         auth_mechanism = crate::metrics::labels::MECHANISM_CLEARTEXT;
                         crate::metrics::counters::auth_attempted(auth_mechanism);
+                        // SECURITY: never send the password in the clear when the
+                        // caller demanded channel binding.
+                        if binding_required {
+                            return Err(WireError::Authentication(
+                                "channel binding required; server requested cleartext".into(),
+                            ));
+                        }
 
                         let password = config
                             .password
@@ -145,7 +171,7 @@ impl Connection {
                     AuthenticationMessage::Sasl { mechanisms } => {
                         auth_mechanism = crate::metrics::labels::MECHANISM_SCRAM;
                         crate::metrics::counters::auth_attempted(auth_mechanism);
-                        self.handle_sasl(&mechanisms, config).await?;
+                        channel_bound = self.handle_sasl(&mechanisms, config).await?;
                     }
                     AuthenticationMessage::SaslContinue { .. } => {
                         return Err(WireError::Protocol(
@@ -187,19 +213,29 @@ impl Connection {
         Ok(())
     }
 
-    /// Handle SASL authentication (SCRAM-SHA-256)
+    /// Handle SASL authentication (SCRAM-SHA-256 or SCRAM-SHA-256-PLUS)
+    ///
+    /// Returns whether the exchange was bound to the TLS channel.
     async fn handle_sasl(
         &mut self,
         mechanisms: &[String],
         config: &ConnectionConfig,
-    ) -> Result<()> {
-        // Check if server supports SCRAM-SHA-256
-        if !mechanisms.contains(&"SCRAM-SHA-256".to_string()) {
-            return Err(WireError::Authentication(format!(
-                "server does not support SCRAM-SHA-256. Available: {}",
-                mechanisms.join(", ")
-            )));
-        }
+    ) -> Result<bool> {
+        // The certificate hash fails for signature algorithms without one
+        // (e.g. Ed25519); only `Require` turns that into a connection error.
+        let tls_end_point = match config.channel_binding {
+            ChannelBindingMode::Disable => None,
+            ChannelBindingMode::Require => self.transport.tls_server_end_point()?,
+            ChannelBindingMode::Prefer => {
+                self.transport.tls_server_end_point().unwrap_or_else(|e| {
+                    tracing::debug!("not using channel binding: {}", e);
+                    None
+                })
+            }
+        };
+        let channel_binding =
+            select_channel_binding(config.channel_binding, mechanisms, tls_end_point)?;
+        let channel_bound = matches!(channel_binding, ChannelBinding::TlsServerEndPoint(_));
 
         // Get password
         let password = config.password.as_ref().ok_or_else(|| {
@@ -208,13 +244,14 @@ impl Connection {
 
         // Create SCRAM client
         // SECURITY: Convert from Zeroizing wrapper while preserving password content
-        let mut scram = ScramClient::new(config.user.clone(), password.as_str().to_string());
-        tracing::debug!("initiating SCRAM-SHA-256 authentication");
+        let mut scram = ScramClient::new(config.user.clone(), password.as_str().to_string())
+            .with_channel_binding(channel_binding);
+        tracing::debug!("initiating {} authentication", scram.mechanism());
 
         // Send SaslInitialResponse with client first message
         let client_first = scram.client_first();
         let msg = FrontendMessage::SaslInitialResponse {
-            mechanism: "SCRAM-SHA-256".to_string(),
+            mechanism: scram.mechanism().to_string(),
             data: client_first.into_bytes(),
         };
         self.send_message(&msg).await?;
@@ -279,8 +316,8 @@ impl Connection {
             .verify_server_final(&server_final, &scram_state)
             .map_err(|e| WireError::Authentication(format!("SCRAM verification failed: {}", e)))?;
 
-        tracing::debug!("SCRAM-SHA-256 authentication successful");
-        Ok(())
+        tracing::debug!("{} authentication successful", scram.mechanism());
+        Ok(channel_bound)
     }
 
     /// Execute a simple query (returns all backend messages)
@@ -321,6 +358,128 @@ impl Connection {
         Ok(messages)
     }
 
+    /// Run several queries in a single round trip over the extended protocol
+    ///
+    /// Each query is sent as `Parse` + `Bind` + `Describe` + `Execute`, and the
+    /// whole batch is closed by one `Sync`, all in a single write. `Parse` is
+    /// skipped for a query already prepared on this connection (see
+    /// [`ConnectionConfigBuilder::statement_cache_capacity`](super::ConnectionConfigBuilder::statement_cache_capacity)).
+    ///
+    /// Returns one message list per query, in order: its `RowDescription`,
+    /// `DataRow`s and `CommandComplete`. The batch runs as one implicit
+    /// transaction, so the first failing query aborts the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::ConnectionBusy`] if the connection is not idle.
+    /// Returns [`WireError::Sql`] with the first server error of the batch.
+    /// Returns [`WireError`] on any I/O or protocol error during execution.
+    pub async fn pipelined_query(&mut self, queries: &[&str]) -> Result<Vec<Vec<BackendMessage>>> {
+        if self.state != ConnectionState::Idle {
+            return Err(WireError::ConnectionBusy(format!(
+                "connection in state: {}",
+                self.state
+            )));
+        }
+
+        self.state.transition(ConnectionState::QueryInProgress)?;
+
+        let mut msgs = Vec::with_capacity(queries.len() * 4 + 1);
+        // Queries whose `Parse` is in flight, oldest first; any left when the
+        // batch fails were never prepared and must leave the cache.
+        let mut pending_parses = std::collections::VecDeque::new();
+        for query in queries {
+            let statement = if self.statements.is_enabled() {
+                match self.statements.prepare(query) {
+                    Prepared::Cached(name) => {
+                        crate::metrics::counters::statement_cache_lookup(true);
+                        name
+                    }
+                    Prepared::New { name, evicted } => {
+                        crate::metrics::counters::statement_cache_lookup(false);
+                        if let Some(evicted) = evicted {
+                            msgs.push(FrontendMessage::Close {
+                                kind: target::STATEMENT,
+                                name: evicted,
+                            });
+                        }
+                        msgs.push(FrontendMessage::Parse {
+                            name: name.clone(),
+                            query: (*query).to_string(),
+                        });
+                        pending_parses.push_back(*query);
+                        name
+                    }
+                }
+            } else {
+                msgs.push(FrontendMessage::Parse {
+                    name: String::new(),
+                    query: (*query).to_string(),
+                });
+                String::new()
+            };
+            msgs.push(FrontendMessage::Bind {
+                portal: String::new(),
+                statement,
+            });
+            msgs.push(FrontendMessage::Describe {
+                kind: target::PORTAL,
+                name: String::new(),
+            });
+            msgs.push(FrontendMessage::Execute {
+                portal: String::new(),
+                max_rows: 0,
+            });
+        }
+        msgs.push(FrontendMessage::Sync);
+        tracing::debug!(
+            queries = queries.len(),
+            cached_statements = self.statements.len(),
+            "sending query pipeline"
+        );
+        self.send_messages(&msgs).await?;
+
+        self.state.transition(ConnectionState::ReadingResults)?;
+
+        let mut results = Vec::with_capacity(queries.len());
+        let mut current = Vec::new();
+        let mut error = None;
+        loop {
+            let msg = self.receive_message().await?;
+            match msg {
+                BackendMessage::ParseComplete => {
+                    pending_parses.pop_front();
+                }
+                BackendMessage::BindComplete
+                | BackendMessage::CloseComplete
+                | BackendMessage::NoData
+                | BackendMessage::ParameterStatus { .. }
+                | BackendMessage::NoticeResponse(_)
+                | BackendMessage::NotificationResponse { .. } => {}
+                BackendMessage::CommandComplete(_) | BackendMessage::EmptyQueryResponse => {
+                    current.push(msg);
+                    results.push(std::mem::take(&mut current));
+                }
+                BackendMessage::ErrorResponse(err) => {
+                    // The server skips everything up to the Sync.
+                    tracing::debug!("PostgreSQL error response in pipeline: {}", err);
+                    error = Some(err);
+                }
+                BackendMessage::ReadyForQuery { .. } => break,
+                _ => current.push(msg),
+            }
+        }
+
+        self.state.transition(ConnectionState::Idle)?;
+        if let Some(err) = error {
+            for query in pending_parses {
+                self.statements.forget(query);
+            }
+            return Err(WireError::Sql(err.to_string()));
+        }
+        Ok(results)
+    }
+
     /// Send a frontend message
     async fn send_message(&mut self, msg: &FrontendMessage) -> Result<()> {
         let buf = encode_message(msg)?;
@@ -329,6 +488,14 @@ impl Connection {
         Ok(())
     }
 
+    /// Send several frontend messages in one write
+    async fn send_messages(&mut self, msgs: &[FrontendMessage]) -> Result<()> {
+        let buf = encode_messages(msgs)?;
+        self.transport.write_all(&buf).await?;
+        self.transport.flush().await?;
+        Ok(())
+    }
+
     /// Receive a backend message
     ///
     /// Decode errors are classified by [`std::io::ErrorKind`]: only
@@ -419,7 +586,9 @@ impl Connection {
             let startup_start = std::time::Instant::now();
 
             use crate::json::validate_row_description;
-            use crate::stream::{extract_json_bytes, AdaptiveChunking, ChunkingStrategy, JsonStream};
+            use crate::stream::{
+                extract_json_bytes, AdaptiveChunking, ChunkingStrategy, CopyRowDecoder, JsonStream,
+            };
             use serde_json::Value;
             use tokio::sync::mpsc;
 
@@ -437,9 +606,10 @@ impl Connection {
 
             self.state.transition(ConnectionState::ReadingResults)?;
 
-            // Read RowDescription, but handle other messages that may come first
+            // Read RowDescription (or CopyOutResponse for a `COPY ... TO STDOUT`
+            // scan), but handle other messages that may come first
             // (e.g., ParameterStatus, BackendKeyData, ErrorResponse, NoticeResponse)
-            let row_desc;
+            let mut copy_rows = None;
             loop {
                 let msg = self.receive_message().await?;
 
@@ -472,7 +642,13 @@ impl Connection {
                         continue;
                     }
                     BackendMessage::RowDescription(_) => {
-                        row_desc = msg;
+                        validate_row_description(&msg)?;
+                        break;
+                    }
+                    BackendMessage::CopyOutResponse { .. } => {
+                        // COPY path: rows arrive as CopyData and are translated
+                        // back into DataRows by the reader below.
+                        copy_rows = Some(CopyRowDecoder::from_copy_out_response(&msg)?);
                         break;
                     }
                     BackendMessage::ReadyForQuery { .. } => {
@@ -493,8 +669,6 @@ impl Connection {
                 }
             }
 
-            validate_row_description(&row_desc)?;
-
             // Record startup timing
             let startup_duration = startup_start.elapsed().as_millis() as u64;
             let entity = extract_entity_from_query(query).unwrap_or_else(|| "unknown".to_string());
@@ -614,6 +788,16 @@ impl Connection {
 
                     // Read next message
                     msg_result = self.receive_message() => {
+                        // On the COPY path, CopyData rows become DataRows and the
+                        // header/trailer/CopyDone messages carry nothing to stream.
+                        let msg_result = match (msg_result, copy_rows.as_mut()) {
+                            (Ok(msg), Some(copy)) => match copy.translate(msg) {
+                                Ok(Some(msg)) => Ok(msg),
+                                Ok(None) => continue,
+                                Err(e) => Err(e),
+                            },
+                            (msg_result, _) => msg_result,
+                        };
                         match msg_result {
                             Ok(msg) => match msg {
                                 BackendMessage::DataRow(_) => {
@@ -1,5 +1,8 @@
 //! Internal helper functions for connection operations
 
+use crate::auth::{ChannelBinding, ChannelBindingMode};
+use crate::{Result, WireError};
+
 /// Extract entity name from query for metrics
 /// Query format: SELECT data FROM v_{entity} ...
 pub(super) fn extract_entity_from_query(query: &str) -> Option<String> {
@@ -9,9 +12,9 @@ pub(super) fn extract_entity_from_query(query: &str) -> Option<String> {
         if let Some(entity_start) = after_from.find('v').or_else(|| after_from.find('t')) {
             let potential_table = &after_from[entity_start..];
             // Extract table name: "v_entity" or "tv_entity"
+            // `)` ends the table name inside `COPY (SELECT ... FROM v_entity) TO STDOUT`.
             let end_pos = potential_table
-                .find(' ')
-                .or_else(|| potential_table.find(';'))
+                .find([' ', ';', ')'])
                 .unwrap_or(potential_table.len());
             let table_name = &potential_table[..end_pos];
             // Extract entity from table name
@@ -22,3 +25,43 @@ pub(super) fn extract_entity_from_query(query: &str) -> Option<String> {
     }
     None
 }
+
+/// Choose the SCRAM channel binding for a SASL exchange
+///
+/// `tls_end_point` is the `tls-server-end-point` hash of the server
+/// certificate, or `None` when the connection is not TLS. Binding is used
+/// whenever both sides can; `Require` fails when they cannot.
+pub(super) fn select_channel_binding(
+    mode: ChannelBindingMode,
+    mechanisms: &[String],
+    tls_end_point: Option<Vec<u8>>,
+) -> Result<ChannelBinding> {
+    let server_offers_plus = mechanisms.iter().any(|m| m == "SCRAM-SHA-256-PLUS");
+
+    let binding = match (mode, tls_end_point) {
+        (ChannelBindingMode::Disable, _) | (_, None) => ChannelBinding::Unsupported,
+        (_, Some(end_point)) if server_offers_plus => ChannelBinding::TlsServerEndPoint(end_point),
+        (_, Some(_)) => ChannelBinding::NotOffered,
+    };
+
+    if mode == ChannelBindingMode::Require
+        && !matches!(binding, ChannelBinding::TlsServerEndPoint(_))
+    {
+        return Err(WireError::Authentication(format!(
+            "channel binding required, but {}",
+            if server_offers_plus {
+                "the connection is not using TLS"
+            } else {
+                "the server does not offer SCRAM-SHA-256-PLUS"
+            }
+        )));
+    }
+    if !mechanisms.iter().any(|m| m == binding.mechanism()) {
+        return Err(WireError::Authentication(format!(
+            "server does not support {}. Available: {}",
+            binding.mechanism(),
+            mechanisms.join(", ")
+        )));
+    }
+    Ok(binding)
+}
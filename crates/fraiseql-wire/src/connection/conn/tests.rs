@@ -1,6 +1,9 @@
 //! Tests for connection configuration types
+#![allow(clippy::unwrap_used)] // Reason: test code, panics acceptable
 
 use super::config::ConnectionConfig;
+use super::helpers::select_channel_binding;
+use crate::auth::{ChannelBinding, ChannelBindingMode};
 use std::time::Duration;
 
 #[test]
@@ -93,6 +96,86 @@ fn test_connection_config_defaults() {
     assert!(config.keepalive_idle.is_none());
     assert!(config.application_name.is_none());
     assert!(config.extra_float_digits.is_none());
+    assert_eq!(config.channel_binding, ChannelBindingMode::Prefer);
+}
+
+#[test]
+fn test_connection_config_builder_channel_binding() {
+    let config = ConnectionConfig::builder("mydb", "myuser")
+        .channel_binding(ChannelBindingMode::Require)
+        .build();
+
+    assert_eq!(config.channel_binding, ChannelBindingMode::Require);
+}
+
+fn mechanisms(names: &[&str]) -> Vec<String> {
+    names.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_channel_binding_used_when_tls_and_offered() {
+    let binding = select_channel_binding(
+        ChannelBindingMode::Prefer,
+        &mechanisms(&["SCRAM-SHA-256-PLUS", "SCRAM-SHA-256"]),
+        Some(vec![7; 32]),
+    )
+    .unwrap();
+    assert_eq!(binding, ChannelBinding::TlsServerEndPoint(vec![7; 32]));
+}
+
+#[test]
+fn test_channel_binding_prefer_falls_back() {
+    let over_tls = select_channel_binding(
+        ChannelBindingMode::Prefer,
+        &mechanisms(&["SCRAM-SHA-256"]),
+        Some(vec![7; 32]),
+    )
+    .unwrap();
+    assert_eq!(over_tls, ChannelBinding::NotOffered);
+
+    let plaintext = select_channel_binding(
+        ChannelBindingMode::Prefer,
+        &mechanisms(&["SCRAM-SHA-256-PLUS", "SCRAM-SHA-256"]),
+        None,
+    )
+    .unwrap();
+    assert_eq!(plaintext, ChannelBinding::Unsupported);
+}
+
+#[test]
+fn test_channel_binding_disable_never_binds() {
+    let binding = select_channel_binding(
+        ChannelBindingMode::Disable,
+        &mechanisms(&["SCRAM-SHA-256-PLUS", "SCRAM-SHA-256"]),
+        Some(vec![7; 32]),
+    )
+    .unwrap();
+    assert_eq!(binding, ChannelBinding::Unsupported);
+}
+
+#[test]
+fn test_channel_binding_require_rejects_unbound_exchange() {
+    for (offered, end_point) in [
+        (&["SCRAM-SHA-256"][..], Some(vec![7; 32])),
+        (&["SCRAM-SHA-256-PLUS", "SCRAM-SHA-256"][..], None),
+    ] {
+        let result =
+            select_channel_binding(ChannelBindingMode::Require, &mechanisms(offered), end_point);
+        assert!(
+            matches!(result, Err(crate::WireError::Authentication(ref msg)) if msg.contains("channel binding required")),
+            "expected channel binding failure, got: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_channel_binding_requires_a_supported_mechanism() {
+    let result = select_channel_binding(
+        ChannelBindingMode::Prefer,
+        &mechanisms(&["SCRAM-SHA-256-PLUS"]),
+        None,
+    );
+    assert!(matches!(result, Err(crate::WireError::Authentication(_))));
 }
 
 // Verify that async functions return Send futures (compile-time check)
@@ -3,15 +3,18 @@
 //! This module handles:
 //! * Transport abstraction (TCP vs Unix socket)
 //! * Connection lifecycle (startup, auth, query execution)
+//! * Prepared statement caching for pipelined queries
 //! * State machine enforcement
 //! * TLS configuration and support
 
 mod conn;
 mod state;
+mod statement_cache;
 mod tls;
 mod transport;
 
 pub use conn::{Connection, ConnectionConfig, ConnectionConfigBuilder};
 pub use state::ConnectionState;
-pub use tls::{parse_server_name, TlsConfig};
+pub use statement_cache::DEFAULT_STATEMENT_CACHE_CAPACITY;
+pub use tls::{parse_server_name, SslMode, TlsConfig};
 pub use transport::Transport;
@@ -0,0 +1,103 @@
+//! Per-connection prepared statement cache
+//!
+//! Maps SQL text to the name of a statement already prepared on the
+//! connection, so a repeated query skips `Parse` and the server skips
+//! re-planning it. The cache is bounded: once full, the least recently used
+//! statement is evicted and must be closed on the server.
+
+use std::collections::HashMap;
+
+/// Default number of prepared statements kept per connection.
+pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 100;
+
+/// Outcome of looking up a query in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Prepared {
+    /// Already prepared on this connection under this name.
+    Cached(String),
+    /// Must be prepared (`Parse`) under `name`. `evicted` names a statement
+    /// dropped to make room, which should be closed on the server.
+    New {
+        name: String,
+        evicted: Option<String>,
+    },
+}
+
+#[derive(Debug)]
+struct Entry {
+    name: String,
+    last_used: u64,
+}
+
+/// LRU cache of prepared statement names, keyed by SQL text.
+#[derive(Debug)]
+pub(crate) struct StatementCache {
+    capacity: usize,
+    next_id: u64,
+    clock: u64,
+    entries: HashMap<String, Entry>,
+}
+
+impl StatementCache {
+    /// Cache holding at most `capacity` statements; 0 disables caching.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            next_id: 0,
+            clock: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Whether queries should be prepared as named, cached statements.
+    pub(crate) const fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Number of cached statements.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Look up `sql`, reserving a statement name on a miss.
+    pub(crate) fn prepare(&mut self, sql: &str) -> Prepared {
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(sql) {
+            entry.last_used = self.clock;
+            return Prepared::Cached(entry.name.clone());
+        }
+
+        let evicted = if self.entries.len() >= self.capacity {
+            self.evict_least_recently_used()
+        } else {
+            None
+        };
+        self.next_id += 1;
+        let name = format!("fraiseql_s{}", self.next_id);
+        self.entries.insert(
+            sql.to_string(),
+            Entry {
+                name: name.clone(),
+                last_used: self.clock,
+            },
+        );
+        Prepared::New { name, evicted }
+    }
+
+    /// Drop `sql` from the cache, e.g. because its `Parse` never completed.
+    pub(crate) fn forget(&mut self, sql: &str) {
+        self.entries.remove(sql);
+    }
+
+    fn evict_least_recently_used(&mut self) -> Option<String> {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(sql, _)| sql.clone())?;
+        self.entries.remove(&oldest).map(|entry| entry.name)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use super::*;
+
+fn new_name(prepared: Prepared) -> String {
+    match prepared {
+        Prepared::New { name, .. } => name,
+        Prepared::Cached(name) => panic!("expected a miss, got cached {name}"),
+    }
+}
+
+#[test]
+fn repeated_sql_hits_the_cache() {
+    let mut cache = StatementCache::new(4);
+    let name = new_name(cache.prepare("SELECT 1"));
+    assert_eq!(cache.prepare("SELECT 1"), Prepared::Cached(name));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn distinct_sql_gets_distinct_names() {
+    let mut cache = StatementCache::new(4);
+    let a = new_name(cache.prepare("SELECT 1"));
+    let b = new_name(cache.prepare("SELECT 2"));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn full_cache_evicts_least_recently_used() {
+    let mut cache = StatementCache::new(2);
+    let a = new_name(cache.prepare("SELECT 1"));
+    let _b = new_name(cache.prepare("SELECT 2"));
+    // Touch the first statement so the second becomes the LRU entry.
+    assert_eq!(cache.prepare("SELECT 1"), Prepared::Cached(a));
+
+    match cache.prepare("SELECT 3") {
+        Prepared::New { evicted, .. } => assert_eq!(evicted.as_deref(), Some("fraiseql_s2")),
+        Prepared::Cached(name) => panic!("expected a miss, got cached {name}"),
+    }
+    assert_eq!(cache.len(), 2);
+    assert!(matches!(cache.prepare("SELECT 2"), Prepared::New { .. }));
+}
+
+#[test]
+fn forgotten_statement_is_prepared_again() {
+    let mut cache = StatementCache::new(4);
+    let first = new_name(cache.prepare("SELEC 1"));
+    cache.forget("SELEC 1");
+    let second = new_name(cache.prepare("SELEC 1"));
+    assert_ne!(first, second);
+}
+
+#[test]
+fn zero_capacity_disables_caching() {
+    assert!(!StatementCache::new(0).is_enabled());
+    assert!(StatementCache::new(DEFAULT_STATEMENT_CACHE_CAPACITY).is_enabled());
+}
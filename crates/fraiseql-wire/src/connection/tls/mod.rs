@@ -5,14 +5,38 @@
 
 use crate::{Result, WireError};
 use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
 use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
 use rustls::RootCertStore;
-use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+use rustls::{CertificateError, ClientConfig, DigitallySignedStruct, SignatureScheme};
 use rustls_pemfile::Item;
 use std::fmt::Debug;
 use std::fs;
 use std::sync::Arc;
 
+/// Postgres `sslmode`, mirroring `fraiseql_core::config::SslMode`.
+///
+/// Only the TLS modes are meaningful for a [`TlsConfig`]; connect without one
+/// for `Disable`. A connection made with a `TlsConfig` always requires TLS, and
+/// unlike libpq, `Prefer` and `Require` still verify the certificate chain
+/// (they behave like `VerifyCa`): fraiseql-wire never accepts an unverified
+/// certificate outside of [`TlsConfigBuilder::danger_accept_invalid_certs`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SslMode {
+    /// Disable SSL.
+    Disable,
+    /// Prefer SSL but allow non-SSL.
+    Prefer,
+    /// Require SSL.
+    Require,
+    /// Require SSL and verify CA.
+    VerifyCa,
+    /// Require SSL and verify full certificate (chain and hostname).
+    #[default]
+    VerifyFull,
+}
+
 /// TLS configuration for secure Postgres connections.
 ///
 /// Provides a builder for creating TLS configurations with various certificate handling options.
@@ -52,6 +76,8 @@ pub struct TlsConfig {
     danger_accept_invalid_certs: bool,
     /// Whether to accept invalid hostnames (development only)
     danger_accept_invalid_hostnames: bool,
+    /// Certificate verification level
+    ssl_mode: SslMode,
     /// Compiled rustls `ClientConfig`
     client_config: Arc<ClientConfig>,
 }
@@ -96,6 +122,12 @@ impl TlsConfig {
     pub const fn danger_accept_invalid_hostnames(&self) -> bool {
         self.danger_accept_invalid_hostnames
     }
+
+    /// Get the configured `sslmode`.
+    #[must_use]
+    pub const fn ssl_mode(&self) -> SslMode {
+        self.ssl_mode
+    }
 }
 
 impl std::fmt::Debug for TlsConfig {
@@ -111,6 +143,7 @@ impl std::fmt::Debug for TlsConfig {
                 "danger_accept_invalid_hostnames",
                 &self.danger_accept_invalid_hostnames,
             )
+            .field("ssl_mode", &self.ssl_mode)
             .field("client_config", &"<ClientConfig>")
             .finish()
     }
@@ -125,6 +158,7 @@ pub struct TlsConfigBuilder {
     verify_hostname: bool,
     danger_accept_invalid_certs: bool,
     danger_accept_invalid_hostnames: bool,
+    ssl_mode: SslMode,
 }
 
 impl Default for TlsConfigBuilder {
@@ -134,6 +168,7 @@ impl Default for TlsConfigBuilder {
             verify_hostname: true,
             danger_accept_invalid_certs: false,
             danger_accept_invalid_hostnames: false,
+            ssl_mode: SslMode::VerifyFull,
         }
     }
 }
@@ -186,6 +221,34 @@ impl TlsConfigBuilder {
         self
     }
 
+    /// Set the `sslmode` (default: `VerifyFull`).
+    ///
+    /// `VerifyFull` checks the certificate chain and that the certificate is
+    /// valid for the server hostname. `VerifyCa` (and `Prefer`/`Require`, see
+    /// [`SslMode`]) check the chain only, for servers reached through an
+    /// address their certificate does not name.
+    ///
+    /// # Errors
+    ///
+    /// [`TlsConfigBuilder::build`] returns `WireError::Config` for
+    /// `SslMode::Disable`: connect without a `TlsConfig` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// // Requires: CA certificate file at the specified path.
+    /// use fraiseql_wire::connection::{SslMode, TlsConfig};
+    /// let tls = TlsConfig::builder()
+    ///     .ca_cert_path("/etc/ssl/certs/rds-ca.pem")
+    ///     .ssl_mode(SslMode::VerifyCa)
+    ///     .build()?;
+    /// # fraiseql_wire::Result::Ok(())
+    /// ```
+    pub const fn ssl_mode(mut self, mode: SslMode) -> Self {
+        self.ssl_mode = mode;
+        self
+    }
+
     /// ⚠️ **DANGER**: Accept invalid certificates (development only).
     ///
     /// **NEVER use in production.** This disables certificate validation entirely,
@@ -258,6 +321,12 @@ impl TlsConfigBuilder {
     pub fn build(self) -> Result<TlsConfig> {
         // SECURITY: Validate TLS configuration before creating client
         validate_tls_security(self.danger_accept_invalid_certs)?;
+        if self.ssl_mode == SslMode::Disable {
+            return Err(WireError::Config(
+                "sslmode=disable cannot be used with a TLS configuration; connect without TLS"
+                    .into(),
+            ));
+        }
 
         let client_config = if self.danger_accept_invalid_certs {
             // Create a client config that accepts any certificate (development only)
@@ -293,11 +362,25 @@ impl TlsConfigBuilder {
             };
 
             // Create ClientConfig using the correct API for rustls 0.23
-            Arc::new(
-                ClientConfig::builder()
-                    .with_root_certificates(root_store)
-                    .with_no_client_auth(),
-            )
+            if self.checks_hostname() {
+                Arc::new(
+                    ClientConfig::builder()
+                        .with_root_certificates(root_store)
+                        .with_no_client_auth(),
+                )
+            } else {
+                let webpki = WebPkiServerVerifier::builder(Arc::new(root_store))
+                    .build()
+                    .map_err(|e| {
+                        WireError::Config(format!("Failed to build certificate verifier: {}", e))
+                    })?;
+                Arc::new(
+                    ClientConfig::builder()
+                        .dangerous()
+                        .with_custom_certificate_verifier(Arc::new(ChainOnlyVerifier(webpki)))
+                        .with_no_client_auth(),
+                )
+            }
         };
 
         Ok(TlsConfig {
@@ -305,10 +388,18 @@ impl TlsConfigBuilder {
             verify_hostname: self.verify_hostname,
             danger_accept_invalid_certs: self.danger_accept_invalid_certs,
             danger_accept_invalid_hostnames: self.danger_accept_invalid_hostnames,
+            ssl_mode: self.ssl_mode,
             client_config,
         })
     }
 
+    /// Whether the server certificate must be valid for the hostname.
+    fn checks_hostname(&self) -> bool {
+        self.ssl_mode == SslMode::VerifyFull
+            && self.verify_hostname
+            && !self.danger_accept_invalid_hostnames
+    }
+
     /// Load a custom CA certificate from a PEM file.
     fn load_custom_ca(&self, ca_path: &str) -> Result<RootCertStore> {
         let ca_cert_data = fs::read(ca_path).map_err(|e| {
@@ -482,3 +573,54 @@ impl ServerCertVerifier for NoVerifier {
         ]
     }
 }
+
+/// A certificate verifier that checks the chain but not the hostname.
+///
+/// Used for `sslmode=verify-ca`: the certificate must chain to a trusted root,
+/// but may name a different host (e.g. a managed database reached through a
+/// proxy or private endpoint).
+#[derive(Debug)]
+struct ChainOnlyVerifier(Arc<WebPkiServerVerifier>);
+
+impl ServerCertVerifier for ChainOnlyVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        match self
+            .0
+            .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+        {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) => Ok(ServerCertVerified::assertion()),
+            other => other,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.0.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.0.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.supported_verify_schemes()
+    }
+}
@@ -113,3 +113,31 @@ fn test_normal_tls_config_works() {
 
     assert!(!config.danger_accept_invalid_certs());
 }
+
+#[test]
+fn test_ssl_mode_defaults_to_verify_full() {
+    assert_eq!(TlsConfigBuilder::default().ssl_mode, SslMode::VerifyFull);
+    assert!(TlsConfigBuilder::default().checks_hostname());
+}
+
+#[test]
+fn test_verify_ca_skips_hostname_check() {
+    install_crypto_provider();
+
+    let builder = TlsConfig::builder().ssl_mode(SslMode::VerifyCa);
+    assert!(!builder.checks_hostname());
+
+    let config = builder
+        .build()
+        .expect("verify-ca TLS config should build successfully");
+    assert_eq!(config.ssl_mode(), SslMode::VerifyCa);
+}
+
+#[test]
+fn test_ssl_mode_disable_is_rejected() {
+    let result = TlsConfig::builder().ssl_mode(SslMode::Disable).build();
+    assert!(
+        matches!(result, Err(WireError::Config(ref msg)) if msg.contains("sslmode=disable")),
+        "sslmode=disable must be rejected, got: {result:?}"
+    );
+}
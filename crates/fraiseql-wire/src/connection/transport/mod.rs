@@ -82,6 +82,31 @@ impl TcpVariant {
         Ok(())
     }
 
+    /// `tls-server-end-point` channel binding data for this stream
+    ///
+    /// Returns `None` for plain TCP, or if the server presented no certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::Authentication`] if the server certificate cannot be
+    /// used for channel binding.
+    pub fn tls_server_end_point(&self) -> Result<Option<Vec<u8>>> {
+        let TcpVariant::Tls(stream) = self else {
+            return Ok(None);
+        };
+        let Some(cert) = stream
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certs| certs.first())
+        else {
+            return Ok(None);
+        };
+        crate::auth::channel_binding::tls_server_end_point(cert)
+            .map(Some)
+            .map_err(|e| WireError::Authentication(e.to_string()))
+    }
+
     /// Apply TCP keepalive settings to the underlying socket.
     ///
     /// Extracts the raw socket reference via `socket2::SockRef` and configures
@@ -264,6 +289,19 @@ impl Transport {
         Ok(())
     }
 
+    /// `tls-server-end-point` channel binding data, if this is a TLS transport
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::Authentication`] if the server certificate cannot be
+    /// used for channel binding.
+    pub fn tls_server_end_point(&self) -> Result<Option<Vec<u8>>> {
+        match self {
+            Transport::Tcp(variant) => variant.tls_server_end_point(),
+            Transport::Unix(_) => Ok(None),
+        }
+    }
+
     /// Apply TCP keepalive to this transport, if it is a TCP connection.
     ///
     /// A no-op for Unix socket transports (keepalive is a TCP-layer feature).
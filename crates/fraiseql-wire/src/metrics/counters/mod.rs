@@ -151,6 +151,20 @@ pub fn connection_failed(phase: &str, error_category: &str) {
     .increment(1);
 }
 
+/// Record a query replayed on a fresh connection after its connection failed
+pub fn query_replayed() {
+    counter!("fraiseql_queries_replayed_total").increment(1);
+}
+
+/// Record a prepared statement cache lookup for a pipelined query
+pub fn statement_cache_lookup(hit: bool) {
+    counter!(
+        "fraiseql_statement_cache_lookups_total",
+        labels::STATUS => if hit { "hit" } else { "miss" },
+    )
+    .increment(1);
+}
+
 /// Record authentication attempt
 pub fn auth_attempted(mechanism: &str) {
     counter!(
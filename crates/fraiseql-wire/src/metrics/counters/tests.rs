@@ -27,6 +27,17 @@ fn test_error_occurred() {
     error_occurred("protocol", labels::PHASE_QUERY);
 }
 
+#[test]
+fn test_query_replayed() {
+    query_replayed();
+}
+
+#[test]
+fn test_statement_cache_lookup() {
+    statement_cache_lookup(true);
+    statement_cache_lookup(false);
+}
+
 #[test]
 fn test_auth_attempted() {
     auth_attempted(labels::MECHANISM_SCRAM);
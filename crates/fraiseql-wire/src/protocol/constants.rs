@@ -46,6 +46,36 @@ pub mod tags {
 
     /// `CopyBothResponse` — bidirectional COPY (streaming replication)
     pub const COPY_BOTH_RESPONSE: u8 = b'W';
+
+    /// `CopyData` — one chunk of `COPY` data (one row for `COPY ... TO STDOUT`)
+    pub const COPY_DATA: u8 = b'd';
+
+    /// `CopyDone` — end of the `COPY` data stream
+    pub const COPY_DONE: u8 = b'c';
+
+    /// `ParseComplete` (extended protocol)
+    pub const PARSE_COMPLETE: u8 = b'1';
+
+    /// `BindComplete` (extended protocol)
+    pub const BIND_COMPLETE: u8 = b'2';
+
+    /// `CloseComplete` (extended protocol)
+    pub const CLOSE_COMPLETE: u8 = b'3';
+
+    /// `NoData` — the described statement or portal returns no rows
+    pub const NO_DATA: u8 = b'n';
+
+    /// `PortalSuspended` — an `Execute` row limit was reached
+    pub const PORTAL_SUSPENDED: u8 = b's';
+}
+
+/// `Describe`/`Close` target kinds (extended protocol)
+pub mod target {
+    /// Prepared statement
+    pub const STATEMENT: u8 = b'S';
+
+    /// Portal
+    pub const PORTAL: u8 = b'P';
 }
 
 /// Authentication types
@@ -216,16 +216,26 @@ pub fn decode_message(data: &mut BytesMut) -> io::Result<(BackendMessage, usize)
         tags::ROW_DESCRIPTION => decode_row_description(msg_data)?,
         tags::EMPTY_QUERY_RESPONSE => BackendMessage::EmptyQueryResponse,
         tags::NOTIFICATION_RESPONSE => decode_notification_response(msg_data)?,
-        // The COPY family begins a bulk-transfer sub-protocol fraiseql-wire does
-        // not implement. Surfacing an explicit `Unsupported` (rather than letting
-        // the tag fall through to the unknown-tag arm) keeps the distinction
-        // honest at the dispatch table: a recognized-but-unsupported message is
-        // not the same as a malformed one, and neither may be mistaken for
-        // "need more bytes" by the read loop (audit H42).
-        tags::COPY_IN_RESPONSE | tags::COPY_OUT_RESPONSE | tags::COPY_BOTH_RESPONSE => {
+        // `COPY ... TO STDOUT` is the bulk-scan path (see `stream::copy_rows`).
+        tags::COPY_OUT_RESPONSE => decode_copy_out_response(msg_data)?,
+        tags::COPY_DATA => BackendMessage::CopyData(Bytes::copy_from_slice(msg_data)),
+        tags::COPY_DONE => BackendMessage::CopyDone,
+        // Extended-protocol acknowledgements (see `Connection::pipelined_query`).
+        tags::PARSE_COMPLETE => BackendMessage::ParseComplete,
+        tags::BIND_COMPLETE => BackendMessage::BindComplete,
+        tags::CLOSE_COMPLETE => BackendMessage::CloseComplete,
+        tags::NO_DATA => BackendMessage::NoData,
+        tags::PORTAL_SUSPENDED => BackendMessage::PortalSuspended,
+        // The inbound and bidirectional COPY sub-protocols are not implemented.
+        // Surfacing an explicit `Unsupported` (rather than letting the tag fall
+        // through to the unknown-tag arm) keeps the distinction honest at the
+        // dispatch table: a recognized-but-unsupported message is not the same
+        // as a malformed one, and neither may be mistaken for "need more bytes"
+        // by the read loop (audit H42).
+        tags::COPY_IN_RESPONSE | tags::COPY_BOTH_RESPONSE => {
             return Err(io::Error::new(
                 io::ErrorKind::Unsupported,
-                "COPY protocol is not supported by fraiseql-wire",
+                "COPY FROM STDIN and COPY BOTH are not supported by fraiseql-wire",
             ))
         }
         _ => {
@@ -485,6 +495,42 @@ fn decode_notification_response(data: &[u8]) -> io::Result<BackendMessage> {
     })
 }
 
+fn decode_copy_out_response(data: &[u8]) -> io::Result<BackendMessage> {
+    let mut cur = Cursor::new(data);
+    let format = cur
+        .read_u8()
+        .map_err(|_| io::Error::new(io::ErrorKind::UnexpectedEof, "copy format"))?;
+    let column_count_i16 = cur
+        .read_i16_be()
+        .map_err(|_| io::Error::new(io::ErrorKind::UnexpectedEof, "copy column count"))?;
+    if column_count_i16 < 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "negative copy column count",
+        ));
+    }
+    let column_count = column_count_i16 as usize;
+    if column_count > MAX_FIELD_COUNT {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "CopyOutResponse column count {column_count} exceeds maximum {MAX_FIELD_COUNT}"
+            ),
+        ));
+    }
+    let mut column_formats = Vec::with_capacity(column_count);
+    for _ in 0..column_count {
+        column_formats.push(
+            cur.read_i16_be()
+                .map_err(|_| io::Error::new(io::ErrorKind::UnexpectedEof, "copy column format"))?,
+        );
+    }
+    Ok(BackendMessage::CopyOutResponse {
+        format,
+        column_formats,
+    })
+}
+
 fn decode_ready_for_query(data: &[u8]) -> io::Result<BackendMessage> {
     let status = *data
         .first()
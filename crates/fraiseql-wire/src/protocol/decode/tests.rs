@@ -359,6 +359,48 @@ fn copy_in_response_is_explicitly_unsupported() {
     assert_eq!(err.kind(), io::ErrorKind::Unsupported);
 }
 
+#[test]
+fn copy_out_response_decodes() {
+    // 'H' CopyOutResponse starts the COPY TO STDOUT bulk-scan path.
+    let mut data = BytesMut::new();
+    data.extend_from_slice(b"H");
+    data.extend_from_slice(&9u32.to_be_bytes()); // len = 4 + format(1) + cols(2) + 1*2
+    data.extend_from_slice(&[1]); // overall binary format
+    data.extend_from_slice(&1i16.to_be_bytes());
+    data.extend_from_slice(&1i16.to_be_bytes());
+
+    let (msg, consumed) = decode_message(&mut data).unwrap();
+    assert_eq!(consumed, 10);
+    match msg {
+        BackendMessage::CopyOutResponse {
+            format,
+            column_formats,
+        } => {
+            assert_eq!(format, 1);
+            assert_eq!(column_formats, vec![1]);
+        }
+        _ => panic!("expected CopyOutResponse"),
+    }
+}
+
+#[test]
+fn copy_data_and_copy_done_decode() {
+    use bytes::Buf;
+
+    let mut data = BytesMut::new();
+    data.extend_from_slice(b"d");
+    data.extend_from_slice(&7u32.to_be_bytes());
+    data.extend_from_slice(b"{}\n");
+    data.extend_from_slice(b"c");
+    data.extend_from_slice(&4u32.to_be_bytes());
+
+    let (msg, consumed) = decode_message(&mut data).unwrap();
+    assert!(matches!(msg, BackendMessage::CopyData(ref row) if row.as_ref() == b"{}\n"));
+    data.advance(consumed);
+    let (msg, _) = decode_message(&mut data).unwrap();
+    assert!(matches!(msg, BackendMessage::CopyDone));
+}
+
 #[test]
 fn decode_message_accepts_length_at_the_cap_boundary() {
     // A header declaring exactly MAX_MESSAGE_LEN is within bounds, so the
@@ -377,3 +419,32 @@ fn decode_message_accepts_length_at_the_cap_boundary() {
         "a length at the cap must not be rejected"
     );
 }
+
+#[test]
+fn extended_protocol_acknowledgements_decode() {
+    use bytes::Buf;
+
+    let mut data = BytesMut::new();
+    for tag in [b'1', b'2', b'3', b'n', b's'] {
+        data.extend_from_slice(&[tag]);
+        data.extend_from_slice(&4u32.to_be_bytes());
+    }
+
+    let mut decoded = Vec::new();
+    while !data.is_empty() {
+        let (msg, consumed) = decode_message(&mut data).unwrap();
+        assert_eq!(consumed, 5);
+        data.advance(consumed);
+        decoded.push(msg);
+    }
+    assert!(matches!(
+        decoded.as_slice(),
+        [
+            BackendMessage::ParseComplete,
+            BackendMessage::BindComplete,
+            BackendMessage::CloseComplete,
+            BackendMessage::NoData,
+            BackendMessage::PortalSuspended,
+        ]
+    ));
+}
@@ -47,11 +47,45 @@ pub fn encode_message(msg: &FrontendMessage) -> io::Result<BytesMut> {
         FrontendMessage::SaslResponse { data } => {
             encode_sasl_response(&mut buf, data)?;
         }
+        FrontendMessage::Parse { name, query } => {
+            encode_parse(&mut buf, name, query)?;
+        }
+        FrontendMessage::Bind { portal, statement } => {
+            encode_bind(&mut buf, portal, statement)?;
+        }
+        FrontendMessage::Describe { kind, name } => {
+            encode_targeted(&mut buf, b'D', *kind, name)?;
+        }
+        FrontendMessage::Execute { portal, max_rows } => {
+            encode_execute(&mut buf, portal, *max_rows)?;
+        }
+        FrontendMessage::Close { kind, name } => {
+            encode_targeted(&mut buf, b'C', *kind, name)?;
+        }
+        FrontendMessage::Sync => {
+            encode_sync(&mut buf)?;
+        }
     }
 
     Ok(buf)
 }
 
+/// Encode several frontend messages into one buffer
+///
+/// Used to pipeline extended-protocol messages so a whole batch goes out in a
+/// single write.
+///
+/// # Errors
+///
+/// Returns `io::Error` if any message cannot be serialized.
+pub fn encode_messages(msgs: &[FrontendMessage]) -> io::Result<BytesMut> {
+    let mut buf = BytesMut::new();
+    for msg in msgs {
+        buf.unsplit(encode_message(msg)?);
+    }
+    Ok(buf)
+}
+
 fn encode_startup(buf: &mut BytesMut, version: i32, params: &[(String, String)]) -> io::Result<()> {
     // Startup messages don't have a type byte
     // Reserve space for length (will be filled at end)
@@ -150,5 +184,83 @@ fn encode_sasl_response(buf: &mut BytesMut, data: &[u8]) -> io::Result<()> {
     Ok(())
 }
 
+fn encode_parse(buf: &mut BytesMut, name: &str, query: &str) -> io::Result<()> {
+    buf.put_u8(b'P');
+    let len_pos = buf.len();
+    buf.put_i32(0);
+
+    buf.put(name.as_bytes());
+    buf.put_u8(0);
+    buf.put(query.as_bytes());
+    buf.put_u8(0);
+
+    // No parameter type OIDs
+    buf.put_i16(0);
+
+    let len = buf.len() - len_pos;
+    fill_length(buf, len_pos, len);
+
+    Ok(())
+}
+
+fn encode_bind(buf: &mut BytesMut, portal: &str, statement: &str) -> io::Result<()> {
+    buf.put_u8(b'B');
+    let len_pos = buf.len();
+    buf.put_i32(0);
+
+    buf.put(portal.as_bytes());
+    buf.put_u8(0);
+    buf.put(statement.as_bytes());
+    buf.put_u8(0);
+
+    // No parameter format codes, no parameters
+    buf.put_i16(0);
+    buf.put_i16(0);
+    // No result format codes: every column comes back as text
+    buf.put_i16(0);
+
+    let len = buf.len() - len_pos;
+    fill_length(buf, len_pos, len);
+
+    Ok(())
+}
+
+/// `Describe` and `Close` share a layout: target kind byte, then a name.
+fn encode_targeted(buf: &mut BytesMut, tag: u8, kind: u8, name: &str) -> io::Result<()> {
+    buf.put_u8(tag);
+    let len_pos = buf.len();
+    buf.put_i32(0);
+
+    buf.put_u8(kind);
+    buf.put(name.as_bytes());
+    buf.put_u8(0);
+
+    let len = buf.len() - len_pos;
+    fill_length(buf, len_pos, len);
+
+    Ok(())
+}
+
+fn encode_execute(buf: &mut BytesMut, portal: &str, max_rows: i32) -> io::Result<()> {
+    buf.put_u8(b'E');
+    let len_pos = buf.len();
+    buf.put_i32(0);
+
+    buf.put(portal.as_bytes());
+    buf.put_u8(0);
+    buf.put_i32(max_rows);
+
+    let len = buf.len() - len_pos;
+    fill_length(buf, len_pos, len);
+
+    Ok(())
+}
+
+fn encode_sync(buf: &mut BytesMut) -> io::Result<()> {
+    buf.put_u8(b'S');
+    buf.put_i32(4); // Length includes itself
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests;
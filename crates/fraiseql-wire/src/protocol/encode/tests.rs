@@ -20,3 +20,57 @@ fn test_encode_terminate() {
     assert_eq!(buf[0], b'X');
     assert_eq!(buf.len(), 5);
 }
+
+#[test]
+fn test_encode_parse_without_parameter_types() {
+    let msg = FrontendMessage::Parse {
+        name: "s1".to_string(),
+        query: "SELECT 1".to_string(),
+    };
+    let buf = encode_message(&msg).unwrap();
+
+    assert_eq!(buf[0], b'P');
+    let len = i32::from_be_bytes([buf[1], buf[2], buf[3], buf[4]]);
+    assert_eq!(len, (buf.len() - 1) as i32);
+    assert_eq!(&buf[5..], b"s1\0SELECT 1\0\0\0");
+}
+
+#[test]
+fn test_encode_bind_requests_text_results() {
+    let msg = FrontendMessage::Bind {
+        portal: String::new(),
+        statement: "s1".to_string(),
+    };
+    let buf = encode_message(&msg).unwrap();
+
+    assert_eq!(buf[0], b'B');
+    assert_eq!(&buf[5..], b"\0s1\0\0\0\0\0\0\0");
+}
+
+#[test]
+fn test_encode_pipeline_in_one_buffer() {
+    let msgs = [
+        FrontendMessage::Describe {
+            kind: b'P',
+            name: String::new(),
+        },
+        FrontendMessage::Execute {
+            portal: String::new(),
+            max_rows: 0,
+        },
+        FrontendMessage::Close {
+            kind: b'S',
+            name: "s1".to_string(),
+        },
+        FrontendMessage::Sync,
+    ];
+    let buf = encode_messages(&msgs).unwrap();
+
+    let expected: &[u8] = &[
+        b'D', 0, 0, 0, 6, b'P', 0, // Describe portal ""
+        b'E', 0, 0, 0, 9, 0, 0, 0, 0, 0, // Execute "" (no row limit)
+        b'C', 0, 0, 0, 8, b'S', b's', b'1', 0, // Close statement "s1"
+        b'S', 0, 0, 0, 4, // Sync
+    ];
+    assert_eq!(&buf[..], expected);
+}
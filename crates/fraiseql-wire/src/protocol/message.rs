@@ -36,6 +36,53 @@ pub enum FrontendMessage {
         /// SASL client final message data
         data: Vec<u8>,
     },
+
+    /// Parse message (extended protocol): prepare `query` as statement `name`.
+    ///
+    /// An empty `name` targets the unnamed statement. Parameter types are
+    /// never sent; fraiseql-wire queries carry no bind parameters.
+    Parse {
+        /// Prepared statement name
+        name: String,
+        /// SQL text
+        query: String,
+    },
+
+    /// Bind message (extended protocol): bind `statement` to `portal` with no
+    /// parameters and text result columns.
+    Bind {
+        /// Destination portal name (empty = unnamed portal)
+        portal: String,
+        /// Source prepared statement name
+        statement: String,
+    },
+
+    /// Describe message (extended protocol)
+    Describe {
+        /// `b'S'` to describe a statement, `b'P'` to describe a portal
+        kind: u8,
+        /// Statement or portal name
+        name: String,
+    },
+
+    /// Execute message (extended protocol)
+    Execute {
+        /// Portal name
+        portal: String,
+        /// Maximum number of rows to return (0 = no limit)
+        max_rows: i32,
+    },
+
+    /// Close message (extended protocol): release a statement or portal
+    Close {
+        /// `b'S'` to close a statement, `b'P'` to close a portal
+        kind: u8,
+        /// Statement or portal name
+        name: String,
+    },
+
+    /// Sync message: ends an extended-protocol pipeline
+    Sync,
 }
 
 /// Backend message (server → client)
@@ -102,6 +149,38 @@ pub enum BackendMessage {
         /// Payload string (empty when `NOTIFY` carried no payload).
         payload: String,
     },
+
+    /// Start of a `COPY ... TO STDOUT` data stream.
+    ///
+    /// Sent in place of `RowDescription`; the rows follow as `CopyData`
+    /// messages, then `CopyDone`, `CommandComplete` and `ReadyForQuery`.
+    CopyOutResponse {
+        /// Overall format: 0 = text, 1 = binary.
+        format: u8,
+        /// Per-column format codes (0 = text, 1 = binary).
+        column_formats: Vec<i16>,
+    },
+
+    /// One chunk of `COPY` data. PostgreSQL sends one row per message.
+    CopyData(Bytes),
+
+    /// End of the `COPY` data stream.
+    CopyDone,
+
+    /// The server finished a `Parse` (extended protocol).
+    ParseComplete,
+
+    /// The server finished a `Bind` (extended protocol).
+    BindComplete,
+
+    /// The server finished a `Close` (extended protocol).
+    CloseComplete,
+
+    /// A described portal or statement returns no rows.
+    NoData,
+
+    /// An `Execute` row limit was reached before the portal completed.
+    PortalSuspended,
 }
 
 /// Authentication message types
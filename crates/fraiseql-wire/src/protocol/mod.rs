@@ -5,12 +5,14 @@
 //!
 //! * Startup and authentication
 //! * Simple Query protocol
+//! * Extended Query protocol for parameterless, pipelined statements
+//!   (`Parse`/`Bind`/`Describe`/`Execute`/`Sync`)
 //! * Result streaming (`RowDescription`, `DataRow`)
 //! * Error handling
 //!
 //! Explicitly NOT supported:
-//! * Extended Query protocol (prepared statements)
-//! * COPY protocol
+//! * Bind parameters
+//! * COPY protocol (other than `COPY ... TO STDOUT`)
 //! * Transactions
 //! * Multi-statement queries
 
@@ -20,7 +22,7 @@ pub mod encode;
 pub mod message;
 
 pub use decode::decode_message;
-pub use encode::encode_message;
+pub use encode::{encode_message, encode_messages};
 pub use message::{
     AuthenticationMessage, BackendMessage, ErrorFields, FieldDescription, FrontendMessage,
 };
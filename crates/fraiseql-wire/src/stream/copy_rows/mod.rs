@@ -0,0 +1,260 @@
+//! `COPY ... TO STDOUT` row decoding
+//!
+//! Large unordered scans can run as
+//! `COPY (SELECT data::text FROM v_entity WHERE ...) TO STDOUT`, which skips
+//! the per-row `DataRow` framing and tuple descriptor work on the server. The
+//! rows arrive as `CopyData` messages, one row each. [`CopyRowDecoder`] turns
+//! them back into single-field `DataRow`s so the rest of the streaming
+//! pipeline (chunking, JSON parsing, metrics) is shared with the normal path.
+
+use crate::protocol::BackendMessage;
+use crate::{Result, WireError};
+use bytes::Bytes;
+
+/// Signature that opens a binary `COPY` stream.
+const BINARY_SIGNATURE: &[u8] = b"PGCOPY\n\xff\r\n\0";
+
+/// Wire format of a `COPY ... TO STDOUT` stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CopyFormat {
+    /// Text format: one escaped line per row.
+    Text,
+    /// Binary format: length-prefixed fields, no escaping.
+    Binary,
+}
+
+impl CopyFormat {
+    /// `COPY` option naming this format.
+    #[must_use]
+    pub const fn sql_option(self) -> &'static str {
+        match self {
+            Self::Text => "FORMAT text",
+            Self::Binary => "FORMAT binary",
+        }
+    }
+}
+
+/// Wrap a `SELECT` in `COPY (...) TO STDOUT` in the given format.
+///
+/// The select list must be the single JSON column cast to `text`: the binary
+/// `jsonb` representation carries a version prefix, while `text` is the raw
+/// JSON document in both formats.
+#[must_use]
+pub fn copy_statement(select_sql: &str, format: CopyFormat) -> String {
+    format!("COPY ({select_sql}) TO STDOUT ({})", format.sql_option())
+}
+
+/// Converts `COPY` messages into the `DataRow` shape the stream reader expects.
+#[derive(Debug)]
+pub struct CopyRowDecoder {
+    format: CopyFormat,
+    header_seen: bool,
+}
+
+impl CopyRowDecoder {
+    /// Create a decoder for a stream announced by `CopyOutResponse`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::Protocol`] if `msg` is not a single-column
+    /// `CopyOutResponse` or uses an unknown format code.
+    pub fn from_copy_out_response(msg: &BackendMessage) -> Result<Self> {
+        let BackendMessage::CopyOutResponse {
+            format,
+            column_formats,
+        } = msg
+        else {
+            return Err(WireError::Protocol("expected CopyOutResponse".into()));
+        };
+        if column_formats.len() != 1 {
+            return Err(WireError::InvalidSchema(format!(
+                "COPY must produce exactly one column, got {}",
+                column_formats.len()
+            )));
+        }
+        let format = match format {
+            0 => CopyFormat::Text,
+            1 => CopyFormat::Binary,
+            other => {
+                return Err(WireError::Protocol(format!(
+                    "unknown COPY format code: {other}"
+                )));
+            }
+        };
+        Ok(Self::new(format))
+    }
+
+    /// Create a decoder for `format`.
+    #[must_use]
+    pub const fn new(format: CopyFormat) -> Self {
+        Self {
+            format,
+            header_seen: false,
+        }
+    }
+
+    /// Translate one backend message read during a `COPY` stream.
+    ///
+    /// `CopyData` carrying a row becomes a single-field `DataRow` (a SQL
+    /// `NULL` becomes a null field, which the reader rejects like any other
+    /// null `data`). `CopyData` carrying only the binary header or trailer,
+    /// and `CopyDone`, yield `None`. Every other message passes through.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WireError::Protocol`] if a `CopyData` payload is malformed.
+    pub fn translate(&mut self, msg: BackendMessage) -> Result<Option<BackendMessage>> {
+        match msg {
+            BackendMessage::CopyData(data) => Ok(self
+                .decode_row(data)?
+                .map(|field| BackendMessage::DataRow(vec![field]))),
+            BackendMessage::CopyDone => Ok(None),
+            other => Ok(Some(other)),
+        }
+    }
+
+    /// Decode one `CopyData` payload.
+    ///
+    /// Returns `None` for payloads that carry no row, `Some(None)` for a SQL
+    /// `NULL` and `Some(Some(bytes))` for a value.
+    fn decode_row(&mut self, data: Bytes) -> Result<Option<Option<Bytes>>> {
+        match self.format {
+            CopyFormat::Text => decode_text_row(&data).map(Some),
+            CopyFormat::Binary => self.decode_binary_row(data),
+        }
+    }
+
+    fn decode_binary_row(&mut self, data: Bytes) -> Result<Option<Option<Bytes>>> {
+        let mut offset = 0;
+        if !self.header_seen {
+            if !data.starts_with(BINARY_SIGNATURE) {
+                return Err(WireError::Protocol("missing binary COPY signature".into()));
+            }
+            offset = BINARY_SIGNATURE.len();
+            let _flags = read_i32(&data, &mut offset)?;
+            let extension_len = read_i32(&data, &mut offset)?;
+            let extension_len = usize::try_from(extension_len)
+                .map_err(|_| WireError::Protocol("negative COPY header extension".into()))?;
+            offset = offset
+                .checked_add(extension_len)
+                .filter(|end| *end <= data.len())
+                .ok_or_else(|| WireError::Protocol("truncated COPY header extension".into()))?;
+            self.header_seen = true;
+            if offset == data.len() {
+                return Ok(None);
+            }
+        }
+
+        let field_count = read_i16(&data, &mut offset)?;
+        match field_count {
+            // File trailer.
+            -1 => return Ok(None),
+            1 => {}
+            n => {
+                return Err(WireError::InvalidSchema(format!(
+                    "COPY must produce exactly one column, got {n}"
+                )));
+            }
+        }
+        let len = read_i32(&data, &mut offset)?;
+        if len == -1 {
+            return Ok(Some(None));
+        }
+        let len = usize::try_from(len)
+            .map_err(|_| WireError::Protocol("negative COPY field length".into()))?;
+        let end = offset
+            .checked_add(len)
+            .filter(|end| *end <= data.len())
+            .ok_or_else(|| WireError::Protocol("truncated COPY field".into()))?;
+        Ok(Some(Some(data.slice(offset..end))))
+    }
+}
+
+/// Decode one text-format row: strip the line terminator and undo escaping.
+fn decode_text_row(data: &Bytes) -> Result<Option<Bytes>> {
+    let line = data.strip_suffix(b"\n").unwrap_or(data.as_ref());
+    if line == b"\\N" {
+        return Ok(None);
+    }
+    if !line.contains(&b'\\') {
+        // Common case: nothing escaped, hand out a view of the message.
+        return Ok(Some(data.slice(..line.len())));
+    }
+
+    let mut out = Vec::with_capacity(line.len());
+    let mut bytes = line.iter().copied().peekable();
+    while let Some(byte) = bytes.next() {
+        if byte != b'\\' {
+            out.push(byte);
+            continue;
+        }
+        let Some(escaped) = bytes.next() else {
+            return Err(WireError::Protocol("dangling backslash in COPY row".into()));
+        };
+        let decoded = match escaped {
+            b'b' => 0x08,
+            b'f' => 0x0c,
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'v' => 0x0b,
+            b'0'..=b'7' => {
+                let mut value = u32::from(escaped - b'0');
+                for _ in 0..2 {
+                    match bytes.peek() {
+                        Some(digit @ b'0'..=b'7') => {
+                            value = value * 8 + u32::from(digit - b'0');
+                            bytes.next();
+                        }
+                        _ => break,
+                    }
+                }
+                (value & 0xff) as u8
+            }
+            b'x' => {
+                let mut value = 0u8;
+                let mut digits = 0;
+                while digits < 2 {
+                    match bytes.peek().and_then(|d| char::from(*d).to_digit(16)) {
+                        Some(digit) => {
+                            value = value * 16 + digit as u8;
+                            bytes.next();
+                            digits += 1;
+                        }
+                        None => break,
+                    }
+                }
+                if digits == 0 {
+                    b'x'
+                } else {
+                    value
+                }
+            }
+            other => other,
+        };
+        out.push(decoded);
+    }
+    Ok(Some(Bytes::from(out)))
+}
+
+fn read_i16(data: &[u8], offset: &mut usize) -> Result<i16> {
+    let bytes = data
+        .get(*offset..*offset + 2)
+        .and_then(|b| <[u8; 2]>::try_from(b).ok())
+        .ok_or_else(|| WireError::Protocol("truncated COPY data".into()))?;
+    *offset += 2;
+    Ok(i16::from_be_bytes(bytes))
+}
+
+fn read_i32(data: &[u8], offset: &mut usize) -> Result<i32> {
+    let bytes = data
+        .get(*offset..*offset + 4)
+        .and_then(|b| <[u8; 4]>::try_from(b).ok())
+        .ok_or_else(|| WireError::Protocol("truncated COPY data".into()))?;
+    *offset += 4;
+    Ok(i32::from_be_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests;
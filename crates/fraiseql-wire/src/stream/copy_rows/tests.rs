@@ -0,0 +1,159 @@
+#![allow(clippy::unwrap_used, clippy::panic)] // Reason: test code, panics acceptable
+use super::*;
+
+fn copy_data(payload: &[u8]) -> BackendMessage {
+    BackendMessage::CopyData(Bytes::copy_from_slice(payload))
+}
+
+fn row_field(msg: Option<BackendMessage>) -> Option<Bytes> {
+    match msg {
+        Some(BackendMessage::DataRow(mut fields)) if fields.len() == 1 => fields.pop().unwrap(),
+        other => panic!("expected single-field DataRow, got {other:?}"),
+    }
+}
+
+fn binary_tuple(value: Option<&[u8]>) -> Vec<u8> {
+    let mut out = 1i16.to_be_bytes().to_vec();
+    match value {
+        Some(value) => {
+            out.extend_from_slice(&(value.len() as i32).to_be_bytes());
+            out.extend_from_slice(value);
+        }
+        None => out.extend_from_slice(&(-1i32).to_be_bytes()),
+    }
+    out
+}
+
+fn binary_header() -> Vec<u8> {
+    let mut out = BINARY_SIGNATURE.to_vec();
+    out.extend_from_slice(&0i32.to_be_bytes()); // flags
+    out.extend_from_slice(&0i32.to_be_bytes()); // header extension length
+    out
+}
+
+#[test]
+fn copy_statement_wraps_select() {
+    assert_eq!(
+        copy_statement("SELECT data::text FROM v_user", CopyFormat::Binary),
+        "COPY (SELECT data::text FROM v_user) TO STDOUT (FORMAT binary)"
+    );
+}
+
+#[test]
+fn decoder_reads_format_from_copy_out_response() {
+    let text = BackendMessage::CopyOutResponse {
+        format: 0,
+        column_formats: vec![0],
+    };
+    assert_eq!(
+        CopyRowDecoder::from_copy_out_response(&text)
+            .unwrap()
+            .format,
+        CopyFormat::Text
+    );
+
+    let two_columns = BackendMessage::CopyOutResponse {
+        format: 1,
+        column_formats: vec![1, 1],
+    };
+    assert!(matches!(
+        CopyRowDecoder::from_copy_out_response(&two_columns),
+        Err(WireError::InvalidSchema(_))
+    ));
+}
+
+#[test]
+fn text_row_without_escapes() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Text);
+    let field = row_field(decoder.translate(copy_data(b"{\"id\": 1}\n")).unwrap());
+    assert_eq!(field.unwrap().as_ref(), b"{\"id\": 1}");
+}
+
+#[test]
+fn text_row_unescapes_backslash_sequences() {
+    // jsonb text of {"s": "a\"b\\c"} goes through COPY text escaping, which
+    // doubles every backslash.
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Text);
+    let field = row_field(
+        decoder
+            .translate(copy_data(b"{\"s\": \"a\\\\\"b\\\\\\\\c\\tx\"}\n"))
+            .unwrap(),
+    );
+    assert_eq!(field.unwrap().as_ref(), b"{\"s\": \"a\\\"b\\\\c\tx\"}");
+}
+
+#[test]
+fn text_row_octal_and_hex_escapes() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Text);
+    let field = row_field(decoder.translate(copy_data(b"\\101\\x42\\n\n")).unwrap());
+    assert_eq!(field.unwrap().as_ref(), b"AB\n");
+}
+
+#[test]
+fn text_null_becomes_null_field() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Text);
+    assert!(row_field(decoder.translate(copy_data(b"\\N\n")).unwrap()).is_none());
+}
+
+#[test]
+fn binary_header_first_row_and_trailer() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Binary);
+
+    let mut first = binary_header();
+    first.extend(binary_tuple(Some(b"{\"id\":1}")));
+    let field = row_field(decoder.translate(copy_data(&first)).unwrap());
+    assert_eq!(field.unwrap().as_ref(), b"{\"id\":1}");
+
+    let field = row_field(
+        decoder
+            .translate(copy_data(&binary_tuple(Some(b"{}"))))
+            .unwrap(),
+    );
+    assert_eq!(field.unwrap().as_ref(), b"{}");
+
+    let field = row_field(decoder.translate(copy_data(&binary_tuple(None))).unwrap());
+    assert!(field.is_none());
+
+    let trailer = (-1i16).to_be_bytes();
+    assert!(decoder.translate(copy_data(&trailer)).unwrap().is_none());
+    assert!(decoder
+        .translate(BackendMessage::CopyDone)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn binary_header_alone_yields_no_row() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Binary);
+    assert!(decoder
+        .translate(copy_data(&binary_header()))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn binary_rejects_missing_signature_and_truncation() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Binary);
+    assert!(matches!(
+        decoder.translate(copy_data(&binary_tuple(Some(b"{}")))),
+        Err(WireError::Protocol(_))
+    ));
+
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Binary);
+    let mut truncated = binary_header();
+    truncated.extend(binary_tuple(Some(b"{\"id\":1}")));
+    truncated.truncate(truncated.len() - 3);
+    assert!(matches!(
+        decoder.translate(copy_data(&truncated)),
+        Err(WireError::Protocol(_))
+    ));
+}
+
+#[test]
+fn non_copy_messages_pass_through() {
+    let mut decoder = CopyRowDecoder::new(CopyFormat::Text);
+    let msg = decoder
+        .translate(BackendMessage::CommandComplete("COPY 3".into()))
+        .unwrap();
+    assert!(matches!(msg, Some(BackendMessage::CommandComplete(tag)) if tag == "COPY 3"));
+}
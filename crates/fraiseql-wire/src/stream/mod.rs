@@ -2,6 +2,7 @@
 
 mod adaptive_chunking;
 mod chunking;
+mod copy_rows;
 mod filter;
 mod json_stream;
 mod memory_estimator;
@@ -10,6 +11,7 @@ mod typed_stream;
 
 pub use adaptive_chunking::AdaptiveChunking;
 pub use chunking::{ChunkingStrategy, RowChunk};
+pub use copy_rows::{copy_statement, CopyFormat, CopyRowDecoder};
 pub use filter::{FilteredStream, Predicate};
 pub use json_stream::{extract_json_bytes, parse_json, JsonStream, StreamState, StreamStats};
 pub use memory_estimator::{ConservativeEstimator, FixedEstimator, MemoryEstimator};
@@ -25,3 +25,53 @@ async fn test_connect_and_query() {
 
     conn.close().await.expect("close");
 }
+
+#[tokio::test]
+async fn test_pipelined_query_reuses_prepared_statements() {
+    use fraiseql_wire::protocol::BackendMessage;
+
+    let container = get_test_container().await;
+
+    let transport = Transport::connect_tcp(&container.host, container.port)
+        .await
+        .expect("connect");
+
+    let mut conn = Connection::new(transport);
+
+    let config = ConnectionConfig::builder(&container.database, &container.user)
+        .password(&container.password)
+        .statement_cache_capacity(2)
+        .build();
+    conn.startup(&config).await.expect("startup");
+
+    // The repeat hits the cache; the third distinct query evicts "SELECT 2".
+    let queries = ["SELECT 1", "SELECT 2", "SELECT 1", "SELECT 3"];
+    for _ in 0..2 {
+        let results = conn.pipelined_query(&queries).await.expect("pipeline");
+        assert_eq!(results.len(), queries.len());
+        for messages in &results {
+            assert!(matches!(
+                messages.first(),
+                Some(BackendMessage::RowDescription(_))
+            ));
+            assert!(matches!(
+                messages.last(),
+                Some(BackendMessage::CommandComplete(_))
+            ));
+        }
+    }
+
+    // A failing query aborts the batch but leaves the connection usable.
+    let err = conn
+        .pipelined_query(&["SELECT 1", "SELECT * FROM no_such_table"])
+        .await
+        .expect_err("missing table");
+    assert!(matches!(err, fraiseql_wire::WireError::Sql(_)));
+    let results = conn
+        .pipelined_query(&["SELECT 1"])
+        .await
+        .expect("after error");
+    assert_eq!(results.len(), 1);
+
+    conn.close().await.expect("close");
+}
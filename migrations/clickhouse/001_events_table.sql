@@ -7,7 +7,7 @@ CREATE TABLE IF NOT EXISTS fraiseql_events (
     event_type String,
     entity_type String,
     entity_id String,
-    timestamp DateTime('UTC'),  -- Stored as DateTime for efficient aggregations
+    timestamp DateTime64(6, 'UTC'),  -- Microseconds, as written by the ClickHouse sink
     data String,  -- Event data as JSON
     user_id Nullable(String),
     org_id Nullable(String)
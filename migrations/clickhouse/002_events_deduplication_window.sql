@@ -0,0 +1,7 @@
+-- Deduplication of retried ClickHouseSink flushes
+-- Non-replicated MergeTree tables ignore `insert_deduplication_token` unless a
+-- deduplication window is set. ALTER rather than a change to 001, so tables
+-- created by earlier migrations (or by the sink itself) get the setting too.
+
+ALTER TABLE fraiseql_events
+    MODIFY SETTING non_replicated_deduplication_window = 1000;  -- Block hashes kept to drop retried inserts